@@ -1,11 +1,13 @@
-import { GeneratedCommand, GeneratorProps } from "@project-gauntlet/api/helpers";
-import { walk, WalkOptions } from "@std/fs/walk";
-import { debounce } from "@std/async/debounce";
+import { GeneratedCommandAction, GeneratedCommandPreview, GeneratorProps } from "@project-gauntlet/api/helpers";
+import { applicationGenerator } from "./generic-generator";
+import { trackRunningApplications } from "./running-applications";
 import { current_os } from "gauntlet:bridge/internal-all";
 import {
     linux_app_from_path,
     linux_application_dirs,
     linux_open_application,
+    linux_open_application_action,
+    linux_focus_application_window,
 } from "gauntlet:bridge/internal-linux";
 import {
     macos_app_from_arbitrary_path,
@@ -13,31 +15,116 @@ import {
     macos_application_dirs,
     macos_major_version,
     macos_open_application,
+    macos_show_application_in_finder,
     macos_open_setting_13_and_post,
     macos_open_setting_pre_13,
     macos_settings_13_and_post,
     macos_settings_pre_13,
     macos_system_applications
 } from "gauntlet:bridge/internal-macos";
+import {
+    windows_app_from_path,
+    windows_application_dirs,
+    windows_open_application,
+    windows_open_uwp_application,
+    windows_show_application_in_explorer,
+    windows_uwp_applications,
+} from "gauntlet:bridge/internal-windows";
 
 export default async function Applications({ add, remove }: GeneratorProps): Promise<void | (() => void)> {
     switch (current_os()) {
         case "linux": {
-            return await genericGenerator(
+            const tracker = trackRunningApplications<LinuxDesktopApplicationData>(
+                (data, executablePaths) => {
+                    return !!data.executable && executablePaths.some(path => path.split("/").pop() === data.executable)
+                },
+                (id, data, running) => {
+                    const desktopActions: GeneratedCommandAction[] = data.actions.map(action => ({
+                        label: action.name,
+                        fn: async () => {
+                            await linux_open_application_action(data.path, action.id)
+                        },
+                    }));
+
+                    if (running) {
+                        return {
+                            name: data.name,
+                            category: "application",
+                            fn: async () => {
+                                try {
+                                    const focused = await linux_focus_application_window(data.executable, data.startup_wm_class);
+                                    if (focused) {
+                                        return
+                                    }
+                                } catch (e) {
+                                    console.error("Unable to switch to running window", e)
+                                }
+
+                                linux_open_application(id)
+                            },
+                            icon: data.icon,
+                            accessory: "Running",
+                            preview: () => applicationPreview(data.path, data.executable, true),
+                            actions: [
+                                {
+                                    label: "Launch New Instance",
+                                    fn: () => {
+                                        linux_open_application(id)
+                                    },
+                                },
+                                ...desktopActions
+                            ],
+                        }
+                    } else {
+                        return {
+                            name: data.name,
+                            category: "application",
+                            fn: () => {
+                                linux_open_application(id)
+                            },
+                            icon: data.icon, // TODO lazy icons
+                            preview: () => applicationPreview(data.path, data.executable, false),
+                            actions: desktopActions,
+                        }
+                    }
+                },
+                add,
+                remove
+            );
+
+            const cleanup = await applicationGenerator(
                 linux_application_dirs(),
                 path => linux_app_from_path(path),
-                (id, data) => ({
+                tracker.commandFromApp,
+                add,
+                tracker.remove
+            );
+
+            return () => {
+                tracker.stop()
+                cleanup()
+            }
+        }
+        case "macos": {
+            // opening application that is already running brings its windows to front
+            const tracker = trackRunningApplications<MacOSDesktopApplicationData>(
+                (data, executablePaths) => executablePaths.some(path => path.startsWith(data.path + "/")),
+                (_id, data, running) => ({
                     name: data.name,
+                    category: "application",
                     fn: () => {
-                        linux_open_application(id)
+                        macos_open_application(data.path)
                     },
-                    icon: data.icon, // TODO lazy icons
+                    icon: data.icon,
+                    accessory: running ? "Running" : undefined,
+                    preview: () => applicationPreview(data.path, undefined, running),
+                    drag: { file: data.path },
+                    actions: [showInFinderAction(data.path)],
                 }),
                 add,
                 remove
             );
-        }
-        case "macos": {
+
             const majorVersion = macos_major_version();
 
             if (majorVersion >= 13) {
@@ -68,13 +155,7 @@ export default async function Applications({ add, remove }: GeneratorProps): Pro
                     switch (app.type) {
                         case "add": {
                             let data = app.data;
-                            add(data.path, {
-                                name: data.name,
-                                fn: () => {
-                                    macos_open_application(data.path)
-                                },
-                                icon: data.icon,
-                            })
+                            add(data.path, tracker.commandFromApp(data.path, data))
                             break;
                         }
                     }
@@ -83,94 +164,81 @@ export default async function Applications({ add, remove }: GeneratorProps): Pro
                 }
             }
 
-            return await genericGenerator(
+            const cleanup = await applicationGenerator(
                 macos_application_dirs(),
                 path => macos_app_from_arbitrary_path(path),
+                tracker.commandFromApp,
+                add,
+                tracker.remove,
+                { exts: ["app"], maxDepth: 2 }
+            );
+
+            return () => {
+                tracker.stop()
+                cleanup()
+            }
+        }
+        case "windows": {
+            try {
+                for (const app of await windows_uwp_applications()) {
+                    add(`uwp:${app.app_id}`, {
+                        name: app.name,
+                        category: "application",
+                        fn: () => {
+                            windows_open_uwp_application(app.app_id)
+                        },
+                        icon: undefined,
+                    })
+                }
+            } catch (e) {
+                console.error("Unable to load packaged applications", e)
+            }
+
+            return await applicationGenerator(
+                windows_application_dirs(),
+                path => windows_app_from_path(path),
                 (_id, data) => ({
                     name: data.name,
+                    category: "application",
                     fn: () => {
-                        macos_open_application(data.path)
+                        windows_open_application(data.path)
                     },
                     icon: data.icon,
+                    drag: { file: data.path },
+                    actions: [
+                        {
+                            label: "Show in Explorer",
+                            fn: () => {
+                                windows_show_application_in_explorer(data.path)
+                            },
+                        }
+                    ],
                 }),
                 add,
                 remove,
-                { exts: ["app"], maxDepth: 2 }
+                { exts: ["lnk"] }
             );
         }
     }
 }
 
-async function genericGenerator<DATA>(
-    directoriesToWatch: string[],
-    appFromPath: (path: string) => Promise<undefined | DesktopPathAction<DATA>>,
-    commandFromApp: (id: string, data: DATA) => GeneratedCommand,
-    add: (id: string, data: GeneratedCommand) => void,
-    remove: (id: string) => void,
-    walkOpts?: WalkOptions
-): Promise<() => void> {
-    const paths = directoriesToWatch
-        .filter(path => {
-            try {
-                Deno.lstatSync(path)
-                return true
-            } catch (err) {
-                // most frequent error here is NotFound
-                return false
-            }
-        });
-
-    for (const path of paths) {
-        for await (const dirEntry of walk(path, walkOpts)) {
-            const app = await appFromPath(dirEntry.path);
-            if (app) {
-                switch (app.type) {
-                    case "add": {
-                        add(app.id, commandFromApp(app.id, app.data))
-                        break;
-                    }
-                }
-            }
-        }
+function applicationPreview(path: string, executable: string | undefined, running: boolean): GeneratedCommandPreview {
+    const metadata = [{ label: "Path", value: path }];
+
+    if (executable) {
+        metadata.push({ label: "Executable", value: executable })
     }
 
-    const watcher = Deno.watchFs(paths);
-
-    const handle = debounce(
-        async (event: Deno.FsEvent) => {
-            switch (event.kind) {
-                case "create":
-                case "modify":
-                case "remove": {
-                    for (const path of event.paths) {
-                        const app = await appFromPath(path);
-                        if (app) {
-                            switch (app.type) {
-                                case "remove": {
-                                    remove(app.id)
-                                    break;
-                                }
-                                case "add": {
-                                    add(app.id, commandFromApp(app.id, app.data))
-                                    break;
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        },
-        1000
-    );
+    metadata.push({ label: "Running", value: running ? "Yes" : "No" })
 
-    // noinspection ES6MissingAwait
-    (async () => {
-        for await (const event of watcher) {
-            handle(event)
-        }
-    })()
+    return { metadata }
+}
 
-    return () => {
-        watcher.close()
+function showInFinderAction(path: string): GeneratedCommandAction {
+    return {
+        label: "Show in Finder",
+        fn: () => {
+            macos_show_application_in_finder(path)
+        },
     }
 }
@@ -0,0 +1,166 @@
+import { Clipboard, GeneratorProps, isIdle, onIdleChange, onPreferenceChange, pluginPreferences } from "@project-gauntlet/api/helpers";
+import { debounce } from "@std/async/debounce";
+import { bookmark_sources, open_bookmark, read_bookmarks } from "gauntlet:bridge/internal-all";
+
+type BookmarksPreferences = {
+    bookmarksFirefox: boolean
+    bookmarksChrome: boolean
+    bookmarksChromium: boolean
+    bookmarksBrave: boolean
+    bookmarksEdge: boolean
+    bookmarksVivaldi: boolean
+}
+
+const browserPreferences: Record<BookmarkBrowser, keyof BookmarksPreferences> = {
+    firefox: "bookmarksFirefox",
+    chrome: "bookmarksChrome",
+    chromium: "bookmarksChromium",
+    brave: "bookmarksBrave",
+    edge: "bookmarksEdge",
+    vivaldi: "bookmarksVivaldi",
+}
+
+const browserNames: Record<BookmarkBrowser, string> = {
+    firefox: "Firefox",
+    chrome: "Google Chrome",
+    chromium: "Chromium",
+    brave: "Brave",
+    edge: "Microsoft Edge",
+    vivaldi: "Vivaldi",
+}
+
+export default async function Bookmarks({ add, remove }: GeneratorProps): Promise<() => void> {
+    const sources = bookmark_sources();
+
+    // title of every added bookmark by source path, to only add bookmarks that changed
+    const addedBookmarks = new Map<string, Map<string, string>>();
+
+    const syncSource = async (source: BookmarkSource) => {
+        const preferences = pluginPreferences<BookmarksPreferences>();
+        const enabled = preferences[browserPreferences[source.browser]] ?? true;
+
+        let bookmarks: Bookmark[] = [];
+        if (enabled) {
+            try {
+                bookmarks = await read_bookmarks(source)
+            } catch (e) {
+                console.error(`Unable to read ${browserNames[source.browser]} bookmarks from '${source.path}'`, e)
+                return
+            }
+        }
+
+        const previous = addedBookmarks.get(source.path) ?? new Map<string, string>();
+        const current = new Map<string, string>();
+
+        for (const bookmark of bookmarks) {
+            const id = `${source.browser}:${source.profile}:${bookmark.url}`;
+
+            // same url can be bookmarked in multiple folders
+            if (current.has(id)) {
+                continue
+            }
+
+            current.set(id, bookmark.title)
+
+            if (previous.get(id) === bookmark.title) {
+                continue
+            }
+
+            add(id, {
+                name: bookmark.title,
+                category: "quicklink",
+                fn: () => {
+                    open_bookmark(source.browser, bookmark.url)
+                },
+                actions: [
+                    {
+                        label: "Copy URL",
+                        fn: async () => {
+                            await Clipboard.writeText(bookmark.url)
+                        },
+                    }
+                ],
+            })
+        }
+
+        for (const id of previous.keys()) {
+            if (!current.has(id)) {
+                remove(id)
+            }
+        }
+
+        addedBookmarks.set(source.path, current)
+    }
+
+    const syncAll = async () => {
+        for (const source of sources) {
+            await syncSource(source)
+        }
+    }
+
+    await syncAll()
+
+    // firefox writes changes to write-ahead log next to database file first,
+    // so the whole profile directory is watched
+    const watchedDirs = [...new Set(sources.map(source => parentDir(source.path)))];
+    const watcher = watchedDirs.length > 0 ? Deno.watchFs(watchedDirs, { recursive: false }) : undefined;
+
+    const changedSources = new Set<BookmarkSource>();
+
+    const handle = debounce(
+        async () => {
+            const sourcesToSync = [...changedSources];
+            changedSources.clear()
+
+            for (const source of sourcesToSync) {
+                await syncSource(source)
+            }
+        },
+        5000
+    );
+
+    // noinspection ES6MissingAwait
+    (async () => {
+        if (!watcher) {
+            return
+        }
+
+        for await (const event of watcher) {
+            for (const path of event.paths) {
+                for (const source of sources) {
+                    if (path.startsWith(source.path)) {
+                        changedSources.add(source)
+
+                        // changed sources are synced once plugin is resumed
+                        if (!isIdle()) {
+                            handle()
+                        }
+                    }
+                }
+            }
+        }
+    })()
+
+    const removeIdleListener = onIdleChange(idle => {
+        if (!idle && changedSources.size > 0) {
+            handle()
+        }
+    });
+
+    const removeListener = onPreferenceChange(event => {
+        if (event.entrypointId === undefined && Object.values(browserPreferences).includes(event.preferenceId as keyof BookmarksPreferences)) {
+            // noinspection ES6MissingAwait
+            syncAll()
+        }
+    });
+
+    return () => {
+        watcher?.close()
+        removeListener()
+        removeIdleListener()
+    }
+}
+
+function parentDir(path: string): string {
+    return path.substring(0, Math.max(path.lastIndexOf("/"), path.lastIndexOf("\\")))
+}
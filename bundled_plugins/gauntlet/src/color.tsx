@@ -0,0 +1,174 @@
+import { Action, ActionPanel, Content, Icons, Inline } from "@project-gauntlet/api/components";
+import { ReactNode } from "react";
+import { Clipboard, showHud } from "@project-gauntlet/api/helpers";
+
+type Rgba = { r: number, g: number, b: number, a: number }
+
+const HEX_PATTERN = /^#([0-9a-f]{3}|[0-9a-f]{4}|[0-9a-f]{6}|[0-9a-f]{8})$/i;
+const RGB_PATTERN = /^rgba?\(\s*(\d{1,3})\s*,\s*(\d{1,3})\s*,\s*(\d{1,3})\s*(?:,\s*([\d.]+%?)\s*)?\)$/i;
+const HSL_PATTERN = /^hsla?\(\s*([\d.]+)(?:deg)?\s*,\s*([\d.]+)%\s*,\s*([\d.]+)%\s*(?:,\s*([\d.]+%?)\s*)?\)$/i;
+
+export default function Color(props: { text: string }): ReactNode | undefined {
+    // this view is executed on every key press in main search bar, nothing is shown if text is not a color
+    const color = parseColor(props.text.trim());
+
+    if (!color) {
+        return undefined
+    }
+
+    const hex = toHex(color);
+    const rgb = toRgb(color);
+    const hsl = toHsl(color);
+
+    const copy = async (value: string) => {
+        await Clipboard.writeText(value)
+        showHud(`${value} copied`)
+    };
+
+    return (
+        <Inline
+            actions={
+                <ActionPanel>
+                    <Action label={"Copy HEX"} onAction={() => copy(hex)}/>
+                    <Action label={"Copy RGB"} onAction={() => copy(rgb)}/>
+                    <Action label={"Copy HSL"} onAction={() => copy(hsl)}/>
+                </ActionPanel>
+            }
+        >
+            <Inline.Left>
+                <Content.ColorSwatch color={hex}/>
+            </Inline.Left>
+            <Inline.Separator icon={Icons.ArrowRight}/>
+            <Inline.Right>
+                <Content.Paragraph>
+                    {hex}
+                </Content.Paragraph>
+                <Content.Paragraph>
+                    {rgb}
+                </Content.Paragraph>
+                <Content.Paragraph>
+                    {hsl}
+                </Content.Paragraph>
+            </Inline.Right>
+        </Inline>
+    )
+}
+
+function parseColor(text: string): Rgba | undefined {
+    const hexMatch = text.match(HEX_PATTERN);
+    if (hexMatch) {
+        let hex = hexMatch[1];
+
+        if (hex.length <= 4) {
+            hex = hex.split("").map(char => char + char).join("")
+        }
+
+        return {
+            r: parseInt(hex.slice(0, 2), 16),
+            g: parseInt(hex.slice(2, 4), 16),
+            b: parseInt(hex.slice(4, 6), 16),
+            a: hex.length === 8 ? parseInt(hex.slice(6, 8), 16) / 255 : 1,
+        }
+    }
+
+    const rgbMatch = text.match(RGB_PATTERN);
+    if (rgbMatch) {
+        const [r, g, b] = rgbMatch.slice(1, 4).map(Number);
+
+        if (r > 255 || g > 255 || b > 255) {
+            return undefined
+        }
+
+        return { r, g, b, a: parseAlpha(rgbMatch[4]) }
+    }
+
+    const hslMatch = text.match(HSL_PATTERN);
+    if (hslMatch) {
+        const [h, s, l] = hslMatch.slice(1, 4).map(Number);
+
+        if (s > 100 || l > 100) {
+            return undefined
+        }
+
+        return { ...hslToRgb(h % 360, s / 100, l / 100), a: parseAlpha(hslMatch[4]) }
+    }
+
+    return undefined
+}
+
+function parseAlpha(value: string | undefined): number {
+    if (value === undefined) {
+        return 1
+    }
+
+    const alpha = value.endsWith("%") ? Number(value.slice(0, -1)) / 100 : Number(value);
+
+    return Math.min(Math.max(alpha, 0), 1)
+}
+
+function hslToRgb(h: number, s: number, l: number): { r: number, g: number, b: number } {
+    const chroma = (1 - Math.abs(2 * l - 1)) * s;
+    const x = chroma * (1 - Math.abs((h / 60) % 2 - 1));
+    const m = l - chroma / 2;
+
+    const [r, g, b] = h < 60 ? [chroma, x, 0]
+        : h < 120 ? [x, chroma, 0]
+        : h < 180 ? [0, chroma, x]
+        : h < 240 ? [0, x, chroma]
+        : h < 300 ? [x, 0, chroma]
+        : [chroma, 0, x];
+
+    return {
+        r: Math.round((r + m) * 255),
+        g: Math.round((g + m) * 255),
+        b: Math.round((b + m) * 255),
+    }
+}
+
+function toHex({ r, g, b, a }: Rgba): string {
+    const channels = a < 1 ? [r, g, b, Math.round(a * 255)] : [r, g, b];
+
+    return "#" + channels.map(channel => channel.toString(16).padStart(2, "0")).join("")
+}
+
+function toRgb({ r, g, b, a }: Rgba): string {
+    return a < 1 ? `rgba(${r}, ${g}, ${b}, ${round(a, 2)})` : `rgb(${r}, ${g}, ${b})`
+}
+
+function toHsl({ r, g, b, a }: Rgba): string {
+    const red = r / 255;
+    const green = g / 255;
+    const blue = b / 255;
+
+    const max = Math.max(red, green, blue);
+    const min = Math.min(red, green, blue);
+    const delta = max - min;
+
+    const l = (max + min) / 2;
+    const s = delta === 0 ? 0 : delta / (1 - Math.abs(2 * l - 1));
+
+    let h = 0;
+    if (delta !== 0) {
+        if (max === red) {
+            h = 60 * (((green - blue) / delta) % 6)
+        } else if (max === green) {
+            h = 60 * ((blue - red) / delta + 2)
+        } else {
+            h = 60 * ((red - green) / delta + 4)
+        }
+    }
+
+    if (h < 0) {
+        h += 360
+    }
+
+    const values = `${Math.round(h)}, ${Math.round(s * 100)}%, ${Math.round(l * 100)}%`;
+
+    return a < 1 ? `hsla(${values}, ${round(a, 2)})` : `hsl(${values})`
+}
+
+function round(value: number, digits: number): number {
+    const factor = Math.pow(10, digits);
+
+    return Math.round(value * factor) / factor
+}
@@ -0,0 +1,95 @@
+import { Action, ActionPanel, Content, Icons, Inline } from "@project-gauntlet/api/components";
+import { ReactNode } from "react";
+import { usePromise } from "@project-gauntlet/api/hooks";
+import { Clipboard, pluginPreferences, showHud } from "@project-gauntlet/api/helpers";
+import { dictionary_lookup_api, dictionary_lookup_wordlist, open_url } from "gauntlet:bridge/internal-all";
+import { webSearchUrl } from "./web-search-engine";
+
+type DictionaryPreferences = {
+    dictionarySource: "api" | "wordlist"
+    dictionaryApiUrl: string
+    dictionaryWordlistPath: string
+}
+
+const DEFINE_PATTERN = /^define\s+(\S+)\s*$/i;
+
+export default function Dictionary(props: { text: string }): ReactNode | undefined {
+    const word = props.text.match(DEFINE_PATTERN)?.[1];
+
+    // hooks cannot be called conditionally, lookup is skipped when prompt doesn't look like "define <word>"
+    const { data: entry, isLoading } = usePromise(lookup, [word ?? ""], { execute: word !== undefined });
+
+    if (word === undefined) {
+        return undefined
+    }
+
+    // this view is executed on every key press in main search bar, nothing is shown for unknown words
+    if (!isLoading && !entry) {
+        return undefined
+    }
+
+    const definitions = (entry?.meanings ?? [])
+        .flatMap(meaning => meaning.definitions.map(definition => meaning.part_of_speech ? `(${meaning.part_of_speech}) ${definition}` : definition))
+        .slice(0, 3);
+
+    return (
+        <Inline
+            actions={
+                <ActionPanel>
+                    <Action
+                        label={"Copy definition"}
+                        onAction={async () => {
+                            await Clipboard.writeText(definitions.join("\n"))
+                            showHud("Definition copied")
+                        }}
+                    />
+                    <Action
+                        label={"Search the Web"}
+                        onAction={() => {
+                            open_url(webSearchUrl(`define ${word}`))
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            <Inline.Left>
+                <Content.H3>
+                    {entry?.word ?? word}
+                </Content.H3>
+                {entry?.phonetic && (
+                    <Content.Paragraph>
+                        {entry.phonetic}
+                    </Content.Paragraph>
+                )}
+            </Inline.Left>
+            <Inline.Separator icon={Icons.Book}/>
+            <Inline.Right>
+                {isLoading ? (
+                    <Content.Paragraph>
+                        Looking up...
+                    </Content.Paragraph>
+                ) : (
+                    definitions.map((definition, index) => (
+                        <Content.Paragraph key={index}>
+                            {definition}
+                        </Content.Paragraph>
+                    ))
+                )}
+            </Inline.Right>
+        </Inline>
+    )
+}
+
+async function lookup(word: string): Promise<DictionaryEntry | undefined> {
+    const { dictionarySource, dictionaryApiUrl, dictionaryWordlistPath } = pluginPreferences<DictionaryPreferences>();
+
+    if (dictionarySource === "wordlist") {
+        if (!dictionaryWordlistPath) {
+            return undefined
+        }
+
+        return await dictionary_lookup_wordlist(dictionaryWordlistPath, word) ?? undefined
+    }
+
+    return await dictionary_lookup_api(dictionaryApiUrl, word) ?? undefined
+}
@@ -0,0 +1,35 @@
+import { GeneratorProps, showHud } from "@project-gauntlet/api/helpers";
+import { trash_empty, trash_list } from "gauntlet:bridge/internal-all";
+import { refreshingGenerator } from "./refreshing-generator";
+
+const REFRESH_INTERVAL_MS = 30 * 1000;
+
+// single command, regenerated when number of items in trash changes so that it is shown next to the name
+export default async function EmptyTrash({ add, remove }: GeneratorProps): Promise<() => void> {
+    return await refreshingGenerator(
+        async () => [(await trash_list()).length],
+        () => "empty-trash",
+        count => ({
+            name: "Empty Trash",
+            accessory: count === 0 ? "Empty" : formatCount(count),
+            fn: () => emptyTrash(),
+        }),
+        add,
+        remove,
+        REFRESH_INTERVAL_MS,
+    )
+}
+
+export async function emptyTrash() {
+    try {
+        const count = await trash_empty();
+
+        showHud(count === 0 ? "Trash is already empty" : `Permanently deleted ${formatCount(count)}`)
+    } catch (e) {
+        showHud(`Unable to empty trash: ${e instanceof Error ? e.message : e}`)
+    }
+}
+
+export function formatCount(count: number): string {
+    return count === 1 ? "1 item" : `${count} items`
+}
@@ -0,0 +1,169 @@
+import { GeneratedCommand, isIdle, onIdleChange } from "@project-gauntlet/api/helpers";
+import { walk, WalkOptions } from "@std/fs/walk";
+import { debounce } from "@std/async/debounce";
+import {
+    application_watcher_next_actions,
+    application_watcher_start,
+    application_watcher_stop
+} from "gauntlet:bridge/internal-all";
+
+export async function genericGenerator<DATA>(
+    directoriesToWatch: string[],
+    appFromPath: (path: string) => Promise<undefined | DesktopPathAction<DATA>>,
+    commandFromApp: (id: string, data: DATA) => GeneratedCommand,
+    add: (id: string, data: GeneratedCommand) => void,
+    remove: (id: string) => void,
+    walkOpts?: WalkOptions
+): Promise<() => void> {
+    const paths = existingPaths(directoriesToWatch);
+
+    await addFromPaths(paths, appFromPath, commandFromApp, add, walkOpts)
+
+    const watcher = Deno.watchFs(paths);
+
+    const handlePaths = async (paths: Iterable<string>) => {
+        for (const path of paths) {
+            const app = await appFromPath(path);
+            if (app) {
+                handleAction(app, commandFromApp, add, remove)
+            }
+        }
+    }
+
+    const handle = debounce(
+        async (event: Deno.FsEvent) => {
+            switch (event.kind) {
+                case "create":
+                case "modify":
+                case "remove": {
+                    await handlePaths(event.paths)
+                }
+            }
+        },
+        1000
+    );
+
+    // changes are not handled while plugin is idle, changed paths are checked again once it is resumed
+    const pendingPaths = new Set<string>();
+
+    // noinspection ES6MissingAwait
+    (async () => {
+        for await (const event of watcher) {
+            if (isIdle()) {
+                switch (event.kind) {
+                    case "create":
+                    case "modify":
+                    case "remove": {
+                        for (const path of event.paths) {
+                            pendingPaths.add(path)
+                        }
+                    }
+                }
+            } else {
+                handle(event)
+            }
+        }
+    })()
+
+    const removeIdleListener = onIdleChange(idle => {
+        if (!idle) {
+            const paths = [...pendingPaths];
+            pendingPaths.clear()
+
+            // noinspection ES6MissingAwait
+            handlePaths(paths)
+        }
+    });
+
+    return () => {
+        watcher.close()
+        removeIdleListener()
+    }
+}
+
+// same as genericGenerator, but changes are watched and converted to applications on rust side
+export async function applicationGenerator<DATA>(
+    directoriesToWatch: string[],
+    appFromPath: (path: string) => Promise<undefined | DesktopPathAction<DATA>>,
+    commandFromApp: (id: string, data: DATA) => GeneratedCommand,
+    add: (id: string, data: GeneratedCommand) => void,
+    remove: (id: string) => void,
+    walkOpts?: WalkOptions
+): Promise<() => void> {
+    const paths = existingPaths(directoriesToWatch);
+
+    await addFromPaths(paths, appFromPath, commandFromApp, add, walkOpts)
+
+    const watcherId = application_watcher_start(paths);
+
+    // noinspection ES6MissingAwait
+    (async () => {
+        while (true) {
+            const actions = await application_watcher_next_actions(watcherId);
+            if (!actions) {
+                break
+            }
+
+            for (const action of actions) {
+                handleAction(action as DesktopPathAction<DATA>, commandFromApp, add, remove)
+            }
+        }
+    })()
+
+    return () => {
+        application_watcher_stop(watcherId)
+    }
+}
+
+function existingPaths(directories: string[]): string[] {
+    return directories
+        .filter(path => {
+            try {
+                Deno.lstatSync(path)
+                return true
+            } catch (err) {
+                // most frequent error here is NotFound
+                return false
+            }
+        });
+}
+
+async function addFromPaths<DATA>(
+    paths: string[],
+    appFromPath: (path: string) => Promise<undefined | DesktopPathAction<DATA>>,
+    commandFromApp: (id: string, data: DATA) => GeneratedCommand,
+    add: (id: string, data: GeneratedCommand) => void,
+    walkOpts?: WalkOptions
+) {
+    for (const path of paths) {
+        for await (const dirEntry of walk(path, walkOpts)) {
+            const app = await appFromPath(dirEntry.path);
+            if (app) {
+                switch (app.type) {
+                    case "add": {
+                        add(app.id, commandFromApp(app.id, app.data))
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+function handleAction<DATA>(
+    app: DesktopPathAction<DATA>,
+    commandFromApp: (id: string, data: DATA) => GeneratedCommand,
+    add: (id: string, data: GeneratedCommand) => void,
+    remove: (id: string) => void,
+) {
+    switch (app.type) {
+        case "remove": {
+            remove(app.id)
+            break;
+        }
+        case "add": {
+            add(app.id, commandFromApp(app.id, app.data))
+            break;
+        }
+    }
+}
@@ -0,0 +1,41 @@
+import { Action, ActionPanel } from "@project-gauntlet/api/components";
+import { ReactElement } from "react";
+import { showHud } from "@project-gauntlet/api/helpers";
+import { media_control } from "gauntlet:bridge/internal-all";
+
+export function nowPlayingTitle(nowPlaying: NowPlaying): string {
+    return nowPlaying.title ?? "Unknown Track"
+}
+
+export function nowPlayingSubtitle(nowPlaying: NowPlaying): string | undefined {
+    const parts = [nowPlaying.artist, nowPlaying.album].filter(part => !!part);
+
+    return parts.length > 0 ? parts.join(" — ") : undefined
+}
+
+// shared by now playing view and inline view, onChange is called after player handled the action
+export function NowPlayingActions({ nowPlaying, onChange }: { nowPlaying: NowPlaying, onChange?: () => void }): ReactElement {
+    const run = async (action: MediaControlAction) => {
+        try {
+            await media_control(action)
+            onChange?.()
+        } catch (e) {
+            showHud(`Unable to control media player: ${e instanceof Error ? e.message : `${e}`}`)
+        }
+    };
+
+    return (
+        <ActionPanel title={nowPlaying.player}>
+            <Action
+                label={nowPlaying.status === "playing" ? "Pause" : "Play"}
+                onAction={() => run("play_pause")}
+            />
+            {nowPlaying.can_go_next && (
+                <Action label="Next Track" onAction={() => run("next")}/>
+            )}
+            {nowPlaying.can_go_previous && (
+                <Action label="Previous Track" onAction={() => run("previous")}/>
+            )}
+        </ActionPanel>
+    )
+}
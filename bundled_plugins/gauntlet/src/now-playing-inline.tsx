@@ -0,0 +1,39 @@
+import { Content, Icons, Inline } from "@project-gauntlet/api/components";
+import { ReactNode } from "react";
+import { usePromise } from "@project-gauntlet/api/hooks";
+import { media_now_playing } from "gauntlet:bridge/internal-all";
+import { NowPlayingActions, nowPlayingSubtitle, nowPlayingTitle } from "./now-playing-controls";
+
+const TRIGGER_WORDS = ["music", "media", "now playing", "playing", "song", "track"];
+
+export default function NowPlayingInline(props: { text: string }): ReactNode | undefined {
+    const triggered = TRIGGER_WORDS.includes(props.text.trim().toLowerCase());
+
+    // hooks cannot be called conditionally, player is only queried when prompt asks for it
+    const { data: nowPlaying, revalidate } = usePromise(async () => await media_now_playing() ?? undefined, [], { execute: triggered });
+
+    if (!triggered || !nowPlaying) {
+        return undefined
+    }
+
+    const subtitle = nowPlayingSubtitle(nowPlaying);
+
+    return (
+        <Inline actions={<NowPlayingActions nowPlaying={nowPlaying} onChange={revalidate}/>}>
+            {nowPlaying.artwork ? (
+                <Inline.Left>
+                    <Content.Image source={{ url: nowPlaying.artwork }}/>
+                </Inline.Left>
+            ) : undefined}
+            {nowPlaying.artwork ? (
+                <Inline.Separator icon={nowPlaying.status === "playing" ? Icons.Play : Icons.Pause}/>
+            ) : undefined}
+            <Inline.Right>
+                <Content.H3>{nowPlayingTitle(nowPlaying)}</Content.H3>
+                {subtitle && (
+                    <Content.Paragraph>{subtitle}</Content.Paragraph>
+                )}
+            </Inline.Right>
+        </Inline>
+    )
+}
@@ -0,0 +1,73 @@
+import { Detail } from "@project-gauntlet/api/components";
+import { ReactElement, useEffect, useState } from "react";
+import { media_now_playing } from "gauntlet:bridge/internal-all";
+import { NowPlayingActions, nowPlayingSubtitle, nowPlayingTitle } from "./now-playing-controls";
+
+const REFRESH_INTERVAL_MS = 2000;
+
+export default function NowPlayingView(): ReactElement {
+    const [nowPlaying, setNowPlaying] = useState<NowPlaying | undefined>(undefined);
+    const [loading, setLoading] = useState(true);
+
+    const refresh = async () => {
+        try {
+            setNowPlaying(await media_now_playing() ?? undefined)
+        } catch (e) {
+            console.error("Unable to get currently playing media", e)
+        } finally {
+            setLoading(false)
+        }
+    };
+
+    useEffect(() => {
+        // noinspection ES6MissingAwait
+        refresh()
+
+        // track can be changed from outside gauntlet
+        const interval = setInterval(refresh, REFRESH_INTERVAL_MS);
+
+        return () => clearInterval(interval)
+    }, []);
+
+    if (!nowPlaying) {
+        return (
+            <Detail isLoading={loading}>
+                <Detail.Content>
+                    <Detail.Content.Paragraph>
+                        {loading ? "" : "Nothing is playing right now"}
+                    </Detail.Content.Paragraph>
+                </Detail.Content>
+            </Detail>
+        )
+    }
+
+    const subtitle = nowPlayingSubtitle(nowPlaying);
+
+    return (
+        <Detail actions={<NowPlayingActions nowPlaying={nowPlaying} onChange={refresh}/>}>
+            <Detail.Metadata>
+                <Detail.Metadata.Value label="Player">{nowPlaying.player}</Detail.Metadata.Value>
+                <Detail.Metadata.Value label="Status">{capitalize(nowPlaying.status)}</Detail.Metadata.Value>
+                {nowPlaying.artist && (
+                    <Detail.Metadata.Value label="Artist">{nowPlaying.artist}</Detail.Metadata.Value>
+                )}
+                {nowPlaying.album && (
+                    <Detail.Metadata.Value label="Album">{nowPlaying.album}</Detail.Metadata.Value>
+                )}
+            </Detail.Metadata>
+            <Detail.Content>
+                {nowPlaying.artwork && (
+                    <Detail.Content.Image source={{ url: nowPlaying.artwork }}/>
+                )}
+                <Detail.Content.H3>{nowPlayingTitle(nowPlaying)}</Detail.Content.H3>
+                {subtitle && (
+                    <Detail.Content.Paragraph>{subtitle}</Detail.Content.Paragraph>
+                )}
+            </Detail.Content>
+        </Detail>
+    )
+}
+
+function capitalize(value: string): string {
+    return value.charAt(0).toUpperCase() + value.slice(1)
+}
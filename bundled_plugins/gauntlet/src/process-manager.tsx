@@ -0,0 +1,266 @@
+import { Action, ActionPanel, Detail, List, TextAccessory } from "@project-gauntlet/api/components";
+import { useNavigation } from "@project-gauntlet/api/hooks";
+import { showHud } from "@project-gauntlet/api/helpers";
+import { ReactElement, useEffect, useState } from "react";
+import { process_force_kill, process_list, process_renice, process_terminate } from "gauntlet:bridge/internal-all";
+
+const REFRESH_INTERVAL_MS = 2000;
+const RENICE_STEP = 5;
+
+export default function ProcessManager(): ReactElement {
+    const { pushView } = useNavigation();
+    const [processes, setProcesses] = useState<ProcessInfo[] | undefined>(undefined);
+    const [searchText, setSearchText] = useState<string | undefined>("");
+
+    useEffect(() => {
+        let stopped = false;
+
+        const refresh = async () => {
+            try {
+                const processes = await process_list();
+                if (!stopped) {
+                    setProcesses(processes)
+                }
+            } catch (e) {
+                console.error("Unable to list processes", e)
+            }
+        };
+
+        // noinspection ES6MissingAwait
+        refresh()
+
+        const interval = setInterval(refresh, REFRESH_INTERVAL_MS);
+
+        return () => {
+            stopped = true
+            clearInterval(interval)
+        }
+    }, []);
+
+    const query = searchText?.trim() ?? "";
+
+    const results = (processes ?? [])
+        .map(process => ({ process, score: query ? matchScore(process, query) : 0 }))
+        .filter(({ score }) => score !== undefined)
+        .sort((a, b) => (b.score!! - a.score!!) || (b.process.cpu_usage - a.process.cpu_usage))
+        .map(({ process }) => process);
+
+    return (
+        <List isLoading={processes === undefined}>
+            <List.SearchBar
+                placeholder="Search processes by name, command, user or PID..."
+                value={searchText}
+                onChange={setSearchText}
+            />
+            {results.map(process => (
+                <List.Item
+                    key={process.pid}
+                    title={process.name}
+                    subtitle={process.user}
+                    accessories={[
+                        <TextAccessory text={`PID ${process.pid}`}/>,
+                        <TextAccessory text={formatCpu(process.cpu_usage)} tooltip="CPU usage"/>,
+                        <TextAccessory text={formatMemory(process.memory)} tooltip="Memory usage"/>,
+                    ]}
+                    onClick={() => pushView(<ProcessDetail process={process}/>)}
+                />
+            ))}
+        </List>
+    )
+}
+
+function ProcessDetail({ process }: { process: ProcessInfo }): ReactElement {
+    const { pushView } = useNavigation();
+    const [nice, setNice] = useState(process.nice);
+
+    const renice = (value: number) => {
+        try {
+            process_renice(process.pid, value)
+            setNice(value)
+            showHud(`Priority of '${process.name}' changed to ${value}`)
+        } catch (e) {
+            showHud(`Unable to change priority: ${errorMessage(e)}`)
+        }
+    };
+
+    return (
+        <Detail
+            actions={
+                <ActionPanel title={process.name}>
+                    <Action
+                        label="Terminate"
+                        onAction={() => pushView(
+                            <ConfirmProcessAction
+                                process={process}
+                                verb="terminate"
+                                run={() => process_terminate(process.pid)}
+                            />
+                        )}
+                    />
+                    <Action
+                        label="Force Kill"
+                        onAction={() => pushView(
+                            <ConfirmProcessAction
+                                process={process}
+                                verb="force kill"
+                                run={() => process_force_kill(process.pid)}
+                            />
+                        )}
+                    />
+                    {nice !== undefined && (
+                        <ActionPanel.Section title="Priority">
+                            <Action
+                                label="Lower Priority"
+                                onAction={() => renice(Math.min(nice + RENICE_STEP, 19))}
+                            />
+                            <Action
+                                label="Raise Priority"
+                                onAction={() => renice(Math.max(nice - RENICE_STEP, -20))}
+                            />
+                        </ActionPanel.Section>
+                    )}
+                </ActionPanel>
+            }
+        >
+            <Detail.Metadata>
+                <Detail.Metadata.Value label="PID">{`${process.pid}`}</Detail.Metadata.Value>
+                {process.parent_pid !== undefined && (
+                    <Detail.Metadata.Value label="Parent PID">{`${process.parent_pid}`}</Detail.Metadata.Value>
+                )}
+                {process.user !== undefined && (
+                    <Detail.Metadata.Value label="User">{process.user}</Detail.Metadata.Value>
+                )}
+                <Detail.Metadata.Value label="CPU">{formatCpu(process.cpu_usage)}</Detail.Metadata.Value>
+                <Detail.Metadata.Value label="Memory">{formatMemory(process.memory)}</Detail.Metadata.Value>
+                {nice !== undefined && (
+                    <Detail.Metadata.Value label="Nice">{`${nice}`}</Detail.Metadata.Value>
+                )}
+            </Detail.Metadata>
+            <Detail.Content>
+                <Detail.Content.H3>{process.name}</Detail.Content.H3>
+                {process.command.length > 0 && (
+                    <Detail.Content.CodeBlock>{process.command}</Detail.Content.CodeBlock>
+                )}
+            </Detail.Content>
+        </Detail>
+    )
+}
+
+// destructive actions need to be confirmed explicitly
+function ConfirmProcessAction({ process, verb, run }: { process: ProcessInfo, verb: string, run: () => void }): ReactElement {
+    const { popView } = useNavigation();
+
+    return (
+        <Detail
+            actions={
+                <ActionPanel>
+                    <Action
+                        label={`Confirm ${capitalize(verb)}`}
+                        onAction={() => {
+                            try {
+                                run()
+                                showHud(`Sent ${verb} signal to '${process.name}' (PID ${process.pid})`)
+                            } catch (e) {
+                                showHud(`Unable to ${verb} '${process.name}': ${errorMessage(e)}`)
+                            }
+
+                            // back to process list
+                            popView()
+                            popView()
+                        }}
+                    />
+                    <Action
+                        label="Cancel"
+                        onAction={() => popView()}
+                    />
+                </ActionPanel>
+            }
+        >
+            <Detail.Content>
+                <Detail.Content.H3>{`${capitalize(verb)} '${process.name}'?`}</Detail.Content.H3>
+                <Detail.Content.Paragraph>
+                    {`Process with PID ${process.pid} will be stopped, any unsaved data in it may be lost.`}
+                </Detail.Content.Paragraph>
+            </Detail.Content>
+        </Detail>
+    )
+}
+
+// characters of the query have to appear in the same order, consecutive and word start matches score higher.
+// returns undefined if there is no match
+function fuzzyScore(text: string, query: string): number | undefined {
+    const textLower = text.toLowerCase();
+    const queryLower = query.toLowerCase();
+
+    let score = 0;
+    let textIndex = 0;
+    let previousMatch = -2;
+
+    for (const char of queryLower) {
+        const index = textLower.indexOf(char, textIndex);
+        if (index === -1) {
+            return undefined
+        }
+
+        score += 1
+
+        if (index === previousMatch + 1) {
+            score += 2
+        }
+
+        if (index === 0 || /[\s\-_./]/.test(textLower[index - 1])) {
+            score += 3
+        }
+
+        previousMatch = index
+        textIndex = index + 1
+    }
+
+    // shorter texts are closer matches
+    return score - text.length / 100
+}
+
+function matchScore(process: ProcessInfo, query: string): number | undefined {
+    if (`${process.pid}` === query) {
+        return Number.MAX_SAFE_INTEGER
+    }
+
+    const scores = [
+        fuzzyScore(process.name, query),
+        fuzzyScore(process.command, query),
+        process.user ? fuzzyScore(process.user, query) : undefined,
+    ]
+        .filter(score => score !== undefined)
+        .map(score => score!!);
+
+    if (scores.length === 0) {
+        return undefined
+    }
+
+    return Math.max(...scores)
+}
+
+function formatCpu(cpuUsage: number): string {
+    return `${cpuUsage.toFixed(1)}%`
+}
+
+function formatMemory(bytes: number): string {
+    const units = ["B", "KB", "MB", "GB", "TB"];
+
+    let value = bytes;
+    let unit = 0;
+    while (value >= 1024 && unit < units.length - 1) {
+        value /= 1024
+        unit += 1
+    }
+
+    return `${value.toFixed(unit === 0 ? 0 : 1)} ${units[unit]}`
+}
+
+function capitalize(text: string): string {
+    return text.charAt(0).toUpperCase() + text.slice(1)
+}
+
+function errorMessage(e: unknown): string {
+    return e instanceof Error ? e.message : `${e}`
+}
@@ -0,0 +1,36 @@
+import { Clipboard, GeneratorProps, pluginPreferences } from "@project-gauntlet/api/helpers";
+import { open_recent_project, recent_projects } from "gauntlet:bridge/internal-all";
+import { refreshingGenerator } from "./refreshing-generator";
+
+type RecentProjectsPreferences = {
+    projectEditorCommand: string
+}
+
+const REFRESH_INTERVAL_MS = 60 * 1000;
+
+export default async function RecentProjects({ add, remove }: GeneratorProps): Promise<() => void> {
+    return await refreshingGenerator(
+        () => recent_projects(),
+        project => `${project.editor}:${project.path}`,
+        project => ({
+            name: `Open ${project.name} in editor`,
+            accessory: project.editor,
+            fn: () => {
+                const { projectEditorCommand } = pluginPreferences<RecentProjectsPreferences>();
+
+                open_recent_project(project, projectEditorCommand || undefined)
+            },
+            actions: [
+                {
+                    label: "Copy Path",
+                    fn: async () => {
+                        await Clipboard.writeText(project.path)
+                    }
+                }
+            ]
+        }),
+        add,
+        remove,
+        REFRESH_INTERVAL_MS,
+    )
+}
@@ -0,0 +1,72 @@
+import { GeneratedCommand, isIdle, onIdleChange } from "@project-gauntlet/api/helpers";
+
+// for sources that cannot be watched for changes, items are reloaded periodically
+// and only items that changed are re-added
+export async function refreshingGenerator<ITEM>(
+    load: () => Promise<ITEM[]>,
+    idFromItem: (item: ITEM) => string,
+    commandFromItem: (item: ITEM) => GeneratedCommand,
+    add: (id: string, data: GeneratedCommand) => void,
+    remove: (id: string) => void,
+    refreshIntervalMs: number,
+): Promise<() => void> {
+    // serialized item by id
+    let added = new Map<string, string>();
+
+    const refresh = async () => {
+        let items: ITEM[];
+        try {
+            items = await load()
+        } catch (e) {
+            console.error("Unable to load items", e)
+            return
+        }
+
+        const current = new Map<string, string>();
+
+        for (const item of items) {
+            const id = idFromItem(item);
+            const serialized = JSON.stringify(item);
+
+            if (current.has(id)) {
+                continue
+            }
+
+            current.set(id, serialized)
+
+            if (added.get(id) !== serialized) {
+                add(id, commandFromItem(item))
+            }
+        }
+
+        for (const id of added.keys()) {
+            if (!current.has(id)) {
+                remove(id)
+            }
+        }
+
+        added = current
+    }
+
+    await refresh()
+
+    let interval = isIdle() ? undefined : setInterval(refresh, refreshIntervalMs);
+
+    // nobody sees search results while window is hidden, so items are reloaded once it is shown again
+    const removeIdleListener = onIdleChange(idle => {
+        clearInterval(interval)
+
+        if (idle) {
+            interval = undefined
+        } else {
+            // noinspection ES6MissingAwait
+            refresh()
+            interval = setInterval(refresh, refreshIntervalMs)
+        }
+    });
+
+    return () => {
+        clearInterval(interval)
+        removeIdleListener()
+    }
+}
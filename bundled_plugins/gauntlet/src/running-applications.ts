@@ -0,0 +1,76 @@
+import { GeneratedCommand, isIdle, onIdleChange } from "@project-gauntlet/api/helpers";
+import { running_executable_paths } from "gauntlet:bridge/internal-all";
+
+const POLL_INTERVAL_MS = 5000;
+
+// re-adds applications when they are started or stopped, so that search results show whether application is running
+export function trackRunningApplications<DATA>(
+    isRunning: (data: DATA, executablePaths: string[]) => boolean,
+    commandFromApp: (id: string, data: DATA, running: boolean) => GeneratedCommand,
+    add: (id: string, data: GeneratedCommand) => void,
+    remove: (id: string) => void,
+): {
+    commandFromApp: (id: string, data: DATA) => GeneratedCommand,
+    remove: (id: string) => void,
+    stop: () => void,
+} {
+    const apps = new Map<string, DATA>();
+    const running = new Set<string>();
+
+    const refresh = async () => {
+        let executablePaths: string[];
+        try {
+            executablePaths = await running_executable_paths();
+        } catch (e) {
+            console.error("Unable to get running processes", e)
+            return
+        }
+
+        for (const [id, data] of apps) {
+            const isAppRunning = isRunning(data, executablePaths);
+
+            if (isAppRunning != running.has(id)) {
+                if (isAppRunning) {
+                    running.add(id)
+                } else {
+                    running.delete(id)
+                }
+
+                add(id, commandFromApp(id, data, isAppRunning))
+            }
+        }
+    }
+
+    let interval = isIdle() ? undefined : setInterval(refresh, POLL_INTERVAL_MS);
+
+    // polling is paused while plugin is idle and running state is refreshed once window is shown again
+    const removeIdleListener = onIdleChange(idle => {
+        clearInterval(interval)
+
+        if (idle) {
+            interval = undefined
+        } else {
+            // noinspection ES6MissingAwait
+            refresh()
+            interval = setInterval(refresh, POLL_INTERVAL_MS)
+        }
+    });
+
+    return {
+        commandFromApp: (id, data) => {
+            apps.set(id, data)
+
+            return commandFromApp(id, data, running.has(id))
+        },
+        remove: id => {
+            apps.delete(id)
+            running.delete(id)
+
+            remove(id)
+        },
+        stop: () => {
+            clearInterval(interval)
+            removeIdleListener()
+        }
+    }
+}
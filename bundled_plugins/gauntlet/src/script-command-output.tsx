@@ -0,0 +1,48 @@
+import { Detail } from "@project-gauntlet/api/components";
+import { ReactElement } from "react";
+import { LastScriptCommandOutput, scriptCommandOutputFile } from "./script-commands";
+
+export default function ScriptCommandOutput(): ReactElement {
+    let lastOutput: LastScriptCommandOutput | undefined;
+
+    try {
+        lastOutput = JSON.parse(Deno.readTextFileSync(scriptCommandOutputFile()))
+    } catch (e) {
+        // no script command with "detail" mode was run yet
+        lastOutput = undefined
+    }
+
+    if (!lastOutput) {
+        return (
+            <Detail>
+                <Detail.Content>
+                    <Detail.Content.Paragraph>
+                        No script command output yet
+                    </Detail.Content.Paragraph>
+                </Detail.Content>
+            </Detail>
+        )
+    }
+
+    const { title, output } = lastOutput;
+
+    return (
+        <Detail>
+            <Detail.Content>
+                <Detail.Content.H3>
+                    {title}
+                </Detail.Content.H3>
+                {output.stdout.length > 0 && (
+                    <Detail.Content.CodeBlock>
+                        {output.stdout}
+                    </Detail.Content.CodeBlock>
+                )}
+                {output.stderr.length > 0 && (
+                    <Detail.Content.CodeBlock>
+                        {output.stderr}
+                    </Detail.Content.CodeBlock>
+                )}
+            </Detail.Content>
+        </Detail>
+    )
+}
@@ -0,0 +1,112 @@
+import { Clipboard, Environment, GeneratedCommand, GeneratorProps, showHud } from "@project-gauntlet/api/helpers";
+import { genericGenerator } from "./generic-generator";
+import {
+    run_script_command,
+    script_command_from_path,
+    script_commands_dir,
+    show_plugin_view
+} from "gauntlet:bridge/internal-all";
+
+export const scriptCommandOutputFile = () => `${Environment.pluginCacheDir}/script-command-output.json`
+
+export type LastScriptCommandOutput = {
+    title: string
+    output: ScriptCommandOutput
+}
+
+// scripts which can be run by typing their keyword followed by argument, by id
+const keywordScripts = new Map<string, ScriptCommandData>();
+
+export default async function ScriptCommands({ add, remove }: GeneratorProps): Promise<void | (() => void)> {
+    return await genericGenerator(
+        [script_commands_dir()],
+        path => script_command_from_path(path),
+        (id, data) => {
+            if (data.keyword) {
+                keywordScripts.set(id, data)
+            } else {
+                keywordScripts.delete(id)
+            }
+
+            return {
+                name: data.title,
+                fn: async () => {
+                    await runScriptCommand(data, [])
+                },
+            }
+        },
+        add,
+        id => {
+            keywordScripts.delete(id)
+            remove(id)
+        },
+    );
+}
+
+// e.g. with "@gauntlet.keyword gh", typing "gh gauntlet" runs the script with "gauntlet" as first argument
+export function search(query: string): GeneratedCommand[] {
+    const commands: GeneratedCommand[] = [];
+
+    for (const data of keywordScripts.values()) {
+        const prefix = `${data.keyword} `;
+
+        if (!query.toLowerCase().startsWith(prefix)) {
+            continue
+        }
+
+        const argument = query.substring(prefix.length).trim();
+
+        if (argument === "") {
+            continue
+        }
+
+        commands.push({
+            name: `${data.title}: ${argument}`,
+            accessory: data.keyword,
+            fn: async () => {
+                await runScriptCommand(data, [argument])
+            },
+        })
+    }
+
+    return commands
+}
+
+async function runScriptCommand(data: ScriptCommandData, args: string[]) {
+    const output = await run_script_command(data.path, args);
+
+    if (!output.success && data.mode != "detail") {
+        showHud(`'${data.title}' failed: ${firstLine(output.stderr) ?? "unknown error"}`)
+        return
+    }
+
+    switch (data.mode) {
+        case "silent": {
+            break;
+        }
+        case "hud": {
+            showHud(firstLine(output.stdout) ?? data.title)
+            break;
+        }
+        case "clipboard": {
+            await Clipboard.writeText(output.stdout.trimEnd())
+            showHud("Script output copied")
+            break;
+        }
+        case "detail": {
+            const lastOutput: LastScriptCommandOutput = { title: data.title, output };
+
+            await Deno.mkdir(Environment.pluginCacheDir, { recursive: true })
+            await Deno.writeTextFile(scriptCommandOutputFile(), JSON.stringify(lastOutput))
+
+            await show_plugin_view("script-command-output")
+            break;
+        }
+    }
+}
+
+function firstLine(text: string): string | undefined {
+    const line = text.trim().split("\n")[0];
+
+    return line ? line : undefined
+}
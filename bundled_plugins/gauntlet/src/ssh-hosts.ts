@@ -0,0 +1,48 @@
+import { Clipboard, GeneratorProps, pluginPreferences } from "@project-gauntlet/api/helpers";
+import { open_ssh_host, ssh_hosts } from "gauntlet:bridge/internal-all";
+import { refreshingGenerator } from "./refreshing-generator";
+
+type SshHostsPreferences = {
+    sshTerminalCommand: string
+}
+
+const REFRESH_INTERVAL_MS = 60 * 1000;
+
+export default async function SshHosts({ add, remove }: GeneratorProps): Promise<() => void> {
+    return await refreshingGenerator(
+        () => ssh_hosts(),
+        host => `${host.host}:${host.port ?? ""}`,
+        host => ({
+            name: `Open SSH to ${host.host}`,
+            accessory: hostAccessory(host),
+            fn: () => {
+                const { sshTerminalCommand } = pluginPreferences<SshHostsPreferences>();
+
+                open_ssh_host(host, sshTerminalCommand || undefined)
+            },
+            actions: [
+                {
+                    label: "Copy SSH Command",
+                    fn: async () => {
+                        const port = host.port ? `-p ${host.port} ` : "";
+
+                        await Clipboard.writeText(`ssh ${port}${host.host}`)
+                    }
+                }
+            ]
+        }),
+        add,
+        remove,
+        REFRESH_INTERVAL_MS,
+    )
+}
+
+function hostAccessory(host: SshHost): string | undefined {
+    if (!host.hostname && !host.user) {
+        return undefined
+    }
+
+    const hostname = host.hostname ?? host.host;
+
+    return host.user ? `${host.user}@${hostname}` : hostname
+}
@@ -0,0 +1,120 @@
+import { Action, ActionPanel, Detail } from "@project-gauntlet/api/components";
+import { Clipboard, showHud } from "@project-gauntlet/api/helpers";
+import { ReactElement, useEffect, useState } from "react";
+import { system_info } from "gauntlet:bridge/internal-all";
+
+const REFRESH_INTERVAL_MS = 5000;
+
+type InfoValue = { label: string, value: string }
+
+export default function SystemInfoView(): ReactElement {
+    const [info, setInfo] = useState<SystemInfo | undefined>(undefined);
+
+    useEffect(() => {
+        let stopped = false;
+
+        const refresh = async () => {
+            try {
+                const info = await system_info();
+                if (!stopped) {
+                    setInfo(info)
+                }
+            } catch (e) {
+                console.error("Unable to get system info", e)
+            }
+        };
+
+        // noinspection ES6MissingAwait
+        refresh()
+
+        // uptime and battery change while the view is open
+        const interval = setInterval(refresh, REFRESH_INTERVAL_MS);
+
+        return () => {
+            stopped = true
+            clearInterval(interval)
+        }
+    }, []);
+
+    if (!info) {
+        return <Detail isLoading={true}/>
+    }
+
+    const network = [
+        ...info.ip_addresses.map(({ interface: name, address }) => ({ label: `IP (${name})`, value: address })),
+        ...(info.wifi_ssid !== undefined ? [{ label: "Wi-Fi", value: info.wifi_ssid }] : []),
+    ];
+
+    const system = [
+        ...(info.hostname !== undefined ? [{ label: "Hostname", value: info.hostname }] : []),
+        ...(info.os !== undefined ? [{ label: "OS", value: info.os }] : []),
+        { label: "Uptime", value: formatUptime(info.uptime) },
+        ...(info.battery !== undefined ? [{ label: "Battery", value: formatBattery(info.battery) }] : []),
+    ];
+
+    const disks = info.disks.map(disk => ({
+        label: `Disk (${disk.mount_point})`,
+        value: `${formatBytes(disk.available_space)} free of ${formatBytes(disk.total_space)}`,
+    }));
+
+    const copy = async ({ label, value }: InfoValue) => {
+        await Clipboard.writeText(value)
+        showHud(`${label} copied`)
+    };
+
+    return (
+        <Detail
+            actions={
+                <ActionPanel title="System Info">
+                    {[network, system, disks].map((values, index) => (
+                        <ActionPanel.Section key={index}>
+                            {values.map(value => (
+                                <Action key={value.label} label={`Copy ${value.label}`} onAction={() => copy(value)}/>
+                            ))}
+                        </ActionPanel.Section>
+                    ))}
+                </ActionPanel>
+            }
+        >
+            <Detail.Metadata>
+                {[...network, ...system, ...disks].map(({ label, value }) => (
+                    <Detail.Metadata.Value key={label} label={label}>{value}</Detail.Metadata.Value>
+                ))}
+            </Detail.Metadata>
+        </Detail>
+    )
+}
+
+function formatUptime(seconds: number): string {
+    const days = Math.floor(seconds / 86400);
+    const hours = Math.floor((seconds % 86400) / 3600);
+    const minutes = Math.floor((seconds % 3600) / 60);
+
+    const parts = [];
+    if (days > 0) {
+        parts.push(`${days}d`)
+    }
+    if (days > 0 || hours > 0) {
+        parts.push(`${hours}h`)
+    }
+    parts.push(`${minutes}m`)
+
+    return parts.join(" ")
+}
+
+function formatBattery({ percentage, charging }: { percentage: number, charging: boolean }): string {
+    return charging ? `${Math.round(percentage)}% (charging)` : `${Math.round(percentage)}%`
+}
+
+function formatBytes(bytes: number): string {
+    const units = ["B", "KB", "MB", "GB", "TB"];
+
+    let value = bytes;
+    let unit = 0;
+    while (value >= 1024 && unit < units.length - 1) {
+        value /= 1024
+        unit += 1
+    }
+
+    return `${value.toFixed(unit === 0 ? 0 : 1)} ${units[unit]}`
+}
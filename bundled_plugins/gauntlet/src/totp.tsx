@@ -0,0 +1,186 @@
+import { Action, ActionPanel, Detail, Form, List, TextAccessory } from "@project-gauntlet/api/components";
+import { useNavigation } from "@project-gauntlet/api/hooks";
+import { Clipboard, pluginPreferences, showHud } from "@project-gauntlet/api/helpers";
+import { ReactElement, useEffect, useState } from "react";
+import { totp_accounts, totp_add_account, totp_generate_code, totp_remove_account } from "gauntlet:bridge/internal-all";
+
+interface TotpPreferences {
+    totpClipboardClearSeconds: number
+}
+
+export default function Totp(): ReactElement {
+    const { pushView } = useNavigation();
+    const [accounts, setAccounts] = useState<TotpAccount[] | undefined>(undefined);
+    const [reloadCounter, setReloadCounter] = useState(0);
+
+    const reload = () => setReloadCounter(counter => counter + 1);
+
+    useEffect(() => {
+        totp_accounts()
+            .then(accounts => setAccounts(accounts))
+            .catch(e => {
+                console.error("Unable to read two-factor accounts", e)
+                setAccounts([])
+            })
+    }, [reloadCounter]);
+
+    return (
+        <List
+            isLoading={accounts === undefined}
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Add Account"
+                        onAction={() => pushView(<AddAccount onAdded={reload}/>)}
+                    />
+                    {accounts !== undefined && accounts.length > 0 && (
+                        <Action
+                            label="Remove Account"
+                            onAction={() => pushView(<RemoveAccount accounts={accounts} onRemoved={reload}/>)}
+                        />
+                    )}
+                </ActionPanel>
+            }
+        >
+            {accounts !== undefined && accounts.length === 0 && (
+                <List.EmptyView
+                    title="No two-factor accounts"
+                    description="Use 'Add Account' action to add secret key or 'otpauth://' link of an account"
+                />
+            )}
+            {(accounts ?? []).map(account => (
+                <List.Item
+                    key={account.id}
+                    title={account.name}
+                    subtitle={account.issuer}
+                    accessories={[<TextAccessory text={`${account.digits} digits, ${account.period}s`}/>]}
+                    onClick={() => copyCode(account)}
+                />
+            ))}
+        </List>
+    )
+}
+
+function AddAccount({ onAdded }: { onAdded: () => void }): ReactElement {
+    const { popView } = useNavigation();
+    const [name, setName] = useState<string | undefined>("");
+    const [secret, setSecret] = useState<string | undefined>("");
+
+    return (
+        <Form
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Save Account"
+                        onAction={async () => {
+                            try {
+                                const account = await totp_add_account(name ?? "", secret ?? "");
+                                showHud(`Added two-factor account '${account.name}'`)
+                                onAdded()
+                                popView()
+                            } catch (e) {
+                                showHud(`Unable to add account: ${errorMessage(e)}`)
+                            }
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            <Form.TextField
+                label="Name (taken from link if empty)"
+                value={name}
+                onChange={setName}
+            />
+            <Form.PasswordField
+                label="Secret Key or otpauth:// Link"
+                value={secret}
+                onChange={setSecret}
+            />
+        </Form>
+    )
+}
+
+function RemoveAccount({ accounts, onRemoved }: { accounts: TotpAccount[], onRemoved: () => void }): ReactElement {
+    const { pushView } = useNavigation();
+
+    return (
+        <List>
+            {accounts.map(account => (
+                <List.Item
+                    key={account.id}
+                    title={account.name}
+                    subtitle={account.issuer}
+                    onClick={() => pushView(<ConfirmRemoveAccount account={account} onRemoved={onRemoved}/>)}
+                />
+            ))}
+        </List>
+    )
+}
+
+// secret cannot be recovered after removal, so it needs to be confirmed explicitly
+function ConfirmRemoveAccount({ account, onRemoved }: { account: TotpAccount, onRemoved: () => void }): ReactElement {
+    const { popView } = useNavigation();
+
+    return (
+        <Detail
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Confirm Remove"
+                        onAction={async () => {
+                            try {
+                                await totp_remove_account(account.id)
+                                showHud(`Removed two-factor account '${account.name}'`)
+                                onRemoved()
+                            } catch (e) {
+                                showHud(`Unable to remove account: ${errorMessage(e)}`)
+                            }
+
+                            // back to account list
+                            popView()
+                            popView()
+                        }}
+                    />
+                    <Action
+                        label="Cancel"
+                        onAction={() => popView()}
+                    />
+                </ActionPanel>
+            }
+        >
+            <Detail.Content>
+                <Detail.Content.H3>{`Remove '${account.name}'?`}</Detail.Content.H3>
+                <Detail.Content.Paragraph>
+                    {"Secret key will be deleted from system secret store. Make sure two-factor authentication can be restored some other way before removing it."}
+                </Detail.Content.Paragraph>
+            </Detail.Content>
+        </Detail>
+    )
+}
+
+async function copyCode(account: TotpAccount) {
+    let code: TotpCode;
+    try {
+        code = await totp_generate_code(account.id);
+    } catch (e) {
+        showHud(`Unable to generate code: ${errorMessage(e)}`)
+        return
+    }
+
+    const { totpClipboardClearSeconds } = pluginPreferences<TotpPreferences>();
+
+    if (totpClipboardClearSeconds > 0) {
+        // cleared by server, so it also happens if plugin is stopped before timeout
+        await Clipboard.writeText(code.code, { clearAfterSeconds: totpClipboardClearSeconds })
+
+        showHud(`Code for '${account.name}' copied, valid for ${code.remaining_seconds}s, clipboard is cleared in ${totpClipboardClearSeconds}s`)
+    } else {
+        await Clipboard.writeText(code.code)
+
+        showHud(`Code for '${account.name}' copied, valid for ${code.remaining_seconds}s`)
+    }
+}
+
+function errorMessage(e: unknown): string {
+    return e instanceof Error ? e.message : `${e}`
+}
@@ -0,0 +1,254 @@
+import { Action, ActionPanel, Detail, List, TextAccessory } from "@project-gauntlet/api/components";
+import { useNavigation } from "@project-gauntlet/api/hooks";
+import { showHud } from "@project-gauntlet/api/helpers";
+import { ReactElement, useEffect, useState } from "react";
+import { trash_delete, trash_list, trash_restore } from "gauntlet:bridge/internal-all";
+import { emptyTrash, formatCount } from "./empty-trash";
+
+export default function Trash(): ReactElement {
+    const { pushView } = useNavigation();
+    const [items, setItems] = useState<TrashItem[] | undefined>(undefined);
+    const [searchText, setSearchText] = useState<string | undefined>("");
+    const [reloadCounter, setReloadCounter] = useState(0);
+
+    const reload = () => setReloadCounter(counter => counter + 1);
+
+    useEffect(() => {
+        trash_list()
+            .then(items => setItems(sortByDeletionDate(items)))
+            .catch(e => {
+                console.error("Unable to list trash", e)
+                setItems([])
+            })
+    }, [reloadCounter]);
+
+    const query = (searchText ?? "").trim().toLowerCase();
+
+    const results = (items ?? [])
+        .filter(item => item.name.toLowerCase().includes(query) || (item.original_path ?? "").toLowerCase().includes(query));
+
+    return (
+        <List
+            isLoading={items === undefined}
+            actions={
+                <ActionPanel>
+                    {items !== undefined && items.length > 0 && (
+                        <Action
+                            label={`Empty Trash (${formatCount(items.length)})`}
+                            onAction={() => pushView(<ConfirmEmptyTrash count={items.length} onEmptied={reload}/>)}
+                        />
+                    )}
+                    <Action
+                        label="Reload"
+                        onAction={reload}
+                    />
+                </ActionPanel>
+            }
+        >
+            <List.SearchBar
+                placeholder="Search trashed files..."
+                value={searchText}
+                onChange={setSearchText}
+            />
+            {items !== undefined && items.length === 0 && (
+                <List.EmptyView title="Trash is empty"/>
+            )}
+            {results.map(item => (
+                <List.Item
+                    key={item.id}
+                    title={item.name}
+                    subtitle={item.original_path !== undefined ? parentDir(item.original_path) : undefined}
+                    accessories={[
+                        <TextAccessory text={formatSize(item)} tooltip="Size"/>,
+                        ...(item.deleted_at !== undefined ? [<TextAccessory text={formatDeletionDate(item.deleted_at)} tooltip="Deleted at"/>] : []),
+                    ]}
+                    onClick={() => pushView(<TrashItemDetail item={item} onChanged={reload}/>)}
+                />
+            ))}
+        </List>
+    )
+}
+
+function TrashItemDetail({ item, onChanged }: { item: TrashItem, onChanged: () => void }): ReactElement {
+    const { pushView } = useNavigation();
+
+    return (
+        <Detail
+            actions={
+                <ActionPanel title={item.name}>
+                    {item.original_path !== undefined && (
+                        <Action
+                            label="Restore"
+                            onAction={() => pushView(
+                                <ConfirmTrashAction
+                                    item={item}
+                                    verb="restore"
+                                    description={`'${item.name}' will be moved back to ${parentDir(item.original_path!!)}`}
+                                    run={async () => {
+                                        const path = await trash_restore(item.id);
+                                        return `Restored '${item.name}' to ${path}`
+                                    }}
+                                    onDone={onChanged}
+                                />
+                            )}
+                        />
+                    )}
+                    <Action
+                        label="Delete Permanently"
+                        onAction={() => pushView(
+                            <ConfirmTrashAction
+                                item={item}
+                                verb="delete permanently"
+                                description={`'${item.name}' will be deleted and cannot be restored`}
+                                run={async () => {
+                                    await trash_delete(item.id);
+                                    return `Permanently deleted '${item.name}'`
+                                }}
+                                onDone={onChanged}
+                            />
+                        )}
+                    />
+                </ActionPanel>
+            }
+        >
+            <Detail.Metadata>
+                <Detail.Metadata.Value label="Name">{item.name}</Detail.Metadata.Value>
+                <Detail.Metadata.Value label="Original Location">{item.original_path !== undefined ? parentDir(item.original_path) : "Unknown"}</Detail.Metadata.Value>
+                <Detail.Metadata.Value label="Size">{formatSize(item)}</Detail.Metadata.Value>
+                {item.deleted_at !== undefined && (
+                    <Detail.Metadata.Value label="Deleted At">{formatDeletionDate(item.deleted_at)}</Detail.Metadata.Value>
+                )}
+            </Detail.Metadata>
+            <Detail.Content>
+                <Detail.Content.H3>{item.name}</Detail.Content.H3>
+                {item.original_path === undefined && (
+                    <Detail.Content.Paragraph>
+                        {"Original location of this item is not known, it can be put back using file manager"}
+                    </Detail.Content.Paragraph>
+                )}
+            </Detail.Content>
+        </Detail>
+    )
+}
+
+// deleting cannot be undone and restoring moves files out of trash, so both need to be confirmed explicitly
+function ConfirmTrashAction(props: { item: TrashItem, verb: string, description: string, run: () => Promise<string>, onDone: () => void }): ReactElement {
+    const { item, verb, description, run, onDone } = props;
+    const { popView } = useNavigation();
+
+    return (
+        <Detail
+            actions={
+                <ActionPanel>
+                    <Action
+                        label={`Confirm ${capitalize(verb)}`}
+                        onAction={async () => {
+                            try {
+                                showHud(await run())
+                                onDone()
+                            } catch (e) {
+                                showHud(`Unable to ${verb} '${item.name}': ${errorMessage(e)}`)
+                            }
+
+                            // back to trash list
+                            popView()
+                            popView()
+                        }}
+                    />
+                    <Action
+                        label="Cancel"
+                        onAction={() => popView()}
+                    />
+                </ActionPanel>
+            }
+        >
+            <Detail.Content>
+                <Detail.Content.H3>{`${capitalize(verb)} '${item.name}'?`}</Detail.Content.H3>
+                <Detail.Content.Paragraph>{description}</Detail.Content.Paragraph>
+            </Detail.Content>
+        </Detail>
+    )
+}
+
+function ConfirmEmptyTrash({ count, onEmptied }: { count: number, onEmptied: () => void }): ReactElement {
+    const { popView } = useNavigation();
+
+    return (
+        <Detail
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Confirm Empty Trash"
+                        onAction={async () => {
+                            await emptyTrash()
+                            onEmptied()
+                            popView()
+                        }}
+                    />
+                    <Action
+                        label="Cancel"
+                        onAction={() => popView()}
+                    />
+                </ActionPanel>
+            }
+        >
+            <Detail.Content>
+                <Detail.Content.H3>{"Empty Trash?"}</Detail.Content.H3>
+                <Detail.Content.Paragraph>
+                    {`${formatCount(count)} will be deleted and cannot be restored.`}
+                </Detail.Content.Paragraph>
+            </Detail.Content>
+        </Detail>
+    )
+}
+
+// most recently deleted first, items without date at the end
+function sortByDeletionDate(items: TrashItem[]): TrashItem[] {
+    return [...items].sort((a, b) => {
+        if (a.deleted_at === b.deleted_at) {
+            return a.name.localeCompare(b.name)
+        }
+
+        if (a.deleted_at === undefined) {
+            return 1
+        }
+
+        if (b.deleted_at === undefined) {
+            return -1
+        }
+
+        return b.deleted_at.localeCompare(a.deleted_at)
+    })
+}
+
+function parentDir(path: string): string {
+    const index = path.lastIndexOf("/");
+
+    return index <= 0 ? "/" : path.substring(0, index)
+}
+
+// date is in local time, as written by file manager
+function formatDeletionDate(deletedAt: string): string {
+    return deletedAt.replace("T", " ")
+}
+
+function formatSize(item: TrashItem): string {
+    const units = ["B", "KB", "MB", "GB", "TB"];
+
+    let value = item.size;
+    let unit = 0;
+    while (value >= 1024 && unit < units.length - 1) {
+        value /= 1024
+        unit += 1
+    }
+
+    return `${value.toFixed(unit === 0 ? 0 : 1)} ${units[unit]}`
+}
+
+function capitalize(text: string): string {
+    return text.charAt(0).toUpperCase() + text.slice(1)
+}
+
+function errorMessage(e: unknown): string {
+    return e instanceof Error ? e.message : `${e}`
+}
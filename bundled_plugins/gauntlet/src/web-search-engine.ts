@@ -0,0 +1,24 @@
+import { pluginPreferences } from "@project-gauntlet/api/helpers";
+
+export type WebSearchEngine = "google" | "duckduckgo" | "bing" | "kagi"
+
+type WebSearchPreferences = {
+    webSearchEngine: WebSearchEngine
+}
+
+const searchUrls: Record<WebSearchEngine, string> = {
+    google: "https://www.google.com/search?q=",
+    duckduckgo: "https://duckduckgo.com/?q=",
+    bing: "https://www.bing.com/search?q=",
+    kagi: "https://kagi.com/search?q=",
+}
+
+export function webSearchEngine(): WebSearchEngine {
+    const { webSearchEngine } = pluginPreferences<WebSearchPreferences>();
+
+    return webSearchEngine in searchUrls ? webSearchEngine : "google"
+}
+
+export function webSearchUrl(query: string): string {
+    return searchUrls[webSearchEngine()] + encodeURIComponent(query)
+}
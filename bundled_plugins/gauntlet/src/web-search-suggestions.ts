@@ -0,0 +1,27 @@
+import { GeneratedCommand, GeneratorSearchContext } from "@project-gauntlet/api/helpers";
+import { open_url, web_search_suggestions } from "gauntlet:bridge/internal-all";
+import { webSearchEngine, webSearchUrl } from "./web-search-engine";
+
+// suggestions are only provided from search function
+export default function WebSearchSuggestions(): void {
+}
+
+// suggestions are shown only in keyword mode, otherwise every typed character would be sent to search engine
+export async function search(query: string, { keywordMode }: GeneratorSearchContext): Promise<GeneratedCommand[]> {
+    if (!keywordMode || query.trim() === "") {
+        return []
+    }
+
+    const suggestions = await web_search_suggestions(webSearchEngine(), query);
+
+    // typed query itself is always the first result
+    const queries = [query.trim(), ...suggestions.filter(suggestion => suggestion !== query.trim())];
+
+    return queries.map(suggestion => ({
+        name: suggestion,
+        accessory: "Search the Web",
+        fn: () => {
+            open_url(webSearchUrl(suggestion))
+        },
+    }))
+}
@@ -0,0 +1,7 @@
+import { CommandProps } from "@project-gauntlet/api/helpers";
+import { open_url } from "gauntlet:bridge/internal-all";
+import { webSearchUrl } from "./web-search-engine";
+
+export default function WebSearch({ arguments: { query } }: CommandProps<{ query: string }>): void {
+    open_url(webSearchUrl(query))
+}
@@ -0,0 +1,35 @@
+export type EntrypointBadge = {
+    type: "count";
+    count: number;
+} | {
+    type: "dot";
+};
+export type UndoEntryKind = "action" | "undo" | "redo";
+export type SearchResultPreview = {
+    text: string | undefined;
+    image: ArrayBuffer | undefined;
+    metadata: SearchResultPreviewMetadata[];
+};
+export type SearchResultPreviewMetadata = {
+    label: string;
+    value: string;
+};
+export type ClipboardData = {
+    text_data?: string;
+    png_data?: number[];
+};
+export type PreferenceValueTypes = {
+    number: number;
+    string: string;
+    enum: string;
+    bool: boolean;
+    list_of_strings: string[];
+    list_of_numbers: number[];
+    list_of_enums: string[];
+    directory: string;
+};
+export type PreferenceValue = number | string | boolean | string[] | number[];
+export type RenderError = {
+    message: string;
+    component_path: string[];
+};
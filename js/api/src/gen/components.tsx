@@ -6,7 +6,9 @@ declare global {
             ["gauntlet:action"]: {
                 id?: string;
                 label: string;
+                keepWindowOpen?: boolean;
                 onAction: () => void;
+                onBatchAction?: (ids: string[]) => void;
             };
             ["gauntlet:action_panel_section"]: {
                 children?: ElementComponent<typeof Action>;
@@ -66,11 +68,41 @@ declare global {
             ["gauntlet:code_block"]: {
                 children?: StringComponent;
             };
-            ["gauntlet:paragraph"]: {
+            ["gauntlet:color_swatch"]: {
+                color: string;
+            };
+            ["gauntlet:sparkline"]: {
+                data: number[];
+                color?: string;
+                height?: number;
+            };
+            ["gauntlet:bar_chart"]: {
+                data: number[];
+                color?: string;
+                height?: number;
+            };
+            ["gauntlet:paragraph_bold"]: {
+                children?: StringComponent;
+            };
+            ["gauntlet:paragraph_italic"]: {
+                children?: StringComponent;
+            };
+            ["gauntlet:paragraph_code"]: {
+                children?: StringComponent;
+            };
+            ["gauntlet:paragraph_link"]: {
+                children?: StringComponent;
+                href: string;
+            };
+            ["gauntlet:paragraph_color"]: {
                 children?: StringComponent;
+                color: TextColor;
+            };
+            ["gauntlet:paragraph"]: {
+                children?: StringOrElementComponent<typeof ParagraphBold | typeof ParagraphItalic | typeof ParagraphCode | typeof ParagraphLink | typeof ParagraphColor>;
             };
             ["gauntlet:content"]: {
-                children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock>;
+                children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock | typeof ColorSwatch | typeof Sparkline | typeof BarChart>;
             };
             ["gauntlet:detail"]: {
                 children?: ElementComponent<typeof ActionPanel | typeof Metadata | typeof Content>;
@@ -107,9 +139,17 @@ declare global {
                 value?: string;
                 onChange?: (value: string | undefined) => void;
             };
+            ["gauntlet:slider"]: {
+                label?: string;
+                value?: number;
+                min?: number;
+                max?: number;
+                step?: number;
+                onChange?: (value: number) => void;
+            };
             ["gauntlet:separator"]: {};
             ["gauntlet:form"]: {
-                children?: ElementComponent<typeof ActionPanel | typeof TextField | typeof PasswordField | typeof Checkbox | typeof DatePicker | typeof Select | typeof Separator>;
+                children?: ElementComponent<typeof ActionPanel | typeof TextField | typeof PasswordField | typeof Checkbox | typeof DatePicker | typeof Select | typeof Slider | typeof Separator>;
                 isLoading?: boolean;
             };
             ["gauntlet:inline_separator"]: {
@@ -139,9 +179,11 @@ declare global {
             };
             ["gauntlet:list_item"]: {
                 children?: ElementComponent<typeof TextAccessory | typeof IconAccessory>;
+                id?: string;
                 title: string;
                 subtitle?: string;
                 icon?: Image;
+                drag?: DragPayload;
                 onClick?: () => void;
             };
             ["gauntlet:list_section"]: {
@@ -152,11 +194,14 @@ declare global {
             ["gauntlet:list"]: {
                 children?: ElementComponent<typeof ActionPanel | typeof ListItem | typeof ListSection | typeof SearchBar | typeof EmptyView | typeof Detail>;
                 isLoading?: boolean;
+                isLoadingMore?: boolean;
+                multiSelect?: boolean;
             };
             ["gauntlet:grid_item"]: {
                 children?: ElementComponent<typeof IconAccessory | typeof Content>;
                 title?: string;
                 subtitle?: string;
+                drag?: DragPayload;
                 onClick?: () => void;
             };
             ["gauntlet:grid_section"]: {
@@ -359,15 +404,34 @@ export type ImageSourceUrl = {
 export type ImageSourceAsset = {
     asset: string;
 };
-export type ImageSource = ImageSourceUrl | ImageSourceAsset;
+export type ImageSourceThumbnail = {
+    thumbnail: string;
+};
+export type ImageSource = ImageSourceUrl | ImageSourceAsset | ImageSourceThumbnail;
 export type Image = ImageSource | Icons;
+export type DragPayloadFile = {
+    file: string;
+};
+export type DragPayloadUrl = {
+    url: string;
+};
+export type DragPayload = DragPayloadFile | DragPayloadUrl;
+export enum TextColor {
+    Accent = "Accent",
+    Muted = "Muted",
+    Success = "Success",
+    Warning = "Warning",
+    Danger = "Danger"
+}
 export interface ActionProps {
     id?: string;
     label: string;
+    keepWindowOpen?: boolean;
     onAction: () => void;
+    onBatchAction?: (ids: string[]) => void;
 }
 export const Action: FC<ActionProps> = (props: ActionProps): ReactNode => {
-    return <gauntlet:action id={props.id} label={props.label} onAction={props.onAction}></gauntlet:action>;
+    return <gauntlet:action id={props.id} label={props.label} keepWindowOpen={props.keepWindowOpen} onAction={props.onAction} onBatchAction={props.onBatchAction}></gauntlet:action>;
 };
 export interface ActionPanelSectionProps {
     children?: ElementComponent<typeof Action>;
@@ -501,14 +565,79 @@ export interface CodeBlockProps {
 export const CodeBlock: FC<CodeBlockProps> = (props: CodeBlockProps): ReactNode => {
     return <gauntlet:code_block>{props.children}</gauntlet:code_block>;
 };
-export interface ParagraphProps {
+export interface ColorSwatchProps {
+    color: string;
+}
+export const ColorSwatch: FC<ColorSwatchProps> = (props: ColorSwatchProps): ReactNode => {
+    return <gauntlet:color_swatch color={props.color}></gauntlet:color_swatch>;
+};
+export interface SparklineProps {
+    data: number[];
+    color?: string;
+    height?: number;
+}
+export const Sparkline: FC<SparklineProps> = (props: SparklineProps): ReactNode => {
+    return <gauntlet:sparkline data={props.data} color={props.color} height={props.height}></gauntlet:sparkline>;
+};
+export interface BarChartProps {
+    data: number[];
+    color?: string;
+    height?: number;
+}
+export const BarChart: FC<BarChartProps> = (props: BarChartProps): ReactNode => {
+    return <gauntlet:bar_chart data={props.data} color={props.color} height={props.height}></gauntlet:bar_chart>;
+};
+export interface ParagraphBoldProps {
+    children?: StringComponent;
+}
+export const ParagraphBold: FC<ParagraphBoldProps> = (props: ParagraphBoldProps): ReactNode => {
+    return <gauntlet:paragraph_bold>{props.children}</gauntlet:paragraph_bold>;
+};
+export interface ParagraphItalicProps {
+    children?: StringComponent;
+}
+export const ParagraphItalic: FC<ParagraphItalicProps> = (props: ParagraphItalicProps): ReactNode => {
+    return <gauntlet:paragraph_italic>{props.children}</gauntlet:paragraph_italic>;
+};
+export interface ParagraphCodeProps {
+    children?: StringComponent;
+}
+export const ParagraphCode: FC<ParagraphCodeProps> = (props: ParagraphCodeProps): ReactNode => {
+    return <gauntlet:paragraph_code>{props.children}</gauntlet:paragraph_code>;
+};
+export interface ParagraphLinkProps {
+    children?: StringComponent;
+    href: string;
+}
+export const ParagraphLink: FC<ParagraphLinkProps> = (props: ParagraphLinkProps): ReactNode => {
+    return <gauntlet:paragraph_link href={props.href}>{props.children}</gauntlet:paragraph_link>;
+};
+export interface ParagraphColorProps {
     children?: StringComponent;
+    color: TextColor;
 }
-export const Paragraph: FC<ParagraphProps> = (props: ParagraphProps): ReactNode => {
+export const ParagraphColor: FC<ParagraphColorProps> = (props: ParagraphColorProps): ReactNode => {
+    return <gauntlet:paragraph_color color={props.color}>{props.children}</gauntlet:paragraph_color>;
+};
+export interface ParagraphProps {
+    children?: StringOrElementComponent<typeof ParagraphBold | typeof ParagraphItalic | typeof ParagraphCode | typeof ParagraphLink | typeof ParagraphColor>;
+}
+export const Paragraph: FC<ParagraphProps> & {
+    Bold: typeof ParagraphBold;
+    Italic: typeof ParagraphItalic;
+    Code: typeof ParagraphCode;
+    Link: typeof ParagraphLink;
+    Color: typeof ParagraphColor;
+} = (props: ParagraphProps): ReactNode => {
     return <gauntlet:paragraph>{props.children}</gauntlet:paragraph>;
 };
+Paragraph.Bold = ParagraphBold;
+Paragraph.Italic = ParagraphItalic;
+Paragraph.Code = ParagraphCode;
+Paragraph.Link = ParagraphLink;
+Paragraph.Color = ParagraphColor;
 export interface ContentProps {
-    children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock>;
+    children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock | typeof ColorSwatch | typeof Sparkline | typeof BarChart>;
 }
 export const Content: FC<ContentProps> & {
     Paragraph: typeof Paragraph;
@@ -521,6 +650,9 @@ export const Content: FC<ContentProps> & {
     H6: typeof H6;
     HorizontalBreak: typeof HorizontalBreak;
     CodeBlock: typeof CodeBlock;
+    ColorSwatch: typeof ColorSwatch;
+    Sparkline: typeof Sparkline;
+    BarChart: typeof BarChart;
 } = (props: ContentProps): ReactNode => {
     return <gauntlet:content>{props.children}</gauntlet:content>;
 };
@@ -534,6 +666,9 @@ Content.H5 = H5;
 Content.H6 = H6;
 Content.HorizontalBreak = HorizontalBreak;
 Content.CodeBlock = CodeBlock;
+Content.ColorSwatch = ColorSwatch;
+Content.Sparkline = Sparkline;
+Content.BarChart = BarChart;
 export interface DetailProps {
     children?: ElementComponent<typeof Metadata | typeof Content>;
     isLoading?: boolean;
@@ -599,11 +734,22 @@ export const Select: FC<SelectProps> & {
     return <gauntlet:select label={props.label} value={props.value} onChange={props.onChange}>{props.children}</gauntlet:select>;
 };
 Select.Item = SelectItem;
+export interface SliderProps {
+    label?: string;
+    value?: number;
+    min?: number;
+    max?: number;
+    step?: number;
+    onChange?: (value: number) => void;
+}
+export const Slider: FC<SliderProps> = (props: SliderProps): ReactNode => {
+    return <gauntlet:slider label={props.label} value={props.value} min={props.min} max={props.max} step={props.step} onChange={props.onChange}></gauntlet:slider>;
+};
 export const Separator: FC = (): ReactNode => {
     return <gauntlet:separator></gauntlet:separator>;
 };
 export interface FormProps {
-    children?: ElementComponent<typeof TextField | typeof PasswordField | typeof Checkbox | typeof DatePicker | typeof Select | typeof Separator>;
+    children?: ElementComponent<typeof TextField | typeof PasswordField | typeof Checkbox | typeof DatePicker | typeof Select | typeof Slider | typeof Separator>;
     isLoading?: boolean;
     actions?: ElementComponent<typeof ActionPanel>;
 }
@@ -613,6 +759,7 @@ export const Form: FC<FormProps> & {
     Checkbox: typeof Checkbox;
     DatePicker: typeof DatePicker;
     Select: typeof Select;
+    Slider: typeof Slider;
     Separator: typeof Separator;
 } = (props: FormProps): ReactNode => {
     return <gauntlet:form isLoading={props.isLoading}>{props.actions as any}{props.children}</gauntlet:form>;
@@ -622,6 +769,7 @@ Form.PasswordField = PasswordField;
 Form.Checkbox = Checkbox;
 Form.DatePicker = DatePicker;
 Form.Select = Select;
+Form.Slider = Slider;
 Form.Separator = Separator;
 export interface InlineSeparatorProps {
     icon?: Icons;
@@ -677,14 +825,16 @@ export const SearchBar: FC<SearchBarProps> = (props: SearchBarProps): ReactNode
     return <gauntlet:search_bar value={props.value} placeholder={props.placeholder} onChange={props.onChange}></gauntlet:search_bar>;
 };
 export interface ListItemProps {
+    id?: string;
     title: string;
     subtitle?: string;
     icon?: Image;
     accessories?: (ElementComponent<typeof TextAccessory> | ElementComponent<typeof IconAccessory>)[];
+    drag?: DragPayload;
     onClick?: () => void;
 }
 export const ListItem: FC<ListItemProps> = (props: ListItemProps): ReactNode => {
-    return <gauntlet:list_item title={props.title} subtitle={props.subtitle} icon={props.icon} onClick={props.onClick}>{props.accessories as any}</gauntlet:list_item>;
+    return <gauntlet:list_item id={props.id} title={props.title} subtitle={props.subtitle} icon={props.icon} drag={props.drag} onClick={props.onClick}>{props.accessories as any}</gauntlet:list_item>;
 };
 export interface ListSectionProps {
     children?: ElementComponent<typeof ListItem>;
@@ -701,6 +851,8 @@ export interface ListProps {
     children?: ElementComponent<typeof ListItem | typeof ListSection | typeof SearchBar | typeof EmptyView | typeof Detail>;
     actions?: ElementComponent<typeof ActionPanel>;
     isLoading?: boolean;
+    isLoadingMore?: boolean;
+    multiSelect?: boolean;
 }
 export const List: FC<ListProps> & {
     Item: typeof ListItem;
@@ -709,7 +861,7 @@ export const List: FC<ListProps> & {
     EmptyView: typeof EmptyView;
     Detail: typeof Detail;
 } = (props: ListProps): ReactNode => {
-    return <gauntlet:list isLoading={props.isLoading}>{props.actions as any}{props.children}</gauntlet:list>;
+    return <gauntlet:list isLoading={props.isLoading} isLoadingMore={props.isLoadingMore} multiSelect={props.multiSelect}>{props.actions as any}{props.children}</gauntlet:list>;
 };
 List.Item = ListItem;
 List.Section = ListSection;
@@ -721,12 +873,13 @@ export interface GridItemProps {
     title?: string;
     subtitle?: string;
     accessory?: ElementComponent<typeof IconAccessory>;
+    drag?: DragPayload;
     onClick?: () => void;
 }
 export const GridItem: FC<GridItemProps> & {
     Content: typeof Content;
 } = (props: GridItemProps): ReactNode => {
-    return <gauntlet:grid_item title={props.title} subtitle={props.subtitle} onClick={props.onClick}>{props.accessory as any}{props.children}</gauntlet:grid_item>;
+    return <gauntlet:grid_item title={props.title} subtitle={props.subtitle} drag={props.drag} onClick={props.onClick}>{props.accessory as any}{props.children}</gauntlet:grid_item>;
 };
 GridItem.Content = Content;
 export interface GridSectionProps {
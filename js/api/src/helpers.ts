@@ -1,11 +1,26 @@
+import type { DragPayload } from "./gen/components";
+import type { PreferenceValue, PreferenceValueTypes } from "./gen/api";
 // @ts-ignore TODO how to add declaration for this?
-import { getAssetData, getAssetDataSync, getPluginPreferences, getEntrypointPreferences, showHudWindow } from "ext:gauntlet/renderer.js";
+import { addConnectivityChangeListener, addIdleChangeListener, addInterPluginEventListener, addPreferenceChangeListener, addSessionValueListener, getAssetData, getAssetDataSync, getPluginPreferences, getEntrypointPreferences, getSessionValue, isPluginIdle, isPluginOnline, registerUndoAction, setSessionValue, showHudWindow } from "ext:gauntlet/renderer.js";
 import {
     clipboard_clear,
     clipboard_read,
     clipboard_read_text,
     clipboard_write,
     clipboard_write_text,
+    secret_read,
+    event_publish,
+    active_window,
+    play_sound,
+    set_entrypoint_badge,
+    view_state_read,
+    view_state_write,
+    http_fetch,
+    realtime_websocket_connect,
+    realtime_event_source_connect,
+    realtime_send,
+    realtime_close,
+    realtime_next_event,
     environment_gauntlet_version,
     environment_is_development,
     environment_plugin_cache_dir,
@@ -20,6 +35,10 @@ export function assetData(path: string): Promise<ArrayBuffer> {
     return getAssetData(path)
 }
 
+// value types of preferences, keyed by preference type used in plugin manifest, e.g.
+// pluginPreferences<{ apiKey: PreferenceValueTypes["string"], limit: PreferenceValueTypes["number"] }>()
+export type { PreferenceValue, PreferenceValueTypes };
+
 export function pluginPreferences<T extends Record<string, any>>(): T {
     return getPluginPreferences()
 }
@@ -28,15 +47,164 @@ export function entrypointPreferences<T extends Record<string, any>>(): T {
     return getEntrypointPreferences()
 }
 
+export type PreferenceChangeEvent = {
+    // undefined if plugin preference was changed
+    entrypointId: string | undefined,
+    preferenceId: string,
+};
+
+// open view is re-rendered automatically, listener is useful for state derived from preferences
+// returns function that removes the listener
+export function onPreferenceChange(listener: (event: PreferenceChangeEvent) => void): () => void {
+    return addPreferenceChangeListener(listener)
+}
+
+// plugin is idle when launcher window is hidden for a while, background work like polling
+// or handling file changes should be paused until it is shown again
+export function isIdle(): boolean {
+    return isPluginIdle()
+}
+
+// returns function that removes the listener
+export function onIdleChange(listener: (idle: boolean) => void): () => void {
+    return addIdleChangeListener(listener)
+}
+
+// machine is considered offline when server is unable to reach the internet,
+// requests can be skipped and cached data shown instead of waiting for them to time out
+export function isOnline(): boolean {
+    return isPluginOnline()
+}
+
+// returns function that removes the listener
+export function onConnectivityChange(listener: (online: boolean) => void): () => void {
+    return addConnectivityChangeListener(listener)
+}
+
 export function showHud(display: string): void {
     return showHudWindow(display)
 }
 
+export type UndoFunction = () => void | UndoFunction | Promise<void | UndoFunction>
+
+// shows transient "Undo" toast in main window, undo is run when toast is clicked or Ctrl+Z is pressed.
+// if undo function returns a function, it is used to redo the change with Ctrl+Shift+Z
+export async function registerUndo(description: string, undo: UndoFunction): Promise<void> {
+    return await registerUndoAction(description, undo)
+}
+
+export type EntrypointBadge = number | "dot"
+
+// badge is shown on main view search result of the entrypoint, e.g. number of unread notifications
+// it is kept until it is cleared by passing undefined or 0, or until plugin is stopped
+export async function setEntrypointBadge(entrypointId: string, badge: EntrypointBadge | undefined): Promise<void> {
+    if (badge === "dot") {
+        return await set_entrypoint_badge(entrypointId, { type: "dot" })
+    }
+
+    if (badge === undefined || badge <= 0) {
+        return await set_entrypoint_badge(entrypointId, undefined)
+    }
+
+    return await set_entrypoint_badge(entrypointId, { type: "count", count: Math.floor(badge) })
+}
+
+export type BuiltinSound = "timer-finished" | "error"
+
+// builtin sound or file from assets directory of the plugin, e.g. { asset: "sounds/done.ogg" }.
+// wav, ogg, mp3 and flac files are supported, nothing is played if sounds are muted in Settings
+export async function playSound(sound: BuiltinSound | { asset: string }): Promise<void> {
+    if (typeof sound === "string") {
+        return await play_sound({ type: "builtin", name: sound })
+    }
+
+    return await play_sound({ type: "asset", path: sound.asset })
+}
+
+// persisted by Gauntlet per entrypoint, meant for small values like last selected tab or filter, see also useViewState hook.
+// value is serialized using JSON.stringify and can be up to 16 KiB, undefined removes it
+export async function getViewState<T>(entrypointId: string, key: string): Promise<T | undefined> {
+    const value = await view_state_read(entrypointId, key);
+
+    return value === undefined ? undefined : JSON.parse(value) as T
+}
+
+export async function setViewState<T>(entrypointId: string, key: string, value: T | undefined): Promise<void> {
+    return await view_state_write(entrypointId, key, value === undefined ? undefined : JSON.stringify(value))
+}
+
+// kept in memory of plugin runtime and shared between all entrypoints of the plugin, e.g. timer started by one
+// entrypoint and shown by another, see also useSessionState hook. cleared when plugin is stopped or reloaded.
+// values are not copied, so mutating stored object is not reported to listeners, undefined removes the value
+export function getSessionState<T>(key: string): T | undefined {
+    return getSessionValue(key) as T | undefined
+}
+
+export function setSessionState<T>(key: string, value: T | undefined): void {
+    setSessionValue(key, value)
+}
+
+// returns function that removes the listener
+export function onSessionStateChange<T>(key: string, listener: (value: T | undefined) => void): () => void {
+    return addSessionValueListener(key, listener)
+}
+
+export type ActiveWindow = {
+    // bundle identifier on macOS, WM_CLASS on X11, executable name on Windows
+    appId: string
+    appName: string | undefined
+    // not available on macOS
+    title: string | undefined
+}
+
+// window which was focused right before launcher window was shown, e.g. to copy url of the browser tab.
+// requires "read" in "active_window" permission in plugin manifest, undefined on Wayland or if window couldn't be detected
+export async function getActiveWindow(): Promise<ActiveWindow | undefined> {
+    const window = await active_window();
+
+    if (window === undefined) {
+        return undefined
+    }
+
+    return { appId: window.app_id, appName: window.app_name, title: window.title }
+}
+
 export interface GeneratedCommand {
     name: string
     icon?: ArrayBuffer
     fn: () => void
     actions?: GeneratedCommandAction[]
+    // short text shown on the right side of search result, e.g. "Running"
+    accessory?: string
+    // called when search result is focused while preview pane is open
+    preview?: () => GeneratedCommandPreview | Promise<GeneratedCommandPreview>
+    // file or url dropped into other application when search result is dragged out of Gauntlet window
+    drag?: DragPayload
+    // hints used to rank command in search results, see GeneratedCommandRanking
+    ranking?: GeneratedCommandRanking
+    // commands with the same key, even from different plugins, are shown as one search result, e.g. "app:/usr/share/applications/firefox.desktop".
+    // highest ranked one is kept and actions of the rest are added to its action panel
+    dedupeKey?: string
+    // used when search is filtered by type, e.g. using "type:app" prefix, "command" by default
+    category?: "application" | "command" | "quicklink" | "file"
+}
+
+// score of search result is `text score * boost + recency bonus`, results with the same score are ordered by how often and recently they were used.
+// text score is higher for better matches, e.g. name starting with the query is above name that only contains it.
+// hints are not used when search query is empty
+export interface GeneratedCommandRanking {
+    // multiplier of text score, between 0.1 and 10, 1 by default
+    boost?: number
+    // query exactly matching one of keywords (case-insensitive) finds the command even if its name doesn't contain it, and ranks it above name matches
+    keywords?: string[]
+    // milliseconds since unix epoch, e.g. when project was last opened. adds up to 1 to score for current time, halves every day
+    timestamp?: number
+}
+
+export interface GeneratedCommandPreview {
+    text?: string
+    image?: ArrayBuffer
+    metadata?: { label: string, value: string }[]
 }
 
 export interface GeneratedCommandAction {
@@ -45,11 +213,24 @@ export interface GeneratedCommandAction {
     fn: () => void
 }
 
+export type CommandProps<ARGUMENTS extends Record<string, string | number | undefined> = Record<string, string | number | undefined>> = {
+    // optional arguments that were not provided are absent
+    arguments: ARGUMENTS,
+};
+
 export type GeneratorProps = {
     add: (id: string, data: GeneratedCommand) => void,
     remove: (id: string) => void,
 };
 
+// exported as `search` from command generator entrypoint, called when text in main search bar changes
+export type GeneratorSearch = (query: string, context: GeneratorSearchContext) => GeneratedCommand[] | Promise<GeneratedCommand[]>;
+
+export type GeneratorSearchContext = {
+    // true when prompt starts with keyword of this plugin, in that case query doesn't include the keyword
+    keywordMode: boolean,
+};
+
 export const Clipboard: Clipboard = {
     read: async function (): Promise<{ "text/plain"?: string | undefined; "image/png"?: ArrayBuffer | undefined; }> {
         const data = await clipboard_read();
@@ -85,8 +266,8 @@ export const Clipboard: Clipboard = {
 
         return await clipboard_write(write_data)
     },
-    writeText: async function (data: string): Promise<void> {
-        return await clipboard_write_text(data)
+    writeText: async function (data: string, options?: { clearAfterSeconds?: number }): Promise<void> {
+        return await clipboard_write_text(data, options?.clearAfterSeconds)
     },
     clear: async function (): Promise<void> {
         await clipboard_clear()
@@ -97,10 +278,41 @@ export interface Clipboard {
     read(): Promise<{ ["text/plain"]?: string, ["image/png"]?: ArrayBuffer }>;
     readText(): Promise<string | undefined>;
     write(data: { ["text/plain"]?: string, ["image/png"]?: ArrayBuffer }): Promise<void>;
-    writeText(data: string): Promise<void>;
+    // if clearAfterSeconds is set, text is removed from clipboard after that time unless something else was copied,
+    // even if plugin is stopped. requires "clear" permission
+    writeText(data: string, options?: { clearAfterSeconds?: number }): Promise<void>;
     clear(): Promise<void>;
 }
 
+export const Secrets: Secrets = {
+    read: async function (service: string, account: string): Promise<string | undefined> {
+        return await secret_read(service, account)
+    },
+}
+
+export interface Secrets {
+    // requires service to be listed in "read" permission of "secrets" section in plugin manifest
+    read(service: string, account: string): Promise<string | undefined>;
+}
+
+export const Events: Events = {
+    publish: async function (topic: string, payload?: any): Promise<void> {
+        await event_publish(topic, JSON.stringify(payload ?? null))
+    },
+    subscribe: function (topic: string, listener: (payload: any, sourcePluginId: string) => void): () => void {
+        return addInterPluginEventListener(topic, listener)
+    },
+}
+
+export interface Events {
+    // requires topic to be listed in "publish" permission of "events" section in plugin manifest.
+    // payload is serialized to JSON and delivered to running plugins subscribed to the topic
+    publish(topic: string, payload?: any): Promise<void>;
+    // requires topic to be listed in "subscribe" permission of "events" section in plugin manifest.
+    // returns function that removes the listener
+    subscribe(topic: string, listener: (payload: any, sourcePluginId: string) => void): () => void;
+}
+
 export const Environment: Environment = {
     get gauntletVersion(): number {
         return environment_gauntlet_version()
@@ -123,3 +335,201 @@ export interface Environment {
     get pluginCacheDir(): string;
 }
 
+export interface HttpRequestOptions {
+    method?: string
+    headers?: Record<string, string>
+    body?: string | ArrayBuffer | Uint8Array
+    // 30 seconds by default
+    timeoutMs?: number
+    // GET responses are cached according to Cache-Control, true by default
+    cache?: boolean
+}
+
+export type HttpErrorKind = "invalid_request" | "permission_denied" | "rate_limited" | "timeout" | "network" | "too_large"
+
+export class HttpError extends Error {
+    readonly kind: HttpErrorKind
+    // only set if kind is "rate_limited"
+    readonly retryAfterMs: number | undefined
+
+    constructor(kind: HttpErrorKind, message: string, retryAfterMs: number | undefined) {
+        super(message)
+        this.name = "HttpError"
+        this.kind = kind
+        this.retryAfterMs = retryAfterMs
+    }
+}
+
+export class HttpResponse {
+    readonly status: number
+    readonly statusText: string
+    // final url after redirects
+    readonly url: string
+    // header names are lowercase
+    readonly headers: Record<string, string>
+    // true if response was served from cache
+    readonly cached: boolean
+    readonly body: ArrayBuffer
+
+    constructor(status: number, statusText: string, url: string, headers: Record<string, string>, cached: boolean, body: ArrayBuffer) {
+        this.status = status
+        this.statusText = statusText
+        this.url = url
+        this.headers = headers
+        this.cached = cached
+        this.body = body
+    }
+
+    get ok(): boolean {
+        return this.status >= 200 && this.status < 300
+    }
+
+    text(): string {
+        return new TextDecoder().decode(this.body)
+    }
+
+    json<T = any>(): T {
+        return JSON.parse(this.text())
+    }
+}
+
+export const Http: Http = {
+    fetch: async function (url: string, options?: HttpRequestOptions): Promise<HttpResponse> {
+        const body = options?.body;
+
+        const result = await http_fetch({
+            url,
+            method: options?.method,
+            headers: options?.headers,
+            body: typeof body === "string" ? new TextEncoder().encode(body) : body instanceof ArrayBuffer ? new Uint8Array(body) : body,
+            timeout_ms: options?.timeoutMs,
+            cache: options?.cache,
+        });
+
+        if (result.type === "error") {
+            throw new HttpError(result.kind, result.message, result.retry_after_ms)
+        }
+
+        const headers = Object.fromEntries(result.headers.map(([name, value]) => [name.toLowerCase(), value]));
+        const responseBody = result.body.buffer.slice(result.body.byteOffset, result.body.byteOffset + result.body.byteLength);
+
+        return new HttpResponse(result.status, result.status_text, result.url, headers, result.cached, responseBody)
+    },
+}
+
+export interface Http {
+    // requires host to be listed in "network" permission in plugin manifest, including hosts of redirects
+    // non-2xx status is not an error, other failures are thrown as HttpError
+    fetch(url: string, options?: HttpRequestOptions): Promise<HttpResponse>;
+}
+
+export interface RealtimeHandlers<MESSAGE> {
+    // called after every successful connection, including reconnections
+    onOpen?: () => void
+    onMessage?: (message: MESSAGE) => void
+    // connection is re-established automatically with increasing delay
+    onReconnecting?: (info: { delayMs: number, reason: string }) => void
+}
+
+export interface EventSourceMessage {
+    // "message" if server didn't specify event type
+    event: string
+    data: string
+    id: string | undefined
+}
+
+export interface RealtimeOptions {
+    headers?: Record<string, string>
+}
+
+export interface RealtimeConnection {
+    close(): void
+}
+
+export interface WebSocketConnection extends RealtimeConnection {
+    // messages sent while reconnecting are delivered after connection is re-established
+    send(data: string): void
+}
+
+export const Realtime: Realtime = {
+    webSocket: function (url: string, handlers: RealtimeHandlers<string | ArrayBuffer>, options?: RealtimeOptions): WebSocketConnection {
+        const id = realtime_websocket_connect(url, options?.headers ?? {});
+
+        listenRealtimeEvents(id, handlers, event => {
+            if (event.type === "binary_message") {
+                return event.data.buffer.slice(event.data.byteOffset, event.data.byteOffset + event.data.byteLength)
+            } else {
+                return event.data
+            }
+        })
+
+        return {
+            send: (data: string) => realtime_send(id, data),
+            close: () => realtime_close(id),
+        }
+    },
+    eventSource: function (url: string, handlers: RealtimeHandlers<EventSourceMessage>, options?: RealtimeOptions): RealtimeConnection {
+        const id = realtime_event_source_connect(url, options?.headers ?? {});
+
+        listenRealtimeEvents(id, handlers, event => {
+            // event source doesn't produce binary messages
+            const message = event as Extract<RealtimeEvent, { type: "message" }>;
+
+            return { event: message.event ?? "message", data: message.data, id: message.id }
+        })
+
+        return {
+            close: () => realtime_close(id),
+        }
+    },
+}
+
+function listenRealtimeEvents<MESSAGE>(
+    id: number,
+    handlers: RealtimeHandlers<MESSAGE>,
+    toMessage: (event: Extract<RealtimeEvent, { type: "message" | "binary_message" }>) => MESSAGE
+) {
+    // noinspection ES6MissingAwait
+    (async () => {
+        while (true) {
+            let event: RealtimeEvent | undefined;
+            try {
+                event = await realtime_next_event(id);
+            } catch (e) {
+                // connection was closed before the first event
+                break
+            }
+
+            if (!event) {
+                break
+            }
+
+            try {
+                switch (event.type) {
+                    case "open": {
+                        handlers.onOpen?.()
+                        break;
+                    }
+                    case "message":
+                    case "binary_message": {
+                        handlers.onMessage?.(toMessage(event))
+                        break;
+                    }
+                    case "reconnecting": {
+                        handlers.onReconnecting?.({ delayMs: event.delay_ms, reason: event.reason })
+                        break;
+                    }
+                }
+            } catch (e) {
+                console.error("Error in realtime connection handler", e)
+            }
+        }
+    })()
+}
+
+export interface Realtime {
+    // requires host to be listed in "network" permission in plugin manifest, redirects are not followed
+    // connections are closed when plugin is disabled or reloaded
+    webSocket(url: string, handlers: RealtimeHandlers<string | ArrayBuffer>, options?: RealtimeOptions): WebSocketConnection;
+    eventSource(url: string, handlers: RealtimeHandlers<EventSourceMessage>, options?: RealtimeOptions): RealtimeConnection;
+}
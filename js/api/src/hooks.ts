@@ -1,9 +1,11 @@
 import { ReactNode, useRef, useId, useState, useCallback, useEffect, MutableRefObject, Dispatch, SetStateAction } from 'react';
 // @ts-ignore TODO how to add declaration for this?
-import { useGauntletContext } from "ext:gauntlet/renderer.js";
+import { addSessionValueListener, getSessionValue, setSessionValue, useGauntletContext, useNavDepth } from "ext:gauntlet/renderer.js";
+import { view_state_read, view_state_write } from "ext:core/ops";
 
-export function useNavigation(): { popView: () => void, pushView: (component: ReactNode) => void } {
+export function useNavigation(): { popView: () => void, pushView: (component: ReactNode) => void, stackDepth: number } {
     const { popView, pushView }: { popView: () => void, pushView: (component: ReactNode) => void } = useGauntletContext();
+    const stackDepth: number = useNavDepth();
 
     return {
         popView: () => {
@@ -11,10 +13,47 @@ export function useNavigation(): { popView: () => void, pushView: (component: Re
         },
         pushView: (component: ReactNode) => {
             pushView(component)
-        }
+        },
+        // position of the calling view in navigation stack, 1 for the view opened from search
+        stackDepth,
     }
 }
 
+export type ViewLifecycleHandlers = {
+    // window was shown again with the view still open
+    onVisible?: () => void;
+    // window was hidden but view is kept open, e.g. to pause polling
+    onHidden?: () => void;
+    // view was closed, called before the view is unmounted
+    onDestroy?: () => void;
+};
+
+export function useViewLifecycle(handlers: ViewLifecycleHandlers): void {
+    const { addViewLifecycleListener }: { addViewLifecycleListener: (listener: (event: "visible" | "hidden" | "destroy") => void) => () => void } = useGauntletContext();
+
+    const handlersRef = useRef(handlers);
+    handlersRef.current = handlers;
+
+    useEffect(() => {
+        return addViewLifecycleListener((event: "visible" | "hidden" | "destroy") => {
+            switch (event) {
+                case "visible": {
+                    handlersRef.current.onVisible?.()
+                    break;
+                }
+                case "hidden": {
+                    handlersRef.current.onHidden?.()
+                    break;
+                }
+                case "destroy": {
+                    handlersRef.current.onDestroy?.()
+                    break;
+                }
+            }
+        })
+    }, []);
+}
+
 export type AsyncState<T> = {
     isLoading: boolean;
     error?: unknown;
@@ -256,6 +295,93 @@ function usePromiseInternal<Return, Args extends unknown[], R = unknown>(
     };
 }
 
+// for slow data sources, items are shown as soon as each batch arrives instead of waiting for all of them
+export function useStream<Item, Args extends unknown[]>(
+    fn: (...args: Args) => AsyncIterable<Item[]>,
+    args?: Args,
+    options?: {
+        execute?: boolean;
+        onError?: (error: unknown) => void;
+        onData?: (data: Item[]) => void; // called once stream is finished, with all items
+    },
+): AsyncState<Item[]> & {
+    revalidate: () => void; // will execute even if options.execute is false
+} {
+    const execute = options?.execute !== false; // execute by default
+
+    const [state, setState] = useState<AsyncState<Item[]>>({ isLoading: execute });
+
+    const iteratorRef = useRef<AsyncIterator<Item[]>>();
+
+    useEffect(() => {
+        return () => {
+            iteratorRef.current?.return?.();
+            iteratorRef.current = undefined;
+        };
+    }, []);
+
+    const callback = useCallback(async (...args: Args): Promise<void> => {
+        // previous stream is not needed anymore, e.g. search text changed
+        iteratorRef.current?.return?.();
+
+        const iterator = fn(...args)[Symbol.asyncIterator]();
+
+        iteratorRef.current = iterator;
+
+        // previous items are kept until first batch of new stream arrives, so that list doesn't flicker
+        setState(prevState => ({ ...prevState, isLoading: true }));
+
+        const items: Item[] = [];
+
+        try {
+            while (true) {
+                const result = await iterator.next();
+
+                // We dont want to handle result/error of non-latest stream
+                if (iterator !== iteratorRef.current) {
+                    return
+                }
+
+                if (result.done) {
+                    break
+                }
+
+                items.push(...result.value);
+
+                setState({ data: [...items], isLoading: true });
+            }
+        } catch (error) {
+            if (iterator === iteratorRef.current) {
+                iteratorRef.current = undefined;
+
+                setState({ data: [...items], error, isLoading: false });
+
+                options?.onError?.(error);
+            }
+            return
+        }
+
+        iteratorRef.current = undefined;
+
+        setState({ data: items, isLoading: false });
+
+        options?.onData?.(items);
+    }, args || []);
+
+    useEffect(() => {
+        if (execute) {
+            callback(...(args || [] as any));
+        }
+    }, [callback, execute]);
+
+    return {
+        revalidate: () => {
+            callback(...(args || [] as any));
+        },
+        ...state
+    };
+}
+
 // persistent, uses localStorage under the hood
 export function useStorage<T>(key: string, initialState: T | (() => T)): [T, Dispatch<SetStateAction<T>>] {
     return useWebStorage(key, initialState, localStorage)
@@ -266,6 +392,85 @@ export function useCache<T>(key: string, initialState: T | (() => T)): [T, Dispa
     return useWebStorage(key, initialState, sessionStorage)
 }
 
+// shared between all entrypoints of the plugin, views using the same key are re-rendered when any of them changes it.
+// initial state is used and stored only if there is no value yet, value is cleared when plugin is stopped or reloaded
+export function useSessionState<T>(key: string, initialState: T | (() => T)): [T, Dispatch<SetStateAction<T>>] {
+    const [value, setValue] = useState<T>(() => {
+        const stored = getSessionValue(key);
+        if (stored !== undefined) {
+            return stored as T
+        }
+
+        return initialState instanceof Function ? initialState() : initialState
+    });
+
+    useEffect(() => {
+        // value could have been changed between first render and subscription
+        const stored = getSessionValue(key);
+        if (stored !== undefined) {
+            setValue(stored as T)
+        } else {
+            // stored outside of render, because it notifies views of other entrypoints
+            setSessionValue(key, value)
+        }
+
+        return addSessionValueListener(key, (value: unknown) => setValue(value as T))
+    }, [key]);
+
+    const setSessionState = useCallback((action: SetStateAction<T>) => {
+        const previous = getSessionValue(key) as T;
+        const next = action instanceof Function ? action(previous) : action;
+
+        // listener of this view updates its own state as well
+        setSessionValue(key, next)
+    }, [key]);
+
+    return [value, setSessionState]
+}
+
+// persisted by Gauntlet per entrypoint, e.g. to restore last selected tab or filter when entrypoint is opened again.
+// initial state is used until stored value is loaded, value can be up to 16 KiB when serialized with JSON.stringify
+export function useViewState<T>(key: string, initialState: T | (() => T)): [T, Dispatch<SetStateAction<T>>] {
+    const { entrypointId }: { entrypointId: () => string } = useGauntletContext();
+
+    const [value, setValue] = useState<T>(initialState);
+
+    // stored value should not override what user has already changed
+    const changed = useRef(false);
+
+    useEffect(() => {
+        let cancelled = false;
+
+        view_state_read(entrypointId(), key)
+            .then(stored => {
+                if (!cancelled && !changed.current && stored !== undefined) {
+                    setValue(JSON.parse(stored) as T)
+                }
+            })
+            .catch(error => console.error("Unable to read view state", error));
+
+        return () => {
+            cancelled = true
+        }
+    }, [key]);
+
+    useEffect(() => {
+        if (!changed.current) {
+            return
+        }
+
+        view_state_write(entrypointId(), key, value === undefined ? undefined : JSON.stringify(value))
+            .catch(error => console.error("Unable to write view state", error));
+    }, [key, value]);
+
+    const setViewState = useCallback((action: SetStateAction<T>) => {
+        changed.current = true;
+        setValue(action)
+    }, []);
+
+    return [value, setViewState]
+}
+
 // keys are shared per plugin, across all entrypoints
 // uses JSON.serialize
 function useWebStorage<T>(
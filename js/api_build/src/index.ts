@@ -588,7 +588,7 @@ function makeChildrenType(type: Children, additionalComponentRefs: ComponentRef[
             )
         }
         case "string_or_members": {
-            const members = { ...type.ordered_members, ...type.ordered_members }
+            const members = { ...type.ordered_members, ...type.per_type_members }
 
             return ts.factory.createTypeReferenceNode(
                 ts.factory.createIdentifier("StringOrElementComponent"),
@@ -762,9 +762,142 @@ function collectAllComponentRefs(propertyType: PropertyType): ComponentRef[] {
     }
 }
 
+function generateApi(apiModelPath: string, typingsOutFile: string, publicOutFile: string) {
+    const content = readFileSync(apiModelPath).toString();
+    const model = JSON.parse(content) as ApiModel
+
+    const resultFile = ts.createSourceFile("unused", "", ts.ScriptTarget.Latest, false, ts.ScriptKind.TS);
+    const printer = ts.createPrinter({ newLine: ts.NewLineKind.LineFeed });
+
+    // ambient declarations used by js runtime itself
+    const typings = printer.printNode(ts.EmitHint.Unspecified, makeApiTypings(model, false), resultFile);
+    writeFileSync(typingsOutFile, typings)
+
+    // same types but exported so that plugins can use them
+    const publicTypes = printer.printNode(ts.EmitHint.Unspecified, makeApiTypings(model, true), resultFile);
+    writeFileSync(publicOutFile, publicTypes)
+}
+
+function makeApiTypings(model: ApiModel, exported: boolean): ts.SourceFile {
+    const modifiers = exported ? [ts.factory.createToken(ts.SyntaxKind.ExportKeyword)] : undefined;
+
+    const types: ts.Statement[] = Object.entries(model.types)
+        .map(([name, type]) => {
+            return ts.factory.createTypeAliasDeclaration(
+                modifiers,
+                ts.factory.createIdentifier(name),
+                undefined,
+                makeApiType(type)
+            )
+        });
+
+    const statements = [...types];
+
+    if (!exported) {
+        const ops = model.ops.map(op => {
+            const params = op.arguments.map(arg => makeApiParameter(arg));
+
+            const result = op.async
+                ? ts.factory.createTypeReferenceNode(ts.factory.createIdentifier("Promise"), [makeApiType(op.result)])
+                : makeApiType(op.result);
+
+            return ts.factory.createFunctionDeclaration(
+                undefined,
+                undefined,
+                ts.factory.createIdentifier(op.name),
+                undefined,
+                params,
+                result,
+                undefined
+            )
+        });
+
+        statements.push(
+            ts.factory.createModuleDeclaration(
+                [ts.factory.createToken(ts.SyntaxKind.DeclareKeyword)],
+                ts.factory.createStringLiteral("ext:core/ops"),
+                ts.factory.createModuleBlock(ops)
+            )
+        )
+    }
+
+    return ts.factory.createSourceFile(
+        statements,
+        ts.factory.createToken(ts.SyntaxKind.EndOfFileToken),
+        ts.NodeFlags.None
+    )
+}
+
+function makeApiParameter(field: ApiField): ts.ParameterDeclaration {
+    return ts.factory.createParameterDeclaration(
+        undefined,
+        undefined,
+        ts.factory.createIdentifier(field.name),
+        field.optional ? ts.factory.createToken(ts.SyntaxKind.QuestionToken) : undefined,
+        makeApiType(field.type),
+        undefined
+    )
+}
+
+function makeApiType(type: ApiType): ts.TypeNode {
+    switch (type.type) {
+        case "string": {
+            return ts.factory.createKeywordTypeNode(ts.SyntaxKind.StringKeyword)
+        }
+        case "number": {
+            return ts.factory.createKeywordTypeNode(ts.SyntaxKind.NumberKeyword)
+        }
+        case "boolean": {
+            return ts.factory.createKeywordTypeNode(ts.SyntaxKind.BooleanKeyword)
+        }
+        case "void": {
+            return ts.factory.createKeywordTypeNode(ts.SyntaxKind.VoidKeyword)
+        }
+        case "undefined": {
+            return ts.factory.createKeywordTypeNode(ts.SyntaxKind.UndefinedKeyword)
+        }
+        case "array_buffer": {
+            return ts.factory.createTypeReferenceNode(ts.factory.createIdentifier("ArrayBuffer"), undefined)
+        }
+        case "literal": {
+            return ts.factory.createLiteralTypeNode(ts.factory.createStringLiteral(type.value))
+        }
+        case "array": {
+            return ts.factory.createArrayTypeNode(makeApiType(type.item))
+        }
+        case "object": {
+            return ts.factory.createTypeLiteralNode(
+                type.fields.map(field => {
+                    return ts.factory.createPropertySignature(
+                        undefined,
+                        ts.factory.createIdentifier(field.name),
+                        field.optional ? ts.factory.createToken(ts.SyntaxKind.QuestionToken) : undefined,
+                        makeApiType(field.type)
+                    )
+                })
+            )
+        }
+        case "union": {
+            return ts.factory.createUnionTypeNode(type.items.map(value => makeApiType(value)))
+        }
+        case "type_ref": {
+            return ts.factory.createTypeReferenceNode(ts.factory.createIdentifier(type.name), undefined)
+        }
+        default: {
+            throw new Error(`unsupported type ${JSON.stringify(type)}`)
+        }
+    }
+}
+
 const genDir = "../api/src/gen";
 if (!existsSync(genDir)) {
     mkdirSync(genDir);
 }
 
-generate("./component_model.json", `${genDir}/components.tsx`)
\ No newline at end of file
+const typingsGenDir = "../typings/gen";
+if (!existsSync(typingsGenDir)) {
+    mkdirSync(typingsGenDir);
+}
+
+generate("./component_model.json", `${genDir}/components.tsx`)
+generateApi("./api_model.json", `${typingsGenDir}/api.d.ts`, `${genDir}/api.ts`)
\ No newline at end of file
@@ -2,6 +2,8 @@ import {
     fetch_action_id_for_shortcut,
     get_command_generator_entrypoint_ids,
     op_log_info,
+    show_search_result_preview,
+    submit_search_results,
     update_loading_bar
 } from "ext:core/ops";
 import { reloadSearchIndex } from "./search-index";
@@ -11,6 +13,18 @@ interface GeneratedCommand { // TODO is it possible to import api here
     icon?: ArrayBuffer
     fn: () => void
     actions?: GeneratedCommandAction[]
+    accessory?: string
+    preview?: () => GeneratedCommandPreview | Promise<GeneratedCommandPreview>
+    drag?: { file: string } | { url: string }
+    ranking?: { boost?: number, keywords?: string[], timestamp?: number }
+    dedupeKey?: string
+    category?: "application" | "command" | "quicklink" | "file"
+}
+
+interface GeneratedCommandPreview {
+    text?: string
+    image?: ArrayBuffer
+    metadata?: { label: string, value: string }[]
 }
 
 interface GeneratedCommandAction {
@@ -26,6 +40,8 @@ type GeneratorProps = {
 
 type Generator = (props: GeneratorProps) => void | (() => (void | Promise<void>)) | Promise<void | (() => (void | Promise<void>))>
 
+type GeneratorSearch = (query: string, context: { keywordMode: boolean }) => GeneratedCommand[] | Promise<GeneratedCommand[]>
+
 type ProcessedGeneratedCommand = { generatorEntrypointId: string, uuid: string, command: GeneratedCommand };
 
 type ProcessedGeneratedCommands = { [lookupEntrypointId: string]: ProcessedGeneratedCommand };
@@ -34,6 +50,10 @@ type GeneratorCleanups = { [generatorEntrypointId: string]: () => (void | Promis
 let storedGeneratedCommands: ProcessedGeneratedCommands = {}
 let generatorCleanups: GeneratorCleanups = {}
 
+// commands returned by search functions of generators for the latest search query
+let storedSearchCommands: ProcessedGeneratedCommands = {}
+let searchGeneration = 0
+
 export async function runCommandGenerators(): Promise<void> {
     for (let [generatorEntrypointId, cleanup] of Object.entries(generatorCleanups)) {
         try {
@@ -96,8 +116,64 @@ export async function runCommandGenerators(): Promise<void> {
     }
 }
 
+export async function runCommandGeneratorSearch(text: string, generation: number, keywordMode: boolean): Promise<void> {
+    // searches are handled concurrently by server, so events can arrive out of order
+    if (generation < searchGeneration) {
+        return
+    }
+
+    searchGeneration = generation
+    storedSearchCommands = {}
+
+    const entrypointIds = await get_command_generator_entrypoint_ids();
+
+    // every generator responds separately, so that slow one doesn't delay the rest
+    for (const generatorEntrypointId of entrypointIds) {
+        // noinspection ES6MissingAwait
+        (async () => {
+            try {
+                const search: GeneratorSearch | undefined = (await import(`gauntlet:entrypoint?${generatorEntrypointId}`)).search;
+
+                if (typeof search !== "function") {
+                    return
+                }
+
+                const commands = await search(text, { keywordMode })
+
+                if (generation !== searchGeneration) {
+                    return
+                }
+
+                const processed: ProcessedGeneratedCommands = {}
+
+                commands.forEach((command, index) => {
+                    processed[generatorEntrypointId + ":search:" + generation + ":" + index] = {
+                        generatorEntrypointId: generatorEntrypointId,
+                        uuid: crypto.randomUUID(),
+                        command
+                    }
+                })
+
+                Object.assign(storedSearchCommands, processed)
+
+                await submit_search_results(generation, toSearchItems(processed))
+            } catch (e) {
+                console.error(`Error occurred when calling search function of command generator for entrypoint: ${generatorEntrypointId}`, e)
+            }
+        })()
+    }
+}
+
+function findGeneratedCommand(entrypointId: string): ProcessedGeneratedCommand | undefined {
+    return storedGeneratedCommands[entrypointId] ?? storedSearchCommands[entrypointId]
+}
+
 export function generatedCommandSearchIndex(): AdditionalSearchItem[] {
-    return Object.entries(storedGeneratedCommands).map(([entrypointLookupId, value]) => ({
+    return toSearchItems(storedGeneratedCommands)
+}
+
+function toSearchItems(commands: ProcessedGeneratedCommands): AdditionalSearchItem[] {
+    return Object.entries(commands).map(([entrypointLookupId, value]) => ({
         generator_entrypoint_id: value.generatorEntrypointId,
         entrypoint_id: entrypointLookupId,
         entrypoint_uuid: value.uuid,
@@ -108,11 +184,44 @@ export function generatedCommandSearchIndex(): AdditionalSearchItem[] {
                 id: action.ref,
                 label: action.label
             })),
+        entrypoint_accessory: value.command.accessory,
+        entrypoint_preview: typeof value.command.preview === "function",
+        entrypoint_drag: value.command.drag,
+        entrypoint_ranking: {
+            boost: value.command.ranking?.boost,
+            keywords: value.command.ranking?.keywords ?? [],
+            timestamp: value.command.ranking?.timestamp,
+        },
+        entrypoint_dedupe_key: value.command.dedupeKey,
+        entrypoint_category: value.command.category,
     }))
 }
 
+export async function showGeneratedCommandPreview(entrypointId: string) {
+    const generatedCommand = findGeneratedCommand(entrypointId);
+
+    let preview: SearchResultPreview | undefined = undefined;
+
+    try {
+        const data = await generatedCommand?.command.preview?.();
+
+        if (data) {
+            preview = {
+                text: data.text,
+                image: data.image,
+                metadata: data.metadata || [],
+            }
+        }
+    } catch (e) {
+        console.error("Error occurred when getting preview of generated command", entrypointId, e)
+    }
+
+    // always respond so that frontend doesn't wait for preview forever
+    await show_search_result_preview(entrypointId, preview)
+}
+
 export async function runGeneratedCommandAction(entrypointId: string, key: string, modifierShift: boolean, modifierControl: boolean, modifierAlt: boolean, modifierMeta: boolean) {
-    const command = storedGeneratedCommands[entrypointId];
+    const command = findGeneratedCommand(entrypointId);
 
     if (command) {
         const id = await fetch_action_id_for_shortcut(command.generatorEntrypointId, key, modifierShift, modifierControl, modifierAlt, modifierMeta);
@@ -126,7 +235,7 @@ export async function runGeneratedCommandAction(entrypointId: string, key: strin
 }
 
 export function runGeneratedCommand(entrypointId: string, action_index: number | undefined) {
-    const generatedCommand = storedGeneratedCommands[entrypointId];
+    const generatedCommand = findGeneratedCommand(entrypointId);
 
     if (generatedCommand) {
         if (typeof action_index == "number") {
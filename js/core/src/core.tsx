@@ -1,7 +1,7 @@
 import type { FC } from "react";
-import { runCommandGenerators, runGeneratedCommand, runGeneratedCommandAction } from "./command-generator";
+import { runCommandGenerators, runCommandGeneratorSearch, runGeneratedCommand, runGeneratedCommandAction, showGeneratedCommandPreview } from "./command-generator";
 import { reloadSearchIndex } from "./search-index";
-import { clearRenderer, render } from "ext:gauntlet/renderer.js";
+import { clearRenderer, findRootWidget, notifyConnectivityChange, notifyIdleChange, notifyInterPluginEvent, notifyPreferenceChange, notifyViewLifecycle, popRendererView, render, runUndoAction } from "ext:gauntlet/renderer.js";
 import {
     clear_inline_view,
     entrypoint_preferences_required,
@@ -12,10 +12,12 @@ import {
     plugin_preferences_required,
     show_plugin_error_view,
     show_preferences_required_view,
+    submit_argument_suggestions,
     op_plugin_get_pending_event
 } from "ext:core/ops";
 
-let latestRootUiWidget: UiWidget | undefined = undefined
+// generation of the latest search for which inline view was requested
+let inlineViewGeneration = 0
 
 function findWidgetWithId(widget: UiWidget, widgetId: number): UiWidget | undefined {
     if (widget.widgetId === widgetId) {
@@ -53,38 +55,47 @@ function findAllActionHandlers(widget: UiWidget): { id: string, onAction: () =>
     return result;
 }
 
+function convertEventArgument(arg: PropertyValue): any {
+    switch (arg.type) {
+        case "Undefined": {
+            return undefined
+        }
+        case "String": {
+            return arg.value
+        }
+        case "Number": {
+            return arg.value
+        }
+        case "Bool": {
+            return arg.value
+        }
+        case "Array": {
+            return arg.value.map(item => convertEventArgument(item))
+        }
+    }
+}
+
 function handleEvent(event: ViewEvent) {
     op_log_trace("plugin_event_handler", `Handling view event: ${Deno.inspect(event)}`);
-    op_log_trace("plugin_event_handler", `Root widget: ${Deno.inspect(latestRootUiWidget)}`);
-    if (latestRootUiWidget) {
-        const widgetWithId = findWidgetWithId(latestRootUiWidget, event.widgetId);
+    const rootUiWidget = findRootWidget(event.renderLocation, event.entrypointId);
+    op_log_trace("plugin_event_handler", `Root widget: ${Deno.inspect(rootUiWidget)}`);
+    if (rootUiWidget) {
+        const widgetWithId = findWidgetWithId(rootUiWidget, event.widgetId);
         op_log_trace("plugin_event_handler", `Found widget with id ${event.widgetId}: ${Deno.inspect(widgetWithId)}`)
 
         if (widgetWithId) {
-            const property = widgetWithId.widgetProperties[event.eventName];
+            // actions without batch handler are run the same way as when nothing is selected
+            const batchFallback = event.eventName === "onBatchAction" && !widgetWithId.widgetProperties[event.eventName];
 
-            op_log_trace("plugin_event_handler", `Found event handler with name ${event.eventName}: ${Deno.inspect(property)}`)
+            const eventName = batchFallback ? "onAction" : event.eventName;
+            const property = widgetWithId.widgetProperties[eventName];
+
+            op_log_trace("plugin_event_handler", `Found event handler with name ${eventName}: ${Deno.inspect(property)}`)
 
             if (property) {
                 if (typeof property === "function") {
 
-                    const eventArgs = event.eventArguments
-                        .map(arg => {
-                            switch (arg.type) {
-                                case "Undefined": {
-                                    return undefined
-                                }
-                                case "String": {
-                                    return arg.value
-                                }
-                                case "Number": {
-                                    return arg.value
-                                }
-                                case "Bool": {
-                                    return arg.value
-                                }
-                            }
-                        });
+                    const eventArgs = batchFallback ? [] : event.eventArguments.map(arg => convertEventArgument(arg));
 
                     op_log_trace("plugin_event_handler", `Calling handler with arguments ${Deno.inspect(eventArgs)}`)
 
@@ -105,8 +116,9 @@ async function handleKeyboardEvent(event: NotReactsKeyboardEvent) {
             break;
         }
         case "PluginView": {
-            if (latestRootUiWidget) {
-                const actionHandlers = findAllActionHandlers(latestRootUiWidget);
+            const rootUiWidget = findRootWidget("View", event.entrypointId);
+            if (rootUiWidget) {
+                const actionHandlers = findAllActionHandlers(rootUiWidget);
 
                 const id = await fetch_action_id_for_shortcut(event.entrypointId, event.key, event.modifierShift, event.modifierControl, event.modifierAlt, event.modifierMeta);
 
@@ -123,6 +135,54 @@ async function handleKeyboardEvent(event: NotReactsKeyboardEvent) {
     }
 }
 
+async function openInlineView(text: string, generation: number) {
+    const endpointId = op_inline_view_endpoint_id();
+
+    if (endpointId) {
+        if (await checkRequiredPreferences(endpointId)) {
+            return;
+        }
+
+        try {
+            const Handler: FC<{ text: string }> = (await import(`gauntlet:entrypoint?${endpointId}`)).default;
+
+            // user has typed more while entrypoint was loading
+            if (generation !== inlineViewGeneration) {
+                op_log_debug("plugin_loop", `Inline view for older search was requested, skipping...`)
+                return;
+            }
+
+            const rootUiWidget = render(endpointId, "InlineView", <Handler text={text}/>);
+
+            if (rootUiWidget.widgetChildren.length === 0) {
+                op_log_debug("plugin_loop", `Inline view rendered no children, clearing inline view...`)
+                clear_inline_view()
+            }
+        } catch (e) {
+            console.error("Error occurred when rendering inline view", e)
+        }
+    }
+}
+
+// command entrypoints can export "argumentSuggestions" function to provide suggestions for values of their arguments
+async function requestArgumentSuggestions(entrypointId: string, argumentId: string, values: Record<string, string>, generation: number) {
+    try {
+        const entrypoint = await import(`gauntlet:entrypoint?${entrypointId}`);
+
+        const argumentSuggestions: ((argumentId: string, values: Record<string, string>) => Promise<string[]> | string[]) | undefined = entrypoint.argumentSuggestions;
+
+        if (typeof argumentSuggestions !== "function") {
+            return;
+        }
+
+        const suggestions = await argumentSuggestions(argumentId, values);
+
+        await submit_argument_suggestions(generation, entrypointId, argumentId, suggestions.map(value => String(value)))
+    } catch (e) {
+        console.error("Error occurred when requesting argument suggestions", entrypointId, argumentId, e)
+    }
+}
+
 async function checkRequiredPreferences(entrypointId: string): Promise<boolean> {
     const pluginPreferencesRequired = plugin_preferences_required();
     const entrypointPreferencesRequired = entrypoint_preferences_required(entrypointId);
@@ -175,7 +235,7 @@ export async function runPluginLoop() {
                     }
 
                     const View: FC = (await import(`gauntlet:entrypoint?${pluginEvent.entrypointId}`)).default;
-                    latestRootUiWidget = render(pluginEvent.entrypointId, "View", <View/>);
+                    render(pluginEvent.entrypointId, "View", <View/>);
                 } catch (e) {
                     console.error("Error occurred when rendering view", pluginEvent.entrypointId, e)
                     show_plugin_error_view(pluginEvent.entrypointId, "View")
@@ -183,7 +243,15 @@ export async function runPluginLoop() {
                 break;
             }
             case "CloseView": {
-                clearRenderer()
+                clearRenderer(pluginEvent.entrypointId)
+                break;
+            }
+            case "PopView": {
+                popRendererView(pluginEvent.entrypointId)
+                break;
+            }
+            case "ViewVisibilityChanged": {
+                notifyViewLifecycle(pluginEvent.entrypointId, pluginEvent.visible ? "visible" : "hidden")
                 break;
             }
             case "RunCommand": {
@@ -192,8 +260,8 @@ export async function runPluginLoop() {
                         break;
                     }
 
-                    const command: () => Promise<void> | void = (await import(`gauntlet:entrypoint?${pluginEvent.entrypointId}`)).default;
-                    command()
+                    const command: (props: { arguments: Record<string, string | number> }) => Promise<void> | void = (await import(`gauntlet:entrypoint?${pluginEvent.entrypointId}`)).default;
+                    command({ arguments: pluginEvent.arguments })
                 } catch (e) {
                     console.error("Error occurred when running a command", pluginEvent.entrypointId, e)
                 }
@@ -207,27 +275,31 @@ export async function runPluginLoop() {
                 }
                 break;
             }
+            case "RequestGeneratedCommandPreview": {
+                // noinspection ES6MissingAwait
+                showGeneratedCommandPreview(pluginEvent.entrypointId)
+                break;
+            }
+            case "RequestArgumentSuggestions": {
+                // noinspection ES6MissingAwait
+                requestArgumentSuggestions(pluginEvent.entrypointId, pluginEvent.argumentId, pluginEvent.values, pluginEvent.generation)
+                break;
+            }
             case "OpenInlineView": {
-                const endpointId = op_inline_view_endpoint_id();
-
-                if (endpointId) {
-                    if (await checkRequiredPreferences(endpointId)) {
-                        break;
-                    }
-
-                    try {
-                        const Handler: FC<{ text: string }> = (await import(`gauntlet:entrypoint?${endpointId}`)).default;
+                // searches are handled concurrently by server, so events can arrive out of order
+                if (pluginEvent.generation < inlineViewGeneration) {
+                    break;
+                }
 
-                        latestRootUiWidget = render(endpointId, "InlineView", <Handler text={pluginEvent.text}/>);
+                inlineViewGeneration = pluginEvent.generation
 
-                        if (latestRootUiWidget.widgetChildren.length === 0) {
-                            op_log_debug("plugin_loop", `Inline view rendered no children, clearing inline view...`)
-                            clear_inline_view()
-                        }
-                    } catch (e) {
-                        console.error("Error occurred when rendering inline view", e)
-                    }
-                }
+                // noinspection ES6MissingAwait
+                openInlineView(pluginEvent.text, pluginEvent.generation)
+                break;
+            }
+            case "SearchQuery": {
+                // noinspection ES6MissingAwait
+                runCommandGeneratorSearch(pluginEvent.text, pluginEvent.generation, pluginEvent.keywordMode)
                 break;
             }
             case "ReloadSearchIndex": {
@@ -239,6 +311,27 @@ export async function runPluginLoop() {
                 reloadSearchIndex(false)
                 break;
             }
+            case "PreferenceChanged": {
+                notifyPreferenceChange({ entrypointId: pluginEvent.entrypointId, preferenceId: pluginEvent.preferenceId })
+                break;
+            }
+            case "RunUndo": {
+                // noinspection ES6MissingAwait
+                runUndoAction(pluginEvent.undoId, pluginEvent.redo)
+                break;
+            }
+            case "IdleChanged": {
+                notifyIdleChange(pluginEvent.idle)
+                break;
+            }
+            case "ConnectivityChanged": {
+                notifyConnectivityChange(pluginEvent.online)
+                break;
+            }
+            case "InterPluginEvent": {
+                notifyInterPluginEvent(pluginEvent)
+                break;
+            }
         }
     }
 }
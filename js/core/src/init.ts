@@ -1,4 +1,21 @@
 import { runPluginLoop } from "gauntlet:core";
+import { op_log_performance_entry } from "ext:core/ops";
+
+// marks and measures of plugin are written to application logs
+const mark = performance.mark.bind(performance);
+const measure = performance.measure.bind(performance);
+
+performance.mark = (...args: Parameters<typeof mark>): PerformanceMark => {
+    const entry = mark(...args);
+    op_log_performance_entry(entry.entryType, entry.name, entry.startTime, entry.duration);
+    return entry
+};
+
+performance.measure = (...args: Parameters<typeof measure>): PerformanceMeasure => {
+    const entry = measure(...args);
+    op_log_performance_entry(entry.entryType, entry.name, entry.startTime, entry.duration);
+    return entry
+};
 
 globalThis.addEventListener("unhandledrejection", (event) => {
     event.preventDefault()
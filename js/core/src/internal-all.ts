@@ -1,5 +1,39 @@
 export {
     run_numbat,
     open_settings,
+    bookmark_sources,
+    read_bookmarks,
+    open_bookmark,
+    process_list,
+    process_terminate,
+    process_force_kill,
+    process_renice,
+    ssh_hosts,
+    open_ssh_host,
+    recent_projects,
+    open_recent_project,
+    totp_accounts,
+    totp_add_account,
+    totp_remove_account,
+    totp_generate_code,
     current_os,
+    running_executable_paths,
+    application_watcher_start,
+    application_watcher_stop,
+    application_watcher_next_actions,
+    script_commands_dir,
+    script_command_from_path,
+    run_script_command,
+    show_plugin_view,
+    dictionary_lookup_api,
+    dictionary_lookup_wordlist,
+    media_now_playing,
+    media_control,
+    system_info,
+    open_url,
+    web_search_suggestions,
+    trash_list,
+    trash_restore,
+    trash_delete,
+    trash_empty,
 } from "ext:core/ops";
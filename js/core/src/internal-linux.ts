@@ -2,4 +2,6 @@ export {
     linux_app_from_path,
     linux_application_dirs,
     linux_open_application,
+    linux_open_application_action,
+    linux_focus_application_window,
 } from "ext:core/ops";
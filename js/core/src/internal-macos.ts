@@ -4,6 +4,7 @@ export {
     macos_application_dirs,
     macos_major_version,
     macos_open_application,
+    macos_show_application_in_finder,
     macos_open_setting_13_and_post,
     macos_open_setting_pre_13,
     macos_settings_13_and_post,
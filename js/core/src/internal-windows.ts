@@ -0,0 +1,8 @@
+export {
+    windows_app_from_path,
+    windows_application_dirs,
+    windows_open_application,
+    windows_open_uwp_application,
+    windows_show_application_in_explorer,
+    windows_uwp_applications,
+} from "ext:core/ops";
@@ -2,7 +2,14 @@ declare module "ext:gauntlet/renderer.js" {
     import { ReactNode } from "react";
 
     export const render: (entrypointId: string, renderLocation: RenderLocation, component: ReactNode) => UiWidget;
-    export const clearRenderer: () => void;
+    export const findRootWidget: (renderLocation: RenderLocation, entrypointId: string) => UiWidget | undefined;
+    export const clearRenderer: (entrypointId: string) => void;
+    export const popRendererView: (entrypointId: string) => void;
+    export const notifyViewLifecycle: (entrypointId: string, event: "visible" | "hidden" | "destroy") => void;
+    export const notifyPreferenceChange: (event: { entrypointId: string | undefined, preferenceId: string }) => void;
+    export const notifyIdleChange: (idle: boolean) => void;
+    export const notifyConnectivityChange: (online: boolean) => void;
+    export const notifyInterPluginEvent: (event: { sourcePluginId: string, topic: string, payload: string }) => void;
 }
 
 declare module "gauntlet:core" {
@@ -1,5 +1,5 @@
 import ReactReconciler, { HostConfig, OpaqueHandle } from "react-reconciler";
-import { createContext, ReactNode, useContext } from 'react';
+import { cloneElement, createContext, createElement, Fragment, ReactElement, ReactNode, useContext } from 'react';
 import { DefaultEventPriority } from 'react-reconciler/constants';
 import {
     asset_data,
@@ -9,6 +9,7 @@ import {
     op_component_model,
     op_log_trace,
     op_react_replace_view,
+    register_undo,
     show_hud
 } from "ext:core/ops";
 
@@ -48,6 +49,8 @@ class GauntletContextValue {
     private _rerender: ((node: ReactNode) => void) | undefined
     private _entrypointId: string | undefined;
     private _clear: (() => void) | undefined;
+    private _rendered: boolean = false
+    private _lifecycleListeners = new Set<(event: ViewLifecycleEvent) => void>()
 
     reset(entrypointId: string, renderLocation: RenderLocation, view: ReactNode, rerender: (node: ReactNode) => void, clear: () => void) {
         this._entrypointId = entrypointId
@@ -56,6 +59,11 @@ class GauntletContextValue {
         this._clear = clear
         this._navStack = []
         this._navStack.push(view)
+        this._rendered = true
+    }
+
+    isRendered = () => {
+        return this._rendered
     }
 
     renderLocation = (): RenderLocation => {
@@ -66,6 +74,10 @@ class GauntletContextValue {
         return this._navStack.length === 1
     }
 
+    navStackDepth = () => {
+        return this._navStack.length
+    }
+
     topmostView = () => {
         return this._navStack[this._navStack.length - 1]
     }
@@ -74,24 +86,43 @@ class GauntletContextValue {
         return this._entrypointId!!
     }
 
-    rerender = (component: ReactNode) => {
-        this._rerender!!(component)
+    // every view in the stack stays mounted to keep its state while it is not visible,
+    // only children of the topmost entry are sent to the frontend
+    rerender = () => {
+        const entries = this._navStack.map((view, index) => (
+            createElement(NAV_ENTRY_TYPE, { key: index }, createElement(navDepthContext.Provider, { value: index + 1 }, view))
+        ))
+
+        this._rerender!!(createElement(gauntletContext.Provider, { value: this }, createElement(Fragment, null, entries)))
+    };
+
+    // cloned elements have new props object, so react doesn't bail out of rendering them while keeping component state
+    refresh = () => {
+        this._navStack = this._navStack.map(view => cloneElement(view as ReactElement))
+
+        this.rerender()
     };
 
     clear = () => {
+        this._rendered = false
         this._clear!!()
     };
 
     pushView = (component: ReactNode) => {
         this._navStack.push(component)
 
-        this.rerender(component)
+        this.rerender()
     };
 
     popView = () => {
+        // bottommost view is closed by frontend
+        if (this._navStack.length <= 1) {
+            return
+        }
+
         this._navStack.pop();
 
-        this.rerender(this.topmostView())
+        this.rerender()
     };
 
     entrypointPreferences = () => {
@@ -101,15 +132,48 @@ class GauntletContextValue {
     pluginPreferences = () => {
         return get_plugin_preferences()
     }
+
+    addViewLifecycleListener = (listener: (event: ViewLifecycleEvent) => void): () => void => {
+        this._lifecycleListeners.add(listener)
+
+        return () => {
+            this._lifecycleListeners.delete(listener)
+        }
+    }
+
+    notifyViewLifecycle = (event: ViewLifecycleEvent) => {
+        for (const listener of this._lifecycleListeners) {
+            try {
+                listener(event)
+            } catch (e) {
+                console.error("Error occurred in view lifecycle listener", e)
+            }
+        }
+    }
+
+    clearViewLifecycleListeners = () => {
+        this._lifecycleListeners.clear()
+    }
 }
 
-const gauntletContextValue = new GauntletContextValue()
-const gauntletContext = createContext(gauntletContextValue);
+const NAV_ENTRY_TYPE = "gauntlet:nav_entry"
+// position of the view in navigation stack, 1 for the view opened by entrypoint
+const navDepthContext = createContext(1);
+
+// every root provides its own value, plugin can have several views rendered at once
+const gauntletContext = createContext(new GauntletContextValue());
+
+// the latest rendered view, used by functions that are called outside of components
+let latestContextValue = new GauntletContextValue()
 
 export function useGauntletContext() {
     return useContext(gauntletContext);
 }
 
+export function useNavDepth(): number {
+    return useContext(navDepthContext);
+}
+
 export async function getAssetData(path: string): Promise<ArrayBuffer> {
     const vecU8 = await asset_data(path);
     return new Uint8Array(vecU8).buffer; // FIXME move array creation into rust if possible
@@ -121,17 +185,252 @@ export function getAssetDataSync(path: string): ArrayBuffer {
 }
 
 export function getPluginPreferences(): Record<string, any> {
-    return gauntletContextValue.pluginPreferences()
+    return latestContextValue.pluginPreferences()
 }
 
 export function getEntrypointPreferences(): Record<string, any> {
-    return gauntletContextValue.entrypointPreferences()
+    return latestContextValue.entrypointPreferences()
 }
 
 export function showHudWindow(display: string): void {
     show_hud(display)
 }
 
+export type PreferenceChangeEvent = { entrypointId: string | undefined, preferenceId: string }
+
+const preferenceChangeListeners = new Set<(event: PreferenceChangeEvent) => void>()
+
+export function addPreferenceChangeListener(listener: (event: PreferenceChangeEvent) => void): () => void {
+    preferenceChangeListeners.add(listener)
+
+    return () => {
+        preferenceChangeListeners.delete(listener)
+    }
+}
+
+let idle = false
+
+const idleChangeListeners = new Set<(idle: boolean) => void>()
+
+export function isPluginIdle(): boolean {
+    return idle
+}
+
+export function addIdleChangeListener(listener: (idle: boolean) => void): () => void {
+    idleChangeListeners.add(listener)
+
+    return () => {
+        idleChangeListeners.delete(listener)
+    }
+}
+
+export function notifyIdleChange(value: boolean) {
+    if (idle === value) {
+        return
+    }
+
+    idle = value
+
+    for (const listener of idleChangeListeners) {
+        try {
+            listener(value)
+        } catch (e) {
+            console.error("Error occurred in idle change listener", e)
+        }
+    }
+}
+
+let online = true
+
+const connectivityChangeListeners = new Set<(online: boolean) => void>()
+
+export function isPluginOnline(): boolean {
+    return online
+}
+
+export function addConnectivityChangeListener(listener: (online: boolean) => void): () => void {
+    connectivityChangeListeners.add(listener)
+
+    return () => {
+        connectivityChangeListeners.delete(listener)
+    }
+}
+
+export function notifyConnectivityChange(value: boolean) {
+    if (online === value) {
+        return
+    }
+
+    online = value
+
+    for (const listener of connectivityChangeListeners) {
+        try {
+            listener(value)
+        } catch (e) {
+            console.error("Error occurred in connectivity change listener", e)
+        }
+    }
+}
+
+export type InterPluginEventListener = (payload: any, sourcePluginId: string) => void
+
+const interPluginEventListeners = new Map<string, Set<InterPluginEventListener>>()
+
+export function addInterPluginEventListener(topic: string, listener: InterPluginEventListener): () => void {
+    let listeners = interPluginEventListeners.get(topic)
+    if (!listeners) {
+        listeners = new Set()
+        interPluginEventListeners.set(topic, listeners)
+    }
+
+    listeners.add(listener)
+
+    return () => {
+        listeners.delete(listener)
+    }
+}
+
+export function notifyInterPluginEvent(event: { sourcePluginId: string, topic: string, payload: string }) {
+    const listeners = interPluginEventListeners.get(event.topic)
+    if (!listeners) {
+        return
+    }
+
+    const payload = JSON.parse(event.payload)
+
+    for (const listener of listeners) {
+        try {
+            listener(payload, event.sourcePluginId)
+        } catch (e) {
+            console.error("Error occurred in event listener", e)
+        }
+    }
+}
+
+// module is evaluated once per plugin runtime, so values are shared between entrypoints of the plugin
+// and are lost when plugin is stopped or reloaded
+const sessionValues = new Map<string, unknown>()
+
+export type SessionValueListener = (value: unknown) => void
+
+const sessionValueListeners = new Map<string, Set<SessionValueListener>>()
+
+export function getSessionValue(key: string): unknown {
+    return sessionValues.get(key)
+}
+
+export function setSessionValue(key: string, value: unknown) {
+    if (Object.is(sessionValues.get(key), value)) {
+        return
+    }
+
+    if (value === undefined) {
+        sessionValues.delete(key)
+    } else {
+        sessionValues.set(key, value)
+    }
+
+    const listeners = sessionValueListeners.get(key)
+    if (!listeners) {
+        return
+    }
+
+    for (const listener of listeners) {
+        try {
+            listener(value)
+        } catch (e) {
+            console.error("Error occurred in session value listener", e)
+        }
+    }
+}
+
+export function addSessionValueListener(key: string, listener: SessionValueListener): () => void {
+    let listeners = sessionValueListeners.get(key)
+    if (!listeners) {
+        listeners = new Set()
+        sessionValueListeners.set(key, listeners)
+    }
+
+    listeners.add(listener)
+
+    return () => {
+        listeners.delete(listener)
+    }
+}
+
+export function notifyPreferenceChange(event: PreferenceChangeEvent) {
+    for (const listener of preferenceChangeListeners) {
+        try {
+            listener(event)
+        } catch (e) {
+            console.error("Error occurred in preference change listener", e)
+        }
+    }
+
+    // plugin preferences are visible to every entrypoint, entrypoint preferences only to that entrypoint
+    for (const { context } of allRoots()) {
+        if (context.isRendered() && (event.entrypointId === undefined || event.entrypointId === context.entrypointId())) {
+            context.refresh()
+        }
+    }
+}
+
+export type ViewLifecycleEvent = "visible" | "hidden" | "destroy"
+
+export function notifyViewLifecycle(entrypointId: string, event: ViewLifecycleEvent) {
+    viewRoots.get(entrypointId)?.context.notifyViewLifecycle(event)
+}
+
+// undo function can return function that redoes the change, and redo function can return function that undoes it again
+export type UndoFunction = () => void | UndoFunction | Promise<void | UndoFunction>
+
+const undoActions = new Map<number, { description: string, undo: UndoFunction | undefined, redo: UndoFunction | undefined }>()
+let nextUndoId = 0
+
+export async function registerUndoAction(description: string, undo: UndoFunction): Promise<void> {
+    const undoId = nextUndoId++
+
+    undoActions.set(undoId, { description, undo, redo: undefined })
+
+    await register_undo(undoId, description, "action")
+}
+
+export async function runUndoAction(undoId: number, redo: boolean): Promise<void> {
+    const action = undoActions.get(undoId)
+    if (!action) {
+        return
+    }
+
+    const fn = redo ? action.redo : action.undo
+    if (!fn) {
+        return
+    }
+
+    let result: void | UndoFunction
+    try {
+        result = await fn()
+    } catch (e) {
+        console.error("Error occurred when running " + (redo ? "redo" : "undo") + " function", e)
+        undoActions.delete(undoId)
+        return
+    }
+
+    if (typeof result !== "function") {
+        undoActions.delete(undoId)
+        return
+    }
+
+    if (redo) {
+        action.undo = result
+        action.redo = undefined
+    } else {
+        action.undo = undefined
+        action.redo = result
+    }
+
+    await register_undo(undoId, action.description, redo ? "redo" : "undo")
+}
+
 function createWidget(hostContext: HostContext, type: ComponentType, properties: Props, children: UiWidget[] = []): Instance {
     const props = Object.fromEntries(
         Object.entries(properties)
@@ -151,6 +450,9 @@ function createWidget(hostContext: HostContext, type: ComponentType, properties:
 
 const componentModel = op_component_model();
 
+// shown in widget inspector for dev plugins
+let renderStartTime = 0
+
 export const createHostConfig = (): HostConfig<
     ComponentType,
     PropsWithChildren,
@@ -230,6 +532,8 @@ export const createHostConfig = (): HostConfig<
         return false;
     },
     getRootHostContext: (_rootContainer: RootUiWidget): HostContext | null => {
+        // called at the start of every render
+        renderStartTime = performance.now()
 
         return new HostContext(1, componentModel);
     },
@@ -335,13 +639,30 @@ export const createHostConfig = (): HostConfig<
     replaceContainerChildren(container: RootUiWidget, newChildren: ChildSet): void {
         // op_log_info("renderer_js_persistence", `replaceContainerChildren is called, container: ${Deno.inspect(container)}, newChildren: ${Deno.inspect(newChildren, { depth: Number.MAX_VALUE })}`)
 
-        container.widgetChildren = newChildren
+        // root that was replaced by newer render, e.g. inline view for previous prompt
+        const root = allRoots().find(root => root.container === container);
+        if (!root) {
+            return
+        }
 
-        const containerComponent = { content: newChildren.map(value => convertComponents(value)) }
+        const context = root.context
+
+        const topmostEntry = newChildren[newChildren.length - 1]
+
+        container.widgetChildren = topmostEntry ? topmostEntry.widgetChildren : []
+
+        const containerComponent = { content: container.widgetChildren.map(value => convertComponents(value)) }
 
         // op_log_info("renderer_js_persistence", `Converted container: ${Deno.inspect(containerComponent, { depth: Number.MAX_VALUE })}`)
 
-        op_react_replace_view(gauntletContextValue.renderLocation(), gauntletContextValue.isBottommostView(), gauntletContextValue.entrypointId(), containerComponent)
+        const renderTime = performance.now() - renderStartTime
+
+        const renderError = op_react_replace_view(context.renderLocation(), context.navStackDepth(), context.entrypointId(), containerComponent, renderTime)
+
+        if (renderError) {
+            const path = renderError.component_path.length === 0 ? "view" : renderError.component_path.join(" > ")
+            console.error(`Rendered view is invalid: ${renderError.message} at ${path}`)
+        }
     },
 
     cloneHiddenInstance(
@@ -421,8 +742,51 @@ const createTracedHostConfig = (hostConfig: any) => new Proxy(hostConfig, {
     }
 });
 
-export function clearRenderer() {
-    gauntletContextValue.clear()
+type RendererRoot = {
+    context: GauntletContextValue,
+    container: RootUiWidget,
+    unmount: () => void
+}
+
+// plugin can have several views open at once, one per entrypoint, e.g. one in main window and one in detached window
+const viewRoots = new Map<string, RendererRoot>()
+let inlineViewRoot: RendererRoot | undefined = undefined
+
+function allRoots(): RendererRoot[] {
+    const roots = [...viewRoots.values()]
+
+    if (inlineViewRoot) {
+        roots.push(inlineViewRoot)
+    }
+
+    return roots
+}
+
+// widget ids are unique only within a root, so events are looked up in the root of the view they came from
+export function findRootWidget(renderLocation: RenderLocation, entrypointId: string): UiWidget | undefined {
+    const root = renderLocation === "InlineView" ? inlineViewRoot : viewRoots.get(entrypointId)
+
+    if (!root || root.context.entrypointId() !== entrypointId) {
+        return undefined
+    }
+
+    return root.container
+}
+
+export function clearRenderer(entrypointId: string) {
+    const root = viewRoots.get(entrypointId)
+    if (!root) {
+        return
+    }
+
+    root.context.notifyViewLifecycle("destroy")
+
+    root.context.clear()
+
+    // listeners registered outside of components are not removed on unmount
+    root.context.clearViewLifecycleListeners()
+
+    viewRoots.delete(entrypointId)
 }
 
 export function render(entrypointId: string, renderLocation: RenderLocation, view: ReactNode): UiWidget {
@@ -438,7 +802,9 @@ export function render(entrypointId: string, renderLocation: RenderLocation, vie
         widgetChildren: [],
     };
 
-    gauntletContextValue.reset(
+    const context = new GauntletContextValue()
+
+    context.reset(
         entrypointId,
         renderLocation,
         view,
@@ -473,7 +839,39 @@ export function render(entrypointId: string, renderLocation: RenderLocation, vie
         null
     );
 
-    gauntletContextValue.rerender(gauntletContextValue.topmostView())
+    const newRoot: RendererRoot = {
+        context,
+        container,
+        unmount: () => {
+            reconciler.updateContainer(
+                null,
+                root,
+                null,
+                null
+            );
+        }
+    }
+
+    // only the root of the same view is replaced, views of other entrypoints keep running
+    let previousRoot: RendererRoot | undefined
+    if (renderLocation === "InlineView") {
+        previousRoot = inlineViewRoot
+        inlineViewRoot = newRoot
+    } else {
+        previousRoot = viewRoots.get(entrypointId)
+        viewRoots.set(entrypointId, newRoot)
+    }
+
+    latestContextValue = context
+
+    // effects of previous root are cleaned up, so that pending requests are cancelled and it doesn't render anymore
+    previousRoot?.unmount()
+
+    context.rerender()
 
     return container
 }
+
+export function popRendererView(entrypointId: string) {
+    viewRoots.get(entrypointId)?.context.popView()
+}
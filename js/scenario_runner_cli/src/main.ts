@@ -1,7 +1,7 @@
 import { Command } from 'commander';
 import { spawnSync } from "node:child_process";
 import path from "node:path";
-import { existsSync, readdirSync, rmSync } from "node:fs";
+import { cpSync, existsSync, readdirSync, rmSync } from "node:fs";
 
 const program = new Command();
 
@@ -12,7 +12,19 @@ program
 program.command('run-scenarios')
     .argument('[plugin]')
     .action(async (plugin) => {
-        await runScenarios(plugin)
+        await runScenarios(plugin, false)
+    });
+
+program.command('check-scenarios')
+    .argument('[plugin]')
+    .action(async (plugin) => {
+        await runScenarios(plugin, true)
+    });
+
+program.command('accept-scenarios')
+    .argument('[plugin]')
+    .action(async (plugin) => {
+        acceptScenarios(plugin)
     });
 
 program.command('run-screenshot-gen')
@@ -28,7 +40,7 @@ async function sleep(ms: number) {
     return new Promise((r) => setTimeout(r, ms));
 }
 
-async function runScenarios(expectedPlugin: string | undefined) {
+async function runScenarios(expectedPlugin: string | undefined, check: boolean) {
     const projectRoot = path.resolve(process.cwd(), '..', '..');
 
     const scenarios = path.join(projectRoot, "scenarios");
@@ -57,6 +69,7 @@ async function runScenarios(expectedPlugin: string | undefined) {
                 GAUNTLET_SCENARIO_RUNNER_TYPE: "scenario_runner",
                 GAUNTLET_SCENARIOS_DIR: scenarios,
                 GAUNTLET_SCENARIO_PLUGIN_NAME: pluginName,
+                GAUNTLET_SCENARIO_CHECK: check ? "true" : "false",
                 XDG_DATA_HOME: path.join(scenariosRun, "data"),
                 XDG_CONFIG_HOME: path.join(scenariosRun, "config"),
                 XDG_CACHE_HOME: path.join(scenariosRun, "cache"),
@@ -76,6 +89,32 @@ async function runScenarios(expectedPlugin: string | undefined) {
     }
 }
 
+// snapshots of the last run become expected ones
+function acceptScenarios(expectedPlugin: string | undefined) {
+    const projectRoot = path.resolve(process.cwd(), '..', '..');
+    const scenarios = path.join(projectRoot, "scenarios");
+    const scenariosOut = path.join(scenarios, "out");
+    const scenariosExpected = path.join(scenarios, "expected");
+
+    for (const plugin of readdirSync(scenariosOut)) {
+        if (expectedPlugin) {
+            if (plugin != expectedPlugin) {
+                continue
+            }
+        }
+
+        const expectedPluginDir = path.join(scenariosExpected, plugin);
+
+        if (existsSync(expectedPluginDir)) {
+            rmSync(expectedPluginDir, { recursive: true })
+        }
+
+        cpSync(path.join(scenariosOut, plugin), expectedPluginDir, { recursive: true })
+
+        console.log("Accepted snapshots for plugin: " + plugin)
+    }
+}
+
 async function runScreenshotGen(expectedPlugin: string | undefined, expectedEntrypoint: string | undefined) {
     const projectRoot = path.resolve(process.cwd(), '..', '..');
     const scenarios = path.join(projectRoot, "scenarios");
@@ -0,0 +1,69 @@
+type EntrypointBadge = {
+    type: "count";
+    count: number;
+} | {
+    type: "dot";
+};
+type Sound = {
+    type: "builtin";
+    name: BuiltinSound;
+} | {
+    type: "asset";
+    path: string;
+};
+type BuiltinSound = "timer-finished" | "error";
+type UndoEntryKind = "action" | "undo" | "redo";
+type SearchResultPreview = {
+    text: string | undefined;
+    image: ArrayBuffer | undefined;
+    metadata: SearchResultPreviewMetadata[];
+};
+type SearchResultPreviewMetadata = {
+    label: string;
+    value: string;
+};
+type ActiveWindow = {
+    app_id: string;
+    app_name: string | undefined;
+    title: string | undefined;
+};
+type ClipboardData = {
+    text_data?: string;
+    png_data?: number[];
+};
+type PreferenceValueTypes = {
+    number: number;
+    string: string;
+    enum: string;
+    bool: boolean;
+    list_of_strings: string[];
+    list_of_numbers: number[];
+    list_of_enums: string[];
+    directory: string;
+};
+type PreferenceValue = number | string | boolean | string[] | number[];
+type RenderError = {
+    message: string;
+    component_path: string[];
+};
+declare module "ext:core/ops" {
+    function show_hud(display: string): void;
+    function set_entrypoint_badge(entrypoint_id: string, badge: EntrypointBadge | undefined): Promise<void>;
+    function register_undo(undo_id: number, description: string, kind: UndoEntryKind): Promise<void>;
+    function show_search_result_preview(entrypoint_id: string, preview: SearchResultPreview | undefined): Promise<void>;
+    function clipboard_read(): Promise<ClipboardData>;
+    function clipboard_read_text(): Promise<string | undefined>;
+    function clipboard_write(data: ClipboardData): Promise<void>;
+    function clipboard_write_text(data: string, clear_after_seconds: number | undefined): Promise<void>;
+    function clipboard_clear(): Promise<void>;
+    function play_sound(sound: Sound): Promise<void>;
+    function secret_read(service: string, account: string): Promise<string | undefined>;
+    function active_window(): Promise<ActiveWindow | undefined>;
+    function event_publish(topic: string, payload: string): Promise<void>;
+    function view_state_read(entrypoint_id: string, key: string): Promise<string | undefined>;
+    function view_state_write(entrypoint_id: string, key: string, value: string | undefined): Promise<void>;
+    function environment_gauntlet_version(): number;
+    function environment_is_development(): boolean;
+    function environment_plugin_data_dir(): string;
+    function environment_plugin_cache_dir(): string;
+}
@@ -1,3 +1,4 @@
+/// <reference path="./gen/api.d.ts" />
 
 // js runtime types
 
@@ -16,7 +17,30 @@ type DesktopPathActionRemove = {
 
 type LinuxDesktopApplicationData = {
     name: string
+    path: string,
     icon: ArrayBuffer | undefined,
+    actions: LinuxDesktopApplicationAction[],
+    executable: string | undefined,
+    startup_wm_class: string | undefined,
+}
+
+type LinuxDesktopApplicationAction = {
+    id: string
+    name: string,
+}
+
+type ScriptCommandData = {
+    path: string
+    title: string
+    description: string | undefined
+    mode: "silent" | "hud" | "clipboard" | "detail"
+    keyword: string | undefined
+}
+
+type ScriptCommandOutput = {
+    success: boolean
+    stdout: string
+    stderr: string
 }
 
 type MacOSDesktopApplicationData = {
@@ -25,6 +49,17 @@ type MacOSDesktopApplicationData = {
     icon: ArrayBuffer | undefined,
 }
 
+type WindowsDesktopApplicationData = {
+    name: string
+    path: string,
+    icon: ArrayBuffer | undefined,
+}
+
+type WindowsUwpApplicationData = {
+    name: string
+    app_id: string,
+}
+
 type MacOSDesktopSettingsPre13Data = {
     name: string
     path: string,
@@ -39,11 +74,13 @@ type MacOSDesktopSettings13AndPostData = {
 
 type PromiseRejectCallback = (type: number, promise: Promise<unknown>, reason: any) => void;
 
-type PluginEvent = ViewEvent | NotReactsKeyboardEvent | RunCommand | RunGeneratedCommand | OpenView | CloseView | OpenInlineView | ReloadSearchIndex | RefreshSearchIndex
+type PluginEvent = ViewEvent | NotReactsKeyboardEvent | RunCommand | RunGeneratedCommand | RequestGeneratedCommandPreview | RequestArgumentSuggestions | OpenView | CloseView | PopView | ViewVisibilityChanged | OpenInlineView | SearchQuery | ReloadSearchIndex | RefreshSearchIndex | PreferenceChanged | RunUndo | IdleChanged | ConnectivityChanged | InterPluginEvent
 type RenderLocation = "InlineView" | "View"
 
 type ViewEvent = {
     type: "ViewEvent"
+    renderLocation: RenderLocation
+    entrypointId: string
     widgetId: number
     eventName: string
     eventArguments: PropertyValue[]
@@ -70,11 +107,24 @@ type OpenView = {
 
 type CloseView = {
     type: "CloseView"
+    entrypointId: string
+}
+
+type PopView = {
+    type: "PopView"
+    entrypointId: string
+}
+
+type ViewVisibilityChanged = {
+    type: "ViewVisibilityChanged"
+    entrypointId: string
+    visible: boolean
 }
 
 type RunCommand = {
     type: "RunCommand"
     entrypointId: string
+    arguments: Record<string, string | number>
 }
 
 type RunGeneratedCommand = {
@@ -83,9 +133,30 @@ type RunGeneratedCommand = {
     actionIndex: number | undefined
 }
 
+type RequestGeneratedCommandPreview = {
+    type: "RequestGeneratedCommandPreview"
+    entrypointId: string
+}
+
+type RequestArgumentSuggestions = {
+    type: "RequestArgumentSuggestions"
+    entrypointId: string
+    argumentId: string
+    values: Record<string, string>
+    generation: number
+}
+
 type OpenInlineView = {
     type: "OpenInlineView"
     text: string
+    generation: number
+}
+
+type SearchQuery = {
+    type: "SearchQuery"
+    text: string
+    generation: number
+    keywordMode: boolean
 }
 
 type ReloadSearchIndex = {
@@ -96,10 +167,40 @@ type RefreshSearchIndex = {
     type: "RefreshSearchIndex"
 }
 
-type PropertyValue = PropertyValueString | PropertyValueNumber | PropertyValueBool | PropertyValueUndefined
+type PreferenceChanged = {
+    type: "PreferenceChanged"
+    entrypointId: string | undefined
+    preferenceId: string
+}
+
+type RunUndo = {
+    type: "RunUndo"
+    undoId: number
+    redo: boolean
+}
+
+type IdleChanged = {
+    type: "IdleChanged"
+    idle: boolean
+}
+
+type ConnectivityChanged = {
+    type: "ConnectivityChanged"
+    online: boolean
+}
+
+type InterPluginEvent = {
+    type: "InterPluginEvent"
+    sourcePluginId: string
+    topic: string
+    payload: string
+}
+
+type PropertyValue = PropertyValueString | PropertyValueNumber | PropertyValueBool | PropertyValueArray | PropertyValueUndefined
 type PropertyValueString = { type: "String", value: string }
 type PropertyValueNumber = { type: "Number", value: number }
 type PropertyValueBool = { type: "Bool", value: boolean }
+type PropertyValueArray = { type: "Array", value: PropertyValue[] }
 type PropertyValueUndefined = { type: "Undefined" }
 
 type UiWidget = {
@@ -118,6 +219,18 @@ type AdditionalSearchItem = {
     entrypoint_uuid: string,
     entrypoint_icon: ArrayBuffer | undefined,
     entrypoint_actions: AdditionalSearchItemAction[],
+    entrypoint_accessory: string | undefined,
+    entrypoint_preview: boolean,
+    entrypoint_drag: { file: string } | { url: string } | undefined,
+    entrypoint_ranking: AdditionalSearchItemRanking,
+    entrypoint_dedupe_key: string | undefined,
+    entrypoint_category: "application" | "command" | "quicklink" | "file" | undefined,
+}
+
+type AdditionalSearchItemRanking = {
+    boost: number | undefined,
+    keywords: string[],
+    timestamp: number | undefined,
 }
 
 type AdditionalSearchItemAction = {
@@ -125,14 +238,146 @@ type AdditionalSearchItemAction = {
     label: string,
 }
 
+type BookmarkBrowser = "firefox" | "chrome" | "chromium" | "brave" | "edge" | "vivaldi"
+
+type BookmarkSource = {
+    browser: BookmarkBrowser,
+    profile: string,
+    path: string,
+}
+
+type Bookmark = {
+    title: string,
+    url: string,
+}
+
+type DictionaryEntry = {
+    word: string,
+    phonetic?: string,
+    meanings: DictionaryMeaning[],
+}
+
+type DictionaryMeaning = {
+    part_of_speech?: string,
+    definitions: string[],
+}
+
+type NowPlaying = {
+    player: string,
+    status: "playing" | "paused" | "stopped",
+    title?: string,
+    artist?: string,
+    album?: string,
+    artwork?: string,
+    can_go_next: boolean,
+    can_go_previous: boolean,
+}
+
+type MediaControlAction = "play_pause" | "next" | "previous"
+
+type SystemInfo = {
+    hostname?: string,
+    os?: string,
+    // seconds
+    uptime: number,
+    ip_addresses: { interface: string, address: string }[],
+    wifi_ssid?: string,
+    battery?: { percentage: number, charging: boolean },
+    disks: { name: string, mount_point: string, total_space: number, available_space: number }[],
+}
+
+type ProcessInfo = {
+    pid: number,
+    parent_pid: number | undefined,
+    name: string,
+    command: string,
+    user: string | undefined,
+    cpu_usage: number,
+    memory: number,
+    nice: number | undefined,
+}
+
+type SshHost = {
+    host: string,
+    port: number | undefined,
+    hostname: string | undefined,
+    user: string | undefined,
+}
+
+type RecentProject = {
+    name: string,
+    path: string,
+    editor: string,
+    launcher: string,
+}
+
+type TotpAccount = {
+    id: string,
+    name: string,
+    issuer: string | undefined,
+    digits: number,
+    period: number,
+    algorithm: "SHA1" | "SHA256" | "SHA512",
+}
+
+type TotpCode = {
+    code: string,
+    remaining_seconds: number,
+}
+
+type TrashItem = {
+    id: string,
+    name: string,
+    original_path: string | undefined,
+    deleted_at: string | undefined,
+    is_dir: boolean,
+    size: number,
+}
+
 declare module "gauntlet:bridge/internal-all" {
     function open_settings(): void
+    function bookmark_sources(): BookmarkSource[]
+    function read_bookmarks(source: BookmarkSource): Promise<Bookmark[]>
+    function open_bookmark(browser: BookmarkBrowser, url: string): void
+    function process_list(): Promise<ProcessInfo[]>
+    function process_terminate(pid: number): void
+    function process_force_kill(pid: number): void
+    function process_renice(pid: number, nice: number): void
+    function ssh_hosts(): Promise<SshHost[]>
+    function open_ssh_host(host: SshHost, terminal_command: string | undefined): void
+    function recent_projects(): Promise<RecentProject[]>
+    function open_recent_project(project: RecentProject, editor_command: string | undefined): void
+    function totp_accounts(): Promise<TotpAccount[]>
+    function totp_add_account(name: string, secret: string): Promise<TotpAccount>
+    function totp_remove_account(id: string): Promise<void>
+    function totp_generate_code(id: string): Promise<TotpCode>
     function run_numbat(input: string): { left: string, right: string }
     function current_os(): string
+    function running_executable_paths(): Promise<string[]>
+    function application_watcher_start(dirs: string[]): number
+    function application_watcher_stop(watcher_id: number): void
+    function application_watcher_next_actions(watcher_id: number): Promise<undefined | DesktopPathAction<unknown>[]>
+    function script_commands_dir(): string
+    function script_command_from_path(path: string): Promise<undefined | DesktopPathAction<ScriptCommandData>>
+    function run_script_command(path: string, args: string[]): Promise<ScriptCommandOutput>
+    function show_plugin_view(entrypoint_id: string): Promise<void>
+    function dictionary_lookup_api(url: string, word: string): Promise<DictionaryEntry | undefined>
+    function dictionary_lookup_wordlist(path: string, word: string): Promise<DictionaryEntry | undefined>
+    function media_now_playing(): Promise<NowPlaying | undefined>
+    function media_control(action: MediaControlAction): Promise<void>
+    function system_info(): Promise<SystemInfo>
+    function open_url(url: string): void
+    function web_search_suggestions(engine: string, query: string): Promise<string[]>
+    function trash_list(): Promise<TrashItem[]>
+    function trash_restore(id: string): Promise<string>
+    function trash_delete(id: string): Promise<void>
+    function trash_empty(): Promise<number>
 }
 
 declare module "gauntlet:bridge/internal-linux" {
     function linux_open_application(desktop_id: string): void
+    function linux_open_application_action(desktop_file_path: string, action_id: string): Promise<void>
+    function linux_focus_application_window(executable: string | undefined, startup_wm_class: string | undefined): Promise<boolean>
     function linux_application_dirs(): string[]
     function linux_app_from_path(path: string): Promise<undefined | DesktopPathAction<LinuxDesktopApplicationData>>
 
@@ -150,15 +395,105 @@ declare module "gauntlet:bridge/internal-macos" {
     function macos_app_from_path(path: string): Promise<undefined | DesktopPathAction<MacOSDesktopApplicationData>>
     function macos_app_from_arbitrary_path(path: string): Promise<undefined | DesktopPathAction<MacOSDesktopApplicationData>>
     function macos_open_application(app_path: String): void
+    function macos_show_application_in_finder(app_path: string): void
+}
+
+declare module "gauntlet:bridge/internal-windows" {
+    function windows_application_dirs(): string[]
+    function windows_app_from_path(path: string): Promise<undefined | DesktopPathAction<WindowsDesktopApplicationData>>
+    function windows_uwp_applications(): Promise<WindowsUwpApplicationData[]>
+    function windows_open_application(shortcut_path: string): void
+    function windows_open_uwp_application(app_id: string): void
+    function windows_show_application_in_explorer(shortcut_path: string): void
+}
+
+type HttpFetchRequest = {
+    url: string,
+    method?: string,
+    headers?: Record<string, string>,
+    body?: Uint8Array,
+    timeout_ms?: number,
+    cache?: boolean,
+}
+
+type HttpFetchResult = {
+    type: "response",
+    status: number,
+    status_text: string,
+    url: string,
+    headers: [string, string][],
+    body: Uint8Array,
+    cached: boolean,
+} | {
+    type: "error",
+    kind: "invalid_request" | "permission_denied" | "rate_limited" | "timeout" | "network" | "too_large",
+    message: string,
+    retry_after_ms?: number,
+}
+
+type RealtimeEvent = {
+    type: "open",
+} | {
+    type: "message",
+    data: string,
+    // only for server-sent events
+    event?: string,
+    id?: string,
+} | {
+    type: "binary_message",
+    data: Uint8Array,
+} | {
+    type: "reconnecting",
+    delay_ms: number,
+    reason: string,
 }
 
 declare module "ext:core/ops" {
     function open_settings(): void
+    function bookmark_sources(): BookmarkSource[]
+    function read_bookmarks(source: BookmarkSource): Promise<Bookmark[]>
+    function open_bookmark(browser: BookmarkBrowser, url: string): void
+    function process_list(): Promise<ProcessInfo[]>
+    function process_terminate(pid: number): void
+    function process_force_kill(pid: number): void
+    function process_renice(pid: number, nice: number): void
+    function ssh_hosts(): Promise<SshHost[]>
+    function open_ssh_host(host: SshHost, terminal_command: string | undefined): void
+    function recent_projects(): Promise<RecentProject[]>
+    function open_recent_project(project: RecentProject, editor_command: string | undefined): void
+    function totp_accounts(): Promise<TotpAccount[]>
+    function totp_add_account(name: string, secret: string): Promise<TotpAccount>
+    function totp_remove_account(id: string): Promise<void>
+    function totp_generate_code(id: string): Promise<TotpCode>
     function run_numbat(input: string): { left: string, right: string }
 
     function current_os(): string
+    function running_executable_paths(): Promise<string[]>
+
+    function application_watcher_start(dirs: string[]): number
+    function application_watcher_stop(watcher_id: number): void
+    function application_watcher_next_actions(watcher_id: number): Promise<undefined | DesktopPathAction<unknown>[]>
+
+    function script_commands_dir(): string
+    function script_command_from_path(path: string): Promise<undefined | DesktopPathAction<ScriptCommandData>>
+    function run_script_command(path: string, args: string[]): Promise<ScriptCommandOutput>
+    function show_plugin_view(entrypoint_id: string): Promise<void>
+
+    function dictionary_lookup_api(url: string, word: string): Promise<DictionaryEntry | undefined>
+    function dictionary_lookup_wordlist(path: string, word: string): Promise<DictionaryEntry | undefined>
+    function media_now_playing(): Promise<NowPlaying | undefined>
+    function media_control(action: MediaControlAction): Promise<void>
+    function system_info(): Promise<SystemInfo>
+    function open_url(url: string): void
+    function web_search_suggestions(engine: string, query: string): Promise<string[]>
+    function trash_list(): Promise<TrashItem[]>
+    function trash_restore(id: string): Promise<string>
+    function trash_delete(id: string): Promise<void>
+    function trash_empty(): Promise<number>
 
     function linux_open_application(desktop_id: string): void
+    function linux_open_application_action(desktop_file_path: string, action_id: string): Promise<void>
+    function linux_focus_application_window(executable: string | undefined, startup_wm_class: string | undefined): Promise<boolean>
     function linux_application_dirs(): string[]
     function linux_app_from_path(path: string): Promise<undefined | DesktopPathAction<LinuxDesktopApplicationData>>
 
@@ -173,12 +508,21 @@ declare module "ext:core/ops" {
     function macos_app_from_path(path: string): Promise<undefined | DesktopPathAction<MacOSDesktopApplicationData>>
     function macos_app_from_arbitrary_path(path: string): Promise<undefined | DesktopPathAction<MacOSDesktopApplicationData>>
     function macos_open_application(app_path: String): void
+    function macos_show_application_in_finder(app_path: string): void
+
+    function windows_application_dirs(): string[]
+    function windows_app_from_path(path: string): Promise<undefined | DesktopPathAction<WindowsDesktopApplicationData>>
+    function windows_uwp_applications(): Promise<WindowsUwpApplicationData[]>
+    function windows_open_application(shortcut_path: string): void
+    function windows_open_uwp_application(app_id: string): void
+    function windows_show_application_in_explorer(shortcut_path: string): void
 
     function op_log_trace(target: string, message: string): void;
     function op_log_debug(target: string, message: string): void;
     function op_log_info(target: string, message: string): void;
     function op_log_warn(target: string, message: string): void;
     function op_log_error(target: string, message: string): void;
+    function op_log_performance_entry(entry_type: string, name: string, start_time: number, duration: number): void;
 
     function op_component_model(): Record<string, Component>;
     function asset_data(path: string): Promise<number[]>;
@@ -197,25 +541,24 @@ declare module "ext:core/ops" {
     function show_preferences_required_view(entrypointId: string, pluginPreferencesRequired: boolean, entrypointPreferencesRequired: boolean): void;
 
     function reload_search_index(searchItems: AdditionalSearchItem[], refreshSearchList: boolean): Promise<void>;
+    function submit_search_results(generation: number, searchItems: AdditionalSearchItem[]): Promise<void>;
+    function submit_argument_suggestions(generation: number, entrypointId: string, argumentId: string, suggestions: string[]): Promise<void>;
 
-    function show_hud(display: string): void;
     function update_loading_bar(entrypoint_id: string, show: boolean): void;
 
-    function op_react_replace_view(render_location: RenderLocation, top_level_view: boolean, entrypoint_id: string, container: any): void;
+    function op_react_replace_view(render_location: RenderLocation, view_stack_depth: number, entrypoint_id: string, container: any, render_time: number): RenderError | null;
     function show_plugin_error_view(entrypoint_id: string, render_location: RenderLocation): void;
 
     function fetch_action_id_for_shortcut(entrypointId: string, key: string, modifierShift: boolean, modifierControl: boolean, modifierAlt: boolean, modifierMeta: boolean): Promise<string | undefined>;
 
-    function clipboard_read(): Promise<{ text_data?: string, png_data?: number[] }>;
-    function clipboard_read_text(): Promise<string | undefined>;
-    function clipboard_write(data: { text_data?: string, png_data?: number[] }): Promise<void>;
-    function clipboard_write_text(data: string): Promise<void>;
-    function clipboard_clear(): Promise<void>;
+    function http_fetch(request: HttpFetchRequest): Promise<HttpFetchResult>;
+    function realtime_websocket_connect(url: string, headers: Record<string, string>): number;
+    function realtime_event_source_connect(url: string, headers: Record<string, string>): number;
+    function realtime_send(id: number, data: string): void;
+    function realtime_close(id: number): void;
+    function realtime_next_event(id: number): Promise<RealtimeEvent | undefined>;
 
-    function environment_gauntlet_version(): number;
-    function environment_is_development(): boolean;
-    function environment_plugin_data_dir(): string;
-    function environment_plugin_cache_dir(): string;
+    // ops generated from api model are declared in gen/api.d.ts
 }
 
 // component model types
@@ -321,3 +664,64 @@ type TypeImageArray = {
     type: "array"
     item: PropertyType
 }
+
+// api model types
+
+type ApiModel = {
+    types: Record<string, ApiType>,
+    ops: ApiOp[],
+}
+
+type ApiOp = {
+    name: string,
+    arguments: ApiField[],
+    result: ApiType,
+    async: boolean,
+}
+
+type ApiField = {
+    name: string,
+    optional: boolean,
+    type: ApiType,
+}
+
+type ApiType = ApiTypeString | ApiTypeNumber | ApiTypeBoolean | ApiTypeVoid | ApiTypeUndefined | ApiTypeArrayBuffer | ApiTypeLiteral | ApiTypeArray | ApiTypeObject | ApiTypeUnion | ApiTypeRef
+
+type ApiTypeString = {
+    type: "string"
+}
+type ApiTypeNumber = {
+    type: "number"
+}
+type ApiTypeBoolean = {
+    type: "boolean"
+}
+type ApiTypeVoid = {
+    type: "void"
+}
+type ApiTypeUndefined = {
+    type: "undefined"
+}
+type ApiTypeArrayBuffer = {
+    type: "array_buffer"
+}
+type ApiTypeLiteral = {
+    type: "literal"
+    value: string
+}
+type ApiTypeArray = {
+    type: "array"
+    item: ApiType
+}
+type ApiTypeObject = {
+    type: "object"
+    fields: ApiField[]
+}
+type ApiTypeUnion = {
+    type: "union"
+    items: ApiType[]
+}
+type ApiTypeRef = {
+    type: "type_ref"
+    name: string
+}
@@ -26,6 +26,7 @@
       makeWrapper
       writeShellScriptBin
       # Packages
+      alsa-lib
       cmake
       deno
       gtk3
@@ -69,7 +70,7 @@
       inherit pname src version RUSTY_V8_ARCHIVE;
       cargoExtraArgs = "--features release";
       nativeBuildInputs = [cmake pkg-config protobuf];
-      buildInputs = [openssl];
+      buildInputs = [openssl] ++ optional isLinux alsa-lib;
       # OPENSSL_CONFIG_DIR didn't work for vendored dependencies
       OPENSSL_NO_VENDOR = true;
     };
@@ -1,8 +1,19 @@
 use anyhow::{anyhow, Context};
 use clap::Parser;
-use gauntlet_client::{generate_complex_theme_sample, generate_simple_theme_sample, open_window};
+use gauntlet_client::{export_settings, generate_complex_theme_sample, generate_simple_theme_sample, import_settings, open_entrypoint, open_window, quit, run_entrypoint};
+use gauntlet_common::dirs::{is_valid_profile_name, Dirs, GAUNTLET_HOME_ENV, GAUNTLET_PROFILE_ENV};
 use gauntlet_management_client::start_management_client;
-use gauntlet_server::start;
+use gauntlet_server::diagnostics::diagnostics_layer;
+use gauntlet_server::control;
+use gauntlet_server::instance::{forward_command, InstanceCommand};
+use gauntlet_server::{plugin_host_fingerprint, run_doctor, run_plugin_host, start, FrontendMode};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::{EnvFilter, Layer};
+use crate::service::{handle_service_command, ServiceCommands};
+
+mod service;
 
 #[derive(Debug, clap::Parser)]
 struct Cli {
@@ -11,24 +22,135 @@ struct Cli {
 
     #[arg(long)]
     minimized: bool,
+
+    /// Start without third-party plugins
+    #[arg(long)]
+    safe_mode: bool,
+
+    /// Don't open window, wait for frontend implemented by another application to connect to frontend socket instead
+    #[arg(long)]
+    external_frontend: bool,
+
+    /// Don't open window, speak JSON-RPC over stdin and stdout instead, for editors which embed Gauntlet search and commands
+    #[arg(long, conflicts_with = "external_frontend")]
+    stdio: bool,
+
+    /// Keep config, data, cache and state inside this directory instead of system locations, same as GAUNTLET_HOME environment variable
+    #[arg(long, global = true)]
+    data_dir: Option<String>,
+
+    /// Use profile with its own plugins, preferences, theme and history instead of the one selected in settings
+    #[arg(long, global = true)]
+    profile: Option<String>,
 }
 
 #[derive(Debug, clap::Subcommand)]
 enum Commands {
-    Open,
+    /// Open main window or, if specified, entrypoint in format <plugin-id>:<entrypoint-id>
+    Open {
+        entrypoint: Option<String>
+    },
+    /// Run command entrypoint in format <plugin-id>:<entrypoint-id>
+    Run {
+        entrypoint: String,
+        /// Values for arguments declared by the entrypoint, in the order they are declared
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        arguments: Vec<String>,
+    },
+    /// Show main window if hidden, hide if shown
+    Toggle,
+    /// Stop running server, plugins and window
+    Quit,
+    /// Search using running server and print results, one per line as <plugin-id>:<entrypoint-id>, name and plugin name separated by tabs
+    Query {
+        query: String,
+        /// Print results as JSON array with ids, names, types and actions
+        #[arg(long)]
+        json: bool,
+    },
     Settings,
+    /// Export config, themes, plugins and their data into an archive
+    Export {
+        path: String,
+    },
+    /// Import archive created by export, database, config and themes are replaced on next start
+    Import {
+        path: String,
+    },
+    /// Manage service which starts Gauntlet on login, systemd user unit on Linux, LaunchAgent on macOS
+    Service {
+        #[command(subcommand)]
+        command: ServiceCommands,
+    },
+    /// Check config, database, global shortcut, display server and installed plugins, and print how to fix found problems. Exits with non-zero code if there are errors
+    Doctor,
+    /// Run runtimes of plugins for launcher on another machine, which lists them in remote_plugin_host config. Connection uses TLS with certificates of both machines pinned by their fingerprints
+    PluginHost {
+        /// Address to listen on, e.g. 0.0.0.0:7450
+        #[arg(long, required_unless_present = "fingerprint")]
+        listen: Option<String>,
+        /// Certificate fingerprint of launcher allowed to start plugins, can be repeated
+        #[arg(long = "trust")]
+        trusted_launchers: Vec<String>,
+        /// Print certificate fingerprint of this machine and exit
+        #[arg(long)]
+        fingerprint: bool,
+    },
     GenerateSampleComplexTheme,
     GenerateSampleSimpleTheme,
 }
 
-pub fn init() {
-    tracing_subscriber::fmt::init();
+// set by server started with --stdio, so that plugin runtime processes, which inherit stdout, don't write logs into it
+const LOG_TO_STDERR_ENV: &'static str = "GAUNTLET_INTERNAL_LOG_TO_STDERR";
 
+pub fn init() {
     let cli = Cli::parse();
 
+    if cli.stdio && cli.command.is_none() {
+        std::env::set_var(LOG_TO_STDERR_ENV, "true");
+    }
+
+    // in stdio mode stdout is reserved for json-rpc messages
+    let log_writer = if std::env::var(LOG_TO_STDERR_ENV).is_ok() {
+        BoxMakeWriter::new(std::io::stderr)
+    } else {
+        BoxMakeWriter::new(std::io::stdout)
+    };
+
+    // env filter applies only to printed logs, diagnostics layer has its own filter
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_writer(log_writer).with_filter(EnvFilter::from_default_env()))
+        .with(diagnostics_layer())
+        .init();
+
+    // passed using environment variable so that child processes use the same directories
+    if let Some(data_dir) = &cli.data_dir {
+        match absolute_path(data_dir) {
+            Ok(data_dir) => std::env::set_var(GAUNTLET_HOME_ENV, data_dir),
+            Err(err) => exit_on_error(Err(err)),
+        }
+    }
+
+    if let Some(profile) = &cli.profile {
+        if !is_valid_profile_name(profile) {
+            exit_on_error(Err(anyhow!("Invalid profile name '{}', only latin letters, numbers, '-' and '_' are allowed", profile)))
+        }
+
+        std::env::set_var(GAUNTLET_PROFILE_ENV, profile)
+    }
+
     match &cli.command {
         None => {
-            if cfg!(feature = "release") {
+            let dirs = Dirs::new();
+
+            // portable install can be moved or unplugged, so it is not registered to start on login
+            let portable = dirs.gauntlet_home().is_some();
+
+            // profile selected in settings while running is used only after restart,
+            // until then settings window and plugin runtimes have to use the same one as server
+            std::env::set_var(GAUNTLET_PROFILE_ENV, dirs.profile());
+
+            if cfg!(feature = "release") && !portable {
                 #[cfg(target_os = "macos")]
                 let result = setup_auto_launch_macos();
 
@@ -41,12 +163,64 @@ pub fn init() {
                 }
             }
 
-            start(cli.minimized)
+            let frontend_mode = if cli.stdio {
+                FrontendMode::ExternalStdio
+            } else if cli.external_frontend {
+                FrontendMode::ExternalSocket
+            } else {
+                FrontendMode::Window
+            };
+
+            start(cli.minimized, cli.safe_mode, frontend_mode)
         }
         Some(command) => {
             match command {
-                Commands::Open => open_window(),
+                Commands::Open { entrypoint: None } => open_window(),
+                Commands::Open { entrypoint: Some(entrypoint) } => {
+                    exit_on_error(
+                        parse_entrypoint(entrypoint)
+                            .and_then(|(plugin_id, entrypoint_id)| open_entrypoint(plugin_id, entrypoint_id))
+                    )
+                }
+                Commands::Run { entrypoint, arguments } => {
+                    exit_on_error(
+                        parse_entrypoint(entrypoint)
+                            .and_then(|(plugin_id, entrypoint_id)| run_entrypoint(plugin_id, entrypoint_id, arguments.clone()))
+                    )
+                }
+                Commands::Toggle => exit_on_error(forward_command(InstanceCommand::ToggleWindow)),
+                Commands::Quit => exit_on_error(quit()),
+                Commands::Query { query, json } => exit_on_error(print_query_results(query, *json)),
                 Commands::Settings => start_management_client(),
+                Commands::Export { path } => {
+                    exit_on_error(
+                        absolute_path(path)
+                            .and_then(|path| export_settings(path))
+                    )
+                }
+                Commands::Import { path } => {
+                    exit_on_error(
+                        absolute_path(path)
+                            .and_then(|path| import_settings(path))
+                            .map(|()| println!("Settings imported, restart Gauntlet to apply them"))
+                    )
+                }
+                Commands::Service { command } => exit_on_error(handle_service_command(command)),
+                Commands::Doctor => {
+                    if !run_doctor() {
+                        std::process::exit(1);
+                    }
+                }
+                Commands::PluginHost { fingerprint: true, .. } => {
+                    exit_on_error(plugin_host_fingerprint().map(|fingerprint| println!("{}", fingerprint)))
+                }
+                Commands::PluginHost { listen, trusted_launchers, .. } => {
+                    exit_on_error(
+                        listen.clone()
+                            .context("--listen is required")
+                            .and_then(|listen| run_plugin_host(listen, trusted_launchers.clone()))
+                    )
+                }
                 Commands::GenerateSampleComplexTheme => generate_complex_theme_sample().expect("Unable to generate complex theme sample"),
                 Commands::GenerateSampleSimpleTheme => generate_simple_theme_sample().expect("Unable to generate simple theme sample")
             };
@@ -54,6 +228,48 @@ pub fn init() {
     }
 }
 
+fn print_query_results(query: &str, json: bool) -> anyhow::Result<()> {
+    let results = control::search(query)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    } else {
+        // same format as accepted by open and run, so output can be piped into them
+        for result in results {
+            println!("{}:{}\t{}\t{}", result.plugin_id, result.entrypoint_id, result.entrypoint_name, result.plugin_name);
+        }
+    }
+
+    Ok(())
+}
+
+// plugin id can contain ':' itself, e.g. "bundled://gauntlet:settings", so split on the last one
+fn parse_entrypoint(value: &str) -> anyhow::Result<(String, String)> {
+    match value.rsplit_once(':') {
+        Some((plugin_id, entrypoint_id)) if !plugin_id.is_empty() && !entrypoint_id.is_empty() && !entrypoint_id.starts_with('/') => {
+            Ok((plugin_id.to_string(), entrypoint_id.to_string()))
+        }
+        _ => Err(anyhow!("Invalid entrypoint '{}', expected format is <plugin-id>:<entrypoint-id>", value))
+    }
+}
+
+// server has its own working directory
+fn absolute_path(path: &str) -> anyhow::Result<String> {
+    let path = std::path::absolute(path)
+        .context("Unable to resolve path")?;
+
+    path.to_str()
+        .map(|path| path.to_string())
+        .ok_or(anyhow!("Path is not valid unicode: {:?}", path))
+}
+
+fn exit_on_error(result: anyhow::Result<()>) {
+    if let Err(err) = result {
+        eprintln!("Error: {:#}", err);
+        std::process::exit(1);
+    }
+}
+
 #[cfg(target_os = "macos")]
 fn setup_auto_launch_macos() -> anyhow::Result<()> {
     let app_path = std::env::current_exe()
@@ -89,14 +305,20 @@ fn setup_auto_launch_windows() -> anyhow::Result<()> {
     setup_auto_launch(app_path)
 }
 
+// disabled in config, previously registered auto-launch is removed
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 fn setup_auto_launch(app_path: String) -> anyhow::Result<()> {
-    auto_launch::AutoLaunchBuilder::new()
+    let auto_launch = auto_launch::AutoLaunchBuilder::new()
         .set_app_name("Gauntlet")
         .set_app_path(&app_path)
         .set_args(&["--minimized"])
-        .build()
-        .and_then(|auto| auto.enable())?;
+        .build()?;
+
+    if gauntlet_common::frontend_config::read_frontend_config().startup.launch_at_login {
+        auto_launch.enable()?;
+    } else if auto_launch.is_enabled()? {
+        auto_launch.disable()?;
+    }
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,265 @@
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+use std::path::PathBuf;
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+use std::process::Command;
+
+use anyhow::anyhow;
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+use anyhow::Context;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ServiceCommands {
+    /// Create and enable service for current user, which starts Gauntlet on login
+    Install,
+    /// Disable and remove service created by install
+    Uninstall,
+    /// Show whether service is installed and running
+    Status,
+}
+
+pub fn handle_service_command(command: &ServiceCommands) -> anyhow::Result<()> {
+    match command {
+        ServiceCommands::Install => install(),
+        ServiceCommands::Uninstall => uninstall(),
+        ServiceCommands::Status => status(),
+    }
+}
+
+#[cfg(target_os = "linux")]
+const SYSTEMD_UNIT_NAME: &str = "gauntlet.service";
+
+#[cfg(target_os = "linux")]
+fn install() -> anyhow::Result<()> {
+    let unit_file = systemd_unit_file()?;
+
+    // sample from assets is used, so that packaged and generated units do not diverge
+    let unit = include_str!("../../../assets/linux/gauntlet.service")
+        .replace("/usr/bin/gauntlet", &current_exe()?);
+
+    std::fs::create_dir_all(unit_file.parent().unwrap())
+        .context("Unable to create systemd user unit directory")?;
+
+    std::fs::write(&unit_file, unit)
+        .with_context(|| format!("Unable to write systemd unit file {:?}", unit_file))?;
+
+    run("systemctl", &["--user", "daemon-reload"])?;
+    run("systemctl", &["--user", "enable", "--now", SYSTEMD_UNIT_NAME])?;
+
+    println!("Service installed: {}", unit_file.display());
+
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn uninstall() -> anyhow::Result<()> {
+    let unit_file = systemd_unit_file()?;
+
+    if !unit_file.exists() {
+        return Err(anyhow!("Service is not installed, {:?} doesn't exist", unit_file))
+    }
+
+    run("systemctl", &["--user", "disable", "--now", SYSTEMD_UNIT_NAME])?;
+
+    std::fs::remove_file(&unit_file)
+        .with_context(|| format!("Unable to remove systemd unit file {:?}", unit_file))?;
+
+    run("systemctl", &["--user", "daemon-reload"])?;
+
+    println!("Service uninstalled");
+
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn status() -> anyhow::Result<()> {
+    let unit_file = systemd_unit_file()?;
+
+    // both commands exit with non-zero code to indicate negative answer
+    let enabled = output("systemctl", &["--user", "is-enabled", SYSTEMD_UNIT_NAME])?;
+    let active = output("systemctl", &["--user", "is-active", SYSTEMD_UNIT_NAME])?;
+
+    println!("Unit file: {} ({})", unit_file.display(), if unit_file.exists() { "installed" } else { "not installed" });
+    println!("Enabled: {}", enabled);
+    println!("Active: {}", active);
+
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn systemd_unit_file() -> anyhow::Result<PathBuf> {
+    let config_dir = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(config_dir) if !config_dir.is_empty() => PathBuf::from(config_dir),
+        _ => home_dir()?.join(".config"),
+    };
+
+    Ok(config_dir.join("systemd").join("user").join(SYSTEMD_UNIT_NAME))
+}
+
+#[cfg(target_os = "macos")]
+const LAUNCH_AGENT_LABEL: &str = "dev.project-gauntlet.gauntlet";
+
+#[cfg(target_os = "macos")]
+fn install() -> anyhow::Result<()> {
+    let plist_file = launch_agent_file()?;
+
+    let plist = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{}</string>
+    <key>ProgramArguments</key>
+    <array>
+        <string>{}</string>
+        <string>--minimized</string>
+    </array>
+    <key>RunAtLoad</key>
+    <true/>
+    <key>KeepAlive</key>
+    <dict>
+        <key>SuccessfulExit</key>
+        <false/>
+    </dict>
+    <key>ProcessType</key>
+    <string>Interactive</string>
+</dict>
+</plist>
+"#,
+        LAUNCH_AGENT_LABEL,
+        escape_xml(&current_exe()?)
+    );
+
+    std::fs::create_dir_all(plist_file.parent().unwrap())
+        .context("Unable to create LaunchAgents directory")?;
+
+    // already loaded agent needs to be unloaded for changes to be picked up
+    if plist_file.exists() {
+        let _ = output("launchctl", &["unload", &path_to_str(&plist_file)?]);
+    }
+
+    std::fs::write(&plist_file, plist)
+        .with_context(|| format!("Unable to write LaunchAgent file {:?}", plist_file))?;
+
+    run("launchctl", &["load", "-w", &path_to_str(&plist_file)?])?;
+
+    println!("Service installed: {}", plist_file.display());
+
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn uninstall() -> anyhow::Result<()> {
+    let plist_file = launch_agent_file()?;
+
+    if !plist_file.exists() {
+        return Err(anyhow!("Service is not installed, {:?} doesn't exist", plist_file))
+    }
+
+    run("launchctl", &["unload", "-w", &path_to_str(&plist_file)?])?;
+
+    std::fs::remove_file(&plist_file)
+        .with_context(|| format!("Unable to remove LaunchAgent file {:?}", plist_file))?;
+
+    println!("Service uninstalled");
+
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn status() -> anyhow::Result<()> {
+    let plist_file = launch_agent_file()?;
+
+    let loaded = Command::new("launchctl")
+        .args(["list", LAUNCH_AGENT_LABEL])
+        .output()
+        .context("Unable to run launchctl")?
+        .status
+        .success();
+
+    println!("LaunchAgent file: {} ({})", plist_file.display(), if plist_file.exists() { "installed" } else { "not installed" });
+    println!("Loaded: {}", if loaded { "yes" } else { "no" });
+
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn launch_agent_file() -> anyhow::Result<PathBuf> {
+    Ok(home_dir()?.join("Library").join("LaunchAgents").join(format!("{}.plist", LAUNCH_AGENT_LABEL)))
+}
+
+#[cfg(target_os = "macos")]
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(target_os = "macos")]
+fn path_to_str(path: &std::path::Path) -> anyhow::Result<String> {
+    path.to_str()
+        .map(|path| path.to_string())
+        .ok_or(anyhow!("Path is not valid unicode: {:?}", path))
+}
+
+// on windows application registers itself to be started on login
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn install() -> anyhow::Result<()> {
+    Err(anyhow!("Service installation is not supported on this platform"))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn uninstall() -> anyhow::Result<()> {
+    Err(anyhow!("Service installation is not supported on this platform"))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn status() -> anyhow::Result<()> {
+    Err(anyhow!("Service installation is not supported on this platform"))
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn home_dir() -> anyhow::Result<PathBuf> {
+    std::env::var_os("HOME")
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+        .ok_or(anyhow!("Unable to find home directory, HOME is not set"))
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn current_exe() -> anyhow::Result<String> {
+    let current_exe = std::env::current_exe()
+        .context("Unable to get current_exe from env")?;
+
+    current_exe.to_str()
+        .map(|path| path.to_string())
+        .ok_or(anyhow!("Executable path is not valid unicode: {:?}", current_exe))
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn run(program: &str, args: &[&str]) -> anyhow::Result<()> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .with_context(|| format!("Unable to run {}", program))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        return Err(anyhow!("'{} {}' failed: {}", program, args.join(" "), stderr.trim()))
+    }
+
+    Ok(())
+}
+
+// returns stdout regardless of exit code
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn output(program: &str, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .with_context(|| format!("Unable to run {}", program))?;
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use gauntlet_client::bench::PluginWidgetContainer;
+use gauntlet_common::model::{EntrypointId, PluginId, RootWidget};
+use serde_json::json;
+
+// multi-select list, so that every item has state, ids are shifted by offset
+// so that two lists share only part of the widgets
+fn list(items: usize, offset: usize) -> Arc<RootWidget> {
+    let members: Vec<_> = (0..items)
+        .map(|index| json!({
+            "__type__": "gauntlet:list_item",
+            "__id__": index + offset + 2,
+            "id": format!("item-{}", index + offset),
+            "title": format!("Item {}", index + offset),
+            "content": []
+        }))
+        .collect();
+
+    let json = json!({
+        "content": [
+            { "__type__": "gauntlet:list", "__id__": 1, "multiSelect": true, "content": members }
+        ]
+    });
+
+    Arc::new(serde_json::from_value(json).expect("invalid widget tree"))
+}
+
+fn replace_view(container: &mut PluginWidgetContainer, root_widget: &Arc<RootWidget>) {
+    container.replace_view(
+        root_widget.clone(),
+        HashMap::new(),
+        &PluginId::from_string("bench"),
+        "Bench Plugin",
+        &EntrypointId::from_string("bench"),
+        "Bench Entrypoint",
+    );
+}
+
+fn state_reconciliation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("state_reconciliation");
+
+    for items in [100, 1_000, 10_000] {
+        let before = list(items, 0);
+        let after = list(items, items / 3);
+
+        group.bench_with_input(BenchmarkId::new("same_tree", items), &before, |b, root_widget| {
+            let mut container = PluginWidgetContainer::new();
+            replace_view(&mut container, root_widget);
+
+            b.iter(|| replace_view(&mut container, root_widget))
+        });
+
+        group.bench_with_input(BenchmarkId::new("changed_tree", items), &(before, after), |b, (before, after)| {
+            let mut container = PluginWidgetContainer::new();
+
+            b.iter(|| {
+                replace_view(&mut container, before);
+                replace_view(&mut container, after);
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, state_reconciliation);
+criterion_main!(benches);
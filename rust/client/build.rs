@@ -59,6 +59,9 @@ fn main() -> anyhow::Result<()> {
                                     output.push_str(&format!("            gauntlet_common::model::UiPropertyValue::Bool({}),\n", arg.name));
                                 }
                             }
+                            PropertyType::Array { ref item } if matches!(**item, PropertyType::String) && !arg.optional => {
+                                output.push_str(&format!("            gauntlet_common::model::UiPropertyValue::Array({}.into_iter().map(|item| gauntlet_common::model::UiPropertyValue::String(item)).collect()),\n", arg.name));
+                            }
                             _ => {
                                 panic!("not yet supported")
                             }
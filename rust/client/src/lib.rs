@@ -1,13 +1,23 @@
+use std::future::Future;
+use std::path::PathBuf;
+use anyhow::anyhow;
 use gauntlet_common::dirs::Dirs;
-use gauntlet_common::model::{BackendRequestData, BackendResponseData, UiRequestData, UiResponseData};
-use gauntlet_common::rpc::backend_api::BackendApi;
+use gauntlet_common::model::{BackendRequestData, BackendResponseData, EntrypointId, ImageCacheStats, PluginId, SimpleThemeTokens, UiRequestData, UiResponseData};
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
 use gauntlet_utils::channel::{RequestReceiver, RequestSender};
-use crate::ui::GauntletComplexTheme;
+use crate::ui::{GauntletComplexTheme, GauntletSimpleTheme};
 
 pub(in crate) mod ui;
 pub(in crate) mod model;
 pub mod global_shortcut;
 
+// internals used by benchmarks in `benches`, not a public api
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench {
+    pub use crate::ui::widget_container::PluginWidgetContainer;
+}
+
 pub fn start_client(
     minimized: bool,
     frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>,
@@ -16,6 +26,17 @@ pub fn start_client(
     ui::run(minimized, frontend_receiver, backend_sender);
 }
 
+// none if window was not started, e.g. with external frontend
+pub fn image_cache_stats() -> Option<ImageCacheStats> {
+    ui::image_cache::image_cache_stats()
+}
+
+// used by doctor command, which runs without window
+#[cfg(target_os = "linux")]
+pub fn is_wayland_session() -> bool {
+    ui::x11::is_wayland_session()
+}
+
 pub fn open_window() {
     tokio::runtime::Builder::new_current_thread()
         .enable_all()
@@ -32,9 +53,7 @@ pub fn open_window() {
                         .await
                         .expect("Unknown error")
                 }
-                Err(_) => {
-                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
-                }
+                Err(err) => log_connection_error(err)
             }
         })
 }
@@ -53,13 +72,99 @@ pub fn open_settings_window() {
                         .await
                         .expect("Unknown error")
                 }
-                Err(_) => {
-                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
-                }
+                Err(err) => log_connection_error(err)
             }
         })
 }
 
+pub fn toggle_window() -> anyhow::Result<()> {
+    block_on(async {
+        let mut backend_api = connect().await?;
+
+        backend_api.toggle_window().await?;
+
+        Ok(())
+    })
+}
+
+pub fn quit() -> anyhow::Result<()> {
+    block_on(async {
+        let mut backend_api = connect().await?;
+
+        backend_api.quit().await?;
+
+        Ok(())
+    })
+}
+
+pub fn open_entrypoint(plugin_id: String, entrypoint_id: String) -> anyhow::Result<()> {
+    block_on(async {
+        let mut backend_api = connect().await?;
+
+        backend_api.open_entrypoint(PluginId::from_string(plugin_id), EntrypointId::from_string(entrypoint_id)).await?;
+
+        Ok(())
+    })
+}
+
+pub fn run_entrypoint(plugin_id: String, entrypoint_id: String, arguments: Vec<String>) -> anyhow::Result<()> {
+    block_on(async {
+        let mut backend_api = connect().await?;
+
+        backend_api.run_entrypoint(PluginId::from_string(plugin_id), EntrypointId::from_string(entrypoint_id), arguments).await?;
+
+        Ok(())
+    })
+}
+
+pub fn export_settings(path: String) -> anyhow::Result<()> {
+    block_on(async {
+        let mut backend_api = connect().await?;
+
+        backend_api.export_settings(path).await?;
+
+        Ok(())
+    })
+}
+
+pub fn import_settings(path: String) -> anyhow::Result<()> {
+    block_on(async {
+        let mut backend_api = connect().await?;
+
+        backend_api.import_settings(path).await?;
+
+        Ok(())
+    })
+}
+
+fn block_on<T>(future: impl Future<Output = T>) -> T {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(future)
+}
+
+async fn connect() -> anyhow::Result<BackendApi> {
+    BackendApi::new()
+        .await
+        .map_err(|err| match err.downcast::<BackendApiError>() {
+            Ok(err) => anyhow!(err),
+            Err(_) => anyhow!("Unable to connect to server. Please check if you have Gauntlet running on your PC"),
+        })
+}
+
+fn log_connection_error(err: anyhow::Error) {
+    match err.downcast_ref::<BackendApiError>() {
+        Some(err @ BackendApiError::IncompatibleVersion { .. }) => {
+            tracing::error!("{}", err)
+        }
+        _ => {
+            tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+        }
+    }
+}
+
 pub fn generate_complex_theme_sample() -> anyhow::Result<()> {
     let dirs = Dirs::new();
 
@@ -106,4 +211,41 @@ pub fn generate_simple_theme_sample() -> anyhow::Result<()> {
     println!("Make changes and rename file to {:?}", simple_theme_file.file_name().unwrap());
 
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn current_simple_theme() -> SimpleThemeTokens {
+    GauntletComplexTheme::current_simple_theme().into()
+}
+
+// saved theme is kept under its name and also becomes current simple theme,
+// config watcher picks it up and reloads theme in the window
+pub fn save_simple_theme(name: &str, theme: SimpleThemeTokens) -> anyhow::Result<PathBuf> {
+    let name = name.trim();
+
+    let valid_name = !name.is_empty()
+        && name.chars().all(|char| char.is_alphanumeric() || char == '-' || char == '_' || char == ' ');
+
+    if !valid_name {
+        return Err(anyhow!("Theme name can only contain letters, digits, spaces, '-' and '_'"));
+    }
+
+    let dirs = Dirs::new();
+
+    let theme_file = dirs.themes_dir().join(format!("{}.json", name));
+
+    if theme_file.exists() {
+        return Err(anyhow!("Theme with name \"{}\" already exists", name));
+    }
+
+    let theme: GauntletSimpleTheme = theme.into();
+
+    let string = serde_json::to_string_pretty(&theme)?;
+
+    std::fs::create_dir_all(dirs.themes_dir())?;
+
+    std::fs::write(&theme_file, &string)?;
+
+    std::fs::write(dirs.theme_simple_file(), &string)?;
+
+    Ok(theme_file)
+}
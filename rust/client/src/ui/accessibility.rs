@@ -0,0 +1,364 @@
+use iced::widget::text_input::focus;
+use iced::Task;
+
+use gauntlet_common::error::ErrorCode;
+use gauntlet_common::i18n::{tr, tr_with};
+use gauntlet_common::model::{SearchResult, SearchResultBadge, SearchResultEntrypointType, UiRenderLocation};
+use gauntlet_common_ui::accessibility::{AccessibilityAction, AccessibilityActionKind, AccessibleNode, Role};
+
+use crate::ui::state::{ArgumentPromptRow, ErrorViewData, GlobalState, MainViewState, PluginViewState};
+use crate::ui::widget::{action_panel_accessibility_node, parse_plugin_widget_key, plugin_widget_key};
+use crate::ui::{alias_action_labels, copy_item_label, AppModel, AppMsg};
+
+// describes contents of main window to assistive technologies, called after every update
+pub fn update_accessibility_tree(state: &AppModel) {
+    gauntlet_common_ui::accessibility::update(|| window_tree(state))
+}
+
+fn window_tree(state: &AppModel) -> (AccessibleNode, Option<String>) {
+    let window = AccessibleNode::new("window", Role::Window)
+        .label("Gauntlet");
+
+    match &state.global_state {
+        GlobalState::MainView { focused_search_result, sub_state, .. } => {
+            let mut focus = None;
+
+            let input = match sub_state {
+                MainViewState::EntrypointArguments { search_result, values, focused_field, .. } => {
+                    focus = Some(format!("argument:{}", focused_field));
+
+                    let fields = search_result.entrypoint_arguments
+                        .iter()
+                        .enumerate()
+                        .map(|(index, argument)| {
+                            let value = values.get(index).cloned().unwrap_or_default();
+
+                            AccessibleNode::new(format!("argument:{}", index), Role::TextInput)
+                                .label(argument.name.clone())
+                                .value(value)
+                                .focusable()
+                        });
+
+                    let prompt_row = sub_state.argument_prompt_row()
+                        .map(|prompt_row| {
+                            let text = match prompt_row {
+                                ArgumentPromptRow::Hint(text) => text,
+                                ArgumentPromptRow::Error(text) => text,
+                            };
+
+                            AccessibleNode::new("argument-prompt-row", Role::Label)
+                                .label(text)
+                        });
+
+                    AccessibleNode::new("arguments", Role::Group)
+                        .label(search_result.entrypoint_name.clone())
+                        .children(fields)
+                        .children(prompt_row)
+                }
+                MainViewState::AddAlias { search_result, value, .. } => {
+                    focus = Some("alias".to_string());
+
+                    let field = AccessibleNode::new("alias", Role::TextInput)
+                        .label(tr("main-alias-placeholder"))
+                        .value(value.clone())
+                        .focusable();
+
+                    AccessibleNode::new("add-alias", Role::Group)
+                        .label(search_result.entrypoint_name.clone())
+                        .children([field])
+                }
+                _ => {
+                    AccessibleNode::new("prompt", Role::SearchInput)
+                        .label(tr("search-placeholder"))
+                        .value(state.prompt.clone())
+                        .focusable()
+                }
+            };
+
+            let inline_views = state.client_context.get_all_inline_view_containers()
+                .iter()
+                .map(|(_, container)| container.inline_accessibility_tree());
+
+            let results = state.search_results
+                .iter()
+                .enumerate()
+                .map(|(index, search_result)| {
+                    let selected = focused_search_result.index == Some(index);
+
+                    let label = match &search_result.entrypoint_badge {
+                        Some(SearchResultBadge::Count(count)) => format!("{}, {}, {}", search_result.entrypoint_name, count, search_result.plugin_name),
+                        Some(SearchResultBadge::Dot) | None => format!("{}, {}", search_result.entrypoint_name, search_result.plugin_name),
+                    };
+
+                    AccessibleNode::new(result_key(index, search_result), Role::ListBoxOption)
+                        .label(label)
+                        .selected(selected)
+                        .clickable()
+                });
+
+            let results = AccessibleNode::new("results", Role::ListBox)
+                .label(tr("main-results"))
+                .children(results);
+
+            let action_panel = match sub_state {
+                MainViewState::SearchResultActionPanel { focused_action_item } => {
+                    focused_search_result.get(&state.search_results)
+                        .map(|search_result| {
+                            let primary_label = match search_result.entrypoint_type {
+                                SearchResultEntrypointType::Command => tr("main-run-command"),
+                                SearchResultEntrypointType::View => tr("main-open-view"),
+                                SearchResultEntrypointType::GeneratedCommand => tr("main-run-command"),
+                            };
+
+                            let labels = std::iter::once(primary_label)
+                                .chain(search_result.entrypoint_actions.iter().map(|action| action.label.clone()))
+                                .chain(search_result.entrypoint_copy_items.iter().map(|item| copy_item_label(item.kind)))
+                                .chain(alias_action_labels(search_result));
+
+                            let items = labels
+                                .enumerate()
+                                .map(|(index, label)| {
+                                    AccessibleNode::new(format!("action:{}", index), Role::MenuItem)
+                                        .label(label)
+                                        .selected(focused_action_item.index == Some(index))
+                                        .clickable()
+                                });
+
+                            if let Some(index) = focused_action_item.index {
+                                focus = Some(format!("action:{}", index));
+                            }
+
+                            AccessibleNode::new("actions", Role::Menu)
+                                .label(tr("main-actions"))
+                                .children(items)
+                        })
+                }
+                MainViewState::InlineViewActionPanel { focused_action_item } => {
+                    let action_panel = state.client_context.get_first_inline_view_action_panel();
+                    let container = state.client_context.get_first_inline_view_container();
+
+                    match (action_panel, container) {
+                        (Some(action_panel), Some(container)) => {
+                            let action_ids = container.get_action_ids();
+
+                            if let Some(widget_id) = focused_action_item.index.and_then(|index| action_ids.get(index)) {
+                                focus = Some(plugin_widget_key(*widget_id));
+                            }
+
+                            Some(action_panel_accessibility_node("actions", &action_panel, &action_ids, focused_action_item.index))
+                        }
+                        _ => None
+                    }
+                }
+                MainViewState::PromptHistory { focused_history_item, entries } => {
+                    let items = entries
+                        .iter()
+                        .enumerate()
+                        .map(|(index, entry)| {
+                            AccessibleNode::new(format!("history:{}", index), Role::MenuItem)
+                                .label(entry.clone())
+                                .selected(focused_history_item.index == Some(index))
+                                .clickable()
+                        });
+
+                    if let Some(index) = focused_history_item.index {
+                        focus = Some(format!("history:{}", index));
+                    }
+
+                    Some(
+                        AccessibleNode::new("history", Role::Menu)
+                            .label(tr("main-prompt-history"))
+                            .children(items)
+                    )
+                }
+                MainViewState::None | MainViewState::EntrypointArguments { .. } | MainViewState::AddAlias { .. } => None,
+            };
+
+            let focus = focus
+                .or_else(|| {
+                    focused_search_result.index
+                        .and_then(|index| state.search_results.get(index).map(|search_result| result_key(index, search_result)))
+                })
+                .unwrap_or_else(|| "prompt".to_string());
+
+            let window = window
+                .child(input)
+                .children(inline_views)
+                .child(results)
+                .children(action_panel);
+
+            (window, Some(focus))
+        }
+        GlobalState::PluginView { plugin_view_data, sub_state, .. } => {
+            let (children, focus) = state.client_context
+                .get_view_container()
+                .accessibility_tree(sub_state, &plugin_view_data.action_shortcuts);
+
+            let view = AccessibleNode::new("plugin-view", Role::Group)
+                .label(plugin_view_data.entrypoint_name.clone())
+                .children(children);
+
+            (window.child(view), focus)
+        }
+        GlobalState::ErrorView { error_view } => {
+            let title = match error_view {
+                ErrorViewData::PreferenceRequired { plugin_preferences_required, entrypoint_preferences_required, .. } => {
+                    match (plugin_preferences_required, entrypoint_preferences_required) {
+                        (true, true) => tr("main-preferences-required-plugin-and-entrypoint"),
+                        (false, true) => tr("main-preferences-required-entrypoint"),
+                        _ => tr("main-preferences-required-plugin"),
+                    }
+                }
+                ErrorViewData::PluginError { .. } => tr("main-plugin-view-error"),
+                ErrorViewData::RenderError { .. } => tr("main-plugin-render-error"),
+                ErrorViewData::BackendTimeout { .. } => tr("backend-timeout"),
+                ErrorViewData::BackendError { error } => match error.code {
+                    ErrorCode::Internal => tr("unknown-error-occurred"),
+                    _ => error.message.clone(),
+                },
+                ErrorViewData::Recovery { safe_mode: true, .. } => tr("main-safe-mode-title"),
+                ErrorViewData::Recovery { safe_mode: false, .. } => tr("main-startup-failed-title"),
+                ErrorViewData::ConfigError { .. } => tr("main-config-error-title"),
+                ErrorViewData::DatabaseRecovery { .. } => tr("main-database-recovered-title"),
+                ErrorViewData::WhatsNew { version, .. } => tr_with("main-whats-new-title", &[("version", (*version).into())]),
+            };
+
+            let alert = AccessibleNode::new("error", Role::Alert)
+                .label(title);
+
+            (window.child(alert), Some("error".to_string()))
+        }
+    }
+}
+
+pub fn handle_accessibility_action(state: &mut AppModel, action: AccessibilityAction) -> Task<AppMsg> {
+    let AccessibilityAction { key, kind } = action;
+
+    match &mut state.global_state {
+        GlobalState::MainView { search_field_id, focused_search_result, sub_state, .. } => {
+            if key == "prompt" {
+                return focus(search_field_id.clone())
+            }
+
+            if let Some(index) = parse_result_key(&key) {
+                let Some(search_result) = state.search_results.get(index).cloned() else {
+                    return Task::none()
+                };
+
+                return match kind {
+                    AccessibilityActionKind::Focus => {
+                        focused_search_result.index = Some(index);
+                        focused_search_result.scroll_to(index)
+                    }
+                    AccessibilityActionKind::Click => Task::done(AppMsg::RunSearchItemAction(search_result, None)),
+                }
+            }
+
+            match sub_state {
+                MainViewState::EntrypointArguments { field_ids, focused_field, .. } => {
+                    let index = key.strip_prefix("argument:").and_then(|index| index.parse::<usize>().ok());
+
+                    match index.and_then(|index| field_ids.get(index).map(|field_id| (index, field_id.clone()))) {
+                        Some((index, field_id)) => {
+                            *focused_field = index;
+                            focus(field_id)
+                        }
+                        None => Task::none()
+                    }
+                }
+                MainViewState::SearchResultActionPanel { focused_action_item } => {
+                    let index = key.strip_prefix("action:").and_then(|index| index.parse::<usize>().ok());
+
+                    let Some(index) = index else {
+                        return Task::none()
+                    };
+
+                    match kind {
+                        AccessibilityActionKind::Focus => {
+                            focused_action_item.index = Some(index);
+                            Task::none()
+                        }
+                        AccessibilityActionKind::Click => {
+                            match focused_search_result.get(&state.search_results) {
+                                Some(search_result) => {
+                                    Task::done(AppMsg::OnAnyActionMainViewSearchResultPanelKeyboardWithFocus {
+                                        search_result: search_result.clone(),
+                                        widget_id: index,
+                                    })
+                                }
+                                None => Task::none()
+                            }
+                        }
+                    }
+                }
+                MainViewState::InlineViewActionPanel { .. } => {
+                    match (parse_plugin_widget_key(&key), kind) {
+                        (Some(widget_id), AccessibilityActionKind::Click) => {
+                            Task::done(AppMsg::OnAnyActionMainViewInlineViewPanelKeyboardWithFocus { widget_id })
+                        }
+                        _ => Task::none()
+                    }
+                }
+                MainViewState::PromptHistory { focused_history_item, .. } => {
+                    let index = key.strip_prefix("history:").and_then(|index| index.parse::<usize>().ok());
+
+                    let Some(index) = index else {
+                        return Task::none()
+                    };
+
+                    match kind {
+                        AccessibilityActionKind::Focus => {
+                            focused_history_item.index = Some(index);
+                            Task::none()
+                        }
+                        AccessibilityActionKind::Click => Task::done(AppMsg::SelectPromptHistoryEntry { index }),
+                    }
+                }
+                MainViewState::AddAlias { field_id, .. } => {
+                    match key.as_str() {
+                        "alias" => focus(field_id.clone()),
+                        _ => Task::none()
+                    }
+                }
+                MainViewState::None => Task::none(),
+            }
+        }
+        GlobalState::PluginView { sub_state, .. } => {
+            let Some(widget_id) = parse_plugin_widget_key(&key) else {
+                return Task::none()
+            };
+
+            match (sub_state, kind) {
+                (PluginViewState::ActionPanel { .. }, AccessibilityActionKind::Click) => {
+                    Task::done(AppMsg::OnAnyActionPluginViewAnyPanelKeyboardWithFocus { widget_id })
+                }
+                (PluginViewState::ActionPanel { .. }, AccessibilityActionKind::Focus) => Task::none(),
+                (PluginViewState::None, AccessibilityActionKind::Focus) => {
+                    state.client_context.focus_control(widget_id)
+                }
+                (PluginViewState::None, AccessibilityActionKind::Click) => {
+                    match state.client_context.activation_event(widget_id) {
+                        Some(widget_event) => {
+                            Task::done(AppMsg::WidgetEvent {
+                                widget_event,
+                                plugin_id: state.client_context.get_view_plugin_id(),
+                                render_location: UiRenderLocation::View,
+                            })
+                        }
+                        None => Task::none()
+                    }
+                }
+            }
+        }
+        GlobalState::ErrorView { .. } => Task::none(),
+    }
+}
+
+// entrypoint is a part of the key so that assistive technology notices when results change
+fn result_key(index: usize, search_result: &SearchResult) -> String {
+    format!("result:{}:{}:{}", index, search_result.plugin_id.to_string(), search_result.entrypoint_id.to_string())
+}
+
+fn parse_result_key(key: &str) -> Option<usize> {
+    key.strip_prefix("result:")?.split(':').next()?.parse().ok()
+}
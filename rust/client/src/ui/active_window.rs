@@ -0,0 +1,95 @@
+use gauntlet_common::model::ActiveWindowContext;
+
+// has to be called before launcher window is opened, while previously focused window is still active.
+// wayland doesn't have a common way to get focused window of another application, so nothing is captured there
+pub fn active_window_context(wayland: bool) -> Option<ActiveWindowContext> {
+    match platform_active_window(wayland) {
+        Ok(context) => context,
+        Err(err) => {
+            tracing::warn!("unable to get active window: {:?}", err);
+
+            None
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn platform_active_window(wayland: bool) -> anyhow::Result<Option<ActiveWindowContext>> {
+    if wayland {
+        return Ok(None)
+    }
+
+    crate::ui::x11::active_window()
+}
+
+#[cfg(target_os = "macos")]
+fn platform_active_window(_wayland: bool) -> anyhow::Result<Option<ActiveWindowContext>> {
+    // window titles of other applications require accessibility permission, so only application is known
+    let context = unsafe {
+        let Some(app) = objc2_app_kit::NSWorkspace::sharedWorkspace().frontmostApplication() else {
+            return Ok(None)
+        };
+
+        let Some(app_id) = app.bundleIdentifier() else {
+            return Ok(None)
+        };
+
+        ActiveWindowContext {
+            app_id: app_id.to_string(),
+            app_name: app.localizedName().map(|name| name.to_string()),
+            title: None,
+        }
+    };
+
+    Ok(Some(context))
+}
+
+#[cfg(target_os = "windows")]
+fn platform_active_window(_wayland: bool) -> anyhow::Result<Option<ActiveWindowContext>> {
+    use std::path::Path;
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION};
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowTextW, GetWindowThreadProcessId};
+
+    unsafe {
+        let window = GetForegroundWindow();
+
+        if window.is_invalid() {
+            return Ok(None)
+        }
+
+        let mut title = [0u16; 512];
+        let title_len = GetWindowTextW(window, &mut title) as usize;
+        let title = String::from_utf16_lossy(&title[..title_len]);
+
+        let mut process_id = 0;
+        GetWindowThreadProcessId(window, Some(&mut process_id as *mut u32));
+
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id)?;
+
+        let mut path = [0u16; 1024];
+        let mut path_len = path.len() as u32;
+        let result = QueryFullProcessImageNameW(process, PROCESS_NAME_WIN32, PWSTR(path.as_mut_ptr()), &mut path_len);
+
+        let _ = CloseHandle(process);
+
+        result?;
+
+        let path = String::from_utf16_lossy(&path[..path_len as usize]);
+        let path = Path::new(&path);
+
+        let app_id = path.file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let app_name = path.file_stem()
+            .map(|name| name.to_string_lossy().to_string());
+
+        Ok(Some(ActiveWindowContext {
+            app_id,
+            app_name,
+            title: Some(title).filter(|title| !title.is_empty()),
+        }))
+    }
+}
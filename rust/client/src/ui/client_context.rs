@@ -1,24 +1,49 @@
 use crate::model::UiViewEvent;
 use crate::ui::widget::{ActionPanel, ComponentWidgetEvent};
 use crate::ui::widget_container::PluginWidgetContainer;
+use crate::ui::image_cache::ImageCache;
+use crate::ui::state::PluginViewData;
 use crate::ui::AppMsg;
-use gauntlet_common::model::{EntrypointId, PhysicalShortcut, PluginId, RootWidget, UiRenderLocation, UiWidgetId};
-use iced::Task;
-use std::collections::HashMap;
+use gauntlet_common::frontend_config::ImageCacheConfig;
+use gauntlet_common::model::{EntrypointId, PhysicalShortcut, PluginId, PluginStyle, RootWidget, UiImageId, UiRenderLocation, UiWidgetId};
+use iced::{window, Task};
+use std::collections::{HashMap, HashSet};
+use std::mem;
 use std::sync::Arc;
 
 pub struct ClientContext {
     inline_views: Vec<(PluginId, PluginWidgetContainer)>, // Vec to have stable ordering
     inline_view_shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>,
+    inline_view_order: Vec<PluginId>,
     view: PluginWidgetContainer,
+    detached_views: Vec<DetachedView>,
+    image_cache: ImageCache,
+}
+
+// plugin view moved out of the main window into its own window,
+// plugin keeps rendering into it until the window is closed.
+// main window can show another view at the same time, including another entrypoint of the same plugin
+pub struct DetachedView {
+    pub window_id: window::Id,
+    pub plugin_view_data: PluginViewData,
+    pub container: PluginWidgetContainer,
+}
+
+impl DetachedView {
+    fn is_entrypoint(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> bool {
+        &self.plugin_view_data.plugin_id == plugin_id && &self.plugin_view_data.entrypoint_id == entrypoint_id
+    }
 }
 
 impl ClientContext {
-    pub fn new() -> Self {
+    pub fn new(image_cache_config: &ImageCacheConfig) -> Self {
         Self {
             inline_views: vec![],
             inline_view_shortcuts: HashMap::new(),
+            inline_view_order: vec![],
             view: PluginWidgetContainer::new(),
+            detached_views: vec![],
+            image_cache: ImageCache::new(image_cache_config),
         }
     }
 
@@ -49,15 +74,25 @@ impl ClientContext {
             .expect("there should always be container for plugin at this point")
     }
 
+    pub fn has_inline_view(&self, plugin_id: &PluginId) -> bool {
+        self.inline_views.iter().any(|(id, _)| id == plugin_id)
+    }
+
     pub fn get_mut_inline_view_container(&mut self, plugin_id: &PluginId) -> &mut PluginWidgetContainer {
-        if let Some(index) = self.inline_views.iter().position(|(id, _)| id == plugin_id) {
-            let (_, container) = &mut self.inline_views[index];
-            container
-        } else {
-            self.inline_views.push((plugin_id.clone(), PluginWidgetContainer::new()));
-            let (_, container) = self.inline_views.last_mut().expect("getting just pushed item");
-            container
-        }
+        let index = match self.inline_views.iter().position(|(id, _)| id == plugin_id) {
+            Some(index) => index,
+            None => {
+                self.inline_views.push((plugin_id.clone(), PluginWidgetContainer::new()));
+                self.sort_inline_views();
+
+                self.inline_views.iter()
+                    .position(|(id, _)| id == plugin_id)
+                    .expect("getting just pushed item")
+            }
+        };
+
+        let (_, container) = &mut self.inline_views[index];
+        container
     }
 
     pub fn get_view_container(&self) -> &PluginWidgetContainer {
@@ -68,6 +103,10 @@ impl ClientContext {
         &mut self.view
     }
 
+    pub fn set_view_style(&mut self, style: PluginStyle) {
+        self.view.set_style(style)
+    }
+
     pub fn get_view_plugin_id(&self) -> PluginId {
         self.view.get_plugin_id()
     }
@@ -76,41 +115,178 @@ impl ClientContext {
         self.view.get_entrypoint_id()
     }
 
+    pub fn detach_view(&mut self, window_id: window::Id, plugin_view_data: PluginViewData) {
+        let container = mem::replace(&mut self.view, PluginWidgetContainer::new());
+
+        self.detached_views.push(DetachedView {
+            window_id,
+            plugin_view_data,
+            container,
+        })
+    }
+
+    pub fn get_detached_view(&self, window_id: window::Id) -> Option<&DetachedView> {
+        self.detached_views.iter()
+            .find(|detached_view| detached_view.window_id == window_id)
+    }
+
+    pub fn get_detached_views(&self) -> &Vec<DetachedView> {
+        &self.detached_views
+    }
+
+    pub fn get_mut_entrypoint_detached_view(&mut self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> Option<&mut DetachedView> {
+        self.detached_views.iter_mut()
+            .find(|detached_view| detached_view.is_entrypoint(plugin_id, entrypoint_id))
+    }
+
+    pub fn has_detached_views(&self) -> bool {
+        !self.detached_views.is_empty()
+    }
+
+    pub fn remove_detached_view(&mut self, window_id: window::Id) -> Option<DetachedView> {
+        let index = self.detached_views.iter()
+            .position(|detached_view| detached_view.window_id == window_id)?;
+
+        Some(self.remove_detached_view_at(index))
+    }
+
+    pub fn remove_entrypoint_detached_view(&mut self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> Option<DetachedView> {
+        let index = self.detached_views.iter()
+            .position(|detached_view| detached_view.is_entrypoint(plugin_id, entrypoint_id))?;
+
+        Some(self.remove_detached_view_at(index))
+    }
+
+    fn remove_detached_view_at(&mut self, index: usize) -> DetachedView {
+        let detached_view = self.detached_views.remove(index);
+
+        self.evict_images();
+
+        detached_view
+    }
+
+    // plugin can have several views open at once, one per entrypoint,
+    // view of entrypoint is either in main window or in detached one
+    fn get_mut_entrypoint_view_container(&mut self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> &mut PluginWidgetContainer {
+        match self.get_mut_entrypoint_detached_view(plugin_id, entrypoint_id) {
+            Some(detached_view) => &mut detached_view.container,
+            None => &mut self.view
+        }
+    }
+
     pub fn render_ui(
         &mut self,
         render_location: UiRenderLocation,
         container: Arc<RootWidget>,
-        images: HashMap<UiWidgetId, Vec<u8>>,
+        images: HashMap<UiWidgetId, UiImageId>,
         plugin_id: &PluginId,
         plugin_name: &str,
         entrypoint_id: &EntrypointId,
         entrypoint_name: &str
     ) -> AppMsg {
-        match render_location {
+        let msg = match render_location {
             UiRenderLocation::InlineView => self.get_mut_inline_view_container(plugin_id).replace_view(container, images, plugin_id, plugin_name, entrypoint_id, entrypoint_name),
-            UiRenderLocation::View => self.get_mut_view_container().replace_view(container, images, plugin_id, plugin_name, entrypoint_id, entrypoint_name)
+            UiRenderLocation::View => self.get_mut_entrypoint_view_container(plugin_id, entrypoint_id).replace_view(container, images, plugin_id, plugin_name, entrypoint_id, entrypoint_name)
+        };
+
+        self.evict_images();
+        self.resolve_images();
+
+        msg
+    }
+
+    // images which need to be requested from server before they can be shown
+    pub fn missing_images(&mut self, images: &HashMap<UiWidgetId, UiImageId>) -> Vec<UiImageId> {
+        self.image_cache.missing(images.values())
+    }
+
+    pub fn insert_images(&mut self, images: HashMap<UiImageId, bytes::Bytes>) {
+        for (image_id, data) in images {
+            self.image_cache.insert(image_id, data);
         }
+
+        self.resolve_images();
+    }
+
+    pub fn set_image_cache_config(&mut self, image_cache_config: &ImageCacheConfig) {
+        self.image_cache.set_config(image_cache_config);
+
+        self.evict_images();
+    }
+
+    fn resolve_images(&mut self) {
+        self.view.resolve_images(&self.image_cache);
+
+        for (_, container) in &mut self.inline_views {
+            container.resolve_images(&self.image_cache);
+        }
+
+        for detached_view in &mut self.detached_views {
+            detached_view.container.resolve_images(&self.image_cache);
+        }
+    }
+
+    fn evict_images(&mut self) {
+        let used_images: HashSet<&UiImageId> = self.view.get_image_ids()
+            .chain(self.inline_views.iter().flat_map(|(_, container)| container.get_image_ids()))
+            .chain(self.detached_views.iter().flat_map(|detached_view| detached_view.container.get_image_ids()))
+            .collect();
+
+        self.image_cache.evict(&used_images);
     }
 
     pub fn set_inline_view_shortcuts(&mut self, shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>) {
         self.inline_view_shortcuts = shortcuts;
     }
 
+    pub fn set_inline_view_order(&mut self, plugin_ids: Vec<PluginId>) {
+        self.inline_view_order = plugin_ids;
+        self.sort_inline_views();
+    }
+
+    // first inline view is the one that receives keyboard actions
+    fn sort_inline_views(&mut self) {
+        let order = &self.inline_view_order;
+
+        self.inline_views.sort_by_key(|(plugin_id, _)| {
+            order.iter()
+                .position(|id| id == plugin_id)
+                .unwrap_or(order.len())
+        })
+    }
+
      pub fn clear_all_inline_views(&mut self) {
         self.inline_views.clear()
     }
 
+    pub fn clear_view(&mut self) {
+        self.view = PluginWidgetContainer::new()
+    }
+
     pub fn clear_inline_view(&mut self, plugin_id: &PluginId) {
         if let Some(index) = self.inline_views.iter().position(|(id, _)| id == plugin_id) {
             self.inline_views.remove(index);
         }
     }
 
-    pub fn handle_event(&self, render_location: UiRenderLocation, plugin_id: &PluginId, event: ComponentWidgetEvent) -> Option<UiViewEvent> {
-        match render_location {
-            UiRenderLocation::InlineView => self.get_inline_view_container(&plugin_id).handle_event(plugin_id.clone(), event),
-            UiRenderLocation::View => self.get_view_container().handle_event(plugin_id.clone(), event)
-        }
+    // widget ids are unique only within a view, so entrypoint of the view is returned together with event
+    pub fn handle_event(&self, render_location: UiRenderLocation, plugin_id: &PluginId, event: ComponentWidgetEvent) -> Option<(EntrypointId, UiViewEvent)> {
+        let container = match render_location {
+            UiRenderLocation::InlineView => self.get_inline_view_container(&plugin_id),
+            UiRenderLocation::View => &self.view
+        };
+
+        let entrypoint_id = container.find_entrypoint_id()?;
+
+        container.handle_event(plugin_id.clone(), event)
+            .map(|event| (entrypoint_id, event))
+    }
+
+    pub fn handle_detached_event(&self, window_id: window::Id, event: ComponentWidgetEvent) -> Option<(EntrypointId, UiViewEvent)> {
+        let detached_view = self.get_detached_view(window_id)?;
+
+        detached_view.container.handle_event(detached_view.plugin_view_data.plugin_id.clone(), event)
+            .map(|event| (detached_view.plugin_view_data.entrypoint_id.clone(), event))
     }
 
     pub fn append_text(&self, text: &str) -> Task<AppMsg> {
@@ -133,6 +309,53 @@ impl ClientContext {
         self.view.get_action_ids()
     }
 
+    pub fn action_keeps_window_open(&self, render_location: UiRenderLocation, plugin_id: &PluginId, widget_id: UiWidgetId) -> bool {
+        match render_location {
+            UiRenderLocation::InlineView => self.get_inline_view_container(plugin_id).action_keeps_window_open(widget_id),
+            UiRenderLocation::View => self.view.action_keeps_window_open(widget_id)
+        }
+    }
+
+    pub fn focus_next_control(&self) -> Task<AppMsg> {
+        self.view.focus_next_control()
+    }
+
+    pub fn focus_previous_control(&self) -> Task<AppMsg> {
+        self.view.focus_previous_control()
+    }
+
+    pub fn focus_control(&self, widget_id: UiWidgetId) -> Task<AppMsg> {
+        self.view.focus_control(widget_id)
+    }
+
+    pub fn focused_control_event(&self) -> Option<ComponentWidgetEvent> {
+        self.view.focused_control_event()
+    }
+
+    pub fn restore_view_focus(&self) -> Task<AppMsg> {
+        self.view.restore_focus()
+    }
+
+    pub fn focus_list_item_mouse(&self, index: usize) {
+        self.view.focus_list_item_mouse(index)
+    }
+
+    pub fn list_scrolled(&self, offset: f32) {
+        self.view.list_scrolled(offset)
+    }
+
+    pub fn toggle_focused_list_item_selection(&self) {
+        self.view.toggle_focused_list_item_selection()
+    }
+
+    pub fn selected_list_items_action(&self, action_shortcuts: &HashMap<String, PhysicalShortcut>, shortcut: &PhysicalShortcut) -> Option<UiWidgetId> {
+        self.view.selected_list_items_action(action_shortcuts, shortcut)
+    }
+
+    pub fn activation_event(&self, widget_id: UiWidgetId) -> Option<ComponentWidgetEvent> {
+        self.view.activation_event(widget_id)
+    }
+
     pub fn focus_up(&self) -> Task<AppMsg> {
         self.view.focus_up()
     }
@@ -0,0 +1,180 @@
+use iced::mouse::Cursor;
+use iced::widget::canvas::{Frame, Geometry, Path, Program, Stroke};
+use iced::{Color, Point, Rectangle, Renderer, Size};
+
+const LINE_WIDTH: f32 = 2.0;
+const BAR_SPACING: f32 = 2.0;
+
+#[derive(Debug, Clone, Copy)]
+pub enum ChartKind {
+    Sparkline,
+    Bar,
+}
+
+// chart without axes and labels, it is scaled to fit all values into available space
+pub struct Chart<'a, Theme>
+where
+    Theme: Catalog,
+{
+    kind: ChartKind,
+    data: Vec<f64>,
+    color: Option<Color>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Theme> Chart<'a, Theme>
+where
+    Theme: Catalog,
+{
+    #[must_use]
+    pub fn new(kind: ChartKind, data: Vec<f64>) -> Self {
+        Self {
+            kind,
+            // non-finite values cannot be scaled, so they are not drawn
+            data: data.into_iter().filter(|value| value.is_finite()).collect(),
+            color: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn color(mut self, color: Option<Color>) -> Self {
+        self.color = color;
+        self
+    }
+
+    #[must_use]
+    pub fn class(mut self, class: <Theme as Catalog>::Class<'a>) -> Self {
+        self.class = class;
+        self
+    }
+
+    // min and max values of vertical axis, bars always start from zero
+    fn range(&self) -> (f64, f64) {
+        let min = self.data.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = self.data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        match self.kind {
+            ChartKind::Sparkline => (min, max),
+            ChartKind::Bar => (min.min(0.0), max.max(0.0)),
+        }
+    }
+
+    fn draw_sparkline(&self, frame: &mut Frame, size: Size, color: Color) {
+        let (min, max) = self.range();
+
+        // line is inset by half of its width, so that it is not cut off at the top and bottom
+        let inset = LINE_WIDTH / 2.0;
+        let height = size.height - LINE_WIDTH;
+
+        let y = |value: f64| {
+            if max == min {
+                // flat data is shown in the middle
+                inset + height / 2.0
+            } else {
+                inset + height - ((value - min) / (max - min)) as f32 * height
+            }
+        };
+
+        let step = if self.data.len() > 1 {
+            size.width / (self.data.len() - 1) as f32
+        } else {
+            0.0
+        };
+
+        let path = Path::new(|builder| {
+            for (index, value) in self.data.iter().enumerate() {
+                let point = Point::new(index as f32 * step, y(*value));
+
+                if index == 0 {
+                    builder.move_to(point);
+                } else {
+                    builder.line_to(point);
+                }
+            }
+
+            // single value is shown as horizontal line across whole chart
+            if let [value] = self.data.as_slice() {
+                builder.line_to(Point::new(size.width, y(*value)));
+            }
+        });
+
+        frame.stroke(&path, Stroke::default().with_color(color).with_width(LINE_WIDTH));
+    }
+
+    fn draw_bars(&self, frame: &mut Frame, size: Size, color: Color, baseline_color: Color) {
+        let (min, max) = self.range();
+
+        let y = |value: f64| {
+            if max == min {
+                size.height
+            } else {
+                size.height - ((value - min) / (max - min)) as f32 * size.height
+            }
+        };
+
+        let baseline = y(0.0);
+
+        let slot_width = size.width / self.data.len() as f32;
+        let bar_width = (slot_width - BAR_SPACING).max(1.0);
+
+        for (index, value) in self.data.iter().enumerate() {
+            let top = y(*value).min(baseline);
+            let bottom = y(*value).max(baseline);
+
+            frame.fill_rectangle(
+                Point::new(index as f32 * slot_width, top),
+                Size::new(bar_width, bottom - top),
+                color,
+            );
+        }
+
+        let baseline = Path::line(Point::new(0.0, baseline), Point::new(size.width, baseline));
+
+        frame.stroke(&baseline, Stroke::default().with_color(baseline_color).with_width(1.0));
+    }
+}
+
+impl<'a, Message, Theme> Program<Message, Theme, Renderer> for Chart<'a, Theme>
+where
+    Theme: Catalog,
+{
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        if !self.data.is_empty() {
+            let styling = Catalog::style(theme, &self.class);
+            let color = self.color.unwrap_or(styling.color);
+
+            match self.kind {
+                ChartKind::Sparkline => self.draw_sparkline(&mut frame, bounds.size(), color),
+                ChartKind::Bar => self.draw_bars(&mut frame, bounds.size(), color, styling.baseline_color),
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    pub color: Color,
+    pub baseline_color: Color,
+}
+
+pub trait Catalog {
+    type Class<'a>;
+
+    fn default<'a>() -> Self::Class<'a>;
+
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
@@ -28,6 +28,8 @@ where
     segment_width: f32,
     height: Length,
     rate: Duration,
+    // static bar filled with loading bar color is shown otherwise
+    animated: bool,
     class: <Theme as Catalog>::Class<'a>,
 }
 
@@ -41,6 +43,7 @@ where
             segment_width: 200.0,
             height: Length::Fixed(1.0),
             rate: Duration::from_secs_f32(1.0),
+            animated: true,
             class: <Theme as Catalog>::Class::default(),
         }
     }
@@ -73,6 +76,12 @@ where
         self
     }
 
+    #[must_use]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
     #[must_use]
     pub fn class(mut self, class: <Theme as Catalog>::Class<'a>) -> Self {
         self.class = class;
@@ -126,6 +135,19 @@ where
         let size = bounds.size();
         let styling = Catalog::style(theme, &self.class);
 
+        if !self.animated {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                styling.loading_bar_color,
+            );
+
+            return;
+        }
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
@@ -184,6 +206,10 @@ where
 
         let bounds = layout.bounds();
 
+        if !self.animated {
+            return Status::Ignored;
+        }
+
         if let Event::Window(window::Event::RedrawRequested(now)) = event {
             if is_visible(&bounds) {
                 let state = state.state.downcast_mut::<LoadingBarState>();
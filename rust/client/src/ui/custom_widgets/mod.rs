@@ -1 +1,4 @@
 pub mod loading_bar;
+pub mod drag_source;
+pub mod chart;
+pub mod transition;
@@ -0,0 +1,283 @@
+use iced::advanced::layout::Limits;
+use iced::advanced::layout::Node;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::tree::State;
+use iced::advanced::widget::tree::Tag;
+use iced::advanced::widget::Operation;
+use iced::advanced::widget::Tree;
+use iced::advanced::Clipboard;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::advanced::Widget;
+use iced::event::Status;
+use iced::mouse;
+use iced::mouse::Cursor;
+use iced::Border;
+use iced::Color;
+use iced::Element;
+use iced::Event;
+use iced::Length;
+use iced::Rectangle;
+use iced::Shadow;
+use iced::Size;
+use iced::Vector;
+use iced::window;
+use std::time::{Duration, Instant};
+
+// distance content travels when sliding in
+const SLIDE_DISTANCE: f32 = 24.0;
+const DURATION: Duration = Duration::from_millis(150);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionKind {
+    // new view slides in from the right, e.g. plugin view opened from search
+    Forward,
+    // previous view slides in from the left, e.g. going back to search
+    Back,
+    Fade,
+}
+
+// animates content every time generation changes, content itself is laid out and receives events as usual.
+// content fades in from background color, so only colors of root container are needed
+pub struct Transition<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    generation: u64,
+    kind: TransitionKind,
+    // content is replaced right away otherwise
+    animated: bool,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Transition<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    #[must_use]
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, generation: u64, kind: TransitionKind) -> Self {
+        Self {
+            content: content.into(),
+            generation,
+            kind,
+            animated: true,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+}
+
+struct TransitionState {
+    generation: u64,
+    // none when no transition is running
+    started_at: Option<Instant>,
+}
+
+impl TransitionState {
+    fn progress(&self) -> f32 {
+        match self.started_at {
+            None => 1.0,
+            Some(started_at) => (started_at.elapsed().as_secs_f32() / DURATION.as_secs_f32()).min(1.0),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Transition<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<TransitionState>()
+    }
+
+    // view that is shown when window is opened is not animated
+    fn state(&self) -> State {
+        State::new(TransitionState {
+            generation: self.generation,
+            started_at: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<TransitionState>();
+
+        if state.generation != self.generation {
+            state.generation = self.generation;
+            state.started_at = if self.animated { Some(Instant::now()) } else { None };
+        }
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> Status {
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let state = tree.state.downcast_mut::<TransitionState>();
+
+            if state.started_at.is_some() {
+                if state.progress() < 1.0 {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                } else {
+                    state.started_at = None;
+                }
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<TransitionState>();
+
+        let progress = state.progress();
+
+        if progress >= 1.0 {
+            self.content.as_widget().draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport);
+
+            return;
+        }
+
+        // ease out, the view moves fast at first and slows down near its place
+        let remaining = (1.0 - progress).powi(3);
+
+        let bounds = layout.bounds();
+        let styling = Catalog::style(theme, &self.class);
+
+        let background = |renderer: &mut Renderer, color: Color| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: styling.border_radius.into(),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                color,
+            );
+        };
+
+        let offset = match self.kind {
+            TransitionKind::Forward => SLIDE_DISTANCE * remaining,
+            TransitionKind::Back => -SLIDE_DISTANCE * remaining,
+            TransitionKind::Fade => 0.0,
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            // sliding content doesn't leave transparent gap at the edge it moves away from
+            background(renderer, styling.background_color);
+
+            renderer.with_translation(Vector::new(offset, 0.0), |renderer| {
+                self.content.as_widget().draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport);
+            });
+
+            background(renderer, Color {
+                a: styling.background_color.a * remaining,
+                ..styling.background_color
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    pub background_color: Color,
+    pub border_radius: f32,
+}
+
+pub trait Catalog {
+    type Class<'a>: Default;
+
+    fn default<'a>() -> Self::Class<'a>;
+
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+impl<'a, Message, Theme, Renderer> From<Transition<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(transition: Transition<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(transition)
+    }
+}
@@ -0,0 +1,85 @@
+use iced::{window, Task};
+use gauntlet_common::model::DragData;
+
+use crate::ui::AppMsg;
+
+// drag crate needs gtk on linux which is not used by the window
+pub const DRAG_SUPPORTED: bool = cfg!(any(target_os = "macos", target_os = "windows"));
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+pub fn start_drag(window_id: window::Id, data: DragData, icon: Option<String>) -> Task<AppMsg> {
+    let path = match data {
+        DragData::File(path) => std::path::PathBuf::from(path),
+        DragData::Url(url) => {
+            match create_url_shortcut(&url) {
+                Ok(path) => path,
+                Err(err) => {
+                    tracing::warn!("unable to create shortcut file for dragged url: {:?}", err);
+                    return Task::none()
+                }
+            }
+        }
+    };
+
+    let image = match icon {
+        Some(icon) => drag::Image::File(icon.into()),
+        None => drag::Image::Raw(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../assets/linux/icon_256.png")).to_vec()),
+    };
+
+    window::run_with_handle(window_id, move |handle| {
+        let result = drag::start_drag(
+            &handle,
+            drag::DragItem::Files(vec![path]),
+            image,
+            |result, _| tracing::debug!("drag finished: {:?}", result),
+            drag::Options::default(),
+        );
+
+        if let Err(err) = result {
+            tracing::warn!("unable to start drag: {:?}", err);
+        }
+    }).map(|_| AppMsg::Noop)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+pub fn start_drag(_window_id: window::Id, _data: DragData, _icon: Option<String>) -> Task<AppMsg> {
+    tracing::warn!("dragging items out of the window is not supported on this platform");
+
+    Task::none()
+}
+
+// other applications accept only files, so url is dropped as internet shortcut file
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+fn create_url_shortcut(url: &str) -> anyhow::Result<std::path::PathBuf> {
+    let dir = gauntlet_common::dirs::Dirs::new().drag_cache_dir();
+
+    std::fs::create_dir_all(&dir)?;
+
+    let name = url.split("://")
+        .nth(1)
+        .and_then(|rest| rest.split(['/', '?', '#']).next())
+        .map(|host| host.chars().filter(|char| char.is_alphanumeric() || *char == '.' || *char == '-').collect::<String>())
+        .filter(|host| !host.is_empty())
+        .unwrap_or_else(|| "link".to_owned());
+
+    #[cfg(target_os = "macos")]
+    let (extension, content) = {
+        let url = url.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+
+        let content = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n<plist version=\"1.0\">\n<dict>\n\t<key>URL</key>\n\t<string>{}</string>\n</dict>\n</plist>\n",
+            url
+        );
+
+        ("webloc", content)
+    };
+
+    #[cfg(target_os = "windows")]
+    let (extension, content) = ("url", format!("[InternetShortcut]\r\nURL={}\r\n", url));
+
+    let path = dir.join(format!("{}.{}", name, extension));
+
+    std::fs::write(&path, content)?;
+
+    Ok(path)
+}
@@ -0,0 +1,201 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use iced::widget::image::Handle;
+use once_cell::sync::Lazy;
+
+use gauntlet_common::frontend_config::ImageCacheConfig;
+use gauntlet_common::model::{ImageCacheStats, UiImageId};
+
+// read by server for diagnostics panel, client runs in the same process
+static IMAGE_CACHE_STATS: Lazy<Mutex<Option<ImageCacheStats>>> = Lazy::new(|| Mutex::new(None));
+
+pub fn image_cache_stats() -> Option<ImageCacheStats> {
+    IMAGE_CACHE_STATS.lock().unwrap().clone()
+}
+
+// images are received once per content hash and shared between renders and containers.
+// images no longer shown are kept, e.g. for going back to previous page of a grid,
+// until cache grows over the ceiling, then least recently used ones are evicted
+pub struct ImageCache {
+    max_size_bytes: u64,
+    images: HashMap<UiImageId, CachedImage>,
+    size_bytes: u64,
+    // incremented on every use, images with the smallest value are evicted first
+    clock: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+struct CachedImage {
+    handle: Handle,
+    // size of encoded image, decoded images are cached by renderer separately
+    size_bytes: u64,
+    last_used: u64,
+}
+
+impl ImageCache {
+    pub fn new(config: &ImageCacheConfig) -> Self {
+        let cache = Self {
+            max_size_bytes: config.max_size_mb * 1024 * 1024,
+            images: HashMap::new(),
+            size_bytes: 0,
+            clock: 0,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        };
+
+        cache.publish_stats();
+
+        cache
+    }
+
+    pub fn set_config(&mut self, config: &ImageCacheConfig) {
+        self.max_size_bytes = config.max_size_mb * 1024 * 1024;
+
+        self.publish_stats();
+    }
+
+    pub fn get(&self, image_id: &UiImageId) -> Option<&Handle> {
+        self.images.get(image_id)
+            .map(|image| &image.handle)
+    }
+
+    // images which need to be requested from server before they can be shown
+    pub fn missing<'a>(&mut self, image_ids: impl Iterator<Item = &'a UiImageId>) -> Vec<UiImageId> {
+        let image_ids: HashSet<&UiImageId> = image_ids.collect();
+
+        let missing: Vec<UiImageId> = image_ids.into_iter()
+            .filter(|image_id| {
+                let cached = self.images.contains_key(*image_id);
+
+                if cached {
+                    self.hits += 1;
+                } else {
+                    self.misses += 1;
+                }
+
+                !cached
+            })
+            .cloned()
+            .collect();
+
+        self.publish_stats();
+
+        missing
+    }
+
+    pub fn insert(&mut self, image_id: UiImageId, data: bytes::Bytes) {
+        self.clock += 1;
+
+        let image = CachedImage {
+            size_bytes: data.len() as u64,
+            handle: Handle::from_bytes(data),
+            last_used: self.clock,
+        };
+
+        self.size_bytes += image.size_bytes;
+
+        if let Some(previous) = self.images.insert(image_id, image) {
+            self.size_bytes -= previous.size_bytes;
+        }
+
+        self.publish_stats();
+    }
+
+    // images that are currently shown are never evicted, even if they alone don't fit under the ceiling
+    pub fn evict(&mut self, used_images: &HashSet<&UiImageId>) {
+        self.clock += 1;
+
+        for image_id in used_images {
+            if let Some(image) = self.images.get_mut(*image_id) {
+                image.last_used = self.clock;
+            }
+        }
+
+        if self.size_bytes > self.max_size_bytes {
+            let mut unused: Vec<(UiImageId, u64)> = self.images.iter()
+                .filter(|(image_id, _)| !used_images.contains(image_id))
+                .map(|(image_id, image)| (image_id.clone(), image.last_used))
+                .collect();
+
+            unused.sort_by_key(|(_, last_used)| *last_used);
+
+            for (image_id, _) in unused {
+                if self.size_bytes <= self.max_size_bytes {
+                    break;
+                }
+
+                if let Some(image) = self.images.remove(&image_id) {
+                    self.size_bytes -= image.size_bytes;
+                    self.evictions += 1;
+                }
+            }
+        }
+
+        self.publish_stats();
+    }
+
+    fn publish_stats(&self) {
+        *IMAGE_CACHE_STATS.lock().unwrap() = Some(ImageCacheStats {
+            entries: self.images.len() as u64,
+            size_bytes: self.size_bytes,
+            max_size_bytes: self.max_size_bytes,
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache_with_max_size(max_size_bytes: u64) -> ImageCache {
+        let mut cache = ImageCache::new(&ImageCacheConfig::default());
+        cache.max_size_bytes = max_size_bytes;
+        cache
+    }
+
+    fn image_id(id: &str) -> UiImageId {
+        id.to_string()
+    }
+
+    #[test]
+    fn least_recently_used_unused_image_is_evicted_first() {
+        let mut cache = cache_with_max_size(20);
+
+        cache.insert(image_id("a"), bytes::Bytes::from(vec![0; 10]));
+        cache.insert(image_id("b"), bytes::Bytes::from(vec![0; 10]));
+
+        let a = image_id("a");
+        cache.evict(&HashSet::from([&a]));
+
+        cache.insert(image_id("c"), bytes::Bytes::from(vec![0; 10]));
+        cache.evict(&HashSet::new());
+
+        assert!(cache.get(&image_id("a")).is_some());
+        assert!(cache.get(&image_id("b")).is_none());
+        assert!(cache.get(&image_id("c")).is_some());
+        assert_eq!(cache.size_bytes, 20);
+        assert_eq!(cache.evictions, 1);
+    }
+
+    #[test]
+    fn used_images_are_kept_over_ceiling() {
+        let mut cache = cache_with_max_size(10);
+
+        cache.insert(image_id("a"), bytes::Bytes::from(vec![0; 10]));
+        cache.insert(image_id("b"), bytes::Bytes::from(vec![0; 10]));
+
+        let a = image_id("a");
+        let b = image_id("b");
+        cache.evict(&HashSet::from([&a, &b]));
+
+        assert_eq!(cache.size_bytes, 20);
+        assert_eq!(cache.evictions, 0);
+    }
+}
@@ -10,54 +10,79 @@ use iced::keyboard::{Key, Modifiers};
 use iced::widget::scrollable::{scroll_to, AbsoluteOffset};
 use iced::widget::text::Shaping;
 use iced::widget::text_input::focus;
-use iced::widget::{button, column, container, horizontal_rule, horizontal_space, row, scrollable, text, text_input, Space};
+use iced::widget::{button, column, container, horizontal_rule, horizontal_space, rich_text, row, scrollable, span, text, text_input, vertical_rule, Space};
 use iced::window::{Level, Position, Screenshot};
-use iced::{event, executor, font, futures, keyboard, stream, window, Alignment, Event, Font, Length, Padding, Pixels, Renderer, Settings, Size, Subscription, Task};
+use iced::{event, executor, font, futures, keyboard, stream, window, Alignment, Event, Font, Length, Padding, Pixels, Point, Renderer, Settings, Size, Subscription, Task};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 use std::sync::{Arc, Mutex as StdMutex, Mutex, RwLock as StdRwLock};
 use iced::alignment::{Horizontal, Vertical};
 use iced_fonts::BOOTSTRAP_FONT_BYTES;
 use serde::Deserialize;
 use tokio::sync::{Mutex as TokioMutex, RwLock as TokioRwLock};
 
-use client_context::ClientContext;
-use gauntlet_common::model::{BackendRequestData, BackendResponseData, EntrypointId, KeyboardEventOrigin, PhysicalKey, PhysicalShortcut, PluginId, RootWidget, RootWidgetMembers, SearchResult, SearchResultEntrypointAction, SearchResultEntrypointType, UiRenderLocation, UiRequestData, UiResponseData, UiWidgetId};
+use client_context::{ClientContext, DetachedView};
+use gauntlet_common::error::ErrorCode;
+use gauntlet_common::model::{parse_entrypoint_arguments, ActiveWindowContext, BackendRequestData, ConfigError, BackendResponseData, SearchKeywordMode, DragData, EntrypointArgumentType, EntrypointArgumentValue, EntrypointId, KeyboardEventOrigin, PhysicalKey, PhysicalShortcut, PluginId, PluginStyle, RootWidget, RootWidgetMembers, SearchResult, SearchResultCategory, SearchResultCopyKind, SearchResultEntrypointAction, SearchResultEntrypointType, SearchResultPreview, SimpleThemeTokens, TextColor, UiImageId, UiRenderLocation, UiRequestData, UiResponseData, UiWidgetId};
+use gauntlet_common::frontend_config::{read_frontend_config, EscapeBehavior, SearchTrigger, SessionRestoreConfig, TypeAheadConfig, WindowPosition};
+use gauntlet_common::i18n::{tr, tr_with};
 use gauntlet_common::rpc::backend_api::{BackendApi, BackendForFrontendApi, BackendForFrontendApiError};
 use gauntlet_common::scenario_convert::{ui_render_location_from_scenario};
 use gauntlet_common::scenario_model::{ScenarioFrontendEvent, ScenarioUiRenderLocation};
+use gauntlet_common_ui::accessibility::AccessibilityAction;
 use gauntlet_common_ui::physical_key_model;
 use gauntlet_utils::channel::{RequestReceiver, RequestSender, Responder};
 
 use crate::model::UiViewEvent;
+use crate::ui::active_window::active_window_context;
 use crate::ui::search_list::search_list;
+use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::{ContainerStyle, ContainerStyleInner};
+use crate::ui::theme::row::RowStyle;
+use crate::ui::theme::span::{SpanStyle, ThemableSpan};
+use crate::ui::theme::text::TextStyle;
 use crate::ui::theme::text_input::TextInputStyle;
 use crate::ui::theme::{Element, ThemableWidget};
-use crate::ui::widget::{render_root, ActionPanel, ActionPanelItem, ComponentWidgetEvent};
+use crate::ui::widget::{render_root, render_shortcut, ActionPanel, ActionPanelItem, ComponentWidgetEvent};
 
+mod accessibility;
+mod active_window;
 mod search_list;
 mod widget;
 mod theme;
 mod client_context;
-mod widget_container;
+pub(in crate) mod widget_container;
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 mod sys_tray;
 mod custom_widgets;
 mod scroll_handle;
 mod state;
 mod hud;
+mod prompt_history;
+mod preview;
 mod grid_navigation;
+mod overlay;
+pub(in crate) mod image_cache;
+#[cfg(test)]
+mod widget_snapshot;
+mod drag;
+#[cfg(target_os = "linux")]
+pub(in crate) mod x11;
 
 use crate::global_shortcut::{convert_physical_shortcut_to_hotkey, register_listener};
 use crate::ui::custom_widgets::loading_bar::LoadingBar;
+use crate::ui::custom_widgets::transition::{Transition, TransitionKind};
 use crate::ui::hud::show_hud_window;
+use crate::ui::overlay::{FocusTarget, Overlay, OverlayStack};
+use crate::ui::preview::PreviewPane;
+use crate::ui::prompt_history::PromptHistory;
 use crate::ui::scroll_handle::ScrollHandle;
-use crate::ui::state::{ErrorViewData, Focus, GlobalState, LoadingBarState, MainViewState, PluginViewData, PluginViewState};
+use crate::ui::state::{ArgumentPromptRow, ErrorViewData, Focus, GlobalState, LoadingBarState, MainViewState, PluginViewData, PluginViewState, Screen};
 use crate::ui::widget_container::PluginWidgetContainer;
-pub use theme::GauntletComplexTheme;
+pub use theme::{GauntletComplexTheme, GauntletSimpleTheme};
 
 pub struct AppModel {
     // logic
@@ -67,19 +92,75 @@ pub struct AppModel {
     frontend_receiver: Arc<TokioRwLock<RequestReceiver<UiRequestData, UiResponseData>>>,
     main_window_id: Option<window::Id>,
     focused: bool,
+    // launcher is not shown over lock screen
+    session_locked: bool,
     wayland: bool,
+    search_trigger: SearchTrigger,
+    escape_behavior: EscapeBehavior,
+    type_ahead: TypeAheadConfig,
+    session_restore: SessionRestoreConfig,
     #[cfg(any(target_os = "macos", target_os = "windows"))]
     tray_icon: tray_icon::TrayIcon,
 
     // ephemeral state
     prompt: String,
+    // prompt was changed, but search is not done until it is committed
+    prompt_search_pending: bool,
+    inline_view_deadline: Option<Instant>,
+    // prompt was changed while typing, search is done when debounce timer with this generation fires
+    search_debounce: Option<u64>,
+    // every search request gets next generation, so that responses arriving out of order can be dropped
+    search_generation: u64,
+    search_results_generation: u64,
+    // enter was pressed before results for the latest prompt arrived, primary action is run when they do
+    submit_after_search: Option<u64>,
+    // search for typed prompt, its result is run right away if it is the only one
+    unique_match_generation: Option<u64>,
+    // plugin view was kept open when window was hidden, to be restored on next open
+    plugin_view_hidden_at: Option<Instant>,
+    // prompt started with plugin keyword, search is limited to that plugin until escape is pressed
+    search_keyword_mode: Option<SearchKeywordMode>,
+    // selected using filter chips or "type:" prefix, kept until changed or window is closed
+    search_category: Option<SearchResultCategory>,
 
     // state
     client_context: ClientContext,
     global_state: GlobalState,
     search_results: Vec<SearchResult>,
+    // search_results are made of results from search index and dynamic results from plugins,
+    // which are sent separately and can arrive before results from search index
+    index_search_results: Vec<SearchResult>,
+    plugin_search_results: Vec<SearchResult>,
+    // plugin responded after timeout, appended to the end so that focused item doesn't move
+    late_plugin_search_results: Vec<SearchResult>,
+    plugin_search_results_generation: u64,
     loading_bar_state: HashMap<(PluginId, EntrypointId), ()>,
-    hud_display: Option<String>
+    hud_display: Option<String>,
+    // description of the latest action that plugin registered undo for, hidden after UNDO_TOAST_DURATION
+    undo_toast: Option<String>,
+    undo_toast_generation: u64,
+    // action panel, prompt history and other layers shown on top of the view, in the order they were opened
+    overlays: OverlayStack,
+    // every change of entrypoint argument gets next generation, suggestions for older values are dropped
+    argument_suggestions_generation: u64,
+    // shown next to plugin view of dev plugins, toggled with ctrl+shift+i
+    widget_inspector: bool,
+    // timings of the latest view render, sent only for dev plugins
+    render_timing: Option<RenderTiming>,
+    prompt_history: PromptHistory,
+    preview_pane: PreviewPane,
+    // toggled with ctrl+shift+p, window is not hidden after running actions until it is closed
+    window_pinned: bool,
+    // incremented when main window switches between search, plugin view and its pages, or error view
+    view_transition_generation: u64,
+    view_transition_kind: TransitionKind,
+}
+
+struct RenderTiming {
+    plugin_id: PluginId,
+    entrypoint_id: EntrypointId,
+    js_render_time_ms: f64,
+    serialization_time_ms: f64,
 }
 
 #[cfg(target_os = "linux")]
@@ -100,6 +181,7 @@ pub enum AppMsg {
     RunCommand {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, EntrypointArgumentValue>,
     },
     RunGeneratedCommandEvent {
         plugin_id: PluginId,
@@ -107,6 +189,9 @@ pub enum AppMsg {
         action_index: Option<usize>
     },
     RunSearchItemAction(SearchResult, Option<usize>),
+    CopySearchResultItem {
+        text: String
+    },
     RunPluginAction {
         render_location: UiRenderLocation,
         plugin_id: PluginId,
@@ -114,20 +199,82 @@ pub enum AppMsg {
     },
     PromptChanged(String),
     PromptSubmit,
+    TogglePromptHistory,
+    TogglePreviewPane,
+    ToggleWindowPin,
+    ShowSearchResultPreview {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        preview: Option<SearchResultPreview>,
+    },
+    SelectPromptHistoryEntry {
+        index: usize
+    },
+    EntrypointArgumentChanged {
+        index: usize,
+        value: String,
+    },
+    DebouncedArgumentSuggestions {
+        generation: u64,
+    },
+    ShowArgumentSuggestions {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        argument_id: String,
+        generation: u64,
+        suggestions: Vec<String>,
+    },
+    SelectArgumentSuggestion {
+        index: usize,
+        value: String,
+    },
+    SubmitEntrypointArguments,
+    AddAlias {
+        search_result: SearchResult,
+    },
+    AliasChanged {
+        value: String,
+    },
+    SubmitAlias,
+    RemoveAliases {
+        search_result: SearchResult,
+    },
     UpdateSearchResults,
-    SetSearchResults(Vec<SearchResult>),
+    DebouncedSearch {
+        generation: u64,
+    },
+    SetSearchResults {
+        generation: u64,
+        results: Vec<SearchResult>,
+        keyword_mode: Option<SearchKeywordMode>,
+        category: Option<SearchResultCategory>,
+    },
+    ExitSearchKeywordMode,
+    SetSearchCategory {
+        category: Option<SearchResultCategory>
+    },
+    CycleSearchCategory,
+    AppendSearchResults {
+        generation: u64,
+        plugin_id: PluginId,
+        results: Vec<SearchResult>,
+        late: bool,
+    },
     RenderPluginUI {
         plugin_id: PluginId,
         plugin_name: String,
         entrypoint_id: EntrypointId,
         entrypoint_name: String,
         render_location: UiRenderLocation,
-        top_level_view: bool,
+        view_stack_depth: usize,
         container: Arc<RootWidget>,
-        images: HashMap<UiWidgetId, Vec<u8>>,
+        images: HashMap<UiWidgetId, UiImageId>,
+    },
+    ImagesLoaded {
+        images: HashMap<UiImageId, bytes::Bytes>,
     },
     HandleRenderPluginUI {
-        top_level_view: bool,
+        view_stack_depth: usize,
         has_children: bool,
         render_location: UiRenderLocation,
     },
@@ -137,10 +284,31 @@ pub enum AppMsg {
         render_location: UiRenderLocation,
         widget_event: ComponentWidgetEvent,
     },
+    // the same as WidgetEvent but for view shown in detached window
+    DetachedWidgetEvent {
+        window_id: window::Id,
+        widget_event: ComponentWidgetEvent,
+    },
+    AccessibilityAction(AccessibilityAction),
+    StartDrag {
+        data: DragData,
+        icon: Option<String>,
+    },
     Noop,
     FontLoaded(Result<(), font::Error>),
     ShowWindow,
     HideWindow,
+    ToggleWindow,
+    Quit,
+    PreviewTheme {
+        theme: Option<SimpleThemeTokens>,
+    },
+    ShowWindowWithView {
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+    },
     ToggleActionPanel {
         keyboard: bool
     },
@@ -155,13 +323,27 @@ pub enum AppMsg {
         entrypoint_id: Option<EntrypointId>,
     },
     OnOpenView {
-        action_shortcuts: HashMap<String, PhysicalShortcut>
+        action_shortcuts: HashMap<String, PhysicalShortcut>,
+        interactive: bool,
+        style: PluginStyle,
     },
     ShowPluginErrorView {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         render_location: UiRenderLocation
     },
+    ShowRenderErrorView {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        message: String,
+        component_path: Vec<String>,
+    },
+    ShowRenderTiming {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        js_render_time_ms: f64,
+        serialization_time_ms: f64,
+    },
     Screenshot {
         save_path: String
     },
@@ -169,15 +351,58 @@ pub enum AppMsg {
         save_path: String,
         screenshot: Screenshot
     },
-    ShowBackendError(BackendForFrontendApiError),
-    ClosePluginView(PluginId),
+    ShowBackendError(BackendForFrontendApiError, Option<Box<AppMsg>>),
+    RetryBackendRequest(Box<AppMsg>),
+    ClosePluginView(PluginId, EntrypointId),
+    DetachPluginView,
+    PopPluginView(PluginId, EntrypointId),
     OpenPluginView(PluginId, EntrypointId),
     InlineViewShortcuts {
         shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>
     },
+    InlineViewOrder {
+        plugin_ids: Vec<PluginId>
+    },
     ShowHud {
         display: String
     },
+    ShowUndoToast {
+        description: String
+    },
+    HideUndoToast {
+        generation: u64
+    },
+    Undo,
+    Redo,
+    ToggleWidgetInspector,
+    SaveBugReport,
+    BugReportScreenshotDone {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        widget_tree: String,
+        screenshot: Screenshot
+    },
+    ShowRecoveryView {
+        safe_mode: bool,
+        description: String,
+        crash_report_path: Option<String>,
+    },
+    ShowConfigError {
+        error: ConfigError,
+    },
+    ConfigReloaded,
+    SessionLockChanged {
+        locked: bool,
+    },
+    ShowDatabaseRecoveryView {
+        reason: String,
+        broken_database_path: String,
+        restored_backup_path: Option<String>,
+    },
+    ShowWhatsNewView {
+        version: u16,
+        changelog: String,
+    },
     OnPrimaryActionMainViewNoPanelKeyboardWithoutFocus,
     OnPrimaryActionMainViewNoPanelKeyboardWithFocus { search_result: SearchResult },
     OnSecondaryActionMainViewNoPanelKeyboardWithFocus { search_result: SearchResult },
@@ -189,6 +414,11 @@ pub enum AppMsg {
     OnAnyActionPluginViewAnyPanel { widget_id: UiWidgetId },
     OnAnyActionMainViewSearchResultPanelMouse { widget_id: UiWidgetId },
     OnPrimaryActionMainViewActionPanelMouse { widget_id: UiWidgetId },
+    FocusSearchResultMouse { index: usize },
+    OpenSearchResultContextMenu { index: usize },
+    ScrollSearchResults { offset: f32 },
+    FocusActionPanelItemMouse { index: usize },
+    ScrollActionPanel { offset: f32 },
     ResetMainViewState,
     OnAnyActionMainViewNoPanelKeyboardAtIndex { index: usize },
     SetGlobalShortcut {
@@ -200,6 +430,11 @@ pub enum AppMsg {
         entrypoint_id: EntrypointId,
         show: bool
     },
+    UpdateActionShortcuts {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        shortcuts: HashMap<String, PhysicalShortcut>
+    },
     PendingPluginViewLoadingBar,
     ShowPluginViewLoadingBar,
     FocusPluginViewSearchBar {
@@ -226,10 +461,29 @@ impl TryInto<iced_layershell::actions::LayershellCustomActionsWithId> for AppMsg
 const WINDOW_WIDTH: f32 = 750.0;
 const WINDOW_HEIGHT: f32 = 450.0;
 
+// inline views of slow plugins that didn't show anything in time are not shown for the current query,
+// so the results below don't jump around while user is looking at them
+const INLINE_VIEW_TIMEOUT: Duration = Duration::from_millis(500);
+
+// pause in typing after which search is done
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(80);
+
+// pause in typing of entrypoint argument after which plugin is asked for suggestions
+const ARGUMENT_SUGGESTIONS_DEBOUNCE: Duration = Duration::from_millis(200);
+
+const UNDO_TOAST_DURATION: Duration = Duration::from_secs(5);
+
+// with top window position, window is placed at this part of monitor height from its top edge
+const WINDOW_TOP_POSITION_RATIO: f32 = 0.2;
+
+// layer shell doesn't tell the size of the monitor, so fixed margin is used instead
+#[cfg(target_os = "linux")]
+const WAYLAND_WINDOW_TOP_MARGIN: i32 = 200;
+
 fn window_settings() -> window::Settings {
     window::Settings {
         size: Size::new(WINDOW_WIDTH, WINDOW_HEIGHT),
-        position: Position::Centered,
+        position: window_position(),
         resizable: false,
         decorations: false,
         transparent: true,
@@ -238,19 +492,78 @@ fn window_settings() -> window::Settings {
             window_kind: window::settings::WindowKind::Popup,
             ..Default::default()
         },
+        // allows to target the window in window manager rules
+        #[cfg(target_os = "linux")]
+        platform_specific: window::settings::PlatformSpecific {
+            application_id: "gauntlet".to_string(),
+            ..Default::default()
+        },
         ..Default::default()
     }
 }
 
+// unlike main window, detached window is a regular one that user can move, resize and close
+fn detached_window_settings() -> window::Settings {
+    window::Settings {
+        size: Size::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+        resizable: true,
+        decorations: true,
+        // separate id, so window manager rules for main window are not applied to it
+        #[cfg(target_os = "linux")]
+        platform_specific: window::settings::PlatformSpecific {
+            application_id: "gauntlet-detached".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+// config is read every time window is opened, so changed position is used without restart
+fn window_position() -> Position {
+    let window_position = read_frontend_config().window.position;
+
+    #[cfg(target_os = "linux")]
+    if let Some(position) = x11::active_monitor_window_position(Size::new(WINDOW_WIDTH, WINDOW_HEIGHT), window_position) {
+        return Position::Specific(position)
+    }
+
+    match window_position {
+        WindowPosition::Center => Position::Centered,
+        WindowPosition::Top => Position::SpecificWith(|window_size, monitor_size| {
+            Point::new(
+                (monitor_size.width - window_size.width) / 2.0,
+                window_vertical_offset(WindowPosition::Top, monitor_size.height, window_size.height),
+            )
+        }),
+    }
+}
+
+// distance between top edge of the monitor and top edge of the window
+fn window_vertical_offset(window_position: WindowPosition, monitor_height: f32, window_height: f32) -> f32 {
+    match window_position {
+        WindowPosition::Center => (monitor_height - window_height) / 2.0,
+        WindowPosition::Top => monitor_height * WINDOW_TOP_POSITION_RATIO,
+    }
+}
 
+
+// size is in logical pixels, layer surface is placed on output chosen by compositor which also tells its scale.
+// TODO fractional scale is not supported on wayland, it needs wp_fractional_scale_v1 with wp_viewporter
+//  in layer shell library and resize of the surface when it is moved to output with different scale.
+//  until then window is rendered at next integer scale and downscaled by compositor
 #[cfg(target_os = "linux")]
 fn layer_shell_settings() -> iced_layershell::reexport::NewLayerShellSettings {
+    let (anchor, margin) = match read_frontend_config().window.position {
+        WindowPosition::Center => (iced_layershell::reexport::Anchor::empty(), Default::default()),
+        WindowPosition::Top => (iced_layershell::reexport::Anchor::Top, Some((WAYLAND_WINDOW_TOP_MARGIN, 0, 0, 0))),
+    };
+
     iced_layershell::reexport::NewLayerShellSettings {
         layer: iced_layershell::reexport::Layer::Overlay,
         keyboard_interactivity: iced_layershell::reexport::KeyboardInteractivity::Exclusive,
         events_transparent: false,
-        anchor: iced_layershell::reexport::Anchor::empty(),
-        margin: Default::default(),
+        anchor,
+        margin,
         exclusive_zone: Some(0),
         size: Some((WINDOW_WIDTH as u32, WINDOW_HEIGHT as u32)),
         use_last_output: false,
@@ -274,6 +587,16 @@ fn open_main_window_non_wayland() -> (window::Id, Task<AppMsg>) {
         window::change_level(main_window_id, Level::AlwaysOnTop),
     );
 
+    #[cfg(target_os = "linux")]
+    tasks.push(
+        x11::request_focus(main_window_id),
+    );
+
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    tasks.push(
+        gauntlet_common_ui::accessibility::attach_window(main_window_id).map(|_| AppMsg::Noop),
+    );
+
     (main_window_id, Task::batch(tasks))
 }
 
@@ -291,23 +614,23 @@ pub fn run(
     frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>,
     backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
 ) {
-    let theme = GauntletComplexTheme::new();
+    GauntletComplexTheme::init();
+
+    let minimized = minimized || !read_frontend_config().startup.open_window;
 
     #[cfg(target_os = "linux")]
     let result = {
-        let wayland = std::env::var("WAYLAND_DISPLAY")
-            .or_else(|_| std::env::var("WAYLAND_SOCKET"))
-            .is_ok();
+        let wayland = x11::is_wayland_session();
 
         if wayland {
-            run_wayland(minimized, frontend_receiver, backend_sender, theme)
+            run_wayland(minimized, frontend_receiver, backend_sender)
         } else {
-            run_non_wayland(minimized, frontend_receiver, backend_sender, theme)
+            run_non_wayland(minimized, frontend_receiver, backend_sender)
         }
     };
 
     #[cfg(not(target_os = "linux"))]
-    let result = run_non_wayland(minimized, frontend_receiver, backend_sender, theme);
+    let result = run_non_wayland(minimized, frontend_receiver, backend_sender);
 
     result.expect("Unable to start application")
 }
@@ -316,7 +639,6 @@ fn run_non_wayland(
     minimized: bool,
     frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>,
     backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
-    theme: GauntletComplexTheme,
 ) -> anyhow::Result<()> {
 
     iced::daemon::<AppModel, AppMsg, GauntletComplexTheme, Renderer>(title, update, view)
@@ -329,10 +651,8 @@ fn run_non_wayland(
             ..Default::default()
         })
         .subscription(subscription)
-        .theme({
-            let theme = theme.clone();
-            move |_, _| theme.clone()
-        })
+        // theme can be replaced when config file changes
+        .theme(|_, _| GauntletComplexTheme::current())
         .run_with(move || new(frontend_receiver, backend_sender, false, minimized))?;
 
     Ok(())
@@ -343,7 +663,6 @@ fn run_wayland(
     minimized: bool,
     frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>,
     backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
-    theme: GauntletComplexTheme,
 ) -> anyhow::Result<()> {
     iced_layershell::build_pattern::daemon("Gauntlet", update, view, wayland_remove_id_info)
         .layer_settings(iced_layershell::settings::LayerShellSettings {
@@ -354,10 +673,8 @@ fn run_wayland(
             ..Default::default()
         })
         .subscription(subscription)
-        .theme({
-            let theme = theme.clone();
-            move |_| theme.clone()
-        })
+        // theme can be replaced when config file changes
+        .theme(|_| GauntletComplexTheme::current())
         .run_with(move || new(frontend_receiver, backend_sender, true, minimized))?;
 
     Ok(())
@@ -373,7 +690,7 @@ fn new(
     wayland: bool,
     minimized: bool,
 ) -> (AppModel, Task<AppMsg>) {
-    let backend_api = BackendForFrontendApi::new(backend_sender);
+    let backend_api = BackendForFrontendApi::with_config(backend_sender, read_frontend_config().backend_requests);
 
     let global_hotkey_manager = GlobalHotKeyManager::new()
         .expect("unable to create global hot key manager");
@@ -382,6 +699,10 @@ fn new(
         font::load(BOOTSTRAP_FONT_BYTES).map(AppMsg::FontLoaded),
     ];
 
+    if !cfg!(feature = "scenario_runner") {
+        gauntlet_common_ui::accessibility::init("Gauntlet");
+    }
+
     let main_window_id = if !minimized {
         #[cfg(target_os = "linux")]
         let (main_window_id, open_task) =  if wayland {
@@ -400,6 +721,18 @@ fn new(
         None
     };
 
+    // window is not shown after start, so plugins can become idle right away
+    if main_window_id.is_none() && !cfg!(feature = "scenario_runner") {
+        let mut backend_client = backend_api.clone();
+
+        tasks.push(Task::perform(async move {
+            backend_client.window_visibility(false, None)
+                .await?;
+
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop)));
+    }
+
     let global_state = if cfg!(feature = "scenario_runner") {
         let gen_in = std::env::var("GAUNTLET_SCREENSHOT_GEN_IN")
             .expect("Unable to read GAUNTLET_SCREENSHOT_GEN_IN");
@@ -428,21 +761,32 @@ fn new(
         );
 
         match event {
-            ScenarioFrontendEvent::ReplaceView { entrypoint_id, render_location, top_level_view, container, images } => {
+            ScenarioFrontendEvent::ReplaceView { entrypoint_id, render_location, view_stack_depth, container, images } => {
                 let plugin_id = PluginId::from_string("__SCREENSHOT_GEN___");
                 let entrypoint_id = EntrypointId::from_string(entrypoint_id);
 
                 let render_location = ui_render_location_from_scenario(render_location);
 
+                // snapshots contain image bytes directly, so they are put into cache before render
+                let image_ids = images.keys()
+                    .map(|widget_id| (*widget_id, widget_id.to_string()))
+                    .collect();
+
+                let images = images.into_iter()
+                    .map(|(widget_id, data)| (widget_id.to_string(), data.into()))
+                    .collect();
+
+                tasks.push(Task::done(AppMsg::ImagesLoaded { images }));
+
                 let msg = AppMsg::RenderPluginUI {
                     plugin_id: plugin_id.clone(),
                     plugin_name: "Screenshot Plugin".to_string(),
                     entrypoint_id: entrypoint_id.clone(),
                     entrypoint_name: "Screenshot Entrypoint".to_string(),
                     render_location,
-                    top_level_view,
+                    view_stack_depth,
                     container: Arc::new(container),
-                    images
+                    images: image_ids
                 };
 
                 tasks.push(Task::done(msg));
@@ -451,13 +795,15 @@ fn new(
                     UiRenderLocation::InlineView => GlobalState::new(text_input::Id::unique()),
                     UiRenderLocation::View => GlobalState::new_plugin(
                         PluginViewData {
-                            top_level_view,
+                            view_stack_depth,
                             plugin_id,
                             plugin_name: "Screenshot Gen".to_string(),
                             entrypoint_id,
                             entrypoint_name: gen_name,
                             action_shortcuts: Default::default(),
+                            interactive: false,
                         },
+                        None,
                     )
                 }
             }
@@ -475,6 +821,7 @@ fn new(
                 let error_view = ErrorViewData::PluginError {
                     plugin_id: PluginId::from_string("__SCREENSHOT_GEN___"),
                     entrypoint_id: EntrypointId::from_string(entrypoint_id),
+                    panic_message: None,
                 };
 
                 GlobalState::new_error(error_view)
@@ -484,6 +831,8 @@ fn new(
         GlobalState::new(text_input::Id::unique())
     };
 
+    let frontend_config = read_frontend_config();
+
     (
         AppModel {
             // logic
@@ -493,25 +842,59 @@ fn new(
             frontend_receiver: Arc::new(TokioRwLock::new(frontend_receiver)),
             main_window_id,
             focused: false,
+            session_locked: false,
             wayland,
+            search_trigger: frontend_config.search_trigger,
+            escape_behavior: frontend_config.escape_behavior,
+            type_ahead: frontend_config.type_ahead,
+            session_restore: frontend_config.session_restore,
             #[cfg(any(target_os = "macos", target_os = "windows"))]
             tray_icon: sys_tray::create_tray(),
 
             // ephemeral state
             prompt: "".to_string(),
+            prompt_search_pending: false,
+            inline_view_deadline: None,
+            search_debounce: None,
+            search_generation: 0,
+            search_results_generation: 0,
+            submit_after_search: None,
+            unique_match_generation: None,
+            plugin_view_hidden_at: None,
+            search_keyword_mode: None,
+            search_category: None,
 
             // state
             global_state,
-            client_context: ClientContext::new(),
+            client_context: ClientContext::new(&frontend_config.image_cache),
             search_results: vec![],
+            index_search_results: vec![],
+            plugin_search_results: vec![],
+            late_plugin_search_results: vec![],
+            plugin_search_results_generation: 0,
             loading_bar_state: HashMap::new(),
             hud_display: None,
+            undo_toast: None,
+            undo_toast_generation: 0,
+            overlays: OverlayStack::new(),
+            argument_suggestions_generation: 0,
+            widget_inspector: false,
+            render_timing: None,
+            prompt_history: PromptHistory::load(&frontend_config.prompt_history),
+            preview_pane: PreviewPane::new(),
+            window_pinned: false,
+            view_transition_generation: 0,
+            view_transition_kind: TransitionKind::Fade,
         },
         Task::batch(tasks),
     )
 }
 
 fn title(state: &AppModel, window: window::Id) -> String {
+    if let Some(detached_view) = state.client_context.get_detached_view(window) {
+        return detached_view.plugin_view_data.entrypoint_name.clone()
+    }
+
     match state.main_window_id {
         Some(main_window_id) => {
             if window == main_window_id {
@@ -527,21 +910,56 @@ fn title(state: &AppModel, window: window::Id) -> String {
 }
 
 fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
+    let previous_screen = state.global_state.screen();
+
+    let task = update_state(state, message);
+
+    state.update_view_transition(previous_screen);
+
+    let panic_task = Task::batch([
+        state.replace_panicked_plugin_view(),
+        state.replace_panicked_detached_view(),
+    ]);
+
+    accessibility::update_accessibility_tree(state);
+
+    // focused search result can be changed by many different messages
+    let preview_task = state.request_search_result_preview();
+
+    Task::batch([task, panic_task, preview_task])
+}
+
+fn update_state(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
     match message {
         AppMsg::OpenView { plugin_id, plugin_name, entrypoint_id, entrypoint_name } => {
             match &mut state.global_state {
                 GlobalState::MainView { pending_plugin_view_data, .. } => {
+                    // entrypoint has only one view, so it is moved back from detached window
+                    let close_detached_view = match state.client_context.remove_entrypoint_detached_view(&plugin_id, &entrypoint_id) {
+                        Some(detached_view) => window::close(detached_view.window_id),
+                        None => Task::none()
+                    };
+
+                    let retry = AppMsg::OpenView {
+                        plugin_id: plugin_id.clone(),
+                        plugin_name: plugin_name.clone(),
+                        entrypoint_id: entrypoint_id.clone(),
+                        entrypoint_name: entrypoint_name.clone(),
+                    };
+
                     *pending_plugin_view_data = Some(PluginViewData {
-                        top_level_view: true,
+                        view_stack_depth: 1,
                         plugin_id: plugin_id.clone(),
                         plugin_name,
                         entrypoint_id: entrypoint_id.clone(),
                         entrypoint_name,
                         action_shortcuts: HashMap::new(),
+                        interactive: false,
                     });
 
                     Task::batch([
-                        state.open_plugin_view(plugin_id, entrypoint_id),
+                        close_detached_view,
+                        state.open_plugin_view(plugin_id, entrypoint_id, Some(retry)),
                         Task::perform(async move { AppMsg::PendingPluginViewLoadingBar }, std::convert::identity)
                     ])
                 }
@@ -553,37 +971,83 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 }
             }
         }
-        AppMsg::RunCommand { plugin_id, entrypoint_id } => {
+        AppMsg::RunCommand { plugin_id, entrypoint_id, arguments } => {
             Task::batch([
-                state.hide_window(),
-                state.run_command(plugin_id, entrypoint_id),
+                state.hide_window_after_action(false),
+                state.run_command(plugin_id, entrypoint_id, arguments),
             ])
         }
         AppMsg::RunGeneratedCommandEvent { plugin_id, entrypoint_id, action_index } => {
             Task::batch([
-                state.hide_window(),
+                state.hide_window_after_action(false),
                 state.run_generated_command(plugin_id, entrypoint_id, action_index),
             ])
         }
         AppMsg::RunPluginAction { render_location, plugin_id, widget_id } => {
+            let keep_window_open = state.client_context.action_keeps_window_open(render_location, &plugin_id, widget_id);
+
             let widget_event = ComponentWidgetEvent::RunAction {
                 widget_id,
             };
 
             Task::batch([
-                state.hide_window(),
+                state.hide_window_after_action(keep_window_open),
                 Task::done(AppMsg::WidgetEvent { widget_event, plugin_id, render_location })
             ])
         }
         AppMsg::RunSearchItemAction(search_result, action_index) => {
+            state.prompt_history.add(&state.prompt);
+
+            let merged_from = action_index
+                .and_then(|index| search_result.entrypoint_actions.get(index))
+                .and_then(|action| action.merged_from.clone());
+
+            // action of duplicate result from another plugin, run by the entrypoint it came from
+            if let Some(source) = merged_from {
+                return Task::done(AppMsg::RunGeneratedCommandEvent {
+                    entrypoint_id: source.entrypoint_id,
+                    plugin_id: source.plugin_id,
+                    action_index: source.action_index,
+                })
+            }
+
             match search_result.entrypoint_type {
                 SearchResultEntrypointType::Command => {
                     match action_index {
                         None => {
-                            Task::done(AppMsg::RunCommand {
-                                entrypoint_id: search_result.entrypoint_id.clone(),
-                                plugin_id: search_result.plugin_id.clone()
-                            })
+                            let fallback_arguments = if search_result.fallback {
+                                // whole search query is passed as the first argument
+                                parse_entrypoint_arguments(&search_result.entrypoint_arguments, &[state.prompt.trim().to_string()]).ok()
+                            } else {
+                                None
+                            };
+
+                            if search_result.entrypoint_arguments.is_empty() {
+                                Task::done(AppMsg::RunCommand {
+                                    entrypoint_id: search_result.entrypoint_id.clone(),
+                                    plugin_id: search_result.plugin_id.clone(),
+                                    arguments: HashMap::new(),
+                                })
+                            } else if let Some(arguments) = fallback_arguments {
+                                Task::done(AppMsg::RunCommand {
+                                    entrypoint_id: search_result.entrypoint_id.clone(),
+                                    plugin_id: search_result.plugin_id.clone(),
+                                    arguments,
+                                })
+                            } else {
+                                match &mut state.global_state {
+                                    GlobalState::MainView { sub_state, .. } => {
+                                        let focus_arguments = MainViewState::entrypoint_arguments(sub_state, search_result, &state.prompt);
+
+                                        Task::batch([
+                                            state.open_overlay(Overlay::EntrypointArguments),
+                                            focus_arguments,
+                                        ])
+                                    }
+                                    GlobalState::ErrorView { .. } => Task::none(),
+                                    GlobalState::PluginView { .. } => Task::none(),
+                                }
+                            }
                         }
                         Some(_) => Task::none()
                     }
@@ -619,6 +1083,15 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         new_prompt.truncate(100); // search query uses regex so just to be safe truncate the prompt
 
                         state.prompt = new_prompt.clone();
+                        state.prompt_history.reset_position();
+
+                        // while history is shown, prompt filters it instead of doing a search
+                        if let MainViewState::PromptHistory { focused_history_item, entries } = sub_state {
+                            *entries = state.prompt_history.search(&new_prompt);
+                            focused_history_item.reset(true);
+
+                            return Task::none()
+                        }
 
                         focused_search_result.reset(true);
 
@@ -628,123 +1101,481 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                     GlobalState::PluginView { .. } => {}
                 }
 
-                state.search(new_prompt, true)
+                // empty prompt is searched right away, so that results don't stay from previous text
+                if state.search_trigger == SearchTrigger::Commit && !new_prompt.is_empty() {
+                    state.prompt_search_pending = true;
+
+                    return Task::none()
+                }
+
+                if new_prompt.is_empty() {
+                    state.search_prompt(new_prompt)
+                } else {
+                    state.debounce_search()
+                }
+            }
+        }
+        AppMsg::DebouncedSearch { generation } => {
+            // prompt was changed again while waiting
+            if state.search_debounce != Some(generation) {
+                return Task::none()
             }
+
+            state.search_prompt(state.prompt.clone())
         }
         AppMsg::UpdateSearchResults => {
             match &state.global_state {
+                // order of inline views could have been changed in settings
                 GlobalState::MainView { .. } => {
-                    state.search(state.prompt.clone(), false)
+                    Task::batch([
+                        state.search(state.prompt.clone(), false),
+                        state.inline_view_order(),
+                    ])
                 }
                 _ => Task::none()
             }
         }
-        AppMsg::PromptSubmit => {
-            state.global_state.primary(&state.client_context, &state.search_results)
-        },
-        AppMsg::SetSearchResults(new_search_results) => {
-            state.search_results = new_search_results;
+        AppMsg::PromptSubmit => state.submit_prompt(),
+        AppMsg::TogglePromptHistory => {
+            let GlobalState::MainView { sub_state, .. } = &mut state.global_state else {
+                return Task::none()
+            };
+
+            match sub_state {
+                MainViewState::None => {
+                    MainViewState::prompt_history(sub_state, state.prompt_history.search(&state.prompt));
+
+                    state.open_overlay(Overlay::PromptHistory)
+                }
+                MainViewState::PromptHistory { .. } => {
+                    MainViewState::initial(sub_state);
+
+                    state.close_overlay(Overlay::PromptHistory)
+                }
+                _ => Task::none()
+            }
+        }
+        AppMsg::TogglePreviewPane => {
+            state.preview_pane.toggle();
 
             Task::none()
         }
-        AppMsg::RenderPluginUI {
-            plugin_id,
-            plugin_name,
-            entrypoint_id,
-            entrypoint_name,
-            render_location,
-            top_level_view,
-            container,
-            images
-        } => {
-            let has_children = container.content.is_some();
+        AppMsg::ToggleWindowPin => {
+            state.window_pinned = !state.window_pinned;
 
-            Task::batch([
-                Task::done(state.client_context.render_ui(
-                    render_location,
-                    container,
-                    images,
-                    &plugin_id,
-                    &plugin_name,
-                    &entrypoint_id,
-                    &entrypoint_name,
-                )),
-                Task::done(AppMsg::HandleRenderPluginUI {
-                    top_level_view,
-                    has_children,
-                    render_location,
-                })
-            ])
+            Task::none()
         }
-        AppMsg::HandleRenderPluginUI {
-            top_level_view,
-            has_children,
-            render_location
-        } => {
-            match &mut state.global_state {
-                GlobalState::MainView { pending_plugin_view_data, focused_search_result, pending_plugin_view_loading_bar, .. } => {
+        AppMsg::ShowSearchResultPreview { plugin_id, entrypoint_id, preview } => {
+            state.preview_pane.set_preview(plugin_id, entrypoint_id, preview);
 
-                    if let LoadingBarState::Pending = pending_plugin_view_loading_bar {
-                        *pending_plugin_view_loading_bar = LoadingBarState::Off;
-                    }
+            Task::none()
+        }
+        AppMsg::SelectPromptHistoryEntry { index } => {
+            let GlobalState::MainView { sub_state, .. } = &mut state.global_state else {
+                return Task::none()
+            };
 
-                    if has_children {
-                        if let UiRenderLocation::InlineView = render_location {
-                            focused_search_result.unfocus();
-                        }
-                    }
+            let entry = match sub_state {
+                MainViewState::PromptHistory { entries, .. } => entries.get(index).cloned(),
+                _ => None
+            };
 
-                    let command = match pending_plugin_view_data {
-                        None => Task::none(),
-                        Some(pending_plugin_view_data) => {
-                            let pending_plugin_view_data = pending_plugin_view_data.clone();
-                            GlobalState::plugin(
-                                &mut state.global_state,
-                                PluginViewData {
-                                    top_level_view,
-                                    ..pending_plugin_view_data
-                                },
-                            )
-                        }
-                    };
+            MainViewState::initial(sub_state);
 
-                    if let UiRenderLocation::InlineView = render_location {
-                        Task::batch([
-                            command,
-                            state.inline_view_shortcuts()
-                        ])
-                    } else {
-                        command
-                    }
-                }
-                GlobalState::ErrorView { .. } => Task::none(),
-                GlobalState::PluginView { plugin_view_data, ..} => {
-                    plugin_view_data.top_level_view = top_level_view;
+            let close = state.close_overlay(Overlay::PromptHistory);
 
-                    Task::none()
-                }
+            match entry {
+                Some(entry) => Task::batch([close, state.set_prompt(entry)]),
+                None => close
             }
         }
-        AppMsg::IcedEvent(window_id, Event::Keyboard(event)) => {
-            let Some(main_window_id) = state.main_window_id else {
+        AppMsg::EntrypointArgumentChanged { index, value } => {
+            if let GlobalState::MainView { sub_state, .. } = &mut state.global_state {
+                let MainViewState::EntrypointArguments { values, focused_field, suggestions, .. } = sub_state else {
+                    return Task::none()
+                };
+
+                values[index] = value;
+                *focused_field = index;
+                *suggestions = None;
+
+                sub_state.validate_argument(index);
+
+                state.debounce_argument_suggestions()
+            } else {
+                Task::none()
+            }
+        }
+        AppMsg::DebouncedArgumentSuggestions { generation } => {
+            // argument was changed again while waiting
+            if state.argument_suggestions_generation != generation {
                 return Task::none()
-            };
+            }
 
-            if window_id != main_window_id {
+            state.request_argument_suggestions(generation)
+        }
+        AppMsg::ShowArgumentSuggestions { plugin_id, entrypoint_id, argument_id, generation, suggestions: new_suggestions } => {
+            if state.argument_suggestions_generation != generation {
                 return Task::none()
             }
 
-            match event {
-                keyboard::Event::KeyPressed { key, modifiers, physical_key, text, .. } => {
-                    tracing::debug!("Key pressed: {:?}. shift: {:?} control: {:?} alt: {:?} meta: {:?}", key, modifiers.shift(), modifiers.control(), modifiers.alt(), modifiers.logo());
-                    match key {
+            if let GlobalState::MainView { sub_state: MainViewState::EntrypointArguments { search_result, suggestions, .. }, .. } = &mut state.global_state {
+                if search_result.plugin_id != plugin_id || search_result.entrypoint_id != entrypoint_id {
+                    return Task::none()
+                }
+
+                let index = search_result.entrypoint_arguments
+                    .iter()
+                    .position(|argument| argument.id == argument_id);
+
+                *suggestions = match index {
+                    Some(index) if !new_suggestions.is_empty() => Some((index, new_suggestions)),
+                    _ => None,
+                };
+            }
+
+            Task::none()
+        }
+        AppMsg::SelectArgumentSuggestion { index, value } => {
+            if let GlobalState::MainView { sub_state, .. } = &mut state.global_state {
+                let MainViewState::EntrypointArguments { values, field_ids, focused_field, suggestions, .. } = sub_state else {
+                    return Task::none()
+                };
+
+                values[index] = value;
+                *focused_field = index;
+                *suggestions = None;
+
+                let field_id = field_ids[index].clone();
+
+                sub_state.validate_argument(index);
+
+                // pending request for previous value is not needed anymore
+                state.argument_suggestions_generation += 1;
+
+                Task::batch([
+                    focus(field_id.clone()),
+                    text_input::move_cursor_to_end(field_id),
+                ])
+            } else {
+                Task::none()
+            }
+        }
+        AppMsg::SubmitEntrypointArguments => {
+            let GlobalState::MainView { sub_state, .. } = &mut state.global_state else {
+                return Task::none()
+            };
+
+            if let Some(focus_invalid_field) = sub_state.validate_all_arguments() {
+                return focus_invalid_field
+            }
+
+            let MainViewState::EntrypointArguments { search_result, values, .. } = sub_state else {
+                return Task::none()
+            };
+
+            match parse_entrypoint_arguments(&search_result.entrypoint_arguments, values) {
+                Ok(arguments) => {
+                    let msg = AppMsg::RunCommand {
+                        plugin_id: search_result.plugin_id.clone(),
+                        entrypoint_id: search_result.entrypoint_id.clone(),
+                        arguments,
+                    };
+
+                    Task::batch([
+                        Task::done(msg),
+                        Task::done(AppMsg::ResetMainViewState)
+                    ])
+                }
+                Err(err) => {
+                    // each argument is already valid at this point
+                    tracing::error!("Unable to parse entrypoint arguments: {:?}", err);
+
+                    Task::none()
+                }
+            }
+        }
+        AppMsg::AddAlias { search_result } => {
+            let GlobalState::MainView { sub_state, .. } = &mut state.global_state else {
+                return Task::none()
+            };
+
+            let focus_field = MainViewState::add_alias(sub_state, search_result);
+
+            // alias field replaces action panel, focus goes back to search field when it is closed
+            state.overlays.close(Overlay::ActionPanel);
+
+            Task::batch([
+                state.open_overlay(Overlay::AddAlias),
+                focus_field,
+            ])
+        }
+        AppMsg::AliasChanged { value: new_value } => {
+            if let GlobalState::MainView { sub_state: MainViewState::AddAlias { value, .. }, .. } = &mut state.global_state {
+                *value = new_value;
+            }
+
+            Task::none()
+        }
+        AppMsg::SubmitAlias => {
+            let GlobalState::MainView { sub_state, .. } = &mut state.global_state else {
+                return Task::none()
+            };
+
+            let MainViewState::AddAlias { search_result, value, .. } = sub_state else {
+                return Task::none()
+            };
+
+            let alias = value.trim().to_string();
+
+            let set_aliases = if alias.is_empty() {
+                Task::none()
+            } else {
+                let mut aliases = search_result.aliases.clone();
+                aliases.push(alias);
+
+                state.set_search_result_aliases(search_result.plugin_id.clone(), search_result.entrypoint_id.clone(), aliases)
+            };
+
+            MainViewState::initial(sub_state);
+
+            Task::batch([
+                set_aliases,
+                state.close_overlay(Overlay::AddAlias),
+            ])
+        }
+        AppMsg::RemoveAliases { search_result } => {
+            state.set_search_result_aliases(search_result.plugin_id, search_result.entrypoint_id, vec![])
+        }
+        AppMsg::SetSearchResults { generation, results, keyword_mode, category } => {
+            // response to older request arrived after response to newer one
+            if generation < state.search_results_generation {
+                return Task::none()
+            }
+
+            state.search_results_generation = generation;
+            state.index_search_results = results;
+            state.merge_search_results();
+
+            let category = match category {
+                Some(category) if state.search_category.is_none() => state.enter_search_category(category),
+                _ => Task::none()
+            };
+
+            let keyword_mode = match keyword_mode {
+                Some(keyword_mode) if state.search_keyword_mode.is_none() => state.enter_search_keyword_mode(keyword_mode),
+                _ => Task::none()
+            };
+
+            if state.submit_after_search.is_some_and(|submit_generation| submit_generation <= generation) {
+                state.submit_after_search = None;
+
+                return Task::batch([
+                    category,
+                    keyword_mode,
+                    state.global_state.primary(&state.client_context, &state.search_results)
+                ])
+            }
+
+            if state.unique_match_generation == Some(generation) {
+                state.unique_match_generation = None;
+
+                if let Some(search_result) = state.unique_match() {
+                    return Task::batch([
+                        category,
+                        keyword_mode,
+                        Task::done(AppMsg::RunSearchItemAction(search_result, None))
+                    ])
+                }
+            }
+
+            Task::batch([
+                category,
+                keyword_mode,
+            ])
+        }
+        AppMsg::ExitSearchKeywordMode => state.exit_search_keyword_mode(),
+        AppMsg::SetSearchCategory { category } => state.set_search_category(category),
+        AppMsg::CycleSearchCategory => {
+            let next = match state.search_category {
+                None => SearchResultCategory::ALL.first().copied(),
+                Some(current) => {
+                    SearchResultCategory::ALL.iter()
+                        .skip_while(|category| **category != current)
+                        .nth(1)
+                        .copied()
+                }
+            };
+
+            state.set_search_category(next)
+        }
+        AppMsg::AppendSearchResults { generation, plugin_id, results, late } => {
+            // results are for older prompt
+            if generation != state.search_generation {
+                return Task::none()
+            }
+
+            tracing::debug!("received {} search results from plugin {:?}, late: {}", results.len(), plugin_id, late);
+
+            if state.plugin_search_results_generation != generation {
+                state.plugin_search_results_generation = generation;
+                state.plugin_search_results.clear();
+                state.late_plugin_search_results.clear();
+            }
+
+            if late {
+                state.late_plugin_search_results.extend(results);
+            } else {
+                state.plugin_search_results.extend(results);
+            }
+
+            // otherwise merged when results from search index arrive
+            if state.search_results_generation == generation {
+                state.merge_search_results();
+            }
+
+            Task::none()
+        }
+        AppMsg::RenderPluginUI {
+            plugin_id,
+            plugin_name,
+            entrypoint_id,
+            entrypoint_name,
+            render_location,
+            view_stack_depth,
+            container,
+            images
+        } => {
+            if let UiRenderLocation::InlineView = render_location {
+                let timed_out = state.inline_view_deadline
+                    .is_some_and(|deadline| Instant::now() > deadline);
+
+                if timed_out && !state.client_context.has_inline_view(&plugin_id) {
+                    tracing::debug!("inline view of plugin {:?} was rendered after timeout, ignoring", plugin_id);
+
+                    return Task::none();
+                }
+            }
+
+            let has_children = container.content.is_some();
+
+            let missing_images = state.client_context.missing_images(&images);
+
+            // state of main window is not affected by renders of detached view
+            let handle_render = match state.client_context.get_mut_entrypoint_detached_view(&plugin_id, &entrypoint_id) {
+                Some(detached_view) if render_location == UiRenderLocation::View => {
+                    detached_view.plugin_view_data.view_stack_depth = view_stack_depth;
+
+                    Task::none()
+                }
+                _ => {
+                    Task::done(AppMsg::HandleRenderPluginUI {
+                        view_stack_depth,
+                        has_children,
+                        render_location,
+                    })
+                }
+            };
+
+            Task::batch([
+                state.load_images(missing_images),
+                Task::done(state.client_context.render_ui(
+                    render_location,
+                    container,
+                    images,
+                    &plugin_id,
+                    &plugin_name,
+                    &entrypoint_id,
+                    &entrypoint_name,
+                )),
+                handle_render,
+            ])
+        }
+        AppMsg::HandleRenderPluginUI {
+            view_stack_depth,
+            has_children,
+            render_location
+        } => {
+            match &mut state.global_state {
+                GlobalState::MainView { pending_plugin_view_data, focused_search_result, pending_plugin_view_loading_bar, .. } => {
+
+                    if let LoadingBarState::Pending = pending_plugin_view_loading_bar {
+                        *pending_plugin_view_loading_bar = LoadingBarState::Off;
+                    }
+
+                    if has_children {
+                        if let UiRenderLocation::InlineView = render_location {
+                            focused_search_result.unfocus();
+                        }
+                    }
+
+                    let command = match pending_plugin_view_data {
+                        None => Task::none(),
+                        Some(pending_plugin_view_data) => {
+                            let pending_plugin_view_data = pending_plugin_view_data.clone();
+                            GlobalState::plugin(
+                                &mut state.global_state,
+                                PluginViewData {
+                                    view_stack_depth,
+                                    ..pending_plugin_view_data
+                                },
+                            )
+                        }
+                    };
+
+                    if let UiRenderLocation::InlineView = render_location {
+                        Task::batch([
+                            command,
+                            state.inline_view_shortcuts(),
+                            state.inline_view_order(),
+                        ])
+                    } else {
+                        command
+                    }
+                }
+                GlobalState::ErrorView { .. } => Task::none(),
+                GlobalState::PluginView { plugin_view_data, ..} => {
+                    plugin_view_data.view_stack_depth = view_stack_depth;
+
+                    Task::none()
+                }
+            }
+        }
+        AppMsg::IcedEvent(window_id, Event::Keyboard(event)) => {
+            if state.client_context.get_detached_view(window_id).is_some() {
+                return state.handle_detached_view_keyboard_event(window_id, event)
+            }
+
+            let Some(main_window_id) = state.main_window_id else {
+                return Task::none()
+            };
+
+            if window_id != main_window_id {
+                return Task::none()
+            }
+
+            match event {
+                keyboard::Event::KeyPressed { key, modifiers, physical_key, text, .. } => {
+                    tracing::debug!("Key pressed: {:?}. shift: {:?} control: {:?} alt: {:?} meta: {:?}", key, modifiers.shift(), modifiers.control(), modifiers.alt(), modifiers.logo());
+                    match key {
+                        Key::Named(Named::ArrowUp) if state.can_recall_prompt_history() => state.recall_previous_prompt(),
+                        Key::Named(Named::ArrowDown) if state.is_recalling_prompt_history() => state.recall_next_prompt(),
                         Key::Named(Named::ArrowUp) => state.global_state.up(&state.client_context, &state.search_results),
                         Key::Named(Named::ArrowDown) => state.global_state.down(&state.client_context, &state.search_results),
                         Key::Named(Named::ArrowLeft) => state.global_state.left(&state.client_context, &state.search_results),
                         Key::Named(Named::ArrowRight) => state.global_state.right(&state.client_context, &state.search_results),
-                        Key::Named(Named::Escape) => state.global_state.back(&state.client_context),
+                        // only the topmost overlay is closed, view behind it stays as it is
+                        Key::Named(Named::Escape) => {
+                            match state.top_modal_overlay() {
+                                Some(overlay) => state.dismiss_overlay(overlay),
+                                None => state.escape(),
+                            }
+                        }
                         Key::Named(Named::Tab) if !modifiers.shift() => state.global_state.next(&state.client_context),
                         Key::Named(Named::Tab) if modifiers.shift() => state.global_state.previous(&state.client_context),
+                        Key::Named(Named::Enter) if modifiers.control() && !modifiers.shift() && !modifiers.alt() && !modifiers.logo() && state.can_resume_recently_used() => {
+                            state.resume_recently_used()
+                        }
                         Key::Named(Named::Enter) => {
                             if modifiers.logo() || modifiers.alt() || modifiers.control() {
                                 Task::none() // to avoid not wanted "enter" presses
@@ -753,17 +1584,22 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                     // for main view, also fired in cases where main text field is not focused
                                     state.global_state.secondary(&state.client_context, &state.search_results)
                                 } else {
-                                    state.global_state.primary(&state.client_context, &state.search_results)
+                                    state.submit_prompt()
                                 }
                             }
                         },
+                        // key press consumed by input method while composing, composed text arrives in separate event
+                        Key::Named(Named::Process) => Task::none(),
                         Key::Named(Named::Backspace) => {
                             match &mut state.global_state {
                                 GlobalState::MainView { sub_state, search_field_id, .. } => {
                                     match sub_state {
                                         MainViewState::None => AppModel::backspace_prompt(&mut state.prompt, search_field_id.clone()),
                                         MainViewState::SearchResultActionPanel { .. } => Task::none(),
-                                        MainViewState::InlineViewActionPanel { .. } => Task::none()
+                                        MainViewState::InlineViewActionPanel { .. } => Task::none(),
+                                        MainViewState::PromptHistory { .. } => Task::none(),
+                                        MainViewState::EntrypointArguments { .. } => Task::none(),
+                                        MainViewState::AddAlias { .. } => Task::none(),
                                     }
                                 }
                                 GlobalState::ErrorView { .. } => Task::none(),
@@ -782,6 +1618,11 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                 return Task::none()
                             };
 
+                            // available in every view, so that window can be pinned before running plugin actions
+                            if let Some(PhysicalShortcut { physical_key: PhysicalKey::KeyP, modifier_shift: true, modifier_control: true, modifier_alt: false, modifier_meta: false }) = physical_key_model(physical_key, modifiers) {
+                                return Task::done(AppMsg::ToggleWindowPin)
+                            }
+
                             match &mut state.global_state {
                                 GlobalState::MainView { sub_state, search_field_id, focused_search_result, .. } => {
                                     match sub_state {
@@ -790,6 +1631,21 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                                 Some(PhysicalShortcut { physical_key: PhysicalKey::KeyK, modifier_shift: false, modifier_control: false, modifier_alt: true, modifier_meta: false }) => {
                                                     Task::perform(async {}, |_| AppMsg::ToggleActionPanel { keyboard: true })
                                                 }
+                                                Some(PhysicalShortcut { physical_key: PhysicalKey::KeyR, modifier_shift: false, modifier_control: true, modifier_alt: false, modifier_meta: false }) if state.prompt_history.enabled() => {
+                                                    Task::done(AppMsg::TogglePromptHistory)
+                                                }
+                                                Some(PhysicalShortcut { physical_key: PhysicalKey::KeyP, modifier_shift: false, modifier_control: false, modifier_alt: true, modifier_meta: false }) => {
+                                                    Task::done(AppMsg::TogglePreviewPane)
+                                                }
+                                                Some(PhysicalShortcut { physical_key: PhysicalKey::KeyF, modifier_shift: false, modifier_control: false, modifier_alt: true, modifier_meta: false }) => {
+                                                    Task::done(AppMsg::CycleSearchCategory)
+                                                }
+                                                Some(PhysicalShortcut { physical_key: PhysicalKey::KeyZ, modifier_shift: false, modifier_control: true, modifier_alt: false, modifier_meta: false }) => {
+                                                    Task::done(AppMsg::Undo)
+                                                }
+                                                Some(PhysicalShortcut { physical_key: PhysicalKey::KeyZ, modifier_shift: true, modifier_control: true, modifier_alt: false, modifier_meta: false }) => {
+                                                    Task::done(AppMsg::Redo)
+                                                }
                                                 Some(PhysicalShortcut { physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta }) => {
                                                     if modifier_shift || modifier_control || modifier_alt || modifier_meta {
                                                         if let Some(search_item) = focused_search_result.get(&state.search_results) {
@@ -874,17 +1730,52 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                                 _ => Task::none()
                                             }
                                         }
+                                        MainViewState::PromptHistory { .. } => {
+                                            match physical_key_model(physical_key, modifiers) {
+                                                Some(PhysicalShortcut { physical_key: PhysicalKey::KeyR, modifier_shift: false, modifier_control: true, modifier_alt: false, modifier_meta: false }) => {
+                                                    Task::done(AppMsg::TogglePromptHistory)
+                                                }
+                                                _ => Task::none()
+                                            }
+                                        }
+                                        MainViewState::EntrypointArguments { .. } => Task::none(),
+                                        MainViewState::AddAlias { .. } => Task::none(),
                                     }
                                 }
                                 GlobalState::ErrorView { .. } => Task::none(),
-                                GlobalState::PluginView { sub_state, .. } => {
+                                GlobalState::PluginView { sub_state, plugin_view_data, .. } => {
                                     match physical_key_model(physical_key, modifiers) {
                                         Some(PhysicalShortcut { physical_key: PhysicalKey::KeyK, modifier_shift: false, modifier_control: false, modifier_alt: true, modifier_meta: false }) => {
                                             Task::perform(async {}, |_| AppMsg::ToggleActionPanel { keyboard: true })
                                         }
-                                        Some(PhysicalShortcut { physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta }) => {
+                                        Some(PhysicalShortcut { physical_key: PhysicalKey::Space, modifier_shift: false, modifier_control: true, modifier_alt: false, modifier_meta: false }) => {
+                                            state.client_context.toggle_focused_list_item_selection();
+
+                                            Task::none()
+                                        }
+                                        Some(PhysicalShortcut { physical_key: PhysicalKey::KeyI, modifier_shift: true, modifier_control: true, modifier_alt: false, modifier_meta: false }) if is_dev_plugin(&plugin_view_data.plugin_id) => {
+                                            Task::done(AppMsg::ToggleWidgetInspector)
+                                        }
+                                        Some(PhysicalShortcut { physical_key: PhysicalKey::KeyB, modifier_shift: true, modifier_control: true, modifier_alt: false, modifier_meta: false }) => {
+                                            Task::done(AppMsg::SaveBugReport)
+                                        }
+                                        Some(PhysicalShortcut { physical_key: PhysicalKey::KeyD, modifier_shift: true, modifier_control: true, modifier_alt: false, modifier_meta: false }) => {
+                                            Task::done(AppMsg::DetachPluginView)
+                                        }
+                                        Some(shortcut) => {
+                                            let PhysicalShortcut { physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta } = shortcut.clone();
+
                                             if modifier_shift || modifier_control || modifier_alt || modifier_meta {
-                                                state.handle_plugin_view_keyboard_event(physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta)
+                                                match state.client_context.selected_list_items_action(&plugin_view_data.action_shortcuts, &shortcut) {
+                                                    Some(widget_id) => {
+                                                        Task::done(AppMsg::RunPluginAction {
+                                                            render_location: UiRenderLocation::View,
+                                                            plugin_id: state.client_context.get_view_plugin_id(),
+                                                            widget_id,
+                                                        })
+                                                    }
+                                                    None => state.handle_plugin_view_keyboard_event(physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta)
+                                                }
                                             } else {
                                                 match sub_state {
                                                     PluginViewState::None => {
@@ -930,24 +1821,100 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             }
 
             if state.wayland {
-                state.hide_window()
+                if state.hides_on_focus_loss() {
+                    state.hide_window()
+                } else {
+                    Task::none()
+                }
             } else {
                 state.on_unfocused()
             }
         }
+        AppMsg::IcedEvent(window_id, Event::Window(window::Event::Closed)) => {
+            state.on_detached_window_closed(window_id)
+        }
         AppMsg::IcedEvent(_, _) => Task::none(),
+        AppMsg::DetachedWidgetEvent { window_id, widget_event } => {
+            state.handle_detached_view_event(window_id, widget_event)
+        }
         AppMsg::WidgetEvent { widget_event: ComponentWidgetEvent::Noop, .. } => Task::none(),
         AppMsg::WidgetEvent { widget_event: ComponentWidgetEvent::PreviousView, .. } => state.global_state.back(&state.client_context),
+        AppMsg::WidgetEvent { widget_event: ComponentWidgetEvent::FocusListItemMouse { index }, .. } => {
+            // action panel is opened for the item that was focused
+            if let GlobalState::PluginView { sub_state: PluginViewState::None, .. } = &state.global_state {
+                state.client_context.focus_list_item_mouse(index);
+            }
+
+            Task::none()
+        }
+        AppMsg::WidgetEvent { widget_event: ComponentWidgetEvent::ListItemContextMenu { index }, .. } => {
+            match &state.global_state {
+                GlobalState::PluginView { sub_state: PluginViewState::None, .. } => {
+                    state.client_context.focus_list_item_mouse(index);
+
+                    Task::done(AppMsg::ToggleActionPanel { keyboard: false })
+                }
+                // the same as click outside of action panel
+                GlobalState::PluginView { sub_state: PluginViewState::ActionPanel { .. }, .. } => {
+                    Task::done(AppMsg::ToggleActionPanel { keyboard: false })
+                }
+                _ => Task::none()
+            }
+        }
+        AppMsg::WidgetEvent { widget_event: ComponentWidgetEvent::ScrollList { offset }, .. } => {
+            state.client_context.list_scrolled(offset);
+
+            Task::none()
+        }
+        AppMsg::WidgetEvent { widget_event: ComponentWidgetEvent::FocusActionMouse { index }, .. } => {
+            Task::done(AppMsg::FocusActionPanelItemMouse { index })
+        }
+        AppMsg::WidgetEvent { widget_event: ComponentWidgetEvent::ScrollActionPanel { offset }, .. } => {
+            Task::done(AppMsg::ScrollActionPanel { offset })
+        }
         AppMsg::WidgetEvent { widget_event, plugin_id, render_location } => {
             state.handle_plugin_event(widget_event, plugin_id, render_location)
         }
-        AppMsg::Noop => Task::none(),
-        AppMsg::FontLoaded(result) => {
-            result.expect("unable to load font");
-            Task::none()
+        AppMsg::AccessibilityAction(action) => {
+            accessibility::handle_accessibility_action(state, action)
         }
-        AppMsg::ShowWindow => state.show_window(),
+        AppMsg::StartDrag { data, icon } => {
+            let Some(main_window_id) = state.main_window_id else {
+                return Task::none()
+            };
+
+            drag::start_drag(main_window_id, data, icon)
+        }
+        AppMsg::Noop => Task::none(),
+        AppMsg::FontLoaded(result) => {
+            result.expect("unable to load font");
+            Task::none()
+        }
+        AppMsg::ShowWindow => state.show_window(true),
         AppMsg::HideWindow => state.hide_window(),
+        AppMsg::ToggleWindow => {
+            match state.main_window_id {
+                None => state.show_window(true),
+                Some(_) => state.hide_window()
+            }
+        }
+        AppMsg::Quit => iced::exit(),
+        AppMsg::PreviewTheme { theme } => {
+            GauntletComplexTheme::preview(theme.map(|theme| theme.into()));
+
+            Task::none()
+        }
+        AppMsg::ShowWindowWithView { plugin_id, plugin_name, entrypoint_id, entrypoint_name } => {
+            Task::batch([
+                state.show_window(false),
+                Task::done(AppMsg::OpenView {
+                    plugin_id,
+                    plugin_name,
+                    entrypoint_id,
+                    entrypoint_name,
+                })
+            ])
+        }
         AppMsg::ShowPreferenceRequiredView {
             plugin_id,
             entrypoint_id,
@@ -964,40 +1931,141 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 },
             )
         }
-        AppMsg::ShowPluginErrorView { plugin_id, entrypoint_id, .. } => {
-            GlobalState::error(
+        AppMsg::ShowPluginErrorView { plugin_id, entrypoint_id, render_location } => {
+            let close_detached_view = match render_location {
+                UiRenderLocation::InlineView => Task::none(),
+                UiRenderLocation::View => state.close_failed_detached_view(&plugin_id, &entrypoint_id),
+            };
+
+            let show_error = GlobalState::error(
                 &mut state.global_state,
                 ErrorViewData::PluginError {
                     plugin_id,
                     entrypoint_id,
+                    panic_message: None,
+                },
+            );
+
+            Task::batch([close_detached_view, show_error])
+        }
+        AppMsg::ShowRenderErrorView { plugin_id, entrypoint_id, message, component_path } => {
+            let close_detached_view = state.close_failed_detached_view(&plugin_id, &entrypoint_id);
+
+            let show_error = GlobalState::error(
+                &mut state.global_state,
+                ErrorViewData::RenderError {
+                    plugin_id,
+                    entrypoint_id,
+                    message,
+                    component_path,
+                },
+            );
+
+            Task::batch([close_detached_view, show_error])
+        }
+        AppMsg::ShowRecoveryView { safe_mode, description, crash_report_path } => {
+            let show_window = state.show_window(false);
+
+            let show_error = GlobalState::error(
+                &mut state.global_state,
+                ErrorViewData::Recovery {
+                    safe_mode,
+                    description,
+                    crash_report_path,
+                },
+            );
+
+            Task::batch([show_window, show_error])
+        }
+        AppMsg::ShowConfigError { error } => {
+            GlobalState::error(
+                &mut state.global_state,
+                ErrorViewData::ConfigError {
+                    error,
+                },
+            )
+        }
+        AppMsg::SessionLockChanged { locked } => {
+            state.session_locked = locked;
+
+            if locked {
+                state.hide_window()
+            } else {
+                Task::none()
+            }
+        }
+        AppMsg::ConfigReloaded => {
+            // window position and focus loss behaviour are read every time they are used, locale is only applied after restart
+            let frontend_config = read_frontend_config();
+
+            state.search_trigger = frontend_config.search_trigger;
+            state.escape_behavior = frontend_config.escape_behavior;
+            state.type_ahead = frontend_config.type_ahead;
+            state.session_restore = frontend_config.session_restore;
+            state.client_context.set_image_cache_config(&frontend_config.image_cache);
+
+            GauntletComplexTheme::init();
+
+            Task::none()
+        }
+        AppMsg::ShowDatabaseRecoveryView { reason, broken_database_path, restored_backup_path } => {
+            let show_window = state.show_window(false);
+
+            let show_error = GlobalState::error(
+                &mut state.global_state,
+                ErrorViewData::DatabaseRecovery {
+                    reason,
+                    broken_database_path,
+                    restored_backup_path,
+                },
+            );
+
+            Task::batch([show_window, show_error])
+        }
+        AppMsg::ShowWhatsNewView { version, changelog } => {
+            GlobalState::error(
+                &mut state.global_state,
+                ErrorViewData::WhatsNew {
+                    version,
+                    changelog,
                 },
             )
         }
-        AppMsg::ShowBackendError(err) => {
+        AppMsg::ShowBackendError(err, retry) => {
             GlobalState::error(
                 &mut state.global_state,
                 match err {
-                    BackendForFrontendApiError::TimeoutError => ErrorViewData::BackendTimeout,
-                    BackendForFrontendApiError::Internal { display } => ErrorViewData::UnknownError { display }
+                    BackendForFrontendApiError::TimeoutError => ErrorViewData::BackendTimeout { retry },
+                    BackendForFrontendApiError::Internal { error } => ErrorViewData::BackendError { error }
                 }
             )
         }
+        AppMsg::RetryBackendRequest(msg) => {
+            Task::batch([
+                GlobalState::initial(&mut state.global_state),
+                Task::done(*msg),
+            ])
+        }
         AppMsg::OpenSettingsPreferences { plugin_id, entrypoint_id, } => {
             state.open_settings_window_preferences(plugin_id, entrypoint_id)
         }
-        AppMsg::OnOpenView { action_shortcuts } => {
+        AppMsg::OnOpenView { action_shortcuts, interactive, style } => {
+            state.client_context.set_view_style(style);
+
             match &mut state.global_state {
                 GlobalState::MainView { pending_plugin_view_data, .. } => {
                     match pending_plugin_view_data {
                         None => {}
                         Some(pending_plugin_view_data) => {
                             pending_plugin_view_data.action_shortcuts = action_shortcuts;
+                            pending_plugin_view_data.interactive = interactive;
                         }
                     };
                 }
                 GlobalState::ErrorView { .. } => { },
                 GlobalState::PluginView { plugin_view_data, ..} => {
                     plugin_view_data.action_shortcuts = action_shortcuts;
+                    plugin_view_data.interactive = interactive;
                 }
             }
 
@@ -1048,44 +2116,72 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             ).then(|_| iced::exit())
         }
         AppMsg::ToggleActionPanel { keyboard } => {
-            match &mut state.global_state {
+            // overlay that was opened or closed
+            let change = match &mut state.global_state {
                 GlobalState::MainView { sub_state, focused_search_result, .. } => {
                     match sub_state {
                         MainViewState::None => {
                             if let Some(search_item) = focused_search_result.get(&state.search_results) {
-                                if search_item.entrypoint_actions.len() > 0 {
+                                if search_item.secondary_action_count() > 0 {
                                     MainViewState::search_result_action_panel(sub_state, keyboard);
+
+                                    Some((Overlay::ActionPanel, true))
+                                } else {
+                                    None
                                 }
                             } else {
                                 if let Some(_) = state.client_context.get_first_inline_view_container() {
                                     MainViewState::inline_result_action_panel(sub_state, keyboard);
+
+                                    Some((Overlay::ActionPanel, true))
+                                } else {
+                                    None
                                 }
                             }
                         }
                         MainViewState::SearchResultActionPanel { .. } => {
                             MainViewState::initial(sub_state);
+
+                            Some((Overlay::ActionPanel, false))
                         }
                         MainViewState::InlineViewActionPanel { .. } => {
                             MainViewState::initial(sub_state);
+
+                            Some((Overlay::ActionPanel, false))
+                        }
+                        MainViewState::PromptHistory { .. } => {
+                            MainViewState::initial(sub_state);
+
+                            Some((Overlay::PromptHistory, false))
                         }
+                        MainViewState::EntrypointArguments { .. } => None,
+                        MainViewState::AddAlias { .. } => None,
                     }
                 }
-                GlobalState::ErrorView { .. } => { },
+                GlobalState::ErrorView { .. } => None,
                 GlobalState::PluginView { sub_state, .. } => {
                     state.client_context.toggle_action_panel();
 
                     match sub_state {
                         PluginViewState::None => {
-                            PluginViewState::action_panel(sub_state, keyboard)
+                            PluginViewState::action_panel(sub_state, keyboard);
+
+                            Some((Overlay::ActionPanel, true))
                         }
                         PluginViewState::ActionPanel { .. } => {
-                            PluginViewState::initial(sub_state)
+                            PluginViewState::initial(sub_state);
+
+                            Some((Overlay::ActionPanel, false))
                         }
                     }
                 }
-            }
+            };
 
-            Task::none()
+            match change {
+                Some((overlay, true)) => state.open_overlay(overlay),
+                Some((overlay, false)) => state.close_overlay(overlay),
+                None => Task::none()
+            }
         }
         AppMsg::OnPrimaryActionMainViewNoPanelKeyboardWithoutFocus => {
             Task::done(AppMsg::OnAnyActionMainViewNoPanelKeyboardAtIndex { index: 0 })
@@ -1100,10 +2196,27 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             Task::done(AppMsg::RunSearchItemAction(search_result, Some(0)))
         }
         AppMsg::OnAnyActionMainViewSearchResultPanelKeyboardWithFocus { search_result, widget_id } => {
+            let entrypoint_action_count = search_result.entrypoint_actions.len();
+
+            let copy_item_count = search_result.entrypoint_copy_items.len();
+
+            // copy actions go after actions provided by plugin, alias actions are the last ones
             let run_action_command = if widget_id == 0 {
                 Task::done(AppMsg::RunSearchItemAction(search_result, None))
-            } else {
+            } else if widget_id <= entrypoint_action_count {
                 Task::done(AppMsg::RunSearchItemAction(search_result, Some(widget_id - 1)))
+            } else if widget_id <= entrypoint_action_count + copy_item_count {
+                match search_result.entrypoint_copy_items.get(widget_id - entrypoint_action_count - 1) {
+                    Some(item) => Task::done(AppMsg::CopySearchResultItem { text: item.value.clone() }),
+                    None => Task::none()
+                }
+            } else {
+                match widget_id - entrypoint_action_count - copy_item_count - 1 {
+                    // action panel is replaced by alias field instead of being closed
+                    0 => return Task::done(AppMsg::AddAlias { search_result }),
+                    1 => Task::done(AppMsg::RemoveAliases { search_result }),
+                    _ => Task::none()
+                }
             };
 
             Task::batch([
@@ -1193,6 +2306,15 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         MainViewState::InlineViewActionPanel { .. } => {
                             Task::none()
                         }
+                        MainViewState::PromptHistory { .. } => {
+                            Task::none()
+                        }
+                        MainViewState::EntrypointArguments { .. } => {
+                            Task::none()
+                        }
+                        MainViewState::AddAlias { .. } => {
+                            Task::none()
+                        }
                     }
                 }
                 GlobalState::ErrorView { .. } => Task::none(),
@@ -1207,16 +2329,39 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             })
         }
         AppMsg::OpenPluginView(plugin_id, entrypoint_id) => {
-            state.open_plugin_view(plugin_id, entrypoint_id)
+            state.open_plugin_view(plugin_id, entrypoint_id, None)
+        }
+        AppMsg::ClosePluginView(plugin_id, entrypoint_id) => {
+            state.close_plugin_view(plugin_id, entrypoint_id)
         }
-        AppMsg::ClosePluginView(plugin_id) => {
-            state.close_plugin_view(plugin_id)
+        AppMsg::DetachPluginView => {
+            state.detach_plugin_view()
+        }
+        AppMsg::PopPluginView(plugin_id, entrypoint_id) => {
+            state.pop_plugin_view(plugin_id, entrypoint_id)
         }
         AppMsg::InlineViewShortcuts { shortcuts } => {
             state.client_context.set_inline_view_shortcuts(shortcuts);
 
             Task::none()
         }
+        AppMsg::InlineViewOrder { plugin_ids } => {
+            state.client_context.set_inline_view_order(plugin_ids);
+
+            Task::none()
+        }
+        AppMsg::ImagesLoaded { images } => {
+            state.client_context.insert_images(images);
+
+            Task::none()
+        }
+        AppMsg::CopySearchResultItem { text } => {
+            Task::batch([
+                state.copy_to_clipboard(text),
+                state.hide_window_after_action(false),
+                Task::done(AppMsg::ShowHud { display: tr("main-copied-to-clipboard") }),
+            ])
+        }
         AppMsg::ShowHud { display } => {
             state.hud_display = Some(display);
 
@@ -1225,12 +2370,160 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 state.wayland,
             )
         }
+        AppMsg::ShowUndoToast { description } => {
+            state.undo_toast = Some(description);
+            state.undo_toast_generation += 1;
+            state.overlays.open(Overlay::UndoToast, FocusTarget::None);
+
+            let generation = state.undo_toast_generation;
+
+            Task::perform(async move {
+                tokio::time::sleep(UNDO_TOAST_DURATION).await;
+
+                AppMsg::HideUndoToast { generation }
+            }, std::convert::identity)
+        }
+        AppMsg::HideUndoToast { generation } => {
+            // toast for newer action is still shown
+            if state.undo_toast_generation == generation {
+                state.undo_toast = None;
+                state.overlays.close(Overlay::UndoToast);
+            }
+
+            Task::none()
+        }
+        AppMsg::Undo => {
+            state.undo_toast = None;
+            state.overlays.close(Overlay::UndoToast);
+
+            state.undo()
+        }
+        AppMsg::Redo => {
+            state.redo()
+        }
+        AppMsg::ToggleWidgetInspector => {
+            state.widget_inspector = !state.widget_inspector;
+
+            Task::none()
+        }
+        AppMsg::SaveBugReport => {
+            let GlobalState::PluginView { .. } = &state.global_state else {
+                return Task::none()
+            };
+
+            let Some(window_id) = state.main_window_id else {
+                return Task::none()
+            };
+
+            let view_container = state.client_context.get_view_container();
+
+            let plugin_id = view_container.get_plugin_id();
+            let entrypoint_id = view_container.get_entrypoint_id();
+            let widget_tree = widget_inspector_text(state, view_container);
+
+            window::screenshot(window_id)
+                .map(move |screenshot| AppMsg::BugReportScreenshotDone {
+                    plugin_id: plugin_id.clone(),
+                    entrypoint_id: entrypoint_id.clone(),
+                    widget_tree: widget_tree.clone(),
+                    screenshot,
+                })
+        }
+        AppMsg::BugReportScreenshotDone { plugin_id, entrypoint_id, widget_tree, screenshot } => {
+            state.save_bug_report(plugin_id, entrypoint_id, widget_tree, screenshot)
+        }
+        AppMsg::ShowRenderTiming { plugin_id, entrypoint_id, js_render_time_ms, serialization_time_ms } => {
+            state.render_timing = Some(RenderTiming {
+                plugin_id,
+                entrypoint_id,
+                js_render_time_ms,
+                serialization_time_ms,
+            });
+
+            Task::none()
+        }
+        AppMsg::FocusSearchResultMouse { index } => {
+            // action panel and other overlays are shown for the item that was focused
+            if let GlobalState::MainView { focused_search_result, sub_state: MainViewState::None, .. } = &mut state.global_state {
+                if index < state.search_results.len() {
+                    focused_search_result.focus_visible(index);
+                }
+            }
+
+            Task::none()
+        }
+        AppMsg::OpenSearchResultContextMenu { index } => {
+            let GlobalState::MainView { focused_search_result, sub_state, .. } = &mut state.global_state else {
+                return Task::none()
+            };
+
+            match sub_state {
+                MainViewState::None if index < state.search_results.len() => {
+                    focused_search_result.focus_visible(index);
+
+                    Task::done(AppMsg::ToggleActionPanel { keyboard: false })
+                }
+                // the same as click outside of action panel
+                MainViewState::SearchResultActionPanel { .. } | MainViewState::InlineViewActionPanel { .. } => {
+                    Task::done(AppMsg::ToggleActionPanel { keyboard: false })
+                }
+                _ => Task::none()
+            }
+        }
+        AppMsg::ScrollSearchResults { offset } => {
+            if let GlobalState::MainView { focused_search_result, .. } = &mut state.global_state {
+                focused_search_result.scrolled(offset);
+            }
+
+            Task::none()
+        }
+        AppMsg::FocusActionPanelItemMouse { index } => {
+            match &mut state.global_state {
+                GlobalState::MainView { sub_state, .. } => {
+                    match sub_state {
+                        MainViewState::SearchResultActionPanel { focused_action_item } => focused_action_item.focus_visible(index),
+                        MainViewState::InlineViewActionPanel { focused_action_item } => focused_action_item.focus_visible(index),
+                        MainViewState::PromptHistory { focused_history_item, .. } => focused_history_item.focus_visible(index),
+                        MainViewState::None | MainViewState::EntrypointArguments { .. } | MainViewState::AddAlias { .. } => {}
+                    }
+                }
+                GlobalState::PluginView { sub_state: PluginViewState::ActionPanel { focused_action_item }, .. } => {
+                    focused_action_item.focus_visible(index)
+                }
+                GlobalState::PluginView { .. } | GlobalState::ErrorView { .. } => {}
+            }
+
+            Task::none()
+        }
+        AppMsg::ScrollActionPanel { offset } => {
+            match &mut state.global_state {
+                GlobalState::MainView { sub_state, .. } => {
+                    match sub_state {
+                        MainViewState::SearchResultActionPanel { focused_action_item } => focused_action_item.scrolled(offset),
+                        MainViewState::InlineViewActionPanel { focused_action_item } => focused_action_item.scrolled(offset),
+                        MainViewState::PromptHistory { focused_history_item, .. } => focused_history_item.scrolled(offset),
+                        MainViewState::None | MainViewState::EntrypointArguments { .. } | MainViewState::AddAlias { .. } => {}
+                    }
+                }
+                GlobalState::PluginView { sub_state: PluginViewState::ActionPanel { focused_action_item }, .. } => {
+                    focused_action_item.scrolled(offset)
+                }
+                GlobalState::PluginView { .. } | GlobalState::ErrorView { .. } => {}
+            }
+
+            Task::none()
+        }
         AppMsg::ResetMainViewState => {
             match &mut state.global_state {
                 GlobalState::MainView { sub_state, .. } => {
                     MainViewState::initial(sub_state);
 
-                    Task::none()
+                    Task::batch([
+                        state.close_overlay(Overlay::ActionPanel),
+                        state.close_overlay(Overlay::PromptHistory),
+                        state.close_overlay(Overlay::EntrypointArguments),
+                        state.close_overlay(Overlay::AddAlias),
+                    ])
                 }
                 GlobalState::ErrorView { .. } => Task::none(),
                 GlobalState::PluginView { .. } => Task::none(),
@@ -1253,6 +2546,11 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 }
 
                 if let Some(shortcut) = shortcut {
+                    // registration is done using XGrabKey which only sees key presses in X11 windows
+                    if state.wayland {
+                        return Err(anyhow!("Global shortcut is not supported in Wayland session, please bind 'gauntlet open' CLI command to a shortcut in compositor settings"))
+                    }
+
                     let hotkey = convert_physical_shortcut_to_hotkey(shortcut);
 
                     *hotkey_guard = Some(hotkey);
@@ -1291,6 +2589,30 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
             Task::none()
         }
+        AppMsg::UpdateActionShortcuts { plugin_id, entrypoint_id, shortcuts } => {
+            let is_same_entrypoint = |data: &PluginViewData| data.plugin_id == plugin_id && data.entrypoint_id == entrypoint_id;
+
+            match &mut state.global_state {
+                GlobalState::MainView { pending_plugin_view_data: Some(pending_plugin_view_data), .. } => {
+                    if is_same_entrypoint(pending_plugin_view_data) {
+                        pending_plugin_view_data.action_shortcuts = shortcuts.clone();
+                    }
+                }
+                GlobalState::PluginView { plugin_view_data, .. } => {
+                    if is_same_entrypoint(plugin_view_data) {
+                        plugin_view_data.action_shortcuts = shortcuts.clone();
+                    }
+                }
+                GlobalState::MainView { .. } | GlobalState::ErrorView { .. } => {}
+            }
+
+            if let Some(detached_view) = state.client_context.get_mut_entrypoint_detached_view(&plugin_id, &entrypoint_id) {
+                detached_view.plugin_view_data.action_shortcuts = shortcuts;
+            }
+
+            // shortcuts of inline view actions could have been changed
+            state.inline_view_shortcuts()
+        }
         AppMsg::PendingPluginViewLoadingBar => {
             if let GlobalState::MainView { pending_plugin_view_loading_bar, .. } = &mut state.global_state {
                 *pending_plugin_view_loading_bar = LoadingBarState::Pending;
@@ -1328,6 +2650,10 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 }
 
 fn view(state: &AppModel, window: window::Id) -> Element<'_, AppMsg> {
+    if let Some(detached_view) = state.client_context.get_detached_view(window) {
+        return view_detached(detached_view)
+    }
+
     match state.main_window_id {
         None => {
             view_hud(state)
@@ -1336,12 +2662,36 @@ fn view(state: &AppModel, window: window::Id) -> Element<'_, AppMsg> {
             if window != main_window_id {
                 view_hud(state)
             } else {
-                view_main(state)
+                Transition::new(view_main(state), state.view_transition_generation, state.view_transition_kind)
+                    .animated(!GauntletComplexTheme::reduced_motion())
+                    .into()
             }
         }
     }
 }
 
+fn view_detached(detached_view: &DetachedView) -> Element<'_, AppMsg> {
+    let DetachedView { window_id, plugin_view_data: PluginViewData { action_shortcuts, .. }, container: view_container } = detached_view;
+
+    // error view is shown in main window on next update, until then show it from here
+    if let Some(panic_message) = view_container.render_panic() {
+        return view_plugin_error(Some(&panic_message))
+    }
+
+    // detached window has no action panel, actions are run by their shortcuts
+    let element = view_container
+        .render_root_widget(&PluginViewState::None, action_shortcuts)
+        .map(|widget_event| AppMsg::DetachedWidgetEvent {
+            window_id: *window_id,
+            widget_event,
+        });
+
+    container(element)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .themed(ContainerStyle::Root)
+}
+
 fn view_hud(state: &AppModel) -> Element<'_, AppMsg> {
     match &state.hud_display {
         Some(hud_display) => {
@@ -1379,6 +2729,70 @@ fn view_hud(state: &AppModel) -> Element<'_, AppMsg> {
     }
 }
 
+fn view_plugin_error<'a>(panic_message: Option<&String>) -> Element<'a, AppMsg> {
+    let description: Element<_> = text(tr("main-plugin-view-error"))
+        .into();
+
+    let description = container(description)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .themed(ContainerStyle::PluginErrorViewTitle);
+
+    let sub_description: Element<_> = text(tr("main-plugin-view-error-report"))
+        .into();
+
+    let sub_description = container(sub_description)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .themed(ContainerStyle::PluginErrorViewDescription);
+
+    let button_label: Element<_> = text(tr("close"))
+        .into();
+
+    let button: Element<_> = button(button_label)
+        .on_press(AppMsg::HideWindow)
+        .into();
+
+    let button = container(button)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .into();
+
+    let mut content = vec![
+        description,
+        sub_description,
+    ];
+
+    if let Some(panic_message) = panic_message {
+        let error_description: Element<_> = text(tr_with("main-plugin-view-error-panic", &[("message", panic_message.clone().into())]))
+            .shaping(Shaping::Advanced)
+            .into();
+
+        let error_description = container(error_description)
+            .width(Length::Fill)
+            .themed(ContainerStyle::PluginErrorViewDescription);
+
+        let error_description = scrollable(error_description)
+            .width(Length::Fill)
+            .into();
+
+        content.push(error_description);
+    }
+
+    content.push(button);
+
+    let content: Element<_> = column(content).into();
+
+    let content: Element<_> = container(content)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .themed(ContainerStyle::Main);
+
+    content
+}
+
 fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
     match &state.global_state {
         GlobalState::ErrorView { error_view } => {
@@ -1388,7 +2802,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                     let (description_text, msg) = match (plugin_preferences_required, entrypoint_preferences_required) {
                         (true, true) => {
                             // TODO do not show "entrypoint" name to user
-                            let description_text = "Before using, plugin and entrypoint preferences need to be specified";
+                            let description_text = tr("main-preferences-required-plugin-and-entrypoint");
                             // note:
                             // we open plugin view and not entrypoint even though both need to be specified
                             let msg = AppMsg::OpenSettingsPreferences { plugin_id: plugin_id.clone(), entrypoint_id: None };
@@ -1396,12 +2810,12 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         }
                         (false, true) => {
                             // TODO do not show "entrypoint" name to user
-                            let description_text = "Before using, entrypoint preferences need to be specified";
+                            let description_text = tr("main-preferences-required-entrypoint");
                             let msg = AppMsg::OpenSettingsPreferences { plugin_id: plugin_id.clone(), entrypoint_id: Some(entrypoint_id.clone()) };
                             (description_text, msg)
                         }
                         (true, false) => {
-                            let description_text = "Before using, plugin preferences need to be specified";
+                            let description_text = tr("main-preferences-required-plugin");
                             let msg = AppMsg::OpenSettingsPreferences { plugin_id: plugin_id.clone(), entrypoint_id: None };
                             (description_text, msg)
                         }
@@ -1417,7 +2831,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .align_x(Horizontal::Center)
                         .themed(ContainerStyle::PreferenceRequiredViewDescription);
 
-                    let button_label: Element<_> = text("Open Settings")
+                    let button_label: Element<_> = text(tr("main-open-settings"))
                         .into();
 
                     let button: Element<_> = button(button_label)
@@ -1443,8 +2857,11 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
 
                     content
                 }
-                ErrorViewData::PluginError { .. } => {
-                    let description: Element<_> = text("Error occurred in plugin when trying to show the view")
+                ErrorViewData::PluginError { panic_message, .. } => {
+                    view_plugin_error(panic_message.as_ref())
+                }
+                ErrorViewData::RenderError { message, component_path, .. } => {
+                    let description: Element<_> = text(tr("main-plugin-render-error"))
                         .into();
 
                     let description = container(description)
@@ -1452,7 +2869,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .align_x(Horizontal::Center)
                         .themed(ContainerStyle::PluginErrorViewTitle);
 
-                    let sub_description: Element<_> = text("Please report this to plugin author")
+                    let sub_description: Element<_> = text(tr("main-plugin-render-error-description"))
                         .into();
 
                     let sub_description = container(sub_description)
@@ -1460,7 +2877,30 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .align_x(Horizontal::Center)
                         .themed(ContainerStyle::PluginErrorViewDescription);
 
-                    let button_label: Element<_> = text("Close")
+                    let component_path = if component_path.is_empty() {
+                        tr("main-plugin-render-error-view")
+                    } else {
+                        component_path.join(" > ")
+                    };
+
+                    let error_description: Element<_> = column([
+                        text(component_path)
+                            .shaping(Shaping::Advanced)
+                            .into(),
+                        text(message)
+                            .shaping(Shaping::Advanced)
+                            .into(),
+                    ]).into();
+
+                    let error_description = container(error_description)
+                        .width(Length::Fill)
+                        .themed(ContainerStyle::PluginErrorViewDescription);
+
+                    let error_description = scrollable(error_description)
+                        .width(Length::Fill)
+                        .into();
+
+                    let button_label: Element<_> = text(tr("close"))
                         .into();
 
                     let button: Element<_> = button(button_label)
@@ -1475,6 +2915,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                     let content: Element<_> = column([
                         description,
                         sub_description,
+                        error_description,
                         button
                     ]).into();
 
@@ -1487,8 +2928,13 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
 
                     content
                 }
-                ErrorViewData::UnknownError { display } => {
-                    let description: Element<_> = text("Unknown error occurred")
+                ErrorViewData::BackendError { error } => {
+                    let title = match error.code {
+                        ErrorCode::Internal => tr("unknown-error-occurred"),
+                        _ => tr("error-occurred"),
+                    };
+
+                    let description: Element<_> = text(title)
                         .into();
 
                     let description = container(description)
@@ -1496,7 +2942,8 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .align_x(Horizontal::Center)
                         .themed(ContainerStyle::PluginErrorViewTitle);
 
-                    let sub_description: Element<_> = text("Please report") // TODO link
+                    let sub_description: Element<_> = text(error.guidance()) // TODO link
+                        .shaping(Shaping::Advanced)
                         .into();
 
                     let sub_description = container(sub_description)
@@ -1504,8 +2951,31 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .align_x(Horizontal::Center)
                         .themed(ContainerStyle::PluginErrorViewDescription);
 
-                    let error_description: Element<_> = text(display)
-                        .shaping(Shaping::Advanced)
+                    let mut error_description = vec![];
+
+                    if let Some(plugin_id) = &error.plugin_id {
+                        error_description.push(
+                            text(tr_with("error-plugin", &[("plugin", plugin_id.to_string().into())]))
+                                .shaping(Shaping::Advanced)
+                                .into()
+                        );
+                    }
+
+                    error_description.push(
+                        text(&error.message)
+                            .shaping(Shaping::Advanced)
+                            .into()
+                    );
+
+                    for cause in &error.causes {
+                        error_description.push(
+                            text(cause)
+                                .shaping(Shaping::Advanced)
+                                .into()
+                        );
+                    }
+
+                    let error_description: Element<_> = column(error_description)
                         .into();
 
                     let error_description = container(error_description)
@@ -1516,7 +2986,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .width(Length::Fill)
                         .into();
 
-                    let button_label: Element<_> = text("Close")
+                    let button_label: Element<_> = text(tr("close"))
                         .into();
 
                     let button: Element<_> = button(button_label)
@@ -1544,16 +3014,28 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
 
                     content
                 }
-                ErrorViewData::BackendTimeout => {
-                    let description: Element<_> = text("Error occurred")
-                        .into();
-
-                    let description = container(description)
+                ErrorViewData::Recovery { safe_mode, description, crash_report_path } => {
+                    let title = if *safe_mode {
+                        tr("main-safe-mode-title")
+                    } else {
+                        tr("main-startup-failed-title")
+                    };
+
+                    let title: Element<_> = text(title)
+                        .into();
+
+                    let title = container(title)
                         .width(Length::Fill)
                         .align_x(Horizontal::Center)
                         .themed(ContainerStyle::PluginErrorViewTitle);
 
-                    let sub_description: Element<_> = text("Backend was unable to process message in a timely manner")
+                    let sub_description = if *safe_mode {
+                        tr("main-safe-mode-description")
+                    } else {
+                        tr("please-report")
+                    };
+
+                    let sub_description: Element<_> = text(sub_description)
                         .into();
 
                     let sub_description = container(sub_description)
@@ -1561,7 +3043,32 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .align_x(Horizontal::Center)
                         .themed(ContainerStyle::PluginErrorViewDescription);
 
-                    let button_label: Element<_> = text("Close")
+                    let mut error_description = vec![
+                        text(description)
+                            .shaping(Shaping::Advanced)
+                            .into()
+                    ];
+
+                    if let Some(crash_report_path) = crash_report_path {
+                        error_description.push(
+                            text(tr_with("main-crash-report", &[("path", crash_report_path.as_str().into())]))
+                                .shaping(Shaping::Advanced)
+                                .into()
+                        );
+                    }
+
+                    let error_description: Element<_> = column(error_description)
+                        .into();
+
+                    let error_description = container(error_description)
+                        .width(Length::Fill)
+                        .themed(ContainerStyle::PluginErrorViewDescription);
+
+                    let error_description = scrollable(error_description)
+                        .width(Length::Fill)
+                        .into();
+
+                    let button_label: Element<_> = text(tr("close"))
                         .into();
 
                     let button: Element<_> = button(button_label)
@@ -1574,8 +3081,9 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .into();
 
                     let content: Element<_> = column([
-                        description,
+                        title,
                         sub_description,
+                        error_description,
                         button
                     ]).into();
 
@@ -1588,524 +3096,2083 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
 
                     content
                 }
-            }
-        }
-        GlobalState::MainView { focused_search_result, sub_state, search_field_id, pending_plugin_view_loading_bar, .. } => {
-            let input: Element<_> = text_input("Search...", &state.prompt)
-                .on_input(AppMsg::PromptChanged)
-                .on_submit(AppMsg::PromptSubmit)
-                .ignore_with_modifiers(true)
-                .id(search_field_id.clone())
-                .width(Length::Fill)
-                .themed(TextInputStyle::MainSearch);
-
-            let search_list = search_list(
-                &state.search_results,
-                &focused_search_result,
-                |search_result| AppMsg::RunSearchItemAction(search_result, None),
-            );
+                ErrorViewData::ConfigError { error } => {
+                    let title: Element<_> = text(tr("main-config-error-title"))
+                        .into();
 
-            let search_list = container(search_list)
-                .width(Length::Fill)
-                .themed(ContainerStyle::MainListInner);
+                    let title = container(title)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .themed(ContainerStyle::PluginErrorViewTitle);
 
-            let list: Element<_> = scrollable(search_list)
-                .id(focused_search_result.scrollable_id.clone())
-                .width(Length::Fill)
-                .into();
+                    let sub_description: Element<_> = text(tr("config-error-default-used"))
+                        .into();
 
-            let list = container(list)
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .themed(ContainerStyle::MainList);
+                    let sub_description = container(sub_description)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .themed(ContainerStyle::PluginErrorViewDescription);
 
-            let input = container(input)
-                .width(Length::Fill)
-                .themed(ContainerStyle::MainSearchBar);
+                    let location = match (error.line, error.column) {
+                        (Some(line), Some(column)) => tr_with("config-error-location-line-column", &[("file", error.config_file.as_str().into()), ("line", line.into()), ("column", column.into())]),
+                        (Some(line), None) => tr_with("config-error-location-line", &[("file", error.config_file.as_str().into()), ("line", line.into())]),
+                        _ => error.config_file.clone(),
+                    };
 
-            let separator = if matches!(pending_plugin_view_loading_bar, LoadingBarState::On) || !state.loading_bar_state.is_empty() {
-                LoadingBar::new()
-                    .into()
-            } else {
-                horizontal_rule(1)
-                    .into()
-            };
+                    let error_description: Element<_> = column([
+                        text(location)
+                            .shaping(Shaping::Advanced)
+                            .into(),
+                        text(&error.message)
+                            .shaping(Shaping::Advanced)
+                            .into(),
+                    ]).into();
 
-            let inline_view = match state.client_context.get_all_inline_view_containers().first() {
-                Some((plugin_id, container)) => {
-                    let plugin_id = plugin_id.clone();
-                    container.render_inline_root_widget()
-                        .map(move |widget_event| {
-                            AppMsg::WidgetEvent {
-                                plugin_id: plugin_id.clone(),
-                                render_location: UiRenderLocation::InlineView,
-                                widget_event,
-                            }
-                        })
-                }
-                None => {
-                    horizontal_space()
-                        .into()
-                }
-            };
+                    let error_description = container(error_description)
+                        .width(Length::Fill)
+                        .themed(ContainerStyle::PluginErrorViewDescription);
 
-            let content: Element<_> = column(vec![
-                inline_view,
-                list,
-            ]).into();
+                    let error_description = scrollable(error_description)
+                        .width(Length::Fill)
+                        .into();
 
-            let (primary_action, action_panel) = if let Some(search_item) = focused_search_result.get(&state.search_results) {
-                let label = match search_item.entrypoint_type {
-                    SearchResultEntrypointType::Command => "Run Command",
-                    SearchResultEntrypointType::View => "Open View",
-                    SearchResultEntrypointType::GeneratedCommand => "Run Command",
-                }.to_string();
+                    let button_label: Element<_> = text(tr("close"))
+                        .into();
 
-                let default_shortcut = PhysicalShortcut {
-                    physical_key: PhysicalKey::Enter,
-                    modifier_shift: false,
-                    modifier_control: false,
-                    modifier_alt: false,
-                    modifier_meta: false,
-                };
+                    let button: Element<_> = button(button_label)
+                        .on_press(AppMsg::HideWindow)
+                        .into();
 
-                let mut actions: Vec<_> = search_item.entrypoint_actions
-                    .iter()
-                    .enumerate()
-                    .map(|(index, action)| {
-                        let physical_shortcut = if index == 0 {
-                            Some(PhysicalShortcut { // secondary action
-                                physical_key: PhysicalKey::Enter,
-                                modifier_shift: true,
-                                modifier_control: false,
-                                modifier_alt: false,
-                                modifier_meta: false,
-                            })
-                        } else {
-                            action.shortcut.clone()
-                        };
+                    let button = container(button)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .into();
 
-                        ActionPanelItem::Action {
-                            label: action.label.clone(),
-                            widget_id: index + 1,
-                            physical_shortcut,
-                        }
-                    })
-                    .collect();
+                    let content: Element<_> = column([
+                        title,
+                        sub_description,
+                        error_description,
+                        button
+                    ]).into();
 
-                let primary_action_widget_id = 0;
+                    let content: Element<_> = container(content)
+                        .align_x(Horizontal::Center)
+                        .align_y(Vertical::Center)
+                        .width(Length::Fill)
+                        .height(Length::Fill)
+                        .themed(ContainerStyle::Main);
 
-                if actions.len() == 0 {
-                    (Some((label, primary_action_widget_id, default_shortcut)), None)
-                } else {
-                    let primary_action = ActionPanelItem::Action {
-                        label: label.clone(),
-                        widget_id: primary_action_widget_id,
-                        physical_shortcut: Some(default_shortcut.clone()),
-                    };
+                    content
+                }
+                ErrorViewData::DatabaseRecovery { reason, broken_database_path, restored_backup_path } => {
+                    let title: Element<_> = text(tr("main-database-recovered-title"))
+                        .into();
 
-                    actions.insert(0, primary_action);
+                    let title = container(title)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .themed(ContainerStyle::PluginErrorViewTitle);
 
-                    let action_panel = ActionPanel {
-                        title: Some(search_item.entrypoint_name.clone()),
-                        items: actions,
+                    let sub_description = if restored_backup_path.is_some() {
+                        tr("main-database-restored")
+                    } else {
+                        tr("main-database-reset")
                     };
 
-                    (Some((label, primary_action_widget_id, default_shortcut)), Some(action_panel))
-                }
-            } else {
-                match state.client_context.get_first_inline_view_action_panel() {
-                    None => (None, None),
-                    Some(action_panel) => {
-                        match action_panel.find_first() {
-                            None => (None, None),
-                            Some((label, widget_id)) => {
-                                let shortcut = PhysicalShortcut {
-                                    physical_key: PhysicalKey::Enter,
-                                    modifier_shift: false,
-                                    modifier_control: false,
-                                    modifier_alt: false,
-                                    modifier_meta: false
-                                };
+                    let sub_description: Element<_> = text(sub_description)
+                        .into();
 
-                                (Some((label, widget_id, shortcut)), Some(action_panel))
-                            }
-                        }
-                    }
-                }
-            };
+                    let sub_description = container(sub_description)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .themed(ContainerStyle::PluginErrorViewDescription);
 
-            let toast_text = if !state.loading_bar_state.is_empty() {
-                Some("Indexing...")
-            } else {
-                None
-            };
+                    let mut error_description = vec![
+                        text(reason)
+                            .shaping(Shaping::Advanced)
+                            .into(),
+                        text(tr_with("main-database-moved", &[("path", broken_database_path.as_str().into())]))
+                            .shaping(Shaping::Advanced)
+                            .into(),
+                    ];
+
+                    if let Some(restored_backup_path) = restored_backup_path {
+                        error_description.push(
+                            text(tr_with("main-database-restored-backup", &[("path", restored_backup_path.as_str().into())]))
+                                .shaping(Shaping::Advanced)
+                                .into()
+                        );
+                    }
 
-            let root = match sub_state {
-                MainViewState::None => {
-                    render_root(
-                        false,
-                        input,
-                        separator,
-                        toast_text,
-                        content,
-                        primary_action,
-                        action_panel,
-                        None::<&ScrollHandle<SearchResultEntrypointAction>>,
-                        "",
-                        || AppMsg::ToggleActionPanel { keyboard: false },
-                        |widget_id| AppMsg::OnPrimaryActionMainViewActionPanelMouse { widget_id },
-                        |widget_id| AppMsg::Noop,
-                        || AppMsg::Noop,
-                    )
-                }
-                MainViewState::SearchResultActionPanel { focused_action_item, .. } => {
-                    render_root(
-                        true,
-                        input,
-                        separator,
-                        toast_text,
-                        content,
-                        primary_action,
-                        action_panel,
-                        Some(focused_action_item),
-                        "",
-                        || AppMsg::ToggleActionPanel { keyboard: false },
-                        |widget_id| AppMsg::OnPrimaryActionMainViewActionPanelMouse { widget_id },
-                        |widget_id| AppMsg::OnAnyActionMainViewSearchResultPanelMouse { widget_id },
-                        || AppMsg::Noop,
-                    )
-                }
-                MainViewState::InlineViewActionPanel { focused_action_item, .. } => {
-                    render_root(
-                        true,
-                        input,
-                        separator,
-                        toast_text,
-                        content,
-                        primary_action,
-                        action_panel,
-                        Some(focused_action_item),
-                        "",
-                        || AppMsg::ToggleActionPanel { keyboard: false },
-                        |widget_id| AppMsg::OnPrimaryActionMainViewActionPanelMouse { widget_id },
-                        |widget_id| AppMsg::OnAnyActionMainViewInlineViewPanelKeyboardWithFocus { widget_id },
-                        || AppMsg::Noop,
-                    )
-                }
-            };
+                    let error_description: Element<_> = column(error_description)
+                        .into();
 
-            let root: Element<_> = container(root)
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .themed(ContainerStyle::Main);
+                    let error_description = container(error_description)
+                        .width(Length::Fill)
+                        .themed(ContainerStyle::PluginErrorViewDescription);
 
-            root
-        }
-        GlobalState::PluginView { plugin_view_data, sub_state, ..  } => {
-            let PluginViewData { plugin_id, action_shortcuts, .. } = plugin_view_data;
+                    let error_description = scrollable(error_description)
+                        .width(Length::Fill)
+                        .into();
 
-            let view_container = state.client_context.get_view_container();
+                    let button_label: Element<_> = text(tr("close"))
+                        .into();
 
-            let container_element = view_container
-                .render_root_widget(sub_state, action_shortcuts)
-                .map(|widget_event| AppMsg::WidgetEvent {
-                    plugin_id: plugin_id.clone(),
-                    render_location: UiRenderLocation::View,
-                    widget_event,
+                    let button: Element<_> = button(button_label)
+                        .on_press(AppMsg::HideWindow)
+                        .into();
+
+                    let button = container(button)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .into();
+
+                    let content: Element<_> = column([
+                        title,
+                        sub_description,
+                        error_description,
+                        button
+                    ]).into();
+
+                    let content: Element<_> = container(content)
+                        .align_x(Horizontal::Center)
+                        .align_y(Vertical::Center)
+                        .width(Length::Fill)
+                        .height(Length::Fill)
+                        .themed(ContainerStyle::Main);
+
+                    content
+                }
+                ErrorViewData::WhatsNew { version, changelog } => {
+                    let title: Element<_> = text(tr_with("main-whats-new-title", &[("version", (*version).into())]))
+                        .into();
+
+                    let title = container(title)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .themed(ContainerStyle::PluginErrorViewTitle);
+
+                    let changelog: Element<_> = text(changelog)
+                        .shaping(Shaping::Advanced)
+                        .into();
+
+                    let changelog = container(changelog)
+                        .width(Length::Fill)
+                        .themed(ContainerStyle::PluginErrorViewDescription);
+
+                    let changelog = scrollable(changelog)
+                        .width(Length::Fill)
+                        .height(Length::Fill)
+                        .into();
+
+                    let button_label: Element<_> = text(tr("close"))
+                        .into();
+
+                    let button: Element<_> = button(button_label)
+                        .on_press(AppMsg::HideWindow)
+                        .into();
+
+                    let button = container(button)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .into();
+
+                    let content: Element<_> = column([
+                        title,
+                        changelog,
+                        button
+                    ]).into();
+
+                    let content: Element<_> = container(content)
+                        .align_x(Horizontal::Center)
+                        .align_y(Vertical::Center)
+                        .width(Length::Fill)
+                        .height(Length::Fill)
+                        .themed(ContainerStyle::Main);
+
+                    content
+                }
+                ErrorViewData::BackendTimeout { retry } => {
+                    let description: Element<_> = text(tr("error-occurred"))
+                        .into();
+
+                    let description = container(description)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .themed(ContainerStyle::PluginErrorViewTitle);
+
+                    let sub_description: Element<_> = text(tr("backend-timeout"))
+                        .into();
+
+                    let sub_description = container(sub_description)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .themed(ContainerStyle::PluginErrorViewDescription);
+
+                    let button_label: Element<_> = text(tr("close"))
+                        .into();
+
+                    let close_button: Element<_> = button(button_label)
+                        .on_press(AppMsg::HideWindow)
+                        .into();
+
+                    let buttons: Element<_> = match retry {
+                        Some(retry) => {
+                            let retry_button_label: Element<_> = text(tr("retry"))
+                                .into();
+
+                            let retry_button: Element<_> = button(retry_button_label)
+                                .on_press(AppMsg::RetryBackendRequest(retry.clone()))
+                                .into();
+
+                            row([retry_button, close_button])
+                                .spacing(8)
+                                .into()
+                        }
+                        None => close_button
+                    };
+
+                    let button = container(buttons)
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .into();
+
+                    let content: Element<_> = column([
+                        description,
+                        sub_description,
+                        button
+                    ]).into();
+
+                    let content: Element<_> = container(content)
+                        .align_x(Horizontal::Center)
+                        .align_y(Vertical::Center)
+                        .width(Length::Fill)
+                        .height(Length::Fill)
+                        .themed(ContainerStyle::Main);
+
+                    content
+                }
+            }
+        }
+        GlobalState::MainView { focused_search_result, sub_state, search_field_id, pending_plugin_view_loading_bar, .. } => {
+            let input: Element<_> = match sub_state {
+                MainViewState::EntrypointArguments { search_result, values, field_ids, focused_field, suggestions, .. } => {
+                    let entrypoint_name: Element<_> = text(search_result.entrypoint_name.to_string())
+                        .shaping(Shaping::Advanced)
+                        .into();
+
+                    let mut fields = vec![entrypoint_name];
+
+                    for (index, argument) in search_result.entrypoint_arguments.iter().enumerate() {
+                        let placeholder = if argument.optional {
+                            tr_with("main-argument-optional", &[("name", argument.name.as_str().into())])
+                        } else {
+                            argument.name.to_string()
+                        };
+
+                        let field: Element<_> = text_input(&placeholder, &values[index])
+                            .on_input(move |value| AppMsg::EntrypointArgumentChanged { index, value })
+                            .on_submit(AppMsg::SubmitEntrypointArguments)
+                            .ignore_with_modifiers(true)
+                            .id(field_ids[index].clone())
+                            .width(Length::Fill)
+                            .themed(TextInputStyle::FormInput);
+
+                        fields.push(field);
+                    }
+
+                    let fields: Element<_> = row(fields)
+                        .spacing(8)
+                        .align_y(Alignment::Center)
+                        .into();
+
+                    let mut prompt_rows = vec![fields];
+
+                    if let Some(prompt_row) = sub_state.argument_prompt_row() {
+                        let prompt_row = match prompt_row {
+                            ArgumentPromptRow::Hint(hint) => span(hint.to_string()).themed(SpanStyle::Color(&TextColor::Muted)),
+                            ArgumentPromptRow::Error(error) => span(error.to_string()).themed(SpanStyle::Color(&TextColor::Danger)),
+                        };
+
+                        let prompt_row: Element<_> = rich_text(vec![prompt_row])
+                            .size(13)
+                            .into();
+
+                        prompt_rows.push(prompt_row);
+                    }
+
+                    match suggestions {
+                        Some((index, suggestions)) if index == focused_field => {
+                            let index = *index;
+
+                            let suggestions: Vec<Element<_>> = suggestions.iter()
+                                .map(|suggestion| {
+                                    let content: Element<_> = text(suggestion.to_string())
+                                        .shaping(Shaping::Advanced)
+                                        .into();
+
+                                    button(content)
+                                        .on_press(AppMsg::SelectArgumentSuggestion { index, value: suggestion.to_string() })
+                                        .themed(ButtonStyle::MetadataTagItem)
+                                })
+                                .collect();
+
+                            let suggestions: Element<_> = row(suggestions)
+                                .spacing(8)
+                                .wrap()
+                                .into();
+
+                            prompt_rows.push(suggestions);
+                        }
+                        _ => {}
+                    }
+
+                    column(prompt_rows)
+                        .spacing(8)
+                        .into()
+                }
+                MainViewState::AddAlias { search_result, value, field_id } => {
+                    let entrypoint_name: Element<_> = text(search_result.entrypoint_name.to_string())
+                        .shaping(Shaping::Advanced)
+                        .into();
+
+                    let field: Element<_> = text_input(&tr("main-alias-placeholder"), value)
+                        .on_input(|value| AppMsg::AliasChanged { value })
+                        .on_submit(AppMsg::SubmitAlias)
+                        .ignore_with_modifiers(true)
+                        .id(field_id.clone())
+                        .width(Length::Fill)
+                        .themed(TextInputStyle::FormInput);
+
+                    row([entrypoint_name, field])
+                        .spacing(8)
+                        .align_y(Alignment::Center)
+                        .into()
+                }
+                _ => {
+                    let input: Element<_> = text_input(&tr("search-placeholder"), &state.prompt)
+                        .on_input(AppMsg::PromptChanged)
+                        .on_submit(AppMsg::PromptSubmit)
+                        .ignore_with_modifiers(true)
+                        .id(search_field_id.clone())
+                        .width(Length::Fill)
+                        .themed(TextInputStyle::MainSearch);
+
+                    match &state.search_keyword_mode {
+                        Some(keyword_mode) => {
+                            let chip: Element<_> = text(keyword_mode.plugin_name.to_string())
+                                .shaping(Shaping::Advanced)
+                                .into();
+
+                            let chip: Element<_> = button(chip)
+                                .on_press(AppMsg::ExitSearchKeywordMode)
+                                .themed(ButtonStyle::MetadataTagItem);
+
+                            row([chip, input])
+                                .spacing(8)
+                                .align_y(Alignment::Center)
+                                .into()
+                        }
+                        None => input
+                    }
+                }
+            };
+
+            let search_list: Element<_> = search_list(
+                &state.search_results,
+                &focused_search_result,
+                |search_result| AppMsg::RunSearchItemAction(search_result, None),
+                |search_result| match search_result.entrypoint_drag {
+                    Some(data) => AppMsg::StartDrag { data, icon: search_result.entrypoint_icon },
+                    None => AppMsg::Noop,
+                },
+                |index| AppMsg::FocusSearchResultMouse { index },
+                |index| AppMsg::OpenSearchResultContextMenu { index },
+            ).into();
+
+            // search returns fallback entrypoints only when nothing else matched
+            let search_list: Element<_> = if state.search_results.iter().any(|search_result| search_result.fallback) {
+                let title: Element<_> = text(tr_with("main-fallback-title", &[("query", state.prompt.trim().into())]))
+                    .shaping(Shaping::Advanced)
+                    .size(15)
+                    .themed(TextStyle::ListSectionTitle);
+
+                let title = row([title])
+                    .themed(RowStyle::ListFirstSectionTitle);
+
+                column([title, search_list])
+                    .into()
+            } else {
+                search_list
+            };
+
+            let search_list = container(search_list)
+                .width(Length::Fill)
+                .themed(ContainerStyle::MainListInner);
+
+            let list: Element<_> = scrollable(search_list)
+                .id(focused_search_result.scrollable_id.clone())
+                .on_scroll(|viewport| AppMsg::ScrollSearchResults { offset: viewport.absolute_offset().y })
+                .width(Length::Fill)
+                .into();
+
+            let list = container(list)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .themed(ContainerStyle::MainList);
+
+            let input = container(input)
+                .width(Length::Fill)
+                .themed(ContainerStyle::MainSearchBar);
+
+            let separator = if matches!(pending_plugin_view_loading_bar, LoadingBarState::On) || !state.loading_bar_state.is_empty() {
+                LoadingBar::new()
+                    .animated(!GauntletComplexTheme::reduced_motion())
+                    .into()
+            } else {
+                horizontal_rule(1)
+                    .into()
+            };
+
+            let inline_views: Vec<Element<_>> = state.client_context.get_all_inline_view_containers()
+                .iter()
+                .map(|(plugin_id, container)| {
+                    let plugin_id = plugin_id.clone();
+                    container.render_inline_root_widget()
+                        .map(move |widget_event| {
+                            AppMsg::WidgetEvent {
+                                plugin_id: plugin_id.clone(),
+                                render_location: UiRenderLocation::InlineView,
+                                widget_event,
+                            }
+                        })
+                })
+                .collect();
+
+            let inline_view: Element<_> = if inline_views.is_empty() {
+                horizontal_space()
+                    .into()
+            } else {
+                column(inline_views)
+                    .into()
+            };
+
+            let list: Element<_> = if state.preview_pane.shown() {
+                let list: Element<_> = container(list)
+                    .width(Length::FillPortion(3))
+                    .height(Length::Fill)
+                    .into();
+
+                let preview = state.preview_pane.render(focused_search_result.get(&state.search_results));
+
+                row(vec![list, vertical_rule(1).into(), preview])
+                    .into()
+            } else {
+                list
+            };
+
+            let mut search_filters: Vec<Element<_>> = std::iter::once(None)
+                .chain(SearchResultCategory::ALL.into_iter().map(Some))
+                .map(|category| {
+                    let chip: Element<_> = text(search_category_label(category))
+                        .shaping(Shaping::Advanced)
+                        .into();
+
+                    let style = if state.search_category == category {
+                        ButtonStyle::MetadataTagItemSelected
+                    } else {
+                        ButtonStyle::MetadataTagItem
+                    };
+
+                    button(chip)
+                        .on_press(AppMsg::SetSearchCategory { category })
+                        .themed(style)
+                })
+                .collect();
+
+            let pin_style = if state.window_pinned {
+                ButtonStyle::MetadataTagItemSelected
+            } else {
+                ButtonStyle::MetadataTagItem
+            };
+
+            let pin_text: Element<_> = text(tr("main-window-pin"))
+                .into();
+
+            let pin_shortcut = render_shortcut(&PhysicalShortcut {
+                physical_key: PhysicalKey::KeyP,
+                modifier_shift: true,
+                modifier_control: true,
+                modifier_alt: false,
+                modifier_meta: false,
+            });
+
+            let pin_content: Element<_> = row(vec![pin_text, pin_shortcut])
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .into();
+
+            let pin_button: Element<_> = button(pin_content)
+                .on_press(AppMsg::ToggleWindowPin)
+                .themed(pin_style);
+
+            search_filters.push(horizontal_space().into());
+            search_filters.push(pin_button);
+
+            let search_filters: Element<_> = row(search_filters)
+                .spacing(8)
+                .into();
+
+            let search_filters: Element<_> = container(search_filters)
+                .width(Length::Fill)
+                .themed(ContainerStyle::MainSearchFilters);
+
+            let mut content = vec![
+                search_filters,
+                inline_view,
+                list,
+            ];
+
+            if let Some(description) = &state.undo_toast {
+                let description: Element<_> = text(description.to_string())
+                    .shaping(Shaping::Advanced)
+                    .into();
+
+                let undo_text: Element<_> = text(tr("main-undo"))
+                    .into();
+
+                let undo_shortcut = render_shortcut(&PhysicalShortcut {
+                    physical_key: PhysicalKey::KeyZ,
+                    modifier_shift: false,
+                    modifier_control: true,
+                    modifier_alt: false,
+                    modifier_meta: false,
                 });
 
-            let element: Element<_> = container(container_element)
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .themed(ContainerStyle::Root);
+                let undo_content: Element<_> = row(vec![undo_text, undo_shortcut])
+                    .spacing(8)
+                    .align_y(Alignment::Center)
+                    .into();
+
+                let undo_button: Element<_> = button(undo_content)
+                    .on_press(AppMsg::Undo)
+                    .themed(ButtonStyle::MetadataTagItem);
+
+                let undo_toast: Element<_> = row(vec![description, horizontal_space().into(), undo_button])
+                    .align_y(Alignment::Center)
+                    .into();
+
+                let undo_toast: Element<_> = container(undo_toast)
+                    .width(Length::Fill)
+                    .themed(ContainerStyle::MainUndoToast);
+
+                content.push(horizontal_rule(1).into());
+                content.push(undo_toast);
+            }
+
+            let content: Element<_> = column(content)
+                .into();
+
+            let (primary_action, action_panel) = if let Some(search_item) = focused_search_result.get(&state.search_results) {
+                let label = match search_item.entrypoint_type {
+                    SearchResultEntrypointType::Command => tr("main-run-command"),
+                    SearchResultEntrypointType::View => tr("main-open-view"),
+                    SearchResultEntrypointType::GeneratedCommand => tr("main-run-command"),
+                };
+
+                let default_shortcut = PhysicalShortcut {
+                    physical_key: PhysicalKey::Enter,
+                    modifier_shift: false,
+                    modifier_control: false,
+                    modifier_alt: false,
+                    modifier_meta: false,
+                };
+
+                let mut actions: Vec<_> = search_item.entrypoint_actions
+                    .iter()
+                    .enumerate()
+                    .map(|(index, action)| {
+                        let physical_shortcut = if index == 0 {
+                            Some(PhysicalShortcut { // secondary action
+                                physical_key: PhysicalKey::Enter,
+                                modifier_shift: true,
+                                modifier_control: false,
+                                modifier_alt: false,
+                                modifier_meta: false,
+                            })
+                        } else {
+                            action.shortcut.clone()
+                        };
+
+                        ActionPanelItem::Action {
+                            label: action.label.clone(),
+                            widget_id: index + 1,
+                            physical_shortcut,
+                        }
+                    })
+                    .collect();
+
+                let copy_actions: Vec<_> = search_item.entrypoint_copy_items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        ActionPanelItem::Action {
+                            label: copy_item_label(item.kind),
+                            widget_id: search_item.entrypoint_actions.len() + index + 1,
+                            physical_shortcut: None,
+                        }
+                    })
+                    .collect();
+
+                if !copy_actions.is_empty() {
+                    actions.push(ActionPanelItem::ActionSection {
+                        title: None,
+                        items: copy_actions,
+                    });
+                }
+
+                let alias_actions: Vec<_> = alias_action_labels(search_item)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, label)| {
+                        ActionPanelItem::Action {
+                            label,
+                            widget_id: search_item.entrypoint_actions.len() + search_item.entrypoint_copy_items.len() + index + 1,
+                            physical_shortcut: None,
+                        }
+                    })
+                    .collect();
+
+                actions.push(ActionPanelItem::ActionSection {
+                    title: None,
+                    items: alias_actions,
+                });
+
+                let primary_action_widget_id = 0;
+
+                if actions.len() == 0 {
+                    (Some((label, primary_action_widget_id, default_shortcut)), None)
+                } else {
+                    let primary_action = ActionPanelItem::Action {
+                        label: label.clone(),
+                        widget_id: primary_action_widget_id,
+                        physical_shortcut: Some(default_shortcut.clone()),
+                    };
+
+                    actions.insert(0, primary_action);
+
+                    let action_panel = ActionPanel {
+                        title: Some(search_item.entrypoint_name.clone()),
+                        items: actions,
+                    };
+
+                    (Some((label, primary_action_widget_id, default_shortcut)), Some(action_panel))
+                }
+            } else {
+                match state.client_context.get_first_inline_view_action_panel() {
+                    None => (None, None),
+                    Some(action_panel) => {
+                        match action_panel.find_first() {
+                            None => (None, None),
+                            Some((label, widget_id)) => {
+                                let shortcut = PhysicalShortcut {
+                                    physical_key: PhysicalKey::Enter,
+                                    modifier_shift: false,
+                                    modifier_control: false,
+                                    modifier_alt: false,
+                                    modifier_meta: false
+                                };
+
+                                (Some((label, widget_id, shortcut)), Some(action_panel))
+                            }
+                        }
+                    }
+                }
+            };
+
+            let toast_text = if !state.loading_bar_state.is_empty() {
+                Some(tr("main-indexing"))
+            } else if state.prompt_search_pending {
+                Some(tr("main-search-pending"))
+            } else {
+                None
+            };
+
+            let root = match sub_state {
+                MainViewState::None => {
+                    render_root(
+                        false,
+                        input,
+                        separator,
+                        toast_text.as_deref(),
+                        content,
+                        primary_action,
+                        action_panel,
+                        None::<&ScrollHandle<SearchResultEntrypointAction>>,
+                        "",
+                        || AppMsg::ToggleActionPanel { keyboard: false },
+                        |widget_id| AppMsg::OnPrimaryActionMainViewActionPanelMouse { widget_id },
+                        |widget_id| AppMsg::Noop,
+                        |index| AppMsg::FocusActionPanelItemMouse { index },
+                        |offset| AppMsg::ScrollActionPanel { offset },
+                        || AppMsg::Noop,
+                    )
+                }
+                MainViewState::SearchResultActionPanel { focused_action_item, .. } => {
+                    render_root(
+                        true,
+                        input,
+                        separator,
+                        toast_text.as_deref(),
+                        content,
+                        primary_action,
+                        action_panel,
+                        Some(focused_action_item),
+                        "",
+                        || AppMsg::ToggleActionPanel { keyboard: false },
+                        |widget_id| AppMsg::OnPrimaryActionMainViewActionPanelMouse { widget_id },
+                        |widget_id| AppMsg::OnAnyActionMainViewSearchResultPanelMouse { widget_id },
+                        |index| AppMsg::FocusActionPanelItemMouse { index },
+                        |offset| AppMsg::ScrollActionPanel { offset },
+                        || AppMsg::Noop,
+                    )
+                }
+                MainViewState::InlineViewActionPanel { focused_action_item, .. } => {
+                    render_root(
+                        true,
+                        input,
+                        separator,
+                        toast_text.as_deref(),
+                        content,
+                        primary_action,
+                        action_panel,
+                        Some(focused_action_item),
+                        "",
+                        || AppMsg::ToggleActionPanel { keyboard: false },
+                        |widget_id| AppMsg::OnPrimaryActionMainViewActionPanelMouse { widget_id },
+                        |widget_id| AppMsg::OnAnyActionMainViewInlineViewPanelKeyboardWithFocus { widget_id },
+                        |index| AppMsg::FocusActionPanelItemMouse { index },
+                        |offset| AppMsg::ScrollActionPanel { offset },
+                        || AppMsg::Noop,
+                    )
+                }
+                MainViewState::PromptHistory { focused_history_item, entries } => {
+                    let items = entries
+                        .iter()
+                        .enumerate()
+                        .map(|(index, entry)| ActionPanelItem::Action {
+                            label: entry.clone(),
+                            widget_id: index,
+                            physical_shortcut: None,
+                        })
+                        .collect();
+
+                    let history_panel = ActionPanel {
+                        title: Some(tr("main-prompt-history")),
+                        items,
+                    };
+
+                    render_root(
+                        true,
+                        input,
+                        separator,
+                        toast_text.as_deref(),
+                        content,
+                        primary_action,
+                        Some(history_panel),
+                        Some(focused_history_item),
+                        "",
+                        || AppMsg::TogglePromptHistory,
+                        |widget_id| AppMsg::OnPrimaryActionMainViewActionPanelMouse { widget_id },
+                        |index| AppMsg::SelectPromptHistoryEntry { index },
+                        |index| AppMsg::FocusActionPanelItemMouse { index },
+                        |offset| AppMsg::ScrollActionPanel { offset },
+                        || AppMsg::Noop,
+                    )
+                }
+                MainViewState::EntrypointArguments { .. } => {
+                    render_root(
+                        false,
+                        input,
+                        separator,
+                        toast_text.as_deref(),
+                        content,
+                        primary_action,
+                        None,
+                        None::<&ScrollHandle<SearchResultEntrypointAction>>,
+                        "",
+                        || AppMsg::Noop,
+                        |_widget_id| AppMsg::SubmitEntrypointArguments,
+                        |_widget_id| AppMsg::Noop,
+                        |index| AppMsg::FocusActionPanelItemMouse { index },
+                        |offset| AppMsg::ScrollActionPanel { offset },
+                        || AppMsg::Noop,
+                    )
+                }
+                MainViewState::AddAlias { .. } => {
+                    // alias is added with the same shortcut which runs search result
+                    let primary_action = primary_action
+                        .map(|(_, widget_id, shortcut)| (tr("main-add-alias"), widget_id, shortcut));
+
+                    render_root(
+                        false,
+                        input,
+                        separator,
+                        toast_text.as_deref(),
+                        content,
+                        primary_action,
+                        None,
+                        None::<&ScrollHandle<SearchResultEntrypointAction>>,
+                        "",
+                        || AppMsg::Noop,
+                        |_widget_id| AppMsg::SubmitAlias,
+                        |_widget_id| AppMsg::Noop,
+                        |index| AppMsg::FocusActionPanelItemMouse { index },
+                        |offset| AppMsg::ScrollActionPanel { offset },
+                        || AppMsg::Noop,
+                    )
+                }
+            };
+
+            let root: Element<_> = container(root)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .themed(ContainerStyle::Main);
+
+            root
+        }
+        GlobalState::PluginView { plugin_view_data, sub_state, ..  } => {
+            let PluginViewData { plugin_id, action_shortcuts, .. } = plugin_view_data;
+
+            let view_container = state.client_context.get_view_container();
+
+            let container_element = view_container
+                .render_root_widget(sub_state, action_shortcuts)
+                .map(|widget_event| AppMsg::WidgetEvent {
+                    plugin_id: plugin_id.clone(),
+                    render_location: UiRenderLocation::View,
+                    widget_event,
+                });
+
+            // error view replaces plugin view on next update, until then show it from here
+            if let Some(panic_message) = view_container.render_panic() {
+                return view_plugin_error(Some(&panic_message))
+            }
+
+            let container_element: Element<_> = if state.widget_inspector && is_dev_plugin(plugin_id) {
+                let inspector: Element<_> = text(widget_inspector_text(state, view_container))
+                    .size(12)
+                    .font(Font::MONOSPACE)
+                    .into();
+
+                let inspector: Element<_> = container(inspector)
+                    .width(Length::Fill)
+                    .themed(ContainerStyle::WidgetInspector);
+
+                let inspector: Element<_> = scrollable(inspector)
+                    .width(Length::FillPortion(2))
+                    .height(Length::Fill)
+                    .into();
+
+                let container_element: Element<_> = container(container_element)
+                    .width(Length::FillPortion(3))
+                    .height(Length::Fill)
+                    .into();
+
+                row([container_element, vertical_rule(1).into(), inspector])
+                    .into()
+            } else {
+                container_element
+            };
+
+            let element: Element<_> = container(container_element)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .themed(ContainerStyle::Root);
+
+            // let element = element.explain(color!(0xFF0000));
+
+            element
+        }
+    }
+}
+
+fn subscription(state: &AppModel) -> Subscription<AppMsg> {
+    let frontend_receiver = state.frontend_receiver.clone();
+
+    struct RequestLoop;
+    struct GlobalShortcutListener;
+
+    let events_subscription = event::listen_with(|event, status, window_id| match status {
+        event::Status::Ignored => Some(AppMsg::IcedEvent(window_id, event)),
+        event::Status::Captured => match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key: Key::Named(Named::Escape), .. }) => Some(AppMsg::IcedEvent(window_id, event)),
+            _ => None
+        }
+    });
+
+    Subscription::batch([
+        Subscription::run_with_id(
+            std::any::TypeId::of::<GlobalShortcutListener>(),
+            stream::channel(
+                10,
+                |sender| async move {
+                    register_listener(sender.clone());
+
+                    std::future::pending::<()>().await;
+
+                    unreachable!()
+                },
+            )
+        ),
+        events_subscription,
+        gauntlet_common_ui::accessibility::subscription().map(AppMsg::AccessibilityAction),
+        Subscription::run_with_id(
+            std::any::TypeId::of::<RequestLoop>(),
+            stream::channel(
+                100,
+                |sender| async move {
+                    request_loop(frontend_receiver, sender).await;
+
+                    panic!("request_rx was unexpectedly closed")
+                },
+            )
+        )
+    ])
+}
+
+
+impl AppModel {
+    fn on_focused(&mut self) -> Task<AppMsg> {
+        self.focused = true;
+        gauntlet_common_ui::accessibility::update_window_focus(true);
+        Task::none()
+    }
+
+    fn on_unfocused(&mut self) -> Task<AppMsg> {
+        gauntlet_common_ui::accessibility::update_window_focus(false);
+
+        // for some reason (on both macOS and linux x11) duplicate Unfocused fires right before Focus event
+        if self.focused && self.hides_on_focus_loss() {
+            self.hide_window()
+        } else {
+            Task::none()
+        }
+    }
+
+    // panic can only happen during view, which cannot change the state, so it is checked after each update
+    fn replace_panicked_plugin_view(&mut self) -> Task<AppMsg> {
+        let GlobalState::PluginView { plugin_view_data: PluginViewData { plugin_id, entrypoint_id, .. }, .. } = &self.global_state else {
+            return Task::none()
+        };
+
+        let Some(panic_message) = self.client_context.get_view_container().render_panic() else {
+            return Task::none()
+        };
+
+        let plugin_id = plugin_id.clone();
+        let entrypoint_id = entrypoint_id.clone();
+
+        let close_view = self.close_plugin_view(plugin_id.clone(), entrypoint_id.clone());
+
+        let show_error = GlobalState::error(
+            &mut self.global_state,
+            ErrorViewData::PluginError {
+                plugin_id,
+                entrypoint_id,
+                panic_message: Some(panic_message),
+            },
+        );
+
+        Task::batch([close_view, show_error])
+    }
+
+    fn hides_on_focus_loss(&self) -> bool {
+        if !read_frontend_config().window.hide_on_focus_loss || self.window_pinned {
+            return false
+        }
+
+        match &self.global_state {
+            GlobalState::PluginView { plugin_view_data, .. } => !plugin_view_data.interactive,
+            GlobalState::MainView { .. } => true,
+            GlobalState::ErrorView { .. } => true,
+        }
+    }
+
+    fn hide_window_after_action(&mut self, keep_window_open: bool) -> Task<AppMsg> {
+        if self.window_pinned || keep_window_open {
+            Task::none()
+        } else {
+            self.hide_window()
+        }
+    }
+
+    fn hide_window(&mut self) -> Task<AppMsg> {
+        let Some(main_window_id) = self.main_window_id.take() else {
+            return Task::none()
+        };
+
+        self.focused = false;
+        self.window_pinned = false;
+
+        let mut commands = vec![];
+
+        // plugins shown in detached windows are not paused while main window is hidden
+        if !self.client_context.has_detached_views() {
+            commands.push(self.set_window_visibility(false, None));
+        }
+
+        #[cfg(target_os = "linux")]
+        if self.wayland {
+            commands.push(
+                Task::done(AppMsg::LayerShell(layer_shell::LayerShellAppMsg::RemoveWindow(main_window_id)))
+            );
+        } else {
+            commands.push(
+                window::close(main_window_id)
+            );
+        };
+
+        #[cfg(not(target_os = "linux"))]
+        commands.push(
+            window::close(main_window_id)
+        );
+
+        #[cfg(target_os = "macos")]
+        unsafe {
+            // when closing NSPanel current active application doesn't automatically become key window
+            // is there a proper way? without doing this manually
+            let app = objc2_app_kit::NSWorkspace::sharedWorkspace()
+                .menuBarOwningApplication();
+
+            if let Some(app) = app {
+                app.activateWithOptions(objc2_app_kit::NSApplicationActivationOptions::empty());
+            }
+        }
+
+        match &self.global_state {
+            GlobalState::PluginView { plugin_view_data: PluginViewData { plugin_id, entrypoint_id, .. }, .. } => {
+                let plugin_id = plugin_id.clone();
+                let entrypoint_id = entrypoint_id.clone();
+
+                if self.session_restore.enabled {
+                    // plugin keeps its navigation stack and client keeps state of widgets, e.g. focused item
+                    self.plugin_view_hidden_at = Some(Instant::now());
+                    commands.push(self.set_plugin_view_visibility(plugin_id, entrypoint_id, false));
+                } else {
+                    commands.push(self.close_plugin_view(plugin_id, entrypoint_id));
+                }
+            }
+            GlobalState::MainView { .. } => {}
+            GlobalState::ErrorView { .. } => {}
+        }
+
+        Task::batch(commands)
+    }
+
+    fn show_window(&mut self, restore_session: bool) -> Task<AppMsg> {
+        if let Some(_) = self.main_window_id {
+            return Task::none()
+        };
+
+        if self.session_locked {
+            tracing::debug!("Not showing window because screen is locked");
+
+            return Task::none()
+        }
+
+        let active_window = active_window_context(self.wayland);
+
+        #[cfg(target_os = "linux")]
+        let (main_window_id, open_task) =  if self.wayland {
+            open_main_window_wayland()
+        } else {
+            open_main_window_non_wayland()
+        };
+
+        #[cfg(not(target_os = "linux"))]
+        let (main_window_id, open_task) = open_main_window_non_wayland();
+
+        self.main_window_id = Some(main_window_id);
+
+        let open_task = Task::batch([
+            open_task,
+            self.set_window_visibility(true, active_window),
+        ]);
+
+        let start_fresh_after = Duration::from_secs(self.session_restore.start_fresh_after_minutes * 60);
+
+        match self.plugin_view_hidden_at.take() {
+            Some(hidden_at) if restore_session && hidden_at.elapsed() < start_fresh_after => {
+                let visible = match &self.global_state {
+                    GlobalState::PluginView { plugin_view_data: PluginViewData { plugin_id, entrypoint_id, .. }, .. } => {
+                        self.set_plugin_view_visibility(plugin_id.clone(), entrypoint_id.clone(), true)
+                    }
+                    _ => Task::none()
+                };
+
+                Task::batch([
+                    open_task,
+                    visible,
+                    self.client_context.restore_view_focus()
+                ])
+            }
+            Some(_) => {
+                let close_view = match &self.global_state {
+                    GlobalState::PluginView { plugin_view_data: PluginViewData { plugin_id, entrypoint_id, .. }, .. } => {
+                        let plugin_id = plugin_id.clone();
+                        let entrypoint_id = entrypoint_id.clone();
+
+                        self.close_plugin_view(plugin_id, entrypoint_id)
+                    }
+                    _ => Task::none()
+                };
+
+                Task::batch([
+                    open_task,
+                    close_view,
+                    self.reset_window_state()
+                ])
+            }
+            None => {
+                Task::batch([
+                    open_task,
+                    self.reset_window_state()
+                ])
+            }
+        }
+    }
+
+    fn update_view_transition(&mut self, previous_screen: Screen) {
+        let kind = match (previous_screen, self.global_state.screen()) {
+            (previous, current) if previous == current => return,
+            (Screen::Main, Screen::Plugin { .. }) => TransitionKind::Forward,
+            (Screen::Plugin { .. }, Screen::Main) => TransitionKind::Back,
+            (Screen::Plugin { view_stack_depth: previous }, Screen::Plugin { view_stack_depth: current }) => {
+                if current > previous {
+                    TransitionKind::Forward
+                } else {
+                    TransitionKind::Back
+                }
+            }
+            _ => TransitionKind::Fade,
+        };
+
+        self.view_transition_generation += 1;
+        self.view_transition_kind = kind;
+    }
+
+    fn reset_window_state(&mut self) -> Task<AppMsg> {
+        self.prompt = "".to_string();
+        self.search_keyword_mode = None;
+        self.search_category = None;
+        self.prompt_search_pending = false;
+        self.search_debounce = None;
+        self.submit_after_search = None;
+        self.unique_match_generation = None;
+
+        self.client_context.clear_all_inline_views();
+
+        GlobalState::initial(&mut self.global_state)
+    }
+
+    // retry is sent again when retry button on timeout error view is pressed
+    fn open_plugin_view(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, retry: Option<AppMsg>) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(async move {
+            let result = backend_client.request_view_render(plugin_id, entrypoint_id)
+                .await?;
+
+            Ok(result)
+        }, move |result| handle_backend_error_with_retry(result, retry, |(action_shortcuts, interactive, style)| AppMsg::OnOpenView { action_shortcuts, interactive, style }))
+    }
+
+    fn close_plugin_view(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
+        // widget state of destroyed view is not needed anymore
+        self.client_context.clear_view();
+
+        self.request_view_close(plugin_id, entrypoint_id)
+    }
+
+    fn request_view_close(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_client.request_view_close(plugin_id, entrypoint_id)
+                .await?;
+
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
+    }
+
+    // moves plugin view from main window to its own window, plugin keeps running it there while launcher is used for other things
+    fn detach_plugin_view(&mut self) -> Task<AppMsg> {
+        // layer shell surfaces cannot be moved, resized or closed by user
+        if self.wayland {
+            tracing::debug!("detaching plugin view is not supported on wayland");
+
+            return Task::none()
+        }
+
+        let GlobalState::PluginView { plugin_view_data, .. } = &self.global_state else {
+            return Task::none()
+        };
+
+        let plugin_view_data = plugin_view_data.clone();
+
+        let (window_id, open_task) = window::open(detached_window_settings());
+
+        self.client_context.detach_view(window_id, plugin_view_data);
+
+        Task::batch([
+            open_task.map(|_| AppMsg::Noop),
+            GlobalState::back_to_search(&mut self.global_state),
+        ])
+    }
+
+    fn on_detached_window_closed(&mut self, window_id: window::Id) -> Task<AppMsg> {
+        let Some(detached_view) = self.client_context.remove_detached_view(window_id) else {
+            return Task::none()
+        };
+
+        let PluginViewData { plugin_id, entrypoint_id, .. } = detached_view.plugin_view_data;
+
+        let mut commands = vec![
+            self.request_view_close(plugin_id, entrypoint_id),
+        ];
+
+        // last shown plugin view is gone, so plugins can be paused like after hiding main window
+        if self.main_window_id.is_none() && !self.client_context.has_detached_views() {
+            commands.push(self.set_window_visibility(false, None));
+        }
+
+        Task::batch(commands)
+    }
+
+    // the same as escape in main window but closes the window instead of going back to search
+    fn detached_view_back(&self, window_id: window::Id) -> Task<AppMsg> {
+        let Some(detached_view) = self.client_context.get_detached_view(window_id) else {
+            return Task::none()
+        };
+
+        let PluginViewData { plugin_id, entrypoint_id, view_stack_depth, .. } = &detached_view.plugin_view_data;
+
+        if *view_stack_depth <= 1 {
+            window::close(window_id)
+        } else {
+            self.pop_plugin_view(plugin_id.clone(), entrypoint_id.clone())
+        }
+    }
+
+    // detached window doesn't have action panel, so actions are run directly
+    fn handle_detached_view_event(&self, window_id: window::Id, widget_event: ComponentWidgetEvent) -> Task<AppMsg> {
+        let Some(detached_view) = self.client_context.get_detached_view(window_id) else {
+            return Task::none()
+        };
+
+        match widget_event {
+            ComponentWidgetEvent::Noop => Task::none(),
+            ComponentWidgetEvent::PreviousView => self.detached_view_back(window_id),
+            ComponentWidgetEvent::FocusListItemMouse { index } => {
+                detached_view.container.focus_list_item_mouse(index);
+
+                Task::none()
+            }
+            ComponentWidgetEvent::ScrollList { offset } => {
+                detached_view.container.list_scrolled(offset);
+
+                Task::none()
+            }
+            ComponentWidgetEvent::ListItemContextMenu { .. }
+            | ComponentWidgetEvent::FocusActionMouse { .. }
+            | ComponentWidgetEvent::ScrollActionPanel { .. } => Task::none(),
+            widget_event => {
+                let plugin_id = detached_view.plugin_view_data.plugin_id.clone();
+
+                let event = self.client_context.handle_detached_event(window_id, widget_event.clone());
+
+                // actions in detached window don't hide main window
+                self.send_plugin_event(widget_event, event, plugin_id, UiRenderLocation::View)
+                    .map(move |msg| {
+                        match msg {
+                            // action panel and drag and drop only work in main window
+                            AppMsg::ToggleActionPanel { .. } | AppMsg::StartDrag { .. } => AppMsg::Noop,
+                            AppMsg::OnAnyActionPluginViewAnyPanel { widget_id } => {
+                                AppMsg::DetachedWidgetEvent {
+                                    window_id,
+                                    widget_event: ComponentWidgetEvent::RunAction { widget_id },
+                                }
+                            }
+                            msg => msg
+                        }
+                    })
+            }
+        }
+    }
+
+    fn handle_detached_view_keyboard_event(&self, window_id: window::Id, event: keyboard::Event) -> Task<AppMsg> {
+        let keyboard::Event::KeyPressed { key, modifiers, physical_key, .. } = event else {
+            return Task::none()
+        };
+
+        if let Key::Named(Named::Escape) = key {
+            return self.detached_view_back(window_id)
+        }
+
+        let Physical::Code(physical_key) = physical_key else {
+            return Task::none()
+        };
+
+        let Some(PhysicalShortcut { physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta }) = physical_key_model(physical_key, modifiers) else {
+            return Task::none()
+        };
+
+        // keys without modifiers are only typed into focused text field
+        if !(modifier_shift || modifier_control || modifier_alt || modifier_meta) {
+            return Task::none()
+        }
+
+        let Some(detached_view) = self.client_context.get_detached_view(window_id) else {
+            return Task::none()
+        };
+
+        let plugin_id = detached_view.plugin_view_data.plugin_id.clone();
+        let entrypoint_id = detached_view.plugin_view_data.entrypoint_id.clone();
+
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(
+            async move {
+                backend_client.send_keyboard_event(plugin_id, entrypoint_id, KeyboardEventOrigin::PluginView, physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta)
+                    .await?;
+
+                Ok(())
+            },
+            |result| handle_backend_error(result, |()| AppMsg::Noop),
+        )
+    }
+
+    // detached window only shows plugin view, so errors are shown in main window
+    fn close_failed_detached_view(&mut self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> Task<AppMsg> {
+        match self.client_context.remove_entrypoint_detached_view(plugin_id, entrypoint_id) {
+            Some(detached_view) => {
+                Task::batch([
+                    window::close(detached_view.window_id),
+                    self.show_window(false),
+                ])
+            }
+            None => Task::none()
+        }
+    }
+
+    fn replace_panicked_detached_view(&mut self) -> Task<AppMsg> {
+        let panicked = self.client_context.get_detached_views()
+            .iter()
+            .find_map(|detached_view| {
+                detached_view.container.render_panic()
+                    .map(|panic_message| (detached_view.plugin_view_data.clone(), panic_message))
+            });
+
+        let Some((PluginViewData { plugin_id, entrypoint_id, .. }, panic_message)) = panicked else {
+            return Task::none()
+        };
+
+        let close_view = Task::batch([
+            self.close_failed_detached_view(&plugin_id, &entrypoint_id),
+            self.request_view_close(plugin_id.clone(), entrypoint_id.clone()),
+        ]);
+
+        let show_error = GlobalState::error(
+            &mut self.global_state,
+            ErrorViewData::PluginError {
+                plugin_id,
+                entrypoint_id,
+                panic_message: Some(panic_message),
+            },
+        );
+
+        Task::batch([close_view, show_error])
+    }
+
+    fn set_window_visibility(&self, visible: bool, active_window: Option<ActiveWindowContext>) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_client.window_visibility(visible, active_window)
+                .await?;
+
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
+    }
+
+    fn set_plugin_view_visibility(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, visible: bool) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_client.request_view_visibility(plugin_id, entrypoint_id, visible)
+                .await?;
+
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
+    }
+
+    fn pop_plugin_view(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_client.request_view_pop(plugin_id, entrypoint_id)
+                .await?;
+
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
+    }
+
+    fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: HashMap<String, EntrypointArgumentValue>) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        let retry = AppMsg::RunCommand {
+            plugin_id: plugin_id.clone(),
+            entrypoint_id: entrypoint_id.clone(),
+            arguments: arguments.clone(),
+        };
+
+        Task::perform(async move {
+            backend_client.request_run_command(plugin_id, entrypoint_id, arguments)
+                .await?;
+
+            Ok(())
+        }, move |result| handle_backend_error_with_retry(result, Some(retry), |()| AppMsg::Noop))
+    }
+
+    fn run_generated_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, action_index: Option<usize>) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        let retry = AppMsg::RunGeneratedCommandEvent {
+            plugin_id: plugin_id.clone(),
+            entrypoint_id: entrypoint_id.clone(),
+            action_index,
+        };
+
+        Task::perform(async move {
+            backend_client.request_run_generated_command(plugin_id, entrypoint_id, action_index)
+                .await?;
+
+            Ok(())
+        }, move |result| handle_backend_error_with_retry(result, Some(retry), |()| AppMsg::Noop))
+    }
+
+    fn request_search_result_preview(&mut self) -> Task<AppMsg> {
+        let search_result = match &self.global_state {
+            GlobalState::MainView { focused_search_result, .. } => focused_search_result.get(&self.search_results),
+            GlobalState::PluginView { .. } | GlobalState::ErrorView { .. } => None,
+        };
+
+        let Some((plugin_id, entrypoint_id)) = self.preview_pane.focus_changed(search_result) else {
+            return Task::none()
+        };
+
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_client.request_search_result_preview(plugin_id, entrypoint_id)
+                .await?;
+
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
+    }
+
+    fn handle_plugin_event(&self, widget_event: ComponentWidgetEvent, plugin_id: PluginId, render_location: UiRenderLocation) -> Task<AppMsg> {
+        let event = self.client_context.handle_event(render_location, &plugin_id, widget_event.clone());
+
+        self.send_plugin_event(widget_event, event, plugin_id, render_location)
+    }
+
+    fn send_plugin_event(
+        &self,
+        widget_event: ComponentWidgetEvent,
+        event: Option<(EntrypointId, UiViewEvent)>,
+        plugin_id: PluginId,
+        render_location: UiRenderLocation
+    ) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(async move {
+            if let Some((entrypoint_id, event)) = event {
+                match event {
+                    UiViewEvent::View { widget_id, event_name, event_arguments } => {
+                        let msg = match widget_event {
+                            ComponentWidgetEvent::ActionClick { .. } => AppMsg::ToggleActionPanel { keyboard: false },
+                            _ => AppMsg::Noop
+                        };
+
+                        backend_client.send_view_event(plugin_id, entrypoint_id, render_location, widget_id, event_name, event_arguments)
+                            .await?;
+
+                        Ok(msg)
+                    }
+                    UiViewEvent::Open { href } => {
+                        backend_client.send_open_event(plugin_id, href)
+                            .await?;
+
+                        Ok(AppMsg::Noop)
+                    }
+                    UiViewEvent::AppEvent { event } => Ok(event)
+                }
+            } else {
+                Ok(AppMsg::Noop)
+            }
+        }, |result| handle_backend_error(result, |msg| msg))
+    }
+
+    fn handle_main_view_keyboard_event(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, physical_key: PhysicalKey, modifier_shift: bool, modifier_control: bool, modifier_alt: bool, modifier_meta: bool) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(
+            async move {
+                backend_client.send_keyboard_event(plugin_id, entrypoint_id, KeyboardEventOrigin::MainView, physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta)
+                    .await?;
+
+                Ok(())
+            },
+            |result| handle_backend_error(result, |()| AppMsg::Noop),
+        )
+    }
+
+    fn handle_plugin_view_keyboard_event(&self, physical_key: PhysicalKey, modifier_shift: bool, modifier_control: bool, modifier_alt: bool, modifier_meta: bool) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        let (plugin_id, entrypoint_id) = {
+            (self.client_context.get_view_plugin_id(), self.client_context.get_view_entrypoint_id())
+        };
+
+        Task::perform(
+            async move {
+                backend_client.send_keyboard_event(plugin_id, entrypoint_id, KeyboardEventOrigin::PluginView, physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta)
+                    .await?;
+
+                Ok(())
+            },
+            |result| handle_backend_error(result, |()| AppMsg::Noop),
+        )
+    }
 
-            // let element = element.explain(color!(0xFF0000));
+    fn handle_inline_plugin_view_keyboard_event(&self, physical_key: PhysicalKey, modifier_shift: bool, modifier_control: bool, modifier_alt: bool, modifier_meta: bool) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
 
-            element
+        let (plugin_id, entrypoint_id) = {
+            match self.client_context.get_first_inline_view_container() {
+                None => {
+                    return Task::none()
+                },
+                Some(container) => (container.get_plugin_id(), container.get_entrypoint_id())
+            }
+        };
+
+        Task::perform(
+            async move {
+                backend_client.send_keyboard_event(plugin_id, entrypoint_id, KeyboardEventOrigin::PluginView, physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta)
+                    .await?;
+
+                Ok(())
+            },
+            |result| handle_backend_error(result, |()| AppMsg::Noop),
+        )
+    }
+
+    fn submit_prompt(&mut self) -> Task<AppMsg> {
+        let prompt_history_shown = matches!(&self.global_state, GlobalState::MainView { sub_state: MainViewState::PromptHistory { .. }, .. });
+
+        if self.prompt_search_pending && !prompt_history_shown {
+            self.search_prompt(self.prompt.clone())
+        } else if self.search_results_generation < self.search_generation && !prompt_history_shown {
+            // results for the text user just typed are not shown yet
+            if let Some(search_result) = self.type_ahead_result() {
+                return Task::done(AppMsg::RunSearchItemAction(search_result, None))
+            }
+
+            let search = if self.search_debounce.is_some() {
+                self.search_prompt(self.prompt.clone())
+            } else {
+                // request is already sent
+                Task::none()
+            };
+
+            self.submit_after_search = Some(self.search_generation);
+
+            search
+        } else {
+            self.global_state.primary(&self.client_context, &self.search_results)
         }
     }
-}
 
-fn subscription(state: &AppModel) -> Subscription<AppMsg> {
-    let frontend_receiver = state.frontend_receiver.clone();
+    // top result shown for the previous prompt is still good enough when its name contains what was typed since
+    fn type_ahead_result(&self) -> Option<SearchResult> {
+        if !self.type_ahead.run_top_result || self.prompt.is_empty() {
+            return None
+        }
 
-    struct RequestLoop;
-    struct GlobalShortcutListener;
+        let GlobalState::MainView { sub_state: MainViewState::None, .. } = &self.global_state else {
+            return None
+        };
 
-    let events_subscription = event::listen_with(|event, status, window_id| match status {
-        event::Status::Ignored => Some(AppMsg::IcedEvent(window_id, event)),
-        event::Status::Captured => match &event {
-            Event::Keyboard(keyboard::Event::KeyPressed { key: Key::Named(Named::Escape), .. }) => Some(AppMsg::IcedEvent(window_id, event)),
+        let search_result = self.search_results.first()?;
+
+        if search_result.fallback {
+            return None
+        }
+
+        let prompt = self.prompt.to_lowercase();
+
+        search_result.entrypoint_name.to_lowercase().contains(&prompt)
+            .then(|| search_result.clone())
+    }
+
+    fn unique_match(&self) -> Option<SearchResult> {
+        let GlobalState::MainView { sub_state: MainViewState::None, .. } = &self.global_state else {
+            return None
+        };
+
+        match self.search_results.as_slice() {
+            [search_result] if !search_result.fallback => Some(search_result.clone()),
             _ => None
         }
-    });
+    }
 
-    Subscription::batch([
-        Subscription::run_with_id(
-            std::any::TypeId::of::<GlobalShortcutListener>(),
-            stream::channel(
-                10,
-                |sender| async move {
-                    register_listener(sender.clone());
+    // arrow up recalls history only when it wouldn't select previous search result instead
+    fn can_recall_prompt_history(&self) -> bool {
+        let GlobalState::MainView { sub_state: MainViewState::None, focused_search_result, .. } = &self.global_state else {
+            return false
+        };
 
-                    std::future::pending::<()>().await;
+        if !self.prompt_history.enabled() {
+            return false
+        }
 
-                    unreachable!()
-                },
-            )
-        ),
-        events_subscription,
-        Subscription::run_with_id(
-            std::any::TypeId::of::<RequestLoop>(),
-            stream::channel(
-                100,
-                |sender| async move {
-                    request_loop(frontend_receiver, sender).await;
+        self.prompt_history.is_recalling(&self.prompt) || (self.prompt.is_empty() && focused_search_result.index.unwrap_or(0) == 0)
+    }
 
-                    panic!("request_rx was unexpectedly closed")
-                },
-            )
-        )
-    ])
-}
+    // quick way to get back to whatever was used last, e.g. after accidentally closing a view
+    fn can_resume_recently_used(&self) -> bool {
+        let GlobalState::MainView { sub_state: MainViewState::None, .. } = &self.global_state else {
+            return false
+        };
+
+        self.prompt.is_empty() && self.first_non_favorite_search_result().is_some_and(|search_result| search_result.recent)
+    }
 
+    // favorites are placed before recently used entrypoints
+    fn first_non_favorite_search_result(&self) -> Option<&SearchResult> {
+        self.search_results.iter()
+            .find(|search_result| !search_result.favorite)
+    }
 
-impl AppModel {
-    fn on_focused(&mut self) -> Task<AppMsg> {
-        self.focused = true;
-        Task::none()
+    fn resume_recently_used(&self) -> Task<AppMsg> {
+        match self.first_non_favorite_search_result() {
+            Some(search_result) => Task::done(AppMsg::RunSearchItemAction(search_result.clone(), None)),
+            None => Task::none()
+        }
     }
 
-    fn on_unfocused(&mut self) -> Task<AppMsg> {
-        // for some reason (on both macOS and linux x11) duplicate Unfocused fires right before Focus event
-        if self.focused {
-            self.hide_window()
-        } else {
-            Task::none()
+    fn is_recalling_prompt_history(&self) -> bool {
+        matches!(&self.global_state, GlobalState::MainView { sub_state: MainViewState::None, .. })
+            && self.prompt_history.is_recalling(&self.prompt)
+    }
+
+    fn recall_previous_prompt(&mut self) -> Task<AppMsg> {
+        match self.prompt_history.previous() {
+            Some(prompt) => self.set_prompt(prompt),
+            None => Task::none()
         }
     }
 
-    fn hide_window(&mut self) -> Task<AppMsg> {
-        let Some(main_window_id) = self.main_window_id.take() else {
+    fn recall_next_prompt(&mut self) -> Task<AppMsg> {
+        match self.prompt_history.next() {
+            Some(prompt) => self.set_prompt(prompt),
+            None => Task::none()
+        }
+    }
+
+    fn set_prompt(&mut self, prompt: String) -> Task<AppMsg> {
+        let GlobalState::MainView { focused_search_result, search_field_id, .. } = &mut self.global_state else {
             return Task::none()
         };
 
-        self.focused = false;
+        focused_search_result.reset(true);
 
-        let mut commands = vec![];
+        let search_field_id = search_field_id.clone();
 
-        #[cfg(target_os = "linux")]
-        if self.wayland {
-            commands.push(
-                Task::done(AppMsg::LayerShell(layer_shell::LayerShellAppMsg::RemoveWindow(main_window_id)))
-            );
-        } else {
-            commands.push(
-                window::close(main_window_id)
-            );
+        self.prompt = prompt.clone();
+
+        Task::batch([
+            focus(search_field_id.clone()),
+            text_input::move_cursor_to_end(search_field_id),
+            self.search_prompt(prompt),
+        ])
+    }
+
+    // search is done only after typing pauses, so that fast typing doesn't send request per keystroke
+    fn debounce_search(&mut self) -> Task<AppMsg> {
+        self.search_generation += 1;
+
+        let generation = self.search_generation;
+
+        self.search_debounce = Some(generation);
+
+        Task::perform(async move {
+            tokio::time::sleep(SEARCH_DEBOUNCE).await;
+
+            generation
+        }, |generation| AppMsg::DebouncedSearch { generation })
+    }
+
+    // plugin is asked for suggestions only after typing pauses, same as with search
+    fn debounce_argument_suggestions(&mut self) -> Task<AppMsg> {
+        self.argument_suggestions_generation += 1;
+
+        let generation = self.argument_suggestions_generation;
+
+        Task::perform(async move {
+            tokio::time::sleep(ARGUMENT_SUGGESTIONS_DEBOUNCE).await;
+
+            generation
+        }, |generation| AppMsg::DebouncedArgumentSuggestions { generation })
+    }
+
+    fn request_argument_suggestions(&self, generation: u64) -> Task<AppMsg> {
+        let GlobalState::MainView { sub_state: MainViewState::EntrypointArguments { search_result, values, focused_field, .. }, .. } = &self.global_state else {
+            return Task::none()
         };
 
-        #[cfg(not(target_os = "linux"))]
-        commands.push(
-            window::close(main_window_id)
-        );
+        let argument = &search_result.entrypoint_arguments[*focused_field];
 
-        #[cfg(target_os = "macos")]
-        unsafe {
-            // when closing NSPanel current active application doesn't automatically become key window
-            // is there a proper way? without doing this manually
-            let app = objc2_app_kit::NSWorkspace::sharedWorkspace()
-                .menuBarOwningApplication();
+        // values of enum arguments are already known
+        if let EntrypointArgumentType::Enum { .. } = argument.argument_type {
+            return Task::none()
+        }
 
-            if let Some(app) = app {
-                app.activateWithOptions(objc2_app_kit::NSApplicationActivationOptions::empty());
-            }
+        let plugin_id = search_result.plugin_id.clone();
+        let entrypoint_id = search_result.entrypoint_id.clone();
+        let argument_id = argument.id.clone();
+
+        let values = search_result.entrypoint_arguments
+            .iter()
+            .zip(values)
+            .map(|(argument, value)| (argument.id.clone(), value.clone()))
+            .collect();
+
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_client.request_argument_suggestions(plugin_id, entrypoint_id, argument_id, values, generation)
+                .await?;
+
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
+    }
+
+    fn search_prompt(&mut self, prompt: String) -> Task<AppMsg> {
+        self.prompt_search_pending = false;
+        self.search_debounce = None;
+        self.inline_view_deadline = Some(Instant::now() + INLINE_VIEW_TIMEOUT);
+
+        let run_unique_match = self.type_ahead.run_unique_match && !prompt.is_empty();
+
+        let search = self.search(prompt, true);
+
+        self.unique_match_generation = run_unique_match.then_some(self.search_generation);
+
+        search
+    }
+
+    fn search(&mut self, new_prompt: String, render_inline_view: bool) -> Task<AppMsg> {
+        self.search_generation += 1;
+
+        let generation = self.search_generation;
+
+        let keyword_plugin_id = self.search_keyword_mode
+            .as_ref()
+            .map(|keyword_mode| keyword_mode.plugin_id.clone());
+
+        let category = self.search_category;
+
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(async move {
+            let search_results = backend_api.search(new_prompt, keyword_plugin_id, category, render_inline_view, generation)
+                .await?;
+
+            Ok(search_results)
+        }, move |result| handle_backend_error(result, |(results, keyword_mode, category)| AppMsg::SetSearchResults { generation, results, keyword_mode, category }))
+    }
+
+    // same as keyword mode, prefix is removed from the prompt and corresponding filter chip is selected instead
+    fn enter_search_category(&mut self, category: SearchResultCategory) -> Task<AppMsg> {
+        let GlobalState::MainView { search_field_id, .. } = &self.global_state else {
+            return Task::none()
+        };
+
+        // prompt could have been changed while search was in progress
+        let Some((prompt_category, rest)) = SearchResultCategory::parse_query_prefix(&self.prompt) else {
+            return Task::none()
+        };
+
+        if prompt_category != category {
+            return Task::none()
+        }
+
+        self.prompt = rest.to_string();
+        self.search_category = Some(category);
+
+        // inline views are not shown while search is limited to one category
+        self.client_context.clear_all_inline_views();
+
+        text_input::move_cursor_to_end(search_field_id.clone())
+    }
+
+    fn set_search_category(&mut self, category: Option<SearchResultCategory>) -> Task<AppMsg> {
+        let GlobalState::MainView { focused_search_result, sub_state, .. } = &mut self.global_state else {
+            return Task::none()
+        };
+
+        if self.search_category == category {
+            return Task::none()
+        }
+
+        self.search_category = category;
+
+        focused_search_result.reset(true);
+        MainViewState::initial(sub_state);
+
+        if category.is_some() {
+            self.client_context.clear_all_inline_views();
+        }
+
+        self.search_prompt(self.prompt.clone())
+    }
+
+    // keyword is removed from the prompt and shown as a chip next to it instead
+    fn enter_search_keyword_mode(&mut self, keyword_mode: SearchKeywordMode) -> Task<AppMsg> {
+        let GlobalState::MainView { search_field_id, .. } = &self.global_state else {
+            return Task::none()
+        };
+
+        // prompt could have been changed while search was in progress
+        let Some((keyword, rest)) = self.prompt.split_once(' ') else {
+            return Task::none()
+        };
+
+        if !keyword.eq_ignore_ascii_case(&keyword_mode.keyword) {
+            return Task::none()
         }
 
+        self.prompt = rest.trim_start().to_string();
+        self.search_keyword_mode = Some(keyword_mode);
+
+        // inline views are not shown while search is limited to one plugin
+        self.client_context.clear_all_inline_views();
+
+        text_input::move_cursor_to_end(search_field_id.clone())
+    }
+
+    fn current_focus_target(&self) -> FocusTarget {
         match &self.global_state {
-            GlobalState::PluginView { plugin_view_data: PluginViewData { plugin_id, .. }, .. } => {
-                commands.push(self.close_plugin_view(plugin_id.clone()));
+            GlobalState::MainView { search_field_id, .. } => FocusTarget::SearchField(search_field_id.clone()),
+            GlobalState::PluginView { .. } => FocusTarget::PluginView,
+            GlobalState::ErrorView { .. } => FocusTarget::None,
+        }
+    }
+
+    fn open_overlay(&mut self, overlay: Overlay) -> Task<AppMsg> {
+        self.overlays.open(overlay, self.current_focus_target());
+
+        if overlay.captures_focus() {
+            // there is no widget with this id, so focus is removed from text field behind the overlay
+            focus(text_input::Id::unique())
+        } else {
+            Task::none()
+        }
+    }
+
+    // focus is returned to what was focused when overlay was opened
+    fn close_overlay(&mut self, overlay: Overlay) -> Task<AppMsg> {
+        match self.overlays.close(overlay) {
+            Some(FocusTarget::SearchField(search_field_id)) => focus(search_field_id),
+            Some(FocusTarget::PluginView) => self.client_context.restore_view_focus(),
+            Some(FocusTarget::None) | None => Task::none(),
+        }
+    }
+
+    fn is_overlay_open(&self, overlay: Overlay) -> bool {
+        match overlay {
+            Overlay::ActionPanel => matches!(
+                self.global_state,
+                GlobalState::MainView { sub_state: MainViewState::SearchResultActionPanel { .. } | MainViewState::InlineViewActionPanel { .. }, .. }
+                    | GlobalState::PluginView { sub_state: PluginViewState::ActionPanel { .. }, .. }
+            ),
+            Overlay::PromptHistory => matches!(self.global_state, GlobalState::MainView { sub_state: MainViewState::PromptHistory { .. }, .. }),
+            Overlay::EntrypointArguments => matches!(self.global_state, GlobalState::MainView { sub_state: MainViewState::EntrypointArguments { .. }, .. }),
+            Overlay::AddAlias => matches!(self.global_state, GlobalState::MainView { sub_state: MainViewState::AddAlias { .. }, .. }),
+            Overlay::UndoToast => self.undo_toast.is_some(),
+        }
+    }
+
+    fn top_modal_overlay(&mut self) -> Option<Overlay> {
+        // overlays which were closed together with their view, e.g. when plugin view was replaced
+        let mut overlays = std::mem::replace(&mut self.overlays, OverlayStack::new());
+        overlays.retain(|overlay| self.is_overlay_open(overlay));
+        self.overlays = overlays;
+
+        self.overlays.top_modal()
+    }
+
+    fn dismiss_overlay(&mut self, overlay: Overlay) -> Task<AppMsg> {
+        match overlay {
+            Overlay::ActionPanel => Task::done(AppMsg::ToggleActionPanel { keyboard: true }),
+            Overlay::PromptHistory => Task::done(AppMsg::TogglePromptHistory),
+            Overlay::EntrypointArguments | Overlay::AddAlias => {
+                if let GlobalState::MainView { sub_state, .. } = &mut self.global_state {
+                    MainViewState::initial(sub_state);
+                }
+
+                self.close_overlay(overlay)
+            }
+            Overlay::UndoToast => {
+                self.undo_toast = None;
+
+                self.close_overlay(overlay)
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Task<AppMsg> {
+        match self.escape_behavior {
+            EscapeBehavior::Hide => Task::done(AppMsg::HideWindow),
+            EscapeBehavior::ClearThenHide if self.can_clear_prompt() => self.set_prompt("".to_string()),
+            EscapeBehavior::ClearThenHide | EscapeBehavior::Back => {
+                if self.can_exit_search_keyword_mode() {
+                    self.exit_search_keyword_mode()
+                } else {
+                    self.global_state.back(&self.client_context)
+                }
             }
-            GlobalState::MainView { .. } => {}
-            GlobalState::ErrorView { .. } => {}
         }
+    }
 
-        Task::batch(commands)
+    fn can_clear_prompt(&self) -> bool {
+        matches!(self.global_state, GlobalState::MainView { sub_state: MainViewState::None, .. }) && !self.prompt.is_empty()
     }
 
-    fn show_window(&mut self) -> Task<AppMsg> {
-        if let Some(_) = self.main_window_id {
-            return Task::none()
-        };
+    fn can_exit_search_keyword_mode(&self) -> bool {
+        matches!(self.global_state, GlobalState::MainView { sub_state: MainViewState::None, .. }) && self.search_keyword_mode.is_some()
+    }
 
-        #[cfg(target_os = "linux")]
-        let (main_window_id, open_task) =  if self.wayland {
-            open_main_window_wayland()
-        } else {
-            open_main_window_non_wayland()
-        };
+    fn exit_search_keyword_mode(&mut self) -> Task<AppMsg> {
+        self.search_keyword_mode = None;
 
-        #[cfg(not(target_os = "linux"))]
-        let (main_window_id, open_task) = open_main_window_non_wayland();
+        let GlobalState::MainView { search_field_id, .. } = &self.global_state else {
+            return Task::none()
+        };
 
-        self.main_window_id = Some(main_window_id);
+        let search_field_id = search_field_id.clone();
 
         Task::batch([
-            open_task,
-            self.reset_window_state()
+            focus(search_field_id),
+            self.search_prompt(self.prompt.clone()),
         ])
     }
 
-    fn reset_window_state(&mut self) -> Task<AppMsg> {
-        self.prompt = "".to_string();
+    fn merge_search_results(&mut self) {
+        let previous_search_results = search_result_keys(&self.search_results);
 
-        self.client_context.clear_all_inline_views();
+        self.merge_search_results_inner();
 
-        GlobalState::initial(&mut self.global_state)
+        // results can be updated without prompt change, e.g. when late plugin results arrive
+        if let GlobalState::MainView { focused_search_result, .. } = &mut self.global_state {
+            focused_search_result.refresh(&previous_search_results, &search_result_keys(&self.search_results))
+        }
     }
 
-    fn open_plugin_view(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
-        let mut backend_client = self.backend_api.clone();
+    fn merge_search_results_inner(&mut self) {
+        let plugin_search_results: Vec<SearchResult> = if self.plugin_search_results_generation == self.search_results_generation {
+            self.plugin_search_results.iter()
+                .chain(self.late_plugin_search_results.iter())
+                .cloned()
+                .collect()
+        } else {
+            vec![]
+        };
 
-        Task::perform(async move {
-            let result = backend_client.request_view_render(plugin_id, entrypoint_id)
-                .await?;
+        if plugin_search_results.is_empty() {
+            self.search_results = self.index_search_results.clone();
+            return
+        }
 
-            Ok(result)
-        }, |result| handle_backend_error(result, |action_shortcuts| AppMsg::OnOpenView { action_shortcuts }))
+        // fallbacks are shown only when nothing else matched
+        self.search_results = self.index_search_results.iter()
+            .filter(|search_result| !search_result.fallback)
+            .cloned()
+            .chain(plugin_search_results)
+            .collect();
     }
 
-    fn close_plugin_view(&self, plugin_id: PluginId) -> Task<AppMsg> {
-        let mut backend_client = self.backend_api.clone();
+    fn copy_to_clipboard(&self, text: String) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
 
         Task::perform(async move {
-            backend_client.request_view_close(plugin_id)
+            backend_api.copy_to_clipboard(text)
                 .await?;
 
             Ok(())
         }, |result| handle_backend_error(result, |()| AppMsg::Noop))
     }
 
-    fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
-        let mut backend_client = self.backend_api.clone();
+    fn set_search_result_aliases(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, aliases: Vec<String>) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
 
         Task::perform(async move {
-            backend_client.request_run_command(plugin_id, entrypoint_id)
+            backend_api.set_search_result_aliases(plugin_id, entrypoint_id, aliases)
                 .await?;
 
             Ok(())
         }, |result| handle_backend_error(result, |()| AppMsg::Noop))
     }
 
-    fn run_generated_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, action_index: Option<usize>) -> Task<AppMsg> {
-        let mut backend_client = self.backend_api.clone();
+    fn save_bug_report(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, widget_tree: String, screenshot: Screenshot) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
 
         Task::perform(async move {
-            backend_client.request_run_generated_command(plugin_id, entrypoint_id, action_index)
+            let screenshot = tokio::task::spawn_blocking(move || encode_png(screenshot))
+                .await
+                .expect("Unable to encode screenshot");
+
+            backend_api.save_bug_report(plugin_id, entrypoint_id, widget_tree, screenshot)
                 .await?;
 
             Ok(())
-        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
+        }, |result| handle_backend_error(result, |()| AppMsg::ShowHud { display: tr("main-bug-report-saved") }))
     }
 
-    fn handle_plugin_event(&self, widget_event: ComponentWidgetEvent, plugin_id: PluginId, render_location: UiRenderLocation) -> Task<AppMsg> {
-        let mut backend_client = self.backend_api.clone();
-
-        let event = self.client_context.handle_event(render_location, &plugin_id, widget_event.clone());
+    fn undo(&self) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
 
         Task::perform(async move {
-            if let Some(event) = event {
-                match event {
-                    UiViewEvent::View { widget_id, event_name, event_arguments } => {
-                        let msg = match widget_event {
-                            ComponentWidgetEvent::ActionClick { .. } => AppMsg::ToggleActionPanel { keyboard: false },
-                            _ => AppMsg::Noop
-                        };
-
-                        backend_client.send_view_event(plugin_id, widget_id, event_name, event_arguments)
-                            .await?;
-
-                        Ok(msg)
-                    }
-                    UiViewEvent::Open { href } => {
-                        backend_client.send_open_event(plugin_id, href)
-                            .await?;
-
-                        Ok(AppMsg::Noop)
-                    }
-                    UiViewEvent::AppEvent { event } => Ok(event)
-                }
-            } else {
-                Ok(AppMsg::Noop)
-            }
-        }, |result| handle_backend_error(result, |msg| msg))
-    }
-
-    fn handle_main_view_keyboard_event(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, physical_key: PhysicalKey, modifier_shift: bool, modifier_control: bool, modifier_alt: bool, modifier_meta: bool) -> Task<AppMsg> {
-        let mut backend_client = self.backend_api.clone();
-
-        Task::perform(
-            async move {
-                backend_client.send_keyboard_event(plugin_id, entrypoint_id, KeyboardEventOrigin::MainView, physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta)
-                    .await?;
-
-                Ok(())
-            },
-            |result| handle_backend_error(result, |()| AppMsg::Noop),
-        )
-    }
-
-    fn handle_plugin_view_keyboard_event(&self, physical_key: PhysicalKey, modifier_shift: bool, modifier_control: bool, modifier_alt: bool, modifier_meta: bool) -> Task<AppMsg> {
-        let mut backend_client = self.backend_api.clone();
-
-        let (plugin_id, entrypoint_id) = {
-            (self.client_context.get_view_plugin_id(), self.client_context.get_view_entrypoint_id())
-        };
-
-        Task::perform(
-            async move {
-                backend_client.send_keyboard_event(plugin_id, entrypoint_id, KeyboardEventOrigin::PluginView, physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta)
-                    .await?;
-
-                Ok(())
-            },
-            |result| handle_backend_error(result, |()| AppMsg::Noop),
-        )
-    }
-
-    fn handle_inline_plugin_view_keyboard_event(&self, physical_key: PhysicalKey, modifier_shift: bool, modifier_control: bool, modifier_alt: bool, modifier_meta: bool) -> Task<AppMsg> {
-        let mut backend_client = self.backend_api.clone();
-
-        let (plugin_id, entrypoint_id) = {
-            match self.client_context.get_first_inline_view_container() {
-                None => {
-                    return Task::none()
-                },
-                Some(container) => (container.get_plugin_id(), container.get_entrypoint_id())
-            }
-        };
-
-        Task::perform(
-            async move {
-                backend_client.send_keyboard_event(plugin_id, entrypoint_id, KeyboardEventOrigin::PluginView, physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta)
-                    .await?;
+            backend_api.undo()
+                .await?;
 
-                Ok(())
-            },
-            |result| handle_backend_error(result, |()| AppMsg::Noop),
-        )
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
     }
 
-    fn search(&self, new_prompt: String, render_inline_view: bool) -> Task<AppMsg> {
+    fn redo(&self) -> Task<AppMsg> {
         let mut backend_api = self.backend_api.clone();
 
         Task::perform(async move {
-            let search_results = backend_api.search(new_prompt, render_inline_view)
+            backend_api.redo()
                 .await?;
 
-            Ok(search_results)
-        }, |result| handle_backend_error(result, |search_results| AppMsg::SetSearchResults(search_results)))
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
     }
 
     fn open_settings_window_preferences(&self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>) -> Task<AppMsg> {
@@ -2126,6 +5193,26 @@ impl AppModel {
             backend_api.inline_view_shortcuts().await
         }, |result| handle_backend_error(result, |shortcuts| AppMsg::InlineViewShortcuts { shortcuts }))
     }
+
+    fn inline_view_order(&self) -> Task<AppMsg> {
+        let backend_api = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_api.inline_view_order().await
+        }, |result| handle_backend_error(result, |plugin_ids| AppMsg::InlineViewOrder { plugin_ids }))
+    }
+
+    fn load_images(&self, image_ids: Vec<UiImageId>) -> Task<AppMsg> {
+        if image_ids.is_empty() {
+            return Task::none()
+        }
+
+        let backend_api = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_api.get_images(image_ids).await
+        }, |result| handle_backend_error(result, |images| AppMsg::ImagesLoaded { images }))
+    }
 }
 
 // these are needed to force focus the text_input in main search view when
@@ -2135,16 +5222,17 @@ impl AppModel {
         if modifiers.control() || modifiers.alt() || modifiers.logo() {
             Task::none()
         } else {
-            match value {
-                Some(value) => {
-                    if let Some(value) = value.chars().next().filter(|c| !c.is_control()) {
-                        *prompt = format!("{}{}", prompt, value);
-                        focus(search_field_id.clone())
-                    } else {
-                        Task::none()
-                    }
-                }
-                None => Task::none()
+            // text committed by input method or compose sequence can contain multiple characters
+            let value: String = value.iter()
+                .flat_map(|value| value.chars())
+                .filter(|c| !c.is_control())
+                .collect();
+
+            if value.is_empty() {
+                Task::none()
+            } else {
+                prompt.push_str(&value);
+                focus(search_field_id.clone())
             }
         }
     }
@@ -2158,10 +5246,86 @@ impl AppModel {
     }
 }
 
+fn search_category_label(category: Option<SearchResultCategory>) -> String {
+    match category {
+        None => tr("main-category-all"),
+        Some(SearchResultCategory::Application) => tr("main-category-application"),
+        Some(SearchResultCategory::Command) => tr("main-category-command"),
+        Some(SearchResultCategory::Quicklink) => tr("main-category-quicklink"),
+        Some(SearchResultCategory::File) => tr("main-category-file"),
+    }
+}
+
+fn alias_action_labels(search_result: &SearchResult) -> Vec<String> {
+    let mut labels = vec![tr("main-add-alias")];
+
+    if !search_result.aliases.is_empty() {
+        labels.push(tr("main-remove-aliases"));
+    }
+
+    labels
+}
+
+fn copy_item_label(kind: SearchResultCopyKind) -> String {
+    match kind {
+        SearchResultCopyKind::Name => tr("main-copy-name"),
+        SearchResultCopyKind::Path => tr("main-copy-path"),
+        SearchResultCopyKind::Url => tr("main-copy-url"),
+        SearchResultCopyKind::Id => tr("main-copy-id"),
+    }
+}
+
+fn widget_inspector_text(state: &AppModel, view_container: &PluginWidgetContainer) -> String {
+    let timing = state.render_timing
+        .as_ref()
+        .filter(|timing| timing.plugin_id == view_container.get_plugin_id() && timing.entrypoint_id == view_container.get_entrypoint_id());
+
+    let js_render_time = timing.map(|timing| format!("{:.2}ms", timing.js_render_time_ms)).unwrap_or("-".to_string());
+    let serialization_time = timing.map(|timing| format!("{:.2}ms", timing.serialization_time_ms)).unwrap_or("-".to_string());
+    let layout_time = view_container.layout_time()
+        .map(|duration| format!("{:.2}ms", duration.as_secs_f64() * 1000.0))
+        .unwrap_or("-".to_string());
+
+    format!(
+        "JS render: {}\nSerialization: {}\nClient layout: {}\n\n{}",
+        js_render_time,
+        serialization_time,
+        layout_time,
+        view_container.inspect()
+    )
+}
+
+fn encode_png(screenshot: Screenshot) -> Vec<u8> {
+    let mut png = vec![];
+
+    let image = image::RgbaImage::from_raw(screenshot.size.width, screenshot.size.height, screenshot.bytes.to_vec())
+        .expect("screenshot size should match its bytes");
+
+    image.write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .expect("Unable to encode screenshot");
+
+    png
+}
+
+// plugins loaded from local directory during development
+fn search_result_keys(search_results: &[SearchResult]) -> Vec<(PluginId, EntrypointId)> {
+    search_results.iter()
+        .map(|search_result| (search_result.plugin_id.clone(), search_result.entrypoint_id.clone()))
+        .collect()
+}
+
+fn is_dev_plugin(plugin_id: &PluginId) -> bool {
+    plugin_id.to_string().starts_with("file://")
+}
+
 fn handle_backend_error<T>(result: Result<T, BackendForFrontendApiError>, convert: impl FnOnce(T) -> AppMsg) -> AppMsg {
+    handle_backend_error_with_retry(result, None, convert)
+}
+
+fn handle_backend_error_with_retry<T>(result: Result<T, BackendForFrontendApiError>, retry: Option<AppMsg>, convert: impl FnOnce(T) -> AppMsg) -> AppMsg {
     match result {
         Ok(val) => convert(val),
-        Err(err) => AppMsg::ShowBackendError(err)
+        Err(err) => AppMsg::ShowBackendError(err, retry.map(Box::new))
     }
 }
 
@@ -2181,7 +5345,7 @@ async fn request_loop(
                     entrypoint_id,
                     entrypoint_name,
                     render_location,
-                    top_level_view,
+                    view_stack_depth,
                     container,
                     images
                 } => {
@@ -2193,7 +5357,7 @@ async fn request_loop(
                         entrypoint_id,
                         entrypoint_name,
                         render_location,
-                        top_level_view,
+                        view_stack_depth,
                         container: Arc::new(container),
                         images
                     }
@@ -2210,6 +5374,36 @@ async fn request_loop(
 
                     AppMsg::ShowWindow
                 }
+                UiRequestData::HideWindow => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::HideWindow
+                }
+                UiRequestData::ToggleWindow => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ToggleWindow
+                }
+                UiRequestData::Quit => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::Quit
+                }
+                UiRequestData::PreviewTheme { theme } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::PreviewTheme { theme }
+                }
+                UiRequestData::ShowPluginView { plugin_id, plugin_name, entrypoint_id, entrypoint_name } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowWindowWithView {
+                        plugin_id,
+                        plugin_name,
+                        entrypoint_id,
+                        entrypoint_name,
+                    }
+                }
                 UiRequestData::ShowPreferenceRequiredView {
                     plugin_id,
                     entrypoint_id,
@@ -2234,6 +5428,37 @@ async fn request_loop(
                         render_location,
                     }
                 }
+                UiRequestData::ShowRenderErrorView { plugin_id, entrypoint_id, message, component_path } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowRenderErrorView {
+                        plugin_id,
+                        entrypoint_id,
+                        message,
+                        component_path,
+                    }
+                }
+                UiRequestData::ShowRenderTiming { plugin_id, entrypoint_id, js_render_time_ms, serialization_time_ms } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowRenderTiming {
+                        plugin_id,
+                        entrypoint_id,
+                        js_render_time_ms,
+                        serialization_time_ms,
+                    }
+                }
+                UiRequestData::ShowArgumentSuggestions { plugin_id, entrypoint_id, argument_id, generation, suggestions } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowArgumentSuggestions {
+                        plugin_id,
+                        entrypoint_id,
+                        argument_id,
+                        generation,
+                        suggestions,
+                    }
+                }
                 UiRequestData::RequestSearchResultUpdate => {
                     responder.respond(UiResponseData::Nothing);
 
@@ -2246,12 +5471,62 @@ async fn request_loop(
                         display
                     }
                 }
+                UiRequestData::ShowUndoToast { description } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowUndoToast {
+                        description
+                    }
+                }
                 UiRequestData::SetGlobalShortcut { shortcut } => {
                     AppMsg::SetGlobalShortcut {
                         shortcut,
                         responder: Arc::new(Mutex::new(Some(responder)))
                     }
                 }
+                UiRequestData::ShowRecoveryView { safe_mode, description, crash_report_path } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowRecoveryView {
+                        safe_mode,
+                        description,
+                        crash_report_path,
+                    }
+                }
+                UiRequestData::ShowConfigError { error } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowConfigError {
+                        error,
+                    }
+                }
+                UiRequestData::ConfigReloaded => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ConfigReloaded
+                }
+                UiRequestData::SessionLockChanged { locked } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::SessionLockChanged { locked }
+                }
+                UiRequestData::ShowDatabaseRecoveryView { reason, broken_database_path, restored_backup_path } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowDatabaseRecoveryView {
+                        reason,
+                        broken_database_path,
+                        restored_backup_path,
+                    }
+                }
+                UiRequestData::ShowWhatsNewView { version, changelog } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowWhatsNewView {
+                        version,
+                        changelog,
+                    }
+                }
                 UiRequestData::UpdateLoadingBar { plugin_id, entrypoint_id, show } => {
                     responder.respond(UiResponseData::Nothing);
 
@@ -2261,6 +5536,34 @@ async fn request_loop(
                         show
                     }
                 }
+                UiRequestData::UpdateActionShortcuts { plugin_id, entrypoint_id, shortcuts } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::UpdateActionShortcuts {
+                        plugin_id,
+                        entrypoint_id,
+                        shortcuts
+                    }
+                }
+                UiRequestData::ShowSearchResultPreview { plugin_id, entrypoint_id, preview } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowSearchResultPreview {
+                        plugin_id,
+                        entrypoint_id,
+                        preview
+                    }
+                }
+                UiRequestData::AppendSearchResults { generation, plugin_id, results, late } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::AppendSearchResults {
+                        generation,
+                        plugin_id,
+                        results,
+                        late,
+                    }
+                }
             }
         };
 
@@ -0,0 +1,130 @@
+use iced::widget::text_input;
+
+// state of each overlay is kept by the view it is opened in, e.g. MainViewState::SearchResultActionPanel,
+// stack only keeps the order in which they were opened and what was focused before
+pub struct OverlayStack {
+    layers: Vec<OverlayLayer>,
+}
+
+struct OverlayLayer {
+    overlay: Overlay,
+    restore_focus: FocusTarget,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlay {
+    ActionPanel,
+    PromptHistory,
+    EntrypointArguments,
+    AddAlias,
+    UndoToast,
+}
+
+impl Overlay {
+    // modal overlays receive keyboard input and are closed by Escape, others are only shown on top
+    pub fn is_modal(&self) -> bool {
+        match self {
+            Overlay::ActionPanel => true,
+            Overlay::PromptHistory => true,
+            Overlay::EntrypointArguments => true,
+            Overlay::AddAlias => true,
+            Overlay::UndoToast => false,
+        }
+    }
+
+    // text fields behind the overlay stop receiving typed text while it is open.
+    // prompt history is filtered using search field, entrypoint arguments and alias focus their own fields
+    pub fn captures_focus(&self) -> bool {
+        match self {
+            Overlay::ActionPanel => true,
+            Overlay::PromptHistory => false,
+            Overlay::EntrypointArguments => false,
+            Overlay::AddAlias => false,
+            Overlay::UndoToast => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum FocusTarget {
+    SearchField(text_input::Id),
+    // focused control or search bar of plugin view, whichever was focused last
+    PluginView,
+    None,
+}
+
+impl OverlayStack {
+    pub fn new() -> Self {
+        Self {
+            layers: vec![],
+        }
+    }
+
+    // overlay that is already open is moved to the top, keeping focus it restores on close
+    pub fn open(&mut self, overlay: Overlay, restore_focus: FocusTarget) {
+        let restore_focus = match self.remove(overlay) {
+            Some(previous) => previous,
+            None => restore_focus,
+        };
+
+        self.layers.push(OverlayLayer { overlay, restore_focus })
+    }
+
+    // returns what was focused when overlay was opened, None if overlay was not open
+    pub fn close(&mut self, overlay: Overlay) -> Option<FocusTarget> {
+        self.remove(overlay)
+    }
+
+    pub fn top_modal(&self) -> Option<Overlay> {
+        self.layers
+            .iter()
+            .rev()
+            .map(|layer| layer.overlay)
+            .find(|overlay| overlay.is_modal())
+    }
+
+    // overlays can also disappear together with the view they were opened in, e.g. when plugin view is closed
+    pub fn retain(&mut self, mut is_open: impl FnMut(Overlay) -> bool) {
+        self.layers.retain(|layer| is_open(layer.overlay))
+    }
+
+    fn remove(&mut self, overlay: Overlay) -> Option<FocusTarget> {
+        let index = self.layers.iter().position(|layer| layer.overlay == overlay)?;
+
+        Some(self.layers.remove(index).restore_focus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_target_is_topmost_modal_overlay() {
+        let mut stack = OverlayStack::new();
+
+        stack.open(Overlay::ActionPanel, FocusTarget::PluginView);
+        stack.open(Overlay::UndoToast, FocusTarget::None);
+
+        assert_eq!(stack.top_modal(), Some(Overlay::ActionPanel));
+
+        stack.open(Overlay::PromptHistory, FocusTarget::None);
+
+        assert_eq!(stack.top_modal(), Some(Overlay::PromptHistory));
+
+        stack.close(Overlay::PromptHistory);
+
+        assert_eq!(stack.top_modal(), Some(Overlay::ActionPanel));
+    }
+
+    #[test]
+    fn reopened_overlay_keeps_original_focus() {
+        let mut stack = OverlayStack::new();
+
+        stack.open(Overlay::ActionPanel, FocusTarget::PluginView);
+        stack.open(Overlay::ActionPanel, FocusTarget::None);
+
+        assert!(matches!(stack.close(Overlay::ActionPanel), Some(FocusTarget::PluginView)));
+        assert!(stack.close(Overlay::ActionPanel).is_none());
+    }
+}
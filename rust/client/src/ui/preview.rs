@@ -0,0 +1,188 @@
+use iced::advanced::image::Handle;
+use iced::widget::text::Shaping;
+use iced::widget::{column, container, image, scrollable, text};
+use iced::Length;
+use gauntlet_common::i18n::tr;
+use gauntlet_common::model::{EntrypointId, PluginId, SearchResult, SearchResultEntrypointType, SearchResultPreview};
+
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::{Element, ThemableWidget};
+
+// quick look at focused search result, shown next to the list of results
+pub struct PreviewPane {
+    shown: bool,
+    // search result for which preview was requested last
+    requested: Option<(PluginId, EntrypointId)>,
+    content: PreviewContent,
+}
+
+enum PreviewContent {
+    // search result doesn't provide preview, only details known to frontend are shown
+    None,
+    Loading,
+    Loaded {
+        text: Option<String>,
+        image: Option<Handle>,
+        metadata: Vec<(String, String)>,
+    },
+}
+
+impl PreviewPane {
+    pub fn new() -> Self {
+        Self {
+            shown: false,
+            requested: None,
+            content: PreviewContent::None,
+        }
+    }
+
+    pub fn shown(&self) -> bool {
+        self.shown
+    }
+
+    pub fn toggle(&mut self) {
+        self.shown = !self.shown;
+        self.requested = None;
+        self.content = PreviewContent::None;
+    }
+
+    // returns search result for which preview needs to be requested from plugin
+    pub fn focus_changed(&mut self, search_result: Option<&SearchResult>) -> Option<(PluginId, EntrypointId)> {
+        if !self.shown {
+            return None
+        }
+
+        let key = search_result.map(|search_result| (search_result.plugin_id.clone(), search_result.entrypoint_id.clone()));
+
+        if key == self.requested {
+            return None
+        }
+
+        self.requested = key.clone();
+
+        match search_result {
+            Some(search_result) if search_result.entrypoint_preview => {
+                self.content = PreviewContent::Loading;
+
+                key
+            }
+            _ => {
+                self.content = PreviewContent::None;
+
+                None
+            }
+        }
+    }
+
+    pub fn set_preview(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, preview: Option<SearchResultPreview>) {
+        // focus could have moved while plugin was preparing the preview
+        if self.requested != Some((plugin_id, entrypoint_id)) {
+            return
+        }
+
+        self.content = match preview {
+            None => PreviewContent::None,
+            Some(preview) => {
+                PreviewContent::Loaded {
+                    text: preview.text,
+                    image: preview.image.map(|bytes| Handle::from_bytes(bytes)),
+                    metadata: preview.metadata
+                        .into_iter()
+                        .map(|item| (item.label, item.value))
+                        .collect(),
+                }
+            }
+        }
+    }
+
+    pub fn render<'a, Message: 'a>(&self, search_result: Option<&SearchResult>) -> Element<'a, Message> {
+        let Some(search_result) = search_result else {
+            return container(column(vec![]))
+                .width(Length::FillPortion(2))
+                .height(Length::Fill)
+                .themed(ContainerStyle::DetailMetadata)
+        };
+
+        let title: Element<_> = text(search_result.entrypoint_name.clone())
+            .shaping(Shaping::Advanced)
+            .size(18)
+            .into();
+
+        let mut content = vec![title];
+
+        match &self.content {
+            PreviewContent::None => {}
+            PreviewContent::Loading => {
+                let loading: Element<_> = text(tr("loading"))
+                    .themed(TextStyle::MetadataItemLabel);
+
+                content.push(container(loading).themed(ContainerStyle::ContentParagraph));
+            }
+            PreviewContent::Loaded { text: preview_text, image: preview_image, metadata } => {
+                if let Some(handle) = preview_image {
+                    let preview_image: Element<_> = image(handle.clone())
+                        .into();
+
+                    content.push(container(preview_image).width(Length::Fill).themed(ContainerStyle::ContentImage));
+                }
+
+                if let Some(preview_text) = preview_text {
+                    let preview_text: Element<_> = text(preview_text.clone())
+                        .shaping(Shaping::Advanced)
+                        .into();
+
+                    content.push(container(preview_text).width(Length::Fill).themed(ContainerStyle::ContentParagraph));
+                }
+
+                for (label, value) in metadata {
+                    content.push(preview_metadata_item(label.clone(), value.clone()));
+                }
+            }
+        }
+
+        let entrypoint_type = match search_result.entrypoint_type {
+            SearchResultEntrypointType::Command => tr("main-preview-type-command"),
+            SearchResultEntrypointType::View => tr("main-preview-type-view"),
+            SearchResultEntrypointType::GeneratedCommand => tr("main-preview-type-generated-command"),
+        };
+
+        content.push(preview_metadata_item(tr("main-preview-type"), entrypoint_type));
+        content.push(preview_metadata_item(tr("main-preview-plugin"), search_result.plugin_name.clone()));
+
+        let content: Element<_> = column(content)
+            .into();
+
+        let content: Element<_> = container(content)
+            .width(Length::Fill)
+            .themed(ContainerStyle::MetadataInner);
+
+        let content: Element<_> = scrollable(content)
+            .width(Length::Fill)
+            .into();
+
+        container(content)
+            .width(Length::FillPortion(2))
+            .height(Length::Fill)
+            .themed(ContainerStyle::DetailMetadata)
+    }
+}
+
+fn preview_metadata_item<'a, Message: 'a>(label: String, value: String) -> Element<'a, Message> {
+    let label: Element<_> = text(label)
+        .shaping(Shaping::Advanced)
+        .themed(TextStyle::MetadataItemLabel);
+
+    let label = container(label)
+        .themed(ContainerStyle::MetadataItemLabel);
+
+    let value: Element<_> = text(value)
+        .shaping(Shaping::Advanced)
+        .into();
+
+    let value = container(value)
+        .themed(ContainerStyle::MetadataItemValue);
+
+    column(vec![label, value])
+        .into()
+}
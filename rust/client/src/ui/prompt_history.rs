@@ -0,0 +1,154 @@
+use std::fs;
+
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::frontend_config::PromptHistoryConfig;
+
+// previous search queries, recalled with arrow keys in empty prompt or searched with ctrl+r
+pub struct PromptHistory {
+    enabled: bool,
+    max_size: usize,
+    // oldest first
+    entries: Vec<String>,
+    // index of entry currently shown in prompt while cycling through history
+    position: Option<usize>,
+}
+
+impl PromptHistory {
+    pub fn load(config: &PromptHistoryConfig) -> Self {
+        // screenshots and scenarios should not depend on previous runs
+        let enabled = config.enabled && !cfg!(feature = "scenario_runner");
+
+        let entries = if enabled {
+            match fs::read_to_string(Dirs::new().prompt_history_file()) {
+                Ok(content) => {
+                    content.lines()
+                        .filter(|line| !line.trim().is_empty())
+                        .map(|line| line.to_string())
+                        .collect()
+                }
+                Err(_) => vec![],
+            }
+        } else {
+            vec![]
+        };
+
+        let mut history = Self {
+            enabled,
+            max_size: config.max_size,
+            entries,
+            position: None,
+        };
+
+        history.truncate();
+
+        history
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn add(&mut self, query: &str) {
+        self.position = None;
+
+        let query = query.trim();
+
+        if !self.enabled || query.is_empty() {
+            return;
+        }
+
+        // repeated query is moved to the end instead of being stored twice
+        self.entries.retain(|entry| entry != query);
+        self.entries.push(query.to_string());
+
+        self.truncate();
+        self.save();
+    }
+
+    // whether prompt currently shows an entry recalled from history
+    pub fn is_recalling(&self, prompt: &str) -> bool {
+        self.position
+            .and_then(|position| self.entries.get(position))
+            .is_some_and(|entry| entry == prompt)
+    }
+
+    pub fn reset_position(&mut self) {
+        self.position = None;
+    }
+
+    pub fn previous(&mut self) -> Option<String> {
+        let position = match self.position {
+            None => self.entries.len().checked_sub(1)?,
+            Some(position) => position.checked_sub(1)?,
+        };
+
+        self.position = Some(position);
+
+        self.entries.get(position).cloned()
+    }
+
+    // going past the newest entry returns to empty prompt
+    pub fn next(&mut self) -> Option<String> {
+        let position = self.position?;
+
+        if position + 1 < self.entries.len() {
+            self.position = Some(position + 1);
+
+            self.entries.get(position + 1).cloned()
+        } else {
+            self.position = None;
+
+            Some(String::new())
+        }
+    }
+
+    // newest first, entries containing query as is are shown before fuzzy matches
+    pub fn search(&self, query: &str) -> Vec<String> {
+        let query = query.trim().to_lowercase();
+
+        let mut exact = vec![];
+        let mut fuzzy = vec![];
+
+        for entry in self.entries.iter().rev() {
+            let lowercase_entry = entry.to_lowercase();
+
+            if lowercase_entry.contains(&query) {
+                exact.push(entry.clone());
+            } else if is_subsequence(&query, &lowercase_entry) {
+                fuzzy.push(entry.clone());
+            }
+        }
+
+        exact.extend(fuzzy);
+
+        exact
+    }
+
+    fn truncate(&mut self) {
+        if self.entries.len() > self.max_size {
+            let excess = self.entries.len() - self.max_size;
+
+            self.entries.drain(..excess);
+        }
+    }
+
+    fn save(&self) {
+        let path = Dirs::new().prompt_history_file();
+
+        let result = path.parent()
+            .map(|dir| fs::create_dir_all(dir))
+            .unwrap_or(Ok(()))
+            .and_then(|_| fs::write(&path, self.entries.join("\n")));
+
+        if let Err(err) = result {
+            tracing::warn!("Unable to save prompt history to {:?}: {:?}", path, err);
+        }
+    }
+}
+
+fn is_subsequence(query: &str, value: &str) -> bool {
+    let mut value = value.chars();
+
+    query.chars()
+        .all(|query_char| value.any(|value_char| value_char == query_char))
+}
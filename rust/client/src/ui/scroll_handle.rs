@@ -1,17 +1,36 @@
+use std::collections::HashSet;
+use std::convert::identity;
+use std::hash::Hash;
 use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 use iced::Task;
 use iced::widget::scrollable::{scroll_to, AbsoluteOffset, Id};
-use crate::ui::AppMsg;
+use crate::ui::{AppMsg, GauntletComplexTheme};
 
 pub const ESTIMATED_MAIN_LIST_ITEM_HEIGHT: f32 = 38.8;
 pub const ESTIMATED_ACTION_ITEM_HEIGHT: f32 = 38.8; // TODO
 
+// rows kept between focused item and top or bottom edge of the list while moving with keyboard
+const SCROLL_OFF: usize = 1;
+
+const SCROLL_ANIMATION_STEPS: u32 = 6;
+const SCROLL_ANIMATION_FRAME: Duration = Duration::from_millis(16);
+
 #[derive(Clone, Debug)]
 pub struct ScrollHandle<T> {
     phantom: PhantomData<T>,
     pub scrollable_id: Id,
     pub index: Option<usize>,
+    // row of focused item counted from the top of visible part of the list
     offset: usize,
+    // last position reported by scrollable, it is also moved by mouse wheel and scrollbar
+    scroll_offset: f32,
+    // incremented for every scroll, remaining frames of previous animation are skipped
+    scroll_generation: Arc<AtomicU64>,
+    // focus was moved by user since the list was reset, so it follows focused item when list is refreshed
+    follows_item: bool,
     rows_per_view: usize,
     item_height: f32,
 }
@@ -23,6 +42,9 @@ impl<T> ScrollHandle<T> {
             scrollable_id: Id::unique(),
             index: if first_focused { Some(0) } else { None },
             offset: 0,
+            scroll_offset: 0.0,
+            scroll_generation: Arc::new(AtomicU64::new(0)),
+            follows_item: false,
             rows_per_view,
             item_height,
         }
@@ -31,12 +53,73 @@ impl<T> ScrollHandle<T> {
     pub fn reset(&mut self, first_focused: bool) {
         self.index = if first_focused { Some(0) } else { None };
         self.offset = 0;
+        self.follows_item = false;
     }
 
     pub fn unfocus(&mut self) {
         self.index = None;
     }
 
+    // focus is moved by mouse to an item that is already visible, so list is not scrolled
+    pub fn focus_visible(&mut self, index: usize) {
+        self.index = Some(index);
+        self.offset = self.visible_row(index);
+        self.follows_item = true;
+    }
+
+    // list was scrolled by wheel or scrollbar, keyboard navigation continues from what is visible now
+    pub fn scrolled(&mut self, scroll_offset: f32) {
+        self.scroll_offset = scroll_offset;
+
+        if let Some(index) = self.index {
+            self.offset = self.visible_row(index);
+        }
+    }
+
+    // for grids, where offset is counted in rows of items, only position animation starts from is updated
+    pub fn set_scroll_offset(&mut self, scroll_offset: f32) {
+        self.scroll_offset = scroll_offset;
+    }
+
+    // list got new items, e.g. plugin rendered it again or search results were updated for the same query.
+    // when most items are the same, focus moves together with the item it was on and scroll position is kept,
+    // otherwise list is treated as a new one. keys identify items between the two versions of the list
+    pub fn refresh<K: Eq + Hash>(&mut self, previous: &[K], current: &[K]) {
+        // first item stays focused until user moves focus
+        if !self.follows_item {
+            return
+        }
+
+        let Some(focused) = self.index.and_then(|index| previous.get(index)) else {
+            return
+        };
+
+        let previous_keys: HashSet<&K> = previous.iter().collect();
+
+        let same_count = current.iter()
+            .filter(|key| previous_keys.contains(key))
+            .count();
+
+        if same_count * 2 < previous.len().max(current.len()) {
+            self.reset(self.index.is_some());
+            return
+        }
+
+        match current.iter().position(|key| key == focused) {
+            Some(index) => self.focus_visible(index),
+            None if current.is_empty() => self.index = None,
+            // focused item was removed, item that took its place is focused instead
+            None => self.focus_visible(self.index.unwrap_or(0).min(current.len() - 1)),
+        }
+    }
+
+    // focused item that was scrolled out of view is brought back at the top or bottom edge on next key press
+    fn visible_row(&self, index: usize) -> usize {
+        let row = (index as f32 * self.item_height - self.scroll_offset) / self.item_height;
+
+        row.round().clamp(0.0, self.rows_per_view as f32) as usize
+    }
+
     pub fn get<'a>(&self, search_results: &'a [T]) -> Option<&'a T> {
         match self.index {
             None => None,
@@ -52,17 +135,16 @@ impl<T> ScrollHandle<T> {
     }
 
     pub fn focus_next_in(&mut self, total_item_amount: usize, amount: usize) -> Option<usize> {
-        self.offset = if self.offset < self.rows_per_view {
-            self.offset + 1
-        } else {
-            self.rows_per_view
-        };
+        self.follows_item = true;
+
+        self.offset = (self.offset + 1).min(self.rows_per_view.saturating_sub(SCROLL_OFF));
 
         match self.index.as_mut() {
             None => {
                 // focus first
                 if total_item_amount > 0 {
                     self.index = Some(0);
+                    self.offset = 0;
 
                     Some(0)
                 } else {
@@ -91,11 +173,9 @@ impl<T> ScrollHandle<T> {
     }
 
     pub fn focus_previous_in(&mut self, amount: usize) -> Option<usize> {
-        self.offset = if self.offset > 1 {
-            self.offset - 1
-        } else {
-            1
-        };
+        self.follows_item = true;
+
+        self.offset = self.offset.saturating_sub(1).max(SCROLL_OFF);
 
         match self.index.as_mut() {
             None => None,
@@ -104,6 +184,9 @@ impl<T> ScrollHandle<T> {
                     Some(new_index) => {
                         *index = new_index;
 
+                        // there are not enough rows above the first items to keep the margin
+                        self.offset = self.offset.min(new_index);
+
                         Some(new_index)
                     }
                     None => None
@@ -112,9 +195,132 @@ impl<T> ScrollHandle<T> {
         }
     }
 
-    pub fn scroll_to<Message: 'static>(&self, row_index: usize) -> Task<Message> {
-        let pos_y = row_index as f32 * self.item_height - (self.offset as f32 * self.item_height);
+    // list was shown again, e.g. after going back from plugin view, so it is scrolled to focused item without animation
+    pub fn restore_scroll(&mut self) -> Task<AppMsg> {
+        let Some(index) = self.index else {
+            return Task::none()
+        };
+
+        let target = (index as f32 * self.item_height - (self.offset as f32 * self.item_height)).max(0.0);
 
-        scroll_to(self.scrollable_id.clone(), AbsoluteOffset { x: 0.0, y: pos_y })
+        self.scroll_offset = target;
+
+        scroll_to(self.scrollable_id.clone(), AbsoluteOffset { x: 0.0, y: target })
     }
-}
\ No newline at end of file
+
+    pub fn scroll_to(&self, row_index: usize) -> Task<AppMsg> {
+        let target = (row_index as f32 * self.item_height - (self.offset as f32 * self.item_height)).max(0.0);
+
+        self.animate_scroll(target)
+    }
+
+    // scrollable doesn't support animated scrolling, so position is moved a bit every frame
+    fn animate_scroll(&self, target: f32) -> Task<AppMsg> {
+        let generation = self.scroll_generation.fetch_add(1, Ordering::Relaxed) + 1;
+
+        if GauntletComplexTheme::reduced_motion() {
+            return scroll_to(self.scrollable_id.clone(), AbsoluteOffset { x: 0.0, y: target })
+        }
+
+        let start = self.scroll_offset;
+        let distance = target - start;
+
+        let frames = (1..=SCROLL_ANIMATION_STEPS)
+            .map(|step| {
+                let scroll_generation = self.scroll_generation.clone();
+                let scrollable_id = self.scrollable_id.clone();
+
+                // ease out, the list moves fast at first and slows down near the target
+                let progress = 1.0 - (1.0 - step as f32 / SCROLL_ANIMATION_STEPS as f32).powi(3);
+                let position = start + distance * progress;
+
+                Task::perform(async move {
+                    tokio::time::sleep(SCROLL_ANIMATION_FRAME * step).await;
+
+                    // newer scroll was started in the meantime
+                    scroll_generation.load(Ordering::Relaxed) == generation
+                }, identity)
+                    .then(move |current| {
+                        if current {
+                            scroll_to(scrollable_id.clone(), AbsoluteOffset { x: 0.0, y: position })
+                        } else {
+                            Task::none()
+                        }
+                    })
+            });
+
+        Task::batch(frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::theme::init_default_theme;
+
+    #[test]
+    fn restored_scroll_keeps_focused_item_on_its_row() {
+        let mut handle: ScrollHandle<()> = ScrollHandle::new(true, 10.0, 7);
+
+        handle.scrolled(50.0);
+        handle.focus_visible(8);
+
+        let _ = handle.restore_scroll();
+
+        assert_eq!(handle.index, Some(8));
+        assert_eq!(handle.scroll_offset, 50.0);
+    }
+
+    #[test]
+    fn hovered_item_keeps_its_row_in_view() {
+        let mut handle: ScrollHandle<()> = ScrollHandle::new(true, 10.0, 7);
+
+        handle.scrolled(50.0);
+        handle.focus_visible(8);
+
+        assert_eq!(handle.index, Some(8));
+        assert_eq!(handle.offset, 3);
+    }
+
+    #[test]
+    fn focused_item_scrolled_out_of_view_is_at_the_edge() {
+        let mut handle: ScrollHandle<()> = ScrollHandle::new(true, 10.0, 7);
+
+        handle.scrolled(200.0);
+        assert_eq!(handle.offset, 0);
+
+        handle.focus_visible(30);
+        handle.scrolled(0.0);
+        assert_eq!(handle.offset, 7);
+    }
+
+    #[test]
+    fn focus_follows_item_when_list_is_refreshed() {
+        init_default_theme();
+
+        let mut handle: ScrollHandle<()> = ScrollHandle::new(true, 10.0, 7);
+
+        handle.focus_next(5);
+        handle.focus_next(5);
+
+        handle.refresh(&["a", "b", "c", "d", "e"], &["new", "a", "b", "c", "d", "e"]);
+        assert_eq!(handle.index, Some(3));
+
+        handle.refresh(&["new", "a", "b", "c", "d", "e"], &["x", "y", "z", "c"]);
+        assert_eq!(handle.index, Some(0));
+    }
+
+    #[test]
+    fn keeps_margin_below_focused_item() {
+        init_default_theme();
+
+        let mut handle: ScrollHandle<()> = ScrollHandle::new(true, 10.0, 7);
+
+        for _ in 0..10 {
+            handle.focus_next(20);
+        }
+
+        assert_eq!(handle.index, Some(10));
+        assert_eq!(handle.offset, 6);
+    }
+}
@@ -2,21 +2,32 @@ use iced::{Alignment, Length};
 use iced::advanced::image::Handle;
 use iced::widget::{column, Component, container, horizontal_space};
 use iced::widget::button;
+use iced::widget::mouse_area;
 use iced::widget::component;
 use iced::widget::row;
 use iced::widget::text;
-use iced::widget::text::Shaping;
-use gauntlet_common::model::SearchResult;
+use iced::widget::text::{Shaping, Span};
+use iced::widget::{rich_text, span};
+use iced::Font;
+use gauntlet_common::i18n::tr;
+use gauntlet_common::model::{SearchResult, SearchResultBadge};
+use crate::ui::custom_widgets::drag_source::DragSource;
+use crate::ui::drag::DRAG_SUPPORTED;
 use crate::ui::scroll_handle::ScrollHandle;
 use crate::ui::theme::{Element, GauntletComplexTheme, ThemableWidget};
 use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
 use crate::ui::theme::image::ImageStyle;
+use crate::ui::theme::row::RowStyle;
 use crate::ui::theme::space::ThemeKindSpace;
+use crate::ui::theme::span::{SpanStyle, ThemableSpan};
 use crate::ui::theme::text::TextStyle;
 
 pub struct SearchList<'a, Message> {
     on_select: Box<dyn Fn(SearchResult) -> Message>,
+    on_drag: Box<dyn Fn(SearchResult) -> Message>,
+    on_focus: Box<dyn Fn(usize) -> Message>,
+    on_context_menu: Box<dyn Fn(usize) -> Message>,
     focused_search_result: Option<usize>,
     search_results: &'a[SearchResult],
 }
@@ -25,47 +36,78 @@ pub fn search_list<'a, Message>(
     search_results: &'a[SearchResult],
     focused_search_result: &ScrollHandle<SearchResult>,
     on_select: impl Fn(SearchResult) -> Message + 'static,
+    on_drag: impl Fn(SearchResult) -> Message + 'static,
+    on_focus: impl Fn(usize) -> Message + 'static,
+    on_context_menu: impl Fn(usize) -> Message + 'static,
 ) -> SearchList<'a, Message> {
-    SearchList::new(search_results, focused_search_result.index, on_select)
+    SearchList::new(search_results, focused_search_result.index, on_select, on_drag, on_focus, on_context_menu)
 }
 
 #[derive(Debug, Clone)]
-pub struct SelectItemEvent(SearchResult);
+pub enum SearchListEvent {
+    Select(SearchResult),
+    Drag(SearchResult),
+    Focus(usize),
+    ContextMenu(usize),
+}
 
 impl<'a, Message> SearchList<'a, Message> {
     pub fn new(
         search_results: &'a[SearchResult],
         focused_search_result: Option<usize>,
         on_open_view: impl Fn(SearchResult) -> Message + 'static,
+        on_drag: impl Fn(SearchResult) -> Message + 'static,
+        on_focus: impl Fn(usize) -> Message + 'static,
+        on_context_menu: impl Fn(usize) -> Message + 'static,
     ) -> Self {
         Self {
             search_results,
             focused_search_result,
             on_select: Box::new(on_open_view),
+            on_drag: Box::new(on_drag),
+            on_focus: Box::new(on_focus),
+            on_context_menu: Box::new(on_context_menu),
         }
     }
 }
 
 impl<'a, Message> Component<Message, GauntletComplexTheme> for SearchList<'a, Message> {
     type State = ();
-    type Event = SelectItemEvent;
+    type Event = SearchListEvent;
 
     fn update(
         &mut self,
         _state: &mut Self::State,
-        SelectItemEvent(event): SelectItemEvent,
+        event: SearchListEvent,
     ) -> Option<Message> {
-        Some((self.on_select)(event))
+        match event {
+            SearchListEvent::Select(search_result) => Some((self.on_select)(search_result)),
+            SearchListEvent::Drag(search_result) => Some((self.on_drag)(search_result)),
+            SearchListEvent::Focus(index) => {
+                // cursor moves within the same item
+                if self.focused_search_result == Some(index) {
+                    None
+                } else {
+                    Some((self.on_focus)(index))
+                }
+            }
+            SearchListEvent::ContextMenu(index) => Some((self.on_context_menu)(index)),
+        }
     }
 
-    fn view(&self, _state: &Self::State) -> Element<SelectItemEvent> {
-        let items: Vec<Element<_>> = self.search_results
+    fn view(&self, _state: &Self::State) -> Element<SearchListEvent> {
+        let mut items: Vec<Element<_>> = self.search_results
             .iter()
             .enumerate()
             .map(|(index, search_result)| {
-                let main_text: Element<_> = text(&search_result.entrypoint_name)
-                    .shaping(Shaping::Advanced)
-                    .into();
+                let main_text: Element<_> = if search_result.entrypoint_name_matches.is_empty() {
+                    text(&search_result.entrypoint_name)
+                        .shaping(Shaping::Advanced)
+                        .into()
+                } else {
+                    rich_text(name_spans(search_result))
+                        .into()
+                };
                 let main_text: Element<_> = container(main_text)
                     .themed(ContainerStyle::MainListItemText);
 
@@ -101,6 +143,44 @@ impl<'a, Message> Component<Message, GauntletComplexTheme> for SearchList<'a, Me
 
                 button_content.push(main_text);
                 button_content.push(spacer);
+
+                if let Some(badge) = &search_result.entrypoint_badge {
+                    let badge_text = match badge {
+                        SearchResultBadge::Count(count) if *count > 99 => "99+".to_string(),
+                        SearchResultBadge::Count(count) => count.to_string(),
+                        SearchResultBadge::Dot => "●".to_string(),
+                    };
+
+                    let badge: Element<_> = text(badge_text)
+                        .themed(TextStyle::MainListItemSubtext);
+                    let badge: Element<_> = container(badge)
+                        .themed(ContainerStyle::MainListItemBadge);
+                    let badge: Element<_> = container(badge)
+                        .themed(ContainerStyle::MainListItemSubText);
+
+                    button_content.push(badge);
+                }
+
+                if let Some(accessory) = &search_result.entrypoint_accessory {
+                    let accessory: Element<_> = text(accessory)
+                        .shaping(Shaping::Advanced)
+                        .themed(TextStyle::MainListItemSubtext);
+                    let accessory: Element<_> = container(accessory)
+                        .themed(ContainerStyle::MainListItemSubText);
+
+                    button_content.push(accessory);
+                }
+
+                if !search_result.aliases.is_empty() {
+                    let aliases: Element<_> = text(search_result.aliases.join(", "))
+                        .shaping(Shaping::Advanced)
+                        .themed(TextStyle::MainListItemSubtext);
+                    let aliases: Element<_> = container(aliases)
+                        .themed(ContainerStyle::MainListItemSubText);
+
+                    button_content.push(aliases);
+                }
+
                 button_content.push(sub_text);
 
                 let button_content: Element<_> = row(button_content)
@@ -118,17 +198,74 @@ impl<'a, Message> Component<Message, GauntletComplexTheme> for SearchList<'a, Me
                     }
                 };
 
-                button(button_content)
+                let button: Element<_> = button(button_content)
                     .width(Length::Fill)
-                    .on_press(SelectItemEvent(search_result.clone()))
-                    .themed(style)
+                    .on_press(SearchListEvent::Select(search_result.clone()))
+                    .themed(style);
+
+                let button: Element<_> = if DRAG_SUPPORTED && search_result.entrypoint_drag.is_some() {
+                    DragSource::new(button)
+                        .on_drag(SearchListEvent::Drag(search_result.clone()))
+                        .into()
+                } else {
+                    button
+                };
+
+                // focus follows the cursor only when it moves, so that item under resting cursor
+                // doesn't take focus when list is scrolled with keyboard
+                mouse_area(button)
+                    .on_move(move |_| SearchListEvent::Focus(index))
+                    .on_right_press(SearchListEvent::ContextMenu(index))
+                    .into()
             })
             .collect();
 
+        // favorites and then recently used entrypoints are always placed before other results
+        let favorite_count = self.search_results
+            .iter()
+            .take_while(|search_result| search_result.favorite)
+            .count();
+
+        let recent_count = self.search_results
+            .iter()
+            .skip(favorite_count)
+            .take_while(|search_result| search_result.recent)
+            .count();
+
+        let section_count = favorite_count + recent_count;
+
+        if section_count > 0 && section_count < items.len() {
+            items.insert(section_count, section_title(tr("main-results"), RowStyle::ListSectionTitle));
+        }
+
+        if recent_count > 0 {
+            let style = if favorite_count > 0 {
+                RowStyle::ListSectionTitle
+            } else {
+                RowStyle::ListFirstSectionTitle
+            };
+
+            items.insert(favorite_count, section_title(tr("main-recent"), style));
+        }
+
+        if favorite_count > 0 {
+            items.insert(0, section_title(tr("main-favorites"), RowStyle::ListFirstSectionTitle));
+        }
+
         column(items).into()
     }
 }
 
+fn section_title<'a>(title: String, style: RowStyle) -> Element<'a, SearchListEvent> {
+    let title: Element<_> = text(title)
+        .shaping(Shaping::Advanced)
+        .size(15)
+        .themed(TextStyle::ListSectionTitle);
+
+    row([title])
+        .themed(style)
+}
+
 impl<'a, Message> From<SearchList<'a, Message>> for Element<'a, Message>
     where
         Message: 'a,
@@ -136,4 +273,32 @@ impl<'a, Message> From<SearchList<'a, Message>> for Element<'a, Message>
     fn from(search_list: SearchList<'a, Message>) -> Self {
         component(search_list)
     }
-}
\ No newline at end of file
+}
+// parts of the name matched by the query are highlighted
+fn name_spans<'a>(search_result: &SearchResult) -> Vec<Span<'a, SearchListEvent, Font>> {
+    let name = &search_result.entrypoint_name;
+
+    let mut spans = vec![];
+    let mut position = 0;
+
+    for range in &search_result.entrypoint_name_matches {
+        // invalid or overlapping range is skipped instead of crashing the ui
+        if range.start < position || name.get(range.clone()).is_none() {
+            continue
+        }
+
+        if position < range.start {
+            spans.push(span(name[position..range.start].to_string()));
+        }
+
+        spans.push(span(name[range.clone()].to_string()).themed(SpanStyle::MainListItemTextMatch));
+
+        position = range.end;
+    }
+
+    if position < name.len() {
+        spans.push(span(name[position..].to_string()));
+    }
+
+    spans
+}
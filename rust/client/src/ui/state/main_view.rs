@@ -1,5 +1,9 @@
 use crate::ui::scroll_handle::{ScrollHandle, ESTIMATED_ACTION_ITEM_HEIGHT};
-use gauntlet_common::model::{SearchResultEntrypointAction, UiWidgetId};
+use crate::ui::AppMsg;
+use gauntlet_common::model::{SearchResult, SearchResultEntrypointAction, UiWidgetId};
+use iced::widget::text_input;
+use iced::widget::text_input::focus;
+use iced::Task;
 
 pub enum MainViewState {
     None,
@@ -10,9 +14,45 @@ pub enum MainViewState {
     InlineViewActionPanel {
         // ephemeral state
         focused_action_item: ScrollHandle<UiWidgetId>,
+    },
+    PromptHistory {
+        // ephemeral state
+        focused_history_item: ScrollHandle<String>,
+
+        // state
+        entries: Vec<String>,
+    },
+    EntrypointArguments {
+        // logic
+        field_ids: Vec<text_input::Id>,
+
+        // ephemeral state
+        focused_field: usize,
+        // validation error of argument with the same index, shown in prompt row while it is focused
+        errors: Vec<Option<String>>,
+        // suggestions provided by plugin for argument with this index
+        suggestions: Option<(usize, Vec<String>)>,
+
+        // state
+        search_result: SearchResult,
+        values: Vec<String>,
+    },
+    AddAlias {
+        // logic
+        field_id: text_input::Id,
+
+        // state
+        search_result: SearchResult,
+        value: String,
     }
 }
 
+// line under argument inputs, describes argument which is currently focused
+pub enum ArgumentPromptRow<'a> {
+    Hint(&'a str),
+    Error(&'a str),
+}
+
 impl MainViewState {
     pub fn new() -> Self {
         MainViewState::None
@@ -33,4 +73,182 @@ impl MainViewState {
             focused_action_item: ScrollHandle::new(focus_first, ESTIMATED_ACTION_ITEM_HEIGHT, 7),
         }
     }
+
+    pub fn prompt_history(prev_state: &mut MainViewState, entries: Vec<String>) {
+        *prev_state = Self::PromptHistory {
+            focused_history_item: ScrollHandle::new(true, ESTIMATED_ACTION_ITEM_HEIGHT, 7),
+            entries,
+        }
+    }
+
+    pub fn entrypoint_arguments(prev_state: &mut MainViewState, search_result: SearchResult, prompt: &str) -> Task<AppMsg> {
+        let values = arguments_from_prompt(prompt, &search_result.entrypoint_name, search_result.entrypoint_arguments.len());
+
+        let field_ids: Vec<_> = search_result.entrypoint_arguments
+            .iter()
+            .map(|_| text_input::Id::unique())
+            .collect();
+
+        let focused_field = values.iter()
+            .position(|value| value.is_empty())
+            .unwrap_or(0);
+
+        let focus_task = focus(field_ids[focused_field].clone());
+
+        *prev_state = Self::EntrypointArguments {
+            field_ids,
+            focused_field,
+            errors: vec![None; field_ids.len()],
+            suggestions: None,
+            search_result,
+            values,
+        };
+
+        focus_task
+    }
+
+    pub fn add_alias(prev_state: &mut MainViewState, search_result: SearchResult) -> Task<AppMsg> {
+        let field_id = text_input::Id::unique();
+
+        let focus_task = focus(field_id.clone());
+
+        *prev_state = Self::AddAlias {
+            field_id,
+            search_result,
+            value: "".to_string(),
+        };
+
+        focus_task
+    }
+
+    pub fn argument_prompt_row(&self) -> Option<ArgumentPromptRow<'_>> {
+        let MainViewState::EntrypointArguments { search_result, focused_field, errors, .. } = self else {
+            return None
+        };
+
+        if let Some(error) = &errors[*focused_field] {
+            return Some(ArgumentPromptRow::Error(error))
+        }
+
+        search_result.entrypoint_arguments[*focused_field].hint
+            .as_deref()
+            .map(ArgumentPromptRow::Hint)
+    }
+
+    // empty value of required argument is only an error when submitting
+    pub fn validate_argument(&mut self, index: usize) {
+        if let MainViewState::EntrypointArguments { search_result, values, errors, .. } = self {
+            let value = &values[index];
+
+            errors[index] = if value.trim().is_empty() {
+                None
+            } else {
+                search_result.entrypoint_arguments[index].parse_value(value)
+                    .err()
+                    .map(|err| err.to_string())
+            };
+        }
+    }
+
+    // focuses first invalid argument, returns None if there is none
+    pub fn validate_all_arguments(&mut self) -> Option<Task<AppMsg>> {
+        let MainViewState::EntrypointArguments { search_result, values, errors, field_ids, focused_field, .. } = self else {
+            return None
+        };
+
+        for (index, argument) in search_result.entrypoint_arguments.iter().enumerate() {
+            errors[index] = argument.parse_value(&values[index])
+                .err()
+                .map(|err| err.to_string());
+        }
+
+        let invalid_field = errors.iter().position(|error| error.is_some())?;
+
+        *focused_field = invalid_field;
+
+        Some(focus(field_ids[invalid_field].clone()))
+    }
+
+    pub fn focus_next_argument(&mut self) -> Task<AppMsg> {
+        match self {
+            MainViewState::EntrypointArguments { field_ids, focused_field, .. } => {
+                *focused_field = (*focused_field + 1) % field_ids.len();
+
+                focus(field_ids[*focused_field].clone())
+            }
+            _ => Task::none()
+        }
+    }
+
+    pub fn focus_previous_argument(&mut self) -> Task<AppMsg> {
+        match self {
+            MainViewState::EntrypointArguments { field_ids, focused_field, .. } => {
+                *focused_field = (*focused_field + field_ids.len() - 1) % field_ids.len();
+
+                focus(field_ids[*focused_field].clone())
+            }
+            _ => Task::none()
+        }
+    }
+}
+
+// words at the start of the prompt that are prefixes of entrypoint name words, in the same order, are what was used to find it,
+// the rest are argument values separated by whitespace, last argument takes whatever is left
+fn arguments_from_prompt(prompt: &str, entrypoint_name: &str, count: usize) -> Vec<String> {
+    let entrypoint_name = entrypoint_name.to_lowercase();
+
+    let mut rest = prompt.trim_start();
+
+    for name_word in entrypoint_name.split_whitespace() {
+        let Some(word) = rest.split_whitespace().next() else {
+            break;
+        };
+
+        if !name_word.starts_with(&word.to_lowercase()) {
+            break;
+        }
+
+        rest = rest[word.len()..].trim_start();
+    }
+
+    let mut values = vec![];
+
+    for index in 0..count {
+        if index == count - 1 {
+            values.push(rest.trim().to_string());
+        } else {
+            match rest.split_once(char::is_whitespace) {
+                Some((value, remaining)) => {
+                    values.push(value.to_string());
+                    rest = remaining.trim_start();
+                }
+                None => {
+                    values.push(rest.to_string());
+                    rest = "";
+                }
+            }
+        }
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arguments_from_prompt_strips_entrypoint_name() {
+        assert_eq!(arguments_from_prompt("translate hello world", "Translate", 1), vec!["hello world"]);
+        assert_eq!(arguments_from_prompt("tr hello world", "Translate", 2), vec!["hello", "world"]);
+        assert_eq!(arguments_from_prompt("search wiki rust lang", "Search Wikipedia", 1), vec!["rust lang"]);
+        assert_eq!(arguments_from_prompt("search", "Search Wikipedia", 1), vec![""]);
+    }
+
+    #[test]
+    fn arguments_from_prompt_keeps_substrings_of_entrypoint_name() {
+        assert_eq!(arguments_from_prompt("translate ran", "Translate", 1), vec!["ran"]);
+        assert_eq!(arguments_from_prompt("translate a", "Translate", 1), vec!["a"]);
+        assert_eq!(arguments_from_prompt("search pedia", "Search Wikipedia", 1), vec!["pedia"]);
+    }
 }
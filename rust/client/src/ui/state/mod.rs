@@ -3,10 +3,11 @@ mod plugin_view;
 
 use crate::ui::client_context::ClientContext;
 use crate::ui::scroll_handle::{ScrollHandle, ESTIMATED_MAIN_LIST_ITEM_HEIGHT};
-pub use crate::ui::state::main_view::MainViewState;
+pub use crate::ui::state::main_view::{ArgumentPromptRow, MainViewState};
 pub use crate::ui::state::plugin_view::PluginViewState;
 use crate::ui::AppMsg;
-use gauntlet_common::model::{EntrypointId, PhysicalShortcut, PluginId, SearchResult};
+use gauntlet_common::error::GauntletError;
+use gauntlet_common::model::{ConfigError, EntrypointId, PhysicalShortcut, PluginId, SearchResult, UiRenderLocation};
 use iced::widget::text_input;
 use iced::widget::text_input::focus;
 use iced::Task;
@@ -32,17 +33,31 @@ pub enum GlobalState {
         // state
         plugin_view_data: PluginViewData,
         sub_state: PluginViewState,
+        // focus of search results the view was opened from, restored when going back to search
+        search_focus: Option<ScrollHandle<SearchResult>>,
     },
 }
 
+// what main window shows, used to pick animation when it changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Screen {
+    Main,
+    Plugin {
+        view_stack_depth: usize,
+    },
+    Error,
+}
+
 #[derive(Clone)]
 pub struct PluginViewData {
-    pub top_level_view: bool,
+    pub view_stack_depth: usize,
     pub plugin_id: PluginId,
     pub plugin_name: String,
     pub entrypoint_id: EntrypointId,
     pub entrypoint_name: String,
     pub action_shortcuts: HashMap<String, PhysicalShortcut>,
+    // window is not hidden on focus loss while view of interactive plugin is open
+    pub interactive: bool,
 }
 
 pub enum ErrorViewData {
@@ -55,10 +70,38 @@ pub enum ErrorViewData {
     PluginError {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        // set when client panicked while rendering plugin view
+        panic_message: Option<String>,
+    },
+    RenderError {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        message: String,
+        component_path: Vec<String>,
+    },
+    BackendTimeout {
+        // message that started the request, sent again when retry button is pressed
+        retry: Option<Box<AppMsg>>,
     },
-    BackendTimeout,
-    UnknownError {
-        display: String
+    BackendError {
+        error: GauntletError
+    },
+    Recovery {
+        safe_mode: bool,
+        description: String,
+        crash_report_path: Option<String>,
+    },
+    ConfigError {
+        error: ConfigError,
+    },
+    DatabaseRecovery {
+        reason: String,
+        broken_database_path: String,
+        restored_backup_path: Option<String>,
+    },
+    WhatsNew {
+        version: u16,
+        changelog: String,
     },
 }
 
@@ -87,10 +130,19 @@ impl GlobalState {
         }
     }
 
-    pub fn new_plugin(plugin_view_data: PluginViewData) -> GlobalState {
+    pub fn new_plugin(plugin_view_data: PluginViewData, search_focus: Option<ScrollHandle<SearchResult>>) -> GlobalState {
         GlobalState::PluginView {
             plugin_view_data,
             sub_state: PluginViewState::new(),
+            search_focus,
+        }
+    }
+
+    pub fn screen(&self) -> Screen {
+        match self {
+            GlobalState::MainView { .. } => Screen::Main,
+            GlobalState::PluginView { plugin_view_data, .. } => Screen::Plugin { view_stack_depth: plugin_view_data.view_stack_depth },
+            GlobalState::ErrorView { .. } => Screen::Error,
         }
     }
 
@@ -105,6 +157,28 @@ impl GlobalState {
         ])
     }
 
+    // the same as initial, but search result the plugin view was opened from stays focused.
+    // search is repeated for the same prompt, so focus follows that result if list has changed
+    pub fn back_to_search(prev_global_state: &mut GlobalState) -> Task<AppMsg> {
+        let search_focus = match prev_global_state {
+            GlobalState::PluginView { search_focus, .. } => search_focus.take(),
+            GlobalState::MainView { .. } | GlobalState::ErrorView { .. } => None,
+        };
+
+        let task = GlobalState::initial(prev_global_state);
+
+        let (Some(search_focus), GlobalState::MainView { focused_search_result, .. }) = (search_focus, prev_global_state) else {
+            return task
+        };
+
+        *focused_search_result = search_focus;
+
+        Task::batch([
+            task,
+            focused_search_result.restore_scroll(),
+        ])
+    }
+
     pub fn error(prev_global_state: &mut GlobalState, error_view_data: ErrorViewData) -> Task<AppMsg> {
         *prev_global_state = GlobalState::new_error(error_view_data);
 
@@ -112,7 +186,12 @@ impl GlobalState {
     }
 
     pub fn plugin(prev_global_state: &mut GlobalState, plugin_view_data: PluginViewData) -> Task<AppMsg> {
-        *prev_global_state = GlobalState::new_plugin(plugin_view_data);
+        let search_focus = match prev_global_state {
+            GlobalState::MainView { focused_search_result, .. } => Some(focused_search_result.clone()),
+            GlobalState::PluginView { .. } | GlobalState::ErrorView { .. } => None,
+        };
+
+        *prev_global_state = GlobalState::new_plugin(plugin_view_data, search_focus);
 
         Task::none()
     }
@@ -164,6 +243,18 @@ impl Focus<SearchResult> for GlobalState {
                             }
                         }
                     }
+                    MainViewState::PromptHistory { focused_history_item, .. } => {
+                        match focused_history_item.index {
+                            None => Task::none(),
+                            Some(index) => Task::done(AppMsg::SelectPromptHistoryEntry { index })
+                        }
+                    }
+                    MainViewState::EntrypointArguments { .. } => {
+                        Task::done(AppMsg::SubmitEntrypointArguments)
+                    }
+                    MainViewState::AddAlias { .. } => {
+                        Task::done(AppMsg::SubmitAlias)
+                    }
                 }
             }
             GlobalState::PluginView { sub_state, .. } => {
@@ -171,7 +262,14 @@ impl Focus<SearchResult> for GlobalState {
 
                 match sub_state {
                     PluginViewState::None => {
-                        if let Some(widget_id) = action_ids.get(0) {
+                        // focused checkbox, link and others are activated instead of running primary action
+                        if let Some(widget_event) = client_context.focused_control_event() {
+                            Task::done(AppMsg::WidgetEvent {
+                                widget_event,
+                                plugin_id: client_context.get_view_plugin_id(),
+                                render_location: UiRenderLocation::View,
+                            })
+                        } else if let Some(widget_id) = action_ids.get(0) {
                             let widget_id = *widget_id;
                             Task::done(AppMsg::OnAnyActionPluginViewNoPanelKeyboardWithFocus { widget_id })
                         } else {
@@ -204,10 +302,12 @@ impl Focus<SearchResult> for GlobalState {
                             Task::done(AppMsg::OnSecondaryActionMainViewNoPanelKeyboardWithoutFocus)
                         }
                     }
-                    MainViewState::SearchResultActionPanel { .. } | MainViewState::InlineViewActionPanel { .. } => {
+                    MainViewState::SearchResultActionPanel { .. } | MainViewState::InlineViewActionPanel { .. } | MainViewState::PromptHistory { .. } => {
                         // secondary does nothing when action panel is opened
                         Task::none()
                     }
+                    MainViewState::EntrypointArguments { .. } => Task::none(),
+                    MainViewState::AddAlias { .. } => Task::none(),
                 }
             }
             GlobalState::PluginView { sub_state, .. } => {
@@ -234,11 +334,15 @@ impl Focus<SearchResult> for GlobalState {
 
     fn back(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { sub_state, .. } => {
+            GlobalState::MainView { sub_state, search_field_id, .. } => {
                 match sub_state {
                     MainViewState::None => {
                         Task::perform(async {}, |_| AppMsg::HideWindow)
                     }
+                    MainViewState::EntrypointArguments { .. } | MainViewState::AddAlias { .. } => {
+                        MainViewState::initial(sub_state);
+                        focus(search_field_id.clone())
+                    }
                     MainViewState::SearchResultActionPanel { .. } => {
                         MainViewState::initial(sub_state);
                         Task::none()
@@ -247,11 +351,15 @@ impl Focus<SearchResult> for GlobalState {
                         MainViewState::initial(sub_state);
                         Task::none()
                     }
+                    MainViewState::PromptHistory { .. } => {
+                        MainViewState::initial(sub_state);
+                        Task::none()
+                    }
                 }
             }
             GlobalState::PluginView {
                 plugin_view_data: PluginViewData {
-                    top_level_view,
+                    view_stack_depth,
                     plugin_id,
                     entrypoint_id,
                     ..
@@ -261,17 +369,17 @@ impl Focus<SearchResult> for GlobalState {
             } => {
                 match sub_state {
                     PluginViewState::None => {
-                        if *top_level_view {
-                            let plugin_id = plugin_id.clone();
+                        let plugin_id = plugin_id.clone();
+                        let entrypoint_id = entrypoint_id.clone();
 
+                        if *view_stack_depth <= 1 {
                             Task::batch([
-                                Task::done(AppMsg::ClosePluginView(plugin_id)),
-                                GlobalState::initial(self)
+                                Task::done(AppMsg::ClosePluginView(plugin_id, entrypoint_id)),
+                                GlobalState::back_to_search(self)
                             ])
                         } else {
-                            let plugin_id = plugin_id.clone();
-                            let entrypoint_id = entrypoint_id.clone();
-                            Task::done(AppMsg::OpenPluginView(plugin_id, entrypoint_id))
+                            // plugin keeps parent views mounted, so popping restores them with their state
+                            Task::done(AppMsg::PopPluginView(plugin_id, entrypoint_id))
                         }
                     }
                     PluginViewState::ActionPanel { .. } => {
@@ -284,17 +392,27 @@ impl Focus<SearchResult> for GlobalState {
             }
         }
     }
-    fn next(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
+    fn next(&mut self, client_context: &ClientContext) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { .. } => Task::none(),
-            GlobalState::PluginView { .. } => Task::none(),
+            GlobalState::MainView { sub_state, .. } => sub_state.focus_next_argument(),
+            GlobalState::PluginView { sub_state, .. } => {
+                match sub_state {
+                    PluginViewState::None => client_context.focus_next_control(),
+                    PluginViewState::ActionPanel { .. } => Task::none()
+                }
+            },
             GlobalState::ErrorView { .. } => Task::none(),
         }
     }
-    fn previous(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
+    fn previous(&mut self, client_context: &ClientContext) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { .. } => Task::none(),
-            GlobalState::PluginView { .. } => Task::none(),
+            GlobalState::MainView { sub_state, .. } => sub_state.focus_previous_argument(),
+            GlobalState::PluginView { sub_state, .. } => {
+                match sub_state {
+                    PluginViewState::None => client_context.focus_previous_control(),
+                    PluginViewState::ActionPanel { .. } => Task::none()
+                }
+            },
             GlobalState::ErrorView { .. } => Task::none(),
         }
     }
@@ -314,6 +432,12 @@ impl Focus<SearchResult> for GlobalState {
                         focused_action_item.focus_previous()
                             .unwrap_or_else(|| Task::none())
                     }
+                    MainViewState::PromptHistory { focused_history_item, .. } => {
+                        focused_history_item.focus_previous()
+                            .unwrap_or_else(|| Task::none())
+                    }
+                    MainViewState::EntrypointArguments { .. } => Task::none(),
+                    MainViewState::AddAlias { .. } => Task::none(),
                 }
             }
             GlobalState::ErrorView { .. } => Task::none(),
@@ -344,8 +468,8 @@ impl Focus<SearchResult> for GlobalState {
                     }
                     MainViewState::SearchResultActionPanel { focused_action_item } => {
                         if let Some(search_item) = focused_search_result.get(focus_list) {
-                            if search_item.entrypoint_actions.len() != 0 {
-                                focused_action_item.focus_next(search_item.entrypoint_actions.len() + 1)
+                            if search_item.secondary_action_count() != 0 {
+                                focused_action_item.focus_next(search_item.secondary_action_count() + 1)
                                     .unwrap_or_else(|| Task::none())
                             } else {
                                 Task::none()
@@ -367,6 +491,16 @@ impl Focus<SearchResult> for GlobalState {
                             None => Task::none()
                         }
                     }
+                    MainViewState::PromptHistory { focused_history_item, entries } => {
+                        if entries.len() != 0 {
+                            focused_history_item.focus_next(entries.len())
+                                .unwrap_or_else(|| Task::none())
+                        } else {
+                            Task::none()
+                        }
+                    }
+                    MainViewState::EntrypointArguments { .. } => Task::none(),
+                    MainViewState::AddAlias { .. } => Task::none(),
                 }
             }
             GlobalState::ErrorView { .. } => Task::none(),
@@ -3,6 +3,7 @@ use image::ImageFormat;
 pub fn create_tray() -> tray_icon::TrayIcon {
     use tray_icon::TrayIconBuilder;
     use tray_icon::menu::{MenuEvent, Menu, MenuItem, PredefinedMenuItem, AboutMetadataBuilder};
+    use gauntlet_common::i18n::tr;
 
     MenuEvent::set_event_handler(Some(|event: MenuEvent| {
         match event.id().as_ref() {
@@ -12,6 +13,12 @@ pub fn create_tray() -> tray_icon::TrayIcon {
             "GAUNTLET_OPEN_SETTING_WINDOW" => {
                 crate::open_settings_window()
             }
+            "GAUNTLET_QUIT" => {
+                // predefined quit item would stop event loop right away, without shutting down server
+                if let Err(err) = crate::quit() {
+                    tracing::error!("Unable to quit: {:?}", err)
+                }
+            }
             _ => {}
         }
     }));
@@ -48,11 +55,11 @@ pub fn create_tray() -> tray_icon::TrayIcon {
     let menu = Menu::with_items(
         &[
             &MenuItem::new("Gauntlet", false, None),
-            &MenuItem::with_id("GAUNTLET_OPEN_MAIN_WINDOW", "Open", true, None),
-            &MenuItem::with_id("GAUNTLET_OPEN_SETTING_WINDOW", "Open Settings", true, None),
+            &MenuItem::with_id("GAUNTLET_OPEN_MAIN_WINDOW", tr("tray-open"), true, None),
+            &MenuItem::with_id("GAUNTLET_OPEN_SETTING_WINDOW", tr("tray-open-settings"), true, None),
             &PredefinedMenuItem::separator(),
-            &PredefinedMenuItem::about(Some("About..."), Some(about_metadata)),
-            &PredefinedMenuItem::quit(Some("Quit Gauntlet")),
+            &PredefinedMenuItem::about(Some(&tr("tray-about")), Some(about_metadata)),
+            &MenuItem::with_id("GAUNTLET_QUIT", tr("tray-quit"), true, None),
         ]
     ).expect("unable to create tray menu");
 
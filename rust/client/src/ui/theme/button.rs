@@ -23,6 +23,7 @@ pub enum ButtonStyle {
     RootBottomPanelPrimaryActionButton,
     RootTopPanelBackButton,
     MetadataTagItem,
+    MetadataTagItemSelected,
 }
 
 impl ButtonStyle {
@@ -68,7 +69,7 @@ impl ButtonStyle {
             ButtonStyle::MetadataLink => {
                 padding_all(0.0).to_iced()
             }
-            ButtonStyle::MetadataTagItem => {
+            ButtonStyle::MetadataTagItem | ButtonStyle::MetadataTagItemSelected => {
                 let theme = &theme.metadata_tag_item_button;
                 theme.padding.to_iced()
             }
@@ -81,6 +82,10 @@ impl ButtonStyle {
         }
     }
 
+    fn is_focused(&self) -> bool {
+        matches!(self, ButtonStyle::GridItemFocused | ButtonStyle::ActionFocused | ButtonStyle::ListItemFocused | ButtonStyle::MainListItemFocused | ButtonStyle::MetadataTagItemSelected)
+    }
+
     fn appearance(&self, theme: &GauntletComplexTheme, state: Status) -> Style {
         let (background_color, background_color_hover, background_color_pressed, text_color, text_color_hover, border_radius, border_width, border_color) = match &self {
             ButtonStyle::RootBottomPanelPrimaryActionButton | ButtonStyle::RootBottomPanelActionToggleButton => {
@@ -131,6 +136,10 @@ impl ButtonStyle {
                 let theme = &theme.metadata_tag_item_button;
                 (Some(&theme.background_color), Some(&theme.background_color_hovered), Some(&theme.background_color), &theme.text_color, &theme.text_color_hovered, &theme.border_radius, &theme.border_width, &theme.border_color)
             }
+            ButtonStyle::MetadataTagItemSelected => {
+                let theme = &theme.metadata_tag_item_button;
+                (Some(&theme.background_color_hovered), Some(&theme.background_color_hovered), Some(&theme.background_color_hovered), &theme.text_color_hovered, &theme.text_color_hovered, &theme.border_radius, &theme.border_width, &theme.border_color)
+            }
             ButtonStyle::ShouldNotBeUsed => {
                 (Some(&NOT_INTENDED_TO_BE_USED), Some(&NOT_INTENDED_TO_BE_USED), Some(&NOT_INTENDED_TO_BE_USED), &NOT_INTENDED_TO_BE_USED, &NOT_INTENDED_TO_BE_USED, &0.0, &1.0, &TRANSPARENT)
             }
@@ -151,6 +160,20 @@ impl ButtonStyle {
             ..Default::default()
         };
 
+        // focused item is outlined, so it doesn't rely only on background color
+        let active = if theme.accessibility.high_contrast && self.is_focused() {
+            Style {
+                border: Border {
+                    color: theme.text.to_iced(),
+                    width: 2.0,
+                    ..active.border
+                },
+                ..active
+            }
+        } else {
+            active
+        };
+
         match state {
             Status::Active => active,
             Status::Pressed => {
@@ -0,0 +1,34 @@
+use iced::widget::Canvas;
+use crate::ui::custom_widgets::chart;
+use crate::ui::custom_widgets::chart::{Chart, Style};
+use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::GauntletComplexTheme;
+
+#[derive(Default)]
+pub enum ChartStyle {
+    #[default]
+    Default,
+}
+
+impl chart::Catalog for GauntletComplexTheme {
+    type Class<'a> = ChartStyle;
+
+    fn default<'a>() -> Self::Class<'a> {
+        ChartStyle::Default
+    }
+
+    fn style(&self, _class: &Self::Class<'_>) -> Style {
+        Style {
+            color: self.content_chart.color.to_iced(),
+            baseline_color: self.content_chart.baseline_color.to_iced(),
+        }
+    }
+}
+
+impl<'a, Message: 'a> ThemableWidget<'a, Message> for Canvas<Chart<'a, GauntletComplexTheme>, Message, GauntletComplexTheme> {
+    type Kind = ChartStyle;
+
+    fn themed(self, _kind: ChartStyle) -> Element<'a, Message> {
+        self.into()
+    }
+}
@@ -10,6 +10,8 @@ pub enum ContainerStyle {
     ActionShortcutModifiersInit, // "init" means every item on list except last one
     ContentCodeBlock,
     ContentCodeBlockText,
+    ContentColorSwatch(Color),
+    ContentChart,
     ContentHorizontalBreak,
     ContentImage,
     ContentParagraph,
@@ -17,6 +19,7 @@ pub enum ContainerStyle {
     DetailContentInner,
     DetailMetadata,
     EmptyViewImage,
+    FocusOutline,
     FormInputLabel,
     Inline,
     ListItemSubtitle,
@@ -25,10 +28,13 @@ pub enum ContainerStyle {
     Main,
     MainList,
     MainListInner,
+    MainListItemBadge,
     MainListItemIcon,
     MainListItemSubText,
     MainListItemText,
     MainSearchBar,
+    MainSearchFilters,
+    MainUndoToast,
     MetadataInner,
     MetadataItemValue,
     MetadataItemLabel,
@@ -58,11 +64,14 @@ pub enum ContainerStyle {
     HudInner,
     Hud,
     RootBottomPanelPrimaryActionButton,
+    WidgetInspector,
 }
 
 pub enum ContainerStyleInner {
     Transparent,
 
+    FocusOutline,
+
     Tooltip,
 
     ActionPanel,
@@ -71,6 +80,7 @@ pub enum ContainerStyleInner {
     Main,
     Root,
     ContentImage,
+    ContentColorSwatch(Color),
     RootBottomPanel,
     InlineInner,
     Hud,
@@ -87,6 +97,19 @@ impl container::Catalog for GauntletComplexTheme {
     fn style(&self, class: &Self::Class<'_>) -> Style {
         match class {
             ContainerStyleInner::Transparent => Default::default(),
+            ContainerStyleInner::FocusOutline => {
+                let theme = &self.form_input_text_field;
+
+                // same color as text, so that it is visible with any background
+                Style {
+                    border: Border {
+                        radius: theme.border_radius.into(),
+                        width: 2.0,
+                        color: self.text.to_iced(),
+                    },
+                    ..Style::default()
+                }
+            }
             ContainerStyleInner::ActionPanel => {
                 let root_theme = &self.root;
                 let panel_theme = &self.action_panel;
@@ -196,6 +219,20 @@ impl container::Catalog for GauntletComplexTheme {
                     ..Style::default()
                 }
             }
+            ContainerStyleInner::ContentColorSwatch(color) => {
+                // same rounding as images
+                let theme = &self.content_image;
+
+                Style {
+                    background: Some((*color).into()),
+                    border: Border {
+                        radius: theme.border_radius.into(),
+                        width: 1.0,
+                        color: self.separator.color.to_iced(),
+                    },
+                    ..Style::default()
+                }
+            }
             ContainerStyleInner::RootBottomPanel => {
                 let root_theme = &self.root;
                 let panel_theme = &self.root_bottom_panel;
@@ -314,6 +351,12 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
                 self.class(ContainerStyleInner::ContentImage)
                     .padding(theme.content_image.padding.to_iced())
             }
+            ContainerStyle::ContentColorSwatch(color) => {
+                self.class(ContainerStyleInner::ContentColorSwatch(color))
+            }
+            ContainerStyle::ContentChart => {
+                self.padding(theme.content_chart.padding.to_iced())
+            }
             ContainerStyle::DetailContentInner => {
                 self.padding(theme.metadata_content_inner.padding.to_iced())
             }
@@ -329,6 +372,9 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
             ContainerStyle::DetailContent => {
                 self.padding(theme.detail_content.padding.to_iced())
             }
+            ContainerStyle::FocusOutline => {
+                self.class(ContainerStyleInner::FocusOutline)
+            }
             ContainerStyle::FormInputLabel => {
                 self.padding(theme.form_input_label.padding.to_iced())
             }
@@ -357,6 +403,11 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
             ContainerStyle::MainListItemSubText => {
                 self.padding(theme.main_list_item_sub_text.padding.to_iced())
             }
+            ContainerStyle::MainListItemBadge => {
+                // looks the same as keys of shortcuts
+                self.class(ContainerStyleInner::ActionShortcutModifier)
+                    .padding(theme.action_shortcut_modifier.padding.to_iced())
+            }
             ContainerStyle::MainListItemIcon => {
                 self.padding(theme.main_list_item_icon.padding.to_iced())
             }
@@ -369,6 +420,18 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
             ContainerStyle::MainSearchBar => {
                 self.padding(theme.main_search_bar.padding.to_iced())
             }
+            ContainerStyle::MainSearchFilters => {
+                // aligned with search results
+                self.padding(theme.main_list_inner.padding.to_iced())
+            }
+            ContainerStyle::MainUndoToast => {
+                self.padding(theme.main_list_inner.padding.to_iced())
+            }
+            ContainerStyle::WidgetInspector => {
+                // looks the same as code block
+                self.class(ContainerStyleInner::ContentCodeBlockText)
+                    .padding(theme.content_code_block_text.padding.to_iced())
+            }
             ContainerStyle::Root => {
                 self.class(ContainerStyleInner::Root)
             }
@@ -5,6 +5,8 @@ use iced::application::DefaultStyle;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use gauntlet_common::dirs::Dirs;
+use gauntlet_common::frontend_config::{read_frontend_config, AccessibilityConfig, ThemeSelection};
+use gauntlet_common::model::{SimpleThemeTokens, ThemeTokenColor};
 
 pub mod button;
 pub mod text_input;
@@ -15,12 +17,16 @@ pub mod date_picker;
 pub mod image;
 pub mod pick_list;
 pub mod checkbox;
+pub mod slider;
 pub mod scrollable;
 pub mod rule;
 pub mod space;
 pub mod grid;
 pub mod tooltip;
+pub mod chart;
+pub mod span;
 mod loading_bar;
+mod transition;
 
 pub type Element<'a, Message> = iced::Element<'a, Message, GauntletComplexTheme>;
 
@@ -58,9 +64,11 @@ pub struct GauntletComplexTheme {
     action_shortcut_modifier: ThemeActionShortcutModifier,
     content_code_block: ThemePaddingOnly,
     content_code_block_text: ThemeCode,
+    content_chart: ThemeChart,
     content_horizontal_break: ThemePaddingOnly,
     content_image: ThemeImage,
     content_paragraph: ThemePaddingOnly,
+    content_paragraph_span: ThemeParagraphSpan,
     detail_content: ThemePaddingOnly,
     detail_metadata: ThemePaddingOnly,
     empty_view_image: ThemePaddingSize,
@@ -73,6 +81,7 @@ pub struct GauntletComplexTheme {
     form_input_date_picker_buttons: ThemeButton,
     form_input_checkbox: ThemeCheckbox,
     form_input_select: ThemeSelect,
+    form_input_slider: ThemeSlider,
     form_input_select_menu: ThemeSelectMenu,
     form_input_text_field: ThemeTextField,
     grid: ExternalThemeGrid,
@@ -100,6 +109,7 @@ pub struct GauntletComplexTheme {
     main_list_item_icon: ThemePaddingOnly,
     main_list_item_sub_text: ThemePaddingTextColor,
     main_list_item_text: ThemePaddingOnly,
+    main_list_item_text_match: ThemeTextColor,
     main_search_bar: ThemePaddingOnly,
     metadata_item_value: ThemePaddingOnly,
     metadata_content_inner: ThemePaddingOnly,
@@ -126,7 +136,10 @@ pub struct GauntletComplexTheme {
     text_accessory: ThemePaddingTextColorSpacing,
     icon_accessory: ThemeIconAccessory,
     hud: ThemeRoot,
-    hud_content: ThemePaddingOnly
+    hud_content: ThemePaddingOnly,
+    // set from config file, not from theme file
+    #[serde(skip)]
+    accessibility: AccessibilityConfig,
 }
 
 impl Default for GauntletComplexTheme {
@@ -195,20 +208,78 @@ fn parse_json_theme<T: Serialize + DeserializeOwned>(theme_file: PathBuf, theme_
 // TODO padding on button is padding, not margin, a lot of margins missing?
 
 impl GauntletComplexTheme {
-    pub fn new() -> Self {
+    // also called when config file changes, widgets use new theme starting with next redraw
+    pub fn init() {
+        init_theme(Self::load());
+    }
+
+    pub fn current() -> Self {
+        get_theme().clone()
+    }
+
+    // scroll animation is not a widget style, so it checks the config through theme
+    pub fn reduced_motion() -> bool {
+        get_theme().accessibility.reduced_motion
+    }
+
+    fn load() -> Self {
         let dirs = Dirs::new();
+        let config = read_frontend_config();
+
+        let complex_theme = match config.theme {
+            ThemeSelection::Auto => parse_json_theme(dirs.complex_theme_file(), "complex theme"),
+            ThemeSelection::Dark | ThemeSelection::Light => None,
+        };
 
-        let theme = parse_json_theme(dirs.complex_theme_file(), "complex theme")
-            .unwrap_or_else(|| {
-                let simple_theme = parse_json_theme(dirs.theme_simple_file(), "simple theme")
-                    .unwrap_or_else(|| GauntletComplexTheme::default_simple_theme());
+        let theme = complex_theme
+            .unwrap_or_else(|| GauntletComplexTheme::default_theme(Self::selected_simple_theme(&dirs, config.theme)));
+
+        theme.with_accessibility(config.accessibility)
+    }
 
-                GauntletComplexTheme::default_theme(simple_theme)
-            });
+    // simple theme that is edited in theme editor, complex theme file is not taken into account
+    pub fn current_simple_theme() -> GauntletSimpleTheme {
+        Self::selected_simple_theme(&Dirs::new(), read_frontend_config().theme)
+    }
+
+    fn selected_simple_theme(dirs: &Dirs, selection: ThemeSelection) -> GauntletSimpleTheme {
+        match selection {
+            ThemeSelection::Auto => {
+                parse_json_theme(dirs.theme_simple_file(), "simple theme")
+                    .unwrap_or_else(|| GauntletComplexTheme::default_simple_theme())
+            }
+            ThemeSelection::Dark => GauntletComplexTheme::default_simple_theme(),
+            ThemeSelection::Light => GauntletComplexTheme::light_simple_theme(),
+        }
+    }
+
+    // theme editor shows changes before they are saved, none goes back to theme from config.
+    // preview is also replaced when config or theme files change
+    pub fn preview(simple_theme: Option<GauntletSimpleTheme>) {
+        match simple_theme {
+            None => Self::init(),
+            Some(simple_theme) => {
+                let accessibility = read_frontend_config().accessibility;
+
+                init_theme(Self::default_theme(simple_theme).with_accessibility(accessibility))
+            }
+        }
+    }
 
-        init_theme(theme.clone());
+    // high contrast is applied on top of any theme, including the one from theme file.
+    // outline around focused items is added by styles of widgets
+    fn with_accessibility(mut self, accessibility: AccessibilityConfig) -> Self {
+        if accessibility.high_contrast {
+            self.root.border_width = self.root.border_width.max(2.0);
+            self.root.border_color = self.text;
+            self.separator.color = self.text;
+            self.form_input_text_field.border_width = self.form_input_text_field.border_width.max(1.0);
+            self.form_input_text_field.border_color = self.text;
+            self.form_input_text_field.border_color_hovered = self.text;
+        }
 
-        theme
+        self.accessibility = accessibility;
+        self
     }
 
     pub fn default_simple_theme() -> GauntletSimpleTheme {
@@ -231,6 +302,26 @@ impl GauntletComplexTheme {
         }
     }
 
+    pub fn light_simple_theme() -> GauntletSimpleTheme {
+        GauntletSimpleTheme {
+            version: CURRENT_SIMPLE_THEME_VERSION,
+            background_lightest_color: LIGHT_BACKGROUND_LIGHTEST,
+            background_lighter_color: LIGHT_BACKGROUND_LIGHTER,
+            background_darker_color: LIGHT_BACKGROUND_DARKER,
+            background_darkest_color: LIGHT_BACKGROUND_DARKEST,
+            text_lightest_color: LIGHT_TEXT_LIGHTEST,
+            text_lighter_color: LIGHT_TEXT_LIGHTER,
+            text_darker_color: LIGHT_TEXT_DARKER,
+            text_darkest_color: LIGHT_TEXT_DARKEST,
+            primary_darker_color: LIGHT_PRIMARY,
+            primary_lighter_color: LIGHT_PRIMARY_HOVERED,
+            root_border_radius: 10.0,
+            root_border_width: 1.0,
+            root_border_color: LIGHT_BACKGROUND_LIGHTER,
+            content_border_radius: BUTTON_BORDER_RADIUS,
+        }
+    }
+
     pub fn default_theme(simple_theme: GauntletSimpleTheme) -> GauntletComplexTheme {
         let GauntletSimpleTheme {
             version: _,
@@ -505,6 +596,9 @@ impl GauntletComplexTheme {
             main_list_item_text: ThemePaddingOnly {
                 padding: padding_all(4.0),
             },
+            main_list_item_text_match: ThemeTextColor {
+                text_color: primary_darker_color,
+            },
             main_list_item_sub_text: ThemePaddingTextColor {
                 padding: padding_axis(4.0, 12.0),
                 text_color: text_darker_color,
@@ -568,6 +662,17 @@ impl GauntletComplexTheme {
                 border_color: primary_darker_color,
                 icon_color: background_darkest_color,
             },
+            form_input_slider: ThemeSlider {
+                rail_color: background_lighter_color,
+                rail_color_filled: primary_darker_color,
+                rail_width: 4.0,
+                rail_border_radius: 2.0,
+                handle_color: primary_darker_color,
+                handle_color_hovered: primary_lighter_color,
+                handle_radius: 7.0,
+                handle_border_width: 0.0,
+                handle_border_color: TRANSPARENT,
+            },
             form_input_select: ThemeSelect {
                 background_color: primary_darker_color,
                 background_color_hovered: primary_lighter_color,
@@ -614,6 +719,21 @@ impl GauntletComplexTheme {
                 loading_bar_color: primary_darker_color,
                 background_color: background_lighter_color,
             },
+            content_chart: ThemeChart {
+                padding: padding_all(8.0),
+                color: primary_darker_color,
+                baseline_color: background_lighter_color,
+            },
+            content_paragraph_span: ThemeParagraphSpan {
+                code_background_color: background_lighter_color,
+                code_border_radius: content_border_radius,
+                link_color: primary_darker_color,
+                accent_color: primary_darker_color,
+                muted_color: text_darker_color,
+                success_color: SUCCESS,
+                warning_color: WARNING,
+                danger_color: DANGER,
+            },
             text_accessory: ThemePaddingTextColorSpacing {
                 padding: padding(4.0, 4.0, 4.0, 16.0),
                 text_color: text_lighter_color,
@@ -632,19 +752,35 @@ impl GauntletComplexTheme {
             hud_content: ThemePaddingOnly {
                 padding: padding_axis(8.0, 16.0),
             },
+            accessibility: AccessibilityConfig::default(),
         }
     }
 }
 
+// styles borrow theme for 'static, so replaced theme is leaked.
+// it is only replaced when theme in config file is changed or when theme editor applies finished edit,
+// so it is not worth the complexity of avoiding it
 fn init_theme(theme: GauntletComplexTheme) {
-    THEME.set(theme).expect("already set");
+    *THEME.write().expect("lock is poisoned") = Some(Box::leak(Box::new(theme)));
+}
+
+// tests render widgets without going through config, so they use default dark theme
+#[cfg(test)]
+pub fn init_default_theme() {
+    let mut current = THEME.write().expect("lock is poisoned");
+
+    if current.is_none() {
+        *current = Some(Box::leak(Box::new(GauntletComplexTheme::default_theme(GauntletComplexTheme::default_simple_theme()))));
+    }
 }
 
 fn get_theme() -> &'static GauntletComplexTheme {
-    &THEME.get().expect("theme global var was not set")
+    THEME.read()
+        .expect("lock is poisoned")
+        .expect("theme global var was not set")
 }
 
-static THEME: once_cell::sync::OnceCell<GauntletComplexTheme> = once_cell::sync::OnceCell::new();
+static THEME: std::sync::RwLock<Option<&'static GauntletComplexTheme>> = std::sync::RwLock::new(None);
 
 const NOT_INTENDED_TO_BE_USED: ThemeColor = ThemeColor::new(0xAF5BFF, 1.0);
 
@@ -661,6 +797,23 @@ const TEXT_DARKEST: ThemeColor = ThemeColor::new(0x1D242C, 1.0);
 const PRIMARY: ThemeColor = ThemeColor::new(0xC79F60, 1.0);
 const PRIMARY_HOVERED: ThemeColor = ThemeColor::new(0xD7B37A, 1.0);
 
+// names are kept the same as in dark theme, "lightest" text is the main text color, which is dark here
+const LIGHT_BACKGROUND_LIGHTEST: ThemeColor = ThemeColor::new(0xA9B0B9, 0.3);
+const LIGHT_BACKGROUND_LIGHTER: ThemeColor = ThemeColor::new(0xC3C8CF, 0.6);
+const LIGHT_BACKGROUND_DARKER: ThemeColor = ThemeColor::new(0xE8EAED, 1.0);
+const LIGHT_BACKGROUND_DARKEST: ThemeColor = ThemeColor::new(0xF5F6F7, 1.0);
+const LIGHT_TEXT_LIGHTEST: ThemeColor = ThemeColor::new(0x1D242C, 1.0);
+const LIGHT_TEXT_LIGHTER: ThemeColor = ThemeColor::new(0x4B5560, 1.0);
+const LIGHT_TEXT_DARKER: ThemeColor = ThemeColor::new(0x6B7785, 1.0);
+const LIGHT_TEXT_DARKEST: ThemeColor = ThemeColor::new(0x1D242C, 1.0);
+const LIGHT_PRIMARY: ThemeColor = ThemeColor::new(0xB58A45, 1.0);
+const LIGHT_PRIMARY_HOVERED: ThemeColor = ThemeColor::new(0xC79F60, 1.0);
+
+// status colors are readable on both dark and light backgrounds, so they are shared between themes
+const SUCCESS: ThemeColor = ThemeColor::new(0x5FA86B, 1.0);
+const WARNING: ThemeColor = ThemeColor::new(0xD19A3A, 1.0);
+const DANGER: ThemeColor = ThemeColor::new(0xD45F5F, 1.0);
+
 const BUTTON_BORDER_RADIUS: f32 = 4.0;
 
 const fn padding(top: f32, right: f32, bottom: f32, left: f32) -> ThemePadding {
@@ -713,6 +866,20 @@ pub struct ThemeCheckbox {
     icon_color: ThemeColor
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThemeSlider {
+    rail_color: ThemeColor,
+    rail_color_filled: ThemeColor,
+    rail_width: f32,
+    rail_border_radius: f32,
+
+    handle_color: ThemeColor,
+    handle_color_hovered: ThemeColor,
+    handle_radius: f32,
+    handle_border_width: f32,
+    handle_border_color: ThemeColor,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThemeSelect {
     background_color: ThemeColor,
@@ -792,6 +959,25 @@ pub struct ThemeLoadingBar {
     background_color: ThemeColor,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThemeChart {
+    padding: ThemePadding,
+    color: ThemeColor,
+    baseline_color: ThemeColor,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThemeParagraphSpan {
+    code_background_color: ThemeColor,
+    code_border_radius: f32,
+    link_color: ThemeColor,
+    accent_color: ThemeColor,
+    muted_color: ThemeColor,
+    success_color: ThemeColor,
+    warning_color: ThemeColor,
+    danger_color: ThemeColor,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThemeLink {
     text_color: ThemeColor,
@@ -990,6 +1176,71 @@ impl ThemeColor {
     }
 }
 
+impl From<ThemeColor> for ThemeTokenColor {
+    fn from(value: ThemeColor) -> Self {
+        ThemeTokenColor {
+            r: value.r,
+            g: value.g,
+            b: value.b,
+            a: value.a,
+        }
+    }
+}
+
+impl From<ThemeTokenColor> for ThemeColor {
+    fn from(value: ThemeTokenColor) -> Self {
+        ThemeColor {
+            r: value.r,
+            g: value.g,
+            b: value.b,
+            a: value.a.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl From<GauntletSimpleTheme> for SimpleThemeTokens {
+    fn from(value: GauntletSimpleTheme) -> Self {
+        SimpleThemeTokens {
+            background_darkest_color: value.background_darkest_color.into(),
+            background_darker_color: value.background_darker_color.into(),
+            background_lighter_color: value.background_lighter_color.into(),
+            background_lightest_color: value.background_lightest_color.into(),
+            text_lightest_color: value.text_lightest_color.into(),
+            text_lighter_color: value.text_lighter_color.into(),
+            text_darker_color: value.text_darker_color.into(),
+            text_darkest_color: value.text_darkest_color.into(),
+            primary_darker_color: value.primary_darker_color.into(),
+            primary_lighter_color: value.primary_lighter_color.into(),
+            root_border_color: value.root_border_color.into(),
+            root_border_radius: value.root_border_radius,
+            root_border_width: value.root_border_width,
+            content_border_radius: value.content_border_radius,
+        }
+    }
+}
+
+impl From<SimpleThemeTokens> for GauntletSimpleTheme {
+    fn from(value: SimpleThemeTokens) -> Self {
+        GauntletSimpleTheme {
+            version: CURRENT_SIMPLE_THEME_VERSION,
+            background_darkest_color: value.background_darkest_color.into(),
+            background_darker_color: value.background_darker_color.into(),
+            background_lighter_color: value.background_lighter_color.into(),
+            background_lightest_color: value.background_lightest_color.into(),
+            text_lightest_color: value.text_lightest_color.into(),
+            text_lighter_color: value.text_lighter_color.into(),
+            text_darker_color: value.text_darker_color.into(),
+            text_darkest_color: value.text_darkest_color.into(),
+            primary_darker_color: value.primary_darker_color.into(),
+            primary_lighter_color: value.primary_lighter_color.into(),
+            root_border_color: value.root_border_color.into(),
+            root_border_radius: value.root_border_radius.max(0.0),
+            root_border_width: value.root_border_width.max(0.0),
+            content_border_radius: value.content_border_radius.max(0.0),
+        }
+    }
+}
+
 pub trait ThemableWidget<'a, Message> {
     type Kind;
 
@@ -0,0 +1,57 @@
+use iced::{Border, Color};
+use iced::widget::{slider, Slider};
+use iced::widget::slider::{Handle, HandleShape, Rail, Status, Style};
+use crate::ui::theme::{Element, GauntletComplexTheme, ThemableWidget};
+
+pub enum SliderStyle {
+    Default,
+}
+
+impl slider::Catalog for GauntletComplexTheme {
+    type Class<'a> = SliderStyle;
+
+    fn default<'a>() -> Self::Class<'a> {
+        SliderStyle::Default
+    }
+
+    fn style(&self, _class: &Self::Class<'_>, status: Status) -> Style {
+        let theme = &self.form_input_slider;
+
+        let handle_color = match status {
+            Status::Active => theme.handle_color.to_iced(),
+            Status::Hovered | Status::Dragged => theme.handle_color_hovered.to_iced(),
+        };
+
+        Style {
+            rail: Rail {
+                backgrounds: (theme.rail_color_filled.to_iced().into(), theme.rail_color.to_iced().into()),
+                width: theme.rail_width,
+                border: Border {
+                    radius: theme.rail_border_radius.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+            },
+            handle: Handle {
+                shape: HandleShape::Circle {
+                    radius: theme.handle_radius,
+                },
+                background: handle_color.into(),
+                border_width: theme.handle_border_width,
+                border_color: theme.handle_border_color.to_iced(),
+            },
+        }
+    }
+}
+
+impl<'a, Message: 'a + Clone> ThemableWidget<'a, Message> for Slider<'a, f64, Message, GauntletComplexTheme> {
+    type Kind = SliderStyle;
+
+    fn themed(self, kind: SliderStyle) -> Element<'a, Message> {
+        match kind {
+            SliderStyle::Default => {
+                self.class(kind)
+            }
+        }.into()
+    }
+}
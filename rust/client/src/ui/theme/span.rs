@@ -0,0 +1,49 @@
+use iced::widget::text::Span;
+use iced::{border, Font};
+use gauntlet_common::model::TextColor;
+use crate::ui::theme::get_theme;
+
+pub enum SpanStyle<'a> {
+    Code,
+    Link,
+    Color(&'a TextColor),
+    MainListItemTextMatch,
+}
+
+// spans are not widgets, so they cannot use theme catalog and take colors directly from the theme
+pub trait ThemableSpan {
+    fn themed(self, kind: SpanStyle) -> Self;
+}
+
+impl<'a, Link> ThemableSpan for Span<'a, Link, Font> {
+    fn themed(self, kind: SpanStyle) -> Self {
+        let theme = get_theme();
+
+        match kind {
+            SpanStyle::Code => {
+                self.background(theme.content_paragraph_span.code_background_color.to_iced())
+                    .border(border::rounded(theme.content_paragraph_span.code_border_radius))
+            }
+            SpanStyle::Link => {
+                self.color(theme.content_paragraph_span.link_color.to_iced())
+                    .underline(true)
+            }
+            SpanStyle::Color(color) => {
+                let theme = &theme.content_paragraph_span;
+
+                let color = match color {
+                    TextColor::Accent => &theme.accent_color,
+                    TextColor::Muted => &theme.muted_color,
+                    TextColor::Success => &theme.success_color,
+                    TextColor::Warning => &theme.warning_color,
+                    TextColor::Danger => &theme.danger_color,
+                };
+
+                self.color(color.to_iced())
+            }
+            SpanStyle::MainListItemTextMatch => {
+                self.color(theme.main_list_item_text_match.text_color.to_iced())
+            }
+        }
+    }
+}
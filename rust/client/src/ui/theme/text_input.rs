@@ -92,13 +92,20 @@ fn focused(theme: &GauntletComplexTheme, style: &TextInputStyle) -> Style {
             }
         },
         TextInputStyle::FormInput => {
+            let high_contrast = theme.accessibility.high_contrast;
             let theme = &theme.form_input_text_field;
 
+            let border_width = if high_contrast {
+                2.0
+            } else {
+                theme.border_width
+            };
+
             Style {
                 background: theme.background_color_hovered.to_iced().into(),
                 border: Border {
                     radius: theme.border_radius.into(),
-                    width: theme.border_width,
+                    width: border_width,
                     color: theme.border_color_hovered.to_iced().into(),
                 },
                 icon: NOT_INTENDED_TO_BE_USED.to_iced(),
@@ -0,0 +1,25 @@
+use crate::ui::custom_widgets::transition;
+use crate::ui::custom_widgets::transition::Style;
+use crate::ui::GauntletComplexTheme;
+
+#[derive(Default)]
+pub enum TransitionStyle {
+    #[default]
+    Root,
+}
+
+impl transition::Catalog for GauntletComplexTheme {
+    type Class<'a> = TransitionStyle;
+
+    fn default<'a>() -> Self::Class<'a> {
+        TransitionStyle::Root
+    }
+
+    // view fades in from background of root container
+    fn style(&self, _class: &Self::Class<'_>) -> Style {
+        Style {
+            background_color: self.root.background_color.to_iced(),
+            border_radius: self.root.border_radius,
+        }
+    }
+}
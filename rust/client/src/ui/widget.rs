@@ -1,29 +1,38 @@
 use crate::model::UiViewEvent;
+use crate::ui::custom_widgets::drag_source::DragSource;
 use crate::ui::custom_widgets::loading_bar::LoadingBar;
+use crate::ui::drag::DRAG_SUPPORTED;
 use crate::ui::grid_navigation::{grid_down_offset, grid_up_offset, GridSectionData};
 use crate::ui::scroll_handle::{ScrollHandle, ESTIMATED_MAIN_LIST_ITEM_HEIGHT};
 use crate::ui::state::PluginViewState;
+use crate::ui::custom_widgets::chart::{Chart, ChartKind};
 use crate::ui::theme::button::ButtonStyle;
+use crate::ui::theme::GauntletComplexTheme;
+use crate::ui::theme::chart::ChartStyle;
 use crate::ui::theme::container::ContainerStyle;
 use crate::ui::theme::date_picker::DatePickerStyle;
 use crate::ui::theme::grid::GridStyle;
 use crate::ui::theme::pick_list::PickListStyle;
 use crate::ui::theme::row::RowStyle;
 use crate::ui::theme::rule::RuleStyle;
+use crate::ui::theme::slider::SliderStyle;
+use crate::ui::theme::span::{SpanStyle, ThemableSpan};
 use crate::ui::theme::text::TextStyle;
 use crate::ui::theme::text_input::TextInputStyle;
 use crate::ui::theme::tooltip::TooltipStyle;
-use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::theme::{Element, GauntletComplexTheme, ThemableWidget};
 use crate::ui::AppMsg;
-use gauntlet_common::model::{ActionPanelSectionWidget, ActionPanelSectionWidgetOrderedMembers, ActionPanelWidget, ActionPanelWidgetOrderedMembers, ActionWidget, CheckboxWidget, CodeBlockWidget, ContentWidget, ContentWidgetOrderedMembers, DatePickerWidget, DetailWidget, EmptyViewWidget, FormWidget, FormWidgetOrderedMembers, GridItemWidget, GridSectionWidget, GridSectionWidgetOrderedMembers, GridWidget, GridWidgetOrderedMembers, H1Widget, H2Widget, H3Widget, H4Widget, H5Widget, H6Widget, HorizontalBreakWidget, IconAccessoryWidget, Icons, Image, ImageWidget, InlineSeparatorWidget, InlineWidget, InlineWidgetOrderedMembers, ListItemAccessories, ListItemWidget, ListSectionWidget, ListSectionWidgetOrderedMembers, ListWidget, ListWidgetOrderedMembers, MetadataIconWidget, MetadataLinkWidget, MetadataSeparatorWidget, MetadataTagItemWidget, MetadataTagListWidget, MetadataTagListWidgetOrderedMembers, MetadataValueWidget, MetadataWidget, MetadataWidgetOrderedMembers, ParagraphWidget, PasswordFieldWidget, PhysicalKey, PhysicalShortcut, PluginId, RootWidget, RootWidgetMembers, SearchBarWidget, SelectWidget, SelectWidgetOrderedMembers, SeparatorWidget, TextAccessoryWidget, TextFieldWidget, UiWidgetId};
-use gauntlet_common_ui::shortcut_to_text;
+use gauntlet_common::model::{ActionPanelSectionWidget, ActionPanelSectionWidgetOrderedMembers, ActionPanelWidget, ActionPanelWidgetOrderedMembers, ActionWidget, BarChartWidget, CheckboxWidget, CodeBlockWidget, ColorSwatchWidget, ContentWidget, ContentWidgetOrderedMembers, DatePickerWidget, DetailWidget, DragData, DragPayload, DragPayloadFile, DragPayloadUrl, EmptyViewWidget, FormWidget, FormWidgetOrderedMembers, GridItemWidget, GridSectionWidget, GridSectionWidgetOrderedMembers, GridWidget, GridWidgetOrderedMembers, H1Widget, H2Widget, H3Widget, H4Widget, H5Widget, H6Widget, HorizontalBreakWidget, IconAccessoryWidget, Icons, Image, ImageWidget, InlineSeparatorWidget, InlineWidget, InlineWidgetOrderedMembers, ListItemAccessories, ListItemWidget, ListSectionWidget, ListSectionWidgetOrderedMembers, ListWidget, ListWidgetOrderedMembers, MetadataIconWidget, MetadataLinkWidget, MetadataSeparatorWidget, MetadataTagItemWidget, MetadataTagListWidget, MetadataTagListWidgetOrderedMembers, MetadataValueWidget, MetadataWidget, MetadataWidgetOrderedMembers, ParagraphWidget, ParagraphWidgetOrderedMembers, PasswordFieldWidget, PhysicalKey, PhysicalShortcut, PluginId, PluginStyle, RootWidget, RootWidgetMembers, SearchBarWidget, SelectWidget, SelectWidgetOrderedMembers, SeparatorWidget, SliderWidget, SparklineWidget, TextAccessoryWidget, TextColor, TextFieldWidget, UiWidgetId};
+use gauntlet_common::i18n::{tr, tr_with};
+use gauntlet_common_ui::accessibility::{AccessibleNode, Role};
+use gauntlet_common_ui::{format_slider_value, shortcut_to_text};
 use iced::alignment::{Horizontal, Vertical};
-use iced::font::Weight;
+use iced::font::{Style as FontStyle, Weight};
 use iced::widget::image::Handle;
-use iced::widget::text::Shaping;
+use iced::widget::text::{Shaping, Span};
 use iced::widget::tooltip::Position;
-use iced::widget::{button, checkbox, column, container, horizontal_rule, horizontal_space, image, mouse_area, pick_list, row, scrollable, stack, text, text_input, tooltip, value, vertical_rule, Space};
-use iced::{Alignment, Font, Length, Task};
+use iced::widget::{button, canvas, checkbox, column, container, horizontal_rule, horizontal_space, image, mouse_area, pick_list, rich_text, row, scrollable, slider, span, stack, text, text_input, tooltip, value, vertical_rule, Space};
+use iced::{Alignment, Color, Font, Length, Task};
 use iced_aw::date_picker::Date;
 use iced_aw::helpers::{date_picker, grid, grid_row};
 use iced_aw::GridRow;
@@ -34,26 +43,51 @@ use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 use std::sync::Arc;
 
+const COLOR_SWATCH_SIZE: f32 = 48.0;
+const CHART_HEIGHT: f32 = 48.0;
+
 #[derive(Debug)]
 pub struct ComponentWidgets<'b> {
     root_widget: &'b mut Option<Arc<RootWidget>>,
     state: &'b mut HashMap<UiWidgetId, ComponentWidgetState>,
-    images: &'b HashMap<UiWidgetId, Vec<u8>>
+    images: &'b HashMap<UiWidgetId, Handle>,
+    style: &'b PluginStyle,
 }
 
 impl<'b> ComponentWidgets<'b> {
     pub fn new(
         root_widget: &'b mut Option<Arc<RootWidget>>,
         state: &'b mut HashMap<UiWidgetId, ComponentWidgetState>,
-        images: &'b HashMap<UiWidgetId, Vec<u8>>
+        images: &'b HashMap<UiWidgetId, Handle>,
+        style: &'b PluginStyle,
     ) -> ComponentWidgets<'b> {
         Self {
             root_widget,
             state,
-            images
+            images,
+            style,
         }
     }
 
+    // plugin style tokens only replace colors of plugin content, tokens are validated by server,
+    // but invalid value still falls back to theme color instead of erroring whole view
+    fn style_color(&self, token: &Option<String>) -> Option<Color> {
+        token.as_deref()
+            .and_then(|color| parse_hex_color(color))
+    }
+
+    fn style_text_color(&self, color: &TextColor) -> Option<Color> {
+        let token = match color {
+            TextColor::Accent => &self.style.accent_color,
+            TextColor::Muted => &self.style.muted_color,
+            TextColor::Success => &self.style.success_color,
+            TextColor::Warning => &self.style.warning_color,
+            TextColor::Danger => &self.style.danger_color,
+        };
+
+        self.style_color(token)
+    }
+
     fn text_field_state(&self, widget_id: UiWidgetId) -> &TextFieldState {
         let state = self.state.get(&widget_id).expect(&format!("requested state should always be present for id: {}", widget_id));
 
@@ -103,6 +137,15 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 
+    fn slider_state(&self, widget_id: UiWidgetId) -> &SliderState {
+        let state = self.state.get(&widget_id).expect(&format!("requested state should always be present for id: {}", widget_id));
+
+        match state {
+            ComponentWidgetState::Slider(state) => state,
+            _ => panic!("SliderState expected, {:?} found", state)
+        }
+    }
+
     fn root_state(&self, widget_id: UiWidgetId) -> &RootState {
         let state = self.state.get(&widget_id).expect(&format!("requested state should always be present for id: {}", widget_id));
 
@@ -157,6 +200,9 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                             FormWidgetOrderedMembers::Select(widget) => {
                                 result.insert(widget.__id__, ComponentWidgetState::select(&widget.value));
                             }
+                            FormWidgetOrderedMembers::Slider(widget) => {
+                                result.insert(widget.__id__, ComponentWidgetState::slider(widget));
+                            }
                             FormWidgetOrderedMembers::Separator(_) => {}
                         }
                     }
@@ -167,6 +213,14 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                     if let Some(widget) = &widget.content.search_bar {
                         result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
                     }
+
+                    if widget.multi_select.unwrap_or(false) {
+                        for widget in list_items(widget) {
+                            if widget.id.is_some() {
+                                result.insert(widget.__id__, ComponentWidgetState::list_item());
+                            }
+                        }
+                    }
                 }
                 RootWidgetMembers::Grid(widget) => {
                     // cursed heuristic
@@ -212,12 +266,48 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
     result
 }
 
+// items of the list in the order they are shown, including items inside sections
+fn list_items(list_widget: &ListWidget) -> Vec<&ListItemWidget> {
+    list_widget.content.ordered_members
+        .iter()
+        .flat_map(|members| {
+            match members {
+                ListWidgetOrderedMembers::ListItem(widget) => vec![widget],
+                ListWidgetOrderedMembers::ListSection(widget) => {
+                    widget.content.ordered_members
+                        .iter()
+                        .map(|members| {
+                            match members {
+                                ListSectionWidgetOrderedMembers::ListItem(widget) => widget,
+                            }
+                        })
+                        .collect()
+                }
+            }
+        })
+        .collect()
+}
+
+// identifies list items between renders, items without id are matched by title
+pub fn list_item_keys(root_widget: &RootWidget) -> Vec<String> {
+    let Some(RootWidgetMembers::List(list_widget)) = &root_widget.content else {
+        return vec![]
+    };
+
+    list_items(list_widget)
+        .into_iter()
+        .map(|widget| widget.id.clone().unwrap_or_else(|| widget.title.to_string()))
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub enum ComponentWidgetState {
     TextField(TextFieldState),
     Checkbox(CheckboxState),
     DatePicker(DatePickerState),
     Select(SelectState),
+    Slider(SliderState),
+    ListItem(ListItemState),
     Root(RootState),
 }
 
@@ -243,10 +333,23 @@ struct SelectState {
     state_value: Option<String>
 }
 
+#[derive(Debug, Clone)]
+struct SliderState {
+    state_value: f64
+}
+
+#[derive(Debug, Clone)]
+struct ListItemState {
+    // only present for items with id in lists with multi-select enabled
+    selected: bool,
+}
+
 #[derive(Debug, Clone)]
 struct RootState {
     show_action_panel: bool,
     focused_item: ScrollHandle<UiWidgetId>,
+    // focused form field, link or tag, which are reachable with tab
+    focused_control: Option<UiWidgetId>,
 }
 
 impl ComponentWidgetState {
@@ -254,6 +357,7 @@ impl ComponentWidgetState {
         ComponentWidgetState::Root(RootState {
             show_action_panel: false,
             focused_item: ScrollHandle::new(false, item_height, rows_per_view),
+            focused_control: None,
         })
     }
 
@@ -289,6 +393,20 @@ impl ComponentWidgetState {
             state_value: value.to_owned()
         })
     }
+
+    fn slider(widget: &SliderWidget) -> ComponentWidgetState {
+        let (min, max, _) = slider_range(widget);
+
+        ComponentWidgetState::Slider(SliderState {
+            state_value: widget.value.unwrap_or(min).clamp(min, max)
+        })
+    }
+
+    fn list_item() -> ComponentWidgetState {
+        ComponentWidgetState::ListItem(ListItemState {
+            selected: false
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -326,6 +444,21 @@ impl<'b> ComponentWidgets<'b> {
     }
 
     pub fn get_action_ids(&self) -> Vec<UiWidgetId> {
+        self.action_widgets()
+            .into_iter()
+            .map(|widget| widget.__id__)
+            .collect()
+    }
+
+    pub fn action_keeps_window_open(&self, widget_id: UiWidgetId) -> bool {
+        self.action_widgets()
+            .into_iter()
+            .find(|widget| widget.__id__ == widget_id)
+            .and_then(|widget| widget.keep_window_open)
+            .unwrap_or(false)
+    }
+
+    fn action_widgets(&self) -> Vec<&ActionWidget> {
         let Some(root_widget) = &self.root_widget else {
             return vec![];
         };
@@ -349,13 +482,13 @@ impl<'b> ComponentWidgets<'b> {
                 for members in &widget.content.ordered_members {
                     match members {
                         ActionPanelWidgetOrderedMembers::Action(widget) => {
-                            result.push(widget.__id__)
+                            result.push(widget)
                         }
                         ActionPanelWidgetOrderedMembers::ActionPanelSection(widget) => {
                             for members in &widget.content.ordered_members {
                                 match members {
                                     ActionPanelSectionWidgetOrderedMembers::Action(widget) => {
-                                        result.push(widget.__id__)
+                                        result.push(widget)
                                     }
                                 }
                             }
@@ -469,12 +602,17 @@ impl<'b> ComponentWidgets<'b> {
 
         let TextFieldState { text_input_id, state_value } = ComponentWidgets::text_field_state_mut_on_state(&mut self.state, widget_id);
 
-        if let Some(value) = text.chars().next().filter(|c| !c.is_control()) {
-            *state_value = format!("{}{}", state_value, value);
+        // text committed by input method or compose sequence can contain multiple characters
+        let value: String = text.chars()
+            .filter(|c| !c.is_control())
+            .collect();
 
-            text_input::focus(text_input_id.clone())
-        } else {
+        if value.is_empty() {
             Task::none()
+        } else {
+            state_value.push_str(&value);
+
+            text_input::focus(text_input_id.clone())
         }
     }
 
@@ -710,56 +848,778 @@ impl<'b> ComponentWidgets<'b> {
             return Task::none();
         };
 
-        match content {
-            RootWidgetMembers::Detail(_) => Task::none(),
-            RootWidgetMembers::Form(_) => Task::none(),
-            RootWidgetMembers::Inline(_) => Task::none(),
-            RootWidgetMembers::List(_) => Task::none(),
-            RootWidgetMembers::Grid(grid_widget) => {
-                let RootState { focused_item, .. } = ComponentWidgets::root_state_mut_on_field(self.state, grid_widget.__id__);
+        match content {
+            RootWidgetMembers::Detail(_) => Task::none(),
+            RootWidgetMembers::Form(_) => Task::none(),
+            RootWidgetMembers::Inline(_) => Task::none(),
+            RootWidgetMembers::List(_) => Task::none(),
+            RootWidgetMembers::Grid(grid_widget) => {
+                let RootState { focused_item, .. } = ComponentWidgets::root_state_mut_on_field(self.state, grid_widget.__id__);
+
+                let total = grid_widget.content.ordered_members
+                    .iter()
+                    .flat_map(|members| {
+                        match members {
+                            GridWidgetOrderedMembers::GridItem(widget) => vec![widget],
+                            GridWidgetOrderedMembers::GridSection(widget) => {
+                                widget.content.ordered_members
+                                    .iter()
+                                    .map(|members| {
+                                        match members {
+                                            GridSectionWidgetOrderedMembers::GridItem(widget) => widget,
+                                        }
+                                    })
+                                    .collect()
+                            }
+                        }
+                    })
+                    .count();
+
+                let _ = focused_item.focus_next(total);
+
+                // focused_item.scroll_to(0)
+                Task::none()
+            }
+        }
+    }
+
+    pub fn get_action_panel(&self, action_shortcuts: &HashMap<String, PhysicalShortcut>) -> Option<ActionPanel> {
+        let Some(root_widget) = &self.root_widget else {
+            return None;
+        };
+
+        let Some(content) = &root_widget.content else {
+            return None;
+        };
+
+        match content {
+            RootWidgetMembers::Detail(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
+            RootWidgetMembers::Form(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
+            RootWidgetMembers::Inline(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
+            RootWidgetMembers::List(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
+            RootWidgetMembers::Grid(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
+        }
+    }
+
+    fn root_widget_id(&self) -> Option<UiWidgetId> {
+        let content = self.root_widget.as_ref()?.content.as_ref()?;
+
+        let widget_id = match content {
+            RootWidgetMembers::Detail(widget) => widget.__id__,
+            RootWidgetMembers::Form(widget) => widget.__id__,
+            RootWidgetMembers::Inline(widget) => widget.__id__,
+            RootWidgetMembers::List(widget) => widget.__id__,
+            RootWidgetMembers::Grid(widget) => widget.__id__,
+        };
+
+        Some(widget_id)
+    }
+
+    fn focused_control(&self) -> Option<UiWidgetId> {
+        let root_widget_id = self.root_widget_id()?;
+
+        match self.state.get(&root_widget_id) {
+            Some(ComponentWidgetState::Root(RootState { focused_control, .. })) => *focused_control,
+            _ => None
+        }
+    }
+
+    // interactive widgets which are not reachable using arrow keys, in the order they are shown
+    fn focusable_controls(&self) -> Vec<UiWidgetId> {
+        let Some(root_widget) = &self.root_widget else {
+            return vec![];
+        };
+
+        let Some(content) = &root_widget.content else {
+            return vec![];
+        };
+
+        match content {
+            RootWidgetMembers::Detail(widget) => detail_controls(widget),
+            RootWidgetMembers::Form(widget) => {
+                widget.content.ordered_members
+                    .iter()
+                    .filter_map(|members| {
+                        match members {
+                            FormWidgetOrderedMembers::TextField(widget) => Some(widget.__id__),
+                            FormWidgetOrderedMembers::PasswordField(widget) => Some(widget.__id__),
+                            FormWidgetOrderedMembers::Checkbox(widget) => Some(widget.__id__),
+                            FormWidgetOrderedMembers::DatePicker(widget) => Some(widget.__id__),
+                            FormWidgetOrderedMembers::Select(widget) => Some(widget.__id__),
+                            FormWidgetOrderedMembers::Slider(widget) => Some(widget.__id__),
+                            FormWidgetOrderedMembers::Separator(_) => None,
+                        }
+                    })
+                    .collect()
+            }
+            RootWidgetMembers::List(widget) => {
+                widget.content.detail
+                    .as_ref()
+                    .map(|widget| detail_controls(widget))
+                    .unwrap_or_default()
+            }
+            RootWidgetMembers::Grid(_) | RootWidgetMembers::Inline(_) => vec![],
+        }
+    }
+
+    pub fn focus_next_control(&mut self) -> Task<AppMsg> {
+        self.move_control_focus(true)
+    }
+
+    pub fn focus_previous_control(&mut self) -> Task<AppMsg> {
+        self.move_control_focus(false)
+    }
+
+    fn move_control_focus(&mut self, forward: bool) -> Task<AppMsg> {
+        let controls = self.focusable_controls();
+
+        if controls.is_empty() {
+            return Task::none()
+        }
+
+        let current = self.focused_control()
+            .and_then(|focused_control| controls.iter().position(|widget_id| *widget_id == focused_control));
+
+        let next = match (current, forward) {
+            (None, true) => 0,
+            (None, false) => controls.len() - 1,
+            (Some(index), true) => (index + 1) % controls.len(),
+            (Some(index), false) => (index + controls.len() - 1) % controls.len(),
+        };
+
+        self.focus_control(controls[next])
+    }
+
+    pub fn focus_control(&mut self, widget_id: UiWidgetId) -> Task<AppMsg> {
+        if !self.focusable_controls().contains(&widget_id) {
+            return Task::none()
+        }
+
+        let Some(root_widget_id) = self.root_widget_id() else {
+            return Task::none()
+        };
+
+        self.root_state_mut(root_widget_id).focused_control = Some(widget_id);
+
+        match self.state.get(&widget_id) {
+            Some(ComponentWidgetState::TextField(TextFieldState { text_input_id, .. })) => text_input::focus(text_input_id.clone()),
+            // there is no widget with this id, so focus is removed from text field that was focused before
+            _ => text_input::focus(text_input::Id::unique()),
+        }
+    }
+
+    pub fn focus_list_item_mouse(&mut self, index: usize) {
+        if let Some(RootState { focused_item, .. }) = self.list_root_state_mut() {
+            focused_item.focus_visible(index)
+        }
+    }
+
+    pub fn list_scrolled(&mut self, offset: f32) {
+        let Some(content) = self.root_widget.as_ref().and_then(|root_widget| root_widget.content.as_ref()) else {
+            return
+        };
+
+        match content {
+            RootWidgetMembers::List(widget) => {
+                ComponentWidgets::root_state_mut_on_field(self.state, widget.__id__).focused_item.scrolled(offset)
+            }
+            // grid keeps focused row counted in rows of items, so only position for scroll animation is updated
+            RootWidgetMembers::Grid(widget) => {
+                ComponentWidgets::root_state_mut_on_field(self.state, widget.__id__).focused_item.set_scroll_offset(offset)
+            }
+            RootWidgetMembers::Detail(_) | RootWidgetMembers::Form(_) | RootWidgetMembers::Inline(_) => {}
+        }
+    }
+
+    // plugin rendered list again, focus follows the item it was on if most of the items stayed the same
+    pub fn list_refreshed(&mut self, previous_items: &[String]) {
+        let Some(root_widget) = self.root_widget.clone() else {
+            return
+        };
+
+        let current_items = list_item_keys(&root_widget);
+
+        if let Some(RootState { focused_item, .. }) = self.list_root_state_mut() {
+            focused_item.refresh(previous_items, &current_items)
+        }
+    }
+
+    fn list_root_state_mut(&mut self) -> Option<&mut RootState> {
+        let Some(root_widget) = &self.root_widget else {
+            return None;
+        };
+
+        let Some(RootWidgetMembers::List(widget)) = &root_widget.content else {
+            return None;
+        };
+
+        Some(ComponentWidgets::root_state_mut_on_field(self.state, widget.__id__))
+    }
+
+    pub fn focused_control_event(&self) -> Option<ComponentWidgetEvent> {
+        self.activation_event(self.focused_control()?)
+    }
+
+    // window is created anew when view is restored, so text field focus and scroll offset need to be applied again
+    pub fn restore_focus(&mut self) -> Task<AppMsg> {
+        let Some(root_widget_id) = self.root_widget_id() else {
+            return Task::none()
+        };
+
+        let focus = match self.focused_control() {
+            Some(widget_id) => self.focus_control(widget_id),
+            None => {
+                match self.first_open() {
+                    AppMsg::FocusPluginViewSearchBar { widget_id } => self.focus_search_bar(widget_id),
+                    _ => Task::none()
+                }
+            }
+        };
+
+        // grid items are scrolled by rows which depend on column count, so only lists are scrolled back
+        let scroll = match self.root_widget.as_ref().and_then(|root_widget| root_widget.content.as_ref()) {
+            Some(RootWidgetMembers::List(_)) => {
+                let RootState { focused_item, .. } = self.root_state(root_widget_id);
+
+                match focused_item.index {
+                    Some(index) => focused_item.scroll_to(index),
+                    None => Task::none()
+                }
+            }
+            _ => Task::none()
+        };
+
+        Task::batch([focus, scroll])
+    }
+
+    // event which is the same as clicking on the widget, used for keyboard and assistive technologies
+    pub fn activation_event(&self, widget_id: UiWidgetId) -> Option<ComponentWidgetEvent> {
+        match self.state.get(&widget_id) {
+            Some(ComponentWidgetState::Checkbox(CheckboxState { state_value })) => {
+                return Some(ComponentWidgetEvent::ToggleCheckbox { widget_id, value: !state_value })
+            }
+            Some(ComponentWidgetState::DatePicker(_)) => {
+                return Some(ComponentWidgetEvent::ToggleDatePicker { widget_id })
+            }
+            Some(ComponentWidgetState::Select(SelectState { state_value })) => {
+                return self.next_select_value(widget_id, state_value)
+                    .map(|value| ComponentWidgetEvent::SelectPickList { widget_id, value })
+            }
+            Some(ComponentWidgetState::TextField(_)) | Some(ComponentWidgetState::Slider(_)) | Some(ComponentWidgetState::Root(_)) => {
+                return None
+            }
+            Some(ComponentWidgetState::ListItem(_)) | None => {}
+        }
+
+        if self.get_action_ids().contains(&widget_id) {
+            return Some(ComponentWidgetEvent::ActionClick { widget_id })
+        }
+
+        let content = self.root_widget.as_ref()?.content.as_ref()?;
+
+        match content {
+            RootWidgetMembers::Detail(widget) => detail_activation_event(widget, widget_id),
+            RootWidgetMembers::List(widget) => {
+                let is_item = widget.content.ordered_members
+                    .iter()
+                    .any(|members| {
+                        match members {
+                            ListWidgetOrderedMembers::ListItem(widget) => widget.__id__ == widget_id,
+                            ListWidgetOrderedMembers::ListSection(widget) => {
+                                widget.content.ordered_members
+                                    .iter()
+                                    .any(|members| match members {
+                                        ListSectionWidgetOrderedMembers::ListItem(widget) => widget.__id__ == widget_id
+                                    })
+                            }
+                        }
+                    });
+
+                if is_item {
+                    Some(ComponentWidgetEvent::ListItemClick { widget_id })
+                } else {
+                    widget.content.detail
+                        .as_ref()
+                        .and_then(|widget| detail_activation_event(widget, widget_id))
+                }
+            }
+            RootWidgetMembers::Grid(widget) => {
+                let is_item = widget.content.ordered_members
+                    .iter()
+                    .any(|members| {
+                        match members {
+                            GridWidgetOrderedMembers::GridItem(widget) => widget.__id__ == widget_id,
+                            GridWidgetOrderedMembers::GridSection(widget) => {
+                                widget.content.ordered_members
+                                    .iter()
+                                    .any(|members| match members {
+                                        GridSectionWidgetOrderedMembers::GridItem(widget) => widget.__id__ == widget_id
+                                    })
+                            }
+                        }
+                    });
+
+                is_item.then(|| ComponentWidgetEvent::GridItemClick { widget_id })
+            }
+            RootWidgetMembers::Form(_) | RootWidgetMembers::Inline(_) => None,
+        }
+    }
+
+    fn selected_list_item_ids(&self) -> Vec<String> {
+        let Some(RootWidgetMembers::List(list_widget)) = self.root_widget.as_ref().and_then(|root_widget| root_widget.content.as_ref()) else {
+            return vec![]
+        };
+
+        list_items(list_widget)
+            .into_iter()
+            .filter(|widget| matches!(self.state.get(&widget.__id__), Some(ComponentWidgetState::ListItem(ListItemState { selected: true }))))
+            .filter_map(|widget| widget.id.clone())
+            .collect()
+    }
+
+    pub fn toggle_focused_list_item_selection(&mut self) {
+        let Some(RootWidgetMembers::List(list_widget)) = self.root_widget.as_ref().and_then(|root_widget| root_widget.content.as_ref()) else {
+            return
+        };
+
+        let RootState { focused_item, .. } = self.root_state(list_widget.__id__);
+
+        let widget_id = focused_item.index
+            .and_then(|index| list_items(list_widget).get(index).map(|widget| widget.__id__));
+
+        let Some(widget_id) = widget_id else {
+            return
+        };
+
+        if let Some(ComponentWidgetState::ListItem(ListItemState { selected })) = self.state.get_mut(&widget_id) {
+            *selected = !*selected;
+        }
+    }
+
+    // action run while list items are selected receives all of them, selection is cleared afterwards
+    pub fn batch_action_event(&mut self, widget_id: UiWidgetId) -> Option<UiViewEvent> {
+        let ids = self.selected_list_item_ids();
+
+        if ids.is_empty() {
+            return None
+        }
+
+        for state in self.state.values_mut() {
+            if let ComponentWidgetState::ListItem(ListItemState { selected }) = state {
+                *selected = false;
+            }
+        }
+
+        Some(create_action_on_batch_action_event(widget_id, ids))
+    }
+
+    // action shortcuts are usually handled by plugin itself,
+    // but plugin doesn't know which items are selected, so in that case action is run from here
+    pub fn selected_list_items_action(&self, action_shortcuts: &HashMap<String, PhysicalShortcut>, shortcut: &PhysicalShortcut) -> Option<UiWidgetId> {
+        if self.selected_list_item_ids().is_empty() {
+            return None
+        }
+
+        let Some(RootWidgetMembers::List(list_widget)) = self.root_widget.as_ref().and_then(|root_widget| root_widget.content.as_ref()) else {
+            return None
+        };
+
+        list_widget.content.actions
+            .as_ref()?
+            .content
+            .ordered_members
+            .iter()
+            .flat_map(|members| {
+                match members {
+                    ActionPanelWidgetOrderedMembers::Action(widget) => vec![widget],
+                    ActionPanelWidgetOrderedMembers::ActionPanelSection(widget) => {
+                        widget.content.ordered_members
+                            .iter()
+                            .map(|members| {
+                                match members {
+                                    ActionPanelSectionWidgetOrderedMembers::Action(widget) => widget,
+                                }
+                            })
+                            .collect()
+                    }
+                }
+            })
+            .find(|widget| widget.id.as_ref().and_then(|id| action_shortcuts.get(id)) == Some(shortcut))
+            .map(|widget| widget.__id__)
+    }
+
+    // pick list cannot be opened programmatically, so keyboard activation cycles through the values
+    fn next_select_value(&self, widget_id: UiWidgetId, state_value: &Option<String>) -> Option<String> {
+        let Some(RootWidgetMembers::Form(form)) = self.root_widget.as_ref()?.content.as_ref() else {
+            return None
+        };
+
+        let values: Vec<_> = form.content.ordered_members
+            .iter()
+            .find_map(|members| {
+                match members {
+                    FormWidgetOrderedMembers::Select(widget) if widget.__id__ == widget_id => Some(widget),
+                    _ => None
+                }
+            })?
+            .content
+            .ordered_members
+            .iter()
+            .map(|members| {
+                match members {
+                    SelectWidgetOrderedMembers::SelectItem(widget) => widget.value.to_owned()
+                }
+            })
+            .collect();
+
+        let next = match state_value {
+            None => 0,
+            Some(state_value) => {
+                values.iter()
+                    .position(|value| value == state_value)
+                    .map(|index| (index + 1) % values.len())
+                    .unwrap_or(0)
+            }
+        };
+
+        values.get(next).cloned()
+    }
+
+    pub fn accessibility_tree(&self, plugin_view_state: &PluginViewState, action_shortcuts: &HashMap<String, PhysicalShortcut>) -> (Vec<AccessibleNode>, Option<String>) {
+        let Some(root_widget) = &self.root_widget else {
+            return (vec![], None);
+        };
+
+        let Some(content) = &root_widget.content else {
+            return (vec![], None);
+        };
+
+        let mut nodes = vec![];
+        let mut focus = None;
+
+        let search_bar = match content {
+            RootWidgetMembers::List(widget) => widget.content.search_bar.as_ref(),
+            RootWidgetMembers::Grid(widget) => widget.content.search_bar.as_ref(),
+            _ => None,
+        };
+
+        if let Some(widget) = search_bar {
+            let TextFieldState { state_value, .. } = self.text_field_state(widget.__id__);
+
+            nodes.push(
+                AccessibleNode::new(plugin_widget_key(widget.__id__), Role::SearchInput)
+                    .label(widget.placeholder.clone().unwrap_or_else(|| tr("search-placeholder")))
+                    .value(state_value.to_string())
+                    .focusable()
+            );
+
+            focus = Some(plugin_widget_key(widget.__id__));
+        }
+
+        match content {
+            RootWidgetMembers::Detail(widget) => {
+                nodes.push(self.detail_accessibility_node(widget));
+            }
+            RootWidgetMembers::Form(widget) => {
+                let fields = widget.content.ordered_members
+                    .iter()
+                    .filter_map(|members| self.form_field_accessibility_node(members));
+
+                nodes.push(
+                    AccessibleNode::new(plugin_widget_key(widget.__id__), Role::Form)
+                        .children(fields)
+                );
+            }
+            RootWidgetMembers::List(widget) => {
+                let RootState { focused_item, .. } = self.root_state(widget.__id__);
+
+                let mut index = 0;
+                let mut item_node = |widget: &ListItemWidget| {
+                    let label = match &widget.subtitle {
+                        None => widget.title.to_string(),
+                        Some(subtitle) => format!("{}, {}", widget.title, subtitle),
+                    };
+
+                    let selected = focused_item.index == Some(index);
+                    index += 1;
+
+                    if selected {
+                        focus = Some(plugin_widget_key(widget.__id__));
+                    }
+
+                    let node = AccessibleNode::new(plugin_widget_key(widget.__id__), Role::ListBoxOption)
+                        .label(label)
+                        .selected(selected)
+                        .clickable();
+
+                    match self.state.get(&widget.__id__) {
+                        Some(ComponentWidgetState::ListItem(ListItemState { selected })) => node.toggled(*selected),
+                        _ => node
+                    }
+                };
+
+                let items: Vec<_> = widget.content.ordered_members
+                    .iter()
+                    .map(|members| {
+                        match members {
+                            ListWidgetOrderedMembers::ListItem(widget) => item_node(widget),
+                            ListWidgetOrderedMembers::ListSection(widget) => {
+                                let items: Vec<_> = widget.content.ordered_members
+                                    .iter()
+                                    .map(|members| match members {
+                                        ListSectionWidgetOrderedMembers::ListItem(widget) => item_node(widget)
+                                    })
+                                    .collect();
+
+                                AccessibleNode::new(plugin_widget_key(widget.__id__), Role::Group)
+                                    .label(widget.title.to_string())
+                                    .children(items)
+                            }
+                        }
+                    })
+                    .collect();
+
+                match (&widget.content.empty_view, items.is_empty()) {
+                    (Some(empty_view), true) => nodes.push(empty_view_accessibility_node(empty_view)),
+                    _ => {
+                        nodes.push(
+                            AccessibleNode::new(plugin_widget_key(widget.__id__), Role::ListBox)
+                                .children(items)
+                        )
+                    }
+                }
+
+                if let Some(detail) = &widget.content.detail {
+                    nodes.push(self.detail_accessibility_node(detail));
+                }
+            }
+            RootWidgetMembers::Grid(widget) => {
+                let RootState { focused_item, .. } = self.root_state(widget.__id__);
+
+                let mut index = 0;
+                let mut item_node = |widget: &GridItemWidget| {
+                    let label = match (&widget.title, &widget.subtitle) {
+                        (Some(title), Some(subtitle)) => format!("{}, {}", title, subtitle),
+                        (Some(title), None) => title.to_string(),
+                        (None, Some(subtitle)) => subtitle.to_string(),
+                        (None, None) => content_text(&widget.content.content),
+                    };
+
+                    let selected = focused_item.index == Some(index);
+                    index += 1;
+
+                    if selected {
+                        focus = Some(plugin_widget_key(widget.__id__));
+                    }
+
+                    AccessibleNode::new(plugin_widget_key(widget.__id__), Role::ListBoxOption)
+                        .label(label)
+                        .selected(selected)
+                        .clickable()
+                };
+
+                let items: Vec<_> = widget.content.ordered_members
+                    .iter()
+                    .map(|members| {
+                        match members {
+                            GridWidgetOrderedMembers::GridItem(widget) => item_node(widget),
+                            GridWidgetOrderedMembers::GridSection(widget) => {
+                                let items: Vec<_> = widget.content.ordered_members
+                                    .iter()
+                                    .map(|members| match members {
+                                        GridSectionWidgetOrderedMembers::GridItem(widget) => item_node(widget)
+                                    })
+                                    .collect();
+
+                                AccessibleNode::new(plugin_widget_key(widget.__id__), Role::Group)
+                                    .label(widget.title.to_string())
+                                    .children(items)
+                            }
+                        }
+                    })
+                    .collect();
+
+                nodes.push(
+                    AccessibleNode::new(plugin_widget_key(widget.__id__), Role::ListBox)
+                        .children(items)
+                );
+            }
+            RootWidgetMembers::Inline(widget) => {
+                nodes.extend(inline_accessibility_nodes(widget, "plugin-widget"));
+            }
+        }
+
+        if let Some(focused_control) = self.focused_control() {
+            focus = Some(plugin_widget_key(focused_control));
+        }
+
+        if let PluginViewState::ActionPanel { focused_action_item } = plugin_view_state {
+            if let Some(action_panel) = self.get_action_panel(action_shortcuts) {
+                let action_ids = self.get_action_ids();
+
+                if let Some(widget_id) = focused_action_item.get(&action_ids) {
+                    focus = Some(plugin_widget_key(*widget_id));
+                }
+
+                nodes.push(action_panel_accessibility_node("plugin-action-panel", &action_panel, &action_ids, focused_action_item.index));
+            }
+        }
+
+        (nodes, focus)
+    }
+
+    pub fn inline_accessibility_tree(&self, key_prefix: &str) -> Vec<AccessibleNode> {
+        match self.root_widget.as_ref().and_then(|root_widget| root_widget.content.as_ref()) {
+            Some(RootWidgetMembers::Inline(widget)) => inline_accessibility_nodes(widget, key_prefix),
+            _ => vec![]
+        }
+    }
+
+    fn detail_accessibility_node(&self, widget: &DetailWidget) -> AccessibleNode {
+        let focused_control = self.focused_control();
+
+        let mut node = AccessibleNode::new(plugin_widget_key(widget.__id__), Role::Document);
+
+        if let Some(content) = &widget.content.content {
+            node = node.children(content_accessibility_nodes(content, "plugin-widget"));
+        }
+
+        if let Some(metadata) = &widget.content.metadata {
+            let items = metadata.content.ordered_members
+                .iter()
+                .filter_map(|members| {
+                    match members {
+                        MetadataWidgetOrderedMembers::MetadataTagList(widget) => {
+                            let tags = widget.content.ordered_members
+                                .iter()
+                                .map(|members| {
+                                    match members {
+                                        MetadataTagListWidgetOrderedMembers::MetadataTagItem(widget) => {
+                                            AccessibleNode::new(plugin_widget_key(widget.__id__), Role::Button)
+                                                .label(widget.content.text.join(""))
+                                                .selected(focused_control == Some(widget.__id__))
+                                                .clickable()
+                                        }
+                                    }
+                                });
+
+                            let node = AccessibleNode::new(plugin_widget_key(widget.__id__), Role::Group)
+                                .label(widget.label.to_string())
+                                .children(tags);
+
+                            Some(node)
+                        }
+                        MetadataWidgetOrderedMembers::MetadataLink(widget) => {
+                            let node = AccessibleNode::new(plugin_widget_key(widget.__id__), Role::Link)
+                                .label(format!("{}: {}", widget.label, widget.content.text.join("")))
+                                .value(widget.href.to_string())
+                                .clickable();
+
+                            Some(node)
+                        }
+                        MetadataWidgetOrderedMembers::MetadataValue(widget) => {
+                            let node = AccessibleNode::new(plugin_widget_key(widget.__id__), Role::Label)
+                                .label(format!("{}: {}", widget.label, widget.content.text.join("")));
+
+                            Some(node)
+                        }
+                        MetadataWidgetOrderedMembers::MetadataIcon(widget) => {
+                            let node = AccessibleNode::new(plugin_widget_key(widget.__id__), Role::Label)
+                                .label(widget.label.to_string());
+
+                            Some(node)
+                        }
+                        MetadataWidgetOrderedMembers::MetadataSeparator(_) => None,
+                    }
+                });
+
+            node = node.child(
+                AccessibleNode::new(plugin_widget_key(metadata.__id__), Role::DescriptionList)
+                    .children(items)
+            );
+        }
+
+        node
+    }
+
+    fn form_field_accessibility_node(&self, members: &FormWidgetOrderedMembers) -> Option<AccessibleNode> {
+        let node = match members {
+            FormWidgetOrderedMembers::TextField(widget) => {
+                let TextFieldState { state_value, .. } = self.text_field_state(widget.__id__);
+
+                AccessibleNode::new(plugin_widget_key(widget.__id__), Role::TextInput)
+                    .label(widget.label.to_owned().unwrap_or_default())
+                    .value(state_value.to_string())
+                    .focusable()
+            }
+            FormWidgetOrderedMembers::PasswordField(widget) => {
+                // value is not exposed, the same way it is not shown
+                AccessibleNode::new(plugin_widget_key(widget.__id__), Role::PasswordInput)
+                    .label(widget.label.to_owned().unwrap_or_default())
+                    .focusable()
+            }
+            FormWidgetOrderedMembers::Checkbox(widget) => {
+                let CheckboxState { state_value } = self.checkbox_state(widget.__id__);
+
+                let label = widget.label.as_ref()
+                    .or(widget.title.as_ref())
+                    .cloned()
+                    .unwrap_or_default();
+
+                AccessibleNode::new(plugin_widget_key(widget.__id__), Role::CheckBox)
+                    .label(label)
+                    .toggled(*state_value)
+                    .clickable()
+            }
+            FormWidgetOrderedMembers::DatePicker(widget) => {
+                let DatePickerState { state_value, .. } = self.date_picker_state(widget.__id__);
 
-                let total = grid_widget.content.ordered_members
+                AccessibleNode::new(plugin_widget_key(widget.__id__), Role::DateInput)
+                    .label(widget.label.to_owned().unwrap_or_default())
+                    .value(state_value.to_string())
+                    .clickable()
+            }
+            FormWidgetOrderedMembers::Select(widget) => {
+                let SelectState { state_value } = self.select_state(widget.__id__);
+
+                let selected_label = widget.content.ordered_members
                     .iter()
-                    .flat_map(|members| {
+                    .find_map(|members| {
                         match members {
-                            GridWidgetOrderedMembers::GridItem(widget) => vec![widget],
-                            GridWidgetOrderedMembers::GridSection(widget) => {
-                                widget.content.ordered_members
-                                    .iter()
-                                    .map(|members| {
-                                        match members {
-                                            GridSectionWidgetOrderedMembers::GridItem(widget) => widget,
-                                        }
-                                    })
-                                    .collect()
+                            SelectWidgetOrderedMembers::SelectItem(item) => {
+                                (Some(&item.value) == state_value.as_ref()).then(|| item.content.text.join(""))
                             }
                         }
                     })
-                    .count();
-
-                let _ = focused_item.focus_next(total);
+                    .unwrap_or_default();
 
-                // focused_item.scroll_to(0)
-                Task::none()
+                AccessibleNode::new(plugin_widget_key(widget.__id__), Role::ComboBox)
+                    .label(widget.label.to_owned().unwrap_or_default())
+                    .value(selected_label)
+                    .clickable()
             }
-        }
-    }
-
-    pub fn get_action_panel(&self, action_shortcuts: &HashMap<String, PhysicalShortcut>) -> Option<ActionPanel> {
-        let Some(root_widget) = &self.root_widget else {
-            return None;
+            FormWidgetOrderedMembers::Slider(widget) => {
+                let SliderState { state_value } = self.slider_state(widget.__id__);
+                let (_, _, step) = slider_range(widget);
+
+                AccessibleNode::new(plugin_widget_key(widget.__id__), Role::Slider)
+                    .label(widget.label.to_owned().unwrap_or_default())
+                    .value(format_slider_value(*state_value, step))
+                    .focusable()
+            }
+            FormWidgetOrderedMembers::Separator(_) => return None,
         };
 
-        let Some(content) = &root_widget.content else {
-            return None;
-        };
+        Some(node)
+    }
 
-        match content {
-            RootWidgetMembers::Detail(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
-            RootWidgetMembers::Form(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
-            RootWidgetMembers::Inline(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
-            RootWidgetMembers::List(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
-            RootWidgetMembers::Grid(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
+    // text fields show focus by themselves
+    fn render_focus_outline<'a>(&self, content: Element<'a, ComponentWidgetEvent>, widget_id: UiWidgetId) -> Element<'a, ComponentWidgetEvent> {
+        if self.focused_control() == Some(widget_id) {
+            container(content)
+                .themed(ContainerStyle::FocusOutline)
+        } else {
+            content
         }
     }
 
@@ -877,6 +1737,8 @@ impl<'b> ComponentWidgets<'b> {
             .on_press(ComponentWidgetEvent::TagClick { widget_id: widget.__id__ })
             .themed(ButtonStyle::MetadataTagItem);
 
+        let tag = self.render_focus_outline(tag, widget.__id__);
+
         container(tag)
             .themed(ContainerStyle::MetadataTagItem)
     }
@@ -918,6 +1780,8 @@ impl<'b> ComponentWidgets<'b> {
             .on_press(ComponentWidgetEvent::LinkClick { widget_id: widget.__id__, href: widget.href.to_owned() })
             .themed(ButtonStyle::MetadataLink);
 
+        let link = self.render_focus_outline(link, widget.__id__);
+
         let content: Element<_> = if widget.href.is_empty() {
             link
         } else {
@@ -986,7 +1850,55 @@ impl<'b> ComponentWidgets<'b> {
     }
 
     fn render_paragraph_widget<'a>(&self, widget: &ParagraphWidget, centered: bool) -> Element<'a, ComponentWidgetEvent> {
-        let paragraph: Element<_> = self.render_text(&widget.content.text, TextRenderType::None);
+        let spans: Vec<Span<'a, ComponentWidgetEvent, Font>> = widget.content.ordered_members
+            .iter()
+            .map(|members| {
+                match members {
+                    ParagraphWidgetOrderedMembers::Text(value) => span(value.to_owned()),
+                    ParagraphWidgetOrderedMembers::ParagraphBold(widget) => {
+                        span(widget.content.text.join(""))
+                            .font(Font {
+                                weight: Weight::Bold,
+                                ..Font::DEFAULT
+                            })
+                    }
+                    ParagraphWidgetOrderedMembers::ParagraphItalic(widget) => {
+                        span(widget.content.text.join(""))
+                            .font(Font {
+                                style: FontStyle::Italic,
+                                ..Font::DEFAULT
+                            })
+                    }
+                    ParagraphWidgetOrderedMembers::ParagraphCode(widget) => {
+                        span(widget.content.text.join(""))
+                            .font(Font::MONOSPACE)
+                            .themed(SpanStyle::Code)
+                    }
+                    ParagraphWidgetOrderedMembers::ParagraphLink(widget) => {
+                        let span = span(widget.content.text.join(""))
+                            .link(ComponentWidgetEvent::LinkClick { widget_id: widget.__id__, href: widget.href.to_owned() })
+                            .themed(SpanStyle::Link);
+
+                        match self.style_color(&self.style.link_color) {
+                            Some(color) => span.color(color),
+                            None => span,
+                        }
+                    }
+                    ParagraphWidgetOrderedMembers::ParagraphColor(widget) => {
+                        let span = span(widget.content.text.join(""))
+                            .themed(SpanStyle::Color(&widget.color));
+
+                        match self.style_text_color(&widget.color) {
+                            Some(color) => span.color(color),
+                            None => span,
+                        }
+                    }
+                }
+            })
+            .collect();
+
+        let paragraph: Element<_> = rich_text(spans)
+            .into();
 
         let mut content = container(paragraph)
             .width(Length::Fill);
@@ -1056,6 +1968,56 @@ impl<'b> ComponentWidgets<'b> {
             .themed(ContainerStyle::ContentCodeBlock)
     }
 
+    fn render_color_swatch_widget<'a>(&self, widget: &ColorSwatchWidget, centered: bool) -> Element<'a, ComponentWidgetEvent> {
+        // invalid color is shown as empty swatch instead of erroring whole view
+        let color = parse_hex_color(&widget.color)
+            .unwrap_or(Color::TRANSPARENT);
+
+        let swatch: Element<_> = container(Space::new(Length::Fixed(COLOR_SWATCH_SIZE), Length::Fixed(COLOR_SWATCH_SIZE)))
+            .themed(ContainerStyle::ContentColorSwatch(color));
+
+        let mut content = container(swatch)
+            .width(Length::Fill);
+
+        if centered {
+            content = content.align_x(Horizontal::Center)
+        }
+
+        content.into()
+    }
+
+    fn render_chart<'a>(&self, kind: ChartKind, data: &[f64], color: &Option<String>, height: &Option<f64>) -> Element<'a, ComponentWidgetEvent> {
+        // same as for color swatch, invalid color falls back to theme color instead of erroring whole view
+        let color = color.as_deref()
+            .and_then(|color| parse_hex_color(color))
+            .or_else(|| self.style_color(&self.style.accent_color));
+
+        let height = height
+            .filter(|height| height.is_finite() && *height > 0.0)
+            .map(|height| height as f32)
+            .unwrap_or(CHART_HEIGHT);
+
+        let chart: Chart<GauntletComplexTheme> = Chart::new(kind, data.to_vec())
+            .color(color);
+
+        let chart: Element<_> = canvas(chart)
+            .width(Length::Fill)
+            .height(Length::Fixed(height))
+            .themed(ChartStyle::Default);
+
+        container(chart)
+            .width(Length::Fill)
+            .themed(ContainerStyle::ContentChart)
+    }
+
+    fn render_sparkline_widget<'a>(&self, widget: &SparklineWidget) -> Element<'a, ComponentWidgetEvent> {
+        self.render_chart(ChartKind::Sparkline, &widget.data, &widget.color, &widget.height)
+    }
+
+    fn render_bar_chart_widget<'a>(&self, widget: &BarChartWidget) -> Element<'a, ComponentWidgetEvent> {
+        self.render_chart(ChartKind::Bar, &widget.data, &widget.color, &widget.height)
+    }
+
     fn render_content_widget<'a>(&self, widget: &ContentWidget, centered: bool) -> Element<'a, ComponentWidgetEvent> {
         let content: Vec<_> = widget.content.ordered_members
             .iter()
@@ -1071,11 +2033,15 @@ impl<'b> ComponentWidgets<'b> {
                     ContentWidgetOrderedMembers::H6(widget) => self.render_h6_widget(widget),
                     ContentWidgetOrderedMembers::HorizontalBreak(widget) => self.render_horizontal_break_widget(widget),
                     ContentWidgetOrderedMembers::CodeBlock(widget) => self.render_code_block_widget(widget),
+                    ContentWidgetOrderedMembers::ColorSwatch(widget) => self.render_color_swatch_widget(widget, centered),
+                    ContentWidgetOrderedMembers::Sparkline(widget) => self.render_sparkline_widget(widget),
+                    ContentWidgetOrderedMembers::BarChart(widget) => self.render_bar_chart_widget(widget),
                 }
             })
             .collect();
 
         let content: Element<_> = column(content)
+            .spacing(self.style.content_spacing.unwrap_or(0.0))
             .into();
 
         if centered {
@@ -1159,18 +2125,20 @@ impl<'b> ComponentWidgets<'b> {
 
     fn render_text_field_widget<'a>(&self, widget: &TextFieldWidget) -> Element<'a, ComponentWidgetEvent> {
         let widget_id = widget.__id__;
-        let TextFieldState { state_value, .. } = self.text_field_state(widget.__id__);
+        let TextFieldState { state_value, text_input_id } = self.text_field_state(widget.__id__);
 
         text_input("", state_value)
+            .id(text_input_id.clone())
             .on_input(move |value| ComponentWidgetEvent::OnChangeTextField { widget_id, value })
             .themed(TextInputStyle::FormInput)
     }
 
     fn render_password_field_widget<'a>(&self, widget: &PasswordFieldWidget) -> Element<'a, ComponentWidgetEvent> {
         let widget_id = widget.__id__;
-        let TextFieldState { state_value, .. } = self.text_field_state(widget_id);
+        let TextFieldState { state_value, text_input_id } = self.text_field_state(widget_id);
 
         text_input("", state_value)
+            .id(text_input_id.clone())
             .secure(true)
             .on_input(move |value| ComponentWidgetEvent::OnChangePasswordField { widget_id, value })
             .themed(TextInputStyle::FormInput)
@@ -1180,9 +2148,11 @@ impl<'b> ComponentWidgets<'b> {
         let widget_id = widget.__id__;
         let CheckboxState { state_value } = self.checkbox_state(widget_id);
 
-        checkbox(widget.title.as_deref().unwrap_or_default(), state_value.to_owned())
+        let checkbox = checkbox(widget.title.as_deref().unwrap_or_default(), state_value.to_owned())
             .on_toggle(move |value| ComponentWidgetEvent::ToggleCheckbox { widget_id, value })
-            .into()
+            .into();
+
+        self.render_focus_outline(checkbox, widget_id)
     }
 
     fn render_date_picker_widget<'a>(&self, widget: &DatePickerWidget) -> Element<'a, ComponentWidgetEvent> {
@@ -1199,7 +2169,7 @@ impl<'b> ComponentWidgets<'b> {
         //     DatePickerUnderlay,
         //     DatePickerOverlay,
 
-        date_picker(
+        let date_picker = date_picker(
             show_picker.to_owned(),
             state_value.to_owned(),
             button,
@@ -1210,7 +2180,9 @@ impl<'b> ComponentWidgets<'b> {
                     value: date.to_string(),
                 }
             },
-        ).themed(DatePickerStyle::Default)
+        ).themed(DatePickerStyle::Default);
+
+        self.render_focus_outline(date_picker, widget_id)
     }
 
     fn render_select_widget<'a>(&self, widget: &SelectWidget) -> Element<'a, ComponentWidgetEvent> {
@@ -1236,11 +2208,34 @@ impl<'b> ComponentWidgets<'b> {
             .flatten()
             .map(|value| value.clone());
 
-        pick_list(
+        let pick_list = pick_list(
             items,
             state_value,
             move |item| ComponentWidgetEvent::SelectPickList { widget_id, value: item.value },
-        ).themed(PickListStyle::Default)
+        ).themed(PickListStyle::Default);
+
+        self.render_focus_outline(pick_list, widget_id)
+    }
+
+    fn render_slider_widget<'a>(&self, widget: &SliderWidget) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let SliderState { state_value } = self.slider_state(widget_id);
+        let (min, max, step) = slider_range(widget);
+
+        let slider: Element<_> = slider(min..=max, *state_value, move |value| ComponentWidgetEvent::OnChangeSlider { widget_id, value })
+            .step(step)
+            .themed(SliderStyle::Default);
+
+        // value is shown next to the slider, so that user knows what exactly is selected
+        let value_label: Element<_> = text(format_slider_value(*state_value, step))
+            .into();
+
+        let content: Element<_> = row(vec![slider, value_label])
+            .spacing(8)
+            .align_y(Alignment::Center)
+            .into();
+
+        self.render_focus_outline(content, widget_id)
     }
 
     fn render_separator_widget<'a>(&self, _widget: &SeparatorWidget) -> Element<'a, ComponentWidgetEvent> {
@@ -1306,7 +2301,8 @@ impl<'b> ComponentWidgets<'b> {
                     FormWidgetOrderedMembers::PasswordField(widget) => render_field(self.render_password_field_widget(widget), &widget.label),
                     FormWidgetOrderedMembers::Checkbox(widget) => render_field(self.render_checkbox_widget(widget), &widget.label),
                     FormWidgetOrderedMembers::DatePicker(widget) => render_field(self.render_date_picker_widget(widget), &widget.label),
-                    FormWidgetOrderedMembers::Select(widget) => render_field(self.render_select_widget(widget), &widget.label)
+                    FormWidgetOrderedMembers::Select(widget) => render_field(self.render_select_widget(widget), &widget.label),
+                    FormWidgetOrderedMembers::Slider(widget) => render_field(self.render_slider_widget(widget), &widget.label),
                 }
             })
             .collect();
@@ -1530,7 +2526,7 @@ impl<'b> ComponentWidgets<'b> {
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
         let widget_id = list_widget.__id__;
-        let RootState { show_action_panel, focused_item } = self.root_state(widget_id);
+        let RootState { show_action_panel, focused_item, .. } = self.root_state(widget_id);
 
         let mut pending: Vec<&ListItemWidget> = vec![];
         let mut items: Vec<Element<_>> = vec![];
@@ -1577,6 +2573,17 @@ impl<'b> ComponentWidgets<'b> {
             items.push(content);
         }
 
+        // items of slow data sources are appended as they arrive, footer shows that list is not complete yet
+        if !items.is_empty() && list_widget.is_loading_more.unwrap_or(false) {
+            let loading_more: Element<_> = text(tr("main-list-loading-more"))
+                .themed(TextStyle::ListSectionSubtitle);
+
+            let loading_more: Element<_> = row([loading_more])
+                .themed(RowStyle::ListSectionTitle);
+
+            items.push(loading_more);
+        }
+
         let content = if items.is_empty() {
             match &list_widget.content.empty_view {
                 Some(widget) => self.render_empty_view_widget(widget),
@@ -1593,9 +2600,25 @@ impl<'b> ComponentWidgets<'b> {
 
             let content: Element<_> = scrollable(content)
                 .id(focused_item.scrollable_id.clone())
+                .on_scroll(|viewport| ComponentWidgetEvent::ScrollList { offset: viewport.absolute_offset().y })
                 .width(Length::Fill)
                 .into();
 
+            let selected_count = self.selected_list_item_ids().len();
+
+            let content: Element<_> = if selected_count > 0 {
+                let selected_count: Element<_> = text(tr_with("main-list-selected", &[("count", selected_count.into())]))
+                    .themed(TextStyle::ListSectionSubtitle);
+
+                let selected_count: Element<_> = row([selected_count])
+                    .themed(RowStyle::ListFirstSectionTitle);
+
+                column([selected_count, content])
+                    .into()
+            } else {
+                content
+            };
+
             let content: Element<_> = container(content)
                 .width(Length::FillPortion(3))
                 .themed(ContainerStyle::List);
@@ -1717,14 +2740,29 @@ impl<'b> ComponentWidgets<'b> {
             content.push(accessories);
         }
 
+        if let Some(ComponentWidgetState::ListItem(ListItemState { selected })) = self.state.get(&widget.__id__) {
+            let widget_id = widget.__id__;
+
+            let selection: Element<_> = checkbox("", *selected)
+                .on_toggle(move |_| ComponentWidgetEvent::ToggleListItemSelection { widget_id })
+                .into();
+
+            let selection: Element<_> = container(selection)
+                .themed(ContainerStyle::ListItemIcon);
+
+            content.insert(0, selection)
+        }
+
         let content: Element<_> = row(content)
             .align_y(Alignment::Center)
             .into();
 
+        let index = index_counter.get();
+
         let style = match item_focus_index {
             None => ButtonStyle::ListItem,
             Some(focused_index) => {
-                if focused_index == index_counter.get() {
+                if focused_index == index {
                     ButtonStyle::ListItemFocused
                 } else {
                     ButtonStyle::ListItem
@@ -1732,12 +2770,27 @@ impl<'b> ComponentWidgets<'b> {
             }
         };
 
-        index_counter.set(index_counter.get() + 1);
+        index_counter.set(index + 1);
 
-        button(content)
+        let content: Element<_> = button(content)
             .on_press(ComponentWidgetEvent::ListItemClick { widget_id: widget.__id__ })
             .width(Length::Fill)
-            .themed(style)
+            .themed(style);
+
+        let content = render_drag_source(widget.__id__, &widget.drag, content);
+
+        // focus follows the cursor only when it moves, so that item under resting cursor
+        // doesn't take focus when list is scrolled with keyboard
+        let content = mouse_area(content)
+            .on_right_press(ComponentWidgetEvent::ListItemContextMenu { index });
+
+        if item_focus_index == Some(index) {
+            content.into()
+        } else {
+            content
+                .on_move(move |_| ComponentWidgetEvent::FocusListItemMouse { index })
+                .into()
+        }
     }
 
     fn render_grid_widget<'a>(
@@ -1747,7 +2800,7 @@ impl<'b> ComponentWidgets<'b> {
         entrypoint_name: &str,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
-        let RootState { show_action_panel, focused_item } = self.root_state(grid_widget.__id__);
+        let RootState { show_action_panel, focused_item, .. } = self.root_state(grid_widget.__id__);
 
         let mut pending: Vec<&GridItemWidget> = vec![];
         let mut items: Vec<Element<_>> = vec![];
@@ -1791,6 +2844,7 @@ impl<'b> ComponentWidgets<'b> {
 
         let content: Element<_> = scrollable(content)
             .id(focused_item.scrollable_id.clone())
+            .on_scroll(|viewport| ComponentWidgetEvent::ScrollList { offset: viewport.absolute_offset().y })
             .width(Length::Fill)
             .into();
 
@@ -1873,6 +2927,8 @@ impl<'b> ComponentWidgets<'b> {
             .width(Length::Fill)
             .themed(style);
 
+        let content = render_drag_source(widget.__id__, &widget.drag, content);
+
         let mut sub_content_left = vec![];
 
         if let Some(title) = &widget.title {
@@ -2000,6 +3056,7 @@ impl<'b> ComponentWidgets<'b> {
 
         let top_separator = if is_loading {
             LoadingBar::new()
+                .animated(!GauntletComplexTheme::reduced_motion())
                 .into()
         } else {
             horizontal_rule(1)
@@ -2038,6 +3095,8 @@ impl<'b> ComponentWidgets<'b> {
                     || ComponentWidgetEvent::ToggleActionPanel { widget_id },
                     |widget_id| ComponentWidgetEvent::RunPrimaryAction { widget_id },
                     |widget_id| ComponentWidgetEvent::ActionClick { widget_id },
+                    |index| ComponentWidgetEvent::FocusActionMouse { index },
+                    |offset| ComponentWidgetEvent::ScrollActionPanel { offset },
                     || ComponentWidgetEvent::Noop,
                 )
             }
@@ -2055,6 +3114,8 @@ impl<'b> ComponentWidgets<'b> {
                     || ComponentWidgetEvent::ToggleActionPanel { widget_id },
                     |widget_id| ComponentWidgetEvent::RunPrimaryAction { widget_id },
                     |widget_id| ComponentWidgetEvent::ActionClick { widget_id },
+                    |index| ComponentWidgetEvent::FocusActionMouse { index },
+                    |offset| ComponentWidgetEvent::ScrollActionPanel { offset },
                     || ComponentWidgetEvent::Noop,
                 )
             }
@@ -2065,8 +3126,9 @@ impl<'b> ComponentWidgets<'b> {
         match image_data {
             Image::ImageSource(_) => {
                 match self.images.get(&widget_id) {
-                    Some(bytes) => {
-                        image(Handle::from_bytes(bytes.clone()))
+                    Some(handle) => {
+                        // same handle is reused between renders so decoded image stays in renderer cache
+                        image(handle.clone())
                             .into()
                     }
                     None => {
@@ -2122,8 +3184,18 @@ fn render_metadata_item<'a>(label: &str, value: Element<'a, ComponentWidgetEvent
         .into()
 }
 
+// zero or negative amount of columns provided by plugin is treated as a single column
 fn grid_width(columns: &Option<f64>) -> usize {
-    columns.map(|value| value.trunc() as usize).unwrap_or(5)
+    columns.map(|value| value.trunc() as usize).unwrap_or(5).max(1)
+}
+
+// min, max and step of the slider, invalid values provided by plugin are replaced with defaults
+fn slider_range(widget: &SliderWidget) -> (f64, f64, f64) {
+    let min = widget.min.unwrap_or(0.0);
+    let max = widget.max.unwrap_or(100.0).max(min);
+    let step = widget.step.filter(|step| *step > 0.0).unwrap_or(1.0);
+
+    (min, max, step)
 }
 
 
@@ -2221,10 +3293,238 @@ impl ActionPanelItem {
     }
 }
 
+pub fn plugin_widget_key(widget_id: UiWidgetId) -> String {
+    format!("plugin-widget:{}", widget_id)
+}
+
+pub fn parse_plugin_widget_key(key: &str) -> Option<UiWidgetId> {
+    key.strip_prefix("plugin-widget:")?.parse().ok()
+}
+
+fn detail_controls(widget: &DetailWidget) -> Vec<UiWidgetId> {
+    let Some(metadata) = &widget.content.metadata else {
+        return vec![];
+    };
+
+    metadata.content.ordered_members
+        .iter()
+        .flat_map(|members| {
+            match members {
+                MetadataWidgetOrderedMembers::MetadataLink(widget) => vec![widget.__id__],
+                MetadataWidgetOrderedMembers::MetadataTagList(widget) => {
+                    widget.content.ordered_members
+                        .iter()
+                        .map(|members| match members {
+                            MetadataTagListWidgetOrderedMembers::MetadataTagItem(widget) => widget.__id__
+                        })
+                        .collect()
+                }
+                _ => vec![],
+            }
+        })
+        .collect()
+}
+
+fn detail_activation_event(widget: &DetailWidget, widget_id: UiWidgetId) -> Option<ComponentWidgetEvent> {
+    let metadata = widget.content.metadata.as_ref()?;
+
+    metadata.content.ordered_members
+        .iter()
+        .find_map(|members| {
+            match members {
+                MetadataWidgetOrderedMembers::MetadataLink(widget) if widget.__id__ == widget_id => {
+                    Some(ComponentWidgetEvent::LinkClick { widget_id, href: widget.href.to_owned() })
+                }
+                MetadataWidgetOrderedMembers::MetadataTagList(widget) => {
+                    widget.content.ordered_members
+                        .iter()
+                        .find_map(|members| {
+                            match members {
+                                MetadataTagListWidgetOrderedMembers::MetadataTagItem(widget) if widget.__id__ == widget_id => {
+                                    Some(ComponentWidgetEvent::TagClick { widget_id })
+                                }
+                                _ => None
+                            }
+                        })
+                }
+                _ => None
+            }
+        })
+}
+
+// supports #rgb, #rgba, #rrggbb and #rrggbbaa
+fn parse_hex_color(value: &str) -> Option<Color> {
+    let hex = value.trim().strip_prefix('#')?;
+
+    if !hex.chars().all(|char| char.is_ascii_hexdigit()) {
+        return None
+    }
+
+    let hex = match hex.len() {
+        3 | 4 => hex.chars().flat_map(|char| [char, char]).collect::<String>(),
+        6 | 8 => hex.to_owned(),
+        _ => return None,
+    };
+
+    let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+
+    let alpha = if hex.len() == 8 { channel(6)? } else { 255 };
+
+    Some(Color::from_rgba8(channel(0)?, channel(2)?, channel(4)?, alpha as f32 / 255.0))
+}
+
+// charts are only drawn, so values are exposed as text for copying and screen readers
+fn chart_text(data: &[f64]) -> String {
+    data.iter()
+        .map(|value| value.to_string())
+        .join(", ")
+}
+
+// styling of paragraph parts is dropped, only text is left
+fn paragraph_text(widget: &ParagraphWidget) -> String {
+    widget.content.ordered_members
+        .iter()
+        .map(|members| {
+            match members {
+                ParagraphWidgetOrderedMembers::Text(value) => value.to_owned(),
+                ParagraphWidgetOrderedMembers::ParagraphBold(widget) => widget.content.text.join(""),
+                ParagraphWidgetOrderedMembers::ParagraphItalic(widget) => widget.content.text.join(""),
+                ParagraphWidgetOrderedMembers::ParagraphCode(widget) => widget.content.text.join(""),
+                ParagraphWidgetOrderedMembers::ParagraphLink(widget) => widget.content.text.join(""),
+                ParagraphWidgetOrderedMembers::ParagraphColor(widget) => widget.content.text.join(""),
+            }
+        })
+        .join("")
+}
+
+fn content_text(widget: &ContentWidget) -> String {
+    widget.content.ordered_members
+        .iter()
+        .filter_map(|members| {
+            match members {
+                ContentWidgetOrderedMembers::Paragraph(widget) => Some(paragraph_text(widget)),
+                ContentWidgetOrderedMembers::H1(widget) => Some(widget.content.text.join("")),
+                ContentWidgetOrderedMembers::H2(widget) => Some(widget.content.text.join("")),
+                ContentWidgetOrderedMembers::H3(widget) => Some(widget.content.text.join("")),
+                ContentWidgetOrderedMembers::H4(widget) => Some(widget.content.text.join("")),
+                ContentWidgetOrderedMembers::H5(widget) => Some(widget.content.text.join("")),
+                ContentWidgetOrderedMembers::H6(widget) => Some(widget.content.text.join("")),
+                ContentWidgetOrderedMembers::CodeBlock(widget) => Some(widget.content.text.join("")),
+                ContentWidgetOrderedMembers::ColorSwatch(widget) => Some(widget.color.clone()),
+                ContentWidgetOrderedMembers::Sparkline(widget) => Some(chart_text(&widget.data)),
+                ContentWidgetOrderedMembers::BarChart(widget) => Some(chart_text(&widget.data)),
+                ContentWidgetOrderedMembers::Image(_) | ContentWidgetOrderedMembers::HorizontalBreak(_) => None,
+            }
+        })
+        .join(" ")
+}
+
+// key prefix is needed because widget ids are only unique within one plugin
+fn content_accessibility_nodes(widget: &ContentWidget, key_prefix: &str) -> Vec<AccessibleNode> {
+    widget.content.ordered_members
+        .iter()
+        .filter_map(|members| {
+            let (widget_id, role, label) = match members {
+                ContentWidgetOrderedMembers::Paragraph(widget) => (widget.__id__, Role::Paragraph, Some(paragraph_text(widget))),
+                ContentWidgetOrderedMembers::H1(widget) => (widget.__id__, Role::Heading, Some(widget.content.text.join(""))),
+                ContentWidgetOrderedMembers::H2(widget) => (widget.__id__, Role::Heading, Some(widget.content.text.join(""))),
+                ContentWidgetOrderedMembers::H3(widget) => (widget.__id__, Role::Heading, Some(widget.content.text.join(""))),
+                ContentWidgetOrderedMembers::H4(widget) => (widget.__id__, Role::Heading, Some(widget.content.text.join(""))),
+                ContentWidgetOrderedMembers::H5(widget) => (widget.__id__, Role::Heading, Some(widget.content.text.join(""))),
+                ContentWidgetOrderedMembers::H6(widget) => (widget.__id__, Role::Heading, Some(widget.content.text.join(""))),
+                ContentWidgetOrderedMembers::CodeBlock(widget) => (widget.__id__, Role::Code, Some(widget.content.text.join(""))),
+                ContentWidgetOrderedMembers::ColorSwatch(widget) => (widget.__id__, Role::Image, Some(widget.color.clone())),
+                ContentWidgetOrderedMembers::Sparkline(widget) => (widget.__id__, Role::Image, Some(chart_text(&widget.data))),
+                ContentWidgetOrderedMembers::BarChart(widget) => (widget.__id__, Role::Image, Some(chart_text(&widget.data))),
+                ContentWidgetOrderedMembers::Image(widget) => (widget.__id__, Role::Image, None),
+                ContentWidgetOrderedMembers::HorizontalBreak(_) => return None,
+            };
+
+            let node = AccessibleNode::new(format!("{}:{}", key_prefix, widget_id), role);
+
+            let node = match label {
+                None => node,
+                Some(label) => node.label(label),
+            };
+
+            Some(node)
+        })
+        .collect()
+}
+
+fn inline_accessibility_nodes(widget: &InlineWidget, key_prefix: &str) -> Vec<AccessibleNode> {
+    widget.content.ordered_members
+        .iter()
+        .filter_map(|members| {
+            match members {
+                InlineWidgetOrderedMembers::Content(widget) => {
+                    let node = AccessibleNode::new(format!("{}:{}", key_prefix, widget.__id__), Role::Group)
+                        .children(content_accessibility_nodes(widget, key_prefix));
+
+                    Some(node)
+                }
+                InlineWidgetOrderedMembers::InlineSeparator(_) => None
+            }
+        })
+        .collect()
+}
+
+fn empty_view_accessibility_node(widget: &EmptyViewWidget) -> AccessibleNode {
+    let label = match &widget.description {
+        None => widget.title.to_string(),
+        Some(description) => format!("{}. {}", widget.title, description),
+    };
+
+    AccessibleNode::new(plugin_widget_key(widget.__id__), Role::Label)
+        .label(label)
+}
+
+// action ids are in the same order as actions in panel, focused index is index in this list
+pub fn action_panel_accessibility_node(key: &str, action_panel: &ActionPanel, action_ids: &[UiWidgetId], focused_index: Option<usize>) -> AccessibleNode {
+    fn item_nodes(items: &[ActionPanelItem], action_ids: &[UiWidgetId], focused_index: Option<usize>) -> Vec<AccessibleNode> {
+        items.iter()
+            .map(|item| {
+                match item {
+                    ActionPanelItem::Action { label, widget_id, .. } => {
+                        let selected = focused_index
+                            .and_then(|index| action_ids.get(index))
+                            .is_some_and(|focused| focused == widget_id);
+
+                        AccessibleNode::new(plugin_widget_key(*widget_id), Role::MenuItem)
+                            .label(label.to_string())
+                            .selected(selected)
+                            .clickable()
+                    }
+                    ActionPanelItem::ActionSection { title, items } => {
+                        let section = AccessibleNode::new(format!("plugin-action-section:{}", item_nodes_key(items)), Role::Group)
+                            .children(item_nodes(items, action_ids, focused_index));
+
+                        match title {
+                            None => section,
+                            Some(title) => section.label(title.to_string()),
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    // sections do not have ids, first action in the section identifies it
+    fn item_nodes_key(items: &[ActionPanelItem]) -> String {
+        ActionPanelItem::find_first(items)
+            .map(|(_, widget_id)| widget_id.to_string())
+            .unwrap_or_default()
+    }
+
+    AccessibleNode::new(key, Role::Menu)
+        .label(action_panel.title.clone().unwrap_or_else(|| tr("main-actions")))
+        .children(item_nodes(&action_panel.items, action_ids, focused_index))
+}
+
 fn convert_action_panel(action_panel: &Option<ActionPanelWidget>, action_shortcuts: &HashMap<String, PhysicalShortcut>) -> Option<ActionPanel> {
     match action_panel {
         Some(ActionPanelWidget { content, title, .. }) => {
-            fn action_widget_to_action(ActionWidget { __id__, id, label }: &ActionWidget, action_shortcuts: &HashMap<String, PhysicalShortcut>) -> ActionPanelItem {
+            fn action_widget_to_action(ActionWidget { __id__, id, label, .. }: &ActionWidget, action_shortcuts: &HashMap<String, PhysicalShortcut>) -> ActionPanelItem {
                 let physical_shortcut: Option<PhysicalShortcut> = id.as_ref()
                     .map(|id| action_shortcuts.get(id))
                     .flatten()
@@ -2276,6 +3576,7 @@ fn render_action_panel_items<'a, T: 'a + Clone>(
     items: Vec<ActionPanelItem>,
     action_panel_focus_index: Option<usize>,
     on_action_click: &dyn Fn(UiWidgetId) -> T,
+    on_action_focus: &dyn Fn(usize) -> T,
     index_counter: &Cell<usize>
 ) -> Vec<Element<'a, T>> {
     let mut columns = vec![];
@@ -2347,10 +3648,12 @@ fn render_action_panel_items<'a, T: 'a + Clone>(
                         .into()
                 };
 
+                let index = index_counter.get();
+
                 let style = match action_panel_focus_index {
                     None => ButtonStyle::Action,
                     Some(focused_index) => {
-                        if focused_index == index_counter.get() {
+                        if focused_index == index {
                             ButtonStyle::ActionFocused
                         } else {
                             ButtonStyle::Action
@@ -2358,13 +3661,23 @@ fn render_action_panel_items<'a, T: 'a + Clone>(
                     }
                 };
 
-                index_counter.set(index_counter.get() + 1);
+                index_counter.set(index + 1);
 
-                let content = button(content)
+                let content: Element<_> = button(content)
                     .on_press(on_action_click(widget_id))
                     .width(Length::Fill)
                     .themed(style);
 
+                let content: Element<_> = if action_panel_focus_index == Some(index) {
+                    content
+                } else {
+                    let on_focus = on_action_focus(index);
+
+                    mouse_area(content)
+                        .on_move(move |_| on_focus.clone())
+                        .into()
+                };
+
                 columns.push(content);
             }
             ActionPanelItem::ActionSection { title, items } => {
@@ -2373,7 +3686,7 @@ fn render_action_panel_items<'a, T: 'a + Clone>(
 
                 columns.push(separator);
 
-                let content = render_action_panel_items(title, items, action_panel_focus_index, on_action_click, index_counter);
+                let content = render_action_panel_items(title, items, action_panel_focus_index, on_action_click, on_action_focus, index_counter);
 
                 for content in content {
                     columns.push(content);
@@ -2390,15 +3703,18 @@ fn render_action_panel_items<'a, T: 'a + Clone>(
 fn render_action_panel<'a, T: 'a + Clone, F: Fn(UiWidgetId) -> T, ACTION>(
     action_panel: ActionPanel,
     on_action_click: F,
+    on_action_focus: impl Fn(usize) -> T,
+    on_action_panel_scroll: impl Fn(f32) -> T + 'a,
     action_panel_scroll_handle: &ScrollHandle<ACTION>,
 ) -> Element<'a, T> {
-    let columns = render_action_panel_items(action_panel.title, action_panel.items, action_panel_scroll_handle.index, &on_action_click, &Cell::new(0));
+    let columns = render_action_panel_items(action_panel.title, action_panel.items, action_panel_scroll_handle.index, &on_action_click, &on_action_focus, &Cell::new(0));
 
     let actions: Element<_> = column(columns)
         .into();
 
     let actions: Element<_> = scrollable(actions)
         .id(action_panel_scroll_handle.scrollable_id.clone())
+        .on_scroll(move |viewport| on_action_panel_scroll(viewport.absolute_offset().y))
         .width(Length::Fill)
         .into();
 
@@ -2419,6 +3735,8 @@ pub fn render_root<'a, T: 'a + Clone, ACTION>(
     on_panel_toggle_click: impl Fn() -> T,
     on_panel_primary_click: impl Fn(UiWidgetId) -> T,
     on_action_click: impl Fn(UiWidgetId) -> T,
+    on_action_focus: impl Fn(usize) -> T,
+    on_action_panel_scroll: impl Fn(f32) -> T + 'a,
     noop_msg: impl Fn() -> T,
 ) -> Element<'a, T>  {
     let entrypoint_name: Element<_> = text(entrypoint_name.to_string())
@@ -2455,7 +3773,7 @@ pub fn render_root<'a, T: 'a + Clone, ACTION>(
 
     let (hide_action_panel, action_panel, bottom_panel) = match action_panel {
         Some(action_panel) => {
-            let actions_text: Element<_> = text("Actions")
+            let actions_text: Element<_> = text(tr("main-actions"))
                 .themed(TextStyle::RootBottomPanelActionToggleText);
 
             let actions_text: Element<_> = container(actions_text)
@@ -2563,7 +3881,7 @@ pub fn render_root<'a, T: 'a + Clone, ACTION>(
 
     if let (Some(action_panel), Some(action_panel_scroll_handle)) = (action_panel, action_panel_scroll_handle) {
         if !hide_action_panel {
-            let action_panel = render_action_panel(action_panel, on_action_click, action_panel_scroll_handle);
+            let action_panel = render_action_panel(action_panel, on_action_click, on_action_focus, on_action_panel_scroll, action_panel_scroll_handle);
 
             let action_panel: Element<_>= container(action_panel)
                 .padding(gauntlet_common_ui::padding(0.0, 8.0, 48.0, 0.0))
@@ -2580,7 +3898,7 @@ pub fn render_root<'a, T: 'a + Clone, ACTION>(
 }
 
 
-fn render_shortcut<'a, T: 'a>(shortcut: &PhysicalShortcut) -> Element<'a, T> {
+pub fn render_shortcut<'a, T: 'a>(shortcut: &PhysicalShortcut) -> Element<'a, T> {
     let mut result = vec![];
 
     let (
@@ -2620,6 +3938,22 @@ fn render_shortcut<'a, T: 'a>(shortcut: &PhysicalShortcut) -> Element<'a, T> {
         .themed(RowStyle::ActionShortcut)
 }
 
+fn render_drag_source<'a>(widget_id: UiWidgetId, drag: &Option<DragPayload>, content: Element<'a, ComponentWidgetEvent>) -> Element<'a, ComponentWidgetEvent> {
+    match drag {
+        Some(drag) if DRAG_SUPPORTED => {
+            let data = match drag {
+                DragPayload::DragPayloadFile(DragPayloadFile { file }) => DragData::File(file.clone()),
+                DragPayload::DragPayloadUrl(DragPayloadUrl { url }) => DragData::Url(url.clone()),
+            };
+
+            DragSource::new(content)
+                .on_drag(ComponentWidgetEvent::StartDrag { widget_id, data })
+                .into()
+        }
+        _ => content
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum ComponentWidgetEvent {
     LinkClick {
@@ -2665,19 +3999,46 @@ pub enum ComponentWidgetEvent {
         widget_id: UiWidgetId,
         value: String
     },
+    OnChangeSlider {
+        widget_id: UiWidgetId,
+        value: f64
+    },
     ToggleActionPanel {
         widget_id: UiWidgetId,
     },
     ListItemClick {
         widget_id: UiWidgetId,
     },
+    ToggleListItemSelection {
+        widget_id: UiWidgetId,
+    },
     GridItemClick {
         widget_id: UiWidgetId,
     },
+    StartDrag {
+        widget_id: UiWidgetId,
+        data: DragData,
+    },
     PreviousView,
     RunPrimaryAction {
         widget_id: UiWidgetId,
     },
+    // events below are not related to any widget and are handled by client itself
+    FocusListItemMouse {
+        index: usize,
+    },
+    ListItemContextMenu {
+        index: usize,
+    },
+    ScrollList {
+        offset: f32,
+    },
+    FocusActionMouse {
+        index: usize,
+    },
+    ScrollActionPanel {
+        offset: f32,
+    },
     Noop,
 }
 
@@ -2756,6 +4117,19 @@ impl ComponentWidgetEvent {
 
                 Some(create_select_on_change_event(widget_id, Some(value)))
             }
+            ComponentWidgetEvent::OnChangeSlider { widget_id, value } => {
+                let state = state.expect("state should always exist for ");
+
+                {
+                    let ComponentWidgetState::Slider(SliderState { state_value }) = state else {
+                        panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                    };
+
+                    *state_value = value;
+                }
+
+                Some(create_slider_on_change_event(widget_id, value))
+            }
             ComponentWidgetEvent::OnChangeTextField { widget_id, value } => {
                 let state = state.expect("state should always exist for ");
 
@@ -2803,10 +4177,31 @@ impl ComponentWidgetEvent {
             ComponentWidgetEvent::ListItemClick { widget_id } => {
                 Some(create_list_item_on_click_event(widget_id))
             }
+            ComponentWidgetEvent::ToggleListItemSelection { widget_id } => {
+                let state = state.expect("state should always exist for ");
+
+                let ComponentWidgetState::ListItem(ListItemState { selected }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *selected = !*selected;
+                None
+            }
             ComponentWidgetEvent::GridItemClick { widget_id } => {
                 Some(create_grid_item_on_click_event(widget_id))
             }
-            ComponentWidgetEvent::Noop | ComponentWidgetEvent::PreviousView => {
+            ComponentWidgetEvent::StartDrag { widget_id: _, data } => {
+                Some(UiViewEvent::AppEvent {
+                    event: AppMsg::StartDrag { data, icon: None }
+                })
+            }
+            ComponentWidgetEvent::Noop
+            | ComponentWidgetEvent::PreviousView
+            | ComponentWidgetEvent::FocusListItemMouse { .. }
+            | ComponentWidgetEvent::ListItemContextMenu { .. }
+            | ComponentWidgetEvent::ScrollList { .. }
+            | ComponentWidgetEvent::FocusActionMouse { .. }
+            | ComponentWidgetEvent::ScrollActionPanel { .. } => {
                 panic!("widget_id on these events is not supposed to be called")
             }
             ComponentWidgetEvent::RunPrimaryAction { widget_id } => {
@@ -2828,14 +4223,23 @@ impl ComponentWidgetEvent {
             ComponentWidgetEvent::CancelDatePicker { widget_id, .. } => widget_id,
             ComponentWidgetEvent::ToggleCheckbox { widget_id, .. } => widget_id,
             ComponentWidgetEvent::SelectPickList { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::OnChangeSlider { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangeTextField { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangePasswordField { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangeSearchBar { widget_id, .. } => widget_id,
             ComponentWidgetEvent::ToggleActionPanel { widget_id } => widget_id,
             ComponentWidgetEvent::ListItemClick { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::ToggleListItemSelection { widget_id } => widget_id,
             ComponentWidgetEvent::GridItemClick { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::StartDrag { widget_id, .. } => widget_id,
             ComponentWidgetEvent::RunPrimaryAction { widget_id } => widget_id,
-            ComponentWidgetEvent::Noop | ComponentWidgetEvent::PreviousView => panic!("widget_id on these events is not supposed to be called"),
+            ComponentWidgetEvent::Noop
+            | ComponentWidgetEvent::PreviousView
+            | ComponentWidgetEvent::FocusListItemMouse { .. }
+            | ComponentWidgetEvent::ListItemContextMenu { .. }
+            | ComponentWidgetEvent::ScrollList { .. }
+            | ComponentWidgetEvent::FocusActionMouse { .. }
+            | ComponentWidgetEvent::ScrollActionPanel { .. } => panic!("widget_id on these events is not supposed to be called"),
         }.to_owned()
     }
 }
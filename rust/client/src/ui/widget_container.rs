@@ -2,24 +2,39 @@ use std::collections::hash_map::Entry;
 use crate::model::UiViewEvent;
 use crate::ui::state::PluginViewState;
 use crate::ui::theme::Element;
-use crate::ui::widget::{create_state, ActionPanel, ComponentWidgetEvent, ComponentWidgetState, ComponentWidgets};
-use gauntlet_common::model::{EntrypointId, PhysicalShortcut, PluginId, RootWidget, UiWidgetId};
+use crate::ui::widget::{create_state, list_item_keys, ActionPanel, ComponentWidgetEvent, ComponentWidgetState, ComponentWidgets};
+use gauntlet_common::model::{EntrypointId, PhysicalShortcut, PluginId, PluginStyle, RootWidget, UiImageId, UiWidgetId};
 use std::collections::HashMap;
 use std::mem;
 use std::ops::DerefMut;
+use std::panic::AssertUnwindSafe;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use iced::Task;
+use iced::widget::image::Handle;
+use iced::widget::horizontal_space;
+use gauntlet_common_ui::accessibility::{AccessibleNode, Role};
 use crate::ui::AppMsg;
+use crate::ui::image_cache::ImageCache;
 
 pub struct PluginWidgetContainer {
     root_widget: Arc<Mutex<Option<Arc<RootWidget>>>>,
     state: Arc<Mutex<HashMap<UiWidgetId, ComponentWidgetState>>>,
-    images: HashMap<UiWidgetId, Vec<u8>>,
+    image_ids: HashMap<UiWidgetId, UiImageId>,
+    // images from client context cache, widgets which images are not loaded yet are rendered empty
+    images: HashMap<UiWidgetId, Handle>,
+    // style tokens of the plugin, only set for views, inline views always use global theme
+    style: PluginStyle,
     plugin_id: Option<PluginId>,
     plugin_name: Option<String>,
     entrypoint_id: Option<EntrypointId>,
-    entrypoint_name: Option<String>
+    entrypoint_name: Option<String>,
+    // how long it took to create elements of the latest rendered view, shown in widget inspector
+    layout_time: Arc<Mutex<Option<Duration>>>,
+    // message of the panic that happened while handling widgets of this view,
+    // once set view is not rendered anymore until container is cleared
+    render_panic: Arc<Mutex<Option<String>>>,
 }
 
 impl PluginWidgetContainer {
@@ -27,11 +42,15 @@ impl PluginWidgetContainer {
         Self {
             root_widget: Arc::new(Mutex::new(None)),
             state: Arc::new(Mutex::new(HashMap::new())),
+            image_ids: HashMap::new(),
             images: HashMap::new(),
+            style: PluginStyle::default(),
             plugin_id: None,
             plugin_name: None,
             entrypoint_id: None,
             entrypoint_name: None,
+            layout_time: Arc::new(Mutex::new(None)),
+            render_panic: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -43,10 +62,15 @@ impl PluginWidgetContainer {
         self.entrypoint_id.clone().expect("entrypoint id should always exist after render")
     }
 
+    // none if view was cleared, e.g. event arrived after view was closed
+    pub fn find_entrypoint_id(&self) -> Option<EntrypointId> {
+        self.entrypoint_id.clone()
+    }
+
     pub fn replace_view(
         &mut self,
         container: Arc<RootWidget>,
-        images: HashMap<UiWidgetId, Vec<u8>>,
+        image_ids: HashMap<UiWidgetId, UiImageId>,
         plugin_id: &PluginId,
         plugin_name: &str,
         entrypoint_id: &EntrypointId,
@@ -58,21 +82,30 @@ impl PluginWidgetContainer {
         self.plugin_name = Some(plugin_name.to_string());
         self.entrypoint_id = Some(entrypoint_id.clone());
         self.entrypoint_name = Some(entrypoint_name.to_string());
-        self.images = images;
+        self.image_ids = image_ids;
+
+        if self.render_panic().is_some() {
+            return AppMsg::Noop
+        }
+
+        let Some(new_state) = self.catch_render_panic(|| create_state(&container)) else {
+            return AppMsg::Noop
+        };
 
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
         // use new state with values from old state but only widget ids which exists in new state
         // so we this way we use already existing values but remove state for removed widgets
-        let old_state = mem::replace(state.deref_mut(), create_state(&container));
+        let old_state = mem::replace(state.deref_mut(), new_state);
 
         for (key, value) in old_state.into_iter() {
             match state.entry(key) {
-                Entry::Occupied(mut entry) => {
+                // widget with the same id can be of a different type in the new view, its old state cannot be reused
+                Entry::Occupied(mut entry) if mem::discriminant(entry.get()) == mem::discriminant(&value) => {
                     entry.insert(value);
                 }
-                Entry::Vacant(_) => {}
+                Entry::Occupied(_) | Entry::Vacant(_) => {}
             }
         }
 
@@ -81,19 +114,54 @@ impl PluginWidgetContainer {
             Some(root_widget) => root_widget.content.is_none()
         };
 
+        let previous_list_items = match root_widget.as_ref() {
+            None => vec![],
+            Some(root_widget) => list_item_keys(root_widget)
+        };
+
         *root_widget = Some(container);
 
         if first_open {
-            ComponentWidgets::new(&mut root_widget, &mut state, &self.images)
-                .first_open()
+            self.catch_render_panic(|| ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).first_open())
+                .unwrap_or(AppMsg::Noop)
         } else {
+            self.catch_render_panic(|| ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).list_refreshed(&previous_list_items));
+
             AppMsg::Noop
         }
     }
 
+    pub fn set_style(&mut self, style: PluginStyle) {
+        self.style = style;
+    }
+
+    pub fn get_image_ids(&self) -> impl Iterator<Item = &UiImageId> {
+        self.image_ids.values()
+    }
+
+    pub fn resolve_images(&mut self, image_cache: &ImageCache) {
+        self.images = self.image_ids.iter()
+            .filter_map(|(widget_id, image_id)| {
+                let handle = image_cache.get(image_id)?;
+
+                Some((*widget_id, handle.clone()))
+            })
+            .collect();
+    }
+
     pub fn handle_event(&self, plugin_id: PluginId, event: ComponentWidgetEvent) -> Option<UiViewEvent> {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
+        if let ComponentWidgetEvent::RunAction { widget_id } | ComponentWidgetEvent::ActionClick { widget_id } = &event {
+            let batch_event = ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style)
+                .batch_action_event(*widget_id);
+
+            if batch_event.is_some() {
+                return batch_event
+            }
+        }
+
         let widget_id = event.widget_id();
 
         event.handle(plugin_id, state.get_mut(&widget_id))
@@ -104,88 +172,513 @@ impl PluginWidgetContainer {
         plugin_view_state: &PluginViewState,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
+        if self.render_panic().is_some() {
+            return horizontal_space().into()
+        }
+
+        let start = Instant::now();
+
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images)
-            .render_root_widget(plugin_view_state, self.entrypoint_name.as_ref(), action_shortcuts)
+        let element = self.catch_render_panic(|| {
+            ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style)
+                .render_root_widget(plugin_view_state, self.entrypoint_name.as_ref(), action_shortcuts)
+        });
+
+        *self.layout_time.lock().expect("lock is poisoned") = Some(start.elapsed());
+
+        element.unwrap_or_else(|| horizontal_space().into())
+    }
+
+    pub fn render_panic(&self) -> Option<String> {
+        self.render_panic.lock().expect("lock is poisoned").clone()
+    }
+
+    // widget tree comes from plugin, malformed one should only break this view and not the whole client.
+    // locks have to be taken outside of closure, otherwise they will be poisoned by the panic
+    fn catch_render_panic<T>(&self, render: impl FnOnce() -> T) -> Option<T> {
+        match std::panic::catch_unwind(AssertUnwindSafe(render)) {
+            Ok(value) => Some(value),
+            Err(payload) => {
+                let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                    message.to_string()
+                } else if let Some(message) = payload.downcast_ref::<String>() {
+                    message.clone()
+                } else {
+                    "unknown panic".to_string()
+                };
+
+                tracing::error!("Panic when rendering view of plugin {:?}, entrypoint {:?}: {}", self.plugin_id, self.entrypoint_id, message);
+
+                *self.render_panic.lock().expect("lock is poisoned") = Some(message);
+
+                None
+            }
+        }
+    }
+
+    pub fn layout_time(&self) -> Option<Duration> {
+        *self.layout_time.lock().expect("lock is poisoned")
+    }
+
+    // text dump of widget tree and widget state, shown in widget inspector
+    pub fn inspect(&self) -> String {
+        let root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let state = self.state.lock().expect("lock is poisoned");
+
+        let mut state: Vec<_> = state.iter().collect();
+        state.sort_by_key(|(widget_id, _)| **widget_id);
+
+        let state = state.into_iter()
+            .map(|(widget_id, state)| format!("{}: {:#?}", widget_id, state))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!("{:#?}\n\n{}", root_widget, state)
     }
 
     pub fn render_inline_root_widget<'a>(&self) -> Element<'a, ComponentWidgetEvent> {
+        // inline view which panicked is just hidden, main view stays usable
+        if self.render_panic().is_some() {
+            return horizontal_space().into()
+        }
+
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images)
-            .render_root_inline_widget(self.plugin_name.as_ref(), self.entrypoint_name.as_ref())
+        self.catch_render_panic(|| {
+            ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style)
+                .render_root_inline_widget(self.plugin_name.as_ref(), self.entrypoint_name.as_ref())
+        }).unwrap_or_else(|| horizontal_space().into())
     }
 
     pub fn append_text(&self, text: &str) -> Task<AppMsg> {
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images).append_text(text)
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).append_text(text)
     }
 
     pub fn backspace_text(&self) -> Task<AppMsg> {
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images).backspace_text()
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).backspace_text()
     }
 
     pub fn focus_search_bar(&self, widget_id: UiWidgetId) -> Task<AppMsg> {
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images).focus_search_bar(widget_id)
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).focus_search_bar(widget_id)
     }
 
     pub fn toggle_action_panel(&self) {
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images).toggle_action_panel()
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).toggle_action_panel()
     }
 
     pub fn get_action_ids(&self) -> Vec<UiWidgetId> {
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images).get_action_ids()
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).get_action_ids()
+    }
+
+    pub fn action_keeps_window_open(&self, widget_id: UiWidgetId) -> bool {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).action_keeps_window_open(widget_id)
     }
 
     pub fn get_action_panel(&self, action_shortcuts: &HashMap<String, PhysicalShortcut>) -> Option<ActionPanel> {
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images).get_action_panel(action_shortcuts)
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).get_action_panel(action_shortcuts)
+    }
+
+    pub fn focus_next_control(&self) -> Task<AppMsg> {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).focus_next_control()
+    }
+
+    pub fn focus_previous_control(&self) -> Task<AppMsg> {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).focus_previous_control()
+    }
+
+    pub fn focus_control(&self, widget_id: UiWidgetId) -> Task<AppMsg> {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).focus_control(widget_id)
+    }
+
+    pub fn restore_focus(&self) -> Task<AppMsg> {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).restore_focus()
+    }
+
+    pub fn focused_control_event(&self) -> Option<ComponentWidgetEvent> {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).focused_control_event()
+    }
+
+    pub fn focus_list_item_mouse(&self, index: usize) {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).focus_list_item_mouse(index)
+    }
+
+    pub fn list_scrolled(&self, offset: f32) {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).list_scrolled(offset)
+    }
+
+    pub fn toggle_focused_list_item_selection(&self) {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).toggle_focused_list_item_selection()
+    }
+
+    pub fn selected_list_items_action(&self, action_shortcuts: &HashMap<String, PhysicalShortcut>, shortcut: &PhysicalShortcut) -> Option<UiWidgetId> {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).selected_list_items_action(action_shortcuts, shortcut)
+    }
+
+    pub fn activation_event(&self, widget_id: UiWidgetId) -> Option<ComponentWidgetEvent> {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).activation_event(widget_id)
+    }
+
+    pub fn accessibility_tree(&self, plugin_view_state: &PluginViewState, action_shortcuts: &HashMap<String, PhysicalShortcut>) -> (Vec<AccessibleNode>, Option<String>) {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).accessibility_tree(plugin_view_state, action_shortcuts)
+    }
+
+    pub fn inline_accessibility_tree(&self) -> AccessibleNode {
+        let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        let key = format!("inline:{}", self.get_plugin_id().to_string());
+
+        let children = ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style)
+            .inline_accessibility_tree(&key);
+
+        let label = match (&self.plugin_name, &self.entrypoint_name) {
+            (Some(plugin_name), Some(entrypoint_name)) => format!("{} - {}", plugin_name, entrypoint_name),
+            _ => String::new(),
+        };
+
+        AccessibleNode::new(key, Role::Group)
+            .label(label)
+            .children(children)
     }
 
     pub fn focus_up(&self) -> Task<AppMsg> {
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images).focus_up()
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).focus_up()
     }
 
     pub fn focus_down(&self) -> Task<AppMsg> {
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images).focus_down()
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).focus_down()
     }
 
     pub fn focus_left(&self) -> Task<AppMsg> {
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images).focus_left()
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).focus_left()
     }
 
     pub fn focus_right(&self) -> Task<AppMsg> {
         let mut root_widget = self.root_widget.lock().expect("lock is poisoned");
         let mut state = self.state.lock().expect("lock is poisoned");
 
-        ComponentWidgets::new(&mut root_widget, &mut state, &self.images).focus_right()
+        ComponentWidgets::new(&mut root_widget, &mut state, &self.images, &self.style).focus_right()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::theme::init_default_theme;
+    use proptest::prelude::*;
+    use serde_json::{json, Value};
+
+    #[derive(Debug, Clone)]
+    enum TestField {
+        TextField(Option<String>),
+        PasswordField(Option<String>),
+        Checkbox(Option<bool>),
+        DatePicker(Option<String>),
+        Select(Option<String>),
+        Slider(Option<f64>, Option<f64>, Option<f64>, Option<f64>),
+        Separator,
+    }
+
+    #[derive(Debug, Clone)]
+    enum TestView {
+        Empty,
+        Detail,
+        Form(Vec<TestField>),
+        List {
+            multi_select: Option<bool>,
+            search_bar: Option<Option<String>>,
+            // item has id, item is inside section
+            items: Vec<(bool, bool)>,
+        },
+        Grid {
+            columns: Option<f64>,
+            search_bar: Option<Option<String>>,
+            items: usize,
+        },
+    }
+
+    // ids are taken in order, so the same view with differently ordered ids
+    // results in the same widgets having different ids
+    struct TestIds {
+        ids: Vec<UiWidgetId>,
+        index: usize,
+    }
+
+    impl TestIds {
+        fn next(&mut self) -> UiWidgetId {
+            let id = self.ids[self.index % self.ids.len()];
+            self.index += 1;
+            id
+        }
+    }
+
+    fn text_field_value() -> impl Strategy<Value = Option<String>> {
+        prop::option::of("[a-z]{0,4}")
+    }
+
+    fn field(date: BoxedStrategy<Option<String>>, number: BoxedStrategy<Option<f64>>) -> impl Strategy<Value = TestField> {
+        prop_oneof![
+            text_field_value().prop_map(TestField::TextField),
+            text_field_value().prop_map(TestField::PasswordField),
+            prop::option::of(any::<bool>()).prop_map(TestField::Checkbox),
+            date.prop_map(TestField::DatePicker),
+            prop::option::of(prop::sample::select(vec!["a".to_string(), "b".to_string(), "missing".to_string()])).prop_map(TestField::Select),
+            (number.clone(), number.clone(), number.clone(), number).prop_map(|(value, min, max, step)| TestField::Slider(value, min, max, step)),
+            Just(TestField::Separator),
+        ]
+    }
+
+    fn view(date: BoxedStrategy<Option<String>>, number: BoxedStrategy<Option<f64>>) -> impl Strategy<Value = TestView> {
+        let search_bar = prop::option::of(text_field_value());
+
+        prop_oneof![
+            Just(TestView::Empty),
+            Just(TestView::Detail),
+            prop::collection::vec(field(date, number.clone()), 0..8).prop_map(TestView::Form),
+            (prop::option::of(any::<bool>()), search_bar.clone(), prop::collection::vec((any::<bool>(), any::<bool>()), 0..8))
+                .prop_map(|(multi_select, search_bar, items)| TestView::List { multi_select, search_bar, items }),
+            (number, search_bar, 0..6usize)
+                .prop_map(|(columns, search_bar, items)| TestView::Grid { columns, search_bar, items }),
+        ]
+    }
+
+    fn well_formed_view() -> impl Strategy<Value = TestView> {
+        let date = prop::option::of(prop_oneof![Just("2024-02-29".to_string()), Just("1999-12-31".to_string()), "[a-z]{0,4}"]).boxed();
+        let number = prop::option::of(-10.0..200.0f64).boxed();
+
+        view(date, number)
+    }
+
+    fn pathological_view() -> impl Strategy<Value = TestView> {
+        let date = prop::option::of(prop_oneof!["[0-9-]{0,12}", Just("2024-13-45".to_string()), Just("-1--1".to_string())]).boxed();
+        let number = prop::option::of(prop_oneof![Just(0.0), Just(-0.0), Just(f64::MAX), Just(f64::MIN), any::<f64>().prop_filter("json has no nan or inf", |value| value.is_finite())]).boxed();
+
+        view(date, number)
+    }
+
+    // small pool of ids so that transitions share some widget ids, which are not necessarily of the same widget type
+    fn unique_ids() -> impl Strategy<Value = Vec<UiWidgetId>> {
+        Just((1..=48).collect::<Vec<UiWidgetId>>()).prop_shuffle()
+    }
+
+    fn duplicated_ids() -> impl Strategy<Value = Vec<UiWidgetId>> {
+        prop::collection::vec(1..6usize, 1..8)
+    }
+
+    fn field_json(field: &TestField, ids: &mut TestIds) -> Value {
+        match field {
+            TestField::TextField(value) => json!({ "__type__": "gauntlet:text_field", "__id__": ids.next(), "label": "Text", "value": value }),
+            TestField::PasswordField(value) => json!({ "__type__": "gauntlet:password_field", "__id__": ids.next(), "value": value }),
+            TestField::Checkbox(value) => json!({ "__type__": "gauntlet:checkbox", "__id__": ids.next(), "title": "Checkbox", "value": value }),
+            TestField::DatePicker(value) => json!({ "__type__": "gauntlet:date_picker", "__id__": ids.next(), "value": value }),
+            TestField::Select(value) => {
+                let id = ids.next();
+                let items: Vec<_> = ["a", "b"].iter()
+                    .map(|value| json!({ "__type__": "gauntlet:select_item", "__id__": ids.next(), "value": value, "content": [{ "__type__": "gauntlet:text_part", "value": value }] }))
+                    .collect();
+
+                json!({ "__type__": "gauntlet:select", "__id__": id, "value": value, "content": items })
+            }
+            TestField::Slider(value, min, max, step) => json!({ "__type__": "gauntlet:slider", "__id__": ids.next(), "value": value, "min": min, "max": max, "step": step }),
+            TestField::Separator => json!({ "__type__": "gauntlet:separator", "__id__": ids.next() }),
+        }
+    }
+
+    fn search_bar_json(search_bar: &Option<Option<String>>, ids: &mut TestIds) -> Option<Value> {
+        search_bar.as_ref()
+            .map(|value| json!({ "__type__": "gauntlet:search_bar", "__id__": ids.next(), "value": value }))
+    }
+
+    fn root_widget(view: &TestView, ids: Vec<UiWidgetId>) -> RootWidget {
+        let mut ids = TestIds { ids, index: 0 };
+
+        let content = match view {
+            TestView::Empty => vec![],
+            TestView::Detail => vec![json!({ "__type__": "gauntlet:detail", "__id__": ids.next(), "content": [] })],
+            TestView::Form(fields) => {
+                let id = ids.next();
+                let fields: Vec<_> = fields.iter()
+                    .map(|field| field_json(field, &mut ids))
+                    .collect();
+
+                vec![json!({ "__type__": "gauntlet:form", "__id__": id, "content": fields })]
+            }
+            TestView::List { multi_select, search_bar, items } => {
+                let id = ids.next();
+                let mut members: Vec<_> = search_bar_json(search_bar, &mut ids).into_iter().collect();
+
+                for (index, (has_id, in_section)) in items.iter().enumerate() {
+                    let item_id = has_id.then(|| format!("item-{}", index));
+                    let item = json!({ "__type__": "gauntlet:list_item", "__id__": ids.next(), "id": item_id, "title": "Item", "content": [] });
+
+                    if *in_section {
+                        members.push(json!({ "__type__": "gauntlet:list_section", "__id__": ids.next(), "title": "Section", "content": [item] }))
+                    } else {
+                        members.push(item)
+                    }
+                }
+
+                vec![json!({ "__type__": "gauntlet:list", "__id__": id, "multiSelect": multi_select, "content": members })]
+            }
+            TestView::Grid { columns, search_bar, items } => {
+                let id = ids.next();
+                let mut members: Vec<_> = search_bar_json(search_bar, &mut ids).into_iter().collect();
+
+                for _ in 0..*items {
+                    let content = json!({ "__type__": "gauntlet:content", "__id__": ids.next(), "content": [] });
+
+                    members.push(json!({ "__type__": "gauntlet:grid_item", "__id__": ids.next(), "title": "Item", "content": [content] }))
+                }
+
+                vec![json!({ "__type__": "gauntlet:grid", "__id__": id, "columns": columns, "content": members })]
+            }
+        };
+
+        serde_json::from_value(json!({ "content": content })).expect("generated widget tree should be valid")
+    }
+
+    fn render(container: &mut PluginWidgetContainer, root_widget: RootWidget) {
+        container.replace_view(
+            Arc::new(root_widget),
+            HashMap::new(),
+            &PluginId::from_string("test"),
+            "Test Plugin",
+            &EntrypointId::from_string("test"),
+            "Test Entrypoint",
+        );
+
+        let _ = container.render_root_widget(&PluginViewState::new(), &HashMap::new());
+    }
+
+    fn current_state(container: &PluginWidgetContainer) -> HashMap<UiWidgetId, ComponentWidgetState> {
+        container.state.lock().expect("lock is poisoned").clone()
+    }
+
+    proptest! {
+        #[test]
+        fn replace_view_reconciles_state(
+            old_view in well_formed_view(),
+            old_ids in unique_ids(),
+            new_view in well_formed_view(),
+            new_ids in unique_ids(),
+        ) {
+            init_default_theme();
+
+            let mut container = PluginWidgetContainer::new();
+
+            render(&mut container, root_widget(&old_view, old_ids));
+            prop_assert_eq!(container.render_panic(), None);
+
+            let old_state = current_state(&container);
+
+            let new_root_widget = root_widget(&new_view, new_ids);
+            let expected_state = create_state(&new_root_widget);
+
+            render(&mut container, new_root_widget);
+            prop_assert_eq!(container.render_panic(), None);
+
+            let new_state = current_state(&container);
+
+            // no stale state for removed ids and no missing state for added ones
+            let mut new_ids: Vec<_> = new_state.keys().collect();
+            let mut expected_ids: Vec<_> = expected_state.keys().collect();
+            new_ids.sort();
+            expected_ids.sort();
+            prop_assert_eq!(new_ids, expected_ids);
+
+            for (widget_id, state) in &new_state {
+                let expected = &expected_state[widget_id];
+
+                prop_assert_eq!(mem::discriminant(state), mem::discriminant(expected), "state of widget {} has wrong type", widget_id);
+
+                // state of widget which kept its id and type is preserved
+                match old_state.get(widget_id) {
+                    Some(old) if mem::discriminant(old) == mem::discriminant(expected) => {
+                        prop_assert_eq!(format!("{:?}", state), format!("{:?}", old), "state of widget {} is not preserved", widget_id);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        #[test]
+        fn replace_view_survives_pathological_trees(
+            views in prop::collection::vec((pathological_view(), duplicated_ids()), 1..4),
+        ) {
+            init_default_theme();
+
+            let mut container = PluginWidgetContainer::new();
+
+            // panics in widget handling are caught and reported in render_panic, they should never escape
+            for (view, ids) in views {
+                render(&mut container, root_widget(&view, ids));
+            }
+        }
     }
 }
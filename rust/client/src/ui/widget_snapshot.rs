@@ -0,0 +1,101 @@
+// golden snapshot tests for widget rendering
+//
+// every fixture in `snapshots` directory is a widget tree in the same json format plugins send to the server,
+// it is rendered through ComponentWidgets and the resulting accessibility tree, which describes
+// what is shown to the user, is compared to the `.snap` file with the same name.
+// golden files are committed, missing one fails the test.
+// to create new ones or update existing ones after intended change run tests with GAUNTLET_UPDATE_SNAPSHOTS=1
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use gauntlet_common::model::{EntrypointId, PluginId, RootWidget};
+
+use crate::ui::state::PluginViewState;
+use crate::ui::theme::init_default_theme;
+use crate::ui::widget_container::PluginWidgetContainer;
+
+fn snapshots_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("snapshots")
+}
+
+fn render_snapshot(fixture: &str) -> String {
+    init_default_theme();
+
+    let path = snapshots_dir().join(format!("{}.json", fixture));
+    let json = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("unable to read fixture {:?}: {}", path, err));
+
+    let root_widget: RootWidget = serde_json::from_str(&json)
+        .unwrap_or_else(|err| panic!("unable to parse fixture {:?}: {}", path, err));
+
+    let mut container = PluginWidgetContainer::new();
+
+    container.replace_view(
+        Arc::new(root_widget),
+        HashMap::new(),
+        &PluginId::from_string("snapshot"),
+        "Snapshot Plugin",
+        &EntrypointId::from_string("snapshot"),
+        "Snapshot Entrypoint",
+    );
+
+    let plugin_view_state = PluginViewState::new();
+    let action_shortcuts = HashMap::new();
+
+    let _ = container.render_root_widget(&plugin_view_state, &action_shortcuts);
+
+    if let Some(message) = container.render_panic() {
+        panic!("rendering of fixture {:?} panicked: {}", path, message)
+    }
+
+    let (nodes, focused) = container.accessibility_tree(&plugin_view_state, &action_shortcuts);
+
+    format!("focused: {:?}\n\n{:#?}\n", focused, nodes)
+}
+
+fn assert_snapshot(fixture: &str) {
+    let actual = render_snapshot(fixture);
+
+    let path = snapshots_dir().join(format!("{}.snap", fixture));
+
+    let update = std::env::var("GAUNTLET_UPDATE_SNAPSHOTS").is_ok_and(|value| value == "1");
+
+    if update {
+        std::fs::write(&path, actual)
+            .unwrap_or_else(|err| panic!("unable to write snapshot {:?}: {}", path, err));
+
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("unable to read snapshot {:?}: {}, run tests with GAUNTLET_UPDATE_SNAPSHOTS=1 to create it", path, err));
+
+    assert!(
+        expected == actual,
+        "snapshot {:?} doesn't match rendered widgets, rerun with GAUNTLET_UPDATE_SNAPSHOTS=1 if the change is intended\n\n--- expected\n{}\n--- actual\n{}",
+        path,
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn list() {
+    assert_snapshot("list");
+}
+
+#[test]
+fn grid() {
+    assert_snapshot("grid");
+}
+
+#[test]
+fn detail() {
+    assert_snapshot("detail");
+}
+
+#[test]
+fn form() {
+    assert_snapshot("form");
+}
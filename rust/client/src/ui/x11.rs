@@ -0,0 +1,240 @@
+use anyhow::{anyhow, Context};
+use iced::{window, Point, Size, Task};
+use raw_window_handle::RawWindowHandle;
+use x11rb::connection::Connection;
+use x11rb::protocol::randr::ConnectionExt as _;
+use x11rb::protocol::xproto::{AtomEnum, ClientMessageEvent, ConnectionExt as _, EventMask};
+use x11rb::CURRENT_TIME;
+
+use gauntlet_common::frontend_config::WindowPosition;
+use gauntlet_common::model::ActiveWindowContext;
+
+use crate::ui::{window_vertical_offset, AppMsg};
+
+// XDG_SESSION_TYPE is checked first, because WAYLAND_DISPLAY can be inherited by apps started in X11 session
+pub fn is_wayland_session() -> bool {
+    match std::env::var("XDG_SESSION_TYPE").as_deref() {
+        Ok("wayland") => true,
+        Ok("x11") => false,
+        _ => std::env::var("WAYLAND_DISPLAY")
+            .or_else(|_| std::env::var("WAYLAND_SOCKET"))
+            .is_ok()
+    }
+}
+
+// window is placed on the monitor with mouse pointer, instead of primary one.
+// winit converts logical position to physical one using scale factor of the same monitor,
+// so position is calculated in physical pixels of that monitor and then divided by its scale factor
+pub fn active_monitor_window_position(window_size: Size, window_position: WindowPosition) -> Option<Point> {
+    match active_monitor() {
+        Ok(monitor) => {
+            let scale_factor = monitor.scale_factor;
+            let window_size = Size::new(window_size.width * scale_factor, window_size.height * scale_factor);
+
+            let x = monitor.x as f32 + (monitor.width as f32 - window_size.width) / 2.0;
+            let y = monitor.y as f32 + window_vertical_offset(window_position, monitor.height as f32, window_size.height);
+
+            Some(Point::new(x / scale_factor, y / scale_factor))
+        }
+        Err(err) => {
+            tracing::warn!("unable to detect active monitor: {:?}", err);
+
+            None
+        }
+    }
+}
+
+struct Monitor {
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+    scale_factor: f32,
+}
+
+fn active_monitor() -> anyhow::Result<Monitor> {
+    let (conn, screen_num) = x11rb::connect(None)?;
+
+    let root = conn.setup().roots[screen_num].root;
+
+    let pointer = conn.query_pointer(root)?.reply()?;
+
+    let monitors = conn.randr_get_monitors(root, true)?.reply()?.monitors;
+
+    let contains_pointer = |monitor: &&x11rb::protocol::randr::MonitorInfo| {
+        let x = pointer.root_x as i32 - monitor.x as i32;
+        let y = pointer.root_y as i32 - monitor.y as i32;
+
+        x >= 0 && y >= 0 && x < monitor.width as i32 && y < monitor.height as i32
+    };
+
+    let monitor = monitors.iter()
+        .find(contains_pointer)
+        .or_else(|| monitors.iter().find(|monitor| monitor.primary))
+        .ok_or(anyhow!("no monitors found"))?;
+
+    let scale_factor = scale_factor_override()
+        .or_else(|| xft_dpi(&conn, root).map(|dpi| dpi / 96.0))
+        .unwrap_or_else(|| monitor_scale_factor(monitor));
+
+    tracing::debug!("active monitor at {}x{}, scale factor: {}", monitor.x, monitor.y, scale_factor);
+
+    Ok(Monitor {
+        x: monitor.x,
+        y: monitor.y,
+        width: monitor.width,
+        height: monitor.height,
+        scale_factor,
+    })
+}
+
+// scale factor is determined the same way winit does it, otherwise window would end up in a different place.
+// variable takes precedence, then global Xft.dpi setting, then physical size of every monitor separately
+fn scale_factor_override() -> Option<f32> {
+    std::env::var("WINIT_X11_SCALE_FACTOR")
+        .ok()?
+        .parse::<f32>()
+        .ok()
+        .filter(|scale_factor| scale_factor.is_finite() && *scale_factor > 0.0)
+}
+
+fn xft_dpi(conn: &impl Connection, root: u32) -> Option<f32> {
+    let resources = conn.get_property(false, root, AtomEnum::RESOURCE_MANAGER, AtomEnum::STRING, 0, u32::MAX)
+        .ok()?
+        .reply()
+        .ok()?
+        .value;
+
+    String::from_utf8_lossy(&resources)
+        .lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+
+            if name.trim() != "Xft.dpi" {
+                return None
+            }
+
+            value.trim().parse::<f32>().ok()
+        })
+        .filter(|dpi| dpi.is_finite() && *dpi > 0.0)
+}
+
+// rounded to 1/12 and never less than 1, monitors which don't report their physical size get 1
+fn monitor_scale_factor(monitor: &x11rb::protocol::randr::MonitorInfo) -> f32 {
+    let width_mm = monitor.width_in_millimeters as f32;
+    let height_mm = monitor.height_in_millimeters as f32;
+
+    if width_mm == 0.0 || height_mm == 0.0 {
+        return 1.0
+    }
+
+    let width = monitor.width as f32;
+    let height = monitor.height as f32;
+
+    let pixels_per_mm = (width * width + height * height).sqrt() / (width_mm * width_mm + height_mm * height_mm).sqrt();
+
+    ((pixels_per_mm * (12.0 * 25.4 / 96.0)).round() / 12.0).max(1.0)
+}
+
+// window managers usually don't give focus to windows that are mapped while another application is focused,
+// which is exactly what happens when window is opened using global shortcut.
+// asking window manager to activate window the same way pagers and taskbars do it is respected by them
+pub fn request_focus(window_id: window::Id) -> Task<AppMsg> {
+    window::run_with_handle(window_id, |handle| {
+        let x11_window = match handle.as_raw() {
+            RawWindowHandle::Xlib(handle) => handle.window as u32,
+            RawWindowHandle::Xcb(handle) => handle.window.get(),
+            _ => return,
+        };
+
+        if let Err(err) = activate_window(x11_window) {
+            tracing::warn!("unable to request focus for window: {:?}", err);
+        }
+    }).map(|_| AppMsg::Noop)
+}
+
+fn activate_window(x11_window: u32) -> anyhow::Result<()> {
+    let (conn, screen_num) = x11rb::connect(None)?;
+
+    let root = conn.setup().roots[screen_num].root;
+
+    let net_active_window = conn.intern_atom(false, b"_NET_ACTIVE_WINDOW")?
+        .reply()
+        .context("unable to get _NET_ACTIVE_WINDOW atom")?
+        .atom;
+
+    // source indication 2 means request comes from pager, it is not subject to focus stealing prevention
+    let event = ClientMessageEvent::new(32, x11_window, net_active_window, [2, CURRENT_TIME, 0, 0, 0]);
+
+    conn.send_event(false, root, EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY, event)?;
+
+    conn.flush()?;
+
+    Ok(())
+}
+
+// application is identified by class part of WM_CLASS, the same value desktop files use in StartupWMClass
+pub fn active_window() -> anyhow::Result<Option<ActiveWindowContext>> {
+    let (conn, screen_num) = x11rb::connect(None)?;
+
+    let root = conn.setup().roots[screen_num].root;
+
+    let intern_atom = |name: &[u8]| -> anyhow::Result<u32> {
+        let atom = conn.intern_atom(false, name)?
+            .reply()
+            .context(format!("unable to get {} atom", String::from_utf8_lossy(name)))?
+            .atom;
+
+        Ok(atom)
+    };
+
+    let net_active_window = intern_atom(b"_NET_ACTIVE_WINDOW")?;
+    let net_wm_name = intern_atom(b"_NET_WM_NAME")?;
+    let utf8_string = intern_atom(b"UTF8_STRING")?;
+
+    let active_window = conn.get_property(false, root, net_active_window, AtomEnum::WINDOW, 0, 1)?
+        .reply()?
+        .value32()
+        .and_then(|mut value| value.next())
+        .filter(|window| *window != 0);
+
+    let Some(active_window) = active_window else {
+        return Ok(None)
+    };
+
+    let wm_class = conn.get_property(false, active_window, AtomEnum::WM_CLASS, AtomEnum::STRING, 0, 1024)?
+        .reply()?
+        .value;
+
+    // instance and class separated by null
+    let mut wm_class = wm_class
+        .split(|byte| *byte == 0)
+        .map(|part| String::from_utf8_lossy(part).to_string());
+
+    let instance = wm_class.next().filter(|value| !value.is_empty());
+    let class = wm_class.next().filter(|value| !value.is_empty());
+
+    let Some(app_id) = class.or(instance) else {
+        return Ok(None)
+    };
+
+    let title = conn.get_property(false, active_window, net_wm_name, utf8_string, 0, 1024)?
+        .reply()?
+        .value;
+
+    let title = if title.is_empty() {
+        conn.get_property(false, active_window, AtomEnum::WM_NAME, AtomEnum::STRING, 0, 1024)?
+            .reply()?
+            .value
+    } else {
+        title
+    };
+
+    let title = String::from_utf8_lossy(&title).to_string();
+
+    Ok(Some(ActiveWindowContext {
+        app_name: Some(app_id.clone()),
+        app_id,
+        title: Some(title).filter(|title| !title.is_empty()),
+    }))
+}
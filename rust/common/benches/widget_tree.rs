@@ -0,0 +1,91 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use gauntlet_common::model::RootWidget;
+use serde_json::{json, Value};
+
+// list view as plugins render it, with sections, accessories and action panel
+fn list_json(items: usize) -> Value {
+    let mut next_id = 0;
+    let mut id = || {
+        next_id += 1;
+        next_id
+    };
+
+    let actions = json!({
+        "__type__": "gauntlet:action_panel",
+        "__id__": id(),
+        "content": [
+            { "__type__": "gauntlet:action", "__id__": id(), "id": "open", "label": "Open" },
+            { "__type__": "gauntlet:action", "__id__": id(), "id": "copy", "label": "Copy" }
+        ]
+    });
+
+    let search_bar = json!({ "__type__": "gauntlet:search_bar", "__id__": id(), "placeholder": "Search..." });
+
+    let mut members = vec![actions, search_bar];
+
+    for section in 0..items.div_ceil(100) {
+        let section_items: Vec<_> = (section * 100..items.min((section + 1) * 100))
+            .map(|index| json!({
+                "__type__": "gauntlet:list_item",
+                "__id__": id(),
+                "id": format!("item-{}", index),
+                "title": format!("Item {}", index),
+                "subtitle": "Subtitle of the item",
+                "content": [
+                    { "__type__": "gauntlet:accessory_text", "__id__": id(), "text": "Accessory", "tooltip": "Tooltip" }
+                ]
+            }))
+            .collect();
+
+        members.push(json!({
+            "__type__": "gauntlet:list_section",
+            "__id__": id(),
+            "title": format!("Section {}", section),
+            "content": section_items
+        }));
+    }
+
+    json!({
+        "content": [
+            { "__type__": "gauntlet:list", "__id__": id(), "content": members }
+        ]
+    })
+}
+
+fn widget_tree(c: &mut Criterion) {
+    let mut group = c.benchmark_group("widget_tree");
+
+    for items in [100, 1_000, 10_000] {
+        let json = list_json(items).to_string();
+        let root_widget: RootWidget = serde_json::from_str(&json).expect("invalid widget tree");
+        let encoded = bincode::encode_to_vec(&root_widget, bincode::config::standard()).expect("unable to encode widget tree");
+
+        group.throughput(Throughput::Elements(items as u64));
+
+        group.bench_with_input(BenchmarkId::new("json_deserialize", items), &json, |b, json| {
+            b.iter(|| serde_json::from_str::<RootWidget>(black_box(json)).expect("invalid widget tree"))
+        });
+
+        group.bench_with_input(BenchmarkId::new("json_serialize", items), &root_widget, |b, root_widget| {
+            b.iter(|| serde_json::to_string(black_box(root_widget)).expect("unable to serialize widget tree"))
+        });
+
+        group.bench_with_input(BenchmarkId::new("bincode_decode", items), &encoded, |b, encoded| {
+            b.iter(|| {
+                let (root_widget, _): (RootWidget, _) = bincode::decode_from_slice(black_box(encoded), bincode::config::standard())
+                    .expect("unable to decode widget tree");
+
+                root_widget
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("bincode_encode", items), &root_widget, |b, root_widget| {
+            b.iter(|| bincode::encode_to_vec(black_box(root_widget), bincode::config::standard()).expect("unable to encode widget tree"))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, widget_tree);
+criterion_main!(benches);
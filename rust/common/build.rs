@@ -44,6 +44,9 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
 
                 let has_text = matches!(children, Children::StringOrMembers { .. } | Children::String { .. });
 
+                // text mixed with members is stored together with them, so that the order is preserved
+                let text_in_ordered_members = matches!(children, Children::StringOrMembers { .. });
+
                 let has_content = children_has_content || props_has_content || has_text;
 
                 let default = IndexMap::new();
@@ -69,6 +72,10 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                         output.push_str(&format!("    {}({}Widget),\n", component_ref.component_name, component_ref.component_name));
                     }
 
+                    if text_in_ordered_members {
+                        output.push_str("    Text(String),\n");
+                    }
+
                     output.push_str("}\n");
                 }
 
@@ -111,7 +118,7 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                             output.push_str(&format!("    pub ordered_members: Vec<{}WidgetOrderedMembers>,\n", name));
                         }
 
-                        if has_text {
+                        if has_text && !text_in_ordered_members {
                             output.push_str("    pub text: Vec<String>,\n");
                         }
 
@@ -236,7 +243,7 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                                 output.push_str("        let mut ordered_members = vec![];\n");
                             }
 
-                            if has_text {
+                            if has_text && !text_in_ordered_members {
                                 output.push_str("        let mut text = vec![];\n");
                             }
 
@@ -280,7 +287,11 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
 
                                 if has_text {
                                     output.push_str(&format!("                {}WidgetMembersOwned::Text {{ value }} => {{\n", name));
-                                    output.push_str(&format!("                    text.insert(0, value);\n"));
+                                    if text_in_ordered_members {
+                                        output.push_str(&format!("                    ordered_members.insert(0, {}WidgetOrderedMembers::Text(value));\n", name));
+                                    } else {
+                                        output.push_str(&format!("                    text.insert(0, value);\n"));
+                                    }
                                     output.push_str(&format!("                }}\n"));
                                 }
 
@@ -324,11 +335,11 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                             }
 
                             if !ordered_members.is_empty() {
-                                output.push_str("            ordered_members\n");
+                                output.push_str("            ordered_members,\n");
                             }
 
-                            if has_text {
-                                output.push_str("            text\n");
+                            if has_text && !text_in_ordered_members {
+                                output.push_str("            text,\n");
                             }
 
                             output.push_str(&format!("        }})\n"));
@@ -431,11 +442,17 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                                     output.push_str(&format!("                }}\n"));
                                 }
 
+                                if text_in_ordered_members {
+                                    output.push_str(&format!("                {}WidgetOrderedMembers::Text(value) => {{\n", name));
+                                    output.push_str(&format!("                    members.push({}WidgetMembersRef::Text {{ value }})\n", name));
+                                    output.push_str(&format!("                }}\n"));
+                                }
+
                                 output.push_str(&format!("            }}\n"));
                                 output.push_str(&format!("        }}\n"));
                             }
 
-                            if has_text {
+                            if has_text && !text_in_ordered_members {
                                 output.push_str(&format!("        for value in &self.text {{\n"));
                                 output.push_str(&format!("            members.push({}WidgetMembersRef::Text {{ value }});\n", name));
                                 output.push_str(&format!("        }}\n"));
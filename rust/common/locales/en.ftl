@@ -0,0 +1,342 @@
+## shared
+
+close = Close
+retry = Retry
+loading = Loading...
+search-placeholder = Search...
+error-occurred = Error occurred
+unknown-error-occurred = Unknown error occurred
+please-report = Please report
+backend-timeout = Backend was unable to process message in a timely manner
+error-plugin = Plugin: { $plugin }
+error-hint-managed = Settings are managed by administrator, ask them to make this change
+error-hint-invalid-plugin = Make sure the plugin is built and its manifest is valid, see plugin logs for details
+error-hint-invalid-config = Fix the config file and try again
+config-error-default-used = Default configuration is used until the error is fixed
+config-error-location-line = { $file }, line { $line }
+config-error-location-line-column = { $file }, line { $line }, column { $column }
+
+## main window
+
+main-open-settings = Open Settings
+main-preferences-required-plugin = Before using, plugin preferences need to be specified
+main-preferences-required-entrypoint = Before using, entrypoint preferences need to be specified
+main-preferences-required-plugin-and-entrypoint = Before using, plugin and entrypoint preferences need to be specified
+main-plugin-view-error = Error occurred in plugin when trying to show the view
+main-plugin-view-error-report = Please report this to plugin author
+main-plugin-view-error-panic = Gauntlet failed to render the view: { $message }
+main-plugin-render-error = Plugin rendered invalid view
+main-plugin-render-error-description = Rendered components don't match what Gauntlet supports, see details below
+main-plugin-render-error-view = View
+main-safe-mode-title = Gauntlet was started in safe mode
+main-safe-mode-description = Third-party plugins are not running until Gauntlet is restarted
+main-startup-failed-title = Gauntlet failed to start
+main-crash-report = Crash report: { $path }
+main-config-error-title = Unable to read configuration file
+main-database-recovered-title = Database was recovered
+main-database-restored = Database could not be opened and was restored from backup
+main-database-reset = Database could not be opened and no working backup was found, plugins and settings were reset
+main-database-moved = Damaged database was moved to: { $path }
+main-database-restored-backup = Restored backup: { $path }
+main-whats-new-title = What's new in Gauntlet v{ $version }
+main-argument-optional = { $name } (optional)
+main-fallback-title = No results for "{ $query }", use it with
+main-run-command = Run Command
+main-open-view = Open View
+main-indexing = Indexing...
+main-search-pending = Press Enter to search
+main-actions = Actions
+main-copy-name = Copy Name
+main-copy-path = Copy Path
+main-copy-url = Copy URL
+main-copy-id = Copy Entrypoint Id
+main-add-alias = Add Alias…
+main-remove-aliases = Remove Aliases
+main-alias-placeholder = Alias, e.g. "ff" for Firefox
+main-copied-to-clipboard = Copied to Clipboard
+main-bug-report-saved = Bug Report Saved
+main-undo = Undo
+main-window-pin = Keep Open
+main-results = Results
+main-recent = Recent
+main-favorites = Favorites
+main-category-all = All
+main-category-application = Applications
+main-category-command = Commands
+main-category-quicklink = Quicklinks
+main-category-file = Files
+main-prompt-history = History
+main-preview-type = Type
+main-preview-type-command = Command
+main-preview-type-view = View
+main-preview-type-generated-command = Generated Command
+main-preview-plugin = Plugin
+main-list-selected = { $count } selected
+main-list-loading-more = Loading more...
+
+## tray
+
+tray-open = Open
+tray-open-settings = Open Settings
+tray-about = About...
+tray-quit = Quit Gauntlet
+
+## settings window
+
+settings-unable-to-connect = Unable to connect to server. Please check if you have Gauntlet running on your PC
+settings-tab-general = General
+settings-tab-plugins = Plugins
+settings-tab-diagnostics = Diagnostics
+settings-tab-import = Import
+settings-tab-statistics = Statistics
+settings-tab-macros = Macros
+settings-tab-permissions = Permissions
+settings-tab-theme = Theme
+settings-download-in-progress = Download in progress
+settings-download-failed = Download failed
+settings-download-successful = Download successful
+settings-managed = Settings are managed by your administrator and cannot be changed
+
+## settings window, general tab
+
+settings-global-shortcut = Global Shortcut
+settings-shortcut-hint-unset = Backspace - Unset Shortcut
+settings-shortcut-hint-stop = Escape - Stop Capturing
+settings-config-error = Unable to read { $file }
+settings-config-error-line = Unable to read { $file }, line { $line }
+settings-config-error-line-column = Unable to read { $file }, line { $line }, column { $column }
+settings-export-import = Export / Import
+settings-archive-path = Archive path
+settings-export = Export
+settings-import = Import
+settings-exported = Settings exported to { $path }
+settings-imported = Settings imported, restart Gauntlet to apply them
+settings-inline-views = Inline Views
+settings-favorites = Favorites
+settings-fallbacks = Fallback Commands
+settings-general-save-error = Unable to save settings
+settings-theme = Theme
+settings-theme-auto = Auto
+settings-theme-dark = Dark
+settings-theme-light = Light
+settings-window-position = Window Position
+settings-window-position-center = Center
+settings-window-position-top = Top
+settings-window-hide-on-focus-loss = Focus Loss
+settings-window-hide-on-focus-loss-enabled = Hide window when it loses focus
+settings-window-hide-on-focus-loss-hint = Window stays open while it is pinned or while view of interactive plugin is open
+settings-max-search-results = Search Results
+settings-max-search-results-limit = Limit number of results
+settings-startup = Startup
+settings-startup-open-window = Open window on start
+settings-startup-launch-at-login = Launch at login
+settings-telemetry = Telemetry
+settings-telemetry-opt-in = Allow anonymous usage data
+settings-telemetry-hint = Gauntlet doesn't collect any data currently, this only records your choice
+settings-restart-hint = Applied after restart
+settings-check-for-updates = Updates
+settings-check-for-updates-enabled = Check for new versions
+settings-check-for-updates-hint = Latest release is looked up on GitHub when Settings are opened
+settings-usage-statistics = Usage Statistics
+settings-usage-statistics-enabled = Record launches and search times
+settings-usage-statistics-hint = Stored only on this computer and shown in Statistics tab
+settings-sound = Sound
+settings-sound-muted = Mute sounds
+settings-sound-hint = Sounds played by plugins, e.g. when timer finishes or action fails
+settings-reduced-motion = Reduced motion
+settings-reduced-motion-enabled = Reduce motion
+settings-reduced-motion-hint = Scrolling and switching between views is not animated and loading indicators don't move
+settings-high-contrast = High contrast
+settings-high-contrast-enabled = Use high contrast
+settings-high-contrast-hint = Stronger borders and outline around focused items, applied on top of selected theme
+settings-update-available = Gauntlet v{ $version } is available
+settings-profile = Profile
+settings-profile-default = Default
+settings-profile-new-name = New profile name
+settings-profile-create = Create
+settings-profile-hint = Each profile has its own plugins, preferences, theme and history
+settings-profile-restart-hint = Applied after restart, currently running with "{ $profile }" profile
+
+## settings window, plugins tab
+
+settings-plugins-select-item = Select item from the list on the left
+settings-plugins-or = or
+settings-plugins-add-hint = Click '+' to add new plugin
+settings-plugins-description = Description
+settings-plugins-show-logs = Show logs
+settings-plugins-check-for-updates = Check for updates
+settings-plugins-remove = Remove plugin
+settings-plugins-remove-confirm = Remove plugin? Its preferences, local storage and data can be kept and are restored if the plugin is installed again
+settings-plugins-remove-purge-data = Remove and delete data
+settings-plugins-remove-keep-data = Remove, keep data
+settings-plugins-remove-cancel = Cancel
+settings-plugins-repository-url = Enter Git Repository URL
+settings-plugins-supported-protocols = Supported protocols:
+settings-plugins-download = Download plugin
+settings-plugins-column-name = Name
+settings-plugins-column-type = Type
+settings-plugins-column-enabled = Enabled
+settings-plugins-column-in-search = In Search
+settings-plugins-type-command = Command
+settings-plugins-type-view = View
+settings-plugins-type-inline-view = Inline View
+settings-plugins-type-command-generator = Command Generator
+settings-preferences-enter-value = Enter value...
+settings-preferences-select-value = Select value...
+settings-plugins-shortcuts = Shortcuts
+settings-plugins-entrypoints = Entrypoints
+settings-plugins-entrypoint-enabled = Enabled
+settings-plugins-entrypoint-visible = Show in search
+settings-plugins-entrypoint-favorite = Pin to favorites
+settings-plugins-aliases = Aliases
+settings-plugins-aliases-enter-value = Enter alias...
+settings-shortcut-reset = Reset
+settings-shortcut-hint-reset = Backspace - Reset to Default
+settings-shortcut-conflict-missing-modifier = Shortcut needs to include Control, Alt or Meta key
+settings-shortcut-conflict-global = Shortcut is already used as global shortcut
+settings-shortcut-conflict-builtin = Shortcut is already used by Gauntlet
+settings-shortcut-conflict-action = Shortcut is already used by "{ $action }"
+
+## settings window, plugin logs
+
+settings-logs-title = Logs: { $plugin }
+settings-logs-follow = Follow
+settings-logs-copy-diagnostics = Copy diagnostics
+settings-logs-empty = No logs
+settings-logs-filter-all = All
+settings-logs-filter-output = Output
+settings-logs-filter-errors = Errors
+
+## settings window, diagnostics tab
+
+settings-diagnostics-record = Record diagnostics
+settings-diagnostics-refresh = Refresh
+settings-diagnostics-empty = Nothing recorded yet, use Gauntlet to collect timings
+settings-diagnostics-disabled = Enable recording to collect render times, search latency, operation calls and IPC round-trip times
+settings-diagnostics-render = Render Times
+settings-diagnostics-search = Search Latency
+settings-diagnostics-op = Operation Calls
+settings-diagnostics-ipc = IPC Round-trip Times
+settings-diagnostics-column-plugin = Plugin
+settings-diagnostics-column-name = Name
+settings-diagnostics-column-count = Count
+settings-diagnostics-column-average = Average
+settings-diagnostics-column-max = Max
+settings-diagnostics-column-total = Total
+settings-diagnostics-image-cache = Image Cache
+settings-diagnostics-image-cache-size = Size
+settings-diagnostics-image-cache-entries = Images
+settings-diagnostics-image-cache-hits = Hits
+settings-diagnostics-image-cache-misses = Misses
+settings-diagnostics-image-cache-evictions = Evictions
+
+## settings window, import tab
+
+settings-launcher-import-description = Quicklinks, snippets and web searches from other launchers are converted into script commands
+settings-launcher-import-raycast-quicklinks = Raycast Quicklinks
+settings-launcher-import-raycast-snippets = Raycast Snippets
+settings-launcher-import-alfred-web-searches = Alfred Web Searches
+settings-launcher-import-albert-web-searches = Albert Web Searches
+settings-launcher-import-albert-snippets = Albert Snippets
+settings-launcher-import-path = Path to exported file or directory
+settings-launcher-import-preview = Preview
+settings-launcher-import-import = Import
+settings-launcher-import-empty = Nothing found to import
+settings-launcher-import-summary = { $count } of { $total } items will be imported
+settings-launcher-import-kind-quicklink = Quicklink
+settings-launcher-import-kind-snippet = Snippet
+settings-launcher-import-kind-web-search = Web Search
+settings-launcher-import-status-ready = Will be imported
+settings-launcher-import-status-skipped = Skipped: { $reason }
+settings-launcher-import-finished = { $count } items imported as script commands
+
+## settings window, statistics tab
+
+settings-statistics-loading = Loading statistics
+settings-statistics-disabled = Collection of usage statistics is disabled in General tab, already recorded data is shown
+settings-statistics-empty = Nothing recorded yet, open commands and views to collect statistics
+settings-statistics-export-path = Path to CSV file
+settings-statistics-export = Export CSV
+settings-statistics-export-finished = Exported to { $path }
+settings-statistics-refresh = Refresh
+settings-statistics-launches-last-days = Launches in last 30 days
+settings-statistics-searches = Searches
+settings-statistics-average-search-latency = Average search time
+settings-statistics-daily = Daily Launches
+settings-statistics-daily-max = busiest day: { $count }
+settings-statistics-plugins = Most Used Plugins
+settings-statistics-entrypoints = Launches per Entrypoint
+
+## settings window, macros tab
+
+settings-macros-description = Macros run existing commands one after another from a single search result, e.g. open application and then paste snippet
+settings-macros-new = New Macro
+settings-macros-empty = No macros yet
+settings-macros-edit = Edit
+settings-macros-remove = Remove
+settings-macros-steps-count = { $count } steps
+settings-macros-name = Macro name
+settings-macros-steps = Steps
+settings-macros-no-steps = Add commands and delays below
+settings-macros-step-delay = Wait { $millis } ms
+settings-macros-select-command = Select command
+settings-macros-add-command = Add Command
+settings-macros-delay = Delay in milliseconds
+settings-macros-add-delay = Add Delay
+settings-macros-save = Save
+settings-macros-cancel = Cancel
+settings-macros-saved = Macro saved, it can be found in search by its name
+settings-macros-removed = Macro removed
+settings-macros-invalid-delay = Delay has to be a whole number of milliseconds
+
+## settings window, permissions tab
+
+settings-permissions-description = Permissions plugins asked for in their manifest. Revoking a permission restarts the plugin if it is running, revoked permissions stay revoked after plugin updates
+settings-permissions-loading = Loading permissions...
+settings-permissions-empty = Installed plugins don't use any permissions
+settings-permissions-granted = Granted
+settings-permissions-granted-at-unknown = Unknown date
+settings-permissions-kind-network = Network
+settings-permissions-kind-filesystem-read = Read files
+settings-permissions-kind-filesystem-write = Write files
+settings-permissions-kind-exec-command = Run command
+settings-permissions-kind-exec-executable = Run executable
+settings-permissions-kind-clipboard = Clipboard
+
+## settings window, theme tab
+
+settings-theme-description = Changes are shown in the launcher window right away, save them to keep them. Theme is only used when "theme" in config is set to "auto" and there is no complex theme file
+settings-theme-loading = Loading theme...
+settings-theme-token-background-darkest = Window background
+settings-theme-token-background-darker = Panel background
+settings-theme-token-background-lighter = Selected item background
+settings-theme-token-background-lightest = Focused action background
+settings-theme-token-text-lightest = Text
+settings-theme-token-text-lighter = Secondary text
+settings-theme-token-text-darker = Placeholder text
+settings-theme-token-text-darkest = Text on accent
+settings-theme-token-primary-darker = Accent
+settings-theme-token-primary-lighter = Hovered accent
+settings-theme-token-root-border = Window border
+settings-theme-red = Red
+settings-theme-green = Green
+settings-theme-blue = Blue
+settings-theme-alpha = Opacity
+settings-theme-hex = Hex color, e.g. #2C323A
+settings-theme-root-border-radius = Window corner radius
+settings-theme-root-border-width = Window border width
+settings-theme-content-border-radius = Item corner radius
+settings-theme-preview = Preview
+settings-theme-preview-search = Search...
+settings-theme-preview-selected-item = Selected item
+settings-theme-preview-hovered-item = Hovered item
+settings-theme-preview-item = Item
+settings-theme-preview-subtitle = Subtitle
+settings-theme-preview-tag = Tag
+settings-theme-preview-actions = Actions
+settings-theme-preview-primary-action = Open
+settings-theme-preview-secondary-action = Copy to Clipboard
+settings-theme-name = Theme name
+settings-theme-save = Save as New Theme
+settings-theme-reset = Reset
+settings-theme-saved = Theme saved to { $path }
@@ -3,18 +3,72 @@ use anyhow::Context;
 
 use directories::{BaseDirs, ProjectDirs};
 
+// set by --data-dir flag, inherited by settings and plugin runtime processes
+pub const GAUNTLET_HOME_ENV: &'static str = "GAUNTLET_HOME";
+
+// set by --profile flag or pinned on start, so that switching profile in settings applies only after restart
+pub const GAUNTLET_PROFILE_ENV: &'static str = "GAUNTLET_PROFILE";
+
+pub const DEFAULT_PROFILE: &'static str = "default";
+
 #[derive(Clone)]
 pub struct Dirs {
-    inner: ProjectDirs
+    inner: ProjectDirs,
+    // portable mode, all directories are placed inside this one instead of system locations
+    gauntlet_home: Option<PathBuf>,
+    // none is default profile which uses directories directly, others use "profiles/<name>" subdirectories
+    profile: Option<String>,
 }
 
 impl Dirs {
     pub fn new() -> Self {
-        Self {
-            inner: ProjectDirs::from("dev", "project-gauntlet", "Gauntlet").unwrap()
+        let gauntlet_home = std::env::var_os(GAUNTLET_HOME_ENV)
+            .filter(|value| !value.is_empty())
+            .map(|value| {
+                let path = PathBuf::from(value);
+
+                std::path::absolute(&path).unwrap_or(path)
+            });
+
+        let mut dirs = Self {
+            inner: ProjectDirs::from("dev", "project-gauntlet", "Gauntlet").unwrap(),
+            gauntlet_home,
+            profile: None,
+        };
+
+        dirs.profile = std::env::var(GAUNTLET_PROFILE_ENV)
+            .ok()
+            .or_else(|| std::fs::read_to_string(dirs.active_profile_file()).ok())
+            .map(|profile| profile.trim().to_string())
+            .filter(|profile| profile != DEFAULT_PROFILE && is_valid_profile_name(profile));
+
+        dirs
+    }
+
+    pub fn profile(&self) -> &str {
+        self.profile.as_deref().unwrap_or(DEFAULT_PROFILE)
+    }
+
+    // name of profile used on next start, shared by all profiles
+    pub fn active_profile_file(&self) -> PathBuf {
+        self.base_data_dir().join("active_profile")
+    }
+
+    pub fn profiles_dir(&self) -> PathBuf {
+        self.base_data_dir().join("profiles")
+    }
+
+    fn profile_dir(&self, base_dir: PathBuf) -> PathBuf {
+        match &self.profile {
+            Some(profile) => base_dir.join("profiles").join(profile),
+            None => base_dir,
         }
     }
 
+    pub fn gauntlet_home(&self) -> Option<&Path> {
+        self.gauntlet_home.as_deref()
+    }
+
     pub fn home_dir(&self) -> PathBuf {
         let path = BaseDirs::new()
             .expect("System didn't report any home directory")
@@ -30,20 +84,53 @@ impl Dirs {
         Ok(path)
     }
 
+    pub fn data_db_backups_dir(&self) -> anyhow::Result<PathBuf> {
+        let path = self.data_dir()?.join("backups");
+
+        Ok(path)
+    }
+
+    // database from imported settings archive, replaces data.db on next start
+    pub fn data_db_import_file(&self) -> anyhow::Result<PathBuf> {
+        let path = self.data_dir()?.join("data.import.db");
+
+        Ok(path)
+    }
+
+    // config files from imported settings archive, replace current ones together with database on next start
+    pub fn config_import_dir(&self) -> anyhow::Result<PathBuf> {
+        let path = self.data_dir()?.join("config.import");
+
+        Ok(path)
+    }
+
     pub fn plugin_data(&self, plugin_uuid: &str) -> anyhow::Result<PathBuf> {
         let plugin_data_dir = self.data_dir()?.join("plugins").join(&plugin_uuid);
 
         Ok(plugin_data_dir)
     }
 
+    // certificate and key identifying this machine to the other side of remote plugin host connection
+    pub fn remote_plugin_host_identity(&self) -> anyhow::Result<(PathBuf, PathBuf)> {
+        let dir = self.data_dir()?.join("remote_plugin_host");
+
+        Ok((dir.join("certificate.der"), dir.join("key.der")))
+    }
+
     pub fn data_dir(&self) -> anyhow::Result<PathBuf> {
-        let data_dir = if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
+        Ok(self.profile_dir(self.base_data_dir()))
+    }
+
+    fn base_data_dir(&self) -> PathBuf {
+        let data_dir = if let Some(gauntlet_home) = &self.gauntlet_home {
+            gauntlet_home.join("data")
+        } else if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
             self.inner.data_dir().to_path_buf()
         } else {
             Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../../dev_data/data")).to_owned()
         };
 
-        Ok(data_dir)
+        data_dir
     }
 
     pub fn config_file(&self) -> PathBuf {
@@ -66,8 +153,23 @@ impl Dirs {
         self.config_dir().join("simple-theme.sample.json")
     }
 
+    // named simple themes saved by theme editor
+    pub fn themes_dir(&self) -> PathBuf {
+        self.config_dir().join("themes")
+    }
+
+    pub fn script_commands_dir(&self) -> PathBuf {
+        self.config_dir().join("scripts")
+    }
+
     pub fn config_dir(&self) -> PathBuf {
-        let config_dir = if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
+        self.profile_dir(self.base_config_dir())
+    }
+
+    fn base_config_dir(&self) -> PathBuf {
+        let config_dir = if let Some(gauntlet_home) = &self.gauntlet_home {
+            gauntlet_home.join("config")
+        } else if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
             self.inner.config_dir().to_path_buf()
         } else {
             Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../../dev_data/config")).to_owned()
@@ -80,14 +182,40 @@ impl Dirs {
         self.cache_dir().join("icons")
     }
 
+    // rasterized icons from system icon themes, unlike plugin icons they are kept between restarts
+    pub fn system_icon_cache_dir(&self) -> PathBuf {
+        self.icon_cache_dir().join("system")
+    }
+
     pub fn plugin_cache(&self, plugin_uuid: &str) -> anyhow::Result<PathBuf> {
         let plugin_cache_dir = self.cache_dir().join("plugins").join(&plugin_uuid);
 
         Ok(plugin_cache_dir)
     }
 
+    // files created for urls dragged out of the window
+    pub fn drag_cache_dir(&self) -> PathBuf {
+        self.cache_dir().join("drag")
+    }
+
+    // thumbnails of files and links shown in plugin views
+    pub fn thumbnail_cache_dir(&self) -> PathBuf {
+        self.cache_dir().join("thumbnails")
+    }
+
+    // last exchange rates fetched by calculator, used when machine is offline
+    pub fn exchange_rates_cache_file(&self) -> PathBuf {
+        self.cache_dir().join("exchange-rates.xml")
+    }
+
     pub fn cache_dir(&self) -> PathBuf {
-        let cache_dir = if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
+        self.profile_dir(self.base_cache_dir())
+    }
+
+    fn base_cache_dir(&self) -> PathBuf {
+        let cache_dir = if let Some(gauntlet_home) = &self.gauntlet_home {
+            gauntlet_home.join("cache")
+        } else if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
             self.inner.cache_dir().to_path_buf()
         } else {
             Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../../dev_data/cache")).to_owned()
@@ -105,12 +233,36 @@ impl Dirs {
         (out_log_file, err_log_file)
     }
 
+    pub fn crash_reports_dir(&self) -> PathBuf {
+        self.state_dir().join("crash_reports")
+    }
+
+    // bundles created from plugin view to be attached to bug reports for plugin authors
+    pub fn bug_reports_dir(&self) -> PathBuf {
+        self.state_dir().join("bug_reports")
+    }
+
+    pub fn startup_marker_file(&self) -> PathBuf {
+        self.state_dir().join("startup_in_progress")
+    }
+
+    // previous search queries, one per line, oldest first
+    pub fn prompt_history_file(&self) -> PathBuf {
+        self.state_dir().join("prompt_history.txt")
+    }
+
     pub fn plugin_local_storage(&self, plugin_uuid: &str) -> PathBuf {
         self.state_dir().join("local_storage").join(&plugin_uuid)
     }
 
     pub fn state_dir(&self) -> PathBuf {
-        let state_dir = if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
+        self.profile_dir(self.base_state_dir())
+    }
+
+    fn base_state_dir(&self) -> PathBuf {
+        let state_dir = if let Some(gauntlet_home) = &self.gauntlet_home {
+            gauntlet_home.join("state")
+        } else if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
             let dir = match self.inner.state_dir() {
                 Some(dir) => dir,
                 None => self.inner.data_local_dir(),
@@ -125,15 +277,35 @@ impl Dirs {
     }
 
     pub fn plugin_uds_socket(&self, plugin_uuid: &str) -> PathBuf {
-        let state_dir = if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
+        self.runtime_dir().join(format!("project-gauntlet-{}.sock", plugin_uuid))
+    }
+
+    pub fn control_socket(&self) -> PathBuf {
+        self.runtime_dir().join("project-gauntlet-control.sock")
+    }
+
+    pub fn instance_socket(&self) -> PathBuf {
+        self.runtime_dir().join("project-gauntlet-instance.sock")
+    }
+
+    pub fn frontend_socket(&self) -> PathBuf {
+        self.runtime_dir().join("project-gauntlet-frontend.sock")
+    }
+
+    // separate runtime dir allows running portable instance alongside installed one
+    fn runtime_dir(&self) -> PathBuf {
+        if let Some(gauntlet_home) = &self.gauntlet_home {
+            gauntlet_home.join("run")
+        } else if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
             self.inner.runtime_dir()
                 .unwrap_or_else(|| Path::new("/tmp"))
                 .to_path_buf()
         } else {
             Path::new("/tmp").to_owned()
-        };
-
-        state_dir.join(format!("project-gauntlet-{}.sock", plugin_uuid))
+        }
     }
+}
 
-}
\ No newline at end of file
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|char| char.is_ascii_alphanumeric() || char == '-' || char == '_')
+}
@@ -0,0 +1,126 @@
+use std::fmt::{Display, Formatter};
+
+use bincode::{Decode, Encode};
+
+use crate::i18n::tr;
+use crate::model::PluginId;
+
+// kind of error, clients use it to decide which guidance to show to user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub enum ErrorCode {
+    // no more specific kind is known, most likely a bug
+    Internal,
+    // request was rejected because settings are deployed by administrator
+    Managed,
+    // plugin cannot be read, e.g. it is not built or its manifest is invalid
+    InvalidPlugin,
+    // config file cannot be parsed
+    InvalidConfig,
+}
+
+// error shared by server and clients, sent in grpc status details to settings window
+// and in backend response to launcher window
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct GauntletError {
+    pub code: ErrorCode,
+    pub plugin_id: Option<PluginId>,
+    // short message shown to user
+    pub message: String,
+    // messages of underlying errors, outermost first
+    pub causes: Vec<String>,
+    // what user can do to fix the error, if server knows more than code-specific guidance
+    pub hint: Option<String>,
+}
+
+impl GauntletError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            plugin_id: None,
+            message: message.into(),
+            causes: vec![],
+            hint: None,
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Internal, message)
+    }
+
+    pub fn with_plugin_id(mut self, plugin_id: PluginId) -> Self {
+        self.plugin_id = Some(plugin_id);
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn with_cause(mut self, cause: &anyhow::Error) -> Self {
+        self.causes.extend(cause.chain().map(|err| err.to_string()));
+        self
+    }
+
+    // structured error is searched for in the whole chain, so it survives context added on top of it,
+    // anything else becomes internal error with the chain as causes
+    pub fn from_anyhow(error: &anyhow::Error) -> Self {
+        if let Some(structured) = error.chain().find_map(|err| err.downcast_ref::<GauntletError>()) {
+            return structured.clone()
+        }
+
+        let mut chain = error.chain().map(|err| err.to_string());
+
+        let message = chain.next().unwrap_or_default();
+
+        Self {
+            code: ErrorCode::Internal,
+            plugin_id: None,
+            message,
+            causes: chain.collect(),
+            hint: None,
+        }
+    }
+
+    // hint from server if present, otherwise generic guidance for the code
+    pub fn guidance(&self) -> String {
+        if let Some(hint) = &self.hint {
+            return hint.clone()
+        }
+
+        match self.code {
+            ErrorCode::Internal => tr("please-report"),
+            ErrorCode::Managed => tr("error-hint-managed"),
+            ErrorCode::InvalidPlugin => tr("error-hint-invalid-plugin"),
+            ErrorCode::InvalidConfig => tr("error-hint-invalid-config"),
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        bincode::encode_to_vec(self, bincode::config::standard())
+            .expect("unable to encode error")
+    }
+
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        bincode::decode_from_slice(bytes, bincode::config::standard())
+            .ok()
+            .map(|(error, _)| error)
+    }
+}
+
+// alternate form includes causes, same as anyhow
+impl Display for GauntletError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        if f.alternate() {
+            for cause in &self.causes {
+                write!(f, ": {}", cause)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for GauntletError {}
@@ -0,0 +1,240 @@
+use serde::Deserialize;
+
+use crate::dirs::Dirs;
+
+// part of config file that is used by frontends, they read it directly when they start.
+// errors are reported by server when it reads the whole config
+#[derive(Debug, Default, Deserialize)]
+pub struct FrontendConfig {
+    pub locale: Option<String>,
+    #[serde(default)]
+    pub search_trigger: SearchTrigger,
+    #[serde(default)]
+    pub escape_behavior: EscapeBehavior,
+    #[serde(default)]
+    pub type_ahead: TypeAheadConfig,
+    #[serde(default)]
+    pub prompt_history: PromptHistoryConfig,
+    #[serde(default)]
+    pub session_restore: SessionRestoreConfig,
+    #[serde(default)]
+    pub theme: ThemeSelection,
+    #[serde(default)]
+    pub accessibility: AccessibilityConfig,
+    #[serde(default)]
+    pub window: WindowConfig,
+    #[serde(default)]
+    pub startup: StartupConfig,
+    #[serde(default)]
+    pub backend_requests: BackendRequestsConfig,
+    #[serde(default)]
+    pub image_cache: ImageCacheConfig,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+pub enum SearchTrigger {
+    // search on every change of the prompt
+    #[default]
+    #[serde(rename = "input")]
+    Input,
+    // search only when text is committed with enter, useful with input methods
+    // which update the prompt on every keystroke while composing
+    #[serde(rename = "commit")]
+    Commit,
+}
+
+// what Escape does when there is no action panel or other overlay to close
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+pub enum EscapeBehavior {
+    // leaves keyword mode, goes to previous view in plugin views and hides window from main view
+    #[default]
+    #[serde(rename = "back")]
+    Back,
+    // same as back, but non-empty prompt of main view is cleared first
+    #[serde(rename = "clear_then_hide")]
+    ClearThenHide,
+    // window is hidden right away, also from plugin views
+    #[serde(rename = "hide")]
+    Hide,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct TypeAheadConfig {
+    // enter pressed before search for the latest prompt is done runs top result that is already shown,
+    // if its name still contains the prompt, instead of waiting for new results
+    pub run_top_result: bool,
+    // result is run without pressing enter when search for typed prompt finds only one
+    pub run_unique_match: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PromptHistoryConfig {
+    pub enabled: bool,
+    // oldest queries are removed when history grows beyond this size
+    pub max_size: usize,
+}
+
+impl Default for PromptHistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_size: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ImageCacheConfig {
+    // images that are not shown anymore are evicted, least recently used first, when cache grows over this size
+    pub max_size_mb: u64,
+}
+
+impl Default for ImageCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_size_mb: 128,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SessionRestoreConfig {
+    // plugin view is kept open when window is hidden and shown again on next open
+    pub enabled: bool,
+    // view is closed instead of restored if window was hidden for longer than this
+    pub start_fresh_after_minutes: u64,
+}
+
+impl Default for SessionRestoreConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start_fresh_after_minutes: 5,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+pub enum ThemeSelection {
+    // theme files from config directory are used if present, otherwise built-in dark theme
+    #[default]
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "dark")]
+    Dark,
+    #[serde(rename = "light")]
+    Light,
+}
+
+impl ThemeSelection {
+    pub fn as_config_value(&self) -> &'static str {
+        match self {
+            ThemeSelection::Auto => "auto",
+            ThemeSelection::Dark => "dark",
+            ThemeSelection::Light => "light",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct AccessibilityConfig {
+    // scrolling and view transitions are not animated and loading indicators are static
+    pub reduced_motion: bool,
+    // stronger borders and outline around focused item, applied on top of any theme
+    pub high_contrast: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WindowConfig {
+    pub position: WindowPosition,
+    // read by client every time window loses focus, so changes are applied without restart
+    pub hide_on_focus_loss: bool,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            position: WindowPosition::default(),
+            hide_on_focus_loss: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+pub enum WindowPosition {
+    // center of the monitor with mouse pointer, if it can be detected
+    #[default]
+    #[serde(rename = "center")]
+    Center,
+    // horizontally centered, closer to the top edge of the monitor
+    #[serde(rename = "top")]
+    Top,
+}
+
+impl WindowPosition {
+    pub fn as_config_value(&self) -> &'static str {
+        match self {
+            WindowPosition::Center => "center",
+            WindowPosition::Top => "top",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct StartupConfig {
+    // window is shown when gauntlet is started without --minimized flag
+    pub open_window: bool,
+    // macos and windows only, gauntlet is registered to start on login with --minimized flag
+    pub launch_at_login: bool,
+}
+
+impl Default for StartupConfig {
+    fn default() -> Self {
+        Self {
+            open_window: true,
+            launch_at_login: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct BackendRequestsConfig {
+    // how long client waits for server to respond, per kind of request
+    pub search_timeout_ms: u64,
+    pub view_timeout_ms: u64,
+    pub event_timeout_ms: u64,
+    pub command_timeout_ms: u64,
+    // only requests which are safe to send twice are retried, e.g. search or view render
+    pub retries: u32,
+    // doubled after every attempt
+    pub retry_backoff_ms: u64,
+}
+
+impl Default for BackendRequestsConfig {
+    fn default() -> Self {
+        Self {
+            search_timeout_ms: 30_000,
+            view_timeout_ms: 30_000,
+            event_timeout_ms: 30_000,
+            command_timeout_ms: 30_000,
+            retries: 2,
+            retry_backoff_ms: 250,
+        }
+    }
+}
+
+pub fn read_frontend_config() -> FrontendConfig {
+    let Ok(content) = std::fs::read_to_string(Dirs::new().config_file()) else {
+        return FrontendConfig::default()
+    };
+
+    toml::from_str(&content).unwrap_or_default()
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use unic_langid::LanguageIdentifier;
+
+use crate::frontend_config::read_frontend_config;
+
+const FALLBACK_LOCALE: &str = "en";
+
+// to add a new locale, create "locales/<language>.ftl" with the same message ids as "en.ftl" and add it here.
+// messages that are missing in the translation are shown in english
+const LOCALES: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en.ftl")),
+];
+
+struct Translations {
+    locale: LanguageIdentifier,
+    bundle: Option<FluentBundle<FluentResource>>,
+    fallback_bundle: FluentBundle<FluentResource>,
+}
+
+static TRANSLATIONS: OnceLock<Translations> = OnceLock::new();
+
+pub fn tr(id: &str) -> String {
+    format_message(id, None)
+}
+
+pub fn tr_with(id: &str, args: &[(&str, FluentValue)]) -> String {
+    let mut fluent_args = FluentArgs::new();
+
+    for (name, value) in args {
+        fluent_args.set(*name, value.clone());
+    }
+
+    format_message(id, Some(&fluent_args))
+}
+
+// locale selected from "locale" field in config file or from system locale
+pub fn current_locale() -> &'static LanguageIdentifier {
+    &translations().locale
+}
+
+// picks value for current locale from map keyed by locale, e.g. translations of entrypoint names in plugin manifest.
+// exact match is preferred, otherwise language without region is used, "pt-BR" falls back to "pt"
+pub fn select_translation<T>(translations: &HashMap<String, T>) -> Option<&T> {
+    let locale = current_locale();
+
+    let exact = translations.iter()
+        .find(|(key, _)| key.parse::<LanguageIdentifier>().is_ok_and(|key| &key == locale))
+        .map(|(_, value)| value);
+
+    exact.or_else(|| {
+        translations.iter()
+            .find(|(key, _)| {
+                key.parse::<LanguageIdentifier>()
+                    .is_ok_and(|key| key.language == locale.language && key.region.is_none())
+            })
+            .map(|(_, value)| value)
+    })
+}
+
+pub fn is_valid_locale(locale: &str) -> bool {
+    locale.parse::<LanguageIdentifier>().is_ok()
+}
+
+fn format_message(id: &str, args: Option<&FluentArgs>) -> String {
+    let translations = translations();
+
+    let bundles = translations.bundle.iter()
+        .chain(std::iter::once(&translations.fallback_bundle));
+
+    for bundle in bundles {
+        let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else {
+            continue
+        };
+
+        let mut errors = vec![];
+        let value = bundle.format_pattern(pattern, args, &mut errors);
+
+        if !errors.is_empty() {
+            tracing::warn!("Errors when formatting message {:?}: {:?}", id, errors);
+        }
+
+        return value.to_string()
+    }
+
+    tracing::warn!("Message {:?} is missing in translations", id);
+
+    id.to_string()
+}
+
+fn translations() -> &'static Translations {
+    TRANSLATIONS.get_or_init(|| {
+        let requested = requested_locale();
+
+        let locale = requested.as_deref()
+            .and_then(|locale| parse_locale(locale))
+            .unwrap_or_else(|| FALLBACK_LOCALE.parse().expect("fallback locale should be valid"));
+
+        tracing::info!("Using locale: {}", locale);
+
+        let bundle = LOCALES.iter()
+            .find(|(language, _)| *language != FALLBACK_LOCALE && *language == locale.language.as_str())
+            .map(|(language, source)| create_bundle(language, source));
+
+        let fallback_source = LOCALES.iter()
+            .find(|(language, _)| *language == FALLBACK_LOCALE)
+            .map(|(_, source)| *source)
+            .expect("fallback locale should be bundled");
+
+        Translations {
+            locale,
+            bundle,
+            fallback_bundle: create_bundle(FALLBACK_LOCALE, fallback_source),
+        }
+    })
+}
+
+fn create_bundle(language: &str, source: &str) -> FluentBundle<FluentResource> {
+    let language: LanguageIdentifier = language.parse().expect("bundled locale should be valid");
+
+    let resource = FluentResource::try_new(source.to_string())
+        .unwrap_or_else(|(_, errors)| panic!("bundled translations for {} are invalid: {:?}", language, errors));
+
+    let mut bundle = FluentBundle::new_concurrent(vec![language]);
+
+    // unicode isolation marks around arguments are shown as is by the ui
+    bundle.set_use_isolating(false);
+
+    bundle.add_resource(resource)
+        .expect("bundled translations should not have duplicate messages");
+
+    bundle
+}
+
+fn requested_locale() -> Option<String> {
+    // screenshots and scenarios are always in english
+    if cfg!(feature = "scenario_runner") {
+        return None
+    }
+
+    read_frontend_config()
+        .locale
+        .or_else(|| sys_locale::get_locale())
+}
+
+// system locale can be in posix format, e.g. "en_US.UTF-8"
+fn parse_locale(locale: &str) -> Option<LanguageIdentifier> {
+    let locale = locale.split(['.', '@']).next()?.replace('_', "-");
+
+    match locale.parse() {
+        Ok(locale) => Some(locale),
+        Err(err) => {
+            tracing::warn!("Unable to parse locale {:?}: {:?}", locale, err);
+            None
+        }
+    }
+}
@@ -5,6 +5,9 @@ pub mod rpc;
 pub mod scenario_convert;
 pub mod scenario_model;
 pub mod dirs;
+pub mod frontend_config;
+pub mod i18n;
+pub mod error;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "type")]
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::anyhow;
 use bincode::{Decode, Encode};
@@ -8,6 +11,8 @@ use gix_url::Scheme;
 use gix_url::Url;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde::de::Error;
+use crate::error::GauntletError;
+use crate::frontend_config::{AccessibilityConfig, StartupConfig, ThemeSelection, WindowConfig, WindowPosition};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
 pub struct PluginId(Arc<str>);
@@ -89,7 +94,7 @@ pub enum UiRenderLocation {
     View
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PhysicalShortcut {
     pub physical_key: PhysicalKey,
     pub modifier_shift: bool,
@@ -104,21 +109,310 @@ pub struct LocalSaveData {
     pub stderr_file_path: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginLogStream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Debug, Clone)]
+pub struct PluginLogLine {
+    pub stream: PluginLogStream,
+    pub text: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PluginLogs {
+    pub lines: Vec<PluginLogLine>,
+    pub stdout_offset: u64,
+    pub stderr_offset: u64,
+    pub reset: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticsKind {
+    Render,
+    Search,
+    Op,
+    Ipc,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiagnosticsEntry {
+    pub kind: DiagnosticsKind,
+    pub plugin_id: Option<PluginId>,
+    pub name: String,
+    pub count: u64,
+    pub total_duration: Duration,
+    pub max_duration: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiagnosticsData {
+    pub enabled: bool,
+    pub entries: Vec<DiagnosticsEntry>,
+    // collected all the time, not only while diagnostics are enabled. none if window is not running
+    pub image_cache: Option<ImageCacheStats>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageCacheStats {
+    pub entries: u64,
+    pub size_bytes: u64,
+    pub max_size_bytes: u64,
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+// data exported from other launchers, converted to script commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportSource {
+    RaycastQuicklinks,
+    RaycastSnippets,
+    AlfredWebSearches,
+    AlbertWebSearches,
+    AlbertSnippets,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportItemKind {
+    Quicklink,
+    Snippet,
+    WebSearch,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportItem {
+    pub title: String,
+    pub kind: ImportItemKind,
+    pub skip_reason: Option<String>,
+}
+
+// manifest permissions of installed plugin that user can revoke in settings
+#[derive(Debug, Clone)]
+pub struct SettingsPluginPermissions {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+    pub permissions: Vec<SettingsPluginPermission>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SettingsPluginPermission {
+    pub kind: PluginPermissionKind,
+    // host, path, command or clipboard operation, as written in manifest
+    pub value: String,
+    // local date and time, none for plugins installed before grants were recorded
+    pub granted_at: Option<String>,
+    pub revoked: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluginPermissionKind {
+    Network,
+    FilesystemRead,
+    FilesystemWrite,
+    ExecCommand,
+    ExecExecutable,
+    Clipboard,
+}
+
+// sequence of existing actions composed in settings, shown in search as a single command
+#[derive(Debug, Clone)]
+pub struct Macro {
+    pub id: String,
+    pub name: String,
+    pub steps: Vec<MacroStep>,
+}
+
+#[derive(Debug, Clone)]
+pub enum MacroStep {
+    // command or generated command, e.g. opening application or running script command
+    RunEntrypoint {
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+        action_index: Option<usize>,
+    },
+    // commands don't report when they are done, so next step may need to wait, e.g. until opened application is focused
+    Delay {
+        millis: u64,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct Profiles {
+    pub profiles: Vec<String>,
+    // profile server is running with
+    pub current: String,
+    // profile used on next start
+    pub active: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigError {
+    pub config_file: String,
+    pub message: String,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+}
+
 #[derive(Debug, Clone)]
 pub struct SearchResult {
     pub plugin_id: PluginId,
     pub plugin_name: String,
     pub entrypoint_id: EntrypointId,
     pub entrypoint_name: String,
+    // byte ranges of entrypoint name that matched the query, highlighted in search results
+    pub entrypoint_name_matches: Vec<Range<usize>>,
     pub entrypoint_icon: Option<String>,
     pub entrypoint_type: SearchResultEntrypointType,
     pub entrypoint_actions: Vec<SearchResultEntrypointAction>,
+    // short text shown next to the name, e.g. state of the item
+    pub entrypoint_accessory: Option<String>,
+    // set by plugin at runtime, e.g. number of unread items
+    pub entrypoint_badge: Option<SearchResultBadge>,
+    // whether plugin can provide preview shown in preview pane
+    pub entrypoint_preview: bool,
+    // file or url dropped into other application when search result is dragged out of the window
+    pub entrypoint_drag: Option<DragData>,
+    pub entrypoint_arguments: Vec<EntrypointArgument>,
+    // values available in action panel of every search result, without plugin having to provide actions for them
+    pub entrypoint_copy_items: Vec<SearchResultCopyItem>,
+    pub fallback: bool,
+    // shown in "Recent" section at the top of results when prompt is empty
+    pub recent: bool,
+    // shown in "Favorites" section before recently used entrypoints when prompt is empty
+    pub favorite: bool,
+    pub entrypoint_category: SearchResultCategory,
+    // set by user from action panel or settings, shown next to plugin name
+    pub aliases: Vec<String>,
+}
+
+impl SearchResult {
+    // actions shown in action panel in addition to primary action
+    pub fn secondary_action_count(&self) -> usize {
+        self.entrypoint_actions.len() + self.entrypoint_copy_items.len() + self.alias_action_count()
+    }
+
+    // "add alias" is always available, "remove aliases" only if there is something to remove
+    pub fn alias_action_count(&self) -> usize {
+        if self.aliases.is_empty() { 1 } else { 2 }
+    }
+}
+
+// used to limit search results to one kind of items
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchResultCategory {
+    Application,
+    Command,
+    Quicklink,
+    File,
+}
+
+impl SearchResultCategory {
+    pub const ALL: [SearchResultCategory; 4] = [
+        SearchResultCategory::Application,
+        SearchResultCategory::Command,
+        SearchResultCategory::Quicklink,
+        SearchResultCategory::File,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchResultCategory::Application => "application",
+            SearchResultCategory::Command => "command",
+            SearchResultCategory::Quicklink => "quicklink",
+            SearchResultCategory::File => "file",
+        }
+    }
+
+    // query like "type:app firefox", returns category and the rest of the query
+    pub fn parse_query_prefix(query: &str) -> Option<(SearchResultCategory, &str)> {
+        let (prefix, rest) = query.split_once(' ')?;
+
+        let category = SearchResultCategory::from_query_name(prefix.strip_prefix("type:")?)?;
+
+        Some((category, rest.trim_start()))
+    }
+
+    // value of "type:" in search query
+    pub fn from_query_name(name: &str) -> Option<SearchResultCategory> {
+        let category = match name.to_lowercase().as_str() {
+            "app" | "apps" | "application" | "applications" => SearchResultCategory::Application,
+            "cmd" | "command" | "commands" => SearchResultCategory::Command,
+            "link" | "links" | "quicklink" | "quicklinks" => SearchResultCategory::Quicklink,
+            "file" | "files" => SearchResultCategory::File,
+            _ => return None
+        };
+
+        Some(category)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResultCopyItem {
+    pub kind: SearchResultCopyKind,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SearchResultCopyKind {
+    Name,
+    Path,
+    Url,
+    Id,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchResultBadge {
+    Count(u32),
+    Dot,
+}
+
+#[derive(Debug, Clone)]
+pub enum DragData {
+    File(String),
+    Url(String),
+}
+
+#[derive(Clone)]
+pub struct SearchResultPreview {
+    pub text: Option<String>,
+    pub image: Option<Vec<u8>>,
+    pub metadata: Vec<SearchResultPreviewMetadata>,
+}
+
+impl fmt::Debug for SearchResultPreview {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // exclude image
+        fmt.debug_struct("SearchResultPreview")
+            .field("text", &self.text)
+            .field("metadata", &self.metadata)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResultPreviewMetadata {
+    pub label: String,
+    pub value: String,
 }
 
 #[derive(Debug, Clone)]
 pub struct SearchResultEntrypointAction {
     pub label: String,
     pub shortcut: Option<PhysicalShortcut>,
+    // set when action was taken from duplicate search result of another generated command
+    pub merged_from: Option<SearchResultActionSource>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResultActionSource {
+    pub plugin_id: PluginId,
+    pub entrypoint_id: EntrypointId,
+    pub action_index: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -128,6 +422,119 @@ pub enum SearchResultEntrypointType {
     GeneratedCommand,
 }
 
+#[derive(Debug, Clone)]
+pub struct EntrypointArgument {
+    pub id: String,
+    pub name: String,
+    pub optional: bool,
+    // shown under argument inputs while this one is focused
+    pub hint: Option<String>,
+    pub argument_type: EntrypointArgumentType,
+}
+
+#[derive(Debug, Clone)]
+pub enum EntrypointArgumentType {
+    String {
+        max_length: Option<usize>,
+    },
+    Number {
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    Enum {
+        enum_values: Vec<PreferenceEnumValue>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum EntrypointArgumentValue {
+    String(String),
+    Number(f64),
+}
+
+impl EntrypointArgument {
+    pub fn parse_value(&self, value: &str) -> anyhow::Result<Option<EntrypointArgumentValue>> {
+        let value = value.trim();
+
+        if value.is_empty() {
+            return if self.optional {
+                Ok(None)
+            } else {
+                Err(anyhow!("Argument '{}' is required", self.name))
+            }
+        }
+
+        let value = match &self.argument_type {
+            EntrypointArgumentType::String { max_length } => {
+                if let Some(max_length) = max_length {
+                    if value.chars().count() > *max_length {
+                        return Err(anyhow!("Argument '{}' can be at most {} characters long", self.name, max_length))
+                    }
+                }
+
+                EntrypointArgumentValue::String(value.to_string())
+            }
+            EntrypointArgumentType::Number { min, max } => {
+                let number = value.parse::<f64>()
+                    .ok()
+                    .filter(|number| number.is_finite())
+                    .ok_or_else(|| anyhow!("Argument '{}' has to be a number", self.name))?;
+
+                if let Some(min) = min {
+                    if number < *min {
+                        return Err(anyhow!("Argument '{}' has to be at least {}", self.name, min))
+                    }
+                }
+
+                if let Some(max) = max {
+                    if number > *max {
+                        return Err(anyhow!("Argument '{}' has to be at most {}", self.name, max))
+                    }
+                }
+
+                EntrypointArgumentValue::Number(number)
+            }
+            EntrypointArgumentType::Enum { enum_values } => {
+                // accept both value and label, case-insensitive, because label is what user sees
+                let enum_value = enum_values.iter()
+                    .find(|enum_value| enum_value.value.eq_ignore_ascii_case(value) || enum_value.label.eq_ignore_ascii_case(value))
+                    .ok_or_else(|| {
+                        let allowed = enum_values.iter()
+                            .map(|enum_value| enum_value.value.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        anyhow!("Argument '{}' has to be one of: {}", self.name, allowed)
+                    })?;
+
+                EntrypointArgumentValue::String(enum_value.value.clone())
+            }
+        };
+
+        Ok(Some(value))
+    }
+}
+
+pub fn parse_entrypoint_arguments(arguments: &[EntrypointArgument], values: &[String]) -> anyhow::Result<HashMap<String, EntrypointArgumentValue>> {
+    if values.len() > arguments.len() {
+        return Err(anyhow!("Too many arguments, expected at most {}", arguments.len()))
+    }
+
+    let mut result = HashMap::new();
+
+    for (index, argument) in arguments.iter().enumerate() {
+        let value = values.get(index)
+            .map(|value| value.as_str())
+            .unwrap_or("");
+
+        if let Some(value) = argument.parse_value(value)? {
+            result.insert(argument.id.clone(), value);
+        }
+    }
+
+    Ok(result)
+}
+
 #[derive(Debug)]
 pub enum UiResponseData {
     Nothing,
@@ -137,6 +544,19 @@ pub enum UiResponseData {
 #[derive(Debug)]
 pub enum UiRequestData {
     ShowWindow,
+    HideWindow,
+    ToggleWindow,
+    Quit,
+    // none ends the preview and goes back to configured theme
+    PreviewTheme {
+        theme: Option<SimpleThemeTokens>,
+    },
+    ShowPluginView {
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+    },
     ClearInlineView {
         plugin_id: PluginId
     },
@@ -146,9 +566,9 @@ pub enum UiRequestData {
         entrypoint_id: EntrypointId,
         entrypoint_name: String,
         render_location: UiRenderLocation,
-        top_level_view: bool,
+        view_stack_depth: usize,
         container: RootWidget,
-        images: HashMap<UiWidgetId, Vec<u8>>,
+        images: HashMap<UiWidgetId, UiImageId>,
     },
     ShowPreferenceRequiredView {
         plugin_id: PluginId,
@@ -161,10 +581,32 @@ pub enum UiRequestData {
         entrypoint_id: EntrypointId,
         render_location: UiRenderLocation,
     },
+    ShowRenderErrorView {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        message: String,
+        component_path: Vec<String>,
+    },
+    ShowRenderTiming {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        js_render_time_ms: f64,
+        serialization_time_ms: f64,
+    },
+    ShowArgumentSuggestions {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        argument_id: String,
+        generation: u64,
+        suggestions: Vec<String>,
+    },
     RequestSearchResultUpdate,
     ShowHud {
         display: String
     },
+    ShowUndoToast {
+        description: String
+    },
     UpdateLoadingBar {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
@@ -173,46 +615,166 @@ pub enum UiRequestData {
     SetGlobalShortcut {
         shortcut: Option<PhysicalShortcut>
     },
+    ShowRecoveryView {
+        safe_mode: bool,
+        description: String,
+        crash_report_path: Option<String>,
+    },
+    ShowConfigError {
+        error: ConfigError,
+    },
+    // config file was changed, frontend reads parts of it that it uses
+    ConfigReloaded,
+    // window is hidden and cannot be shown while screen is locked
+    SessionLockChanged {
+        locked: bool,
+    },
+    ShowDatabaseRecoveryView {
+        reason: String,
+        broken_database_path: String,
+        restored_backup_path: Option<String>,
+    },
+    ShowWhatsNewView {
+        version: u16,
+        changelog: String,
+    },
+    UpdateActionShortcuts {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        shortcuts: HashMap<String, PhysicalShortcut>,
+    },
+    ShowSearchResultPreview {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        preview: Option<SearchResultPreview>,
+    },
+    // dynamic search results of one plugin, sent separately from search index results of the same generation
+    AppendSearchResults {
+        generation: u64,
+        plugin_id: PluginId,
+        results: Vec<SearchResult>,
+        // plugin responded after timeout
+        late: bool,
+    },
+}
+
+// style tokens from plugin manifest, already validated by server.
+// applied only to views of the plugin, everything not listed here is taken from global theme
+#[derive(Debug, Clone, Default)]
+pub struct PluginStyle {
+    pub accent_color: Option<String>,
+    pub muted_color: Option<String>,
+    pub success_color: Option<String>,
+    pub warning_color: Option<String>,
+    pub danger_color: Option<String>,
+    pub link_color: Option<String>,
+    pub content_spacing: Option<f32>,
 }
 
 #[derive(Debug)]
 pub enum BackendResponseData {
     Nothing,
     Search {
-        results: Vec<SearchResult>
+        results: Vec<SearchResult>,
+        // set when prompt started with plugin keyword, results are for the rest of the prompt
+        keyword_mode: Option<SearchKeywordMode>,
+        // set when prompt started with "type:" prefix, results are for the rest of the prompt
+        category: Option<SearchResultCategory>,
     },
     RequestViewRender {
-        shortcuts: HashMap<String, PhysicalShortcut>
+        shortcuts: HashMap<String, PhysicalShortcut>,
+        // plugin is marked as interactive in its manifest, window is not hidden on focus loss while view is open
+        interactive: bool,
+        style: PluginStyle,
     },
     InlineViewShortcuts {
         shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>
     },
+    InlineViewOrder {
+        plugin_ids: Vec<PluginId>
+    },
+    Images {
+        images: HashMap<UiImageId, bytes::Bytes>
+    },
+    BugReport {
+        // directory bundle was saved into
+        path: String,
+    },
+    // request failed on server side
+    Error {
+        error: GauntletError
+    },
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+pub struct ActiveWindowContext {
+    // bundle identifier on macOS, WM_CLASS on X11, executable name on Windows
+    pub app_id: String,
+    pub app_name: Option<String>,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone)]
 pub enum BackendRequestData {
     Search {
         text: String,
-        render_inline_view: bool
+        // search is limited to this plugin while client is in keyword mode
+        keyword_plugin_id: Option<PluginId>,
+        // search is limited to this category while filter is selected
+        category: Option<SearchResultCategory>,
+        render_inline_view: bool,
+        // increases with every search request, used to ignore inline view renders for older prompts
+        generation: u64,
     },
     RequestViewRender {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId
     },
+    // plugin can have several views open at once, one per entrypoint
     RequestViewClose {
         plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
+    RequestViewPop {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
+    RequestViewVisibility {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        visible: bool,
+    },
+    // plugins are asked to pause background work after window stays hidden for a while
+    WindowVisibility {
+        visible: bool,
+        // window which was focused right before launcher window was shown, none when hidden or unknown
+        active_window: Option<ActiveWindowContext>,
     },
     RequestRunCommand {
         plugin_id: PluginId,
-        entrypoint_id: EntrypointId
+        entrypoint_id: EntrypointId,
+        arguments: HashMap<String, EntrypointArgumentValue>,
     },
     RequestRunGeneratedCommand {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_index: Option<usize>
     },
+    RequestArgumentSuggestions {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        argument_id: String,
+        values: HashMap<String, String>,
+        generation: u64,
+    },
+    RequestSearchResultPreview {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
     SendViewEvent {
         plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        render_location: UiRenderLocation,
         widget_id: UiWidgetId,
         event_name: String,
         event_arguments: Vec<UiPropertyValue>
@@ -237,6 +799,27 @@ pub enum BackendRequestData {
         entrypoint_id: Option<EntrypointId>
     },
     InlineViewShortcuts,
+    InlineViewOrder,
+    GetImages {
+        image_ids: Vec<UiImageId>
+    },
+    CopyToClipboard {
+        text: String
+    },
+    SetSearchResultAliases {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        aliases: Vec<String>,
+    },
+    SaveBugReport {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        widget_tree: String,
+        // png
+        screenshot: Vec<u8>,
+    },
+    Undo,
+    Redo,
 }
 
 #[derive(Debug, Clone)]
@@ -338,6 +921,9 @@ pub trait WidgetVisitor {
     async fn h6_widget(&mut self, _widget: &H6Widget) {}
     async fn horizontal_break_widget(&mut self, _widget: &HorizontalBreakWidget) {}
     async fn code_block_widget(&mut self, _widget: &CodeBlockWidget) {}
+    async fn color_swatch_widget(&mut self, _widget: &ColorSwatchWidget) {}
+    async fn sparkline_widget(&mut self, _widget: &SparklineWidget) {}
+    async fn bar_chart_widget(&mut self, _widget: &BarChartWidget) {}
     async fn paragraph_widget(&mut self, _widget: &ParagraphWidget) {}
     async fn content_widget(&mut self, widget: &ContentWidget) {
         for members in &widget.content.ordered_members {
@@ -352,6 +938,9 @@ pub trait WidgetVisitor {
                 ContentWidgetOrderedMembers::H6(widget) => self.h6_widget(widget).await,
                 ContentWidgetOrderedMembers::HorizontalBreak(widget) => self.horizontal_break_widget(widget).await,
                 ContentWidgetOrderedMembers::CodeBlock(widget) => self.code_block_widget(widget).await,
+                ContentWidgetOrderedMembers::ColorSwatch(widget) => self.color_swatch_widget(widget).await,
+                ContentWidgetOrderedMembers::Sparkline(widget) => self.sparkline_widget(widget).await,
+                ContentWidgetOrderedMembers::BarChart(widget) => self.bar_chart_widget(widget).await,
             }
         }
     }
@@ -380,6 +969,7 @@ pub trait WidgetVisitor {
             }
         }
     }
+    async fn slider_widget(&mut self, _widget: &SliderWidget) {}
     async fn separator_widget(&mut self, _widget: &SeparatorWidget) {}
     async fn form_widget(&mut self, widget: &FormWidget) {
         if let Some(widget) = &widget.content.actions {
@@ -392,6 +982,7 @@ pub trait WidgetVisitor {
                 FormWidgetOrderedMembers::Checkbox(widget) => self.checkbox_widget(widget).await,
                 FormWidgetOrderedMembers::DatePicker(widget) => self.date_picker_widget(widget).await,
                 FormWidgetOrderedMembers::Select(widget) => self.select_widget(widget).await,
+                FormWidgetOrderedMembers::Slider(widget) => self.slider_widget(widget).await,
                 FormWidgetOrderedMembers::Separator(widget) => self.separator_widget(widget).await,
             }
         }
@@ -484,6 +1075,9 @@ pub trait WidgetVisitor {
                 ContentWidgetOrderedMembers::H6(widget) => self.h6_widget(widget).await,
                 ContentWidgetOrderedMembers::HorizontalBreak(widget) => self.horizontal_break_widget(widget).await,
                 ContentWidgetOrderedMembers::CodeBlock(widget) => self.code_block_widget(widget).await,
+                ContentWidgetOrderedMembers::ColorSwatch(widget) => self.color_swatch_widget(widget).await,
+                ContentWidgetOrderedMembers::Sparkline(widget) => self.sparkline_widget(widget).await,
+                ContentWidgetOrderedMembers::BarChart(widget) => self.bar_chart_widget(widget).await,
             }
         }
     }
@@ -538,6 +1132,9 @@ pub enum UiPropertyValue {
 
 pub type UiWidgetId = usize;
 
+// sha256 of image bytes, same image used by multiple widgets or renders has the same id
+pub type UiImageId = String;
+
 #[derive(Debug, Clone)]
 pub struct SettingsEntrypoint {
     pub entrypoint_id: EntrypointId,
@@ -545,8 +1142,237 @@ pub struct SettingsEntrypoint {
     pub entrypoint_description: String,
     pub entrypoint_type: SettingsEntrypointType,
     pub enabled: bool,
+    pub hidden: bool,
     pub preferences: HashMap<String, PluginPreference>,
     pub preferences_user_data: HashMap<String, PluginPreferenceUserData>,
+    pub actions: Vec<SettingsEntrypointAction>,
+    // set by user in settings, entrypoint can be found in search by typing any of them
+    pub aliases: Vec<String>,
+    // pinned by user, shown in "Favorites" section at the top of results when prompt is empty
+    pub favorite: bool,
+}
+
+// settings from general tab of settings window, stored in config file
+#[derive(Debug, Clone)]
+pub struct GeneralSettings {
+    pub theme: ThemeSelection,
+    pub window_position: WindowPosition,
+    pub hide_window_on_focus_loss: bool,
+    // none shows all results
+    pub max_search_results: Option<u32>,
+    pub open_window_on_start: bool,
+    pub launch_at_login: bool,
+    pub telemetry: bool,
+    pub check_for_updates: bool,
+    // local usage statistics shown in statistics tab of settings window
+    pub usage_statistics: bool,
+    // sounds played by plugins
+    pub sound_muted: bool,
+    pub reduced_motion: bool,
+    pub high_contrast: bool,
+}
+
+// same as values used when config file doesn't have them
+impl Default for GeneralSettings {
+    fn default() -> Self {
+        let window = WindowConfig::default();
+        let startup = StartupConfig::default();
+        let accessibility = AccessibilityConfig::default();
+
+        Self {
+            theme: ThemeSelection::default(),
+            window_position: window.position,
+            hide_window_on_focus_loss: window.hide_on_focus_loss,
+            max_search_results: None,
+            open_window_on_start: startup.open_window,
+            launch_at_login: startup.launch_at_login,
+            telemetry: false,
+            check_for_updates: false,
+            usage_statistics: true,
+            sound_muted: false,
+            reduced_motion: accessibility.reduced_motion,
+            high_contrast: accessibility.high_contrast,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThemeTokenColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: f32,
+}
+
+// tokens of simple theme, edited in theme tab of settings window. rest of the theme is derived from them
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimpleThemeTokens {
+    pub background_darkest_color: ThemeTokenColor,
+    pub background_darker_color: ThemeTokenColor,
+    pub background_lighter_color: ThemeTokenColor,
+    pub background_lightest_color: ThemeTokenColor,
+    pub text_lightest_color: ThemeTokenColor,
+    pub text_lighter_color: ThemeTokenColor,
+    pub text_darker_color: ThemeTokenColor,
+    pub text_darkest_color: ThemeTokenColor,
+    pub primary_darker_color: ThemeTokenColor,
+    pub primary_lighter_color: ThemeTokenColor,
+    pub root_border_color: ThemeTokenColor,
+    pub root_border_radius: f32,
+    pub root_border_width: f32,
+    pub content_border_radius: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeColorToken {
+    BackgroundDarkest,
+    BackgroundDarker,
+    BackgroundLighter,
+    BackgroundLightest,
+    TextLightest,
+    TextLighter,
+    TextDarker,
+    TextDarkest,
+    PrimaryDarker,
+    PrimaryLighter,
+    RootBorder,
+}
+
+impl ThemeColorToken {
+    pub const ALL: [ThemeColorToken; 11] = [
+        ThemeColorToken::BackgroundDarkest,
+        ThemeColorToken::BackgroundDarker,
+        ThemeColorToken::BackgroundLighter,
+        ThemeColorToken::BackgroundLightest,
+        ThemeColorToken::TextLightest,
+        ThemeColorToken::TextLighter,
+        ThemeColorToken::TextDarker,
+        ThemeColorToken::TextDarkest,
+        ThemeColorToken::PrimaryDarker,
+        ThemeColorToken::PrimaryLighter,
+        ThemeColorToken::RootBorder,
+    ];
+}
+
+impl SimpleThemeTokens {
+    pub fn color(&self, token: ThemeColorToken) -> ThemeTokenColor {
+        match token {
+            ThemeColorToken::BackgroundDarkest => self.background_darkest_color,
+            ThemeColorToken::BackgroundDarker => self.background_darker_color,
+            ThemeColorToken::BackgroundLighter => self.background_lighter_color,
+            ThemeColorToken::BackgroundLightest => self.background_lightest_color,
+            ThemeColorToken::TextLightest => self.text_lightest_color,
+            ThemeColorToken::TextLighter => self.text_lighter_color,
+            ThemeColorToken::TextDarker => self.text_darker_color,
+            ThemeColorToken::TextDarkest => self.text_darkest_color,
+            ThemeColorToken::PrimaryDarker => self.primary_darker_color,
+            ThemeColorToken::PrimaryLighter => self.primary_lighter_color,
+            ThemeColorToken::RootBorder => self.root_border_color,
+        }
+    }
+
+    pub fn set_color(&mut self, token: ThemeColorToken, color: ThemeTokenColor) {
+        let field = match token {
+            ThemeColorToken::BackgroundDarkest => &mut self.background_darkest_color,
+            ThemeColorToken::BackgroundDarker => &mut self.background_darker_color,
+            ThemeColorToken::BackgroundLighter => &mut self.background_lighter_color,
+            ThemeColorToken::BackgroundLightest => &mut self.background_lightest_color,
+            ThemeColorToken::TextLightest => &mut self.text_lightest_color,
+            ThemeColorToken::TextLighter => &mut self.text_lighter_color,
+            ThemeColorToken::TextDarker => &mut self.text_darker_color,
+            ThemeColorToken::TextDarkest => &mut self.text_darkest_color,
+            ThemeColorToken::PrimaryDarker => &mut self.primary_darker_color,
+            ThemeColorToken::PrimaryLighter => &mut self.primary_lighter_color,
+            ThemeColorToken::RootBorder => &mut self.root_border_color,
+        };
+
+        *field = color;
+    }
+}
+
+// computed from local database, shown in statistics tab of settings window
+#[derive(Debug, Clone)]
+pub struct UsageStatistics {
+    // collection is disabled in settings, already recorded data is still returned
+    pub enabled: bool,
+    // most launched first
+    pub entrypoints: Vec<UsageEntrypointStatistics>,
+    // most launched first
+    pub plugins: Vec<UsagePluginStatistics>,
+    // oldest first, days without usage are included
+    pub daily: Vec<UsageDayStatistics>,
+    pub search_count: u64,
+    pub average_search_latency_micros: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UsageEntrypointStatistics {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+    pub entrypoint_id: EntrypointId,
+    pub entrypoint_name: String,
+    pub launches: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct UsagePluginStatistics {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+    pub launches: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct UsageDayStatistics {
+    // YYYY-MM-DD in local time
+    pub date: String,
+    pub launches: u64,
+    pub searches: u64,
+}
+
+// plugin which search is routed to, because prompt started with its keyword
+#[derive(Debug, Clone)]
+pub struct SearchKeywordMode {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+    pub keyword: String,
+}
+
+// newer release found on github
+#[derive(Debug, Clone)]
+pub struct AvailableUpdate {
+    pub version: u16,
+    pub url: String,
+}
+
+// partial update of entrypoint settings, fields that are none are left as is
+#[derive(Debug, Clone, Default)]
+pub struct SettingsEntrypointUpdate {
+    pub enabled: Option<bool>,
+    pub hidden: Option<bool>,
+    pub aliases: Option<Vec<String>>,
+    pub favorite: Option<bool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SettingsEntrypointAction {
+    pub action_id: String,
+    pub description: String,
+    pub shortcut: PhysicalShortcut,
+    // shortcut declared in plugin manifest, used when user didn't override it
+    pub default_shortcut: PhysicalShortcut,
+}
+
+// reason why shortcut for plugin action cannot be used
+#[derive(Debug, Clone)]
+pub enum ActionShortcutConflict {
+    // without control, alt or meta key press would be typed into search bar
+    MissingModifier,
+    GlobalShortcut,
+    // used by gauntlet itself, e.g. opening action panel
+    Builtin,
+    Action {
+        action_description: String,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -560,6 +1386,24 @@ pub struct SettingsPlugin {
     pub preferences_user_data: HashMap<String, PluginPreferenceUserData>,
 }
 
+#[derive(Debug, Clone)]
+pub struct SettingsInlineView {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+    pub entrypoint_id: EntrypointId,
+    pub entrypoint_name: String,
+    pub enabled: bool,
+}
+
+// item of user ordered lists in general tab of settings window, e.g. favorites and fallbacks
+#[derive(Debug, Clone)]
+pub struct SettingsEntrypointReference {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+    pub entrypoint_id: EntrypointId,
+    pub entrypoint_name: String,
+}
+
 #[derive(Debug, Clone)]
 pub enum SettingsEntrypointType {
     Command,
@@ -591,6 +1435,9 @@ pub enum PluginPreferenceUserData {
     ListOfEnums {
         value: Option<Vec<String>>,
     },
+    Directory {
+        value: Option<String>,
+    },
     // TODO be careful about exposing secrets to logs when adding password type
 }
 
@@ -599,6 +1446,10 @@ pub enum PluginPreference {
     Number {
         name: String,
         default: Option<f64>,
+        min: Option<f64>,
+        max: Option<f64>,
+        // present if preference is shown as slider
+        step: Option<f64>,
         description: String,
     },
     String {
@@ -633,6 +1484,82 @@ pub enum PluginPreference {
         enum_values: Vec<PreferenceEnumValue>,
         description: String,
     },
+    Directory {
+        name: String,
+        default: Option<String>,
+        description: String,
+    },
+}
+
+impl PluginPreference {
+    pub fn validate(&self, user_data: &PluginPreferenceUserData) -> anyhow::Result<()> {
+        let enum_value_exists = |enum_values: &[PreferenceEnumValue], value: &str| {
+            enum_values.iter().any(|enum_value| enum_value.value == value)
+        };
+
+        match (self, user_data) {
+            (PluginPreference::Number { min, max, .. }, PluginPreferenceUserData::Number { value }) => {
+                if let Some(value) = value {
+                    if !value.is_finite() {
+                        Err(anyhow!("Value has to be a number"))?
+                    }
+
+                    match (min, max) {
+                        (Some(min), Some(max)) if value < min || value > max => Err(anyhow!("Value has to be between {} and {}", min, max))?,
+                        (Some(min), None) if value < min => Err(anyhow!("Value has to be at least {}", min))?,
+                        (None, Some(max)) if value > max => Err(anyhow!("Value has to be at most {}", max))?,
+                        _ => {}
+                    }
+                }
+            }
+            (PluginPreference::String { .. }, PluginPreferenceUserData::String { .. }) => {}
+            (PluginPreference::Enum { enum_values, .. }, PluginPreferenceUserData::Enum { value }) => {
+                if let Some(value) = value {
+                    if !enum_value_exists(enum_values, value) {
+                        Err(anyhow!("Unknown value: {}", value))?
+                    }
+                }
+            }
+            (PluginPreference::Bool { .. }, PluginPreferenceUserData::Bool { .. }) => {}
+            (PluginPreference::ListOfStrings { .. }, PluginPreferenceUserData::ListOfStrings { .. }) => {}
+            (PluginPreference::ListOfNumbers { .. }, PluginPreferenceUserData::ListOfNumbers { value }) => {
+                if let Some(value) = value {
+                    if value.iter().any(|value| !value.is_finite()) {
+                        Err(anyhow!("All values have to be numbers"))?
+                    }
+                }
+            }
+            (PluginPreference::ListOfEnums { enum_values, .. }, PluginPreferenceUserData::ListOfEnums { value }) => {
+                if let Some(value) = value {
+                    for (index, item) in value.iter().enumerate() {
+                        if !enum_value_exists(enum_values, item) {
+                            Err(anyhow!("Unknown value: {}", item))?
+                        }
+
+                        if value[..index].contains(item) {
+                            Err(anyhow!("Value is selected more than once: {}", item))?
+                        }
+                    }
+                }
+            }
+            (PluginPreference::Directory { .. }, PluginPreferenceUserData::Directory { value }) => {
+                if let Some(value) = value {
+                    let path = PathBuf::from(value);
+
+                    if !path.is_absolute() {
+                        Err(anyhow!("Path has to be absolute"))?
+                    }
+
+                    if !path.is_dir() {
+                        Err(anyhow!("Directory doesn't exist"))?
+                    }
+                }
+            }
+            _ => Err(anyhow!("Value type doesn't match preference type"))?
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -643,7 +1570,7 @@ pub struct PreferenceEnumValue {
 
 
 // copy of iced (currently fork) PhysicalKey but without modifiers
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PhysicalKey {
     Backquote,
     Backslash,
@@ -1,22 +1,26 @@
 use std::collections::HashMap;
+use std::time::Duration;
 use thiserror::Error;
 use tonic::{Code, Request};
 use tonic::transport::Channel;
 
 use gauntlet_utils::channel::{RequestError, RequestSender};
 
-use crate::model::{BackendRequestData, BackendResponseData, DownloadStatus, EntrypointId, KeyboardEventOrigin, LocalSaveData, PhysicalKey, PhysicalShortcut, PluginId, PluginPreferenceUserData, SearchResult, SettingsEntrypoint, SettingsEntrypointType, SettingsPlugin, UiPropertyValue, UiWidgetId};
-use crate::rpc::grpc::{RpcDownloadPluginRequest, RpcDownloadStatus, RpcDownloadStatusRequest, RpcEntrypointTypeSettings, RpcGetGlobalShortcutRequest, RpcPingRequest, RpcPluginsRequest, RpcRemovePluginRequest, RpcSaveLocalPluginRequest, RpcSetEntrypointStateRequest, RpcSetGlobalShortcutRequest, RpcSetPluginStateRequest, RpcSetPreferenceValueRequest, RpcShortcut, RpcShowSettingsWindowRequest, RpcShowWindowRequest};
+use crate::error::GauntletError;
+use crate::frontend_config::BackendRequestsConfig;
+use crate::model::{ActionShortcutConflict, ActiveWindowContext, AvailableUpdate, BackendRequestData, BackendResponseData, ConfigError, DiagnosticsData, DiagnosticsEntry, DiagnosticsKind, DownloadStatus, EntrypointArgumentValue, EntrypointId, GeneralSettings, ImageCacheStats, ImportItem, ImportSource, KeyboardEventOrigin, LocalSaveData, Macro, PluginPermissionKind, PhysicalKey, PhysicalShortcut, PluginId, PluginLogLine, PluginLogStream, PluginLogs, PluginPreferenceUserData, PluginStyle, Profiles, SearchKeywordMode, SearchResult, SearchResultCategory, SettingsEntrypoint, SettingsEntrypointReference, SettingsEntrypointType, SettingsEntrypointUpdate, SettingsInlineView, SettingsPlugin, SettingsPluginPermissions, SimpleThemeTokens, UiImageId, UiPropertyValue, UiRenderLocation, UiWidgetId, UsageStatistics};
+use crate::rpc::grpc::{RpcCheckForUpdatesRequest, RpcGetSimpleThemeRequest, RpcPreviewSimpleThemeRequest, RpcSaveSimpleThemeRequest, RpcGetMacrosRequest, RpcGetMacroStepCandidatesRequest, RpcGetPluginPermissionsRequest, RpcSetPluginPermissionRevokedRequest, RpcRemoveMacroRequest, RpcSaveMacroRequest, RpcDiagnosticsKind, RpcDownloadPluginRequest, RpcDownloadStatus, RpcDownloadStatusRequest, RpcEntrypointAliases, RpcEntrypointTypeSettings, RpcExportSettingsRequest, RpcGetConfigErrorRequest, RpcGetDiagnosticsRequest, RpcGetGeneralSettingsRequest, RpcGetGlobalShortcutRequest, RpcGetInlineViewOrderRequest, RpcGetFavoritesRequest, RpcSetFavoritesRequest, RpcGetFallbackOrderRequest, RpcSetFallbackOrderRequest, RpcGetProfilesRequest, RpcGetUsageStatisticsRequest, RpcExportUsageStatisticsRequest, RpcImportFromLauncherRequest, RpcImportSettingsRequest, RpcIsManagedRequest, RpcGetConfigRevisionRequest, RpcOpenEntrypointRequest, RpcPingRequest, RpcPluginLogStream, RpcPluginLogsRequest, RpcPluginPreference, RpcPluginsRequest, RpcRemovePluginRequest, RpcRunEntrypointRequest, RpcSaveLocalPluginRequest, RpcSetActionShortcutRequest, RpcSetActiveProfileRequest, RpcSetDiagnosticsEnabledRequest, RpcSetEntrypointHiddenRequest, RpcSetEntrypointStateRequest, RpcSetGeneralSettingsRequest, RpcSetGlobalShortcutRequest, RpcSetInlineViewOrderRequest, RpcSetPluginStateRequest, RpcSetPreferenceValueRequest, RpcShortcut, RpcShowSettingsWindowRequest, RpcShowWindowRequest, RpcToggleWindowRequest, RpcQuitRequest, RpcUpdateEntrypointRequest};
 use crate::rpc::grpc::rpc_backend_client::RpcBackendClient;
-use crate::rpc::grpc_convert::{plugin_preference_from_rpc, plugin_preference_user_data_from_rpc, plugin_preference_user_data_to_rpc};
+use crate::rpc::grpc_convert::{action_shortcut_conflict_from_rpc, entrypoint_action_from_rpc, entrypoint_key_to_rpc, entrypoint_reference_from_rpc, general_settings_from_rpc, general_settings_to_rpc, import_item_from_rpc, import_source_to_rpc, macro_from_rpc, macro_to_rpc, plugin_permission_kind_to_rpc, plugin_permissions_from_rpc, plugin_preference_from_rpc, plugin_preference_user_data_from_rpc, plugin_preference_user_data_to_rpc, shortcut_to_rpc, simple_theme_from_rpc, simple_theme_to_rpc, usage_statistics_from_rpc};
+use crate::rpc::{normalize_protocol_version, LEGACY_PROTOCOL_VERSION, MIN_SUPPORTED_PROTOCOL_VERSION, PROTOCOL_VERSION};
 
 #[derive(Error, Debug, Clone)]
 pub enum BackendForFrontendApiError {
     #[error("Frontend wasn't able to process request in a timely manner")]
     TimeoutError,
-    #[error("Internal Error: {display:?}")]
+    #[error("Internal Error: {error:#}")]
     Internal {
-        display: String
+        error: GauntletError
     },
 }
 
@@ -24,68 +28,214 @@ impl From<RequestError> for BackendForFrontendApiError {
     fn from(error: RequestError) -> BackendForFrontendApiError {
         match error {
             RequestError::TimeoutError => BackendForFrontendApiError::TimeoutError,
-            RequestError::OtherSideWasDropped => BackendForFrontendApiError::Internal { display: "other side was dropped".to_string() }
+            RequestError::OtherSideWasDropped => BackendForFrontendApiError::Internal { error: GauntletError::internal("other side was dropped") }
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BackendRequestKind {
+    Search,
+    View,
+    Event,
+    Command,
+}
+
+impl BackendRequestKind {
+    fn of(request: &BackendRequestData) -> Self {
+        match request {
+            BackendRequestData::Search { .. } => BackendRequestKind::Search,
+            BackendRequestData::RequestArgumentSuggestions { .. } => BackendRequestKind::Search,
+            BackendRequestData::RequestSearchResultPreview { .. } => BackendRequestKind::Search,
+            BackendRequestData::RequestViewRender { .. } => BackendRequestKind::View,
+            BackendRequestData::RequestViewClose { .. } => BackendRequestKind::View,
+            BackendRequestData::RequestViewPop { .. } => BackendRequestKind::View,
+            BackendRequestData::RequestViewVisibility { .. } => BackendRequestKind::View,
+            BackendRequestData::WindowVisibility { .. } => BackendRequestKind::View,
+            BackendRequestData::InlineViewShortcuts => BackendRequestKind::View,
+            BackendRequestData::InlineViewOrder => BackendRequestKind::View,
+            BackendRequestData::GetImages { .. } => BackendRequestKind::View,
+            BackendRequestData::SendViewEvent { .. } => BackendRequestKind::Event,
+            BackendRequestData::SendKeyboardEvent { .. } => BackendRequestKind::Event,
+            BackendRequestData::SendOpenEvent { .. } => BackendRequestKind::Event,
+            BackendRequestData::RequestRunCommand { .. } => BackendRequestKind::Command,
+            BackendRequestData::RequestRunGeneratedCommand { .. } => BackendRequestKind::Command,
+            BackendRequestData::OpenSettingsWindow => BackendRequestKind::Command,
+            BackendRequestData::OpenSettingsWindowPreferences { .. } => BackendRequestKind::Command,
+            BackendRequestData::CopyToClipboard { .. } => BackendRequestKind::Command,
+            BackendRequestData::SetSearchResultAliases { .. } => BackendRequestKind::Command,
+            BackendRequestData::SaveBugReport { .. } => BackendRequestKind::Command,
+            BackendRequestData::Undo => BackendRequestKind::Command,
+            BackendRequestData::Redo => BackendRequestKind::Command,
+        }
+    }
+}
+
+// request can be sent again if it timed out without changing the outcome,
+// e.g. view close is not, because it would also close the view that was shown after it
+fn is_idempotent(request: &BackendRequestData) -> bool {
+    match request {
+        BackendRequestData::Search { .. } => true,
+        BackendRequestData::RequestArgumentSuggestions { .. } => true,
+        BackendRequestData::RequestSearchResultPreview { .. } => true,
+        BackendRequestData::RequestViewRender { .. } => true,
+        BackendRequestData::RequestViewVisibility { .. } => true,
+        BackendRequestData::WindowVisibility { .. } => true,
+        BackendRequestData::InlineViewShortcuts => true,
+        BackendRequestData::InlineViewOrder => true,
+        BackendRequestData::GetImages { .. } => true,
+        _ => false
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BackendForFrontendApi {
-    backend_sender: RequestSender<BackendRequestData, BackendResponseData>
+    backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
+    config: BackendRequestsConfig,
 }
 
 impl BackendForFrontendApi {
     pub fn new(backend_sender: RequestSender<BackendRequestData, BackendResponseData>) -> Self {
+        Self::with_config(backend_sender, BackendRequestsConfig::default())
+    }
+
+    pub fn with_config(backend_sender: RequestSender<BackendRequestData, BackendResponseData>, config: BackendRequestsConfig) -> Self {
         Self {
-            backend_sender
+            backend_sender,
+            config,
+        }
+    }
+
+    async fn send_receive(&self, request: BackendRequestData) -> Result<BackendResponseData, BackendForFrontendApiError> {
+        let kind = BackendRequestKind::of(&request);
+
+        let timeout_ms = match kind {
+            BackendRequestKind::Search => self.config.search_timeout_ms,
+            BackendRequestKind::View => self.config.view_timeout_ms,
+            BackendRequestKind::Event => self.config.event_timeout_ms,
+            BackendRequestKind::Command => self.config.command_timeout_ms,
+        };
+        let timeout = Duration::from_millis(timeout_ms);
+
+        let retries = if is_idempotent(&request) { self.config.retries } else { 0 };
+
+        let mut backoff = Duration::from_millis(self.config.retry_backoff_ms);
+        let mut attempt = 0;
+
+        loop {
+            match self.backend_sender.send_receive_timeout(request.clone(), timeout).await {
+                Err(RequestError::TimeoutError) if attempt < retries => {
+                    attempt += 1;
+
+                    tracing::warn!("Backend request {:?} timed out, retrying in {:?}, attempt {} of {}", kind, backoff, attempt, retries);
+
+                    tokio::time::sleep(backoff).await;
+
+                    backoff = backoff * 2;
+                }
+                Ok(BackendResponseData::Error { error }) => return Err(BackendForFrontendApiError::Internal { error }),
+                result => return Ok(result?),
+            }
         }
     }
 
-    pub async fn search(&mut self, text: String, render_inline_view: bool) -> Result<Vec<SearchResult>, BackendForFrontendApiError> {
+    pub async fn search(
+        &mut self,
+        text: String,
+        keyword_plugin_id: Option<PluginId>,
+        category: Option<SearchResultCategory>,
+        render_inline_view: bool,
+        generation: u64
+    ) -> Result<(Vec<SearchResult>, Option<SearchKeywordMode>, Option<SearchResultCategory>), BackendForFrontendApiError> {
         let request = BackendRequestData::Search {
             text,
+            keyword_plugin_id,
+            category,
             render_inline_view,
+            generation,
         };
 
-        let BackendResponseData::Search { results } = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::Search { results, keyword_mode, category } = self.send_receive(request).await? else {
             unreachable!()
         };
 
-        Ok(results)
+        Ok((results, keyword_mode, category))
     }
 
-    pub async fn request_view_render(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Result<HashMap<String, PhysicalShortcut>, BackendForFrontendApiError> {
+    pub async fn request_view_render(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Result<(HashMap<String, PhysicalShortcut>, bool, PluginStyle), BackendForFrontendApiError> {
         let request = BackendRequestData::RequestViewRender {
             plugin_id,
             entrypoint_id,
         };
 
-        let BackendResponseData::RequestViewRender { shortcuts } = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::RequestViewRender { shortcuts, interactive, style } = self.send_receive(request).await? else {
             unreachable!()
         };
 
-        Ok(shortcuts)
+        Ok((shortcuts, interactive, style))
     }
 
-    pub async fn request_view_close(&mut self, plugin_id: PluginId) -> Result<(), BackendForFrontendApiError> {
+    pub async fn request_view_close(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Result<(), BackendForFrontendApiError> {
         let request = BackendRequestData::RequestViewClose {
             plugin_id,
+            entrypoint_id,
+        };
+
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn request_view_visibility(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, visible: bool) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::RequestViewVisibility {
+            plugin_id,
+            entrypoint_id,
+            visible,
+        };
+
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn window_visibility(&mut self, visible: bool, active_window: Option<ActiveWindowContext>) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::WindowVisibility {
+            visible,
+            active_window,
+        };
+
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn request_view_pop(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::RequestViewPop {
+            plugin_id,
+            entrypoint_id,
         };
 
-        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
             unreachable!()
         };
 
         Ok(())
     }
 
-    pub async fn request_run_command(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Result<(), BackendForFrontendApiError> {
+    pub async fn request_run_command(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: HashMap<String, EntrypointArgumentValue>) -> Result<(), BackendForFrontendApiError> {
         let request = BackendRequestData::RequestRunCommand {
             plugin_id,
             entrypoint_id,
+            arguments,
         };
 
-        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
             unreachable!()
         };
 
@@ -99,7 +249,38 @@ impl BackendForFrontendApi {
             action_index,
         };
 
-        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    // suggestions are delivered separately by frontend api when plugin provides them
+    pub async fn request_argument_suggestions(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, argument_id: String, values: HashMap<String, String>, generation: u64) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::RequestArgumentSuggestions {
+            plugin_id,
+            entrypoint_id,
+            argument_id,
+            values,
+            generation,
+        };
+
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    // preview is delivered separately by frontend api when plugin provides it
+    pub async fn request_search_result_preview(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::RequestSearchResultPreview {
+            plugin_id,
+            entrypoint_id,
+        };
+
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
             unreachable!()
         };
 
@@ -109,18 +290,22 @@ impl BackendForFrontendApi {
     pub async fn send_view_event(
         &mut self,
         plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        render_location: UiRenderLocation,
         widget_id: UiWidgetId,
         event_name: String,
         event_arguments: Vec<UiPropertyValue>
     ) -> Result<(), BackendForFrontendApiError> {
         let request = BackendRequestData::SendViewEvent {
             plugin_id,
+            entrypoint_id,
+            render_location,
             widget_id,
             event_name,
             event_arguments,
         };
 
-        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
             unreachable!()
         };
 
@@ -149,7 +334,7 @@ impl BackendForFrontendApi {
             modifier_meta,
         };
 
-        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
             unreachable!()
         };
 
@@ -162,7 +347,7 @@ impl BackendForFrontendApi {
             href,
         };
 
-        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
             unreachable!()
         };
 
@@ -172,7 +357,68 @@ impl BackendForFrontendApi {
     pub async fn open_settings_window(&mut self, ) -> Result<(), BackendForFrontendApiError> {
         let request = BackendRequestData::OpenSettingsWindow;
 
-        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn copy_to_clipboard(&mut self, text: String) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::CopyToClipboard {
+            text,
+        };
+
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn set_search_result_aliases(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, aliases: Vec<String>) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::SetSearchResultAliases {
+            plugin_id,
+            entrypoint_id,
+            aliases,
+        };
+
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn save_bug_report(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, widget_tree: String, screenshot: Vec<u8>) -> Result<String, BackendForFrontendApiError> {
+        let request = BackendRequestData::SaveBugReport {
+            plugin_id,
+            entrypoint_id,
+            widget_tree,
+            screenshot,
+        };
+
+        let BackendResponseData::BugReport { path } = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(path)
+    }
+
+    pub async fn undo(&mut self) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::Undo;
+
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn redo(&mut self) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::Redo;
+
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
             unreachable!()
         };
 
@@ -185,7 +431,7 @@ impl BackendForFrontendApi {
             entrypoint_id,
         };
 
-        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::Nothing = self.send_receive(request).await? else {
             unreachable!()
         };
 
@@ -195,21 +441,51 @@ impl BackendForFrontendApi {
     pub async fn inline_view_shortcuts(&self) -> Result<HashMap<PluginId, HashMap<String, PhysicalShortcut>>, BackendForFrontendApiError> {
         let request = BackendRequestData::InlineViewShortcuts;
 
-        let BackendResponseData::InlineViewShortcuts { shortcuts } = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::InlineViewShortcuts { shortcuts } = self.send_receive(request).await? else {
             unreachable!()
         };
 
         Ok(shortcuts)
     }
+
+    pub async fn inline_view_order(&self) -> Result<Vec<PluginId>, BackendForFrontendApiError> {
+        let request = BackendRequestData::InlineViewOrder;
+
+        let BackendResponseData::InlineViewOrder { plugin_ids } = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(plugin_ids)
+    }
+
+    pub async fn get_images(&self, image_ids: Vec<UiImageId>) -> Result<HashMap<UiImageId, bytes::Bytes>, BackendForFrontendApiError> {
+        let request = BackendRequestData::GetImages { image_ids };
+
+        let BackendResponseData::Images { images } = self.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(images)
+    }
 }
 
 #[derive(Error, Debug, Clone)]
 pub enum BackendApiError {
     #[error("Timeout Error")]
     Timeout,
-    #[error("Internal Backend Error: {display:?}")]
+    #[error("Internal Backend Error: {error:#}")]
     Internal {
-        display: String
+        error: GauntletError
+    },
+    #[error("Incompatible Backend Version: client protocol version is {client_version}, server protocol version is {server_version}. Please restart Gauntlet after update")]
+    IncompatibleVersion {
+        client_version: u32,
+        server_version: u32,
+    },
+    #[error("Running Gauntlet server is too old to support this request, it requires protocol version {required_version} but server has {server_version}. Please restart Gauntlet after update")]
+    UnsupportedByServer {
+        required_version: u32,
+        server_version: u32,
     },
 }
 
@@ -218,8 +494,10 @@ impl From<tonic::Status> for BackendApiError {
         match error.code() {
             Code::Ok => unreachable!(),
             Code::DeadlineExceeded => BackendApiError::Timeout,
+            // server older than structured errors only sends the message
             _ => BackendApiError::Internal {
-                display: format!("{}", error)
+                error: GauntletError::decode(error.details())
+                    .unwrap_or_else(|| GauntletError::internal(error.message()))
             }
         }
 
@@ -229,26 +507,71 @@ impl From<tonic::Status> for BackendApiError {
 impl From<prost::UnknownEnumValue> for BackendApiError {
     fn from(error: prost::UnknownEnumValue) -> BackendApiError {
         BackendApiError::Internal {
-            display: format!("{}", error)
+            error: GauntletError::internal(format!("{}", error))
         }
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct BackendApi {
-    client: RpcBackendClient<Channel>
+    client: RpcBackendClient<Channel>,
+    server_protocol_version: u32,
 }
 
 impl BackendApi {
     pub async fn new() -> anyhow::Result<Self> {
-        Ok(Self {
-            client: RpcBackendClient::connect("http://127.0.0.1:42320").await?
-        })
+        let mut backend_api = Self {
+            client: RpcBackendClient::connect("http://127.0.0.1:42320").await?,
+            server_protocol_version: LEGACY_PROTOCOL_VERSION,
+        };
+
+        backend_api.ping().await?;
+
+        Ok(backend_api)
+    }
+
+    pub fn server_protocol_version(&self) -> u32 {
+        self.server_protocol_version
+    }
+
+    fn require_server_protocol_version(&self, required_version: u32) -> Result<(), BackendApiError> {
+        if self.server_protocol_version < required_version {
+            Err(BackendApiError::UnsupportedByServer {
+                required_version,
+                server_version: self.server_protocol_version,
+            })
+        } else {
+            Ok(())
+        }
     }
 
     pub async fn ping(&mut self) -> Result<(), BackendApiError> {
-        let _ = self.client.ping(Request::new(RpcPingRequest::default()))
-            .await?;
+        let request = RpcPingRequest {
+            protocol_version: PROTOCOL_VERSION,
+        };
+
+        let response = self.client.ping(Request::new(request))
+            .await
+            .map_err(|err| match err.code() {
+                Code::FailedPrecondition => BackendApiError::IncompatibleVersion {
+                    client_version: PROTOCOL_VERSION,
+                    server_version: self.server_protocol_version,
+                },
+                _ => err.into()
+            })?
+            .into_inner();
+
+        let server_version = normalize_protocol_version(response.protocol_version);
+        let server_min_supported_version = normalize_protocol_version(response.min_supported_protocol_version);
+
+        if server_version < MIN_SUPPORTED_PROTOCOL_VERSION || PROTOCOL_VERSION < server_min_supported_version {
+            return Err(BackendApiError::IncompatibleVersion {
+                client_version: PROTOCOL_VERSION,
+                server_version,
+            })
+        }
+
+        self.server_protocol_version = server_version;
 
         Ok(())
     }
@@ -267,7 +590,65 @@ impl BackendApi {
         Ok(())
     }
 
+    pub async fn toggle_window(&mut self) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(3)?;
+
+        let _ = self.client.toggle_window(Request::new(RpcToggleWindowRequest::default()))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn quit(&mut self) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(23)?;
+
+        let _ = self.client.quit(Request::new(RpcQuitRequest::default()))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn open_entrypoint(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(3)?;
+
+        let request = RpcOpenEntrypointRequest {
+            plugin_id: plugin_id.to_string(),
+            entrypoint_id: entrypoint_id.to_string(),
+        };
+
+        self.client.open_entrypoint(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn run_entrypoint(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: Vec<String>) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(3)?;
+
+        let request = RpcRunEntrypointRequest {
+            plugin_id: plugin_id.to_string(),
+            entrypoint_id: entrypoint_id.to_string(),
+            arguments,
+        };
+
+        self.client.run_entrypoint(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn plugins(&mut self) -> Result<HashMap<PluginId, SettingsPlugin>, BackendApiError> {
+        let server_protocol_version = self.server_protocol_version;
+
+        // slider is shown only for number preferences which have step
+        let preference_from_rpc = |mut value: RpcPluginPreference| {
+            if server_protocol_version < 29 {
+                value.step = None;
+            }
+
+            plugin_preference_from_rpc(value)
+        };
+
         let plugins = self.client.plugins(Request::new(RpcPluginsRequest::default()))
             .await?
             .into_inner()
@@ -290,16 +671,22 @@ impl BackendApi {
 
                         let entrypoint = SettingsEntrypoint {
                             enabled: entrypoint.enabled,
+                            hidden: entrypoint.hidden,
                             entrypoint_id: id.clone(),
                             entrypoint_name: entrypoint.entrypoint_name.clone(),
                             entrypoint_description: entrypoint.entrypoint_description,
                             entrypoint_type,
                             preferences: entrypoint.preferences.into_iter()
-                                .map(|(key, value)| (key, plugin_preference_from_rpc(value)))
+                                .map(|(key, value)| (key, preference_from_rpc(value)))
                                 .collect(),
                             preferences_user_data: entrypoint.preferences_user_data.into_iter()
                                 .map(|(key, value)| (key, plugin_preference_user_data_from_rpc(value)))
                                 .collect(),
+                            actions: entrypoint.actions.into_iter()
+                                .map(|action| entrypoint_action_from_rpc(action))
+                                .collect(),
+                            aliases: entrypoint.aliases,
+                            favorite: entrypoint.favorite,
                         };
                         (id, entrypoint)
                     })
@@ -313,7 +700,7 @@ impl BackendApi {
                     enabled: plugin.enabled,
                     entrypoints,
                     preferences: plugin.preferences.into_iter()
-                        .map(|(key, value)| (key, plugin_preference_from_rpc(value)))
+                        .map(|(key, value)| (key, preference_from_rpc(value)))
                         .collect(),
                     preferences_user_data: plugin.preferences_user_data.into_iter()
                         .map(|(key, value)| (key, plugin_preference_user_data_from_rpc(value)))
@@ -352,6 +739,43 @@ impl BackendApi {
         Ok(())
     }
 
+    pub async fn set_entrypoint_hidden(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, hidden: bool) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(5)?;
+
+        let request = RpcSetEntrypointHiddenRequest {
+            plugin_id: plugin_id.to_string(),
+            entrypoint_id: entrypoint_id.to_string(),
+            hidden,
+        };
+
+        self.client.set_entrypoint_hidden(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_entrypoint(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, update: SettingsEntrypointUpdate) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(12)?;
+
+        if update.favorite.is_some() {
+            self.require_server_protocol_version(19)?;
+        }
+
+        let request = RpcUpdateEntrypointRequest {
+            plugin_id: plugin_id.to_string(),
+            entrypoint_id: entrypoint_id.to_string(),
+            enabled: update.enabled,
+            hidden: update.hidden,
+            aliases: update.aliases.map(|aliases| RpcEntrypointAliases { aliases }),
+            favorite: update.favorite,
+        };
+
+        self.client.update_entrypoint(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn set_global_shortcut(&mut self, shortcut: Option<PhysicalShortcut>) -> Result<(), BackendApiError> {
         let request = RpcSetGlobalShortcutRequest {
             shortcut: shortcut.map(|shortcut| {
@@ -392,6 +816,123 @@ impl BackendApi {
         ))
     }
 
+    // returns conflict if shortcut cannot be used, in that case it is not saved
+    pub async fn set_action_shortcut(
+        &mut self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        action_id: String,
+        shortcut: Option<PhysicalShortcut>
+    ) -> Result<Option<ActionShortcutConflict>, BackendApiError> {
+        self.require_server_protocol_version(11)?;
+
+        let request = RpcSetActionShortcutRequest {
+            plugin_id: plugin_id.to_string(),
+            entrypoint_id: entrypoint_id.to_string(),
+            action_id,
+            shortcut: shortcut.map(|shortcut| shortcut_to_rpc(shortcut)),
+        };
+
+        let response = self.client.set_action_shortcut(Request::new(request))
+            .await?
+            .into_inner();
+
+        Ok(response.conflict.map(|conflict| action_shortcut_conflict_from_rpc(conflict)))
+    }
+
+    pub async fn get_inline_view_order(&mut self) -> Result<Vec<SettingsInlineView>, BackendApiError> {
+        self.require_server_protocol_version(4)?;
+
+        let inline_views = self.client.get_inline_view_order(Request::new(RpcGetInlineViewOrderRequest::default()))
+            .await?
+            .into_inner()
+            .inline_views
+            .into_iter()
+            .map(|inline_view| SettingsInlineView {
+                plugin_id: PluginId::from_string(inline_view.plugin_id),
+                plugin_name: inline_view.plugin_name,
+                entrypoint_id: EntrypointId::from_string(inline_view.entrypoint_id),
+                entrypoint_name: inline_view.entrypoint_name,
+                enabled: inline_view.enabled,
+            })
+            .collect();
+
+        Ok(inline_views)
+    }
+
+    pub async fn set_inline_view_order(&mut self, plugin_ids: Vec<PluginId>) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(4)?;
+
+        let request = RpcSetInlineViewOrderRequest {
+            plugin_ids: plugin_ids.into_iter()
+                .map(|plugin_id| plugin_id.to_string())
+                .collect(),
+        };
+
+        self.client.set_inline_view_order(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_favorites(&mut self) -> Result<Vec<SettingsEntrypointReference>, BackendApiError> {
+        self.require_server_protocol_version(19)?;
+
+        let favorites = self.client.get_favorites(Request::new(RpcGetFavoritesRequest::default()))
+            .await?
+            .into_inner()
+            .favorites
+            .into_iter()
+            .map(|favorite| entrypoint_reference_from_rpc(favorite))
+            .collect();
+
+        Ok(favorites)
+    }
+
+    pub async fn set_favorites(&mut self, favorites: Vec<(PluginId, EntrypointId)>) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(19)?;
+
+        let request = RpcSetFavoritesRequest {
+            favorites: favorites.into_iter()
+                .map(|favorite| entrypoint_key_to_rpc(favorite))
+                .collect(),
+        };
+
+        self.client.set_favorites(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_fallback_order(&mut self) -> Result<Vec<SettingsEntrypointReference>, BackendApiError> {
+        self.require_server_protocol_version(19)?;
+
+        let fallbacks = self.client.get_fallback_order(Request::new(RpcGetFallbackOrderRequest::default()))
+            .await?
+            .into_inner()
+            .fallbacks
+            .into_iter()
+            .map(|fallback| entrypoint_reference_from_rpc(fallback))
+            .collect();
+
+        Ok(fallbacks)
+    }
+
+    pub async fn set_fallback_order(&mut self, fallbacks: Vec<(PluginId, EntrypointId)>) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(19)?;
+
+        let request = RpcSetFallbackOrderRequest {
+            fallbacks: fallbacks.into_iter()
+                .map(|fallback| entrypoint_key_to_rpc(fallback))
+                .collect(),
+        };
+
+        self.client.set_fallback_order(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn set_preference_value(&mut self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>, id: String, user_data: PluginPreferenceUserData) -> Result<(), BackendApiError> {
         let request = RpcSetPreferenceValueRequest {
             plugin_id: plugin_id.to_string(),
@@ -439,8 +980,12 @@ impl BackendApi {
         Ok(plugins)
     }
 
-    pub async fn remove_plugin(&mut self, plugin_id: PluginId) -> Result<(), BackendApiError> {
-        let request = RpcRemovePluginRequest { plugin_id: plugin_id.to_string() };
+    pub async fn remove_plugin(&mut self, plugin_id: PluginId, purge_data: bool) -> Result<(), BackendApiError> {
+        if purge_data {
+            self.require_server_protocol_version(20)?;
+        }
+
+        let request = RpcRemovePluginRequest { plugin_id: plugin_id.to_string(), purge_data };
 
         self.client.remove_plugin(Request::new(request))
             .await?;
@@ -448,6 +993,403 @@ impl BackendApi {
         Ok(())
     }
 
+    pub async fn plugin_logs(&mut self, plugin_id: PluginId, stdout_offset: u64, stderr_offset: u64) -> Result<PluginLogs, BackendApiError> {
+        self.require_server_protocol_version(7)?;
+
+        let request = RpcPluginLogsRequest {
+            plugin_id: plugin_id.to_string(),
+            stdout_offset,
+            stderr_offset,
+        };
+
+        let response = self.client.plugin_logs(Request::new(request))
+            .await?
+            .into_inner();
+
+        let lines = response.lines
+            .into_iter()
+            .map(|line| {
+                let stream = match line.stream.try_into()? {
+                    RpcPluginLogStream::Stdout => PluginLogStream::Stdout,
+                    RpcPluginLogStream::Stderr => PluginLogStream::Stderr,
+                };
+
+                Ok::<PluginLogLine, BackendApiError>(PluginLogLine { stream, text: line.text })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PluginLogs {
+            lines,
+            stdout_offset: response.stdout_offset,
+            stderr_offset: response.stderr_offset,
+            reset: response.reset,
+        })
+    }
+
+    pub async fn get_diagnostics(&mut self) -> Result<DiagnosticsData, BackendApiError> {
+        self.require_server_protocol_version(8)?;
+
+        let response = self.client.get_diagnostics(Request::new(RpcGetDiagnosticsRequest::default()))
+            .await?
+            .into_inner();
+
+        let entries = response.entries
+            .into_iter()
+            .map(|entry| {
+                let kind = match entry.kind.try_into()? {
+                    RpcDiagnosticsKind::Render => DiagnosticsKind::Render,
+                    RpcDiagnosticsKind::Search => DiagnosticsKind::Search,
+                    RpcDiagnosticsKind::Op => DiagnosticsKind::Op,
+                    RpcDiagnosticsKind::Ipc => DiagnosticsKind::Ipc,
+                };
+
+                Ok::<DiagnosticsEntry, BackendApiError>(DiagnosticsEntry {
+                    kind,
+                    plugin_id: entry.plugin_id.map(|plugin_id| PluginId::from_string(plugin_id)),
+                    name: entry.name,
+                    count: entry.count,
+                    total_duration: Duration::from_micros(entry.total_micros),
+                    max_duration: Duration::from_micros(entry.max_micros),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // older servers don't report image cache, diagnostics are still shown without it
+        let image_cache = response.image_cache
+            .filter(|_| self.require_server_protocol_version(30).is_ok())
+            .map(|image_cache| ImageCacheStats {
+                entries: image_cache.entries,
+                size_bytes: image_cache.size_bytes,
+                max_size_bytes: image_cache.max_size_bytes,
+                hits: image_cache.hits,
+                misses: image_cache.misses,
+                evictions: image_cache.evictions,
+            });
+
+        Ok(DiagnosticsData {
+            enabled: response.enabled,
+            entries,
+            image_cache,
+        })
+    }
+
+    pub async fn set_diagnostics_enabled(&mut self, enabled: bool) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(8)?;
+
+        let request = RpcSetDiagnosticsEnabledRequest { enabled };
+
+        self.client.set_diagnostics_enabled(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_config_error(&mut self) -> Result<Option<ConfigError>, BackendApiError> {
+        self.require_server_protocol_version(9)?;
+
+        let response = self.client.get_config_error(Request::new(RpcGetConfigErrorRequest::default()))
+            .await?
+            .into_inner();
+
+        let config_error = response.error.map(|error| ConfigError {
+            config_file: error.config_file,
+            message: error.message,
+            line: error.line,
+            column: error.column,
+        });
+
+        Ok(config_error)
+    }
+
+    pub async fn is_managed(&mut self) -> Result<bool, BackendApiError> {
+        self.require_server_protocol_version(17)?;
+
+        let response = self.client.is_managed(Request::new(RpcIsManagedRequest::default()))
+            .await?
+            .into_inner();
+
+        Ok(response.managed)
+    }
+
+    pub async fn get_config_revision(&mut self) -> Result<u64, BackendApiError> {
+        self.require_server_protocol_version(22)?;
+
+        let response = self.client.get_config_revision(Request::new(RpcGetConfigRevisionRequest::default()))
+            .await?
+            .into_inner();
+
+        Ok(response.revision)
+    }
+
+    pub async fn get_general_settings(&mut self) -> Result<GeneralSettings, BackendApiError> {
+        self.require_server_protocol_version(13)?;
+
+        let response = self.client.get_general_settings(Request::new(RpcGetGeneralSettingsRequest::default()))
+            .await?
+            .into_inner();
+
+        Ok(general_settings_from_rpc(response.settings.unwrap_or_default(), &GeneralSettings::default()))
+    }
+
+    pub async fn set_general_settings(&mut self, settings: GeneralSettings) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(13)?;
+
+        let mut settings = general_settings_to_rpc(settings);
+
+        // older servers don't have these settings
+        if self.server_protocol_version < 26 {
+            settings.hide_window_on_focus_loss = None;
+        }
+
+        if self.server_protocol_version < 27 {
+            settings.sound_muted = None;
+        }
+
+        if self.server_protocol_version < 28 {
+            settings.reduced_motion = None;
+            settings.high_contrast = None;
+        }
+
+        let request = RpcSetGeneralSettingsRequest {
+            settings: Some(settings),
+        };
+
+        self.client.set_general_settings(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn check_for_updates(&mut self) -> Result<Option<AvailableUpdate>, BackendApiError> {
+        self.require_server_protocol_version(14)?;
+
+        let response = self.client.check_for_updates(Request::new(RpcCheckForUpdatesRequest::default()))
+            .await?
+            .into_inner();
+
+        let update = response.update
+            .map(|update| AvailableUpdate {
+                version: update.version as u16,
+                url: update.url,
+            });
+
+        Ok(update)
+    }
+
+    pub async fn get_simple_theme(&mut self) -> Result<SimpleThemeTokens, BackendApiError> {
+        self.require_server_protocol_version(24)?;
+
+        let response = self.client.get_simple_theme(Request::new(RpcGetSimpleThemeRequest::default()))
+            .await?
+            .into_inner();
+
+        Ok(simple_theme_from_rpc(response.theme.unwrap_or_default()))
+    }
+
+    pub async fn preview_simple_theme(&mut self, theme: Option<SimpleThemeTokens>) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(24)?;
+
+        let request = RpcPreviewSimpleThemeRequest {
+            theme: theme.map(|theme| simple_theme_to_rpc(theme)),
+        };
+
+        self.client.preview_simple_theme(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn save_simple_theme(&mut self, name: String, theme: SimpleThemeTokens) -> Result<String, BackendApiError> {
+        self.require_server_protocol_version(24)?;
+
+        let request = RpcSaveSimpleThemeRequest {
+            name,
+            theme: Some(simple_theme_to_rpc(theme)),
+        };
+
+        let response = self.client.save_simple_theme(Request::new(request))
+            .await?
+            .into_inner();
+
+        Ok(response.path)
+    }
+
+    pub async fn export_settings(&mut self, path: String) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(10)?;
+
+        let request = RpcExportSettingsRequest { path };
+
+        self.client.export_settings(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn import_settings(&mut self, path: String) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(10)?;
+
+        let request = RpcImportSettingsRequest { path };
+
+        self.client.import_settings(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    // with dry_run nothing is written, returned items show what would be imported
+    pub async fn import_from_launcher(&mut self, source: ImportSource, path: String, dry_run: bool) -> Result<Vec<ImportItem>, BackendApiError> {
+        self.require_server_protocol_version(15)?;
+
+        let request = RpcImportFromLauncherRequest {
+            source: import_source_to_rpc(source).into(),
+            path,
+            dry_run,
+        };
+
+        let response = self.client.import_from_launcher(Request::new(request))
+            .await?
+            .into_inner();
+
+        let items = response.items
+            .into_iter()
+            .map(|item| import_item_from_rpc(item))
+            .collect();
+
+        Ok(items)
+    }
+
+    pub async fn get_profiles(&mut self) -> Result<Profiles, BackendApiError> {
+        self.require_server_protocol_version(16)?;
+
+        let response = self.client.get_profiles(Request::new(RpcGetProfilesRequest::default()))
+            .await?
+            .into_inner();
+
+        Ok(Profiles {
+            profiles: response.profiles,
+            current: response.current,
+            active: response.active,
+        })
+    }
+
+    // profile is created if it doesn't exist, applied after restart
+    pub async fn set_active_profile(&mut self, name: String) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(16)?;
+
+        let request = RpcSetActiveProfileRequest { name };
+
+        self.client.set_active_profile(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_usage_statistics(&mut self) -> Result<UsageStatistics, BackendApiError> {
+        self.require_server_protocol_version(18)?;
+
+        let response = self.client.get_usage_statistics(Request::new(RpcGetUsageStatisticsRequest::default()))
+            .await?
+            .into_inner();
+
+        Ok(usage_statistics_from_rpc(response))
+    }
+
+    // launches per entrypoint per day are written to csv file at given path
+    pub async fn export_usage_statistics(&mut self, path: String) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(18)?;
+
+        let request = RpcExportUsageStatisticsRequest { path };
+
+        self.client.export_usage_statistics(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_macros(&mut self) -> Result<Vec<Macro>, BackendApiError> {
+        self.require_server_protocol_version(21)?;
+
+        let macros = self.client.get_macros(Request::new(RpcGetMacrosRequest::default()))
+            .await?
+            .into_inner()
+            .macros
+            .into_iter()
+            .map(|item| macro_from_rpc(item))
+            .collect();
+
+        Ok(macros)
+    }
+
+    // macro with empty id is created, returns id of saved macro
+    pub async fn save_macro(&mut self, item: Macro) -> Result<String, BackendApiError> {
+        self.require_server_protocol_version(21)?;
+
+        let request = RpcSaveMacroRequest {
+            item: Some(macro_to_rpc(item)),
+        };
+
+        let response = self.client.save_macro(Request::new(request))
+            .await?
+            .into_inner();
+
+        Ok(response.id)
+    }
+
+    pub async fn remove_macro(&mut self, id: String) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(21)?;
+
+        let request = RpcRemoveMacroRequest { id };
+
+        self.client.remove_macro(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_macro_step_candidates(&mut self) -> Result<Vec<SettingsEntrypointReference>, BackendApiError> {
+        self.require_server_protocol_version(21)?;
+
+        let entrypoints = self.client.get_macro_step_candidates(Request::new(RpcGetMacroStepCandidatesRequest::default()))
+            .await?
+            .into_inner()
+            .entrypoints
+            .into_iter()
+            .map(|entrypoint| entrypoint_reference_from_rpc(entrypoint))
+            .collect();
+
+        Ok(entrypoints)
+    }
+
+    pub async fn get_plugin_permissions(&mut self) -> Result<Vec<SettingsPluginPermissions>, BackendApiError> {
+        self.require_server_protocol_version(25)?;
+
+        let plugins = self.client.get_plugin_permissions(Request::new(RpcGetPluginPermissionsRequest::default()))
+            .await?
+            .into_inner()
+            .plugins
+            .into_iter()
+            .map(|plugin| plugin_permissions_from_rpc(plugin))
+            .collect();
+
+        Ok(plugins)
+    }
+
+    pub async fn set_plugin_permission_revoked(&mut self, plugin_id: PluginId, kind: PluginPermissionKind, value: String, revoked: bool) -> Result<(), BackendApiError> {
+        self.require_server_protocol_version(25)?;
+
+        let request = RpcSetPluginPermissionRevokedRequest {
+            plugin_id: plugin_id.to_string(),
+            kind: plugin_permission_kind_to_rpc(kind).into(),
+            value,
+            revoked,
+        };
+
+        self.client.set_plugin_permission_revoked(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn save_local_plugin(&mut self, path: String) -> Result<LocalSaveData, BackendApiError> {
         let request = RpcSaveLocalPluginRequest { path };
 
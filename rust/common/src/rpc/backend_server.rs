@@ -1,15 +1,18 @@
 use std::collections::HashMap;
+use std::future::Future;
 use std::net::SocketAddr;
 use std::time::Duration;
 
 use tokio::net::TcpStream;
-use tonic::{Request, Response, Status};
+use tonic::{Code, Request, Response, Status};
 use tonic::transport::Server;
 
-use crate::model::{DownloadStatus, EntrypointId, LocalSaveData, PhysicalKey, PhysicalShortcut, PluginId, PluginPreferenceUserData, SettingsEntrypointType, SettingsPlugin};
-use crate::rpc::grpc::{RpcDownloadPluginRequest, RpcDownloadPluginResponse, RpcDownloadStatus, RpcDownloadStatusRequest, RpcDownloadStatusResponse, RpcDownloadStatusValue, RpcEntrypoint, RpcEntrypointTypeSettings, RpcGetGlobalShortcutRequest, RpcGetGlobalShortcutResponse, RpcPingRequest, RpcPingResponse, RpcPlugin, RpcPluginsRequest, RpcPluginsResponse, RpcRemovePluginRequest, RpcRemovePluginResponse, RpcSaveLocalPluginRequest, RpcSaveLocalPluginResponse, RpcSetEntrypointStateRequest, RpcSetEntrypointStateResponse, RpcSetGlobalShortcutRequest, RpcSetGlobalShortcutResponse, RpcSetPluginStateRequest, RpcSetPluginStateResponse, RpcSetPreferenceValueRequest, RpcSetPreferenceValueResponse, RpcShortcut, RpcShowSettingsWindowRequest, RpcShowSettingsWindowResponse, RpcShowWindowRequest, RpcShowWindowResponse};
+use crate::error::{ErrorCode, GauntletError};
+use crate::model::{ActionShortcutConflict, AvailableUpdate, ConfigError, DiagnosticsData, DiagnosticsKind, DownloadStatus, EntrypointId, GeneralSettings, ImportItem, ImportSource, LocalSaveData, Macro, PluginPermissionKind, PhysicalKey, PhysicalShortcut, PluginId, PluginLogStream, PluginLogs, PluginPreferenceUserData, Profiles, SettingsEntrypointType, SettingsEntrypointReference, SettingsEntrypointUpdate, SettingsInlineView, SettingsPlugin, SettingsPluginPermissions, SimpleThemeTokens, UsageStatistics};
+use crate::rpc::grpc::{RpcAvailableUpdate, RpcGetMacrosRequest, RpcGetMacrosResponse, RpcSaveMacroRequest, RpcSaveMacroResponse, RpcRemoveMacroRequest, RpcRemoveMacroResponse, RpcGetMacroStepCandidatesRequest, RpcGetMacroStepCandidatesResponse, RpcGetPluginPermissionsRequest, RpcGetPluginPermissionsResponse, RpcSetPluginPermissionRevokedRequest, RpcSetPluginPermissionRevokedResponse, RpcCheckForUpdatesRequest, RpcCheckForUpdatesResponse, RpcGetSimpleThemeRequest, RpcGetSimpleThemeResponse, RpcPreviewSimpleThemeRequest, RpcPreviewSimpleThemeResponse, RpcSaveSimpleThemeRequest, RpcSaveSimpleThemeResponse, RpcConfigError, RpcDiagnosticsEntry, RpcDiagnosticsKind, RpcDownloadPluginRequest, RpcDownloadPluginResponse, RpcDownloadStatus, RpcDownloadStatusRequest, RpcDownloadStatusResponse, RpcDownloadStatusValue, RpcEntrypoint, RpcEntrypointTypeSettings, RpcExportSettingsRequest, RpcExportSettingsResponse, RpcGetConfigErrorRequest, RpcGetConfigErrorResponse, RpcGetDiagnosticsRequest, RpcGetDiagnosticsResponse, RpcImageCacheStats, RpcGetGeneralSettingsRequest, RpcGetGeneralSettingsResponse, RpcGetGlobalShortcutRequest, RpcGetGlobalShortcutResponse, RpcGetInlineViewOrderRequest, RpcGetInlineViewOrderResponse, RpcGetFavoritesRequest, RpcGetFavoritesResponse, RpcSetFavoritesRequest, RpcSetFavoritesResponse, RpcGetFallbackOrderRequest, RpcGetFallbackOrderResponse, RpcSetFallbackOrderRequest, RpcSetFallbackOrderResponse, RpcGetProfilesRequest, RpcGetProfilesResponse, RpcGetUsageStatisticsRequest, RpcGetUsageStatisticsResponse, RpcExportUsageStatisticsRequest, RpcExportUsageStatisticsResponse, RpcImportFromLauncherRequest, RpcImportFromLauncherResponse, RpcImportSettingsRequest, RpcImportSettingsResponse, RpcInlineView, RpcIsManagedRequest, RpcIsManagedResponse, RpcGetConfigRevisionRequest, RpcGetConfigRevisionResponse, RpcOpenEntrypointRequest, RpcOpenEntrypointResponse, RpcPingRequest, RpcPingResponse, RpcPlugin, RpcPluginLogLine, RpcPluginLogStream, RpcPluginLogsRequest, RpcPluginLogsResponse, RpcPluginsRequest, RpcPluginsResponse, RpcRemovePluginRequest, RpcRemovePluginResponse, RpcRunEntrypointRequest, RpcRunEntrypointResponse, RpcSaveLocalPluginRequest, RpcSaveLocalPluginResponse, RpcSetDiagnosticsEnabledRequest, RpcSetDiagnosticsEnabledResponse, RpcSetEntrypointHiddenRequest, RpcSetEntrypointHiddenResponse, RpcSetEntrypointStateRequest, RpcSetEntrypointStateResponse, RpcSetGeneralSettingsRequest, RpcSetGeneralSettingsResponse, RpcSetGlobalShortcutRequest, RpcSetGlobalShortcutResponse, RpcSetInlineViewOrderRequest, RpcSetInlineViewOrderResponse, RpcSetPluginStateRequest, RpcSetPluginStateResponse, RpcSetActionShortcutRequest, RpcSetActionShortcutResponse, RpcSetPreferenceValueRequest, RpcSetPreferenceValueResponse, RpcSetActiveProfileRequest, RpcSetActiveProfileResponse, RpcShortcut, RpcShowSettingsWindowRequest, RpcShowSettingsWindowResponse, RpcShowWindowRequest, RpcShowWindowResponse, RpcToggleWindowRequest, RpcToggleWindowResponse, RpcQuitRequest, RpcQuitResponse, RpcUpdateEntrypointRequest, RpcUpdateEntrypointResponse};
 use crate::rpc::grpc::rpc_backend_server::{RpcBackend, RpcBackendServer};
-use crate::rpc::grpc_convert::{plugin_preference_to_rpc, plugin_preference_user_data_from_rpc, plugin_preference_user_data_to_rpc};
+use crate::rpc::grpc_convert::{action_shortcut_conflict_to_rpc, entrypoint_action_to_rpc, entrypoint_key_from_rpc, entrypoint_reference_to_rpc, general_settings_from_rpc, general_settings_to_rpc, import_item_to_rpc, import_source_from_rpc, macro_from_rpc, macro_to_rpc, plugin_permission_kind_from_rpc, plugin_permissions_to_rpc, plugin_preference_to_rpc, plugin_preference_user_data_from_rpc, plugin_preference_user_data_to_rpc, shortcut_from_rpc, simple_theme_from_rpc, simple_theme_to_rpc, usage_statistics_to_rpc};
+use crate::rpc::{normalize_protocol_version, MIN_SUPPORTED_PROTOCOL_VERSION, PROTOCOL_VERSION};
 
 pub async fn wait_for_backend_server() {
     loop {
@@ -23,16 +26,31 @@ pub async fn wait_for_backend_server() {
     }
 }
 
-pub async fn start_backend_server(server: Box<dyn BackendServer + Sync + Send>) {
+// requests that are already being handled are finished before shutdown future resolves
+pub async fn start_backend_server(server: Box<dyn BackendServer + Sync + Send>, shutdown: impl Future<Output = ()>) {
     let addr = "127.0.0.1:42320".parse().unwrap();
 
     Server::builder()
         .add_service(RpcBackendServer::new(RpcBackendServerImpl::new(server)))
-        .serve(addr)
+        .serve_with_shutdown(addr, shutdown)
         .await
         .expect("unable to start backend server");
 }
 
+// structured error is sent in details, message is kept for clients which don't read them
+fn error_to_status(err: anyhow::Error) -> Status {
+    let error = GauntletError::from_anyhow(&err);
+
+    let code = match error.code {
+        ErrorCode::Internal => Code::Internal,
+        ErrorCode::Managed => Code::PermissionDenied,
+        ErrorCode::InvalidPlugin => Code::FailedPrecondition,
+        ErrorCode::InvalidConfig => Code::FailedPrecondition,
+    };
+
+    Status::with_details(code, format!("{:#}", err), error.encode().into())
+}
+
 struct RpcBackendServerImpl {
     server: Box<dyn BackendServer + Sync + Send>
 }
@@ -51,6 +69,15 @@ pub trait BackendServer {
 
     async fn show_settings_window(&self) -> anyhow::Result<()>;
 
+    async fn toggle_window(&self) -> anyhow::Result<()>;
+
+    // returns once shutdown is requested, not when it is finished
+    async fn quit(&self) -> anyhow::Result<()>;
+
+    async fn open_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> anyhow::Result<()>;
+
+    async fn run_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: Vec<String>) -> anyhow::Result<()>;
+
     async fn plugins(&self) -> anyhow::Result<Vec<SettingsPlugin>>;
 
     async fn set_plugin_state(
@@ -66,6 +93,20 @@ pub trait BackendServer {
         enabled: bool
     ) -> anyhow::Result<()>;
 
+    async fn set_entrypoint_hidden(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        hidden: bool
+    ) -> anyhow::Result<()>;
+
+    async fn update_entrypoint(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        update: SettingsEntrypointUpdate
+    ) -> anyhow::Result<()>;
+
     async fn set_global_shortcut(
         &self,
         shortcut: Option<PhysicalShortcut>
@@ -75,6 +116,26 @@ pub trait BackendServer {
         &self,
     ) -> anyhow::Result<(Option<PhysicalShortcut>, Option<String>)>;
 
+    async fn set_action_shortcut(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        action_id: String,
+        shortcut: Option<PhysicalShortcut>
+    ) -> anyhow::Result<Option<ActionShortcutConflict>>;
+
+    async fn get_inline_view_order(&self) -> anyhow::Result<Vec<SettingsInlineView>>;
+
+    async fn set_inline_view_order(&self, plugin_ids: Vec<PluginId>) -> anyhow::Result<()>;
+
+    async fn get_favorites(&self) -> anyhow::Result<Vec<SettingsEntrypointReference>>;
+
+    async fn set_favorites(&self, favorites: Vec<(PluginId, EntrypointId)>) -> anyhow::Result<()>;
+
+    async fn get_fallback_order(&self) -> anyhow::Result<Vec<SettingsEntrypointReference>>;
+
+    async fn set_fallback_order(&self, fallbacks: Vec<(PluginId, EntrypointId)>) -> anyhow::Result<()>;
+
     async fn set_preference_value(
         &self,
         plugin_id: PluginId,
@@ -87,7 +148,60 @@ pub trait BackendServer {
 
     async fn download_status(&self) -> anyhow::Result<HashMap<PluginId, DownloadStatus>>;
 
-    async fn remove_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()>;
+    async fn remove_plugin(&self, plugin_id: PluginId, purge_data: bool) -> anyhow::Result<()>;
+
+    async fn plugin_logs(&self, plugin_id: PluginId, stdout_offset: u64, stderr_offset: u64) -> anyhow::Result<PluginLogs>;
+
+    async fn get_diagnostics(&self) -> anyhow::Result<DiagnosticsData>;
+
+    async fn set_diagnostics_enabled(&self, enabled: bool) -> anyhow::Result<()>;
+
+    async fn get_config_error(&self) -> anyhow::Result<Option<ConfigError>>;
+
+    async fn is_managed(&self) -> anyhow::Result<bool>;
+
+    async fn get_config_revision(&self) -> anyhow::Result<u64>;
+
+    async fn get_general_settings(&self) -> anyhow::Result<GeneralSettings>;
+
+    async fn set_general_settings(&self, settings: GeneralSettings) -> anyhow::Result<()>;
+
+    async fn check_for_updates(&self) -> anyhow::Result<Option<AvailableUpdate>>;
+
+    async fn get_simple_theme(&self) -> anyhow::Result<SimpleThemeTokens>;
+
+    // none restores theme from theme files
+    async fn preview_simple_theme(&self, theme: Option<SimpleThemeTokens>) -> anyhow::Result<()>;
+
+    // returns path of saved theme
+    async fn save_simple_theme(&self, name: String, theme: SimpleThemeTokens) -> anyhow::Result<String>;
+
+    async fn export_settings(&self, path: String) -> anyhow::Result<()>;
+
+    async fn import_settings(&self, path: String) -> anyhow::Result<()>;
+
+    async fn import_from_launcher(&self, source: ImportSource, path: String, dry_run: bool) -> anyhow::Result<Vec<ImportItem>>;
+
+    async fn get_profiles(&self) -> anyhow::Result<Profiles>;
+
+    async fn set_active_profile(&self, name: String) -> anyhow::Result<()>;
+
+    async fn get_usage_statistics(&self) -> anyhow::Result<UsageStatistics>;
+
+    async fn export_usage_statistics(&self, path: String) -> anyhow::Result<()>;
+
+    async fn get_macros(&self) -> anyhow::Result<Vec<Macro>>;
+
+    // returns id of saved macro, new id is assigned if macro id is empty
+    async fn save_macro(&self, item: Macro) -> anyhow::Result<String>;
+
+    async fn remove_macro(&self, id: String) -> anyhow::Result<()>;
+
+    async fn get_macro_step_candidates(&self) -> anyhow::Result<Vec<SettingsEntrypointReference>>;
+
+    async fn get_plugin_permissions(&self) -> anyhow::Result<Vec<SettingsPluginPermissions>>;
+
+    async fn set_plugin_permission_revoked(&self, plugin_id: PluginId, kind: PluginPermissionKind, value: String, revoked: bool) -> anyhow::Result<()>;
 
     async fn save_local_plugin(&self, path: String) -> anyhow::Result<LocalSaveData>;
 }
@@ -95,14 +209,27 @@ pub trait BackendServer {
 
 #[tonic::async_trait]
 impl RpcBackend for RpcBackendServerImpl {
-    async fn ping(&self, _: Request<RpcPingRequest>) -> Result<Response<RpcPingResponse>, Status> {
-        Ok(Response::new(RpcPingResponse::default()))
+    async fn ping(&self, request: Request<RpcPingRequest>) -> Result<Response<RpcPingResponse>, Status> {
+        let client_protocol_version = normalize_protocol_version(request.into_inner().protocol_version);
+
+        if client_protocol_version < MIN_SUPPORTED_PROTOCOL_VERSION {
+            return Err(Status::failed_precondition(format!(
+                "Client protocol version {} is not supported, server requires at least version {}",
+                client_protocol_version,
+                MIN_SUPPORTED_PROTOCOL_VERSION
+            )))
+        }
+
+        Ok(Response::new(RpcPingResponse {
+            protocol_version: PROTOCOL_VERSION,
+            min_supported_protocol_version: MIN_SUPPORTED_PROTOCOL_VERSION,
+        }))
     }
 
     async fn show_window(&self, _request: Request<RpcShowWindowRequest>) -> Result<Response<RpcShowWindowResponse>, Status> {
         self.server.show_window()
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+            .map_err(error_to_status)?;
 
         Ok(Response::new(RpcShowWindowResponse::default()))
     }
@@ -110,21 +237,58 @@ impl RpcBackend for RpcBackendServerImpl {
     async fn show_settings_window(&self, _request: Request<RpcShowSettingsWindowRequest>) -> Result<Response<RpcShowSettingsWindowResponse>, Status> {
         self.server.show_settings_window()
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+            .map_err(error_to_status)?;
 
         Ok(Response::new(RpcShowSettingsWindowResponse::default()))
     }
 
+    async fn toggle_window(&self, _request: Request<RpcToggleWindowRequest>) -> Result<Response<RpcToggleWindowResponse>, Status> {
+        self.server.toggle_window()
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcToggleWindowResponse::default()))
+    }
+
+    async fn quit(&self, _request: Request<RpcQuitRequest>) -> Result<Response<RpcQuitResponse>, Status> {
+        self.server.quit()
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcQuitResponse::default()))
+    }
+
+    async fn open_entrypoint(&self, request: Request<RpcOpenEntrypointRequest>) -> Result<Response<RpcOpenEntrypointResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.open_entrypoint(PluginId::from_string(request.plugin_id), EntrypointId::from_string(request.entrypoint_id))
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcOpenEntrypointResponse::default()))
+    }
+
+    async fn run_entrypoint(&self, request: Request<RpcRunEntrypointRequest>) -> Result<Response<RpcRunEntrypointResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.run_entrypoint(PluginId::from_string(request.plugin_id), EntrypointId::from_string(request.entrypoint_id), request.arguments)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcRunEntrypointResponse::default()))
+    }
+
     async fn plugins(&self, _: Request<RpcPluginsRequest>) -> Result<Response<RpcPluginsResponse>, Status> {
         let plugins = self.server.plugins()
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?
+            .map_err(error_to_status)?
             .into_iter()
             .map(|plugin| {
                 let entrypoints = plugin.entrypoints
                     .into_iter()
                     .map(|(_, entrypoint)| RpcEntrypoint {
                         enabled: entrypoint.enabled,
+                        hidden: entrypoint.hidden,
                         entrypoint_id: entrypoint.entrypoint_id.to_string(),
                         entrypoint_name: entrypoint.entrypoint_name,
                         entrypoint_description: entrypoint.entrypoint_description,
@@ -140,6 +304,11 @@ impl RpcBackend for RpcBackendServerImpl {
                         preferences_user_data: entrypoint.preferences_user_data.into_iter()
                             .map(|(key, value)| (key, plugin_preference_user_data_to_rpc(value)))
                             .collect(),
+                        actions: entrypoint.actions.into_iter()
+                            .map(|action| entrypoint_action_to_rpc(action))
+                            .collect(),
+                        aliases: entrypoint.aliases,
+                        favorite: entrypoint.favorite,
                     })
                     .collect();
 
@@ -171,7 +340,7 @@ impl RpcBackend for RpcBackendServerImpl {
 
         self.server.set_plugin_state(plugin_id, enabled)
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+            .map_err(error_to_status)?;
 
         Ok(Response::new(RpcSetPluginStateResponse::default()))
     }
@@ -187,11 +356,47 @@ impl RpcBackend for RpcBackendServerImpl {
 
         self.server.set_entrypoint_state(plugin_id, entrypoint_id, enabled)
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+            .map_err(error_to_status)?;
 
         Ok(Response::new(RpcSetEntrypointStateResponse::default()))
     }
 
+    async fn set_entrypoint_hidden(&self, request: Request<RpcSetEntrypointHiddenRequest>) -> Result<Response<RpcSetEntrypointHiddenResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = request.plugin_id;
+        let entrypoint_id = request.entrypoint_id;
+        let hidden = request.hidden;
+
+        let plugin_id = PluginId::from_string(plugin_id);
+        let entrypoint_id = EntrypointId::from_string(entrypoint_id);
+
+        self.server.set_entrypoint_hidden(plugin_id, entrypoint_id, hidden)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcSetEntrypointHiddenResponse::default()))
+    }
+
+    async fn update_entrypoint(&self, request: Request<RpcUpdateEntrypointRequest>) -> Result<Response<RpcUpdateEntrypointResponse>, Status> {
+        let request = request.into_inner();
+
+        let plugin_id = PluginId::from_string(request.plugin_id);
+        let entrypoint_id = EntrypointId::from_string(request.entrypoint_id);
+
+        let update = SettingsEntrypointUpdate {
+            enabled: request.enabled,
+            hidden: request.hidden,
+            aliases: request.aliases.map(|aliases| aliases.aliases),
+            favorite: request.favorite,
+        };
+
+        self.server.update_entrypoint(plugin_id, entrypoint_id, update)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcUpdateEntrypointResponse::default()))
+    }
+
     async fn set_preference_value(&self, request: Request<RpcSetPreferenceValueRequest>) -> Result<Response<RpcSetPreferenceValueResponse>, Status> {
         let request = request.into_inner();
         let plugin_id = request.plugin_id;
@@ -208,7 +413,7 @@ impl RpcBackend for RpcBackendServerImpl {
 
         self.server.set_preference_value(plugin_id, entrypoint_id, preference_id, plugin_preference_user_data_from_rpc(preference_value))
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+            .map_err(error_to_status)?;
 
         Ok(Response::new(RpcSetPreferenceValueResponse::default()))
     }
@@ -235,7 +440,7 @@ impl RpcBackend for RpcBackendServerImpl {
 
         self.server.set_global_shortcut(shortcut)
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+            .map_err(error_to_status)?;
 
         Ok(Response::new(RpcSetGlobalShortcutResponse::default()))
     }
@@ -243,7 +448,7 @@ impl RpcBackend for RpcBackendServerImpl {
     async fn get_global_shortcut(&self, _request: Request<RpcGetGlobalShortcutRequest>) -> Result<Response<RpcGetGlobalShortcutResponse>, Status> {
         let (shortcut, error) = self.server.get_global_shortcut()
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+            .map_err(error_to_status)?;
 
         Ok(Response::new(RpcGetGlobalShortcutResponse {
             shortcut: shortcut.map(|shortcut| RpcShortcut {
@@ -257,6 +462,104 @@ impl RpcBackend for RpcBackendServerImpl {
         }))
     }
 
+    async fn set_action_shortcut(&self, request: Request<RpcSetActionShortcutRequest>) -> Result<Response<RpcSetActionShortcutResponse>, Status> {
+        let request = request.into_inner();
+
+        let plugin_id = PluginId::from_string(request.plugin_id);
+        let entrypoint_id = EntrypointId::from_string(request.entrypoint_id);
+        let shortcut = request.shortcut.map(|shortcut| shortcut_from_rpc(shortcut));
+
+        let conflict = self.server.set_action_shortcut(plugin_id, entrypoint_id, request.action_id, shortcut)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcSetActionShortcutResponse {
+            conflict: conflict.map(|conflict| action_shortcut_conflict_to_rpc(conflict)),
+        }))
+    }
+
+    async fn get_inline_view_order(&self, _request: Request<RpcGetInlineViewOrderRequest>) -> Result<Response<RpcGetInlineViewOrderResponse>, Status> {
+        let inline_views = self.server.get_inline_view_order()
+            .await
+            .map_err(error_to_status)?;
+
+        let inline_views = inline_views.into_iter()
+            .map(|inline_view| RpcInlineView {
+                plugin_id: inline_view.plugin_id.to_string(),
+                plugin_name: inline_view.plugin_name,
+                entrypoint_id: inline_view.entrypoint_id.to_string(),
+                entrypoint_name: inline_view.entrypoint_name,
+                enabled: inline_view.enabled,
+            })
+            .collect();
+
+        Ok(Response::new(RpcGetInlineViewOrderResponse { inline_views }))
+    }
+
+    async fn set_inline_view_order(&self, request: Request<RpcSetInlineViewOrderRequest>) -> Result<Response<RpcSetInlineViewOrderResponse>, Status> {
+        let plugin_ids = request.into_inner()
+            .plugin_ids
+            .into_iter()
+            .map(|plugin_id| PluginId::from_string(plugin_id))
+            .collect();
+
+        self.server.set_inline_view_order(plugin_ids)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcSetInlineViewOrderResponse::default()))
+    }
+
+    async fn get_favorites(&self, _request: Request<RpcGetFavoritesRequest>) -> Result<Response<RpcGetFavoritesResponse>, Status> {
+        let favorites = self.server.get_favorites()
+            .await
+            .map_err(error_to_status)?
+            .into_iter()
+            .map(|favorite| entrypoint_reference_to_rpc(favorite))
+            .collect();
+
+        Ok(Response::new(RpcGetFavoritesResponse { favorites }))
+    }
+
+    async fn set_favorites(&self, request: Request<RpcSetFavoritesRequest>) -> Result<Response<RpcSetFavoritesResponse>, Status> {
+        let favorites = request.into_inner()
+            .favorites
+            .into_iter()
+            .map(|favorite| entrypoint_key_from_rpc(favorite))
+            .collect();
+
+        self.server.set_favorites(favorites)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcSetFavoritesResponse::default()))
+    }
+
+    async fn get_fallback_order(&self, _request: Request<RpcGetFallbackOrderRequest>) -> Result<Response<RpcGetFallbackOrderResponse>, Status> {
+        let fallbacks = self.server.get_fallback_order()
+            .await
+            .map_err(error_to_status)?
+            .into_iter()
+            .map(|fallback| entrypoint_reference_to_rpc(fallback))
+            .collect();
+
+        Ok(Response::new(RpcGetFallbackOrderResponse { fallbacks }))
+    }
+
+    async fn set_fallback_order(&self, request: Request<RpcSetFallbackOrderRequest>) -> Result<Response<RpcSetFallbackOrderResponse>, Status> {
+        let fallbacks = request.into_inner()
+            .fallbacks
+            .into_iter()
+            .map(|fallback| entrypoint_key_from_rpc(fallback))
+            .collect();
+
+        self.server.set_fallback_order(fallbacks)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcSetFallbackOrderResponse::default()))
+    }
+
     async fn download_plugin(&self, request: Request<RpcDownloadPluginRequest>) -> Result<Response<RpcDownloadPluginResponse>, Status> {
         let request = request.into_inner();
         let plugin_id = request.plugin_id;
@@ -265,7 +568,7 @@ impl RpcBackend for RpcBackendServerImpl {
 
         self.server.download_plugin(plugin_id)
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+            .map_err(error_to_status)?;
 
         Ok(Response::new(RpcDownloadPluginResponse::default()))
     }
@@ -273,7 +576,7 @@ impl RpcBackend for RpcBackendServerImpl {
     async fn download_status(&self, _: Request<RpcDownloadStatusRequest>) -> Result<Response<RpcDownloadStatusResponse>, Status> {
         let status_per_plugin = self.server.download_status()
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?
+            .map_err(error_to_status)?
             .into_iter()
             .map(|(plugin_id, status)| {
                 let (status, message) = match status {
@@ -299,20 +602,345 @@ impl RpcBackend for RpcBackendServerImpl {
 
         let plugin_id = PluginId::from_string(plugin_id);
 
-        self.server.remove_plugin(plugin_id)
+        self.server.remove_plugin(plugin_id, request.purge_data)
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+            .map_err(error_to_status)?;
 
         Ok(Response::new(RpcRemovePluginResponse::default()))
     }
 
+    async fn plugin_logs(&self, request: Request<RpcPluginLogsRequest>) -> Result<Response<RpcPluginLogsResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = PluginId::from_string(request.plugin_id);
+
+        let logs = self.server.plugin_logs(plugin_id, request.stdout_offset, request.stderr_offset)
+            .await
+            .map_err(error_to_status)?;
+
+        let lines = logs.lines
+            .into_iter()
+            .map(|line| {
+                let stream = match line.stream {
+                    PluginLogStream::Stdout => RpcPluginLogStream::Stdout,
+                    PluginLogStream::Stderr => RpcPluginLogStream::Stderr,
+                };
+
+                RpcPluginLogLine { stream: stream.into(), text: line.text }
+            })
+            .collect();
+
+        Ok(Response::new(RpcPluginLogsResponse {
+            lines,
+            stdout_offset: logs.stdout_offset,
+            stderr_offset: logs.stderr_offset,
+            reset: logs.reset,
+        }))
+    }
+
+    async fn get_diagnostics(&self, _: Request<RpcGetDiagnosticsRequest>) -> Result<Response<RpcGetDiagnosticsResponse>, Status> {
+        let diagnostics = self.server.get_diagnostics()
+            .await
+            .map_err(error_to_status)?;
+
+        let entries = diagnostics.entries
+            .into_iter()
+            .map(|entry| {
+                let kind = match entry.kind {
+                    DiagnosticsKind::Render => RpcDiagnosticsKind::Render,
+                    DiagnosticsKind::Search => RpcDiagnosticsKind::Search,
+                    DiagnosticsKind::Op => RpcDiagnosticsKind::Op,
+                    DiagnosticsKind::Ipc => RpcDiagnosticsKind::Ipc,
+                };
+
+                RpcDiagnosticsEntry {
+                    kind: kind.into(),
+                    plugin_id: entry.plugin_id.map(|plugin_id| plugin_id.to_string()),
+                    name: entry.name,
+                    count: entry.count,
+                    total_micros: entry.total_duration.as_micros() as u64,
+                    max_micros: entry.max_duration.as_micros() as u64,
+                }
+            })
+            .collect();
+
+        let image_cache = diagnostics.image_cache
+            .map(|image_cache| RpcImageCacheStats {
+                entries: image_cache.entries,
+                size_bytes: image_cache.size_bytes,
+                max_size_bytes: image_cache.max_size_bytes,
+                hits: image_cache.hits,
+                misses: image_cache.misses,
+                evictions: image_cache.evictions,
+            });
+
+        Ok(Response::new(RpcGetDiagnosticsResponse {
+            enabled: diagnostics.enabled,
+            entries,
+            image_cache,
+        }))
+    }
+
+    async fn set_diagnostics_enabled(&self, request: Request<RpcSetDiagnosticsEnabledRequest>) -> Result<Response<RpcSetDiagnosticsEnabledResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.set_diagnostics_enabled(request.enabled)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcSetDiagnosticsEnabledResponse::default()))
+    }
+
+    async fn get_config_error(&self, _: Request<RpcGetConfigErrorRequest>) -> Result<Response<RpcGetConfigErrorResponse>, Status> {
+        let config_error = self.server.get_config_error()
+            .await
+            .map_err(error_to_status)?;
+
+        let error = config_error.map(|error| RpcConfigError {
+            config_file: error.config_file,
+            message: error.message,
+            line: error.line,
+            column: error.column,
+        });
+
+        Ok(Response::new(RpcGetConfigErrorResponse { error }))
+    }
+
+    async fn is_managed(&self, _: Request<RpcIsManagedRequest>) -> Result<Response<RpcIsManagedResponse>, Status> {
+        let managed = self.server.is_managed()
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcIsManagedResponse { managed }))
+    }
+
+    async fn get_config_revision(&self, _: Request<RpcGetConfigRevisionRequest>) -> Result<Response<RpcGetConfigRevisionResponse>, Status> {
+        let revision = self.server.get_config_revision()
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcGetConfigRevisionResponse { revision }))
+    }
+
+    async fn get_general_settings(&self, _: Request<RpcGetGeneralSettingsRequest>) -> Result<Response<RpcGetGeneralSettingsResponse>, Status> {
+        let settings = self.server.get_general_settings()
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcGetGeneralSettingsResponse { settings: Some(general_settings_to_rpc(settings)) }))
+    }
+
+    async fn set_general_settings(&self, request: Request<RpcSetGeneralSettingsRequest>) -> Result<Response<RpcSetGeneralSettingsResponse>, Status> {
+        let settings = request.into_inner()
+            .settings
+            .ok_or_else(|| Status::invalid_argument("settings are required"))?;
+
+        // whole config section is written back, so fields older clients don't know about must keep their stored value
+        let stored_settings = self.server.get_general_settings()
+            .await
+            .map_err(error_to_status)?;
+
+        self.server.set_general_settings(general_settings_from_rpc(settings, &stored_settings))
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcSetGeneralSettingsResponse::default()))
+    }
+
+    async fn check_for_updates(&self, _: Request<RpcCheckForUpdatesRequest>) -> Result<Response<RpcCheckForUpdatesResponse>, Status> {
+        let update = self.server.check_for_updates()
+            .await
+            .map_err(error_to_status)?;
+
+        let update = update
+            .map(|update| RpcAvailableUpdate {
+                version: update.version as u32,
+                url: update.url,
+            });
+
+        Ok(Response::new(RpcCheckForUpdatesResponse { update }))
+    }
+
+    async fn get_simple_theme(&self, _: Request<RpcGetSimpleThemeRequest>) -> Result<Response<RpcGetSimpleThemeResponse>, Status> {
+        let theme = self.server.get_simple_theme()
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcGetSimpleThemeResponse { theme: Some(simple_theme_to_rpc(theme)) }))
+    }
+
+    async fn preview_simple_theme(&self, request: Request<RpcPreviewSimpleThemeRequest>) -> Result<Response<RpcPreviewSimpleThemeResponse>, Status> {
+        let theme = request.into_inner()
+            .theme
+            .map(|theme| simple_theme_from_rpc(theme));
+
+        self.server.preview_simple_theme(theme)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcPreviewSimpleThemeResponse::default()))
+    }
+
+    async fn save_simple_theme(&self, request: Request<RpcSaveSimpleThemeRequest>) -> Result<Response<RpcSaveSimpleThemeResponse>, Status> {
+        let request = request.into_inner();
+
+        let theme = request.theme
+            .ok_or_else(|| Status::invalid_argument("theme is required"))?;
+
+        let path = self.server.save_simple_theme(request.name, simple_theme_from_rpc(theme))
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcSaveSimpleThemeResponse { path }))
+    }
+
+    async fn export_settings(&self, request: Request<RpcExportSettingsRequest>) -> Result<Response<RpcExportSettingsResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.export_settings(request.path)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcExportSettingsResponse::default()))
+    }
+
+    async fn import_settings(&self, request: Request<RpcImportSettingsRequest>) -> Result<Response<RpcImportSettingsResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.import_settings(request.path)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcImportSettingsResponse::default()))
+    }
+
+    async fn import_from_launcher(&self, request: Request<RpcImportFromLauncherRequest>) -> Result<Response<RpcImportFromLauncherResponse>, Status> {
+        let request = request.into_inner();
+        let source = import_source_from_rpc(request.source());
+
+        let items = self.server.import_from_launcher(source, request.path, request.dry_run)
+            .await
+            .map_err(error_to_status)?;
+
+        let items = items.into_iter()
+            .map(|item| import_item_to_rpc(item))
+            .collect();
+
+        Ok(Response::new(RpcImportFromLauncherResponse { items }))
+    }
+
+    async fn get_profiles(&self, _request: Request<RpcGetProfilesRequest>) -> Result<Response<RpcGetProfilesResponse>, Status> {
+        let profiles = self.server.get_profiles()
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcGetProfilesResponse {
+            profiles: profiles.profiles,
+            current: profiles.current,
+            active: profiles.active,
+        }))
+    }
+
+    async fn set_active_profile(&self, request: Request<RpcSetActiveProfileRequest>) -> Result<Response<RpcSetActiveProfileResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.set_active_profile(request.name)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcSetActiveProfileResponse::default()))
+    }
+
+    async fn get_usage_statistics(&self, _request: Request<RpcGetUsageStatisticsRequest>) -> Result<Response<RpcGetUsageStatisticsResponse>, Status> {
+        let statistics = self.server.get_usage_statistics()
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(usage_statistics_to_rpc(statistics)))
+    }
+
+    async fn export_usage_statistics(&self, request: Request<RpcExportUsageStatisticsRequest>) -> Result<Response<RpcExportUsageStatisticsResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.export_usage_statistics(request.path)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcExportUsageStatisticsResponse::default()))
+    }
+
+    async fn get_macros(&self, _request: Request<RpcGetMacrosRequest>) -> Result<Response<RpcGetMacrosResponse>, Status> {
+        let macros = self.server.get_macros()
+            .await
+            .map_err(error_to_status)?
+            .into_iter()
+            .map(|item| macro_to_rpc(item))
+            .collect();
+
+        Ok(Response::new(RpcGetMacrosResponse { macros }))
+    }
+
+    async fn save_macro(&self, request: Request<RpcSaveMacroRequest>) -> Result<Response<RpcSaveMacroResponse>, Status> {
+        let item = request.into_inner()
+            .item
+            .ok_or_else(|| Status::invalid_argument("macro is required"))?;
+
+        let id = self.server.save_macro(macro_from_rpc(item))
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcSaveMacroResponse { id }))
+    }
+
+    async fn remove_macro(&self, request: Request<RpcRemoveMacroRequest>) -> Result<Response<RpcRemoveMacroResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.remove_macro(request.id)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcRemoveMacroResponse::default()))
+    }
+
+    async fn get_macro_step_candidates(&self, _request: Request<RpcGetMacroStepCandidatesRequest>) -> Result<Response<RpcGetMacroStepCandidatesResponse>, Status> {
+        let entrypoints = self.server.get_macro_step_candidates()
+            .await
+            .map_err(error_to_status)?
+            .into_iter()
+            .map(|entrypoint| entrypoint_reference_to_rpc(entrypoint))
+            .collect();
+
+        Ok(Response::new(RpcGetMacroStepCandidatesResponse { entrypoints }))
+    }
+
+    async fn get_plugin_permissions(&self, _request: Request<RpcGetPluginPermissionsRequest>) -> Result<Response<RpcGetPluginPermissionsResponse>, Status> {
+        let plugins = self.server.get_plugin_permissions()
+            .await
+            .map_err(error_to_status)?
+            .into_iter()
+            .map(|plugin| plugin_permissions_to_rpc(plugin))
+            .collect();
+
+        Ok(Response::new(RpcGetPluginPermissionsResponse { plugins }))
+    }
+
+    async fn set_plugin_permission_revoked(&self, request: Request<RpcSetPluginPermissionRevokedRequest>) -> Result<Response<RpcSetPluginPermissionRevokedResponse>, Status> {
+        let request = request.into_inner();
+        let kind = plugin_permission_kind_from_rpc(request.kind());
+
+        self.server.set_plugin_permission_revoked(PluginId::from_string(request.plugin_id), kind, request.value, request.revoked)
+            .await
+            .map_err(error_to_status)?;
+
+        Ok(Response::new(RpcSetPluginPermissionRevokedResponse::default()))
+    }
+
     async fn save_local_plugin(&self, request: Request<RpcSaveLocalPluginRequest>) -> Result<Response<RpcSaveLocalPluginResponse>, Status> {
         let request = request.into_inner();
         let path = request.path;
 
         let local_save_data = self.server.save_local_plugin(path)
             .await
-            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+            .map_err(error_to_status)?;
 
         Ok(Response::new(RpcSaveLocalPluginResponse {
             stdout_file_path: local_save_data.stdout_file_path,
@@ -1,9 +1,10 @@
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use anyhow::anyhow;
 use thiserror::Error;
 use gauntlet_utils::channel::{RequestError, RequestSender};
 
-use crate::model::{EntrypointId, PhysicalShortcut, PluginId, RootWidget, UiRenderLocation, UiRequestData, UiResponseData, UiWidgetId};
+use crate::model::{ConfigError, EntrypointId, PhysicalShortcut, PluginId, RootWidget, SearchResult, SearchResultPreview, SimpleThemeTokens, UiImageId, UiRenderLocation, UiRequestData, UiResponseData, UiWidgetId};
 
 #[derive(Error, Debug)]
 pub enum FrontendApiError {
@@ -22,34 +23,172 @@ impl From<RequestError> for FrontendApiError {
     }
 }
 
+#[tonic::async_trait]
+pub trait FrontendApi: Send + Sync {
+    async fn request_search_results_update(&self) -> Result<(), FrontendApiError>;
+
+    async fn replace_view(
+        &self,
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+        render_location: UiRenderLocation,
+        view_stack_depth: usize,
+        container: RootWidget,
+        images: HashMap<UiWidgetId, UiImageId>,
+    ) -> Result<(), FrontendApiError>;
+
+    async fn clear_inline_view(&self, plugin_id: PluginId) -> Result<(), FrontendApiError>;
+
+    async fn show_window(&self) -> Result<(), FrontendApiError>;
+
+    async fn hide_window(&self) -> Result<(), FrontendApiError>;
+
+    async fn toggle_window(&self) -> Result<(), FrontendApiError>;
+
+    // frontend exits after server has been shut down
+    async fn quit(&self) -> Result<(), FrontendApiError>;
+
+    async fn preview_theme(&self, theme: Option<SimpleThemeTokens>) -> Result<(), FrontendApiError>;
+
+    async fn show_plugin_view(
+        &self,
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+    ) -> Result<(), FrontendApiError>;
+
+    async fn show_preference_required_view(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        plugin_preferences_required: bool,
+        entrypoint_preferences_required: bool,
+    ) -> Result<(), FrontendApiError>;
+
+    async fn show_plugin_error_view(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        render_location: UiRenderLocation,
+    ) -> Result<(), FrontendApiError>;
+
+    async fn show_render_error_view(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        message: String,
+        component_path: Vec<String>,
+    ) -> Result<(), FrontendApiError>;
+
+    async fn show_render_timing(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        js_render_time_ms: f64,
+        serialization_time_ms: f64,
+    ) -> Result<(), FrontendApiError>;
+
+    async fn show_argument_suggestions(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        argument_id: String,
+        generation: u64,
+        suggestions: Vec<String>,
+    ) -> Result<(), FrontendApiError>;
+
+    async fn show_hud(&self, display: String) -> Result<(), FrontendApiError>;
+
+    async fn show_undo_toast(&self, description: String) -> Result<(), FrontendApiError>;
+
+    async fn show_recovery_view(
+        &self,
+        safe_mode: bool,
+        description: String,
+        crash_report_path: Option<String>,
+    ) -> Result<(), FrontendApiError>;
+
+    async fn show_config_error(&self, error: ConfigError) -> Result<(), FrontendApiError>;
+
+    async fn config_reloaded(&self) -> Result<(), FrontendApiError>;
+
+    async fn session_lock_changed(&self, locked: bool) -> Result<(), FrontendApiError>;
+
+    async fn show_database_recovery_view(
+        &self,
+        reason: String,
+        broken_database_path: String,
+        restored_backup_path: Option<String>,
+    ) -> Result<(), FrontendApiError>;
+
+    async fn show_whats_new_view(&self, version: u16, changelog: String) -> Result<(), FrontendApiError>;
+
+    async fn update_loading_bar(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        show: bool
+    ) -> Result<(), FrontendApiError>;
+
+    async fn set_global_shortcut(&self, shortcut: Option<PhysicalShortcut>) -> anyhow::Result<()>;
+
+    async fn update_action_shortcuts(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        shortcuts: HashMap<String, PhysicalShortcut>
+    ) -> Result<(), FrontendApiError>;
+
+    async fn show_search_result_preview(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        preview: Option<SearchResultPreview>
+    ) -> Result<(), FrontendApiError>;
+
+    async fn append_search_results(
+        &self,
+        generation: u64,
+        plugin_id: PluginId,
+        results: Vec<SearchResult>,
+        late: bool,
+    ) -> Result<(), FrontendApiError>;
+}
+
 #[derive(Debug, Clone)]
-pub struct FrontendApi {
+pub struct FrontendApiImpl {
     frontend_sender: RequestSender<UiRequestData, UiResponseData>,
 }
 
-impl FrontendApi {
+impl FrontendApiImpl {
     pub fn new(frontend_sender: RequestSender<UiRequestData, UiResponseData>) -> Self {
         Self {
             frontend_sender
         }
     }
+}
 
-    pub async fn request_search_results_update(&self) -> Result<(), FrontendApiError> {
+#[tonic::async_trait]
+impl FrontendApi for FrontendApiImpl {
+    async fn request_search_results_update(&self) -> Result<(), FrontendApiError> {
         let _ = self.frontend_sender.send_receive(UiRequestData::RequestSearchResultUpdate).await;
 
         Ok(())
     }
 
-    pub async fn replace_view(
+    async fn replace_view(
         &self,
         plugin_id: PluginId,
         plugin_name: String,
         entrypoint_id: EntrypointId,
         entrypoint_name: String,
         render_location: UiRenderLocation,
-        top_level_view: bool,
+        view_stack_depth: usize,
         container: RootWidget,
-        images: HashMap<UiWidgetId, Vec<u8>>,
+        images: HashMap<UiWidgetId, UiImageId>,
     ) -> Result<(), FrontendApiError> {
         let request = UiRequestData::ReplaceView {
             plugin_id,
@@ -57,7 +196,7 @@ impl FrontendApi {
             entrypoint_id,
             entrypoint_name,
             render_location,
-            top_level_view,
+            view_stack_depth,
             container,
             images,
         };
@@ -69,7 +208,7 @@ impl FrontendApi {
         Ok(())
     }
 
-    pub async fn clear_inline_view(&self, plugin_id: PluginId) -> Result<(), FrontendApiError> {
+    async fn clear_inline_view(&self, plugin_id: PluginId) -> Result<(), FrontendApiError> {
         let request = UiRequestData::ClearInlineView {
             plugin_id,
         };
@@ -81,7 +220,7 @@ impl FrontendApi {
         Ok(())
     }
 
-    pub async fn show_window(&self) -> Result<(), FrontendApiError> {
+    async fn show_window(&self) -> Result<(), FrontendApiError> {
         let UiResponseData::Nothing = self.frontend_sender.send_receive(UiRequestData::ShowWindow).await? else {
             unreachable!()
         };
@@ -89,7 +228,60 @@ impl FrontendApi {
         Ok(())
     }
 
-    pub async fn show_preference_required_view(
+    async fn hide_window(&self) -> Result<(), FrontendApiError> {
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(UiRequestData::HideWindow).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn toggle_window(&self) -> Result<(), FrontendApiError> {
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(UiRequestData::ToggleWindow).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn quit(&self) -> Result<(), FrontendApiError> {
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(UiRequestData::Quit).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn preview_theme(&self, theme: Option<SimpleThemeTokens>) -> Result<(), FrontendApiError> {
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(UiRequestData::PreviewTheme { theme }).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn show_plugin_view(
+        &self,
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowPluginView {
+            plugin_id,
+            plugin_name,
+            entrypoint_id,
+            entrypoint_name,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn show_preference_required_view(
         &self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
@@ -110,7 +302,7 @@ impl FrontendApi {
         Ok(())
     }
 
-    pub async fn show_plugin_error_view(
+    async fn show_plugin_error_view(
         &self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
@@ -129,7 +321,72 @@ impl FrontendApi {
         Ok(())
     }
 
-    pub async fn show_hud(
+    async fn show_render_error_view(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        message: String,
+        component_path: Vec<String>,
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowRenderErrorView {
+            plugin_id,
+            entrypoint_id,
+            message,
+            component_path,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn show_render_timing(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        js_render_time_ms: f64,
+        serialization_time_ms: f64,
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowRenderTiming {
+            plugin_id,
+            entrypoint_id,
+            js_render_time_ms,
+            serialization_time_ms,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn show_argument_suggestions(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        argument_id: String,
+        generation: u64,
+        suggestions: Vec<String>,
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowArgumentSuggestions {
+            plugin_id,
+            entrypoint_id,
+            argument_id,
+            generation,
+            suggestions,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn show_hud(
         &self,
         display: String,
     ) -> Result<(), FrontendApiError> {
@@ -144,7 +401,101 @@ impl FrontendApi {
         Ok(())
     }
 
-    pub async fn update_loading_bar(
+    async fn show_undo_toast(
+        &self,
+        description: String,
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowUndoToast {
+            description,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn show_recovery_view(
+        &self,
+        safe_mode: bool,
+        description: String,
+        crash_report_path: Option<String>,
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowRecoveryView {
+            safe_mode,
+            description,
+            crash_report_path,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn show_config_error(&self, error: ConfigError) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowConfigError {
+            error,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn config_reloaded(&self) -> Result<(), FrontendApiError> {
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(UiRequestData::ConfigReloaded).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn session_lock_changed(&self, locked: bool) -> Result<(), FrontendApiError> {
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(UiRequestData::SessionLockChanged { locked }).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn show_database_recovery_view(
+        &self,
+        reason: String,
+        broken_database_path: String,
+        restored_backup_path: Option<String>,
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowDatabaseRecoveryView {
+            reason,
+            broken_database_path,
+            restored_backup_path,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn show_whats_new_view(&self, version: u16, changelog: String) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowWhatsNewView {
+            version,
+            changelog,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn update_loading_bar(
         &self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
@@ -163,7 +514,7 @@ impl FrontendApi {
         Ok(())
     }
 
-    pub async fn set_global_shortcut(
+    async fn set_global_shortcut(
         &self,
         shortcut: Option<PhysicalShortcut>
     ) -> anyhow::Result<()> {
@@ -180,4 +531,402 @@ impl FrontendApi {
             UiResponseData::Err(err) => Err(err)
         }
     }
-}
\ No newline at end of file
+
+    async fn update_action_shortcuts(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        shortcuts: HashMap<String, PhysicalShortcut>
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::UpdateActionShortcuts {
+            plugin_id,
+            entrypoint_id,
+            shortcuts,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn show_search_result_preview(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        preview: Option<SearchResultPreview>
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowSearchResultPreview {
+            plugin_id,
+            entrypoint_id,
+            preview,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    async fn append_search_results(
+        &self,
+        generation: u64,
+        plugin_id: PluginId,
+        results: Vec<SearchResult>,
+        late: bool,
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::AppendSearchResults {
+            generation,
+            plugin_id,
+            results,
+            late,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+}
+
+// records requests instead of sending them to frontend, used to test server logic without running frontend
+#[derive(Debug, Clone, Default)]
+pub struct RecordingFrontendApi {
+    requests: Arc<Mutex<Vec<UiRequestData>>>,
+}
+
+impl RecordingFrontendApi {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn take_requests(&self) -> Vec<UiRequestData> {
+        std::mem::take(&mut self.requests.lock().unwrap())
+    }
+
+    fn record(&self, request: UiRequestData) {
+        self.requests.lock().unwrap().push(request);
+    }
+}
+
+#[tonic::async_trait]
+impl FrontendApi for RecordingFrontendApi {
+    async fn request_search_results_update(&self) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::RequestSearchResultUpdate);
+
+        Ok(())
+    }
+
+    async fn replace_view(
+        &self,
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+        render_location: UiRenderLocation,
+        view_stack_depth: usize,
+        container: RootWidget,
+        images: HashMap<UiWidgetId, UiImageId>,
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ReplaceView {
+            plugin_id,
+            plugin_name,
+            entrypoint_id,
+            entrypoint_name,
+            render_location,
+            view_stack_depth,
+            container,
+            images,
+        });
+
+        Ok(())
+    }
+
+    async fn clear_inline_view(&self, plugin_id: PluginId) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ClearInlineView {
+            plugin_id,
+        });
+
+        Ok(())
+    }
+
+    async fn show_window(&self) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowWindow);
+
+        Ok(())
+    }
+
+    async fn hide_window(&self) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::HideWindow);
+
+        Ok(())
+    }
+
+    async fn toggle_window(&self) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ToggleWindow);
+
+        Ok(())
+    }
+
+    async fn quit(&self) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::Quit);
+
+        Ok(())
+    }
+
+    async fn preview_theme(&self, theme: Option<SimpleThemeTokens>) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::PreviewTheme { theme });
+
+        Ok(())
+    }
+
+    async fn show_plugin_view(
+        &self,
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowPluginView {
+            plugin_id,
+            plugin_name,
+            entrypoint_id,
+            entrypoint_name,
+        });
+
+        Ok(())
+    }
+
+    async fn show_preference_required_view(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        plugin_preferences_required: bool,
+        entrypoint_preferences_required: bool,
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowPreferenceRequiredView {
+            plugin_id,
+            entrypoint_id,
+            plugin_preferences_required,
+            entrypoint_preferences_required,
+        });
+
+        Ok(())
+    }
+
+    async fn show_plugin_error_view(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        render_location: UiRenderLocation,
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowPluginErrorView {
+            plugin_id,
+            entrypoint_id,
+            render_location,
+        });
+
+        Ok(())
+    }
+
+    async fn show_render_error_view(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        message: String,
+        component_path: Vec<String>,
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowRenderErrorView {
+            plugin_id,
+            entrypoint_id,
+            message,
+            component_path,
+        });
+
+        Ok(())
+    }
+
+    async fn show_render_timing(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        js_render_time_ms: f64,
+        serialization_time_ms: f64,
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowRenderTiming {
+            plugin_id,
+            entrypoint_id,
+            js_render_time_ms,
+            serialization_time_ms,
+        });
+
+        Ok(())
+    }
+
+    async fn show_argument_suggestions(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        argument_id: String,
+        generation: u64,
+        suggestions: Vec<String>,
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowArgumentSuggestions {
+            plugin_id,
+            entrypoint_id,
+            argument_id,
+            generation,
+            suggestions,
+        });
+
+        Ok(())
+    }
+
+    async fn show_hud(&self, display: String) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowHud {
+            display,
+        });
+
+        Ok(())
+    }
+
+    async fn show_undo_toast(&self, description: String) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowUndoToast {
+            description,
+        });
+
+        Ok(())
+    }
+
+    async fn show_recovery_view(
+        &self,
+        safe_mode: bool,
+        description: String,
+        crash_report_path: Option<String>,
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowRecoveryView {
+            safe_mode,
+            description,
+            crash_report_path,
+        });
+
+        Ok(())
+    }
+
+    async fn show_config_error(&self, error: ConfigError) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowConfigError {
+            error,
+        });
+
+        Ok(())
+    }
+
+    async fn config_reloaded(&self) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ConfigReloaded);
+
+        Ok(())
+    }
+
+    async fn session_lock_changed(&self, locked: bool) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::SessionLockChanged { locked });
+
+        Ok(())
+    }
+
+    async fn show_database_recovery_view(
+        &self,
+        reason: String,
+        broken_database_path: String,
+        restored_backup_path: Option<String>,
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowDatabaseRecoveryView {
+            reason,
+            broken_database_path,
+            restored_backup_path,
+        });
+
+        Ok(())
+    }
+
+    async fn show_whats_new_view(&self, version: u16, changelog: String) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowWhatsNewView {
+            version,
+            changelog,
+        });
+
+        Ok(())
+    }
+
+    async fn update_loading_bar(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        show: bool
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::UpdateLoadingBar {
+            plugin_id,
+            entrypoint_id,
+            show,
+        });
+
+        Ok(())
+    }
+
+    async fn set_global_shortcut(&self, shortcut: Option<PhysicalShortcut>) -> anyhow::Result<()> {
+        self.record(UiRequestData::SetGlobalShortcut {
+            shortcut,
+        });
+
+        Ok(())
+    }
+
+    async fn update_action_shortcuts(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        shortcuts: HashMap<String, PhysicalShortcut>
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::UpdateActionShortcuts {
+            plugin_id,
+            entrypoint_id,
+            shortcuts,
+        });
+
+        Ok(())
+    }
+
+    async fn show_search_result_preview(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        preview: Option<SearchResultPreview>
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::ShowSearchResultPreview {
+            plugin_id,
+            entrypoint_id,
+            preview,
+        });
+
+        Ok(())
+    }
+
+    async fn append_search_results(
+        &self,
+        generation: u64,
+        plugin_id: PluginId,
+        results: Vec<SearchResult>,
+        late: bool,
+    ) -> Result<(), FrontendApiError> {
+        self.record(UiRequestData::AppendSearchResults {
+            generation,
+            plugin_id,
+            results,
+            late,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{RootWidget, UiImageId, UiPropertyValue, UiWidgetId};
+
+// Protocol for frontends implemented outside of this repository, e.g. a TUI or a minimal launcher window.
+// Server started with --external-frontend doesn't open its own window and instead
+// waits for a frontend to connect to the frontend socket, only one frontend is connected at a time.
+//
+// Protocol is newline delimited json. Frontend sends requests with an id of its choice and server answers
+// each of them with a response with the same id. Independently of that, server sends events,
+// e.g. when plugin renders a view. First request has to be `hello`.
+//
+//   -> {"id":1,"type":"hello","protocol_version":2}
+//   <- {"type":"response","id":1,"result":{"type":"hello","protocol_version":2}}
+//   -> {"id":2,"type":"open_view","plugin_id":"bundled://gauntlet","entrypoint_id":"settings"}
+//   <- {"type":"event","event":{"type":"replace_view","plugin_id":"bundled://gauntlet",...,"container":{...}}}
+//   <- {"type":"response","id":2,"result":{"type":"view_opened","interactive":true}}
+//
+// Like control socket, messages here are considered stable, fields and variants are only added.
+// Bump FRONTEND_PROTOCOL_VERSION when that happens, so that frontends can check what server supports.
+
+pub const FRONTEND_PROTOCOL_VERSION: u32 = 2;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FrontendRequestMessage {
+    pub id: u64,
+    #[serde(flatten)]
+    pub request: FrontendRequest,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FrontendRequest {
+    Hello {
+        protocol_version: u32,
+    },
+    // category prefix is supported the same way as in main window, e.g. "files: notes"
+    Search {
+        query: String,
+    },
+    OpenView {
+        plugin_id: String,
+        entrypoint_id: String,
+    },
+    // plugin can have several views open at once, one per entrypoint.
+    // if entrypoint is not specified, the one that was opened the latest is used (protocol version 1 behaviour)
+    CloseView {
+        plugin_id: String,
+        #[serde(default)]
+        entrypoint_id: Option<String>,
+    },
+    // go back to the previous view of the view stack
+    PopView {
+        plugin_id: String,
+        #[serde(default)]
+        entrypoint_id: Option<String>,
+    },
+    RunCommand {
+        plugin_id: String,
+        entrypoint_id: String,
+    },
+    // action index is none for primary action
+    RunGeneratedCommand {
+        plugin_id: String,
+        entrypoint_id: String,
+        action_index: Option<usize>,
+    },
+    // event name and arguments are the same as the ones of react component, e.g. "onChange" with ["text"]
+    // widget ids are unique only within a view, entrypoint id and inline are taken from replace_view event
+    ViewEvent {
+        plugin_id: String,
+        #[serde(default)]
+        entrypoint_id: Option<String>,
+        #[serde(default)]
+        inline: bool,
+        widget_id: UiWidgetId,
+        event_name: String,
+        event_arguments: Vec<serde_json::Value>,
+    },
+    // image ids are taken from `images` of replace_view event
+    Images {
+        image_ids: Vec<UiImageId>,
+    },
+    // plugins pause background work while frontend is hidden
+    WindowVisibility {
+        visible: bool,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FrontendServerMessage {
+    Response {
+        id: u64,
+        result: FrontendResponse,
+    },
+    Event {
+        event: FrontendEvent,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FrontendResponse {
+    Ok,
+    Error {
+        message: String,
+    },
+    Hello {
+        protocol_version: u32,
+    },
+    SearchResults {
+        results: Vec<FrontendSearchResult>,
+    },
+    ViewOpened {
+        // false if view only runs code and never renders anything
+        interactive: bool,
+    },
+    // base64 encoded image bytes
+    Images {
+        images: HashMap<UiImageId, String>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FrontendSearchResult {
+    pub plugin_id: String,
+    pub plugin_name: String,
+    pub entrypoint_id: String,
+    pub entrypoint_name: String,
+    // "command", "view" or "generated-command"
+    pub entrypoint_type: String,
+    pub entrypoint_accessory: Option<String>,
+    pub entrypoint_actions: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FrontendEvent {
+    ShowWindow,
+    HideWindow,
+    ToggleWindow,
+    // e.g. entrypoint was opened using cli, frontend is expected to show itself and send open_view
+    OpenView {
+        plugin_id: String,
+        plugin_name: String,
+        entrypoint_id: String,
+        entrypoint_name: String,
+    },
+    // container is the same widget tree plugins render, see `schema` of component model for components and their props
+    ReplaceView {
+        plugin_id: String,
+        plugin_name: String,
+        entrypoint_id: String,
+        entrypoint_name: String,
+        // inline views are shown under the search bar of main window
+        inline: bool,
+        view_stack_depth: usize,
+        container: RootWidget,
+        images: HashMap<UiWidgetId, UiImageId>,
+    },
+    ClearInlineView {
+        plugin_id: String,
+    },
+    ShowPluginError {
+        plugin_id: String,
+        entrypoint_id: String,
+    },
+    ShowRenderError {
+        plugin_id: String,
+        entrypoint_id: String,
+        message: String,
+    },
+    UpdateLoadingBar {
+        plugin_id: String,
+        entrypoint_id: String,
+        show: bool,
+    },
+    // search results changed, e.g. plugin was installed, search should be repeated
+    SearchResultsChanged,
+    ShowHud {
+        display: String,
+    },
+}
+
+pub fn json_to_property_value(value: serde_json::Value) -> UiPropertyValue {
+    match value {
+        serde_json::Value::Null => UiPropertyValue::Undefined,
+        serde_json::Value::Bool(value) => UiPropertyValue::Bool(value),
+        serde_json::Value::Number(value) => UiPropertyValue::Number(value.as_f64().unwrap_or_default()),
+        serde_json::Value::String(value) => UiPropertyValue::String(value),
+        serde_json::Value::Array(values) => UiPropertyValue::Array(values.into_iter().map(json_to_property_value).collect()),
+        serde_json::Value::Object(values) => {
+            UiPropertyValue::Object(values.into_iter().map(|(key, value)| (key, json_to_property_value(value))).collect())
+        }
+    }
+}
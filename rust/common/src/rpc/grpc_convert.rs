@@ -1,6 +1,8 @@
-use crate::model::{PluginPreference, PluginPreferenceUserData, PreferenceEnumValue};
+use crate::frontend_config::{ThemeSelection, WindowPosition};
+use crate::model::{ActionShortcutConflict, EntrypointId, GeneralSettings, ImportItem, ImportItemKind, ImportSource, Macro, MacroStep, PhysicalKey, PhysicalShortcut, PluginId, PluginPermissionKind, PluginPreference, PluginPreferenceUserData, PreferenceEnumValue, SettingsEntrypointAction, SettingsEntrypointReference, SettingsPluginPermission, SettingsPluginPermissions, SimpleThemeTokens, ThemeTokenColor, UsageDayStatistics, UsageEntrypointStatistics, UsagePluginStatistics, UsageStatistics};
+use crate::rpc::grpc::rpc_macro_step::Step;
 use crate::rpc::grpc::rpc_ui_property_value::Value;
-use crate::rpc::grpc::{RpcEnumValue, RpcPluginPreference, RpcPluginPreferenceUserData, RpcPluginPreferenceValueType, RpcUiPropertyValue};
+use crate::rpc::grpc::{RpcActionShortcutConflict, RpcActionShortcutConflictKind, RpcEntrypointAction, RpcEntrypointKey, RpcEntrypointReference, RpcEnumValue, RpcGeneralSettings, RpcImportItem, RpcImportItemKind, RpcImportSource, RpcMacro, RpcMacroStep, RpcMacroStepDelay, RpcMacroStepRunEntrypoint, RpcPluginPermission, RpcPluginPermissionKind, RpcPluginPermissions, RpcPluginPreference, RpcPluginPreferenceUserData, RpcPluginPreferenceValueType, RpcShortcut, RpcSimpleTheme, RpcThemeColor, RpcThemeSelection, RpcUiPropertyValue, RpcGetUsageStatisticsResponse, RpcUsageDayStatistics, RpcUsageEntrypointStatistics, RpcUsagePluginStatistics, RpcWindowPosition};
 
 pub fn plugin_preference_user_data_from_rpc(value: RpcPluginPreferenceUserData) -> PluginPreferenceUserData {
     let value_type: RpcPluginPreferenceValueType = value.r#type.try_into().unwrap();
@@ -123,6 +125,19 @@ pub fn plugin_preference_user_data_from_rpc(value: RpcPluginPreferenceUserData)
                 value,
             }
         }
+        RpcPluginPreferenceValueType::Directory => {
+            let value = value.value
+                .map(|value| {
+                    match value.value.unwrap() {
+                        Value::String(value) => value,
+                        _ => unreachable!()
+                    }
+                });
+
+            PluginPreferenceUserData::Directory {
+                value
+            }
+        }
     }
 }
 
@@ -183,17 +198,27 @@ pub fn plugin_preference_user_data_to_rpc(value: PluginPreferenceUserData) -> Rp
                 ..RpcPluginPreferenceUserData::default()
             }
         }
+        PluginPreferenceUserData::Directory { value } => {
+            RpcPluginPreferenceUserData {
+                r#type: RpcPluginPreferenceValueType::Directory.into(),
+                value: value.map(|value| RpcUiPropertyValue { value: Some(Value::String(value)) }),
+                ..RpcPluginPreferenceUserData::default()
+            }
+        }
     }
 }
 
 pub fn plugin_preference_to_rpc(value: PluginPreference) -> RpcPluginPreference {
     match value {
-        PluginPreference::Number { name, default, description } => {
+        PluginPreference::Number { name, default, min, max, step, description } => {
             RpcPluginPreference {
                 r#type: RpcPluginPreferenceValueType::Number.into(),
                 default: default.map(|value| RpcUiPropertyValue { value: Some(Value::Number(value)) }),
                 name,
                 description,
+                min,
+                max,
+                step,
                 ..RpcPluginPreference::default()
             }
         }
@@ -257,6 +282,15 @@ pub fn plugin_preference_to_rpc(value: PluginPreference) -> RpcPluginPreference
                 ..RpcPluginPreference::default()
             }
         }
+        PluginPreference::Directory { name, default, description } => {
+            RpcPluginPreference {
+                r#type: RpcPluginPreferenceValueType::Directory.into(),
+                default: default.map(|value| RpcUiPropertyValue { value: Some(Value::String(value)) }),
+                name,
+                description,
+                ..RpcPluginPreference::default()
+            }
+        }
     }
 }
 
@@ -275,6 +309,9 @@ pub fn plugin_preference_from_rpc(value: RpcPluginPreference) -> PluginPreferenc
             PluginPreference::Number {
                 default,
                 name: value.name,
+                min: value.min,
+                max: value.max,
+                step: value.step,
                 description: value.description,
             }
         }
@@ -401,6 +438,453 @@ pub fn plugin_preference_from_rpc(value: RpcPluginPreference) -> PluginPreferenc
                 description: value.description,
             }
         }
+        RpcPluginPreferenceValueType::Directory => {
+            let default = value.default
+                .map(|value| {
+                    match value.value.unwrap() {
+                        Value::String(value) => value,
+                        _ => unreachable!()
+                    }
+                });
+
+            PluginPreference::Directory {
+                default,
+                name: value.name,
+                description: value.description,
+            }
+        }
+    }
+}
+
+
+pub fn shortcut_to_rpc(value: PhysicalShortcut) -> RpcShortcut {
+    RpcShortcut {
+        physical_key: value.physical_key.to_value(),
+        modifier_shift: value.modifier_shift,
+        modifier_control: value.modifier_control,
+        modifier_alt: value.modifier_alt,
+        modifier_meta: value.modifier_meta,
+    }
+}
+
+pub fn shortcut_from_rpc(value: RpcShortcut) -> PhysicalShortcut {
+    PhysicalShortcut {
+        physical_key: PhysicalKey::from_value(value.physical_key),
+        modifier_shift: value.modifier_shift,
+        modifier_control: value.modifier_control,
+        modifier_alt: value.modifier_alt,
+        modifier_meta: value.modifier_meta,
+    }
+}
+
+pub fn entrypoint_action_to_rpc(value: SettingsEntrypointAction) -> RpcEntrypointAction {
+    RpcEntrypointAction {
+        action_id: value.action_id,
+        description: value.description,
+        shortcut: Some(shortcut_to_rpc(value.shortcut)),
+        default_shortcut: Some(shortcut_to_rpc(value.default_shortcut)),
+    }
+}
+
+pub fn entrypoint_action_from_rpc(value: RpcEntrypointAction) -> SettingsEntrypointAction {
+    SettingsEntrypointAction {
+        action_id: value.action_id,
+        description: value.description,
+        shortcut: shortcut_from_rpc(value.shortcut.unwrap()),
+        default_shortcut: shortcut_from_rpc(value.default_shortcut.unwrap()),
+    }
+}
+
+pub fn entrypoint_reference_to_rpc(value: SettingsEntrypointReference) -> RpcEntrypointReference {
+    RpcEntrypointReference {
+        plugin_id: value.plugin_id.to_string(),
+        plugin_name: value.plugin_name,
+        entrypoint_id: value.entrypoint_id.to_string(),
+        entrypoint_name: value.entrypoint_name,
+    }
+}
+
+pub fn entrypoint_reference_from_rpc(value: RpcEntrypointReference) -> SettingsEntrypointReference {
+    SettingsEntrypointReference {
+        plugin_id: PluginId::from_string(value.plugin_id),
+        plugin_name: value.plugin_name,
+        entrypoint_id: EntrypointId::from_string(value.entrypoint_id),
+        entrypoint_name: value.entrypoint_name,
+    }
+}
+
+pub fn entrypoint_key_to_rpc((plugin_id, entrypoint_id): (PluginId, EntrypointId)) -> RpcEntrypointKey {
+    RpcEntrypointKey {
+        plugin_id: plugin_id.to_string(),
+        entrypoint_id: entrypoint_id.to_string(),
+    }
+}
+
+pub fn entrypoint_key_from_rpc(value: RpcEntrypointKey) -> (PluginId, EntrypointId) {
+    (PluginId::from_string(value.plugin_id), EntrypointId::from_string(value.entrypoint_id))
+}
+
+pub fn action_shortcut_conflict_to_rpc(value: ActionShortcutConflict) -> RpcActionShortcutConflict {
+    match value {
+        ActionShortcutConflict::MissingModifier => RpcActionShortcutConflict {
+            kind: RpcActionShortcutConflictKind::MissingModifier.into(),
+            action_description: String::new(),
+        },
+        ActionShortcutConflict::GlobalShortcut => RpcActionShortcutConflict {
+            kind: RpcActionShortcutConflictKind::GlobalShortcut.into(),
+            action_description: String::new(),
+        },
+        ActionShortcutConflict::Builtin => RpcActionShortcutConflict {
+            kind: RpcActionShortcutConflictKind::Builtin.into(),
+            action_description: String::new(),
+        },
+        ActionShortcutConflict::Action { action_description } => RpcActionShortcutConflict {
+            kind: RpcActionShortcutConflictKind::Action.into(),
+            action_description,
+        },
+    }
+}
+
+pub fn action_shortcut_conflict_from_rpc(value: RpcActionShortcutConflict) -> ActionShortcutConflict {
+    match value.kind() {
+        RpcActionShortcutConflictKind::MissingModifier => ActionShortcutConflict::MissingModifier,
+        RpcActionShortcutConflictKind::GlobalShortcut => ActionShortcutConflict::GlobalShortcut,
+        RpcActionShortcutConflictKind::Builtin => ActionShortcutConflict::Builtin,
+        RpcActionShortcutConflictKind::Action => ActionShortcutConflict::Action {
+            action_description: value.action_description,
+        },
     }
 }
 
+pub fn general_settings_to_rpc(value: GeneralSettings) -> RpcGeneralSettings {
+    let theme = match value.theme {
+        ThemeSelection::Auto => RpcThemeSelection::ThemeAuto,
+        ThemeSelection::Dark => RpcThemeSelection::ThemeDark,
+        ThemeSelection::Light => RpcThemeSelection::ThemeLight,
+    };
+
+    let window_position = match value.window_position {
+        WindowPosition::Center => RpcWindowPosition::WindowCenter,
+        WindowPosition::Top => RpcWindowPosition::WindowTop,
+    };
+
+    RpcGeneralSettings {
+        theme: theme.into(),
+        window_position: window_position.into(),
+        max_search_results: value.max_search_results,
+        open_window_on_start: value.open_window_on_start,
+        launch_at_login: value.launch_at_login,
+        telemetry: value.telemetry,
+        check_for_updates: value.check_for_updates,
+        usage_statistics: value.usage_statistics,
+        sound_muted: Some(value.sound_muted),
+        hide_window_on_focus_loss: Some(value.hide_window_on_focus_loss),
+        reduced_motion: Some(value.reduced_motion),
+        high_contrast: Some(value.high_contrast),
+    }
+}
+
+// optional fields are missing when other side uses older protocol version, value from `fallback` is used for them
+pub fn general_settings_from_rpc(value: RpcGeneralSettings, fallback: &GeneralSettings) -> GeneralSettings {
+    let theme = match value.theme() {
+        RpcThemeSelection::ThemeAuto => ThemeSelection::Auto,
+        RpcThemeSelection::ThemeDark => ThemeSelection::Dark,
+        RpcThemeSelection::ThemeLight => ThemeSelection::Light,
+    };
+
+    let window_position = match value.window_position() {
+        RpcWindowPosition::WindowCenter => WindowPosition::Center,
+        RpcWindowPosition::WindowTop => WindowPosition::Top,
+    };
+
+    GeneralSettings {
+        theme,
+        window_position,
+        max_search_results: value.max_search_results,
+        open_window_on_start: value.open_window_on_start,
+        launch_at_login: value.launch_at_login,
+        telemetry: value.telemetry,
+        check_for_updates: value.check_for_updates,
+        usage_statistics: value.usage_statistics,
+        sound_muted: value.sound_muted.unwrap_or(fallback.sound_muted),
+        hide_window_on_focus_loss: value.hide_window_on_focus_loss.unwrap_or(fallback.hide_window_on_focus_loss),
+        reduced_motion: value.reduced_motion.unwrap_or(fallback.reduced_motion),
+        high_contrast: value.high_contrast.unwrap_or(fallback.high_contrast),
+    }
+}
+
+pub fn simple_theme_to_rpc(value: SimpleThemeTokens) -> RpcSimpleTheme {
+    RpcSimpleTheme {
+        background_darkest_color: Some(theme_color_to_rpc(value.background_darkest_color)),
+        background_darker_color: Some(theme_color_to_rpc(value.background_darker_color)),
+        background_lighter_color: Some(theme_color_to_rpc(value.background_lighter_color)),
+        background_lightest_color: Some(theme_color_to_rpc(value.background_lightest_color)),
+        text_lightest_color: Some(theme_color_to_rpc(value.text_lightest_color)),
+        text_lighter_color: Some(theme_color_to_rpc(value.text_lighter_color)),
+        text_darker_color: Some(theme_color_to_rpc(value.text_darker_color)),
+        text_darkest_color: Some(theme_color_to_rpc(value.text_darkest_color)),
+        primary_darker_color: Some(theme_color_to_rpc(value.primary_darker_color)),
+        primary_lighter_color: Some(theme_color_to_rpc(value.primary_lighter_color)),
+        root_border_color: Some(theme_color_to_rpc(value.root_border_color)),
+        root_border_radius: value.root_border_radius,
+        root_border_width: value.root_border_width,
+        content_border_radius: value.content_border_radius,
+    }
+}
+
+pub fn simple_theme_from_rpc(value: RpcSimpleTheme) -> SimpleThemeTokens {
+    SimpleThemeTokens {
+        background_darkest_color: theme_color_from_rpc(value.background_darkest_color.unwrap_or_default()),
+        background_darker_color: theme_color_from_rpc(value.background_darker_color.unwrap_or_default()),
+        background_lighter_color: theme_color_from_rpc(value.background_lighter_color.unwrap_or_default()),
+        background_lightest_color: theme_color_from_rpc(value.background_lightest_color.unwrap_or_default()),
+        text_lightest_color: theme_color_from_rpc(value.text_lightest_color.unwrap_or_default()),
+        text_lighter_color: theme_color_from_rpc(value.text_lighter_color.unwrap_or_default()),
+        text_darker_color: theme_color_from_rpc(value.text_darker_color.unwrap_or_default()),
+        text_darkest_color: theme_color_from_rpc(value.text_darkest_color.unwrap_or_default()),
+        primary_darker_color: theme_color_from_rpc(value.primary_darker_color.unwrap_or_default()),
+        primary_lighter_color: theme_color_from_rpc(value.primary_lighter_color.unwrap_or_default()),
+        root_border_color: theme_color_from_rpc(value.root_border_color.unwrap_or_default()),
+        root_border_radius: value.root_border_radius,
+        root_border_width: value.root_border_width,
+        content_border_radius: value.content_border_radius,
+    }
+}
+
+fn theme_color_to_rpc(value: ThemeTokenColor) -> RpcThemeColor {
+    RpcThemeColor {
+        r: value.r as u32,
+        g: value.g as u32,
+        b: value.b as u32,
+        a: value.a,
+    }
+}
+
+fn theme_color_from_rpc(value: RpcThemeColor) -> ThemeTokenColor {
+    ThemeTokenColor {
+        r: value.r.min(255) as u8,
+        g: value.g.min(255) as u8,
+        b: value.b.min(255) as u8,
+        a: value.a.clamp(0.0, 1.0),
+    }
+}
+
+pub fn import_source_to_rpc(value: ImportSource) -> RpcImportSource {
+    match value {
+        ImportSource::RaycastQuicklinks => RpcImportSource::RaycastQuicklinks,
+        ImportSource::RaycastSnippets => RpcImportSource::RaycastSnippets,
+        ImportSource::AlfredWebSearches => RpcImportSource::AlfredWebSearches,
+        ImportSource::AlbertWebSearches => RpcImportSource::AlbertWebSearches,
+        ImportSource::AlbertSnippets => RpcImportSource::AlbertSnippets,
+    }
+}
+
+pub fn import_source_from_rpc(value: RpcImportSource) -> ImportSource {
+    match value {
+        RpcImportSource::RaycastQuicklinks => ImportSource::RaycastQuicklinks,
+        RpcImportSource::RaycastSnippets => ImportSource::RaycastSnippets,
+        RpcImportSource::AlfredWebSearches => ImportSource::AlfredWebSearches,
+        RpcImportSource::AlbertWebSearches => ImportSource::AlbertWebSearches,
+        RpcImportSource::AlbertSnippets => ImportSource::AlbertSnippets,
+    }
+}
+
+pub fn import_item_to_rpc(value: ImportItem) -> RpcImportItem {
+    let kind = match value.kind {
+        ImportItemKind::Quicklink => RpcImportItemKind::ImportQuicklink,
+        ImportItemKind::Snippet => RpcImportItemKind::ImportSnippet,
+        ImportItemKind::WebSearch => RpcImportItemKind::ImportWebSearch,
+    };
+
+    RpcImportItem {
+        title: value.title,
+        kind: kind.into(),
+        skip_reason: value.skip_reason,
+    }
+}
+
+pub fn import_item_from_rpc(value: RpcImportItem) -> ImportItem {
+    let kind = match value.kind() {
+        RpcImportItemKind::ImportQuicklink => ImportItemKind::Quicklink,
+        RpcImportItemKind::ImportSnippet => ImportItemKind::Snippet,
+        RpcImportItemKind::ImportWebSearch => ImportItemKind::WebSearch,
+    };
+
+    ImportItem {
+        title: value.title,
+        kind,
+        skip_reason: value.skip_reason,
+    }
+}
+
+pub fn usage_statistics_to_rpc(value: UsageStatistics) -> RpcGetUsageStatisticsResponse {
+    RpcGetUsageStatisticsResponse {
+        enabled: value.enabled,
+        entrypoints: value.entrypoints
+            .into_iter()
+            .map(|entrypoint| RpcUsageEntrypointStatistics {
+                plugin_id: entrypoint.plugin_id.to_string(),
+                plugin_name: entrypoint.plugin_name,
+                entrypoint_id: entrypoint.entrypoint_id.to_string(),
+                entrypoint_name: entrypoint.entrypoint_name,
+                launches: entrypoint.launches,
+            })
+            .collect(),
+        plugins: value.plugins
+            .into_iter()
+            .map(|plugin| RpcUsagePluginStatistics {
+                plugin_id: plugin.plugin_id.to_string(),
+                plugin_name: plugin.plugin_name,
+                launches: plugin.launches,
+            })
+            .collect(),
+        daily: value.daily
+            .into_iter()
+            .map(|day| RpcUsageDayStatistics {
+                date: day.date,
+                launches: day.launches,
+                searches: day.searches,
+            })
+            .collect(),
+        search_count: value.search_count,
+        average_search_latency_micros: value.average_search_latency_micros,
+    }
+}
+
+pub fn usage_statistics_from_rpc(value: RpcGetUsageStatisticsResponse) -> UsageStatistics {
+    UsageStatistics {
+        enabled: value.enabled,
+        entrypoints: value.entrypoints
+            .into_iter()
+            .map(|entrypoint| UsageEntrypointStatistics {
+                plugin_id: PluginId::from_string(entrypoint.plugin_id),
+                plugin_name: entrypoint.plugin_name,
+                entrypoint_id: EntrypointId::from_string(entrypoint.entrypoint_id),
+                entrypoint_name: entrypoint.entrypoint_name,
+                launches: entrypoint.launches,
+            })
+            .collect(),
+        plugins: value.plugins
+            .into_iter()
+            .map(|plugin| UsagePluginStatistics {
+                plugin_id: PluginId::from_string(plugin.plugin_id),
+                plugin_name: plugin.plugin_name,
+                launches: plugin.launches,
+            })
+            .collect(),
+        daily: value.daily
+            .into_iter()
+            .map(|day| UsageDayStatistics {
+                date: day.date,
+                launches: day.launches,
+                searches: day.searches,
+            })
+            .collect(),
+        search_count: value.search_count,
+        average_search_latency_micros: value.average_search_latency_micros,
+    }
+}
+
+pub fn macro_to_rpc(value: Macro) -> RpcMacro {
+    RpcMacro {
+        id: value.id,
+        name: value.name,
+        steps: value.steps
+            .into_iter()
+            .map(|step| {
+                let step = match step {
+                    MacroStep::RunEntrypoint { plugin_id, plugin_name, entrypoint_id, entrypoint_name, action_index } => {
+                        Step::RunEntrypoint(RpcMacroStepRunEntrypoint {
+                            plugin_id: plugin_id.to_string(),
+                            plugin_name,
+                            entrypoint_id: entrypoint_id.to_string(),
+                            entrypoint_name,
+                            action_index: action_index.map(|index| index as u32),
+                        })
+                    }
+                    MacroStep::Delay { millis } => Step::Delay(RpcMacroStepDelay { millis }),
+                };
+
+                RpcMacroStep { step: Some(step) }
+            })
+            .collect(),
+    }
+}
+
+pub fn macro_from_rpc(value: RpcMacro) -> Macro {
+    Macro {
+        id: value.id,
+        name: value.name,
+        steps: value.steps
+            .into_iter()
+            .map(|step| {
+                match step.step.unwrap() {
+                    Step::RunEntrypoint(step) => {
+                        MacroStep::RunEntrypoint {
+                            plugin_id: PluginId::from_string(step.plugin_id),
+                            plugin_name: step.plugin_name,
+                            entrypoint_id: EntrypointId::from_string(step.entrypoint_id),
+                            entrypoint_name: step.entrypoint_name,
+                            action_index: step.action_index.map(|index| index as usize),
+                        }
+                    }
+                    Step::Delay(step) => MacroStep::Delay { millis: step.millis },
+                }
+            })
+            .collect(),
+    }
+}
+
+pub fn plugin_permission_kind_to_rpc(value: PluginPermissionKind) -> RpcPluginPermissionKind {
+    match value {
+        PluginPermissionKind::Network => RpcPluginPermissionKind::PermissionNetwork,
+        PluginPermissionKind::FilesystemRead => RpcPluginPermissionKind::PermissionFilesystemRead,
+        PluginPermissionKind::FilesystemWrite => RpcPluginPermissionKind::PermissionFilesystemWrite,
+        PluginPermissionKind::ExecCommand => RpcPluginPermissionKind::PermissionExecCommand,
+        PluginPermissionKind::ExecExecutable => RpcPluginPermissionKind::PermissionExecExecutable,
+        PluginPermissionKind::Clipboard => RpcPluginPermissionKind::PermissionClipboard,
+    }
+}
+
+pub fn plugin_permission_kind_from_rpc(value: RpcPluginPermissionKind) -> PluginPermissionKind {
+    match value {
+        RpcPluginPermissionKind::PermissionNetwork => PluginPermissionKind::Network,
+        RpcPluginPermissionKind::PermissionFilesystemRead => PluginPermissionKind::FilesystemRead,
+        RpcPluginPermissionKind::PermissionFilesystemWrite => PluginPermissionKind::FilesystemWrite,
+        RpcPluginPermissionKind::PermissionExecCommand => PluginPermissionKind::ExecCommand,
+        RpcPluginPermissionKind::PermissionExecExecutable => PluginPermissionKind::ExecExecutable,
+        RpcPluginPermissionKind::PermissionClipboard => PluginPermissionKind::Clipboard,
+    }
+}
+
+pub fn plugin_permissions_to_rpc(value: SettingsPluginPermissions) -> RpcPluginPermissions {
+    RpcPluginPermissions {
+        plugin_id: value.plugin_id.to_string(),
+        plugin_name: value.plugin_name,
+        permissions: value.permissions
+            .into_iter()
+            .map(|permission| RpcPluginPermission {
+                kind: plugin_permission_kind_to_rpc(permission.kind).into(),
+                value: permission.value,
+                granted_at: permission.granted_at,
+                revoked: permission.revoked,
+            })
+            .collect(),
+    }
+}
+
+pub fn plugin_permissions_from_rpc(value: RpcPluginPermissions) -> SettingsPluginPermissions {
+    SettingsPluginPermissions {
+        plugin_id: PluginId::from_string(value.plugin_id),
+        plugin_name: value.plugin_name,
+        permissions: value.permissions
+            .into_iter()
+            .map(|permission| SettingsPluginPermission {
+                kind: plugin_permission_kind_from_rpc(permission.kind()),
+                value: permission.value,
+                granted_at: permission.granted_at,
+                revoked: permission.revoked,
+            })
+            .collect(),
+    }
+}
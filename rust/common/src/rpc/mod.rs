@@ -1,5 +1,23 @@
 pub mod backend_api;
 pub mod frontend_api;
 pub mod backend_server;
+pub mod frontend_protocol;
 mod grpc;
 mod grpc_convert;
+
+// version of the grpc api between cli, client, management client and server
+// bump on every change to the api, so newer clients can detect rpcs which older server doesn't have.
+// bump MIN_SUPPORTED_PROTOCOL_VERSION only when older versions can no longer be served
+pub const PROTOCOL_VERSION: u32 = 30;
+pub const MIN_SUPPORTED_PROTOCOL_VERSION: u32 = 1;
+
+// version 1 is the api before protocol version was introduced, it is sent as 0 (protobuf default)
+pub(crate) const LEGACY_PROTOCOL_VERSION: u32 = 1;
+
+pub(crate) fn normalize_protocol_version(version: u32) -> u32 {
+    if version == 0 {
+        LEGACY_PROTOCOL_VERSION
+    } else {
+        version
+    }
+}
@@ -14,7 +14,7 @@ pub enum ScenarioFrontendEvent {
     ReplaceView {
         entrypoint_id: String,
         render_location: ScenarioUiRenderLocation,
-        top_level_view: bool,
+        view_stack_depth: usize,
         container: RootWidget,
         #[serde(with="base64")]
         images: HashMap<UiWidgetId, Vec<u8>>,
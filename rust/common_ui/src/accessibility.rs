@@ -0,0 +1,396 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
+
+use accesskit::{Action, ActionHandler, ActionRequest, ActivationHandler, Node, NodeId, Tree, TreeUpdate};
+pub use accesskit::Role;
+use iced::futures::channel::mpsc;
+use iced::futures::{SinkExt, StreamExt};
+use iced::{stream, window, Subscription, Task};
+
+// semantics of the ui exposed to screen readers and other assistive technologies using AccessKit.
+// iced doesn't have accessibility support, so frontends describe what is shown on the screen themselves
+// after every update. tree is only built when assistive technology is actually listening
+
+#[derive(Debug, Clone)]
+pub struct AccessibleNode {
+    // identifies the node between updates, so that assistive technology can track focus
+    key: String,
+    role: Role,
+    label: Option<String>,
+    value: Option<String>,
+    selected: Option<bool>,
+    toggled: Option<bool>,
+    disabled: bool,
+    focusable: bool,
+    clickable: bool,
+    children: Vec<AccessibleNode>,
+}
+
+impl AccessibleNode {
+    pub fn new(key: impl Into<String>, role: Role) -> Self {
+        Self {
+            key: key.into(),
+            role,
+            label: None,
+            value: None,
+            selected: None,
+            toggled: None,
+            disabled: false,
+            focusable: false,
+            clickable: false,
+            children: vec![],
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    pub fn toggled(mut self, toggled: bool) -> Self {
+        self.toggled = Some(toggled);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn focusable(mut self) -> Self {
+        self.focusable = true;
+        self
+    }
+
+    // clickable nodes are also focusable
+    pub fn clickable(mut self) -> Self {
+        self.focusable = true;
+        self.clickable = true;
+        self
+    }
+
+    pub fn child(mut self, child: AccessibleNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoIterator<Item = AccessibleNode>) -> Self {
+        self.children.extend(children);
+        self
+    }
+}
+
+// action requested by assistive technology, e.g. screen reader user activated list item
+#[derive(Debug, Clone)]
+pub struct AccessibilityAction {
+    pub key: String,
+    pub kind: AccessibilityActionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessibilityActionKind {
+    Focus,
+    Click,
+}
+
+struct AccessibilityState {
+    app_name: String,
+    adapter: Option<PlatformAdapter>,
+}
+
+// adapters are not thread safe on all platforms, ui updates are always done on main thread
+thread_local! {
+    static STATE: RefCell<Option<AccessibilityState>> = const { RefCell::new(None) };
+}
+
+// action handler is called by adapter on its own thread, so node ids are resolved using map shared with it
+static NODE_KEYS: Mutex<BTreeMap<u64, String>> = Mutex::new(BTreeMap::new());
+
+static ACTION_SENDER: OnceLock<mpsc::UnboundedSender<AccessibilityAction>> = OnceLock::new();
+static ACTION_RECEIVER: Mutex<Option<mpsc::UnboundedReceiver<AccessibilityAction>>> = Mutex::new(None);
+
+pub fn init(app_name: &str) {
+    let (sender, receiver) = mpsc::unbounded();
+
+    if ACTION_SENDER.set(sender).is_err() {
+        tracing::warn!("accessibility is already initialized");
+        return;
+    }
+
+    *ACTION_RECEIVER.lock().expect("lock is poisoned") = Some(receiver);
+
+    // on linux assistive technologies are connected through at-spi bus, which doesn't need a window.
+    // this also covers layer shell windows on wayland which do not have a handle for other adapters
+    #[cfg(target_os = "linux")]
+    let adapter = Some(PlatformAdapter::Unix(accesskit_unix::Adapter::new(InitialTree, ActionForwarder, Deactivation)));
+
+    #[cfg(not(target_os = "linux"))]
+    let adapter: Option<PlatformAdapter> = None;
+
+    STATE.with_borrow_mut(|state| {
+        *state = Some(AccessibilityState {
+            app_name: app_name.to_string(),
+            adapter,
+        })
+    });
+}
+
+// on windows and macos adapter is attached to native window, needs to be done every time window is opened
+pub fn attach_window(window_id: window::Id) -> Task<()> {
+    window::run_with_handle(window_id, |handle| {
+        #[cfg(any(target_os = "windows", target_os = "macos"))]
+        {
+            use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+            let adapter = match handle.window_handle().map(|handle| handle.as_raw()) {
+                #[cfg(target_os = "windows")]
+                Ok(RawWindowHandle::Win32(handle)) => {
+                    let hwnd = accesskit_windows::HWND(handle.hwnd.get() as *mut std::ffi::c_void);
+
+                    PlatformAdapter::Windows(accesskit_windows::SubclassingAdapter::new(hwnd, InitialTree, ActionForwarder))
+                }
+                #[cfg(target_os = "macos")]
+                Ok(RawWindowHandle::AppKit(handle)) => {
+                    let view = handle.ns_view.as_ptr();
+
+                    PlatformAdapter::MacOs(unsafe { accesskit_macos::SubclassingAdapter::new(view, InitialTree, ActionForwarder) })
+                }
+                _ => {
+                    tracing::warn!("unable to attach accessibility adapter, unsupported window handle");
+                    return;
+                }
+            };
+
+            STATE.with_borrow_mut(|state| {
+                if let Some(state) = state {
+                    state.adapter = Some(adapter);
+                }
+            });
+        }
+
+        #[cfg(target_os = "linux")]
+        let _ = handle;
+    })
+}
+
+// root is expected to be a node with window role, focus is a key of focused node
+pub fn update(build: impl FnOnce() -> (AccessibleNode, Option<String>)) {
+    STATE.with_borrow_mut(|state| {
+        let Some(state) = state else {
+            return;
+        };
+
+        let app_name = state.app_name.clone();
+
+        let Some(adapter) = &mut state.adapter else {
+            return;
+        };
+
+        let build_update = move || {
+            let (root, focus) = build();
+            tree_update(app_name, root, focus)
+        };
+
+        match adapter {
+            #[cfg(target_os = "linux")]
+            PlatformAdapter::Unix(adapter) => adapter.update_if_active(build_update),
+            #[cfg(target_os = "windows")]
+            PlatformAdapter::Windows(adapter) => {
+                if let Some(events) = adapter.update_if_active(build_update) {
+                    events.raise();
+                }
+            }
+            #[cfg(target_os = "macos")]
+            PlatformAdapter::MacOs(adapter) => {
+                if let Some(events) = adapter.update_if_active(build_update) {
+                    events.raise();
+                }
+            }
+        }
+    });
+}
+
+pub fn update_window_focus(focused: bool) {
+    STATE.with_borrow_mut(|state| {
+        let Some(AccessibilityState { adapter: Some(adapter), .. }) = state else {
+            return;
+        };
+
+        match adapter {
+            #[cfg(target_os = "linux")]
+            PlatformAdapter::Unix(adapter) => adapter.update_window_focus_state(focused),
+            // focus of native window is tracked by subclassing adapter itself
+            #[cfg(target_os = "windows")]
+            PlatformAdapter::Windows(_) => {
+                let _ = focused;
+            }
+            #[cfg(target_os = "macos")]
+            PlatformAdapter::MacOs(_) => {
+                let _ = focused;
+            }
+        }
+    });
+}
+
+pub fn subscription() -> Subscription<AccessibilityAction> {
+    struct AccessibilityActionListener;
+
+    Subscription::run_with_id(
+        TypeId::of::<AccessibilityActionListener>(),
+        stream::channel(
+            10,
+            |mut sender| async move {
+                let receiver = ACTION_RECEIVER.lock().expect("lock is poisoned").take();
+
+                if let Some(mut receiver) = receiver {
+                    while let Some(action) = receiver.next().await {
+                        if let Err(err) = sender.send(action).await {
+                            tracing::warn!("unable to forward accessibility action: {:?}", err);
+                        }
+                    }
+                }
+
+                std::future::pending::<()>().await;
+
+                unreachable!()
+            },
+        )
+    )
+}
+
+enum PlatformAdapter {
+    #[cfg(target_os = "linux")]
+    Unix(accesskit_unix::Adapter),
+    #[cfg(target_os = "windows")]
+    Windows(accesskit_windows::SubclassingAdapter),
+    #[cfg(target_os = "macos")]
+    MacOs(accesskit_macos::SubclassingAdapter),
+}
+
+fn node_id(key: &str) -> NodeId {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    NodeId(hasher.finish())
+}
+
+fn tree_update(app_name: String, root: AccessibleNode, focus: Option<String>) -> TreeUpdate {
+    let mut nodes = vec![];
+    let mut keys = BTreeMap::new();
+
+    let root_id = add_node(root, &mut nodes, &mut keys);
+
+    let focus = focus
+        .map(|focus| node_id(&focus))
+        .filter(|focus| keys.contains_key(&focus.0))
+        .unwrap_or(root_id);
+
+    *NODE_KEYS.lock().expect("lock is poisoned") = keys;
+
+    let mut tree = Tree::new(root_id);
+    tree.app_name = Some(app_name);
+    tree.toolkit_name = Some("iced".to_string());
+
+    TreeUpdate {
+        nodes,
+        tree: Some(tree),
+        focus,
+    }
+}
+
+fn add_node(node: AccessibleNode, nodes: &mut Vec<(NodeId, Node)>, keys: &mut BTreeMap<u64, String>) -> NodeId {
+    let id = node_id(&node.key);
+
+    let children: Vec<_> = node.children
+        .into_iter()
+        .map(|child| add_node(child, nodes, keys))
+        .collect();
+
+    let mut result = Node::new(node.role);
+
+    if let Some(label) = node.label {
+        result.set_label(label);
+    }
+
+    if let Some(value) = node.value {
+        result.set_value(value);
+    }
+
+    if let Some(selected) = node.selected {
+        result.set_selected(selected);
+    }
+
+    if let Some(toggled) = node.toggled {
+        result.set_toggled(if toggled { accesskit::Toggled::True } else { accesskit::Toggled::False });
+    }
+
+    if node.disabled {
+        result.set_disabled();
+    }
+
+    if node.focusable {
+        result.add_action(Action::Focus);
+    }
+
+    if node.clickable {
+        result.add_action(Action::Click);
+    }
+
+    result.set_children(children);
+
+    nodes.push((id, result));
+    keys.insert(id.0, node.key);
+
+    id
+}
+
+struct InitialTree;
+
+impl ActivationHandler for InitialTree {
+    fn request_initial_tree(&mut self) -> Option<TreeUpdate> {
+        // tree is provided on next ui update
+        None
+    }
+}
+
+struct ActionForwarder;
+
+impl ActionHandler for ActionForwarder {
+    fn do_action(&mut self, request: ActionRequest) {
+        let kind = match request.action {
+            Action::Focus => AccessibilityActionKind::Focus,
+            Action::Click => AccessibilityActionKind::Click,
+            _ => return,
+        };
+
+        let Some(key) = NODE_KEYS.lock().expect("lock is poisoned").get(&request.target.0).cloned() else {
+            return;
+        };
+
+        if let Some(sender) = ACTION_SENDER.get() {
+            let _ = sender.unbounded_send(AccessibilityAction { key, kind });
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+struct Deactivation;
+
+#[cfg(target_os = "linux")]
+impl accesskit::DeactivationHandler for Deactivation {
+    fn deactivate_accessibility(&mut self) {
+    }
+}
@@ -1,3 +1,5 @@
+pub mod accessibility;
+
 use iced::{Element, Padding, Pixels};
 use iced::border::Radius;
 use iced::keyboard::Modifiers;
@@ -23,6 +25,16 @@ pub fn radius(top_left: impl Into<Pixels>, top_right: impl Into<Pixels>, bottom_
     }
 }
 
+// shows as many decimal places as slider step has, so that floating point errors are not visible
+pub fn format_slider_value(value: f64, step: f64) -> String {
+    let precision = step.to_string()
+        .split_once('.')
+        .map(|(_, fraction)| fraction.len())
+        .unwrap_or(0);
+
+    format!("{:.*}", precision, value)
+}
+
 pub fn shortcut_to_text<'a, Message, Theme: text::Catalog + 'a>(
     shortcut: &PhysicalShortcut
 ) -> (
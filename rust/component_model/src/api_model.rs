@@ -0,0 +1,299 @@
+use indexmap::IndexMap;
+use serde::Serialize;
+
+// part of plugin runtime api that is not covered by component model, typescript definitions are generated from it.
+// has to be kept in sync with ops in plugin_runtime and with types they (de)serialize
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiModel {
+    pub types: IndexMap<String, ApiType>,
+    pub ops: Vec<ApiOp>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiOp {
+    pub name: String,
+    pub arguments: Vec<ApiField>,
+    pub result: ApiType,
+    #[serde(rename = "async")]
+    pub is_async: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiField {
+    pub name: String,
+    pub optional: bool,
+    #[serde(rename = "type")]
+    pub field_type: ApiType,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum ApiType {
+    #[serde(rename = "string")]
+    String,
+    #[serde(rename = "number")]
+    Number,
+    #[serde(rename = "boolean")]
+    Boolean,
+    #[serde(rename = "void")]
+    Void,
+    #[serde(rename = "undefined")]
+    Undefined,
+    #[serde(rename = "array_buffer")]
+    ArrayBuffer,
+    #[serde(rename = "literal")]
+    Literal {
+        value: String
+    },
+    #[serde(rename = "array")]
+    Array {
+        item: Box<ApiType>
+    },
+    #[serde(rename = "object")]
+    Object {
+        fields: Vec<ApiField>
+    },
+    #[serde(rename = "union")]
+    Union {
+        items: Vec<ApiType>
+    },
+    // type defined in this model or hand-written in typings
+    #[serde(rename = "type_ref")]
+    TypeRef {
+        name: String
+    },
+}
+
+fn field(name: impl Into<String>, optional: bool, field_type: ApiType) -> ApiField {
+    ApiField {
+        name: name.into(),
+        optional,
+        field_type,
+    }
+}
+
+fn op<I>(name: impl Into<String>, is_async: bool, arguments: I, result: ApiType) -> ApiOp
+    where I: IntoIterator<Item=ApiField>
+{
+    ApiOp {
+        name: name.into(),
+        arguments: arguments.into_iter().collect(),
+        result,
+        is_async,
+    }
+}
+
+fn type_ref(name: impl Into<String>) -> ApiType {
+    ApiType::TypeRef { name: name.into() }
+}
+
+fn literal(value: impl Into<String>) -> ApiType {
+    ApiType::Literal { value: value.into() }
+}
+
+fn optional(item: ApiType) -> ApiType {
+    ApiType::Union { items: vec![item, ApiType::Undefined] }
+}
+
+fn array(item: ApiType) -> ApiType {
+    ApiType::Array { item: Box::new(item) }
+}
+
+pub fn create_api_model() -> ApiModel {
+    let mut types = IndexMap::new();
+
+    // JsEntrypointBadge
+    types.insert(
+        "EntrypointBadge".to_string(),
+        ApiType::Union {
+            items: vec![
+                ApiType::Object {
+                    fields: vec![
+                        field("type", false, literal("count")),
+                        field("count", false, ApiType::Number),
+                    ]
+                },
+                ApiType::Object {
+                    fields: vec![
+                        field("type", false, literal("dot")),
+                    ]
+                },
+            ]
+        }
+    );
+
+    // JsSound
+    types.insert(
+        "Sound".to_string(),
+        ApiType::Union {
+            items: vec![
+                ApiType::Object {
+                    fields: vec![
+                        field("type", false, literal("builtin")),
+                        field("name", false, type_ref("BuiltinSound")),
+                    ]
+                },
+                ApiType::Object {
+                    fields: vec![
+                        field("type", false, literal("asset")),
+                        field("path", false, ApiType::String),
+                    ]
+                },
+            ]
+        }
+    );
+
+    // JsBuiltinSound
+    types.insert(
+        "BuiltinSound".to_string(),
+        ApiType::Union {
+            items: vec![literal("timer-finished"), literal("error")]
+        }
+    );
+
+    // JsUndoEntryKind
+    types.insert(
+        "UndoEntryKind".to_string(),
+        ApiType::Union {
+            items: vec![literal("action"), literal("undo"), literal("redo")]
+        }
+    );
+
+    // JsSearchResultPreview
+    types.insert(
+        "SearchResultPreview".to_string(),
+        ApiType::Object {
+            fields: vec![
+                field("text", false, optional(ApiType::String)),
+                field("image", false, optional(ApiType::ArrayBuffer)),
+                field("metadata", false, array(type_ref("SearchResultPreviewMetadata"))),
+            ]
+        }
+    );
+
+    types.insert(
+        "SearchResultPreviewMetadata".to_string(),
+        ApiType::Object {
+            fields: vec![
+                field("label", false, ApiType::String),
+                field("value", false, ApiType::String),
+            ]
+        }
+    );
+
+    // JsActiveWindow
+    types.insert(
+        "ActiveWindow".to_string(),
+        ApiType::Object {
+            fields: vec![
+                field("app_id", false, ApiType::String),
+                field("app_name", false, optional(ApiType::String)),
+                field("title", false, optional(ApiType::String)),
+            ]
+        }
+    );
+
+    // JsClipboardData
+    types.insert(
+        "ClipboardData".to_string(),
+        ApiType::Object {
+            fields: vec![
+                field("text_data", true, ApiType::String),
+                field("png_data", true, array(ApiType::Number)),
+            ]
+        }
+    );
+
+    // JsPreferenceUserData, value of each preference type defined in plugin manifest
+    types.insert(
+        "PreferenceValueTypes".to_string(),
+        ApiType::Object {
+            fields: vec![
+                field("number", false, ApiType::Number),
+                field("string", false, ApiType::String),
+                field("enum", false, ApiType::String),
+                field("bool", false, ApiType::Boolean),
+                field("list_of_strings", false, array(ApiType::String)),
+                field("list_of_numbers", false, array(ApiType::Number)),
+                field("list_of_enums", false, array(ApiType::String)),
+                field("directory", false, ApiType::String),
+            ]
+        }
+    );
+
+    types.insert(
+        "PreferenceValue".to_string(),
+        ApiType::Union {
+            items: vec![
+                ApiType::Number,
+                ApiType::String,
+                ApiType::Boolean,
+                array(ApiType::String),
+                array(ApiType::Number),
+            ]
+        }
+    );
+
+    // JsRenderError, returned by op_react_replace_view
+    types.insert(
+        "RenderError".to_string(),
+        ApiType::Object {
+            fields: vec![
+                field("message", false, ApiType::String),
+                field("component_path", false, array(ApiType::String)),
+            ]
+        }
+    );
+
+    let ops = vec![
+        op("show_hud", false, [field("display", false, ApiType::String)], ApiType::Void),
+        op("set_entrypoint_badge", true, [
+            field("entrypoint_id", false, ApiType::String),
+            field("badge", false, optional(type_ref("EntrypointBadge"))),
+        ], ApiType::Void),
+        op("register_undo", true, [
+            field("undo_id", false, ApiType::Number),
+            field("description", false, ApiType::String),
+            field("kind", false, type_ref("UndoEntryKind")),
+        ], ApiType::Void),
+        op("show_search_result_preview", true, [
+            field("entrypoint_id", false, ApiType::String),
+            field("preview", false, optional(type_ref("SearchResultPreview"))),
+        ], ApiType::Void),
+        op("clipboard_read", true, [], type_ref("ClipboardData")),
+        op("clipboard_read_text", true, [], optional(ApiType::String)),
+        op("clipboard_write", true, [field("data", false, type_ref("ClipboardData"))], ApiType::Void),
+        op("clipboard_write_text", true, [field("data", false, ApiType::String)], ApiType::Void),
+        op("clipboard_clear", true, [], ApiType::Void),
+        op("play_sound", true, [field("sound", false, type_ref("Sound"))], ApiType::Void),
+        op("secret_read", true, [
+            field("service", false, ApiType::String),
+            field("account", false, ApiType::String),
+        ], optional(ApiType::String)),
+        op("active_window", true, [], optional(type_ref("ActiveWindow"))),
+        // payload is json serialized by plugin
+        op("event_publish", true, [
+            field("topic", false, ApiType::String),
+            field("payload", false, ApiType::String),
+        ], ApiType::Void),
+        // values are json serialized by plugin
+        op("view_state_read", true, [
+            field("entrypoint_id", false, ApiType::String),
+            field("key", false, ApiType::String),
+        ], optional(ApiType::String)),
+        op("view_state_write", true, [
+            field("entrypoint_id", false, ApiType::String),
+            field("key", false, ApiType::String),
+            field("value", false, optional(ApiType::String)),
+        ], ApiType::Void),
+        op("environment_gauntlet_version", false, [], ApiType::Number),
+        op("environment_is_development", false, [], ApiType::Boolean),
+        op("environment_plugin_data_dir", false, [], ApiType::String),
+        op("environment_plugin_cache_dir", false, [], ApiType::String),
+    ];
+
+    ApiModel {
+        types,
+        ops,
+    }
+}
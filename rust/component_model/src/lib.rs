@@ -4,6 +4,10 @@ use std::sync::Arc;
 use indexmap::IndexMap;
 use serde::{Serialize, Serializer};
 
+mod api_model;
+
+pub use api_model::{create_api_model, ApiField, ApiModel, ApiOp, ApiType};
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ComponentName(Arc<str>);
 
@@ -168,6 +172,7 @@ pub enum Children {
         per_type_members: IndexMap<String, ComponentRef>,
         #[serde(rename = "textPartInternalName")]
         text_part_internal_name: String,
+        description: String,
     },
     #[serde(rename = "members")]
     Members {
@@ -193,7 +198,7 @@ pub struct ComponentRef {
     pub arity: Arity,
 }
 
-fn children_string_or_members<I1, I2>(ordered_members: I1, per_type_members: I2) -> Children
+fn children_string_or_members<I1, I2>(ordered_members: I1, per_type_members: I2, description: String) -> Children
 where I1: IntoIterator<Item=(String, ComponentRef)>,
       I2: IntoIterator<Item=(String, ComponentRef)>
 {
@@ -201,6 +206,7 @@ where I1: IntoIterator<Item=(String, ComponentRef)>,
         text_part_internal_name: "text_part".to_owned(),
         ordered_members: ordered_members.into_iter().collect(),
         per_type_members: per_type_members.into_iter().collect(),
+        description,
     }
 }
 
@@ -548,6 +554,13 @@ fn root(children: &[&Component]) -> Component {
                     map
                 },
             }),
+            ("ImageSourceThumbnail".to_owned(), SharedType::Object {
+                items: {
+                    let mut map = IndexMap::new();
+                    map.insert("thumbnail".to_string(), PropertyType::String);
+                    map
+                },
+            }),
             ("ImageSource".to_owned(), SharedType::Union {
                 items: vec![
                     PropertyType::SharedTypeRef {
@@ -556,6 +569,9 @@ fn root(children: &[&Component]) -> Component {
                     PropertyType::SharedTypeRef {
                         name: "ImageSourceAsset".to_owned()
                     },
+                    PropertyType::SharedTypeRef {
+                        name: "ImageSourceThumbnail".to_owned()
+                    },
                 ]
             }),
             ("Image".to_owned(), SharedType::Union {
@@ -568,6 +584,39 @@ fn root(children: &[&Component]) -> Component {
                     }
                 ],
             }),
+            ("DragPayloadFile".to_owned(), SharedType::Object {
+                items: {
+                    let mut map = IndexMap::new();
+                    map.insert("file".to_string(), PropertyType::String);
+                    map
+                },
+            }),
+            ("DragPayloadUrl".to_owned(), SharedType::Object {
+                items: {
+                    let mut map = IndexMap::new();
+                    map.insert("url".to_string(), PropertyType::String);
+                    map
+                },
+            }),
+            ("DragPayload".to_owned(), SharedType::Union {
+                items: vec![
+                    PropertyType::SharedTypeRef {
+                        name: "DragPayloadFile".to_owned()
+                    },
+                    PropertyType::SharedTypeRef {
+                        name: "DragPayloadUrl".to_owned()
+                    },
+                ]
+            }),
+            ("TextColor".to_owned(), SharedType::Enum {
+                items: [
+                    "Accent",
+                    "Muted",
+                    "Success",
+                    "Warning",
+                    "Danger",
+                ].into_iter().map(|s| s.to_string()).collect()
+            }),
         ]),
     }
 }
@@ -602,7 +651,11 @@ pub fn create_component_model() -> Vec<Component> {
         [
             property("id", mark_doc!("/action/props/id.md"), true, PropertyType::String),
             property("label", mark_doc!("/action/props/label.md"), false, PropertyType::String),
-            event("onAction", mark_doc!("/action/props/onAction.md"), false, [])
+            property("keepWindowOpen", mark_doc!("/action/props/keepWindowOpen.md"), true, PropertyType::Boolean),
+            event("onAction", mark_doc!("/action/props/onAction.md"), false, []),
+            event("onBatchAction", mark_doc!("/action/props/onBatchAction.md"), true, [
+                property("ids", "".to_string(), false, PropertyType::Array { item: Box::new(PropertyType::String) })
+            ])
         ],
         children_none(),
     );
@@ -799,6 +852,40 @@ pub fn create_component_model() -> Vec<Component> {
         children_none(),
     );
 
+    let color_swatch_component = component(
+        "color_swatch",
+        mark_doc!("/color_swatch/description.md"),
+        "ColorSwatch",
+        [
+            property("color", mark_doc!("/color_swatch/props/color.md"), false, PropertyType::String),
+        ],
+        children_none(),
+    );
+
+    let sparkline_component = component(
+        "sparkline",
+        mark_doc!("/sparkline/description.md"),
+        "Sparkline",
+        [
+            property("data", mark_doc!("/sparkline/props/data.md"), false, PropertyType::Array { item: Box::new(PropertyType::Number) }),
+            property("color", mark_doc!("/sparkline/props/color.md"), true, PropertyType::String),
+            property("height", mark_doc!("/sparkline/props/height.md"), true, PropertyType::Number),
+        ],
+        children_none(),
+    );
+
+    let bar_chart_component = component(
+        "bar_chart",
+        mark_doc!("/bar_chart/description.md"),
+        "BarChart",
+        [
+            property("data", mark_doc!("/bar_chart/props/data.md"), false, PropertyType::Array { item: Box::new(PropertyType::Number) }),
+            property("color", mark_doc!("/bar_chart/props/color.md"), true, PropertyType::String),
+            property("height", mark_doc!("/bar_chart/props/height.md"), true, PropertyType::Number),
+        ],
+        children_none(),
+    );
+
     let code_block_component = component(
         "code_block",
         mark_doc!("/code_block/description.md"),
@@ -814,16 +901,66 @@ pub fn create_component_model() -> Vec<Component> {
     //     children_string()
     // );
 
+    let paragraph_bold_component = component(
+        "paragraph_bold",
+        mark_doc!("/paragraph_bold/description.md"),
+        "ParagraphBold",
+        [],
+        children_string(mark_doc!("/paragraph_bold/props/children.md")),
+    );
+
+    let paragraph_italic_component = component(
+        "paragraph_italic",
+        mark_doc!("/paragraph_italic/description.md"),
+        "ParagraphItalic",
+        [],
+        children_string(mark_doc!("/paragraph_italic/props/children.md")),
+    );
+
+    let paragraph_code_component = component(
+        "paragraph_code",
+        mark_doc!("/paragraph_code/description.md"),
+        "ParagraphCode",
+        [],
+        children_string(mark_doc!("/paragraph_code/props/children.md")),
+    );
+
+    let paragraph_link_component = component(
+        "paragraph_link",
+        mark_doc!("/paragraph_link/description.md"),
+        "ParagraphLink",
+        [
+            property("href", mark_doc!("/paragraph_link/props/href.md"), false, PropertyType::String),
+        ],
+        children_string(mark_doc!("/paragraph_link/props/children.md")),
+    );
+
+    let paragraph_color_component = component(
+        "paragraph_color",
+        mark_doc!("/paragraph_color/description.md"),
+        "ParagraphColor",
+        [
+            property("color", mark_doc!("/paragraph_color/props/color.md"), false, PropertyType::SharedTypeRef { name: "TextColor".to_owned() }),
+        ],
+        children_string(mark_doc!("/paragraph_color/props/children.md")),
+    );
+
     let paragraph_component = component(
         "paragraph",
         mark_doc!("/paragraph/description.md"),
         "Paragraph",
         [],
-        children_string(mark_doc!("/paragraph/props/children.md")),
-        // children_string_or_members([
-        //     member("Link", &link_component),
-        //     member("Code", &code_component),
-        // ]),
+        children_string_or_members(
+            [
+                member("Bold", &paragraph_bold_component, Arity::ZeroOrMore),
+                member("Italic", &paragraph_italic_component, Arity::ZeroOrMore),
+                member("Code", &paragraph_code_component, Arity::ZeroOrMore),
+                member("Link", &paragraph_link_component, Arity::ZeroOrMore),
+                member("Color", &paragraph_color_component, Arity::ZeroOrMore),
+            ],
+            [],
+            mark_doc!("/paragraph/props/children.md"),
+        ),
     );
 
     // content shouldn't have any interactable items
@@ -845,6 +982,9 @@ pub fn create_component_model() -> Vec<Component> {
                 member("H6", &h6_component, Arity::ZeroOrMore),
                 member("HorizontalBreak", &horizontal_break_component, Arity::ZeroOrMore),
                 member("CodeBlock", &code_block_component, Arity::ZeroOrMore),
+                member("ColorSwatch", &color_swatch_component, Arity::ZeroOrMore),
+                member("Sparkline", &sparkline_component, Arity::ZeroOrMore),
+                member("BarChart", &bar_chart_component, Arity::ZeroOrMore),
                 // member("Code", &code_component),
             ],
             []
@@ -962,6 +1102,23 @@ pub fn create_component_model() -> Vec<Component> {
         ),
     );
 
+    let slider_component = component(
+        "slider",
+        mark_doc!("/slider/description.md"),
+        "Slider",
+        [
+            property("label", mark_doc!("/slider/props/label.md"), true, PropertyType::String),
+            property("value", mark_doc!("/slider/props/value.md"), true, PropertyType::Number),
+            property("min", mark_doc!("/slider/props/min.md"), true, PropertyType::Number),
+            property("max", mark_doc!("/slider/props/max.md"), true, PropertyType::Number),
+            property("step", mark_doc!("/slider/props/step.md"), true, PropertyType::Number),
+            event("onChange", mark_doc!("/slider/props/onChange.md"), true, [
+                property("value", "".to_string(), false, PropertyType::Number)
+            ])
+        ],
+        children_none(),
+    );
+
     // let multi_select_component = component(
     //     "multi_select",
     //     "MultiSelect",
@@ -993,6 +1150,7 @@ pub fn create_component_model() -> Vec<Component> {
                 member("Checkbox", &checkbox_component, Arity::ZeroOrMore),
                 member("DatePicker", &date_picker_component, Arity::ZeroOrMore),
                 member("Select", &select_component, Arity::ZeroOrMore),
+                member("Slider", &slider_component, Arity::ZeroOrMore),
                 // member("MultiSelect", &multi_select_component),
                 member("Separator", &separator_component, Arity::ZeroOrMore),
             ],
@@ -1082,10 +1240,12 @@ pub fn create_component_model() -> Vec<Component> {
         mark_doc!("/list_item/description.md"),
         "ListItem",
         [
+            property("id", mark_doc!("/list_item/props/id.md"),true, PropertyType::String),
             property("title", mark_doc!("/list_item/props/title.md"),false, PropertyType::String),
             property("subtitle", mark_doc!("/list_item/props/subtitle.md"),true, PropertyType::String),
             property("icon", mark_doc!("/list_item/props/icon.md"),true, PropertyType::SharedTypeRef { name: "Image".to_owned() }),
             property("accessories", mark_doc!("/list_item/props/accessories.md"),true, PropertyType::Array { item: Box::new(PropertyType::Union { items: vec![component_ref(&accessory_text_component, Arity::ZeroOrMore), component_ref(&accessory_icon_component, Arity::ZeroOrMore)]}) }),
+            property("drag", mark_doc!("/list_item/props/drag.md"),true, PropertyType::SharedTypeRef { name: "DragPayload".to_owned() }),
             event("onClick", mark_doc!("/list_item/props/onClick.md"), true, [])
         ],
         children_none(),
@@ -1114,6 +1274,8 @@ pub fn create_component_model() -> Vec<Component> {
         [
             property("actions", mark_doc!("/list/props/actions.md"), true, component_ref(&action_panel_component, Arity::ZeroOrOne)),
             property("isLoading", mark_doc!("/list/props/isLoading.md"), true, PropertyType::Boolean),
+            property("isLoadingMore", mark_doc!("/list/props/isLoadingMore.md"), true, PropertyType::Boolean),
+            property("multiSelect", mark_doc!("/list/props/multiSelect.md"), true, PropertyType::Boolean),
         ],
         children_members(
             [
@@ -1136,6 +1298,7 @@ pub fn create_component_model() -> Vec<Component> {
             property("title", mark_doc!("/grid_item/props/title.md"), true, PropertyType::String),
             property("subtitle", mark_doc!("/grid_item/props/subtitle.md"), true, PropertyType::String),
             property("accessory", mark_doc!("/grid_item/props/accessory.md"),true, component_ref(&accessory_icon_component, Arity::ZeroOrOne)),
+            property("drag", mark_doc!("/grid_item/props/drag.md"),true, PropertyType::SharedTypeRef { name: "DragPayload".to_owned() }),
             event("onClick", mark_doc!("/grid_item/props/onClick.md"), true, [])
         ],
         children_members(
@@ -1203,10 +1366,18 @@ pub fn create_component_model() -> Vec<Component> {
     // Detail
     // Detail.Content
     // Detail.Content.Paragraph
+    // Detail.Content.Paragraph.Bold
+    // Detail.Content.Paragraph.Italic
+    // Detail.Content.Paragraph.Code
+    // Detail.Content.Paragraph.Link
+    // Detail.Content.Paragraph.Color
     // Detail.Content.Image
     // Detail.Content.H1-6
     // Detail.Content.HorizontalBreak
     // Detail.Content.CodeBlock
+    // Detail.Content.ColorSwatch
+    // Detail.Content.Sparkline
+    // Detail.Content.BarChart
     // Detail.Metadata
     // Detail.Metadata.TagList
     // Detail.Metadata.TagList.Item
@@ -1284,7 +1455,15 @@ pub fn create_component_model() -> Vec<Component> {
         h6_component,
         horizontal_break_component,
         code_block_component,
+        color_swatch_component,
+        sparkline_component,
+        bar_chart_component,
         // code_component,
+        paragraph_bold_component,
+        paragraph_italic_component,
+        paragraph_code_component,
+        paragraph_link_component,
+        paragraph_color_component,
         paragraph_component,
         content_component,
 
@@ -1297,6 +1476,7 @@ pub fn create_component_model() -> Vec<Component> {
         date_picker_component,
         select_item_component,
         select_component,
+        slider_component,
         // multi_select_component,
         separator_component,
         form_component,
@@ -1,12 +1,12 @@
 use std::{env, fs};
 use anyhow::anyhow;
-use gauntlet_component_model::create_component_model;
+use gauntlet_component_model::{create_api_model, create_component_model};
 
 fn main() -> anyhow::Result<()> {
     let args: Vec<String> = env::args().collect();
 
     match args.len() {
-        2 => {
+        2 | 3 => {
             let path_to_save = &args[1];
 
             let components = create_component_model();
@@ -15,6 +15,15 @@ fn main() -> anyhow::Result<()> {
 
             fs::write(path_to_save, json)?;
 
+            // ops and types of plugin runtime api
+            if let Some(api_path_to_save) = args.get(2) {
+                let api = create_api_model();
+
+                let json = serde_json::to_string_pretty(&api)?;
+
+                fs::write(api_path_to_save, json)?;
+            }
+
             Ok(())
         }
         args @ _ => {
@@ -1,4 +1,5 @@
 use iced::application::{Appearance, DefaultStyle};
+use iced::Border;
 
 pub mod container;
 pub mod text;
@@ -8,6 +9,7 @@ pub mod text_input;
 pub mod number_input;
 pub mod rule;
 pub mod checkbox;
+pub mod slider;
 pub mod pick_list;
 pub mod scrollable;
 pub mod shortcut_selector;
@@ -15,7 +17,37 @@ pub mod shortcut_selector;
 pub type Element<'a, Message> = iced::Element<'a, Message, GauntletSettingsTheme>;
 
 #[derive(Default)]
-pub struct GauntletSettingsTheme;
+pub struct GauntletSettingsTheme {
+    high_contrast: bool,
+}
+
+impl GauntletSettingsTheme {
+    pub fn new(high_contrast: bool) -> Self {
+        Self { high_contrast }
+    }
+
+    // in high contrast mode every control gets visible border,
+    // hovered and focused controls are outlined with thicker border of text color
+    fn border(&self, border: Border, outlined: bool) -> Border {
+        if !self.high_contrast {
+            return border
+        }
+
+        if outlined {
+            Border {
+                color: TEXT_LIGHTEST.to_iced(),
+                width: 2.0,
+                ..border
+            }
+        } else {
+            Border {
+                color: TEXT_LIGHTER.to_iced(),
+                width: border.width.max(1.0),
+                ..border
+            }
+        }
+    }
+}
 
 impl DefaultStyle for GauntletSettingsTheme {
     fn default_style(&self) -> Appearance {
@@ -22,11 +22,16 @@ impl button::Catalog for GauntletSettingsTheme {
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        match status {
+        let style = match status {
             Status::Active => active(class),
             Status::Hovered => hovered(class),
             Status::Pressed => pressed(class),
             Status::Disabled => disabled(class)
+        };
+
+        Style {
+            border: self.border(style.border, matches!(status, Status::Hovered | Status::Pressed)),
+            ..style
         }
     }
 }
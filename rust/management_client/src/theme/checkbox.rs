@@ -11,10 +11,15 @@ impl checkbox::Catalog for GauntletSettingsTheme {
     }
 
     fn style(&self, _class: &Self::Class<'_>, status: Status) -> Style {
-        match status {
+        let style = match status {
             Status::Active { is_checked } => active(is_checked),
             Status::Hovered { is_checked } => hovered(is_checked),
             Status::Disabled { is_checked } => disabled(is_checked),
+        };
+
+        Style {
+            border: self.border(style.border, matches!(status, Status::Hovered { .. })),
+            ..style
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::theme::{GauntletSettingsTheme, BACKGROUND_DARKER, BACKGROUND_LIGHTER, BACKGROUND_LIGHTEST, DANGER, TRANSPARENT};
+use crate::theme::{GauntletSettingsTheme, BACKGROUND_DARKER, BACKGROUND_LIGHTER, BACKGROUND_LIGHTEST, DANGER, PRIMARY, TRANSPARENT};
 use iced::widget::container;
 use iced::widget::container::Style;
 use iced::{Border, Color};
@@ -7,7 +7,16 @@ pub enum ContainerStyle {
     Transparent,
     Box,
     TextInputLike,
-    TextInputMissingValue
+    TextInputMissingValue,
+    // bar of a chart
+    Bar,
+    // row of reorderable list over which dragged row will be dropped
+    DropTarget,
+    // colors of launcher theme that is being edited, not of settings window
+    ThemePreview {
+        background: Color,
+        border: Border,
+    },
 }
 
 impl container::Catalog for GauntletSettingsTheme {
@@ -21,24 +30,28 @@ impl container::Catalog for GauntletSettingsTheme {
         match class {
             ContainerStyle::Transparent => Default::default(),
             ContainerStyle::Box => {
+                let border = Border {
+                    color: BACKGROUND_LIGHTER.to_iced(),
+                    radius: 10.0.into(),
+                    width: 1.0,
+                };
+
                 Style {
                     background: Some(BACKGROUND_DARKER.to_iced().into()),
-                    border: Border {
-                        color: BACKGROUND_LIGHTER.to_iced(),
-                        radius: 10.0.into(),
-                        width: 1.0,
-                    },
+                    border: self.border(border, false),
                     ..Default::default()
                 }
             }
             ContainerStyle::TextInputLike => {
+                let border = Border {
+                    radius: 4.0.into(),
+                    width: 1.0,
+                    color: BACKGROUND_LIGHTEST.to_iced().into(),
+                };
+
                 Style {
                     background: Some(BACKGROUND_LIGHTEST.to_iced().into()),
-                    border: Border {
-                        radius: 4.0.into(),
-                        width: 1.0,
-                        color: BACKGROUND_LIGHTEST.to_iced().into(),
-                    },
+                    border: self.border(border, false),
                     ..Default::default()
                 }
             }
@@ -55,6 +68,34 @@ impl container::Catalog for GauntletSettingsTheme {
                     ..Default::default()
                 }
             }
+            ContainerStyle::Bar => {
+                Style {
+                    background: Some(PRIMARY.to_iced().into()),
+                    border: Border {
+                        radius: 2.0.into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }
+            }
+            ContainerStyle::DropTarget => {
+                Style {
+                    background: Some(BACKGROUND_LIGHTER.to_iced().into()),
+                    border: Border {
+                        color: PRIMARY.to_iced(),
+                        radius: 4.0.into(),
+                        width: 1.0,
+                    },
+                    ..Default::default()
+                }
+            }
+            ContainerStyle::ThemePreview { background, border } => {
+                Style {
+                    background: Some((*background).into()),
+                    border: *border,
+                    ..Default::default()
+                }
+            }
         }
     }
 }
\ No newline at end of file
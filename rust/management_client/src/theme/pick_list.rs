@@ -10,7 +10,12 @@ impl pick_list::Catalog for GauntletSettingsTheme {
     }
 
     fn style(&self, _class: &(), status: pick_list::Status) -> pick_list::Style {
-        pick_list_appearance(status)
+        let style = pick_list_appearance(status);
+
+        pick_list::Style {
+            border: self.border(style.border, !matches!(status, pick_list::Status::Active)),
+            ..style
+        }
     }
 }
 
@@ -48,14 +53,16 @@ impl overlay::menu::Catalog for GauntletSettingsTheme {
     }
 
     fn style(&self, _class: &()) -> overlay::menu::Style {
+        let border = Border {
+            radius: BUTTON_BORDER_RADIUS.into(),
+            width: 1.0,
+            color: BACKGROUND_DARKER.to_iced().into(),
+        };
+
         overlay::menu::Style {
             text_color: TEXT_LIGHTEST.to_iced(),
             background: BACKGROUND_DARKEST.to_iced().into(),
-            border: Border {
-                radius: BUTTON_BORDER_RADIUS.into(),
-                width: 1.0,
-                color: BACKGROUND_DARKER.to_iced().into(),
-            },
+            border: self.border(border, false),
             selected_text_color: TEXT_LIGHTEST.to_iced(),
             selected_background: BACKGROUND_DARKER.to_iced().into(),
         }
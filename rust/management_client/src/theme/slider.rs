@@ -0,0 +1,39 @@
+use crate::theme::{GauntletSettingsTheme, BACKGROUND_LIGHTER, PRIMARY, PRIMARY_HOVERED, TRANSPARENT};
+use iced::widget::slider;
+use iced::widget::slider::{Handle, HandleShape, Rail, Status, Style};
+use iced::Border;
+
+impl slider::Catalog for GauntletSettingsTheme {
+    type Class<'a> = ();
+
+    fn default<'a>() -> Self::Class<'a> {
+        ()
+    }
+
+    fn style(&self, _class: &Self::Class<'_>, status: Status) -> Style {
+        let handle_color = match status {
+            Status::Active => PRIMARY.to_iced(),
+            Status::Hovered | Status::Dragged => PRIMARY_HOVERED.to_iced(),
+        };
+
+        Style {
+            rail: Rail {
+                backgrounds: (PRIMARY.to_iced().into(), BACKGROUND_LIGHTER.to_iced().into()),
+                width: 4.0,
+                border: Border {
+                    radius: 2.0.into(),
+                    width: 0.0,
+                    color: TRANSPARENT.to_iced(),
+                },
+            },
+            handle: Handle {
+                shape: HandleShape::Circle {
+                    radius: 7.0,
+                },
+                background: handle_color.into(),
+                border_width: 0.0,
+                border_color: TRANSPARENT.to_iced(),
+            },
+        }
+    }
+}
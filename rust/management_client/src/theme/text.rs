@@ -1,5 +1,6 @@
 use iced::widget::text;
 use iced::widget::text::Style;
+use iced::Color;
 use crate::theme::{DANGER_BRIGHT, GauntletSettingsTheme, SUCCESS, TEXT_DARKER};
 
 pub enum TextStyle {
@@ -7,6 +8,8 @@ pub enum TextStyle {
     Subtitle,
     Positive,
     Destructive,
+    // text of launcher theme that is being edited
+    ThemePreview(Color),
 }
 
 impl text::Catalog for GauntletSettingsTheme {
@@ -38,6 +41,11 @@ impl text::Catalog for GauntletSettingsTheme {
                     color: Some(DANGER_BRIGHT.to_iced()),
                 }
             }
+            TextStyle::ThemePreview(color) => {
+                Style {
+                    color: Some(*color),
+                }
+            }
         }
     }
 }
@@ -28,7 +28,7 @@ impl text_input::Catalog for GauntletSettingsTheme {
             selection: BACKGROUND_DARKER.to_iced(),
         };
 
-        match status {
+        let style = match status {
             Status::Active => active,
             Status::Hovered => Style {
                 background: Background::Color(BACKGROUND_DARKER.to_iced().into()),
@@ -43,6 +43,11 @@ impl text_input::Catalog for GauntletSettingsTheme {
                 value: active.placeholder,
                 ..active
             },
+        };
+
+        Style {
+            border: self.border(style.border, matches!(status, Status::Focused)),
+            ..style
         }
     }
 }
@@ -1,20 +1,32 @@
 use std::collections::HashMap;
 use std::time::Duration;
 
-use iced::{Alignment, alignment, font, futures, Length, Padding, Size, Subscription, time, window, Task, Renderer, padding};
+use iced::{Alignment, alignment, event, font, futures, keyboard, Length, Padding, Size, Subscription, time, window, Task, Renderer, padding, Event};
 use iced::advanced::text::Shaping;
-use iced::widget::{button, column, container, horizontal_rule, horizontal_space, mouse_area, row, scrollable, stack, text, value};
+use iced::keyboard::key::Named;
+use iced::keyboard::Key;
+use iced::widget::{button, column, container, focus_next, focus_previous, horizontal_rule, horizontal_space, mouse_area, row, scrollable, stack, text, value};
 use iced_aw::Spinner;
 use iced_fonts::{Bootstrap, BOOTSTRAP_FONT, BOOTSTRAP_FONT_BYTES};
 use itertools::Itertools;
 
-use gauntlet_common::model::{DownloadStatus, PluginId};
+use gauntlet_common::error::{ErrorCode, GauntletError};
+use gauntlet_common::frontend_config::{read_frontend_config, AccessibilityConfig};
+use gauntlet_common::i18n::{tr, tr_with};
+use gauntlet_common::model::{AvailableUpdate, DownloadStatus, PluginId};
 use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use gauntlet_common_ui::accessibility::{AccessibilityAction, AccessibilityActionKind, AccessibleNode, Role};
 use gauntlet_common_ui::padding;
 use crate::theme::{Element, GauntletSettingsTheme};
 use crate::theme::button::ButtonStyle;
 use crate::theme::container::ContainerStyle;
 use crate::theme::text::TextStyle;
+use crate::views::diagnostics::{ManagementAppDiagnosticsMsgIn, ManagementAppDiagnosticsMsgOut, ManagementAppDiagnosticsState};
+use crate::views::launcher_import::{ManagementAppImportMsgIn, ManagementAppImportMsgOut, ManagementAppImportState};
+use crate::views::permissions::{ManagementAppPermissionsMsgIn, ManagementAppPermissionsMsgOut, ManagementAppPermissionsState};
+use crate::views::statistics::{ManagementAppStatisticsMsgIn, ManagementAppStatisticsMsgOut, ManagementAppStatisticsState};
+use crate::views::macros::{ManagementAppMacrosMsgIn, ManagementAppMacrosMsgOut, ManagementAppMacrosState};
+use crate::views::theme_editor::{ManagementAppThemeEditorMsgIn, ManagementAppThemeEditorMsgOut, ManagementAppThemeEditorState};
 use crate::views::general::{ManagementAppGeneralMsgIn, ManagementAppGeneralMsgOut, ManagementAppGeneralState};
 use crate::views::plugins::{ManagementAppPluginMsgIn, ManagementAppPluginMsgOut, ManagementAppPluginsState};
 
@@ -25,7 +37,7 @@ pub fn run() {
             ..Default::default()
         })
         .subscription(subscription)
-        .theme(|_| GauntletSettingsTheme::default())
+        .theme(|state| GauntletSettingsTheme::new(state.accessibility.high_contrast))
         .run_with(new)
         .expect("Unable to start settings application");
 }
@@ -35,9 +47,21 @@ struct ManagementAppModel {
     error_view: Option<ErrorView>,
     downloads_info: HashMap<PluginId, DownloadInfo>,
     download_info_shown: bool,
+    available_update: Option<AvailableUpdate>,
     current_settings_view: SettingsView,
     general_state: ManagementAppGeneralState,
-    plugins_state: ManagementAppPluginsState
+    plugins_state: ManagementAppPluginsState,
+    diagnostics_state: ManagementAppDiagnosticsState,
+    import_state: ManagementAppImportState,
+    statistics_state: ManagementAppStatisticsState,
+    macros_state: ManagementAppMacrosState,
+    permissions_state: ManagementAppPermissionsState,
+    theme_editor_state: ManagementAppThemeEditorState,
+    managed: bool,
+    // changes when config file is edited while settings window is open
+    config_revision: Option<u64>,
+    // read from config file directly, the same way as main window does it
+    accessibility: AccessibilityConfig,
 }
 
 
@@ -46,6 +70,12 @@ enum ManagementAppMsg {
     FontLoaded(Result<(), font::Error>),
     General(ManagementAppGeneralMsgIn),
     Plugin(ManagementAppPluginMsgIn),
+    Diagnostics(ManagementAppDiagnosticsMsgIn),
+    Import(ManagementAppImportMsgIn),
+    Statistics(ManagementAppStatisticsMsgIn),
+    Macros(ManagementAppMacrosMsgIn),
+    Permissions(ManagementAppPermissionsMsgIn),
+    ThemeEditor(ManagementAppThemeEditorMsgIn),
     SwitchView(SettingsView),
     DownloadStatus { plugins: HashMap<PluginId, DownloadStatus> },
     HandleBackendError(BackendApiError),
@@ -53,18 +83,34 @@ enum ManagementAppMsg {
     DownloadPlugin { plugin_id: PluginId },
     Noop,
     ToggleDownloadInfo,
+    UpdateAvailable(Option<AvailableUpdate>),
+    ManagedReloaded(bool),
+    CheckConfigRevision,
+    ConfigRevisionChecked(u64),
+    OpenUpdatePage(String),
+    WindowOpened(window::Id),
+    AccessibilityAction(AccessibilityAction),
+    FocusNext,
+    FocusPrevious,
+    SwitchToNextView,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum SettingsView {
     General,
-    Plugins
+    Plugins,
+    Diagnostics,
+    Import,
+    Statistics,
+    Macros,
+    Permissions,
+    Theme,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum ErrorView {
-    UnknownError {
-        display: String
+    BackendError {
+        error: GauntletError
     },
     Timeout,
 }
@@ -79,6 +125,8 @@ pub enum DownloadInfo {
 }
 
 fn new() -> (ManagementAppModel, Task<ManagementAppMsg>) {
+    gauntlet_common_ui::accessibility::init("Gauntlet Settings");
+
     let backend_api = futures::executor::block_on(async {
         anyhow::Ok(BackendApi::new().await?)
     })
@@ -91,9 +139,19 @@ fn new() -> (ManagementAppModel, Task<ManagementAppMsg>) {
             error_view: None,
             downloads_info: HashMap::new(),
             download_info_shown: false,
+            available_update: None,
             current_settings_view: SettingsView::Plugins,
             general_state: ManagementAppGeneralState::new(backend_api.clone()),
             plugins_state: ManagementAppPluginsState::new(backend_api.clone()),
+            diagnostics_state: ManagementAppDiagnosticsState::new(backend_api.clone()),
+            import_state: ManagementAppImportState::new(backend_api.clone()),
+            statistics_state: ManagementAppStatisticsState::new(backend_api.clone()),
+            macros_state: ManagementAppMacrosState::new(backend_api.clone()),
+            permissions_state: ManagementAppPermissionsState::new(backend_api.clone()),
+            theme_editor_state: ManagementAppThemeEditorState::new(backend_api.clone()),
+            managed: false,
+            config_revision: None,
+            accessibility: read_frontend_config().accessibility,
         },
         Task::batch([
             font::load(BOOTSTRAP_FONT_BYTES).map(ManagementAppMsg::FontLoaded),
@@ -101,6 +159,53 @@ fn new() -> (ManagementAppModel, Task<ManagementAppMsg>) {
                 async {},
                 |()| ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::RequestPluginReload)
             ),
+            Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestConfigErrorReload)),
+            Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestGeneralSettingsReload)),
+            Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestProfilesReload)),
+            Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestInlineViewsReload)),
+            Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestFavoritesReload)),
+            Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestFallbacksReload)),
+            Task::perform(
+                {
+                    let backend_api = backend_api.clone();
+
+                    async move {
+                        match backend_api {
+                            Some(mut backend_api) => backend_api.is_managed().await,
+                            None => Ok(false)
+                        }
+                    }
+                },
+                |result| {
+                    match result {
+                        Ok(managed) => ManagementAppMsg::ManagedReloaded(managed),
+                        Err(err) => ManagementAppMsg::HandleBackendError(err)
+                    }
+                }
+            ),
+            Task::perform(
+                {
+                    let backend_api = backend_api.clone();
+
+                    async move {
+                        match backend_api {
+                            Some(mut backend_api) => backend_api.check_for_updates().await,
+                            None => Ok(None)
+                        }
+                    }
+                },
+                |update| {
+                    // network errors are not worth interrupting the user
+                    match update {
+                        Ok(update) => ManagementAppMsg::UpdateAvailable(update),
+                        Err(err) => {
+                            tracing::warn!("Unable to check for updates: {:?}", err);
+
+                            ManagementAppMsg::Noop
+                        }
+                    }
+                }
+            ),
             Task::perform(
                 async {
                     match backend_api {
@@ -130,6 +235,24 @@ fn new() -> (ManagementAppModel, Task<ManagementAppMsg>) {
 }
 
 fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<ManagementAppMsg> {
+    let task = update_state(state, message);
+
+    gauntlet_common_ui::accessibility::update(|| accessibility_tree(state));
+
+    task
+}
+
+fn update_state(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<ManagementAppMsg> {
+    match message {
+        ManagementAppMsg::WindowOpened(window_id) => {
+            return gauntlet_common_ui::accessibility::attach_window(window_id)
+                .map(|_| ManagementAppMsg::Noop)
+        }
+        ManagementAppMsg::FocusNext => return focus_next(),
+        ManagementAppMsg::FocusPrevious => return focus_previous(),
+        _ => {}
+    }
+
     let backend_api = match &state.backend_api {
         Some(backend_api) => backend_api.clone(),
         None => {
@@ -154,6 +277,12 @@ fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<Man
                         ManagementAppPluginMsgOut::SelectedItem(selected_item) => {
                             ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::SelectItem(selected_item))
                         }
+                        ManagementAppPluginMsgOut::PluginLogsMsg(msg) => {
+                            ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::PluginLogsMsg(msg))
+                        }
+                        ManagementAppPluginMsgOut::ShortcutConflict { plugin_id, entrypoint_id, action_id, conflict } => {
+                            ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::ShortcutConflict { plugin_id, entrypoint_id, action_id, conflict })
+                        }
                         ManagementAppPluginMsgOut::HandleBackendError(err) => {
                             ManagementAppMsg::HandleBackendError(err)
                         }
@@ -167,25 +296,224 @@ fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<Man
                         ManagementAppGeneralMsgOut::Noop => {
                             ManagementAppMsg::General(ManagementAppGeneralMsgIn::Noop)
                         },
+                        ManagementAppGeneralMsgOut::InlineViewsReloaded(inline_views) => {
+                            ManagementAppMsg::General(ManagementAppGeneralMsgIn::InlineViewsReloaded(inline_views))
+                        },
+                        ManagementAppGeneralMsgOut::FavoritesReloaded(favorites) => {
+                            ManagementAppMsg::General(ManagementAppGeneralMsgIn::FavoritesReloaded(favorites))
+                        },
+                        ManagementAppGeneralMsgOut::FallbacksReloaded(fallbacks) => {
+                            ManagementAppMsg::General(ManagementAppGeneralMsgIn::FallbacksReloaded(fallbacks))
+                        },
+                        ManagementAppGeneralMsgOut::ConfigErrorReloaded(config_error) => {
+                            ManagementAppMsg::General(ManagementAppGeneralMsgIn::ConfigErrorReloaded(config_error))
+                        },
+                        ManagementAppGeneralMsgOut::SettingsArchiveFinished(status) => {
+                            ManagementAppMsg::General(ManagementAppGeneralMsgIn::SettingsArchiveFinished(status))
+                        },
+                        ManagementAppGeneralMsgOut::GeneralSettingsReloaded(settings) => {
+                            ManagementAppMsg::General(ManagementAppGeneralMsgIn::GeneralSettingsReloaded(settings))
+                        },
+                        ManagementAppGeneralMsgOut::GeneralSettingsSaved(result) => {
+                            ManagementAppMsg::General(ManagementAppGeneralMsgIn::GeneralSettingsSaved(result))
+                        },
+                        ManagementAppGeneralMsgOut::ProfilesReloaded(profiles) => {
+                            ManagementAppMsg::General(ManagementAppGeneralMsgIn::ProfilesReloaded(profiles))
+                        },
+                        ManagementAppGeneralMsgOut::ActiveProfileSaved(result) => {
+                            ManagementAppMsg::General(ManagementAppGeneralMsgIn::ActiveProfileSaved(result))
+                        },
                         ManagementAppGeneralMsgOut::HandleBackendError(err) => {
                             ManagementAppMsg::HandleBackendError(err)
                         }
                     }
                 })
         }
+        ManagementAppMsg::Diagnostics(message) => {
+            state.diagnostics_state.update(message)
+                .map(|msg| {
+                    match msg {
+                        ManagementAppDiagnosticsMsgOut::DiagnosticsReloaded(diagnostics) => {
+                            ManagementAppMsg::Diagnostics(ManagementAppDiagnosticsMsgIn::DiagnosticsReloaded(diagnostics))
+                        }
+                        ManagementAppDiagnosticsMsgOut::HandleBackendError(err) => {
+                            ManagementAppMsg::HandleBackendError(err)
+                        }
+                    }
+                })
+        }
+        ManagementAppMsg::Import(message) => {
+            state.import_state.update(message)
+                .map(|msg| {
+                    match msg {
+                        ManagementAppImportMsgOut::PreviewLoaded(result) => {
+                            ManagementAppMsg::Import(ManagementAppImportMsgIn::PreviewLoaded(result))
+                        }
+                        ManagementAppImportMsgOut::ImportFinished(result) => {
+                            ManagementAppMsg::Import(ManagementAppImportMsgIn::ImportFinished(result))
+                        }
+                        ManagementAppImportMsgOut::HandleBackendError(err) => {
+                            ManagementAppMsg::HandleBackendError(err)
+                        }
+                    }
+                })
+        }
+        ManagementAppMsg::Statistics(message) => {
+            state.statistics_state.update(message)
+                .map(|msg| {
+                    match msg {
+                        ManagementAppStatisticsMsgOut::StatisticsReloaded(statistics) => {
+                            ManagementAppMsg::Statistics(ManagementAppStatisticsMsgIn::StatisticsReloaded(statistics))
+                        }
+                        ManagementAppStatisticsMsgOut::ExportFinished(result) => {
+                            ManagementAppMsg::Statistics(ManagementAppStatisticsMsgIn::ExportFinished(result))
+                        }
+                        ManagementAppStatisticsMsgOut::HandleBackendError(err) => {
+                            ManagementAppMsg::HandleBackendError(err)
+                        }
+                    }
+                })
+        }
+        ManagementAppMsg::Macros(message) => {
+            state.macros_state.update(message)
+                .map(|msg| {
+                    match msg {
+                        ManagementAppMacrosMsgOut::MacrosReloaded { macros, candidates } => {
+                            ManagementAppMsg::Macros(ManagementAppMacrosMsgIn::MacrosReloaded { macros, candidates })
+                        }
+                        ManagementAppMacrosMsgOut::Saved(result) => {
+                            ManagementAppMsg::Macros(ManagementAppMacrosMsgIn::Saved(result))
+                        }
+                        ManagementAppMacrosMsgOut::Removed(result) => {
+                            ManagementAppMsg::Macros(ManagementAppMacrosMsgIn::Removed(result))
+                        }
+                        ManagementAppMacrosMsgOut::HandleBackendError(err) => {
+                            ManagementAppMsg::HandleBackendError(err)
+                        }
+                    }
+                })
+        }
+        ManagementAppMsg::Permissions(message) => {
+            state.permissions_state.update(message)
+                .map(|msg| {
+                    match msg {
+                        ManagementAppPermissionsMsgOut::PermissionsReloaded(plugins) => {
+                            ManagementAppMsg::Permissions(ManagementAppPermissionsMsgIn::PermissionsReloaded(plugins))
+                        }
+                        ManagementAppPermissionsMsgOut::HandleBackendError(err) => {
+                            ManagementAppMsg::HandleBackendError(err)
+                        }
+                    }
+                })
+        }
+        ManagementAppMsg::ThemeEditor(message) => {
+            state.theme_editor_state.update(message)
+                .map(|msg| {
+                    match msg {
+                        ManagementAppThemeEditorMsgOut::Noop => {
+                            ManagementAppMsg::Noop
+                        }
+                        ManagementAppThemeEditorMsgOut::ThemeReloaded(theme) => {
+                            ManagementAppMsg::ThemeEditor(ManagementAppThemeEditorMsgIn::ThemeReloaded(theme))
+                        }
+                        ManagementAppThemeEditorMsgOut::Saved(result) => {
+                            ManagementAppMsg::ThemeEditor(ManagementAppThemeEditorMsgIn::Saved(result))
+                        }
+                        ManagementAppThemeEditorMsgOut::HandleBackendError(err) => {
+                            ManagementAppMsg::HandleBackendError(err)
+                        }
+                    }
+                })
+        }
+        ManagementAppMsg::ManagedReloaded(managed) => {
+            state.managed = managed;
+            state.general_state.set_managed(managed);
+            state.plugins_state.set_managed(managed);
+            state.import_state.set_managed(managed);
+            state.macros_state.set_managed(managed);
+            state.permissions_state.set_managed(managed);
+            state.theme_editor_state.set_managed(managed);
+
+            Task::none()
+        }
+        ManagementAppMsg::CheckConfigRevision => {
+            let mut backend_api = backend_api.clone();
+
+            Task::perform(
+                async move {
+                    backend_api.get_config_revision().await
+                },
+                |result| {
+                    match result {
+                        Ok(revision) => ManagementAppMsg::ConfigRevisionChecked(revision),
+                        // older server doesn't report config changes, settings stay as they were read when window was opened
+                        Err(BackendApiError::UnsupportedByServer { .. }) => ManagementAppMsg::Noop,
+                        Err(err) => ManagementAppMsg::HandleBackendError(err)
+                    }
+                }
+            )
+        }
+        ManagementAppMsg::ConfigRevisionChecked(revision) => {
+            match state.config_revision.replace(revision) {
+                Some(previous_revision) if previous_revision != revision => {
+                    state.accessibility = read_frontend_config().accessibility;
+
+                    let mut backend_api = backend_api.clone();
+
+                    Task::batch([
+                        Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestConfigErrorReload)),
+                        Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestGeneralSettingsReload)),
+                        Task::perform(
+                            async move {
+                                backend_api.is_managed().await
+                            },
+                            |result| handle_backend_error(result, |managed| ManagementAppMsg::ManagedReloaded(managed))
+                        ),
+                    ])
+                }
+                _ => Task::none()
+            }
+        }
         ManagementAppMsg::FontLoaded(result) => {
             result.expect("unable to load font");
             Task::none()
         }
         ManagementAppMsg::SwitchView(view) => {
+            // unsaved theme edits are only shown in launcher while theme tab is open
+            let end_theme_preview = if state.current_settings_view == SettingsView::Theme && view != SettingsView::Theme {
+                Task::done(ManagementAppMsg::ThemeEditor(ManagementAppThemeEditorMsgIn::EndPreview))
+            } else {
+                Task::none()
+            };
+
             state.current_settings_view = view;
 
-            Task::none()
+            let reload = match state.current_settings_view {
+                // entrypoints could have been enabled, disabled or pinned in plugins view
+                SettingsView::General => Task::batch([
+                    Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestInlineViewsReload)),
+                    Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestFavoritesReload)),
+                    Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestFallbacksReload)),
+                    Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestConfigErrorReload)),
+                    Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestGeneralSettingsReload)),
+                    Task::done(ManagementAppMsg::General(ManagementAppGeneralMsgIn::RequestProfilesReload)),
+                ]),
+                SettingsView::Plugins => Task::none(),
+                SettingsView::Diagnostics => Task::done(ManagementAppMsg::Diagnostics(ManagementAppDiagnosticsMsgIn::RequestDiagnosticsReload)),
+                SettingsView::Import => Task::none(),
+                SettingsView::Statistics => Task::done(ManagementAppMsg::Statistics(ManagementAppStatisticsMsgIn::RequestStatisticsReload)),
+                SettingsView::Macros => Task::done(ManagementAppMsg::Macros(ManagementAppMacrosMsgIn::RequestMacrosReload)),
+                SettingsView::Permissions => Task::done(ManagementAppMsg::Permissions(ManagementAppPermissionsMsgIn::RequestPermissionsReload)),
+                SettingsView::Theme => Task::done(ManagementAppMsg::ThemeEditor(ManagementAppThemeEditorMsgIn::RequestThemeReload)),
+            };
+
+            Task::batch([end_theme_preview, reload])
         }
         ManagementAppMsg::HandleBackendError(err) => {
             state.error_view = Some(match err {
                 BackendApiError::Timeout => ErrorView::Timeout,
-                BackendApiError::Internal { display } => ErrorView::UnknownError { display }
+                BackendApiError::Internal { error } => ErrorView::BackendError { error },
+                err @ (BackendApiError::IncompatibleVersion { .. } | BackendApiError::UnsupportedByServer { .. }) => ErrorView::BackendError { error: GauntletError::internal(err.to_string()) },
             });
 
             Task::none()
@@ -259,12 +587,129 @@ fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<Man
             state.download_info_shown = !state.download_info_shown;
             Task::none()
         }
+        ManagementAppMsg::UpdateAvailable(update) => {
+            state.available_update = update;
+            Task::none()
+        }
+        ManagementAppMsg::OpenUpdatePage(url) => {
+            if let Err(err) = open::that_detached(&url) {
+                tracing::warn!("Unable to open {}: {:?}", url, err);
+            }
+
+            Task::none()
+        }
+        ManagementAppMsg::AccessibilityAction(AccessibilityAction { key, kind }) => {
+            match (SettingsView::from_accessibility_key(&key), kind) {
+                (Some(view), AccessibilityActionKind::Click | AccessibilityActionKind::Focus) => {
+                    Task::done(ManagementAppMsg::SwitchView(view))
+                }
+                (None, _) => Task::none()
+            }
+        }
+        ManagementAppMsg::SwitchToNextView => {
+            let view = match state.current_settings_view {
+                SettingsView::General => SettingsView::Plugins,
+                SettingsView::Plugins => SettingsView::Diagnostics,
+                SettingsView::Diagnostics => SettingsView::Import,
+                SettingsView::Import => SettingsView::Statistics,
+                SettingsView::Statistics => SettingsView::Macros,
+                SettingsView::Macros => SettingsView::Permissions,
+                SettingsView::Permissions => SettingsView::Theme,
+                SettingsView::Theme => SettingsView::General,
+            };
+
+            Task::done(ManagementAppMsg::SwitchView(view))
+        }
+        ManagementAppMsg::WindowOpened(_) | ManagementAppMsg::FocusNext | ManagementAppMsg::FocusPrevious => Task::none(),
     }
 }
 
+impl SettingsView {
+    const ALL: [SettingsView; 8] = [SettingsView::General, SettingsView::Plugins, SettingsView::Diagnostics, SettingsView::Import, SettingsView::Statistics, SettingsView::Macros, SettingsView::Permissions, SettingsView::Theme];
+
+    fn accessibility_key(&self) -> &'static str {
+        match self {
+            SettingsView::General => "tab:general",
+            SettingsView::Plugins => "tab:plugins",
+            SettingsView::Diagnostics => "tab:diagnostics",
+            SettingsView::Import => "tab:import",
+            SettingsView::Statistics => "tab:statistics",
+            SettingsView::Macros => "tab:macros",
+            SettingsView::Permissions => "tab:permissions",
+            SettingsView::Theme => "tab:theme",
+        }
+    }
+
+    fn from_accessibility_key(key: &str) -> Option<SettingsView> {
+        SettingsView::ALL
+            .into_iter()
+            .find(|view| view.accessibility_key() == key)
+    }
+
+    fn label(&self) -> String {
+        match self {
+            SettingsView::General => tr("settings-tab-general"),
+            SettingsView::Plugins => tr("settings-tab-plugins"),
+            SettingsView::Diagnostics => tr("settings-tab-diagnostics"),
+            SettingsView::Import => tr("settings-tab-import"),
+            SettingsView::Statistics => tr("settings-tab-statistics"),
+            SettingsView::Macros => tr("settings-tab-macros"),
+            SettingsView::Permissions => tr("settings-tab-permissions"),
+            SettingsView::Theme => tr("settings-tab-theme"),
+        }
+    }
+}
+
+// contents of views are not described yet, only navigation between them
+fn accessibility_tree(state: &ManagementAppModel) -> (AccessibleNode, Option<String>) {
+    let window = AccessibleNode::new("window", Role::Window)
+        .label("Gauntlet Settings");
+
+    if state.backend_api.is_none() {
+        let alert = AccessibleNode::new("error", Role::Alert)
+            .label(tr("settings-unable-to-connect"));
+
+        return (window.child(alert), Some("error".to_string()))
+    }
+
+    if let Some(error_view) = &state.error_view {
+        let label = match error_view {
+            ErrorView::BackendError { error } => match error.code {
+                ErrorCode::Internal => tr("unknown-error-occurred"),
+                _ => error.message.clone(),
+            },
+            ErrorView::Timeout => tr("backend-timeout"),
+        };
+
+        let alert = AccessibleNode::new("error", Role::Alert)
+            .label(label);
+
+        return (window.child(alert), Some("error".to_string()))
+    }
+
+    let tabs = SettingsView::ALL
+        .into_iter()
+        .map(|view| {
+            AccessibleNode::new(view.accessibility_key(), Role::Tab)
+                .label(view.label())
+                .selected(view == state.current_settings_view)
+                .clickable()
+        });
+
+    let tab_list = AccessibleNode::new("tabs", Role::TabList)
+        .children(tabs);
+
+    let tab_panel = AccessibleNode::new("tab-panel", Role::TabPanel)
+        .label(state.current_settings_view.label());
+
+    let focus = state.current_settings_view.accessibility_key().to_string();
+
+    (window.child(tab_list).child(tab_panel), Some(focus))
+}
+
 fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
     if let None = &state.backend_api {
-        let description: Element<_> = text("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+        let description: Element<_> = text(tr("settings-unable-to-connect"))
             .into();
 
         let content: Element<_> = container(description)
@@ -280,7 +725,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
     if let Some(err) = &state.error_view {
         return match err {
             ErrorView::Timeout => {
-                let description: Element<_> = text("Error occurred")
+                let description: Element<_> = text(tr("error-occurred"))
                     .into();
 
                 let description = container(description)
@@ -289,7 +734,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
                     .padding(12)
                     .into();
 
-                let sub_description: Element<_> = text("Backend was unable to process message in a timely manner")
+                let sub_description: Element<_> = text(tr("backend-timeout"))
                     .into();
 
                 let sub_description = container(sub_description)
@@ -312,8 +757,13 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
 
                 content
             }
-            ErrorView::UnknownError { display } => {
-                let description: Element<_> = text("Unknown error occurred")
+            ErrorView::BackendError { error } => {
+                let title = match error.code {
+                    ErrorCode::Internal => tr("unknown-error-occurred"),
+                    _ => tr("error-occurred"),
+                };
+
+                let description: Element<_> = text(title)
                     .into();
 
                 let description = container(description)
@@ -322,7 +772,8 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
                     .padding(12)
                     .into();
 
-                let sub_description: Element<_> = text("Please report")
+                let sub_description: Element<_> = text(error.guidance())
+                    .shaping(Shaping::Advanced)
                     .into();
 
                 let sub_description = container(sub_description)
@@ -331,8 +782,32 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
                     .padding(12)
                     .into();
 
-                let error_description: Element<_> = text(display)
-                    .shaping(Shaping::Advanced)
+                let mut error_description = vec![];
+
+                if let Some(plugin_id) = &error.plugin_id {
+                    error_description.push(
+                        text(tr_with("error-plugin", &[("plugin", plugin_id.to_string().into())]))
+                            .shaping(Shaping::Advanced)
+                            .into()
+                    );
+                }
+
+                error_description.push(
+                    text(&error.message)
+                        .shaping(Shaping::Advanced)
+                        .into()
+                );
+
+                for cause in &error.causes {
+                    error_description.push(
+                        text(cause)
+                            .shaping(Shaping::Advanced)
+                            .into()
+                    );
+                }
+
+                let error_description: Element<_> = column(error_description)
+                    .align_x(Alignment::Center)
                     .into();
 
                 let error_description = container(error_description)
@@ -369,6 +844,30 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
             state.plugins_state.view()
                 .map(|msg| ManagementAppMsg::Plugin(msg))
         }
+        SettingsView::Diagnostics => {
+            state.diagnostics_state.view()
+                .map(|msg| ManagementAppMsg::Diagnostics(msg))
+        }
+        SettingsView::Import => {
+            state.import_state.view()
+                .map(|msg| ManagementAppMsg::Import(msg))
+        }
+        SettingsView::Statistics => {
+            state.statistics_state.view()
+                .map(|msg| ManagementAppMsg::Statistics(msg))
+        }
+        SettingsView::Macros => {
+            state.macros_state.view()
+                .map(|msg| ManagementAppMsg::Macros(msg))
+        }
+        SettingsView::Permissions => {
+            state.permissions_state.view()
+                .map(|msg| ManagementAppMsg::Permissions(msg))
+        }
+        SettingsView::Theme => {
+            state.theme_editor_state.view()
+                .map(|msg| ManagementAppMsg::ThemeEditor(msg))
+        }
     };
 
     let icon_general: Element<_> = value(Bootstrap::GearFill)
@@ -379,7 +878,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
         .align_x(alignment::Horizontal::Center)
         .into();
 
-    let text_general: Element<_> = text("General")
+    let text_general: Element<_> = text(tr("settings-tab-general"))
         .height(Length::Fill)
         .align_y(alignment::Vertical::Center)
         .align_x(alignment::Horizontal::Center)
@@ -410,7 +909,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
         .align_x(alignment::Horizontal::Center)
         .into();
 
-    let text_plugins: Element<_> = text("Plugins")
+    let text_plugins: Element<_> = text(tr("settings-tab-plugins"))
         .height(Length::Fill)
         .align_y(alignment::Vertical::Center)
         .align_x(alignment::Horizontal::Center)
@@ -433,18 +932,247 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
         .padding(8.0)
         .into();
 
-    let top_bar_buttons: Element<_> = row(vec![general_button, plugins_button])
+    let icon_diagnostics: Element<_> = value(Bootstrap::Activity)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_diagnostics: Element<_> = text(tr("settings-tab-diagnostics"))
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
         .into();
 
-    let top_bar_buttons: Element<_> = container(top_bar_buttons)
+    let diagnostics_button: Element<_> = column(vec![icon_diagnostics, text_diagnostics])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let diagnostics_button: Element<_> = button(diagnostics_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::Diagnostics))
+        .height(Length::Fill)
+        .width(80)
+        .class(if state.current_settings_view == SettingsView::Diagnostics { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+        .into();
+
+    let diagnostics_button: Element<_> = container(diagnostics_button)
+        .padding(8.0)
+        .into();
+
+    let icon_import: Element<_> = value(Bootstrap::BoxArrowInDown)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_import: Element<_> = text(tr("settings-tab-import"))
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let import_button: Element<_> = column(vec![icon_import, text_import])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let import_button: Element<_> = button(import_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::Import))
+        .height(Length::Fill)
+        .width(80)
+        .class(if state.current_settings_view == SettingsView::Import { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+        .into();
+
+    let import_button: Element<_> = container(import_button)
+        .padding(8.0)
+        .into();
+
+    let icon_statistics: Element<_> = value(Bootstrap::BarChartFill)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_statistics: Element<_> = text(tr("settings-tab-statistics"))
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let statistics_button: Element<_> = column(vec![icon_statistics, text_statistics])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let statistics_button: Element<_> = button(statistics_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::Statistics))
+        .height(Length::Fill)
+        .width(80)
+        .class(if state.current_settings_view == SettingsView::Statistics { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+        .into();
+
+    let statistics_button: Element<_> = container(statistics_button)
+        .padding(8.0)
+        .into();
+
+    let icon_macros: Element<_> = value(Bootstrap::Magic)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
         .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_macros: Element<_> = text(tr("settings-tab-macros"))
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
         .align_x(alignment::Horizontal::Center)
         .into();
 
-    let top_bar_left_space: Element<_> = horizontal_space()
+    let macros_button: Element<_> = column(vec![icon_macros, text_macros])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
         .width(Length::Fill)
         .into();
 
+    let macros_button: Element<_> = button(macros_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::Macros))
+        .height(Length::Fill)
+        .width(80)
+        .class(if state.current_settings_view == SettingsView::Macros { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+        .into();
+
+    let macros_button: Element<_> = container(macros_button)
+        .padding(8.0)
+        .into();
+
+    let icon_permissions: Element<_> = value(Bootstrap::ShieldLock)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_permissions: Element<_> = text(tr("settings-tab-permissions"))
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let permissions_button: Element<_> = column(vec![icon_permissions, text_permissions])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let permissions_button: Element<_> = button(permissions_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::Permissions))
+        .height(Length::Fill)
+        .width(80)
+        .class(if state.current_settings_view == SettingsView::Permissions { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+        .into();
+
+    let permissions_button: Element<_> = container(permissions_button)
+        .padding(8.0)
+        .into();
+
+    let icon_theme: Element<_> = value(Bootstrap::Brush)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_theme: Element<_> = text(tr("settings-tab-theme"))
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let theme_button: Element<_> = column(vec![icon_theme, text_theme])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let theme_button: Element<_> = button(theme_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::Theme))
+        .height(Length::Fill)
+        .width(80)
+        .class(if state.current_settings_view == SettingsView::Theme { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+        .into();
+
+    let theme_button: Element<_> = container(theme_button)
+        .padding(8.0)
+        .into();
+
+    let top_bar_buttons: Element<_> = row(vec![general_button, plugins_button, diagnostics_button, import_button, statistics_button, macros_button, permissions_button, theme_button])
+        .into();
+
+    let top_bar_buttons: Element<_> = container(top_bar_buttons)
+        .width(Length::Fill)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let top_bar_left: Element<_> = match &state.available_update {
+        None => {
+            horizontal_space()
+                .width(Length::Fill)
+                .into()
+        }
+        Some(update) => {
+            let icon: Element<_> = value(Bootstrap::ArrowUpCircleFill)
+                .font(BOOTSTRAP_FONT)
+                .height(Length::Fill)
+                .align_y(alignment::Vertical::Center)
+                .size(16)
+                .class(TextStyle::Positive)
+                .into();
+
+            let label: Element<_> = text(tr_with("settings-update-available", &[("version", update.version.into())]))
+                .height(Length::Fill)
+                .align_y(alignment::Vertical::Center)
+                .into();
+
+            let content: Element<_> = row(vec![icon, label])
+                .spacing(8.0)
+                .height(Length::Fill)
+                .align_y(Alignment::Center)
+                .into();
+
+            let content: Element<_> = button(content)
+                .class(ButtonStyle::DownloadInfo)
+                .on_press(ManagementAppMsg::OpenUpdatePage(update.url.clone()))
+                .padding(Padding::from([4, 8]))
+                .height(Length::Fill)
+                .into();
+
+            let content: Element<_> = container(content)
+                .height(Length::Fill)
+                .padding(Padding::from([18.0, 12.0]))
+                .into();
+
+            container(content)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .center_y(Length::Fill)
+                .align_x(alignment::Horizontal::Left)
+                .into()
+        }
+    };
+
     let top_bar_right = {
         let mut successful_count = 0;
         let mut in_progress_count = 0;
@@ -467,10 +1195,14 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
         let mut download_info_icons = vec![];
 
         if in_progress_count > 0 {
-            let spinner: Element<_> = Spinner::new()
-                .width(Length::Fixed(16.0))
-                .height(Length::Fill)
-                .into();
+            let spinner: Element<_> = if state.accessibility.reduced_motion {
+                static_spinner(16.0)
+            } else {
+                Spinner::new()
+                    .width(Length::Fixed(16.0))
+                    .height(Length::Fill)
+                    .into()
+            };
 
             let spinner: Element<_> = container(spinner)
                 .height(Length::Fill)
@@ -570,7 +1302,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
         }
     };
 
-    let top_bar: Element<_> = row(vec![top_bar_left_space, top_bar_buttons, top_bar_right])
+    let top_bar: Element<_> = row(vec![top_bar_left, top_bar_buttons, top_bar_right])
         .width(Length::Fill)
         .into();
 
@@ -585,8 +1317,36 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
     let separator: Element<_> = horizontal_rule(1)
         .into();
 
-    let content: Element<_> = column(vec![top_bar, separator, content])
-        .into();
+    let content: Element<_> = if state.managed {
+        let icon: Element<_> = value(Bootstrap::LockFill)
+            .font(BOOTSTRAP_FONT)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let label: Element<_> = text(tr("settings-managed"))
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let managed_banner: Element<_> = row(vec![icon, label])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let managed_banner: Element<_> = container(managed_banner)
+            .padding(Padding::from([8.0, 16.0]))
+            .width(Length::Fill)
+            .into();
+
+        let managed_separator: Element<_> = horizontal_rule(1)
+            .into();
+
+        column(vec![top_bar, separator, managed_banner, managed_separator, content])
+            .into()
+    } else {
+        column(vec![top_bar, separator, content])
+            .into()
+    };
 
     let download_info_panel: Element<_> = {
         let downloads: Vec<Element<_>> = state.downloads_info.iter()
@@ -594,7 +1354,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
             .map(|(plugin_id, info)| {
                 match info {
                     DownloadInfo::InProgress => {
-                        let kind_text: Element<_> = text("Download in progress")
+                        let kind_text: Element<_> = text(tr("settings-download-in-progress"))
                             .into();
 
                         let kind_text: Element<_> = container(kind_text)
@@ -611,9 +1371,13 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
                             .padding(padding::bottom(16))
                             .into();
 
-                        let spinner: Element<_> = Spinner::new()
-                            .width(Length::Fixed(32.0))
-                            .into();
+                        let spinner: Element<_> = if state.accessibility.reduced_motion {
+                            static_spinner(32.0)
+                        } else {
+                            Spinner::new()
+                                .width(Length::Fixed(32.0))
+                                .into()
+                        };
 
                         let spinner: Element<_> = container(spinner)
                             .padding(16)
@@ -630,7 +1394,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
                             .into()
                     }
                     DownloadInfo::Error { message } => {
-                        let kind_text: Element<_> = text("Download failed")
+                        let kind_text: Element<_> = text(tr("settings-download-failed"))
                             .into();
 
                         let kind_text: Element<_> = container(kind_text)
@@ -673,7 +1437,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
                             .into()
                     }
                     DownloadInfo::Successful => {
-                        let kind_text: Element<_> = text("Download successful")
+                        let kind_text: Element<_> = text(tr("settings-download-successful"))
                             .into();
 
                         let kind_text: Element<_> = container(kind_text)
@@ -751,9 +1515,45 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
         .into()
 }
 
-fn subscription(_state: &ManagementAppModel) -> Subscription<ManagementAppMsg> {
-    time::every(Duration::from_millis(300))
-        .map(|_| ManagementAppMsg::CheckDownloadStatus)
+// shown instead of animated spinner when motion is reduced
+fn static_spinner<'a>(size: f32) -> Element<'a, ManagementAppMsg> {
+    value(Bootstrap::HourglassSplit)
+        .size(size)
+        .align_y(alignment::Vertical::Center)
+        .font(BOOTSTRAP_FONT)
+        .into()
+}
+
+fn subscription(state: &ManagementAppModel) -> Subscription<ManagementAppMsg> {
+    Subscription::batch([
+        time::every(Duration::from_millis(300))
+            .map(|_| ManagementAppMsg::CheckDownloadStatus),
+        time::every(Duration::from_secs(2))
+            .map(|_| ManagementAppMsg::CheckConfigRevision),
+        state.plugins_state.subscription()
+            .map(|msg| ManagementAppMsg::Plugin(msg)),
+        state.diagnostics_state.subscription()
+            .map(|msg| ManagementAppMsg::Diagnostics(msg)),
+        window::open_events()
+            .map(ManagementAppMsg::WindowOpened),
+        gauntlet_common_ui::accessibility::subscription()
+            .map(ManagementAppMsg::AccessibilityAction),
+        // ctrl+tab switches between views, tab moves focus between text fields
+        event::listen_with(|event, status, _window_id| {
+            match (event, status) {
+                (Event::Keyboard(keyboard::Event::KeyPressed { key: Key::Named(Named::Tab), modifiers, .. }), event::Status::Ignored) => {
+                    if modifiers.control() {
+                        Some(ManagementAppMsg::SwitchToNextView)
+                    } else if modifiers.shift() {
+                        Some(ManagementAppMsg::FocusPrevious)
+                    } else {
+                        Some(ManagementAppMsg::FocusNext)
+                    }
+                }
+                _ => None
+            }
+        }),
+    ])
 }
 
 
@@ -0,0 +1,280 @@
+use std::time::Duration;
+
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use gauntlet_common::i18n::tr;
+use gauntlet_common::model::{DiagnosticsData, DiagnosticsEntry, DiagnosticsKind, ImageCacheStats};
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use iced::alignment::Horizontal;
+use iced::widget::text::Shaping;
+use iced::widget::{button, checkbox, column, container, horizontal_rule, row, scrollable, text};
+use iced::{time, Alignment, Length, Padding, Subscription, Task};
+use itertools::Itertools;
+
+pub struct ManagementAppDiagnosticsState {
+    backend_api: Option<BackendApi>,
+    enabled: bool,
+    entries: Vec<DiagnosticsEntry>,
+    image_cache: Option<ImageCacheStats>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppDiagnosticsMsgIn {
+    RequestDiagnosticsReload,
+    DiagnosticsReloaded(DiagnosticsData),
+    SetEnabled(bool),
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppDiagnosticsMsgOut {
+    DiagnosticsReloaded(DiagnosticsData),
+    HandleBackendError(BackendApiError)
+}
+
+impl ManagementAppDiagnosticsState {
+    pub fn new(backend_api: Option<BackendApi>) -> Self {
+        Self {
+            backend_api,
+            enabled: false,
+            entries: vec![],
+            image_cache: None,
+        }
+    }
+
+    pub fn update(&mut self, message: ManagementAppDiagnosticsMsgIn) -> Task<ManagementAppDiagnosticsMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => {
+                return Task::none()
+            }
+        };
+
+        match message {
+            ManagementAppDiagnosticsMsgIn::RequestDiagnosticsReload => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    let diagnostics = backend_api.get_diagnostics()
+                        .await?;
+
+                    Ok(diagnostics)
+                }, |result| handle_backend_error(result, |diagnostics| ManagementAppDiagnosticsMsgOut::DiagnosticsReloaded(diagnostics)))
+            }
+            ManagementAppDiagnosticsMsgIn::DiagnosticsReloaded(diagnostics) => {
+                self.enabled = diagnostics.enabled;
+                self.entries = diagnostics.entries;
+                self.image_cache = diagnostics.image_cache;
+
+                Task::none()
+            }
+            ManagementAppDiagnosticsMsgIn::SetEnabled(enabled) => {
+                self.enabled = enabled;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_diagnostics_enabled(enabled)
+                        .await?;
+
+                    let diagnostics = backend_api.get_diagnostics()
+                        .await?;
+
+                    Ok(diagnostics)
+                }, |result| handle_backend_error(result, |diagnostics| ManagementAppDiagnosticsMsgOut::DiagnosticsReloaded(diagnostics)))
+            }
+        }
+    }
+
+    // timings are only changing while recording, image cache stats are refreshed with the button
+    pub fn subscription(&self) -> Subscription<ManagementAppDiagnosticsMsgIn> {
+        if self.enabled {
+            time::every(Duration::from_secs(1))
+                .map(|_| ManagementAppDiagnosticsMsgIn::RequestDiagnosticsReload)
+        } else {
+            Subscription::none()
+        }
+    }
+
+    pub fn view(&self) -> Element<ManagementAppDiagnosticsMsgIn> {
+        let enabled: Element<_> = checkbox(tr("settings-diagnostics-record"), self.enabled)
+            .on_toggle(ManagementAppDiagnosticsMsgIn::SetEnabled)
+            .into();
+
+        let refresh_button: Element<_> = button(text(tr("settings-diagnostics-refresh")))
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppDiagnosticsMsgIn::RequestDiagnosticsReload)
+            .into();
+
+        let header: Element<_> = row(vec![enabled, refresh_button])
+            .spacing(16.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let mut content = vec![header];
+
+        if let Some(image_cache) = &self.image_cache {
+            content.push(view_image_cache(image_cache));
+        }
+
+        if self.entries.is_empty() {
+            let hint = if self.enabled {
+                tr("settings-diagnostics-empty")
+            } else {
+                tr("settings-diagnostics-disabled")
+            };
+
+            let hint: Element<_> = text(hint)
+                .class(TextStyle::Subtitle)
+                .into();
+
+            content.push(hint);
+        } else {
+            let sections = [
+                (DiagnosticsKind::Render, tr("settings-diagnostics-render")),
+                (DiagnosticsKind::Search, tr("settings-diagnostics-search")),
+                (DiagnosticsKind::Op, tr("settings-diagnostics-op")),
+                (DiagnosticsKind::Ipc, tr("settings-diagnostics-ipc")),
+            ];
+
+            for (kind, title) in sections {
+                let entries: Vec<_> = self.entries.iter()
+                    .filter(|entry| entry.kind == kind)
+                    .sorted_by(|a, b| b.total_duration.cmp(&a.total_duration))
+                    .collect();
+
+                if entries.is_empty() {
+                    continue
+                }
+
+                content.push(view_section(title, entries));
+            }
+        }
+
+        let content: Element<_> = column(content)
+            .spacing(16.0)
+            .padding(Padding::new(12.0))
+            .into();
+
+        scrollable(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+fn view_section<'a>(title: String, entries: Vec<&'a DiagnosticsEntry>) -> Element<'a, ManagementAppDiagnosticsMsgIn> {
+    let title: Element<_> = text(title)
+        .size(14)
+        .class(TextStyle::Subtitle)
+        .into();
+
+    let header = view_row(
+        tr("settings-diagnostics-column-plugin"),
+        tr("settings-diagnostics-column-name"),
+        tr("settings-diagnostics-column-count"),
+        tr("settings-diagnostics-column-average"),
+        tr("settings-diagnostics-column-max"),
+        tr("settings-diagnostics-column-total"),
+    );
+
+    let mut rows = vec![title, header, horizontal_rule(1).into()];
+
+    for entry in entries {
+        let average = entry.total_duration / (entry.count.max(1) as u32);
+
+        rows.push(view_row(
+            entry.plugin_id.as_ref().map(|plugin_id| plugin_id.to_string()).unwrap_or("-".to_string()),
+            entry.name.clone(),
+            entry.count.to_string(),
+            format_duration(average),
+            format_duration(entry.max_duration),
+            format_duration(entry.total_duration),
+        ));
+    }
+
+    column(rows)
+        .spacing(4.0)
+        .into()
+}
+
+fn view_image_cache<'a>(image_cache: &ImageCacheStats) -> Element<'a, ManagementAppDiagnosticsMsgIn> {
+    let title: Element<_> = text(tr("settings-diagnostics-image-cache"))
+        .size(14)
+        .class(TextStyle::Subtitle)
+        .into();
+
+    let values = [
+        (tr("settings-diagnostics-image-cache-size"), format!("{} / {}", format_size(image_cache.size_bytes), format_size(image_cache.max_size_bytes))),
+        (tr("settings-diagnostics-image-cache-entries"), image_cache.entries.to_string()),
+        (tr("settings-diagnostics-image-cache-hits"), image_cache.hits.to_string()),
+        (tr("settings-diagnostics-image-cache-misses"), image_cache.misses.to_string()),
+        (tr("settings-diagnostics-image-cache-evictions"), image_cache.evictions.to_string()),
+    ];
+
+    let mut rows = vec![title, horizontal_rule(1).into()];
+
+    for (label, value) in values {
+        let label: Element<_> = text(label)
+            .width(Length::FillPortion(4))
+            .into();
+
+        let value: Element<_> = text(value)
+            .align_x(Horizontal::Right)
+            .width(Length::FillPortion(1))
+            .into();
+
+        rows.push(row(vec![label, value]).spacing(8.0).into());
+    }
+
+    column(rows)
+        .spacing(4.0)
+        .into()
+}
+
+fn view_row<'a>(plugin: String, name: String, count: String, average: String, max: String, total: String) -> Element<'a, ManagementAppDiagnosticsMsgIn> {
+    let plugin: Element<_> = text(plugin)
+        .shaping(Shaping::Advanced)
+        .width(Length::FillPortion(4))
+        .into();
+
+    let name: Element<_> = text(name)
+        .shaping(Shaping::Advanced)
+        .width(Length::FillPortion(3))
+        .into();
+
+    let numbers = [count, average, max, total]
+        .into_iter()
+        .map(|value| {
+            text(value)
+                .align_x(Horizontal::Right)
+                .width(Length::FillPortion(1))
+                .into()
+        });
+
+    let mut content = vec![plugin, name];
+    content.extend(numbers);
+
+    container(row(content).spacing(8.0))
+        .width(Length::Fill)
+        .into()
+}
+
+fn format_size(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+fn format_duration(duration: Duration) -> String {
+    if duration.as_secs() > 0 {
+        format!("{:.2}s", duration.as_secs_f64())
+    } else {
+        format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+    }
+}
+
+pub fn handle_backend_error<T>(result: Result<T, BackendApiError>, convert: impl FnOnce(T) -> ManagementAppDiagnosticsMsgOut) -> ManagementAppDiagnosticsMsgOut {
+    match result {
+        Ok(val) => convert(val),
+        Err(err) => ManagementAppDiagnosticsMsgOut::HandleBackendError(err)
+    }
+}
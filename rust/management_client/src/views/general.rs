@@ -1,21 +1,42 @@
 use crate::components::shortcut_selector::ShortcutSelector;
 use crate::theme::text::TextStyle;
 use crate::theme::Element;
-use gauntlet_common::model::PhysicalShortcut;
+use gauntlet_common::dirs::{Dirs, DEFAULT_PROFILE};
+use gauntlet_common::i18n::{tr, tr_with};
+use gauntlet_common::frontend_config::{ThemeSelection, WindowPosition};
+use gauntlet_common::model::{ConfigError, EntrypointId, GeneralSettings, PhysicalShortcut, PluginId, Profiles, SettingsEntrypointReference, SettingsInlineView};
 use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
 use iced::alignment::Horizontal;
 use iced::widget::text::Shaping;
 use iced::widget::tooltip::Position;
-use iced::widget::{column, container, row, text, tooltip, value, Space};
-use iced::{alignment, Alignment, Length, Padding, Task};
+use iced::widget::{button, checkbox, column, container, mouse_area, pick_list, row, text, text_input, tooltip, value, Space};
+use iced::{alignment, mouse, Alignment, Length, Padding, Task};
+use iced_aw::number_input;
 use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
+use std::fmt::Display;
+use crate::theme::button::ButtonStyle;
 use crate::theme::container::ContainerStyle;
 
 pub struct ManagementAppGeneralState {
     backend_api: Option<BackendApi>,
     current_shortcut: Option<PhysicalShortcut>,
     current_shortcut_error: Option<String>,
-    currently_capturing: bool
+    currently_capturing: bool,
+    inline_views: Vec<SettingsInlineView>,
+    favorites: Vec<SettingsEntrypointReference>,
+    fallbacks: Vec<SettingsEntrypointReference>,
+    // list and index of row that is being dragged, and index of row it will be dropped on
+    dragging: Option<(ReorderableList, usize)>,
+    drag_target: Option<usize>,
+    config_error: Option<ConfigError>,
+    settings_archive_path: String,
+    settings_archive_status: Option<Result<String, String>>,
+    general_settings: Option<GeneralSettings>,
+    general_settings_error: Option<String>,
+    profiles: Option<Profiles>,
+    new_profile_name: String,
+    profile_error: Option<String>,
+    managed: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -26,15 +47,75 @@ pub enum ManagementAppGeneralMsgIn {
         shortcut: Option<PhysicalShortcut>,
         error: Option<String>
     },
+    RequestInlineViewsReload,
+    InlineViewsReloaded(Vec<SettingsInlineView>),
+    RequestFavoritesReload,
+    FavoritesReloaded(Vec<SettingsEntrypointReference>),
+    RequestFallbacksReload,
+    FallbacksReloaded(Vec<SettingsEntrypointReference>),
+    RequestConfigErrorReload,
+    ConfigErrorReloaded(Option<ConfigError>),
+    SettingsArchivePathChanged(String),
+    ExportSettings,
+    ImportSettings,
+    SettingsArchiveFinished(Result<String, String>),
+    RequestGeneralSettingsReload,
+    GeneralSettingsReloaded(GeneralSettings),
+    UpdateGeneralSettings(GeneralSettings),
+    GeneralSettingsSaved(Result<(), String>),
+    RequestProfilesReload,
+    ProfilesReloaded(Profiles),
+    NewProfileNameChanged(String),
+    SetActiveProfile(String),
+    ActiveProfileSaved(Result<(), String>),
+    MoveItem {
+        list: ReorderableList,
+        from: usize,
+        to: usize,
+    },
+    DragStarted {
+        list: ReorderableList,
+        index: usize,
+    },
+    DragEntered {
+        list: ReorderableList,
+        index: usize,
+    },
+    DragFinished,
+    DragCancelled,
+    RemoveFavorite {
+        index: usize,
+    },
+    ToggleInlineView {
+        index: usize,
+        enabled: bool,
+    },
     Noop
 }
 
 #[derive(Debug, Clone)]
 pub enum ManagementAppGeneralMsgOut {
     Noop,
+    InlineViewsReloaded(Vec<SettingsInlineView>),
+    FavoritesReloaded(Vec<SettingsEntrypointReference>),
+    FallbacksReloaded(Vec<SettingsEntrypointReference>),
+    ConfigErrorReloaded(Option<ConfigError>),
+    SettingsArchiveFinished(Result<String, String>),
+    GeneralSettingsReloaded(GeneralSettings),
+    GeneralSettingsSaved(Result<(), String>),
+    ProfilesReloaded(Profiles),
+    ActiveProfileSaved(Result<(), String>),
     HandleBackendError(BackendApiError)
 }
 
+// lists in which rows can be dragged to change the order, order is saved right after the drop
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReorderableList {
+    Favorites,
+    Fallbacks,
+    InlineViews,
+}
+
 impl ManagementAppGeneralState {
     pub fn new(backend_api: Option<BackendApi>) -> Self {
         Self {
@@ -42,9 +123,27 @@ impl ManagementAppGeneralState {
             current_shortcut: None,
             current_shortcut_error: None,
             currently_capturing: false,
+            inline_views: vec![],
+            favorites: vec![],
+            fallbacks: vec![],
+            dragging: None,
+            drag_target: None,
+            config_error: None,
+            settings_archive_path: Dirs::new().home_dir().join("gauntlet-settings.zip").to_string_lossy().to_string(),
+            settings_archive_status: None,
+            general_settings: None,
+            general_settings_error: None,
+            profiles: None,
+            new_profile_name: "".to_string(),
+            profile_error: None,
+            managed: false,
         }
     }
 
+    pub fn set_managed(&mut self, managed: bool) {
+        self.managed = managed;
+    }
+
     pub fn update(&mut self, message: ManagementAppGeneralMsgIn) -> Task<ManagementAppGeneralMsgOut> {
         let backend_api = match &self.backend_api {
             Some(backend_api) => backend_api.clone(),
@@ -53,6 +152,11 @@ impl ManagementAppGeneralState {
             }
         };
 
+        // widgets that cannot be disabled still send messages, they are ignored
+        if self.managed && message.changes_settings() {
+            return Task::none()
+        }
+
         match message {
             ManagementAppGeneralMsgIn::ShortcutCaptured(shortcut) => {
                 self.current_shortcut = shortcut.clone();
@@ -80,6 +184,315 @@ impl ManagementAppGeneralState {
 
                 Task::none()
             }
+            ManagementAppGeneralMsgIn::RequestInlineViewsReload => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    let inline_views = backend_api.get_inline_view_order()
+                        .await?;
+
+                    Ok(inline_views)
+                }, |result| handle_backend_error(result, |inline_views| ManagementAppGeneralMsgOut::InlineViewsReloaded(inline_views)))
+            }
+            ManagementAppGeneralMsgIn::InlineViewsReloaded(inline_views) => {
+                self.inline_views = inline_views;
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::RequestFavoritesReload => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    let favorites = backend_api.get_favorites()
+                        .await?;
+
+                    Ok(favorites)
+                }, |result| handle_backend_error(result, |favorites| ManagementAppGeneralMsgOut::FavoritesReloaded(favorites)))
+            }
+            ManagementAppGeneralMsgIn::FavoritesReloaded(favorites) => {
+                self.favorites = favorites;
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::RequestFallbacksReload => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    let fallbacks = backend_api.get_fallback_order()
+                        .await?;
+
+                    Ok(fallbacks)
+                }, |result| handle_backend_error(result, |fallbacks| ManagementAppGeneralMsgOut::FallbacksReloaded(fallbacks)))
+            }
+            ManagementAppGeneralMsgIn::FallbacksReloaded(fallbacks) => {
+                self.fallbacks = fallbacks;
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::RequestConfigErrorReload => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    let config_error = backend_api.get_config_error()
+                        .await?;
+
+                    Ok(config_error)
+                }, |result| handle_backend_error(result, |config_error| ManagementAppGeneralMsgOut::ConfigErrorReloaded(config_error)))
+            }
+            ManagementAppGeneralMsgIn::ConfigErrorReloaded(config_error) => {
+                self.config_error = config_error;
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::SettingsArchivePathChanged(path) => {
+                self.settings_archive_path = path;
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::ExportSettings => {
+                let path = self.settings_archive_path.clone();
+
+                self.settings_archive_status = None;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.export_settings(path.clone())
+                        .await?;
+
+                    Ok(tr_with("settings-exported", &[("path", path.into())]))
+                }, |result| handle_settings_archive_result(result))
+            }
+            ManagementAppGeneralMsgIn::ImportSettings => {
+                let path = self.settings_archive_path.clone();
+
+                self.settings_archive_status = None;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.import_settings(path)
+                        .await?;
+
+                    Ok(tr("settings-imported"))
+                }, |result| handle_settings_archive_result(result))
+            }
+            ManagementAppGeneralMsgIn::SettingsArchiveFinished(status) => {
+                self.settings_archive_status = Some(status);
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::RequestGeneralSettingsReload => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    let settings = backend_api.get_general_settings()
+                        .await?;
+
+                    Ok(settings)
+                }, |result| handle_backend_error(result, |settings| ManagementAppGeneralMsgOut::GeneralSettingsReloaded(settings)))
+            }
+            ManagementAppGeneralMsgIn::GeneralSettingsReloaded(settings) => {
+                self.general_settings = Some(settings);
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::UpdateGeneralSettings(settings) => {
+                self.general_settings = Some(settings.clone());
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_general_settings(settings)
+                        .await?;
+
+                    Ok(())
+                }, |result| {
+                    match result {
+                        Ok(()) => ManagementAppGeneralMsgOut::GeneralSettingsSaved(Ok(())),
+                        // e.g. config file that cannot be parsed, shown next to the settings
+                        Err(BackendApiError::Internal { error }) => ManagementAppGeneralMsgOut::GeneralSettingsSaved(Err(format!("{:#}", error))),
+                        Err(err) => ManagementAppGeneralMsgOut::HandleBackendError(err)
+                    }
+                })
+            }
+            ManagementAppGeneralMsgIn::GeneralSettingsSaved(result) => {
+                self.general_settings_error = result.err();
+
+                // saved config is read again by server, it could have errors that were there before
+                self.update(ManagementAppGeneralMsgIn::RequestConfigErrorReload)
+            }
+            ManagementAppGeneralMsgIn::RequestProfilesReload => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    let profiles = backend_api.get_profiles()
+                        .await?;
+
+                    Ok(profiles)
+                }, |result| handle_backend_error(result, |profiles| ManagementAppGeneralMsgOut::ProfilesReloaded(profiles)))
+            }
+            ManagementAppGeneralMsgIn::ProfilesReloaded(profiles) => {
+                self.profiles = Some(profiles);
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::NewProfileNameChanged(name) => {
+                self.new_profile_name = name;
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::SetActiveProfile(name) => {
+                self.profile_error = None;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_active_profile(name)
+                        .await?;
+
+                    Ok(())
+                }, |result| {
+                    match result {
+                        Ok(()) => ManagementAppGeneralMsgOut::ActiveProfileSaved(Ok(())),
+                        // e.g. invalid profile name
+                        Err(BackendApiError::Internal { error }) => ManagementAppGeneralMsgOut::ActiveProfileSaved(Err(format!("{:#}", error))),
+                        Err(err) => ManagementAppGeneralMsgOut::HandleBackendError(err)
+                    }
+                })
+            }
+            ManagementAppGeneralMsgIn::ActiveProfileSaved(result) => {
+                match result {
+                    Ok(()) => self.new_profile_name = "".to_string(),
+                    Err(error) => self.profile_error = Some(error),
+                }
+
+                self.update(ManagementAppGeneralMsgIn::RequestProfilesReload)
+            }
+            ManagementAppGeneralMsgIn::MoveItem { list, from, to } => {
+                let len = match list {
+                    ReorderableList::Favorites => self.favorites.len(),
+                    ReorderableList::Fallbacks => self.fallbacks.len(),
+                    ReorderableList::InlineViews => self.inline_views.len(),
+                };
+
+                if from == to || from >= len || to >= len {
+                    return Task::none()
+                }
+
+                match list {
+                    ReorderableList::Favorites => move_item(&mut self.favorites, from, to),
+                    ReorderableList::Fallbacks => move_item(&mut self.fallbacks, from, to),
+                    ReorderableList::InlineViews => move_item(&mut self.inline_views, from, to),
+                }
+
+                self.save_order(list)
+            }
+            ManagementAppGeneralMsgIn::DragStarted { list, index } => {
+                self.dragging = Some((list, index));
+                self.drag_target = Some(index);
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::DragEntered { list, index } => {
+                if self.dragging.is_some_and(|(dragging_list, _)| dragging_list == list) {
+                    self.drag_target = Some(index);
+                }
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::DragFinished => {
+                let dragging = self.dragging.take();
+                let drag_target = self.drag_target.take();
+
+                match (dragging, drag_target) {
+                    (Some((list, from)), Some(to)) => self.update(ManagementAppGeneralMsgIn::MoveItem { list, from, to }),
+                    _ => Task::none()
+                }
+            }
+            ManagementAppGeneralMsgIn::DragCancelled => {
+                self.dragging = None;
+                self.drag_target = None;
+
+                Task::none()
+            }
+            ManagementAppGeneralMsgIn::RemoveFavorite { index } => {
+                if index >= self.favorites.len() {
+                    return Task::none()
+                }
+
+                self.favorites.remove(index);
+
+                self.save_order(ReorderableList::Favorites)
+            }
+            ManagementAppGeneralMsgIn::ToggleInlineView { index, enabled } => {
+                let Some(inline_view) = self.inline_views.get_mut(index) else {
+                    return Task::none()
+                };
+
+                inline_view.enabled = enabled;
+
+                let plugin_id = inline_view.plugin_id.clone();
+                let entrypoint_id = inline_view.entrypoint_id.clone();
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_entrypoint_state(plugin_id, entrypoint_id, enabled)
+                        .await?;
+
+                    Ok(())
+                }, |result| handle_backend_error(result, |()| ManagementAppGeneralMsgOut::Noop))
+            }
+        }
+    }
+
+    fn save_order(&self, list: ReorderableList) -> Task<ManagementAppGeneralMsgOut> {
+        let Some(backend_api) = &self.backend_api else {
+            return Task::none()
+        };
+
+        let mut backend_api = backend_api.clone();
+
+        let entrypoints = |items: &[SettingsEntrypointReference]| -> Vec<(PluginId, EntrypointId)> {
+            items.iter()
+                .map(|item| (item.plugin_id.clone(), item.entrypoint_id.clone()))
+                .collect()
+        };
+
+        match list {
+            ReorderableList::Favorites => {
+                let favorites = entrypoints(&self.favorites);
+
+                Task::perform(async move {
+                    backend_api.set_favorites(favorites)
+                        .await?;
+
+                    Ok(())
+                }, |result| handle_backend_error(result, |()| ManagementAppGeneralMsgOut::Noop))
+            }
+            ReorderableList::Fallbacks => {
+                let fallbacks = entrypoints(&self.fallbacks);
+
+                Task::perform(async move {
+                    backend_api.set_fallback_order(fallbacks)
+                        .await?;
+
+                    Ok(())
+                }, |result| handle_backend_error(result, |()| ManagementAppGeneralMsgOut::Noop))
+            }
+            ReorderableList::InlineViews => {
+                let plugin_ids = self.inline_views.iter()
+                    .map(|inline_view| inline_view.plugin_id.clone())
+                    .collect();
+
+                Task::perform(async move {
+                    backend_api.set_inline_view_order(plugin_ids)
+                        .await?;
+
+                    Ok(())
+                }, |result| handle_backend_error(result, |()| ManagementAppGeneralMsgOut::Noop))
+            }
         }
     }
 
@@ -96,9 +509,39 @@ impl ManagementAppGeneralState {
             .height(Length::Fixed(35.0))
             .into();
 
-        let field = self.view_field("Global Shortcut", field.into());
+        let field = self.view_field(tr("settings-global-shortcut"), field.into());
 
-        let content: Element<_> = column(vec![field])
+        let mut fields = vec![];
+
+        if let Some(config_error) = &self.config_error {
+            fields.push(self.view_config_error(config_error));
+        }
+
+        fields.push(field);
+
+        if let Some(profiles) = &self.profiles {
+            fields.push(self.view_profiles(profiles));
+        }
+
+        if let Some(general_settings) = &self.general_settings {
+            fields.append(&mut self.view_general_settings(general_settings));
+        }
+
+        if !self.favorites.is_empty() {
+            fields.push(self.view_favorites());
+        }
+
+        if !self.fallbacks.is_empty() {
+            fields.push(self.view_fallbacks());
+        }
+
+        if !self.inline_views.is_empty() {
+            fields.push(self.view_inline_views());
+        }
+
+        fields.push(self.view_settings_archive());
+
+        let content: Element<_> = column(fields)
             .into();
 
         let content: Element<_> = container(content)
@@ -112,7 +555,579 @@ impl ManagementAppGeneralState {
         content
     }
 
-    fn view_field<'a>(&'a self, label: &'a str, input: Element<'a, ManagementAppGeneralMsgIn>) -> Element<'a, ManagementAppGeneralMsgIn> {
+    fn view_config_error<'a>(&'a self, config_error: &'a ConfigError) -> Element<'a, ManagementAppGeneralMsgIn> {
+        let error_icon: Element<_> = value(Bootstrap::ExclamationTriangleFill)
+            .font(BOOTSTRAP_FONT)
+            .class(TextStyle::Destructive)
+            .into();
+
+        let location = match (config_error.line, config_error.column) {
+            (Some(line), Some(column)) => tr_with("settings-config-error-line-column", &[("file", config_error.config_file.as_str().into()), ("line", line.into()), ("column", column.into())]),
+            (Some(line), None) => tr_with("settings-config-error-line", &[("file", config_error.config_file.as_str().into()), ("line", line.into())]),
+            _ => tr_with("settings-config-error", &[("file", config_error.config_file.as_str().into())]),
+        };
+
+        let location: Element<_> = text(location)
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Destructive)
+            .into();
+
+        let message: Element<_> = text(&config_error.message)
+            .shaping(Shaping::Advanced)
+            .into();
+
+        let hint: Element<_> = text(tr("config-error-default-used"))
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let description: Element<_> = column(vec![location, message, hint])
+            .spacing(4)
+            .into();
+
+        let content: Element<_> = row(vec![error_icon, description])
+            .spacing(12)
+            .into();
+
+        let content: Element<_> = container(content)
+            .width(Length::Fill)
+            .padding(16.0)
+            .class(ContainerStyle::Box)
+            .into();
+
+        container(content)
+            .width(Length::Fill)
+            .padding(12)
+            .into()
+    }
+
+    fn view_general_settings(&self, settings: &GeneralSettings) -> Vec<Element<ManagementAppGeneralMsgIn>> {
+        let mut fields = vec![];
+
+        if let Some(error) = &self.general_settings_error {
+            let error: Element<_> = text(error)
+                .shaping(Shaping::Advanced)
+                .class(TextStyle::Destructive)
+                .into();
+
+            fields.push(self.view_setting(tr("settings-general-save-error"), error, None));
+        }
+
+        let theme_options = vec![
+            SettingOption::new(ThemeSelection::Auto, tr("settings-theme-auto")),
+            SettingOption::new(ThemeSelection::Dark, tr("settings-theme-dark")),
+            SettingOption::new(ThemeSelection::Light, tr("settings-theme-light")),
+        ];
+
+        let selected_theme = theme_options.iter()
+            .find(|option| option.value == settings.theme)
+            .cloned();
+
+        let theme: Element<_> = pick_list(theme_options, selected_theme, {
+            let settings = settings.clone();
+
+            move |option: SettingOption<ThemeSelection>| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                theme: option.value,
+                ..settings.clone()
+            })
+        }).into();
+
+        fields.push(self.view_setting(tr("settings-theme"), theme, Some(tr("settings-restart-hint"))));
+
+        let window_position_options = vec![
+            SettingOption::new(WindowPosition::Center, tr("settings-window-position-center")),
+            SettingOption::new(WindowPosition::Top, tr("settings-window-position-top")),
+        ];
+
+        let selected_window_position = window_position_options.iter()
+            .find(|option| option.value == settings.window_position)
+            .cloned();
+
+        let window_position: Element<_> = pick_list(window_position_options, selected_window_position, {
+            let settings = settings.clone();
+
+            move |option: SettingOption<WindowPosition>| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                window_position: option.value,
+                ..settings.clone()
+            })
+        }).into();
+
+        fields.push(self.view_setting(tr("settings-window-position"), window_position, None));
+
+        let hide_on_focus_loss: Element<_> = checkbox(tr("settings-window-hide-on-focus-loss-enabled"), settings.hide_window_on_focus_loss)
+            .on_toggle({
+                let settings = settings.clone();
+
+                move |hide_window_on_focus_loss| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                    hide_window_on_focus_loss,
+                    ..settings.clone()
+                })
+            })
+            .into();
+
+        fields.push(self.view_setting(tr("settings-window-hide-on-focus-loss"), hide_on_focus_loss, Some(tr("settings-window-hide-on-focus-loss-hint"))));
+
+        let limit_results: Element<_> = checkbox(tr("settings-max-search-results-limit"), settings.max_search_results.is_some())
+            .on_toggle({
+                let settings = settings.clone();
+
+                move |limit| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                    max_search_results: limit.then_some(DEFAULT_MAX_SEARCH_RESULTS),
+                    ..settings.clone()
+                })
+            })
+            .into();
+
+        let mut max_results_content = vec![limit_results];
+
+        if let Some(max_search_results) = settings.max_search_results {
+            let max_results_input: Element<_> = number_input(max_search_results as f64, 1.0..10000.0, std::convert::identity)
+                .width(Length::Fill)
+                .into();
+
+            let max_results_input = max_results_input.map({
+                let settings = settings.clone();
+
+                move |value: f64| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                    max_search_results: Some(value.round().max(1.0) as u32),
+                    ..settings.clone()
+                })
+            });
+
+            max_results_content.push(max_results_input);
+        }
+
+        let max_results: Element<_> = column(max_results_content)
+            .spacing(8)
+            .into();
+
+        fields.push(self.view_setting(tr("settings-max-search-results"), max_results, None));
+
+        let open_window: Element<_> = checkbox(tr("settings-startup-open-window"), settings.open_window_on_start)
+            .on_toggle({
+                let settings = settings.clone();
+
+                move |open_window_on_start| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                    open_window_on_start,
+                    ..settings.clone()
+                })
+            })
+            .into();
+
+        let mut startup_content = vec![open_window];
+
+        // auto-launch is only set up by gauntlet itself on these platforms,
+        // on linux it is up to the user, e.g. using systemd service
+        if cfg!(any(target_os = "macos", target_os = "windows")) {
+            let launch_at_login: Element<_> = checkbox(tr("settings-startup-launch-at-login"), settings.launch_at_login)
+                .on_toggle({
+                    let settings = settings.clone();
+
+                    move |launch_at_login| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                        launch_at_login,
+                        ..settings.clone()
+                    })
+                })
+                .into();
+
+            startup_content.push(launch_at_login);
+        }
+
+        let startup: Element<_> = column(startup_content)
+            .spacing(8)
+            .into();
+
+        fields.push(self.view_setting(tr("settings-startup"), startup, Some(tr("settings-restart-hint"))));
+
+        let telemetry: Element<_> = checkbox(tr("settings-telemetry-opt-in"), settings.telemetry)
+            .on_toggle({
+                let settings = settings.clone();
+
+                move |telemetry| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                    telemetry,
+                    ..settings.clone()
+                })
+            })
+            .into();
+
+        fields.push(self.view_setting(tr("settings-telemetry"), telemetry, Some(tr("settings-telemetry-hint"))));
+
+        let check_for_updates: Element<_> = checkbox(tr("settings-check-for-updates-enabled"), settings.check_for_updates)
+            .on_toggle({
+                let settings = settings.clone();
+
+                move |check_for_updates| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                    check_for_updates,
+                    ..settings.clone()
+                })
+            })
+            .into();
+
+        fields.push(self.view_setting(tr("settings-check-for-updates"), check_for_updates, Some(tr("settings-check-for-updates-hint"))));
+
+        let usage_statistics: Element<_> = checkbox(tr("settings-usage-statistics-enabled"), settings.usage_statistics)
+            .on_toggle({
+                let settings = settings.clone();
+
+                move |usage_statistics| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                    usage_statistics,
+                    ..settings.clone()
+                })
+            })
+            .into();
+
+        fields.push(self.view_setting(tr("settings-usage-statistics"), usage_statistics, Some(tr("settings-usage-statistics-hint"))));
+
+        let sound_muted: Element<_> = checkbox(tr("settings-sound-muted"), settings.sound_muted)
+            .on_toggle({
+                let settings = settings.clone();
+
+                move |sound_muted| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                    sound_muted,
+                    ..settings.clone()
+                })
+            })
+            .into();
+
+        fields.push(self.view_setting(tr("settings-sound"), sound_muted, Some(tr("settings-sound-hint"))));
+
+        let reduced_motion: Element<_> = checkbox(tr("settings-reduced-motion-enabled"), settings.reduced_motion)
+            .on_toggle({
+                let settings = settings.clone();
+
+                move |reduced_motion| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                    reduced_motion,
+                    ..settings.clone()
+                })
+            })
+            .into();
+
+        fields.push(self.view_setting(tr("settings-reduced-motion"), reduced_motion, Some(tr("settings-reduced-motion-hint"))));
+
+        let high_contrast: Element<_> = checkbox(tr("settings-high-contrast-enabled"), settings.high_contrast)
+            .on_toggle({
+                let settings = settings.clone();
+
+                move |high_contrast| ManagementAppGeneralMsgIn::UpdateGeneralSettings(GeneralSettings {
+                    high_contrast,
+                    ..settings.clone()
+                })
+            })
+            .into();
+
+        fields.push(self.view_setting(tr("settings-high-contrast"), high_contrast, Some(tr("settings-high-contrast-hint"))));
+
+        fields
+    }
+
+    fn view_setting<'a>(&'a self, label: String, input: Element<'a, ManagementAppGeneralMsgIn>, hint: Option<String>) -> Element<'a, ManagementAppGeneralMsgIn> {
+        let label: Element<_> = text(label)
+            .shaping(Shaping::Advanced)
+            .align_x(Horizontal::Right)
+            .width(Length::Fill)
+            .into();
+
+        let label: Element<_> = container(label)
+            .width(Length::FillPortion(3))
+            .padding(4)
+            .into();
+
+        let input: Element<_> = container(input)
+            .width(Length::FillPortion(3))
+            .padding(4)
+            .into();
+
+        let after: Element<_> = match hint {
+            Some(hint) => {
+                text(hint)
+                    .shaping(Shaping::Advanced)
+                    .class(TextStyle::Subtitle)
+                    .width(Length::FillPortion(3))
+                    .into()
+            }
+            None => {
+                Space::with_width(Length::FillPortion(3))
+                    .into()
+            }
+        };
+
+        let after: Element<_> = container(after)
+            .width(Length::FillPortion(3))
+            .padding(Padding::from([0.0, 8.0]))
+            .into();
+
+        row(vec![label, input, after])
+            .align_y(Alignment::Center)
+            .padding(12)
+            .into()
+    }
+
+    // selected profile is used after restart, until then server keeps using the one it was started with
+    fn view_profiles<'a>(&'a self, profiles: &'a Profiles) -> Element<'a, ManagementAppGeneralMsgIn> {
+        let profile_options: Vec<_> = profiles.profiles.iter()
+            .map(|profile| SettingOption::new(profile.clone(), profile_label(profile)))
+            .collect();
+
+        let selected_profile = profile_options.iter()
+            .find(|option| option.value == profiles.active)
+            .cloned();
+
+        let profile: Element<_> = pick_list(profile_options, selected_profile, |option: SettingOption<String>| ManagementAppGeneralMsgIn::SetActiveProfile(option.value))
+            .into();
+
+        let new_profile_input: Element<_> = text_input(&tr("settings-profile-new-name"), &self.new_profile_name)
+            .on_input_maybe((!self.managed).then_some(ManagementAppGeneralMsgIn::NewProfileNameChanged))
+            .on_submit_maybe((!self.new_profile_name.is_empty()).then(|| ManagementAppGeneralMsgIn::SetActiveProfile(self.new_profile_name.clone())))
+            .into();
+
+        let new_profile_button: Element<_> = button(text(tr("settings-profile-create")))
+            .class(ButtonStyle::Primary)
+            .on_press_maybe((!self.managed && !self.new_profile_name.is_empty()).then(|| ManagementAppGeneralMsgIn::SetActiveProfile(self.new_profile_name.clone())))
+            .into();
+
+        let new_profile: Element<_> = row(vec![new_profile_input, new_profile_button])
+            .spacing(8)
+            .into();
+
+        let mut content = vec![profile, new_profile];
+
+        if let Some(error) = &self.profile_error {
+            let error: Element<_> = text(error)
+                .shaping(Shaping::Advanced)
+                .class(TextStyle::Destructive)
+                .into();
+
+            content.push(error);
+        }
+
+        let content: Element<_> = column(content)
+            .spacing(8)
+            .into();
+
+        let hint = if profiles.active != profiles.current {
+            tr_with("settings-profile-restart-hint", &[("profile", profile_label(&profiles.current).into())])
+        } else {
+            tr("settings-profile-hint")
+        };
+
+        self.view_setting(tr("settings-profile"), content, Some(hint))
+    }
+
+    fn view_settings_archive(&self) -> Element<ManagementAppGeneralMsgIn> {
+        let label: Element<_> = text(tr("settings-export-import"))
+            .shaping(Shaping::Advanced)
+            .align_x(Horizontal::Right)
+            .width(Length::Fill)
+            .into();
+
+        let label: Element<_> = container(label)
+            .width(Length::FillPortion(3))
+            .padding(4)
+            .into();
+
+        let path_input: Element<_> = text_input(&tr("settings-archive-path"), &self.settings_archive_path)
+            .on_input(ManagementAppGeneralMsgIn::SettingsArchivePathChanged)
+            .into();
+
+        let export_button: Element<_> = button(text(tr("settings-export")))
+            .class(ButtonStyle::Primary)
+            .on_press_maybe((!self.settings_archive_path.is_empty()).then_some(ManagementAppGeneralMsgIn::ExportSettings))
+            .into();
+
+        let import_button: Element<_> = button(text(tr("settings-import")))
+            .class(ButtonStyle::Primary)
+            .on_press_maybe((!self.managed && !self.settings_archive_path.is_empty()).then_some(ManagementAppGeneralMsgIn::ImportSettings))
+            .into();
+
+        let buttons: Element<_> = row(vec![export_button, import_button])
+            .spacing(8)
+            .into();
+
+        let mut content = vec![path_input, buttons];
+
+        if let Some(status) = &self.settings_archive_status {
+            let status: Element<_> = match status {
+                Ok(message) => {
+                    text(message)
+                        .shaping(Shaping::Advanced)
+                        .class(TextStyle::Positive)
+                        .into()
+                }
+                Err(message) => {
+                    text(message)
+                        .shaping(Shaping::Advanced)
+                        .class(TextStyle::Destructive)
+                        .into()
+                }
+            };
+
+            content.push(status);
+        }
+
+        let content: Element<_> = column(content)
+            .spacing(8)
+            .width(Length::FillPortion(3))
+            .padding(4)
+            .into();
+
+        let after: Element<_> = Space::with_width(Length::FillPortion(3))
+            .into();
+
+        row(vec![label, content, after])
+            .align_y(Alignment::Start)
+            .padding(12)
+            .into()
+    }
+
+    // pinned entrypoints shown at the top of main view when prompt is empty
+    fn view_favorites(&self) -> Element<ManagementAppGeneralMsgIn> {
+        let items = self.favorites.iter()
+            .enumerate()
+            .map(|(index, favorite)| {
+                let name: Element<_> = text(format!("{} - {}", favorite.entrypoint_name, favorite.plugin_name))
+                    .shaping(Shaping::Advanced)
+                    .width(Length::Fill)
+                    .into();
+
+                let remove_button: Element<_> = button(value(Bootstrap::X).font(BOOTSTRAP_FONT))
+                    .on_press_maybe((!self.managed).then_some(ManagementAppGeneralMsgIn::RemoveFavorite { index }))
+                    .class(ButtonStyle::TableRow)
+                    .into();
+
+                vec![name, remove_button]
+            })
+            .collect();
+
+        self.view_reorderable_list(tr("settings-favorites"), ReorderableList::Favorites, items)
+    }
+
+    // fallbacks are shown only when nothing else matched
+    fn view_fallbacks(&self) -> Element<ManagementAppGeneralMsgIn> {
+        let items = self.fallbacks.iter()
+            .map(|fallback| {
+                let name: Element<_> = text(format!("{} - {}", fallback.entrypoint_name, fallback.plugin_name))
+                    .shaping(Shaping::Advanced)
+                    .width(Length::Fill)
+                    .into();
+
+                vec![name]
+            })
+            .collect();
+
+        self.view_reorderable_list(tr("settings-fallbacks"), ReorderableList::Fallbacks, items)
+    }
+
+    // inline views shown under main search bar at the same time are ordered top to bottom
+    fn view_inline_views(&self) -> Element<ManagementAppGeneralMsgIn> {
+        let items = self.inline_views.iter()
+            .enumerate()
+            .map(|(index, inline_view)| {
+                let checkbox: Element<_> = checkbox("", inline_view.enabled)
+                    .on_toggle_maybe((!self.managed).then_some(move |enabled| ManagementAppGeneralMsgIn::ToggleInlineView { index, enabled }))
+                    .into();
+
+                let name: Element<_> = text(format!("{} - {}", inline_view.entrypoint_name, inline_view.plugin_name))
+                    .shaping(Shaping::Advanced)
+                    .width(Length::Fill)
+                    .into();
+
+                vec![checkbox, name]
+            })
+            .collect();
+
+        self.view_reorderable_list(tr("settings-inline-views"), ReorderableList::InlineViews, items)
+    }
+
+    // rows are reordered either by dragging the handle or with up and down buttons,
+    // dropping outside the list cancels the drag
+    fn view_reorderable_list<'a>(&'a self, label: String, list: ReorderableList, items: Vec<Vec<Element<'a, ManagementAppGeneralMsgIn>>>) -> Element<'a, ManagementAppGeneralMsgIn> {
+        let label: Element<_> = text(label)
+            .shaping(Shaping::Advanced)
+            .align_x(Horizontal::Right)
+            .width(Length::Fill)
+            .into();
+
+        let label: Element<_> = container(label)
+            .width(Length::FillPortion(3))
+            .padding(4)
+            .into();
+
+        let len = items.len();
+
+        let dragging_index = self.dragging
+            .filter(|(dragging_list, _)| *dragging_list == list)
+            .map(|(_, index)| index);
+
+        let items: Vec<Element<_>> = items.into_iter()
+            .enumerate()
+            .map(|(index, content)| {
+                let handle: Element<_> = value(Bootstrap::GripVertical)
+                    .font(BOOTSTRAP_FONT)
+                    .class(TextStyle::Subtitle)
+                    .into();
+
+                let handle: Element<_> = if self.managed {
+                    handle
+                } else {
+                    mouse_area(handle)
+                        .on_press(ManagementAppGeneralMsgIn::DragStarted { list, index })
+                        .interaction(mouse::Interaction::Grab)
+                        .into()
+                };
+
+                let up_button: Element<_> = button(value(Bootstrap::ArrowUp).font(BOOTSTRAP_FONT))
+                    .on_press_maybe(index.checked_sub(1).filter(|_| !self.managed).map(|to| ManagementAppGeneralMsgIn::MoveItem { list, from: index, to }))
+                    .class(ButtonStyle::TableRow)
+                    .into();
+
+                let down_button: Element<_> = button(value(Bootstrap::ArrowDown).font(BOOTSTRAP_FONT))
+                    .on_press_maybe((!self.managed && index + 1 != len).then_some(ManagementAppGeneralMsgIn::MoveItem { list, from: index, to: index + 1 }))
+                    .class(ButtonStyle::TableRow)
+                    .into();
+
+                let mut row_content = vec![handle];
+                row_content.extend(content);
+                row_content.push(up_button);
+                row_content.push(down_button);
+
+                let item: Element<_> = row(row_content)
+                    .align_y(Alignment::Center)
+                    .spacing(4)
+                    .into();
+
+                let is_drop_target = dragging_index.is_some_and(|dragging_index| dragging_index != index)
+                    && self.drag_target == Some(index);
+
+                let item: Element<_> = container(item)
+                    .class(if is_drop_target { ContainerStyle::DropTarget } else { ContainerStyle::Transparent })
+                    .into();
+
+                mouse_area(item)
+                    .on_enter(ManagementAppGeneralMsgIn::DragEntered { list, index })
+                    .on_release(ManagementAppGeneralMsgIn::DragFinished)
+                    .into()
+            })
+            .collect();
+
+        let list: Element<_> = mouse_area(column(items))
+            .on_release(ManagementAppGeneralMsgIn::DragFinished)
+            .on_exit(ManagementAppGeneralMsgIn::DragCancelled)
+            .into();
+
+        let list: Element<_> = container(list)
+            .width(Length::FillPortion(3))
+            .padding(4)
+            .into();
+
+        let after: Element<_> = Space::with_width(Length::FillPortion(3))
+            .into();
+
+        row(vec![label, list, after])
+            .align_y(Alignment::Start)
+            .padding(12)
+            .into()
+    }
+
+    fn view_field<'a>(&'a self, label: String, input: Element<'a, ManagementAppGeneralMsgIn>) -> Element<'a, ManagementAppGeneralMsgIn> {
         let label: Element<_> = text(label)
             .shaping(Shaping::Advanced)
             .align_x(Horizontal::Right)
@@ -130,12 +1145,12 @@ impl ManagementAppGeneralState {
             .into();
 
         let after = if self.currently_capturing {
-            let hint1: Element<_> = text("Backspace - Unset Shortcut")
+            let hint1: Element<_> = text(tr("settings-shortcut-hint-unset"))
                 .width(Length::Fill)
                 .class(TextStyle::Subtitle)
                 .into();
 
-            let hint2: Element<_> = text("Escape - Stop Capturing")
+            let hint2: Element<_> = text(tr("settings-shortcut-hint-stop"))
                 .width(Length::Fill)
                 .class(TextStyle::Subtitle)
                 .into();
@@ -193,6 +1208,66 @@ impl ManagementAppGeneralState {
     }
 }
 
+// used when limit of search results is enabled in settings
+const DEFAULT_MAX_SEARCH_RESULTS: u32 = 50;
+
+#[derive(Debug, Clone, PartialEq)]
+struct SettingOption<T> {
+    value: T,
+    label: String,
+}
+
+impl<T> SettingOption<T> {
+    fn new(value: T, label: String) -> Self {
+        Self { value, label }
+    }
+}
+
+impl<T> Display for SettingOption<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl ManagementAppGeneralMsgIn {
+    fn changes_settings(&self) -> bool {
+        match self {
+            ManagementAppGeneralMsgIn::ShortcutCaptured(_) => true,
+            ManagementAppGeneralMsgIn::CapturingChanged(_) => true,
+            ManagementAppGeneralMsgIn::ImportSettings => true,
+            ManagementAppGeneralMsgIn::UpdateGeneralSettings(_) => true,
+            ManagementAppGeneralMsgIn::SetActiveProfile(_) => true,
+            ManagementAppGeneralMsgIn::MoveItem { .. } => true,
+            ManagementAppGeneralMsgIn::DragStarted { .. } => true,
+            ManagementAppGeneralMsgIn::RemoveFavorite { .. } => true,
+            ManagementAppGeneralMsgIn::ToggleInlineView { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+fn move_item<T>(items: &mut Vec<T>, from: usize, to: usize) {
+    let item = items.remove(from);
+    items.insert(to, item);
+}
+
+fn profile_label(profile: &str) -> String {
+    if profile == DEFAULT_PROFILE {
+        tr("settings-profile-default")
+    } else {
+        profile.to_string()
+    }
+}
+
+// errors like missing file are shown next to the buttons instead of replacing the whole view
+fn handle_settings_archive_result(result: Result<String, BackendApiError>) -> ManagementAppGeneralMsgOut {
+    match result {
+        Ok(message) => ManagementAppGeneralMsgOut::SettingsArchiveFinished(Ok(message)),
+        Err(BackendApiError::Internal { error }) => ManagementAppGeneralMsgOut::SettingsArchiveFinished(Err(format!("{:#}", error))),
+        Err(err) => ManagementAppGeneralMsgOut::HandleBackendError(err)
+    }
+}
+
 pub fn handle_backend_error<T>(result: Result<T, BackendApiError>, convert: impl FnOnce(T) -> ManagementAppGeneralMsgOut) -> ManagementAppGeneralMsgOut {
     match result {
         Ok(val) => convert(val),
@@ -0,0 +1,315 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::i18n::{tr, tr_with};
+use gauntlet_common::model::{ImportItem, ImportItemKind, ImportSource};
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use iced::widget::text::Shaping;
+use iced::widget::{button, column, container, horizontal_rule, pick_list, row, scrollable, text, text_input};
+use iced::{Alignment, Length, Padding, Task};
+
+// import is done in two steps, first user sees what will be imported and what will be skipped and why
+pub struct ManagementAppImportState {
+    backend_api: Option<BackendApi>,
+    source: ImportSource,
+    path: String,
+    preview: Option<Vec<ImportItem>>,
+    status: Option<Result<String, String>>,
+    managed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppImportMsgIn {
+    SourceChanged(ImportSource),
+    PathChanged(String),
+    Preview,
+    PreviewLoaded(Result<Vec<ImportItem>, String>),
+    Import,
+    ImportFinished(Result<Vec<ImportItem>, String>),
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppImportMsgOut {
+    PreviewLoaded(Result<Vec<ImportItem>, String>),
+    ImportFinished(Result<Vec<ImportItem>, String>),
+    HandleBackendError(BackendApiError)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct SourceOption {
+    value: ImportSource,
+    label: String,
+}
+
+impl Display for SourceOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl ManagementAppImportState {
+    pub fn new(backend_api: Option<BackendApi>) -> Self {
+        let source = ImportSource::RaycastQuicklinks;
+
+        Self {
+            backend_api,
+            source,
+            path: default_path(source),
+            preview: None,
+            status: None,
+            managed: false,
+        }
+    }
+
+    pub fn set_managed(&mut self, managed: bool) {
+        self.managed = managed;
+    }
+
+    pub fn update(&mut self, message: ManagementAppImportMsgIn) -> Task<ManagementAppImportMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => {
+                return Task::none()
+            }
+        };
+
+        match message {
+            ManagementAppImportMsgIn::SourceChanged(source) => {
+                self.source = source;
+                self.path = default_path(source);
+                self.preview = None;
+                self.status = None;
+
+                Task::none()
+            }
+            ManagementAppImportMsgIn::PathChanged(path) => {
+                self.path = path;
+                self.preview = None;
+                self.status = None;
+
+                Task::none()
+            }
+            ManagementAppImportMsgIn::Preview => {
+                let source = self.source;
+                let path = self.path.clone();
+
+                self.status = None;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.import_from_launcher(source, path, true)
+                        .await
+                }, |result| handle_import_result(result, ManagementAppImportMsgOut::PreviewLoaded))
+            }
+            ManagementAppImportMsgIn::PreviewLoaded(result) => {
+                match result {
+                    Ok(items) => self.preview = Some(items),
+                    Err(error) => {
+                        self.preview = None;
+                        self.status = Some(Err(error));
+                    }
+                }
+
+                Task::none()
+            }
+            ManagementAppImportMsgIn::Import => {
+                let source = self.source;
+                let path = self.path.clone();
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.import_from_launcher(source, path, false)
+                        .await
+                }, |result| handle_import_result(result, ManagementAppImportMsgOut::ImportFinished))
+            }
+            ManagementAppImportMsgIn::ImportFinished(result) => {
+                self.preview = None;
+                self.status = Some(result.map(|items| {
+                    let imported = items.iter()
+                        .filter(|item| item.skip_reason.is_none())
+                        .count();
+
+                    tr_with("settings-launcher-import-finished", &[("count", imported.into())])
+                }));
+
+                Task::none()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<ManagementAppImportMsgIn> {
+        let source_options = vec![
+            SourceOption { value: ImportSource::RaycastQuicklinks, label: tr("settings-launcher-import-raycast-quicklinks") },
+            SourceOption { value: ImportSource::RaycastSnippets, label: tr("settings-launcher-import-raycast-snippets") },
+            SourceOption { value: ImportSource::AlfredWebSearches, label: tr("settings-launcher-import-alfred-web-searches") },
+            SourceOption { value: ImportSource::AlbertWebSearches, label: tr("settings-launcher-import-albert-web-searches") },
+            SourceOption { value: ImportSource::AlbertSnippets, label: tr("settings-launcher-import-albert-snippets") },
+        ];
+
+        let selected_source = source_options.iter()
+            .find(|option| option.value == self.source)
+            .cloned();
+
+        let source: Element<_> = pick_list(source_options, selected_source, |option| ManagementAppImportMsgIn::SourceChanged(option.value))
+            .into();
+
+        let path: Element<_> = text_input(&tr("settings-launcher-import-path"), &self.path)
+            .on_input(ManagementAppImportMsgIn::PathChanged)
+            .into();
+
+        let preview_button: Element<_> = button(text(tr("settings-launcher-import-preview")))
+            .class(ButtonStyle::Primary)
+            .on_press_maybe((!self.path.is_empty()).then_some(ManagementAppImportMsgIn::Preview))
+            .into();
+
+        let header: Element<_> = row(vec![source, path, preview_button])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let description: Element<_> = text(tr("settings-launcher-import-description"))
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let mut content = vec![description, header];
+
+        if let Some(status) = &self.status {
+            let status: Element<_> = match status {
+                Ok(message) => {
+                    text(message)
+                        .shaping(Shaping::Advanced)
+                        .class(TextStyle::Positive)
+                        .into()
+                }
+                Err(message) => {
+                    text(message)
+                        .shaping(Shaping::Advanced)
+                        .class(TextStyle::Destructive)
+                        .into()
+                }
+            };
+
+            content.push(status);
+        }
+
+        if let Some(items) = &self.preview {
+            content.push(view_preview(items, self.managed));
+        }
+
+        let content: Element<_> = column(content)
+            .spacing(16.0)
+            .padding(Padding::new(12.0))
+            .into();
+
+        scrollable(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+// importing writes script commands, so it is disabled when settings are managed
+fn view_preview(items: &[ImportItem], managed: bool) -> Element<ManagementAppImportMsgIn> {
+    let importable = items.iter()
+        .filter(|item| item.skip_reason.is_none())
+        .count();
+
+    if items.is_empty() {
+        return text(tr("settings-launcher-import-empty"))
+            .class(TextStyle::Subtitle)
+            .into()
+    }
+
+    let summary: Element<_> = text(tr_with("settings-launcher-import-summary", &[("count", importable.into()), ("total", items.len().into())]))
+        .shaping(Shaping::Advanced)
+        .into();
+
+    let import_button: Element<_> = button(text(tr("settings-launcher-import-import")))
+        .class(ButtonStyle::Primary)
+        .on_press_maybe((!managed && importable > 0).then_some(ManagementAppImportMsgIn::Import))
+        .into();
+
+    let summary: Element<_> = row(vec![summary, import_button])
+        .spacing(16.0)
+        .align_y(Alignment::Center)
+        .into();
+
+    let mut rows = vec![summary, horizontal_rule(1).into()];
+
+    for item in items {
+        let title: Element<_> = text(item.title.clone())
+            .shaping(Shaping::Advanced)
+            .width(Length::FillPortion(3))
+            .into();
+
+        let kind = match item.kind {
+            ImportItemKind::Quicklink => tr("settings-launcher-import-kind-quicklink"),
+            ImportItemKind::Snippet => tr("settings-launcher-import-kind-snippet"),
+            ImportItemKind::WebSearch => tr("settings-launcher-import-kind-web-search"),
+        };
+
+        let kind: Element<_> = text(kind)
+            .class(TextStyle::Subtitle)
+            .width(Length::FillPortion(1))
+            .into();
+
+        let status: Element<_> = match &item.skip_reason {
+            None => {
+                text(tr("settings-launcher-import-status-ready"))
+                    .class(TextStyle::Positive)
+                    .width(Length::FillPortion(3))
+                    .into()
+            }
+            Some(reason) => {
+                text(tr_with("settings-launcher-import-status-skipped", &[("reason", reason.clone().into())]))
+                    .shaping(Shaping::Advanced)
+                    .class(TextStyle::Destructive)
+                    .width(Length::FillPortion(3))
+                    .into()
+            }
+        };
+
+        let item: Element<_> = row(vec![title, kind, status])
+            .spacing(8.0)
+            .into();
+
+        rows.push(container(item).width(Length::Fill).into());
+    }
+
+    column(rows)
+        .spacing(4.0)
+        .into()
+}
+
+fn default_path(source: ImportSource) -> String {
+    let home_dir = Dirs::new().home_dir();
+
+    let path: PathBuf = match source {
+        ImportSource::RaycastQuicklinks => home_dir.join("Downloads").join("Quicklinks.json"),
+        ImportSource::RaycastSnippets => home_dir.join("Downloads").join("Snippets.json"),
+        ImportSource::AlfredWebSearches => home_dir.join("Library").join("Application Support").join("Alfred").join("Alfred.alfredpreferences"),
+        ImportSource::AlbertWebSearches => home_dir.join(".config").join("albert").join("websearch").join("engines.json"),
+        ImportSource::AlbertSnippets => home_dir.join(".local").join("share").join("albert").join("snippets"),
+    };
+
+    path.to_string_lossy().to_string()
+}
+
+// errors like unreadable file are shown in the view instead of replacing the whole view
+fn handle_import_result(
+    result: Result<Vec<ImportItem>, BackendApiError>,
+    convert: impl FnOnce(Result<Vec<ImportItem>, String>) -> ManagementAppImportMsgOut
+) -> ManagementAppImportMsgOut {
+    match result {
+        Ok(items) => convert(Ok(items)),
+        Err(BackendApiError::Internal { error }) => convert(Err(format!("{:#}", error))),
+        Err(err) => ManagementAppImportMsgOut::HandleBackendError(err)
+    }
+}
@@ -0,0 +1,491 @@
+use std::fmt::Display;
+
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use gauntlet_common::i18n::{tr, tr_with};
+use gauntlet_common::model::{Macro, MacroStep, SettingsEntrypointReference};
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use iced::widget::text::Shaping;
+use iced::widget::{button, column, container, horizontal_rule, horizontal_space, pick_list, row, scrollable, text, text_input, value};
+use iced::{Alignment, Length, Padding, Task};
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
+
+// macro is edited as a local draft and sent to server as a whole when saved
+pub struct ManagementAppMacrosState {
+    backend_api: Option<BackendApi>,
+    macros: Vec<Macro>,
+    candidates: Vec<SettingsEntrypointReference>,
+    draft: Option<Macro>,
+    selected_candidate: Option<CandidateOption>,
+    delay: String,
+    status: Option<Result<String, String>>,
+    managed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppMacrosMsgIn {
+    RequestMacrosReload,
+    MacrosReloaded {
+        macros: Vec<Macro>,
+        candidates: Vec<SettingsEntrypointReference>,
+    },
+    NewMacro,
+    EditMacro(String),
+    CancelEdit,
+    NameChanged(String),
+    CandidateSelected(CandidateOption),
+    AddCommandStep,
+    DelayChanged(String),
+    AddDelayStep,
+    MoveStep {
+        from: usize,
+        to: usize,
+    },
+    RemoveStep(usize),
+    Save,
+    Saved(Result<(), String>),
+    RemoveMacro(String),
+    Removed(Result<(), String>),
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppMacrosMsgOut {
+    MacrosReloaded {
+        macros: Vec<Macro>,
+        candidates: Vec<SettingsEntrypointReference>,
+    },
+    Saved(Result<(), String>),
+    Removed(Result<(), String>),
+    HandleBackendError(BackendApiError)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateOption {
+    index: usize,
+    label: String,
+}
+
+impl Display for CandidateOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl ManagementAppMacrosState {
+    pub fn new(backend_api: Option<BackendApi>) -> Self {
+        Self {
+            backend_api,
+            macros: vec![],
+            candidates: vec![],
+            draft: None,
+            selected_candidate: None,
+            delay: "500".to_string(),
+            status: None,
+            managed: false,
+        }
+    }
+
+    pub fn set_managed(&mut self, managed: bool) {
+        self.managed = managed;
+    }
+
+    pub fn update(&mut self, message: ManagementAppMacrosMsgIn) -> Task<ManagementAppMacrosMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => {
+                return Task::none()
+            }
+        };
+
+        match message {
+            ManagementAppMacrosMsgIn::RequestMacrosReload => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    let macros = backend_api.get_macros()
+                        .await?;
+
+                    // list of commands changes when plugins are enabled or generate new commands
+                    let candidates = backend_api.get_macro_step_candidates()
+                        .await?;
+
+                    Ok((macros, candidates))
+                }, |result| {
+                    match result {
+                        Ok((macros, candidates)) => ManagementAppMacrosMsgOut::MacrosReloaded { macros, candidates },
+                        Err(err) => ManagementAppMacrosMsgOut::HandleBackendError(err)
+                    }
+                })
+            }
+            ManagementAppMacrosMsgIn::MacrosReloaded { macros, candidates } => {
+                self.macros = macros;
+                self.candidates = candidates;
+                self.selected_candidate = None;
+
+                Task::none()
+            }
+            ManagementAppMacrosMsgIn::NewMacro => {
+                self.draft = Some(Macro {
+                    id: "".to_string(),
+                    name: "".to_string(),
+                    steps: vec![],
+                });
+                self.status = None;
+
+                Task::none()
+            }
+            ManagementAppMacrosMsgIn::EditMacro(id) => {
+                self.draft = self.macros.iter()
+                    .find(|item| item.id == id)
+                    .cloned();
+                self.status = None;
+
+                Task::none()
+            }
+            ManagementAppMacrosMsgIn::CancelEdit => {
+                self.draft = None;
+                self.status = None;
+
+                Task::none()
+            }
+            ManagementAppMacrosMsgIn::NameChanged(name) => {
+                if let Some(draft) = &mut self.draft {
+                    draft.name = name;
+                }
+
+                Task::none()
+            }
+            ManagementAppMacrosMsgIn::CandidateSelected(option) => {
+                self.selected_candidate = Some(option);
+
+                Task::none()
+            }
+            ManagementAppMacrosMsgIn::AddCommandStep => {
+                let candidate = self.selected_candidate.as_ref()
+                    .and_then(|option| self.candidates.get(option.index));
+
+                if let (Some(draft), Some(candidate)) = (&mut self.draft, candidate) {
+                    draft.steps.push(MacroStep::RunEntrypoint {
+                        plugin_id: candidate.plugin_id.clone(),
+                        plugin_name: candidate.plugin_name.clone(),
+                        entrypoint_id: candidate.entrypoint_id.clone(),
+                        entrypoint_name: candidate.entrypoint_name.clone(),
+                        action_index: None,
+                    });
+                }
+
+                Task::none()
+            }
+            ManagementAppMacrosMsgIn::DelayChanged(delay) => {
+                self.delay = delay;
+
+                Task::none()
+            }
+            ManagementAppMacrosMsgIn::AddDelayStep => {
+                match self.delay.trim().parse::<u64>() {
+                    Ok(millis) => {
+                        if let Some(draft) = &mut self.draft {
+                            draft.steps.push(MacroStep::Delay { millis });
+                        }
+                        self.status = None;
+                    }
+                    Err(_) => {
+                        self.status = Some(Err(tr("settings-macros-invalid-delay")));
+                    }
+                }
+
+                Task::none()
+            }
+            ManagementAppMacrosMsgIn::MoveStep { from, to } => {
+                if let Some(draft) = &mut self.draft {
+                    if from < draft.steps.len() && to < draft.steps.len() {
+                        let step = draft.steps.remove(from);
+                        draft.steps.insert(to, step);
+                    }
+                }
+
+                Task::none()
+            }
+            ManagementAppMacrosMsgIn::RemoveStep(index) => {
+                if let Some(draft) = &mut self.draft {
+                    if index < draft.steps.len() {
+                        draft.steps.remove(index);
+                    }
+                }
+
+                Task::none()
+            }
+            ManagementAppMacrosMsgIn::Save => {
+                let Some(draft) = self.draft.clone() else {
+                    return Task::none()
+                };
+
+                self.status = None;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.save_macro(draft)
+                        .await
+                }, |result| handle_macro_result(result, ManagementAppMacrosMsgOut::Saved))
+            }
+            ManagementAppMacrosMsgIn::Saved(result) => {
+                let saved = result.is_ok();
+
+                self.status = Some(result.map(|()| tr("settings-macros-saved")));
+
+                if saved {
+                    self.draft = None;
+
+                    self.update(ManagementAppMacrosMsgIn::RequestMacrosReload)
+                } else {
+                    Task::none()
+                }
+            }
+            ManagementAppMacrosMsgIn::RemoveMacro(id) => {
+                self.status = None;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.remove_macro(id)
+                        .await
+                }, |result| handle_macro_result(result, ManagementAppMacrosMsgOut::Removed))
+            }
+            ManagementAppMacrosMsgIn::Removed(result) => {
+                let removed = result.is_ok();
+
+                self.status = Some(result.map(|()| tr("settings-macros-removed")));
+
+                if removed {
+                    self.update(ManagementAppMacrosMsgIn::RequestMacrosReload)
+                } else {
+                    Task::none()
+                }
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<ManagementAppMacrosMsgIn> {
+        let description: Element<_> = text(tr("settings-macros-description"))
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let mut content = vec![description];
+
+        if let Some(status) = &self.status {
+            let status: Element<_> = match status {
+                Ok(message) => {
+                    text(message)
+                        .shaping(Shaping::Advanced)
+                        .class(TextStyle::Positive)
+                        .into()
+                }
+                Err(message) => {
+                    text(message)
+                        .shaping(Shaping::Advanced)
+                        .class(TextStyle::Destructive)
+                        .into()
+                }
+            };
+
+            content.push(status);
+        }
+
+        match &self.draft {
+            Some(draft) => content.push(self.view_editor(draft)),
+            None => content.push(self.view_list()),
+        }
+
+        let content: Element<_> = column(content)
+            .spacing(16.0)
+            .padding(Padding::new(12.0))
+            .into();
+
+        scrollable(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn view_list(&self) -> Element<ManagementAppMacrosMsgIn> {
+        let new_button: Element<_> = button(text(tr("settings-macros-new")))
+            .class(ButtonStyle::Primary)
+            .on_press_maybe((!self.managed).then_some(ManagementAppMacrosMsgIn::NewMacro))
+            .into();
+
+        let mut rows = vec![new_button, horizontal_rule(1).into()];
+
+        if self.macros.is_empty() {
+            let empty: Element<_> = text(tr("settings-macros-empty"))
+                .class(TextStyle::Subtitle)
+                .into();
+
+            rows.push(empty);
+        }
+
+        for item in &self.macros {
+            let name: Element<_> = text(item.name.clone())
+                .shaping(Shaping::Advanced)
+                .width(Length::FillPortion(3))
+                .into();
+
+            let steps: Element<_> = text(tr_with("settings-macros-steps-count", &[("count", item.steps.len().into())]))
+                .class(TextStyle::Subtitle)
+                .width(Length::FillPortion(1))
+                .into();
+
+            let edit_button: Element<_> = button(text(tr("settings-macros-edit")))
+                .class(ButtonStyle::Primary)
+                .on_press_maybe((!self.managed).then_some(ManagementAppMacrosMsgIn::EditMacro(item.id.clone())))
+                .into();
+
+            let remove_button: Element<_> = button(text(tr("settings-macros-remove")))
+                .class(ButtonStyle::Destructive)
+                .on_press_maybe((!self.managed).then_some(ManagementAppMacrosMsgIn::RemoveMacro(item.id.clone())))
+                .into();
+
+            let item: Element<_> = row(vec![name, steps, edit_button, remove_button])
+                .spacing(8.0)
+                .align_y(Alignment::Center)
+                .into();
+
+            rows.push(container(item).width(Length::Fill).into());
+        }
+
+        column(rows)
+            .spacing(8.0)
+            .into()
+    }
+
+    fn view_editor<'a>(&'a self, draft: &'a Macro) -> Element<'a, ManagementAppMacrosMsgIn> {
+        let name: Element<_> = text_input(&tr("settings-macros-name"), &draft.name)
+            .on_input(ManagementAppMacrosMsgIn::NameChanged)
+            .into();
+
+        let steps_title: Element<_> = text(tr("settings-macros-steps"))
+            .into();
+
+        let mut rows = vec![name, steps_title];
+
+        if draft.steps.is_empty() {
+            let empty: Element<_> = text(tr("settings-macros-no-steps"))
+                .class(TextStyle::Subtitle)
+                .into();
+
+            rows.push(empty);
+        }
+
+        let len = draft.steps.len();
+
+        for (index, step) in draft.steps.iter().enumerate() {
+            let label = match step {
+                MacroStep::RunEntrypoint { plugin_name, entrypoint_name, .. } => format!("{}. {} ({})", index + 1, entrypoint_name, plugin_name),
+                MacroStep::Delay { millis } => format!("{}. {}", index + 1, tr_with("settings-macros-step-delay", &[("millis", (*millis).into())])),
+            };
+
+            let label: Element<_> = text(label)
+                .shaping(Shaping::Advanced)
+                .width(Length::Fill)
+                .into();
+
+            let up_button: Element<_> = button(value(Bootstrap::ArrowUp).font(BOOTSTRAP_FONT))
+                .on_press_maybe(index.checked_sub(1).map(|to| ManagementAppMacrosMsgIn::MoveStep { from: index, to }))
+                .class(ButtonStyle::TableRow)
+                .into();
+
+            let down_button: Element<_> = button(value(Bootstrap::ArrowDown).font(BOOTSTRAP_FONT))
+                .on_press_maybe((index + 1 != len).then_some(ManagementAppMacrosMsgIn::MoveStep { from: index, to: index + 1 }))
+                .class(ButtonStyle::TableRow)
+                .into();
+
+            let remove_button: Element<_> = button(value(Bootstrap::Trash).font(BOOTSTRAP_FONT))
+                .on_press(ManagementAppMacrosMsgIn::RemoveStep(index))
+                .class(ButtonStyle::TableRow)
+                .into();
+
+            let step: Element<_> = row(vec![label, up_button, down_button, remove_button])
+                .spacing(8.0)
+                .align_y(Alignment::Center)
+                .into();
+
+            rows.push(step);
+        }
+
+        rows.push(horizontal_rule(1).into());
+
+        let candidate_options: Vec<_> = self.candidates.iter()
+            .enumerate()
+            .map(|(index, candidate)| CandidateOption {
+                index,
+                label: format!("{} ({})", candidate.entrypoint_name, candidate.plugin_name),
+            })
+            .collect();
+
+        let candidates: Element<_> = pick_list(candidate_options, self.selected_candidate.clone(), ManagementAppMacrosMsgIn::CandidateSelected)
+            .placeholder(tr("settings-macros-select-command"))
+            .width(Length::Fill)
+            .into();
+
+        let add_command_button: Element<_> = button(text(tr("settings-macros-add-command")))
+            .class(ButtonStyle::Primary)
+            .on_press_maybe(self.selected_candidate.is_some().then_some(ManagementAppMacrosMsgIn::AddCommandStep))
+            .into();
+
+        let add_command: Element<_> = row(vec![candidates, add_command_button])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let delay: Element<_> = text_input(&tr("settings-macros-delay"), &self.delay)
+            .on_input(ManagementAppMacrosMsgIn::DelayChanged)
+            .width(Length::Fill)
+            .into();
+
+        let add_delay_button: Element<_> = button(text(tr("settings-macros-add-delay")))
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppMacrosMsgIn::AddDelayStep)
+            .into();
+
+        let add_delay: Element<_> = row(vec![delay, add_delay_button])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let cancel_button: Element<_> = button(text(tr("settings-macros-cancel")))
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppMacrosMsgIn::CancelEdit)
+            .into();
+
+        let save_button: Element<_> = button(text(tr("settings-macros-save")))
+            .class(ButtonStyle::Positive)
+            .on_press_maybe((!draft.name.trim().is_empty() && !draft.steps.is_empty()).then_some(ManagementAppMacrosMsgIn::Save))
+            .into();
+
+        let buttons: Element<_> = row(vec![horizontal_space().into(), cancel_button, save_button])
+            .spacing(8.0)
+            .into();
+
+        rows.push(add_command);
+        rows.push(add_delay);
+        rows.push(buttons);
+
+        column(rows)
+            .spacing(8.0)
+            .into()
+    }
+}
+
+// errors like missing name are shown in the view instead of replacing the whole view
+fn handle_macro_result<T>(
+    result: Result<T, BackendApiError>,
+    convert: impl FnOnce(Result<(), String>) -> ManagementAppMacrosMsgOut
+) -> ManagementAppMacrosMsgOut {
+    match result {
+        Ok(_) => convert(Ok(())),
+        Err(BackendApiError::Internal { error }) => convert(Err(format!("{:#}", error))),
+        Err(err) => ManagementAppMacrosMsgOut::HandleBackendError(err)
+    }
+}
@@ -1,2 +1,8 @@
+pub mod diagnostics;
 pub mod general;
-pub mod plugins;
\ No newline at end of file
+pub mod launcher_import;
+pub mod macros;
+pub mod permissions;
+pub mod plugins;
+pub mod statistics;
+pub mod theme_editor;
\ No newline at end of file
@@ -0,0 +1,201 @@
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use gauntlet_common::i18n::tr;
+use gauntlet_common::model::{PluginId, PluginPermissionKind, SettingsPluginPermission, SettingsPluginPermissions};
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use iced::widget::text::Shaping;
+use iced::widget::{checkbox, column, container, horizontal_rule, row, scrollable, text};
+use iced::{Alignment, Length, Padding, Task};
+
+// permissions are listed as plugin requested them in manifest,
+// revoking one restarts plugin if it is running, because runtime permissions are set when it is started
+pub struct ManagementAppPermissionsState {
+    backend_api: Option<BackendApi>,
+    plugins: Option<Vec<SettingsPluginPermissions>>,
+    managed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppPermissionsMsgIn {
+    RequestPermissionsReload,
+    PermissionsReloaded(Vec<SettingsPluginPermissions>),
+    SetRevoked {
+        plugin_id: PluginId,
+        kind: PluginPermissionKind,
+        value: String,
+        revoked: bool,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppPermissionsMsgOut {
+    PermissionsReloaded(Vec<SettingsPluginPermissions>),
+    HandleBackendError(BackendApiError)
+}
+
+impl ManagementAppPermissionsState {
+    pub fn new(backend_api: Option<BackendApi>) -> Self {
+        Self {
+            backend_api,
+            plugins: None,
+            managed: false,
+        }
+    }
+
+    pub fn set_managed(&mut self, managed: bool) {
+        self.managed = managed;
+    }
+
+    pub fn update(&mut self, message: ManagementAppPermissionsMsgIn) -> Task<ManagementAppPermissionsMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => {
+                return Task::none()
+            }
+        };
+
+        match message {
+            ManagementAppPermissionsMsgIn::RequestPermissionsReload => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.get_plugin_permissions()
+                        .await
+                }, |result| {
+                    match result {
+                        Ok(plugins) => ManagementAppPermissionsMsgOut::PermissionsReloaded(plugins),
+                        Err(err) => ManagementAppPermissionsMsgOut::HandleBackendError(err)
+                    }
+                })
+            }
+            ManagementAppPermissionsMsgIn::PermissionsReloaded(plugins) => {
+                self.plugins = Some(plugins);
+
+                Task::none()
+            }
+            ManagementAppPermissionsMsgIn::SetRevoked { plugin_id, kind, value, revoked } => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_plugin_permission_revoked(plugin_id, kind, value, revoked)
+                        .await?;
+
+                    backend_api.get_plugin_permissions()
+                        .await
+                }, |result| {
+                    match result {
+                        Ok(plugins) => ManagementAppPermissionsMsgOut::PermissionsReloaded(plugins),
+                        Err(err) => ManagementAppPermissionsMsgOut::HandleBackendError(err)
+                    }
+                })
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<ManagementAppPermissionsMsgIn> {
+        let Some(plugins) = &self.plugins else {
+            return container(text(tr("settings-permissions-loading")).class(TextStyle::Subtitle))
+                .padding(Padding::new(12.0))
+                .into()
+        };
+
+        let description: Element<_> = text(tr("settings-permissions-description"))
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let mut content = vec![description];
+
+        let plugins: Vec<_> = plugins.iter()
+            .filter(|plugin| !plugin.permissions.is_empty())
+            .collect();
+
+        if plugins.is_empty() {
+            let empty: Element<_> = text(tr("settings-permissions-empty"))
+                .class(TextStyle::Subtitle)
+                .into();
+
+            content.push(empty);
+        }
+
+        for plugin in plugins {
+            content.push(self.view_plugin(plugin));
+        }
+
+        let content: Element<_> = column(content)
+            .spacing(16.0)
+            .padding(Padding::new(12.0))
+            .into();
+
+        scrollable(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn view_plugin(&self, plugin: &SettingsPluginPermissions) -> Element<ManagementAppPermissionsMsgIn> {
+        let name: Element<_> = text(plugin.plugin_name.clone())
+            .shaping(Shaping::Advanced)
+            .size(16)
+            .into();
+
+        let mut rows = vec![name, horizontal_rule(1).into()];
+
+        for permission in &plugin.permissions {
+            rows.push(self.view_permission(&plugin.plugin_id, permission));
+        }
+
+        column(rows)
+            .spacing(8.0)
+            .into()
+    }
+
+    fn view_permission(&self, plugin_id: &PluginId, permission: &SettingsPluginPermission) -> Element<ManagementAppPermissionsMsgIn> {
+        let kind: Element<_> = text(permission_kind_label(permission.kind))
+            .class(TextStyle::Subtitle)
+            .width(Length::FillPortion(2))
+            .into();
+
+        let value: Element<_> = text(permission.value.clone())
+            .shaping(Shaping::Advanced)
+            .width(Length::FillPortion(4))
+            .into();
+
+        let granted_at: Element<_> = text(permission.granted_at.clone().unwrap_or_else(|| tr("settings-permissions-granted-at-unknown")))
+            .class(TextStyle::Subtitle)
+            .width(Length::FillPortion(2))
+            .into();
+
+        let granted: Element<_> = checkbox(tr("settings-permissions-granted"), !permission.revoked)
+            .on_toggle_maybe((!self.managed).then(|| {
+                let plugin_id = plugin_id.clone();
+                let kind = permission.kind;
+                let value = permission.value.clone();
+
+                move |granted: bool| ManagementAppPermissionsMsgIn::SetRevoked {
+                    plugin_id: plugin_id.clone(),
+                    kind,
+                    value: value.clone(),
+                    revoked: !granted,
+                }
+            }))
+            .width(Length::FillPortion(1))
+            .into();
+
+        row(vec![kind, value, granted_at, granted])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into()
+    }
+}
+
+fn permission_kind_label(kind: PluginPermissionKind) -> String {
+    match kind {
+        PluginPermissionKind::Network => tr("settings-permissions-kind-network"),
+        PluginPermissionKind::FilesystemRead => tr("settings-permissions-kind-filesystem-read"),
+        PluginPermissionKind::FilesystemWrite => tr("settings-permissions-kind-filesystem-write"),
+        PluginPermissionKind::ExecCommand => tr("settings-permissions-kind-exec-command"),
+        PluginPermissionKind::ExecExecutable => tr("settings-permissions-kind-exec-executable"),
+        PluginPermissionKind::Clipboard => tr("settings-permissions-kind-clipboard"),
+    }
+}
@@ -1,33 +1,59 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
+use std::time::Duration;
 
-use iced::{padding, Alignment, Length, Padding, Task};
+use iced::{padding, time, Alignment, Length, Padding, Subscription, Task};
 use iced::widget::{button, column, container, row, scrollable, text, text_input, value, vertical_rule};
 use iced::widget::text::Shaping;
 use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
 use gauntlet_common::{settings_env_data_from_string, SettingsEnvData};
-use gauntlet_common::model::{EntrypointId, PluginId, PluginPreferenceUserData, SettingsPlugin};
+use gauntlet_common::i18n::{tr, tr_with};
+use gauntlet_common::model::{ActionShortcutConflict, EntrypointId, PluginId, PluginPreferenceUserData, SettingsEntrypointUpdate, SettingsPlugin};
 use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
 
 use crate::theme::button::ButtonStyle;
+use crate::theme::container::ContainerStyle;
 use crate::theme::Element;
 use crate::theme::text::TextStyle;
+use crate::views::plugins::entrypoints::{entrypoint_settings_ui, entrypoint_type_label, PluginEntrypointsMsg};
+use crate::views::plugins::logs::{PluginLogsMsg, PluginLogsState};
 use crate::views::plugins::preferences::{PluginPreferencesMsg, preferences_ui, SelectItem};
+use crate::views::plugins::shortcuts::{shortcuts_ui, PluginShortcutsMsg};
 use crate::views::plugins::table::{PluginTableMsgIn, PluginTableMsgOut, PluginTableState, PluginTableUpdateResult};
 
+mod entrypoints;
+mod logs;
 mod preferences;
+mod shortcuts;
 mod table;
 
 #[derive(Debug, Clone)]
 pub enum ManagementAppPluginMsgIn {
     PluginTableMsg(PluginTableMsgIn),
     PluginPreferenceMsg(PluginPreferencesMsg),
+    PluginShortcutsMsg(PluginShortcutsMsg),
+    PluginEntrypointsMsg(PluginEntrypointsMsg),
+    ShortcutConflict {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        action_id: String,
+        conflict: ActionShortcutConflict,
+    },
+    PluginLogsMsg(PluginLogsMsg),
+    OpenLogs {
+        plugin_id: PluginId
+    },
     RequestPluginReload,
     PluginsReloaded(HashMap<PluginId, SettingsPlugin>),
-    RemovePlugin {
+    RequestPluginRemoval {
         plugin_id: PluginId
     },
+    CancelPluginRemoval,
+    RemovePlugin {
+        plugin_id: PluginId,
+        purge_data: bool,
+    },
     DownloadPlugin {
         plugin_id: PluginId,
     },
@@ -41,6 +67,13 @@ pub enum ManagementAppPluginMsgOut {
     DownloadPlugin {
         plugin_id: PluginId,
     },
+    PluginLogsMsg(PluginLogsMsg),
+    ShortcutConflict {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        action_id: String,
+        conflict: ActionShortcutConflict,
+    },
     HandleBackendError(BackendApiError),
     Noop
 }
@@ -50,7 +83,15 @@ pub struct ManagementAppPluginsState {
     table_state: PluginTableState,
     plugin_data: Rc<RefCell<PluginDataContainer>>,
     preference_user_data: HashMap<(PluginId, Option<EntrypointId>, String), PluginPreferenceUserDataState>,
+    preference_errors: HashMap<(PluginId, Option<EntrypointId>, String), String>,
+    shortcut_errors: HashMap<(PluginId, EntrypointId, String), String>,
+    capturing_shortcut: Option<(PluginId, EntrypointId, String)>,
+    new_aliases: HashMap<(PluginId, EntrypointId), String>,
     selected_item: SelectedItem,
+    logs_state: Option<PluginLogsState>,
+    // plugin for which user is asked whether to keep its data before removal
+    pending_removal: Option<PluginId>,
+    managed: bool,
 }
 
 const SETTINGS_ENV: &'static str = "GAUNTLET_INTERNAL_SETTINGS";
@@ -79,11 +120,22 @@ impl ManagementAppPluginsState {
             backend_api,
             plugin_data: Rc::new(RefCell::new(PluginDataContainer::new())),
             preference_user_data: HashMap::new(),
+            preference_errors: HashMap::new(),
+            shortcut_errors: HashMap::new(),
+            capturing_shortcut: None,
+            new_aliases: HashMap::new(),
             selected_item: select_item,
             table_state: PluginTableState::new(),
+            logs_state: None,
+            pending_removal: None,
+            managed: false,
         }
     }
 
+    pub fn set_managed(&mut self, managed: bool) {
+        self.managed = managed;
+    }
+
     pub fn update(&mut self, message: ManagementAppPluginMsgIn) -> Task<ManagementAppPluginMsgOut> {
         let backend_api = match &self.backend_api {
             Some(backend_api) => backend_api.clone(),
@@ -92,6 +144,11 @@ impl ManagementAppPluginsState {
             }
         };
 
+        // widgets that cannot be disabled still send messages, they are ignored
+        if self.managed && message.changes_settings() {
+            return Task::none()
+        }
+
         match message {
             ManagementAppPluginMsgIn::PluginTableMsg(message) => {
                 match self.table_state.update(message) {
@@ -130,6 +187,22 @@ impl ManagementAppPluginsState {
                                     |result| handle_backend_error(result, |plugins| ManagementAppPluginMsgOut::PluginsReloaded(plugins))
                                 )
                             }
+                            PluginTableMsgOut::SetEntrypointHidden { hidden, plugin_id, entrypoint_id } => {
+                                let mut backend_client = backend_api.clone();
+
+                                Task::perform(
+                                    async move {
+                                        backend_client.set_entrypoint_hidden(plugin_id, entrypoint_id, hidden)
+                                            .await?;
+
+                                        let plugins = backend_client.plugins()
+                                            .await?;
+
+                                        Ok(plugins)
+                                    },
+                                    |result| handle_backend_error(result, |plugins| ManagementAppPluginMsgOut::PluginsReloaded(plugins))
+                                )
+                            }
                             PluginTableMsgOut::SelectItem(selected_item) => {
                                 Task::perform(async move { selected_item }, ManagementAppPluginMsgOut::SelectedItem)
                             }
@@ -153,8 +226,19 @@ impl ManagementAppPluginsState {
             ManagementAppPluginMsgIn::PluginPreferenceMsg(msg) => {
                 match msg {
                     PluginPreferencesMsg::UpdatePreferenceValue { plugin_id, entrypoint_id, id, user_data } => {
+                        let key = (plugin_id.clone(), entrypoint_id.clone(), id.clone());
+
                         self.preference_user_data
-                            .insert((plugin_id.clone(), entrypoint_id.clone(), id.clone()), user_data.clone());
+                            .insert(key.clone(), user_data.clone());
+
+                        // invalid value is kept in the input but not saved until it is fixed
+                        if let Err(err) = self.validate_preference_value(&plugin_id, &entrypoint_id, &id, &user_data) {
+                            self.preference_errors.insert(key, format!("{:#}", err));
+
+                            return Task::none()
+                        }
+
+                        self.preference_errors.remove(&key);
 
                         let mut backend_api = backend_api.clone();
 
@@ -170,6 +254,186 @@ impl ManagementAppPluginsState {
                     }
                 }
             }
+            ManagementAppPluginMsgIn::PluginShortcutsMsg(msg) => {
+                match msg {
+                    PluginShortcutsMsg::SetShortcut { plugin_id, entrypoint_id, action_id, shortcut } => {
+                        self.shortcut_errors.remove(&(plugin_id.clone(), entrypoint_id.clone(), action_id.clone()));
+
+                        let mut backend_api = backend_api.clone();
+
+                        Task::perform(
+                            async move {
+                                let conflict = backend_api.set_action_shortcut(plugin_id.clone(), entrypoint_id.clone(), action_id.clone(), shortcut)
+                                    .await?;
+
+                                let plugins = backend_api.plugins()
+                                    .await?;
+
+                                Ok((plugin_id, entrypoint_id, action_id, conflict, plugins))
+                            },
+                            |result| handle_backend_error(result, |(plugin_id, entrypoint_id, action_id, conflict, plugins)| {
+                                match conflict {
+                                    None => ManagementAppPluginMsgOut::PluginsReloaded(plugins),
+                                    Some(conflict) => ManagementAppPluginMsgOut::ShortcutConflict {
+                                        plugin_id,
+                                        entrypoint_id,
+                                        action_id,
+                                        conflict,
+                                    },
+                                }
+                            })
+                        )
+                    }
+                    PluginShortcutsMsg::CapturingChanged { plugin_id, entrypoint_id, action_id, capturing } => {
+                        let key = (plugin_id, entrypoint_id, action_id);
+
+                        if capturing {
+                            self.capturing_shortcut = Some(key);
+                        } else if self.capturing_shortcut.as_ref() == Some(&key) {
+                            self.capturing_shortcut = None;
+                        }
+
+                        Task::none()
+                    }
+                }
+            }
+            ManagementAppPluginMsgIn::PluginEntrypointsMsg(msg) => {
+                let (plugin_id, entrypoint_id, update) = match msg {
+                    PluginEntrypointsMsg::SetEnabled { plugin_id, entrypoint_id, enabled } => {
+                        let update = SettingsEntrypointUpdate {
+                            enabled: Some(enabled),
+                            ..SettingsEntrypointUpdate::default()
+                        };
+
+                        (plugin_id, entrypoint_id, update)
+                    }
+                    PluginEntrypointsMsg::SetHidden { plugin_id, entrypoint_id, hidden } => {
+                        let update = SettingsEntrypointUpdate {
+                            hidden: Some(hidden),
+                            ..SettingsEntrypointUpdate::default()
+                        };
+
+                        (plugin_id, entrypoint_id, update)
+                    }
+                    PluginEntrypointsMsg::SetFavorite { plugin_id, entrypoint_id, favorite } => {
+                        let update = SettingsEntrypointUpdate {
+                            favorite: Some(favorite),
+                            ..SettingsEntrypointUpdate::default()
+                        };
+
+                        (plugin_id, entrypoint_id, update)
+                    }
+                    PluginEntrypointsMsg::SetAliases { plugin_id, entrypoint_id, aliases } => {
+                        self.new_aliases.remove(&(plugin_id.clone(), entrypoint_id.clone()));
+
+                        let update = SettingsEntrypointUpdate {
+                            aliases: Some(aliases),
+                            ..SettingsEntrypointUpdate::default()
+                        };
+
+                        (plugin_id, entrypoint_id, update)
+                    }
+                    PluginEntrypointsMsg::NewAliasChanged { plugin_id, entrypoint_id, value } => {
+                        self.new_aliases.insert((plugin_id, entrypoint_id), value);
+
+                        return Task::none()
+                    }
+                };
+
+                let mut backend_client = backend_api.clone();
+
+                Task::perform(
+                    async move {
+                        backend_client.update_entrypoint(plugin_id, entrypoint_id, update)
+                            .await?;
+
+                        let plugins = backend_client.plugins()
+                            .await?;
+
+                        Ok(plugins)
+                    },
+                    |result| handle_backend_error(result, |plugins| ManagementAppPluginMsgOut::PluginsReloaded(plugins))
+                )
+            }
+            ManagementAppPluginMsgIn::ShortcutConflict { plugin_id, entrypoint_id, action_id, conflict } => {
+                let error = match conflict {
+                    ActionShortcutConflict::MissingModifier => tr("settings-shortcut-conflict-missing-modifier"),
+                    ActionShortcutConflict::GlobalShortcut => tr("settings-shortcut-conflict-global"),
+                    ActionShortcutConflict::Builtin => tr("settings-shortcut-conflict-builtin"),
+                    ActionShortcutConflict::Action { action_description } => {
+                        tr_with("settings-shortcut-conflict-action", &[("action", action_description.into())])
+                    }
+                };
+
+                self.shortcut_errors.insert((plugin_id, entrypoint_id, action_id), error);
+
+                Task::none()
+            }
+            ManagementAppPluginMsgIn::OpenLogs { plugin_id } => {
+                self.logs_state = Some(PluginLogsState::new(plugin_id));
+
+                Task::done(ManagementAppPluginMsgOut::PluginLogsMsg(PluginLogsMsg::Poll))
+            }
+            ManagementAppPluginMsgIn::PluginLogsMsg(msg) => {
+                let Some(logs_state) = &mut self.logs_state else {
+                    return Task::none()
+                };
+
+                match msg {
+                    PluginLogsMsg::Poll => {
+                        let Some((stdout_offset, stderr_offset)) = logs_state.start_fetch() else {
+                            return Task::none()
+                        };
+
+                        let plugin_id = logs_state.plugin_id().clone();
+                        let mut backend_api = backend_api.clone();
+
+                        Task::perform(
+                            async move {
+                                let logs = backend_api.plugin_logs(plugin_id.clone(), stdout_offset, stderr_offset)
+                                    .await?;
+
+                                Ok((plugin_id, logs))
+                            },
+                            |result| handle_backend_error(result, |(plugin_id, logs)| ManagementAppPluginMsgOut::PluginLogsMsg(PluginLogsMsg::LogsFetched { plugin_id, logs }))
+                        )
+                    }
+                    PluginLogsMsg::LogsFetched { plugin_id, logs } => {
+                        logs_state.apply_logs(plugin_id, logs)
+                    }
+                    PluginLogsMsg::SetFilter(filter) => {
+                        logs_state.set_filter(filter);
+
+                        Task::none()
+                    }
+                    PluginLogsMsg::SetSearch(search) => {
+                        logs_state.set_search(search);
+
+                        Task::none()
+                    }
+                    PluginLogsMsg::ToggleFollow(follow) => {
+                        logs_state.set_follow(follow);
+
+                        if follow {
+                            Task::done(ManagementAppPluginMsgOut::PluginLogsMsg(PluginLogsMsg::Poll))
+                        } else {
+                            Task::none()
+                        }
+                    }
+                    PluginLogsMsg::CopyDiagnostics => {
+                        let plugin_data = self.plugin_data.borrow();
+
+                        let diagnostics = logs_state.diagnostics(plugin_data.plugins.get(logs_state.plugin_id()));
+
+                        iced::clipboard::write(diagnostics)
+                    }
+                    PluginLogsMsg::Close => {
+                        self.logs_state = None;
+
+                        Task::none()
+                    }
+                }
+            }
             ManagementAppPluginMsgIn::RequestPluginReload => {
                 let mut backend_api = backend_api.clone();
 
@@ -188,14 +452,25 @@ impl ManagementAppPluginsState {
 
                 Task::none()
             }
-            ManagementAppPluginMsgIn::RemovePlugin { plugin_id } => {
+            ManagementAppPluginMsgIn::RequestPluginRemoval { plugin_id } => {
+                self.pending_removal = Some(plugin_id);
+
+                Task::none()
+            }
+            ManagementAppPluginMsgIn::CancelPluginRemoval => {
+                self.pending_removal = None;
+
+                Task::none()
+            }
+            ManagementAppPluginMsgIn::RemovePlugin { plugin_id, purge_data } => {
                 self.selected_item = SelectedItem::None;
+                self.pending_removal = None;
 
                 let mut backend_client = backend_api.clone();
 
                 Task::perform(
                     async move {
-                        backend_client.remove_plugin(plugin_id)
+                        backend_client.remove_plugin(plugin_id, purge_data)
                             .await?;
 
                         let plugins = backend_client.plugins()
@@ -211,6 +486,7 @@ impl ManagementAppPluginsState {
             }
             ManagementAppPluginMsgIn::SelectItem(selected_item) => {
                 self.selected_item = selected_item;
+                self.pending_removal = None;
 
                 Task::none()
             }
@@ -220,7 +496,34 @@ impl ManagementAppPluginsState {
         }
     }
 
+    fn validate_preference_value(
+        &self,
+        plugin_id: &PluginId,
+        entrypoint_id: &Option<EntrypointId>,
+        id: &str,
+        user_data: &PluginPreferenceUserDataState
+    ) -> anyhow::Result<()> {
+        let plugin_data = self.plugin_data.borrow();
+
+        let Some(plugin) = plugin_data.plugins.get(plugin_id) else {
+            return Ok(())
+        };
+
+        let preferences = match entrypoint_id {
+            None => Some(&plugin.preferences),
+            Some(entrypoint_id) => plugin.entrypoints.get(entrypoint_id).map(|entrypoint| &entrypoint.preferences),
+        };
+
+        match preferences.and_then(|preferences| preferences.get(id)) {
+            None => Ok(()),
+            Some(preference) => preference.validate(&user_data.clone().to_user_data())
+        }
+    }
+
     fn apply_plugin_reload(&mut self, plugins: HashMap<PluginId, SettingsPlugin>) {
+        self.preference_errors.clear();
+        self.shortcut_errors.clear();
+
         self.preference_user_data = plugins.iter()
             .map(|(plugin_id, plugin)| {
                 let mut result = vec![];
@@ -265,7 +568,24 @@ impl ManagementAppPluginsState {
         self.table_state.apply_plugin_reload(self.plugin_data.clone(), plugin_refs)
     }
 
+    pub fn subscription(&self) -> Subscription<ManagementAppPluginMsgIn> {
+        match &self.logs_state {
+            Some(logs_state) if logs_state.follow() => {
+                time::every(Duration::from_secs(1))
+                    .map(|_| ManagementAppPluginMsgIn::PluginLogsMsg(PluginLogsMsg::Poll))
+            }
+            _ => Subscription::none()
+        }
+    }
+
     pub fn view(&self) -> Element<ManagementAppPluginMsgIn> {
+        if let Some(logs_state) = &self.logs_state {
+            let plugin_data = self.plugin_data.borrow();
+
+            return logs_state.view(plugin_data.plugins.get(logs_state.plugin_id()))
+                .map(|msg| ManagementAppPluginMsgIn::PluginLogsMsg(msg))
+        }
+
         let table: Element<_> = self.table_state.view()
             .map(|msg| ManagementAppPluginMsgIn::PluginTableMsg(msg));
 
@@ -275,9 +595,9 @@ impl ManagementAppPluginsState {
 
         let sidebar_content: Element<_> = match &self.selected_item {
             SelectedItem::None => {
-                let text1: Element<_> = text("Select item from the list on the left").into();
-                let text2: Element<_> = text("or").into();
-                let text3: Element<_> = text("Click '+' to add new plugin").into();
+                let text1: Element<_> = text(tr("settings-plugins-select-item")).into();
+                let text2: Element<_> = text(tr("settings-plugins-or")).into();
+                let text3: Element<_> = text(tr("settings-plugins-add-hint")).into();
 
                 let text_column = column(vec![text1, text2, text3])
                     .align_x(Alignment::Center);
@@ -296,7 +616,7 @@ impl ManagementAppPluginsState {
 
                 match plugin {
                     None => {
-                        let loading_text: Element<_> = text("Loading...").into();
+                        let loading_text: Element<_> = text(tr("loading")).into();
 
                         container(loading_text)
                             .align_y(Alignment::Center)
@@ -328,7 +648,7 @@ impl ManagementAppPluginsState {
                         ];
 
                         if !plugin.plugin_description.is_empty() {
-                            let description_label: Element<_> = text("Description")
+                            let description_label: Element<_> = text(tr("settings-plugins-description"))
                                 .size(14)
                                 .class(TextStyle::Subtitle)
                                 .into();
@@ -351,10 +671,93 @@ impl ManagementAppPluginsState {
                         }
 
                         column_content.push(
-                            preferences_ui(plugin_id.clone(), None, &plugin.preferences, &self.preference_user_data)
+                            preferences_ui(plugin_id.clone(), None, &plugin.preferences, &self.preference_user_data, &self.preference_errors)
                                 .map(|msg| ManagementAppPluginMsgIn::PluginPreferenceMsg(msg))
                         );
 
+                        if !plugin.entrypoints.is_empty() {
+                            let entrypoints_label: Element<_> = text(tr("settings-plugins-entrypoints"))
+                                .size(14)
+                                .class(TextStyle::Subtitle)
+                                .into();
+
+                            let entrypoints_label = container(entrypoints_label)
+                                .padding(padding::left(8.0))
+                                .into();
+
+                            column_content.push(entrypoints_label);
+
+                            let mut entrypoints: Vec<_> = plugin.entrypoints.values().collect();
+
+                            entrypoints.sort_by_key(|entrypoint| &entrypoint.entrypoint_name);
+
+                            for entrypoint in entrypoints {
+                                let entrypoint_id = entrypoint.entrypoint_id.clone();
+
+                                let name: Element<_> = text(entrypoint.entrypoint_name.to_string())
+                                    .shaping(Shaping::Advanced)
+                                    .width(Length::Fill)
+                                    .into();
+
+                                let entrypoint_type: Element<_> = text(entrypoint_type_label(&entrypoint.entrypoint_type))
+                                    .size(14)
+                                    .class(TextStyle::Subtitle)
+                                    .into();
+
+                                let header: Element<_> = row(vec![name, entrypoint_type])
+                                    .align_y(Alignment::Center)
+                                    .into();
+
+                                let header: Element<_> = button(header)
+                                    .class(ButtonStyle::TableRow)
+                                    .width(Length::Fill)
+                                    .on_press(ManagementAppPluginMsgIn::SelectItem(SelectedItem::Entrypoint {
+                                        plugin_id: plugin_id.clone(),
+                                        entrypoint_id: entrypoint_id.clone(),
+                                    }))
+                                    .into();
+
+                                let mut entrypoint_content = vec![header];
+
+                                if !entrypoint.entrypoint_description.is_empty() {
+                                    let description = text(entrypoint.entrypoint_description.to_string())
+                                        .shaping(Shaping::Advanced);
+
+                                    let description = container(description)
+                                        .padding(Padding::from([0.0, 8.0]))
+                                        .into();
+
+                                    entrypoint_content.push(description);
+                                }
+
+                                entrypoint_content.push(
+                                    entrypoint_settings_ui(plugin_id.clone(), entrypoint, &self.new_aliases)
+                                        .map(|msg| ManagementAppPluginMsgIn::PluginEntrypointsMsg(msg))
+                                );
+
+                                entrypoint_content.push(
+                                    preferences_ui(plugin_id.clone(), Some(entrypoint_id.clone()), &entrypoint.preferences, &self.preference_user_data, &self.preference_errors)
+                                        .map(|msg| ManagementAppPluginMsgIn::PluginPreferenceMsg(msg))
+                                );
+
+                                entrypoint_content.push(
+                                    shortcuts_ui(plugin_id.clone(), entrypoint_id, &entrypoint.actions, &self.capturing_shortcut, &self.shortcut_errors)
+                                        .map(|msg| ManagementAppPluginMsgIn::PluginShortcutsMsg(msg))
+                                );
+
+                                let entrypoint_content: Element<_> = column(entrypoint_content)
+                                    .spacing(8)
+                                    .into();
+
+                                let entrypoint_content = container(entrypoint_content)
+                                    .padding(Padding::new(4.0))
+                                    .class(ContainerStyle::Box)
+                                    .into();
+
+                                column_content.push(entrypoint_content);
+                            }
+                        }
+
                         let content: Element<_> = column(column_content)
                             .spacing(12)
                             .into();
@@ -364,10 +767,25 @@ impl ManagementAppPluginsState {
                             .width(Length::Fill)
                             .into();
 
-                        let mut column_content = vec![content];
+                        let logs_text: Element<_> = text(tr("settings-plugins-show-logs"))
+                            .into();
+
+                        let logs_text_container: Element<_> = container(logs_text)
+                            .width(Length::Fill)
+                            .align_y(Alignment::Center)
+                            .align_x(Alignment::Center)
+                            .into();
+
+                        let logs_button: Element<_> = button(logs_text_container)
+                            .width(Length::Fill)
+                            .class(ButtonStyle::Primary)
+                            .on_press(ManagementAppPluginMsgIn::OpenLogs { plugin_id: plugin.plugin_id.clone() })
+                            .into();
+
+                        let mut column_content = vec![content, logs_button];
 
                         if !plugin.plugin_id.to_string().starts_with("bundled://") {
-                             let check_for_updates_text: Element<_> = text("Check for updates")
+                             let check_for_updates_text: Element<_> = text(tr("settings-plugins-check-for-updates"))
                                 .into();
 
                             let check_for_updates_text_container: Element<_> = container(check_for_updates_text)
@@ -379,27 +797,31 @@ impl ManagementAppPluginsState {
                             let check_for_updates_button: Element<_> = button(check_for_updates_text_container)
                                 .width(Length::Fill)
                                 .class(ButtonStyle::Primary)
-                                .on_press(ManagementAppPluginMsgIn::DownloadPlugin { plugin_id: plugin.plugin_id.clone() })
+                                .on_press_maybe((!self.managed).then(|| ManagementAppPluginMsgIn::DownloadPlugin { plugin_id: plugin.plugin_id.clone() }))
                                 .into();
 
                             column_content.push(check_for_updates_button);
 
-                            let remove_text: Element<_> = text("Remove plugin")
-                                .into();
-
-                            let remove_button_text_container: Element<_> = container(remove_text)
-                                .width(Length::Fill)
-                                .align_y(Alignment::Center)
-                                .align_x(Alignment::Center)
-                                .into();
-
-                            let remove_button: Element<_> = button(remove_button_text_container)
-                                .width(Length::Fill)
-                                .class(ButtonStyle::Destructive)
-                                .on_press(ManagementAppPluginMsgIn::RemovePlugin { plugin_id: plugin.plugin_id.clone() })
-                                .into();
-
-                            column_content.push(remove_button);
+                            if self.pending_removal.as_ref() == Some(&plugin.plugin_id) {
+                                column_content.push(self.view_removal_confirmation(&plugin.plugin_id));
+                            } else {
+                                let remove_text: Element<_> = text(tr("settings-plugins-remove"))
+                                    .into();
+
+                                let remove_button_text_container: Element<_> = container(remove_text)
+                                    .width(Length::Fill)
+                                    .align_y(Alignment::Center)
+                                    .align_x(Alignment::Center)
+                                    .into();
+
+                                let remove_button: Element<_> = button(remove_button_text_container)
+                                    .width(Length::Fill)
+                                    .class(ButtonStyle::Destructive)
+                                    .on_press_maybe((!self.managed).then(|| ManagementAppPluginMsgIn::RequestPluginRemoval { plugin_id: plugin.plugin_id.clone() }))
+                                    .into();
+
+                                column_content.push(remove_button);
+                            }
                         }
 
                         let content: Element<_> = column(column_content)
@@ -423,7 +845,7 @@ impl ManagementAppPluginsState {
 
                 match entrypoint {
                     None => {
-                        let loading_text: Element<_> = text("Loading...").into();
+                        let loading_text: Element<_> = text(tr("loading")).into();
 
                         container(loading_text)
                             .align_y(Alignment::Center)
@@ -440,12 +862,21 @@ impl ManagementAppPluginsState {
                             .padding(Padding::new(8.0))
                             .into();
 
+                        let entrypoint_type: Element<_> = text(entrypoint_type_label(&entrypoint.entrypoint_type))
+                            .class(TextStyle::Subtitle)
+                            .into();
+
+                        let entrypoint_type = container(entrypoint_type)
+                            .padding(padding::bottom(8.0))
+                            .into();
+
                         let mut column_content = vec![
                             name,
+                            entrypoint_type,
                         ];
 
                         if !entrypoint.entrypoint_description.is_empty() {
-                            let description_label: Element<_> = text("Description")
+                            let description_label: Element<_> = text(tr("settings-plugins-description"))
                                 .size(14)
                                 .class(TextStyle::Subtitle)
                                 .into();
@@ -465,10 +896,20 @@ impl ManagementAppPluginsState {
                         }
 
                         column_content.push(
-                            preferences_ui(plugin_id.clone(), Some(entrypoint_id.clone()), &entrypoint.preferences, &self.preference_user_data)
+                            entrypoint_settings_ui(plugin_id.clone(), entrypoint, &self.new_aliases)
+                                .map(|msg| ManagementAppPluginMsgIn::PluginEntrypointsMsg(msg))
+                        );
+
+                        column_content.push(
+                            preferences_ui(plugin_id.clone(), Some(entrypoint_id.clone()), &entrypoint.preferences, &self.preference_user_data, &self.preference_errors)
                                 .map(|msg| ManagementAppPluginMsgIn::PluginPreferenceMsg(msg))
                         );
 
+                        column_content.push(
+                            shortcuts_ui(plugin_id.clone(), entrypoint_id.clone(), &entrypoint.actions, &self.capturing_shortcut, &self.shortcut_errors)
+                                .map(|msg| ManagementAppPluginMsgIn::PluginShortcutsMsg(msg))
+                        );
+
                         let column: Element<_> = column(column_content)
                             .spacing(12)
                             .into();
@@ -486,14 +927,14 @@ impl ManagementAppPluginsState {
                 }
             }
             SelectedItem::NewPlugin { repository_url } => {
-                let url_input: Element<_> = text_input("Enter Git Repository URL", &repository_url)
+                let url_input: Element<_> = text_input(&tr("settings-plugins-repository-url"), &repository_url)
                     .on_input(|value| ManagementAppPluginMsgIn::SelectItem(SelectedItem::NewPlugin { repository_url: value }))
                     .on_submit(ManagementAppPluginMsgIn::DownloadPlugin { plugin_id: PluginId::from_string(repository_url) })
                     .into();
 
                 let content: Element<_> = column(vec![
                     url_input,
-                    text("Supported protocols:").into(),
+                    text(tr("settings-plugins-supported-protocols")).into(),
                     text("http(s), ssh, git").into(),
                 ]).into();
 
@@ -518,7 +959,7 @@ impl ManagementAppPluginsState {
         };
 
         let top_button_text = if plugin_url.is_some() {
-            text("Download plugin")
+            text(tr("settings-plugins-download"))
         } else {
             value(Bootstrap::Plus)
                 .font(BOOTSTRAP_FONT)
@@ -535,9 +976,10 @@ impl ManagementAppPluginsState {
             None => ManagementAppPluginMsgIn::SelectItem(SelectedItem::NewPlugin { repository_url: Default::default() })
         };
 
+        // plugin installation is disabled when settings are managed
         let top_button = button(top_button_text_container)
             .width(Length::Fill)
-            .on_press(top_button_action)
+            .on_press_maybe((!self.managed).then_some(top_button_action))
             .into();
 
         let sidebar: Element<_> = column(vec![top_button, sidebar_content])
@@ -558,6 +1000,49 @@ impl ManagementAppPluginsState {
         
         content
     }
+
+    fn view_removal_confirmation(&self, plugin_id: &PluginId) -> Element<ManagementAppPluginMsgIn> {
+        let description: Element<_> = text(tr("settings-plugins-remove-confirm"))
+            .shaping(Shaping::Advanced)
+            .into();
+
+        let description = container(description)
+            .width(Length::Fill)
+            .align_x(Alignment::Center)
+            .padding(Padding::new(8.0))
+            .into();
+
+        let buttons = [
+            (tr("settings-plugins-remove-purge-data"), ButtonStyle::Destructive, ManagementAppPluginMsgIn::RemovePlugin { plugin_id: plugin_id.clone(), purge_data: true }),
+            (tr("settings-plugins-remove-keep-data"), ButtonStyle::Destructive, ManagementAppPluginMsgIn::RemovePlugin { plugin_id: plugin_id.clone(), purge_data: false }),
+            (tr("settings-plugins-remove-cancel"), ButtonStyle::Primary, ManagementAppPluginMsgIn::CancelPluginRemoval),
+        ];
+
+        let mut column_content = vec![description];
+
+        for (label, style, msg) in buttons {
+            let label: Element<_> = text(label)
+                .into();
+
+            let label: Element<_> = container(label)
+                .width(Length::Fill)
+                .align_y(Alignment::Center)
+                .align_x(Alignment::Center)
+                .into();
+
+            let confirm_button: Element<_> = button(label)
+                .width(Length::Fill)
+                .class(style)
+                .on_press(msg)
+                .into();
+
+            column_content.push(confirm_button);
+        }
+
+        column(column_content)
+            .spacing(8.0)
+            .into()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -621,7 +1106,10 @@ pub enum PluginPreferenceUserDataState {
     ListOfEnums {
         value: Option<Vec<String>>,
         new_value: Option<SelectItem>
-    }
+    },
+    Directory {
+        value: Option<String>,
+    },
 }
 
 impl PluginPreferenceUserDataState {
@@ -643,6 +1131,7 @@ impl PluginPreferenceUserDataState {
                 value,
                 new_value: None
             },
+            PluginPreferenceUserData::Directory { value } => PluginPreferenceUserDataState::Directory { value },
         }
     }
 
@@ -655,6 +1144,24 @@ impl PluginPreferenceUserDataState {
             PluginPreferenceUserDataState::ListOfStrings { value, .. } => PluginPreferenceUserData::ListOfStrings { value },
             PluginPreferenceUserDataState::ListOfNumbers { value, .. } => PluginPreferenceUserData::ListOfNumbers { value },
             PluginPreferenceUserDataState::ListOfEnums { value, .. } => PluginPreferenceUserData::ListOfEnums { value },
+            PluginPreferenceUserDataState::Directory { value } => PluginPreferenceUserData::Directory { value },
+        }
+    }
+}
+
+impl ManagementAppPluginMsgIn {
+    fn changes_settings(&self) -> bool {
+        match self {
+            ManagementAppPluginMsgIn::PluginTableMsg(message) => {
+                matches!(message, PluginTableMsgIn::EnabledToggleItem(_) | PluginTableMsgIn::VisibleToggleItem { .. })
+            }
+            ManagementAppPluginMsgIn::PluginPreferenceMsg(_) => true,
+            ManagementAppPluginMsgIn::PluginShortcutsMsg(_) => true,
+            ManagementAppPluginMsgIn::PluginEntrypointsMsg(_) => true,
+            ManagementAppPluginMsgIn::RequestPluginRemoval { .. } => true,
+            ManagementAppPluginMsgIn::RemovePlugin { .. } => true,
+            ManagementAppPluginMsgIn::DownloadPlugin { .. } => true,
+            _ => false,
         }
     }
 }
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use iced::widget::text::Shaping;
+use iced::widget::{button, checkbox, column, container, row, text, text_input};
+use iced::{padding, widget, Alignment, Length, Padding};
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
+use gauntlet_common::i18n::tr;
+use gauntlet_common::model::{EntrypointId, PluginId, SettingsEntrypoint, SettingsEntrypointType};
+
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+
+#[derive(Debug, Clone)]
+pub enum PluginEntrypointsMsg {
+    SetEnabled {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        enabled: bool,
+    },
+    SetHidden {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        hidden: bool,
+    },
+    SetFavorite {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        favorite: bool,
+    },
+    SetAliases {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        aliases: Vec<String>,
+    },
+    NewAliasChanged {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        value: String,
+    },
+}
+
+pub fn entrypoint_type_label(entrypoint_type: &SettingsEntrypointType) -> String {
+    match entrypoint_type {
+        SettingsEntrypointType::Command => tr("settings-plugins-type-command"),
+        SettingsEntrypointType::View => tr("settings-plugins-type-view"),
+        SettingsEntrypointType::InlineView => tr("settings-plugins-type-inline-view"),
+        SettingsEntrypointType::CommandGenerator => tr("settings-plugins-type-command-generator")
+    }
+}
+
+// enabled, visibility and favorite toggles, followed by aliases editor
+pub fn entrypoint_settings_ui<'a>(
+    plugin_id: PluginId,
+    entrypoint: &SettingsEntrypoint,
+    new_aliases: &HashMap<(PluginId, EntrypointId), String>,
+) -> Element<'a, PluginEntrypointsMsg> {
+    let entrypoint_id = entrypoint.entrypoint_id.clone();
+
+    let enabled: Element<_> = checkbox(tr("settings-plugins-entrypoint-enabled"), entrypoint.enabled)
+        .on_toggle({
+            let plugin_id = plugin_id.clone();
+            let entrypoint_id = entrypoint_id.clone();
+
+            move |enabled| PluginEntrypointsMsg::SetEnabled {
+                plugin_id: plugin_id.clone(),
+                entrypoint_id: entrypoint_id.clone(),
+                enabled,
+            }
+        })
+        .into();
+
+    // inline views and command generators are not shown in search results as a single item
+    let on_visible_toggle = match entrypoint.entrypoint_type {
+        SettingsEntrypointType::Command | SettingsEntrypointType::View => {
+            let plugin_id = plugin_id.clone();
+            let entrypoint_id = entrypoint_id.clone();
+
+            Some(move |visible: bool| PluginEntrypointsMsg::SetHidden {
+                plugin_id: plugin_id.clone(),
+                entrypoint_id: entrypoint_id.clone(),
+                hidden: !visible,
+            })
+        }
+        SettingsEntrypointType::InlineView | SettingsEntrypointType::CommandGenerator => None
+    };
+
+    let visible: Element<_> = checkbox(tr("settings-plugins-entrypoint-visible"), !entrypoint.hidden)
+        .on_toggle_maybe(on_visible_toggle)
+        .into();
+
+    let on_favorite_toggle = match entrypoint.entrypoint_type {
+        SettingsEntrypointType::Command | SettingsEntrypointType::View => {
+            let plugin_id = plugin_id.clone();
+            let entrypoint_id = entrypoint_id.clone();
+
+            Some(move |favorite: bool| PluginEntrypointsMsg::SetFavorite {
+                plugin_id: plugin_id.clone(),
+                entrypoint_id: entrypoint_id.clone(),
+                favorite,
+            })
+        }
+        SettingsEntrypointType::InlineView | SettingsEntrypointType::CommandGenerator => None
+    };
+
+    let favorite: Element<_> = checkbox(tr("settings-plugins-entrypoint-favorite"), entrypoint.favorite)
+        .on_toggle_maybe(on_favorite_toggle)
+        .into();
+
+    let toggles: Element<_> = row(vec![enabled, visible, favorite])
+        .spacing(16.0)
+        .into();
+
+    let toggles = container(toggles)
+        .padding(Padding::from([4.0, 8.0]))
+        .into();
+
+    let mut column_content = vec![toggles];
+
+    if matches!(entrypoint.entrypoint_type, SettingsEntrypointType::Command | SettingsEntrypointType::View) {
+        column_content.push(aliases_ui(plugin_id, entrypoint_id, &entrypoint.aliases, new_aliases));
+    }
+
+    column(column_content)
+        .spacing(4)
+        .into()
+}
+
+fn aliases_ui<'a>(
+    plugin_id: PluginId,
+    entrypoint_id: EntrypointId,
+    aliases: &[String],
+    new_aliases: &HashMap<(PluginId, EntrypointId), String>,
+) -> Element<'a, PluginEntrypointsMsg> {
+    let label: Element<_> = text(tr("settings-plugins-aliases"))
+        .size(14)
+        .class(TextStyle::Subtitle)
+        .into();
+
+    let label = container(label)
+        .padding(padding::left(8.0))
+        .into();
+
+    let mut column_content = vec![label];
+
+    for (index, alias) in aliases.iter().enumerate() {
+        let mut remaining_aliases = aliases.to_vec();
+        remaining_aliases.remove(index);
+
+        let alias_text: Element<_> = text(alias.clone())
+            .shaping(Shaping::Advanced)
+            .width(Length::Fill)
+            .into();
+
+        let remove_icon = widget::value(Bootstrap::Dash)
+            .font(BOOTSTRAP_FONT);
+
+        let remove_button: Element<_> = button(remove_icon)
+            .class(ButtonStyle::Primary)
+            .on_press(PluginEntrypointsMsg::SetAliases {
+                plugin_id: plugin_id.clone(),
+                entrypoint_id: entrypoint_id.clone(),
+                aliases: remaining_aliases,
+            })
+            .padding(Padding::from([5.0, 7.0]))
+            .into();
+
+        let item: Element<_> = row(vec![alias_text, remove_button])
+            .align_y(Alignment::Center)
+            .into();
+
+        let item = container(item)
+            .padding(Padding::from([4.0, 8.0]))
+            .into();
+
+        column_content.push(item);
+    }
+
+    let new_alias = new_aliases.get(&(plugin_id.clone(), entrypoint_id.clone()))
+        .cloned()
+        .unwrap_or_default();
+
+    let add_msg = if new_alias.trim().is_empty() {
+        None
+    } else {
+        let mut aliases = aliases.to_vec();
+        aliases.push(new_alias.trim().to_owned());
+
+        Some(PluginEntrypointsMsg::SetAliases {
+            plugin_id: plugin_id.clone(),
+            entrypoint_id: entrypoint_id.clone(),
+            aliases,
+        })
+    };
+
+    let add_icon: Element<_> = widget::value(Bootstrap::Plus)
+        .font(BOOTSTRAP_FONT)
+        .into();
+
+    let add_button: Element<_> = button(add_icon)
+        .class(ButtonStyle::Primary)
+        .on_press_maybe(add_msg.clone())
+        .padding(Padding::from([5.0, 7.0]))
+        .into();
+
+    let add_text_input: Element<_> = text_input(&tr("settings-plugins-aliases-enter-value"), &new_alias)
+        .on_input(move |value| PluginEntrypointsMsg::NewAliasChanged {
+            plugin_id: plugin_id.clone(),
+            entrypoint_id: entrypoint_id.clone(),
+            value,
+        })
+        .on_submit_maybe(add_msg)
+        .into();
+
+    let add_item: Element<_> = row(vec![add_text_input, add_button])
+        .align_y(Alignment::Center)
+        .into();
+
+    let add_item = container(add_item)
+        .padding(Padding::from([4.0, 8.0]))
+        .into();
+
+    column_content.push(add_item);
+
+    column(column_content)
+        .spacing(4)
+        .into()
+}
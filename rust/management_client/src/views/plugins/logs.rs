@@ -0,0 +1,293 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use iced::{Alignment, Font, Length, Padding, Task};
+use iced::widget::{button, checkbox, column, container, pick_list, row, scrollable, text, text_input, value};
+use iced::widget::scrollable::RelativeOffset;
+use iced::widget::text::Shaping;
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
+use gauntlet_common::i18n::{tr, tr_with};
+use gauntlet_common::model::{PluginId, PluginLogLine, PluginLogStream, PluginLogs, SettingsPlugin};
+
+use crate::theme::button::ButtonStyle;
+use crate::theme::Element;
+use crate::theme::text::TextStyle;
+
+// older lines are dropped, full logs are still available in the files
+const MAX_LINES: usize = 5000;
+
+#[derive(Debug, Clone)]
+pub enum PluginLogsMsg {
+    Poll,
+    LogsFetched {
+        plugin_id: PluginId,
+        logs: PluginLogs,
+    },
+    SetFilter(PluginLogFilter),
+    SetSearch(String),
+    ToggleFollow(bool),
+    CopyDiagnostics,
+    Close,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginLogFilter {
+    All,
+    Output,
+    Errors,
+}
+
+impl PluginLogFilter {
+    const ALL: [PluginLogFilter; 3] = [PluginLogFilter::All, PluginLogFilter::Output, PluginLogFilter::Errors];
+
+    fn matches(&self, stream: PluginLogStream) -> bool {
+        match self {
+            PluginLogFilter::All => true,
+            PluginLogFilter::Output => stream == PluginLogStream::Stdout,
+            PluginLogFilter::Errors => stream == PluginLogStream::Stderr,
+        }
+    }
+}
+
+impl Display for PluginLogFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluginLogFilter::All => write!(f, "{}", tr("settings-logs-filter-all")),
+            PluginLogFilter::Output => write!(f, "{}", tr("settings-logs-filter-output")),
+            PluginLogFilter::Errors => write!(f, "{}", tr("settings-logs-filter-errors")),
+        }
+    }
+}
+
+pub struct PluginLogsState {
+    plugin_id: PluginId,
+    lines: VecDeque<PluginLogLine>,
+    stdout_offset: u64,
+    stderr_offset: u64,
+    filter: PluginLogFilter,
+    search: String,
+    follow: bool,
+    fetch_in_progress: bool,
+    scrollable_id: scrollable::Id,
+}
+
+impl PluginLogsState {
+    pub fn new(plugin_id: PluginId) -> Self {
+        Self {
+            plugin_id,
+            lines: VecDeque::new(),
+            stdout_offset: 0,
+            stderr_offset: 0,
+            filter: PluginLogFilter::All,
+            search: "".to_string(),
+            follow: true,
+            fetch_in_progress: false,
+            scrollable_id: scrollable::Id::unique(),
+        }
+    }
+
+    pub fn plugin_id(&self) -> &PluginId {
+        &self.plugin_id
+    }
+
+    pub fn follow(&self) -> bool {
+        self.follow
+    }
+
+    // returns offsets to request next increment from, or None if request is already in flight
+    pub fn start_fetch(&mut self) -> Option<(u64, u64)> {
+        if self.fetch_in_progress {
+            return None
+        }
+
+        self.fetch_in_progress = true;
+
+        Some((self.stdout_offset, self.stderr_offset))
+    }
+
+    pub fn apply_logs<T>(&mut self, plugin_id: PluginId, logs: PluginLogs) -> Task<T> {
+        if plugin_id != self.plugin_id {
+            return Task::none()
+        }
+
+        self.fetch_in_progress = false;
+
+        if logs.reset {
+            self.lines.clear();
+        }
+
+        let has_new_lines = !logs.lines.is_empty();
+
+        self.lines.extend(logs.lines);
+        self.stdout_offset = logs.stdout_offset;
+        self.stderr_offset = logs.stderr_offset;
+
+        if self.lines.len() > MAX_LINES {
+            let excess = self.lines.len() - MAX_LINES;
+            self.lines.drain(..excess);
+        }
+
+        if self.follow && has_new_lines {
+            scrollable::snap_to(self.scrollable_id.clone(), RelativeOffset::END)
+        } else {
+            Task::none()
+        }
+    }
+
+    pub fn set_filter(&mut self, filter: PluginLogFilter) {
+        self.filter = filter;
+    }
+
+    pub fn set_search(&mut self, search: String) {
+        self.search = search;
+    }
+
+    pub fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+    }
+
+    fn visible_lines(&self) -> impl Iterator<Item=&PluginLogLine> {
+        let search = self.search.to_lowercase();
+
+        self.lines
+            .iter()
+            .filter(move |line| self.filter.matches(line.stream))
+            .filter(move |line| search.is_empty() || line.text.to_lowercase().contains(&search))
+    }
+
+    pub fn diagnostics(&self, plugin: Option<&SettingsPlugin>) -> String {
+        let mut result = vec![];
+
+        result.push(format!("Plugin id: {}", self.plugin_id.to_string()));
+
+        if let Some(plugin) = plugin {
+            result.push(format!("Plugin name: {}", plugin.plugin_name));
+            result.push(format!("Plugin enabled: {}", plugin.enabled));
+
+            let mut entrypoints: Vec<_> = plugin.entrypoints
+                .values()
+                .map(|entrypoint| format!("  {} ({:?}, enabled: {})", entrypoint.entrypoint_id.to_string(), entrypoint.entrypoint_type, entrypoint.enabled))
+                .collect();
+
+            entrypoints.sort();
+
+            result.push("Entrypoints:".to_string());
+            result.extend(entrypoints);
+        }
+
+        result.push(format!("Operating system: {} {}", std::env::consts::OS, std::env::consts::ARCH));
+        result.push(format!("Log filter: {}", self.filter));
+
+        if !self.search.is_empty() {
+            result.push(format!("Log search: {}", self.search));
+        }
+
+        result.push("Logs:".to_string());
+
+        for line in self.visible_lines() {
+            result.push(format!("[{}] {}", stream_label(line.stream), line.text));
+        }
+
+        result.join("\n")
+    }
+
+    pub fn view(&self, plugin: Option<&SettingsPlugin>) -> Element<PluginLogsMsg> {
+        let back_button: Element<_> = button(value(Bootstrap::ArrowLeft).font(BOOTSTRAP_FONT))
+            .class(ButtonStyle::Primary)
+            .on_press(PluginLogsMsg::Close)
+            .into();
+
+        let plugin_name = plugin
+            .map(|plugin| plugin.plugin_name.to_string())
+            .unwrap_or_else(|| self.plugin_id.to_string());
+
+        let title: Element<_> = text(tr_with("settings-logs-title", &[("plugin", plugin_name.as_str().into())]))
+            .shaping(Shaping::Advanced)
+            .width(Length::Fill)
+            .into();
+
+        let filter: Element<_> = pick_list(
+            PluginLogFilter::ALL,
+            Some(self.filter),
+            PluginLogsMsg::SetFilter
+        )
+            .into();
+
+        let search: Element<_> = text_input(&tr("search-placeholder"), &self.search)
+            .on_input(PluginLogsMsg::SetSearch)
+            .width(Length::Fixed(200.0))
+            .into();
+
+        let follow: Element<_> = checkbox(tr("settings-logs-follow"), self.follow)
+            .on_toggle(PluginLogsMsg::ToggleFollow)
+            .into();
+
+        let copy_button: Element<_> = button(text(tr("settings-logs-copy-diagnostics")))
+            .class(ButtonStyle::Primary)
+            .on_press(PluginLogsMsg::CopyDiagnostics)
+            .into();
+
+        let header: Element<_> = row(vec![back_button, title, filter, search, follow, copy_button])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let lines: Vec<Element<_>> = self.visible_lines()
+            .map(|line| {
+                let class = match line.stream {
+                    PluginLogStream::Stdout => TextStyle::Default,
+                    PluginLogStream::Stderr => TextStyle::Destructive,
+                };
+
+                text(line.text.to_string())
+                    .font(Font::MONOSPACE)
+                    .size(13)
+                    .shaping(Shaping::Advanced)
+                    .class(class)
+                    .into()
+            })
+            .collect();
+
+        let content: Element<_> = if lines.is_empty() {
+            let empty_text: Element<_> = text(tr("settings-logs-empty"))
+                .class(TextStyle::Subtitle)
+                .into();
+
+            container(empty_text)
+                .align_y(Alignment::Center)
+                .align_x(Alignment::Center)
+                .height(Length::Fill)
+                .width(Length::Fill)
+                .into()
+        } else {
+            let lines: Element<_> = column(lines)
+                .spacing(2.0)
+                .padding(Padding::new(8.0))
+                .width(Length::Fill)
+                .into();
+
+            scrollable(lines)
+                .id(self.scrollable_id.clone())
+                .height(Length::Fill)
+                .width(Length::Fill)
+                .into()
+        };
+
+        let content: Element<_> = column(vec![header, content])
+            .spacing(8.0)
+            .into();
+
+        container(content)
+            .padding(Padding::new(8.0))
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .into()
+    }
+}
+
+fn stream_label(stream: PluginLogStream) -> &'static str {
+    match stream {
+        PluginLogStream::Stdout => "stdout",
+        PluginLogStream::Stderr => "stderr",
+    }
+}
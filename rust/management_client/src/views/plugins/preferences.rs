@@ -3,9 +3,11 @@ use crate::theme::container::ContainerStyle;
 use crate::theme::text::TextStyle;
 use crate::theme::Element;
 use crate::views::plugins::PluginPreferenceUserDataState;
+use gauntlet_common::i18n::tr;
 use gauntlet_common::model::{EntrypointId, PluginId, PluginPreference};
-use iced::widget::{button, checkbox, column, container, pick_list, row, text, text_input};
-use iced::{padding, widget, Length, Padding};
+use gauntlet_common_ui::format_slider_value;
+use iced::widget::{button, checkbox, column, container, pick_list, row, slider, text, text_input};
+use iced::{padding, widget, Alignment, Length, Padding};
 use iced_aw::number_input;
 use std::collections::HashMap;
 use std::fmt::Display;
@@ -38,7 +40,8 @@ pub fn preferences_ui<'a>(
     plugin_id: PluginId,
     entrypoint_id: Option<EntrypointId>,
     preferences: &HashMap<String, PluginPreference>,
-    preference_user_data: &HashMap<(PluginId, Option<EntrypointId>, String), PluginPreferenceUserDataState>
+    preference_user_data: &HashMap<(PluginId, Option<EntrypointId>, String), PluginPreferenceUserDataState>,
+    preference_errors: &HashMap<(PluginId, Option<EntrypointId>, String), String>,
 ) -> Element<'a, PluginPreferencesMsg> {
     let mut column_content = vec![];
 
@@ -53,6 +56,7 @@ pub fn preferences_ui<'a>(
         let entrypoint_id = entrypoint_id.clone();
 
         let user_data = preference_user_data.get(&(plugin_id.clone(), entrypoint_id.clone(), preference_id.clone()));
+        let error = preference_errors.get(&(plugin_id.clone(), entrypoint_id.clone(), preference_id.clone()));
 
         let (preference_name, description) = match preference {
             PluginPreference::Number { name, description, .. } => (name, description),
@@ -62,6 +66,7 @@ pub fn preferences_ui<'a>(
             PluginPreference::ListOfStrings { name, description, .. } => (name, description),
             PluginPreference::ListOfNumbers { name, description, .. } => (name, description),
             PluginPreference::ListOfEnums { name, description, .. } => (name, description),
+            PluginPreference::Directory { name, description, .. } => (name, description),
         };
 
         let preference_id = preference_id.to_owned();
@@ -94,7 +99,7 @@ pub fn preferences_ui<'a>(
         }
 
         let input_field: Element<_> = match preference {
-            PluginPreference::Number { default, .. } => {
+            PluginPreference::Number { default, min, max, step, .. } => {
                 let value = match user_data {
                     None => None,
                     Some(PluginPreferenceUserDataState::Number { value }) => value.to_owned(),
@@ -103,11 +108,33 @@ pub fn preferences_ui<'a>(
 
                 let missing = value.as_ref().or(default.as_ref()).is_none();
 
-                let value = value.or(default.to_owned()).unwrap_or_default();
+                let input_field: Element<_> = match (min, max, step) {
+                    (Some(min), Some(max), Some(step)) => {
+                        let value = value.or(default.to_owned()).unwrap_or(*min);
 
-                let input_field: Element<_> = number_input(value, f64::MIN..f64::MAX, std::convert::identity)
-                    .width(Length::Fill)
-                    .into();
+                        let slider: Element<_> = slider(*min..=*max, value, std::convert::identity)
+                            .step(*step)
+                            .width(Length::Fill)
+                            .into();
+
+                        let value_label: Element<_> = text(format_slider_value(value, *step))
+                            .into();
+
+                        row(vec![slider, value_label])
+                            .spacing(8)
+                            .align_y(Alignment::Center)
+                            .into()
+                    }
+                    _ => {
+                        let value = value.or(default.to_owned()).unwrap_or_default();
+
+                        let bounds = min.unwrap_or(f64::MIN)..max.unwrap_or(f64::MAX);
+
+                        number_input(value, bounds, std::convert::identity)
+                            .width(Length::Fill)
+                            .into()
+                    }
+                };
 
                 let input_field = input_field.map(Box::new(move |value| {
                     PluginPreferencesMsg::UpdatePreferenceValue {
@@ -324,7 +351,7 @@ pub fn preferences_ui<'a>(
                     .padding(padding::bottom(8.0))
                     .into();
 
-                let add_text_input: Element<_> = text_input("Enter value...", &new_value)
+                let add_text_input: Element<_> = text_input(&tr("settings-preferences-enter-value"), &new_value)
                     .on_input(move |new_value| PluginPreferencesMsg::UpdatePreferenceValue {
                         plugin_id: plugin_id.clone(),
                         entrypoint_id: entrypoint_id.clone(),
@@ -593,7 +620,7 @@ pub fn preferences_ui<'a>(
                         },
                     }),
                 )
-                    .placeholder("Select value...")
+                    .placeholder(tr("settings-preferences-select-value"))
                     .width(Length::Fill)
                     .into();
 
@@ -616,10 +643,53 @@ pub fn preferences_ui<'a>(
 
                 content
             }
+            PluginPreference::Directory { default, .. } => {
+                let value = match user_data {
+                    None => None,
+                    Some(PluginPreferenceUserDataState::Directory { value }) => value.to_owned(),
+                    Some(_) => unreachable!()
+                };
+
+                let missing = value.as_ref().or(default.as_ref()).is_none();
+
+                let placeholder = default.to_owned().unwrap_or_else(|| "Enter absolute path to directory...".to_owned());
+
+                let input_field: Element<_> = text_input(&placeholder, &value.unwrap_or_default())
+                    .on_input(Box::new(move |value| {
+                        PluginPreferencesMsg::UpdatePreferenceValue {
+                            plugin_id: plugin_id.clone(),
+                            entrypoint_id: entrypoint_id.clone(),
+                            id: preference_id.to_owned(),
+                            user_data: PluginPreferenceUserDataState::Directory {
+                                value: Some(value).filter(|value| !value.is_empty()),
+                            },
+                        }
+                    }))
+                    .into();
+
+                let input_field = container(input_field)
+                    .padding(Padding::new(8.0))
+                    .class(if missing { ContainerStyle::TextInputMissingValue } else { ContainerStyle::Transparent  })
+                    .into();
+
+                input_field
+            }
         };
 
         input_field_column.push(input_field);
 
+        if let Some(error) = error {
+            let error = text(error.to_owned())
+                .shaping(Shaping::Advanced)
+                .class(TextStyle::Destructive);
+
+            let error = container(error)
+                .padding(Padding::from([0.0, 8.0]))
+                .into();
+
+            input_field_column.push(error);
+        }
+
         let content: Element<_> = column(input_field_column)
             .into();
 
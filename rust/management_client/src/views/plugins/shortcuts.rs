@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use iced::widget::text::Shaping;
+use iced::widget::{button, column, container, row, text};
+use iced::{padding, Alignment, Length, Padding};
+use gauntlet_common::i18n::tr;
+use gauntlet_common::model::{EntrypointId, PhysicalShortcut, PluginId, SettingsEntrypointAction};
+
+use crate::components::shortcut_selector::ShortcutSelector;
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+
+#[derive(Debug, Clone)]
+pub enum PluginShortcutsMsg {
+    // none resets shortcut to the one declared by plugin
+    SetShortcut {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        action_id: String,
+        shortcut: Option<PhysicalShortcut>,
+    },
+    CapturingChanged {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        action_id: String,
+        capturing: bool,
+    },
+}
+
+pub fn shortcuts_ui<'a>(
+    plugin_id: PluginId,
+    entrypoint_id: EntrypointId,
+    actions: &[SettingsEntrypointAction],
+    currently_capturing: &Option<(PluginId, EntrypointId, String)>,
+    shortcut_errors: &HashMap<(PluginId, EntrypointId, String), String>,
+) -> Element<'a, PluginShortcutsMsg> {
+    if actions.is_empty() {
+        return column(vec![]).into()
+    }
+
+    let label: Element<_> = text(tr("settings-plugins-shortcuts"))
+        .size(14)
+        .class(TextStyle::Subtitle)
+        .into();
+
+    let label = container(label)
+        .padding(padding::left(8.0))
+        .into();
+
+    let mut column_content = vec![label];
+
+    for action in actions {
+        let key = (plugin_id.clone(), entrypoint_id.clone(), action.action_id.clone());
+
+        let action_label: Element<_> = text(action.description.clone())
+            .shaping(Shaping::Advanced)
+            .width(Length::Fill)
+            .into();
+
+        let shortcut_selector: Element<_> = ShortcutSelector::new(
+            &Some(action.shortcut.clone()),
+            {
+                let (plugin_id, entrypoint_id, action_id) = key.clone();
+
+                move |shortcut| PluginShortcutsMsg::SetShortcut {
+                    plugin_id: plugin_id.clone(),
+                    entrypoint_id: entrypoint_id.clone(),
+                    action_id: action_id.clone(),
+                    shortcut,
+                }
+            },
+            {
+                let (plugin_id, entrypoint_id, action_id) = key.clone();
+
+                move |capturing| PluginShortcutsMsg::CapturingChanged {
+                    plugin_id: plugin_id.clone(),
+                    entrypoint_id: entrypoint_id.clone(),
+                    action_id: action_id.clone(),
+                    capturing,
+                }
+            },
+        ).into();
+
+        let shortcut_selector: Element<_> = container(shortcut_selector)
+            .width(Length::Fill)
+            .height(Length::Fixed(35.0))
+            .into();
+
+        let mut action_row = vec![action_label, shortcut_selector];
+
+        if action.shortcut != action.default_shortcut {
+            let reset_button: Element<_> = button(text(tr("settings-shortcut-reset")))
+                .class(ButtonStyle::Primary)
+                .on_press(PluginShortcutsMsg::SetShortcut {
+                    plugin_id: plugin_id.clone(),
+                    entrypoint_id: entrypoint_id.clone(),
+                    action_id: action.action_id.clone(),
+                    shortcut: None,
+                })
+                .into();
+
+            action_row.push(reset_button);
+        }
+
+        let action_row: Element<_> = row(action_row)
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let action_row = container(action_row)
+            .padding(Padding::from([4.0, 8.0]))
+            .into();
+
+        column_content.push(action_row);
+
+        if currently_capturing.as_ref() == Some(&key) {
+            let hint1: Element<_> = text(tr("settings-shortcut-hint-reset"))
+                .class(TextStyle::Subtitle)
+                .into();
+
+            let hint2: Element<_> = text(tr("settings-shortcut-hint-stop"))
+                .class(TextStyle::Subtitle)
+                .into();
+
+            let hints = column(vec![hint1, hint2])
+                .padding(Padding::from([0.0, 8.0]))
+                .into();
+
+            column_content.push(hints);
+        } else if let Some(error) = shortcut_errors.get(&key) {
+            let error = text(error.to_owned())
+                .shaping(Shaping::Advanced)
+                .class(TextStyle::Destructive);
+
+            let error = container(error)
+                .padding(Padding::from([0.0, 8.0]))
+                .into();
+
+            column_content.push(error);
+        }
+    }
+
+    let element: Element<_> = column(column_content)
+        .spacing(4)
+        .into();
+
+    element
+}
@@ -8,17 +8,24 @@ use iced::widget::scrollable::Id;
 use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
 use iced_table::table;
 
+use gauntlet_common::i18n::tr;
 use gauntlet_common::model::{EntrypointId, PluginId, SettingsEntrypointType, SettingsPlugin};
 
 use crate::theme::{Element, GauntletSettingsTheme};
 use crate::theme::button::ButtonStyle;
 use crate::views::plugins::{PluginDataContainer, SelectedItem, SettingsPluginData};
+use crate::views::plugins::entrypoints::entrypoint_type_label;
 
 #[derive(Debug, Clone)]
 pub enum PluginTableMsgIn {
     TableSyncHeader(scrollable::AbsoluteOffset),
     SelectItem(SelectedItem),
     EnabledToggleItem(EnabledItem),
+    VisibleToggleItem {
+        visible: bool,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
     ToggleShowEntrypoints {
         plugin_id: PluginId,
     },
@@ -34,6 +41,11 @@ pub enum PluginTableMsgOut {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
     },
+    SetEntrypointHidden {
+        hidden: bool,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
     SelectItem(SelectedItem),
     ToggleShowEntrypoints {
         plugin_id: PluginId,
@@ -60,6 +72,7 @@ impl PluginTableState {
                 Column::new(ColumnKind::Name),
                 Column::new(ColumnKind::Type),
                 Column::new(ColumnKind::EnableToggle),
+                Column::new(ColumnKind::VisibleToggle),
             ],
             rows: vec![],
             header: Id::unique(),
@@ -88,6 +101,11 @@ impl PluginTableState {
                     }
                 }
             }
+            PluginTableMsgIn::VisibleToggleItem { visible, plugin_id, entrypoint_id } => {
+                PluginTableUpdateResult::Value(
+                    PluginTableMsgOut::SetEntrypointHidden { hidden: !visible, plugin_id, entrypoint_id }
+                )
+            }
             PluginTableMsgIn::SelectItem(item) => {
                 PluginTableUpdateResult::Value(
                     PluginTableMsgOut::SelectItem(item)
@@ -177,6 +195,7 @@ enum ColumnKind {
     Name,
     Type,
     EnableToggle,
+    VisibleToggle,
 }
 
 struct Column {
@@ -201,19 +220,25 @@ impl<'a> table::Column<'a, PluginTableMsgIn, GauntletSettingsTheme, Renderer> fo
                     .into()
             }
             ColumnKind::Name => {
-                container(text("Name"))
+                container(text(tr("settings-plugins-column-name")))
                     .height(Length::Fixed(30.0))
                     .align_y(Alignment::Center)
                     .into()
             }
             ColumnKind::Type => {
-                container(text("Type"))
+                container(text(tr("settings-plugins-column-type")))
                     .height(Length::Fixed(30.0))
                     .align_y(Alignment::Center)
                     .into()
             }
             ColumnKind::EnableToggle => {
-                container(text("Enabled"))
+                container(text(tr("settings-plugins-column-enabled")))
+                    .height(Length::Fixed(30.0))
+                    .align_y(Alignment::Center)
+                    .into()
+            }
+            ColumnKind::VisibleToggle => {
+                container(text(tr("settings-plugins-column-in-search")))
                     .height(Length::Fixed(30.0))
                     .align_y(Alignment::Center)
                     .into()
@@ -327,14 +352,7 @@ impl<'a> table::Column<'a, PluginTableMsgIn, GauntletSettingsTheme, Renderer> fo
                         let plugin = plugin_data.plugins.get(&plugin_id).unwrap();
                         let entrypoint = plugin.entrypoints.get(&entrypoint_id).unwrap();
 
-                        let entrypoint_type = match entrypoint.entrypoint_type {
-                            SettingsEntrypointType::Command => "Command",
-                            SettingsEntrypointType::View => "View",
-                            SettingsEntrypointType::InlineView => "Inline View",
-                            SettingsEntrypointType::CommandGenerator => "Command Generator"
-                        };
-
-                        container(text(entrypoint_type.to_string()))
+                        container(text(entrypoint_type_label(&entrypoint.entrypoint_type)))
                             .align_y(Alignment::Center)
                             .into()
                     }
@@ -426,6 +444,52 @@ impl<'a> table::Column<'a, PluginTableMsgIn, GauntletSettingsTheme, Renderer> fo
                     .align_x(Alignment::Center)
                     .into()
             }
+            ColumnKind::VisibleToggle => {
+                match row_entry {
+                    Row::Plugin { .. } => {
+                        horizontal_space()
+                            .into()
+                    }
+                    Row::Entrypoint { plugin_data, plugin_id, entrypoint_id } => {
+                        let plugin_data = plugin_data.borrow();
+                        let plugin = plugin_data.plugins.get(&plugin_id).unwrap();
+                        let entrypoint = plugin.entrypoints.get(&entrypoint_id).unwrap();
+
+                        // inline views are never shown as search results
+                        let content: Element<_> = match entrypoint.entrypoint_type {
+                            SettingsEntrypointType::InlineView => {
+                                horizontal_space()
+                                    .into()
+                            }
+                            _ => {
+                                let on_toggle = if plugin.enabled && entrypoint.enabled {
+                                    let plugin_id = plugin_id.clone();
+                                    let entrypoint_id = entrypoint_id.clone();
+
+                                    Some(move |visible| PluginTableMsgIn::VisibleToggleItem {
+                                        visible,
+                                        plugin_id: plugin_id.clone(),
+                                        entrypoint_id: entrypoint_id.clone(),
+                                    })
+                                } else {
+                                    None
+                                };
+
+                                checkbox("", !entrypoint.hidden)
+                                    .on_toggle_maybe(on_toggle)
+                                    .into()
+                            }
+                        };
+
+                        container(content)
+                            .width(Length::Fill)
+                            .height(Length::Fixed(40.0))
+                            .align_y(Alignment::Center)
+                            .align_x(Alignment::Center)
+                            .into()
+                    }
+                }
+            }
         }
     }
 
@@ -434,7 +498,8 @@ impl<'a> table::Column<'a, PluginTableMsgIn, GauntletSettingsTheme, Renderer> fo
             ColumnKind::ShowEntrypointsToggle => 35.0,
             ColumnKind::Name => 350.0,
             ColumnKind::Type => 200.0,
-            ColumnKind::EnableToggle => 75.0
+            ColumnKind::EnableToggle => 75.0,
+            ColumnKind::VisibleToggle => 85.0,
         }
     }
 
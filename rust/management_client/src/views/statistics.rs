@@ -0,0 +1,344 @@
+use crate::theme::button::ButtonStyle;
+use crate::theme::container::ContainerStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::i18n::{tr, tr_with};
+use gauntlet_common::model::{UsageDayStatistics, UsageStatistics};
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use iced::alignment::Horizontal;
+use iced::widget::text::Shaping;
+use iced::widget::{button, column, container, horizontal_rule, horizontal_space, row, scrollable, text, text_input, vertical_space};
+use iced::{Alignment, Length, Padding, Task};
+
+const HISTOGRAM_HEIGHT: f32 = 120.0;
+
+// everything shown here is computed from local database, nothing is sent anywhere
+pub struct ManagementAppStatisticsState {
+    backend_api: Option<BackendApi>,
+    statistics: Option<UsageStatistics>,
+    export_path: String,
+    export_status: Option<Result<String, String>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppStatisticsMsgIn {
+    RequestStatisticsReload,
+    StatisticsReloaded(UsageStatistics),
+    ExportPathChanged(String),
+    Export,
+    ExportFinished(Result<(), String>),
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppStatisticsMsgOut {
+    StatisticsReloaded(UsageStatistics),
+    ExportFinished(Result<(), String>),
+    HandleBackendError(BackendApiError)
+}
+
+impl ManagementAppStatisticsState {
+    pub fn new(backend_api: Option<BackendApi>) -> Self {
+        let export_path = Dirs::new()
+            .home_dir()
+            .join("gauntlet-usage.csv")
+            .to_string_lossy()
+            .to_string();
+
+        Self {
+            backend_api,
+            statistics: None,
+            export_path,
+            export_status: None,
+        }
+    }
+
+    pub fn update(&mut self, message: ManagementAppStatisticsMsgIn) -> Task<ManagementAppStatisticsMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => {
+                return Task::none()
+            }
+        };
+
+        match message {
+            ManagementAppStatisticsMsgIn::RequestStatisticsReload => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.get_usage_statistics()
+                        .await
+                }, |result| {
+                    match result {
+                        Ok(statistics) => ManagementAppStatisticsMsgOut::StatisticsReloaded(statistics),
+                        Err(err) => ManagementAppStatisticsMsgOut::HandleBackendError(err)
+                    }
+                })
+            }
+            ManagementAppStatisticsMsgIn::StatisticsReloaded(statistics) => {
+                self.statistics = Some(statistics);
+
+                Task::none()
+            }
+            ManagementAppStatisticsMsgIn::ExportPathChanged(path) => {
+                self.export_path = path;
+                self.export_status = None;
+
+                Task::none()
+            }
+            ManagementAppStatisticsMsgIn::Export => {
+                let path = self.export_path.clone();
+
+                self.export_status = None;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.export_usage_statistics(path)
+                        .await
+                }, |result| {
+                    // errors like unwritable path are shown in the view instead of replacing the whole view
+                    match result {
+                        Ok(()) => ManagementAppStatisticsMsgOut::ExportFinished(Ok(())),
+                        Err(BackendApiError::Internal { error }) => ManagementAppStatisticsMsgOut::ExportFinished(Err(format!("{:#}", error))),
+                        Err(err) => ManagementAppStatisticsMsgOut::HandleBackendError(err)
+                    }
+                })
+            }
+            ManagementAppStatisticsMsgIn::ExportFinished(result) => {
+                self.export_status = Some(result.map(|()| tr_with("settings-statistics-export-finished", &[("path", self.export_path.clone().into())])));
+
+                Task::none()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<ManagementAppStatisticsMsgIn> {
+        let Some(statistics) = &self.statistics else {
+            return container(text(tr("settings-statistics-loading")).class(TextStyle::Subtitle))
+                .padding(Padding::new(12.0))
+                .into()
+        };
+
+        let mut content = vec![];
+
+        if !statistics.enabled {
+            let hint: Element<_> = text(tr("settings-statistics-disabled"))
+                .shaping(Shaping::Advanced)
+                .class(TextStyle::Subtitle)
+                .into();
+
+            content.push(hint);
+        }
+
+        content.push(self.view_export());
+
+        let total_launches: u64 = statistics.daily.iter()
+            .map(|day| day.launches)
+            .sum();
+
+        let average_latency = match statistics.average_search_latency_micros {
+            None => "-".to_string(),
+            Some(micros) => format!("{:.2}ms", micros as f64 / 1000.0),
+        };
+
+        content.push(view_summary(vec![
+            (tr("settings-statistics-launches-last-days"), total_launches.to_string()),
+            (tr("settings-statistics-searches"), statistics.search_count.to_string()),
+            (tr("settings-statistics-average-search-latency"), average_latency),
+        ]));
+
+        content.push(view_histogram(&statistics.daily));
+
+        if statistics.entrypoints.is_empty() {
+            let empty: Element<_> = text(tr("settings-statistics-empty"))
+                .class(TextStyle::Subtitle)
+                .into();
+
+            content.push(empty);
+        } else {
+            let rows = statistics.plugins.iter()
+                .map(|plugin| (plugin.plugin_name.clone(), None, plugin.launches))
+                .collect();
+
+            content.push(view_table(tr("settings-statistics-plugins"), rows));
+
+            let rows = statistics.entrypoints.iter()
+                .map(|entrypoint| (entrypoint.entrypoint_name.clone(), Some(entrypoint.plugin_name.clone()), entrypoint.launches))
+                .collect();
+
+            content.push(view_table(tr("settings-statistics-entrypoints"), rows));
+        }
+
+        let content: Element<_> = column(content)
+            .spacing(16.0)
+            .padding(Padding::new(12.0))
+            .into();
+
+        scrollable(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn view_export(&self) -> Element<ManagementAppStatisticsMsgIn> {
+        let path: Element<_> = text_input(&tr("settings-statistics-export-path"), &self.export_path)
+            .on_input(ManagementAppStatisticsMsgIn::ExportPathChanged)
+            .into();
+
+        let export_button: Element<_> = button(text(tr("settings-statistics-export")))
+            .class(ButtonStyle::Primary)
+            .on_press_maybe((!self.export_path.is_empty()).then_some(ManagementAppStatisticsMsgIn::Export))
+            .into();
+
+        let refresh_button: Element<_> = button(text(tr("settings-statistics-refresh")))
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppStatisticsMsgIn::RequestStatisticsReload)
+            .into();
+
+        let header: Element<_> = row(vec![path, export_button, refresh_button])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let Some(status) = &self.export_status else {
+            return header
+        };
+
+        let status: Element<_> = match status {
+            Ok(message) => {
+                text(message)
+                    .shaping(Shaping::Advanced)
+                    .class(TextStyle::Positive)
+                    .into()
+            }
+            Err(message) => {
+                text(message)
+                    .shaping(Shaping::Advanced)
+                    .class(TextStyle::Destructive)
+                    .into()
+            }
+        };
+
+        column(vec![header, status])
+            .spacing(8.0)
+            .into()
+    }
+}
+
+fn view_summary<'a>(items: Vec<(String, String)>) -> Element<'a, ManagementAppStatisticsMsgIn> {
+    let items = items.into_iter()
+        .map(|(label, value)| {
+            let value: Element<_> = text(value)
+                .size(20)
+                .into();
+
+            let label: Element<_> = text(label)
+                .shaping(Shaping::Advanced)
+                .class(TextStyle::Subtitle)
+                .into();
+
+            column(vec![value, label])
+                .spacing(4.0)
+                .width(Length::Fill)
+                .into()
+        })
+        .collect::<Vec<_>>();
+
+    row(items)
+        .spacing(16.0)
+        .into()
+}
+
+// one bar per day, height is relative to the busiest day
+fn view_histogram<'a>(days: &[UsageDayStatistics]) -> Element<'a, ManagementAppStatisticsMsgIn> {
+    let title: Element<_> = text(tr("settings-statistics-daily"))
+        .size(14)
+        .class(TextStyle::Subtitle)
+        .into();
+
+    let max_launches = days.iter()
+        .map(|day| day.launches)
+        .max()
+        .unwrap_or(0);
+
+    let bars = days.iter()
+        .map(|day| {
+            let height = (day.launches as f32 / max_launches.max(1) as f32 * HISTOGRAM_HEIGHT).max(1.0);
+
+            let bar: Element<_> = container(horizontal_space())
+                .width(Length::Fill)
+                .height(height)
+                .class(ContainerStyle::Bar)
+                .into();
+
+            column(vec![vertical_space().into(), bar])
+                .width(Length::Fill)
+                .height(HISTOGRAM_HEIGHT)
+                .into()
+        })
+        .collect::<Vec<Element<_>>>();
+
+    let bars: Element<_> = row(bars)
+        .spacing(2.0)
+        .into();
+
+    let first_day: Element<_> = text(days.first().map(|day| day.date.clone()).unwrap_or_default())
+        .size(12)
+        .class(TextStyle::Subtitle)
+        .into();
+
+    let last_day: Element<_> = text(days.last().map(|day| day.date.clone()).unwrap_or_default())
+        .size(12)
+        .class(TextStyle::Subtitle)
+        .into();
+
+    let max_label: Element<_> = text(tr_with("settings-statistics-daily-max", &[("count", max_launches.into())]))
+        .size(12)
+        .class(TextStyle::Subtitle)
+        .into();
+
+    let axis: Element<_> = row(vec![first_day, horizontal_space().into(), max_label, horizontal_space().into(), last_day])
+        .into();
+
+    column(vec![title, bars, axis])
+        .spacing(4.0)
+        .into()
+}
+
+fn view_table<'a>(title: String, rows: Vec<(String, Option<String>, u64)>) -> Element<'a, ManagementAppStatisticsMsgIn> {
+    let title: Element<_> = text(title)
+        .size(14)
+        .class(TextStyle::Subtitle)
+        .into();
+
+    let mut content = vec![title, horizontal_rule(1).into()];
+
+    for (name, plugin_name, launches) in rows {
+        let name: Element<_> = text(name)
+            .shaping(Shaping::Advanced)
+            .width(Length::FillPortion(3))
+            .into();
+
+        let plugin_name: Element<_> = text(plugin_name.unwrap_or_default())
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .width(Length::FillPortion(3))
+            .into();
+
+        let launches: Element<_> = text(launches.to_string())
+            .align_x(Horizontal::Right)
+            .width(Length::FillPortion(1))
+            .into();
+
+        let item: Element<_> = row(vec![name, plugin_name, launches])
+            .spacing(8.0)
+            .into();
+
+        content.push(container(item).width(Length::Fill).into());
+    }
+
+    column(content)
+        .spacing(4.0)
+        .into()
+}
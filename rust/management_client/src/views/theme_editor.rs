@@ -0,0 +1,674 @@
+use crate::theme::button::ButtonStyle;
+use crate::theme::container::ContainerStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::{Element, BACKGROUND_LIGHTER};
+use gauntlet_common::i18n::{tr, tr_with};
+use gauntlet_common::model::{SimpleThemeTokens, ThemeColorToken, ThemeTokenColor};
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use iced::widget::text::Shaping;
+use iced::widget::{button, column, container, horizontal_rule, horizontal_space, row, scrollable, slider, text, text_input, vertical_space};
+use iced::{Alignment, Border, Color, Length, Padding, Task};
+
+const PREVIEW_WIDTH: f32 = 420.0;
+const TRANSPARENT: ThemeTokenColor = ThemeTokenColor { r: 0, g: 0, b: 0, a: 0.0 };
+
+// theme is edited locally, launcher window shows edits as preview until theme is saved.
+// sliders update preview when released, every preview replaces the whole launcher theme
+pub struct ManagementAppThemeEditorState {
+    backend_api: Option<BackendApi>,
+    theme: Option<SimpleThemeTokens>,
+    selected_token: ThemeColorToken,
+    hex: String,
+    name: String,
+    // launcher shows edited theme instead of configured one
+    previewing: bool,
+    status: Option<Result<String, String>>,
+    managed: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppThemeEditorMsgIn {
+    RequestThemeReload,
+    ThemeReloaded(SimpleThemeTokens),
+    SelectToken(ThemeColorToken),
+    ColorChanged(ThemeTokenColor),
+    HexChanged(String),
+    RootBorderRadiusChanged(f32),
+    RootBorderWidthChanged(f32),
+    ContentBorderRadiusChanged(f32),
+    ApplyPreview,
+    EndPreview,
+    Reset,
+    NameChanged(String),
+    Save,
+    Saved(Result<String, String>),
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppThemeEditorMsgOut {
+    Noop,
+    ThemeReloaded(SimpleThemeTokens),
+    Saved(Result<String, String>),
+    HandleBackendError(BackendApiError)
+}
+
+impl ManagementAppThemeEditorState {
+    pub fn new(backend_api: Option<BackendApi>) -> Self {
+        Self {
+            backend_api,
+            theme: None,
+            selected_token: ThemeColorToken::BackgroundDarkest,
+            hex: "".to_string(),
+            name: "".to_string(),
+            previewing: false,
+            status: None,
+            managed: false,
+        }
+    }
+
+    pub fn set_managed(&mut self, managed: bool) {
+        self.managed = managed;
+    }
+
+    pub fn update(&mut self, message: ManagementAppThemeEditorMsgIn) -> Task<ManagementAppThemeEditorMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => {
+                return Task::none()
+            }
+        };
+
+        match message {
+            ManagementAppThemeEditorMsgIn::RequestThemeReload => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.get_simple_theme()
+                        .await
+                }, |result| {
+                    match result {
+                        Ok(theme) => ManagementAppThemeEditorMsgOut::ThemeReloaded(theme),
+                        Err(err) => ManagementAppThemeEditorMsgOut::HandleBackendError(err)
+                    }
+                })
+            }
+            ManagementAppThemeEditorMsgIn::ThemeReloaded(theme) => {
+                self.hex = format_hex(theme.color(self.selected_token));
+                self.theme = Some(theme);
+
+                Task::none()
+            }
+            ManagementAppThemeEditorMsgIn::SelectToken(token) => {
+                self.selected_token = token;
+
+                if let Some(theme) = &self.theme {
+                    self.hex = format_hex(theme.color(token));
+                }
+
+                Task::none()
+            }
+            ManagementAppThemeEditorMsgIn::ColorChanged(color) => {
+                if let Some(theme) = &mut self.theme {
+                    theme.set_color(self.selected_token, color);
+                }
+
+                self.hex = format_hex(color);
+
+                Task::none()
+            }
+            ManagementAppThemeEditorMsgIn::HexChanged(hex) => {
+                let parsed = parse_hex(&hex);
+
+                self.hex = hex;
+
+                match (&mut self.theme, parsed) {
+                    (Some(theme), Some((r, g, b))) => {
+                        let color = theme.color(self.selected_token);
+
+                        theme.set_color(self.selected_token, ThemeTokenColor { r, g, b, ..color });
+
+                        self.update(ManagementAppThemeEditorMsgIn::ApplyPreview)
+                    }
+                    _ => Task::none()
+                }
+            }
+            ManagementAppThemeEditorMsgIn::RootBorderRadiusChanged(value) => {
+                if let Some(theme) = &mut self.theme {
+                    theme.root_border_radius = value;
+                }
+
+                Task::none()
+            }
+            ManagementAppThemeEditorMsgIn::RootBorderWidthChanged(value) => {
+                if let Some(theme) = &mut self.theme {
+                    theme.root_border_width = value;
+                }
+
+                Task::none()
+            }
+            ManagementAppThemeEditorMsgIn::ContentBorderRadiusChanged(value) => {
+                if let Some(theme) = &mut self.theme {
+                    theme.content_border_radius = value;
+                }
+
+                Task::none()
+            }
+            ManagementAppThemeEditorMsgIn::ApplyPreview => {
+                let Some(theme) = self.theme.clone() else {
+                    return Task::none()
+                };
+
+                self.previewing = true;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.preview_simple_theme(Some(theme))
+                        .await
+                }, handle_preview_result)
+            }
+            ManagementAppThemeEditorMsgIn::EndPreview => {
+                if !self.previewing {
+                    return Task::none()
+                }
+
+                self.previewing = false;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.preview_simple_theme(None)
+                        .await
+                }, handle_preview_result)
+            }
+            ManagementAppThemeEditorMsgIn::Reset => {
+                self.status = None;
+
+                Task::batch([
+                    self.update(ManagementAppThemeEditorMsgIn::EndPreview),
+                    self.update(ManagementAppThemeEditorMsgIn::RequestThemeReload),
+                ])
+            }
+            ManagementAppThemeEditorMsgIn::NameChanged(name) => {
+                self.name = name;
+
+                Task::none()
+            }
+            ManagementAppThemeEditorMsgIn::Save => {
+                let Some(theme) = self.theme.clone() else {
+                    return Task::none()
+                };
+
+                let name = self.name.trim().to_string();
+
+                self.status = None;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.save_simple_theme(name, theme)
+                        .await
+                }, |result| {
+                    // errors like already existing name are shown in the view instead of replacing the whole view
+                    match result {
+                        Ok(path) => ManagementAppThemeEditorMsgOut::Saved(Ok(path)),
+                        Err(BackendApiError::Internal { error }) => ManagementAppThemeEditorMsgOut::Saved(Err(format!("{:#}", error))),
+                        Err(err) => ManagementAppThemeEditorMsgOut::HandleBackendError(err)
+                    }
+                })
+            }
+            ManagementAppThemeEditorMsgIn::Saved(result) => {
+                if result.is_ok() {
+                    // saved theme becomes current theme, launcher reloads it from file
+                    self.previewing = false;
+                    self.name = "".to_string();
+                }
+
+                self.status = Some(result.map(|path| tr_with("settings-theme-saved", &[("path", path.into())])));
+
+                Task::none()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<ManagementAppThemeEditorMsgIn> {
+        let Some(theme) = &self.theme else {
+            return container(text(tr("settings-theme-loading")).class(TextStyle::Subtitle))
+                .padding(Padding::new(12.0))
+                .into()
+        };
+
+        let description: Element<_> = text(tr("settings-theme-description"))
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let mut content = vec![description];
+
+        if let Some(status) = &self.status {
+            let status: Element<_> = match status {
+                Ok(message) => {
+                    text(message)
+                        .shaping(Shaping::Advanced)
+                        .class(TextStyle::Positive)
+                        .into()
+                }
+                Err(message) => {
+                    text(message)
+                        .shaping(Shaping::Advanced)
+                        .class(TextStyle::Destructive)
+                        .into()
+                }
+            };
+
+            content.push(status);
+        }
+
+        let editor: Element<_> = column(vec![self.view_color_editor(theme), view_sizes(theme)])
+            .spacing(16.0)
+            .width(Length::Fill)
+            .into();
+
+        let tokens: Element<_> = row(vec![self.view_tokens(theme), editor])
+            .spacing(16.0)
+            .into();
+
+        content.push(tokens);
+        content.push(horizontal_rule(1).into());
+        content.push(view_preview(theme));
+        content.push(self.view_save());
+
+        let content: Element<_> = column(content)
+            .spacing(16.0)
+            .padding(Padding::new(12.0))
+            .into();
+
+        scrollable(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn view_tokens(&self, theme: &SimpleThemeTokens) -> Element<ManagementAppThemeEditorMsgIn> {
+        let tokens = ThemeColorToken::ALL
+            .into_iter()
+            .map(|token| {
+                let swatch: Element<_> = container(horizontal_space())
+                    .width(16)
+                    .height(16)
+                    .class(ContainerStyle::ThemePreview {
+                        background: to_iced(theme.color(token)),
+                        border: Border {
+                            color: BACKGROUND_LIGHTER.to_iced(),
+                            radius: 4.0.into(),
+                            width: 1.0,
+                        },
+                    })
+                    .into();
+
+                let label: Element<_> = text(token_label(token))
+                    .shaping(Shaping::Advanced)
+                    .into();
+
+                let content: Element<_> = row(vec![swatch, label])
+                    .spacing(8.0)
+                    .align_y(Alignment::Center)
+                    .into();
+
+                button(content)
+                    .width(Length::Fill)
+                    .on_press(ManagementAppThemeEditorMsgIn::SelectToken(token))
+                    .class(if token == self.selected_token { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+                    .into()
+            })
+            .collect::<Vec<Element<_>>>();
+
+        column(tokens)
+            .spacing(2.0)
+            .width(240)
+            .into()
+    }
+
+    fn view_color_editor(&self, theme: &SimpleThemeTokens) -> Element<ManagementAppThemeEditorMsgIn> {
+        let color = theme.color(self.selected_token);
+
+        let title: Element<_> = text(token_label(self.selected_token))
+            .size(14)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let red = view_slider(
+            tr("settings-theme-red"),
+            color.r.to_string(),
+            slider(0..=255u8, color.r, move |r| ManagementAppThemeEditorMsgIn::ColorChanged(ThemeTokenColor { r, ..color }))
+                .on_release(ManagementAppThemeEditorMsgIn::ApplyPreview)
+                .into()
+        );
+
+        let green = view_slider(
+            tr("settings-theme-green"),
+            color.g.to_string(),
+            slider(0..=255u8, color.g, move |g| ManagementAppThemeEditorMsgIn::ColorChanged(ThemeTokenColor { g, ..color }))
+                .on_release(ManagementAppThemeEditorMsgIn::ApplyPreview)
+                .into()
+        );
+
+        let blue = view_slider(
+            tr("settings-theme-blue"),
+            color.b.to_string(),
+            slider(0..=255u8, color.b, move |b| ManagementAppThemeEditorMsgIn::ColorChanged(ThemeTokenColor { b, ..color }))
+                .on_release(ManagementAppThemeEditorMsgIn::ApplyPreview)
+                .into()
+        );
+
+        let alpha = view_slider(
+            tr("settings-theme-alpha"),
+            format!("{:.0}%", color.a * 100.0),
+            slider(0.0..=1.0f32, color.a, move |a| ManagementAppThemeEditorMsgIn::ColorChanged(ThemeTokenColor { a, ..color }))
+                .step(0.01f32)
+                .on_release(ManagementAppThemeEditorMsgIn::ApplyPreview)
+                .into()
+        );
+
+        let hex: Element<_> = text_input(&tr("settings-theme-hex"), &self.hex)
+            .on_input(ManagementAppThemeEditorMsgIn::HexChanged)
+            .into();
+
+        column(vec![title, red, green, blue, alpha, hex])
+            .spacing(8.0)
+            .into()
+    }
+
+    fn view_save(&self) -> Element<ManagementAppThemeEditorMsgIn> {
+        let name: Element<_> = text_input(&tr("settings-theme-name"), &self.name)
+            .on_input(ManagementAppThemeEditorMsgIn::NameChanged)
+            .width(Length::Fill)
+            .into();
+
+        let reset_button: Element<_> = button(text(tr("settings-theme-reset")))
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppThemeEditorMsgIn::Reset)
+            .into();
+
+        let save_button: Element<_> = button(text(tr("settings-theme-save")))
+            .class(ButtonStyle::Positive)
+            .on_press_maybe((!self.managed && !self.name.trim().is_empty()).then_some(ManagementAppThemeEditorMsgIn::Save))
+            .into();
+
+        row(vec![name, reset_button, save_button])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into()
+    }
+}
+
+fn view_sizes<'a>(theme: &SimpleThemeTokens) -> Element<'a, ManagementAppThemeEditorMsgIn> {
+    let root_border_radius = view_slider(
+        tr("settings-theme-root-border-radius"),
+        format!("{:.0}", theme.root_border_radius),
+        slider(0.0..=24.0f32, theme.root_border_radius, ManagementAppThemeEditorMsgIn::RootBorderRadiusChanged)
+            .step(1.0f32)
+            .on_release(ManagementAppThemeEditorMsgIn::ApplyPreview)
+            .into()
+    );
+
+    let root_border_width = view_slider(
+        tr("settings-theme-root-border-width"),
+        format!("{:.0}", theme.root_border_width),
+        slider(0.0..=8.0f32, theme.root_border_width, ManagementAppThemeEditorMsgIn::RootBorderWidthChanged)
+            .step(1.0f32)
+            .on_release(ManagementAppThemeEditorMsgIn::ApplyPreview)
+            .into()
+    );
+
+    let content_border_radius = view_slider(
+        tr("settings-theme-content-border-radius"),
+        format!("{:.0}", theme.content_border_radius),
+        slider(0.0..=16.0f32, theme.content_border_radius, ManagementAppThemeEditorMsgIn::ContentBorderRadiusChanged)
+            .step(1.0f32)
+            .on_release(ManagementAppThemeEditorMsgIn::ApplyPreview)
+            .into()
+    );
+
+    column(vec![root_border_radius, root_border_width, content_border_radius])
+        .spacing(8.0)
+        .into()
+}
+
+fn view_slider<'a>(label: String, value: String, slider: Element<'a, ManagementAppThemeEditorMsgIn>) -> Element<'a, ManagementAppThemeEditorMsgIn> {
+    let label: Element<_> = text(label)
+        .shaping(Shaping::Advanced)
+        .width(160)
+        .into();
+
+    let value: Element<_> = text(value)
+        .class(TextStyle::Subtitle)
+        .width(48)
+        .into();
+
+    row(vec![label, slider, value])
+        .spacing(8.0)
+        .align_y(Alignment::Center)
+        .into()
+}
+
+// approximation of main window, uses tokens the same way as default launcher theme derives widget styles from them
+fn view_preview<'a>(theme: &SimpleThemeTokens) -> Element<'a, ManagementAppThemeEditorMsgIn> {
+    let title: Element<_> = text(tr("settings-theme-preview"))
+        .size(14)
+        .class(TextStyle::Subtitle)
+        .into();
+
+    let content_radius = theme.content_border_radius;
+
+    let search: Element<_> = text(tr("settings-theme-preview-search"))
+        .class(TextStyle::ThemePreview(to_iced(theme.text_darker_color)))
+        .into();
+
+    let search: Element<_> = container(search)
+        .padding(12.0)
+        .into();
+
+    let selected_item = view_preview_list_item(theme, tr("settings-theme-preview-selected-item"), theme.background_lighter_color);
+    let hovered_item = view_preview_list_item(theme, tr("settings-theme-preview-hovered-item"), theme.background_darker_color);
+    let item = view_preview_list_item(theme, tr("settings-theme-preview-item"), TRANSPARENT);
+
+    let tag: Element<_> = text(tr("settings-theme-preview-tag"))
+        .class(TextStyle::ThemePreview(to_iced(theme.text_darkest_color)))
+        .into();
+
+    let tag: Element<_> = container(tag)
+        .padding([2.0, 8.0])
+        .class(preview_container(theme.primary_darker_color, content_radius))
+        .into();
+
+    let hovered_tag: Element<_> = text(tr("settings-theme-preview-tag"))
+        .class(TextStyle::ThemePreview(to_iced(theme.text_darkest_color)))
+        .into();
+
+    let hovered_tag: Element<_> = container(hovered_tag)
+        .padding([2.0, 8.0])
+        .class(preview_container(theme.primary_lighter_color, content_radius))
+        .into();
+
+    let tags: Element<_> = row(vec![tag, hovered_tag])
+        .spacing(8.0)
+        .padding([4.0, 8.0])
+        .into();
+
+    let list: Element<_> = column(vec![selected_item, hovered_item, item, tags])
+        .spacing(2.0)
+        .padding([0.0, 8.0])
+        .into();
+
+    let bottom_panel: Element<_> = row(vec![
+        horizontal_space().into(),
+        text(tr("settings-theme-preview-primary-action"))
+            .class(TextStyle::ThemePreview(to_iced(theme.text_lightest_color)))
+            .into(),
+        text(tr("settings-theme-preview-actions"))
+            .class(TextStyle::ThemePreview(to_iced(theme.text_lighter_color)))
+            .into(),
+    ])
+        .spacing(16.0)
+        .into();
+
+    let bottom_panel: Element<_> = container(bottom_panel)
+        .padding([6.0, 8.0])
+        .class(preview_container(theme.background_darker_color, 0.0))
+        .into();
+
+    let launcher: Element<_> = column(vec![search, list, vertical_space().into(), bottom_panel])
+        .spacing(8.0)
+        .into();
+
+    let launcher: Element<_> = container(launcher)
+        .width(PREVIEW_WIDTH)
+        .height(280)
+        .padding(theme.root_border_width)
+        .class(ContainerStyle::ThemePreview {
+            background: to_iced(theme.background_darkest_color),
+            border: Border {
+                color: to_iced(theme.root_border_color),
+                radius: theme.root_border_radius.into(),
+                width: theme.root_border_width,
+            },
+        })
+        .into();
+
+    let content: Element<_> = row(vec![launcher, view_preview_action_panel(theme)])
+        .spacing(16.0)
+        .into();
+
+    column(vec![title, content])
+        .spacing(8.0)
+        .into()
+}
+
+fn view_preview_list_item<'a>(theme: &SimpleThemeTokens, title: String, background: ThemeTokenColor) -> Element<'a, ManagementAppThemeEditorMsgIn> {
+    let title: Element<_> = text(title)
+        .shaping(Shaping::Advanced)
+        .class(TextStyle::ThemePreview(to_iced(theme.text_lightest_color)))
+        .into();
+
+    let subtitle: Element<_> = text(tr("settings-theme-preview-subtitle"))
+        .shaping(Shaping::Advanced)
+        .class(TextStyle::ThemePreview(to_iced(theme.text_lighter_color)))
+        .into();
+
+    let content: Element<_> = row(vec![title, subtitle])
+        .spacing(8.0)
+        .into();
+
+    container(content)
+        .width(Length::Fill)
+        .padding(8.0)
+        .class(preview_container(background, theme.content_border_radius))
+        .into()
+}
+
+fn view_preview_action_panel<'a>(theme: &SimpleThemeTokens) -> Element<'a, ManagementAppThemeEditorMsgIn> {
+    let title: Element<_> = text(tr("settings-theme-preview-actions"))
+        .class(TextStyle::ThemePreview(to_iced(theme.text_lighter_color)))
+        .into();
+
+    let title: Element<_> = container(title)
+        .padding([2.0, 8.0])
+        .into();
+
+    let focused_action = view_preview_action(theme, tr("settings-theme-preview-primary-action"), theme.background_lightest_color, "Enter");
+    let action = view_preview_action(theme, tr("settings-theme-preview-secondary-action"), TRANSPARENT, "C");
+
+    let actions: Element<_> = column(vec![title, focused_action, action])
+        .spacing(2.0)
+        .into();
+
+    container(actions)
+        .width(240)
+        .padding(8.0)
+        .class(preview_container(theme.background_darker_color, theme.content_border_radius))
+        .into()
+}
+
+fn view_preview_action<'a>(theme: &SimpleThemeTokens, title: String, background: ThemeTokenColor, shortcut: &'a str) -> Element<'a, ManagementAppThemeEditorMsgIn> {
+    let title: Element<_> = text(title)
+        .shaping(Shaping::Advanced)
+        .class(TextStyle::ThemePreview(to_iced(theme.text_lightest_color)))
+        .into();
+
+    let shortcut: Element<_> = text(shortcut)
+        .class(TextStyle::ThemePreview(to_iced(theme.text_lightest_color)))
+        .into();
+
+    let shortcut: Element<_> = container(shortcut)
+        .padding([0.0, 8.0])
+        .class(preview_container(theme.background_lightest_color, theme.content_border_radius))
+        .into();
+
+    let content: Element<_> = row(vec![title, horizontal_space().into(), shortcut])
+        .align_y(Alignment::Center)
+        .into();
+
+    container(content)
+        .width(Length::Fill)
+        .padding(8.0)
+        .class(preview_container(background, theme.content_border_radius))
+        .into()
+}
+
+fn preview_container(background: ThemeTokenColor, radius: f32) -> ContainerStyle {
+    ContainerStyle::ThemePreview {
+        background: to_iced(background),
+        border: Border {
+            radius: radius.into(),
+            ..Default::default()
+        },
+    }
+}
+
+fn handle_preview_result(result: Result<(), BackendApiError>) -> ManagementAppThemeEditorMsgOut {
+    match result {
+        Ok(()) => ManagementAppThemeEditorMsgOut::Noop,
+        Err(err) => ManagementAppThemeEditorMsgOut::HandleBackendError(err)
+    }
+}
+
+fn token_label(token: ThemeColorToken) -> String {
+    match token {
+        ThemeColorToken::BackgroundDarkest => tr("settings-theme-token-background-darkest"),
+        ThemeColorToken::BackgroundDarker => tr("settings-theme-token-background-darker"),
+        ThemeColorToken::BackgroundLighter => tr("settings-theme-token-background-lighter"),
+        ThemeColorToken::BackgroundLightest => tr("settings-theme-token-background-lightest"),
+        ThemeColorToken::TextLightest => tr("settings-theme-token-text-lightest"),
+        ThemeColorToken::TextLighter => tr("settings-theme-token-text-lighter"),
+        ThemeColorToken::TextDarker => tr("settings-theme-token-text-darker"),
+        ThemeColorToken::TextDarkest => tr("settings-theme-token-text-darkest"),
+        ThemeColorToken::PrimaryDarker => tr("settings-theme-token-primary-darker"),
+        ThemeColorToken::PrimaryLighter => tr("settings-theme-token-primary-lighter"),
+        ThemeColorToken::RootBorder => tr("settings-theme-token-root-border"),
+    }
+}
+
+fn to_iced(color: ThemeTokenColor) -> Color {
+    Color::from_rgba8(color.r, color.g, color.b, color.a)
+}
+
+fn format_hex(color: ThemeTokenColor) -> String {
+    format!("#{:02X}{:02X}{:02X}", color.r, color.g, color.b)
+}
+
+// alpha is edited separately, so only #RRGGBB is accepted
+fn parse_hex(value: &str) -> Option<(u8, u8, u8)> {
+    let value = value.trim();
+    let value = value.strip_prefix('#').unwrap_or(value);
+
+    if value.len() != 6 || !value.is_ascii() {
+        return None
+    }
+
+    let r = u8::from_str_radix(&value[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&value[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&value[4..6], 16).ok()?;
+
+    Some((r, g, b))
+}
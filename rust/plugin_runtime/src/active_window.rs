@@ -0,0 +1,21 @@
+use deno_core::{op2, OpState};
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+use crate::model::JsActiveWindow;
+
+#[op2(async)]
+#[serde]
+pub async fn active_window(state: Rc<RefCell<OpState>>) -> anyhow::Result<Option<JsActiveWindow>> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.active_window().await
+}
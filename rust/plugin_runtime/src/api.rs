@@ -1,4 +1,4 @@
-use crate::model::{JsAdditionalSearchItem, JsClipboardData, JsPreferenceUserData};
+use crate::model::{JsActiveWindow, JsAdditionalSearchItem, JsClipboardData, JsEntrypointBadge, JsPreferenceUserData, JsRenderError, JsSearchResultPreview, JsSound, JsSystemInfo, JsUndoEntryKind};
 use crate::{JsRequest, JsResponse, JsUiRenderLocation};
 use gauntlet_common::model::{EntrypointId, RootWidget, UiRenderLocation};
 use std::collections::HashMap;
@@ -8,6 +8,8 @@ use gauntlet_utils::channel::{RequestError, RequestSender};
 #[allow(async_fn_in_trait)]
 pub trait BackendForPluginRuntimeApi {
     async fn reload_search_index(&self, generated_commands: Vec<JsAdditionalSearchItem>, refresh_search_list: bool) -> anyhow::Result<()> ;
+    async fn submit_search_results(&self, generation: u64, results: Vec<JsAdditionalSearchItem>) -> anyhow::Result<()>;
+    async fn submit_argument_suggestions(&self, generation: u64, entrypoint_id: EntrypointId, argument_id: String, suggestions: Vec<String>) -> anyhow::Result<()>;
     async fn get_asset_data(&self, path: &str) -> anyhow::Result<Vec<u8>>;
     async fn get_command_generator_entrypoint_ids(&self) -> anyhow::Result<Vec<String>>;
     async fn get_plugin_preferences(&self) -> anyhow::Result<HashMap<String, JsPreferenceUserData>>;
@@ -17,10 +19,22 @@ pub trait BackendForPluginRuntimeApi {
     async fn clipboard_read(&self) -> anyhow::Result<JsClipboardData>;
     async fn clipboard_read_text(&self) -> anyhow::Result<Option<String>>;
     async fn clipboard_write(&self, data: JsClipboardData) -> anyhow::Result<()>;
-    async fn clipboard_write_text(&self, data: String) -> anyhow::Result<()>;
+    async fn clipboard_write_text(&self, data: String, clear_after_seconds: Option<u64>) -> anyhow::Result<()>;
     async fn clipboard_clear(&self) -> anyhow::Result<()>;
+    async fn secret_read(&self, service: String, account: String) -> anyhow::Result<Option<String>>;
+    async fn event_publish(&self, topic: String, payload: String) -> anyhow::Result<()>;
+    async fn active_window(&self) -> anyhow::Result<Option<JsActiveWindow>>;
+    async fn view_state_read(&self, entrypoint_id: EntrypointId, key: String) -> anyhow::Result<Option<String>>;
+    async fn view_state_write(&self, entrypoint_id: EntrypointId, key: String, value: Option<String>) -> anyhow::Result<()>;
+    async fn web_search_suggestions(&self, engine: String, query: String) -> anyhow::Result<Vec<String>>;
+    async fn system_info(&self) -> anyhow::Result<JsSystemInfo>;
+    async fn play_sound(&self, sound: JsSound) -> anyhow::Result<()>;
     async fn ui_update_loading_bar(&self, entrypoint_id: EntrypointId, show: bool) -> anyhow::Result<()>;
+    async fn ui_set_entrypoint_badge(&self, entrypoint_id: EntrypointId, badge: Option<JsEntrypointBadge>) -> anyhow::Result<()>;
+    async fn ui_register_undo(&self, undo_id: u32, description: String, kind: JsUndoEntryKind) -> anyhow::Result<()>;
+    async fn ui_show_search_result_preview(&self, entrypoint_id: EntrypointId, preview: Option<JsSearchResultPreview>) -> anyhow::Result<()>;
     async fn ui_show_hud(&self, display: String) -> anyhow::Result<()>;
+    async fn ui_show_plugin_view(&self, entrypoint_id: EntrypointId) -> anyhow::Result<()>;
     async fn ui_get_action_id_for_shortcut(
         &self,
         entrypoint_id: EntrypointId,
@@ -34,7 +48,7 @@ pub trait BackendForPluginRuntimeApi {
         &self,
         entrypoint_id: EntrypointId,
         render_location: UiRenderLocation,
-        top_level_view: bool,
+        view_stack_depth: usize,
         container: RootWidget,
     ) -> anyhow::Result<()>;
     async fn ui_show_plugin_error_view(
@@ -42,6 +56,18 @@ pub trait BackendForPluginRuntimeApi {
         entrypoint_id: EntrypointId,
         render_location: UiRenderLocation
     ) -> anyhow::Result<()>;
+    async fn ui_show_render_error_view(
+        &self,
+        entrypoint_id: EntrypointId,
+        render_location: UiRenderLocation,
+        error: JsRenderError
+    ) -> anyhow::Result<()>;
+    async fn ui_render_timing(
+        &self,
+        entrypoint_id: EntrypointId,
+        js_render_time_ms: f64,
+        serialization_time_ms: f64
+    ) -> anyhow::Result<()>;
     async fn ui_show_preferences_required_view(
         &self,
         entrypoint_id: EntrypointId,
@@ -89,6 +115,32 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn submit_search_results(&self, generation: u64, results: Vec<JsAdditionalSearchItem>) -> anyhow::Result<()> {
+        let request = JsRequest::SubmitSearchResults {
+            generation,
+            results,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn submit_argument_suggestions(&self, generation: u64, entrypoint_id: EntrypointId, argument_id: String, suggestions: Vec<String>) -> anyhow::Result<()> {
+        let request = JsRequest::SubmitArgumentSuggestions {
+            generation,
+            entrypoint_id,
+            argument_id,
+            suggestions,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
     async fn get_asset_data(&self, path: &str) -> anyhow::Result<Vec<u8>> {
         let request = JsRequest::GetAssetData {
             path: path.to_string(),
@@ -178,9 +230,10 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
-    async fn clipboard_write_text(&self, data: String) -> anyhow::Result<()> {
+    async fn clipboard_write_text(&self, data: String, clear_after_seconds: Option<u64>) -> anyhow::Result<()> {
         let request = JsRequest::ClipboardWriteText {
             data,
+            clear_after_seconds,
         };
 
         match self.request(request).await? {
@@ -198,6 +251,96 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn secret_read(&self, service: String, account: String) -> anyhow::Result<Option<String>> {
+        let request = JsRequest::SecretRead {
+            service,
+            account,
+        };
+
+        match self.request(request).await? {
+            JsResponse::SecretRead { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn event_publish(&self, topic: String, payload: String) -> anyhow::Result<()> {
+        let request = JsRequest::PublishEvent {
+            topic,
+            payload,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn view_state_read(&self, entrypoint_id: EntrypointId, key: String) -> anyhow::Result<Option<String>> {
+        let request = JsRequest::ReadViewState {
+            entrypoint_id,
+            key,
+        };
+
+        match self.request(request).await? {
+            JsResponse::ViewState { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn view_state_write(&self, entrypoint_id: EntrypointId, key: String, value: Option<String>) -> anyhow::Result<()> {
+        let request = JsRequest::WriteViewState {
+            entrypoint_id,
+            key,
+            value,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn web_search_suggestions(&self, engine: String, query: String) -> anyhow::Result<Vec<String>> {
+        let request = JsRequest::WebSearchSuggestions {
+            engine,
+            query,
+        };
+
+        match self.request(request).await? {
+            JsResponse::WebSearchSuggestions { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn active_window(&self) -> anyhow::Result<Option<JsActiveWindow>> {
+        let request = JsRequest::GetActiveWindow;
+
+        match self.request(request).await? {
+            JsResponse::ActiveWindow { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn system_info(&self) -> anyhow::Result<JsSystemInfo> {
+        let request = JsRequest::GetSystemInfo;
+
+        match self.request(request).await? {
+            JsResponse::SystemInfo { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn play_sound(&self, sound: JsSound) -> anyhow::Result<()> {
+        let request = JsRequest::PlaySound {
+            sound,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
     async fn ui_update_loading_bar(&self, entrypoint_id: EntrypointId, show: bool) -> anyhow::Result<()> {
         let request = JsRequest::UpdateLoadingBar {
             entrypoint_id,
@@ -210,6 +353,43 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn ui_set_entrypoint_badge(&self, entrypoint_id: EntrypointId, badge: Option<JsEntrypointBadge>) -> anyhow::Result<()> {
+        let request = JsRequest::SetEntrypointBadge {
+            entrypoint_id,
+            badge,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn ui_register_undo(&self, undo_id: u32, description: String, kind: JsUndoEntryKind) -> anyhow::Result<()> {
+        let request = JsRequest::RegisterUndo {
+            undo_id,
+            description,
+            kind,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn ui_show_search_result_preview(&self, entrypoint_id: EntrypointId, preview: Option<JsSearchResultPreview>) -> anyhow::Result<()> {
+        let request = JsRequest::ShowSearchResultPreview {
+            entrypoint_id,
+            preview,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
     async fn ui_show_hud(&self, display: String) -> anyhow::Result<()> {
         let request = JsRequest::ShowHud {
             display,
@@ -221,6 +401,17 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn ui_show_plugin_view(&self, entrypoint_id: EntrypointId) -> anyhow::Result<()> {
+        let request = JsRequest::ShowPluginView {
+            entrypoint_id,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
     async fn ui_get_action_id_for_shortcut(&self, entrypoint_id: EntrypointId, key: String, modifier_shift: bool, modifier_control: bool, modifier_alt: bool, modifier_meta: bool) -> anyhow::Result<Option<String>> {
         let request = JsRequest::GetActionIdForShortcut {
             entrypoint_id,
@@ -241,7 +432,7 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         &self,
         entrypoint_id: EntrypointId,
         render_location: UiRenderLocation,
-        top_level_view: bool,
+        view_stack_depth: usize,
         container: RootWidget,
     ) -> anyhow::Result<()> {
         let request = JsRequest::Render {
@@ -250,7 +441,7 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
                 UiRenderLocation::InlineView => JsUiRenderLocation::InlineView,
                 UiRenderLocation::View => JsUiRenderLocation::View
             },
-            top_level_view,
+            view_stack_depth,
             container,
         };
 
@@ -275,6 +466,35 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn ui_show_render_error_view(&self, entrypoint_id: EntrypointId, render_location: UiRenderLocation, error: JsRenderError) -> anyhow::Result<()> {
+        let request = JsRequest::ShowRenderErrorView {
+            entrypoint_id,
+            render_location: match render_location {
+                UiRenderLocation::InlineView => JsUiRenderLocation::InlineView,
+                UiRenderLocation::View => JsUiRenderLocation::View
+            },
+            error,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn ui_render_timing(&self, entrypoint_id: EntrypointId, js_render_time_ms: f64, serialization_time_ms: f64) -> anyhow::Result<()> {
+        let request = JsRequest::RenderTiming {
+            entrypoint_id,
+            js_render_time_ms,
+            serialization_time_ms,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
     async fn ui_show_preferences_required_view(&self, entrypoint_id: EntrypointId, plugin_preferences_required: bool, entrypoint_preferences_required: bool) -> anyhow::Result<()> {
         let request = JsRequest::ShowPreferenceRequiredView {
             entrypoint_id,
@@ -70,7 +70,7 @@ pub async fn clipboard_write(state: Rc<RefCell<OpState>>, #[serde] data: JSClipb
 }
 
 #[op2(async)]
-pub async fn clipboard_write_text(state: Rc<RefCell<OpState>>, #[string] data: String) -> anyhow::Result<()> {
+pub async fn clipboard_write_text(state: Rc<RefCell<OpState>>, #[string] data: String, #[serde] clear_after_seconds: Option<u64>) -> anyhow::Result<()> {
     let api = {
         let state = state.borrow();
 
@@ -81,7 +81,7 @@ pub async fn clipboard_write_text(state: Rc<RefCell<OpState>>, #[string] data: S
         api
     };
 
-    api.clipboard_write_text(data).await
+    api.clipboard_write_text(data, clear_after_seconds).await
 }
 
 #[op2(async)]
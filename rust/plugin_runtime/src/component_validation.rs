@@ -0,0 +1,334 @@
+use std::collections::HashMap;
+
+use deno_core::v8;
+use gauntlet_component_model::{Arity, Children, Component, ComponentRef, Property, PropertyKind, PropertyType, SharedType};
+use indexmap::IndexMap;
+
+use crate::component_model::ComponentModel;
+use crate::model::JsRenderError;
+
+// widget tree is checked against component model before it is deserialized, so that
+// instead of generic deserialization error plugin developer sees which component is wrong and why
+pub fn validate_root_widget<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    component_model: &ComponentModel,
+    container: v8::Local<v8::Value>,
+) -> Result<(), JsRenderError> {
+    let components = component_model.components();
+
+    let Some(Component::Root { children, shared_types, .. }) = components.get("gauntlet:root") else {
+        panic!("root component is missing in component model")
+    };
+
+    let context = ValidationContext {
+        components,
+        shared_types,
+    };
+
+    let container = v8::Local::new(scope, container);
+
+    let Ok(container) = v8::Local::<v8::Object>::try_from(container) else {
+        return Err(render_error(&[], "rendered view is not an object".to_string()))
+    };
+
+    let content = get_content(scope, container, &[])?;
+
+    if content.len() > 1 {
+        return Err(render_error(&[], format!("view can only have one top-level component, but {} were rendered", content.len())))
+    }
+
+    let allowed = AllowedChildren {
+        text: false,
+        members: children.iter().collect(),
+    };
+
+    validate_children(scope, &context, content, &[], "view", &allowed)
+}
+
+struct ValidationContext<'a> {
+    components: &'a HashMap<String, Component>,
+    shared_types: &'a IndexMap<String, SharedType>,
+}
+
+struct AllowedChildren<'a> {
+    text: bool,
+    members: Vec<&'a ComponentRef>,
+}
+
+fn validate_children<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    context: &ValidationContext,
+    content: Vec<v8::Local<'s, v8::Value>>,
+    path: &[String],
+    parent_name: &str,
+    allowed: &AllowedChildren,
+) -> Result<(), JsRenderError> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+
+    // top-level component is shown without index because there can only be one
+    let show_index = !path.is_empty();
+
+    for (index, child) in content.into_iter().enumerate() {
+        let Ok(child) = v8::Local::<v8::Object>::try_from(child) else {
+            return Err(render_error(path, format!("child at index {} of {} is not a component", index, parent_name)))
+        };
+
+        let Some(child_type) = get(scope, child, "__type__").filter(|value| value.is_string()) else {
+            return Err(render_error(path, format!("child at index {} of {} doesn't have a component type", index, parent_name)))
+        };
+
+        let child_type = child_type.to_rust_string_lossy(scope);
+
+        let Some(component) = context.components.get(&child_type) else {
+            return Err(render_error(path, format!("unknown component '{}' at index {} of {}", child_type, index, parent_name)))
+        };
+
+        match component {
+            Component::Root { .. } => {
+                return Err(render_error(path, format!("root component cannot be used inside {}", parent_name)))
+            }
+            Component::TextPart { props, .. } => {
+                let child_path = child_path(path, "text", index, show_index);
+
+                if !allowed.text {
+                    return Err(render_error(&child_path, format!("{} doesn't accept text as children", parent_name)))
+                }
+
+                validate_props(scope, context, child, props, &child_path)?;
+            }
+            Component::Standard { internal_name, name, props, children, .. } => {
+                let name = name.to_string();
+                let child_path = child_path(path, &name, index, show_index);
+
+                let Some(member) = allowed.members.iter().find(|member| &member.component_internal_name == internal_name) else {
+                    return Err(render_error(&child_path, format!("{} is not allowed inside {}", name, parent_name)))
+                };
+
+                let count = counts.entry(internal_name.as_str()).or_default();
+                *count += 1;
+
+                if matches!(member.arity, Arity::One | Arity::ZeroOrOne) && *count > 1 {
+                    return Err(render_error(&child_path, format!("{} can only contain one {}", parent_name, name)))
+                }
+
+                validate_props(scope, context, child, props, &child_path)?;
+
+                let allowed = allowed_children(props, children);
+
+                let content = get_content(scope, child, &child_path)?;
+
+                validate_children(scope, context, content, &child_path, &name, &allowed)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_props<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    context: &ValidationContext,
+    object: v8::Local<'s, v8::Object>,
+    props: &[Property],
+    path: &[String],
+) -> Result<(), JsRenderError> {
+    for prop in props {
+        // functions are removed before widget tree is sent, and components are passed as children
+        if prop.property_type.kind() != PropertyKind::Property {
+            continue
+        }
+
+        match get(scope, object, &prop.name) {
+            None => {
+                if !prop.optional {
+                    return Err(render_error(path, format!("required property '{}' is missing", prop.name)))
+                }
+            }
+            Some(value) => {
+                if !matches_type(scope, context, value, &prop.property_type) {
+                    let value = describe_value(scope, value);
+
+                    return Err(render_error(path, format!("property '{}' has invalid value {}, expected {}", prop.name, value, describe_type(&prop.property_type))))
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn matches_type<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    context: &ValidationContext,
+    value: v8::Local<'s, v8::Value>,
+    property_type: &PropertyType,
+) -> bool {
+    match property_type {
+        PropertyType::String => value.is_string(),
+        PropertyType::Number => value.is_number(),
+        PropertyType::Boolean => value.is_boolean(),
+        PropertyType::Component { .. } => true,
+        PropertyType::Function { .. } => true,
+        PropertyType::SharedTypeRef { name } => {
+            match context.shared_types.get(name) {
+                Some(SharedType::Enum { items }) => {
+                    value.is_string() && items.contains(&value.to_rust_string_lossy(scope))
+                }
+                Some(SharedType::Object { items }) => {
+                    let Ok(object) = v8::Local::<v8::Object>::try_from(value) else {
+                        return false
+                    };
+
+                    items.iter()
+                        .all(|(name, item)| {
+                            get(scope, object, name)
+                                .is_some_and(|value| matches_type(scope, context, value, item))
+                        })
+                }
+                Some(SharedType::Union { items }) => {
+                    items.iter()
+                        .any(|item| matches_type(scope, context, value, item))
+                }
+                None => true,
+            }
+        }
+        PropertyType::Union { items } => {
+            items.iter()
+                .any(|item| matches_type(scope, context, value, item))
+        }
+        PropertyType::Array { item } => {
+            let Ok(array) = v8::Local::<v8::Array>::try_from(value) else {
+                return false
+            };
+
+            (0..array.length())
+                .all(|index| {
+                    array.get_index(scope, index)
+                        .is_some_and(|value| matches_type(scope, context, value, item))
+                })
+        }
+    }
+}
+
+fn allowed_children<'a>(props: &'a [Property], children: &'a Children) -> AllowedChildren<'a> {
+    let mut members = vec![];
+
+    // component properties are rendered as children of the component
+    for prop in props {
+        collect_component_refs(&prop.property_type, &mut members);
+    }
+
+    let text = match children {
+        Children::StringOrMembers { ordered_members, per_type_members, .. } => {
+            members.extend(ordered_members.values());
+            members.extend(per_type_members.values());
+            true
+        }
+        Children::Members { ordered_members, per_type_members } => {
+            members.extend(ordered_members.values());
+            members.extend(per_type_members.values());
+            false
+        }
+        Children::String { .. } => true,
+        Children::None => false,
+    };
+
+    AllowedChildren {
+        text,
+        members,
+    }
+}
+
+fn collect_component_refs<'a>(property_type: &'a PropertyType, refs: &mut Vec<&'a ComponentRef>) {
+    match property_type {
+        PropertyType::Component { reference } => refs.push(reference),
+        PropertyType::Union { items } => {
+            for item in items {
+                collect_component_refs(item, refs)
+            }
+        }
+        PropertyType::Array { item } => collect_component_refs(item, refs),
+        _ => {}
+    }
+}
+
+fn get_content<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    object: v8::Local<'s, v8::Object>,
+    path: &[String],
+) -> Result<Vec<v8::Local<'s, v8::Value>>, JsRenderError> {
+    let Some(content) = get(scope, object, "content") else {
+        return Ok(vec![])
+    };
+
+    let Ok(content) = v8::Local::<v8::Array>::try_from(content) else {
+        return Err(render_error(path, "children of component are not an array".to_string()))
+    };
+
+    let content = (0..content.length())
+        .filter_map(|index| content.get_index(scope, index))
+        .collect();
+
+    Ok(content)
+}
+
+// null and undefined are treated the same as missing property
+fn get<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    object: v8::Local<'s, v8::Object>,
+    key: &str,
+) -> Option<v8::Local<'s, v8::Value>> {
+    let key = v8::String::new(scope, key)?;
+
+    object.get(scope, key.into())
+        .filter(|value| !value.is_null_or_undefined())
+}
+
+fn child_path(path: &[String], name: &str, index: usize, show_index: bool) -> Vec<String> {
+    let mut path = path.to_vec();
+
+    if show_index {
+        path.push(format!("{}[{}]", name, index));
+    } else {
+        path.push(name.to_string());
+    }
+
+    path
+}
+
+fn describe_value<'s>(scope: &mut v8::HandleScope<'s>, value: v8::Local<'s, v8::Value>) -> String {
+    if value.is_string() {
+        format!("\"{}\"", value.to_rust_string_lossy(scope))
+    } else if value.is_array() {
+        "array".to_string()
+    } else if value.is_object() {
+        "object".to_string()
+    } else {
+        value.to_rust_string_lossy(scope)
+    }
+}
+
+fn describe_type(property_type: &PropertyType) -> String {
+    match property_type {
+        PropertyType::String => "string".to_string(),
+        PropertyType::Number => "number".to_string(),
+        PropertyType::Boolean => "boolean".to_string(),
+        PropertyType::Component { reference } => reference.component_name.to_string(),
+        PropertyType::Function { .. } => "function".to_string(),
+        PropertyType::SharedTypeRef { name } => name.to_string(),
+        PropertyType::Union { items } => {
+            items.iter()
+                .map(|item| describe_type(item))
+                .collect::<Vec<_>>()
+                .join(" | ")
+        }
+        PropertyType::Array { item } => format!("{}[]", describe_type(item)),
+    }
+}
+
+fn render_error(path: &[String], message: String) -> JsRenderError {
+    JsRenderError {
+        message,
+        component_path: path.to_vec(),
+    }
+}
@@ -24,17 +24,35 @@ use crate::command_generators::get_command_generator_entrypoint_ids;
 use crate::component_model::ComponentModel;
 use crate::environment::{environment_gauntlet_version, environment_is_development, environment_plugin_cache_dir, environment_plugin_data_dir};
 use crate::events::{op_plugin_get_pending_event, EventReceiver, JsEvent};
+use crate::http::{http_fetch, HttpClient};
 use crate::JsPluginCode;
-use crate::logs::{op_log_debug, op_log_error, op_log_info, op_log_trace, op_log_warn};
+use crate::logs::{op_log_debug, op_log_error, op_log_info, op_log_performance_entry, op_log_trace, op_log_warn};
 use crate::model::JsInit;
-use crate::permissions::{permissions_to_deno};
+use crate::permissions::{permissions_to_deno, ExecutableDirectories};
 use crate::plugin_data::PluginData;
-use crate::plugins::applications::current_os;
+use crate::plugins::applications::{application_watcher_next_actions, application_watcher_start, application_watcher_stop, current_os, ApplicationWatchers};
+use crate::plugins::bookmarks::{bookmark_sources, open_bookmark, read_bookmarks};
+use crate::plugins::dictionary::{dictionary_lookup_api, dictionary_lookup_wordlist};
+use crate::plugins::media::{media_control, media_now_playing};
 use crate::plugins::numbat::{run_numbat, NumbatContext};
+use crate::plugins::process_manager::{process_force_kill, process_list, process_renice, process_terminate, ProcessMonitor};
+use crate::plugins::processes::running_executable_paths;
+use crate::plugins::quick_open::{open_recent_project, open_ssh_host, recent_projects, ssh_hosts};
+use crate::plugins::script_commands::{run_script_command, script_command_from_path, script_commands_dir};
 use crate::plugins::settings::open_settings;
+use crate::plugins::system_info::system_info;
+use crate::plugins::web_search::{open_url, web_search_suggestions};
 use crate::preferences::{entrypoint_preferences_required, get_entrypoint_preferences, get_plugin_preferences, plugin_preferences_required};
-use crate::search::reload_search_index;
-use crate::ui::{clear_inline_view, fetch_action_id_for_shortcut, op_component_model, op_inline_view_endpoint_id, op_react_replace_view, show_hud, show_plugin_error_view, show_preferences_required_view, update_loading_bar};
+use crate::realtime::{realtime_close, realtime_event_source_connect, realtime_next_event, realtime_send, realtime_websocket_connect, RealtimeConnections};
+use crate::search::{reload_search_index, submit_argument_suggestions, submit_search_results};
+use crate::secrets::secret_read;
+use crate::event_bus::event_publish;
+use crate::active_window::active_window;
+use crate::sound::play_sound;
+use crate::view_state::{view_state_read, view_state_write};
+use crate::plugins::totp::{totp_accounts, totp_add_account, totp_generate_code, totp_remove_account};
+use crate::plugins::trash::{trash_delete, trash_empty, trash_list, trash_restore};
+use crate::ui::{clear_inline_view, fetch_action_id_for_shortcut, op_component_model, op_inline_view_endpoint_id, op_react_replace_view, register_undo, set_entrypoint_badge, show_hud, show_plugin_error_view, show_plugin_view, show_preferences_required_view, show_search_result_preview, update_loading_bar};
 
 
 
@@ -57,7 +75,7 @@ impl CustomModuleLoader {
     }
 }
 
-const MODULES: [(&str, &str); 10] = [
+const MODULES: [(&str, &str); 11] = [
     ("gauntlet:init", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../js/core/dist/init.js"))),
     ("gauntlet:bridge/components", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../js/bridge_build/dist/bridge-components.js"))),
     ("gauntlet:bridge/hooks", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../js/bridge_build/dist/bridge-hooks.js"))),
@@ -68,6 +86,7 @@ const MODULES: [(&str, &str); 10] = [
     ("gauntlet:bridge/internal-all", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../js/bridge_build/dist/bridge-internal-all.js"))),
     ("gauntlet:bridge/internal-linux", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../js/bridge_build/dist/bridge-internal-linux.js"))),
     ("gauntlet:bridge/internal-macos", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../js/bridge_build/dist/bridge-internal-macos.js"))),
+    ("gauntlet:bridge/internal-windows", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../js/bridge_build/dist/bridge-internal-windows.js"))),
 ];
 
 impl ModuleLoader for CustomModuleLoader {
@@ -97,6 +116,7 @@ impl ModuleLoader for CustomModuleLoader {
             ("gauntlet:bridge/internal-all", _) => "gauntlet:bridge/internal-all",
             ("gauntlet:bridge/internal-linux", _) => "gauntlet:bridge/internal-linux",
             ("gauntlet:bridge/internal-macos", _) => "gauntlet:bridge/internal-macos",
+            ("gauntlet:bridge/internal-windows", _) => "gauntlet:bridge/internal-windows",
             ("react", _) => "gauntlet:bridge/react",
             ("react/jsx-runtime", _) => "gauntlet:bridge/react-jsx-runtime",
             ("@project-gauntlet/api/components", _) => "gauntlet:bridge/components",
@@ -164,6 +184,7 @@ deno_core::extension!(
         op_log_info,
         op_log_warn,
         op_log_error,
+        op_log_performance_entry,
 
         // command generators
         get_command_generator_entrypoint_ids,
@@ -182,6 +203,9 @@ deno_core::extension!(
         fetch_action_id_for_shortcut,
         show_hud,
         update_loading_bar,
+        set_entrypoint_badge,
+        register_undo,
+        show_search_result_preview,
 
         // preferences
         get_plugin_preferences,
@@ -191,6 +215,8 @@ deno_core::extension!(
 
         // search
         reload_search_index,
+        submit_search_results,
+        submit_argument_suggestions,
 
         // clipboard
         clipboard_read_text,
@@ -199,6 +225,32 @@ deno_core::extension!(
         clipboard_write_text,
         clipboard_clear,
 
+        // secrets
+        secret_read,
+
+        // event bus
+        event_publish,
+
+        // active window
+        active_window,
+
+        // sound
+        play_sound,
+
+        // view state
+        view_state_read,
+        view_state_write,
+
+        // http
+        http_fetch,
+
+        // realtime
+        realtime_websocket_connect,
+        realtime_event_source_connect,
+        realtime_send,
+        realtime_close,
+        realtime_next_event,
+
         // plugin environment
         environment_gauntlet_version,
         environment_is_development,
@@ -210,6 +262,8 @@ deno_core::extension!(
         plugin_data: PluginData,
         component_model: ComponentModel,
         backend_api: BackendForPluginRuntimeApiProxy,
+        http_client: HttpClient,
+        executable_directories: ExecutableDirectories,
         outer_handle: Handle
     },
     state = |state, options| {
@@ -217,6 +271,9 @@ deno_core::extension!(
         state.put(options.plugin_data);
         state.put(options.component_model);
         state.put(options.backend_api);
+        state.put(options.http_client);
+        state.put(options.executable_directories);
+        state.put(RealtimeConnections::default());
         state.put(options.outer_handle);
     },
 );
@@ -264,9 +321,63 @@ deno_core::extension!(
 
         // plugins applications
         current_os,
+        running_executable_paths,
+        application_watcher_start,
+        application_watcher_stop,
+        application_watcher_next_actions,
+
+        // plugins bookmarks
+        bookmark_sources,
+        read_bookmarks,
+        open_bookmark,
+
+        // plugins process manager
+        process_list,
+        process_terminate,
+        process_force_kill,
+        process_renice,
+
+        // plugins ssh hosts and recent projects
+        ssh_hosts,
+        open_ssh_host,
+        recent_projects,
+        open_recent_project,
+
+        // plugins totp
+        totp_accounts,
+        totp_add_account,
+        totp_remove_account,
+        totp_generate_code,
 
         // plugins settings
         open_settings,
+
+        // plugins script commands
+        script_commands_dir,
+        script_command_from_path,
+        run_script_command,
+        show_plugin_view,
+
+        // plugins dictionary
+        dictionary_lookup_api,
+        dictionary_lookup_wordlist,
+
+        // plugins media
+        media_now_playing,
+        media_control,
+
+        // plugins system info
+        system_info,
+
+        // plugins web search
+        open_url,
+        web_search_suggestions,
+
+        // plugins trash
+        trash_list,
+        trash_restore,
+        trash_delete,
+        trash_empty,
     ],
     esm_entry_point = "ext:gauntlet/internal-all/bootstrap.js",
     esm = [
@@ -278,6 +389,8 @@ deno_core::extension!(
     },
     state = |state, options| {
         state.put(options.numbat_context);
+        state.put(ApplicationWatchers::default());
+        state.put(ProcessMonitor::default());
     },
 );
 
@@ -289,6 +402,8 @@ deno_core::extension!(
         crate::plugins::applications::linux_app_from_path,
         crate::plugins::applications::linux_application_dirs,
         crate::plugins::applications::linux_open_application,
+        crate::plugins::applications::linux_open_application_action,
+        crate::plugins::applications::linux_focus_application_window,
     ],
     esm_entry_point = "ext:gauntlet/internal-linux/bootstrap.js",
     esm = [
@@ -312,6 +427,7 @@ deno_core::extension!(
         crate::plugins::applications::macos_app_from_arbitrary_path,
         crate::plugins::applications::macos_app_from_path,
         crate::plugins::applications::macos_open_application,
+        crate::plugins::applications::macos_show_application_in_finder,
     ],
     esm_entry_point = "ext:gauntlet/internal-macos/bootstrap.js",
     esm = [
@@ -320,6 +436,25 @@ deno_core::extension!(
     ]
 );
 
+#[cfg(target_os = "windows")]
+deno_core::extension!(
+    gauntlet_internal_windows,
+    ops = [
+        // plugins applications windows
+        crate::plugins::applications::windows_application_dirs,
+        crate::plugins::applications::windows_app_from_path,
+        crate::plugins::applications::windows_uwp_applications,
+        crate::plugins::applications::windows_open_application,
+        crate::plugins::applications::windows_show_application_in_explorer,
+        crate::plugins::applications::windows_open_uwp_application,
+    ],
+    esm_entry_point = "ext:gauntlet/internal-windows/bootstrap.js",
+    esm = [
+        "ext:gauntlet/internal-windows/bootstrap.js" =  "../../js/bridge_build/dist/bridge-internal-windows-bootstrap.js",
+        "ext:gauntlet/internal-windows.js" =  "../../js/core/dist/internal-windows.js",
+    ]
+);
+
 
 pub async fn start_js_runtime(
     outer_handle: Handle,
@@ -372,6 +507,13 @@ pub async fn start_js_runtime(
         Path::new(&init.plugin_cache_dir),
     )?;
 
+    let executable_directories = ExecutableDirectories::new(
+        &init.permissions.exec,
+        &home_dir,
+        Path::new(&init.plugin_data_dir),
+        Path::new(&init.plugin_cache_dir),
+    )?;
+
     let gauntlet_esm = if cfg!(feature = "release") && !init.dev_plugin {
         prod::gauntlet_esm::init_ops_and_esm()
     } else {
@@ -391,6 +533,8 @@ pub async fn start_js_runtime(
             ),
             ComponentModel::new(),
             api,
+            HttpClient::new(init.network_rate_limit.clone()),
+            executable_directories,
             outer_handle
         ),
         gauntlet_esm,
@@ -404,6 +548,9 @@ pub async fn start_js_runtime(
 
         #[cfg(target_os = "linux")]
         extensions.push(gauntlet_internal_linux::init_ops_and_esm());
+
+        #[cfg(target_os = "windows")]
+        extensions.push(gauntlet_internal_windows::init_ops_and_esm());
     }
 
     let mut worker = MainWorker::bootstrap_from_options(
@@ -0,0 +1,20 @@
+use deno_core::{op2, OpState};
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+
+// events are delivered by server to running plugins which subscribe to the topic in their manifest
+#[op2(async)]
+pub async fn event_publish(state: Rc<RefCell<OpState>>, #[string] topic: String, #[string] payload: String) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.event_publish(topic, payload).await
+}
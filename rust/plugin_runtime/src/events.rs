@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::pin::Pin;
 use std::rc::Rc;
 use anyhow::anyhow;
@@ -8,6 +9,7 @@ use deno_core::futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc::Receiver;
 use gauntlet_common::model::UiWidgetId;
+use crate::model::JsUiRenderLocation;
 
 #[derive(Debug, Deserialize, Serialize, Encode, Decode)]
 #[serde(tag = "type")]
@@ -16,18 +18,48 @@ pub enum JsEvent {
         #[serde(rename = "entrypointId")]
         entrypoint_id: String
     },
-    CloseView,
-    RunCommand {
+    CloseView {
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: String
+    },
+    PopView {
         #[serde(rename = "entrypointId")]
         entrypoint_id: String
     },
+    ViewVisibilityChanged {
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: String,
+        visible: bool,
+    },
+    RunCommand {
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: String,
+        arguments: HashMap<String, JsEntrypointArgumentValue>,
+    },
     RunGeneratedCommand {
         #[serde(rename = "entrypointId")]
         entrypoint_id: String,
         #[serde(rename = "actionIndex")]
         action_index: Option<usize>
     },
+    RequestGeneratedCommandPreview {
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: String,
+    },
+    RequestArgumentSuggestions {
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: String,
+        #[serde(rename = "argumentId")]
+        argument_id: String,
+        values: HashMap<String, String>,
+        generation: u64,
+    },
+    // widget ids are unique only within a view, so event is routed by the view it came from
     ViewEvent {
+        #[serde(rename = "renderLocation")]
+        render_location: JsUiRenderLocation,
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: String,
         #[serde(rename = "widgetId")]
         widget_id: UiWidgetId,
         #[serde(rename = "eventName")]
@@ -52,9 +84,43 @@ pub enum JsEvent {
     OpenInlineView {
         #[serde(rename = "text")]
         text: String,
+        #[serde(rename = "generation")]
+        generation: u64,
+    },
+    SearchQuery {
+        #[serde(rename = "text")]
+        text: String,
+        #[serde(rename = "generation")]
+        generation: u64,
+        #[serde(rename = "keywordMode")]
+        keyword_mode: bool,
     },
     ReloadSearchIndex,
     RefreshSearchIndex,
+    PreferenceChanged {
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: Option<String>,
+        #[serde(rename = "preferenceId")]
+        preference_id: String,
+    },
+    RunUndo {
+        #[serde(rename = "undoId")]
+        undo_id: u32,
+        redo: bool,
+    },
+    IdleChanged {
+        idle: bool,
+    },
+    ConnectivityChanged {
+        online: bool,
+    },
+    // payload is json encoded by the publishing plugin
+    InterPluginEvent {
+        #[serde(rename = "sourcePluginId")]
+        source_plugin_id: String,
+        topic: String,
+        payload: String,
+    },
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Encode, Decode)]
@@ -76,9 +142,19 @@ pub enum JsUiPropertyValue {
     Bool {
         value: bool
     },
+    Array {
+        value: Vec<JsUiPropertyValue>
+    },
     Undefined,
 }
 
+#[derive(Debug, Deserialize, Serialize, Encode, Decode)]
+#[serde(untagged)]
+pub enum JsEntrypointArgumentValue {
+    String(String),
+    Number(f64),
+}
+
 pub struct EventReceiver {
     event_stream: Rc<RefCell<Receiver<JsEvent>>>,
 }
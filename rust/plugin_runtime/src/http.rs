@@ -0,0 +1,577 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::io::Read;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use deno_core::url::Url;
+use deno_core::{op2, JsBuffer, OpState, ToJsBuffer};
+use deno_runtime::deno_permissions::PermissionsContainer;
+use flate2::read::{DeflateDecoder, ZlibDecoder};
+use serde::{Deserialize, Serialize};
+
+use crate::model::JsPluginNetworkRateLimit;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_REDIRECTS: usize = 5;
+const MAX_RESPONSE_SIZE: u64 = 50 * 1024 * 1024;
+const MAX_CACHE_SIZE: usize = 16 * 1024 * 1024;
+
+#[derive(Clone)]
+pub struct HttpClient {
+    agent: ureq::Agent,
+    rate_limiter: Arc<Mutex<RateLimiter>>,
+    cache: Arc<Mutex<HttpCache>>,
+}
+
+impl HttpClient {
+    pub fn new(rate_limit: JsPluginNetworkRateLimit) -> Self {
+        // redirects are followed manually so that every location is checked against network permissions
+        let agent = ureq::AgentBuilder::new()
+            .redirects(0)
+            .user_agent("Gauntlet")
+            .build();
+
+        Self {
+            agent,
+            rate_limiter: Arc::new(Mutex::new(RateLimiter::new(rate_limit))),
+            cache: Arc::new(Mutex::new(HttpCache::new())),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct HttpRequest {
+    url: String,
+    method: Option<String>,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    body: Option<JsBuffer>,
+    timeout_ms: Option<u64>,
+    // only GET requests are cached
+    #[serde(default = "default_cache")]
+    cache: bool,
+}
+
+fn default_cache() -> bool {
+    true
+}
+
+// errors are returned as value instead of thrown so that kind is preserved when passed to js
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HttpFetchResult {
+    Response {
+        status: u16,
+        status_text: String,
+        url: String,
+        headers: Vec<(String, String)>,
+        body: ToJsBuffer,
+        cached: bool,
+    },
+    Error {
+        kind: HttpErrorKind,
+        message: String,
+        retry_after_ms: Option<u64>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpErrorKind {
+    InvalidRequest,
+    PermissionDenied,
+    RateLimited,
+    Timeout,
+    Network,
+    TooLarge,
+}
+
+#[derive(Debug)]
+struct HttpError {
+    kind: HttpErrorKind,
+    message: String,
+    retry_after: Option<Duration>,
+}
+
+impl HttpError {
+    fn new(kind: HttpErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            retry_after: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct HttpResponse {
+    status: u16,
+    status_text: String,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Arc<Vec<u8>>,
+}
+
+impl HttpResponse {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[op2(async)]
+#[serde]
+pub async fn http_fetch(state: Rc<RefCell<OpState>>, #[serde] request: HttpRequest) -> HttpFetchResult {
+    let result = fetch(state, request).await;
+
+    match result {
+        Ok((response, cached)) => HttpFetchResult::Response {
+            status: response.status,
+            status_text: response.status_text,
+            url: response.url,
+            headers: response.headers,
+            body: response.body.as_ref().clone().into(),
+            cached,
+        },
+        Err(err) => HttpFetchResult::Error {
+            kind: err.kind,
+            message: err.message,
+            retry_after_ms: err.retry_after.map(|duration| duration.as_millis() as u64),
+        },
+    }
+}
+
+async fn fetch(state: Rc<RefCell<OpState>>, request: HttpRequest) -> Result<(HttpResponse, bool), HttpError> {
+    let client = state.borrow().borrow::<HttpClient>().clone();
+
+    let mut url = Url::parse(&request.url)
+        .map_err(|err| HttpError::new(HttpErrorKind::InvalidRequest, format!("invalid url '{}': {}", request.url, err)))?;
+
+    let mut method = request.method
+        .map(|method| method.to_uppercase())
+        .unwrap_or_else(|| "GET".to_string());
+
+    let mut body = request.body.map(|body| body.to_vec());
+
+    let timeout = request.timeout_ms
+        .map(|timeout| Duration::from_millis(timeout))
+        .unwrap_or(DEFAULT_TIMEOUT);
+
+    let mut headers: Vec<(String, String)> = request.headers.into_iter().collect();
+    headers.sort();
+
+    check_url(&state, &url)?;
+
+    let cache_key = if request.cache && method == "GET" {
+        Some(cache_key(&url, &headers))
+    } else {
+        None
+    };
+
+    let cached = cache_key.as_ref()
+        .and_then(|key| client.cache.lock().expect("lock is poisoned").get(key));
+
+    if let Some(cached) = &cached {
+        if cached.is_fresh() {
+            return Ok((cached.response.clone(), true))
+        }
+    }
+
+    // cached responses don't count towards the limit
+    client.rate_limiter.lock().expect("lock is poisoned").acquire()
+        .map_err(|retry_after| HttpError {
+            kind: HttpErrorKind::RateLimited,
+            message: "too many requests, rate limit of plugin is exceeded".to_string(),
+            retry_after: Some(retry_after),
+        })?;
+
+    let mut request_headers = headers.clone();
+    if let Some(cached) = &cached {
+        request_headers.extend(cached.validation_headers());
+    }
+
+    let mut redirects = 0;
+
+    let response = loop {
+        let response = tokio::task::spawn_blocking({
+            let agent = client.agent.clone();
+            let method = method.clone();
+            let url = url.clone();
+            let headers = request_headers.clone();
+            let body = body.clone();
+
+            move || send(&agent, &method, &url, &headers, body.as_deref(), timeout)
+        }).await.map_err(|err| HttpError::new(HttpErrorKind::Network, err.to_string()))??;
+
+        let location = match response.status {
+            301 | 302 | 303 | 307 | 308 => response.header("location"),
+            _ => None,
+        };
+
+        let Some(location) = location else {
+            break response
+        };
+
+        redirects += 1;
+        if redirects > MAX_REDIRECTS {
+            return Err(HttpError::new(HttpErrorKind::Network, format!("too many redirects when fetching '{}'", request.url)))
+        }
+
+        let redirect_url = url.join(location)
+            .map_err(|err| HttpError::new(HttpErrorKind::Network, format!("invalid redirect location '{}': {}", location, err)))?;
+
+        check_url(&state, &redirect_url)?;
+
+        // same as browsers, credentials meant for one origin are not sent to another one
+        if redirect_url.origin() != url.origin() {
+            remove_credential_headers(&mut request_headers);
+        }
+
+        url = redirect_url;
+
+        // same as browsers, body is dropped unless redirect explicitly preserves the method
+        if response.status == 303 || (matches!(response.status, 301 | 302) && method == "POST") {
+            method = "GET".to_string();
+            body = None;
+        }
+    };
+
+    if response.status == 304 {
+        if let (Some(key), Some(cached)) = (&cache_key, cached) {
+            let response = cached.response.clone();
+
+            client.cache.lock().expect("lock is poisoned").revalidated(key, cache_control(response.header("cache-control")));
+
+            return Ok((response, true))
+        }
+    }
+
+    if let Some(key) = cache_key {
+        if response.status == 200 {
+            client.cache.lock().expect("lock is poisoned").insert(key, response.clone());
+        }
+    }
+
+    Ok((response, false))
+}
+
+fn check_url(state: &Rc<RefCell<OpState>>, url: &Url) -> Result<(), HttpError> {
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(HttpError::new(HttpErrorKind::InvalidRequest, format!("unsupported url scheme '{}'", url.scheme())))
+    }
+
+    let mut state = state.borrow_mut();
+
+    state.borrow_mut::<PermissionsContainer>()
+        .check_net_url(url, "Http.fetch()")
+        .map_err(|err| HttpError::new(HttpErrorKind::PermissionDenied, err.to_string()))
+}
+
+fn remove_credential_headers(headers: &mut Vec<(String, String)>) {
+    headers.retain(|(name, _)| {
+        !["authorization", "cookie", "proxy-authorization"]
+            .iter()
+            .any(|credential_header| name.eq_ignore_ascii_case(credential_header))
+    })
+}
+
+fn cache_key(url: &Url, headers: &[(String, String)]) -> String {
+    let headers = headers.iter()
+        .map(|(name, value)| format!("{}:{}", name.to_lowercase(), value))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!("{}\n{}", url, headers)
+}
+
+// blocking
+fn send(agent: &ureq::Agent, method: &str, url: &Url, headers: &[(String, String)], body: Option<&[u8]>, timeout: Duration) -> Result<HttpResponse, HttpError> {
+    let mut request = agent.request_url(method, url)
+        .timeout(timeout);
+
+    for (name, value) in headers {
+        request = request.set(name, value);
+    }
+
+    // gzip is decoded by ureq, deflate is decoded below
+    if !headers.iter().any(|(name, _)| name.eq_ignore_ascii_case("accept-encoding")) {
+        request = request.set("Accept-Encoding", "gzip, deflate");
+    }
+
+    let result = match body {
+        Some(body) => request.send_bytes(body),
+        None => request.call(),
+    };
+
+    let response = match result {
+        Ok(response) => response,
+        // non-2xx status is not an error, same as in fetch
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(ureq::Error::Transport(err)) => {
+            let timed_out = std::error::Error::source(&err)
+                .and_then(|source| source.downcast_ref::<std::io::Error>())
+                .is_some_and(|source| matches!(source.kind(), std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock));
+
+            let kind = if timed_out { HttpErrorKind::Timeout } else { HttpErrorKind::Network };
+
+            return Err(HttpError::new(kind, err.to_string()))
+        }
+    };
+
+    let status = response.status();
+    let status_text = response.status_text().to_string();
+    let response_url = response.get_url().to_string();
+    let content_encoding = response.header("content-encoding")
+        .map(|value| value.trim().to_lowercase());
+
+    let headers: Vec<(String, String)> = response.headers_names()
+        .into_iter()
+        .filter(|name| {
+            // body is returned decoded, so these headers no longer describe it
+            let decoded = matches!(content_encoding.as_deref(), Some("gzip") | Some("deflate"));
+
+            !(decoded && (name == "content-encoding" || name == "content-length"))
+        })
+        .map(|name| {
+            let value = response.all(&name).join(", ");
+            (name, value)
+        })
+        .collect();
+
+    let mut body = vec![];
+    response.into_reader()
+        .take(MAX_RESPONSE_SIZE + 1)
+        .read_to_end(&mut body)
+        .map_err(|err| HttpError::new(HttpErrorKind::Network, format!("unable to read response body: {}", err)))?;
+
+    if body.len() as u64 > MAX_RESPONSE_SIZE {
+        return Err(HttpError::new(HttpErrorKind::TooLarge, format!("response body is larger than {} bytes", MAX_RESPONSE_SIZE)))
+    }
+
+    if content_encoding.as_deref() == Some("deflate") {
+        body = decode_deflate(&body)
+            .map_err(|err| HttpError::new(HttpErrorKind::Network, format!("unable to decode deflate response body: {}", err)))?;
+    }
+
+    Ok(HttpResponse {
+        status,
+        status_text,
+        url: response_url,
+        headers,
+        body: Arc::new(body),
+    })
+}
+
+// deflate content encoding is supposed to be zlib wrapped, but some servers send raw deflate stream
+fn decode_deflate(body: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut decoded = vec![];
+
+    if ZlibDecoder::new(body).read_to_end(&mut decoded).is_ok() {
+        return Ok(decoded)
+    }
+
+    decoded.clear();
+    DeflateDecoder::new(body).read_to_end(&mut decoded)?;
+
+    Ok(decoded)
+}
+
+struct CacheControl {
+    no_store: bool,
+    no_cache: bool,
+    max_age: Option<u64>,
+}
+
+fn cache_control(value: Option<&str>) -> CacheControl {
+    let mut cache_control = CacheControl {
+        no_store: false,
+        no_cache: false,
+        max_age: None,
+    };
+
+    for directive in value.unwrap_or_default().split(',') {
+        let directive = directive.trim().to_lowercase();
+
+        match directive.split_once('=') {
+            Some(("max-age", value)) => cache_control.max_age = value.trim_matches('"').parse().ok(),
+            None if directive == "no-store" => cache_control.no_store = true,
+            None if directive == "no-cache" => cache_control.no_cache = true,
+            _ => {}
+        }
+    }
+
+    cache_control
+}
+
+#[derive(Clone)]
+struct CacheEntry {
+    response: HttpResponse,
+    fresh_until: Option<Instant>,
+    last_used: Instant,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self) -> bool {
+        self.fresh_until.is_some_and(|fresh_until| Instant::now() < fresh_until)
+    }
+
+    fn validation_headers(&self) -> Vec<(String, String)> {
+        let mut headers = vec![];
+
+        if let Some(etag) = self.response.header("etag") {
+            headers.push(("If-None-Match".to_string(), etag.to_string()));
+        }
+
+        if let Some(last_modified) = self.response.header("last-modified") {
+            headers.push(("If-Modified-Since".to_string(), last_modified.to_string()));
+        }
+
+        headers
+    }
+}
+
+fn fresh_until(cache_control: &CacheControl) -> Option<Instant> {
+    if cache_control.no_cache {
+        return None
+    }
+
+    cache_control.max_age
+        .map(|max_age| Instant::now() + Duration::from_secs(max_age))
+}
+
+// responses without max-age are kept only if they can be revalidated
+struct HttpCache {
+    entries: HashMap<String, CacheEntry>,
+    size: usize,
+}
+
+impl HttpCache {
+    fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            size: 0,
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<CacheEntry> {
+        let entry = self.entries.get_mut(key)?;
+
+        entry.last_used = Instant::now();
+
+        Some(entry.clone())
+    }
+
+    fn insert(&mut self, key: String, response: HttpResponse) {
+        let cache_control = cache_control(response.header("cache-control"));
+
+        self.remove(&key);
+
+        if cache_control.no_store {
+            return
+        }
+
+        let fresh_until = fresh_until(&cache_control);
+        let can_revalidate = response.header("etag").is_some() || response.header("last-modified").is_some();
+
+        if fresh_until.is_none() && !can_revalidate {
+            return
+        }
+
+        let size = response.body.len();
+        if size > MAX_CACHE_SIZE {
+            return
+        }
+
+        while self.size + size > MAX_CACHE_SIZE {
+            let oldest = self.entries.iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+
+            match oldest {
+                Some(oldest) => self.remove(&oldest),
+                None => break,
+            }
+        }
+
+        self.size += size;
+        self.entries.insert(key, CacheEntry {
+            response,
+            fresh_until,
+            last_used: Instant::now(),
+        });
+    }
+
+    fn revalidated(&mut self, key: &str, cache_control: CacheControl) {
+        if let Some(entry) = self.entries.get_mut(key) {
+            entry.fresh_until = fresh_until(&cache_control);
+            entry.last_used = Instant::now();
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.size -= entry.response.body.len();
+        }
+    }
+}
+
+// sliding window
+struct RateLimiter {
+    limit: JsPluginNetworkRateLimit,
+    requests: VecDeque<Instant>,
+}
+
+impl RateLimiter {
+    fn new(limit: JsPluginNetworkRateLimit) -> Self {
+        Self {
+            limit,
+            requests: VecDeque::new(),
+        }
+    }
+
+    // returns time after which request can be retried if limit is exceeded
+    fn acquire(&mut self) -> Result<(), Duration> {
+        let now = Instant::now();
+        let interval = Duration::from_secs(self.limit.interval_seconds as u64);
+
+        while let Some(oldest) = self.requests.front() {
+            if now.duration_since(*oldest) >= interval {
+                self.requests.pop_front();
+            } else {
+                break
+            }
+        }
+
+        if self.requests.len() >= self.limit.requests as usize {
+            let oldest = self.requests.front().copied().unwrap_or(now);
+
+            return Err(interval.saturating_sub(now.duration_since(oldest)))
+        }
+
+        self.requests.push_back(now);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn credential_headers_are_removed() {
+        let mut headers = vec![
+            ("Accept".to_string(), "application/json".to_string()),
+            ("authorization".to_string(), "Bearer token".to_string()),
+            ("Cookie".to_string(), "session=1".to_string()),
+            ("Proxy-Authorization".to_string(), "Basic dXNlcjpwYXNz".to_string()),
+        ];
+
+        remove_credential_headers(&mut headers);
+
+        assert_eq!(headers, vec![("Accept".to_string(), "application/json".to_string())]);
+    }
+}
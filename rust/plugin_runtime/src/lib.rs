@@ -1,22 +1,32 @@
+mod active_window;
 mod api;
 mod assets;
 mod clipboard;
 mod command_generators;
 mod component_model;
+mod component_validation;
 mod deno;
 mod environment;
+mod event_bus;
 mod events;
+mod http;
 mod logs;
 mod model;
 mod permissions;
 mod plugin_data;
 mod plugins;
 mod preferences;
+mod realtime;
 mod search;
+mod secrets;
+mod sound;
 mod ui;
+mod view_state;
+mod wasm;
 
 use crate::api::BackendForPluginRuntimeApiProxy;
 use crate::deno::start_js_runtime;
+use crate::wasm::start_wasm_runtime;
 use anyhow::{anyhow, Context};
 use bincode::{Decode, Encode};
 use deno_core::futures::SinkExt;
@@ -42,11 +52,13 @@ use tokio_util::sync::CancellationToken;
 use gauntlet_utils::channel::{Payload, RequestReceiver};
 
 pub use api::BackendForPluginRuntimeApi;
+pub use events::JsEntrypointArgumentValue;
 pub use events::JsEvent;
 pub use events::JsKeyboardEventOrigin;
 pub use events::JsUiPropertyValue;
 pub use model::*;
-pub use permissions::PERMISSIONS_VARIABLE_PATTERN;
+pub use permissions::{validate_permissions, PERMISSIONS_VARIABLE_PATTERN};
+pub use wasm::WASM_COMPONENT_ASSET;
 
 pub fn run_plugin_runtime(socket_name: String) {
     tokio::runtime::Builder::new_current_thread()
@@ -150,7 +162,10 @@ async fn run(outer_handle: Handle, stop_token: CancellationToken, init: JsInit,
         }
         result @ _ = {
             tokio::task::unconstrained(async {
-                 start_js_runtime(outer_handle, init, event_receiver, api).await
+                match init.code.runtime {
+                    JsPluginRuntime::Deno => start_js_runtime(outer_handle, init, event_receiver, api).await,
+                    JsPluginRuntime::Wasm => start_wasm_runtime(init, event_receiver, api).await,
+                }
             })
         } => {
             if let Err(err) = result {
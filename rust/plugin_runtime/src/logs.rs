@@ -60,5 +60,18 @@ pub fn op_log_error(state: Rc<RefCell<OpState>>, #[string] target: String, #[str
 
     tracing::error!(target = target, plugin_id = plugin_id, message);
 
+    Ok(())
+}
+
+// performance.mark and performance.measure called by plugin, marks have zero duration
+#[op2(fast)]
+pub fn op_log_performance_entry(state: Rc<RefCell<OpState>>, #[string] entry_type: String, #[string] name: String, start_time: f64, duration: f64) -> anyhow::Result<()> {
+    let plugin_id = state.borrow()
+        .borrow::<PluginData>()
+        .plugin_id()
+        .to_string();
+
+    tracing::info!(target = "performance", plugin_id = plugin_id, entry_type = entry_type, start_time = start_time, duration = duration, "{} '{}' took {:.3}ms", entry_type, name, duration);
+
     Ok(())
 }
\ No newline at end of file
@@ -21,6 +21,14 @@ pub enum JsUiRenderLocation {
 #[derive(Debug, Encode, Decode)]
 pub struct JsPluginCode {
     pub js: HashMap<String, String>,
+    pub runtime: JsPluginRuntime,
+}
+
+#[derive(Debug, Clone, Copy, Encode, Decode)]
+pub enum JsPluginRuntime {
+    Deno,
+    // experimental, plugin is a webassembly component, js code is empty
+    Wasm,
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -29,6 +37,8 @@ pub struct JsInit {
     pub plugin_uuid: String,
     pub code: JsPluginCode,
     pub permissions: JsPluginPermissions,
+    // set in application config, not in plugin manifest, so that plugin cannot raise its own limit
+    pub network_rate_limit: JsPluginNetworkRateLimit,
     pub inline_view_entrypoint_id: Option<String>,
     pub dev_plugin: bool,
     pub home_dir: String,
@@ -49,6 +59,12 @@ pub struct JsPluginPermissions {
     pub main_search_bar: Vec<JsPluginPermissionsMainSearchBar>,
 }
 
+#[derive(Clone, Debug, Encode, Decode)]
+pub struct JsPluginNetworkRateLimit {
+    pub requests: u32,
+    pub interval_seconds: u32,
+}
+
 #[derive(Debug, Encode, Decode)]
 pub struct JsPluginPermissionsFileSystem {
     pub read: Vec<String>,
@@ -99,9 +115,24 @@ pub enum JsResponse {
     ClipboardReadText {
         data: Option<String>
     },
+    SecretRead {
+        data: Option<String>
+    },
+    ActiveWindow {
+        data: Option<JsActiveWindow>
+    },
+    WebSearchSuggestions {
+        data: Vec<String>
+    },
+    SystemInfo {
+        data: JsSystemInfo
+    },
     ActionIdForShortcut {
         data: Option<String>
     },
+    ViewState {
+        data: Option<String>
+    },
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -109,7 +140,7 @@ pub enum JsRequest {
     Render {
         entrypoint_id: EntrypointId,
         render_location: JsUiRenderLocation,
-        top_level_view: bool,
+        view_stack_depth: usize,
         container: RootWidget,
     },
     ClearInlineView,
@@ -117,6 +148,16 @@ pub enum JsRequest {
         entrypoint_id: EntrypointId,
         render_location: JsUiRenderLocation,
     },
+    ShowRenderErrorView {
+        entrypoint_id: EntrypointId,
+        render_location: JsUiRenderLocation,
+        error: JsRenderError,
+    },
+    RenderTiming {
+        entrypoint_id: EntrypointId,
+        js_render_time_ms: f64,
+        serialization_time_ms: f64,
+    },
     ShowPreferenceRequiredView {
         entrypoint_id: EntrypointId,
         plugin_preferences_required: bool,
@@ -125,14 +166,31 @@ pub enum JsRequest {
     ShowHud {
         display: String
     },
+    ShowPluginView {
+        entrypoint_id: EntrypointId,
+    },
     UpdateLoadingBar {
         entrypoint_id: EntrypointId,
         show: bool
     },
+    ShowSearchResultPreview {
+        entrypoint_id: EntrypointId,
+        preview: Option<JsSearchResultPreview>,
+    },
     ReloadSearchIndex {
         generated_commands: Vec<JsAdditionalSearchItem>,
         refresh_search_list: bool
     },
+    SubmitSearchResults {
+        generation: u64,
+        results: Vec<JsAdditionalSearchItem>,
+    },
+    SubmitArgumentSuggestions {
+        generation: u64,
+        entrypoint_id: EntrypointId,
+        argument_id: String,
+        suggestions: Vec<String>,
+    },
     GetAssetData {
         path: String,
     },
@@ -151,9 +209,34 @@ pub enum JsRequest {
         data: JsClipboardData
     },
     ClipboardWriteText {
-        data: String
+        data: String,
+        // cleared by server, so that it happens even if plugin is stopped in the meantime
+        clear_after_seconds: Option<u64>,
     },
     ClipboardClear,
+    SecretRead {
+        service: String,
+        account: String,
+    },
+    PublishEvent {
+        topic: String,
+        payload: String,
+    },
+    GetActiveWindow,
+    WebSearchSuggestions {
+        engine: String,
+        query: String,
+    },
+    GetSystemInfo,
+    SetEntrypointBadge {
+        entrypoint_id: EntrypointId,
+        badge: Option<JsEntrypointBadge>,
+    },
+    RegisterUndo {
+        undo_id: u32,
+        description: String,
+        kind: JsUndoEntryKind,
+    },
     GetActionIdForShortcut {
         entrypoint_id: EntrypointId,
         key: String,
@@ -162,6 +245,60 @@ pub enum JsRequest {
         modifier_alt: bool,
         modifier_meta: bool
     },
+    ReadViewState {
+        entrypoint_id: EntrypointId,
+        key: String,
+    },
+    WriteViewState {
+        entrypoint_id: EntrypointId,
+        key: String,
+        value: Option<String>,
+    },
+    PlaySound {
+        sound: JsSound,
+    },
+}
+
+impl JsRequest {
+    pub fn name(&self) -> &'static str {
+        match self {
+            JsRequest::Render { .. } => "Render",
+            JsRequest::ClearInlineView => "ClearInlineView",
+            JsRequest::ShowPluginErrorView { .. } => "ShowPluginErrorView",
+            JsRequest::ShowRenderErrorView { .. } => "ShowRenderErrorView",
+            JsRequest::RenderTiming { .. } => "RenderTiming",
+            JsRequest::ShowPreferenceRequiredView { .. } => "ShowPreferenceRequiredView",
+            JsRequest::ShowHud { .. } => "ShowHud",
+            JsRequest::ShowPluginView { .. } => "ShowPluginView",
+            JsRequest::UpdateLoadingBar { .. } => "UpdateLoadingBar",
+            JsRequest::ShowSearchResultPreview { .. } => "ShowSearchResultPreview",
+            JsRequest::ReloadSearchIndex { .. } => "ReloadSearchIndex",
+            JsRequest::SubmitSearchResults { .. } => "SubmitSearchResults",
+            JsRequest::SubmitArgumentSuggestions { .. } => "SubmitArgumentSuggestions",
+            JsRequest::GetAssetData { .. } => "GetAssetData",
+            JsRequest::GetCommandGeneratorEntrypointIds => "GetCommandGeneratorEntrypointIds",
+            JsRequest::GetPluginPreferences => "GetPluginPreferences",
+            JsRequest::GetEntrypointPreferences { .. } => "GetEntrypointPreferences",
+            JsRequest::PluginPreferencesRequired => "PluginPreferencesRequired",
+            JsRequest::EntrypointPreferencesRequired { .. } => "EntrypointPreferencesRequired",
+            JsRequest::ClipboardRead => "ClipboardRead",
+            JsRequest::ClipboardReadText => "ClipboardReadText",
+            JsRequest::ClipboardWrite { .. } => "ClipboardWrite",
+            JsRequest::ClipboardWriteText { .. } => "ClipboardWriteText",
+            JsRequest::ClipboardClear => "ClipboardClear",
+            JsRequest::SecretRead { .. } => "SecretRead",
+            JsRequest::PublishEvent { .. } => "PublishEvent",
+            JsRequest::GetActiveWindow => "GetActiveWindow",
+            JsRequest::WebSearchSuggestions { .. } => "WebSearchSuggestions",
+            JsRequest::GetSystemInfo => "GetSystemInfo",
+            JsRequest::SetEntrypointBadge { .. } => "SetEntrypointBadge",
+            JsRequest::RegisterUndo { .. } => "RegisterUndo",
+            JsRequest::GetActionIdForShortcut { .. } => "GetActionIdForShortcut",
+            JsRequest::ReadViewState { .. } => "ReadViewState",
+            JsRequest::WriteViewState { .. } => "WriteViewState",
+            JsRequest::PlaySound { .. } => "PlaySound",
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Encode, Decode)]
@@ -172,6 +309,12 @@ pub struct JsAdditionalSearchItem {
     pub entrypoint_uuid: String,
     pub entrypoint_icon: Option<Vec<u8>>,
     pub entrypoint_actions: Vec<JsAdditionalSearchItemAction>,
+    pub entrypoint_accessory: Option<String>,
+    pub entrypoint_preview: bool,
+    pub entrypoint_drag: Option<JsDragPayload>,
+    pub entrypoint_ranking: JsAdditionalSearchItemRanking,
+    pub entrypoint_dedupe_key: Option<String>,
+    pub entrypoint_category: Option<JsSearchResultCategory>,
 }
 
 impl fmt::Debug for JsAdditionalSearchItem {
@@ -183,6 +326,12 @@ impl fmt::Debug for JsAdditionalSearchItem {
             .field("entrypoint_id", &self.entrypoint_id)
             .field("entrypoint_uuid", &self.entrypoint_uuid)
             .field("entrypoint_actions", &self.entrypoint_actions)
+            .field("entrypoint_accessory", &self.entrypoint_accessory)
+            .field("entrypoint_preview", &self.entrypoint_preview)
+            .field("entrypoint_drag", &self.entrypoint_drag)
+            .field("entrypoint_ranking", &self.entrypoint_ranking)
+            .field("entrypoint_dedupe_key", &self.entrypoint_dedupe_key)
+            .field("entrypoint_category", &self.entrypoint_category)
             .finish()
     }
 }
@@ -193,6 +342,101 @@ pub struct JsAdditionalSearchItemAction {
     pub label: String,
 }
 
+#[derive(Debug, Deserialize, Serialize, Encode, Decode)]
+pub struct JsAdditionalSearchItemRanking {
+    pub boost: Option<f64>,
+    pub keywords: Vec<String>,
+    // milliseconds since unix epoch
+    pub timestamp: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Encode, Decode)]
+#[serde(rename_all = "snake_case")]
+pub enum JsSearchResultCategory {
+    Application,
+    Command,
+    Quicklink,
+    File,
+}
+
+#[derive(Debug, Deserialize, Serialize, Encode, Decode)]
+#[serde(untagged)]
+pub enum JsDragPayload {
+    File {
+        file: String
+    },
+    Url {
+        url: String
+    },
+}
+
+#[derive(Debug, Deserialize, Serialize, Encode, Decode)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JsEntrypointBadge {
+    Count {
+        count: u32
+    },
+    Dot,
+}
+
+// builtin sounds are generated by server, asset is a file from assets directory of the plugin
+#[derive(Debug, Deserialize, Serialize, Encode, Decode)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JsSound {
+    Builtin {
+        name: JsBuiltinSound
+    },
+    Asset {
+        path: String
+    },
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Encode, Decode)]
+#[serde(rename_all = "kebab-case")]
+pub enum JsBuiltinSound {
+    TimerFinished,
+    Error,
+}
+
+// action is newly registered, undo and redo mean that action was undone or redone and can be reverted again
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Encode, Decode)]
+#[serde(rename_all = "snake_case")]
+pub enum JsUndoEntryKind {
+    Action,
+    Undo,
+    Redo,
+}
+
+// rendered widget tree doesn't match component model, path is made of component names from the top of the view
+#[derive(Debug, Clone, Serialize, Encode, Decode)]
+pub struct JsRenderError {
+    pub message: String,
+    pub component_path: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Encode, Decode)]
+pub struct JsSearchResultPreview {
+    pub text: Option<String>,
+    pub image: Option<Vec<u8>>,
+    pub metadata: Vec<JsSearchResultPreviewMetadata>,
+}
+
+impl fmt::Debug for JsSearchResultPreview {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // exclude image
+        fmt.debug_struct("JsSearchResultPreview")
+            .field("text", &self.text)
+            .field("metadata", &self.metadata)
+            .finish()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Encode, Decode)]
+pub struct JsSearchResultPreviewMetadata {
+    pub label: String,
+    pub value: String,
+}
+
 #[derive(Debug, Deserialize, Serialize, Encode, Decode)]
 #[serde(untagged)]
 pub enum JsPreferenceUserData {
@@ -207,4 +451,43 @@ pub enum JsPreferenceUserData {
 pub struct JsClipboardData {
     pub text_data: Option<String>,
     pub png_data: Option<Vec<u8>>
-}
\ No newline at end of file
+}
+
+// window which was focused when launcher window was shown
+#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+pub struct JsActiveWindow {
+    pub app_id: String,
+    pub app_name: Option<String>,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+pub struct JsSystemInfo {
+    pub hostname: Option<String>,
+    pub os: Option<String>,
+    pub uptime: u64,
+    pub ip_addresses: Vec<JsSystemInfoIpAddress>,
+    pub wifi_ssid: Option<String>,
+    pub battery: Option<JsSystemInfoBattery>,
+    pub disks: Vec<JsSystemInfoDisk>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+pub struct JsSystemInfoIpAddress {
+    pub interface: String,
+    pub address: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+pub struct JsSystemInfoBattery {
+    pub percentage: f64,
+    pub charging: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+pub struct JsSystemInfoDisk {
+    pub name: String,
+    pub mount_point: String,
+    pub total_space: u64,
+    pub available_space: u64,
+}
@@ -38,6 +38,18 @@ pub fn permissions_to_deno(
     ))
 }
 
+// used by doctor command, permissions which cannot be converted prevent plugin runtime from starting
+pub fn validate_permissions(
+    permissions: &JsPluginPermissions,
+    home_dir: &Path,
+    plugin_data_dir: &Path,
+    plugin_cache_dir: &Path,
+) -> anyhow::Result<()> {
+    permissions_to_deno(Arc::new(RealFs), permissions, home_dir, plugin_data_dir, plugin_cache_dir)?;
+
+    Ok(())
+}
+
 fn path_permission<P: Eq + Hash, T: QueryDescriptor<AllowDesc = P, DenyDesc = P> + Hash>(
     paths: &[String],
     to_permission: fn(PathBuf) -> P,
@@ -147,11 +159,54 @@ fn run_permission(
     Ok(Permissions::new_unary(allow_list, None, false))
 }
 
+// deno only allows running exact executables listed in permissions,
+// script commands are added by user at any time, so their directory is checked separately
+pub struct ExecutableDirectories {
+    dirs: Vec<PathBuf>,
+}
+
+impl ExecutableDirectories {
+    pub fn new(
+        permissions: &JsPluginPermissionsExec,
+        home_dir: &Path,
+        plugin_data_dir: &Path,
+        plugin_cache_dir: &Path,
+    ) -> anyhow::Result<Self> {
+        let dirs = permissions.executable
+            .iter()
+            .map(|path| augment_path(path, home_dir, plugin_data_dir, plugin_cache_dir))
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter()
+            .filter_map(std::convert::identity)
+            .collect();
+
+        Ok(Self { dirs })
+    }
+
+    pub fn check(&self, path: &Path) -> anyhow::Result<()> {
+        let path = path.canonicalize()?;
+
+        // directory may not exist yet when plugin is started
+        let allowed = self.dirs
+            .iter()
+            .filter_map(|dir| dir.canonicalize().ok())
+            .any(|dir| path.starts_with(dir));
+
+        if !allowed {
+            Err(anyhow!("Plugin doesn't have 'exec' permission for {:?}", path))?
+        }
+
+        Ok(())
+    }
+}
+
 fn augment_path(path: &String, home_dir: &Path, plugin_data_dir: &Path, plugin_cache_dir: &Path) -> anyhow::Result<Option<PathBuf>> {
     if let Some(matches) = PERMISSIONS_VARIABLE_PATTERN.captures(path) {
         let namespace = &matches["namespace"];
         let name = &matches["name"];
 
+        let script_commands_dir = Dirs::new().script_commands_dir();
+
         let replacement = match (namespace, name) {
             ("macos", "user-home") => {
                 if cfg!(target_os = "macos") {
@@ -176,6 +231,7 @@ fn augment_path(path: &String, home_dir: &Path, plugin_data_dir: &Path, plugin_c
             },
             ("common", "plugin-data") => Some(plugin_data_dir),
             ("common", "plugin-cache") => Some(plugin_cache_dir),
+            ("common", "script-commands") => Some(script_commands_dir.as_path()),
             (_, _) => {
                 Err(anyhow!("Trying to load plugin with unknown variable in path in manifest permissions: {}", path))?
             }
@@ -14,6 +14,13 @@ mod linux;
 #[cfg(target_os = "macos")]
 mod macos;
 
+#[cfg(target_os = "windows")]
+mod windows;
+
+mod watcher;
+
+pub use watcher::ApplicationWatchers;
+
 #[derive(Debug, Serialize)]
 #[serde(tag = "type")]
 pub enum DesktopPathAction {
@@ -32,7 +39,18 @@ pub enum DesktopPathAction {
 #[derive(Debug, Serialize)]
 pub struct DesktopApplication {
     name: String,
+    path: String,
     icon: Option<Vec<u8>>,
+    actions: Vec<DesktopApplicationAction>,
+    executable: Option<String>,
+    startup_wm_class: Option<String>,
+}
+
+#[cfg(target_os = "linux")]
+#[derive(Debug, Serialize)]
+pub struct DesktopApplicationAction {
+    id: String,
+    name: String,
 }
 
 #[cfg(target_os = "macos")]
@@ -43,12 +61,27 @@ pub struct DesktopApplication {
     icon: Option<Vec<u8>>,
 }
 
-#[cfg(all(not(target_os = "linux"), not(target_os = "macos")))]
+#[cfg(target_os = "windows")]
+#[derive(Debug, Serialize)]
+pub struct DesktopApplication {
+    name: String,
+    path: String,
+    icon: Option<Vec<u8>>,
+}
+
+#[cfg(all(not(target_os = "linux"), not(target_os = "macos"), not(target_os = "windows")))]
 #[derive(Debug, Serialize)]
 pub struct DesktopApplication {
 
 }
 
+#[cfg(target_os = "windows")]
+#[derive(Debug, Serialize)]
+pub struct WindowsUwpApplication {
+    name: String,
+    app_id: String,
+}
+
 #[cfg(target_os = "macos")]
 #[derive(Debug, Serialize)]
 pub struct DesktopSettingsPre13Data {
@@ -72,6 +105,73 @@ pub fn current_os() -> &'static str {
     std::env::consts::OS
 }
 
+#[op2]
+pub fn application_watcher_start(state: &mut OpState, #[serde] dirs: Vec<String>) -> anyhow::Result<u32> {
+    let dirs = dirs.into_iter()
+        .map(|dir| PathBuf::from(dir))
+        .collect();
+
+    state.borrow_mut::<ApplicationWatchers>()
+        .start(dirs)
+}
+
+#[op2(fast)]
+pub fn application_watcher_stop(state: &mut OpState, watcher_id: u32) {
+    state.borrow_mut::<ApplicationWatchers>()
+        .stop(watcher_id)
+}
+
+// resolves to None after watcher is stopped
+#[op2(async)]
+#[serde]
+pub async fn application_watcher_next_actions(state: Rc<RefCell<OpState>>, watcher_id: u32) -> anyhow::Result<Option<Vec<DesktopPathAction>>> {
+    let (receiver, app_from_path) = {
+        let state = state.borrow();
+
+        let receiver = state
+            .borrow::<ApplicationWatchers>()
+            .receiver(watcher_id)?;
+
+        (receiver, app_from_path_fn(&state))
+    };
+
+    let Some(paths) = watcher::next_changed_paths(receiver).await else {
+        return Ok(None)
+    };
+
+    let actions = spawn_blocking(move || {
+        paths.into_iter()
+            .filter_map(|path| app_from_path(path))
+            .collect()
+    }).await?;
+
+    Ok(Some(actions))
+}
+
+#[cfg(target_os = "linux")]
+fn app_from_path_fn(state: &OpState) -> impl Fn(PathBuf) -> Option<DesktopPathAction> + Send + 'static {
+    let home_dir = state
+        .borrow::<PluginData>()
+        .home_dir();
+
+    move |path| linux::linux_app_from_path(home_dir.clone(), path)
+}
+
+#[cfg(target_os = "macos")]
+fn app_from_path_fn(_state: &OpState) -> impl Fn(PathBuf) -> Option<DesktopPathAction> + Send + 'static {
+    |path| macos::macos_app_from_arbitrary_path(path)
+}
+
+#[cfg(target_os = "windows")]
+fn app_from_path_fn(_state: &OpState) -> impl Fn(PathBuf) -> Option<DesktopPathAction> + Send + 'static {
+    |path| windows::windows_app_from_path(path)
+}
+
+#[cfg(all(not(target_os = "linux"), not(target_os = "macos"), not(target_os = "windows")))]
+fn app_from_path_fn(_state: &OpState) -> impl Fn(PathBuf) -> Option<DesktopPathAction> + Send + 'static {
+    |_path| None
+}
+
 #[cfg(target_os = "linux")]
 #[op2(async)]
 #[serde]
@@ -118,6 +218,19 @@ pub fn linux_open_application(#[string] desktop_file_id: String) -> anyhow::Resu
     Ok(())
 }
 
+// returns false if application doesn't have any open windows
+#[cfg(target_os = "linux")]
+#[op2(async)]
+pub async fn linux_focus_application_window(#[serde] executable: Option<String>, #[serde] startup_wm_class: Option<String>) -> anyhow::Result<bool> {
+    spawn_blocking(move || linux::linux_focus_application_window(executable, startup_wm_class)).await?
+}
+
+#[cfg(target_os = "linux")]
+#[op2(async)]
+pub async fn linux_open_application_action(#[string] desktop_file_path: String, #[string] action_id: String) -> anyhow::Result<()> {
+    spawn_blocking(move || linux::linux_open_application_action(PathBuf::from(desktop_file_path), &action_id)).await?
+}
+
 #[cfg(target_os = "macos")]
 #[op2(fast)]
 pub fn macos_major_version() -> u8 {
@@ -167,6 +280,15 @@ pub fn macos_open_application(#[string] app_path: String) -> anyhow::Result<()>
     Ok(())
 }
 
+#[cfg(target_os = "macos")]
+#[op2(fast)]
+pub fn macos_show_application_in_finder(#[string] app_path: String) -> anyhow::Result<()> {
+
+    spawn_detached("open", &["-R", app_path.as_str()])?;
+
+    Ok(())
+}
+
 #[cfg(target_os = "macos")]
 #[op2]
 #[serde]
@@ -211,6 +333,57 @@ pub fn macos_open_setting_pre_13(#[string] setting_path: String) -> anyhow::Resu
     Ok(())
 }
 
+#[cfg(target_os = "windows")]
+#[op2]
+#[serde]
+pub fn windows_application_dirs() -> Vec<String> {
+    windows::windows_application_dirs()
+        .into_iter()
+        .map(|path| path.to_str().expect("non-utf8 paths are not supported").to_string())
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+#[op2(async)]
+#[serde]
+pub async fn windows_app_from_path(#[string] path: String) -> anyhow::Result<Option<DesktopPathAction>> {
+    Ok(spawn_blocking(|| windows::windows_app_from_path(PathBuf::from(path))).await?)
+}
+
+#[cfg(target_os = "windows")]
+#[op2(async)]
+#[serde]
+pub async fn windows_uwp_applications() -> anyhow::Result<Vec<WindowsUwpApplication>> {
+    spawn_blocking(|| windows::windows_uwp_applications()).await?
+}
+
+#[cfg(target_os = "windows")]
+#[op2(fast)]
+pub fn windows_open_application(#[string] shortcut_path: String) -> anyhow::Result<()> {
+
+    spawn_detached("explorer.exe", &[shortcut_path])?;
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+#[op2(fast)]
+pub fn windows_show_application_in_explorer(#[string] shortcut_path: String) -> anyhow::Result<()> {
+
+    spawn_detached("explorer.exe", &[format!("/select,{}", shortcut_path)])?;
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+#[op2(fast)]
+pub fn windows_open_uwp_application(#[string] app_id: String) -> anyhow::Result<()> {
+
+    spawn_detached("explorer.exe", &[format!("shell:AppsFolder\\{}", app_id)])?;
+
+    Ok(())
+}
+
 #[cfg(unix)]
 pub fn spawn_detached<I, S>(
     path: &str,
@@ -253,9 +426,41 @@ where
     }
 }
 
+#[cfg(windows)]
+pub fn spawn_detached<I, S>(
+    path: &str,
+    args: I,
+) -> std::io::Result<()>
+where
+    I: IntoIterator<Item = S> + Copy,
+    S: AsRef<std::ffi::OsStr>,
+{
+    use std::os::windows::process::CommandExt;
+    use std::process::{Command, Stdio};
+
+    // process is not attached to console and doesn't receive ctrl+c of the parent process group
+    const DETACHED_PROCESS: u32 = 0x00000008;
+    const CREATE_NEW_PROCESS_GROUP: u32 = 0x00000200;
+
+    Command::new(path)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .creation_flags(DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP)
+        .spawn()
+        .map(|_| ())
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
 pub(in crate::plugins::applications) fn resize_icon(data: Vec<u8>) -> anyhow::Result<Vec<u8>> {
-    let data = image::load_from_memory_with_format(&data, ImageFormat::Png)?;
-    let data = image::imageops::resize(&data, 48, 48, FilterType::Lanczos3);
+    resize_icon_to(data, 48)
+}
+
+// format is detected from data, icons provided by system are not always png
+pub(in crate::plugins::applications) fn resize_icon_to(data: Vec<u8>, size: u32) -> anyhow::Result<Vec<u8>> {
+    let data = image::load_from_memory(&data)?;
+    let data = image::imageops::resize(&data, size, size, FilterType::Lanczos3);
 
     let mut buffer = std::io::Cursor::new(vec![]);
 
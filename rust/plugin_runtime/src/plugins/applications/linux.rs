@@ -3,13 +3,16 @@ use std::fs::Metadata;
 use std::path::{Path, PathBuf};
 use std::{env, fs};
 
-use crate::plugins::applications::{resize_icon, DesktopApplication, DesktopPathAction};
+use anyhow::{anyhow, Context};
+use crate::plugins::applications::{spawn_detached, DesktopApplication, DesktopApplicationAction, DesktopPathAction};
+use crate::plugins::processes;
 use freedesktop_entry_parser::parse_entry;
-use freedesktop_icons::lookup;
 use image::imageops::FilterType;
 use image::ImageFormat;
 use walkdir::WalkDir;
 
+mod icons;
+
 pub fn linux_application_dirs(home_dir: PathBuf) -> Vec<PathBuf> {
     let data_home = match env::var_os("XDG_DATA_HOME") {
         Some(val) => {
@@ -102,11 +105,11 @@ pub fn linux_app_from_path(home_dir: PathBuf, path: PathBuf) -> Option<DesktopPa
 }
 
 fn create_app_entry(desktop_file_path: &Path) -> Option<DesktopApplication> {
-    let entry = parse_entry(desktop_file_path)
+    let desktop_file = parse_entry(desktop_file_path)
         .inspect_err(|err| tracing::warn!("error parsing .desktop file at path {:?}: {:?}", desktop_file_path, err))
         .ok()?;
 
-    let entry = entry.section("Desktop Entry");
+    let entry = desktop_file.section("Desktop Entry");
 
     let name = entry.attr("Name")?;
     let icon = entry.attr("Icon").map(|s| s.to_string());
@@ -120,58 +123,179 @@ fn create_app_entry(desktop_file_path: &Path) -> Option<DesktopApplication> {
 
     let icon = icon
         .map(|icon| {
-            let icon_path = PathBuf::from(&icon);
-            if icon_path.is_absolute() {
-                Some(icon_path)
-            } else {
-                lookup(&icon)
-                    .with_size(48)
-                    .find()
-            }
+            icons::load_icon(&icon)
+                .inspect_err(|err| tracing::warn!("error processing icon of {:?}: {:?}", desktop_file_path, err))
+                .ok()
+        })
+        .flatten();
+
+    // additional actions like "New Private Window", each one is defined in separate "Desktop Action <id>" section
+    let actions = entry.attr("Actions")
+        .unwrap_or_default()
+        .split(';')
+        .filter(|action_id| !action_id.is_empty())
+        .filter_map(|action_id| {
+            let action = desktop_file.section(format!("Desktop Action {}", action_id));
+
+            let Some(name) = action.attr("Name") else {
+                tracing::warn!("action {:?} in .desktop file at path {:?} doesn't have a name", action_id, desktop_file_path);
+                return None
+            };
+
+            Some(DesktopApplicationAction {
+                id: action_id.to_string(),
+                name: name.to_string(),
+            })
         })
-        .flatten()
-        .inspect(|path| tracing::debug!("icon path: {:?}", path))
-        .map(|path| {
-            match path.extension() {
-                None => Err(anyhow::anyhow!("unknown format")),
-                Some(extension) => {
-                    match extension.to_str() {
-                        Some("png") => {
-                            let data = std::fs::read(path)?;
+        .collect();
 
-                            resize_icon(data)
-                        },
-                        Some("svg") => {
-                            let data = std::fs::read(path)?;
+    let executable = entry.attr("TryExec")
+        .map(|try_exec| try_exec.to_string())
+        .or_else(|| entry.attr("Exec").and_then(|exec| exec_executable(exec)))
+        .and_then(|executable| {
+            Path::new(&executable)
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .map(|file_name| file_name.to_string())
+        });
 
-                            let tree = resvg::usvg::Tree::from_data(&data, &resvg::usvg::Options::default())?;
+    let startup_wm_class = entry.attr("StartupWMClass")
+        .map(|class| class.to_string());
 
-                            let pixmap_size = tree.size().to_int_size();
-                            let mut pixmap = resvg::tiny_skia::Pixmap::new(pixmap_size.width(), pixmap_size.height()).unwrap();
+    Some(DesktopApplication {
+        name: name.to_string(),
+        path: desktop_file_path.to_str()?.to_string(),
+        icon,
+        actions,
+        executable,
+        startup_wm_class,
+    })
+}
 
-                            resvg::render(&tree, resvg::tiny_skia::Transform::default(), &mut pixmap.as_mut());
+// executable that is actually started, skipping common wrappers
+fn exec_executable(exec: &str) -> Option<String> {
+    let args = parse_exec(exec).ok()?;
 
-                            let data = pixmap.encode_png()?;
+    let mut args = args.into_iter()
+        .skip_while(|arg| arg == "env" || arg.ends_with("/env") || arg.contains('='));
 
-                            let data = resize_icon(data)?;
+    let executable = args.next()?;
 
-                            Ok(data)
-                        },
-                        Some("xpm") => Err(anyhow::anyhow!("xpm format")),
-                        _ => Err(anyhow::anyhow!("unsupported by spec format {:?}", extension)),
+    if executable == "flatpak" || executable.ends_with("/flatpak") {
+        return args.find_map(|arg| arg.strip_prefix("--command=").map(|command| command.to_string()))
+    }
+
+    Some(executable)
+}
+
+// window is found either by class which StartupWMClass is meant to match or by executable of window's process
+pub fn linux_focus_application_window(executable: Option<String>, startup_wm_class: Option<String>) -> anyhow::Result<bool> {
+    let windows = processes::open_windows()?;
+
+    let pids: HashSet<u32> = match &executable {
+        Some(executable) => {
+            processes::running_processes()
+                .into_iter()
+                .filter(|process| process.executable.file_name().is_some_and(|file_name| file_name == executable.as_str()))
+                .map(|process| process.pid)
+                .collect()
+        }
+        None => HashSet::new(),
+    };
+
+    let matches_class = |class: &str, expected: &Option<String>| {
+        expected.as_ref().is_some_and(|expected| expected.eq_ignore_ascii_case(class))
+    };
+
+    let window = windows.iter()
+        .find(|window| {
+            let class_matches = window.class
+                .as_ref()
+                .is_some_and(|class| matches_class(class, &startup_wm_class) || matches_class(class, &executable));
+
+            let pid_matches = window.pid.is_some_and(|pid| pids.contains(&pid));
+
+            class_matches || pid_matches
+        });
+
+    match window {
+        Some(window) => {
+            tracing::debug!("Focusing window {:?}", window);
+
+            processes::focus_window(window.id)?;
+
+            Ok(true)
+        }
+        None => Ok(false)
+    }
+}
+
+// gtk-launch can only start main action of the application,
+// so command line of additional action is executed directly
+pub fn linux_open_application_action(desktop_file_path: PathBuf, action_id: &str) -> anyhow::Result<()> {
+    let desktop_file = parse_entry(&desktop_file_path)
+        .with_context(|| format!("error parsing .desktop file at path {:?}", desktop_file_path))?;
+
+    let exec = desktop_file.section(format!("Desktop Action {}", action_id))
+        .attr("Exec")
+        .ok_or_else(|| anyhow!("action {:?} in .desktop file at path {:?} doesn't have Exec key", action_id, desktop_file_path))?;
+
+    let args = parse_exec(exec)?;
+
+    let Some((program, args)) = args.split_first() else {
+        return Err(anyhow!("action {:?} in .desktop file at path {:?} has empty Exec key", action_id, desktop_file_path))
+    };
+
+    spawn_detached(program, args)?;
+
+    Ok(())
+}
+
+// https://specifications.freedesktop.org/desktop-entry-spec/latest/exec-variables.html
+// field codes are removed, because action is started without any files or urls
+fn parse_exec(exec: &str) -> anyhow::Result<Vec<String>> {
+    let mut args = vec![];
+    let mut current: Option<String> = None;
+    let mut chars = exec.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            ' ' | '\t' => {
+                if let Some(arg) = current.take() {
+                    args.push(arg);
+                }
+            }
+            '"' => {
+                let arg = current.get_or_insert_with(String::new);
+
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => {
+                            match chars.next() {
+                                Some(escaped) => arg.push(escaped),
+                                None => return Err(anyhow!("unexpected end of Exec key: {:?}", exec))
+                            }
+                        }
+                        Some(char) => arg.push(char),
+                        None => return Err(anyhow!("unterminated quote in Exec key: {:?}", exec))
                     }
                 }
             }
-        })
-        .map(|res| {
-            res
-                .inspect_err(|err| tracing::warn!("error processing icon of {:?}: {:?}", desktop_file_path, err))
-                .ok()
-        })
-        .flatten();
+            '%' => {
+                match chars.next() {
+                    Some('%') => current.get_or_insert_with(String::new).push('%'),
+                    Some(_) => {}
+                    None => return Err(anyhow!("unexpected end of Exec key: {:?}", exec))
+                }
+            }
+            char => current.get_or_insert_with(String::new).push(char),
+        }
+    }
 
-    Some(DesktopApplication {
-        name: name.to_string(),
-        icon,
-    })
+    if let Some(arg) = current.take() {
+        args.push(arg);
+    }
+
+    Ok(args)
 }
@@ -0,0 +1,240 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::anyhow;
+use freedesktop_entry_parser::parse_entry;
+use freedesktop_icons::lookup;
+use gauntlet_common::dirs::Dirs;
+use once_cell::sync::Lazy;
+use resvg::tiny_skia::{Pixmap, PremultipliedColorU8, Transform};
+
+use crate::plugins::applications::resize_icon_to;
+
+// logical size of application icons
+const ICON_SIZE: u16 = 48;
+
+// symbolic icons are meant to be recolored to text color by toolkit,
+// light gray is what gtk uses by default, and it is visible on both light and dark backgrounds
+const SYMBOLIC_ICON_COLOR: (u8, u8, u8) = (0xbe, 0xbe, 0xbe);
+
+static ICON_THEME: Lazy<Option<String>> = Lazy::new(|| {
+    let theme = user_icon_theme();
+
+    tracing::debug!("icon theme: {:?}", theme);
+
+    theme
+});
+
+static SCALE_FACTOR: Lazy<u16> = Lazy::new(|| {
+    let scale_factor = display_scale_factor();
+
+    tracing::debug!("icon scale factor: {}", scale_factor);
+
+    scale_factor
+});
+
+// icon is either absolute path or icon name which is looked up in user's icon theme
+pub fn load_icon(icon: &str) -> anyhow::Result<Vec<u8>> {
+    let theme = ICON_THEME.as_deref();
+    let scale = *SCALE_FACTOR;
+    let size = (ICON_SIZE * scale) as u32;
+
+    let cache_file = Dirs::new()
+        .system_icon_cache_dir()
+        .join(cache_file_name(theme, icon, size));
+
+    if let Ok(data) = std::fs::read(&cache_file) {
+        return Ok(data)
+    }
+
+    let icon_path = PathBuf::from(icon);
+
+    let (icon_path, symbolic) = if icon_path.is_absolute() {
+        (icon_path, false)
+    } else {
+        find_icon(icon, theme, scale)
+            .ok_or(anyhow!("icon {:?} not found", icon))?
+    };
+
+    tracing::debug!("icon path: {:?}", icon_path);
+
+    let data = rasterize(&icon_path, size, symbolic)?;
+
+    if let Err(err) = write_cache_file(&cache_file, &data) {
+        tracing::warn!("unable to cache icon {:?}: {:?}", cache_file, err);
+    }
+
+    Ok(data)
+}
+
+// full color variant of symbolic icon is preferred if theme has one
+fn find_icon(icon: &str, theme: Option<&str>, scale: u16) -> Option<(PathBuf, bool)> {
+    let find = |name: &str| {
+        let lookup = lookup(name)
+            .with_size(ICON_SIZE)
+            .with_scale(scale)
+            .with_cache();
+
+        match theme {
+            Some(theme) => lookup.with_theme(theme).find(),
+            None => lookup.find(),
+        }
+    };
+
+    match icon.strip_suffix("-symbolic") {
+        Some(full_color_icon) => {
+            find(full_color_icon)
+                .map(|path| (path, false))
+                .or_else(|| find(icon).map(|path| (path, true)))
+        }
+        None => find(icon).map(|path| (path, false))
+    }
+}
+
+fn rasterize(icon_path: &Path, size: u32, symbolic: bool) -> anyhow::Result<Vec<u8>> {
+    let extension = icon_path.extension()
+        .and_then(|extension| extension.to_str())
+        .ok_or(anyhow!("unknown format"))?;
+
+    match extension {
+        "png" => {
+            let data = std::fs::read(icon_path)?;
+
+            resize_icon_to(data, size)
+        }
+        "svg" => {
+            let data = std::fs::read(icon_path)?;
+
+            render_svg(&data, size, symbolic)
+        }
+        "xpm" => Err(anyhow!("xpm format")),
+        _ => Err(anyhow!("unsupported by spec format {:?}", extension)),
+    }
+}
+
+// svg is rendered directly at target size instead of being scaled after rasterization, so it stays sharp
+fn render_svg(data: &[u8], size: u32, symbolic: bool) -> anyhow::Result<Vec<u8>> {
+    let tree = resvg::usvg::Tree::from_data(data, &resvg::usvg::Options::default())?;
+
+    let tree_size = tree.size();
+
+    let mut pixmap = Pixmap::new(size, size)
+        .ok_or(anyhow!("invalid icon size: {}", size))?;
+
+    let scale = size as f32 / tree_size.width().max(tree_size.height());
+
+    // non-square icons are centered
+    let offset_x = (size as f32 - tree_size.width() * scale) / 2.0;
+    let offset_y = (size as f32 - tree_size.height() * scale) / 2.0;
+
+    let transform = Transform::from_scale(scale, scale)
+        .post_translate(offset_x, offset_y);
+
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+    if symbolic {
+        let (red, green, blue) = SYMBOLIC_ICON_COLOR;
+
+        for pixel in pixmap.pixels_mut() {
+            let alpha = pixel.alpha();
+
+            let premultiply = |color: u8| ((color as u16 * alpha as u16) / 255) as u8;
+
+            if let Some(color) = PremultipliedColorU8::from_rgba(premultiply(red), premultiply(green), premultiply(blue), alpha) {
+                *pixel = color;
+            }
+        }
+    }
+
+    Ok(pixmap.encode_png()?)
+}
+
+fn cache_file_name(theme: Option<&str>, icon: &str, size: u32) -> String {
+    let sanitize = |value: &str| value.replace(|char: char| !char.is_ascii_alphanumeric() && char != '-' && char != '.', "_");
+
+    format!("{}-{}-{}.png", sanitize(theme.unwrap_or("default")), sanitize(icon), size)
+}
+
+fn write_cache_file(cache_file: &Path, data: &[u8]) -> anyhow::Result<()> {
+    if let Some(parent) = cache_file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(cache_file, data)?;
+
+    Ok(())
+}
+
+// there is no toolkit independent way to get scale factor outside of gui process,
+// so environment variables used by gtk and qt to configure it are checked
+fn display_scale_factor() -> u16 {
+    ["GDK_SCALE", "QT_SCALE_FACTOR"]
+        .into_iter()
+        .find_map(|var| std::env::var(var).ok()?.parse::<f32>().ok())
+        .filter(|scale_factor| scale_factor.is_finite())
+        .map(|scale_factor| scale_factor.ceil().clamp(1.0, 4.0) as u16)
+        .unwrap_or(1)
+}
+
+fn user_icon_theme() -> Option<String> {
+    let kde = std::env::var("XDG_CURRENT_DESKTOP")
+        .map(|desktop| desktop.split(':').any(|desktop| desktop == "KDE"))
+        .unwrap_or(false);
+
+    if kde {
+        kde_icon_theme()
+            .or_else(gnome_icon_theme)
+            .or_else(gtk_icon_theme)
+    } else {
+        gnome_icon_theme()
+            .or_else(gtk_icon_theme)
+            .or_else(kde_icon_theme)
+    }
+}
+
+fn gnome_icon_theme() -> Option<String> {
+    let output = Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "icon-theme"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None
+    }
+
+    let theme = String::from_utf8(output.stdout).ok()?;
+    let theme = theme.trim().trim_matches('\'');
+
+    if theme.is_empty() {
+        None
+    } else {
+        Some(theme.to_string())
+    }
+}
+
+fn gtk_icon_theme() -> Option<String> {
+    let settings_file = config_home()?.join("gtk-3.0").join("settings.ini");
+
+    let settings = parse_entry(settings_file).ok()?;
+
+    settings.section("Settings")
+        .attr("gtk-icon-theme-name")
+        .map(|theme| theme.to_string())
+}
+
+fn kde_icon_theme() -> Option<String> {
+    let globals_file = config_home()?.join("kdeglobals");
+
+    let globals = parse_entry(globals_file).ok()?;
+
+    globals.section("Icons")
+        .attr("Theme")
+        .map(|theme| theme.to_string())
+}
+
+fn config_home() -> Option<PathBuf> {
+    match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(config_home) if !config_home.is_empty() => Some(PathBuf::from(config_home)),
+        _ => Some(Dirs::new().home_dir().join(".config")),
+    }
+}
@@ -0,0 +1,107 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use indexmap::IndexSet;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+// changes usually come in bursts, e.g. when package manager installs application,
+// so they are collected for some time and processed together
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(1000);
+
+#[derive(Default)]
+pub struct ApplicationWatchers {
+    next_id: u32,
+    watchers: HashMap<u32, ApplicationWatcher>,
+}
+
+struct ApplicationWatcher {
+    // events stop being produced when watcher is dropped
+    _watcher: RecommendedWatcher,
+    receiver: Rc<RefCell<UnboundedReceiver<PathBuf>>>,
+}
+
+impl ApplicationWatchers {
+    pub fn start(&mut self, dirs: Vec<PathBuf>) -> anyhow::Result<u32> {
+        let (sender, receiver) = unbounded_channel();
+
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            match event {
+                Ok(event) => {
+                    if let EventKind::Access(_) = event.kind {
+                        return;
+                    }
+
+                    for path in event.paths {
+                        // receiver is dropped together with watcher
+                        let _ = sender.send(path);
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!("error watching application directories: {:?}", err);
+                }
+            }
+        })?;
+
+        for dir in dirs {
+            // directories that don't exist are expected, not every one of them is present on every system
+            if !dir.exists() {
+                continue
+            }
+
+            watcher.watch(&dir, RecursiveMode::Recursive)?;
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.watchers.insert(id, ApplicationWatcher {
+            _watcher: watcher,
+            receiver: Rc::new(RefCell::new(receiver)),
+        });
+
+        Ok(id)
+    }
+
+    pub fn stop(&mut self, id: u32) {
+        self.watchers.remove(&id);
+    }
+
+    pub fn receiver(&self, id: u32) -> anyhow::Result<Rc<RefCell<UnboundedReceiver<PathBuf>>>> {
+        self.watchers.get(&id)
+            .map(|watcher| watcher.receiver.clone())
+            .ok_or(anyhow!("application watcher with id {} doesn't exist", id))
+    }
+}
+
+// returns None when watcher was stopped
+pub async fn next_changed_paths(receiver: Rc<RefCell<UnboundedReceiver<PathBuf>>>) -> Option<Vec<PathBuf>> {
+    let mut receiver = receiver.borrow_mut();
+
+    let mut paths = IndexSet::new();
+
+    paths.insert(receiver.recv().await?);
+
+    let deadline = tokio::time::sleep(DEBOUNCE_DURATION);
+    tokio::pin!(deadline);
+
+    loop {
+        tokio::select! {
+            _ = &mut deadline => break,
+            path = receiver.recv() => {
+                match path {
+                    Some(path) => {
+                        paths.insert(path);
+                    }
+                    None => break
+                }
+            }
+        }
+    }
+
+    Some(paths.into_iter().collect())
+}
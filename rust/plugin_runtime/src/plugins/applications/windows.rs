@@ -0,0 +1,226 @@
+use std::ffi::OsStr;
+use std::mem::size_of;
+use std::os::windows::ffi::OsStrExt;
+use std::os::windows::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{anyhow, Context};
+use image::{ImageFormat, RgbaImage};
+use serde::Deserialize;
+use windows::core::PCWSTR;
+use windows::Win32::Graphics::Gdi::{CreateCompatibleDC, DeleteDC, DeleteObject, GetDIBits, GetObjectW, BITMAP, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS};
+use windows::Win32::Storage::FileSystem::FILE_FLAGS_AND_ATTRIBUTES;
+use windows::Win32::UI::Shell::{SHGetFileInfoW, SHFILEINFOW, SHGFI_ICON, SHGFI_LARGEICON};
+use windows::Win32::UI::WindowsAndMessaging::{DestroyIcon, GetIconInfo, ICONINFO};
+
+use crate::plugins::applications::{resize_icon, DesktopApplication, DesktopPathAction, WindowsUwpApplication};
+
+// prevents console window from flashing when powershell is started
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+pub fn windows_application_dirs() -> Vec<PathBuf> {
+    let start_menu = |var: &str| {
+        std::env::var_os(var)
+            .filter(|dir| !dir.is_empty())
+            .map(|dir| PathBuf::from(dir).join("Microsoft").join("Windows").join("Start Menu").join("Programs"))
+    };
+
+    // per user and all users start menu
+    [start_menu("APPDATA"), start_menu("PROGRAMDATA")]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+pub fn windows_app_from_path(path: PathBuf) -> Option<DesktopPathAction> {
+    if path.extension() != Some(OsStr::new("lnk")) {
+        return None
+    }
+
+    let path_str = path.to_str()?.to_string();
+
+    if !path.is_file() {
+        return Some(DesktopPathAction::Remove {
+            id: path_str,
+        })
+    }
+
+    let name = path.file_stem()?.to_str()?.to_string();
+
+    // start menu folders of installed programs usually contain shortcut to uninstaller
+    if name.to_lowercase().contains("uninstall") {
+        return None
+    }
+
+    let icon = extract_icon(&path)
+        .inspect_err(|err| tracing::error!("error processing icon of {:?}: {:?}", path, err))
+        .ok();
+
+    Some(DesktopPathAction::Add {
+        id: path_str.clone(),
+        data: DesktopApplication {
+            name,
+            path: path_str,
+            icon,
+        },
+    })
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum StartApps {
+    Single(StartApp),
+    Multiple(Vec<StartApp>),
+}
+
+#[derive(Debug, Deserialize)]
+struct StartApp {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "AppID")]
+    app_id: String,
+}
+
+// packaged (uwp) applications are not represented as files in start menu,
+// so they are listed using Get-StartApps which includes both shortcuts and packaged applications
+pub fn windows_uwp_applications() -> anyhow::Result<Vec<WindowsUwpApplication>> {
+    let output = Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", "Get-StartApps | ConvertTo-Json"])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .context("Unable to run powershell")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        return Err(anyhow!("Get-StartApps failed: {}", stderr.trim()))
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    if stdout.trim().is_empty() {
+        return Ok(vec![])
+    }
+
+    // ConvertTo-Json returns object instead of array if there is only one item
+    let apps = match serde_json::from_str::<StartApps>(&stdout).context("Unexpected Get-StartApps output")? {
+        StartApps::Single(app) => vec![app],
+        StartApps::Multiple(apps) => apps,
+    };
+
+    // application user model id of packaged applications has "PackageFamilyName!AppId" format,
+    // others are paths or ids of shortcuts which are already found in start menu directories
+    let apps = apps.into_iter()
+        .filter(|app| app.app_id.contains('!'))
+        .map(|app| WindowsUwpApplication {
+            name: app.name,
+            app_id: app.app_id,
+        })
+        .collect();
+
+    Ok(apps)
+}
+
+fn extract_icon(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let path_wide: Vec<u16> = path.as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+
+    let mut file_info = SHFILEINFOW::default();
+
+    let result = unsafe {
+        SHGetFileInfoW(
+            PCWSTR(path_wide.as_ptr()),
+            FILE_FLAGS_AND_ATTRIBUTES(0),
+            Some(&mut file_info),
+            size_of::<SHFILEINFOW>() as u32,
+            SHGFI_ICON | SHGFI_LARGEICON,
+        )
+    };
+
+    if result == 0 || file_info.hIcon.is_invalid() {
+        return Err(anyhow!("Unable to get icon"))
+    }
+
+    let icon = file_info.hIcon;
+
+    let png = unsafe {
+        let mut icon_info = ICONINFO::default();
+
+        let png = match GetIconInfo(icon, &mut icon_info) {
+            Ok(()) => {
+                let png = bitmap_to_png(icon_info.hbmColor);
+
+                let _ = DeleteObject(icon_info.hbmColor);
+                let _ = DeleteObject(icon_info.hbmMask);
+
+                png
+            }
+            Err(err) => Err(anyhow!("Unable to get icon info: {:?}", err))
+        };
+
+        let _ = DestroyIcon(icon);
+
+        png
+    }?;
+
+    resize_icon(png)
+}
+
+unsafe fn bitmap_to_png(bitmap: windows::Win32::Graphics::Gdi::HBITMAP) -> anyhow::Result<Vec<u8>> {
+    if bitmap.is_invalid() {
+        return Err(anyhow!("Icon doesn't have color bitmap"))
+    }
+
+    let mut bitmap_data = BITMAP::default();
+
+    let size = GetObjectW(bitmap, size_of::<BITMAP>() as i32, Some(&mut bitmap_data as *mut BITMAP as *mut _));
+    if size == 0 {
+        return Err(anyhow!("Unable to get icon bitmap"))
+    }
+
+    let width = bitmap_data.bmWidth;
+    let height = bitmap_data.bmHeight;
+
+    let mut bitmap_info = BITMAPINFO {
+        bmiHeader: BITMAPINFOHEADER {
+            biSize: size_of::<BITMAPINFOHEADER>() as u32,
+            biWidth: width,
+            // negative height means top-down bitmap
+            biHeight: -height,
+            biPlanes: 1,
+            biBitCount: 32,
+            biCompression: BI_RGB.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let mut pixels = vec![0u8; (width * height * 4) as usize];
+
+    let dc = CreateCompatibleDC(None);
+
+    let lines = GetDIBits(dc, bitmap, 0, height as u32, Some(pixels.as_mut_ptr() as *mut _), &mut bitmap_info, DIB_RGB_COLORS);
+
+    let _ = DeleteDC(dc);
+
+    if lines == 0 {
+        return Err(anyhow!("Unable to get icon bitmap bits"))
+    }
+
+    // bgra to rgba
+    for pixel in pixels.chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+    }
+
+    let image = RgbaImage::from_raw(width as u32, height as u32, pixels)
+        .ok_or(anyhow!("Icon bitmap has unexpected size"))?;
+
+    let mut buffer = std::io::Cursor::new(vec![]);
+
+    image.write_to(&mut buffer, ImageFormat::Png)?;
+
+    Ok(buffer.into_inner())
+}
@@ -0,0 +1,361 @@
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use anyhow::{anyhow, Context};
+use deno_core::{op2, OpState};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{Connection, SqliteConnection};
+
+use crate::plugin_data::PluginData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Browser {
+    Firefox,
+    Chrome,
+    Chromium,
+    Brave,
+    Edge,
+    Vivaldi,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BookmarkSource {
+    browser: Browser,
+    profile: String,
+    path: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Bookmark {
+    title: String,
+    url: String,
+}
+
+#[op2]
+#[serde]
+pub fn bookmark_sources(state: Rc<RefCell<OpState>>) -> Vec<BookmarkSource> {
+    let home_dir = {
+        let state = state.borrow();
+
+        let home_dir = state
+            .borrow::<PluginData>()
+            .home_dir();
+
+        home_dir
+    };
+
+    let firefox_sources = firefox_profile_dirs(&home_dir)
+        .into_iter()
+        .flat_map(|dir| find_profiles(&dir, "places.sqlite"))
+        .map(|(profile, path)| (Browser::Firefox, profile, path));
+
+    let chromium_sources = chromium_user_data_dirs(&home_dir)
+        .into_iter()
+        .flat_map(|(browser, dir)| {
+            find_profiles(&dir, "Bookmarks")
+                .into_iter()
+                .map(move |(profile, path)| (browser, profile, path))
+        });
+
+    firefox_sources
+        .chain(chromium_sources)
+        .filter_map(|(browser, profile, path)| {
+            Some(BookmarkSource {
+                browser,
+                profile,
+                path: path.to_str()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[op2(async)]
+#[serde]
+pub async fn read_bookmarks(state: Rc<RefCell<OpState>>, #[serde] source: BookmarkSource) -> anyhow::Result<Vec<Bookmark>> {
+    let plugin_cache_dir = {
+        let state = state.borrow();
+
+        let plugin_cache_dir = state
+            .borrow::<PluginData>()
+            .plugin_cache_dir()
+            .to_string();
+
+        plugin_cache_dir
+    };
+
+    let path = PathBuf::from(&source.path);
+
+    match source.browser {
+        Browser::Firefox => {
+            let copy_dir = Path::new(&plugin_cache_dir)
+                .join("bookmarks")
+                .join(&source.profile);
+
+            read_firefox_bookmarks(&path, &copy_dir).await
+        }
+        _ => {
+            let data = tokio::fs::read(&path)
+                .await
+                .with_context(|| format!("unable to read bookmarks file {:?}", path))?;
+
+            read_chromium_bookmarks(&data)
+        }
+    }
+}
+
+// opens bookmark in browser it came from, falls back to default browser if it cannot be started
+#[op2]
+pub fn open_bookmark(#[serde] browser: Browser, #[string] url: String) -> anyhow::Result<()> {
+    if let Err(err) = open::with_detached(&url, browser_app(browser)) {
+        tracing::warn!("unable to open bookmark in {:?}, using default browser: {:?}", browser, err);
+
+        open::that_detached(&url)
+            .map_err(|err| anyhow!("unable to open url '{}': {}", url, err))?;
+    }
+
+    Ok(())
+}
+
+// database is locked while firefox is running, so a copy is read instead.
+// write-ahead log is copied too, because recent changes may not be in the main file yet
+async fn read_firefox_bookmarks(places_file: &Path, copy_dir: &Path) -> anyhow::Result<Vec<Bookmark>> {
+    tokio::fs::create_dir_all(copy_dir)
+        .await
+        .context("unable to create directory for bookmarks database copy")?;
+
+    let copy_file = copy_dir.join("places.sqlite");
+    let copy_wal_file = copy_dir.join("places.sqlite-wal");
+
+    let _ = tokio::fs::remove_file(&copy_wal_file).await;
+
+    tokio::fs::copy(places_file, &copy_file)
+        .await
+        .with_context(|| format!("unable to copy bookmarks database {:?}", places_file))?;
+
+    let mut wal_file = places_file.as_os_str().to_owned();
+    wal_file.push("-wal");
+
+    if Path::new(&wal_file).exists() {
+        tokio::fs::copy(&wal_file, &copy_wal_file)
+            .await
+            .with_context(|| format!("unable to copy bookmarks database write-ahead log {:?}", wal_file))?;
+    }
+
+    let options = SqliteConnectOptions::new()
+        .filename(&copy_file);
+
+    let mut conn = SqliteConnection::connect_with(&options)
+        .await
+        .context("unable to open bookmarks database")?;
+
+    // type 1 is bookmark, "place:" urls are smart bookmarks i.e. saved queries
+    // language=SQLite
+    let bookmarks = sqlx::query_as::<_, (String, String)>(r#"
+        SELECT COALESCE(NULLIF(b.title, ''), p.title, p.url), p.url
+        FROM moz_bookmarks b
+        JOIN moz_places p ON p.id = b.fk
+        WHERE b.type = 1 AND p.url NOT LIKE 'place:%'
+    "#)
+        .fetch_all(&mut conn)
+        .await
+        .context("unable to read bookmarks database")?
+        .into_iter()
+        .map(|(title, url)| Bookmark { title, url })
+        .collect();
+
+    conn.close().await?;
+
+    let _ = tokio::fs::remove_dir_all(copy_dir).await;
+
+    Ok(bookmarks)
+}
+
+#[derive(Debug, Deserialize)]
+struct ChromiumBookmarksFile {
+    roots: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ChromiumBookmarkNode {
+    Url {
+        name: String,
+        url: String,
+    },
+    Folder {
+        #[serde(default)]
+        children: Vec<ChromiumBookmarkNode>,
+    },
+}
+
+fn read_chromium_bookmarks(data: &[u8]) -> anyhow::Result<Vec<Bookmark>> {
+    let file: ChromiumBookmarksFile = serde_json::from_slice(data)
+        .context("unexpected format of bookmarks file")?;
+
+    fn collect(node: ChromiumBookmarkNode, bookmarks: &mut Vec<Bookmark>) {
+        match node {
+            ChromiumBookmarkNode::Url { name, url } => {
+                let title = if name.is_empty() { url.clone() } else { name };
+
+                bookmarks.push(Bookmark { title, url })
+            }
+            ChromiumBookmarkNode::Folder { children } => {
+                for child in children {
+                    collect(child, bookmarks)
+                }
+            }
+        }
+    }
+
+    let mut bookmarks = vec![];
+
+    // roots also contain values that are not bookmark folders, e.g. sync metadata
+    for root in file.roots.into_values() {
+        if let Ok(node) = serde_json::from_value::<ChromiumBookmarkNode>(root) {
+            collect(node, &mut bookmarks)
+        }
+    }
+
+    Ok(bookmarks)
+}
+
+// profiles are subdirectories containing the file, in some layouts they are in "Profiles" directory
+fn find_profiles(dir: &Path, file_name: &str) -> Vec<(String, PathBuf)> {
+    [dir.to_path_buf(), dir.join("Profiles")]
+        .into_iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path().join(file_name);
+
+            if !path.is_file() {
+                return None
+            }
+
+            let profile = entry.file_name().to_str()?.to_string();
+
+            Some((profile, path))
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn firefox_profile_dirs(home_dir: &Path) -> Vec<PathBuf> {
+    vec![
+        home_dir.join(".mozilla").join("firefox"),
+        home_dir.join("snap").join("firefox").join("common").join(".mozilla").join("firefox"),
+        home_dir.join(".var").join("app").join("org.mozilla.firefox").join(".mozilla").join("firefox"),
+    ]
+}
+
+#[cfg(target_os = "linux")]
+fn chromium_user_data_dirs(home_dir: &Path) -> Vec<(Browser, PathBuf)> {
+    let config_dir = home_dir.join(".config");
+
+    vec![
+        (Browser::Chrome, config_dir.join("google-chrome")),
+        (Browser::Chromium, config_dir.join("chromium")),
+        (Browser::Brave, config_dir.join("BraveSoftware").join("Brave-Browser")),
+        (Browser::Edge, config_dir.join("microsoft-edge")),
+        (Browser::Vivaldi, config_dir.join("vivaldi")),
+    ]
+}
+
+#[cfg(target_os = "linux")]
+fn browser_app(browser: Browser) -> &'static str {
+    match browser {
+        Browser::Firefox => "firefox",
+        Browser::Chrome => "google-chrome",
+        Browser::Chromium => "chromium",
+        Browser::Brave => "brave-browser",
+        Browser::Edge => "microsoft-edge",
+        Browser::Vivaldi => "vivaldi",
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn firefox_profile_dirs(home_dir: &Path) -> Vec<PathBuf> {
+    vec![
+        home_dir.join("Library").join("Application Support").join("Firefox"),
+    ]
+}
+
+#[cfg(target_os = "macos")]
+fn chromium_user_data_dirs(home_dir: &Path) -> Vec<(Browser, PathBuf)> {
+    let app_support_dir = home_dir.join("Library").join("Application Support");
+
+    vec![
+        (Browser::Chrome, app_support_dir.join("Google").join("Chrome")),
+        (Browser::Chromium, app_support_dir.join("Chromium")),
+        (Browser::Brave, app_support_dir.join("BraveSoftware").join("Brave-Browser")),
+        (Browser::Edge, app_support_dir.join("Microsoft Edge")),
+        (Browser::Vivaldi, app_support_dir.join("Vivaldi")),
+    ]
+}
+
+#[cfg(target_os = "macos")]
+fn browser_app(browser: Browser) -> &'static str {
+    match browser {
+        Browser::Firefox => "Firefox",
+        Browser::Chrome => "Google Chrome",
+        Browser::Chromium => "Chromium",
+        Browser::Brave => "Brave Browser",
+        Browser::Edge => "Microsoft Edge",
+        Browser::Vivaldi => "Vivaldi",
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn firefox_profile_dirs(_home_dir: &Path) -> Vec<PathBuf> {
+    std::env::var_os("APPDATA")
+        .map(|app_data| PathBuf::from(app_data).join("Mozilla").join("Firefox"))
+        .into_iter()
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn chromium_user_data_dirs(_home_dir: &Path) -> Vec<(Browser, PathBuf)> {
+    let Some(local_app_data) = std::env::var_os("LOCALAPPDATA").map(PathBuf::from) else {
+        return vec![]
+    };
+
+    vec![
+        (Browser::Chrome, local_app_data.join("Google").join("Chrome").join("User Data")),
+        (Browser::Chromium, local_app_data.join("Chromium").join("User Data")),
+        (Browser::Brave, local_app_data.join("BraveSoftware").join("Brave-Browser").join("User Data")),
+        (Browser::Edge, local_app_data.join("Microsoft").join("Edge").join("User Data")),
+        (Browser::Vivaldi, local_app_data.join("Vivaldi").join("User Data")),
+    ]
+}
+
+// names registered in "App Paths", which is what "start" command uses
+#[cfg(target_os = "windows")]
+fn browser_app(browser: Browser) -> &'static str {
+    match browser {
+        Browser::Firefox => "firefox",
+        Browser::Chrome => "chrome",
+        Browser::Chromium => "chromium",
+        Browser::Brave => "brave",
+        Browser::Edge => "msedge",
+        Browser::Vivaldi => "vivaldi",
+    }
+}
+
+#[cfg(all(not(target_os = "linux"), not(target_os = "macos"), not(target_os = "windows")))]
+fn firefox_profile_dirs(_home_dir: &Path) -> Vec<PathBuf> {
+    vec![]
+}
+
+#[cfg(all(not(target_os = "linux"), not(target_os = "macos"), not(target_os = "windows")))]
+fn chromium_user_data_dirs(_home_dir: &Path) -> Vec<(Browser, PathBuf)> {
+    vec![]
+}
+
+#[cfg(all(not(target_os = "linux"), not(target_os = "macos"), not(target_os = "windows")))]
+fn browser_app(_browser: Browser) -> &'static str {
+    ""
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{anyhow, Context};
+use deno_core::op2;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(3);
+const MAX_DEFINITIONS: usize = 3;
+
+// inline view is rendered on every key press, so lookups are cached for the lifetime of plugin runtime
+static API_CACHE: Lazy<Mutex<HashMap<String, Option<DictionaryEntry>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+static WORDLIST_CACHE: Lazy<Mutex<Option<Wordlist>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DictionaryEntry {
+    word: String,
+    phonetic: Option<String>,
+    meanings: Vec<DictionaryMeaning>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DictionaryMeaning {
+    part_of_speech: Option<String>,
+    definitions: Vec<String>,
+}
+
+struct Wordlist {
+    path: PathBuf,
+    modified: SystemTime,
+    entries: HashMap<String, Vec<String>>,
+}
+
+// response format of https://dictionaryapi.dev, other apis need to return the same structure
+#[derive(Deserialize)]
+struct ApiEntry {
+    word: String,
+    phonetic: Option<String>,
+    #[serde(default)]
+    meanings: Vec<ApiMeaning>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiMeaning {
+    part_of_speech: Option<String>,
+    #[serde(default)]
+    definitions: Vec<ApiDefinition>,
+}
+
+#[derive(Deserialize)]
+struct ApiDefinition {
+    definition: String,
+}
+
+// `{word}` in url is replaced with looked up word, otherwise the word is appended to url
+#[op2(async)]
+#[serde]
+pub async fn dictionary_lookup_api(#[string] url: String, #[string] word: String) -> anyhow::Result<Option<DictionaryEntry>> {
+    let word = word.trim().to_lowercase();
+
+    let url = if url.contains("{word}") {
+        url.replace("{word}", &encode_word(&word))
+    } else {
+        format!("{}{}", url, encode_word(&word))
+    };
+
+    if let Some(entry) = API_CACHE.lock().expect("lock is poisoned").get(&url) {
+        return Ok(entry.clone())
+    }
+
+    let entry = tokio::task::spawn_blocking({
+        let url = url.clone();
+        move || fetch_entry(&url)
+    }).await??;
+
+    API_CACHE.lock().expect("lock is poisoned").insert(url, entry.clone());
+
+    Ok(entry)
+}
+
+// wordlist is a text file with `word<TAB>definition` on every line, word can be repeated to add more definitions
+#[op2(async)]
+#[serde]
+pub async fn dictionary_lookup_wordlist(#[string] path: String, #[string] word: String) -> anyhow::Result<Option<DictionaryEntry>> {
+    let word = word.trim().to_lowercase();
+
+    tokio::task::spawn_blocking(move || {
+        let path = PathBuf::from(path);
+
+        let modified = std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .with_context(|| format!("unable to read wordlist file: {:?}", path))?;
+
+        let mut cache = WORDLIST_CACHE.lock().expect("lock is poisoned");
+
+        let up_to_date = matches!(&*cache, Some(wordlist) if wordlist.path == path && wordlist.modified == modified);
+
+        if !up_to_date {
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("unable to read wordlist file: {:?}", path))?;
+
+            *cache = Some(Wordlist {
+                path,
+                modified,
+                entries: parse_wordlist(&content),
+            });
+        }
+
+        let entry = cache.as_ref()
+            .and_then(|wordlist| wordlist.entries.get(&word))
+            .map(|definitions| DictionaryEntry {
+                word: word.clone(),
+                phonetic: None,
+                meanings: vec![DictionaryMeaning {
+                    part_of_speech: None,
+                    definitions: definitions.iter().take(MAX_DEFINITIONS).cloned().collect(),
+                }],
+            });
+
+        Ok(entry)
+    }).await?
+}
+
+fn parse_wordlist(content: &str) -> HashMap<String, Vec<String>> {
+    let mut entries: HashMap<String, Vec<String>> = HashMap::new();
+
+    for line in content.lines() {
+        if line.starts_with('#') {
+            continue
+        }
+
+        let Some((word, definition)) = line.split_once('\t') else {
+            continue
+        };
+
+        let word = word.trim().to_lowercase();
+        let definition = definition.trim();
+
+        if word.is_empty() || definition.is_empty() {
+            continue
+        }
+
+        entries.entry(word)
+            .or_default()
+            .push(definition.to_string());
+    }
+
+    entries
+}
+
+// blocking, unknown word is expected to be responded to with 404
+fn fetch_entry(url: &str) -> anyhow::Result<Option<DictionaryEntry>> {
+    let response = match ureq::get(url).timeout(REQUEST_TIMEOUT).call() {
+        Ok(response) => response,
+        Err(ureq::Error::Status(404, _)) => return Ok(None),
+        Err(err) => return Err(anyhow!("unable to fetch definition: {}", err)),
+    };
+
+    let entries: Vec<ApiEntry> = serde_json::from_str(&response.into_string()?)
+        .context("unable to parse definition")?;
+
+    let entry = entries.into_iter()
+        .next()
+        .map(|entry| DictionaryEntry {
+            word: entry.word,
+            phonetic: entry.phonetic,
+            meanings: entry.meanings
+                .into_iter()
+                .map(|meaning| DictionaryMeaning {
+                    part_of_speech: meaning.part_of_speech,
+                    definitions: meaning.definitions
+                        .into_iter()
+                        .take(MAX_DEFINITIONS)
+                        .map(|definition| definition.definition)
+                        .collect(),
+                })
+                .filter(|meaning| !meaning.definitions.is_empty())
+                .collect(),
+        });
+
+    Ok(entry)
+}
+
+fn encode_word(word: &str) -> String {
+    percent_encoding::utf8_percent_encode(word, percent_encoding::NON_ALPHANUMERIC).to_string()
+}
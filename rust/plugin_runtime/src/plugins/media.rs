@@ -0,0 +1,88 @@
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+use base64::prelude::BASE64_STANDARD;
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+use base64::Engine;
+use deno_core::op2;
+use serde::{Deserialize, Serialize};
+use tokio::task::spawn_blocking;
+
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "macos")]
+mod macos;
+
+#[derive(Debug, Serialize)]
+pub struct NowPlaying {
+    player: String,
+    status: PlaybackStatus,
+    title: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+    // http(s) or data url, local files are converted to data urls so they can go through image pipeline
+    artwork: Option<String>,
+    can_go_next: bool,
+    can_go_previous: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaybackStatus {
+    Playing,
+    Paused,
+    Stopped,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaControlAction {
+    PlayPause,
+    Next,
+    Previous,
+}
+
+// returns currently playing player, or the first paused one if nothing is playing
+#[op2(async)]
+#[serde]
+pub async fn media_now_playing() -> anyhow::Result<Option<NowPlaying>> {
+    spawn_blocking(|| now_playing()).await?
+}
+
+#[op2(async)]
+pub async fn media_control(#[serde] action: MediaControlAction) -> anyhow::Result<()> {
+    spawn_blocking(move || control(action)).await?
+}
+
+#[cfg(target_os = "linux")]
+fn now_playing() -> anyhow::Result<Option<NowPlaying>> {
+    linux::now_playing()
+}
+
+#[cfg(target_os = "linux")]
+fn control(action: MediaControlAction) -> anyhow::Result<()> {
+    linux::control(action)
+}
+
+#[cfg(target_os = "macos")]
+fn now_playing() -> anyhow::Result<Option<NowPlaying>> {
+    macos::now_playing()
+}
+
+#[cfg(target_os = "macos")]
+fn control(action: MediaControlAction) -> anyhow::Result<()> {
+    macos::control(action)
+}
+
+#[cfg(target_os = "windows")]
+fn now_playing() -> anyhow::Result<Option<NowPlaying>> {
+    Ok(None)
+}
+
+#[cfg(target_os = "windows")]
+fn control(_action: MediaControlAction) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!("media controls are not supported on windows"))
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn artwork_data_url(bytes: &[u8], mime_type: &str) -> String {
+    format!("data:{};base64,{}", mime_type, BASE64_STANDARD.encode(bytes))
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context};
+use url::Url;
+use zbus::blocking::fdo::DBusProxy;
+use zbus::blocking::{Connection, Proxy};
+use zbus::zvariant::{OwnedValue, Value};
+
+use crate::plugins::media::{artwork_data_url, MediaControlAction, NowPlaying, PlaybackStatus};
+
+const MPRIS_BUS_NAME_PREFIX: &str = "org.mpris.MediaPlayer2.";
+const MPRIS_OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+const MPRIS_PLAYER_INTERFACE: &str = "org.mpris.MediaPlayer2.Player";
+
+pub fn now_playing() -> anyhow::Result<Option<NowPlaying>> {
+    let connection = Connection::session()
+        .context("unable to connect to dbus session bus")?;
+
+    let Some((bus_name, proxy, status)) = active_player(&connection)? else {
+        return Ok(None)
+    };
+
+    let metadata: HashMap<String, OwnedValue> = proxy.get_property("Metadata")
+        .unwrap_or_default();
+
+    let artwork = metadata_string(&metadata, "mpris:artUrl")
+        .and_then(|url| artwork_url(&url));
+
+    Ok(Some(NowPlaying {
+        player: bus_name.trim_start_matches(MPRIS_BUS_NAME_PREFIX).to_string(),
+        status,
+        title: metadata_string(&metadata, "xesam:title"),
+        artist: metadata_string(&metadata, "xesam:artist"),
+        album: metadata_string(&metadata, "xesam:album"),
+        artwork,
+        can_go_next: proxy.get_property("CanGoNext").unwrap_or(false),
+        can_go_previous: proxy.get_property("CanGoPrevious").unwrap_or(false),
+    }))
+}
+
+pub fn control(action: MediaControlAction) -> anyhow::Result<()> {
+    let connection = Connection::session()
+        .context("unable to connect to dbus session bus")?;
+
+    let (_, proxy, _) = active_player(&connection)?
+        .ok_or_else(|| anyhow!("no media player is running"))?;
+
+    let method = match action {
+        MediaControlAction::PlayPause => "PlayPause",
+        MediaControlAction::Next => "Next",
+        MediaControlAction::Previous => "Previous",
+    };
+
+    proxy.call_method(method, &())
+        .with_context(|| format!("unable to call {} on media player", method))?;
+
+    Ok(())
+}
+
+// player that is playing right now is preferred over paused ones
+fn active_player(connection: &Connection) -> anyhow::Result<Option<(String, Proxy<'static>, PlaybackStatus)>> {
+    let dbus = DBusProxy::new(connection)?;
+
+    let mut players = vec![];
+
+    for name in dbus.list_names()? {
+        let name = name.to_string();
+
+        if !name.starts_with(MPRIS_BUS_NAME_PREFIX) {
+            continue
+        }
+
+        let proxy = Proxy::new(connection, name.clone(), MPRIS_OBJECT_PATH, MPRIS_PLAYER_INTERFACE)?;
+
+        let status = match proxy.get_property::<String>("PlaybackStatus") {
+            Ok(status) if status == "Playing" => PlaybackStatus::Playing,
+            Ok(status) if status == "Paused" => PlaybackStatus::Paused,
+            Ok(_) => PlaybackStatus::Stopped,
+            Err(err) => {
+                tracing::debug!("unable to get playback status of media player {}: {:?}", name, err);
+                continue
+            }
+        };
+
+        players.push((name, proxy, status));
+    }
+
+    let player_index = players.iter()
+        .position(|(_, _, status)| *status == PlaybackStatus::Playing)
+        .or_else(|| players.iter().position(|(_, _, status)| *status == PlaybackStatus::Paused))
+        .or_else(|| if players.is_empty() { None } else { Some(0) });
+
+    Ok(player_index.map(|index| players.swap_remove(index)))
+}
+
+// artists are a list of strings, everything else is a single string
+fn metadata_string(metadata: &HashMap<String, OwnedValue>, key: &str) -> Option<String> {
+    let value: &Value = metadata.get(key)?;
+
+    let value = match value {
+        Value::Str(value) => value.to_string(),
+        Value::Array(values) => {
+            values.iter()
+                .filter_map(|value| match value {
+                    Value::Str(value) => Some(value.to_string()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+        _ => return None,
+    };
+
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn artwork_url(url: &str) -> Option<String> {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        return Some(url.to_string())
+    }
+
+    let path: PathBuf = Url::parse(url)
+        .ok()?
+        .to_file_path()
+        .ok()?;
+
+    let mime_type = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        // players often cache artwork without extension, format is detected when image is decoded anyway
+        _ => "application/octet-stream",
+    };
+
+    match std::fs::read(&path) {
+        Ok(bytes) => Some(artwork_data_url(&bytes, mime_type)),
+        Err(err) => {
+            tracing::debug!("unable to read artwork file {:?}: {:?}", path, err);
+            None
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::ffi::{c_void, CString};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use block2::{Block, RcBlock};
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, Bool};
+use objc2::{msg_send, ClassType};
+use objc2_foundation::{NSData, NSDictionary, NSNumber, NSString};
+
+use crate::plugins::media::{artwork_data_url, MediaControlAction, NowPlaying, PlaybackStatus};
+
+// private framework, there is no public api to get information about media played by other applications
+const MEDIA_REMOTE_PATH: &str = "/System/Library/PrivateFrameworks/MediaRemote.framework/MediaRemote";
+
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(1);
+
+// MRMediaRemoteCommand
+const COMMAND_TOGGLE_PLAY_PAUSE: u32 = 2;
+const COMMAND_NEXT_TRACK: u32 = 4;
+const COMMAND_PREVIOUS_TRACK: u32 = 5;
+
+type GetNowPlayingInfoFn = unsafe extern "C" fn(queue: *mut c_void, block: &Block<dyn Fn(*mut NSDictionary<NSString, AnyObject>)>);
+type SendCommandFn = unsafe extern "C" fn(command: u32, options: *mut c_void) -> Bool;
+
+extern "C" {
+    fn dispatch_get_global_queue(identifier: isize, flags: usize) -> *mut c_void;
+}
+
+struct NowPlayingInfo {
+    title: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+    artwork: Option<String>,
+    playing: bool,
+}
+
+pub fn now_playing() -> anyhow::Result<Option<NowPlaying>> {
+    let get_now_playing_info: GetNowPlayingInfoFn = unsafe { std::mem::transmute(media_remote_symbol("MRMediaRemoteGetNowPlayingInfo")?) };
+
+    let (sender, receiver) = mpsc::channel();
+
+    let block = RcBlock::new(move |info: *mut NSDictionary<NSString, AnyObject>| {
+        let info = unsafe { Retained::retain(info) };
+
+        let _ = sender.send(info.map(|info| read_now_playing_info(&info)));
+    });
+
+    unsafe {
+        get_now_playing_info(dispatch_get_global_queue(0, 0), &block);
+    }
+
+    let info = match receiver.recv_timeout(RESPONSE_TIMEOUT) {
+        Ok(Some(info)) => info,
+        Ok(None) | Err(_) => return Ok(None),
+    };
+
+    if info.title.is_none() && info.artist.is_none() {
+        return Ok(None)
+    }
+
+    Ok(Some(NowPlaying {
+        // media remote doesn't tell which application is playing without additional calls
+        player: "Now Playing".to_string(),
+        status: if info.playing { PlaybackStatus::Playing } else { PlaybackStatus::Paused },
+        title: info.title,
+        artist: info.artist,
+        album: info.album,
+        artwork: info.artwork,
+        can_go_next: true,
+        can_go_previous: true,
+    }))
+}
+
+pub fn control(action: MediaControlAction) -> anyhow::Result<()> {
+    let send_command: SendCommandFn = unsafe { std::mem::transmute(media_remote_symbol("MRMediaRemoteSendCommand")?) };
+
+    let command = match action {
+        MediaControlAction::PlayPause => COMMAND_TOGGLE_PLAY_PAUSE,
+        MediaControlAction::Next => COMMAND_NEXT_TRACK,
+        MediaControlAction::Previous => COMMAND_PREVIOUS_TRACK,
+    };
+
+    let success = unsafe { send_command(command, std::ptr::null_mut()) };
+
+    if !success.as_bool() {
+        return Err(anyhow!("media command was not handled"))
+    }
+
+    Ok(())
+}
+
+fn media_remote_symbol(name: &str) -> anyhow::Result<*mut c_void> {
+    let path = CString::new(MEDIA_REMOTE_PATH)?;
+    let name = CString::new(name)?;
+
+    unsafe {
+        // framework is never unloaded, dlopen returns the same handle on subsequent calls
+        let handle = libc::dlopen(path.as_ptr(), libc::RTLD_LAZY);
+        if handle.is_null() {
+            return Err(anyhow!("unable to load MediaRemote framework"))
+        }
+
+        let symbol = libc::dlsym(handle, name.as_ptr());
+        if symbol.is_null() {
+            return Err(anyhow!("unable to find {:?} in MediaRemote framework", name))
+        }
+
+        Ok(symbol)
+    }
+}
+
+fn read_now_playing_info(info: &NSDictionary<NSString, AnyObject>) -> NowPlayingInfo {
+    let playback_rate = info_value::<NSNumber>(info, "kMRMediaRemoteNowPlayingInfoPlaybackRate")
+        .map(|rate| rate.as_f64())
+        .unwrap_or(0.0);
+
+    let artwork = info_value::<NSData>(info, "kMRMediaRemoteNowPlayingInfoArtworkData")
+        .map(|data| artwork_data_url(data.bytes(), "application/octet-stream"));
+
+    NowPlayingInfo {
+        title: info_string(info, "kMRMediaRemoteNowPlayingInfoTitle"),
+        artist: info_string(info, "kMRMediaRemoteNowPlayingInfoArtist"),
+        album: info_string(info, "kMRMediaRemoteNowPlayingInfoAlbum"),
+        artwork,
+        playing: playback_rate > 0.0,
+    }
+}
+
+fn info_string(info: &NSDictionary<NSString, AnyObject>, key: &str) -> Option<String> {
+    info_value::<NSString>(info, key)
+        .map(|value| value.to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn info_value<T: ClassType>(info: &NSDictionary<NSString, AnyObject>, key: &str) -> Option<Retained<T>> {
+    let value = info.objectForKey(&NSString::from_str(key))?;
+
+    let is_kind_of: bool = unsafe { msg_send![&*value, isKindOfClass: T::class()] };
+
+    if is_kind_of {
+        Some(unsafe { Retained::cast(value) })
+    } else {
+        None
+    }
+}
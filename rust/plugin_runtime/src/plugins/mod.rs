@@ -1,3 +1,14 @@
 pub mod applications;
+pub mod bookmarks;
+pub mod dictionary;
+pub mod media;
 pub mod numbat;
+pub mod process_manager;
+pub mod processes;
+pub mod quick_open;
+pub mod script_commands;
 pub mod settings;
+pub mod system_info;
+pub mod totp;
+pub mod trash;
+pub mod web_search;
@@ -1,5 +1,6 @@
-use anyhow::anyhow;
+use anyhow::{anyhow, Context as _};
 use deno_core::{op2, OpState};
+use gauntlet_common::dirs::Dirs;
 use numbat::markup::{Formatter, PlainTextFormatter};
 use numbat::module_importer::BuiltinModuleImporter;
 use numbat::pretty_print::PrettyPrint;
@@ -8,6 +9,10 @@ use numbat::{Context, InterpreterResult};
 use serde::Serialize;
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::Duration;
+
+const EXCHANGE_RATES_URL: &str = "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-daily.xml";
+const EXCHANGE_RATES_TIMEOUT: Duration = Duration::from_secs(5);
 
 #[derive(Clone)]
 pub struct NumbatContext(Rc<RefCell<Context>>);
@@ -19,7 +24,7 @@ impl NumbatContext {
         context.load_currency_module_on_demand(true);
 
         if cfg!(feature = "release") {
-            Context::prefetch_exchange_rates();
+            load_exchange_rates();
         }
 
         let _ = context.interpret("use prelude", CodeSource::Internal);
@@ -28,6 +33,42 @@ impl NumbatContext {
     }
 }
 
+// rates are fetched when plugin runtime is started, last fetched rates are used if that fails, e.g. when offline
+fn load_exchange_rates() {
+    let cache_file = Dirs::new().exchange_rates_cache_file();
+
+    match fetch_exchange_rates() {
+        Ok(rates) => {
+            Context::set_exchange_rates(&rates);
+
+            let result = std::fs::create_dir_all(cache_file.parent().expect("cache file should have parent"))
+                .and_then(|_| std::fs::write(&cache_file, &rates));
+
+            if let Err(err) = result {
+                tracing::warn!("unable to cache exchange rates {:?}: {:?}", cache_file, err);
+            }
+        }
+        Err(err) => {
+            tracing::warn!("unable to fetch exchange rates, using cached ones: {:?}", err);
+
+            if let Ok(rates) = std::fs::read_to_string(&cache_file) {
+                Context::set_exchange_rates(&rates);
+            }
+        }
+    }
+}
+
+// blocking, rates are returned as xml which numbat parses itself
+fn fetch_exchange_rates() -> anyhow::Result<String> {
+    let rates = ureq::get(EXCHANGE_RATES_URL)
+        .timeout(EXCHANGE_RATES_TIMEOUT)
+        .call()
+        .context("unable to fetch exchange rates")?
+        .into_string()?;
+
+    Ok(rates)
+}
+
 #[derive(Debug, Serialize)]
 struct NumbatResult {
     left: String,
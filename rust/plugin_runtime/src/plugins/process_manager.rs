@@ -0,0 +1,204 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use anyhow::anyhow;
+use deno_core::{op2, OpState};
+use serde::Serialize;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, Signal, System, UpdateKind, Users, MINIMUM_CPU_UPDATE_INTERVAL};
+use tokio::task::spawn_blocking;
+
+// cpu usage is calculated from difference between two refreshes,
+// so system is kept between calls and each call samples usage since the previous one
+#[derive(Clone)]
+pub struct ProcessMonitor {
+    inner: Arc<Mutex<ProcessMonitorInner>>,
+}
+
+struct ProcessMonitorInner {
+    system: System,
+    users: Users,
+    sampled: bool,
+}
+
+impl Default for ProcessMonitor {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(ProcessMonitorInner {
+                system: System::new(),
+                users: Users::new(),
+                sampled: false,
+            }))
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProcessInfo {
+    pid: u32,
+    parent_pid: Option<u32>,
+    name: String,
+    command: String,
+    user: Option<String>,
+    // percent of total cpu time of all cores
+    cpu_usage: f32,
+    // bytes
+    memory: u64,
+    // not available on windows
+    nice: Option<i32>,
+}
+
+#[op2(async)]
+#[serde]
+pub async fn process_list(state: Rc<RefCell<OpState>>) -> anyhow::Result<Vec<ProcessInfo>> {
+    let monitor = {
+        let state = state.borrow();
+
+        state.borrow::<ProcessMonitor>().clone()
+    };
+
+    spawn_blocking(move || {
+        let mut monitor = monitor.inner.lock().map_err(|_| anyhow!("process monitor lock is poisoned"))?;
+
+        let refresh_kind = ProcessRefreshKind::nothing()
+            .with_cpu()
+            .with_memory()
+            .with_cmd(UpdateKind::OnlyIfNotSet)
+            .with_user(UpdateKind::OnlyIfNotSet);
+
+        // first sample doesn't have anything to compare cpu usage to
+        if !monitor.sampled {
+            monitor.system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+            monitor.users.refresh();
+            monitor.sampled = true;
+
+            std::thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+        }
+
+        monitor.system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+
+        let cpu_count = std::thread::available_parallelism()
+            .map(|count| count.get())
+            .unwrap_or(1) as f32;
+
+        let ProcessMonitorInner { system, users, .. } = &mut *monitor;
+
+        let processes = system.processes()
+            .iter()
+            // threads are listed as processes on linux
+            .filter(|(_, process)| process.thread_kind().is_none())
+            .map(|(pid, process)| {
+                let command = process.cmd()
+                    .iter()
+                    .map(|arg| arg.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                let user = process.user_id()
+                    .and_then(|user_id| users.get_user_by_id(user_id))
+                    .map(|user| user.name().to_string());
+
+                ProcessInfo {
+                    pid: pid.as_u32(),
+                    parent_pid: process.parent().map(|pid| pid.as_u32()),
+                    name: process.name().to_string_lossy().to_string(),
+                    command,
+                    user,
+                    cpu_usage: process.cpu_usage() / cpu_count,
+                    memory: process.memory(),
+                    nice: process_nice(pid.as_u32()),
+                }
+            })
+            .collect();
+
+        Ok(processes)
+    }).await?
+}
+
+#[op2(fast)]
+pub fn process_terminate(pid: u32) -> anyhow::Result<()> {
+    send_signal(pid, false)
+}
+
+#[op2(fast)]
+pub fn process_force_kill(pid: u32) -> anyhow::Result<()> {
+    send_signal(pid, true)
+}
+
+// lower value means higher priority, only privileged users can decrease it
+#[op2(fast)]
+pub fn process_renice(pid: u32, nice: i32) -> anyhow::Result<()> {
+    set_process_nice(pid, nice.clamp(-20, 19))
+}
+
+fn send_signal(pid: u32, force: bool) -> anyhow::Result<()> {
+    let mut system = System::new();
+
+    let sysinfo_pid = Pid::from_u32(pid);
+
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[sysinfo_pid]), true, ProcessRefreshKind::nothing());
+
+    let process = system.process(sysinfo_pid)
+        .ok_or(anyhow!("process {} doesn't exist", pid))?;
+
+    // windows doesn't have graceful termination signal
+    let sent = if force {
+        process.kill()
+    } else {
+        process.kill_with(Signal::Term)
+            .unwrap_or_else(|| process.kill())
+    };
+
+    if sent {
+        Ok(())
+    } else {
+        Err(anyhow!("unable to send signal to process {}, insufficient permissions", pid))
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn process_nice(pid: u32) -> Option<i32> {
+    // -1 is valid priority, so errno has to be checked to detect error
+    unsafe {
+        *errno() = 0;
+
+        let nice = libc::getpriority(libc::PRIO_PROCESS, pid as libc::id_t);
+
+        if nice == -1 && *errno() != 0 {
+            None
+        } else {
+            Some(nice)
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn set_process_nice(pid: u32, nice: i32) -> anyhow::Result<()> {
+    let result = unsafe { libc::setpriority(libc::PRIO_PROCESS, pid as libc::id_t, nice) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(anyhow!("unable to change priority of process {}: {}", pid, std::io::Error::last_os_error()))
+    }
+}
+
+#[cfg(target_os = "linux")]
+unsafe fn errno() -> *mut libc::c_int {
+    libc::__errno_location()
+}
+
+#[cfg(target_os = "macos")]
+unsafe fn errno() -> *mut libc::c_int {
+    libc::__error()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn process_nice(_pid: u32) -> Option<i32> {
+    None
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn set_process_nice(_pid: u32, _nice: i32) -> anyhow::Result<()> {
+    Err(anyhow!("changing process priority is not supported on this system"))
+}
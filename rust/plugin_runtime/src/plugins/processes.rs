@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+use deno_core::op2;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+use tokio::task::spawn_blocking;
+
+#[cfg(target_os = "linux")]
+mod x11;
+
+// shared by features that need to know what is currently running on the system
+// e.g. indication of running applications in search results
+
+#[derive(Debug, Clone)]
+pub struct RunningProcess {
+    pub pid: u32,
+    pub executable: PathBuf,
+}
+
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone)]
+pub struct OpenWindow {
+    pub id: u32,
+    pub pid: Option<u32>,
+    pub title: Option<String>,
+    pub class: Option<String>,
+}
+
+pub fn running_processes() -> Vec<RunningProcess> {
+    let mut system = System::new();
+
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::Always),
+    );
+
+    system.processes()
+        .iter()
+        .filter_map(|(pid, process)| {
+            // not available for processes of other users and kernel threads
+            let executable = process.exe()?;
+
+            Some(RunningProcess {
+                pid: pid.as_u32(),
+                executable: executable.to_path_buf(),
+            })
+        })
+        .collect()
+}
+
+// only works in x11 session, wayland doesn't allow clients to list or activate windows of other clients
+#[cfg(target_os = "linux")]
+pub fn open_windows() -> anyhow::Result<Vec<OpenWindow>> {
+    x11::open_windows()
+}
+
+#[cfg(target_os = "linux")]
+pub fn focus_window(window_id: u32) -> anyhow::Result<()> {
+    x11::focus_window(window_id)
+}
+
+#[op2(async)]
+#[serde]
+pub async fn running_executable_paths() -> anyhow::Result<Vec<String>> {
+    let processes = spawn_blocking(|| running_processes()).await?;
+
+    let mut paths: Vec<_> = processes
+        .into_iter()
+        .filter_map(|process| process.executable.to_str().map(|path| path.to_string()))
+        .collect();
+
+    paths.sort();
+    paths.dedup();
+
+    Ok(paths)
+}
@@ -0,0 +1,89 @@
+use anyhow::Context;
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{Atom, AtomEnum, ClientMessageEvent, ConnectionExt as _, EventMask, Window};
+use x11rb::rust_connection::RustConnection;
+use x11rb::CURRENT_TIME;
+
+use crate::plugins::processes::OpenWindow;
+
+pub fn open_windows() -> anyhow::Result<Vec<OpenWindow>> {
+    let (conn, screen_num) = x11rb::connect(None)?;
+
+    let root = conn.setup().roots[screen_num].root;
+
+    let net_client_list = intern_atom(&conn, b"_NET_CLIENT_LIST")?;
+    let net_wm_pid = intern_atom(&conn, b"_NET_WM_PID")?;
+    let net_wm_name = intern_atom(&conn, b"_NET_WM_NAME")?;
+    let utf8_string = intern_atom(&conn, b"UTF8_STRING")?;
+
+    let windows: Vec<Window> = conn.get_property(false, root, net_client_list, AtomEnum::WINDOW, 0, u32::MAX)?
+        .reply()
+        .context("unable to get _NET_CLIENT_LIST")?
+        .value32()
+        .map(|windows| windows.collect())
+        .unwrap_or_default();
+
+    let read_window = |window: Window| -> anyhow::Result<OpenWindow> {
+        let pid = conn.get_property(false, window, net_wm_pid, AtomEnum::CARDINAL, 0, 1)?
+            .reply()?
+            .value32()
+            .and_then(|mut value| value.next());
+
+        let title = conn.get_property(false, window, net_wm_name, utf8_string, 0, u32::MAX)?
+            .reply()?
+            .value;
+
+        let title = String::from_utf8(title).ok()
+            .filter(|title| !title.is_empty());
+
+        // WM_CLASS contains two null terminated strings, instance and class names
+        let class = conn.get_property(false, window, AtomEnum::WM_CLASS, AtomEnum::STRING, 0, u32::MAX)?
+            .reply()?
+            .value;
+
+        let class = class.split(|byte| *byte == 0)
+            .filter(|part| !part.is_empty())
+            .nth(1)
+            .and_then(|class| String::from_utf8(class.to_vec()).ok());
+
+        Ok(OpenWindow {
+            id: window,
+            pid,
+            title,
+            class,
+        })
+    };
+
+    // window can be closed while list is being read
+    let windows = windows.into_iter()
+        .filter_map(|window| read_window(window).ok())
+        .collect();
+
+    Ok(windows)
+}
+
+pub fn focus_window(window_id: u32) -> anyhow::Result<()> {
+    let (conn, screen_num) = x11rb::connect(None)?;
+
+    let root = conn.setup().roots[screen_num].root;
+
+    let net_active_window = intern_atom(&conn, b"_NET_ACTIVE_WINDOW")?;
+
+    // source indication 2 means request comes from pager, it is not subject to focus stealing prevention
+    let event = ClientMessageEvent::new(32, window_id, net_active_window, [2, CURRENT_TIME, 0, 0, 0]);
+
+    conn.send_event(false, root, EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY, event)?;
+
+    conn.flush()?;
+
+    Ok(())
+}
+
+fn intern_atom(conn: &RustConnection, name: &[u8]) -> anyhow::Result<Atom> {
+    let atom = conn.intern_atom(false, name)?
+        .reply()
+        .with_context(|| format!("unable to get {} atom", String::from_utf8_lossy(name)))?
+        .atom;
+
+    Ok(atom)
+}
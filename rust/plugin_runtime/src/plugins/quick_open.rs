@@ -0,0 +1,763 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use anyhow::{anyhow, Context};
+use deno_core::{op2, OpState};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{Connection, SqliteConnection};
+use tokio::task::spawn_blocking;
+
+use crate::plugin_data::PluginData;
+use crate::plugins::applications::spawn_detached;
+
+// ssh config can include files which include other files
+const SSH_CONFIG_MAX_INCLUDE_DEPTH: usize = 16;
+
+static JETBRAINS_ENTRY_KEY: Lazy<Regex> = Lazy::new(|| Regex::new(r#"<entry key="([^"]+)""#).unwrap());
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SshHost {
+    host: String,
+    port: Option<u16>,
+    hostname: Option<String>,
+    user: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecentProject {
+    name: String,
+    path: String,
+    // name of the editor application
+    editor: String,
+    // command line launcher of the editor
+    launcher: String,
+}
+
+#[op2(async)]
+#[serde]
+pub async fn ssh_hosts(state: Rc<RefCell<OpState>>) -> anyhow::Result<Vec<SshHost>> {
+    let home_dir = home_dir(&state);
+
+    spawn_blocking(move || {
+        let ssh_dir = home_dir.join(".ssh");
+
+        let mut hosts = vec![];
+
+        read_ssh_config(&ssh_dir, &ssh_dir.join("config"), 0, &mut hosts);
+
+        // hosts from config take precedence, known_hosts usually contains the same host under its real name
+        let mut known: HashSet<String> = hosts.iter()
+            .flat_map(|host: &SshHost| [Some(host.host.clone()), host.hostname.clone()])
+            .flatten()
+            .collect();
+
+        for host in read_known_hosts(&ssh_dir.join("known_hosts")) {
+            if known.insert(host.host.clone()) {
+                hosts.push(host)
+            }
+        }
+
+        Ok(hosts)
+    }).await?
+}
+
+// terminal command may contain "{command}" placeholder which is replaced with ssh command,
+// if it doesn't, ssh command is appended to the end
+#[op2]
+pub fn open_ssh_host(#[serde] host: SshHost, #[serde] terminal_command: Option<String>) -> anyhow::Result<()> {
+    let mut ssh_command = vec!["ssh".to_string()];
+
+    if let Some(port) = host.port {
+        ssh_command.push("-p".to_string());
+        ssh_command.push(port.to_string());
+    }
+
+    ssh_command.push(host.host.clone());
+
+    match terminal_command.filter(|command| !command.trim().is_empty()) {
+        Some(terminal_command) => run_command_template(&terminal_command, "{command}", &ssh_command),
+        None => open_in_default_terminal(&host, &ssh_command),
+    }
+}
+
+#[op2(async)]
+#[serde]
+pub async fn recent_projects(state: Rc<RefCell<OpState>>) -> anyhow::Result<Vec<RecentProject>> {
+    let home_dir = home_dir(&state);
+
+    let mut projects = vec![];
+
+    for (editor, launcher, dir) in vscode_config_dirs(&home_dir) {
+        let state_file = dir.join("User").join("globalStorage").join("state.vscdb");
+
+        if !state_file.is_file() {
+            continue
+        }
+
+        match read_vscode_recent_projects(&state_file).await {
+            Ok(paths) => {
+                projects.extend(paths.into_iter().map(|path| project(path, editor, launcher)))
+            }
+            Err(err) => {
+                tracing::warn!("unable to read recent projects of {}: {:?}", editor, err)
+            }
+        }
+    }
+
+    let jetbrains_projects = spawn_blocking(move || read_jetbrains_recent_projects(&home_dir)).await?;
+
+    projects.extend(jetbrains_projects);
+
+    let mut seen = HashSet::new();
+
+    let projects = projects.into_iter()
+        .filter(|project| Path::new(&project.path).exists())
+        .filter(|project| seen.insert((project.editor.clone(), project.path.clone())))
+        .collect();
+
+    Ok(projects)
+}
+
+// editor command may contain "{path}" placeholder which is replaced with project path,
+// if it doesn't, path is appended to the end
+#[op2]
+pub fn open_recent_project(#[serde] project: RecentProject, #[serde] editor_command: Option<String>) -> anyhow::Result<()> {
+    match editor_command.filter(|command| !command.trim().is_empty()) {
+        Some(editor_command) => run_command_template(&editor_command, "{path}", &[project.path]),
+        None => open_in_editor(&project),
+    }
+}
+
+fn home_dir(state: &Rc<RefCell<OpState>>) -> PathBuf {
+    let state = state.borrow();
+
+    let home_dir = state
+        .borrow::<PluginData>()
+        .home_dir();
+
+    home_dir
+}
+
+fn project(path: String, editor: &str, launcher: &str) -> RecentProject {
+    let name = Path::new(&path)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(&path)
+        .to_string();
+
+    RecentProject {
+        name,
+        path,
+        editor: editor.to_string(),
+        launcher: launcher.to_string(),
+    }
+}
+
+fn read_ssh_config(ssh_dir: &Path, file: &Path, depth: usize, hosts: &mut Vec<SshHost>) {
+    if depth > SSH_CONFIG_MAX_INCLUDE_DEPTH {
+        tracing::warn!("ssh config include depth limit reached at {:?}", file);
+        return
+    }
+
+    let Ok(content) = std::fs::read_to_string(file) else {
+        return
+    };
+
+    // index of first host of the current "Host" block
+    let mut block_start = hosts.len();
+
+    for line in content.lines() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue
+        }
+
+        // keyword and arguments are separated by whitespace or optional "="
+        let Some((keyword, arguments)) = line.split_once(|char: char| char.is_whitespace() || char == '=') else {
+            continue
+        };
+
+        let arguments = arguments.trim_start_matches(|char: char| char.is_whitespace() || char == '=').trim();
+
+        match keyword.to_lowercase().as_str() {
+            "host" => {
+                block_start = hosts.len();
+
+                // patterns are not hosts that can be connected to
+                for host in arguments.split_whitespace() {
+                    if host.contains(['*', '?', '!']) {
+                        continue
+                    }
+
+                    hosts.push(SshHost {
+                        host: host.to_string(),
+                        port: None,
+                        hostname: None,
+                        user: None,
+                    })
+                }
+            }
+            "match" => {
+                block_start = hosts.len();
+            }
+            // first obtained value is used by ssh
+            "hostname" => {
+                for host in &mut hosts[block_start..] {
+                    host.hostname.get_or_insert_with(|| arguments.to_string());
+                }
+            }
+            "user" => {
+                for host in &mut hosts[block_start..] {
+                    host.user.get_or_insert_with(|| arguments.to_string());
+                }
+            }
+            "port" => {
+                for host in &mut hosts[block_start..] {
+                    if host.port.is_none() {
+                        host.port = arguments.parse().ok();
+                    }
+                }
+            }
+            "include" => {
+                for pattern in arguments.split_whitespace() {
+                    for included_file in resolve_include(ssh_dir, pattern) {
+                        read_ssh_config(ssh_dir, &included_file, depth + 1, hosts)
+                    }
+                }
+
+                block_start = hosts.len();
+            }
+            _ => {}
+        }
+    }
+}
+
+// relative paths are relative to ~/.ssh, wildcards are supported only in file name
+fn resolve_include(ssh_dir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let pattern = match pattern.strip_prefix("~/") {
+        Some(pattern) => ssh_dir.parent().map(|home_dir| home_dir.join(pattern)).unwrap_or_else(|| PathBuf::from(pattern)),
+        None => ssh_dir.join(pattern),
+    };
+
+    let Some(file_name) = pattern.file_name().and_then(|name| name.to_str()) else {
+        return vec![]
+    };
+
+    if !file_name.contains(['*', '?']) {
+        return vec![pattern]
+    }
+
+    let Some(dir) = pattern.parent() else {
+        return vec![]
+    };
+
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![]
+    };
+
+    let mut files: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| wildcard_match(file_name, name))
+                .unwrap_or(false)
+        })
+        .collect();
+
+    // ssh reads matched files in lexical order
+    files.sort();
+
+    files
+}
+
+fn wildcard_match(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+
+    fn matches(pattern: &[char], value: &[char]) -> bool {
+        match pattern.first() {
+            None => value.is_empty(),
+            Some('*') => (0..=value.len()).any(|skip| matches(&pattern[1..], &value[skip..])),
+            Some('?') => !value.is_empty() && matches(&pattern[1..], &value[1..]),
+            Some(char) => value.first() == Some(char) && matches(&pattern[1..], &value[1..]),
+        }
+    }
+
+    matches(&pattern, &value)
+}
+
+fn read_known_hosts(file: &Path) -> Vec<SshHost> {
+    let Ok(content) = std::fs::read_to_string(file) else {
+        return vec![]
+    };
+
+    let mut hosts = vec![];
+
+    for line in content.lines() {
+        let line = line.trim();
+
+        // revoked keys and certificate authorities are not hosts
+        if line.is_empty() || line.starts_with('#') || line.starts_with('@') {
+            continue
+        }
+
+        let Some(names) = line.split_whitespace().next() else {
+            continue
+        };
+
+        // hashed host names cannot be recovered
+        if names.starts_with("|1|") {
+            continue
+        }
+
+        let names: Vec<(String, Option<u16>)> = names.split(',')
+            .filter(|name| !name.contains(['*', '?', '!']))
+            .map(parse_known_host)
+            .collect();
+
+        // the same line usually contains host name and its ip address, ip is used only if there is nothing else
+        let name = names.iter()
+            .find(|(name, _)| name.parse::<std::net::IpAddr>().is_err())
+            .or(names.first());
+
+        if let Some((name, port)) = name {
+            hosts.push(SshHost {
+                host: name.clone(),
+                port: *port,
+                hostname: None,
+                user: None,
+            })
+        }
+    }
+
+    hosts
+}
+
+// non-standard port is stored as "[host]:port"
+fn parse_known_host(name: &str) -> (String, Option<u16>) {
+    let Some(name) = name.strip_prefix('[') else {
+        return (name.to_string(), None)
+    };
+
+    match name.split_once("]:") {
+        Some((host, port)) => (host.to_string(), port.parse().ok()),
+        None => (name.trim_end_matches(']').to_string(), None),
+    }
+}
+
+async fn read_vscode_recent_projects(state_file: &Path) -> anyhow::Result<Vec<String>> {
+    #[derive(Debug, Deserialize)]
+    struct RecentlyOpened {
+        entries: Vec<RecentlyOpenedEntry>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct RecentlyOpenedEntry {
+        folder_uri: Option<String>,
+        workspace: Option<RecentlyOpenedWorkspace>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct RecentlyOpenedWorkspace {
+        config_path: String,
+    }
+
+    let options = SqliteConnectOptions::new()
+        .filename(state_file)
+        .read_only(true);
+
+    let mut conn = SqliteConnection::connect_with(&options)
+        .await
+        .context("unable to open editor state database")?;
+
+    // language=SQLite
+    let value = sqlx::query_scalar::<_, String>("SELECT value FROM ItemTable WHERE key = 'history.recentlyOpenedPathsList'")
+        .fetch_optional(&mut conn)
+        .await
+        .context("unable to read editor state database")?;
+
+    conn.close().await?;
+
+    let Some(value) = value else {
+        return Ok(vec![])
+    };
+
+    let recently_opened: RecentlyOpened = serde_json::from_str(&value)
+        .context("unexpected format of recently opened list")?;
+
+    // recently opened files and remote folders are not included
+    let paths = recently_opened.entries
+        .into_iter()
+        .filter_map(|entry| entry.folder_uri.or(entry.workspace.map(|workspace| workspace.config_path)))
+        .filter_map(|uri| file_uri_to_path(&uri))
+        .collect();
+
+    Ok(paths)
+}
+
+fn file_uri_to_path(uri: &str) -> Option<String> {
+    let path = uri.strip_prefix("file://")?;
+
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        let escaped = (byte == b'%')
+            .then(|| bytes.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match escaped {
+            Some(escaped) => {
+                decoded.push(escaped);
+                index += 3;
+            }
+            None => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+
+    let path = String::from_utf8(decoded).ok()?;
+
+    // windows paths look like "/c:/Users/..."
+    if cfg!(windows) {
+        Some(path.trim_start_matches('/').replace('/', "\\"))
+    } else {
+        Some(path)
+    }
+}
+
+fn read_jetbrains_recent_projects(home_dir: &Path) -> Vec<RecentProject> {
+    let Some(config_dir) = jetbrains_config_dir(home_dir) else {
+        return vec![]
+    };
+
+    let Ok(entries) = std::fs::read_dir(&config_dir) else {
+        return vec![]
+    };
+
+    let mut product_dirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+
+    // directories are named "<product><version>", newest version goes first
+    product_dirs.sort();
+    product_dirs.reverse();
+
+    let home_dir = home_dir.to_str().unwrap_or_default();
+
+    let mut projects = vec![];
+
+    for product_dir in product_dirs {
+        let Some(dir_name) = product_dir.file_name().and_then(|name| name.to_str()) else {
+            continue
+        };
+
+        let product_name = dir_name.trim_end_matches(|char: char| char.is_ascii_digit() || char == '.');
+
+        let Some((editor, launcher)) = jetbrains_product(product_name) else {
+            continue
+        };
+
+        // rider stores solutions instead of projects
+        for file_name in ["recentProjects.xml", "recentSolutions.xml"] {
+            let Ok(content) = std::fs::read_to_string(product_dir.join("options").join(file_name)) else {
+                continue
+            };
+
+            for captures in JETBRAINS_ENTRY_KEY.captures_iter(&content) {
+                let path = unescape_xml(&captures[1]).replace("$USER_HOME$", home_dir);
+
+                projects.push(project(path, editor, launcher))
+            }
+        }
+    }
+
+    projects
+}
+
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+// launchers are names of shell scripts created by jetbrains toolbox
+fn jetbrains_product(product_name: &str) -> Option<(&'static str, &'static str)> {
+    let product = match product_name {
+        "IntelliJIdea" => ("IntelliJ IDEA", "idea"),
+        "IdeaIC" => ("IntelliJ IDEA CE", "idea"),
+        "PyCharm" => ("PyCharm", "pycharm"),
+        "PyCharmCE" => ("PyCharm CE", "pycharm"),
+        "WebStorm" => ("WebStorm", "webstorm"),
+        "CLion" => ("CLion", "clion"),
+        "GoLand" => ("GoLand", "goland"),
+        "RustRover" => ("RustRover", "rustrover"),
+        "Rider" => ("Rider", "rider"),
+        "PhpStorm" => ("PhpStorm", "phpstorm"),
+        "RubyMine" => ("RubyMine", "rubymine"),
+        "DataGrip" => ("DataGrip", "datagrip"),
+        "DataSpell" => ("DataSpell", "dataspell"),
+        "Aqua" => ("Aqua", "aqua"),
+        _ => return None
+    };
+
+    Some(product)
+}
+
+fn run_command_template(template: &str, placeholder: &str, arguments: &[String]) -> anyhow::Result<()> {
+    let parts = split_command(template);
+
+    let mut command = vec![];
+    let mut replaced = false;
+
+    for part in parts {
+        if part == placeholder {
+            command.extend(arguments.iter().cloned());
+            replaced = true;
+        } else {
+            command.push(part)
+        }
+    }
+
+    if !replaced {
+        command.extend(arguments.iter().cloned());
+    }
+
+    let Some((program, args)) = command.split_first() else {
+        return Err(anyhow!("command is empty"))
+    };
+
+    spawn_detached(program, args)
+        .with_context(|| format!("unable to run command {:?}", command))
+}
+
+// splits command into arguments, single and double quotes can be used for arguments containing whitespace
+fn split_command(command: &str) -> Vec<String> {
+    let mut parts = vec![];
+    let mut current: Option<String> = None;
+    let mut quote = None;
+
+    for char in command.chars() {
+        match (quote, char) {
+            (Some(quote_char), char) if char == quote_char => {
+                quote = None;
+            }
+            (Some(_), char) => {
+                current.get_or_insert_with(String::new).push(char);
+            }
+            (None, '"' | '\'') => {
+                quote = Some(char);
+                current.get_or_insert_with(String::new);
+            }
+            (None, char) if char.is_whitespace() => {
+                if let Some(part) = current.take() {
+                    parts.push(part)
+                }
+            }
+            (None, char) => {
+                current.get_or_insert_with(String::new).push(char);
+            }
+        }
+    }
+
+    if let Some(part) = current {
+        parts.push(part)
+    }
+
+    parts
+}
+
+#[cfg(target_os = "linux")]
+fn vscode_config_dirs(home_dir: &Path) -> Vec<(&'static str, &'static str, PathBuf)> {
+    let config_dir = home_dir.join(".config");
+
+    vec![
+        ("Visual Studio Code", "code", config_dir.join("Code")),
+        ("Visual Studio Code - Insiders", "code-insiders", config_dir.join("Code - Insiders")),
+        ("Code - OSS", "code-oss", config_dir.join("Code - OSS")),
+        ("VSCodium", "codium", config_dir.join("VSCodium")),
+    ]
+}
+
+#[cfg(target_os = "linux")]
+fn jetbrains_config_dir(home_dir: &Path) -> Option<PathBuf> {
+    Some(home_dir.join(".config").join("JetBrains"))
+}
+
+#[cfg(target_os = "linux")]
+fn open_in_default_terminal(_host: &SshHost, ssh_command: &[String]) -> anyhow::Result<()> {
+    // terminal and arguments after which command to run is passed
+    let terminals: [(&str, &[&str]); 9] = [
+        ("x-terminal-emulator", &["-e"]),
+        ("gnome-terminal", &["--"]),
+        ("konsole", &["-e"]),
+        ("xfce4-terminal", &["-x"]),
+        ("alacritty", &["-e"]),
+        ("kitty", &[]),
+        ("foot", &[]),
+        ("wezterm", &["start", "--"]),
+        ("xterm", &["-e"]),
+    ];
+
+    // commonly used to configure preferred terminal by window managers
+    let user_terminal = std::env::var("TERMINAL")
+        .ok()
+        .filter(|terminal| !terminal.is_empty());
+
+    if let Some(terminal) = user_terminal {
+        let args = terminals.iter()
+            .find(|(name, _)| terminal.ends_with(name))
+            .map(|(_, args)| *args)
+            .unwrap_or(&["-e"]);
+
+        return run_terminal(&terminal, args, ssh_command)
+    }
+
+    let (terminal, args) = terminals.iter()
+        .find(|(name, _)| find_in_path(name))
+        .ok_or(anyhow!("unable to find terminal, please configure terminal command in plugin preferences"))?;
+
+    run_terminal(terminal, args, ssh_command)
+}
+
+#[cfg(target_os = "linux")]
+fn run_terminal(terminal: &str, args: &[&str], ssh_command: &[String]) -> anyhow::Result<()> {
+    let args: Vec<String> = args.iter()
+        .map(|arg| arg.to_string())
+        .chain(ssh_command.iter().cloned())
+        .collect();
+
+    spawn_detached(terminal, &args)
+        .with_context(|| format!("unable to run terminal {:?}", terminal))
+}
+
+#[cfg(target_os = "linux")]
+fn find_in_path(program: &str) -> bool {
+    std::env::var_os("PATH")
+        .map(|path| std::env::split_paths(&path).any(|dir| dir.join(program).is_file()))
+        .unwrap_or(false)
+}
+
+#[cfg(target_os = "linux")]
+fn open_in_editor(project: &RecentProject) -> anyhow::Result<()> {
+    spawn_detached(&project.launcher, &[&project.path])
+        .with_context(|| format!("unable to run {:?}, please configure editor command in plugin preferences", project.launcher))
+}
+
+#[cfg(target_os = "macos")]
+fn vscode_config_dirs(home_dir: &Path) -> Vec<(&'static str, &'static str, PathBuf)> {
+    let app_support_dir = home_dir.join("Library").join("Application Support");
+
+    vec![
+        ("Visual Studio Code", "code", app_support_dir.join("Code")),
+        ("Visual Studio Code - Insiders", "code-insiders", app_support_dir.join("Code - Insiders")),
+        ("Code - OSS", "code-oss", app_support_dir.join("Code - OSS")),
+        ("VSCodium", "codium", app_support_dir.join("VSCodium")),
+    ]
+}
+
+#[cfg(target_os = "macos")]
+fn jetbrains_config_dir(home_dir: &Path) -> Option<PathBuf> {
+    Some(home_dir.join("Library").join("Application Support").join("JetBrains"))
+}
+
+// terminal app handles ssh urls by running ssh
+#[cfg(target_os = "macos")]
+fn open_in_default_terminal(host: &SshHost, _ssh_command: &[String]) -> anyhow::Result<()> {
+    let url = match host.port {
+        Some(port) => format!("ssh://{}:{}", host.host, port),
+        None => format!("ssh://{}", host.host),
+    };
+
+    open::that_detached(&url)
+        .map_err(|err| anyhow!("unable to open url '{}': {}", url, err))
+}
+
+// command line launchers are usually not in PATH of applications started from finder,
+// so application is opened by its name
+#[cfg(target_os = "macos")]
+fn open_in_editor(project: &RecentProject) -> anyhow::Result<()> {
+    spawn_detached("open", &["-a", project.editor.as_str(), project.path.as_str()])
+        .with_context(|| format!("unable to open {:?} in {:?}", project.path, project.editor))
+}
+
+#[cfg(target_os = "windows")]
+fn vscode_config_dirs(_home_dir: &Path) -> Vec<(&'static str, &'static str, PathBuf)> {
+    let Some(app_data) = std::env::var_os("APPDATA").map(PathBuf::from) else {
+        return vec![]
+    };
+
+    vec![
+        ("Visual Studio Code", "code", app_data.join("Code")),
+        ("Visual Studio Code - Insiders", "code-insiders", app_data.join("Code - Insiders")),
+        ("Code - OSS", "code-oss", app_data.join("Code - OSS")),
+        ("VSCodium", "codium", app_data.join("VSCodium")),
+    ]
+}
+
+#[cfg(target_os = "windows")]
+fn jetbrains_config_dir(_home_dir: &Path) -> Option<PathBuf> {
+    std::env::var_os("APPDATA")
+        .map(|app_data| PathBuf::from(app_data).join("JetBrains"))
+}
+
+#[cfg(target_os = "windows")]
+fn open_in_default_terminal(_host: &SshHost, ssh_command: &[String]) -> anyhow::Result<()> {
+    // "start" opens command in new console window
+    let args: Vec<String> = ["/C", "start"].into_iter()
+        .map(|arg| arg.to_string())
+        .chain(ssh_command.iter().cloned())
+        .collect();
+
+    spawn_detached("cmd", &args)
+        .context("unable to open terminal")
+}
+
+// launchers are batch files which cannot be started without cmd
+#[cfg(target_os = "windows")]
+fn open_in_editor(project: &RecentProject) -> anyhow::Result<()> {
+    spawn_detached("cmd", &["/C", project.launcher.as_str(), project.path.as_str()])
+        .with_context(|| format!("unable to run {:?}, please configure editor command in plugin preferences", project.launcher))
+}
+
+#[cfg(all(not(target_os = "linux"), not(target_os = "macos"), not(target_os = "windows")))]
+fn vscode_config_dirs(_home_dir: &Path) -> Vec<(&'static str, &'static str, PathBuf)> {
+    vec![]
+}
+
+#[cfg(all(not(target_os = "linux"), not(target_os = "macos"), not(target_os = "windows")))]
+fn jetbrains_config_dir(_home_dir: &Path) -> Option<PathBuf> {
+    None
+}
+
+#[cfg(all(not(target_os = "linux"), not(target_os = "macos"), not(target_os = "windows")))]
+fn open_in_default_terminal(_host: &SshHost, _ssh_command: &[String]) -> anyhow::Result<()> {
+    Err(anyhow!("please configure terminal command in plugin preferences"))
+}
+
+#[cfg(all(not(target_os = "linux"), not(target_os = "macos"), not(target_os = "windows")))]
+fn open_in_editor(_project: &RecentProject) -> anyhow::Result<()> {
+    Err(anyhow!("please configure editor command in plugin preferences"))
+}
@@ -0,0 +1,225 @@
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use anyhow::anyhow;
+use deno_core::{op2, OpState};
+use serde::Serialize;
+use tokio::task::spawn_blocking;
+use gauntlet_common::dirs::Dirs;
+use crate::permissions::ExecutableDirectories;
+
+// metadata is read from comments at the top of the script, e.g.
+//
+// #!/bin/bash
+// # @gauntlet.title Say Hello
+// # @gauntlet.description Prints greeting
+// # @gauntlet.mode detail
+// # @gauntlet.keyword hello
+//
+// script with keyword can also be run by typing the keyword followed by text,
+// which is passed to the script as its first argument
+const HEADER_MAX_LINES: usize = 20;
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum ScriptCommandPathAction {
+    #[serde(rename = "add")]
+    Add {
+        id: String,
+        data: ScriptCommand
+    },
+    #[serde(rename = "remove")]
+    Remove {
+        id: String
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScriptCommand {
+    path: String,
+    title: String,
+    description: Option<String>,
+    mode: ScriptCommandMode,
+    keyword: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub enum ScriptCommandMode {
+    #[serde(rename = "silent")]
+    Silent,
+    #[serde(rename = "hud")]
+    Hud,
+    #[serde(rename = "clipboard")]
+    Clipboard,
+    #[serde(rename = "detail")]
+    Detail,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScriptCommandOutput {
+    success: bool,
+    stdout: String,
+    stderr: String,
+}
+
+#[op2]
+#[string]
+pub fn script_commands_dir() -> anyhow::Result<String> {
+    let dir = Dirs::new().script_commands_dir();
+
+    std::fs::create_dir_all(&dir)?;
+
+    dir.to_str()
+        .map(|dir| dir.to_string())
+        .ok_or(anyhow!("non-uft8 paths are not supported"))
+}
+
+#[op2(async)]
+#[serde]
+pub async fn script_command_from_path(#[string] path: String) -> anyhow::Result<Option<ScriptCommandPathAction>> {
+    spawn_blocking(move || {
+        let path = PathBuf::from(path);
+
+        if path.is_dir() {
+            return Ok(None)
+        }
+
+        let id = path.to_str()
+            .ok_or(anyhow!("non-uft8 paths are not supported"))?
+            .to_string();
+
+        if !path.exists() {
+            return Ok(Some(ScriptCommandPathAction::Remove { id }))
+        }
+
+        match read_script_command(&path)? {
+            Some(data) => Ok(Some(ScriptCommandPathAction::Add { id, data })),
+            None => Ok(Some(ScriptCommandPathAction::Remove { id })),
+        }
+    }).await?
+}
+
+#[op2(async)]
+#[serde]
+pub async fn run_script_command(state: Rc<RefCell<OpState>>, #[string] path: String, #[serde] arguments: Vec<String>) -> anyhow::Result<ScriptCommandOutput> {
+    let path = PathBuf::from(path);
+
+    state.borrow()
+        .borrow::<ExecutableDirectories>()
+        .check(&path)?;
+
+    spawn_blocking(move || {
+        let mut command = std::process::Command::new(&path);
+
+        command.args(arguments);
+
+        if let Some(parent) = path.parent() {
+            command.current_dir(parent);
+        }
+
+        let output = command.output()
+            .map_err(|err| anyhow!("unable to run script command {:?}: {}", path, err))?;
+
+        Ok(ScriptCommandOutput {
+            success: output.status.success(),
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+        })
+    }).await?
+}
+
+fn read_script_command(path: &Path) -> anyhow::Result<Option<ScriptCommand>> {
+    if !is_executable(path)? {
+        return Ok(None)
+    }
+
+    // not a text file, e.g. compiled binary
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Ok(None)
+    };
+
+    let mut title = None;
+    let mut description = None;
+    let mut mode = ScriptCommandMode::Silent;
+    let mut keyword = None;
+
+    for line in content.lines().take(HEADER_MAX_LINES) {
+        let Some((key, value)) = parse_header_line(line) else {
+            continue
+        };
+
+        match key {
+            "title" => title = Some(value.to_string()),
+            "description" => description = Some(value.to_string()),
+            "keyword" => keyword = value.split_whitespace().next().map(|value| value.to_lowercase()),
+            "mode" => {
+                mode = match value {
+                    "silent" => ScriptCommandMode::Silent,
+                    "hud" => ScriptCommandMode::Hud,
+                    "clipboard" => ScriptCommandMode::Clipboard,
+                    "detail" => ScriptCommandMode::Detail,
+                    _ => {
+                        tracing::warn!("unknown script command mode '{}' in {:?}, falling back to 'silent'", value, path);
+                        ScriptCommandMode::Silent
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let Some(title) = title else {
+        return Ok(None)
+    };
+
+    let path = path.to_str()
+        .ok_or(anyhow!("non-uft8 paths are not supported"))?
+        .to_string();
+
+    Ok(Some(ScriptCommand {
+        path,
+        title,
+        description,
+        mode,
+        keyword,
+    }))
+}
+
+fn parse_header_line(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim_start();
+
+    let line = ["#", "//", "--", ";", "REM", "::"]
+        .iter()
+        .find_map(|prefix| line.strip_prefix(prefix))?;
+
+    let line = line.trim_start().strip_prefix("@gauntlet.")?;
+
+    let (key, value) = line.split_once(char::is_whitespace)?;
+
+    let value = value.trim();
+
+    if value.is_empty() {
+        None
+    } else {
+        Some((key, value))
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> anyhow::Result<bool> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let metadata = std::fs::metadata(path)?;
+
+    Ok(metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> anyhow::Result<bool> {
+    let extension = path.extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_lowercase());
+
+    Ok(matches!(extension.as_deref(), Some("exe" | "bat" | "cmd")))
+}
+
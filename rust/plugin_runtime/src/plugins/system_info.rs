@@ -0,0 +1,24 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use deno_core::{op2, OpState};
+
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+use crate::model::JsSystemInfo;
+
+// sampled by server, plugin runtime is sandboxed and doesn't have access to network interfaces
+#[op2(async)]
+#[serde]
+pub async fn system_info(state: Rc<RefCell<OpState>>) -> anyhow::Result<JsSystemInfo> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.system_info().await
+}
@@ -0,0 +1,414 @@
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context};
+use deno_core::{op2, OpState};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+use tokio::task::spawn_blocking;
+use url::Url;
+use uuid::Uuid;
+
+use crate::plugin_data::PluginData;
+
+// secrets are kept in system secret store, only metadata of accounts is stored in plugin data directory
+const SECRET_SERVICE: &str = "gauntlet-totp";
+
+const ACCOUNTS_FILE: &str = "totp-accounts.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotpAccount {
+    id: String,
+    name: String,
+    issuer: Option<String>,
+    digits: u32,
+    // seconds
+    period: u64,
+    algorithm: TotpAlgorithm,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TotpAlgorithm {
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TotpCode {
+    code: String,
+    remaining_seconds: u64,
+}
+
+#[op2(async)]
+#[serde]
+pub async fn totp_accounts(state: Rc<RefCell<OpState>>) -> anyhow::Result<Vec<TotpAccount>> {
+    let accounts_file = accounts_file(&state);
+
+    spawn_blocking(move || read_accounts(&accounts_file)).await?
+}
+
+// secret is either base32 encoded key or "otpauth://" uri, usually obtained from qr code
+#[op2(async)]
+#[serde]
+pub async fn totp_add_account(state: Rc<RefCell<OpState>>, #[string] name: String, #[string] secret: String) -> anyhow::Result<TotpAccount> {
+    let accounts_file = accounts_file(&state);
+
+    spawn_blocking(move || {
+        let (account, secret) = parse_account(name, &secret)?;
+
+        // make sure secret can actually be used before saving it
+        generate_code(&account, &secret, unix_time()?)?;
+
+        secret_entry(&account.id)?
+            .set_secret(&secret)
+            .map_err(|err| anyhow!("unable to save secret to system secret store: {}", err))?;
+
+        let mut accounts = read_accounts(&accounts_file)?;
+        accounts.push(account.clone());
+        write_accounts(&accounts_file, &accounts)?;
+
+        Ok(account)
+    }).await?
+}
+
+#[op2(async)]
+pub async fn totp_remove_account(state: Rc<RefCell<OpState>>, #[string] id: String) -> anyhow::Result<()> {
+    let accounts_file = accounts_file(&state);
+
+    spawn_blocking(move || {
+        let mut accounts = read_accounts(&accounts_file)?;
+        accounts.retain(|account| account.id != id);
+        write_accounts(&accounts_file, &accounts)?;
+
+        match secret_entry(&id)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(err) => Err(anyhow!("unable to remove secret from system secret store: {}", err)),
+        }
+    }).await?
+}
+
+#[op2(async)]
+#[serde]
+pub async fn totp_generate_code(state: Rc<RefCell<OpState>>, #[string] id: String) -> anyhow::Result<TotpCode> {
+    let accounts_file = accounts_file(&state);
+
+    spawn_blocking(move || {
+        let account = read_accounts(&accounts_file)?
+            .into_iter()
+            .find(|account| account.id == id)
+            .ok_or(anyhow!("totp account with id {} doesn't exist", id))?;
+
+        let secret = secret_entry(&account.id)?
+            .get_secret()
+            .map_err(|err| anyhow!("unable to read secret from system secret store: {}", err))?;
+
+        let time = unix_time()?;
+
+        Ok(TotpCode {
+            code: generate_code(&account, &secret, time)?,
+            remaining_seconds: account.period - time % account.period,
+        })
+    }).await?
+}
+
+fn accounts_file(state: &Rc<RefCell<OpState>>) -> PathBuf {
+    let state = state.borrow();
+
+    let plugin_data_dir = state
+        .borrow::<PluginData>()
+        .plugin_data_dir()
+        .to_string();
+
+    Path::new(&plugin_data_dir).join(ACCOUNTS_FILE)
+}
+
+fn secret_entry(id: &str) -> anyhow::Result<keyring::Entry> {
+    keyring::Entry::new(SECRET_SERVICE, id)
+        .map_err(|err| anyhow!("unable to access system secret store: {}", err))
+}
+
+fn read_accounts(accounts_file: &Path) -> anyhow::Result<Vec<TotpAccount>> {
+    if !accounts_file.exists() {
+        return Ok(vec![])
+    }
+
+    let data = std::fs::read(accounts_file)
+        .context("unable to read totp accounts")?;
+
+    let accounts = serde_json::from_slice(&data)
+        .context("unexpected format of totp accounts file")?;
+
+    Ok(accounts)
+}
+
+fn write_accounts(accounts_file: &Path, accounts: &[TotpAccount]) -> anyhow::Result<()> {
+    let data = serde_json::to_vec_pretty(accounts)?;
+
+    std::fs::write(accounts_file, data)
+        .context("unable to write totp accounts")?;
+
+    Ok(())
+}
+
+fn unix_time() -> anyhow::Result<u64> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())
+}
+
+fn parse_account(name: String, secret: &str) -> anyhow::Result<(TotpAccount, Vec<u8>)> {
+    let secret = secret.trim();
+
+    if secret.starts_with("otpauth://") {
+        return parse_otpauth_uri(name, secret)
+    }
+
+    if name.trim().is_empty() {
+        return Err(anyhow!("account name is required"))
+    }
+
+    let account = TotpAccount {
+        id: Uuid::new_v4().to_string(),
+        name: name.trim().to_string(),
+        issuer: None,
+        digits: 6,
+        period: 30,
+        algorithm: TotpAlgorithm::Sha1,
+    };
+
+    Ok((account, decode_base32(secret)?))
+}
+
+// https://github.com/google/google-authenticator/wiki/Key-Uri-Format
+fn parse_otpauth_uri(name: String, uri: &str) -> anyhow::Result<(TotpAccount, Vec<u8>)> {
+    let uri = Url::parse(uri).context("invalid otpauth uri")?;
+
+    if uri.host_str() != Some("totp") {
+        return Err(anyhow!("only time-based one-time passwords are supported"))
+    }
+
+    let label = percent_encoding::percent_decode_str(uri.path().trim_start_matches('/'))
+        .decode_utf8_lossy()
+        .to_string();
+
+    // label is either "account" or "issuer:account"
+    let (label_issuer, label_name) = match label.split_once(':') {
+        Some((issuer, name)) => (Some(issuer.trim().to_string()), name.trim().to_string()),
+        None => (None, label.trim().to_string()),
+    };
+
+    let mut secret = None;
+    let mut issuer = label_issuer;
+    let mut digits = 6;
+    let mut period = 30;
+    let mut algorithm = TotpAlgorithm::Sha1;
+
+    for (key, value) in uri.query_pairs() {
+        match key.as_ref() {
+            "secret" => secret = Some(decode_base32(&value)?),
+            "issuer" => issuer = Some(value.to_string()),
+            "digits" => digits = value.parse().context("invalid digits parameter")?,
+            "period" => period = value.parse().context("invalid period parameter")?,
+            "algorithm" => {
+                algorithm = match value.to_uppercase().as_str() {
+                    "SHA1" => TotpAlgorithm::Sha1,
+                    "SHA256" => TotpAlgorithm::Sha256,
+                    "SHA512" => TotpAlgorithm::Sha512,
+                    _ => return Err(anyhow!("unsupported algorithm: {}", value))
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let secret = secret.ok_or(anyhow!("otpauth uri doesn't contain secret"))?;
+
+    if !(6..=8).contains(&digits) {
+        return Err(anyhow!("unsupported number of digits: {}", digits))
+    }
+
+    if period == 0 {
+        return Err(anyhow!("period cannot be zero"))
+    }
+
+    let name = if name.trim().is_empty() { label_name } else { name.trim().to_string() };
+
+    let account = TotpAccount {
+        id: Uuid::new_v4().to_string(),
+        name,
+        issuer: issuer.filter(|issuer| !issuer.is_empty()),
+        digits,
+        period,
+        algorithm,
+    };
+
+    Ok((account, secret))
+}
+
+// https://datatracker.ietf.org/doc/html/rfc6238
+fn generate_code(account: &TotpAccount, secret: &[u8], time: u64) -> anyhow::Result<String> {
+    let counter = (time / account.period).to_be_bytes();
+
+    let hash = match account.algorithm {
+        TotpAlgorithm::Sha1 => compute_hmac::<Hmac<Sha1>>(secret, &counter)?,
+        TotpAlgorithm::Sha256 => compute_hmac::<Hmac<Sha256>>(secret, &counter)?,
+        TotpAlgorithm::Sha512 => compute_hmac::<Hmac<Sha512>>(secret, &counter)?,
+    };
+
+    // dynamic truncation
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+
+    let binary = u32::from_be_bytes([hash[offset], hash[offset + 1], hash[offset + 2], hash[offset + 3]]) & 0x7fff_ffff;
+
+    let code = binary % 10u32.pow(account.digits);
+
+    Ok(format!("{:0width$}", code, width = account.digits as usize))
+}
+
+fn compute_hmac<M: Mac + hmac::digest::KeyInit>(key: &[u8], message: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let mut mac = <M as hmac::digest::KeyInit>::new_from_slice(key)
+        .map_err(|_| anyhow!("invalid secret length"))?;
+
+    mac.update(message);
+
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+// https://datatracker.ietf.org/doc/html/rfc4648#section-6
+fn decode_base32(value: &str) -> anyhow::Result<Vec<u8>> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+    let mut result = vec![];
+    let mut buffer: u64 = 0;
+    let mut bits = 0;
+
+    // secrets are often displayed in groups separated by spaces and in lowercase
+    for char in value.chars().filter(|char| !char.is_whitespace() && *char != '-' && *char != '=') {
+        let char = char.to_ascii_uppercase() as u8;
+
+        let index = ALPHABET.iter()
+            .position(|alphabet_char| *alphabet_char == char)
+            .ok_or(anyhow!("secret is not valid base32"))?;
+
+        buffer = (buffer << 5) | index as u64;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    if result.is_empty() {
+        return Err(anyhow!("secret is empty"))
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(algorithm: TotpAlgorithm) -> TotpAccount {
+        TotpAccount {
+            id: "test".to_string(),
+            name: "test".to_string(),
+            issuer: None,
+            digits: 8,
+            period: 30,
+            algorithm,
+        }
+    }
+
+    // https://datatracker.ietf.org/doc/html/rfc6238#appendix-B
+    #[test]
+    fn generate_code_matches_rfc_6238_vectors() {
+        let sha1_secret = b"12345678901234567890";
+        let sha256_secret = b"12345678901234567890123456789012";
+        let sha512_secret = b"1234567890123456789012345678901234567890123456789012345678901234";
+
+        let vectors = [
+            (59, "94287082", "46119246", "90693936"),
+            (1111111109, "07081804", "68084774", "25091201"),
+            (1111111111, "14050471", "67062674", "99943326"),
+            (1234567890, "89005924", "91819424", "93441116"),
+            (2000000000, "69279037", "90698825", "38618901"),
+            (20000000000, "65353130", "77737706", "47863826"),
+        ];
+
+        for (time, sha1_code, sha256_code, sha512_code) in vectors {
+            assert_eq!(generate_code(&account(TotpAlgorithm::Sha1), sha1_secret, time).unwrap(), sha1_code);
+            assert_eq!(generate_code(&account(TotpAlgorithm::Sha256), sha256_secret, time).unwrap(), sha256_code);
+            assert_eq!(generate_code(&account(TotpAlgorithm::Sha512), sha512_secret, time).unwrap(), sha512_code);
+        }
+    }
+
+    #[test]
+    fn decode_base32_accepts_formatted_secrets() {
+        assert_eq!(decode_base32("MZXW6YTBOI======").unwrap(), b"foobar");
+        assert_eq!(decode_base32("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ").unwrap(), b"12345678901234567890");
+        assert_eq!(decode_base32("gezd gnbv gy3t qojq gezd gnbv gy3t qojq").unwrap(), b"12345678901234567890");
+        assert_eq!(decode_base32("GEZD-GNBV-GY3T-QOJQ").unwrap(), b"1234567890");
+    }
+
+    #[test]
+    fn decode_base32_rejects_invalid_secrets() {
+        assert!(decode_base32("").is_err());
+        assert!(decode_base32("  ==").is_err());
+        assert!(decode_base32("GEZDGNBV1").is_err());
+        assert!(decode_base32("GEZDGNBV!").is_err());
+    }
+
+    #[test]
+    fn parse_otpauth_uri_reads_parameters() {
+        let (account, secret) = parse_otpauth_uri(
+            "".to_string(),
+            "otpauth://totp/Example:alice@google.com?secret=JBSWY3DPEHPK3PXP&issuer=Example&algorithm=SHA256&digits=8&period=60",
+        ).unwrap();
+
+        assert_eq!(account.name, "alice@google.com");
+        assert_eq!(account.issuer.as_deref(), Some("Example"));
+        assert!(matches!(account.algorithm, TotpAlgorithm::Sha256));
+        assert_eq!(account.digits, 8);
+        assert_eq!(account.period, 60);
+        assert_eq!(secret, b"Hello!\xde\xad\xbe\xef");
+
+        let (account, _) = parse_otpauth_uri(
+            "Work".to_string(),
+            "otpauth://totp/alice%40google.com?secret=JBSWY3DPEHPK3PXP",
+        ).unwrap();
+
+        assert_eq!(account.name, "Work");
+        assert_eq!(account.issuer, None);
+        assert!(matches!(account.algorithm, TotpAlgorithm::Sha1));
+        assert_eq!(account.digits, 6);
+        assert_eq!(account.period, 30);
+    }
+
+    #[test]
+    fn parse_otpauth_uri_rejects_malformed_uris() {
+        let malformed = [
+            "otpauth://",
+            "not a uri",
+            "otpauth://hotp/Example:alice?secret=JBSWY3DPEHPK3PXP&counter=0",
+            "otpauth://totp/Example:alice",
+            "otpauth://totp/Example:alice?secret=not-base32!",
+            "otpauth://totp/Example:alice?secret=JBSWY3DPEHPK3PXP&digits=5",
+            "otpauth://totp/Example:alice?secret=JBSWY3DPEHPK3PXP&digits=9",
+            "otpauth://totp/Example:alice?secret=JBSWY3DPEHPK3PXP&digits=six",
+            "otpauth://totp/Example:alice?secret=JBSWY3DPEHPK3PXP&period=0",
+            "otpauth://totp/Example:alice?secret=JBSWY3DPEHPK3PXP&period=-30",
+            "otpauth://totp/Example:alice?secret=JBSWY3DPEHPK3PXP&algorithm=MD5",
+        ];
+
+        for uri in malformed {
+            assert!(parse_otpauth_uri("".to_string(), uri).is_err(), "{} should be rejected", uri);
+        }
+    }
+}
@@ -0,0 +1,293 @@
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use anyhow::{anyhow, Context};
+use deno_core::{op2, OpState};
+use serde::Serialize;
+use tokio::task::spawn_blocking;
+
+use crate::plugin_data::PluginData;
+
+#[derive(Debug, Serialize)]
+pub struct TrashItem {
+    // name of the file inside trash directory, unique within it
+    id: String,
+    name: String,
+    // not available on macos, finder keeps it in its own metadata
+    original_path: Option<String>,
+    // local time in "YYYY-MM-DDThh:mm:ss" format, as written by file manager
+    deleted_at: Option<String>,
+    is_dir: bool,
+    // bytes, for directories sum of all files inside
+    size: u64,
+}
+
+#[op2(async)]
+#[serde]
+pub async fn trash_list(state: Rc<RefCell<OpState>>) -> anyhow::Result<Vec<TrashItem>> {
+    let trash = trash_dir(&state)?;
+
+    spawn_blocking(move || trash.list()).await?
+}
+
+// returns path the item was restored to
+#[op2(async)]
+#[string]
+pub async fn trash_restore(state: Rc<RefCell<OpState>>, #[string] id: String) -> anyhow::Result<String> {
+    let trash = trash_dir(&state)?;
+
+    spawn_blocking(move || trash.restore(&id)).await?
+}
+
+#[op2(async)]
+pub async fn trash_delete(state: Rc<RefCell<OpState>>, #[string] id: String) -> anyhow::Result<()> {
+    let trash = trash_dir(&state)?;
+
+    spawn_blocking(move || trash.delete(&id)).await?
+}
+
+// returns number of permanently deleted items
+#[op2(async)]
+pub async fn trash_empty(state: Rc<RefCell<OpState>>) -> anyhow::Result<u32> {
+    let trash = trash_dir(&state)?;
+
+    spawn_blocking(move || {
+        let items = trash.list()?;
+
+        for item in &items {
+            trash.delete(&item.id)?;
+        }
+
+        Ok(items.len() as u32)
+    }).await?
+}
+
+// only trash in home directory is used, trash directories of other mounted volumes are not listed
+struct TrashDir {
+    files: PathBuf,
+    // freedesktop trash spec keeps original path and deletion date in separate .trashinfo files
+    info: Option<PathBuf>,
+}
+
+fn trash_dir(state: &Rc<RefCell<OpState>>) -> anyhow::Result<TrashDir> {
+    let home_dir = {
+        let state = state.borrow();
+
+        state.borrow::<PluginData>().home_dir()
+    };
+
+    trash_dir_in_home(&home_dir)
+}
+
+#[cfg(target_os = "linux")]
+fn trash_dir_in_home(home_dir: &Path) -> anyhow::Result<TrashDir> {
+    let data_home = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .unwrap_or_else(|| home_dir.join(".local").join("share"));
+
+    let trash = data_home.join("Trash");
+
+    Ok(TrashDir {
+        files: trash.join("files"),
+        info: Some(trash.join("info")),
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn trash_dir_in_home(home_dir: &Path) -> anyhow::Result<TrashDir> {
+    Ok(TrashDir {
+        files: home_dir.join(".Trash"),
+        info: None,
+    })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn trash_dir_in_home(_home_dir: &Path) -> anyhow::Result<TrashDir> {
+    Err(anyhow!("trash is not supported on this system"))
+}
+
+impl TrashDir {
+    fn list(&self) -> anyhow::Result<Vec<TrashItem>> {
+        // trash directory is created by file manager when something is deleted for the first time
+        let entries = match std::fs::read_dir(&self.files) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err).context(format!("unable to read trash directory {:?}", self.files)),
+        };
+
+        let mut items = vec![];
+
+        for entry in entries {
+            let entry = entry?;
+
+            let Some(id) = entry.file_name().to_str().map(|name| name.to_string()) else {
+                continue
+            };
+
+            // finder metadata
+            if self.info.is_none() && id == ".DS_Store" {
+                continue
+            }
+
+            let metadata = std::fs::symlink_metadata(entry.path())?;
+
+            let (original_path, deleted_at) = match self.read_info(&id) {
+                Ok(Some((original_path, deleted_at))) => (Some(original_path), deleted_at),
+                Ok(None) => (None, None),
+                Err(err) => {
+                    tracing::warn!("Unable to read trash info of {:?}: {:?}", id, err);
+                    (None, None)
+                }
+            };
+
+            let name = original_path.as_ref()
+                .and_then(|path| Path::new(path).file_name())
+                .and_then(|name| name.to_str())
+                .unwrap_or(id.as_str())
+                .to_string();
+
+            let size = if metadata.is_dir() {
+                walkdir::WalkDir::new(entry.path())
+                    .into_iter()
+                    .filter_map(|entry| entry.ok())
+                    .filter_map(|entry| entry.metadata().ok())
+                    .filter(|metadata| metadata.is_file())
+                    .map(|metadata| metadata.len())
+                    .sum()
+            } else {
+                metadata.len()
+            };
+
+            items.push(TrashItem {
+                id,
+                name,
+                original_path,
+                deleted_at,
+                is_dir: metadata.is_dir(),
+                size,
+            })
+        }
+
+        Ok(items)
+    }
+
+    fn restore(&self, id: &str) -> anyhow::Result<String> {
+        let path = self.item_path(id)?;
+
+        let Some((original_path, _)) = self.read_info(id)? else {
+            return Err(anyhow!("original location of '{}' is unknown, it can be put back using file manager", id))
+        };
+
+        let original_path = PathBuf::from(original_path);
+
+        if std::fs::symlink_metadata(&original_path).is_ok() {
+            return Err(anyhow!("{:?} already exists", original_path))
+        }
+
+        if let Some(parent) = original_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::rename(&path, &original_path)
+            .context(format!("unable to move {:?} to {:?}", path, original_path))?;
+
+        self.remove_info(id)?;
+
+        Ok(original_path.to_string_lossy().to_string())
+    }
+
+    fn delete(&self, id: &str) -> anyhow::Result<()> {
+        let path = self.item_path(id)?;
+
+        let metadata = std::fs::symlink_metadata(&path)
+            .context(format!("{:?} is not in trash", id))?;
+
+        if metadata.is_dir() {
+            std::fs::remove_dir_all(&path)?;
+        } else {
+            std::fs::remove_file(&path)?;
+        }
+
+        self.remove_info(id)?;
+
+        Ok(())
+    }
+
+    // id comes from plugin, make sure it doesn't point outside of trash directory
+    fn item_path(&self, id: &str) -> anyhow::Result<PathBuf> {
+        if id.is_empty() || id == "." || id == ".." || id.contains(['/', '\\']) {
+            return Err(anyhow!("invalid trash item id: {:?}", id))
+        }
+
+        Ok(self.files.join(id))
+    }
+
+    fn info_path(&self, id: &str) -> Option<PathBuf> {
+        self.info.as_ref()
+            .map(|info| info.join(format!("{}.trashinfo", id)))
+    }
+
+    fn read_info(&self, id: &str) -> anyhow::Result<Option<(String, Option<String>)>> {
+        let Some(info_path) = self.info_path(id) else {
+            return Ok(None)
+        };
+
+        let content = match std::fs::read_to_string(&info_path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(parse_trash_info(&content))
+    }
+
+    fn remove_info(&self, id: &str) -> anyhow::Result<()> {
+        let Some(info_path) = self.info_path(id) else {
+            return Ok(())
+        };
+
+        match std::fs::remove_file(&info_path) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err).context(format!("unable to remove {:?}", info_path)),
+        }
+    }
+}
+
+// [Trash Info]
+// Path=/home/user/some%20file.txt
+// DeletionDate=2024-01-31T12:00:00
+fn parse_trash_info(content: &str) -> Option<(String, Option<String>)> {
+    let mut in_section = false;
+    let mut path = None;
+    let mut deletion_date = None;
+
+    for line in content.lines() {
+        let line = line.trim();
+
+        if line.starts_with('[') {
+            in_section = line == "[Trash Info]";
+            continue
+        }
+
+        if !in_section {
+            continue
+        }
+
+        if let Some(value) = line.strip_prefix("Path=") {
+            path = percent_encoding::percent_decode_str(value)
+                .decode_utf8()
+                .ok()
+                .map(|path| path.to_string());
+        } else if let Some(value) = line.strip_prefix("DeletionDate=") {
+            deletion_date = Some(value.to_string());
+        }
+    }
+
+    // only home trash is used, where paths are always absolute
+    let path = path.filter(|path| Path::new(path).is_absolute())?;
+
+    Some((path, deletion_date))
+}
@@ -0,0 +1,30 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use anyhow::anyhow;
+use deno_core::{op2, OpState};
+
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+
+#[op2(fast)]
+pub fn open_url(#[string] url: String) -> anyhow::Result<()> {
+    open::that_detached(&url)
+        .map_err(|err| anyhow!("unable to open url '{}': {}", url, err))
+}
+
+// suggestions are fetched by server which also takes care of debouncing and caching
+#[op2(async)]
+#[serde]
+pub async fn web_search_suggestions(state: Rc<RefCell<OpState>>, #[string] engine: String, #[string] query: String) -> anyhow::Result<Vec<String>> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.web_search_suggestions(engine, query).await
+}
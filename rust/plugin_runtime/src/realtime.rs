@@ -0,0 +1,475 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::rc::Rc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use deno_core::url::Url;
+use deno_core::{op2, OpState, ToJsBuffer};
+use deno_runtime::deno_permissions::PermissionsContainer;
+use futures::{SinkExt, StreamExt};
+use serde::Serialize;
+use tokio::runtime::Handle;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::{HeaderName, HeaderValue};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_util::sync::CancellationToken;
+
+const RECONNECT_INITIAL_DELAY: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+// servers are expected to send keep-alive comments, connection without any data for this long is considered dead
+const EVENT_SOURCE_READ_TIMEOUT: Duration = Duration::from_secs(90);
+
+// connections are closed when plugin runtime stops, e.g. when plugin is disabled, because op state is dropped together with it
+#[derive(Default)]
+pub struct RealtimeConnections {
+    next_id: u32,
+    connections: HashMap<u32, RealtimeConnection>,
+}
+
+struct RealtimeConnection {
+    cancellation_token: CancellationToken,
+    events: Rc<RefCell<UnboundedReceiver<RealtimeEvent>>>,
+    // only websocket can send messages
+    outgoing: Option<UnboundedSender<String>>,
+}
+
+impl Drop for RealtimeConnection {
+    fn drop(&mut self) {
+        self.cancellation_token.cancel();
+    }
+}
+
+impl RealtimeConnections {
+    fn insert(&mut self, cancellation_token: CancellationToken, events: UnboundedReceiver<RealtimeEvent>, outgoing: Option<UnboundedSender<String>>) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.connections.insert(id, RealtimeConnection {
+            cancellation_token,
+            events: Rc::new(RefCell::new(events)),
+            outgoing,
+        });
+
+        id
+    }
+}
+
+enum RealtimeEvent {
+    Open,
+    Message {
+        data: String,
+        event: Option<String>,
+        id: Option<String>,
+    },
+    BinaryMessage {
+        data: Vec<u8>,
+    },
+    Reconnecting {
+        delay: Duration,
+        reason: String,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JsRealtimeEvent {
+    Open,
+    Message {
+        data: String,
+        event: Option<String>,
+        id: Option<String>,
+    },
+    BinaryMessage {
+        data: ToJsBuffer,
+    },
+    Reconnecting {
+        delay_ms: u64,
+        reason: String,
+    },
+}
+
+#[op2]
+pub fn realtime_websocket_connect(state: &mut OpState, #[string] url: String, #[serde] headers: HashMap<String, String>) -> anyhow::Result<u32> {
+    let url = parse_url(state, &url, &["ws", "wss"], "Realtime.webSocket()")?;
+
+    let (events_sender, events_receiver) = unbounded_channel();
+    let (outgoing_sender, outgoing_receiver) = unbounded_channel();
+    let cancellation_token = CancellationToken::new();
+
+    state.borrow::<Handle>()
+        .spawn(run_websocket(url, headers, events_sender, outgoing_receiver, cancellation_token.clone()));
+
+    let id = state.borrow_mut::<RealtimeConnections>()
+        .insert(cancellation_token, events_receiver, Some(outgoing_sender));
+
+    Ok(id)
+}
+
+#[op2]
+pub fn realtime_event_source_connect(state: &mut OpState, #[string] url: String, #[serde] headers: HashMap<String, String>) -> anyhow::Result<u32> {
+    let url = parse_url(state, &url, &["http", "https"], "Realtime.eventSource()")?;
+
+    let (events_sender, events_receiver) = unbounded_channel();
+    let cancellation_token = CancellationToken::new();
+
+    state.borrow::<Handle>()
+        .spawn(run_event_source(url, headers, events_sender, cancellation_token.clone()));
+
+    let id = state.borrow_mut::<RealtimeConnections>()
+        .insert(cancellation_token, events_receiver, None);
+
+    Ok(id)
+}
+
+#[op2(fast)]
+pub fn realtime_send(state: &mut OpState, id: u32, #[string] data: String) -> anyhow::Result<()> {
+    let connections = state.borrow::<RealtimeConnections>();
+
+    let connection = connections.connections.get(&id)
+        .ok_or_else(|| anyhow!("realtime connection with id {} doesn't exist", id))?;
+
+    let outgoing = connection.outgoing.as_ref()
+        .ok_or_else(|| anyhow!("messages can only be sent over websocket"))?;
+
+    outgoing.send(data)
+        .map_err(|_| anyhow!("realtime connection with id {} is closed", id))
+}
+
+#[op2(fast)]
+pub fn realtime_close(state: &mut OpState, id: u32) {
+    state.borrow_mut::<RealtimeConnections>()
+        .connections
+        .remove(&id);
+}
+
+// resolves to None after connection is closed
+#[op2(async)]
+#[serde]
+pub async fn realtime_next_event(state: Rc<RefCell<OpState>>, id: u32) -> anyhow::Result<Option<JsRealtimeEvent>> {
+    let events = {
+        let state = state.borrow();
+
+        state.borrow::<RealtimeConnections>()
+            .connections
+            .get(&id)
+            .map(|connection| connection.events.clone())
+            .ok_or_else(|| anyhow!("realtime connection with id {} doesn't exist", id))?
+    };
+
+    let mut events = events.borrow_mut();
+
+    let event = events.recv()
+        .await
+        .map(|event| match event {
+            RealtimeEvent::Open => JsRealtimeEvent::Open,
+            RealtimeEvent::Message { data, event, id } => JsRealtimeEvent::Message { data, event, id },
+            RealtimeEvent::BinaryMessage { data } => JsRealtimeEvent::BinaryMessage { data: data.into() },
+            RealtimeEvent::Reconnecting { delay, reason } => JsRealtimeEvent::Reconnecting {
+                delay_ms: delay.as_millis() as u64,
+                reason,
+            },
+        });
+
+    Ok(event)
+}
+
+fn parse_url(state: &mut OpState, url: &str, schemes: &[&str], api_name: &str) -> anyhow::Result<Url> {
+    let url = Url::parse(url)
+        .with_context(|| format!("invalid url '{}'", url))?;
+
+    if !schemes.contains(&url.scheme()) {
+        return Err(anyhow!("unsupported url scheme '{}', expected one of: {}", url.scheme(), schemes.join(", ")))
+    }
+
+    // redirects are not followed, so checking only initial url is enough
+    state.borrow_mut::<PermissionsContainer>()
+        .check_net_url(&url, api_name)
+        .map_err(|err| anyhow!("{}", err))?;
+
+    Ok(url)
+}
+
+struct Backoff {
+    delay: Duration,
+}
+
+impl Backoff {
+    fn new() -> Self {
+        Self {
+            delay: RECONNECT_INITIAL_DELAY,
+        }
+    }
+
+    fn next(&mut self) -> Duration {
+        let delay = self.delay;
+
+        self.delay = (self.delay * 2).min(RECONNECT_MAX_DELAY);
+
+        delay
+    }
+
+    fn reset(&mut self) {
+        self.delay = RECONNECT_INITIAL_DELAY;
+    }
+}
+
+// returns false if connection was closed while waiting
+async fn wait_before_reconnect(events: &UnboundedSender<RealtimeEvent>, delay: Duration, reason: String, cancellation_token: &CancellationToken) -> bool {
+    tracing::debug!("realtime connection lost, reconnecting in {:?}: {}", delay, reason);
+
+    if events.send(RealtimeEvent::Reconnecting { delay, reason }).is_err() {
+        return false
+    }
+
+    tokio::select! {
+        _ = cancellation_token.cancelled() => false,
+        _ = tokio::time::sleep(delay) => true,
+    }
+}
+
+async fn run_websocket(
+    url: Url,
+    headers: HashMap<String, String>,
+    events: UnboundedSender<RealtimeEvent>,
+    mut outgoing: UnboundedReceiver<String>,
+    cancellation_token: CancellationToken,
+) {
+    let mut backoff = Backoff::new();
+
+    loop {
+        let result = tokio::select! {
+            _ = cancellation_token.cancelled() => return,
+            result = websocket_session(&url, &headers, &events, &mut outgoing, &mut backoff) => result,
+        };
+
+        let reason = match result {
+            Ok(()) => "connection closed by server".to_string(),
+            Err(err) => format!("{:#}", err),
+        };
+
+        if !wait_before_reconnect(&events, backoff.next(), reason, &cancellation_token).await {
+            return
+        }
+    }
+}
+
+async fn websocket_session(
+    url: &Url,
+    headers: &HashMap<String, String>,
+    events: &UnboundedSender<RealtimeEvent>,
+    outgoing: &mut UnboundedReceiver<String>,
+    backoff: &mut Backoff,
+) -> anyhow::Result<()> {
+    let mut request = url.as_str().into_client_request()?;
+
+    for (name, value) in headers {
+        request.headers_mut().insert(HeaderName::from_bytes(name.as_bytes())?, HeaderValue::from_str(value)?);
+    }
+
+    let (stream, _) = tokio::time::timeout(CONNECT_TIMEOUT, tokio_tungstenite::connect_async(request))
+        .await
+        .context("timed out connecting to websocket")??;
+
+    backoff.reset();
+
+    let (mut write, mut read) = stream.split();
+
+    let _ = events.send(RealtimeEvent::Open);
+
+    loop {
+        tokio::select! {
+            message = outgoing.recv() => {
+                match message {
+                    Some(message) => write.send(Message::Text(message)).await?,
+                    None => return Ok(()),
+                }
+            }
+            message = read.next() => {
+                match message {
+                    Some(Ok(Message::Text(data))) => {
+                        let _ = events.send(RealtimeEvent::Message { data, event: None, id: None });
+                    }
+                    Some(Ok(Message::Binary(data))) => {
+                        let _ = events.send(RealtimeEvent::BinaryMessage { data });
+                    }
+                    Some(Ok(Message::Close(_))) | None => return Ok(()),
+                    // pings are answered by tungstenite
+                    Some(Ok(_)) => {}
+                    Some(Err(err)) => return Err(err.into()),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct EventSourceState {
+    last_event_id: Option<String>,
+    retry: Option<Duration>,
+    opened: bool,
+}
+
+async fn run_event_source(
+    url: Url,
+    headers: HashMap<String, String>,
+    events: UnboundedSender<RealtimeEvent>,
+    cancellation_token: CancellationToken,
+) {
+    // redirects are not followed so that they don't bypass network permissions
+    let agent = ureq::AgentBuilder::new()
+        .redirects(0)
+        .user_agent("Gauntlet")
+        .timeout_connect(CONNECT_TIMEOUT)
+        .timeout_read(EVENT_SOURCE_READ_TIMEOUT)
+        .build();
+
+    let mut backoff = Backoff::new();
+    let mut state = EventSourceState::default();
+
+    loop {
+        // reading is blocking, so session notices cancellation only after next line or read timeout
+        let result = tokio::task::spawn_blocking({
+            let agent = agent.clone();
+            let url = url.clone();
+            let headers = headers.clone();
+            let events = events.clone();
+            let cancellation_token = cancellation_token.clone();
+
+            move || {
+                let result = event_source_session(&agent, &url, &headers, &mut state, &events, &cancellation_token);
+
+                (state, result)
+            }
+        }).await;
+
+        let reason = match result {
+            Ok((new_state, result)) => {
+                state = new_state;
+
+                match result {
+                    Ok(()) => "connection closed by server".to_string(),
+                    Err(err) => format!("{:#}", err),
+                }
+            }
+            Err(err) => {
+                state = EventSourceState::default();
+
+                format!("{:#}", err)
+            }
+        };
+
+        if cancellation_token.is_cancelled() {
+            return
+        }
+
+        if state.opened {
+            backoff.reset();
+            state.opened = false;
+        }
+
+        // server can ask for longer delay using retry field
+        let delay = backoff.next().max(state.retry.unwrap_or_default());
+
+        if !wait_before_reconnect(&events, delay, reason, &cancellation_token).await {
+            return
+        }
+    }
+}
+
+// blocking, implements parsing described in https://html.spec.whatwg.org/multipage/server-sent-events.html
+fn event_source_session(
+    agent: &ureq::Agent,
+    url: &Url,
+    headers: &HashMap<String, String>,
+    state: &mut EventSourceState,
+    events: &UnboundedSender<RealtimeEvent>,
+    cancellation_token: &CancellationToken,
+) -> anyhow::Result<()> {
+    let mut request = agent.request_url("GET", url)
+        .set("Accept", "text/event-stream")
+        .set("Cache-Control", "no-cache");
+
+    for (name, value) in headers {
+        request = request.set(name, value);
+    }
+
+    if let Some(last_event_id) = &state.last_event_id {
+        request = request.set("Last-Event-ID", last_event_id);
+    }
+
+    let response = request.call()
+        .context("unable to connect to event source")?;
+
+    if response.status() != 200 {
+        return Err(anyhow!("unexpected event source response status: {}", response.status()))
+    }
+
+    state.opened = true;
+
+    let _ = events.send(RealtimeEvent::Open);
+
+    let mut event_type = String::new();
+    let mut data = String::new();
+    let mut event_id = state.last_event_id.clone();
+
+    for line in BufReader::new(response.into_reader()).lines() {
+        if cancellation_token.is_cancelled() {
+            return Ok(())
+        }
+
+        let line = line.context("unable to read from event source")?;
+
+        if line.is_empty() {
+            state.last_event_id = event_id.clone();
+
+            if !data.is_empty() {
+                // trailing newline is removed when event is dispatched
+                data.pop();
+
+                let event = RealtimeEvent::Message {
+                    data: std::mem::take(&mut data),
+                    event: Some(if event_type.is_empty() { "message".to_string() } else { std::mem::take(&mut event_type) }),
+                    id: event_id.clone(),
+                };
+
+                if events.send(event).is_err() {
+                    return Ok(())
+                }
+            }
+
+            event_type.clear();
+
+            continue
+        }
+
+        if line.starts_with(':') {
+            continue
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line.as_str(), ""),
+        };
+
+        match field {
+            "event" => event_type = value.to_string(),
+            "data" => {
+                data.push_str(value);
+                data.push('\n');
+            }
+            "id" if !value.contains('\0') => event_id = Some(value.to_string()),
+            "retry" => {
+                if let Ok(retry) = value.parse::<u64>() {
+                    state.retry = Some(Duration::from_millis(retry));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use deno_core::{op2, OpState};
 use std::cell::RefCell;
 use std::rc::Rc;
+use gauntlet_common::model::EntrypointId;
 use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
 use crate::model::JsAdditionalSearchItem;
 
@@ -20,3 +21,35 @@ pub async fn reload_search_index(state: Rc<RefCell<OpState>>, #[serde] generated
 
     Ok(())
 }
+
+// results of search query sent to all plugins, generation is the one received in search query event
+#[op2(async)]
+pub async fn submit_search_results(state: Rc<RefCell<OpState>>, #[number] generation: u64, #[serde] results: Vec<JsAdditionalSearchItem>) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.submit_search_results(generation, results).await
+}
+
+// suggestions for argument of entrypoint, generation is the one received in argument suggestions event
+#[op2(async)]
+pub async fn submit_argument_suggestions(state: Rc<RefCell<OpState>>, #[number] generation: u64, #[string] entrypoint_id: String, #[string] argument_id: String, #[serde] suggestions: Vec<String>) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.submit_argument_suggestions(generation, EntrypointId::from_string(entrypoint_id), argument_id, suggestions).await
+}
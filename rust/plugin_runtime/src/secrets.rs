@@ -0,0 +1,20 @@
+use deno_core::{op2, OpState};
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+
+#[op2(async)]
+#[string]
+pub async fn secret_read(state: Rc<RefCell<OpState>>, #[string] service: String, #[string] account: String) -> anyhow::Result<Option<String>> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.secret_read(service, account).await
+}
@@ -0,0 +1,21 @@
+use deno_core::{op2, OpState};
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+use crate::model::JsSound;
+
+// played by server, nothing is played if sounds are muted in settings
+#[op2(async)]
+pub async fn play_sound(state: Rc<RefCell<OpState>>, #[serde] sound: JsSound) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.play_sound(sound).await
+}
@@ -2,19 +2,23 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::io::Read;
 use std::rc::Rc;
+use std::time::Instant;
 use anyhow::{anyhow, Context};
 use deno_core::{op2, OpState, serde_v8, v8};
+use deno_core::url::Url;
+use deno_runtime::deno_permissions::PermissionsContainer;
 use futures::executor::block_on;
 use indexmap::IndexMap;
 use serde::{de, Deserialize, Deserializer, Serialize};
 use serde::de::Error;
 use tokio::runtime::Handle;
-use gauntlet_common::model::{ActionPanelSectionWidget, ActionPanelSectionWidgetOrderedMembers, ActionPanelWidget, ActionPanelWidgetOrderedMembers, ActionWidget, CheckboxWidget, CodeBlockWidget, ContentWidget, ContentWidgetOrderedMembers, DatePickerWidget, DetailWidget, EmptyViewWidget, EntrypointId, FormWidget, FormWidgetOrderedMembers, GridItemWidget, GridSectionWidget, GridSectionWidgetOrderedMembers, GridWidget, GridWidgetOrderedMembers, H1Widget, H2Widget, H3Widget, H4Widget, H5Widget, H6Widget, HorizontalBreakWidget, IconAccessoryWidget, Image, ImageSource, ImageSourceAsset, ImageSourceUrl, ImageWidget, InlineSeparatorWidget, InlineWidget, InlineWidgetOrderedMembers, ListItemAccessories, ListItemWidget, ListSectionWidget, ListSectionWidgetOrderedMembers, ListWidget, ListWidgetOrderedMembers, MetadataIconWidget, MetadataLinkWidget, MetadataSeparatorWidget, MetadataTagItemWidget, MetadataTagListWidget, MetadataTagListWidgetOrderedMembers, MetadataValueWidget, MetadataWidget, MetadataWidgetOrderedMembers, ParagraphWidget, PasswordFieldWidget, PhysicalKey, PluginId, RootWidget, RootWidgetMembers, SearchBarWidget, SelectItemWidget, SelectWidget, SelectWidgetOrderedMembers, SeparatorWidget, TextAccessoryWidget, TextFieldWidget, UiPropertyValue, UiRenderLocation, UiWidgetId, WidgetVisitor};
+use gauntlet_common::model::{ActionPanelSectionWidget, ActionPanelSectionWidgetOrderedMembers, ActionPanelWidget, ActionPanelWidgetOrderedMembers, ActionWidget, CheckboxWidget, CodeBlockWidget, ContentWidget, ContentWidgetOrderedMembers, DatePickerWidget, DetailWidget, EmptyViewWidget, EntrypointId, FormWidget, FormWidgetOrderedMembers, GridItemWidget, GridSectionWidget, GridSectionWidgetOrderedMembers, GridWidget, GridWidgetOrderedMembers, H1Widget, H2Widget, H3Widget, H4Widget, H5Widget, H6Widget, HorizontalBreakWidget, IconAccessoryWidget, Image, ImageSource, ImageSourceAsset, ImageSourceThumbnail, ImageSourceUrl, ImageWidget, InlineSeparatorWidget, InlineWidget, InlineWidgetOrderedMembers, ListItemAccessories, ListItemWidget, ListSectionWidget, ListSectionWidgetOrderedMembers, ListWidget, ListWidgetOrderedMembers, MetadataIconWidget, MetadataLinkWidget, MetadataSeparatorWidget, MetadataTagItemWidget, MetadataTagListWidget, MetadataTagListWidgetOrderedMembers, MetadataValueWidget, MetadataWidget, MetadataWidgetOrderedMembers, ParagraphWidget, PasswordFieldWidget, PhysicalKey, PluginId, RootWidget, RootWidgetMembers, SearchBarWidget, SelectItemWidget, SelectWidget, SelectWidgetOrderedMembers, SeparatorWidget, TextAccessoryWidget, TextFieldWidget, UiPropertyValue, UiRenderLocation, UiWidgetId, WidgetVisitor};
 use gauntlet_component_model::{Component, Property, PropertyType, SharedType};
 use gauntlet_component_model::Component::Root;
 use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
 use crate::component_model::ComponentModel;
-use crate::model::JsUiRenderLocation;
+use crate::component_validation::validate_root_widget;
+use crate::model::{JsEntrypointBadge, JsRenderError, JsSearchResultPreview, JsUiRenderLocation, JsUndoEntryKind};
 use crate::plugin_data::PluginData;
 
 #[op2]
@@ -94,23 +98,23 @@ pub fn op_inline_view_endpoint_id(state: Rc<RefCell<OpState>>) -> Option<String>
 }
 
 #[op2]
+#[serde]
 pub fn op_react_replace_view<'a>(
     scope: &mut v8::HandleScope,
     state: Rc<RefCell<OpState>>,
     #[serde] render_location: JsUiRenderLocation,
-    top_level_view: bool,
+    #[serde] view_stack_depth: usize,
     #[string] entrypoint_id: &str,
     #[serde] container: serde_v8::Value<'a>,
-) -> anyhow::Result<()> {
+    render_time: f64,
+) -> anyhow::Result<Option<JsRenderError>> {
     tracing::trace!(target = "renderer_rs", "Calling op_react_replace_view...");
 
-    let mut deserializer = serde_v8::Deserializer::new(scope, container.v8_value, None);
-
-    let container = RootWidget::deserialize(&mut deserializer)?;
-
     let entrypoint_id = EntrypointId::from_string(entrypoint_id);
 
-    let (api, outer_handle) = {
+    let serialization_start = Instant::now();
+
+    let (api, outer_handle, dev_plugin, validation_result) = {
         let state = state.borrow();
 
         let api = state
@@ -121,7 +125,18 @@ pub fn op_react_replace_view<'a>(
             .borrow::<Handle>()
             .clone();
 
-        (api, outer_handle)
+        let component_model = state
+            .borrow::<ComponentModel>();
+
+        let dev_plugin = state
+            .borrow::<PluginData>()
+            .plugin_id()
+            .to_string()
+            .starts_with("file://");
+
+        let validation_result = validate_root_widget(scope, component_model, container.v8_value);
+
+        (api, outer_handle, dev_plugin, validation_result)
     };
 
     let render_location = match render_location {
@@ -129,17 +144,108 @@ pub fn op_react_replace_view<'a>(
         JsUiRenderLocation::View => UiRenderLocation::View,
     };
 
-    block_on(async move {
-        outer_handle.spawn(async move {
-            api.ui_render(
-                entrypoint_id,
-                render_location,
-                top_level_view,
-                container,
-            ).await
-        }).await
+    // error is also returned to plugin, so that it is visible in plugin logs
+    if let Err(error) = validation_result {
+        let error_copy = error.clone();
+
+        block_on(async move {
+            outer_handle.spawn(async move {
+                api.ui_show_render_error_view(
+                    entrypoint_id,
+                    render_location,
+                    error_copy,
+                ).await
+            }).await
+        })??;
+
+        return Ok(Some(error))
+    }
+
+    let mut deserializer = serde_v8::Deserializer::new(scope, container.v8_value, None);
+
+    let container = RootWidget::deserialize(&mut deserializer)?;
+
+    // thumbnails are generated by server, which doesn't know about permissions of the plugin
+    check_thumbnail_permissions(&state, &container)?;
+
+    let serialization_time = serialization_start.elapsed();
+
+    block_on({
+        let api = api.clone();
+        let outer_handle = outer_handle.clone();
+        let entrypoint_id = entrypoint_id.clone();
+
+        async move {
+            outer_handle.spawn(async move {
+                api.ui_render(
+                    entrypoint_id,
+                    render_location,
+                    view_stack_depth,
+                    container,
+                ).await
+            }).await
+        }
     })??;
 
+    // timings are shown in widget inspector which is only available for dev plugins
+    if dev_plugin && render_location == UiRenderLocation::View {
+        outer_handle.spawn(async move {
+            let serialization_time = serialization_time.as_secs_f64() * 1000.0;
+
+            if let Err(err) = api.ui_render_timing(entrypoint_id, render_time, serialization_time).await {
+                tracing::warn!("unable to send render timing: {:?}", err);
+            }
+        });
+    }
+
+    Ok(None)
+}
+
+#[derive(Default)]
+struct ThumbnailSourceCollector {
+    sources: Vec<String>,
+}
+
+impl WidgetVisitor for ThumbnailSourceCollector {
+    async fn image(&mut self, _widget_id: UiWidgetId, widget: &Image) {
+        if let Image::ImageSource(ImageSource::ImageSourceThumbnail(ImageSourceThumbnail { thumbnail })) = widget {
+            self.sources.push(thumbnail.clone());
+        }
+    }
+}
+
+fn check_thumbnail_permissions(state: &Rc<RefCell<OpState>>, container: &RootWidget) -> anyhow::Result<()> {
+    let mut collector = ThumbnailSourceCollector::default();
+
+    block_on(collector.root_widget(container));
+
+    let mut state = state.borrow_mut();
+    let permissions = state.borrow_mut::<PermissionsContainer>();
+
+    for source in collector.sources {
+        if source.starts_with("http://") || source.starts_with("https://") {
+            let url = Url::parse(&source)
+                .with_context(|| format!("invalid thumbnail url: {}", source))?;
+
+            permissions.check_net_url(&url, "thumbnail")
+                .map_err(|err| anyhow!("{}", err))?;
+        } else {
+            let path = if source.starts_with("file://") {
+                Url::parse(&source)
+                    .ok()
+                    .and_then(|url| url.to_file_path().ok())
+                    .ok_or_else(|| anyhow!("invalid thumbnail file url: {}", source))?
+                    .to_string_lossy()
+                    .to_string()
+            } else {
+                source
+            };
+
+            permissions.check_read(&path, "thumbnail")
+                .map_err(|err| anyhow!("{}", err))?;
+        }
+    }
+
     Ok(())
 }
 
@@ -200,6 +306,21 @@ pub async fn show_hud(state: Rc<RefCell<OpState>>, #[string] display: String) ->
     api.ui_show_hud(display).await
 }
 
+#[op2(async)]
+pub async fn show_plugin_view(state: Rc<RefCell<OpState>>, #[string] entrypoint_id: String) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.ui_show_plugin_view(EntrypointId::from_string(entrypoint_id)).await
+}
+
 #[op2(async)]
 pub async fn update_loading_bar(state: Rc<RefCell<OpState>>, #[string] entrypoint_id: String, show: bool) -> anyhow::Result<()> {
     let api = {
@@ -215,6 +336,54 @@ pub async fn update_loading_bar(state: Rc<RefCell<OpState>>, #[string] entrypoin
     api.ui_update_loading_bar(EntrypointId::from_string(entrypoint_id), show).await
 }
 
+// badge is shown on search result of the entrypoint until it is cleared or plugin is stopped
+#[op2(async)]
+pub async fn set_entrypoint_badge(state: Rc<RefCell<OpState>>, #[string] entrypoint_id: String, #[serde] badge: Option<JsEntrypointBadge>) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.ui_set_entrypoint_badge(EntrypointId::from_string(entrypoint_id), badge).await
+}
+
+// undo function itself is kept in js, server only keeps track of the order
+#[op2(async)]
+pub async fn register_undo(state: Rc<RefCell<OpState>>, #[smi] undo_id: u32, #[string] description: String, #[serde] kind: JsUndoEntryKind) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.ui_register_undo(undo_id, description, kind).await
+}
+
+// none if generated command doesn't exist anymore or its preview function failed
+#[op2(async)]
+pub async fn show_search_result_preview(state: Rc<RefCell<OpState>>, #[string] entrypoint_id: String, #[serde] preview: Option<JsSearchResultPreview>) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.ui_show_search_result_preview(EntrypointId::from_string(entrypoint_id), preview).await
+}
+
 #[allow(unused)]
 fn debug_object_to_json(
     scope: &mut v8::HandleScope,
@@ -0,0 +1,37 @@
+use deno_core::{op2, OpState};
+use std::cell::RefCell;
+use std::rc::Rc;
+use gauntlet_common::model::EntrypointId;
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+
+// values are json serialized on js side, so any serializable value can be stored
+#[op2(async)]
+#[string]
+pub async fn view_state_read(state: Rc<RefCell<OpState>>, #[string] entrypoint_id: String, #[string] key: String) -> anyhow::Result<Option<String>> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.view_state_read(EntrypointId::from_string(entrypoint_id), key).await
+}
+
+#[op2(async)]
+pub async fn view_state_write(state: Rc<RefCell<OpState>>, #[string] entrypoint_id: String, #[string] key: String, #[serde] value: Option<String>) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.view_state_write(EntrypointId::from_string(entrypoint_id), key, value).await
+}
@@ -0,0 +1,309 @@
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{Connection, SqliteConnection};
+use tokio::sync::mpsc::Receiver;
+use wasmtime::component::{Component, Linker, ResourceTable};
+use wasmtime::{Config, Engine, Store};
+use wasmtime_wasi::{OutputFile, WasiCtx, WasiCtxBuilder, WasiView};
+use gauntlet_common::model::{EntrypointId, RootWidget, UiRenderLocation};
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+use crate::events::JsEvent;
+use crate::model::{JsAdditionalSearchItem, JsInit};
+
+wasmtime::component::bindgen!({
+    path: "wit/plugin.wit",
+    world: "plugin",
+    async: true,
+});
+
+use self::gauntlet::plugin::host::{Host, RenderLocation};
+
+// experimental alternative to deno runtime, for plugins written in languages which compile to webassembly components.
+// plugin gets the same backend api as js plugins, but only the part declared in wit/plugin.wit.
+// wasi is available without file system, network and environment variables, stdout and stderr go to plugin log files
+pub const WASM_COMPONENT_ASSET: &'static str = "plugin.wasm";
+
+// storage is shared with localStorage of deno runtime, file name, schema and size limit are the ones used by deno
+const LOCAL_STORAGE_FILE: &'static str = "local_storage";
+const MAX_STORAGE_SIZE: usize = 10 * 1024 * 1024;
+
+// long-running plugin code yields back to event loop this often, so that plugin can be stopped
+const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+pub async fn start_wasm_runtime(
+    init: JsInit,
+    mut event_receiver: Receiver<JsEvent>,
+    api: BackendForPluginRuntimeApiProxy,
+) -> anyhow::Result<()> {
+    let component_bytes = api.get_asset_data(WASM_COMPONENT_ASSET)
+        .await
+        .context("Unable to read webassembly component of the plugin")?;
+
+    let mut config = Config::new();
+    config.async_support(true);
+    config.wasm_component_model(true);
+    config.epoch_interruption(true);
+
+    let engine = Engine::new(&config)?;
+
+    let component = Component::new(&engine, &component_bytes)
+        .context("Unable to compile webassembly component of the plugin")?;
+
+    let mut linker = Linker::new(&engine);
+
+    wasmtime_wasi::add_to_linker_async(&mut linker)?;
+    Plugin::add_to_linker(&mut linker, |state: &mut WasmPluginState| state)?;
+
+    let mut store = Store::new(&engine, WasmPluginState::new(&init, api)?);
+
+    store.epoch_deadline_async_yield_and_update(1);
+
+    let _epoch_ticker = EpochTicker::start(engine.clone());
+
+    let plugin = Plugin::instantiate_async(&mut store, &component, &linker)
+        .await
+        .context("Unable to instantiate webassembly component of the plugin")?;
+
+    plugin.call_init(&mut store)
+        .await?
+        .map_err(|err| anyhow!("Plugin initialization has failed: {}", err))?;
+
+    // trap leaves component in unusable state, so it stops the runtime, errors returned by plugin don't
+    while let Some(event) = event_receiver.recv().await {
+        let event = serde_json::to_string(&event)?;
+
+        if let Err(err) = plugin.call_handle_event(&mut store, &event).await? {
+            tracing::error!("Plugin has failed to handle event: {}", err);
+        }
+    }
+
+    Ok(())
+}
+
+struct WasmPluginState {
+    api: BackendForPluginRuntimeApiProxy,
+    local_storage_dir: PathBuf,
+    local_storage: Option<SqliteConnection>,
+    wasi: WasiCtx,
+    table: ResourceTable,
+}
+
+impl WasmPluginState {
+    fn new(init: &JsInit, api: BackendForPluginRuntimeApiProxy) -> anyhow::Result<Self> {
+        let mut wasi = WasiCtxBuilder::new();
+
+        match &init.stdout_file {
+            Some(stdout_file) => wasi.stdout(OutputFile::new(create_log_file(stdout_file)?)),
+            None => wasi.inherit_stdout(),
+        };
+
+        match &init.stderr_file {
+            Some(stderr_file) => wasi.stderr(OutputFile::new(create_log_file(stderr_file)?)),
+            None => wasi.inherit_stderr(),
+        };
+
+        Ok(Self {
+            api,
+            local_storage_dir: PathBuf::from(&init.local_storage_dir),
+            local_storage: None,
+            wasi: wasi.build(),
+            table: ResourceTable::new(),
+        })
+    }
+
+    // opened on first use, most plugins don't need storage
+    async fn local_storage(&mut self) -> anyhow::Result<&mut SqliteConnection> {
+        if self.local_storage.is_none() {
+            std::fs::create_dir_all(&self.local_storage_dir)?;
+
+            let options = SqliteConnectOptions::new()
+                .filename(self.local_storage_dir.join(LOCAL_STORAGE_FILE))
+                .create_if_missing(true);
+
+            let mut conn = SqliteConnection::connect_with(&options)
+                .await
+                .context("Unable to open plugin storage")?;
+
+            // language=SQLite
+            sqlx::query("CREATE TABLE IF NOT EXISTS data (key VARCHAR UNIQUE, value VARCHAR)")
+                .execute(&mut conn)
+                .await?;
+
+            self.local_storage = Some(conn);
+        }
+
+        Ok(self.local_storage.as_mut().unwrap())
+    }
+
+    async fn read_storage(&mut self, key: &str) -> anyhow::Result<Option<String>> {
+        let conn = self.local_storage().await?;
+
+        // language=SQLite
+        let value = sqlx::query_scalar::<_, String>("SELECT value FROM data WHERE key = ?1")
+            .bind(key)
+            .fetch_optional(conn)
+            .await?;
+
+        Ok(value)
+    }
+
+    async fn write_storage(&mut self, key: &str, value: Option<&str>) -> anyhow::Result<()> {
+        let conn = self.local_storage().await?;
+
+        let Some(value) = value else {
+            // language=SQLite
+            sqlx::query("DELETE FROM data WHERE key = ?1")
+                .bind(key)
+                .execute(conn)
+                .await?;
+
+            return Ok(())
+        };
+
+        // language=SQLite
+        let size = sqlx::query_scalar::<_, i64>("SELECT COALESCE(SUM(LENGTH(key) + LENGTH(value)), 0) FROM data WHERE key != ?1")
+            .bind(key)
+            .fetch_one(&mut *conn)
+            .await?;
+
+        if size as usize + key.len() + value.len() > MAX_STORAGE_SIZE {
+            return Err(anyhow!("Storage cannot be larger than {} bytes", MAX_STORAGE_SIZE))
+        }
+
+        // language=SQLite
+        sqlx::query("INSERT OR REPLACE INTO data (key, value) VALUES (?1, ?2)")
+            .bind(key)
+            .bind(value)
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl WasiView for WasmPluginState {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.wasi
+    }
+}
+
+#[async_trait::async_trait]
+impl Host for WasmPluginState {
+    async fn plugin_preferences(&mut self) -> Result<String, String> {
+        let preferences = self.api.get_plugin_preferences()
+            .await
+            .map_err(error_to_string)?;
+
+        serde_json::to_string(&preferences)
+            .map_err(error_to_string)
+    }
+
+    async fn entrypoint_preferences(&mut self, entrypoint_id: String) -> Result<String, String> {
+        let preferences = self.api.get_entrypoint_preferences(EntrypointId::from_string(entrypoint_id))
+            .await
+            .map_err(error_to_string)?;
+
+        serde_json::to_string(&preferences)
+            .map_err(error_to_string)
+    }
+
+    async fn reload_search_index(&mut self, items: String, refresh_search_list: bool) -> Result<(), String> {
+        let items: Vec<JsAdditionalSearchItem> = serde_json::from_str(&items)
+            .map_err(error_to_string)?;
+
+        self.api.reload_search_index(items, refresh_search_list)
+            .await
+            .map_err(error_to_string)
+    }
+
+    async fn render(&mut self, entrypoint_id: String, location: RenderLocation, view_stack_depth: u32, container: String) -> Result<(), String> {
+        let container: RootWidget = serde_json::from_str(&container)
+            .map_err(error_to_string)?;
+
+        let render_location = match location {
+            RenderLocation::InlineView => UiRenderLocation::InlineView,
+            RenderLocation::View => UiRenderLocation::View,
+        };
+
+        self.api.ui_render(EntrypointId::from_string(entrypoint_id), render_location, view_stack_depth as usize, container)
+            .await
+            .map_err(error_to_string)
+    }
+
+    async fn clear_inline_view(&mut self) -> Result<(), String> {
+        self.api.ui_clear_inline_view()
+            .await
+            .map_err(error_to_string)
+    }
+
+    async fn show_hud(&mut self, display: String) -> Result<(), String> {
+        self.api.ui_show_hud(display)
+            .await
+            .map_err(error_to_string)
+    }
+
+    async fn storage_get(&mut self, key: String) -> Result<Option<String>, String> {
+        self.read_storage(&key)
+            .await
+            .map_err(error_to_string)
+    }
+
+    async fn storage_set(&mut self, key: String, value: Option<String>) -> Result<(), String> {
+        self.write_storage(&key, value.as_deref())
+            .await
+            .map_err(error_to_string)
+    }
+}
+
+// engine epoch is incremented from separate thread because plugin code blocks the thread it runs on
+struct EpochTicker {
+    stopped: Arc<AtomicBool>,
+}
+
+impl EpochTicker {
+    fn start(engine: Engine) -> Self {
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let thread_stopped = stopped.clone();
+
+        std::thread::spawn(move || {
+            while !thread_stopped.load(Ordering::SeqCst) {
+                std::thread::sleep(EPOCH_TICK);
+
+                engine.increment_epoch();
+            }
+        });
+
+        Self {
+            stopped,
+        }
+    }
+}
+
+impl Drop for EpochTicker {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+fn create_log_file(path: &str) -> anyhow::Result<File> {
+    let path = PathBuf::from(path);
+
+    std::fs::create_dir_all(path.parent().unwrap())?;
+
+    Ok(File::create(path)?)
+}
+
+fn error_to_string(err: impl std::fmt::Display) -> String {
+    format!("{:#}", err)
+}
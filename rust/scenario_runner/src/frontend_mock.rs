@@ -1,14 +1,18 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use gauntlet_common::model::{BackendRequestData, BackendResponseData, EntrypointId, PluginId, UiRequestData, UiResponseData};
+use gauntlet_common::model::{BackendRequestData, BackendResponseData, EntrypointId, KeyboardEventOrigin, PhysicalKey, PluginId, UiPropertyValue, UiRenderLocation, UiRequestData, UiResponseData};
 use gauntlet_common::rpc::backend_api::{BackendApi, BackendForFrontendApi};
 use gauntlet_common::rpc::backend_server::wait_for_backend_server;
 use gauntlet_common::scenario_convert::{ui_render_location_to_scenario};
 use gauntlet_common::scenario_model::ScenarioFrontendEvent;
 use gauntlet_utils::channel::{RequestReceiver, RequestSender};
 
-use crate::model::ScenarioBackendEvent;
+use crate::model::{ScenarioBackendEvent, ScenarioFile, ScenarioKeyboardEventOrigin, ScenarioStep};
+
+// renders are asynchronous, scenario fails if nothing is rendered during this time
+const FRONTEND_EVENT_TIMEOUT: Duration = Duration::from_secs(30);
 
 pub async fn start_scenario_runner_frontend(
     request_receiver: RequestReceiver<UiRequestData, UiResponseData>,
@@ -20,6 +24,10 @@ pub async fn start_scenario_runner_frontend(
     let plugin_name = std::env::var("GAUNTLET_SCENARIO_PLUGIN_NAME")
         .expect("Unable to read GAUNTLET_SCENARIO_PLUGIN_NAME");
 
+    // when set, snapshots are also compared with expected ones and runner fails on mismatch
+    let check = std::env::var("GAUNTLET_SCENARIO_CHECK")
+        .is_ok_and(|value| value == "true");
+
     let scenario_dir = Path::new(&scenario_dir);
 
     let scenario_plugin_dir = scenario_dir
@@ -40,13 +48,25 @@ pub async fn start_scenario_runner_frontend(
         .join("out")
         .join(&plugin_name);
 
+    let scenario_expected_dir = scenario_dir
+        .join("expected")
+        .join(&plugin_name);
+
     fs::create_dir_all(&scenario_out_dir)
         .expect("unable to create scenario_out_dir");
 
-    let (sender, mut receiver) = tokio::sync::mpsc::channel(1);
+    let mut output = ScenarioOutput {
+        out_dir: scenario_out_dir,
+        expected_dir: check.then_some(scenario_expected_dir),
+        mismatches: vec![],
+    };
+
+    let (sender, receiver) = tokio::sync::mpsc::channel(100);
+
+    let images_backend_client = BackendForFrontendApi::new(backend_sender.clone());
 
     tokio::spawn(async move {
-        request_loop(request_receiver, sender).await
+        request_loop(request_receiver, sender, images_backend_client).await
     });
 
     println!("waiting for backend");
@@ -64,6 +84,14 @@ pub async fn start_scenario_runner_frontend(
 
     println!("local plugin saved");
 
+    let plugin_id = PluginId::from_string(format!("file://{scenario_plugin_dir}"));
+
+    let mut frontend_events = FrontendEvents {
+        receiver,
+        latest: None,
+        awaiting: false,
+    };
+
     for entrypoint in fs::read_dir(&scenario_data_dir)? {
         let entrypoint = entrypoint?;
         if !entrypoint.metadata()?.is_dir() {
@@ -77,6 +105,8 @@ pub async fn start_scenario_runner_frontend(
 
         println!("entrypoint: {}", &entrypoint_name);
 
+        let entrypoint_id = EntrypointId::from_string(&entrypoint_name);
+
         for scenario in fs::read_dir(&entrypoint.path())? {
             let scenario = scenario?;
             if !scenario.metadata()?.is_file() {
@@ -92,26 +122,53 @@ pub async fn start_scenario_runner_frontend(
             let scenario_data = fs::read(&scenario_path)
                 .expect("unable to read scenario scenario from file");
 
-            let event: ScenarioBackendEvent = serde_json::from_slice(&scenario_data)
-                .expect("unable to deserialize scenario event");
+            let scenario_file: ScenarioFile = serde_json::from_slice(&scenario_data)
+                .expect("unable to deserialize scenario");
 
-            match event {
-                ScenarioBackendEvent::Search { text } => {
-                    backend_for_frontend_client.search(text, true).await?;
-                }
-                ScenarioBackendEvent::RequestViewRender => {
-                    let plugin_id = PluginId::from_string(format!("file://{scenario_plugin_dir}"));
-                    let entrypoint_id = EntrypointId::from_string(&entrypoint_name);
+            match scenario_file {
+                ScenarioFile::Event(event) => {
+                    frontend_events.before_action();
+
+                    match event {
+                        ScenarioBackendEvent::Search { text } => {
+                            backend_for_frontend_client.search(text, None, None, true, 0).await?;
+                        }
+                        ScenarioBackendEvent::RequestViewRender => {
+                            backend_for_frontend_client.request_view_render(plugin_id.clone(), entrypoint_id.clone()).await?;
+                        }
+                    }
+
+                    println!("waiting for scenario to finish");
+
+                    let event = frontend_events.next().await;
 
-                    backend_for_frontend_client.request_view_render(plugin_id, entrypoint_id).await?;
+                    output.save_event(scenario_name, event);
                 }
-            }
+                ScenarioFile::Script(script) => {
+                    for step in script.steps {
+                        println!("step: {:?}", &step);
+
+                        match step {
+                            ScenarioStep::Snapshot { name } => {
+                                let event = frontend_events.latest().await;
+
+                                output.save_event(format!("{}-{}", scenario_name, name), event);
+
+                                continue
+                            }
+                            step => {
+                                frontend_events.before_action();
 
-            println!("waiting for scenario to finish");
+                                run_step(&mut backend_for_frontend_client, &plugin_id, &entrypoint_id, step).await?;
+                            }
+                        }
+                    }
 
-            match receiver.recv().await {
-                None => unreachable!(),
-                Some(event) => save_event(&scenario_out_dir, scenario_name, event)
+                    // view is closed so next scenario starts from clean state
+                    backend_for_frontend_client.request_view_close(plugin_id.clone(), entrypoint_id.clone()).await?;
+
+                    frontend_events.before_action();
+                }
             }
 
             println!("scenario finished");
@@ -120,41 +177,183 @@ pub async fn start_scenario_runner_frontend(
 
     println!("all scenarios done");
 
+    if !output.mismatches.is_empty() {
+        for mismatch in &output.mismatches {
+            eprintln!("snapshot mismatch: {}", mismatch);
+        }
+
+        std::process::exit(1)
+    }
+
     std::process::exit(0)
 }
 
-fn save_event(scenario_out_dir: &Path, scenario_name: String, event: ScenarioFrontendEvent) {
-    let json = serde_json::to_string_pretty(&event)
-        .expect("unable to serialize scenario event");
+async fn run_step(
+    backend_for_frontend_client: &mut BackendForFrontendApi,
+    plugin_id: &PluginId,
+    entrypoint_id: &EntrypointId,
+    step: ScenarioStep
+) -> anyhow::Result<()> {
+    match step {
+        ScenarioStep::Search { text } => {
+            backend_for_frontend_client.search(text, None, None, true, 0).await?;
+        }
+        ScenarioStep::RequestViewRender => {
+            backend_for_frontend_client.request_view_render(plugin_id.clone(), entrypoint_id.clone()).await?;
+        }
+        ScenarioStep::RequestViewClose => {
+            backend_for_frontend_client.request_view_close(plugin_id.clone(), entrypoint_id.clone()).await?;
+        }
+        ScenarioStep::KeyboardEvent { key, origin, shift, control, alt, meta } => {
+            let origin = match origin {
+                ScenarioKeyboardEventOrigin::MainView => KeyboardEventOrigin::MainView,
+                ScenarioKeyboardEventOrigin::PluginView => KeyboardEventOrigin::PluginView,
+            };
+
+            backend_for_frontend_client.send_keyboard_event(
+                plugin_id.clone(),
+                entrypoint_id.clone(),
+                origin,
+                PhysicalKey::from_value(key),
+                shift,
+                control,
+                alt,
+                meta
+            ).await?;
+        }
+        ScenarioStep::ViewEvent { widget_id, event_name, event_arguments } => {
+            let event_arguments = event_arguments.into_iter()
+                .map(|value| json_to_property_value(value))
+                .collect();
 
-    let entrypoint_id = match event {
-        ScenarioFrontendEvent::ReplaceView { entrypoint_id, .. } => entrypoint_id,
-        ScenarioFrontendEvent::ShowPreferenceRequiredView { entrypoint_id, .. } => entrypoint_id,
-        ScenarioFrontendEvent::ShowPluginErrorView { entrypoint_id, .. } => entrypoint_id,
-    };
+            backend_for_frontend_client.send_view_event(plugin_id.clone(), entrypoint_id.clone(), UiRenderLocation::View, widget_id, event_name, event_arguments).await?;
+        }
+        ScenarioStep::Snapshot { .. } => unreachable!()
+    }
 
-    let out_dir = Path::new(scenario_out_dir)
-        .join(entrypoint_id);
+    Ok(())
+}
 
-    fs::create_dir_all(&out_dir)
-        .expect("Unable to create scenario out dir");
+fn json_to_property_value(value: serde_json::Value) -> UiPropertyValue {
+    match value {
+        serde_json::Value::Null => UiPropertyValue::Undefined,
+        serde_json::Value::Bool(value) => UiPropertyValue::Bool(value),
+        serde_json::Value::Number(value) => UiPropertyValue::Number(value.as_f64().unwrap_or_default()),
+        serde_json::Value::String(value) => UiPropertyValue::String(value),
+        serde_json::Value::Array(value) => UiPropertyValue::Array(value.into_iter().map(|value| json_to_property_value(value)).collect()),
+        serde_json::Value::Object(value) => UiPropertyValue::Object(value.into_iter().map(|(key, value)| (key, json_to_property_value(value))).collect()),
+    }
+}
 
-    let out_path = out_dir
-        .join(format!("{}.json", scenario_name));
+// records everything frontend was asked to show, so snapshot contains the latest state
+struct FrontendEvents {
+    receiver: tokio::sync::mpsc::Receiver<ScenarioFrontendEvent>,
+    latest: Option<ScenarioFrontendEvent>,
+    awaiting: bool,
+}
 
-    fs::write(&out_path, json)
-        .expect("unable to write scenario event to file");
+impl FrontendEvents {
+    async fn next(&mut self) -> ScenarioFrontendEvent {
+        tokio::time::timeout(FRONTEND_EVENT_TIMEOUT, self.receiver.recv())
+            .await
+            .expect("timed out waiting for frontend event")
+            .expect("frontend request loop stopped")
+    }
+
+    fn drain(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            self.latest = Some(event);
+        }
+    }
+
+    // events rendered before the action are not the result of it
+    fn before_action(&mut self) {
+        self.drain();
+        self.awaiting = true;
+    }
+
+    async fn latest(&mut self) -> ScenarioFrontendEvent {
+        if self.awaiting || self.latest.is_none() {
+            let event = self.next().await;
+            self.latest = Some(event);
+            self.awaiting = false;
+        }
+
+        self.drain();
+
+        self.latest.take().expect("no frontend event recorded")
+    }
+}
+
+struct ScenarioOutput {
+    out_dir: PathBuf,
+    expected_dir: Option<PathBuf>,
+    mismatches: Vec<String>,
+}
+
+impl ScenarioOutput {
+    fn save_event(&mut self, scenario_name: String, event: ScenarioFrontendEvent) {
+        let json = serde_json::to_string_pretty(&event)
+            .expect("unable to serialize scenario event");
+
+        let entrypoint_id = match event {
+            ScenarioFrontendEvent::ReplaceView { entrypoint_id, .. } => entrypoint_id,
+            ScenarioFrontendEvent::ShowPreferenceRequiredView { entrypoint_id, .. } => entrypoint_id,
+            ScenarioFrontendEvent::ShowPluginErrorView { entrypoint_id, .. } => entrypoint_id,
+        };
+
+        let out_dir = self.out_dir
+            .join(&entrypoint_id);
+
+        fs::create_dir_all(&out_dir)
+            .expect("Unable to create scenario out dir");
+
+        let file_name = format!("{}.json", scenario_name);
+
+        let out_path = out_dir
+            .join(&file_name);
+
+        fs::write(&out_path, &json)
+            .expect("unable to write scenario event to file");
+
+        if let Some(expected_dir) = &self.expected_dir {
+            let expected_path = expected_dir
+                .join(&entrypoint_id)
+                .join(&file_name);
+
+            let Ok(expected) = fs::read(&expected_path) else {
+                self.mismatches.push(format!("{:?} doesn't have expected snapshot at {:?}", out_path, expected_path));
+                return;
+            };
+
+            // compared as values so formatting of expected file doesn't matter
+            let expected: serde_json::Value = serde_json::from_slice(&expected)
+                .expect("unable to deserialize expected snapshot");
+
+            let actual: serde_json::Value = serde_json::from_str(&json)
+                .expect("unable to deserialize snapshot");
+
+            if expected != actual {
+                self.mismatches.push(format!("{:?} is different from {:?}", out_path, expected_path));
+            }
+        }
+    }
 }
 
-async fn request_loop(mut request_receiver: RequestReceiver<UiRequestData, UiResponseData>, scenario_sender: tokio::sync::mpsc::Sender<ScenarioFrontendEvent>) {
+async fn request_loop(
+    mut request_receiver: RequestReceiver<UiRequestData, UiResponseData>,
+    scenario_sender: tokio::sync::mpsc::Sender<ScenarioFrontendEvent>,
+    backend_client: BackendForFrontendApi,
+) {
     loop {
         let (request_data, responder) = request_receiver.recv().await;
 
         match request_data {
-            UiRequestData::UpdateLoadingBar { .. } | UiRequestData::ShowHud { .. } | UiRequestData::ShowWindow | UiRequestData::ClearInlineView { .. } => {
-                unreachable!()
+            // scripted scenarios can trigger these, but they don't affect rendered view
+            UiRequestData::UpdateLoadingBar { .. } | UiRequestData::ShowHud { .. } | UiRequestData::ShowUndoToast { .. } | UiRequestData::ShowRenderTiming { .. } | UiRequestData::ShowArgumentSuggestions { .. } | UiRequestData::ShowWindow | UiRequestData::HideWindow | UiRequestData::ToggleWindow | UiRequestData::Quit | UiRequestData::PreviewTheme { .. } | UiRequestData::ShowPluginView { .. } | UiRequestData::ClearInlineView { .. } => {
+                // noop
             }
-            UiRequestData::SetGlobalShortcut { .. } | UiRequestData::RequestSearchResultUpdate => {
+            UiRequestData::SetGlobalShortcut { .. } | UiRequestData::UpdateActionShortcuts { .. } | UiRequestData::ShowSearchResultPreview { .. } | UiRequestData::AppendSearchResults { .. } | UiRequestData::RequestSearchResultUpdate | UiRequestData::ShowConfigError { .. } | UiRequestData::ConfigReloaded | UiRequestData::SessionLockChanged { .. } | UiRequestData::ShowDatabaseRecoveryView { .. } | UiRequestData::ShowWhatsNewView { .. } | UiRequestData::ShowRecoveryView { .. } => {
                 // noop
             }
             UiRequestData::ReplaceView {
@@ -163,14 +362,31 @@ async fn request_loop(mut request_receiver: RequestReceiver<UiRequestData, UiRes
                 entrypoint_id,
                 entrypoint_name: _,
                 render_location,
-                top_level_view,
+                view_stack_depth,
                 container,
                 images
             } => {
+                // snapshots keep image bytes instead of ids, so they don't depend on image cache state
+                let image_ids = images.values()
+                    .cloned()
+                    .collect();
+
+                let image_data = backend_client.get_images(image_ids)
+                    .await
+                    .expect("unable to get images");
+
+                let images = images.into_iter()
+                    .filter_map(|(widget_id, image_id)| {
+                        let data = image_data.get(&image_id)?;
+
+                        Some((widget_id, data.to_vec()))
+                    })
+                    .collect();
+
                 let event = ScenarioFrontendEvent::ReplaceView {
                     entrypoint_id: entrypoint_id.to_string(),
                     render_location: ui_render_location_to_scenario(render_location),
-                    top_level_view,
+                    view_stack_depth,
                     container,
                     images,
                 };
@@ -189,6 +405,9 @@ async fn request_loop(mut request_receiver: RequestReceiver<UiRequestData, UiRes
                     .await
                     .expect("send failed")
             }
+            UiRequestData::ShowRenderErrorView { plugin_id: _, entrypoint_id, message, component_path } => {
+                panic!("entrypoint {} rendered invalid view: {} at {}", entrypoint_id.to_string(), message, component_path.join(" > "))
+            }
             UiRequestData::ShowPreferenceRequiredView { plugin_id: _, entrypoint_id, plugin_preferences_required, entrypoint_preferences_required } => {
                 let event = ScenarioFrontendEvent::ShowPreferenceRequiredView {
                     entrypoint_id: entrypoint_id.to_string(),
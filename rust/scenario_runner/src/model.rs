@@ -1,4 +1,12 @@
 use serde::{Deserialize, Serialize};
+use gauntlet_common::model::UiWidgetId;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ScenarioFile {
+    Script(ScenarioScript),
+    Event(ScenarioBackendEvent),
+}
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "type")]
@@ -8,3 +16,48 @@ pub enum ScenarioBackendEvent {
     },
     RequestViewRender,
 }
+
+// steps are executed in order, every snapshot step produces separate output file
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ScenarioScript {
+    pub steps: Vec<ScenarioStep>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum ScenarioStep {
+    Search {
+        text: String
+    },
+    RequestViewRender,
+    RequestViewClose,
+    KeyboardEvent {
+        key: String,
+        #[serde(default)]
+        origin: ScenarioKeyboardEventOrigin,
+        #[serde(default)]
+        shift: bool,
+        #[serde(default)]
+        control: bool,
+        #[serde(default)]
+        alt: bool,
+        #[serde(default)]
+        meta: bool,
+    },
+    ViewEvent {
+        widget_id: UiWidgetId,
+        event_name: String,
+        #[serde(default)]
+        event_arguments: Vec<serde_json::Value>,
+    },
+    Snapshot {
+        name: String
+    },
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub enum ScenarioKeyboardEventOrigin {
+    MainView,
+    #[default]
+    PluginView,
+}
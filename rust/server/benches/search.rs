@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use gauntlet_common::model::{EntrypointId, PluginId, SearchResultCategory, SearchResultEntrypointType};
+use gauntlet_common::rpc::frontend_api::RecordingFrontendApi;
+use gauntlet_server::bench::{SearchIndex, SearchIndexItem, SearchIndexItemRanking};
+
+const PLUGINS: usize = 10;
+const ENTRIES_PER_PLUGIN: usize = 1_000;
+
+const WORDS: [&str; 16] = [
+    "open", "file", "window", "settings", "calculator", "terminal", "music", "browser",
+    "notes", "clipboard", "color", "picker", "system", "monitor", "погода", "café",
+];
+
+fn entry_name(index: usize) -> String {
+    let first = WORDS[index % WORDS.len()];
+    let second = WORDS[(index / WORDS.len()) % WORDS.len()];
+    let third = WORDS[(index / (WORDS.len() * WORDS.len())) % WORDS.len()];
+
+    format!("{} {} {} {}", first, second, third, index)
+}
+
+fn search_item(index: usize) -> SearchIndexItem {
+    SearchIndexItem {
+        entrypoint_type: SearchResultEntrypointType::GeneratedCommand,
+        entrypoint_name: entry_name(index),
+        entrypoint_id: EntrypointId::from_string(format!("entrypoint-{}", index)),
+        entrypoint_icon_path: None,
+        entrypoint_frecency: (index % 7) as f64,
+        entrypoint_actions: vec![],
+        entrypoint_accessory: None,
+        entrypoint_preview: false,
+        entrypoint_drag: None,
+        entrypoint_arguments: vec![],
+        entrypoint_fallback: false,
+        entrypoint_hidden: false,
+        entrypoint_generator_id: Some(EntrypointId::from_string("generator")),
+        entrypoint_ranking: SearchIndexItemRanking {
+            keywords: vec![WORDS[(index + 3) % WORDS.len()].to_string()],
+            ..SearchIndexItemRanking::default()
+        },
+        entrypoint_dedupe_key: None,
+        entrypoint_category: if index % 2 == 0 { SearchResultCategory::Command } else { SearchResultCategory::File },
+        entrypoint_aliases: vec![],
+    }
+}
+
+fn create_search_index() -> SearchIndex {
+    let search_index = SearchIndex::create_index(Arc::new(RecordingFrontendApi::new()))
+        .expect("unable to create search index");
+
+    for plugin in 0..PLUGINS {
+        let items = (0..ENTRIES_PER_PLUGIN)
+            .map(|index| search_item(plugin * ENTRIES_PER_PLUGIN + index))
+            .collect();
+
+        search_index.save_for_plugin(PluginId::from_string(format!("plugin-{}", plugin)), format!("Plugin {}", plugin), items, false)
+            .expect("unable to save search items");
+    }
+
+    search_index
+}
+
+fn search(c: &mut Criterion) {
+    let search_index = create_search_index();
+
+    let mut group = c.benchmark_group("search_10k");
+
+    for query in ["", "o", "open", "window set", "calculator terminal music", "pogoda", "no match at all"] {
+        group.bench_with_input(BenchmarkId::new("all", query), query, |b, query| {
+            b.iter(|| search_index.search(black_box(query), None, true).expect("search failed"))
+        });
+    }
+
+    group.bench_function("category", |b| {
+        b.iter(|| search_index.search(black_box("file"), Some(SearchResultCategory::File), true).expect("search failed"))
+    });
+
+    group.bench_function("no_transliteration", |b| {
+        b.iter(|| search_index.search(black_box("open"), None, false).expect("search failed"))
+    });
+
+    group.finish();
+}
+
+fn save_for_plugin(c: &mut Criterion) {
+    let search_index = create_search_index();
+
+    let items: Vec<_> = (0..ENTRIES_PER_PLUGIN)
+        .map(search_item)
+        .collect();
+
+    c.bench_function("save_for_plugin_1k", |b| {
+        b.iter(|| {
+            search_index.save_for_plugin(PluginId::from_string("plugin-0"), "Plugin 0".to_string(), items.clone(), false)
+                .expect("unable to save search items")
+        })
+    });
+}
+
+criterion_group!(benches, search, save_for_plugin);
+criterion_main!(benches);
@@ -0,0 +1 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN fallback BOOLEAN NOT NULL DEFAULT FALSE;
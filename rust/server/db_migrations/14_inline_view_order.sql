@@ -0,0 +1,7 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN priority INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE inline_view_order
+(
+    plugin_id TEXT    NOT NULL PRIMARY KEY REFERENCES plugin (id) ON DELETE CASCADE,
+    position  INTEGER NOT NULL
+);
@@ -0,0 +1 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN hidden BOOLEAN NOT NULL DEFAULT FALSE;
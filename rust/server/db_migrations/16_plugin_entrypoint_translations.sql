@@ -0,0 +1 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN translations JSON NOT NULL DEFAULT ('{}');
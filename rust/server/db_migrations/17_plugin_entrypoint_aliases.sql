@@ -0,0 +1 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN aliases JSON NOT NULL DEFAULT ('[]');
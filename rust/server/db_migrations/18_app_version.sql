@@ -0,0 +1,7 @@
+CREATE TABLE app_version
+(
+    id                TEXT    NOT NULL, -- should always be "app_version"
+    last_seen_version INTEGER NOT NULL,
+
+    PRIMARY KEY (id)
+);
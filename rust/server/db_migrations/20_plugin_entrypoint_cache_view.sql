@@ -0,0 +1 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN cache_view BOOLEAN NOT NULL DEFAULT FALSE;
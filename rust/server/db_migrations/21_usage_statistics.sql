@@ -0,0 +1,15 @@
+CREATE TABLE usage_entrypoint_launch
+(
+    plugin_id     TEXT    NOT NULL,
+    entrypoint_id TEXT    NOT NULL,
+    launched_at   INTEGER NOT NULL -- unix timestamp in seconds
+);
+
+CREATE INDEX usage_entrypoint_launch_launched_at ON usage_entrypoint_launch (launched_at);
+
+CREATE TABLE usage_search_daily
+(
+    day                  TEXT    NOT NULL PRIMARY KEY, -- YYYY-MM-DD in local time
+    search_count         INTEGER NOT NULL,
+    total_latency_micros INTEGER NOT NULL
+);
@@ -0,0 +1,9 @@
+CREATE TABLE plugin_entrypoint_view_state
+(
+    plugin_id     TEXT NOT NULL REFERENCES plugin (id) ON DELETE CASCADE,
+    entrypoint_id TEXT NOT NULL,
+    key           TEXT NOT NULL,
+    value         TEXT NOT NULL, -- json serialized by plugin
+
+    PRIMARY KEY (plugin_id, entrypoint_id, key)
+);
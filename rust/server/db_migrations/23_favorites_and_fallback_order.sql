@@ -0,0 +1,17 @@
+CREATE TABLE plugin_entrypoint_favorite
+(
+    plugin_id     TEXT    NOT NULL REFERENCES plugin (id) ON DELETE CASCADE,
+    entrypoint_id TEXT    NOT NULL,
+    position      INTEGER NOT NULL,
+
+    PRIMARY KEY (plugin_id, entrypoint_id)
+);
+
+CREATE TABLE fallback_order
+(
+    plugin_id     TEXT    NOT NULL REFERENCES plugin (id) ON DELETE CASCADE,
+    entrypoint_id TEXT    NOT NULL,
+    position      INTEGER NOT NULL,
+
+    PRIMARY KEY (plugin_id, entrypoint_id)
+);
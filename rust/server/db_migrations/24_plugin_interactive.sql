@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN interactive BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,8 @@
+-- user data of plugins removed without purging it, restored when plugin with the same id is installed again
+CREATE TABLE removed_plugin_data
+(
+    id                                TEXT NOT NULL PRIMARY KEY,
+    uuid                              TEXT NOT NULL,
+    preferences_user_data             JSON NOT NULL,
+    entrypoint_preferences_user_data  JSON NOT NULL
+);
@@ -0,0 +1,7 @@
+CREATE TABLE macro
+(
+    id       TEXT    NOT NULL PRIMARY KEY,
+    name     TEXT    NOT NULL,
+    steps    TEXT    NOT NULL, -- json
+    position INTEGER NOT NULL
+);
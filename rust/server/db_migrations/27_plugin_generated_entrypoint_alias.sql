@@ -0,0 +1,8 @@
+CREATE TABLE plugin_generated_entrypoint_alias
+(
+    plugin_id     TEXT NOT NULL REFERENCES plugin (id) ON DELETE CASCADE,
+    entrypoint_id TEXT NOT NULL,
+    aliases       TEXT NOT NULL, -- json
+
+    PRIMARY KEY (plugin_id, entrypoint_id)
+);
@@ -0,0 +1,10 @@
+CREATE TABLE plugin_permission_grant
+(
+    plugin_id  TEXT    NOT NULL REFERENCES plugin (id) ON DELETE CASCADE,
+    kind       TEXT    NOT NULL,
+    value      TEXT    NOT NULL,
+    granted_at INTEGER,          -- null for plugins installed before grants were recorded
+    revoked    INTEGER NOT NULL,
+
+    PRIMARY KEY (plugin_id, kind, value)
+);
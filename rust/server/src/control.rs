@@ -0,0 +1,278 @@
+use std::io::{BufRead, BufReader, Write};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use interprocess::local_socket::tokio::{RecvHalf, SendHalf};
+use interprocess::local_socket::traits::tokio::{Listener, Stream};
+use interprocess::local_socket::{ListenerOptions, Name};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio_util::sync::CancellationToken;
+
+use gauntlet_common::model::{EntrypointId, PluginId, SearchResultCategory, SearchResultEntrypointType};
+
+use crate::plugins::ApplicationManager;
+
+// Control socket for external tools, e.g. window manager keybindings or scripts.
+// Protocol is newline delimited json, one request per line, one response per line:
+//
+//   echo '{"type":"toggle_window"}' | socat - UNIX-CONNECT:/run/user/1000/project-gauntlet-control.sock
+//
+// Unlike grpc api used by management client, requests and responses here are considered stable
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ControlRequest {
+    ShowWindow,
+    HideWindow,
+    ToggleWindow,
+    OpenEntrypoint {
+        plugin_id: String,
+        entrypoint_id: String,
+    },
+    Search {
+        query: String,
+    },
+    Plugins,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ControlResponse {
+    Ok,
+    Error {
+        message: String,
+    },
+    SearchResults {
+        results: Vec<ControlSearchResult>,
+    },
+    Plugins {
+        plugins: Vec<ControlPlugin>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ControlSearchResult {
+    pub plugin_id: String,
+    pub plugin_name: String,
+    pub entrypoint_id: String,
+    pub entrypoint_name: String,
+    pub entrypoint_type: String,
+    // labels of actions of generated commands, primary action first
+    #[serde(default)]
+    pub entrypoint_actions: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ControlPlugin {
+    plugin_id: String,
+    plugin_name: String,
+    enabled: bool,
+    entrypoints: Vec<ControlEntrypoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ControlEntrypoint {
+    entrypoint_id: String,
+    entrypoint_name: String,
+    enabled: bool,
+}
+
+// listener is dropped when shutdown token is cancelled, which also removes socket file
+pub(in crate) async fn start_control_server(application_manager: Arc<ApplicationManager>, shutdown_token: CancellationToken) -> anyhow::Result<()> {
+    // socket file is left behind if server wasn't stopped cleanly,
+    // at this point we know that no other server is running
+    #[cfg(unix)]
+    {
+        use gauntlet_common::dirs::Dirs;
+
+        let _ = std::fs::remove_file(Dirs::new().control_socket());
+    }
+
+    let listener = ListenerOptions::new()
+        .name(control_socket_name()?)
+        .create_tokio()
+        .context("unable to create control socket")?;
+
+    loop {
+        let conn = tokio::select! {
+            conn = listener.accept() => conn,
+            _ = shutdown_token.cancelled() => return Ok(()),
+        };
+
+        let conn = match conn {
+            Ok(conn) => conn,
+            Err(err) => {
+                tracing::warn!("unable to accept control socket connection: {:?}", err);
+                continue;
+            }
+        };
+
+        let application_manager = application_manager.clone();
+
+        tokio::spawn(async move {
+            let (recv, send) = conn.split();
+
+            if let Err(err) = handle_connection(application_manager, recv, send).await {
+                tracing::warn!("error occurred when handling control socket connection: {:?}", err);
+            }
+        });
+    }
+}
+
+async fn handle_connection(application_manager: Arc<ApplicationManager>, recv: RecvHalf, mut send: SendHalf) -> anyhow::Result<()> {
+    let mut lines = BufReader::new(recv).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<ControlRequest>(&line) {
+            Ok(request) => {
+                tracing::debug!("handling control socket request: {:?}", request);
+
+                handle_request(&application_manager, request)
+                    .await
+                    .unwrap_or_else(|err| ControlResponse::Error { message: format!("{:#}", err) })
+            }
+            Err(err) => ControlResponse::Error { message: format!("Invalid request: {}", err) },
+        };
+
+        let mut response = serde_json::to_string(&response)?;
+        response.push('\n');
+
+        send.write_all(response.as_bytes()).await?;
+    }
+
+    Ok(())
+}
+
+async fn handle_request(application_manager: &ApplicationManager, request: ControlRequest) -> anyhow::Result<ControlResponse> {
+    let response = match request {
+        ControlRequest::ShowWindow => {
+            application_manager.show_window().await?;
+
+            ControlResponse::Ok
+        }
+        ControlRequest::HideWindow => {
+            application_manager.hide_window().await?;
+
+            ControlResponse::Ok
+        }
+        ControlRequest::ToggleWindow => {
+            application_manager.toggle_window().await?;
+
+            ControlResponse::Ok
+        }
+        ControlRequest::OpenEntrypoint { plugin_id, entrypoint_id } => {
+            application_manager.open_entrypoint(PluginId::from_string(plugin_id), EntrypointId::from_string(entrypoint_id)).await?;
+
+            ControlResponse::Ok
+        }
+        ControlRequest::Search { query } => {
+            let (query, category) = match SearchResultCategory::parse_query_prefix(&query) {
+                Some((category, query)) => (query, Some(category)),
+                None => (query.as_str(), None),
+            };
+
+            let results = application_manager.search(query, None, category, false, 0)?
+                .into_iter()
+                .map(|result| ControlSearchResult {
+                    plugin_id: result.plugin_id.to_string(),
+                    plugin_name: result.plugin_name,
+                    entrypoint_id: result.entrypoint_id.to_string(),
+                    entrypoint_name: result.entrypoint_name,
+                    entrypoint_type: match result.entrypoint_type {
+                        SearchResultEntrypointType::Command => "command",
+                        SearchResultEntrypointType::View => "view",
+                        SearchResultEntrypointType::GeneratedCommand => "generated-command",
+                    }.to_string(),
+                    entrypoint_actions: result.entrypoint_actions
+                        .into_iter()
+                        .map(|action| action.label)
+                        .collect(),
+                })
+                .collect();
+
+            ControlResponse::SearchResults { results }
+        }
+        ControlRequest::Plugins => {
+            let plugins = application_manager.plugins()
+                .await?
+                .into_iter()
+                .map(|plugin| ControlPlugin {
+                    plugin_id: plugin.plugin_id.to_string(),
+                    plugin_name: plugin.plugin_name,
+                    enabled: plugin.enabled,
+                    entrypoints: plugin.entrypoints
+                        .into_values()
+                        .map(|entrypoint| ControlEntrypoint {
+                            entrypoint_id: entrypoint.entrypoint_id.to_string(),
+                            entrypoint_name: entrypoint.entrypoint_name,
+                            enabled: entrypoint.enabled,
+                        })
+                        .collect(),
+                })
+                .collect();
+
+            ControlResponse::Plugins { plugins }
+        }
+    };
+
+    Ok(response)
+}
+
+// used by cli, server has to be running
+pub fn search(query: &str) -> anyhow::Result<Vec<ControlSearchResult>> {
+    use interprocess::local_socket::traits::Stream as _;
+
+    let mut stream = interprocess::local_socket::Stream::connect(control_socket_name()?)
+        .map_err(|_| anyhow!("Gauntlet is not running"))?;
+
+    let mut request = serde_json::to_string(&ControlRequest::Search { query: query.to_string() })?;
+    request.push('\n');
+
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = String::new();
+    BufReader::new(stream).read_line(&mut response)?;
+
+    match serde_json::from_str::<ControlResponse>(&response)? {
+        ControlResponse::SearchResults { results } => Ok(results),
+        ControlResponse::Error { message } => Err(anyhow!(message)),
+        response => Err(anyhow!("Unexpected response: {:?}", response)),
+    }
+}
+
+pub fn is_running() -> bool {
+    use interprocess::local_socket::traits::Stream as _;
+
+    control_socket_name()
+        .and_then(|name| interprocess::local_socket::Stream::connect(name))
+        .is_ok()
+}
+
+fn control_socket_name() -> std::io::Result<Name<'static>> {
+    // namespaced, removed when both client and server disconnect
+    #[cfg(target_os = "windows")]
+    let name = {
+        use interprocess::local_socket::ToNsName;
+
+        "project-gauntlet-control".to_ns_name::<interprocess::local_socket::GenericNamespaced>()?
+    };
+
+    #[cfg(unix)]
+    let name = {
+        use interprocess::local_socket::ToFsName;
+        use gauntlet_common::dirs::Dirs;
+
+        let control_socket = Dirs::new().control_socket();
+
+        std::fs::create_dir_all(control_socket.parent().unwrap())?;
+
+        control_socket.to_fs_name::<interprocess::os::unix::local_socket::FilesystemUdSocket>()?
+    };
+
+    Ok(name)
+}
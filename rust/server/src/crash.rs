@@ -0,0 +1,142 @@
+use std::backtrace::Backtrace;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use once_cell::sync::Lazy;
+use vergen_pretty::vergen_pretty_env;
+
+use gauntlet_common::dirs::Dirs;
+
+// path of the report written by the panic hook, used to point user to it in recovery view
+static LAST_CRASH_REPORT: Lazy<Mutex<Option<PathBuf>>> = Lazy::new(|| Mutex::new(None));
+
+pub fn install_panic_hook(dirs: Dirs) {
+    let default_hook = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        let message = if let Some(message) = info.payload().downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = info.payload().downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic payload".to_string()
+        };
+
+        let location = info.location()
+            .map(|location| format!("{}:{}:{}", location.file(), location.line(), location.column()))
+            .unwrap_or_else(|| "unknown location".to_string());
+
+        let thread = std::thread::current()
+            .name()
+            .unwrap_or("<unnamed>")
+            .to_string();
+
+        let description = format!("Thread '{}' panicked at {}:\n{}", thread, location, message);
+
+        match write_crash_report(&dirs, &description, Some(Backtrace::force_capture())) {
+            Ok(path) => {
+                *LAST_CRASH_REPORT.lock().unwrap_or_else(|err| err.into_inner()) = Some(path);
+            }
+            Err(err) => {
+                eprintln!("Unable to write crash report: {:?}", err);
+            }
+        }
+
+        default_hook(info);
+    }));
+}
+
+pub fn last_crash_report() -> Option<PathBuf> {
+    LAST_CRASH_REPORT.lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone()
+}
+
+pub fn latest_crash_report(dirs: &Dirs) -> Option<String> {
+    let entries = std::fs::read_dir(dirs.crash_reports_dir()).ok()?;
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+
+            Some((modified, entry.path()))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .and_then(|(_, path)| path.to_str().map(|path| path.to_string()))
+}
+
+pub fn write_crash_report(dirs: &Dirs, description: &str, backtrace: Option<Backtrace>) -> anyhow::Result<PathBuf> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or_default();
+
+    let mut report = vec![];
+
+    report.push("Gauntlet Crash Report".to_string());
+    report.push(format!("Timestamp: {}", timestamp));
+    report.push(format!("Operating system: {} {}", std::env::consts::OS, std::env::consts::ARCH));
+
+    for (name, value) in vergen_pretty_env!() {
+        if let Some(value) = value {
+            report.push(format!("{}: {}", name, value));
+        }
+    }
+
+    report.push("".to_string());
+    report.push(description.to_string());
+
+    if let Some(backtrace) = backtrace {
+        report.push("".to_string());
+        report.push("Backtrace:".to_string());
+        report.push(backtrace.to_string());
+    }
+
+    let crash_reports_dir = dirs.crash_reports_dir();
+
+    std::fs::create_dir_all(&crash_reports_dir)?;
+
+    let path = crash_reports_dir.join(format!("crash-{}.txt", timestamp));
+
+    std::fs::write(&path, report.join("\n"))?;
+
+    Ok(path)
+}
+
+// marker is removed only when startup finishes,
+// so if it is still present on the next start, previous startup never finished
+pub struct StartupMarker {
+    path: PathBuf,
+}
+
+impl StartupMarker {
+    pub fn new(dirs: &Dirs) -> Self {
+        Self {
+            path: dirs.startup_marker_file(),
+        }
+    }
+
+    pub fn previous_startup_failed(&self) -> bool {
+        self.path.exists()
+    }
+
+    pub fn startup_started(&self) -> anyhow::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(&self.path, "")?;
+
+        Ok(())
+    }
+
+    pub fn startup_finished(&self) -> anyhow::Result<()> {
+        if self.path.exists() {
+            std::fs::remove_file(&self.path)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::filter::filter_fn;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use gauntlet_common::model::{DiagnosticsData, DiagnosticsEntry, DiagnosticsKind, PluginId};
+
+// spans with this target are only recorded when diagnostics mode is enabled
+pub const DIAGNOSTICS_TARGET: &str = "gauntlet_diagnostics";
+
+static DIAGNOSTICS: Lazy<Diagnostics> = Lazy::new(|| Diagnostics::new());
+
+pub fn diagnostics_layer<S>() -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>
+{
+    DiagnosticsLayer
+        .with_filter(filter_fn(|metadata| metadata.target() == DIAGNOSTICS_TARGET && DIAGNOSTICS.is_enabled()))
+}
+
+pub fn set_diagnostics_enabled(enabled: bool) {
+    DIAGNOSTICS.set_enabled(enabled)
+}
+
+pub fn diagnostics_data() -> DiagnosticsData {
+    DIAGNOSTICS.data()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DiagnosticsKey {
+    kind: DiagnosticsKind,
+    plugin_id: Option<String>,
+    name: String,
+}
+
+#[derive(Debug, Clone, Default)]
+struct DiagnosticsStats {
+    count: u64,
+    total_duration: Duration,
+    max_duration: Duration,
+}
+
+struct Diagnostics {
+    enabled: AtomicBool,
+    stats: Mutex<HashMap<DiagnosticsKey, DiagnosticsStats>>,
+}
+
+impl Diagnostics {
+    fn new() -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            stats: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    fn set_enabled(&self, enabled: bool) {
+        // every recording session starts from scratch
+        if enabled {
+            self.stats.lock().unwrap().clear();
+        }
+
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    fn record(&self, key: DiagnosticsKey, duration: Duration) {
+        let mut stats = self.stats.lock().unwrap();
+
+        let stats = stats.entry(key).or_default();
+
+        stats.count += 1;
+        stats.total_duration += duration;
+        stats.max_duration = stats.max_duration.max(duration);
+    }
+
+    fn data(&self) -> DiagnosticsData {
+        let stats = self.stats.lock().unwrap();
+
+        let entries = stats.iter()
+            .map(|(key, stats)| DiagnosticsEntry {
+                kind: key.kind.clone(),
+                plugin_id: key.plugin_id.clone().map(|plugin_id| PluginId::from_string(plugin_id)),
+                name: key.name.clone(),
+                count: stats.count,
+                total_duration: stats.total_duration,
+                max_duration: stats.max_duration,
+            })
+            .collect();
+
+        DiagnosticsData {
+            enabled: self.is_enabled(),
+            entries,
+            // window runs in the same process
+            image_cache: gauntlet_client::image_cache_stats(),
+        }
+    }
+}
+
+struct SpanTiming {
+    key: DiagnosticsKey,
+    start: Instant,
+}
+
+struct DiagnosticsLayer;
+
+impl<S> Layer<S> for DiagnosticsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let kind = match attrs.metadata().name() {
+            "render" => DiagnosticsKind::Render,
+            "search" => DiagnosticsKind::Search,
+            "op" => DiagnosticsKind::Op,
+            "ipc" => DiagnosticsKind::Ipc,
+            _ => return,
+        };
+
+        let mut visitor = SpanFieldVisitor::default();
+        attrs.record(&mut visitor);
+
+        let key = DiagnosticsKey {
+            kind,
+            plugin_id: visitor.plugin_id,
+            name: visitor.name.unwrap_or_default(),
+        };
+
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanTiming { key, start: Instant::now() });
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+
+        let Some(timing) = span.extensions_mut().remove::<SpanTiming>() else {
+            return;
+        };
+
+        DIAGNOSTICS.record(timing.key, timing.start.elapsed());
+    }
+}
+
+#[derive(Default)]
+struct SpanFieldVisitor {
+    plugin_id: Option<String>,
+    name: Option<String>,
+}
+
+impl Visit for SpanFieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "plugin_id" => self.plugin_id = Some(value.to_string()),
+            "name" => self.name = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.record_str(field, &format!("{:?}", value))
+    }
+}
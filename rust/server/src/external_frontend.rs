@@ -0,0 +1,563 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::Context;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use interprocess::local_socket::traits::tokio::{Listener, Stream};
+use interprocess::local_socket::ListenerOptions;
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use gauntlet_common::model::{BackendRequestData, BackendResponseData, EntrypointId, PluginId, SearchResultCategory, SearchResultEntrypointType, UiRenderLocation, UiRequestData, UiResponseData};
+use gauntlet_common::rpc::backend_api::BackendForFrontendApi;
+use gauntlet_common::rpc::frontend_protocol::{json_to_property_value, FrontendEvent, FrontendRequest, FrontendRequestMessage, FrontendResponse, FrontendSearchResult, FrontendServerMessage, FRONTEND_PROTOCOL_VERSION};
+use gauntlet_utils::channel::{RequestReceiver, RequestSender};
+
+// Replaces iced client when server is started with --external-frontend or --stdio.
+// Requests server sends to frontend are converted to events of frontend protocol
+// and requests of connected frontend are forwarded to server, see frontend_protocol for the protocol itself.
+//
+// With --stdio the same requests, responses and events are wrapped into JSON-RPC 2.0 messages
+// and exchanged over stdin and stdout of the server process, so that editors can start gauntlet
+// as a child process the same way they start language servers:
+//
+//   -> {"jsonrpc":"2.0","id":1,"method":"hello","params":{"protocol_version":2}}
+//   <- {"jsonrpc":"2.0","id":1,"result":{"type":"hello","protocol_version":2}}
+//   -> {"jsonrpc":"2.0","id":2,"method":"search","params":{"query":"sett"}}
+//   <- {"jsonrpc":"2.0","id":2,"result":{"type":"search_results","results":[...]}}
+//   <- {"jsonrpc":"2.0","method":"show_hud","params":{"display":"Copied"}}
+
+pub(in crate) enum Transport {
+    // frontend socket, frontends can connect and reconnect any number of times
+    Socket,
+    // stdin and stdout, server stops when stdin is closed
+    Stdio,
+}
+
+#[derive(Clone, Copy)]
+enum Encoding {
+    FrontendProtocol,
+    JsonRpc,
+}
+
+// json-rpc ids can be numbers or strings, frontend protocol ids are always numbers
+enum OutgoingMessage {
+    Response {
+        id: Value,
+        result: Result<FrontendResponse, ErrorResponse>,
+    },
+    Event {
+        event: FrontendEvent,
+    },
+}
+
+struct ErrorResponse {
+    code: i64,
+    message: String,
+}
+
+// codes defined by json-rpc specification
+const JSON_RPC_PARSE_ERROR: i64 = -32700;
+const JSON_RPC_INVALID_REQUEST: i64 = -32600;
+const JSON_RPC_SERVER_ERROR: i64 = -32000;
+
+// sender of the latest connected frontend, events are dropped while no frontend is connected
+type CurrentFrontend = Arc<Mutex<Option<mpsc::UnboundedSender<OutgoingMessage>>>>;
+
+pub(in crate) fn start_external_frontend(
+    transport: Transport,
+    frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>,
+    backend_sender: RequestSender<BackendRequestData, BackendResponseData>
+) {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start external frontend tokio runtime");
+
+    let result = runtime.block_on(async move {
+        let current_frontend: CurrentFrontend = Arc::new(Mutex::new(None));
+        let quit = CancellationToken::new();
+
+        forward_events(frontend_receiver, current_frontend.clone(), quit.clone());
+
+        let frontend = async {
+            match transport {
+                Transport::Socket => run_socket_frontend(backend_sender, current_frontend).await,
+                Transport::Stdio => run_stdio_frontend(backend_sender, current_frontend).await,
+            }
+        };
+
+        tokio::select! {
+            result = frontend => result,
+            _ = quit.cancelled() => Ok(()),
+        }
+    });
+
+    if let Err(err) = result {
+        tracing::error!("External frontend stopped because of an error: {:?}", err);
+    }
+
+    // blocking stdin read can't be cancelled, don't wait for it
+    runtime.shutdown_background();
+}
+
+fn forward_events(mut frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>, current_frontend: CurrentFrontend, quit: CancellationToken) {
+    tokio::spawn(async move {
+        loop {
+            let (request_data, responder) = frontend_receiver.recv().await;
+
+            // frontend protocol is one-way for events, server doesn't wait for frontend to handle them
+            responder.respond(UiResponseData::Nothing);
+
+            // server is already shut down, connected frontends see the connection closed
+            if let UiRequestData::Quit = request_data {
+                quit.cancel();
+                break;
+            }
+
+            let Some(event) = convert_event(request_data) else {
+                continue;
+            };
+
+            if let Some(sender) = current_frontend.lock().expect("lock is poisoned").as_ref() {
+                let _ = sender.send(OutgoingMessage::Event { event });
+            }
+        }
+    });
+}
+
+async fn run_stdio_frontend(
+    backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
+    current_frontend: CurrentFrontend,
+) -> anyhow::Result<()> {
+    tracing::info!("Using stdin and stdout as external frontend");
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+
+    *current_frontend.lock().expect("lock is poisoned") = Some(sender.clone());
+
+    let backend_api = BackendForFrontendApi::new(backend_sender);
+
+    handle_connection(backend_api, Encoding::JsonRpc, tokio::io::stdin(), tokio::io::stdout(), sender, receiver).await
+}
+
+async fn run_socket_frontend(
+    backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
+    current_frontend: CurrentFrontend,
+) -> anyhow::Result<()> {
+    // namespaced, removed when both client and server disconnect
+    #[cfg(target_os = "windows")]
+    let name = {
+        use interprocess::local_socket::ToNsName;
+
+        "project-gauntlet-frontend".to_ns_name::<interprocess::local_socket::GenericNamespaced>()?
+    };
+
+    #[cfg(unix)]
+    let name = {
+        use interprocess::local_socket::ToFsName;
+        use gauntlet_common::dirs::Dirs;
+
+        let frontend_socket = Dirs::new().frontend_socket();
+
+        // socket file is left behind if server wasn't stopped cleanly,
+        // at this point we know that no other server is running
+        let _ = std::fs::remove_file(&frontend_socket);
+
+        std::fs::create_dir_all(frontend_socket.parent().unwrap())?;
+
+        frontend_socket.to_fs_name::<interprocess::os::unix::local_socket::FilesystemUdSocket>()?
+    };
+
+    let listener = ListenerOptions::new()
+        .name(name)
+        .create_tokio()
+        .context("unable to create frontend socket")?;
+
+    tracing::info!("Waiting for external frontend to connect");
+
+    loop {
+        let conn = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(err) => {
+                tracing::warn!("unable to accept frontend socket connection: {:?}", err);
+                continue;
+            }
+        };
+
+        tracing::info!("External frontend connected");
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        // previously connected frontend still receives responses to its requests, but not events
+        *current_frontend.lock().expect("lock is poisoned") = Some(sender.clone());
+
+        let backend_api = BackendForFrontendApi::new(backend_sender.clone());
+
+        tokio::spawn(async move {
+            let (recv, send) = conn.split();
+
+            if let Err(err) = handle_connection(backend_api, Encoding::FrontendProtocol, recv, send, sender, receiver).await {
+                tracing::warn!("error occurred when handling frontend socket connection: {:?}", err);
+            }
+        });
+    }
+}
+
+async fn handle_connection(
+    backend_api: BackendForFrontendApi,
+    encoding: Encoding,
+    recv: impl AsyncRead + Unpin + Send,
+    mut send: impl AsyncWrite + Unpin + Send + 'static,
+    sender: mpsc::UnboundedSender<OutgoingMessage>,
+    mut receiver: mpsc::UnboundedReceiver<OutgoingMessage>,
+) -> anyhow::Result<()> {
+    let writer = tokio::spawn(async move {
+        while let Some(message) = receiver.recv().await {
+            let Some(mut message) = encoding.encode(message)? else {
+                continue;
+            };
+            message.push('\n');
+
+            send.write_all(message.as_bytes()).await?;
+            send.flush().await?;
+        }
+
+        anyhow::Ok(())
+    });
+
+    let search_generation = Arc::new(AtomicU64::new(0));
+    // entrypoint of the latest view opened per plugin, for requests of frontends that don't specify one
+    let opened_views = Arc::new(Mutex::new(HashMap::new()));
+    let mut hello_received = false;
+
+    let mut lines = BufReader::new(recv).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (id, request) = match encoding.decode(&line) {
+            Ok(message) => message,
+            Err((id, error)) => {
+                tracing::warn!("invalid external frontend request: {}", error.message);
+
+                if let Some(id) = id {
+                    let _ = sender.send(OutgoingMessage::Response { id, result: Err(error) });
+                }
+
+                continue;
+            }
+        };
+
+        tracing::debug!("handling external frontend request: {:?}", request);
+
+        let hello = matches!(request, FrontendRequest::Hello { .. });
+
+        if !hello_received && !hello {
+            let _ = sender.send(OutgoingMessage::Response {
+                id,
+                result: Err(ErrorResponse { code: JSON_RPC_INVALID_REQUEST, message: "First request has to be hello".to_string() }),
+            });
+
+            continue;
+        }
+
+        hello_received = true;
+
+        let backend_api = backend_api.clone();
+        let search_generation = search_generation.clone();
+        let opened_views = opened_views.clone();
+        let sender = sender.clone();
+
+        // requests are handled concurrently, e.g. search is not blocked by view which takes long to render
+        tokio::spawn(async move {
+            let result = handle_request(backend_api, &search_generation, &opened_views, request)
+                .await
+                .map_err(|err| ErrorResponse { code: JSON_RPC_SERVER_ERROR, message: format!("{:#}", err) });
+
+            let _ = sender.send(OutgoingMessage::Response { id, result });
+        });
+    }
+
+    tracing::info!("External frontend disconnected");
+
+    writer.abort();
+
+    Ok(())
+}
+
+impl Encoding {
+    // on error returns id of the request if it could be read, so that error can be sent back
+    fn decode(&self, line: &str) -> Result<(Value, FrontendRequest), (Option<Value>, ErrorResponse)> {
+        match self {
+            Encoding::FrontendProtocol => {
+                let message = serde_json::from_str::<FrontendRequestMessage>(line)
+                    .map_err(|err| (None, ErrorResponse { code: JSON_RPC_INVALID_REQUEST, message: err.to_string() }))?;
+
+                Ok((Value::from(message.id), message.request))
+            }
+            Encoding::JsonRpc => {
+                let message = serde_json::from_str::<Value>(line)
+                    .map_err(|err| (Some(Value::Null), ErrorResponse { code: JSON_RPC_PARSE_ERROR, message: err.to_string() }))?;
+
+                let id = message.get("id").cloned();
+
+                let invalid_request = |message: String| (id.clone().or(Some(Value::Null)), ErrorResponse { code: JSON_RPC_INVALID_REQUEST, message });
+
+                let Some(method) = message.get("method").and_then(|method| method.as_str()) else {
+                    return Err(invalid_request("method is missing".to_string()));
+                };
+
+                // params are fields of the request variant named by the method,
+                // e.g. method "search" with params {"query": "..."} is {"type": "search", "query": "..."}
+                let mut request = match message.get("params") {
+                    None | Some(Value::Null) => serde_json::Map::new(),
+                    Some(Value::Object(params)) => params.clone(),
+                    Some(_) => return Err(invalid_request("params have to be an object".to_string())),
+                };
+
+                request.insert("type".to_string(), Value::from(method));
+
+                let request = serde_json::from_value::<FrontendRequest>(Value::Object(request))
+                    .map_err(|err| invalid_request(err.to_string()))?;
+
+                // notifications don't have an id and don't get a response
+                Ok((id.unwrap_or(Value::Null), request))
+            }
+        }
+    }
+
+    // returns none if message is not sent with this encoding
+    fn encode(&self, message: OutgoingMessage) -> anyhow::Result<Option<String>> {
+        let message = match self {
+            Encoding::FrontendProtocol => {
+                let message = match message {
+                    OutgoingMessage::Response { id, result } => {
+                        let Some(id) = id.as_u64() else {
+                            return Ok(None)
+                        };
+
+                        let result = result.unwrap_or_else(|error| FrontendResponse::Error { message: error.message });
+
+                        FrontendServerMessage::Response { id, result }
+                    }
+                    OutgoingMessage::Event { event } => FrontendServerMessage::Event { event },
+                };
+
+                serde_json::to_string(&message)?
+            }
+            Encoding::JsonRpc => {
+                let message = match message {
+                    OutgoingMessage::Response { id: Value::Null, result: Ok(_) } => return Ok(None),
+                    OutgoingMessage::Response { id, result: Ok(result) } => {
+                        json!({ "jsonrpc": "2.0", "id": id, "result": result })
+                    }
+                    OutgoingMessage::Response { id, result: Err(error) } => {
+                        json!({ "jsonrpc": "2.0", "id": id, "error": { "code": error.code, "message": error.message } })
+                    }
+                    OutgoingMessage::Event { event } => {
+                        let Value::Object(mut params) = serde_json::to_value(&event)? else {
+                            unreachable!("events are serialized as objects")
+                        };
+
+                        let method = params.remove("type").unwrap_or(Value::Null);
+
+                        json!({ "jsonrpc": "2.0", "method": method, "params": params })
+                    }
+                };
+
+                serde_json::to_string(&message)?
+            }
+        };
+
+        Ok(Some(message))
+    }
+}
+
+async fn handle_request(
+    mut backend_api: BackendForFrontendApi,
+    search_generation: &AtomicU64,
+    opened_views: &Mutex<HashMap<String, String>>,
+    request: FrontendRequest
+) -> anyhow::Result<FrontendResponse> {
+    let response = match request {
+        FrontendRequest::Hello { protocol_version } => {
+            if protocol_version > FRONTEND_PROTOCOL_VERSION {
+                tracing::warn!("External frontend uses newer protocol version {}, server supports {}", protocol_version, FRONTEND_PROTOCOL_VERSION);
+            }
+
+            FrontendResponse::Hello { protocol_version: FRONTEND_PROTOCOL_VERSION }
+        }
+        FrontendRequest::Search { query } => {
+            let (query, category) = match SearchResultCategory::parse_query_prefix(&query) {
+                Some((category, query)) => (query, Some(category)),
+                None => (query.as_str(), None),
+            };
+
+            let generation = search_generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+            let (results, _, _) = backend_api.search(query.to_string(), None, category, false, generation).await?;
+
+            let results = results.into_iter()
+                .map(|result| FrontendSearchResult {
+                    plugin_id: result.plugin_id.to_string(),
+                    plugin_name: result.plugin_name,
+                    entrypoint_id: result.entrypoint_id.to_string(),
+                    entrypoint_name: result.entrypoint_name,
+                    entrypoint_type: match result.entrypoint_type {
+                        SearchResultEntrypointType::Command => "command",
+                        SearchResultEntrypointType::View => "view",
+                        SearchResultEntrypointType::GeneratedCommand => "generated-command",
+                    }.to_string(),
+                    entrypoint_accessory: result.entrypoint_accessory,
+                    entrypoint_actions: result.entrypoint_actions
+                        .into_iter()
+                        .map(|action| action.label)
+                        .collect(),
+                })
+                .collect();
+
+            FrontendResponse::SearchResults { results }
+        }
+        FrontendRequest::OpenView { plugin_id, entrypoint_id } => {
+            opened_views.lock().unwrap().insert(plugin_id.clone(), entrypoint_id.clone());
+
+            let (_, interactive, _) = backend_api.request_view_render(PluginId::from_string(plugin_id), EntrypointId::from_string(entrypoint_id)).await?;
+
+            FrontendResponse::ViewOpened { interactive }
+        }
+        FrontendRequest::CloseView { plugin_id, entrypoint_id } => {
+            let entrypoint_id = opened_view_entrypoint(opened_views, &plugin_id, entrypoint_id)?;
+
+            backend_api.request_view_close(PluginId::from_string(plugin_id), entrypoint_id).await?;
+
+            FrontendResponse::Ok
+        }
+        FrontendRequest::PopView { plugin_id, entrypoint_id } => {
+            let entrypoint_id = opened_view_entrypoint(opened_views, &plugin_id, entrypoint_id)?;
+
+            backend_api.request_view_pop(PluginId::from_string(plugin_id), entrypoint_id).await?;
+
+            FrontendResponse::Ok
+        }
+        FrontendRequest::RunCommand { plugin_id, entrypoint_id } => {
+            backend_api.request_run_command(PluginId::from_string(plugin_id), EntrypointId::from_string(entrypoint_id), Default::default()).await?;
+
+            FrontendResponse::Ok
+        }
+        FrontendRequest::RunGeneratedCommand { plugin_id, entrypoint_id, action_index } => {
+            backend_api.request_run_generated_command(PluginId::from_string(plugin_id), EntrypointId::from_string(entrypoint_id), action_index).await?;
+
+            FrontendResponse::Ok
+        }
+        FrontendRequest::ViewEvent { plugin_id, entrypoint_id, inline, widget_id, event_name, event_arguments } => {
+            let entrypoint_id = opened_view_entrypoint(opened_views, &plugin_id, entrypoint_id)?;
+
+            let render_location = if inline { UiRenderLocation::InlineView } else { UiRenderLocation::View };
+
+            let event_arguments = event_arguments.into_iter()
+                .map(json_to_property_value)
+                .collect();
+
+            backend_api.send_view_event(PluginId::from_string(plugin_id), entrypoint_id, render_location, widget_id, event_name, event_arguments).await?;
+
+            FrontendResponse::Ok
+        }
+        FrontendRequest::Images { image_ids } => {
+            let images = backend_api.get_images(image_ids)
+                .await?
+                .into_iter()
+                .map(|(image_id, bytes)| (image_id, BASE64_STANDARD.encode(bytes)))
+                .collect();
+
+            FrontendResponse::Images { images }
+        }
+        FrontendRequest::WindowVisibility { visible } => {
+            backend_api.window_visibility(visible, None).await?;
+
+            FrontendResponse::Ok
+        }
+    };
+
+    Ok(response)
+}
+
+fn opened_view_entrypoint(opened_views: &Mutex<HashMap<String, String>>, plugin_id: &str, entrypoint_id: Option<String>) -> anyhow::Result<EntrypointId> {
+    let entrypoint_id = match entrypoint_id {
+        Some(entrypoint_id) => entrypoint_id,
+        None => {
+            opened_views.lock()
+                .unwrap()
+                .get(plugin_id)
+                .cloned()
+                .with_context(|| format!("No view of plugin {} was opened, entrypoint_id has to be specified", plugin_id))?
+        }
+    };
+
+    Ok(EntrypointId::from_string(entrypoint_id))
+}
+
+// requests not listed here are specific to built-in frontend and are not part of the stable protocol
+fn convert_event(request_data: UiRequestData) -> Option<FrontendEvent> {
+    let event = match request_data {
+        UiRequestData::ShowWindow => FrontendEvent::ShowWindow,
+        UiRequestData::HideWindow => FrontendEvent::HideWindow,
+        UiRequestData::ToggleWindow => FrontendEvent::ToggleWindow,
+        UiRequestData::ShowPluginView { plugin_id, plugin_name, entrypoint_id, entrypoint_name } => {
+            FrontendEvent::OpenView {
+                plugin_id: plugin_id.to_string(),
+                plugin_name,
+                entrypoint_id: entrypoint_id.to_string(),
+                entrypoint_name,
+            }
+        }
+        UiRequestData::ReplaceView { plugin_id, plugin_name, entrypoint_id, entrypoint_name, render_location, view_stack_depth, container, images } => {
+            FrontendEvent::ReplaceView {
+                plugin_id: plugin_id.to_string(),
+                plugin_name,
+                entrypoint_id: entrypoint_id.to_string(),
+                entrypoint_name,
+                inline: matches!(render_location, UiRenderLocation::InlineView),
+                view_stack_depth,
+                container,
+                images,
+            }
+        }
+        UiRequestData::ClearInlineView { plugin_id } => FrontendEvent::ClearInlineView { plugin_id: plugin_id.to_string() },
+        UiRequestData::ShowPluginErrorView { plugin_id, entrypoint_id, .. } => {
+            FrontendEvent::ShowPluginError {
+                plugin_id: plugin_id.to_string(),
+                entrypoint_id: entrypoint_id.to_string(),
+            }
+        }
+        UiRequestData::ShowRenderErrorView { plugin_id, entrypoint_id, message, .. } => {
+            FrontendEvent::ShowRenderError {
+                plugin_id: plugin_id.to_string(),
+                entrypoint_id: entrypoint_id.to_string(),
+                message,
+            }
+        }
+        UiRequestData::UpdateLoadingBar { plugin_id, entrypoint_id, show } => {
+            FrontendEvent::UpdateLoadingBar {
+                plugin_id: plugin_id.to_string(),
+                entrypoint_id: entrypoint_id.to_string(),
+                show,
+            }
+        }
+        UiRequestData::RequestSearchResultUpdate => FrontendEvent::SearchResultsChanged,
+        UiRequestData::ShowHud { display } => FrontendEvent::ShowHud { display },
+        request_data => {
+            tracing::debug!("request is not supported by external frontend protocol, ignoring: {:?}", request_data);
+
+            return None
+        }
+    };
+
+    Some(event)
+}
@@ -0,0 +1,183 @@
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+
+use anyhow::{anyhow, Context};
+use interprocess::local_socket::prelude::*;
+use interprocess::local_socket::{Listener, ListenerOptions, Name, Stream};
+use serde::{Deserialize, Serialize};
+use tokio::runtime::Handle;
+
+use gauntlet_common::rpc::frontend_api::FrontendApi;
+
+// Only one server is allowed to run at a time. Running instance holds a local socket,
+// which is also used by subsequent invocations to forward their command to it.
+// Unlike control socket, it is created before anything else is started,
+// so there is no window during startup in which second server could be started
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InstanceCommand {
+    ShowWindow,
+    ToggleWindow,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum InstanceResponse {
+    Ok,
+    Error {
+        message: String,
+    },
+}
+
+pub enum InstanceClaim {
+    Claimed(InstanceListener),
+    AlreadyRunning,
+}
+
+pub struct InstanceListener {
+    listener: Listener,
+}
+
+pub fn claim_instance() -> anyhow::Result<InstanceClaim> {
+    match create_listener() {
+        Ok(listener) => return Ok(InstanceClaim::Claimed(InstanceListener { listener })),
+        Err(err) if err.kind() == ErrorKind::AddrInUse => {}
+        Err(err) => return Err(err).context("unable to create instance socket"),
+    }
+
+    if Stream::connect(instance_socket_name()?).is_ok() {
+        return Ok(InstanceClaim::AlreadyRunning)
+    }
+
+    // socket file is left behind if previous instance wasn't stopped cleanly
+    #[cfg(unix)]
+    {
+        use gauntlet_common::dirs::Dirs;
+
+        let _ = std::fs::remove_file(Dirs::new().instance_socket());
+    }
+
+    let listener = create_listener()
+        .context("unable to create instance socket")?;
+
+    Ok(InstanceClaim::Claimed(InstanceListener { listener }))
+}
+
+pub fn forward_command(command: InstanceCommand) -> anyhow::Result<()> {
+    let mut stream = Stream::connect(instance_socket_name()?)
+        .map_err(|_| anyhow!("Gauntlet is not running"))?;
+
+    let mut request = serde_json::to_string(&command)?;
+    request.push('\n');
+
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = String::new();
+    BufReader::new(stream).read_line(&mut response)?;
+
+    match serde_json::from_str::<InstanceResponse>(&response)? {
+        InstanceResponse::Ok => Ok(()),
+        InstanceResponse::Error { message } => Err(anyhow!(message)),
+    }
+}
+
+impl InstanceListener {
+    // accepting is blocking, so it is done on a separate thread, commands are executed on the server runtime
+    pub fn start(self, runtime: Handle, frontend_api: impl FrontendApi + 'static) {
+        std::thread::spawn(move || {
+            for conn in self.listener.incoming() {
+                let conn = match conn {
+                    Ok(conn) => conn,
+                    Err(err) => {
+                        tracing::warn!("unable to accept instance socket connection: {:?}", err);
+                        continue;
+                    }
+                };
+
+                if let Err(err) = handle_connection(&runtime, &frontend_api, conn) {
+                    tracing::warn!("error occurred when handling instance socket connection: {:?}", err);
+                }
+            }
+        });
+    }
+}
+
+fn handle_connection(runtime: &Handle, frontend_api: &dyn FrontendApi, conn: Stream) -> anyhow::Result<()> {
+    let mut reader = BufReader::new(conn);
+
+    let mut line = String::new();
+
+    // instance claim check connects without sending anything
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(())
+    }
+
+    let response = match serde_json::from_str::<InstanceCommand>(&line) {
+        Ok(command) => {
+            tracing::info!("handling command forwarded from another instance: {:?}", command);
+
+            match runtime.block_on(handle_command(frontend_api, command)) {
+                Ok(()) => InstanceResponse::Ok,
+                Err(err) => InstanceResponse::Error { message: format!("{:#}", err) },
+            }
+        }
+        Err(err) => InstanceResponse::Error { message: format!("Invalid command: {}", err) },
+    };
+
+    let mut response = serde_json::to_string(&response)?;
+    response.push('\n');
+
+    reader.get_mut().write_all(response.as_bytes())?;
+
+    Ok(())
+}
+
+async fn handle_command(frontend_api: &dyn FrontendApi, command: InstanceCommand) -> anyhow::Result<()> {
+    match command {
+        InstanceCommand::ShowWindow => frontend_api.show_window().await?,
+        InstanceCommand::ToggleWindow => frontend_api.toggle_window().await?,
+    }
+
+    Ok(())
+}
+
+// accepting thread can't be interrupted and keeps listener until process exits,
+// so socket file is removed by hand to not be left behind
+pub fn remove_instance_socket() {
+    #[cfg(unix)]
+    {
+        use gauntlet_common::dirs::Dirs;
+
+        let _ = std::fs::remove_file(Dirs::new().instance_socket());
+    }
+}
+
+fn create_listener() -> std::io::Result<Listener> {
+    ListenerOptions::new()
+        .name(instance_socket_name()?)
+        .create_sync()
+}
+
+fn instance_socket_name() -> std::io::Result<Name<'static>> {
+    // namespaced, removed when both client and server disconnect
+    #[cfg(target_os = "windows")]
+    let name = {
+        use interprocess::local_socket::ToNsName;
+
+        "project-gauntlet-instance".to_ns_name::<interprocess::local_socket::GenericNamespaced>()?
+    };
+
+    #[cfg(unix)]
+    let name = {
+        use interprocess::local_socket::ToFsName;
+        use gauntlet_common::dirs::Dirs;
+
+        let instance_socket = Dirs::new().instance_socket();
+
+        std::fs::create_dir_all(instance_socket.parent().unwrap())?;
+
+        instance_socket.to_fs_name::<interprocess::os::unix::local_socket::FilesystemUdSocket>()?
+    };
+
+    Ok(name)
+}
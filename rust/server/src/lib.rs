@@ -1,26 +1,65 @@
+use std::panic::AssertUnwindSafe;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Instant;
+use anyhow::anyhow;
+use futures::FutureExt;
+use tokio::runtime::Handle;
 use vergen_pretty::vergen_pretty_env;
-use gauntlet_client::{open_window, start_client};
-use gauntlet_common::model::{BackendRequestData, BackendResponseData, UiRequestData, UiResponseData};
-use gauntlet_common::rpc::backend_api::BackendApi;
+use gauntlet_client::start_client;
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::error::{ErrorCode, GauntletError};
+use gauntlet_common::model::{BackendRequestData, BackendResponseData, SearchResultCategory, UiRequestData, UiResponseData};
 use gauntlet_common::rpc::backend_server::start_backend_server;
+use gauntlet_common::rpc::frontend_api::{FrontendApi, FrontendApiImpl};
 use gauntlet_common::{settings_env_data_from_string, settings_env_data_to_string, SettingsEnvData};
 use gauntlet_plugin_runtime::run_plugin_runtime;
 use gauntlet_utils::channel::{channel, RequestReceiver, RequestSender};
+use crate::instance::{claim_instance, forward_command, remove_instance_socket, InstanceClaim, InstanceCommand, InstanceListener};
+use crate::crash::{install_panic_hook, last_crash_report, latest_crash_report, write_crash_report, StartupMarker};
 use crate::plugins::ApplicationManager;
+use crate::plugins::macros::is_macros_plugin;
 use crate::rpc::BackendServerImpl;
 use crate::search::SearchIndex;
+use crate::external_frontend::{start_external_frontend, Transport};
+use crate::shutdown::{shutdown_on_signal, Shutdown, SHUTDOWN_TIMEOUT};
 
 pub mod rpc;
+pub mod diagnostics;
+pub mod instance;
+pub mod control;
 pub(in crate) mod search;
+pub(in crate) mod search_aggregator;
+pub(in crate) mod search_syntax;
 pub(in crate) mod plugins;
 pub(in crate) mod model;
+pub(in crate) mod crash;
+pub(in crate) mod external_frontend;
+pub(in crate) mod shutdown;
+
+pub use crate::plugins::doctor::run_doctor;
+pub use crate::plugins::remote_host::{plugin_host_fingerprint, run_plugin_host};
+
+// internals used by benchmarks in `benches`, not a public api
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench {
+    pub use crate::search::{SearchIndex, SearchIndexItem, SearchIndexItemRanking};
+}
 
 const SETTINGS_ENV: &'static str = "GAUNTLET_INTERNAL_SETTINGS";
 const PLUGIN_RUNTIME_ENV: &'static str = "GAUNTLET_INTERNAL_PLUGIN_RUNTIME";
 
-pub fn start(minimized: bool) {
+pub enum FrontendMode {
+    // built-in window
+    Window,
+    // frontend connected to frontend socket
+    ExternalSocket,
+    // frontend speaking json-rpc over stdin and stdout, e.g. editor integration
+    ExternalStdio,
+}
+
+pub fn start(minimized: bool, safe_mode: bool, frontend_mode: FrontendMode) {
     if let Ok(socket_name) = std::env::var(PLUGIN_RUNTIME_ENV) {
         run_plugin_runtime(socket_name);
 
@@ -39,17 +78,49 @@ pub fn start(minimized: bool) {
 
     #[cfg(not(feature = "scenario_runner"))]
     {
-        if is_server_running() {
-            open_window()
-        } else {
-            let (frontend_sender, frontend_receiver) = channel::<UiRequestData, UiResponseData>();
-            let (backend_sender, backend_receiver) = channel::<BackendRequestData, BackendResponseData>();
+        let instance_listener = match claim_instance() {
+            Ok(InstanceClaim::Claimed(instance_listener)) => Some(instance_listener),
+            Ok(InstanceClaim::AlreadyRunning) => {
+                // stdout of running instance is not available, opening its window would be confusing for the editor
+                if let FrontendMode::ExternalStdio = frontend_mode {
+                    tracing::error!("Gauntlet is already running, --stdio requires server to be started by the editor");
 
-            std::thread::spawn(|| {
-                start_server(frontend_sender, backend_receiver);
-            });
+                    std::process::exit(1)
+                }
+
+                if safe_mode {
+                    tracing::warn!("Gauntlet is already running, --safe-mode flag is ignored");
+                }
+
+                if !minimized {
+                    tracing::info!("Gauntlet is already running, opening window...");
 
-            start_client(minimized, frontend_receiver, backend_sender)
+                    if let Err(err) = forward_command(InstanceCommand::ShowWindow) {
+                        tracing::error!("Unable to open window of running instance: {:?}", err);
+                    }
+                }
+
+                return;
+            }
+            Err(err) => {
+                // not being able to detect other instance shouldn't prevent starting
+                tracing::error!("Unable to check if Gauntlet is already running: {:?}", err);
+
+                None
+            }
+        };
+
+        let (frontend_sender, frontend_receiver) = channel::<UiRequestData, UiResponseData>();
+        let (backend_sender, backend_receiver) = channel::<BackendRequestData, BackendResponseData>();
+
+        std::thread::spawn(move || {
+            start_server(frontend_sender, backend_receiver, safe_mode, instance_listener);
+        });
+
+        match frontend_mode {
+            FrontendMode::Window => start_client(minimized, frontend_receiver, backend_sender),
+            FrontendMode::ExternalSocket => start_external_frontend(Transport::Socket, frontend_receiver, backend_sender),
+            FrontendMode::ExternalStdio => start_external_frontend(Transport::Stdio, frontend_receiver, backend_sender),
         }
     }
 }
@@ -74,7 +145,7 @@ fn run_scenario_runner() {
             let (backend_sender, backend_receiver) = channel::<BackendRequestData, BackendResponseData>();
 
             std::thread::spawn(|| {
-                start_server(frontend_sender, backend_receiver)
+                start_server(frontend_sender, backend_receiver, false, None)
             });
 
             start_frontend_mock(frontend_receiver, backend_sender)
@@ -84,33 +155,67 @@ fn run_scenario_runner() {
 }
 
 
-fn is_server_running() -> bool {
-    tokio::runtime::Builder::new_multi_thread()
+fn start_server(
+    request_sender: RequestSender<UiRequestData, UiResponseData>,
+    backend_receiver: RequestReceiver<BackendRequestData, BackendResponseData>,
+    safe_mode: bool,
+    instance_listener: Option<InstanceListener>
+) {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
         .enable_all()
         .build()
-        .expect("unable to start server tokio runtime")
-        .block_on(async {
-            let test_fn = || async {
-                let mut api = BackendApi::new().await?;
+        .expect("unable to start server tokio runtime");
 
-                api.ping().await?;
+    let result = runtime.block_on(async {
+        let shutdown = Shutdown::new();
 
-                anyhow::Ok(())
-            };
+        shutdown_on_signal(shutdown.clone());
 
-            test_fn().await.is_ok()
-        })
+        let has_instance_listener = instance_listener.is_some();
+
+        if let Some(instance_listener) = instance_listener {
+            instance_listener.start(Handle::current(), FrontendApiImpl::new(request_sender.clone()));
+        }
+
+        let result = run_server(request_sender.clone(), backend_receiver, safe_mode, shutdown).await;
+
+        if has_instance_listener {
+            remove_instance_socket();
+        }
+
+        if result.is_ok() {
+            quit_frontend(request_sender).await;
+        }
+
+        result
+    });
+
+    // crash report is already written at this point
+    if let Err(err) = result {
+        tracing::error!("Server stopped because of an error: {:?}", err);
+    }
+
+    // tasks which are still running, e.g. blocking reads, are not waited for
+    runtime.shutdown_background();
 }
 
-fn start_server(request_sender: RequestSender<UiRequestData, UiResponseData>, backend_receiver: RequestReceiver<BackendRequestData, BackendResponseData>) {
-    tokio::runtime::Builder::new_multi_thread()
-        .enable_all()
-        .build()
-        .expect("unable to start server tokio runtime")
-        .block_on(async {
-            run_server(request_sender, backend_receiver).await
-        })
-        .unwrap();
+// frontend runs on main thread, process exits when it returns
+async fn quit_frontend(request_sender: RequestSender<UiRequestData, UiResponseData>) {
+    let frontend_api = FrontendApiImpl::new(request_sender);
+
+    match tokio::time::timeout(SHUTDOWN_TIMEOUT, frontend_api.quit()).await {
+        Ok(Ok(())) => {}
+        Ok(Err(err)) => {
+            tracing::error!("Unable to stop frontend, exiting: {:?}", err);
+
+            std::process::exit(0)
+        }
+        Err(_) => {
+            tracing::error!("Frontend didn't respond to quit request in {:?}, exiting", SHUTDOWN_TIMEOUT);
+
+            std::process::exit(0)
+        }
+    }
 }
 
 #[cfg(feature = "scenario_runner")]
@@ -128,10 +233,137 @@ fn start_frontend_mock(
         .unwrap();
 }
 
-async fn run_server(frontend_sender: RequestSender<UiRequestData, UiResponseData>, mut backend_receiver: RequestReceiver<BackendRequestData, BackendResponseData>) -> anyhow::Result<()> {
-    let application_manager = ApplicationManager::create(frontend_sender).await?;
+async fn run_server(frontend_sender: RequestSender<UiRequestData, UiResponseData>, mut backend_receiver: RequestReceiver<BackendRequestData, BackendResponseData>, safe_mode: bool, shutdown: Shutdown) -> anyhow::Result<()> {
+    let dirs = Dirs::new();
 
-    let mut application_manager = Arc::new(application_manager);
+    install_panic_hook(dirs.clone());
+
+    let startup_marker = StartupMarker::new(&dirs);
+
+    let safe_mode_reason = if safe_mode {
+        Some(("Safe mode was requested using --safe-mode flag".to_string(), None))
+    } else if startup_marker.previous_startup_failed() {
+        Some(("Previous start of Gauntlet did not finish".to_string(), latest_crash_report(&dirs)))
+    } else {
+        None
+    };
+
+    startup_marker.startup_started()?;
+
+    let application_manager = match safe_mode_reason {
+        None => {
+            match start_application_catching(&dirs, frontend_sender.clone(), false, shutdown.clone()).await {
+                Ok(application_manager) => application_manager,
+                Err((err, crash_report_path)) => {
+                    let description = format!("Gauntlet failed to start: {:#}", err);
+
+                    start_application_safe_mode(&dirs, frontend_sender, description, crash_report_path, shutdown.clone()).await?
+                }
+            }
+        }
+        Some((description, crash_report_path)) => {
+            start_application_safe_mode(&dirs, frontend_sender, description, crash_report_path, shutdown.clone()).await?
+        }
+    };
+
+    startup_marker.startup_finished()?;
+
+    loop {
+        let (request_data, responder) = tokio::select! {
+            request = backend_receiver.recv() => request,
+            _ = shutdown.requested() => break,
+        };
+
+        let response_data = match handle_request(application_manager.clone(), request_data).await {
+            Ok(response_data) => response_data,
+            Err(err) => {
+                tracing::warn!("error occurred when handling frontend request {:?}", err);
+
+                BackendResponseData::Error { error: GauntletError::from_anyhow(&err) }
+            }
+        };
+
+        responder.respond(response_data);
+    }
+
+    tracing::info!("Shutting down...");
+
+    // subsystems with shutdown token are already stopping by themselves at this point
+    match tokio::time::timeout(SHUTDOWN_TIMEOUT, application_manager.shutdown()).await {
+        Ok(()) => tracing::info!("Shutdown finished"),
+        Err(_) => tracing::warn!("Shutdown didn't finish in {:?}, exiting anyway", SHUTDOWN_TIMEOUT),
+    }
+
+    Ok(())
+}
+
+async fn start_application_safe_mode(
+    dirs: &Dirs,
+    frontend_sender: RequestSender<UiRequestData, UiResponseData>,
+    description: String,
+    crash_report_path: Option<String>,
+    shutdown: Shutdown
+) -> anyhow::Result<Arc<ApplicationManager>> {
+    tracing::warn!("Starting in safe mode: {}", description);
+
+    let frontend_api = FrontendApiImpl::new(frontend_sender.clone());
+
+    match start_application_catching(dirs, frontend_sender, true, shutdown).await {
+        Ok(application_manager) => {
+            if let Err(err) = frontend_api.show_recovery_view(true, description, crash_report_path).await {
+                tracing::error!("Unable to show recovery view: {:?}", err);
+            }
+
+            Ok(application_manager)
+        }
+        Err((err, safe_mode_crash_report_path)) => {
+            let description = format!("{}\n\nStarting in safe mode also failed: {:#}", description, err);
+
+            if let Err(err) = frontend_api.show_recovery_view(false, description, safe_mode_crash_report_path.or(crash_report_path)).await {
+                tracing::error!("Unable to show recovery view: {:?}", err);
+            }
+
+            Err(err)
+        }
+    }
+}
+
+// returns crash report path together with the error
+async fn start_application_catching(
+    dirs: &Dirs,
+    frontend_sender: RequestSender<UiRequestData, UiResponseData>,
+    safe_mode: bool,
+    shutdown: Shutdown
+) -> Result<Arc<ApplicationManager>, (anyhow::Error, Option<String>)> {
+    let result = AssertUnwindSafe(start_application(frontend_sender, safe_mode, shutdown))
+        .catch_unwind()
+        .await;
+
+    let (err, crash_report_path) = match result {
+        Ok(Ok(application_manager)) => return Ok(application_manager),
+        Ok(Err(err)) => {
+            let crash_report_path = write_crash_report(dirs, &format!("Startup failed: {:?}", err), None)
+                .inspect_err(|err| tracing::error!("Unable to write crash report: {:?}", err))
+                .ok();
+
+            (err, crash_report_path)
+        }
+        Err(_) => {
+            // report is written by panic hook
+            (anyhow!("panic occurred during startup"), last_crash_report())
+        }
+    };
+
+    let crash_report_path = crash_report_path
+        .and_then(|path| path.to_str().map(|path| path.to_string()));
+
+    Err((err, crash_report_path))
+}
+
+async fn start_application(frontend_sender: RequestSender<UiRequestData, UiResponseData>, safe_mode: bool, shutdown: Shutdown) -> anyhow::Result<Arc<ApplicationManager>> {
+    let application_manager = ApplicationManager::create(frontend_sender, safe_mode, shutdown.clone()).await?;
+
+    let application_manager = Arc::new(application_manager);
 
     application_manager.clear_all_icon_cache_dir()?;
 
@@ -151,63 +383,169 @@ async fn run_server(frontend_sender: RequestSender<UiRequestData, UiResponseData
         }
     }
 
-    application_manager.reload_all_plugins().await?; // TODO do not fail here ?
+    application_manager.reload_all_plugins().await?;
 
     tokio::spawn({
         let application_manager = application_manager.clone();
+        let shutdown_token = shutdown.subsystem_token();
 
         async move {
-            start_backend_server(Box::new(BackendServerImpl::new(application_manager.clone()))).await
+            start_backend_server(Box::new(BackendServerImpl::new(application_manager.clone())), shutdown_token.cancelled_owned()).await
         }
     });
 
-    loop {
-        let (request_data, responder) = backend_receiver.recv().await;
+    #[cfg(not(feature = "scenario_runner"))]
+    tokio::spawn({
+        let application_manager = application_manager.clone();
+        let shutdown_token = shutdown.subsystem_token();
 
-        let response_data = handle_request(application_manager.clone(), request_data)
-            .await
-            .unwrap(); // TODO error handling
+        async move {
+            if let Err(err) = control::start_control_server(application_manager, shutdown_token).await {
+                tracing::error!("unable to start control server: {:?}", err);
+            }
+        }
+    });
 
-        responder.respond(response_data);
-    }
+    #[cfg(not(feature = "scenario_runner"))]
+    tokio::spawn({
+        let application_manager = application_manager.clone();
+
+        async move {
+            if let Err(err) = application_manager.watch_config().await {
+                tracing::error!("unable to watch config file: {:?}", err);
+            }
+        }
+    });
+
+    #[cfg(not(feature = "scenario_runner"))]
+    tokio::spawn({
+        let application_manager = application_manager.clone();
+
+        async move {
+            if let Err(err) = application_manager.watch_session_lock().await {
+                tracing::error!("unable to watch screen lock: {:?}", err);
+            }
+        }
+    });
+
+    Ok(application_manager)
 }
 
 async fn handle_request(application_manager: Arc<ApplicationManager>, request_data: BackendRequestData) -> anyhow::Result<BackendResponseData> {
     let response_data = match request_data {
-        BackendRequestData::Search { text, render_inline_view } => {
-            let results = application_manager.search(&text, render_inline_view)?;
+        BackendRequestData::Search { text, keyword_plugin_id, category, render_inline_view, generation } => {
+            let (text, category, prefix_category) = match category {
+                Some(category) => (text, Some(category), None),
+                None => {
+                    match SearchResultCategory::parse_query_prefix(&text) {
+                        Some((category, text)) => (text.to_string(), Some(category), Some(category)),
+                        None => (text, None, None),
+                    }
+                }
+            };
+
+            let (text, keyword_plugin_id, keyword_mode) = match keyword_plugin_id {
+                Some(keyword_plugin_id) => (text, Some(keyword_plugin_id), None),
+                None => {
+                    match application_manager.search_keyword_mode(&text).await? {
+                        Some((keyword_mode, text)) => (text, Some(keyword_mode.plugin_id.clone()), Some(keyword_mode)),
+                        None => (text, None, None),
+                    }
+                }
+            };
+
+            // inline views are not shown while search is limited to one plugin or category
+            let render_inline_view = render_inline_view && keyword_plugin_id.is_none() && category.is_none();
+
+            let search_start = Instant::now();
+
+            let results = application_manager.search(&text, keyword_plugin_id.as_ref(), category, render_inline_view, generation)?;
+
+            application_manager.record_search_latency(search_start.elapsed());
+
+            application_manager.handle_plugin_search(&text, keyword_plugin_id, category, generation);
+
+            if application_manager.should_warm_up_plugins() {
+                let application_manager = application_manager.clone();
+
+                tokio::spawn(async move {
+                    if let Err(err) = application_manager.warm_up_plugins().await {
+                        tracing::error!("error when warming up plugins: {:?}", err);
+                    }
+                });
+            }
 
             BackendResponseData::Search {
                 results,
+                keyword_mode,
+                category: prefix_category,
             }
         }
         BackendRequestData::RequestViewRender { plugin_id, entrypoint_id } => {
-            let shortcuts = application_manager.handle_render_view(plugin_id.clone(), entrypoint_id.clone())
+            let (shortcuts, interactive, style) = application_manager.handle_render_view(plugin_id.clone(), entrypoint_id.clone())
                 .await?;
 
             BackendResponseData::RequestViewRender {
-                shortcuts
+                shortcuts,
+                interactive,
+                style,
             }
         }
-        BackendRequestData::RequestViewClose { plugin_id } => {
-            application_manager.handle_view_close(plugin_id);
+        BackendRequestData::RequestViewClose { plugin_id, entrypoint_id } => {
+            application_manager.handle_view_close(plugin_id, entrypoint_id);
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::RequestViewPop { plugin_id, entrypoint_id } => {
+            application_manager.handle_view_pop(plugin_id, entrypoint_id);
 
             BackendResponseData::Nothing
         }
-        BackendRequestData::RequestRunCommand { plugin_id, entrypoint_id } => {
-            application_manager.handle_run_command(plugin_id, entrypoint_id)
-                .await;
+        BackendRequestData::RequestViewVisibility { plugin_id, entrypoint_id, visible } => {
+            application_manager.handle_view_visibility(plugin_id, entrypoint_id, visible);
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::WindowVisibility { visible, active_window } => {
+            application_manager.handle_window_visibility(visible, active_window);
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::RequestRunCommand { plugin_id, entrypoint_id, arguments } => {
+            if is_macros_plugin(&plugin_id) {
+                // macro can wait between steps, frontend shouldn't be blocked until all of them are done
+                let application_manager = application_manager.clone();
+
+                tokio::spawn(async move {
+                    application_manager.run_macro(entrypoint_id)
+                        .await
+                });
+            } else {
+                application_manager.handle_run_command(plugin_id, entrypoint_id, arguments)
+                    .await;
+            }
 
             BackendResponseData::Nothing
         }
         BackendRequestData::RequestRunGeneratedCommand { plugin_id, entrypoint_id, action_index } => {
-            application_manager.handle_run_generated_command(plugin_id, entrypoint_id, action_index)
-                .await;
+            if let Err(err) = application_manager.handle_run_generated_command(plugin_id, entrypoint_id, action_index).await {
+                tracing::error!(target = "plugin", "Unable to run generated command: {:?}", err);
+            }
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::RequestArgumentSuggestions { plugin_id, entrypoint_id, argument_id, values, generation } => {
+            application_manager.handle_argument_suggestions(plugin_id, entrypoint_id, argument_id, values, generation);
 
             BackendResponseData::Nothing
         }
-        BackendRequestData::SendViewEvent { plugin_id, widget_id, event_name, event_arguments } => {
-            application_manager.handle_view_event(plugin_id, widget_id, event_name, event_arguments);
+        BackendRequestData::RequestSearchResultPreview { plugin_id, entrypoint_id } => {
+            application_manager.handle_search_result_preview(plugin_id, entrypoint_id);
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::SendViewEvent { plugin_id, entrypoint_id, render_location, widget_id, event_name, event_arguments } => {
+            application_manager.handle_view_event(plugin_id, entrypoint_id, render_location, widget_id, event_name, event_arguments);
 
             BackendResponseData::Nothing
         }
@@ -240,12 +578,58 @@ async fn handle_request(application_manager: Arc<ApplicationManager>, request_da
 
             BackendResponseData::Nothing
         }
+        BackendRequestData::CopyToClipboard { text } => {
+            application_manager.handle_copy_to_clipboard(text)?;
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::SetSearchResultAliases { plugin_id, entrypoint_id, aliases } => {
+            // aliases can also be changed in settings, so they are managed the same way
+            if application_manager.is_managed() {
+                return Err(GauntletError::new(ErrorCode::Managed, "Settings are managed by administrator and cannot be changed").into())
+            }
+
+            application_manager.set_search_result_aliases(plugin_id, entrypoint_id, aliases)
+                .await?;
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::SaveBugReport { plugin_id, entrypoint_id, widget_tree, screenshot } => {
+            let path = application_manager.save_bug_report(plugin_id, entrypoint_id, widget_tree, screenshot)
+                .await?;
+
+            BackendResponseData::BugReport { path }
+        }
+        BackendRequestData::Undo => {
+            application_manager.handle_undo();
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::Redo => {
+            application_manager.handle_redo();
+
+            BackendResponseData::Nothing
+        }
         BackendRequestData::InlineViewShortcuts => {
             let shortcuts = application_manager.inline_view_shortcuts()
                 .await?;
 
             BackendResponseData::InlineViewShortcuts { shortcuts }
         }
+        BackendRequestData::InlineViewOrder => {
+            let plugin_ids = application_manager.get_inline_view_order()
+                .await?
+                .into_iter()
+                .map(|inline_view| inline_view.plugin_id)
+                .collect();
+
+            BackendResponseData::InlineViewOrder { plugin_ids }
+        }
+        BackendRequestData::GetImages { image_ids } => {
+            let images = application_manager.get_images(image_ids);
+
+            BackendResponseData::Images { images }
+        }
     };
 
     Ok(response_data)
@@ -0,0 +1,26 @@
+use std::sync::{Arc, Mutex};
+
+use gauntlet_common::model::ActiveWindowContext;
+
+// window which was focused when launcher window was shown the last time,
+// kept after launcher window is hidden, because commands usually run after that
+#[derive(Clone)]
+pub struct ActiveWindow {
+    inner: Arc<Mutex<Option<ActiveWindowContext>>>,
+}
+
+impl ActiveWindow {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn set(&self, context: Option<ActiveWindowContext>) {
+        *self.inner.lock().expect("lock is poisoned") = context;
+    }
+
+    pub fn get(&self) -> Option<ActiveWindowContext> {
+        self.inner.lock().expect("lock is poisoned").clone()
+    }
+}
@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use vergen_pretty::vergen_pretty_env;
+
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::{PluginLogLine, PluginLogStream};
+
+pub struct BugReport {
+    pub plugin_id: String,
+    pub plugin_name: String,
+    pub plugin_version: Option<String>,
+    pub entrypoint_id: String,
+    pub entrypoint_name: String,
+    pub widget_tree: String,
+    // png
+    pub screenshot: Vec<u8>,
+    pub logs: Vec<PluginLogLine>,
+}
+
+// bundle is a directory, so it can be zipped and attached to an issue as is
+pub fn write_bug_report(dirs: &Dirs, report: BugReport) -> anyhow::Result<PathBuf> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or_default();
+
+    let mut summary = vec![];
+
+    summary.push("Gauntlet Plugin Bug Report".to_string());
+    summary.push(format!("Timestamp: {}", timestamp));
+    summary.push(format!("Operating system: {} {}", std::env::consts::OS, std::env::consts::ARCH));
+
+    for (name, value) in vergen_pretty_env!() {
+        if let Some(value) = value {
+            summary.push(format!("{}: {}", name, value));
+        }
+    }
+
+    summary.push("".to_string());
+    summary.push(format!("Plugin: {} ({})", report.plugin_name, report.plugin_id));
+    summary.push(format!("Plugin version: {}", report.plugin_version.as_deref().unwrap_or("-")));
+    summary.push(format!("Entrypoint: {} ({})", report.entrypoint_name, report.entrypoint_id));
+
+    let logs = report.logs
+        .into_iter()
+        .map(|line| {
+            let stream = match line.stream {
+                PluginLogStream::Stdout => "stdout",
+                PluginLogStream::Stderr => "stderr",
+            };
+
+            format!("[{}] {}", stream, line.text)
+        })
+        .collect::<Vec<_>>();
+
+    let bug_report_dir = dirs.bug_reports_dir().join(format!("bug-report-{}", timestamp));
+
+    std::fs::create_dir_all(&bug_report_dir)?;
+
+    std::fs::write(bug_report_dir.join("report.txt"), summary.join("\n"))?;
+    std::fs::write(bug_report_dir.join("widget_tree.txt"), report.widget_tree)?;
+    std::fs::write(bug_report_dir.join("screenshot.png"), report.screenshot)?;
+    std::fs::write(bug_report_dir.join("logs.txt"), logs.join("\n"))?;
+
+    Ok(bug_report_dir)
+}
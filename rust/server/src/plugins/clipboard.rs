@@ -2,12 +2,16 @@ use anyhow::{anyhow, Context, Error};
 use arboard::ImageData;
 use image::RgbaImage;
 use std::io::Cursor;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use gauntlet_plugin_runtime::JsClipboardData;
 
 #[derive(Clone)]
 pub struct Clipboard {
     clipboard: Arc<RwLock<arboard::Clipboard>>,
+    // reading is refused while screen is locked
+    paused: Arc<AtomicBool>,
 }
 
 impl Clipboard {
@@ -17,10 +21,25 @@ impl Clipboard {
 
         Ok(Self {
             clipboard: Arc::new(RwLock::new(clipboard)),
+            paused: Arc::new(AtomicBool::new(false)),
         })
     }
 
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::SeqCst);
+    }
+
+    fn ensure_not_paused(&self) -> anyhow::Result<()> {
+        if self.paused.load(Ordering::SeqCst) {
+            return Err(anyhow!("Clipboard cannot be read while screen is locked"))
+        }
+
+        Ok(())
+    }
+
     pub fn read(&self) -> anyhow::Result<JsClipboardData> {
+        self.ensure_not_paused()?;
+
         let mut clipboard = self.clipboard.write().expect("lock is poisoned");
 
         let png_data = match clipboard.get_image() {
@@ -64,6 +83,8 @@ impl Clipboard {
     }
 
     pub fn read_text(&self) -> anyhow::Result<Option<String>> {
+        self.ensure_not_paused()?;
+
         let mut clipboard = self.clipboard.write().expect("lock is poisoned");
 
         let data = match clipboard.get_text() {
@@ -124,6 +145,37 @@ impl Clipboard {
         Ok(())
     }
 
+    // text is removed after timeout unless something else was copied in the meantime, e.g. for one-time passwords
+    pub fn write_text_and_clear_after(&self, data: String, clear_after: Duration) -> anyhow::Result<()> {
+        self.write_text(data.clone())?;
+
+        let clipboard = self.clone();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(clear_after).await;
+
+            if let Err(err) = clipboard.clear_if_text(&data) {
+                tracing::warn!("Unable to clear clipboard: {:?}", err);
+            }
+        });
+
+        Ok(())
+    }
+
+    // content is only compared, not returned, so it is done regardless of screen lock
+    fn clear_if_text(&self, data: &str) -> anyhow::Result<()> {
+        let mut clipboard = self.clipboard.write().expect("lock is poisoned");
+
+        match clipboard.get_text() {
+            Ok(current) if current == data => {
+                clipboard.clear()
+                    .map_err(|err| unknown_err_clipboard(err))
+            }
+            Ok(_) | Err(arboard::Error::ContentNotAvailable) => Ok(()),
+            Err(err) => Err(unknown_err_clipboard(err)),
+        }
+    }
+
     pub fn clear(&self) -> anyhow::Result<()> {
         let mut clipboard = self.clipboard.write().expect("lock is poisoned");
 
@@ -1,23 +1,65 @@
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
 use serde::Deserialize;
 
 use gauntlet_common::dirs::Dirs;
+use gauntlet_common::frontend_config::{AccessibilityConfig, BackendRequestsConfig, EscapeBehavior, ImageCacheConfig, PromptHistoryConfig, SearchTrigger, SessionRestoreConfig, StartupConfig, ThemeSelection, TypeAheadConfig, WindowConfig};
+use gauntlet_common::model::{ConfigError, GeneralSettings};
+use gauntlet_plugin_runtime::JsPluginNetworkRateLimit;
 use crate::plugins::data_db_repository::{DataDbRepository, DbWritePendingPlugin};
+use crate::plugins::remote_host::RemotePluginHost;
 
 pub struct ConfigReader {
     dirs: Dirs,
     repository: DataDbRepository,
+    config_error: Mutex<Option<ConfigError>>,
+    plugin_runtime: Mutex<PluginRuntimeConfig>,
+    search: Mutex<SearchConfig>,
+    remote_plugin_host: Mutex<RemotePluginHostConfig>,
+    general_settings: Mutex<GeneralSettings>,
+    managed: Mutex<bool>,
+    // incremented every time config is read, settings window polls it to know when to reload shown settings
+    revision: AtomicU64,
 }
 
 impl ConfigReader {
     pub fn new(dirs: Dirs, repository: DataDbRepository) -> Self {
         Self {
             dirs,
-            repository
+            repository,
+            config_error: Mutex::new(None),
+            plugin_runtime: Mutex::new(PluginRuntimeConfig::default()),
+            search: Mutex::new(SearchConfig::default()),
+            remote_plugin_host: Mutex::new(RemotePluginHostConfig::default()),
+            general_settings: Mutex::new(ApplicationConfig::default().general_settings()),
+            managed: Mutex::new(false),
+            revision: AtomicU64::new(0),
         }
     }
 
-    pub async fn reload_config(&self) -> anyhow::Result<()> {
-        let config = self.read_config();
+    // returns error of the config file, default configuration is used in that case
+    pub async fn reload_config(&self) -> anyhow::Result<Option<ConfigError>> {
+        let (config, config_error) = match self.read_config() {
+            Ok(config) => (config, None),
+            Err(err) => {
+                tracing::error!("Unable to read config, using default configuration, error: {:?}", err);
+
+                (ApplicationConfig::default(), Some(err))
+            }
+        };
+
+        *self.config_error.lock().unwrap() = config_error.clone();
+        *self.general_settings.lock().unwrap() = config.general_settings();
+        *self.plugin_runtime.lock().unwrap() = config.plugin_runtime;
+        *self.search.lock().unwrap() = config.search;
+        *self.remote_plugin_host.lock().unwrap() = config.remote_plugin_host;
+        *self.managed.lock().unwrap() = config.managed;
+
+        self.revision.fetch_add(1, Ordering::SeqCst);
 
         for plugin in config.plugins {
             let exists = self.repository.does_plugin_exist(&plugin.id).await?;
@@ -32,29 +74,147 @@ impl ConfigReader {
             }
         }
 
-        Ok(())
+        Ok(config_error)
+    }
+
+    pub fn config_error(&self) -> Option<ConfigError> {
+        self.config_error.lock().unwrap().clone()
     }
 
-    fn read_config(&self) -> ApplicationConfig {
-        let config_file = self.dirs.config_file();
-        let config_content = std::fs::read_to_string(config_file);
+    pub fn lazy_plugin_start(&self) -> bool {
+        self.plugin_runtime.lock().unwrap().lazy_start
+    }
 
-        match config_content {
-            Ok(config_content) => {
-                toml::from_str(&config_content)
-                    .unwrap_or_else(|err| {
-                        tracing::error!("Unable to parse config, error: {:?}", err);
+    // none if plugins should keep running background work while window is hidden
+    pub fn plugin_idle_after(&self) -> Option<Duration> {
+        let plugin_runtime = self.plugin_runtime.lock().unwrap();
 
-                        ApplicationConfig::default()
-                    })
-            }
-            Err(_) => {
-                tracing::info!("No config found, using default configuration");
+        plugin_runtime.idle_mode
+            .then(|| Duration::from_secs(plugin_runtime.idle_after_seconds))
+    }
+
+    pub fn plugin_min_render_interval(&self, plugin_id: &str) -> Duration {
+        let plugin_runtime = self.plugin_runtime.lock().unwrap();
+
+        let max_renders_per_second = plugin_runtime.plugin_max_renders_per_second
+            .get(plugin_id)
+            .copied()
+            .unwrap_or(plugin_runtime.max_renders_per_second);
+
+        if max_renders_per_second == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs(1) / max_renders_per_second
+        }
+    }
+
+    pub fn plugin_network_rate_limit(&self, plugin_id: &str) -> JsPluginNetworkRateLimit {
+        let plugin_runtime = self.plugin_runtime.lock().unwrap();
+
+        let rate_limit = plugin_runtime.plugin_network_rate_limit
+            .get(plugin_id)
+            .unwrap_or(&plugin_runtime.network_rate_limit);
+
+        JsPluginNetworkRateLimit {
+            requests: rate_limit.requests,
+            interval_seconds: rate_limit.interval_seconds,
+        }
+    }
+
+    // none if runtime of the plugin is started on this machine
+    pub fn remote_plugin_host(&self, plugin_id: &str) -> Option<RemotePluginHost> {
+        let remote_plugin_host = self.remote_plugin_host.lock().unwrap();
 
-                ApplicationConfig::default()
+        if !remote_plugin_host.plugins.iter().any(|id| id == plugin_id) {
+            return None
+        }
+
+        match (&remote_plugin_host.address, &remote_plugin_host.host_fingerprint) {
+            (Some(address), Some(host_fingerprint)) => Some(RemotePluginHost {
+                address: address.clone(),
+                host_fingerprint: host_fingerprint.clone(),
+            }),
+            _ => {
+                tracing::warn!("Plugin {} is listed in remote_plugin_host config but address or host_fingerprint is not set, starting it locally", plugin_id);
+
+                None
             }
         }
     }
+
+    pub fn search_transliteration(&self) -> bool {
+        self.search.lock().unwrap().transliteration
+    }
+
+    pub fn search_max_results(&self) -> Option<u32> {
+        self.search.lock().unwrap().max_results
+    }
+
+    pub fn general_settings(&self) -> GeneralSettings {
+        self.general_settings.lock().unwrap().clone()
+    }
+
+    pub fn managed(&self) -> bool {
+        *self.managed.lock().unwrap()
+    }
+
+    pub fn revision(&self) -> u64 {
+        self.revision.load(Ordering::SeqCst)
+    }
+
+    fn read_config(&self) -> Result<ApplicationConfig, ConfigError> {
+        read_config_file(&self.dirs)
+    }
+}
+
+// Ok if file doesn't exist, default configuration is used in that case
+pub fn read_config_file(dirs: &Dirs) -> Result<ApplicationConfig, ConfigError> {
+    let config_file = dirs.config_file();
+    let config_file_display = config_file.to_string_lossy().to_string();
+
+    let config_content = match std::fs::read_to_string(&config_file) {
+        Ok(config_content) => config_content,
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            tracing::info!("No config found, using default configuration");
+
+            return Ok(ApplicationConfig::default())
+        }
+        Err(err) => {
+            return Err(ConfigError {
+                config_file: config_file_display,
+                message: err.to_string(),
+                line: None,
+                column: None,
+            })
+        }
+    };
+
+    toml::from_str(&config_content)
+        .map_err(|err| {
+            let (line, column) = err.span()
+                .map(|span| line_and_column(&config_content, span.start))
+                .unzip();
+
+            ConfigError {
+                config_file: config_file_display,
+                message: err.message().to_string(),
+                line,
+                column,
+            }
+        })
+}
+
+// both are 1-based, as shown by text editors
+fn line_and_column(content: &str, offset: usize) -> (u32, u32) {
+    let before = content.get(..offset).unwrap_or(content);
+
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n')
+        .next()
+        .map(|line| line.chars().count())
+        .unwrap_or(0) + 1;
+
+    (line as u32, column as u32)
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -63,6 +223,75 @@ pub struct ApplicationConfig {
     // configuration_mode: ConfigurationModeConfig,
     #[serde(default)]
     plugins: Vec<PluginEntryConfig>,
+    #[serde(default)]
+    plugin_runtime: PluginRuntimeConfig,
+    #[serde(default)]
+    search: SearchConfig,
+    #[serde(default)]
+    remote_plugin_host: RemotePluginHostConfig,
+    // read by frontends directly when they start, here only to report invalid values
+    #[allow(unused)]
+    locale: Option<String>,
+    #[allow(unused)]
+    #[serde(default)]
+    search_trigger: SearchTrigger,
+    #[allow(unused)]
+    #[serde(default)]
+    escape_behavior: EscapeBehavior,
+    #[allow(unused)]
+    #[serde(default)]
+    type_ahead: TypeAheadConfig,
+    #[allow(unused)]
+    #[serde(default)]
+    prompt_history: PromptHistoryConfig,
+    #[allow(unused)]
+    #[serde(default)]
+    session_restore: SessionRestoreConfig,
+    #[allow(unused)]
+    #[serde(default)]
+    backend_requests: BackendRequestsConfig,
+    #[allow(unused)]
+    #[serde(default)]
+    image_cache: ImageCacheConfig,
+    #[serde(default)]
+    theme: ThemeSelection,
+    #[serde(default)]
+    accessibility: AccessibilityConfig,
+    #[serde(default)]
+    window: WindowConfig,
+    #[serde(default)]
+    startup: StartupConfig,
+    #[serde(default)]
+    telemetry: bool,
+    #[serde(default)]
+    check_for_updates: bool,
+    #[serde(default)]
+    usage_statistics: UsageStatisticsConfig,
+    #[serde(default)]
+    sound: SoundConfig,
+    // deployed by administrator, settings window is read-only and plugins cannot be installed or removed
+    #[serde(default)]
+    managed: bool,
+}
+
+impl ApplicationConfig {
+    // part of the config that can be changed in general tab of settings window
+    fn general_settings(&self) -> GeneralSettings {
+        GeneralSettings {
+            theme: self.theme,
+            window_position: self.window.position,
+            hide_window_on_focus_loss: self.window.hide_on_focus_loss,
+            max_search_results: self.search.max_results,
+            open_window_on_start: self.startup.open_window,
+            launch_at_login: self.startup.launch_at_login,
+            telemetry: self.telemetry,
+            check_for_updates: self.check_for_updates,
+            usage_statistics: self.usage_statistics.enabled,
+            sound_muted: self.sound.muted,
+            reduced_motion: self.accessibility.reduced_motion,
+            high_contrast: self.accessibility.high_contrast,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -70,6 +299,107 @@ struct PluginEntryConfig {
     id: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct PluginRuntimeConfig {
+    // runtime of plugin is started when one of its entrypoints is used or in background after first search,
+    // plugins with command generators are always started right away because their results need to be in search index
+    lazy_start: bool,
+    // plugins are asked to pause polling and file watching after window is hidden for this long
+    idle_mode: bool,
+    idle_after_seconds: u64,
+    // successive renders of plugin views that arrive faster are coalesced, only the latest one is sent to frontend.
+    // zero disables the limit
+    max_renders_per_second: u32,
+    // overrides of max_renders_per_second by plugin id
+    plugin_max_renders_per_second: HashMap<String, u32>,
+    // requests made with Http.fetch helper, responses served from cache are not counted
+    network_rate_limit: NetworkRateLimitConfig,
+    // overrides of network_rate_limit by plugin id
+    plugin_network_rate_limit: HashMap<String, NetworkRateLimitConfig>,
+}
+
+impl Default for PluginRuntimeConfig {
+    fn default() -> Self {
+        Self {
+            lazy_start: true,
+            idle_mode: true,
+            idle_after_seconds: 60,
+            max_renders_per_second: 60,
+            plugin_max_renders_per_second: HashMap::new(),
+            network_rate_limit: NetworkRateLimitConfig::default(),
+            plugin_network_rate_limit: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct NetworkRateLimitConfig {
+    requests: u32,
+    interval_seconds: u32,
+}
+
+impl Default for NetworkRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            requests: 120,
+            interval_seconds: 60,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct SearchConfig {
+    // names in non-latin scripts are additionally matched by their latin transliteration
+    transliteration: bool,
+    // none shows all results
+    max_results: Option<u32>,
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        Self {
+            transliteration: true,
+            max_results: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct RemotePluginHostConfig {
+    // address of another machine running `gauntlet plugin-host`
+    address: Option<String>,
+    // certificate fingerprint printed by `gauntlet plugin-host --fingerprint` on that machine
+    host_fingerprint: Option<String>,
+    // ids of plugins which runtimes are started there
+    plugins: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct UsageStatisticsConfig {
+    // launches and search latency are stored in local database, never sent anywhere
+    enabled: bool,
+}
+
+impl Default for UsageStatisticsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SoundConfig {
+    // checked every time plugin plays a sound, so changes are applied without restart
+    muted: bool,
+}
+
 // #[derive(Deserialize, Debug, Default)]
 // enum ConfigurationModeConfig {
 //     #[serde(rename = "config")]
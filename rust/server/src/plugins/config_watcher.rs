@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+use gauntlet_common::dirs::Dirs;
+
+// editors often write file in several steps, e.g. truncate and write or write to temporary file and rename,
+// so changes are collected for some time and config is read once they settle
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(500);
+
+pub struct ConfigWatcher {
+    // events stop being produced when watcher is dropped
+    _watcher: RecommendedWatcher,
+    receiver: UnboundedReceiver<()>,
+}
+
+impl ConfigWatcher {
+    pub fn new(dirs: &Dirs) -> anyhow::Result<Self> {
+        let config_dir = dirs.config_dir();
+
+        // theme files are read together with config
+        let watched_files = vec![
+            dirs.config_file(),
+            dirs.complex_theme_file(),
+            dirs.theme_simple_file(),
+        ];
+
+        let (sender, receiver) = unbounded_channel();
+
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            match event {
+                Ok(event) => {
+                    if let EventKind::Access(_) = event.kind {
+                        return;
+                    }
+
+                    if event.paths.iter().any(|path| watched_files.contains(path)) {
+                        // receiver is dropped together with watcher
+                        let _ = sender.send(());
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!("error watching config directory: {:?}", err);
+                }
+            }
+        })?;
+
+        // directory is watched instead of the file, so that config file can be created after start and replaced by editors
+        std::fs::create_dir_all(&config_dir)?;
+
+        watcher.watch(&config_dir, RecursiveMode::NonRecursive)?;
+
+        Ok(Self {
+            _watcher: watcher,
+            receiver,
+        })
+    }
+
+    // returns false when watcher stopped producing events
+    pub async fn next_change(&mut self) -> bool {
+        if self.receiver.recv().await.is_none() {
+            return false
+        }
+
+        let deadline = tokio::time::sleep(DEBOUNCE_DURATION);
+        tokio::pin!(deadline);
+
+        loop {
+            tokio::select! {
+                _ = &mut deadline => break,
+                change = self.receiver.recv() => {
+                    if change.is_none() {
+                        break
+                    }
+                }
+            }
+        }
+
+        true
+    }
+}
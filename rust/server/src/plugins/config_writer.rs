@@ -0,0 +1,72 @@
+use std::io::ErrorKind;
+use std::path::Path;
+
+use anyhow::{anyhow, Context};
+use toml_edit::{value, DocumentMut, Item, Table};
+
+use gauntlet_common::error::{ErrorCode, GauntletError};
+use gauntlet_common::model::GeneralSettings;
+
+// settings changed in settings window are written back to config file,
+// everything else in the file, including comments and formatting, is kept as is
+pub fn write_general_settings(config_file: &Path, settings: &GeneralSettings) -> anyhow::Result<()> {
+    let content = match std::fs::read_to_string(config_file) {
+        Ok(content) => content,
+        Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err).context("unable to read config file"),
+    };
+
+    let mut document = content.parse::<DocumentMut>()
+        .map_err(|err| {
+            GauntletError::new(ErrorCode::InvalidConfig, "config file cannot be parsed, it needs to be fixed before settings can be saved")
+                .with_cause(&err.into())
+        })?;
+
+    document["theme"] = value(settings.theme.as_config_value());
+    document["telemetry"] = value(settings.telemetry);
+    document["check_for_updates"] = value(settings.check_for_updates);
+
+    let window = table(&mut document, "window")?;
+    window["position"] = value(settings.window_position.as_config_value());
+    window["hide_on_focus_loss"] = value(settings.hide_window_on_focus_loss);
+
+    let search = table(&mut document, "search")?;
+    match settings.max_search_results {
+        None => {
+            search.remove("max_results");
+        }
+        Some(max_results) => {
+            search["max_results"] = value(max_results as i64);
+        }
+    }
+
+    let startup = table(&mut document, "startup")?;
+    startup["open_window"] = value(settings.open_window_on_start);
+    startup["launch_at_login"] = value(settings.launch_at_login);
+
+    let usage_statistics = table(&mut document, "usage_statistics")?;
+    usage_statistics["enabled"] = value(settings.usage_statistics);
+
+    let sound = table(&mut document, "sound")?;
+    sound["muted"] = value(settings.sound_muted);
+
+    let accessibility = table(&mut document, "accessibility")?;
+    accessibility["reduced_motion"] = value(settings.reduced_motion);
+    accessibility["high_contrast"] = value(settings.high_contrast);
+
+    if let Some(config_dir) = config_file.parent() {
+        std::fs::create_dir_all(config_dir)?;
+    }
+
+    std::fs::write(config_file, document.to_string())
+        .context("unable to write config file")?;
+
+    Ok(())
+}
+
+fn table<'a>(document: &'a mut DocumentMut, key: &str) -> anyhow::Result<&'a mut Table> {
+    document.entry(key)
+        .or_insert(Item::Table(Table::new()))
+        .as_table_mut()
+        .ok_or_else(|| anyhow!("'{}' in config file is expected to be a table", key))
+}
@@ -0,0 +1,83 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::net::TcpStream;
+use tokio_util::sync::CancellationToken;
+
+use crate::plugins::js::{AllPluginCommandData, PluginCommand};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+// public dns servers, ip addresses are used so that check doesn't hang on name resolution.
+// connection to any of them is enough to consider machine online
+const CHECK_ADDRESSES: [&str; 2] = ["1.1.1.1:443", "8.8.8.8:443"];
+
+// plugins are told when network becomes unavailable, so that they can show cached data
+// instead of waiting for requests to time out. requests made by server itself, like update check, are skipped while offline
+#[derive(Clone)]
+pub struct ConnectivityMonitor {
+    // assumed to be online until first check says otherwise
+    online: Arc<AtomicBool>,
+    command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>,
+    shutdown_token: CancellationToken,
+}
+
+impl ConnectivityMonitor {
+    pub fn new(command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>, shutdown_token: CancellationToken) -> Self {
+        Self {
+            online: Arc::new(AtomicBool::new(true)),
+            command_broadcaster,
+            shutdown_token,
+        }
+    }
+
+    pub fn is_online(&self) -> bool {
+        self.online.load(Ordering::SeqCst)
+    }
+
+    pub fn start(&self) {
+        let monitor = self.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let online = check_connectivity().await;
+
+                monitor.set_online(online);
+
+                tokio::select! {
+                    _ = tokio::time::sleep(CHECK_INTERVAL) => {}
+                    _ = monitor.shutdown_token.cancelled() => break
+                }
+            }
+        });
+    }
+
+    fn set_online(&self, online: bool) {
+        if self.online.swap(online, Ordering::SeqCst) == online {
+            return
+        }
+
+        if online {
+            tracing::info!(target = "plugin", "Network is available again");
+        } else {
+            tracing::info!(target = "plugin", "Network is unavailable, plugins are notified that machine is offline");
+        }
+
+        // it is possible to have 0 plugins
+        let _ = self.command_broadcaster.send(PluginCommand::All {
+            data: AllPluginCommandData::ConnectivityChanged { online }
+        });
+    }
+}
+
+async fn check_connectivity() -> bool {
+    for address in CHECK_ADDRESSES {
+        if let Ok(Ok(_)) = tokio::time::timeout(CHECK_TIMEOUT, TcpStream::connect(address)).await {
+            return true
+        }
+    }
+
+    false
+}
@@ -0,0 +1,404 @@
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context};
+use sqlx::{Pool, Sqlite, SqlitePool};
+use sqlx::migrate::Migrator;
+use sqlx::sqlite::SqliteConnectOptions;
+
+use gauntlet_common::dirs::Dirs;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./db_migrations");
+
+// oldest backups are removed when there are more
+const MAX_BACKUPS: usize = 5;
+
+pub struct DataDbRecovery {
+    pub reason: String,
+    pub broken_db_file: PathBuf,
+    pub restored_backup_file: Option<PathBuf>,
+}
+
+// opens data.db, verifies its integrity and applies pending migrations, making a backup beforehand.
+// if database is corrupted, it is moved aside and replaced by the latest working backup or by a new database.
+// other errors, e.g. failed migration or full disk, are returned as is, replacing database in that case would lose data
+pub async fn open_data_db(dirs: &Dirs) -> anyhow::Result<(Pool<Sqlite>, Option<DataDbRecovery>)> {
+    let data_db_file = dirs.data_db_file()?;
+    let backups_dir = dirs.data_db_backups_dir()?;
+
+    std::fs::create_dir_all(&data_db_file.parent().unwrap())
+        .context("Unable to create data directory")?;
+
+    let import_file = dirs.data_db_import_file()?;
+    if import_file.exists() {
+        apply_import(&data_db_file, &import_file, &backups_dir)?;
+        apply_config_import(&dirs.config_import_dir()?, &dirs.config_dir(), &backups_dir)?;
+    }
+
+    let err = match open_and_migrate(&data_db_file, &backups_dir).await {
+        Ok(pool) => return Ok((pool, None)),
+        Err(err) => err,
+    };
+
+    if !data_db_file.exists() || !is_corrupted(&err) {
+        return Err(err)
+    }
+
+    tracing::error!("Database is corrupted, trying to recover: {:?}", err);
+
+    let reason = format!("{:#}", err);
+
+    let broken_db_file = move_broken_db_aside(&data_db_file)?;
+
+    for backup_file in list_backups(&backups_dir)?.into_iter().rev() {
+        std::fs::copy(&backup_file, &data_db_file)
+            .context("Unable to restore database backup")?;
+
+        match open_and_migrate(&data_db_file, &backups_dir).await {
+            Ok(pool) => {
+                tracing::info!("Database restored from backup {:?}", backup_file);
+
+                let recovery = DataDbRecovery {
+                    reason,
+                    broken_db_file,
+                    restored_backup_file: Some(backup_file),
+                };
+
+                return Ok((pool, Some(recovery)))
+            }
+            Err(err) => {
+                tracing::error!("Unable to restore database backup {:?}: {:?}", backup_file, err);
+
+                std::fs::remove_file(&data_db_file)
+                    .context("Unable to remove broken database backup copy")?;
+
+                if !is_corrupted(&err) {
+                    // backup itself is fine, so the problem is somewhere else,
+                    // broken database is put back to be recovered on next start
+                    std::fs::rename(&broken_db_file, &data_db_file)
+                        .context("Unable to move broken database back")?;
+
+                    return Err(err)
+                }
+            }
+        }
+    }
+
+    tracing::warn!("No working database backup found, creating new database");
+
+    let pool = open_and_migrate(&data_db_file, &backups_dir)
+        .await
+        .context("Unable to create new database")?;
+
+    let recovery = DataDbRecovery {
+        reason,
+        broken_db_file,
+        restored_backup_file: None,
+    };
+
+    Ok((pool, Some(recovery)))
+}
+
+// used by doctor command, which can run while server is using the database,
+// so it is opened read-only, without recovery and without applying migrations.
+// returns None if database doesn't exist yet, otherwise number of migrations that will be applied on next start
+pub async fn open_data_db_read_only(dirs: &Dirs) -> anyhow::Result<Option<(Pool<Sqlite>, usize)>> {
+    let data_db_file = dirs.data_db_file()?;
+
+    if !data_db_file.exists() {
+        return Ok(None)
+    }
+
+    let conn = SqliteConnectOptions::new()
+        .filename(&data_db_file)
+        .read_only(true);
+
+    let pool = SqlitePool::connect_with(conn)
+        .await
+        .context("Unable to open database connection")?;
+
+    check_integrity(&pool).await?;
+
+    let applied_versions = applied_migration_versions(&pool).await?;
+
+    let pending_migrations = MIGRATOR.iter()
+        .filter(|migration| !applied_versions.contains(&migration.version))
+        .count();
+
+    Ok(Some((pool, pending_migrations)))
+}
+
+// current database is kept in backups, so it can be restored if imported one doesn't work
+fn apply_import(data_db_file: &Path, import_file: &Path, backups_dir: &Path) -> anyhow::Result<()> {
+    tracing::info!("Replacing database with imported one from {:?}", import_file);
+
+    if data_db_file.exists() {
+        std::fs::create_dir_all(backups_dir)
+            .context("Unable to create database backups directory")?;
+
+        let backup_file = backups_dir.join(format!("data-before-import-{}.db", timestamp()));
+
+        std::fs::rename(data_db_file, &backup_file)
+            .context("Unable to backup database before import")?;
+
+        remove_journal_files(data_db_file);
+    }
+
+    std::fs::rename(import_file, data_db_file)
+        .context("Unable to move imported database")?;
+
+    Ok(())
+}
+
+// applied right after database from the same archive, current config files are kept in database backups directory.
+// config and data directories can be on different file systems, so files are copied instead of moved
+fn apply_config_import(config_import_dir: &Path, config_dir: &Path, backups_dir: &Path) -> anyhow::Result<()> {
+    if !config_import_dir.exists() {
+        return Ok(())
+    }
+
+    let timestamp = timestamp();
+
+    std::fs::create_dir_all(config_dir)
+        .context("Unable to create config directory")?;
+
+    for entry in std::fs::read_dir(config_import_dir).context("Unable to read imported config directory")? {
+        let entry = entry?;
+
+        let config_file = config_dir.join(entry.file_name());
+
+        tracing::info!("Replacing {:?} with imported one", config_file);
+
+        if config_file.exists() {
+            std::fs::create_dir_all(backups_dir)
+                .context("Unable to create database backups directory")?;
+
+            let backup_file = backups_dir.join(format!("before-import-{}-{}", timestamp, entry.file_name().to_string_lossy()));
+
+            std::fs::copy(&config_file, &backup_file)
+                .with_context(|| format!("Unable to backup {:?} before import", config_file))?;
+        }
+
+        std::fs::copy(entry.path(), &config_file)
+            .with_context(|| format!("Unable to copy imported {:?}", config_file))?;
+    }
+
+    std::fs::remove_dir_all(config_import_dir)
+        .context("Unable to remove imported config directory")?;
+
+    Ok(())
+}
+
+async fn open_and_migrate(data_db_file: &Path, backups_dir: &Path) -> anyhow::Result<Pool<Sqlite>> {
+    let conn = SqliteConnectOptions::new()
+        .filename(data_db_file)
+        .create_if_missing(true);
+
+    let pool = SqlitePool::connect_with(conn)
+        .await
+        .context("Unable to open database connection")?;
+
+    match check_and_migrate(&pool, backups_dir).await {
+        Ok(()) => Ok(pool),
+        Err(err) => {
+            // file needs to be released before it can be moved
+            pool.close().await;
+
+            Err(err)
+        }
+    }
+}
+
+async fn check_and_migrate(pool: &Pool<Sqlite>, backups_dir: &Path) -> anyhow::Result<()> {
+    check_integrity(pool).await?;
+
+    let applied_versions = applied_migration_versions(pool).await?;
+
+    // new database doesn't have anything worth backing up
+    if let Some(latest_applied_version) = applied_versions.iter().max() {
+        let has_pending = MIGRATOR.iter()
+            .any(|migration| !applied_versions.contains(&migration.version));
+
+        if has_pending {
+            backup(pool, backups_dir, *latest_applied_version).await?;
+        }
+    }
+
+    MIGRATOR.run(pool)
+        .await
+        .context("Unable apply database migration")?;
+
+    Ok(())
+}
+
+async fn check_integrity(pool: &Pool<Sqlite>) -> anyhow::Result<()> {
+    // language=SQLite
+    let problems = sqlx::query_as::<_, (String, )>("PRAGMA integrity_check")
+        .fetch_all(pool)
+        .await
+        .context("Unable to check database integrity")?
+        .into_iter()
+        .map(|(result, )| result)
+        .filter(|result| result != "ok")
+        .collect::<Vec<_>>();
+
+    if !problems.is_empty() {
+        return Err(DataDbCorrupted(problems.join("; ")).into())
+    }
+
+    Ok(())
+}
+
+#[derive(Debug)]
+struct DataDbCorrupted(String);
+
+impl std::fmt::Display for DataDbCorrupted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Database integrity check failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for DataDbCorrupted {}
+
+// https://www.sqlite.org/rescode.html
+const SQLITE_CORRUPT: i32 = 11;
+const SQLITE_NOTADB: i32 = 26;
+
+fn is_corrupted(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        if cause.is::<DataDbCorrupted>() {
+            return true
+        }
+
+        match cause.downcast_ref::<sqlx::Error>() {
+            Some(sqlx::Error::Database(db_err)) => {
+                // sqlite reports extended result code, primary one is in the lowest byte
+                db_err.code()
+                    .and_then(|code| code.parse::<i32>().ok())
+                    .is_some_and(|code| matches!(code & 0xff, SQLITE_CORRUPT | SQLITE_NOTADB))
+            }
+            _ => false
+        }
+    })
+}
+
+async fn applied_migration_versions(pool: &Pool<Sqlite>) -> anyhow::Result<Vec<i64>> {
+    // language=SQLite
+    let table_exists = sqlx::query_as::<_, (u8, )>("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations'")
+        .fetch_optional(pool)
+        .await?
+        .is_some();
+
+    if !table_exists {
+        return Ok(vec![])
+    }
+
+    // language=SQLite
+    let versions = sqlx::query_as::<_, (i64, )>("SELECT version FROM _sqlx_migrations WHERE success = true")
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|(version, )| version)
+        .collect();
+
+    Ok(versions)
+}
+
+async fn backup(pool: &Pool<Sqlite>, backups_dir: &Path, version: i64) -> anyhow::Result<()> {
+    std::fs::create_dir_all(backups_dir)
+        .context("Unable to create database backups directory")?;
+
+    let backup_file = backups_dir.join(format!("data-v{}-{}.db", version, timestamp()));
+
+    tracing::info!("Backing up database before migration to {:?}", backup_file);
+
+    let backup_file_str = backup_file.to_str()
+        .ok_or(anyhow!("Database backup path is not valid unicode: {:?}", backup_file))?;
+
+    // language=SQLite
+    sqlx::query("VACUUM INTO ?1")
+        .bind(backup_file_str)
+        .execute(pool)
+        .await
+        .context("Unable to backup database")?;
+
+    let backups = list_backups(backups_dir)?;
+
+    if backups.len() > MAX_BACKUPS {
+        for old_backup in &backups[..backups.len() - MAX_BACKUPS] {
+            if let Err(err) = std::fs::remove_file(old_backup) {
+                tracing::warn!("Unable to remove old database backup {:?}: {:?}", old_backup, err);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// oldest first
+fn list_backups(backups_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    if !backups_dir.exists() {
+        return Ok(vec![])
+    }
+
+    let mut backups = std::fs::read_dir(backups_dir)
+        .context("Unable to read database backups directory")?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|extension| extension == "db"))
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+
+            Some((modified, entry.path()))
+        })
+        .collect::<Vec<_>>();
+
+    backups.sort_by_key(|(modified, _)| *modified);
+
+    Ok(backups.into_iter().map(|(_, path)| path).collect())
+}
+
+fn move_broken_db_aside(data_db_file: &Path) -> anyhow::Result<PathBuf> {
+    let broken_db_file = data_db_file.with_file_name(format!("data-broken-{}.db", timestamp()));
+
+    std::fs::rename(data_db_file, &broken_db_file)
+        .context("Unable to move broken database")?;
+
+    remove_journal_files(data_db_file);
+
+    Ok(broken_db_file)
+}
+
+// journal of the previous database is of no use for the new one
+fn remove_journal_files(data_db_file: &Path) {
+    for suffix in ["-wal", "-shm", "-journal"] {
+        let mut journal_file = data_db_file.as_os_str().to_owned();
+        journal_file.push(suffix);
+
+        let _ = std::fs::remove_file(journal_file);
+    }
+}
+
+fn timestamp() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_corruption_triggers_recovery() {
+        let corrupted = anyhow::Error::from(DataDbCorrupted("row 1 missing from index".to_string()))
+            .context("Unable to open database");
+
+        assert!(is_corrupted(&corrupted));
+
+        let migration_failed = anyhow!("no such column: name")
+            .context("Unable apply database migration");
+
+        assert!(!is_corrupted(&migration_failed));
+    }
+}
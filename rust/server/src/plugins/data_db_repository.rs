@@ -1,23 +1,24 @@
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::{anyhow, Context};
+use anyhow::anyhow;
 use futures::{StreamExt, TryStreamExt};
 use futures::future::join_all;
 use serde::{Deserialize, Serialize};
-use sqlx::{Error, Executor, Pool, Row, Sqlite, SqlitePool};
-use sqlx::migrate::Migrator;
-use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{Error, Executor, Pool, Row, Sqlite};
 use sqlx::types::Json;
 use typed_path::TypedPathBuf;
 use uuid::Uuid;
-use gauntlet_common::model::{PhysicalKey, PhysicalShortcut, PluginId};
+use gauntlet_common::model::{PhysicalKey, PhysicalShortcut, PluginId, PluginPermissionKind};
 use gauntlet_common::dirs::Dirs;
+use gauntlet_common::i18n::select_translation;
 use crate::model::ActionShortcutKey;
+use crate::plugins::data_db_migration::{open_data_db, DataDbRecovery};
 use crate::plugins::frecency::{FrecencyItemStats, FrecencyMetaParams};
 use crate::plugins::loader::PluginManifestActionShortcutKey;
 
-static MIGRATOR: Migrator = sqlx::migrate!("./db_migrations");
+// launches older than a year are removed
+const USAGE_STATISTICS_RETENTION_SECONDS: i64 = 365 * 24 * 60 * 60;
 
 #[derive(Clone)]
 pub struct DataDbRepository {
@@ -30,6 +31,8 @@ pub struct DbReadPlugin {
     pub uuid: String,
     pub name: String,
     pub description: String,
+    pub keyword: Option<String>,
+    pub interactive: bool,
     pub enabled: bool,
     #[sqlx(json)]
     pub code: DbCode,
@@ -43,6 +46,16 @@ pub struct DbReadPlugin {
     pub preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
 }
 
+// kept after plugin is removed without purging its data
+#[derive(sqlx::FromRow)]
+pub struct DbReadRemovedPluginData {
+    pub uuid: String,
+    #[sqlx(json)]
+    pub preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
+    #[sqlx(json)]
+    pub entrypoint_preferences_user_data: HashMap<String, HashMap<String, DbPluginPreferenceUserData>>,
+}
+
 #[derive(sqlx::FromRow)]
 pub struct DbReadPluginEntrypoint {
     pub id: String,
@@ -62,17 +75,108 @@ pub struct DbReadPluginEntrypoint {
     pub actions: Vec<DbPluginAction>,
     #[sqlx(json)]
     pub actions_user_data: Vec<DbPluginActionUserData>,
+    #[sqlx(json)]
+    pub arguments: Vec<DbPluginArgument>,
+    #[sqlx(json)]
+    pub translations: HashMap<String, DbPluginEntrypointTranslation>,
+    pub fallback: bool,
+    pub priority: i64,
+    pub hidden: bool,
+    #[sqlx(json)]
+    pub aliases: Vec<String>,
+    pub cache_view: bool,
+}
+
+impl DbReadPluginEntrypoint {
+    // shortcut set by user takes precedence over the one declared in plugin manifest
+    pub fn action_shortcuts(&self) -> anyhow::Result<HashMap<String, PhysicalShortcut>> {
+        self.actions.iter()
+            .map(|action| {
+                let shortcut = match self.action_user_shortcut(&action.id) {
+                    Some(shortcut) => shortcut,
+                    None => action.default_shortcut()?,
+                };
+
+                Ok((action.id.clone(), shortcut))
+            })
+            .collect()
+    }
+
+    pub fn action_user_shortcut(&self, action_id: &str) -> Option<PhysicalShortcut> {
+        self.actions_user_data.iter()
+            .find(|data| data.id == action_id)
+            .map(|data| {
+                PhysicalShortcut {
+                    physical_key: PhysicalKey::from_value(data.key.to_owned()),
+                    modifier_shift: data.modifier_shift,
+                    modifier_control: data.modifier_control,
+                    modifier_alt: data.modifier_alt,
+                    modifier_meta: data.modifier_meta,
+                }
+            })
+    }
+
+    // translation provided by plugin for current locale, if any
+    pub fn localized_name(&self) -> String {
+        select_translation(&self.translations)
+            .map(|translation| translation.name.clone())
+            .unwrap_or_else(|| self.name.clone())
+    }
+
+    pub fn localized_description(&self) -> String {
+        select_translation(&self.translations)
+            .and_then(|translation| translation.description.clone())
+            .unwrap_or_else(|| self.description.clone())
+    }
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct DbCode {
     pub js: HashMap<String, String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub style: DbPluginStyle,
+    // values can contain variables, resolved every time plugin runtime is started
+    #[serde(default)]
+    pub environment_variables: HashMap<String, String>,
+    #[serde(default)]
+    pub runtime: DbPluginRuntime,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DbPluginRuntime {
+    #[default]
+    Deno,
+    // experimental, component is stored as plugin asset
+    Wasm,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct DbPluginStyle {
+    #[serde(default)]
+    pub accent_color: Option<String>,
+    #[serde(default)]
+    pub muted_color: Option<String>,
+    #[serde(default)]
+    pub success_color: Option<String>,
+    #[serde(default)]
+    pub warning_color: Option<String>,
+    #[serde(default)]
+    pub danger_color: Option<String>,
+    #[serde(default)]
+    pub link_color: Option<String>,
+    #[serde(default)]
+    pub content_spacing: Option<f32>,
 }
 
 pub struct DbWritePlugin {
     pub id: String,
     pub name: String,
     pub description: String,
+    pub keyword: Option<String>,
+    pub interactive: bool,
     pub enabled: bool,
     pub code: DbCode,
     pub entrypoints: Vec<DbWritePluginEntrypoint>,
@@ -90,6 +194,11 @@ pub struct DbWritePluginEntrypoint {
     pub entrypoint_type: String,
     pub preferences: HashMap<String, DbPluginPreference>,
     pub actions: Vec<DbPluginAction>,
+    pub arguments: Vec<DbPluginArgument>,
+    pub fallback: bool,
+    pub priority: i64,
+    pub translations: HashMap<String, DbPluginEntrypointTranslation>,
+    pub cache_view: bool,
 }
 
 pub struct DbWritePluginAssetData {
@@ -128,6 +237,49 @@ pub struct DbPluginPermissions {
     pub clipboard: Vec<DbPluginClipboardPermissions>,
     #[serde(default)]
     pub main_search_bar: Vec<DbPluginMainSearchBarPermissions>,
+    #[serde(default)]
+    pub active_window: Vec<DbPluginActiveWindowPermissions>,
+    #[serde(default)]
+    pub secrets: DbPluginPermissionsSecrets,
+    #[serde(default)]
+    pub events: DbPluginPermissionsEvents,
+}
+
+impl DbPluginPermissions {
+    // permissions that user can revoke in settings, as kind and value
+    pub fn revocable(&self) -> Vec<(PluginPermissionKind, String)> {
+        let values = |kind: PluginPermissionKind, values: &[String]| {
+            values.iter()
+                .map(move |value| (kind, value.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        let clipboard = self.clipboard.iter()
+            .map(|permission| (PluginPermissionKind::Clipboard, db_clipboard_permission_to_str(permission).to_string()));
+
+        values(PluginPermissionKind::Network, &self.network)
+            .into_iter()
+            .chain(values(PluginPermissionKind::FilesystemRead, &self.filesystem.read))
+            .chain(values(PluginPermissionKind::FilesystemWrite, &self.filesystem.write))
+            .chain(values(PluginPermissionKind::ExecCommand, &self.exec.command))
+            .chain(values(PluginPermissionKind::ExecExecutable, &self.exec.executable))
+            .chain(clipboard)
+            .collect()
+    }
+
+    // permissions plugin runtime is started with
+    pub fn without_revoked(mut self, revoked: &HashSet<(PluginPermissionKind, String)>) -> Self {
+        let is_granted = |kind: PluginPermissionKind, value: &str| !revoked.contains(&(kind, value.to_string()));
+
+        self.network.retain(|value| is_granted(PluginPermissionKind::Network, value));
+        self.filesystem.read.retain(|value| is_granted(PluginPermissionKind::FilesystemRead, value));
+        self.filesystem.write.retain(|value| is_granted(PluginPermissionKind::FilesystemWrite, value));
+        self.exec.command.retain(|value| is_granted(PluginPermissionKind::ExecCommand, value));
+        self.exec.executable.retain(|value| is_granted(PluginPermissionKind::ExecExecutable, value));
+        self.clipboard.retain(|permission| is_granted(PluginPermissionKind::Clipboard, db_clipboard_permission_to_str(permission)));
+
+        self
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Default)]
@@ -138,6 +290,20 @@ pub struct DbPluginPermissionsFileSystem {
     pub write: Vec<String>,
 }
 
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct DbPluginPermissionsSecrets {
+    #[serde(default)]
+    pub read: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct DbPluginPermissionsEvents {
+    #[serde(default)]
+    pub publish: Vec<String>,
+    #[serde(default)]
+    pub subscribe: Vec<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Default)]
 pub struct DbPluginPermissionsExec {
     #[serde(default)]
@@ -162,6 +328,12 @@ pub enum DbPluginMainSearchBarPermissions {
     Read,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub enum DbPluginActiveWindowPermissions {
+    #[serde(rename = "read")]
+    Read,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "type")]
 pub enum DbPluginPreferenceUserData {
@@ -192,6 +364,10 @@ pub enum DbPluginPreferenceUserData {
     #[serde(rename = "list_of_enums")]
     ListOfEnums {
         value: Option<Vec<String>>,
+    },
+    #[serde(rename = "directory")]
+    Directory {
+        value: Option<String>,
     }
 }
 
@@ -203,6 +379,75 @@ pub struct DbPluginAction {
     pub kind: DbPluginActionShortcutKind
 }
 
+impl DbPluginAction {
+    pub fn default_shortcut(&self) -> anyhow::Result<PhysicalShortcut> {
+        let (physical_key, modifier_shift) = match ActionShortcutKey::from_value(&self.key) {
+            Some(key) => key.to_physical_key(),
+            None => {
+                return Err(anyhow!("unknown key: {}", &self.key))
+            },
+        };
+
+        let (modifier_control, modifier_alt, modifier_meta) = match self.kind {
+            DbPluginActionShortcutKind::Main => {
+                if cfg!(target_os = "macos") {
+                    (false, false, true)
+                } else {
+                    (true, false, false)
+                }
+            },
+            DbPluginActionShortcutKind::Alternative => {
+                (false, true, false)
+            },
+        };
+
+        Ok(PhysicalShortcut {
+            physical_key,
+            modifier_shift,
+            modifier_control,
+            modifier_alt,
+            modifier_meta,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbPluginEntrypointTranslation {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbPluginArgument {
+    pub id: String,
+    pub name: String,
+    pub optional: bool,
+    #[serde(default)]
+    pub hint: Option<String>,
+    pub argument_type: DbPluginArgumentType,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum DbPluginArgumentType {
+    #[serde(rename = "string")]
+    String {
+        #[serde(default)]
+        max_length: Option<usize>,
+    },
+    #[serde(rename = "number")]
+    Number {
+        #[serde(default)]
+        min: Option<f64>,
+        #[serde(default)]
+        max: Option<f64>,
+    },
+    #[serde(rename = "enum")]
+    Enum {
+        enum_values: Vec<DbPreferenceEnumValue>,
+    },
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DbPluginActionUserData {
     pub id: String,
@@ -213,6 +458,49 @@ pub struct DbPluginActionUserData {
     pub modifier_meta: bool
 }
 
+#[derive(sqlx::FromRow)]
+pub struct DbInlineView {
+    pub plugin_id: String,
+    pub plugin_name: String,
+    pub entrypoint_id: String,
+    pub entrypoint_name: String,
+    pub enabled: bool,
+}
+
+#[derive(sqlx::FromRow)]
+pub struct DbEntrypointReference {
+    pub plugin_id: String,
+    pub plugin_name: String,
+    pub entrypoint_id: String,
+    pub entrypoint_name: String,
+}
+
+#[derive(sqlx::FromRow)]
+pub struct DbMacro {
+    pub id: String,
+    pub name: String,
+    #[sqlx(json)]
+    pub steps: Vec<DbMacroStep>,
+}
+
+// plugin and entrypoint names are kept so that step can be shown after its plugin is removed
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum DbMacroStep {
+    #[serde(rename = "run_entrypoint")]
+    RunEntrypoint {
+        plugin_id: String,
+        plugin_name: String,
+        entrypoint_id: String,
+        entrypoint_name: String,
+        action_index: Option<usize>,
+    },
+    #[serde(rename = "delay")]
+    Delay {
+        millis: u64,
+    },
+}
+
 #[derive(sqlx::FromRow)]
 pub struct DbSettingsData {
     #[sqlx(json)]
@@ -247,6 +535,12 @@ pub enum DbPluginPreference {
     Number {
         name: Option<String>, // option for db backwards compatibility, in settings id will be shown
         default: Option<f64>,
+        #[serde(default)]
+        min: Option<f64>,
+        #[serde(default)]
+        max: Option<f64>,
+        #[serde(default)]
+        step: Option<f64>,
         description: String,
     },
     #[serde(rename = "string")]
@@ -286,6 +580,12 @@ pub enum DbPluginPreference {
         default: Option<Vec<String>>,
         enum_values: Vec<DbPreferenceEnumValue>,
         description: String,
+    },
+    #[serde(rename = "directory")]
+    Directory {
+        name: Option<String>,
+        default: Option<String>,
+        description: String,
     }
 }
 
@@ -317,32 +617,60 @@ pub struct DbPluginEntrypointFrecencyStats {
     pub num_accesses: i32,
 }
 
-impl DataDbRepository {
-    pub async fn new(dirs: Dirs) -> anyhow::Result<Self> {
-        let data_db_file = dirs.data_db_file()?;
+#[derive(sqlx::FromRow)]
+pub struct DbUsageEntrypointLaunches {
+    pub plugin_id: String,
+    pub plugin_name: String,
+    pub entrypoint_id: String,
+    pub entrypoint_name: String,
+    pub launches: i64,
+}
 
-        std::fs::create_dir_all(&data_db_file.parent().unwrap())
-            .context("Unable to create data directory")?;
+#[derive(sqlx::FromRow)]
+pub struct DbUsageEntrypointDay {
+    pub day: String,
+    pub plugin_id: String,
+    pub plugin_name: String,
+    pub entrypoint_id: String,
+    pub entrypoint_name: String,
+    pub launches: i64,
+}
 
-        let conn = SqliteConnectOptions::new()
-            .filename(data_db_file)
-            .create_if_missing(true);
+#[derive(sqlx::FromRow)]
+pub struct DbPluginPermissionGrant {
+    pub kind: String,
+    pub value: String,
+    // local date and time
+    pub granted_at: Option<String>,
+    pub revoked: bool,
+}
 
-        let pool = SqlitePool::connect_with(conn)
-            .await
-            .context("Unable to open database connection")?;
+#[derive(sqlx::FromRow)]
+pub struct DbUsageDay {
+    pub day: String,
+    pub launches: i64,
+    pub searches: i64,
+}
 
-        // TODO backup before migration? up to 5 backups?
-        MIGRATOR.run(&pool)
-            .await
-            .context("Unable apply database migration")?;
+impl DataDbRepository {
+    pub async fn new(dirs: Dirs) -> anyhow::Result<(Self, Option<DataDbRecovery>)> {
+        let (pool, recovery) = open_data_db(&dirs).await?;
 
         let db_repository = Self { pool };
 
         db_repository.apply_uuid_default_value().await?;
         db_repository.remove_legacy_bundled_plugins().await?;
 
-        Ok(db_repository)
+        Ok((db_repository, recovery))
+    }
+
+    // see open_data_db_read_only
+    pub async fn open_read_only(dirs: &Dirs) -> anyhow::Result<Option<(Self, usize)>> {
+        let result = open_data_db_read_only(dirs)
+            .await?
+            .map(|(pool, pending_migrations)| (Self { pool }, pending_migrations));
+
+        Ok(result)
     }
 
     async fn apply_uuid_default_value(&self) -> anyhow::Result<()> {
@@ -378,13 +706,33 @@ impl DataDbRepository {
     }
 
     async fn remove_legacy_bundled_plugins(&self) -> anyhow::Result<()> {
-        self.remove_plugin("builtin://applications").await?;
-        self.remove_plugin("builtin://calculator").await?;
-        self.remove_plugin("builtin://settings").await?;
+        self.remove_plugin("builtin://applications", false).await?;
+        self.remove_plugin("builtin://calculator", false).await?;
+        self.remove_plugin("builtin://settings", false).await?;
 
         Ok(())
     }
 
+    // consistent copy of the database which can be made while it is in use
+    pub async fn export_to(&self, path: &Path) -> anyhow::Result<()> {
+        let path = path.to_str()
+            .ok_or(anyhow!("Database export path is not valid unicode: {:?}", path))?;
+
+        // language=SQLite
+        sqlx::query("VACUUM INTO ?1")
+            .bind(path)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // waits for queries that are in progress, queries started after this fail.
+    // closing last connection checkpoints write-ahead log into database file
+    pub async fn close(&self) {
+        self.pool.close().await
+    }
+
     pub async fn list_plugins(&self) -> anyhow::Result<Vec<DbReadPlugin>> {
         // language=SQLite
         let plugins = sqlx::query_as::<_, DbReadPlugin>("SELECT * FROM plugin")
@@ -509,63 +857,9 @@ impl DataDbRepository {
     }
 
     pub async fn action_shortcuts(&self, plugin_id: &str, entrypoint_id: &str) -> anyhow::Result<HashMap<String, PhysicalShortcut>> {
-        let DbReadPluginEntrypoint { actions, actions_user_data, .. } = self.get_entrypoint_by_id(plugin_id, entrypoint_id)
-            .await?;
-
-        let actions_user_data: HashMap<_, _> = actions_user_data.into_iter()
-            .map(|data| (data.id, (data.key, data.modifier_shift, data.modifier_control, data.modifier_alt, data.modifier_meta)))
-            .collect();
-
-        let action_shortcuts = actions.into_iter()
-            .map(|action| {
-                let id = action.id;
-
-                let shortcut = match actions_user_data.get(&id) {
-                    None => {
-                        let (physical_key, modifier_shift) = match ActionShortcutKey::from_value(&action.key) {
-                            Some(key) => key.to_physical_key(),
-                            None => {
-                                return Err(anyhow!("unknown key: {}", &action.key))
-                            },
-                        };
-
-                        let (modifier_control, modifier_alt, modifier_meta) = match action.kind {
-                            DbPluginActionShortcutKind::Main => {
-                                if cfg!(target_os = "macos") {
-                                    (false, false, true)
-                                } else {
-                                    (true, false, false)
-                                }
-                            },
-                            DbPluginActionShortcutKind::Alternative => {
-                                (false, true, false)
-                            },
-                        };
-
-                        PhysicalShortcut {
-                            physical_key,
-                            modifier_shift,
-                            modifier_control,
-                            modifier_alt,
-                            modifier_meta,
-                        }
-                    }
-                    Some(&(ref key, modifier_shift, modifier_control, modifier_alt, modifier_meta)) => {
-                        PhysicalShortcut {
-                            physical_key: PhysicalKey::from_value(key.to_owned()),
-                            modifier_shift,
-                            modifier_control,
-                            modifier_alt,
-                            modifier_meta,
-                        }
-                    }
-                };
-
-                Ok((id, shortcut))
-            })
-            .collect::<Result<HashMap<_, _>, _>>()?;
-
-        Ok(action_shortcuts)
+        self.get_entrypoint_by_id(plugin_id, entrypoint_id)
+            .await?
+            .action_shortcuts()
     }
 
     pub async fn get_action_id_for_shortcut(
@@ -578,62 +872,25 @@ impl DataDbRepository {
         modifier_alt: bool,
         modifier_meta: bool
     ) -> anyhow::Result<Option<String>> {
-        // language=SQLite
-        let sql = r#"SELECT json_each.value ->> 'id' FROM plugin_entrypoint e, json_each(actions_user_data) WHERE e.plugin_id = ?1 AND e.id = ?2  AND json_each.value ->> 'key' = ?3 AND json_each.value ->> 'modifier_shift' = ?4 AND json_each.value ->> 'modifier_control' = ?5 AND json_each.value ->> 'modifier_alt' = ?6 AND json_each.value ->> 'modifier_meta' = ?6"#;
-
-        let action_id = sqlx::query_as::<_, (String, )>(sql)
-            .bind(plugin_id)
-            .bind(entrypoint_id)
-            .bind(key.to_value())
-            .bind(modifier_shift)
-            .bind(modifier_control)
-            .bind(modifier_alt)
-            .bind(modifier_meta)
-            .fetch_optional(&self.pool)
-            .await?
-            .map(|result| result.0);
-
-        match action_id {
-            Some(action_id) => Ok(Some(action_id)),
-            None => {
-                let kind = if cfg!(target_os = "macos") {
-                    match (modifier_control, modifier_alt, modifier_meta) {
-                        (false, false, true) => DbPluginActionShortcutKind::Main,
-                        (false, true, false) => DbPluginActionShortcutKind::Alternative,
-                        _ => return Ok(None)
-                    }
-                } else {
-                    match (modifier_control, modifier_alt, modifier_meta) {
-                        (true, false, false) => DbPluginActionShortcutKind::Main,
-                        (false, true, false) => DbPluginActionShortcutKind::Alternative,
-                        _ => return Ok(None)
-                    }
-                };
-
-                let kind = match kind {
-                    DbPluginActionShortcutKind::Main => "main".to_owned(),
-                    DbPluginActionShortcutKind::Alternative => "alternative".to_owned(),
-                };
-
-                // language=SQLite
-                let sql = r#"SELECT json_each.value ->> 'id' FROM plugin_entrypoint e, json_each(actions) WHERE e.plugin_id = ?1 AND e.id = ?2  AND json_each.value ->> 'key' = ?3 AND json_each.value ->> 'kind' = ?4"#;
+        let Some(entrypoint) = self.get_entrypoint_by_id_option(plugin_id, entrypoint_id).await? else {
+            return Ok(None);
+        };
 
-                let Some(logical_key) = ActionShortcutKey::from_physical_key(key, modifier_shift) else {
-                    return Ok(None);
-                };
+        let shortcut = PhysicalShortcut {
+            physical_key: key,
+            modifier_shift,
+            modifier_control,
+            modifier_alt,
+            modifier_meta,
+        };
 
-                let action_id = sqlx::query_as::<_, (String, )>(sql)
-                    .bind(plugin_id)
-                    .bind(entrypoint_id)
-                    .bind(logical_key.to_value())
-                    .bind(&kind)
-                    .fetch_optional(&self.pool)
-                    .await?
-                    .map(|result| result.0);
+        // matched against effective shortcuts, so that default shortcut doesn't trigger action if user changed it
+        let action_id = entrypoint.action_shortcuts()?
+            .into_iter()
+            .find(|(_, action_shortcut)| action_shortcut == &shortcut)
+            .map(|(action_id, _)| action_id);
 
-                Ok(action_id)
-            }
-        }
+        Ok(action_id)
     }
 
     pub async fn list_pending_plugins(&self) -> anyhow::Result<Vec<DbReadPendingPlugin>> {
@@ -731,41 +988,239 @@ impl DataDbRepository {
             .collect()
     }
 
-    pub async fn mark_entrypoint_frecency(&self, plugin_id: &str, entrypoint_id: &str) -> anyhow::Result<()> {
-        let mut tx = self.pool.begin().await?;
+    // order set by user comes first, the rest is ordered by priority declared by plugin
+    pub async fn get_inline_view_order(&self) -> anyhow::Result<Vec<DbInlineView>> {
+        // language=SQLite
+        let sql = r#"
+            SELECT e.plugin_id, p.name AS plugin_name, e.id AS entrypoint_id, e.name AS entrypoint_name, e.enabled
+                FROM plugin_entrypoint e
+                    JOIN plugin p ON p.id = e.plugin_id
+                    LEFT JOIN inline_view_order o ON o.plugin_id = e.plugin_id
+                WHERE e.type = 'inline-view'
+                ORDER BY o.position IS NULL, o.position, e.priority DESC, p.name
+        "#;
 
-        // TODO reset time after 5 half lives
-        //  https://github.com/camdencheek/fre/blob/6574ee7045061957de24855567e0abf05f2778d9/src/main.rs#L23
-        //  why? dunno
+        let inline_views = sqlx::query_as::<_, DbInlineView>(sql)
+            .fetch_all(&self.pool)
+            .await?;
 
-        #[derive(sqlx::FromRow)]
-        struct DbFrecencyMetaParams {
-            pub reference_time: f64,
-            pub half_life: f64,
-        }
+        Ok(inline_views)
+    }
+
+    pub async fn set_inline_view_order(&self, plugin_ids: Vec<String>) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
 
         // language=SQLite
-        let meta_params = sqlx::query_as::<_, DbFrecencyMetaParams>("SELECT reference_time, half_life FROM plugin_entrypoint_frecency_stats")
-            .fetch_optional(&mut *tx)
+        sqlx::query("DELETE FROM inline_view_order")
+            .execute(&mut *tx)
             .await?;
 
-        let meta_params = match meta_params {
-            None => FrecencyMetaParams::default(),
-            Some(meta_params) => FrecencyMetaParams {
-                reference_time: meta_params.reference_time,
-                half_life: meta_params.half_life,
-            }
-        };
+        for (position, plugin_id) in plugin_ids.into_iter().enumerate() {
+            // language=SQLite
+            sqlx::query("INSERT INTO inline_view_order (plugin_id, position) VALUES(?1, ?2)")
+                .bind(plugin_id)
+                .bind(position as i64)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
 
+    // entrypoints of removed plugins are removed by cascade, removed entrypoints of existing plugins are skipped by join
+    pub async fn get_favorites(&self) -> anyhow::Result<Vec<DbEntrypointReference>> {
         // language=SQLite
-        let stats = sqlx::query_as::<_, DbPluginEntrypointFrecencyStats>("SELECT plugin_id, entrypoint_id, reference_time, half_life, last_accessed, frecency, num_accesses FROM plugin_entrypoint_frecency_stats WHERE plugin_id = ?1 and entrypoint_id = ?2")
-            .bind(plugin_id)
-            .bind(entrypoint_id)
-            .fetch_optional(&mut *tx)
+        let sql = r#"
+            SELECT f.plugin_id, p.name AS plugin_name, f.entrypoint_id, e.name AS entrypoint_name
+                FROM plugin_entrypoint_favorite f
+                    JOIN plugin p ON p.id = f.plugin_id
+                    JOIN plugin_entrypoint e ON e.plugin_id = f.plugin_id AND e.id = f.entrypoint_id
+                ORDER BY f.position
+        "#;
+
+        let favorites = sqlx::query_as::<_, DbEntrypointReference>(sql)
+            .fetch_all(&self.pool)
             .await?;
 
-        let mut new_stats = match stats {
-            None => {
+        Ok(favorites)
+    }
+
+    pub async fn set_favorites(&self, entrypoints: Vec<(String, String)>) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        // language=SQLite
+        sqlx::query("DELETE FROM plugin_entrypoint_favorite")
+            .execute(&mut *tx)
+            .await?;
+
+        for (position, (plugin_id, entrypoint_id)) in entrypoints.into_iter().enumerate() {
+            // language=SQLite
+            sqlx::query("INSERT OR IGNORE INTO plugin_entrypoint_favorite (plugin_id, entrypoint_id, position) VALUES(?1, ?2, ?3)")
+                .bind(plugin_id)
+                .bind(entrypoint_id)
+                .bind(position as i64)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    // newly pinned entrypoint is placed at the end
+    pub async fn set_entrypoint_favorite(&self, plugin_id: &str, entrypoint_id: &str, favorite: bool) -> anyhow::Result<()> {
+        if favorite {
+            // language=SQLite
+            let sql = r#"
+                INSERT OR IGNORE INTO plugin_entrypoint_favorite (plugin_id, entrypoint_id, position)
+                    VALUES(?1, ?2, (SELECT COALESCE(MAX(position) + 1, 0) FROM plugin_entrypoint_favorite))
+            "#;
+
+            sqlx::query(sql)
+                .bind(plugin_id)
+                .bind(entrypoint_id)
+                .execute(&self.pool)
+                .await?;
+        } else {
+            // language=SQLite
+            sqlx::query("DELETE FROM plugin_entrypoint_favorite WHERE plugin_id = ?1 AND entrypoint_id = ?2")
+                .bind(plugin_id)
+                .bind(entrypoint_id)
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    // order set by user comes first, the rest is ordered by name
+    pub async fn get_fallback_order(&self) -> anyhow::Result<Vec<DbEntrypointReference>> {
+        // language=SQLite
+        let sql = r#"
+            SELECT e.plugin_id, p.name AS plugin_name, e.id AS entrypoint_id, e.name AS entrypoint_name
+                FROM plugin_entrypoint e
+                    JOIN plugin p ON p.id = e.plugin_id
+                    LEFT JOIN fallback_order o ON o.plugin_id = e.plugin_id AND o.entrypoint_id = e.id
+                WHERE e.fallback
+                ORDER BY o.position IS NULL, o.position, e.name, p.name
+        "#;
+
+        let fallbacks = sqlx::query_as::<_, DbEntrypointReference>(sql)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(fallbacks)
+    }
+
+    pub async fn set_fallback_order(&self, entrypoints: Vec<(String, String)>) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        // language=SQLite
+        sqlx::query("DELETE FROM fallback_order")
+            .execute(&mut *tx)
+            .await?;
+
+        for (position, (plugin_id, entrypoint_id)) in entrypoints.into_iter().enumerate() {
+            // language=SQLite
+            sqlx::query("INSERT OR IGNORE INTO fallback_order (plugin_id, entrypoint_id, position) VALUES(?1, ?2, ?3)")
+                .bind(plugin_id)
+                .bind(entrypoint_id)
+                .bind(position as i64)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn get_macros(&self) -> anyhow::Result<Vec<DbMacro>> {
+        // language=SQLite
+        let macros = sqlx::query_as::<_, DbMacro>("SELECT id, name, steps FROM macro ORDER BY position")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(macros)
+    }
+
+    pub async fn get_macro(&self, id: &str) -> anyhow::Result<Option<DbMacro>> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, DbMacro>("SELECT id, name, steps FROM macro WHERE id = ?1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    // new macro is placed at the end
+    pub async fn save_macro(&self, id: &str, name: &str, steps: Vec<DbMacroStep>) -> anyhow::Result<()> {
+        // language=SQLite
+        let sql = r#"
+            INSERT INTO macro (id, name, steps, position)
+                VALUES(?1, ?2, ?3, (SELECT COALESCE(MAX(position) + 1, 0) FROM macro))
+                ON CONFLICT (id) DO UPDATE SET name = ?2, steps = ?3
+        "#;
+
+        sqlx::query(sql)
+            .bind(id)
+            .bind(name)
+            .bind(Json(steps))
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_macro(&self, id: &str) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("DELETE FROM macro WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn mark_entrypoint_frecency(&self, plugin_id: &str, entrypoint_id: &str) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        // TODO reset time after 5 half lives
+        //  https://github.com/camdencheek/fre/blob/6574ee7045061957de24855567e0abf05f2778d9/src/main.rs#L23
+        //  why? dunno
+
+        #[derive(sqlx::FromRow)]
+        struct DbFrecencyMetaParams {
+            pub reference_time: f64,
+            pub half_life: f64,
+        }
+
+        // language=SQLite
+        let meta_params = sqlx::query_as::<_, DbFrecencyMetaParams>("SELECT reference_time, half_life FROM plugin_entrypoint_frecency_stats")
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        let meta_params = match meta_params {
+            None => FrecencyMetaParams::default(),
+            Some(meta_params) => FrecencyMetaParams {
+                reference_time: meta_params.reference_time,
+                half_life: meta_params.half_life,
+            }
+        };
+
+        // language=SQLite
+        let stats = sqlx::query_as::<_, DbPluginEntrypointFrecencyStats>("SELECT plugin_id, entrypoint_id, reference_time, half_life, last_accessed, frecency, num_accesses FROM plugin_entrypoint_frecency_stats WHERE plugin_id = ?1 and entrypoint_id = ?2")
+            .bind(plugin_id)
+            .bind(entrypoint_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        let mut new_stats = match stats {
+            None => {
                 FrecencyItemStats::new(meta_params.reference_time, meta_params.half_life)
             }
             Some(stats) => {
@@ -803,6 +1258,17 @@ impl DataDbRepository {
         Ok(())
     }
 
+    // last_accessed is relative to reference_time
+    pub async fn recently_used_entrypoints(&self, limit: usize) -> anyhow::Result<Vec<(String, String)>> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, (String, String)>("SELECT plugin_id, entrypoint_id FROM plugin_entrypoint_frecency_stats WHERE num_accesses > 0 ORDER BY reference_time + last_accessed DESC LIMIT ?1")
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
     pub async fn get_frecency_for_plugin(&self, plugin_id: &str) -> anyhow::Result<HashMap<String, f64>> {
         // language=SQLite
         let result = sqlx::query_as::<_, (String, f64)>("SELECT entrypoint_id, frecency FROM plugin_entrypoint_frecency_stats WHERE plugin_id = ?1")
@@ -815,6 +1281,162 @@ impl DataDbRepository {
         Ok(result)
     }
 
+    pub async fn record_entrypoint_launch(&self, plugin_id: &str, entrypoint_id: &str) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        // language=SQLite
+        sqlx::query("INSERT INTO usage_entrypoint_launch (plugin_id, entrypoint_id, launched_at) VALUES(?1, ?2, CAST(strftime('%s', 'now') AS INTEGER))")
+            .bind(plugin_id)
+            .bind(entrypoint_id)
+            .execute(&mut *tx)
+            .await?;
+
+        // language=SQLite
+        sqlx::query("DELETE FROM usage_entrypoint_launch WHERE launched_at < CAST(strftime('%s', 'now') AS INTEGER) - ?1")
+            .bind(USAGE_STATISTICS_RETENTION_SECONDS)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    // only daily totals are kept, individual searches are not stored
+    pub async fn record_search_latency(&self, latency_micros: i64) -> anyhow::Result<()> {
+        // language=SQLite
+        let sql = r#"
+            INSERT INTO usage_search_daily (day, search_count, total_latency_micros)
+                VALUES(date('now', 'localtime'), 1, ?1)
+                ON CONFLICT (day) DO UPDATE SET search_count = search_count + 1, total_latency_micros = total_latency_micros + excluded.total_latency_micros
+        "#;
+
+        sqlx::query(sql)
+            .bind(latency_micros)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // entrypoints which are not in database anymore, like removed plugins or generated entrypoints, are shown by their id
+    pub async fn usage_entrypoint_launches(&self) -> anyhow::Result<Vec<DbUsageEntrypointLaunches>> {
+        // language=SQLite
+        let sql = r#"
+            SELECT l.plugin_id, COALESCE(p.name, l.plugin_id) AS plugin_name, l.entrypoint_id, COALESCE(e.name, l.entrypoint_id) AS entrypoint_name, COUNT(*) AS launches
+                FROM usage_entrypoint_launch l
+                LEFT JOIN plugin p ON p.id = l.plugin_id
+                LEFT JOIN plugin_entrypoint e ON e.id = l.entrypoint_id AND e.plugin_id = l.plugin_id
+                GROUP BY l.plugin_id, l.entrypoint_id
+                ORDER BY launches DESC
+        "#;
+
+        let result = sqlx::query_as::<_, DbUsageEntrypointLaunches>(sql)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    // one row per day, including days without any usage, oldest first
+    pub async fn usage_daily(&self, days: u32) -> anyhow::Result<Vec<DbUsageDay>> {
+        // language=SQLite
+        let sql = r#"
+            WITH RECURSIVE days(day, n) AS (
+                SELECT date('now', 'localtime'), 1
+                UNION ALL
+                SELECT date(day, '-1 day'), n + 1 FROM days WHERE n < ?1
+            )
+            SELECT
+                days.day,
+                (SELECT COUNT(*) FROM usage_entrypoint_launch WHERE date(launched_at, 'unixepoch', 'localtime') = days.day) AS launches,
+                COALESCE((SELECT search_count FROM usage_search_daily WHERE usage_search_daily.day = days.day), 0) AS searches
+            FROM days
+            ORDER BY days.day
+        "#;
+
+        let result = sqlx::query_as::<_, DbUsageDay>(sql)
+            .bind(days)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    // returns number of searches and their total latency
+    pub async fn usage_search_totals(&self) -> anyhow::Result<(i64, i64)> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, (i64, i64)>("SELECT COALESCE(SUM(search_count), 0), COALESCE(SUM(total_latency_micros), 0) FROM usage_search_daily")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn usage_entrypoint_launches_per_day(&self) -> anyhow::Result<Vec<DbUsageEntrypointDay>> {
+        // language=SQLite
+        let sql = r#"
+            SELECT date(l.launched_at, 'unixepoch', 'localtime') AS day, l.plugin_id, COALESCE(p.name, l.plugin_id) AS plugin_name, l.entrypoint_id, COALESCE(e.name, l.entrypoint_id) AS entrypoint_name, COUNT(*) AS launches
+                FROM usage_entrypoint_launch l
+                LEFT JOIN plugin p ON p.id = l.plugin_id
+                LEFT JOIN plugin_entrypoint e ON e.id = l.entrypoint_id AND e.plugin_id = l.plugin_id
+                GROUP BY day, l.plugin_id, l.entrypoint_id
+                ORDER BY day, launches DESC
+        "#;
+
+        let result = sqlx::query_as::<_, DbUsageEntrypointDay>(sql)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_view_state(&self, plugin_id: &str, entrypoint_id: &str, key: &str) -> anyhow::Result<Option<String>> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, (String, )>("SELECT value FROM plugin_entrypoint_view_state WHERE plugin_id = ?1 AND entrypoint_id = ?2 AND key = ?3")
+            .bind(plugin_id)
+            .bind(entrypoint_id)
+            .bind(key)
+            .fetch_optional(&self.pool)
+            .await?
+            .map(|(value, )| value);
+
+        Ok(result)
+    }
+
+    // none removes the value
+    pub async fn set_view_state(&self, plugin_id: &str, entrypoint_id: &str, key: &str, value: Option<&str>) -> anyhow::Result<()> {
+        match value {
+            None => {
+                // language=SQLite
+                sqlx::query("DELETE FROM plugin_entrypoint_view_state WHERE plugin_id = ?1 AND entrypoint_id = ?2 AND key = ?3")
+                    .bind(plugin_id)
+                    .bind(entrypoint_id)
+                    .bind(key)
+                    .execute(&self.pool)
+                    .await?;
+            }
+            Some(value) => {
+                // language=SQLite
+                let sql = r#"
+                    INSERT INTO plugin_entrypoint_view_state (plugin_id, entrypoint_id, key, value)
+                        VALUES(?1, ?2, ?3, ?4)
+                        ON CONFLICT (plugin_id, entrypoint_id, key) DO UPDATE SET value = ?4
+                "#;
+
+                sqlx::query(sql)
+                    .bind(plugin_id)
+                    .bind(entrypoint_id)
+                    .bind(key)
+                    .bind(value)
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn set_plugin_enabled(&self, plugin_id: &str, enabled: bool) -> anyhow::Result<()> {
         // language=SQLite
         sqlx::query("UPDATE plugin SET enabled = ?1 WHERE id = ?2")
@@ -826,6 +1448,54 @@ impl DataDbRepository {
         Ok(())
     }
 
+    // permissions of plugins installed before grants were recorded have no rows until they are revoked or plugin is updated
+    pub async fn get_permission_grants(&self, plugin_id: &str) -> anyhow::Result<Vec<DbPluginPermissionGrant>> {
+        // language=SQLite
+        let sql = r#"
+            SELECT kind, value, strftime('%Y-%m-%d %H:%M', granted_at, 'unixepoch', 'localtime') AS granted_at, revoked
+                FROM plugin_permission_grant
+                WHERE plugin_id = ?1
+        "#;
+
+        let result = sqlx::query_as::<_, DbPluginPermissionGrant>(sql)
+            .bind(plugin_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_revoked_permissions(&self, plugin_id: &str) -> anyhow::Result<HashSet<(PluginPermissionKind, String)>> {
+        let result = self.get_permission_grants(plugin_id)
+            .await?
+            .into_iter()
+            .filter(|grant| grant.revoked)
+            .map(|grant| (db_permission_kind_from_str(&grant.kind), grant.value))
+            .collect();
+
+        Ok(result)
+    }
+
+    pub async fn set_permission_revoked(&self, plugin_id: &str, kind: PluginPermissionKind, value: &str, revoked: bool) -> anyhow::Result<()> {
+        // language=SQLite
+        let sql = r#"
+            INSERT INTO plugin_permission_grant (plugin_id, kind, value, granted_at, revoked)
+                VALUES(?1, ?2, ?3, NULL, ?4)
+                    ON CONFLICT (plugin_id, kind, value)
+                        DO UPDATE SET revoked = ?4
+        "#;
+
+        sqlx::query(sql)
+            .bind(plugin_id)
+            .bind(db_permission_kind_to_str(kind))
+            .bind(value)
+            .bind(revoked)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn set_plugin_entrypoint_enabled(&self, plugin_id: &str, entrypoint_id: &str, enabled: bool) -> anyhow::Result<()> {
         // language=SQLite
         sqlx::query("UPDATE plugin_entrypoint SET enabled = ?1 WHERE id = ?2 AND plugin_id = ?3")
@@ -838,6 +1508,99 @@ impl DataDbRepository {
         Ok(())
     }
 
+    pub async fn set_plugin_entrypoint_hidden(&self, plugin_id: &str, entrypoint_id: &str, hidden: bool) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("UPDATE plugin_entrypoint SET hidden = ?1 WHERE id = ?2 AND plugin_id = ?3")
+            .bind(hidden)
+            .bind(entrypoint_id)
+            .bind(plugin_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_plugin_entrypoint_aliases(&self, plugin_id: &str, entrypoint_id: &str, aliases: Vec<String>) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("UPDATE plugin_entrypoint SET aliases = ?1 WHERE id = ?2 AND plugin_id = ?3")
+            .bind(Json(aliases))
+            .bind(entrypoint_id)
+            .bind(plugin_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // generated entrypoints are not stored in plugin_entrypoint, so their aliases are kept separately
+    pub async fn get_generated_entrypoint_aliases(&self, plugin_id: &str) -> anyhow::Result<HashMap<String, Vec<String>>> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, (String, Json<Vec<String>>)>("SELECT entrypoint_id, aliases FROM plugin_generated_entrypoint_alias WHERE plugin_id = ?1")
+            .bind(plugin_id)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|(entrypoint_id, aliases)| (entrypoint_id, aliases.0))
+            .collect();
+
+        Ok(result)
+    }
+
+    pub async fn set_generated_entrypoint_aliases(&self, plugin_id: &str, entrypoint_id: &str, aliases: Vec<String>) -> anyhow::Result<()> {
+        if aliases.is_empty() {
+            // language=SQLite
+            sqlx::query("DELETE FROM plugin_generated_entrypoint_alias WHERE plugin_id = ?1 AND entrypoint_id = ?2")
+                .bind(plugin_id)
+                .bind(entrypoint_id)
+                .execute(&self.pool)
+                .await?;
+        } else {
+            // language=SQLite
+            sqlx::query("INSERT OR REPLACE INTO plugin_generated_entrypoint_alias (plugin_id, entrypoint_id, aliases) VALUES(?1, ?2, ?3)")
+                .bind(plugin_id)
+                .bind(entrypoint_id)
+                .bind(Json(aliases))
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    // none resets shortcut to the one declared in plugin manifest
+    pub async fn set_action_shortcut(&self, plugin_id: &str, entrypoint_id: &str, action_id: &str, shortcut: Option<PhysicalShortcut>) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let mut user_data = self.get_entrypoint_by_id_with_executor(plugin_id, entrypoint_id, &mut *tx)
+            .await?
+            .actions_user_data;
+
+        user_data.retain(|data| data.id != action_id);
+
+        if let Some(shortcut) = shortcut {
+            user_data.push(DbPluginActionUserData {
+                id: action_id.to_string(),
+                key: shortcut.physical_key.to_value(),
+                modifier_shift: shortcut.modifier_shift,
+                modifier_control: shortcut.modifier_control,
+                modifier_alt: shortcut.modifier_alt,
+                modifier_meta: shortcut.modifier_meta,
+            });
+        }
+
+        // language=SQLite
+        sqlx::query("UPDATE plugin_entrypoint SET actions_user_data = ?1 WHERE id = ?2 AND plugin_id = ?3")
+            .bind(Json(user_data))
+            .bind(entrypoint_id)
+            .bind(plugin_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
     pub async fn set_global_shortcut(&self, shortcut: Option<PhysicalShortcut>, error: Option<String>) -> anyhow::Result<()> {
         // language=SQLite
         let sql = r#"
@@ -915,6 +1678,36 @@ impl DataDbRepository {
         }
     }
 
+    pub async fn get_last_seen_version(&self) -> anyhow::Result<Option<u16>> {
+        // language=SQLite
+        let version = sqlx::query_as::<_, (i64,)>("SELECT last_seen_version FROM app_version")
+            .fetch_optional(&self.pool)
+            .await?
+            .map(|(version,)| version as u16);
+
+        Ok(version)
+    }
+
+    pub async fn set_last_seen_version(&self, version: u16) -> anyhow::Result<()> {
+        // language=SQLite
+        let sql = r#"
+            INSERT INTO app_version (id, last_seen_version)
+                VALUES(?1, ?2)
+                    ON CONFLICT (id)
+                        DO UPDATE SET last_seen_version = ?2
+        "#;
+
+        let id = "app_version"; // only one row in the table
+
+        sqlx::query(sql)
+            .bind(id)
+            .bind(version as i64)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn set_preference_value(&self, plugin_id: String, entrypoint_id: Option<String>, preference_id: String, value: DbPluginPreferenceUserData) -> anyhow::Result<()> {
         let mut tx = self.pool.begin().await?;
 
@@ -965,28 +1758,99 @@ impl DataDbRepository {
         Ok(())
     }
 
-    pub async fn remove_plugin(&self, plugin_id: &str) -> anyhow::Result<()> {
+    // entrypoints with their shortcuts, assets, favorites, fallback and inline view order are removed by cascade
+    pub async fn remove_plugin(&self, plugin_id: &str, keep_user_data: bool) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        if keep_user_data {
+            if let Some(plugin) = self.get_plugin_by_id_option_with_executor(plugin_id, &mut *tx).await? {
+                let entrypoint_preferences_user_data = self.get_entrypoints_by_plugin_id_with_executor(plugin_id, &mut *tx).await?
+                    .into_iter()
+                    .map(|entrypoint| (entrypoint.id, entrypoint.preferences_user_data))
+                    .collect::<HashMap<_, _>>();
+
+                // language=SQLite
+                sqlx::query("INSERT OR REPLACE INTO removed_plugin_data (id, uuid, preferences_user_data, entrypoint_preferences_user_data) VALUES(?1, ?2, ?3, ?4)")
+                    .bind(plugin_id)
+                    .bind(plugin.uuid)
+                    .bind(Json(plugin.preferences_user_data))
+                    .bind(Json(entrypoint_preferences_user_data))
+                    .execute(&mut *tx)
+                    .await?;
+            }
+        } else {
+            // language=SQLite
+            sqlx::query("DELETE FROM removed_plugin_data WHERE id = ?1")
+                .bind(plugin_id)
+                .execute(&mut *tx)
+                .await?;
+
+            // language=SQLite
+            sqlx::query("DELETE FROM usage_entrypoint_launch WHERE plugin_id = ?1")
+                .bind(plugin_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        // language=SQLite
+        sqlx::query("DELETE FROM plugin_entrypoint_frecency_stats WHERE plugin_id = ?1")
+            .bind(plugin_id)
+            .execute(&mut *tx)
+            .await?;
+
         // language=SQLite
         sqlx::query("DELETE FROM plugin WHERE id = ?1")
             .bind(plugin_id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
+
+        tx.commit().await?;
+
         Ok(())
     }
 
+    async fn take_removed_plugin_data<'a, E>(&self, plugin_id: &str, executor: E) -> anyhow::Result<Option<DbReadRemovedPluginData>>
+        where
+            E: Executor<'a, Database=Sqlite>,
+    {
+        // language=SQLite
+        let result = sqlx::query_as::<_, DbReadRemovedPluginData>("DELETE FROM removed_plugin_data WHERE id = ?1 RETURNING uuid, preferences_user_data, entrypoint_preferences_user_data")
+            .bind(plugin_id)
+            .fetch_optional(executor)
+            .await?;
+
+        Ok(result)
+    }
+
     pub async fn save_plugin(&self, new_plugin: DbWritePlugin) -> anyhow::Result<()> {
         let mut tx = self.pool.begin().await?;
 
-        let (uuid, enabled, preferences_user_data) = self.get_plugin_by_id_option_with_executor(&new_plugin.id, &mut *tx).await?
-            .map(|plugin| (plugin.uuid, plugin.enabled, plugin.preferences_user_data))
-            .unwrap_or((Uuid::new_v4().to_string(), new_plugin.enabled, HashMap::new()));
+        let granted_permissions = new_plugin.permissions.revocable();
+
+        // data kept when plugin was removed is restored when it is installed again,
+        // uuid is the same so local storage and data directory are found again
+        let mut removed_entrypoint_preferences_user_data = HashMap::new();
+
+        let (uuid, enabled, preferences_user_data) = match self.get_plugin_by_id_option_with_executor(&new_plugin.id, &mut *tx).await? {
+            Some(plugin) => (plugin.uuid, plugin.enabled, plugin.preferences_user_data),
+            None => {
+                match self.take_removed_plugin_data(&new_plugin.id, &mut *tx).await? {
+                    Some(removed) => {
+                        removed_entrypoint_preferences_user_data = removed.entrypoint_preferences_user_data;
+
+                        (removed.uuid, new_plugin.enabled, removed.preferences_user_data)
+                    }
+                    None => (Uuid::new_v4().to_string(), new_plugin.enabled, HashMap::new())
+                }
+            }
+        };
 
         // language=SQLite
         let sql = r#"
-            INSERT INTO plugin (id, name, enabled, code, permissions, preferences, preferences_user_data, description, type, uuid)
-                VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            INSERT INTO plugin (id, name, enabled, code, permissions, preferences, preferences_user_data, description, type, uuid, keyword, interactive)
+                VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
                     ON CONFLICT (id)
-                        DO UPDATE SET name = ?2, enabled = ?3, code = ?4, permissions = ?5, preferences = ?6, preferences_user_data = ?7, description = ?8, type = ?9, uuid = ?10
+                        DO UPDATE SET name = ?2, enabled = ?3, code = ?4, permissions = ?5, preferences = ?6, preferences_user_data = ?7, description = ?8, type = ?9, uuid = ?10, keyword = ?11, interactive = ?12
         "#;
 
         sqlx::query(sql)
@@ -1000,6 +1864,8 @@ impl DataDbRepository {
             .bind(new_plugin.description)
             .bind(new_plugin.plugin_type)
             .bind(uuid)
+            .bind(new_plugin.keyword)
+            .bind(new_plugin.interactive)
             .execute(&mut *tx)
             .await?;
 
@@ -1011,12 +1877,17 @@ impl DataDbRepository {
         for new_entrypoint in new_plugin.entrypoints {
             old_entrypoint_ids.remove(&new_entrypoint.id);
 
-            let (uuid, preferences_user_data, actions_user_data, enabled) = self.get_entrypoint_by_id_option_with_executor(&new_plugin.id, &new_entrypoint.id, &mut *tx).await?
-                .map(|entrypoint| (entrypoint.uuid, entrypoint.preferences_user_data, entrypoint.actions_user_data, entrypoint.enabled))
-                .unwrap_or((Uuid::new_v4().to_string(), HashMap::new(), vec![], true));
+            let (uuid, preferences_user_data, actions_user_data, enabled, hidden, aliases) = self.get_entrypoint_by_id_option_with_executor(&new_plugin.id, &new_entrypoint.id, &mut *tx).await?
+                .map(|entrypoint| (entrypoint.uuid, entrypoint.preferences_user_data, entrypoint.actions_user_data, entrypoint.enabled, entrypoint.hidden, entrypoint.aliases))
+                .unwrap_or_else(|| {
+                    let preferences_user_data = removed_entrypoint_preferences_user_data.remove(&new_entrypoint.id)
+                        .unwrap_or_default();
+
+                    (Uuid::new_v4().to_string(), preferences_user_data, vec![], true, false, vec![])
+                });
 
             // language=SQLite
-            sqlx::query("INSERT OR REPLACE INTO plugin_entrypoint (id, plugin_id, name, enabled, type, preferences, preferences_user_data, description, actions, actions_user_data, icon_path, uuid) VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)")
+            sqlx::query("INSERT OR REPLACE INTO plugin_entrypoint (id, plugin_id, name, enabled, type, preferences, preferences_user_data, description, actions, actions_user_data, icon_path, uuid, arguments, fallback, priority, hidden, translations, aliases, cache_view) VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)")
                 .bind(&new_entrypoint.id)
                 .bind(&new_plugin.id)
                 .bind(new_entrypoint.name)
@@ -1029,6 +1900,13 @@ impl DataDbRepository {
                 .bind(Json(actions_user_data))
                 .bind(new_entrypoint.icon_path)
                 .bind(uuid)
+                .bind(Json(new_entrypoint.arguments))
+                .bind(new_entrypoint.fallback)
+                .bind(new_entrypoint.priority)
+                .bind(hidden)
+                .bind(Json(new_entrypoint.translations))
+                .bind(Json(aliases))
+                .bind(new_entrypoint.cache_view)
                 .execute(&mut *tx)
                 .await?;
         }
@@ -1065,6 +1943,40 @@ impl DataDbRepository {
                 .await?;
         }
 
+        // language=SQLite
+        let mut old_permission_grants = sqlx::query_as::<_, (String, String)>("SELECT kind, value FROM plugin_permission_grant WHERE plugin_id = ?1")
+            .bind(&new_plugin.id)
+            .fetch_all(&mut *tx)
+            .await?
+            .into_iter()
+            .collect::<HashSet<_>>();
+
+        // time of grant and revoked state are kept when plugin is updated
+        for (kind, value) in granted_permissions {
+            let kind = db_permission_kind_to_str(kind);
+
+            old_permission_grants.remove(&(kind.to_string(), value.clone()));
+
+            // language=SQLite
+            sqlx::query("INSERT OR IGNORE INTO plugin_permission_grant (plugin_id, kind, value, granted_at, revoked) VALUES(?1, ?2, ?3, CAST(strftime('%s', 'now') AS INTEGER), FALSE)")
+                .bind(&new_plugin.id)
+                .bind(kind)
+                .bind(&value)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        // permission which is added back to manifest later is granted again
+        for (kind, value) in old_permission_grants {
+            // language=SQLite
+            sqlx::query("DELETE FROM plugin_permission_grant WHERE plugin_id = ?1 AND kind = ?2 AND value = ?3")
+                .bind(&new_plugin.id)
+                .bind(&kind)
+                .bind(&value)
+                .execute(&mut *tx)
+                .await?;
+        }
+
         tx.commit().await?;
 
         Ok(())
@@ -1108,3 +2020,34 @@ pub fn db_plugin_type_from_str(value: &str) -> DbPluginType {
         _ => panic!("illegal plugin_type: {}", value)
     }
 }
+
+pub fn db_permission_kind_to_str(value: PluginPermissionKind) -> &'static str {
+    match value {
+        PluginPermissionKind::Network => "network",
+        PluginPermissionKind::FilesystemRead => "filesystem-read",
+        PluginPermissionKind::FilesystemWrite => "filesystem-write",
+        PluginPermissionKind::ExecCommand => "exec-command",
+        PluginPermissionKind::ExecExecutable => "exec-executable",
+        PluginPermissionKind::Clipboard => "clipboard",
+    }
+}
+
+pub fn db_permission_kind_from_str(value: &str) -> PluginPermissionKind {
+    match value {
+        "network" => PluginPermissionKind::Network,
+        "filesystem-read" => PluginPermissionKind::FilesystemRead,
+        "filesystem-write" => PluginPermissionKind::FilesystemWrite,
+        "exec-command" => PluginPermissionKind::ExecCommand,
+        "exec-executable" => PluginPermissionKind::ExecExecutable,
+        "clipboard" => PluginPermissionKind::Clipboard,
+        _ => panic!("illegal permission kind: {}", value)
+    }
+}
+
+fn db_clipboard_permission_to_str(value: &DbPluginClipboardPermissions) -> &'static str {
+    match value {
+        DbPluginClipboardPermissions::Read => "read",
+        DbPluginClipboardPermissions::Write => "write",
+        DbPluginClipboardPermissions::Clear => "clear",
+    }
+}
@@ -0,0 +1,317 @@
+use std::path::Path;
+
+use anyhow::Context;
+
+use gauntlet_common::dirs::Dirs;
+use gauntlet_plugin_runtime::{validate_permissions, JsPluginPermissions, JsPluginPermissionsExec, JsPluginPermissionsFileSystem, JsPluginPermissionsMainSearchBar};
+
+use crate::control;
+use crate::plugins::config_reader::read_config_file;
+use crate::plugins::data_db_repository::{DataDbRepository, DbPluginMainSearchBarPermissions, DbPluginRuntime, DbReadPlugin};
+use crate::plugins::environment_variables::validate_environment_variables;
+
+enum CheckStatus {
+    Ok,
+    Warning,
+    Error,
+}
+
+struct CheckResult {
+    name: String,
+    status: CheckStatus,
+    message: String,
+    // what user can do about it
+    fix: Option<String>,
+}
+
+impl CheckResult {
+    fn ok(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { name: name.into(), status: CheckStatus::Ok, message: message.into(), fix: None }
+    }
+
+    fn warning(name: impl Into<String>, message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self { name: name.into(), status: CheckStatus::Warning, message: message.into(), fix: Some(fix.into()) }
+    }
+
+    fn error(name: impl Into<String>, message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self { name: name.into(), status: CheckStatus::Error, message: message.into(), fix: Some(fix.into()) }
+    }
+}
+
+// checks are done without server, so they also work when it fails to start.
+// prints report and returns false if any check failed, warnings are not considered failures
+pub fn run_doctor() -> bool {
+    let results = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start doctor tokio runtime")
+        .block_on(run_checks());
+
+    let mut errors = 0;
+    let mut warnings = 0;
+
+    for result in &results {
+        let label = match result.status {
+            CheckStatus::Ok => "[ok]     ",
+            CheckStatus::Warning => {
+                warnings += 1;
+                "[warning]"
+            }
+            CheckStatus::Error => {
+                errors += 1;
+                "[error]  "
+            }
+        };
+
+        println!("{} {}: {}", label, result.name, result.message);
+
+        if let Some(fix) = &result.fix {
+            println!("          fix: {}", fix);
+        }
+    }
+
+    println!();
+
+    if errors == 0 && warnings == 0 {
+        println!("No problems found");
+    } else {
+        println!("{} error(s), {} warning(s)", errors, warnings);
+    }
+
+    errors == 0
+}
+
+async fn run_checks() -> Vec<CheckResult> {
+    let dirs = Dirs::new();
+
+    let mut results = vec![];
+
+    results.push(check_running());
+    results.push(check_directories(&dirs));
+    results.push(check_config(&dirs));
+
+    #[cfg(target_os = "linux")]
+    results.push(check_display_server());
+
+    match DataDbRepository::open_read_only(&dirs).await {
+        Ok(None) => {
+            results.push(CheckResult::ok("Database", "not created yet, it is created on first start"));
+        }
+        Ok(Some((_, pending_migrations))) if pending_migrations > 0 => {
+            results.push(CheckResult::warning(
+                "Database",
+                format!("created by older version of Gauntlet, {} migration(s) are pending, remaining checks are skipped", pending_migrations),
+                "start Gauntlet once, database is backed up and migrated on start",
+            ));
+        }
+        Ok(Some((repository, _))) => {
+            results.push(CheckResult::ok("Database", "integrity check passed"));
+            results.push(check_global_shortcut(&repository).await);
+            results.extend(check_plugins(&dirs, &repository).await);
+        }
+        Err(err) => {
+            let backups_dir = dirs.data_db_backups_dir()
+                .map(|dir| dir.display().to_string())
+                .unwrap_or_default();
+
+            results.push(CheckResult::error(
+                "Database",
+                format!("{:#}", err),
+                format!("restart Gauntlet, broken database is moved aside and the latest working backup from {} is restored", backups_dir),
+            ));
+        }
+    }
+
+    results
+}
+
+fn check_running() -> CheckResult {
+    if control::is_running() {
+        CheckResult::ok("Server", "running")
+    } else {
+        CheckResult::warning("Server", "not running", "start it with `gauntlet --minimized` or install service which starts it on login with `gauntlet service install`")
+    }
+}
+
+fn check_directories(dirs: &Dirs) -> CheckResult {
+    let data_dir = match dirs.data_dir() {
+        Ok(data_dir) => data_dir,
+        Err(err) => return CheckResult::error("Directories", format!("{:#}", err), "set GAUNTLET_HOME environment variable to directory Gauntlet can use"),
+    };
+
+    for dir in [data_dir, dirs.cache_dir(), dirs.state_dir()] {
+        if let Err(err) = check_writable(&dir) {
+            return CheckResult::error(
+                "Directories",
+                format!("{:#}", err),
+                format!("make sure {} is owned by your user and is writable, or pass --data-dir to use another location", dir.display()),
+            )
+        }
+    }
+
+    CheckResult::ok("Directories", "data, cache and state directories are writable")
+}
+
+// directory is created if missing, the same as server would do it
+fn check_writable(dir: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("unable to create directory {}", dir.display()))?;
+
+    let probe_file = dir.join(".gauntlet-doctor");
+
+    std::fs::write(&probe_file, b"")
+        .with_context(|| format!("unable to write into directory {}", dir.display()))?;
+
+    let _ = std::fs::remove_file(&probe_file);
+
+    Ok(())
+}
+
+fn check_config(dirs: &Dirs) -> CheckResult {
+    let config_file = dirs.config_file();
+
+    if !config_file.exists() {
+        return CheckResult::ok("Config", format!("{} doesn't exist, default configuration is used", config_file.display()))
+    }
+
+    match read_config_file(dirs) {
+        Ok(_) => CheckResult::ok("Config", format!("{} is valid", config_file.display())),
+        Err(err) => {
+            let location = match (err.line, err.column) {
+                (Some(line), Some(column)) => format!(" at line {}, column {}", line, column),
+                _ => "".to_string(),
+            };
+
+            CheckResult::error(
+                "Config",
+                format!("{} is invalid{}: {}", err.config_file, location, err.message),
+                "fix the error, until then default configuration is used. see README for available options",
+            )
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn check_display_server() -> CheckResult {
+    if gauntlet_client::is_wayland_session() {
+        if std::env::var("WAYLAND_DISPLAY").is_err() && std::env::var("WAYLAND_SOCKET").is_err() {
+            return CheckResult::error("Display server", "Wayland session, but WAYLAND_DISPLAY is not set", "start Gauntlet from graphical session, for systemd service import environment with `systemctl --user import-environment WAYLAND_DISPLAY`")
+        }
+
+        // layer shell is not implemented by mutter
+        let gnome = std::env::var("XDG_CURRENT_DESKTOP")
+            .map(|desktop| desktop.split(':').any(|desktop| desktop == "GNOME"))
+            .unwrap_or(false);
+
+        if gnome {
+            return CheckResult::error("Display server", "Wayland session in GNOME, which doesn't support zwlr_layer_shell_v1 protocol", "start Gauntlet with XDG_SESSION_TYPE=x11 environment variable to open window as regular window instead")
+        }
+
+        CheckResult::ok("Display server", "Wayland, window is opened using zwlr_layer_shell_v1 protocol")
+    } else {
+        if std::env::var("DISPLAY").is_err() {
+            return CheckResult::error("Display server", "X11 session, but DISPLAY is not set", "start Gauntlet from graphical session, for systemd service import environment with `systemctl --user import-environment DISPLAY`")
+        }
+
+        CheckResult::ok("Display server", "X11")
+    }
+}
+
+async fn check_global_shortcut(repository: &DataDbRepository) -> CheckResult {
+    #[cfg(target_os = "linux")]
+    if gauntlet_client::is_wayland_session() {
+        return CheckResult::warning("Global shortcut", "not supported on Wayland", "bind `gauntlet open` or `gauntlet toggle` to a shortcut in compositor settings")
+    }
+
+    match repository.get_global_shortcut().await {
+        Ok(None) => CheckResult::ok("Global shortcut", "default shortcut is registered on first start"),
+        Ok(Some((None, _))) => CheckResult::warning("Global shortcut", "not set", "set it in General tab of Settings or bind `gauntlet open` to a shortcut in system settings"),
+        Ok(Some((Some(_), Some(error)))) => CheckResult::error("Global shortcut", format!("unable to register on last start: {}", error), "choose another shortcut in General tab of Settings, this one is likely used by another application"),
+        Ok(Some((Some(_), None))) => CheckResult::ok("Global shortcut", "registered without errors on last start"),
+        Err(err) => CheckResult::error("Global shortcut", format!("{:#}", err), "restart Gauntlet"),
+    }
+}
+
+// plugin runtime cannot be started without server, so everything runtime needs to start is checked instead
+async fn check_plugins(dirs: &Dirs, repository: &DataDbRepository) -> Vec<CheckResult> {
+    let plugins = match repository.list_plugins().await {
+        Ok(plugins) => plugins,
+        Err(err) => return vec![CheckResult::error("Plugins", format!("unable to list plugins: {:#}", err), "restart Gauntlet")],
+    };
+
+    let home_dir = dirs.home_dir();
+
+    let mut results = vec![];
+    let mut checked = 0;
+
+    for plugin in plugins {
+        if !plugin.enabled {
+            continue
+        }
+
+        checked += 1;
+
+        let name = format!("Plugin '{}'", plugin.name);
+
+        if let Err(err) = check_plugin(dirs, &home_dir, &plugin) {
+            let fix = if plugin.id.starts_with("file://") {
+                "fix plugin manifest and reload plugin".to_string()
+            } else {
+                format!("update or reinstall plugin {} in Settings, or disable it", plugin.id)
+            };
+
+            results.push(CheckResult::error(name, format!("{:#}", err), fix))
+        }
+    }
+
+    if results.is_empty() {
+        results.push(CheckResult::ok("Plugins", format!("{} enabled plugin(s) have everything they need to start", checked)));
+    }
+
+    results
+}
+
+fn check_plugin(dirs: &Dirs, home_dir: &Path, plugin: &DbReadPlugin) -> anyhow::Result<()> {
+    // component of wasm plugins is stored with assets
+    if matches!(plugin.code.runtime, DbPluginRuntime::Deno) && plugin.code.js.is_empty() {
+        return Err(anyhow::anyhow!("plugin code is missing"))
+    }
+
+    let plugin_data_dir = dirs.plugin_data(&plugin.uuid)?;
+    let plugin_cache_dir = dirs.plugin_cache(&plugin.uuid)?;
+
+    check_writable(&plugin_data_dir)?;
+    check_writable(&plugin_cache_dir)?;
+
+    let permissions = JsPluginPermissions {
+        environment: plugin.permissions.environment.clone(),
+        network: plugin.permissions.network.clone(),
+        filesystem: JsPluginPermissionsFileSystem {
+            read: plugin.permissions.filesystem.read.clone(),
+            write: plugin.permissions.filesystem.write.clone(),
+        },
+        exec: JsPluginPermissionsExec {
+            command: plugin.permissions.exec.command.clone(),
+            executable: plugin.permissions.exec.executable.clone(),
+        },
+        system: plugin.permissions.system.clone(),
+        main_search_bar: plugin.permissions.main_search_bar
+            .iter()
+            .map(|permission| match permission {
+                DbPluginMainSearchBarPermissions::Read => JsPluginPermissionsMainSearchBar::Read,
+            })
+            .collect(),
+    };
+
+    validate_permissions(&permissions, home_dir, &plugin_data_dir, &plugin_cache_dir)
+        .context("invalid permissions")?;
+
+    let preference_ids = plugin.preferences
+        .keys()
+        .map(|id| id.as_str())
+        .collect::<Vec<_>>();
+
+    validate_environment_variables(&plugin.code.environment_variables, &preference_ids, &plugin.permissions.secrets.read)?;
+
+    Ok(())
+}
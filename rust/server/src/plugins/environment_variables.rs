@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use gauntlet_common::i18n::current_locale;
+
+use crate::plugins::data_db_repository::{DbPluginPreference, DbPluginPreferenceUserData};
+use crate::plugins::secrets::read_secret;
+
+// e.g. "{env:PATH}:{preference:toolDirectory}" or "{secret:github/token}"
+static VARIABLE_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{(?<namespace>[a-z]+?):(?<name>.*?)}").expect("invalid regex"));
+
+pub fn validate_environment_variables(
+    environment_variables: &HashMap<String, String>,
+    preference_ids: &[&str],
+    secret_services: &[String],
+) -> anyhow::Result<()> {
+    for (name, value) in environment_variables {
+        if name.is_empty() || name.contains(['=', '\0']) {
+            Err(anyhow!("Environment variable has invalid name {:?}", name))?
+        }
+
+        if value.contains('\0') {
+            Err(anyhow!("Environment variable '{}' has invalid value", name))?
+        }
+
+        for captures in VARIABLE_PATTERN.captures_iter(value) {
+            let namespace = &captures["namespace"];
+            let variable = &captures["name"];
+
+            match namespace {
+                "env" => {}
+                "gauntlet" => {
+                    if variable != "locale" {
+                        Err(anyhow!("Environment variable '{}' uses unknown variable '{{gauntlet:{}}}'", name, variable))?
+                    }
+                }
+                "preference" => {
+                    if !preference_ids.contains(&variable) {
+                        Err(anyhow!("Environment variable '{}' uses preference '{}' which is not declared as plugin preference", name, variable))?
+                    }
+                }
+                "secret" => {
+                    let (service, _) = split_secret(variable)
+                        .ok_or_else(|| anyhow!("Environment variable '{}' uses secret '{}', expected format is '{{secret:<service>/<account>}}'", name, variable))?;
+
+                    if !secret_services.iter().any(|allowed| allowed == service) {
+                        Err(anyhow!("Environment variable '{}' uses secret of service '{}' but plugin doesn't have 'read' permission for it", name, service))?
+                    }
+                }
+                _ => Err(anyhow!("Environment variable '{}' uses variable with unknown namespace '{}'", name, namespace))?
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn uses_preference(environment_variables: &HashMap<String, String>, preference_id: &str) -> bool {
+    environment_variables.values()
+        .flat_map(|value| VARIABLE_PATTERN.captures_iter(value))
+        .any(|captures| &captures["namespace"] == "preference" && &captures["name"] == preference_id)
+}
+
+// blocking, reading secrets can wait for system secret store.
+// variable which cannot be resolved, e.g. because secret is not yet stored, is not passed to plugin runtime
+pub fn resolve_environment_variables(
+    environment_variables: HashMap<String, String>,
+    preferences: &HashMap<String, DbPluginPreference>,
+    preferences_user_data: &HashMap<String, DbPluginPreferenceUserData>,
+) -> HashMap<String, String> {
+    environment_variables.into_iter()
+        .filter_map(|(name, value)| {
+            match resolve_value(&value, preferences, preferences_user_data) {
+                Ok(value) => Some((name, value)),
+                Err(err) => {
+                    tracing::warn!(target = "plugin", "Not setting environment variable '{}' for plugin: {:?}", name, err);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+fn resolve_value(
+    value: &str,
+    preferences: &HashMap<String, DbPluginPreference>,
+    preferences_user_data: &HashMap<String, DbPluginPreferenceUserData>,
+) -> anyhow::Result<String> {
+    let mut result = String::new();
+    let mut last_end = 0;
+
+    for captures in VARIABLE_PATTERN.captures_iter(value) {
+        let whole = captures.get(0).expect("group 0 is always present");
+        let namespace = &captures["namespace"];
+        let name = &captures["name"];
+
+        result.push_str(&value[last_end..whole.start()]);
+        last_end = whole.end();
+
+        let replacement = match namespace {
+            // e.g. to add directory to PATH inherited by server
+            "env" => std::env::var(name).unwrap_or_default(),
+            "gauntlet" => posix_locale(),
+            "preference" => preference_value(name, preferences, preferences_user_data)?,
+            "secret" => {
+                let (service, account) = split_secret(name)
+                    .ok_or_else(|| anyhow!("invalid secret reference '{}'", name))?;
+
+                read_secret(service, account)?
+                    .ok_or_else(|| anyhow!("secret '{}' is not stored", name))?
+            }
+            _ => Err(anyhow!("unknown variable namespace '{}'", namespace))?
+        };
+
+        result.push_str(&replacement);
+    }
+
+    result.push_str(&value[last_end..]);
+
+    Ok(result)
+}
+
+fn split_secret(name: &str) -> Option<(&str, &str)> {
+    name.split_once('/')
+        .filter(|(service, account)| !service.is_empty() && !account.is_empty())
+}
+
+// locale of Gauntlet UI in form expected by LANG and LC_* variables, e.g. "de_DE.UTF-8"
+fn posix_locale() -> String {
+    let locale = current_locale();
+
+    match &locale.region {
+        None => format!("{}.UTF-8", locale.language.as_str()),
+        Some(region) => format!("{}_{}.UTF-8", locale.language.as_str(), region.as_str()),
+    }
+}
+
+fn preference_value(
+    id: &str,
+    preferences: &HashMap<String, DbPluginPreference>,
+    preferences_user_data: &HashMap<String, DbPluginPreferenceUserData>,
+) -> anyhow::Result<String> {
+    let value = match preferences_user_data.get(id) {
+        Some(DbPluginPreferenceUserData::Number { value }) => value.map(|value| value.to_string()),
+        Some(DbPluginPreferenceUserData::String { value }) => value.clone(),
+        Some(DbPluginPreferenceUserData::Enum { value }) => value.clone(),
+        Some(DbPluginPreferenceUserData::Bool { value }) => value.map(|value| value.to_string()),
+        Some(DbPluginPreferenceUserData::Directory { value }) => value.clone(),
+        // lists are joined the same way as PATH
+        Some(DbPluginPreferenceUserData::ListOfStrings { value }) => value.as_ref().map(|value| join_list(value)),
+        Some(DbPluginPreferenceUserData::ListOfEnums { value }) => value.as_ref().map(|value| join_list(value)),
+        Some(DbPluginPreferenceUserData::ListOfNumbers { value }) => value.as_ref().map(|value| join_list(value)),
+        None => match preferences.get(id) {
+            Some(DbPluginPreference::Number { default, .. }) => default.map(|value| value.to_string()),
+            Some(DbPluginPreference::String { default, .. }) => default.clone(),
+            Some(DbPluginPreference::Enum { default, .. }) => default.clone(),
+            Some(DbPluginPreference::Bool { default, .. }) => default.map(|value| value.to_string()),
+            Some(DbPluginPreference::Directory { default, .. }) => default.clone(),
+            Some(DbPluginPreference::ListOfStrings { default, .. }) => default.as_ref().map(|value| join_list(value)),
+            Some(DbPluginPreference::ListOfEnums { default, .. }) => default.as_ref().map(|value| join_list(value)),
+            Some(DbPluginPreference::ListOfNumbers { default, .. }) => default.as_ref().map(|value| join_list(value)),
+            None => Err(anyhow!("preference '{}' doesn't exist", id))?,
+        }
+    };
+
+    value.ok_or_else(|| anyhow!("preference '{}' doesn't have a value", id))
+}
+
+fn join_list<T: ToString>(values: &[T]) -> String {
+    let separator = if cfg!(windows) { ";" } else { ":" };
+
+    values.iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use anyhow::anyhow;
+use gauntlet_common::model::PluginId;
+
+use crate::plugins::js::{OnePluginCommandData, PluginCommand};
+
+// same order of magnitude as messages of control socket, events are meant for small notifications, not for moving data
+const MAX_PAYLOAD_SIZE: usize = 64 * 1024;
+
+// broker for events plugins send to each other, e.g. "pomodoro started" published by timer plugin
+// and consumed by plugin showing status in its entrypoint badge.
+// plugins subscribe to topics in their manifest, subscriptions are registered when plugin is started
+// and removed when it is stopped. plugins with subscriptions are never deferred by lazy start,
+// nothing is buffered for plugins which are stopped or started later
+#[derive(Clone)]
+pub struct EventBus {
+    // topics each running plugin subscribes to
+    subscriptions: Arc<Mutex<HashMap<PluginId, Vec<String>>>>,
+    command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>,
+}
+
+impl EventBus {
+    pub fn new(command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>) -> Self {
+        Self {
+            subscriptions: Arc::new(Mutex::new(HashMap::new())),
+            command_broadcaster,
+        }
+    }
+
+    pub fn add_plugin(&self, plugin_id: PluginId, topics: Vec<String>) {
+        if topics.is_empty() {
+            return
+        }
+
+        let mut subscriptions = self.subscriptions.lock().expect("lock is poisoned");
+
+        subscriptions.insert(plugin_id, topics);
+    }
+
+    pub fn remove_plugin(&self, plugin_id: &PluginId) {
+        let mut subscriptions = self.subscriptions.lock().expect("lock is poisoned");
+
+        subscriptions.remove(plugin_id);
+    }
+
+    // publisher doesn't receive its own events even if it subscribes to the topic
+    pub fn publish(&self, source_plugin_id: &PluginId, topic: String, payload: String) -> anyhow::Result<()> {
+        if payload.len() > MAX_PAYLOAD_SIZE {
+            return Err(anyhow!("Event payload is too large, {} bytes, max is {} bytes", payload.len(), MAX_PAYLOAD_SIZE));
+        }
+
+        let subscribers = {
+            let subscriptions = self.subscriptions.lock().expect("lock is poisoned");
+
+            subscriptions.iter()
+                .filter(|(plugin_id, _)| *plugin_id != source_plugin_id)
+                .filter(|(_, topics)| topics.contains(&topic))
+                .map(|(plugin_id, _)| plugin_id.clone())
+                .collect::<Vec<_>>()
+        };
+
+        tracing::debug!("Plugin {:?} published event to topic {:?}, subscribers: {:?}", source_plugin_id, topic, subscribers);
+
+        for plugin_id in subscribers {
+            // subscriber could have been stopped in the meantime
+            let _ = self.command_broadcaster.send(PluginCommand::One {
+                id: plugin_id,
+                data: OnePluginCommandData::InterPluginEvent {
+                    source_plugin_id: source_plugin_id.clone(),
+                    topic: topic.clone(),
+                    payload: payload.clone(),
+                },
+            });
+        }
+
+        Ok(())
+    }
+}
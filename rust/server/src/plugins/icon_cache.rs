@@ -17,8 +17,20 @@ impl IconCache {
         let cache_dir = self.dirs.icon_cache_dir();
         std::fs::create_dir_all(&cache_dir)?;
 
-        if cache_dir.exists() {
-            std::fs::remove_dir_all(&cache_dir)?;
+        let system_icon_cache_dir = self.dirs.system_icon_cache_dir();
+
+        for entry in std::fs::read_dir(&cache_dir)? {
+            let path = entry?.path();
+
+            if path == system_icon_cache_dir {
+                continue
+            }
+
+            if path.is_dir() {
+                std::fs::remove_dir_all(&path)?;
+            } else {
+                std::fs::remove_file(&path)?;
+            }
         }
 
         Ok(())
@@ -0,0 +1,123 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::plugins::js::{AllPluginCommandData, PluginCommand};
+
+// plugins are told to pause background work, like polling and watching files,
+// when window stays hidden for a while, and to resume it as soon as window is shown
+#[derive(Clone)]
+pub struct IdleCoordinator {
+    state: Arc<Mutex<IdleState>>,
+    command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>,
+    // pending timers are dropped on shutdown instead of waking up stopped plugins
+    shutdown_token: CancellationToken,
+}
+
+struct IdleState {
+    idle: bool,
+    // incremented on every visibility change, so that timer of previous hide doesn't make plugins idle
+    generation: u64,
+}
+
+impl IdleCoordinator {
+    pub fn new(command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>, shutdown_token: CancellationToken) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(IdleState {
+                idle: false,
+                generation: 0,
+            })),
+            command_broadcaster,
+            shutdown_token,
+        }
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.state.lock().expect("lock is poisoned").idle
+    }
+
+    // none means idle mode is disabled in config
+    pub fn window_hidden(&self, idle_after: Option<Duration>) {
+        let generation = {
+            let mut state = self.state.lock().expect("lock is poisoned");
+            state.generation += 1;
+            state.generation
+        };
+
+        let Some(idle_after) = idle_after else {
+            return
+        };
+
+        let coordinator = self.clone();
+
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = tokio::time::sleep(idle_after) => coordinator.become_idle(generation),
+                _ = coordinator.shutdown_token.cancelled() => {}
+            }
+        });
+    }
+
+    pub fn window_shown(&self) {
+        let mut state = self.state.lock().expect("lock is poisoned");
+        state.generation += 1;
+
+        if state.idle {
+            state.idle = false;
+
+            tracing::info!(target = "plugin", "Window is shown, resuming background work of plugins");
+
+            self.send_idle_changed(false);
+        }
+    }
+
+    // background work is paused right away when screen is locked
+    pub fn session_locked(&self) {
+        let generation = {
+            let mut state = self.state.lock().expect("lock is poisoned");
+            state.generation += 1;
+            state.generation
+        };
+
+        self.become_idle(generation);
+    }
+
+    // window is still hidden after unlock, so plugins are resumed and become idle again the usual way
+    pub fn session_unlocked(&self, idle_after: Option<Duration>) {
+        {
+            let mut state = self.state.lock().expect("lock is poisoned");
+
+            if state.idle {
+                state.idle = false;
+
+                tracing::info!(target = "plugin", "Screen is unlocked, resuming background work of plugins");
+
+                self.send_idle_changed(false);
+            }
+        }
+
+        self.window_hidden(idle_after);
+    }
+
+    fn become_idle(&self, generation: u64) {
+        let mut state = self.state.lock().expect("lock is poisoned");
+
+        if state.generation != generation || state.idle {
+            return
+        }
+
+        state.idle = true;
+
+        tracing::info!(target = "plugin", "Window is hidden, pausing background work of plugins");
+
+        self.send_idle_changed(true);
+    }
+
+    fn send_idle_changed(&self, idle: bool) {
+        // it is possible to have 0 plugins
+        let _ = self.command_broadcaster.send(PluginCommand::All {
+            data: AllPluginCommandData::IdleChanged { idle }
+        });
+    }
+}
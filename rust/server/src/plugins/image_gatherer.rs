@@ -1,27 +1,52 @@
 use std::collections::HashMap;
-use gauntlet_common::model::{Image, ImageSource, ImageSourceAsset, ImageSourceUrl, RootWidget, UiWidgetId, WidgetVisitor};
+use gauntlet_common::model::{Image, ImageSource, ImageSourceAsset, ImageSourceThumbnail, ImageSourceUrl, PluginId, RootWidget, UiImageId, UiWidgetId, WidgetVisitor};
 use gauntlet_plugin_runtime::BackendForPluginRuntimeApi;
 use crate::plugins::js::BackendForPluginRuntimeApiImpl;
+use crate::plugins::image_store::ImageStore;
+use crate::plugins::thumbnail_cache::ThumbnailCache;
 use futures::StreamExt;
 use std::io::Read;
+use anyhow::{anyhow, Context};
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 
 pub struct ImageGatherer<'a> {
     api: &'a BackendForPluginRuntimeApiImpl,
-    image_sources: HashMap<UiWidgetId, anyhow::Result<Vec<u8>>>
+    image_store: &'a ImageStore,
+    thumbnail_cache: &'a ThumbnailCache,
+    plugin_id: &'a PluginId,
+    image_sources: HashMap<UiWidgetId, anyhow::Result<UiImageId>>
 }
 
 impl<'a> WidgetVisitor for ImageGatherer<'a> {
     async fn image(&mut self, widget_id: UiWidgetId, widget: &Image) {
         if let Image::ImageSource(image_source) = &widget {
-            self.image_sources.insert(widget_id, get_image_date(&self.api, image_source).await);
+            let image_id = self.resolve_image(image_source).await;
+
+            // file may not be supported or required tool may not be installed, item is shown without thumbnail instead of failing whole view
+            if let (ImageSource::ImageSourceThumbnail(ImageSourceThumbnail { thumbnail }), Err(err)) = (image_source, &image_id) {
+                tracing::debug!("unable to generate thumbnail for {}: {:?}", thumbnail, err);
+                return
+            }
+
+            self.image_sources.insert(widget_id, image_id);
         }
     }
 }
 
 impl<'a> ImageGatherer<'a> {
-    pub async fn run_gatherer(api: &'a BackendForPluginRuntimeApiImpl, root_widget: &RootWidget) -> anyhow::Result<HashMap<UiWidgetId, Vec<u8>>> {
+    pub async fn run_gatherer(
+        api: &'a BackendForPluginRuntimeApiImpl,
+        image_store: &'a ImageStore,
+        thumbnail_cache: &'a ThumbnailCache,
+        plugin_id: &'a PluginId,
+        root_widget: &RootWidget
+    ) -> anyhow::Result<HashMap<UiWidgetId, UiImageId>> {
         let mut gatherer = Self {
             api,
+            image_store,
+            thumbnail_cache,
+            plugin_id,
             image_sources: HashMap::new()
         };
 
@@ -32,18 +57,36 @@ impl<'a> ImageGatherer<'a> {
             .map(|(widget_id, image)| image.map(|image| (widget_id, image)))
             .collect::<anyhow::Result<_>>()
     }
+
+    async fn resolve_image(&self, source: &ImageSource) -> anyhow::Result<UiImageId> {
+        let source_key = match source {
+            ImageSource::ImageSourceAsset(ImageSourceAsset { asset }) => format!("asset:{}", asset),
+            ImageSource::ImageSourceUrl(ImageSourceUrl { url }) => format!("url:{}", url),
+            ImageSource::ImageSourceThumbnail(ImageSourceThumbnail { thumbnail }) => format!("thumbnail:{}", thumbnail),
+        };
+
+        if let Some(image_id) = self.image_store.get_source(self.plugin_id, &source_key) {
+            return Ok(image_id)
+        }
+
+        let bytes = get_image_date(&self.api, &self.thumbnail_cache, source).await?;
+
+        Ok(self.image_store.insert(self.plugin_id, source_key, bytes.into()))
+    }
 }
 
-async fn get_image_date(api: &BackendForPluginRuntimeApiImpl, source: &ImageSource) -> anyhow::Result<Vec<u8>> {
+async fn get_image_date(api: &BackendForPluginRuntimeApiImpl, thumbnail_cache: &ThumbnailCache, source: &ImageSource) -> anyhow::Result<Vec<u8>> {
     match source {
         ImageSource::ImageSourceAsset(ImageSourceAsset { asset }) => {
             let bytes = api.get_asset_data(&asset).await?;
 
             Ok(bytes)
         }
+        ImageSource::ImageSourceUrl(ImageSourceUrl { url }) if url.starts_with("data:") => {
+            decode_data_url(url)
+        }
         ImageSource::ImageSourceUrl(ImageSourceUrl { url }) => {
             // FIXME implement error handling so it doesn't error whole view
-            // TODO implement caching
 
             let bytes = ureq::get(&url)
                 .call()?
@@ -54,5 +97,26 @@ async fn get_image_date(api: &BackendForPluginRuntimeApiImpl, source: &ImageSour
 
             Ok(bytes)
         }
+        ImageSource::ImageSourceThumbnail(ImageSourceThumbnail { thumbnail }) => {
+            let thumbnail_cache = thumbnail_cache.clone();
+            let thumbnail = thumbnail.clone();
+
+            tokio::task::spawn_blocking(move || thumbnail_cache.get_thumbnail(&thumbnail)).await?
+        }
     }
 }
+
+// e.g. "data:image/png;base64,iVBORw0KGgo...", only base64 encoded data is supported
+fn decode_data_url(url: &str) -> anyhow::Result<Vec<u8>> {
+    let (header, data) = url.split_once(',')
+        .ok_or_else(|| anyhow!("invalid data url"))?;
+
+    if !header.ends_with(";base64") {
+        return Err(anyhow!("only base64 encoded data urls are supported"))
+    }
+
+    let bytes = BASE64_STANDARD.decode(data)
+        .context("unable to decode data url")?;
+
+    Ok(bytes)
+}
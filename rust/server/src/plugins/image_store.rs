@@ -0,0 +1,88 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use bytes::Bytes;
+use gauntlet_common::model::{PluginId, UiImageId};
+use sha2::{Digest, Sha256};
+
+// images used in plugin views are kept here once per unique content,
+// frontend receives only image ids with every render and requests bytes of images it doesn't have yet
+#[derive(Clone)]
+pub struct ImageStore {
+    inner: Arc<Mutex<ImageStoreInner>>,
+}
+
+struct ImageStoreInner {
+    images: HashMap<UiImageId, StoredImage>,
+    // assets and urls that were already resolved, so they are not read or downloaded again on every render
+    sources: HashMap<PluginId, HashMap<String, UiImageId>>,
+}
+
+struct StoredImage {
+    data: Bytes,
+    plugins: HashSet<PluginId>,
+}
+
+impl ImageStore {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(ImageStoreInner {
+                images: HashMap::new(),
+                sources: HashMap::new(),
+            })),
+        }
+    }
+
+    pub fn get_source(&self, plugin_id: &PluginId, source: &str) -> Option<UiImageId> {
+        let inner = self.inner.lock().expect("lock is poisoned");
+
+        inner.sources
+            .get(plugin_id)
+            .and_then(|sources| sources.get(source))
+            .cloned()
+    }
+
+    pub fn insert(&self, plugin_id: &PluginId, source: String, data: Bytes) -> UiImageId {
+        let image_id = format!("{:x}", Sha256::digest(&data));
+
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+
+        inner.images
+            .entry(image_id.clone())
+            .or_insert_with(|| StoredImage { data, plugins: HashSet::new() })
+            .plugins
+            .insert(plugin_id.clone());
+
+        inner.sources
+            .entry(plugin_id.clone())
+            .or_default()
+            .insert(source, image_id.clone());
+
+        image_id
+    }
+
+    // ids which are not in the store anymore are skipped
+    pub fn get_images(&self, image_ids: Vec<UiImageId>) -> HashMap<UiImageId, Bytes> {
+        let inner = self.inner.lock().expect("lock is poisoned");
+
+        image_ids.into_iter()
+            .filter_map(|image_id| {
+                let data = inner.images.get(&image_id)?.data.clone();
+
+                Some((image_id, data))
+            })
+            .collect()
+    }
+
+    // called when plugin is stopped, images are kept while other plugins still use them
+    pub fn remove_plugin(&self, plugin_id: &PluginId) {
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+
+        inner.sources.remove(plugin_id);
+
+        inner.images.retain(|_, image| {
+            image.plugins.remove(plugin_id);
+
+            !image.plugins.is_empty()
+        });
+    }
+}
@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::hash::Hash;
 use std::io;
@@ -25,19 +25,41 @@ use tokio::net::TcpStream;
 use tokio::sync::Mutex;
 use tokio::task::spawn_blocking;
 use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
 use gauntlet_common::dirs::Dirs;
-use gauntlet_common::model::{EntrypointId, KeyboardEventOrigin, PhysicalKey, PluginId, RootWidget, SearchResultEntrypointType, UiPropertyValue, UiRenderLocation, UiWidgetId};
+use gauntlet_common::model::{DragData, EntrypointArgument, EntrypointArgumentType, EntrypointArgumentValue, EntrypointId, KeyboardEventOrigin, PreferenceEnumValue, PhysicalKey, PluginId, RootWidget, SearchResult, SearchResultBadge, SearchResultCategory, SearchResultEntrypointAction, SearchResultEntrypointType, SearchResultPreview, SearchResultPreviewMetadata, UiPropertyValue, UiRenderLocation, UiWidgetId};
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::settings_env_data_to_string;
-use gauntlet_plugin_runtime::{recv_message, send_message, BackendForPluginRuntimeApi, JsAdditionalSearchItem, JsClipboardData, JsInit, JsKeyboardEventOrigin, JsPluginCode, JsPluginPermissions, JsPreferenceUserData, JsEvent, JsUiPropertyValue, JsRequest, JsUiRenderLocation, JsResponse, JsMessage, JsPluginPermissionsFileSystem, JsPluginPermissionsExec, JsPluginPermissionsMainSearchBar, JsMessageSide, JsPluginRuntimeMessage};
+use gauntlet_plugin_runtime::{recv_message, send_message, BackendForPluginRuntimeApi, JsAdditionalSearchItem, JsClipboardData, JsEntrypointArgumentValue, JsInit, JsKeyboardEventOrigin, JsPluginCode, JsPluginPermissions, JsPreferenceUserData, JsEvent, JsUiPropertyValue, JsRequest, JsUiRenderLocation, JsResponse, JsMessage, JsPluginPermissionsFileSystem, JsPluginPermissionsExec, JsPluginPermissionsMainSearchBar, JsMessageSide, JsPluginRuntimeMessage, JsSearchResultPreview, JsDragPayload, JsSystemInfo, JsPluginNetworkRateLimit, JsEntrypointBadge, JsSearchResultCategory, JsUndoEntryKind, JsRenderError, JsSound, JsActiveWindow};
 use crate::model::{IntermediateUiEvent};
 use crate::plugins::clipboard::Clipboard;
-use crate::plugins::data_db_repository::{db_entrypoint_from_str, DataDbRepository, DbPluginClipboardPermissions, DbPluginEntrypointType, DbPluginPreference, DbPluginPreferenceUserData, DbReadPlugin, DbReadPluginEntrypoint};
+use crate::plugins::secrets::read_secret;
+use crate::plugins::sound::SoundPlayer;
+use crate::plugins::system_info::read_system_info;
+use crate::plugins::web_search_suggestions::WebSearchSuggestions;
+use crate::plugins::render_coalescer::{PendingRender, RenderCoalescer, RenderDecision, RenderFlushReceiver};
+use crate::plugins::data_db_repository::{db_entrypoint_from_str, DataDbRepository, DbPluginArgument, DbPluginArgumentType, DbPluginClipboardPermissions, DbPluginEntrypointType, DbPluginPreference, DbPluginPreferenceUserData, DbReadPlugin, DbReadPluginEntrypoint};
 use crate::plugins::icon_cache::IconCache;
+use crate::plugins::idle::IdleCoordinator;
+use crate::plugins::connectivity::ConnectivityMonitor;
+use crate::plugins::remote_host::{RemotePluginHost, RemoteRuntime};
+use crate::plugins::loader::normalize_asset_path;
 use crate::plugins::run_status::RunStatusGuard;
-use crate::search::{SearchIndex, SearchIndexItem, SearchIndexItemAction};
+use crate::search::{search_result_copy_items, SearchIndex, SearchIndexItem, SearchIndexItemAction, SearchIndexItemRanking};
+use crate::search_aggregator::SearchAggregator;
 use crate::{PLUGIN_RUNTIME_ENV, SETTINGS_ENV};
 use crate::plugins::image_gatherer::ImageGatherer;
+use crate::plugins::image_store::ImageStore;
+use crate::plugins::thumbnail_cache::ThumbnailCache;
+use crate::plugins::view_cache::ViewCache;
+use crate::plugins::undo_stack::{UndoEntry, UndoStack};
+use crate::plugins::event_bus::EventBus;
+use crate::plugins::active_window::ActiveWindow;
+use crate::diagnostics::DIAGNOSTICS_TARGET;
+
+// view state is meant for small values like selected tab or filter
+const MAX_VIEW_STATE_KEY_SIZE: usize = 256;
+const MAX_VIEW_STATE_VALUE_SIZE: usize = 16 * 1024;
 
 pub struct PluginRuntimeData {
     pub id: PluginId,
@@ -45,15 +67,34 @@ pub struct PluginRuntimeData {
     pub name: String,
     pub entrypoint_names: HashMap<EntrypointId, String>,
     pub code: JsPluginCode,
+    // already resolved, set on plugin runtime process
+    pub environment_variables: HashMap<String, String>,
     pub inline_view_entrypoint_id: Option<String>,
     pub permissions: PluginPermissions,
     pub command_receiver: tokio::sync::broadcast::Receiver<PluginCommand>,
+    pub idle_coordinator: IdleCoordinator,
+    pub connectivity_monitor: ConnectivityMonitor,
+    // set if runtime is started on another machine
+    pub remote_host: Option<RemotePluginHost>,
     pub db_repository: DataDbRepository,
     pub search_index: SearchIndex,
+    pub search_aggregator: SearchAggregator,
     pub icon_cache: IconCache,
-    pub frontend_api: FrontendApi,
+    pub image_store: ImageStore,
+    pub view_cache: ViewCache,
+    pub undo_stack: UndoStack,
+    pub event_bus: EventBus,
+    pub active_window: ActiveWindow,
+    // entrypoints which declare cache_view in plugin manifest
+    pub cached_view_entrypoint_ids: HashSet<EntrypointId>,
+    // renders that arrive sooner after the previous one are coalesced
+    pub min_render_interval: Duration,
+    // requests made with Http.fetch helper
+    pub network_rate_limit: JsPluginNetworkRateLimit,
+    pub frontend_api: Arc<dyn FrontendApi>,
     pub dirs: Dirs,
     pub clipboard: Clipboard,
+    pub sound_player: SoundPlayer,
 }
 
 pub struct PluginPermissions {
@@ -64,11 +105,28 @@ pub struct PluginPermissions {
     pub system: Vec<String>,
     pub clipboard: Vec<PluginPermissionsClipboard>,
     pub main_search_bar: Vec<JsPluginPermissionsMainSearchBar>,
+    pub active_window: Vec<PluginPermissionsActiveWindow>,
+    pub secrets: PluginPermissionsSecrets,
+    pub events: PluginPermissionsEvents,
 }
 
 #[derive(Clone, Debug)]
 pub struct PluginRuntimePermissions {
     pub clipboard: Vec<PluginPermissionsClipboard>,
+    pub active_window: Vec<PluginPermissionsActiveWindow>,
+    pub secrets: PluginPermissionsSecrets,
+    pub events: PluginPermissionsEvents,
+}
+
+#[derive(Clone, Debug)]
+pub struct PluginPermissionsSecrets {
+    pub read: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PluginPermissionsEvents {
+    pub publish: Vec<String>,
+    pub subscribe: Vec<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -78,6 +136,11 @@ pub enum PluginPermissionsClipboard {
     Clear
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PluginPermissionsActiveWindow {
+    Read,
+}
+
 #[derive(Clone, Debug)]
 pub enum PluginCommand {
     One {
@@ -94,15 +157,36 @@ pub enum OnePluginCommandData {
     RenderView {
         entrypoint_id: EntrypointId,
     },
-    CloseView,
+    CloseView {
+        entrypoint_id: EntrypointId,
+    },
+    PopView {
+        entrypoint_id: EntrypointId,
+    },
+    ViewVisibilityChanged {
+        entrypoint_id: EntrypointId,
+        visible: bool,
+    },
     RunCommand {
         entrypoint_id: String,
+        arguments: HashMap<String, EntrypointArgumentValue>,
     },
     RunGeneratedCommand {
         entrypoint_id: String,
         action_index: Option<usize>
     },
+    RequestGeneratedCommandPreview {
+        entrypoint_id: String,
+    },
+    RequestArgumentSuggestions {
+        entrypoint_id: String,
+        argument_id: String,
+        values: HashMap<String, String>,
+        generation: u64,
+    },
     HandleViewEvent {
+        render_location: UiRenderLocation,
+        entrypoint_id: EntrypointId,
         widget_id: UiWidgetId,
         event_name: String,
         event_arguments: Vec<UiPropertyValue>,
@@ -118,117 +202,103 @@ pub enum OnePluginCommandData {
     },
     ReloadSearchIndex,
     RefreshSearchIndex,
+    PreferenceChanged {
+        entrypoint_id: Option<EntrypointId>,
+        preference_id: String,
+    },
+    SearchQuery {
+        text: String,
+        generation: u64,
+    },
+    RunUndo {
+        undo_id: u32,
+        redo: bool,
+    },
+    InterPluginEvent {
+        source_plugin_id: PluginId,
+        topic: String,
+        payload: String,
+    },
 }
 
 #[derive(Clone, Debug)]
 pub enum AllPluginCommandData {
     OpenInlineView {
-        text: String
-    }
+        text: String,
+        generation: u64,
+    },
+    SearchQuery {
+        text: String,
+        generation: u64,
+    },
+    IdleChanged {
+        idle: bool,
+    },
+    ConnectivityChanged {
+        online: bool,
+    },
 }
 
 pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: RunStatusGuard) -> anyhow::Result<()> {
 
     let runtime_permissions = PluginRuntimePermissions {
         clipboard: data.permissions.clipboard,
+        active_window: data.permissions.active_window,
+        secrets: data.permissions.secrets,
+        events: data.permissions.events,
     };
 
+    let (render_coalescer, mut render_flush_receiver) = RenderCoalescer::new(data.min_render_interval);
+
     let api = BackendForPluginRuntimeApiImpl::new(
         data.icon_cache.clone(),
         data.db_repository,
         data.search_index,
+        data.search_aggregator,
+        data.image_store.clone(),
+        ThumbnailCache::new(data.dirs.clone()),
+        data.view_cache.clone(),
+        data.undo_stack.clone(),
+        data.event_bus.clone(),
+        data.active_window,
         data.clipboard,
+        data.sound_player,
         data.frontend_api,
         data.uuid.clone(),
         data.id.clone(),
         data.name,
         data.entrypoint_names,
+        data.cached_view_entrypoint_ids,
+        render_coalescer,
         runtime_permissions,
     );
 
     let mut command_receiver = data.command_receiver;
+    let idle_coordinator = data.idle_coordinator;
+    let connectivity_monitor = data.connectivity_monitor;
     let cache = data.icon_cache;
+    let image_store = data.image_store;
+    let view_cache = data.view_cache;
+    let undo_stack = data.undo_stack;
+    let event_bus = data.event_bus;
     let plugin_uuid = data.uuid.clone();
     let plugin_id = data.id.clone();
 
     let plugin_id_str = plugin_id.to_string();
     let dev_plugin = plugin_id_str.starts_with("file://");
 
-    let (stdout_file, stderr_file) = if dev_plugin {
-        let (stdout_file, stderr_file) = data.dirs.plugin_log_files(&plugin_uuid);
-
-        let stdout_file = stdout_file
-            .to_str()
-            .context("non-uft8 paths are not supported")?
-            .to_string();
-
-        let stderr_file = stderr_file.to_str()
-            .context("non-uft8 paths are not supported")?
-            .to_string();
-
-        (Some(stdout_file), Some(stderr_file))
-    } else {
-        (None, None)
+    // runtime of the plugin is started on another machine, paths in init message point to directories there
+    let remote_runtime = match &data.remote_host {
+        Some(remote_host) => Some(RemoteRuntime::connect(&data.dirs, remote_host, &plugin_uuid, &data.environment_variables).await?),
+        None => None,
     };
 
-    let home_dir = data.dirs.home_dir();
-    let local_storage_dir = data.dirs.plugin_local_storage(&plugin_uuid);
-    let uds_socket_file = data.dirs.plugin_uds_socket(&plugin_uuid);
-    let plugin_cache_dir = data.dirs.plugin_cache(&plugin_uuid)?;
-    let plugin_data_dir = data.dirs.plugin_data(&plugin_uuid)?;
-
-    #[cfg(target_os = "windows")]
-    let name_str = format!("project-gauntlet-{}", plugin_uuid);
-
-    #[cfg(unix)]
-    let name_str = uds_socket_file.clone();
-
-    // namespaced, removed when both client and server disconnect
-    #[cfg(target_os = "windows")]
-    let name = name_str.clone().to_ns_name::<interprocess::local_socket::GenericNamespaced>()?;
-
-    // not namespaced, needs to be cleaned up manually,
-    // by using close-behind semantics and additionally removing it before creating a new runtime
-    #[cfg(unix)]
-    let name = {
-        let uds_socket_file = uds_socket_file.clone();
-
-        // manually remove in case of unexpected situation where removing after connection did not work properly
-        let _ = std::fs::remove_file(&uds_socket_file);
-
-        std::fs::create_dir_all(&uds_socket_file.parent().unwrap())?;
-
-        uds_socket_file.to_fs_name::<interprocess::os::unix::local_socket::FilesystemUdSocket>()?
+    let runtime_dirs = match &remote_runtime {
+        Some(remote_runtime) => remote_runtime.dirs.clone(),
+        None => PluginRuntimeDirs::new(&data.dirs, &plugin_uuid)?,
     };
 
-    let opts = ListenerOptions::new().name(name);
-
-    let listener = opts.create_tokio()?;
-
-    let home_dir = home_dir
-        .to_str()
-        .context("non-uft8 paths are not supported")?
-        .to_string();
-
-    let local_storage_dir = local_storage_dir
-        .to_str()
-        .context("non-uft8 paths are not supported")?
-        .to_string();
-
-    let uds_socket_file = uds_socket_file
-        .to_str()
-        .context("non-uft8 paths are not supported")?
-        .to_string();
-
-    let plugin_cache_dir = plugin_cache_dir
-        .to_str()
-        .context("non-uft8 paths are not supported")?
-        .to_string();
-
-    let plugin_data_dir = plugin_data_dir
-        .to_str()
-        .context("non-uft8 paths are not supported")?
-        .to_string();
+    let socket = PluginRuntimeSocket::create(&data.dirs, &plugin_uuid)?;
 
     let permissions = JsPluginPermissions {
         environment: data.permissions.environment,
@@ -244,40 +314,46 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         plugin_uuid: plugin_uuid.clone(),
         code: data.code,
         permissions,
+        network_rate_limit: data.network_rate_limit,
         inline_view_entrypoint_id: data.inline_view_entrypoint_id,
         dev_plugin,
-        home_dir,
-        local_storage_dir,
-        plugin_cache_dir,
-        plugin_data_dir,
-        stdout_file,
-        stderr_file,
+        home_dir: runtime_dirs.home_dir,
+        local_storage_dir: runtime_dirs.local_storage_dir,
+        plugin_cache_dir: runtime_dirs.plugin_cache_dir,
+        plugin_data_dir: runtime_dirs.plugin_data_dir,
+        stdout_file: Some(runtime_dirs.stdout_file),
+        stderr_file: Some(runtime_dirs.stderr_file),
     };
 
-    let current_exe = std::env::current_exe()
-        .context("unable to get current_exe")?;
+    match remote_runtime {
+        // connects to the socket in place of plugin runtime process and forwards everything to the host
+        Some(remote_runtime) => {
+            let socket_name = socket.name.clone();
 
-    #[cfg(not(feature = "scenario_runner"))]
-    std::process::Command::new(current_exe)
-        .env(PLUGIN_RUNTIME_ENV, name_str)
-        .spawn()
-        .context("start plugin runtime process")?;
-
-    // use only for debugging and scenario_runner, only works if only one plugin is enabled
-    #[cfg(feature = "scenario_runner")]
-    std::thread::spawn(move || {
-        gauntlet_plugin_runtime::run_plugin_runtime(name_str.to_str().unwrap().to_string())
-    });
-
-    let conn = listener.accept().await?;
+            tokio::spawn(async move {
+                if let Err(err) = remote_runtime.forward(socket_name).await {
+                    tracing::error!("Connection to remote plugin host faced an error {:?}", err);
+                }
+            });
+        }
+        None => spawn_plugin_runtime_process(&socket.name, &data.environment_variables)?,
+    }
 
-    #[cfg(unix)]
-    let _ = std::fs::remove_file(&uds_socket_file);
+    let conn = socket.accept().await?;
 
     let (mut recver, mut sender) = conn.split();
 
     send_message(JsMessageSide::Backend, &mut sender, init).await?;
 
+    // plugin started while window is hidden, e.g. one with command generators right after start with --minimized
+    if idle_coordinator.is_idle() {
+        send_message(JsMessageSide::Backend, &mut sender, JsMessage::Event(JsEvent::IdleChanged { idle: true })).await?;
+    }
+
+    if !connectivity_monitor.is_online() {
+        send_message(JsMessageSide::Backend, &mut sender, JsMessage::Event(JsEvent::ConnectivityChanged { online: false })).await?;
+    }
+
     let sender = Mutex::new(sender);
 
     tokio::select! {
@@ -294,7 +370,7 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         result = {
              tokio::task::unconstrained(async {
                 loop {
-                    if let Err(err) = event_loop(&mut command_receiver, &sender, plugin_id.clone()).await {
+                    if let Err(err) = event_loop(&mut command_receiver, &sender, plugin_id.clone(), &api.render_coalescer).await {
                         tracing::error!("Event loop faced an error {:?}", err);
                         break;
                     }
@@ -323,6 +399,18 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         } => {
             tracing::error!("Request loop has been stopped {:?}", plugin_id)
         }
+        result = {
+             tokio::task::unconstrained(async {
+                loop {
+                    if let Err(err) = render_flush_loop(&mut render_flush_receiver, &api).await {
+                        tracing::error!("Render flush loop faced an error {:?}", err);
+                        break;
+                    }
+                }
+             })
+        } => {
+            tracing::error!("Render flush loop has been stopped {:?}", plugin_id)
+        }
     }
 
     drop((recver, sender));
@@ -333,10 +421,137 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         tracing::error!(target = "plugin", "plugin {:?} unable to cleanup icon cache {:?}", plugin_id, err)
     }
 
+    image_store.remove_plugin(&plugin_id);
+    view_cache.remove_plugin(&plugin_id);
+    undo_stack.remove_plugin(&plugin_id);
+    event_bus.remove_plugin(&plugin_id);
+
+    // badges are only valid while plugin is running
+    api.search_index.clear_entrypoint_badges(&plugin_id);
+
     Ok(())
 }
 
-async fn event_loop(command_receiver: &mut tokio::sync::broadcast::Receiver<PluginCommand>, send: &Mutex<SendHalf>, plugin_id: PluginId) -> anyhow::Result<()>  {
+// paths used by plugin runtime, they are on the machine where runtime is running
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PluginRuntimeDirs {
+    pub home_dir: String,
+    pub local_storage_dir: String,
+    pub plugin_cache_dir: String,
+    pub plugin_data_dir: String,
+    pub stdout_file: String,
+    pub stderr_file: String,
+}
+
+impl PluginRuntimeDirs {
+    pub fn new(dirs: &Dirs, plugin_uuid: &str) -> anyhow::Result<Self> {
+        let (stdout_file, stderr_file) = dirs.plugin_log_files(plugin_uuid);
+
+        Ok(Self {
+            home_dir: path_to_string(dirs.home_dir())?,
+            local_storage_dir: path_to_string(dirs.plugin_local_storage(plugin_uuid))?,
+            plugin_cache_dir: path_to_string(dirs.plugin_cache(plugin_uuid)?)?,
+            plugin_data_dir: path_to_string(dirs.plugin_data(plugin_uuid)?)?,
+            stdout_file: path_to_string(stdout_file)?,
+            stderr_file: path_to_string(stderr_file)?,
+        })
+    }
+}
+
+fn path_to_string(path: PathBuf) -> anyhow::Result<String> {
+    path.to_str()
+        .context("non-uft8 paths are not supported")
+        .map(|path| path.to_string())
+}
+
+// socket which plugin runtime process connects to after it is started
+pub struct PluginRuntimeSocket {
+    // passed to plugin runtime process
+    pub name: String,
+    listener: interprocess::local_socket::tokio::Listener,
+    #[cfg(unix)]
+    socket_file: PathBuf,
+}
+
+impl PluginRuntimeSocket {
+    #[cfg_attr(target_os = "windows", allow(unused_variables))]
+    pub fn create(dirs: &Dirs, plugin_uuid: &str) -> anyhow::Result<Self> {
+        // namespaced, removed when both client and server disconnect
+        #[cfg(target_os = "windows")]
+        {
+            let name_str = format!("project-gauntlet-{}", plugin_uuid);
+
+            let name = name_str.clone().to_ns_name::<interprocess::local_socket::GenericNamespaced>()?;
+
+            let listener = ListenerOptions::new().name(name).create_tokio()?;
+
+            Ok(Self {
+                name: name_str,
+                listener,
+            })
+        }
+
+        // not namespaced, needs to be cleaned up manually,
+        // by using close-behind semantics and additionally removing it before creating a new runtime
+        #[cfg(unix)]
+        {
+            let socket_file = dirs.plugin_uds_socket(plugin_uuid);
+
+            // manually remove in case of unexpected situation where removing after connection did not work properly
+            let _ = std::fs::remove_file(&socket_file);
+
+            std::fs::create_dir_all(&socket_file.parent().unwrap())?;
+
+            let name = socket_file.clone().to_fs_name::<interprocess::os::unix::local_socket::FilesystemUdSocket>()?;
+
+            let listener = ListenerOptions::new().name(name).create_tokio()?;
+
+            Ok(Self {
+                name: path_to_string(socket_file.clone())?,
+                listener,
+                socket_file,
+            })
+        }
+    }
+
+    pub async fn accept(self) -> anyhow::Result<interprocess::local_socket::tokio::Stream> {
+        let conn = self.listener.accept().await?;
+
+        #[cfg(unix)]
+        let _ = std::fs::remove_file(&self.socket_file);
+
+        Ok(conn)
+    }
+}
+
+#[cfg_attr(feature = "scenario_runner", allow(unused_variables))]
+pub fn spawn_plugin_runtime_process(socket_name: &str, environment_variables: &HashMap<String, String>) -> anyhow::Result<()> {
+    #[cfg(not(feature = "scenario_runner"))]
+    {
+        let current_exe = std::env::current_exe()
+            .context("unable to get current_exe")?;
+
+        std::process::Command::new(current_exe)
+            .envs(environment_variables)
+            .env(PLUGIN_RUNTIME_ENV, socket_name)
+            .spawn()
+            .context("start plugin runtime process")?;
+    }
+
+    // use only for debugging and scenario_runner, only works if only one plugin is enabled
+    #[cfg(feature = "scenario_runner")]
+    {
+        let socket_name = socket_name.to_string();
+
+        std::thread::spawn(move || {
+            gauntlet_plugin_runtime::run_plugin_runtime(socket_name)
+        });
+    }
+
+    Ok(())
+}
+
+async fn event_loop(command_receiver: &mut tokio::sync::broadcast::Receiver<PluginCommand>, send: &Mutex<SendHalf>, plugin_id: PluginId, render_coalescer: &RenderCoalescer) -> anyhow::Result<()>  {
     let command = command_receiver.recv().await?;
 
     let event = match command {
@@ -350,12 +565,21 @@ async fn event_loop(command_receiver: &mut tokio::sync::broadcast::Receiver<Plug
                             entrypoint_id,
                         })
                     }
-                    OnePluginCommandData::CloseView => {
-                        Some(IntermediateUiEvent::CloseView)
+                    OnePluginCommandData::CloseView { entrypoint_id } => {
+                        render_coalescer.discard(UiRenderLocation::View, &entrypoint_id);
+
+                        Some(IntermediateUiEvent::CloseView { entrypoint_id })
+                    }
+                    OnePluginCommandData::PopView { entrypoint_id } => {
+                        Some(IntermediateUiEvent::PopView { entrypoint_id })
+                    }
+                    OnePluginCommandData::ViewVisibilityChanged { entrypoint_id, visible } => {
+                        Some(IntermediateUiEvent::ViewVisibilityChanged { entrypoint_id, visible })
                     }
-                    OnePluginCommandData::RunCommand { entrypoint_id } => {
+                    OnePluginCommandData::RunCommand { entrypoint_id, arguments } => {
                         Some(IntermediateUiEvent::RunCommand {
                             entrypoint_id,
+                            arguments,
                         })
                     }
                     OnePluginCommandData::RunGeneratedCommand { entrypoint_id, action_index } => {
@@ -364,8 +588,23 @@ async fn event_loop(command_receiver: &mut tokio::sync::broadcast::Receiver<Plug
                             action_index
                         })
                     }
-                    OnePluginCommandData::HandleViewEvent { widget_id, event_name, event_arguments } => {
+                    OnePluginCommandData::RequestGeneratedCommandPreview { entrypoint_id } => {
+                        Some(IntermediateUiEvent::RequestGeneratedCommandPreview {
+                            entrypoint_id
+                        })
+                    }
+                    OnePluginCommandData::RequestArgumentSuggestions { entrypoint_id, argument_id, values, generation } => {
+                        Some(IntermediateUiEvent::RequestArgumentSuggestions {
+                            entrypoint_id,
+                            argument_id,
+                            values,
+                            generation,
+                        })
+                    }
+                    OnePluginCommandData::HandleViewEvent { render_location, entrypoint_id, widget_id, event_name, event_arguments } => {
                         Some(IntermediateUiEvent::HandleViewEvent {
+                            render_location,
+                            entrypoint_id,
                             widget_id,
                             event_name,
                             event_arguments,
@@ -388,13 +627,35 @@ async fn event_loop(command_receiver: &mut tokio::sync::broadcast::Receiver<Plug
                     OnePluginCommandData::RefreshSearchIndex => {
                         Some(IntermediateUiEvent::RefreshSearchIndex)
                     }
+                    OnePluginCommandData::PreferenceChanged { entrypoint_id, preference_id } => {
+                        Some(IntermediateUiEvent::PreferenceChanged { entrypoint_id, preference_id })
+                    }
+                    // only sent to plugin which keyword was typed
+                    OnePluginCommandData::SearchQuery { text, generation } => {
+                        Some(IntermediateUiEvent::SearchQuery { text, generation, keyword_mode: true })
+                    }
+                    OnePluginCommandData::RunUndo { undo_id, redo } => {
+                        Some(IntermediateUiEvent::RunUndo { undo_id, redo })
+                    }
+                    OnePluginCommandData::InterPluginEvent { source_plugin_id, topic, payload } => {
+                        Some(IntermediateUiEvent::InterPluginEvent { source_plugin_id, topic, payload })
+                    }
                 }
             }
         }
         PluginCommand::All { data } => {
             match data {
-                AllPluginCommandData::OpenInlineView { text } => {
-                    Some(IntermediateUiEvent::OpenInlineView { text })
+                AllPluginCommandData::OpenInlineView { text, generation } => {
+                    Some(IntermediateUiEvent::OpenInlineView { text, generation })
+                }
+                AllPluginCommandData::SearchQuery { text, generation } => {
+                    Some(IntermediateUiEvent::SearchQuery { text, generation, keyword_mode: false })
+                }
+                AllPluginCommandData::IdleChanged { idle } => {
+                    Some(IntermediateUiEvent::IdleChanged { idle })
+                }
+                AllPluginCommandData::ConnectivityChanged { online } => {
+                    Some(IntermediateUiEvent::ConnectivityChanged { online })
                 }
             }
         }
@@ -404,12 +665,34 @@ async fn event_loop(command_receiver: &mut tokio::sync::broadcast::Receiver<Plug
     if let Some(event) = event {
         let mut send = send.lock().await;
 
-        send_message(JsMessageSide::Backend, &mut send, JsMessage::Event(from_intermediate_to_js_event(event))).await?;
+        let ipc_span = tracing::info_span!(target: DIAGNOSTICS_TARGET, "ipc", plugin_id = %plugin_id.to_string(), name = "event");
+
+        send_message(JsMessageSide::Backend, &mut send, JsMessage::Event(from_intermediate_to_js_event(event)))
+            .instrument(ipc_span)
+            .await?;
     }
 
     Ok(())
 }
 
+async fn render_flush_loop(render_flush_receiver: &mut RenderFlushReceiver, api: &BackendForPluginRuntimeApiImpl) -> anyhow::Result<()> {
+    let target = render_flush_receiver.next_flush()
+        .await
+        .ok_or_else(|| anyhow!("render coalescer was dropped"))?;
+
+    let Some(render) = api.render_coalescer.take_pending(&target) else {
+        return Ok(())
+    };
+
+    let (render_location, _) = target;
+
+    // there is no plugin runtime request to return the error to
+    if let Err(err) = api.send_render(render_location, render).await {
+        tracing::warn!(target = "plugin", "Unable to send coalesced render of plugin {:?}: {:?}", api.plugin_id, err);
+    }
+
+    Ok(())
+}
 
 async fn request_loop(recv: &mut RecvHalf, send: &Mutex<SendHalf>, api: &BackendForPluginRuntimeApiImpl) -> anyhow::Result<bool>  {
     match recv_message::<JsPluginRuntimeMessage>(JsMessageSide::Backend, recv).await {
@@ -422,13 +705,18 @@ async fn request_loop(recv: &mut RecvHalf, send: &Mutex<SendHalf>, api: &Backend
             match message {
                 JsPluginRuntimeMessage::Stopped => Ok(true),
                 JsPluginRuntimeMessage::Request(message) => {
-                    match handle_message(message, api).await {
+                    let op_span = tracing::info_span!(target: DIAGNOSTICS_TARGET, "op", plugin_id = %api.plugin_id.to_string(), name = message.name());
+                    let ipc_span = tracing::info_span!(target: DIAGNOSTICS_TARGET, "ipc", plugin_id = %api.plugin_id.to_string(), name = "response");
+
+                    match handle_message(message, api).instrument(op_span).await {
                         Ok(response) => {
                             let mut send = send.lock().await;
 
                             tracing::trace!("Sending request response: {:?}", response);
 
-                            send_message(JsMessageSide::Backend, &mut send, JsMessage::Response(Ok(response))).await?;
+                            send_message(JsMessageSide::Backend, &mut send, JsMessage::Response(Ok(response)))
+                                .instrument(ipc_span)
+                                .await?;
 
                             Ok(false)
                         }
@@ -437,7 +725,9 @@ async fn request_loop(recv: &mut RecvHalf, send: &Mutex<SendHalf>, api: &Backend
 
                             let err = format!("{:?}", err);
 
-                            send_message(JsMessageSide::Backend, &mut send, JsMessage::Response(Err(err))).await?;
+                            send_message(JsMessageSide::Backend, &mut send, JsMessage::Response(Err(err)))
+                                .instrument(ipc_span)
+                                .await?;
 
                             Ok(false)
                         }
@@ -450,13 +740,17 @@ async fn request_loop(recv: &mut RecvHalf, send: &Mutex<SendHalf>, api: &Backend
 
 async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl) -> anyhow::Result<JsResponse> {
     match message {
-        JsRequest::Render { entrypoint_id, render_location, top_level_view, container } => {
+        JsRequest::Render { entrypoint_id, render_location, view_stack_depth, container } => {
             let render_location = match render_location {
                 JsUiRenderLocation::InlineView => UiRenderLocation::InlineView,
                 JsUiRenderLocation::View => UiRenderLocation::View
             };
 
-            api.ui_render(entrypoint_id, render_location, top_level_view, container).await?;
+            let render_span = tracing::info_span!(target: DIAGNOSTICS_TARGET, "render", plugin_id = %api.plugin_id.to_string(), name = %entrypoint_id.to_string());
+
+            api.ui_render(entrypoint_id, render_location, view_stack_depth, container)
+                .instrument(render_span)
+                .await?;
 
             Ok(JsResponse::Nothing)
         }
@@ -475,6 +769,21 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
 
             Ok(JsResponse::Nothing)
         }
+        JsRequest::ShowRenderErrorView { entrypoint_id, render_location, error } => {
+            let render_location = match render_location {
+                JsUiRenderLocation::InlineView => UiRenderLocation::InlineView,
+                JsUiRenderLocation::View => UiRenderLocation::View
+            };
+
+            api.ui_show_render_error_view(entrypoint_id, render_location, error).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::RenderTiming { entrypoint_id, js_render_time_ms, serialization_time_ms } => {
+            api.ui_render_timing(entrypoint_id, js_render_time_ms, serialization_time_ms).await?;
+
+            Ok(JsResponse::Nothing)
+        }
         JsRequest::ShowPreferenceRequiredView { entrypoint_id, plugin_preferences_required, entrypoint_preferences_required } => {
             api.ui_show_preferences_required_view(entrypoint_id, plugin_preferences_required, entrypoint_preferences_required).await?;
 
@@ -485,16 +794,36 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
 
             Ok(JsResponse::Nothing)
         }
+        JsRequest::ShowPluginView { entrypoint_id } => {
+            api.ui_show_plugin_view(entrypoint_id).await?;
+
+            Ok(JsResponse::Nothing)
+        }
         JsRequest::UpdateLoadingBar { entrypoint_id, show } => {
             api.ui_update_loading_bar(entrypoint_id, show).await?;
 
             Ok(JsResponse::Nothing)
         }
+        JsRequest::ShowSearchResultPreview { entrypoint_id, preview } => {
+            api.ui_show_search_result_preview(entrypoint_id, preview).await?;
+
+            Ok(JsResponse::Nothing)
+        }
         JsRequest::ReloadSearchIndex { generated_commands, refresh_search_list } => {
             api.reload_search_index(generated_commands, refresh_search_list).await?;
 
             Ok(JsResponse::Nothing)
         }
+        JsRequest::SubmitSearchResults { generation, results } => {
+            api.submit_search_results(generation, results).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::SubmitArgumentSuggestions { generation, entrypoint_id, argument_id, suggestions } => {
+            api.submit_argument_suggestions(generation, entrypoint_id, argument_id, suggestions).await?;
+
+            Ok(JsResponse::Nothing)
+        }
         JsRequest::GetAssetData { path } => {
             let data = api.get_asset_data(&path).await?;
 
@@ -556,8 +885,8 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
 
             Ok(JsResponse::Nothing)
         }
-        JsRequest::ClipboardWriteText { data } => {
-            api.clipboard_write_text(data).await?;
+        JsRequest::ClipboardWriteText { data, clear_after_seconds } => {
+            api.clipboard_write_text(data, clear_after_seconds).await?;
 
             Ok(JsResponse::Nothing)
         }
@@ -566,6 +895,66 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
 
             Ok(JsResponse::Nothing)
         }
+        JsRequest::SecretRead { service, account } => {
+            let data = api.secret_read(service, account).await?;
+
+            Ok(JsResponse::SecretRead {
+                data
+            })
+        }
+        JsRequest::PublishEvent { topic, payload } => {
+            api.event_publish(topic, payload).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::GetActiveWindow => {
+            let data = api.active_window().await?;
+
+            Ok(JsResponse::ActiveWindow {
+                data
+            })
+        }
+        JsRequest::ReadViewState { entrypoint_id, key } => {
+            let data = api.view_state_read(entrypoint_id, key).await?;
+
+            Ok(JsResponse::ViewState {
+                data
+            })
+        }
+        JsRequest::WriteViewState { entrypoint_id, key, value } => {
+            api.view_state_write(entrypoint_id, key, value).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::PlaySound { sound } => {
+            api.play_sound(sound).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::WebSearchSuggestions { engine, query } => {
+            let data = api.web_search_suggestions(engine, query).await?;
+
+            Ok(JsResponse::WebSearchSuggestions {
+                data
+            })
+        }
+        JsRequest::GetSystemInfo => {
+            let data = api.system_info().await?;
+
+            Ok(JsResponse::SystemInfo {
+                data
+            })
+        }
+        JsRequest::SetEntrypointBadge { entrypoint_id, badge } => {
+            api.ui_set_entrypoint_badge(entrypoint_id, badge).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::RegisterUndo { undo_id, description, kind } => {
+            api.ui_register_undo(undo_id, description, kind).await?;
+
+            Ok(JsResponse::Nothing)
+        }
         JsRequest::GetActionIdForShortcut { entrypoint_id, key, modifier_shift, modifier_control, modifier_alt, modifier_meta } => {
             let data = api.ui_get_action_id_for_shortcut(
                 entrypoint_id,
@@ -583,33 +972,71 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
     }
 }
 
+fn ui_property_value_to_js(value: UiPropertyValue) -> JsUiPropertyValue {
+    match value {
+        UiPropertyValue::String(value) => JsUiPropertyValue::String { value },
+        UiPropertyValue::Number(value) => JsUiPropertyValue::Number { value },
+        UiPropertyValue::Bool(value) => JsUiPropertyValue::Bool { value },
+        UiPropertyValue::Array(value) => JsUiPropertyValue::Array { value: value.into_iter().map(|value| ui_property_value_to_js(value)).collect() },
+        UiPropertyValue::Undefined => JsUiPropertyValue::Undefined,
+        UiPropertyValue::Bytes(_) | UiPropertyValue::Object(_)  => {
+            todo!()
+        }
+    }
+}
+
 fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
     match event {
         IntermediateUiEvent::OpenView { entrypoint_id } => JsEvent::OpenView {
             entrypoint_id: entrypoint_id.to_string(),
         },
-        IntermediateUiEvent::CloseView => JsEvent::CloseView,
-        IntermediateUiEvent::RunCommand { entrypoint_id } => JsEvent::RunCommand {
-            entrypoint_id
+        IntermediateUiEvent::CloseView { entrypoint_id } => JsEvent::CloseView {
+            entrypoint_id: entrypoint_id.to_string(),
+        },
+        IntermediateUiEvent::PopView { entrypoint_id } => JsEvent::PopView {
+            entrypoint_id: entrypoint_id.to_string(),
+        },
+        IntermediateUiEvent::ViewVisibilityChanged { entrypoint_id, visible } => JsEvent::ViewVisibilityChanged {
+            entrypoint_id: entrypoint_id.to_string(),
+            visible,
+        },
+        IntermediateUiEvent::RunCommand { entrypoint_id, arguments } => JsEvent::RunCommand {
+            entrypoint_id,
+            arguments: arguments.into_iter()
+                .map(|(id, value)| {
+                    let value = match value {
+                        EntrypointArgumentValue::String(value) => JsEntrypointArgumentValue::String(value),
+                        EntrypointArgumentValue::Number(value) => JsEntrypointArgumentValue::Number(value),
+                    };
+
+                    (id, value)
+                })
+                .collect(),
         },
         IntermediateUiEvent::RunGeneratedCommand { entrypoint_id, action_index } => JsEvent::RunGeneratedCommand {
             entrypoint_id,
             action_index,
         },
-        IntermediateUiEvent::HandleViewEvent { widget_id, event_name, event_arguments } => {
+        IntermediateUiEvent::RequestGeneratedCommandPreview { entrypoint_id } => JsEvent::RequestGeneratedCommandPreview {
+            entrypoint_id,
+        },
+        IntermediateUiEvent::RequestArgumentSuggestions { entrypoint_id, argument_id, values, generation } => JsEvent::RequestArgumentSuggestions {
+            entrypoint_id,
+            argument_id,
+            values,
+            generation,
+        },
+        IntermediateUiEvent::HandleViewEvent { render_location, entrypoint_id, widget_id, event_name, event_arguments } => {
             let event_arguments = event_arguments.into_iter()
-                .map(|arg| match arg {
-                    UiPropertyValue::String(value) => JsUiPropertyValue::String { value },
-                    UiPropertyValue::Number(value) => JsUiPropertyValue::Number { value },
-                    UiPropertyValue::Bool(value) => JsUiPropertyValue::Bool { value },
-                    UiPropertyValue::Undefined => JsUiPropertyValue::Undefined,
-                    UiPropertyValue::Array(_) | UiPropertyValue::Bytes(_) | UiPropertyValue::Object(_)  => {
-                        todo!()
-                    }
-                })
+                .map(|arg| ui_property_value_to_js(arg))
                 .collect();
 
             JsEvent::ViewEvent {
+                render_location: match render_location {
+                    UiRenderLocation::InlineView => JsUiRenderLocation::InlineView,
+                    UiRenderLocation::View => JsUiRenderLocation::View,
+                },
+                entrypoint_id: entrypoint_id.to_string(),
                 widget_id,
                 event_name,
                 event_arguments,
@@ -629,10 +1056,230 @@ fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
                 modifier_meta
             }
         }
-        IntermediateUiEvent::OpenInlineView { text } => JsEvent::OpenInlineView { text },
+        IntermediateUiEvent::OpenInlineView { text, generation } => JsEvent::OpenInlineView { text, generation },
+        IntermediateUiEvent::SearchQuery { text, generation, keyword_mode } => JsEvent::SearchQuery { text, generation, keyword_mode },
         IntermediateUiEvent::ReloadSearchIndex => JsEvent::ReloadSearchIndex,
         IntermediateUiEvent::RefreshSearchIndex => JsEvent::RefreshSearchIndex,
+        IntermediateUiEvent::PreferenceChanged { entrypoint_id, preference_id } => JsEvent::PreferenceChanged {
+            entrypoint_id: entrypoint_id.map(|id| id.to_string()),
+            preference_id,
+        },
+        IntermediateUiEvent::RunUndo { undo_id, redo } => JsEvent::RunUndo { undo_id, redo },
+        IntermediateUiEvent::IdleChanged { idle } => JsEvent::IdleChanged { idle },
+        IntermediateUiEvent::ConnectivityChanged { online } => JsEvent::ConnectivityChanged { online },
+        IntermediateUiEvent::InterPluginEvent { source_plugin_id, topic, payload } => JsEvent::InterPluginEvent {
+            source_plugin_id: source_plugin_id.to_string(),
+            topic,
+            payload,
+        },
+    }
+}
+
+// also used for plugins which runtime is not started yet, in that case there are no generated commands
+pub async fn reload_plugin_search_index(
+    repository: &DataDbRepository,
+    icon_cache: &IconCache,
+    search_index: &SearchIndex,
+    plugin_id: &PluginId,
+    plugin_uuid: &str,
+    generated_commands: Vec<JsAdditionalSearchItem>,
+    refresh_search_list: bool,
+) -> anyhow::Result<()> {
+    icon_cache.clear_plugin_icon_cache_dir(plugin_uuid)
+        .context("error when clearing up icon cache before recreating it")?;
+
+    let DbReadPlugin { name, .. } = repository.get_plugin_by_id(&plugin_id.to_string())
+        .await
+        .context("error when getting plugin by id")?;
+
+    let entrypoints = repository.get_entrypoints_by_plugin_id(&plugin_id.to_string())
+        .await
+        .context("error when getting entrypoints by plugin id")?;
+
+    let frecency_map = repository.get_frecency_for_plugin(&plugin_id.to_string())
+        .await
+        .context("error when getting frecency for plugin")?;
+
+    let mut generated_aliases = repository.get_generated_entrypoint_aliases(&plugin_id.to_string())
+        .await
+        .context("error when getting aliases of generated entrypoints")?;
+
+    let mut shortcuts = HashMap::new();
+
+    for DbReadPluginEntrypoint { id, .. } in &entrypoints {
+        let entrypoint_shortcuts = repository.action_shortcuts(&plugin_id.to_string(), id).await?;
+        shortcuts.insert(id.clone(), entrypoint_shortcuts);
     }
+
+    let hidden_entrypoint_ids = entrypoints.iter()
+        .filter(|entrypoint| entrypoint.hidden)
+        .map(|entrypoint| entrypoint.id.clone())
+        .collect::<HashSet<_>>();
+
+    let mut plugins_search_items = generated_commands.into_iter()
+        .map(|item| {
+            let entrypoint_icon_path = match item.entrypoint_icon {
+                None => None,
+                Some(data) => Some(icon_cache.save_entrypoint_icon_to_cache(plugin_uuid, &item.entrypoint_uuid, &data)?),
+            };
+
+            let entrypoint_frecency = frecency_map.get(&item.entrypoint_id).cloned().unwrap_or(0.0);
+
+            let entrypoint_aliases = generated_aliases.remove(&item.entrypoint_id)
+                .unwrap_or_default();
+
+            let shortcuts = shortcuts
+                .get(&item.generator_entrypoint_id);
+
+            let entrypoint_actions = item.entrypoint_actions.iter()
+                .map(|action| {
+                    let shortcut = match (shortcuts, &action.id) {
+                        (Some(shortcuts), Some(id)) => {
+                            shortcuts.get(id).cloned()
+                        }
+                        _ => None
+                    };
+
+                    SearchIndexItemAction {
+                        label: action.label.clone(),
+                        shortcut,
+                    }
+                })
+                .collect();
+
+            Ok(SearchIndexItem {
+                entrypoint_type: SearchResultEntrypointType::GeneratedCommand,
+                entrypoint_id: EntrypointId::from_string(item.entrypoint_id),
+                entrypoint_name: item.entrypoint_name,
+                entrypoint_icon_path,
+                entrypoint_frecency,
+                entrypoint_actions,
+                entrypoint_accessory: item.entrypoint_accessory,
+                entrypoint_preview: item.entrypoint_preview,
+                entrypoint_drag: item.entrypoint_drag.map(|drag| match drag {
+                    JsDragPayload::File { file } => DragData::File(file),
+                    JsDragPayload::Url { url } => DragData::Url(url),
+                }),
+                entrypoint_arguments: vec![],
+                entrypoint_fallback: false,
+                entrypoint_hidden: hidden_entrypoint_ids.contains(&item.generator_entrypoint_id),
+                entrypoint_generator_id: Some(EntrypointId::from_string(item.generator_entrypoint_id)),
+                entrypoint_ranking: SearchIndexItemRanking {
+                    boost: item.entrypoint_ranking.boost,
+                    keywords: item.entrypoint_ranking.keywords
+                        .into_iter()
+                        .chain(entrypoint_aliases.iter().cloned())
+                        .collect(),
+                    timestamp: item.entrypoint_ranking.timestamp,
+                },
+                entrypoint_dedupe_key: item.entrypoint_dedupe_key,
+                entrypoint_category: search_result_category(item.entrypoint_category),
+                entrypoint_aliases,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut icon_asset_data = HashMap::new();
+
+    for entrypoint in &entrypoints {
+        if let Some(path_to_asset) = &entrypoint.icon_path {
+            let result = match normalize_asset_path(path_to_asset) {
+                Ok(path) => repository.get_asset_data(&plugin_id.to_string(), &path).await,
+                Err(err) => Err(err),
+            };
+
+            if let Ok(data) = result {
+                icon_asset_data.insert((entrypoint.id.clone(), path_to_asset.clone()), data);
+            }
+        }
+    }
+
+    let mut builtin_search_items = entrypoints.into_iter()
+        .filter(|entrypoint| entrypoint.enabled)
+        .map(|entrypoint| {
+            let entrypoint_type = db_entrypoint_from_str(&entrypoint.entrypoint_type);
+            let entrypoint_id = entrypoint.id.to_string();
+            let entrypoint_name = entrypoint.localized_name();
+
+            let entrypoint_frecency = frecency_map.get(&entrypoint_id).cloned().unwrap_or(0.0);
+
+            let entrypoint_icon_path = match entrypoint.icon_path {
+                None => None,
+                Some(path_to_asset) => {
+                    match icon_asset_data.get(&(entrypoint.id, path_to_asset)) {
+                        None => None,
+                        Some(data) => Some(icon_cache.save_entrypoint_icon_to_cache(plugin_uuid, &entrypoint.uuid, data)?)
+                    }
+                },
+            };
+
+            let entrypoint_id = EntrypointId::from_string(entrypoint_id);
+
+            match &entrypoint_type {
+                DbPluginEntrypointType::Command => {
+                    Ok(Some(SearchIndexItem {
+                        entrypoint_type: SearchResultEntrypointType::Command,
+                        entrypoint_name,
+                        entrypoint_id,
+                        entrypoint_icon_path,
+                        entrypoint_frecency,
+                        entrypoint_actions: vec![],
+                        entrypoint_accessory: None,
+                        entrypoint_preview: false,
+                        entrypoint_drag: None,
+                        entrypoint_arguments: entrypoint_arguments_from_db(entrypoint.arguments),
+                        entrypoint_fallback: entrypoint.fallback,
+                        entrypoint_hidden: entrypoint.hidden,
+                        entrypoint_generator_id: None,
+                        entrypoint_ranking: SearchIndexItemRanking {
+                            keywords: entrypoint.aliases.clone(),
+                            ..SearchIndexItemRanking::default()
+                        },
+                        entrypoint_dedupe_key: None,
+                        entrypoint_category: SearchResultCategory::Command,
+                        entrypoint_aliases: entrypoint.aliases,
+                    }))
+                },
+                DbPluginEntrypointType::View => {
+                    Ok(Some(SearchIndexItem {
+                        entrypoint_type: SearchResultEntrypointType::View,
+                        entrypoint_name,
+                        entrypoint_id,
+                        entrypoint_icon_path,
+                        entrypoint_frecency,
+                        entrypoint_actions: vec![],
+                        entrypoint_accessory: None,
+                        entrypoint_preview: false,
+                        entrypoint_drag: None,
+                        entrypoint_arguments: vec![],
+                        entrypoint_fallback: false,
+                        entrypoint_hidden: entrypoint.hidden,
+                        entrypoint_generator_id: None,
+                        entrypoint_ranking: SearchIndexItemRanking {
+                            keywords: entrypoint.aliases.clone(),
+                            ..SearchIndexItemRanking::default()
+                        },
+                        entrypoint_dedupe_key: None,
+                        entrypoint_category: SearchResultCategory::Command,
+                        entrypoint_aliases: entrypoint.aliases,
+                    }))
+                },
+                DbPluginEntrypointType::CommandGenerator | DbPluginEntrypointType::InlineView => {
+                    Ok(None)
+                }
+            }
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .into_iter()
+        .flat_map(|item| item)
+        .collect::<Vec<_>>();
+
+    plugins_search_items.append(&mut builtin_search_items);
+
+    search_index.save_for_plugin(plugin_id.clone(), name, plugins_search_items, refresh_search_list)
+        .context("error when updating search index")?;
+
+    Ok(())
 }
 
 #[derive(Clone)]
@@ -640,13 +1287,24 @@ pub struct BackendForPluginRuntimeApiImpl {
     icon_cache: IconCache,
     repository: DataDbRepository,
     search_index: SearchIndex,
+    search_aggregator: SearchAggregator,
+    image_store: ImageStore,
+    thumbnail_cache: ThumbnailCache,
+    view_cache: ViewCache,
+    undo_stack: UndoStack,
+    event_bus: EventBus,
+    active_window: ActiveWindow,
     clipboard: Clipboard,
-    frontend_api: FrontendApi,
+    sound_player: SoundPlayer,
+    frontend_api: Arc<dyn FrontendApi>,
     plugin_uuid: String,
     plugin_id: PluginId,
     plugin_name: String,
     entrypoint_names: HashMap<EntrypointId, String>,
-    permissions: PluginRuntimePermissions
+    cached_view_entrypoint_ids: HashSet<EntrypointId>,
+    render_coalescer: RenderCoalescer,
+    permissions: PluginRuntimePermissions,
+    web_search_suggestions: WebSearchSuggestions,
 }
 
 impl BackendForPluginRuntimeApiImpl {
@@ -654,62 +1312,140 @@ impl BackendForPluginRuntimeApiImpl {
         icon_cache: IconCache,
         repository: DataDbRepository,
         search_index: SearchIndex,
+        search_aggregator: SearchAggregator,
+        image_store: ImageStore,
+        thumbnail_cache: ThumbnailCache,
+        view_cache: ViewCache,
+        undo_stack: UndoStack,
+        event_bus: EventBus,
+        active_window: ActiveWindow,
         clipboard: Clipboard,
-        frontend_api: FrontendApi,
+        sound_player: SoundPlayer,
+        frontend_api: Arc<dyn FrontendApi>,
         plugin_uuid: String,
         plugin_id: PluginId,
         plugin_name: String,
         entrypoint_names: HashMap<EntrypointId, String>,
+        cached_view_entrypoint_ids: HashSet<EntrypointId>,
+        render_coalescer: RenderCoalescer,
         permissions: PluginRuntimePermissions
     ) -> Self {
         Self {
             icon_cache,
             repository,
             search_index,
+            search_aggregator,
+            image_store,
+            thumbnail_cache,
+            view_cache,
+            undo_stack,
+            event_bus,
+            active_window,
             clipboard,
+            sound_player,
             frontend_api,
             plugin_uuid,
             plugin_id,
             plugin_name,
             entrypoint_names,
-            permissions
+            cached_view_entrypoint_ids,
+            render_coalescer,
+            permissions,
+            web_search_suggestions: WebSearchSuggestions::new(),
         }
     }
+
+    async fn send_render(&self, render_location: UiRenderLocation, render: PendingRender) -> anyhow::Result<()> {
+        let PendingRender { entrypoint_id, view_stack_depth, container } = render;
+
+        let entrypoint_name = self.entrypoint_names
+            .get(&entrypoint_id)
+            .expect("entrypoint name for id should always exist")
+            .to_string();
+
+        let images = ImageGatherer::run_gatherer(&self, &self.image_store, &self.thumbnail_cache, &self.plugin_id, &container).await?;
+
+        // only root view is cached, views pushed on top of it are not shown when view is reopened
+        if render_location == UiRenderLocation::View && view_stack_depth == 1 && self.cached_view_entrypoint_ids.contains(&entrypoint_id) {
+            let result = self.view_cache.insert(
+                self.plugin_id.clone(),
+                entrypoint_id.clone(),
+                self.plugin_name.clone(),
+                entrypoint_name.clone(),
+                &container,
+                images.clone()
+            );
+
+            if let Err(err) = result {
+                tracing::warn!(target = "plugin", "Unable to cache view of entrypoint {:?}: {:?}", entrypoint_id, err);
+            }
+        }
+
+        let ipc_span = tracing::info_span!(target: DIAGNOSTICS_TARGET, "ipc", plugin_id = %self.plugin_id.to_string(), name = "replace_view");
+
+        self.frontend_api.replace_view(
+            self.plugin_id.clone(),
+            self.plugin_name.clone(),
+            entrypoint_id,
+            entrypoint_name,
+            render_location,
+            view_stack_depth,
+            container,
+            images
+        )
+            .instrument(ipc_span)
+            .await?;
+
+        Ok(())
+    }
 }
 
 impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
     async fn reload_search_index(&self, generated_commands: Vec<JsAdditionalSearchItem>, refresh_search_list: bool) -> anyhow::Result<()> {
-        self.icon_cache.clear_plugin_icon_cache_dir(&self.plugin_uuid)
-            .context("error when clearing up icon cache before recreating it")?;
+        reload_plugin_search_index(
+            &self.repository,
+            &self.icon_cache,
+            &self.search_index,
+            &self.plugin_id,
+            &self.plugin_uuid,
+            generated_commands,
+            refresh_search_list
+        ).await
+    }
 
-        let DbReadPlugin { name, .. } = self.repository.get_plugin_by_id(&self.plugin_id.to_string())
-            .await
-            .context("error when getting plugin by id")?;
+    async fn submit_argument_suggestions(&self, generation: u64, entrypoint_id: EntrypointId, argument_id: String, suggestions: Vec<String>) -> anyhow::Result<()> {
+        self.frontend_api.show_argument_suggestions(self.plugin_id.clone(), entrypoint_id, argument_id, generation, suggestions).await?;
 
+        Ok(())
+    }
+
+    async fn submit_search_results(&self, generation: u64, results: Vec<JsAdditionalSearchItem>) -> anyhow::Result<()> {
         let entrypoints = self.repository.get_entrypoints_by_plugin_id(&self.plugin_id.to_string())
             .await
             .context("error when getting entrypoints by plugin id")?;
 
-        let frecency_map = self.repository.get_frecency_for_plugin(&self.plugin_id.to_string())
-            .await
-            .context("error when getting frecency for plugin")?;
+        let hidden_entrypoint_ids = entrypoints.iter()
+            .filter(|entrypoint| entrypoint.hidden)
+            .map(|entrypoint| entrypoint.id.clone())
+            .collect::<HashSet<_>>();
 
         let mut shortcuts = HashMap::new();
 
-        for DbReadPluginEntrypoint { id, .. } in &entrypoints {
-            let entrypoint_shortcuts = self.repository.action_shortcuts(&self.plugin_id.to_string(), id).await?;
-            shortcuts.insert(id.clone(), entrypoint_shortcuts);
+        for item in &results {
+            if !shortcuts.contains_key(&item.generator_entrypoint_id) {
+                let entrypoint_shortcuts = self.repository.action_shortcuts(&self.plugin_id.to_string(), &item.generator_entrypoint_id).await?;
+                shortcuts.insert(item.generator_entrypoint_id.clone(), entrypoint_shortcuts);
+            }
         }
 
-        let mut plugins_search_items = generated_commands.into_iter()
+        let results = results.into_iter()
+            .filter(|item| !hidden_entrypoint_ids.contains(&item.generator_entrypoint_id))
             .map(|item| {
-                let entrypoint_icon_path = match item.entrypoint_icon {
+                let entrypoint_icon = match item.entrypoint_icon {
                     None => None,
                     Some(data) => Some(self.icon_cache.save_entrypoint_icon_to_cache(&self.plugin_uuid, &item.entrypoint_uuid, &data)?),
                 };
 
-                let entrypoint_frecency = frecency_map.get(&item.entrypoint_id).cloned().unwrap_or(0.0);
-
                 let shortcuts = shortcuts
                     .get(&item.generator_entrypoint_id);
 
@@ -722,99 +1458,62 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                             _ => None
                         };
 
-                        SearchIndexItemAction {
+                        SearchResultEntrypointAction {
                             label: action.label.clone(),
                             shortcut,
+                            merged_from: None,
                         }
                     })
                     .collect();
 
-                Ok(SearchIndexItem {
-                    entrypoint_type: SearchResultEntrypointType::GeneratedCommand,
-                    entrypoint_id: EntrypointId::from_string(item.entrypoint_id),
+                let entrypoint_id = EntrypointId::from_string(item.entrypoint_id);
+                let entrypoint_badge = self.search_index.entrypoint_badge(&self.plugin_id, &entrypoint_id);
+
+                let entrypoint_drag = item.entrypoint_drag.map(|drag| match drag {
+                    JsDragPayload::File { file } => DragData::File(file),
+                    JsDragPayload::Url { url } => DragData::Url(url),
+                });
+
+                let entrypoint_copy_items = search_result_copy_items(
+                    &entrypoint_id,
+                    &item.entrypoint_name,
+                    &SearchResultEntrypointType::GeneratedCommand,
+                    &entrypoint_drag
+                );
+
+                Ok(SearchResult {
+                    plugin_id: self.plugin_id.clone(),
+                    plugin_name: self.plugin_name.clone(),
+                    entrypoint_id,
                     entrypoint_name: item.entrypoint_name,
-                    entrypoint_icon_path,
-                    entrypoint_frecency,
+                    entrypoint_name_matches: vec![],
+                    entrypoint_icon,
+                    entrypoint_type: SearchResultEntrypointType::GeneratedCommand,
                     entrypoint_actions,
+                    entrypoint_accessory: item.entrypoint_accessory,
+                    entrypoint_badge,
+                    entrypoint_preview: item.entrypoint_preview,
+                    entrypoint_drag,
+                    entrypoint_arguments: vec![],
+                    entrypoint_copy_items,
+                    fallback: false,
+                    recent: false,
+                    favorite: false,
+                    entrypoint_category: search_result_category(item.entrypoint_category),
+                    aliases: vec![],
                 })
             })
             .collect::<anyhow::Result<Vec<_>>>()?;
 
-        let mut icon_asset_data = HashMap::new();
-
-        for entrypoint in &entrypoints {
-            if let Some(path_to_asset) = &entrypoint.icon_path {
-                let result = self.repository.get_asset_data(&self.plugin_id.to_string(), path_to_asset)
-                    .await;
-
-                if let Ok(data) = result {
-                    icon_asset_data.insert((entrypoint.id.clone(), path_to_asset.clone()), data);
-                }
-            }
-        }
-
-        let mut builtin_search_items = entrypoints.into_iter()
-            .filter(|entrypoint| entrypoint.enabled)
-            .map(|entrypoint| {
-                let entrypoint_type = db_entrypoint_from_str(&entrypoint.entrypoint_type);
-                let entrypoint_id = entrypoint.id.to_string();
-
-                let entrypoint_frecency = frecency_map.get(&entrypoint_id).cloned().unwrap_or(0.0);
-
-                let entrypoint_icon_path = match entrypoint.icon_path {
-                    None => None,
-                    Some(path_to_asset) => {
-                        match icon_asset_data.get(&(entrypoint.id, path_to_asset)) {
-                            None => None,
-                            Some(data) => Some(self.icon_cache.save_entrypoint_icon_to_cache(&self.plugin_uuid, &entrypoint.uuid, data)?)
-                        }
-                    },
-                };
-
-                let entrypoint_id = EntrypointId::from_string(entrypoint_id);
-
-                match &entrypoint_type {
-                    DbPluginEntrypointType::Command => {
-                        Ok(Some(SearchIndexItem {
-                            entrypoint_type: SearchResultEntrypointType::Command,
-                            entrypoint_name: entrypoint.name,
-                            entrypoint_id,
-                            entrypoint_icon_path,
-                            entrypoint_frecency,
-                            entrypoint_actions: vec![],
-                        }))
-                    },
-                    DbPluginEntrypointType::View => {
-                        Ok(Some(SearchIndexItem {
-                            entrypoint_type: SearchResultEntrypointType::View,
-                            entrypoint_name: entrypoint.name,
-                            entrypoint_id,
-                            entrypoint_icon_path,
-                            entrypoint_frecency,
-                            entrypoint_actions: vec![],
-                        }))
-                    },
-                    DbPluginEntrypointType::CommandGenerator | DbPluginEntrypointType::InlineView => {
-                        Ok(None)
-                    }
-                }
-            })
-            .collect::<anyhow::Result<Vec<_>>>()?
-            .into_iter()
-            .flat_map(|item| item)
-            .collect::<Vec<_>>();
-
-        plugins_search_items.append(&mut builtin_search_items);
-
-        self.search_index.save_for_plugin(self.plugin_id.clone(), name, plugins_search_items, refresh_search_list)
-            .context("error when updating search index")?;
-
-        Ok(())
+        self.search_aggregator.submit(self.plugin_id.clone(), generation, results).await
     }
 
     async fn get_asset_data(&self, path: &str) -> anyhow::Result<Vec<u8>> {
+        let path = normalize_asset_path(path)?;
+
         let data = self.repository.get_asset_data(&self.plugin_id.to_string(), &path)
-            .await?;
+            .await
+            .with_context(|| format!("Asset not found: {:?}", path))?;
 
         Ok(data)
     }
@@ -850,14 +1549,14 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         let DbReadPlugin { preferences, preferences_user_data, .. } = self.repository
             .get_plugin_by_id(&self.plugin_id.to_string()).await?;
 
-        Ok(any_preferences_missing_value(preferences, preferences_user_data))
+        Ok(any_preferences_missing_value(&preferences, &preferences_user_data))
     }
 
     async fn entrypoint_preferences_required(&self, entrypoint_id: EntrypointId) -> anyhow::Result<bool> {
         let DbReadPluginEntrypoint { preferences, preferences_user_data, .. } = self.repository
             .get_entrypoint_by_id(&self.plugin_id.to_string(), &entrypoint_id.to_string()).await?;
 
-        Ok(any_preferences_missing_value(preferences, preferences_user_data))
+        Ok(any_preferences_missing_value(&preferences, &preferences_user_data))
     }
 
     async fn clipboard_read(&self) -> anyhow::Result<JsClipboardData> {
@@ -905,7 +1604,7 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         self.clipboard.write(data)
     }
 
-    async fn clipboard_write_text(&self, data: String) -> anyhow::Result<()> {
+    async fn clipboard_write_text(&self, data: String, clear_after_seconds: Option<u64>) -> anyhow::Result<()> {
         let allow = self
             .permissions
             .clipboard
@@ -917,7 +1616,21 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
 
         tracing::debug!("Writing text to clipboard, plugin id: {:?}", self.plugin_id);
 
-        self.clipboard.write_text(data)
+        match clear_after_seconds {
+            None => self.clipboard.write_text(data),
+            Some(clear_after_seconds) => {
+                let allow = self
+                    .permissions
+                    .clipboard
+                    .contains(&PluginPermissionsClipboard::Clear);
+
+                if !allow {
+                    return Err(anyhow!("Plugin doesn't have 'clear' permission for clipboard"));
+                }
+
+                self.clipboard.write_text_and_clear_after(data, Duration::from_secs(clear_after_seconds))
+            }
+        }
     }
 
     async fn clipboard_clear(&self) -> anyhow::Result<()> {
@@ -935,18 +1648,177 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         self.clipboard.clear()
     }
 
+    async fn secret_read(&self, service: String, account: String) -> anyhow::Result<Option<String>> {
+        let allow = self
+            .permissions
+            .secrets
+            .read
+            .contains(&service);
+
+        if !allow {
+            return Err(anyhow!("Plugin doesn't have 'read' permission for secret service '{}'", service));
+        }
+
+        tracing::debug!("Reading secret of service {:?}, plugin id: {:?}", service, self.plugin_id);
+
+        tokio::task::spawn_blocking(move || read_secret(&service, &account)).await?
+    }
+
+    async fn event_publish(&self, topic: String, payload: String) -> anyhow::Result<()> {
+        let allow = self
+            .permissions
+            .events
+            .publish
+            .contains(&topic);
+
+        if !allow {
+            return Err(anyhow!("Plugin doesn't have 'publish' permission for event topic '{}'", topic));
+        }
+
+        self.event_bus.publish(&self.plugin_id, topic, payload)
+    }
+
+    async fn active_window(&self) -> anyhow::Result<Option<JsActiveWindow>> {
+        let allow = self
+            .permissions
+            .active_window
+            .contains(&PluginPermissionsActiveWindow::Read);
+
+        if !allow {
+            return Err(anyhow!("Plugin doesn't have 'read' permission for active window"));
+        }
+
+        let active_window = self.active_window.get()
+            .map(|context| JsActiveWindow {
+                app_id: context.app_id,
+                app_name: context.app_name,
+                title: context.title,
+            });
+
+        Ok(active_window)
+    }
+
+    async fn view_state_read(&self, entrypoint_id: EntrypointId, key: String) -> anyhow::Result<Option<String>> {
+        self.repository.get_view_state(&self.plugin_id.to_string(), &entrypoint_id.to_string(), &key).await
+    }
+
+    async fn view_state_write(&self, entrypoint_id: EntrypointId, key: String, value: Option<String>) -> anyhow::Result<()> {
+        if key.len() > MAX_VIEW_STATE_KEY_SIZE {
+            return Err(anyhow!("View state key is longer than {} bytes", MAX_VIEW_STATE_KEY_SIZE));
+        }
+
+        if let Some(value) = &value {
+            if value.len() > MAX_VIEW_STATE_VALUE_SIZE {
+                return Err(anyhow!("View state value for key '{}' is larger than {} bytes", key, MAX_VIEW_STATE_VALUE_SIZE));
+            }
+        }
+
+        self.repository.set_view_state(&self.plugin_id.to_string(), &entrypoint_id.to_string(), &key, value.as_deref()).await
+    }
+
+    async fn web_search_suggestions(&self, engine: String, query: String) -> anyhow::Result<Vec<String>> {
+        self.web_search_suggestions.suggestions(engine, query).await
+    }
+
+    async fn system_info(&self) -> anyhow::Result<JsSystemInfo> {
+        tokio::task::spawn_blocking(|| read_system_info()).await?
+    }
+
+    async fn play_sound(&self, sound: JsSound) -> anyhow::Result<()> {
+        if self.sound_player.is_muted() {
+            return Ok(())
+        }
+
+        let sound_player = self.sound_player.clone();
+
+        match sound {
+            JsSound::Builtin { name } => {
+                tokio::task::spawn_blocking(move || sound_player.play_builtin(name)).await?
+            }
+            JsSound::Asset { path } => {
+                let data = self.get_asset_data(&path).await?;
+
+                tokio::task::spawn_blocking(move || sound_player.play_data(data)).await?
+            }
+        }
+    }
+
     async fn ui_update_loading_bar(&self, entrypoint_id: EntrypointId, show: bool) -> anyhow::Result<()> {
         self.frontend_api.update_loading_bar(self.plugin_id.clone(), entrypoint_id, show).await?;
 
         Ok(())
     }
 
+    async fn ui_set_entrypoint_badge(&self, entrypoint_id: EntrypointId, badge: Option<JsEntrypointBadge>) -> anyhow::Result<()> {
+        let badge = badge.map(|badge| match badge {
+            JsEntrypointBadge::Count { count } => SearchResultBadge::Count(count),
+            JsEntrypointBadge::Dot => SearchResultBadge::Dot,
+        });
+
+        self.search_index.set_entrypoint_badge(self.plugin_id.clone(), entrypoint_id, badge);
+
+        Ok(())
+    }
+
+    async fn ui_register_undo(&self, undo_id: u32, description: String, kind: JsUndoEntryKind) -> anyhow::Result<()> {
+        let entry = UndoEntry {
+            plugin_id: self.plugin_id.clone(),
+            undo_id,
+            description: description.clone(),
+        };
+
+        match kind {
+            JsUndoEntryKind::Action => {
+                self.undo_stack.push_action(entry);
+
+                self.frontend_api.show_undo_toast(description).await?;
+            }
+            JsUndoEntryKind::Undo => self.undo_stack.push_redo(entry),
+            JsUndoEntryKind::Redo => self.undo_stack.push_undo(entry),
+        }
+
+        Ok(())
+    }
+
+    async fn ui_show_search_result_preview(&self, entrypoint_id: EntrypointId, preview: Option<JsSearchResultPreview>) -> anyhow::Result<()> {
+        let preview = preview.map(|preview| {
+            SearchResultPreview {
+                text: preview.text,
+                image: preview.image,
+                metadata: preview.metadata
+                    .into_iter()
+                    .map(|item| SearchResultPreviewMetadata { label: item.label, value: item.value })
+                    .collect(),
+            }
+        });
+
+        self.frontend_api.show_search_result_preview(self.plugin_id.clone(), entrypoint_id, preview).await?;
+
+        Ok(())
+    }
+
     async fn ui_show_hud(&self, display: String) -> anyhow::Result<()> {
         self.frontend_api.show_hud(display).await?;
 
         Ok(())
     }
 
+    async fn ui_show_plugin_view(&self, entrypoint_id: EntrypointId) -> anyhow::Result<()> {
+        let entrypoint_name = self.entrypoint_names
+            .get(&entrypoint_id)
+            .ok_or_else(|| anyhow!("Entrypoint with id '{}' doesn't exist", entrypoint_id.to_string()))?
+            .to_string();
+
+        self.frontend_api.show_plugin_view(
+            self.plugin_id.clone(),
+            self.plugin_name.clone(),
+            entrypoint_id,
+            entrypoint_name
+        ).await?;
+
+        Ok(())
+    }
+
     async fn ui_get_action_id_for_shortcut(
         &self,
         entrypoint_id: EntrypointId,
@@ -973,40 +1845,76 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         &self,
         entrypoint_id: EntrypointId,
         render_location: UiRenderLocation,
-        top_level_view: bool,
+        view_stack_depth: usize,
         container: RootWidget,
     ) -> anyhow::Result<()> {
+        let render = PendingRender {
+            entrypoint_id,
+            view_stack_depth,
+            container,
+        };
 
-        let entrypoint_name = self.entrypoint_names
-            .get(&entrypoint_id)
-            .expect("entrypoint name for id should always exist")
-            .to_string();
+        match self.render_coalescer.submit(render_location, render) {
+            RenderDecision::Send(render) => self.send_render(render_location, render).await,
+            RenderDecision::Deferred => Ok(()),
+        }
+    }
 
-        let images = ImageGatherer::run_gatherer(&self, &container).await?;
+    async fn ui_show_plugin_error_view(
+        &self,
+        entrypoint_id: EntrypointId,
+        render_location: UiRenderLocation
+    ) -> anyhow::Result<()> {
+        self.render_coalescer.discard(render_location, &entrypoint_id);
 
-        self.frontend_api.replace_view(
+        self.frontend_api.show_plugin_error_view(
             self.plugin_id.clone(),
-            self.plugin_name.clone(),
             entrypoint_id,
-            entrypoint_name,
-            render_location,
-            top_level_view,
-            container,
-            images
+            render_location
         ).await?;
 
         Ok(())
     }
 
-    async fn ui_show_plugin_error_view(
+    async fn ui_show_render_error_view(
         &self,
         entrypoint_id: EntrypointId,
-        render_location: UiRenderLocation
+        render_location: UiRenderLocation,
+        error: JsRenderError
     ) -> anyhow::Result<()> {
-        self.frontend_api.show_plugin_error_view(
+        self.render_coalescer.discard(render_location, &entrypoint_id);
+
+        match render_location {
+            // inline view is too small to show error details, they are in plugin logs
+            UiRenderLocation::InlineView => {
+                tracing::warn!(target = "plugin", "Entrypoint {:?} rendered invalid inline view: {} at {}", entrypoint_id, error.message, error.component_path.join(" > "));
+
+                self.frontend_api.clear_inline_view(self.plugin_id.clone()).await?;
+            }
+            UiRenderLocation::View => {
+                self.frontend_api.show_render_error_view(
+                    self.plugin_id.clone(),
+                    entrypoint_id,
+                    error.message,
+                    error.component_path
+                ).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn ui_render_timing(
+        &self,
+        entrypoint_id: EntrypointId,
+        js_render_time_ms: f64,
+        serialization_time_ms: f64
+    ) -> anyhow::Result<()> {
+        self.frontend_api.show_render_timing(
             self.plugin_id.clone(),
             entrypoint_id,
-            render_location
+            js_render_time_ms,
+            serialization_time_ms
         ).await?;
 
         Ok(())
@@ -1018,6 +1926,7 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         plugin_preferences_required: bool,
         entrypoint_preferences_required: bool
     ) -> anyhow::Result<()> {
+        self.render_coalescer.discard(UiRenderLocation::View, &entrypoint_id);
 
         self.frontend_api.show_preference_required_view(
             self.plugin_id.clone(),
@@ -1030,12 +1939,23 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
     }
 
     async fn ui_clear_inline_view(&self) -> anyhow::Result<()> {
+        self.render_coalescer.discard_location(UiRenderLocation::InlineView);
+
         self.frontend_api.clear_inline_view(self.plugin_id.clone()).await?;
 
         Ok(())
     }
 }
 
+fn search_result_category(category: Option<JsSearchResultCategory>) -> SearchResultCategory {
+    match category {
+        Some(JsSearchResultCategory::Application) => SearchResultCategory::Application,
+        Some(JsSearchResultCategory::Command) | None => SearchResultCategory::Command,
+        Some(JsSearchResultCategory::Quicklink) => SearchResultCategory::Quicklink,
+        Some(JsSearchResultCategory::File) => SearchResultCategory::File,
+    }
+}
+
 
 fn preferences_to_js(
     preferences: HashMap<String, DbPluginPreference>,
@@ -1052,6 +1972,7 @@ fn preferences_to_js(
                     DbPluginPreference::ListOfStrings { default, .. } => JsPreferenceUserData::ListOfStrings(default.expect("at this point preference should always have value")),
                     DbPluginPreference::ListOfNumbers { default, .. } => JsPreferenceUserData::ListOfNumbers(default.expect("at this point preference should always have value")),
                     DbPluginPreference::ListOfEnums { default, .. } => JsPreferenceUserData::ListOfStrings(default.expect("at this point preference should always have value")),
+                    DbPluginPreference::Directory { default, .. } => JsPreferenceUserData::String(default.expect("at this point preference should always have value")),
                 }
                 Some(user_data) => match user_data {
                     DbPluginPreferenceUserData::Number { value } => JsPreferenceUserData::Number(value.expect("at this point preference should always have value")),
@@ -1061,6 +1982,7 @@ fn preferences_to_js(
                     DbPluginPreferenceUserData::ListOfStrings { value } => JsPreferenceUserData::ListOfStrings(value.expect("at this point preference should always have value")),
                     DbPluginPreferenceUserData::ListOfNumbers { value } => JsPreferenceUserData::ListOfNumbers(value.expect("at this point preference should always have value")),
                     DbPluginPreferenceUserData::ListOfEnums { value } => JsPreferenceUserData::ListOfStrings(value.expect("at this point preference should always have value")),
+                    DbPluginPreferenceUserData::Directory { value } => JsPreferenceUserData::String(value.expect("at this point preference should always have value")),
                 }
             };
 
@@ -1069,9 +1991,29 @@ fn preferences_to_js(
         .collect()
 }
 
-fn any_preferences_missing_value(preferences: HashMap<String, DbPluginPreference>, preferences_user_data: HashMap<String, DbPluginPreferenceUserData>) -> bool {
+pub(super) fn entrypoint_arguments_from_db(arguments: Vec<DbPluginArgument>) -> Vec<EntrypointArgument> {
+    arguments.into_iter()
+        .map(|argument| EntrypointArgument {
+            id: argument.id,
+            name: argument.name,
+            optional: argument.optional,
+            hint: argument.hint,
+            argument_type: match argument.argument_type {
+                DbPluginArgumentType::String { max_length } => EntrypointArgumentType::String { max_length },
+                DbPluginArgumentType::Number { min, max } => EntrypointArgumentType::Number { min, max },
+                DbPluginArgumentType::Enum { enum_values } => EntrypointArgumentType::Enum {
+                    enum_values: enum_values.into_iter()
+                        .map(|enum_value| PreferenceEnumValue { label: enum_value.label, value: enum_value.value })
+                        .collect(),
+                },
+            },
+        })
+        .collect()
+}
+
+pub(super) fn any_preferences_missing_value(preferences: &HashMap<String, DbPluginPreference>, preferences_user_data: &HashMap<String, DbPluginPreferenceUserData>) -> bool {
     for (name, preference) in preferences {
-        match preferences_user_data.get(&name) {
+        match preferences_user_data.get(name) {
             None => {
                 let no_default = match preference {
                     DbPluginPreference::Number { default, .. } => default.is_none(),
@@ -1081,6 +2023,7 @@ fn any_preferences_missing_value(preferences: HashMap<String, DbPluginPreference
                     DbPluginPreference::ListOfStrings { default, .. } => default.is_none(),
                     DbPluginPreference::ListOfNumbers { default, .. } => default.is_none(),
                     DbPluginPreference::ListOfEnums { default, .. } => default.is_none(),
+                    DbPluginPreference::Directory { default, .. } => default.is_none(),
                 };
 
                 if no_default {
@@ -1096,6 +2039,7 @@ fn any_preferences_missing_value(preferences: HashMap<String, DbPluginPreference
                     DbPluginPreferenceUserData::ListOfStrings { value } => value.is_none(),
                     DbPluginPreferenceUserData::ListOfNumbers { value } => value.is_none(),
                     DbPluginPreferenceUserData::ListOfEnums { value } => value.is_none(),
+                    DbPluginPreferenceUserData::Directory { value } => value.is_none(),
                 };
 
                 if no_value {
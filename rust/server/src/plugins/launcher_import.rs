@@ -0,0 +1,770 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{Map, Number, Value};
+
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::{ImportItem, ImportItemKind, ImportSource};
+
+// urls of all sources are converted to use this placeholder for text typed after keyword
+const QUERY_PLACEHOLDER: &str = "{query}";
+
+const SNIPPET_DELIMITER: &str = "GAUNTLET_SNIPPET_END";
+
+// raycast placeholders, only argument can be supported because script commands receive only typed text
+static RAYCAST_PLACEHOLDER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\{(argument|query|clipboard|selection|date|time|datetime|day|uuid|cursor|snippet|browser-tab)(\s[^}]*)?\}").unwrap()
+});
+
+// items from other launchers are converted to script commands, so that they don't need a separate storage.
+// quicklinks and web searches open url in default browser, snippets copy text to clipboard
+#[derive(Clone)]
+pub struct LauncherImport {
+    dirs: Dirs,
+}
+
+struct ImportCandidate {
+    title: String,
+    kind: ImportItemKind,
+    // error is shown to user as a reason why item was skipped
+    action: Result<ImportAction, String>,
+}
+
+enum ImportAction {
+    OpenUrl {
+        url: String,
+        keyword: Option<String>,
+    },
+    CopyText {
+        text: String,
+    },
+}
+
+#[derive(Deserialize)]
+struct RaycastQuicklink {
+    name: String,
+    link: String,
+}
+
+#[derive(Deserialize)]
+struct RaycastSnippet {
+    name: String,
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct AlbertWebSearch {
+    name: String,
+    trigger: Option<String>,
+    url: String,
+}
+
+impl LauncherImport {
+    pub fn new(dirs: Dirs) -> Self {
+        Self {
+            dirs,
+        }
+    }
+
+    pub fn import(&self, source: ImportSource, path: &Path, dry_run: bool) -> anyhow::Result<Vec<ImportItem>> {
+        if cfg!(windows) {
+            return Err(anyhow!("importing from other launchers is only supported on Linux and macOS"))
+        }
+
+        let candidates = match source {
+            ImportSource::RaycastQuicklinks => read_raycast_quicklinks(path)?,
+            ImportSource::RaycastSnippets => read_raycast_snippets(path)?,
+            ImportSource::AlfredWebSearches => read_alfred_web_searches(path)?,
+            ImportSource::AlbertWebSearches => read_albert_web_searches(path)?,
+            ImportSource::AlbertSnippets => read_albert_snippets(path)?,
+        };
+
+        let scripts_dir = self.dirs.script_commands_dir();
+
+        if !dry_run {
+            fs::create_dir_all(&scripts_dir)
+                .context("unable to create script commands directory")?;
+        }
+
+        let prefix = match source {
+            ImportSource::RaycastQuicklinks | ImportSource::RaycastSnippets => "raycast",
+            ImportSource::AlfredWebSearches => "alfred",
+            ImportSource::AlbertWebSearches | ImportSource::AlbertSnippets => "albert",
+        };
+
+        let source_name = match source {
+            ImportSource::RaycastQuicklinks | ImportSource::RaycastSnippets => "Raycast",
+            ImportSource::AlfredWebSearches => "Alfred",
+            ImportSource::AlbertWebSearches | ImportSource::AlbertSnippets => "Albert",
+        };
+
+        let mut used_file_names = HashSet::new();
+        let mut items = vec![];
+
+        for candidate in candidates {
+            let file_name = unique_file_name(prefix, &candidate.title, &mut used_file_names);
+            let script_path = scripts_dir.join(&file_name);
+
+            let script = candidate.action
+                .and_then(|action| {
+                    // file names are stable, so importing the same file again doesn't duplicate scripts
+                    if script_path.exists() {
+                        return Err("already imported".to_string())
+                    }
+
+                    script_content(&candidate.title, source_name, action)
+                });
+
+            let skip_reason = match script {
+                Ok(script) => {
+                    if !dry_run {
+                        write_script(&script_path, &script)?;
+                    }
+
+                    None
+                }
+                Err(reason) => Some(reason),
+            };
+
+            items.push(ImportItem {
+                title: candidate.title,
+                kind: candidate.kind,
+                skip_reason,
+            })
+        }
+
+        let imported = items.iter().filter(|item| item.skip_reason.is_none()).count();
+
+        tracing::info!("{} {} of {} items from {:?}", if dry_run { "Would import" } else { "Imported" }, imported, items.len(), path);
+
+        Ok(items)
+    }
+}
+
+// exported using "Export Quicklinks" command in Raycast
+fn read_raycast_quicklinks(path: &Path) -> anyhow::Result<Vec<ImportCandidate>> {
+    let quicklinks: Vec<RaycastQuicklink> = read_json(path)?;
+
+    let candidates = quicklinks.into_iter()
+        .map(|quicklink| {
+            let action = raycast_url(&quicklink.link)
+                .map(|url| {
+                    let keyword = url.contains(QUERY_PLACEHOLDER)
+                        .then(|| keyword_from_title(&quicklink.name));
+
+                    ImportAction::OpenUrl { url, keyword }
+                });
+
+            ImportCandidate {
+                title: quicklink.name,
+                kind: ImportItemKind::Quicklink,
+                action,
+            }
+        })
+        .collect();
+
+    Ok(candidates)
+}
+
+// exported using "Export Snippets" command in Raycast
+fn read_raycast_snippets(path: &Path) -> anyhow::Result<Vec<ImportCandidate>> {
+    let snippets: Vec<RaycastSnippet> = read_json(path)?;
+
+    let candidates = snippets.into_iter()
+        .map(|snippet| {
+            let action = raycast_text(&snippet.text)
+                .map(|text| ImportAction::CopyText { text });
+
+            ImportCandidate {
+                title: snippet.name,
+                kind: ImportItemKind::Snippet,
+                action,
+            }
+        })
+        .collect();
+
+    Ok(candidates)
+}
+
+// path is Alfred.alfredpreferences directory, custom web searches are read from preferences
+// and web searches defined in workflows as keyword input connected to "open url" action
+fn read_alfred_web_searches(path: &Path) -> anyhow::Result<Vec<ImportCandidate>> {
+    let mut candidates = vec![];
+
+    let prefs_path = path.join("preferences").join("features").join("websearch").join("prefs.plist");
+
+    if prefs_path.exists() {
+        let prefs = read_plist(&prefs_path)?;
+
+        let sites = prefs.get("customSites")
+            .and_then(|sites| sites.as_object())
+            .map(|sites| sites.values().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for site in sites {
+            let keyword = plist_string(site, "keyword");
+            let url = plist_string(site, "url");
+
+            let title = plist_string(site, "text")
+                .map(|text| alfred_title(&text))
+                .or_else(|| keyword.clone())
+                .unwrap_or_else(|| "Web Search".to_string());
+
+            let enabled = site.get("enabled")
+                .and_then(|enabled| enabled.as_bool())
+                .unwrap_or(true);
+
+            let action = match url {
+                None => Err("web search doesn't have url".to_string()),
+                Some(_) if !enabled => Err("disabled in Alfred".to_string()),
+                Some(url) => Ok(ImportAction::OpenUrl { url, keyword: keyword.map(|keyword| keyword.to_lowercase()) })
+            };
+
+            candidates.push(ImportCandidate {
+                title,
+                kind: ImportItemKind::WebSearch,
+                action,
+            })
+        }
+    }
+
+    let workflows_dir = path.join("workflows");
+
+    if workflows_dir.exists() {
+        let mut workflow_paths: Vec<PathBuf> = fs::read_dir(&workflows_dir)
+            .context("unable to read Alfred workflows directory")?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path().join("info.plist"))
+            .filter(|path| path.exists())
+            .collect();
+
+        workflow_paths.sort();
+
+        for workflow_path in workflow_paths {
+            // one broken workflow shouldn't prevent importing the rest
+            match read_alfred_workflow(&workflow_path) {
+                Ok(workflow_candidates) => candidates.extend(workflow_candidates),
+                Err(err) => tracing::warn!("unable to read Alfred workflow {:?}: {:?}", workflow_path, err),
+            }
+        }
+    }
+
+    if candidates.is_empty() && !prefs_path.exists() && !workflows_dir.exists() {
+        return Err(anyhow!("{:?} doesn't look like Alfred.alfredpreferences directory", path))
+    }
+
+    Ok(candidates)
+}
+
+fn read_alfred_workflow(path: &Path) -> anyhow::Result<Vec<ImportCandidate>> {
+    let workflow = read_plist(path)?;
+
+    let workflow_name = plist_string(&workflow, "name");
+
+    let objects = workflow.get("objects")
+        .and_then(|objects| objects.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    let object_with_uid = |uid: &str| {
+        objects.iter()
+            .find(|object| plist_string(object, "uid").as_deref() == Some(uid))
+    };
+
+    let mut candidates = vec![];
+
+    for object in &objects {
+        if plist_string(object, "type").as_deref() != Some("alfred.workflow.input.keyword") {
+            continue
+        }
+
+        let Some(uid) = plist_string(object, "uid") else {
+            continue
+        };
+
+        let config = object.get("config").cloned().unwrap_or(Value::Null);
+
+        let Some(keyword) = plist_string(&config, "keyword") else {
+            continue
+        };
+
+        let destinations = workflow.get("connections")
+            .and_then(|connections| connections.get(&uid))
+            .and_then(|connections| connections.as_array())
+            .cloned()
+            .unwrap_or_default();
+
+        let urls = destinations.iter()
+            .filter_map(|connection| plist_string(connection, "destinationuid"))
+            .filter_map(|destination| object_with_uid(&destination))
+            .filter(|destination| plist_string(destination, "type").as_deref() == Some("alfred.workflow.action.openurl"))
+            .filter_map(|destination| destination.get("config").and_then(|config| plist_string(config, "url")));
+
+        for url in urls {
+            let title = plist_string(&config, "text")
+                .or_else(|| plist_string(&config, "title"))
+                .or_else(|| workflow_name.clone())
+                .map(|title| alfred_title(&title))
+                .unwrap_or_else(|| keyword.clone());
+
+            candidates.push(ImportCandidate {
+                title,
+                kind: ImportItemKind::WebSearch,
+                action: Ok(ImportAction::OpenUrl { url, keyword: Some(keyword.to_lowercase()) }),
+            })
+        }
+    }
+
+    Ok(candidates)
+}
+
+// engines.json from websearch extension config directory, e.g. ~/.config/albert/websearch/engines.json
+fn read_albert_web_searches(path: &Path) -> anyhow::Result<Vec<ImportCandidate>> {
+    let web_searches: Vec<AlbertWebSearch> = read_json(path)?;
+
+    let candidates = web_searches.into_iter()
+        .map(|web_search| {
+            let keyword = web_search.trigger
+                .as_deref()
+                .and_then(|trigger| trigger.split_whitespace().next())
+                .map(|trigger| trigger.to_lowercase())
+                .unwrap_or_else(|| keyword_from_title(&web_search.name));
+
+            let url = web_search.url.replace("%s", QUERY_PLACEHOLDER);
+
+            ImportCandidate {
+                title: web_search.name,
+                kind: ImportItemKind::WebSearch,
+                action: Ok(ImportAction::OpenUrl { url, keyword: Some(keyword) }),
+            }
+        })
+        .collect();
+
+    Ok(candidates)
+}
+
+// snippets extension keeps every snippet as a separate text file, file name is the title
+fn read_albert_snippets(path: &Path) -> anyhow::Result<Vec<ImportCandidate>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(path)
+        .with_context(|| format!("unable to read Albert snippets directory {:?}", path))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect();
+
+    paths.sort();
+
+    let candidates = paths.into_iter()
+        .filter_map(|path| {
+            let title = path.file_stem()?.to_str()?.to_string();
+
+            let action = fs::read_to_string(&path)
+                .map(|text| ImportAction::CopyText { text })
+                .map_err(|_| "not a text file".to_string());
+
+            Some(ImportCandidate {
+                title,
+                kind: ImportItemKind::Snippet,
+                action,
+            })
+        })
+        .collect();
+
+    Ok(candidates)
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> anyhow::Result<T> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("unable to read {:?}", path))?;
+
+    serde_json::from_str(&content)
+        .with_context(|| format!("unable to parse {:?}", path))
+}
+
+fn raycast_url(link: &str) -> Result<String, String> {
+    let mut unsupported = None;
+
+    let url = RAYCAST_PLACEHOLDER.replace_all(link, |captures: &regex::Captures| {
+        match &captures[1] {
+            "argument" | "query" => QUERY_PLACEHOLDER.to_string(),
+            name => {
+                unsupported.get_or_insert_with(|| name.to_string());
+                captures[0].to_string()
+            }
+        }
+    });
+
+    match unsupported {
+        Some(name) => Err(format!("uses unsupported {{{}}} placeholder", name)),
+        None => Ok(url.to_string()),
+    }
+}
+
+fn raycast_text(text: &str) -> Result<String, String> {
+    let mut unsupported = None;
+
+    let text = RAYCAST_PLACEHOLDER.replace_all(text, |captures: &regex::Captures| {
+        match &captures[1] {
+            "cursor" => "".to_string(),
+            name => {
+                unsupported.get_or_insert_with(|| name.to_string());
+                captures[0].to_string()
+            }
+        }
+    });
+
+    match unsupported {
+        Some(name) => Err(format!("uses unsupported {{{}}} placeholder", name)),
+        None => Ok(text.to_string()),
+    }
+}
+
+// alfred titles usually contain the query, e.g. "Search GitHub for '{query}'"
+fn alfred_title(text: &str) -> String {
+    text.replace("'{query}'", "")
+        .replace("\"{query}\"", "")
+        .replace(QUERY_PLACEHOLDER, "")
+        .trim()
+        .to_string()
+}
+
+fn keyword_from_title(title: &str) -> String {
+    slug(title)
+}
+
+fn unique_file_name(prefix: &str, title: &str, used_file_names: &mut HashSet<String>) -> String {
+    let base = format!("{}-{}", prefix, slug(title));
+
+    let mut file_name = format!("{}.sh", base);
+    let mut index = 2;
+
+    while !used_file_names.insert(file_name.clone()) {
+        file_name = format!("{}-{}.sh", base, index);
+        index += 1;
+    }
+
+    file_name
+}
+
+fn slug(text: &str) -> String {
+    let slug = text.to_lowercase()
+        .split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    if slug.is_empty() {
+        "item".to_string()
+    } else {
+        slug
+    }
+}
+
+fn script_content(title: &str, source_name: &str, action: ImportAction) -> Result<String, String> {
+    // header value has to fit on one line
+    let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let mut script = format!(
+        "#!/bin/sh\n# @gauntlet.title {}\n# @gauntlet.description Imported from {}\n",
+        title,
+        source_name
+    );
+
+    match action {
+        ImportAction::OpenUrl { url, keyword } => {
+            let open_command = if cfg!(target_os = "macos") { "open" } else { "xdg-open" };
+
+            script.push_str("# @gauntlet.mode silent\n");
+
+            if url.contains(QUERY_PLACEHOLDER) {
+                let Some(keyword) = keyword else {
+                    return Err("url requires a query, but there is no keyword to type it after".to_string())
+                };
+
+                script.push_str(&format!("# @gauntlet.keyword {}\n", keyword));
+                script.push_str(URL_ENCODE_FUNCTION);
+
+                let url = url.split(QUERY_PLACEHOLDER)
+                    .map(|part| shell_quote(part))
+                    .collect::<Vec<_>>()
+                    .join("\"$(urlencode \"$1\")\"");
+
+                script.push_str(&format!("\nexec {} {}\n", open_command, url));
+            } else {
+                script.push_str(&format!("\nexec {} {}\n", open_command, shell_quote(&url)));
+            }
+        }
+        ImportAction::CopyText { text } => {
+            if text.lines().any(|line| line == SNIPPET_DELIMITER) {
+                return Err(format!("text contains '{}' line", SNIPPET_DELIMITER))
+            }
+
+            script.push_str("# @gauntlet.mode clipboard\n");
+            script.push_str(&format!("\ncat <<'{}'\n{}", SNIPPET_DELIMITER, text));
+
+            if !text.ends_with('\n') {
+                script.push('\n');
+            }
+
+            script.push_str(SNIPPET_DELIMITER);
+            script.push('\n');
+        }
+    }
+
+    Ok(script)
+}
+
+const URL_ENCODE_FUNCTION: &str = r#"
+urlencode() {
+    LC_ALL=C
+    string=$1
+    while [ -n "$string" ]; do
+        rest=${string#?}
+        char=${string%"$rest"}
+        case $char in
+            [a-zA-Z0-9.~_-]) printf '%s' "$char" ;;
+            *) printf '%%%02X' "'$char" ;;
+        esac
+        string=$rest
+    done
+}
+"#;
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+fn write_script(path: &Path, script: &str) -> anyhow::Result<()> {
+    fs::write(path, script)
+        .with_context(|| format!("unable to write script command {:?}", path))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+    }
+
+    Ok(())
+}
+
+fn plist_string(value: &Value, key: &str) -> Option<String> {
+    value.get(key)
+        .and_then(|value| value.as_str())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+// alfred writes preferences as xml property lists, only what is needed for them is supported
+fn read_plist(path: &Path) -> anyhow::Result<Value> {
+    let content = fs::read(path)
+        .with_context(|| format!("unable to read {:?}", path))?;
+
+    if content.starts_with(b"bplist") {
+        return Err(anyhow!("{:?} is a binary property list, convert it using 'plutil -convert xml1'", path))
+    }
+
+    let content = String::from_utf8(content)
+        .with_context(|| format!("{:?} is not a text file", path))?;
+
+    let mut reader = PlistReader {
+        content: &content,
+        position: 0,
+    };
+
+    loop {
+        match reader.next_tag()? {
+            Some(PlistTag::Open("plist")) => break,
+            Some(_) => continue,
+            None => return Err(anyhow!("{:?} is not a property list", path)),
+        }
+    }
+
+    let tag = reader.next_tag()?
+        .ok_or(anyhow!("{:?} is empty property list", path))?;
+
+    reader.value(tag)
+        .with_context(|| format!("unable to parse {:?}", path))
+}
+
+#[derive(Debug)]
+enum PlistTag<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    Empty(&'a str),
+}
+
+struct PlistReader<'a> {
+    content: &'a str,
+    position: usize,
+}
+
+impl<'a> PlistReader<'a> {
+    fn next_tag(&mut self) -> anyhow::Result<Option<PlistTag<'a>>> {
+        let content = self.content;
+
+        loop {
+            let Some(start) = content[self.position..].find('<') else {
+                return Ok(None)
+            };
+
+            let start = self.position + start;
+            let rest = &content[start..];
+
+            if rest.starts_with("<!--") {
+                let end = rest.find("-->").ok_or(anyhow!("unterminated comment"))?;
+                self.position = start + end + 3;
+                continue
+            }
+
+            let end = rest.find('>').ok_or(anyhow!("unterminated tag"))?;
+            let tag = &rest[1..end];
+
+            self.position = start + end + 1;
+
+            // xml declaration and doctype
+            if tag.starts_with('?') || tag.starts_with('!') {
+                continue
+            }
+
+            let name = |tag: &'a str| tag.split_whitespace().next().unwrap_or("");
+
+            let tag = if let Some(tag) = tag.strip_prefix('/') {
+                PlistTag::Close(name(tag))
+            } else if let Some(tag) = tag.strip_suffix('/') {
+                PlistTag::Empty(name(tag))
+            } else {
+                PlistTag::Open(name(tag))
+            };
+
+            return Ok(Some(tag))
+        }
+    }
+
+    fn text(&mut self, name: &str) -> anyhow::Result<String> {
+        let closing = format!("</{}>", name);
+
+        let end = self.content[self.position..]
+            .find(&closing)
+            .ok_or(anyhow!("unterminated <{}>", name))?;
+
+        let text = &self.content[self.position..self.position + end];
+
+        self.position += end + closing.len();
+
+        Ok(unescape_xml(text))
+    }
+
+    fn value(&mut self, tag: PlistTag<'a>) -> anyhow::Result<Value> {
+        let value = match tag {
+            PlistTag::Empty("true") => Value::Bool(true),
+            PlistTag::Empty("false") => Value::Bool(false),
+            PlistTag::Empty("string" | "data" | "date") => Value::String("".to_string()),
+            PlistTag::Empty("dict") => Value::Object(Map::new()),
+            PlistTag::Empty("array") => Value::Array(vec![]),
+            PlistTag::Open(name @ ("string" | "data" | "date")) => Value::String(self.text(name)?),
+            PlistTag::Open("integer") => {
+                let text = self.text("integer")?;
+                let value: i64 = text.trim().parse().with_context(|| format!("invalid integer '{}'", text))?;
+
+                Value::Number(Number::from(value))
+            }
+            PlistTag::Open("real") => {
+                let text = self.text("real")?;
+                let value: f64 = text.trim().parse().with_context(|| format!("invalid real '{}'", text))?;
+
+                Number::from_f64(value)
+                    .map(Value::Number)
+                    .unwrap_or(Value::Null)
+            }
+            PlistTag::Open("dict") => {
+                let mut map = Map::new();
+
+                loop {
+                    match self.next_tag()? {
+                        Some(PlistTag::Close("dict")) => break,
+                        Some(PlistTag::Open("key")) => {
+                            let key = self.text("key")?;
+
+                            let tag = self.next_tag()?
+                                .ok_or(anyhow!("value for key '{}' is missing", key))?;
+
+                            map.insert(key, self.value(tag)?);
+                        }
+                        Some(tag) => return Err(anyhow!("unexpected tag in dict: {:?}", tag)),
+                        None => return Err(anyhow!("unterminated <dict>")),
+                    }
+                }
+
+                Value::Object(map)
+            }
+            PlistTag::Open("array") => {
+                let mut array = vec![];
+
+                loop {
+                    match self.next_tag()? {
+                        Some(PlistTag::Close("array")) => break,
+                        Some(tag) => array.push(self.value(tag)?),
+                        None => return Err(anyhow!("unterminated <array>")),
+                    }
+                }
+
+                Value::Array(array)
+            }
+            tag => return Err(anyhow!("unexpected tag: {:?}", tag)),
+        };
+
+        Ok(value)
+    }
+}
+
+fn unescape_xml(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(';') else {
+            break
+        };
+
+        let entity = &rest[1..end];
+
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                entity.strip_prefix("#x")
+                    .map(|hex| u32::from_str_radix(hex, 16).ok())
+                    .unwrap_or_else(|| entity.strip_prefix('#').and_then(|decimal| decimal.parse().ok()))
+                    .and_then(char::from_u32)
+            }
+        };
+
+        match decoded {
+            Some(decoded) => {
+                result.push(decoded);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+
+    result
+}
@@ -13,11 +13,61 @@ use walkdir::WalkDir;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use typed_path::{TypedPathBuf, Utf8TypedPath, Utf8UnixComponent, Utf8WindowsComponent, Utf8WindowsPrefix, Utf8WindowsPrefixComponent};
+use gauntlet_common::error::{ErrorCode, GauntletError};
+use gauntlet_common::i18n::is_valid_locale;
 use gauntlet_common::model::{DownloadStatus, PluginId};
-use gauntlet_plugin_runtime::PERMISSIONS_VARIABLE_PATTERN;
+use gauntlet_plugin_runtime::{PERMISSIONS_VARIABLE_PATTERN, WASM_COMPONENT_ASSET};
 use crate::model::ActionShortcutKey;
-use crate::plugins::data_db_repository::{DataDbRepository, db_entrypoint_to_str, db_plugin_type_to_str, DbCode, DbPluginAction, DbPluginActionShortcutKind, DbPluginEntrypointType, DbPluginPermissions, DbPluginPreference, DbPluginPreferenceUserData, DbPluginType, DbPreferenceEnumValue, DbWritePlugin, DbWritePluginAssetData, DbWritePluginEntrypoint, DbPluginClipboardPermissions, DbPluginMainSearchBarPermissions, DbPluginPermissionsFileSystem, DbPluginPermissionsExec};
+use crate::plugins::data_db_repository::{DataDbRepository, db_entrypoint_to_str, db_plugin_type_to_str, DbCode, DbPluginAction, DbPluginActionShortcutKind, DbPluginArgument, DbPluginArgumentType, DbPluginEntrypointType, DbPluginPermissions, DbPluginPreference, DbPluginPreferenceUserData, DbPluginType, DbPreferenceEnumValue, DbWritePlugin, DbWritePluginAssetData, DbWritePluginEntrypoint, DbPluginClipboardPermissions, DbPluginMainSearchBarPermissions, DbPluginActiveWindowPermissions, DbPluginPermissionsFileSystem, DbPluginPermissionsExec, DbPluginPermissionsSecrets, DbPluginPermissionsEvents, DbPluginEntrypointTranslation, DbPluginStyle, DbPluginRuntime};
 use crate::plugins::download_status::DownloadStatusHolder;
+use crate::plugins::environment_variables::validate_environment_variables;
+
+// shown in settings window with guidance how to fix the plugin
+fn invalid_plugin_error(plugin_id: &PluginId, err: anyhow::Error) -> anyhow::Error {
+    GauntletError::new(ErrorCode::InvalidPlugin, format!("Unable to read plugin: {}", plugin_id))
+        .with_plugin_id(plugin_id.clone())
+        .with_cause(&err)
+        .into()
+}
+
+// asset paths come from plugin code and manifest, they are resolved relative to assets directory
+// and are not allowed to point outside of it. stored asset paths use platform separator
+pub fn normalize_asset_path(path: &str) -> anyhow::Result<String> {
+    if path.starts_with('/') || path.starts_with('\\') || path.contains(':') {
+        return Err(anyhow!("Asset path has to be relative to assets directory: {:?}", path))
+    }
+
+    let mut components = vec![];
+
+    for component in path.split(['/', '\\']) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                if components.pop().is_none() {
+                    return Err(anyhow!("Asset path points outside of assets directory: {:?}", path))
+                }
+            }
+            component => components.push(component),
+        }
+    }
+
+    if components.is_empty() {
+        return Err(anyhow!("Asset path doesn't point to a file: {:?}", path))
+    }
+
+    Ok(components.join(std::path::MAIN_SEPARATOR_STR))
+}
+
+const MAX_STYLE_CONTENT_SPACING: f32 = 32.0;
+
+// same formats as accepted by client when parsing colors of color swatch and charts
+fn is_valid_hex_color(value: &str) -> bool {
+    let Some(hex) = value.strip_prefix('#') else {
+        return false
+    };
+
+    matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|char| char.is_ascii_hexdigit())
+}
 
 pub struct PluginLoader {
     db_repository: DataDbRepository,
@@ -57,6 +107,8 @@ impl PluginLoader {
                     id: plugin_data.id,
                     name: plugin_data.name,
                     description: plugin_data.description,
+                    keyword: plugin_data.keyword,
+                    interactive: plugin_data.interactive,
                     enabled: false,
                     code: plugin_data.code,
                     entrypoints: plugin_data.entrypoints,
@@ -93,12 +145,14 @@ impl PluginLoader {
 
         let plugin_data = PluginLoader::read_plugin_dir(&plugin_dir, plugin_id.clone())
             .await
-            .context(format!("Unable to read plugin: {}", &plugin_id.to_string()))?;
+            .map_err(|err| invalid_plugin_error(&plugin_id, err))?;
 
         self.db_repository.save_plugin(DbWritePlugin {
             id: plugin_data.id,
             name: plugin_data.name,
             description: plugin_data.description,
+            keyword: plugin_data.keyword,
+            interactive: plugin_data.interactive,
             enabled: true,
             code: plugin_data.code,
             entrypoints: plugin_data.entrypoints,
@@ -113,18 +167,16 @@ impl PluginLoader {
 
     pub async fn save_bundled_plugin(&self, id: &str, dir: &Dir<'_>) -> anyhow::Result<PluginId> {
         let plugin_id = PluginId::from_string(format!("bundled://{id}"));
-        let temp_dir = tempfile::tempdir()?;
 
-        dir.extract(&temp_dir)?;
-
-        let plugin_data = PluginLoader::read_plugin_dir(temp_dir.path(), plugin_id.clone())
-            .await
-            .context(format!("Unable to read plugin: {}", &plugin_id.to_string()))?;
+        let plugin_data = PluginLoader::read_bundled_plugin_dir(dir, plugin_id.clone())
+            .map_err(|err| invalid_plugin_error(&plugin_id, err))?;
 
         self.db_repository.save_plugin(DbWritePlugin {
             id: plugin_data.id,
             name: plugin_data.name,
             description: plugin_data.description,
+            keyword: plugin_data.keyword,
+            interactive: plugin_data.interactive,
             enabled: true,
             code: plugin_data.code,
             entrypoints: plugin_data.entrypoints,
@@ -152,7 +204,13 @@ impl PluginLoader {
         let assets = plugin_dir.join("assets");
 
         let js_dir_context = js_dir.display().to_string();
-        let js_files = std::fs::read_dir(js_dir).context(js_dir_context)?;
+
+        // plugins using wasm runtime don't have js directory
+        let js_files: Vec<_> = match std::fs::read_dir(js_dir) {
+            Ok(js_files) => js_files.collect(),
+            Err(err) if matches!(err.kind(), ErrorKind::NotFound) => vec![],
+            Err(err) => return Err(err).context(js_dir_context),
+        };
 
         let js: HashMap<_, _> = js_files.into_iter()
             .collect::<std::io::Result<Vec<DirEntry>>>()
@@ -211,7 +269,79 @@ impl PluginLoader {
         let plugin_manifest_path = plugin_dir.join("gauntlet.toml");
         let plugin_manifest_path_context = plugin_manifest_path.display().to_string();
         let plugin_manifest_content = std::fs::read_to_string(plugin_manifest_path).context(plugin_manifest_path_context)?;
-        let plugin_manifest: PluginManifest = toml::from_str(&plugin_manifest_content)
+
+        Self::read_plugin_data(plugin_id, js, asset_data, &plugin_manifest_content)
+    }
+
+    // bundled plugins are embedded into binary, so they are read directly from it without touching the disk
+    fn read_bundled_plugin_dir(plugin_dir: &Dir, plugin_id: PluginId) -> anyhow::Result<PluginDownloadData> {
+        let js_dir = plugin_dir.get_dir("js")
+            .ok_or(anyhow!("Bundled plugin doesn't have js directory"))?;
+
+        let js = js_dir.files()
+            .filter(|file| file.path().extension() == Some(OsStr::new("js")))
+            .map(|file| {
+                let path = file.path();
+
+                let js_content = file.contents_utf8()
+                    .ok_or(anyhow!("js file is not a valid utf-8: {:?}", path))?
+                    .to_owned();
+
+                let id = path.file_stem()
+                    .expect("embedded file doesn't have filename?")
+                    .to_str()
+                    .ok_or(anyhow!("filename is not a valid utf-8"))?
+                    .to_owned();
+
+                Ok((id, js_content))
+            })
+            .collect::<anyhow::Result<HashMap<_, _>>>()
+            .context("Unable to read bundled plugin js data")?;
+
+        let mut asset_data = vec![];
+
+        if let Some(assets) = plugin_dir.get_dir("assets") {
+            Self::collect_bundled_assets(assets, assets.path(), &mut asset_data)
+                .context("Unable to read bundled plugin asset data")?;
+        }
+
+        let plugin_manifest_content = plugin_dir.get_file("gauntlet.toml")
+            .ok_or(anyhow!("Bundled plugin doesn't have gauntlet.toml"))?
+            .contents_utf8()
+            .ok_or(anyhow!("gauntlet.toml is not a valid utf-8"))?;
+
+        Self::read_plugin_data(plugin_id, js, asset_data, plugin_manifest_content)
+    }
+
+    fn collect_bundled_assets(dir: &Dir, assets: &Path, result: &mut Vec<DbWritePluginAssetData>) -> anyhow::Result<()> {
+        for file in dir.files() {
+            let path = file.path()
+                .strip_prefix(assets)
+                .expect("assets is a base of embedded file path")
+                .to_str()
+                .ok_or(anyhow!("filename is not a valid utf-8"))?
+                .to_owned();
+
+            result.push(DbWritePluginAssetData {
+                path,
+                data: file.contents().to_vec(),
+            });
+        }
+
+        for dir in dir.dirs() {
+            Self::collect_bundled_assets(dir, assets, result)?;
+        }
+
+        Ok(())
+    }
+
+    fn read_plugin_data(
+        plugin_id: PluginId,
+        js: HashMap<String, String>,
+        asset_data: Vec<DbWritePluginAssetData>,
+        plugin_manifest_content: &str
+    ) -> anyhow::Result<PluginDownloadData> {
+        let plugin_manifest: PluginManifest = toml::from_str(plugin_manifest_content)
             .context("Unable to read plugin manifest")?;
 
         tracing::debug!("Plugin config read: {:?}", plugin_manifest);
@@ -220,6 +350,26 @@ impl PluginLoader {
 
         let plugin_name = plugin_manifest.gauntlet.name;
         let plugin_description = plugin_manifest.gauntlet.description;
+        let plugin_keyword = plugin_manifest.gauntlet.keyword;
+        let plugin_interactive = plugin_manifest.gauntlet.interactive;
+        let plugin_version = plugin_manifest.gauntlet.version;
+
+        let plugin_runtime = match plugin_manifest.gauntlet.runtime {
+            PluginManifestRuntime::Deno => {
+                if js.is_empty() {
+                    return Err(anyhow!("Plugin doesn't have any js files"))
+                }
+
+                DbPluginRuntime::Deno
+            }
+            PluginManifestRuntime::Wasm => {
+                if !asset_data.iter().any(|asset| asset.path == WASM_COMPONENT_ASSET) {
+                    return Err(anyhow!("Plugin using wasm runtime doesn't have {} in assets directory", WASM_COMPONENT_ASSET))
+                }
+
+                DbPluginRuntime::Wasm
+            }
+        };
 
         let entrypoints: Vec<_> = plugin_manifest.entrypoint
             .into_iter()
@@ -236,27 +386,7 @@ impl PluginLoader {
                 }).to_owned(),
                 preferences: entrypoint.preferences
                     .into_iter()
-                    .map(|preference| match preference {
-                        PluginManifestPreference::Number { id, name, default, description } => (id, DbPluginPreference::Number { name: Some(name), default, description }),
-                        PluginManifestPreference::String { id, name, default, description } => (id, DbPluginPreference::String { name: Some(name), default, description }),
-                        PluginManifestPreference::Enum { id, name, default, description, enum_values } => {
-                            let enum_values = enum_values.into_iter()
-                                .map(|PluginManifestPreferenceEnumValue { label, value } | DbPreferenceEnumValue { label, value })
-                                .collect();
-
-                            (id, DbPluginPreference::Enum { name: Some(name), default, description, enum_values })
-                        },
-                        PluginManifestPreference::Bool { id, name, default, description } => (id, DbPluginPreference::Bool { name: Some(name), default, description }),
-                        PluginManifestPreference::ListOfStrings { id, name, description } => (id, DbPluginPreference::ListOfStrings { name: Some(name), default: None, description }),
-                        PluginManifestPreference::ListOfNumbers { id, name, description } => (id, DbPluginPreference::ListOfNumbers { name: Some(name), default: None, description }),
-                        PluginManifestPreference::ListOfEnums { id, name, description, enum_values } => {
-                            let enum_values = enum_values.into_iter()
-                                .map(|PluginManifestPreferenceEnumValue { label, value } | DbPreferenceEnumValue { label, value })
-                                .collect();
-
-                            (id, DbPluginPreference::ListOfEnums { name: Some(name), default: None, description, enum_values })
-                        },
-                    })
+                    .map(|preference| Self::preference_to_db(preference))
                     .collect(),
                 actions: entrypoint.actions.into_iter()
                     .map(|action| DbPluginAction {
@@ -269,32 +399,45 @@ impl PluginLoader {
                         },
                     })
                     .collect(),
+                arguments: entrypoint.arguments.into_iter()
+                    .map(|argument| {
+                        let (id, name, optional, hint, argument_type) = match argument {
+                            PluginManifestArgument::String { id, name, optional, hint, max_length } => {
+                                (id, name, optional, hint, DbPluginArgumentType::String { max_length })
+                            },
+                            PluginManifestArgument::Number { id, name, optional, hint, min, max } => {
+                                (id, name, optional, hint, DbPluginArgumentType::Number { min, max })
+                            },
+                            PluginManifestArgument::Enum { id, name, optional, hint, enum_values } => {
+                                let enum_values = enum_values.into_iter()
+                                    .map(|PluginManifestPreferenceEnumValue { label, value } | DbPreferenceEnumValue { label, value })
+                                    .collect();
+
+                                (id, name, optional, hint, DbPluginArgumentType::Enum { enum_values })
+                            },
+                        };
+
+                        DbPluginArgument {
+                            id,
+                            name,
+                            optional,
+                            hint,
+                            argument_type,
+                        }
+                    })
+                    .collect(),
+                fallback: entrypoint.fallback,
+                priority: entrypoint.priority.unwrap_or(0),
+                translations: entrypoint.translations.into_iter()
+                    .map(|(locale, translation)| (locale, DbPluginEntrypointTranslation { name: translation.name, description: translation.description }))
+                    .collect(),
+                cache_view: entrypoint.cache_view,
             })
             .collect();
 
         let plugin_preferences = plugin_manifest.preferences
             .into_iter()
-            .map(|preference| match preference {
-                PluginManifestPreference::Number { id, name, default, description } => (id, DbPluginPreference::Number { name: Some(name), default, description }),
-                PluginManifestPreference::String { id, name, default, description } => (id, DbPluginPreference::String { name: Some(name), default, description }),
-                PluginManifestPreference::Enum { id, name, default, description, enum_values } => {
-                    let enum_values = enum_values.into_iter()
-                        .map(|PluginManifestPreferenceEnumValue { label, value } | DbPreferenceEnumValue { label, value })
-                        .collect();
-
-                    (id, DbPluginPreference::Enum { name: Some(name), default, description, enum_values })
-                },
-                PluginManifestPreference::Bool { id, name, default, description } => (id, DbPluginPreference::Bool { name: Some(name), default, description }),
-                PluginManifestPreference::ListOfStrings { id, name, description } => (id, DbPluginPreference::ListOfStrings { name: Some(name), default: None, description }),
-                PluginManifestPreference::ListOfNumbers { id, name, description } => (id, DbPluginPreference::ListOfNumbers { name: Some(name), default: None, description }),
-                PluginManifestPreference::ListOfEnums { id, name, description, enum_values } => {
-                    let enum_values = enum_values.into_iter()
-                        .map(|PluginManifestPreferenceEnumValue { label, value } | DbPreferenceEnumValue { label, value })
-                        .collect();
-
-                    (id, DbPluginPreference::ListOfEnums { name: Some(name), default: None, description, enum_values })
-                },
-            })
+            .map(|preference| Self::preference_to_db(preference))
             .collect();
 
         let clipboard = plugin_manifest.permissions
@@ -319,6 +462,16 @@ impl PluginLoader {
             })
             .collect();
 
+        let active_window = plugin_manifest.permissions
+            .active_window
+            .into_iter()
+            .map(|permission| {
+                match permission {
+                    PluginManifestActiveWindowPermissions::Read => DbPluginActiveWindowPermissions::Read,
+                }
+            })
+            .collect();
+
         let permissions = DbPluginPermissions {
             environment: plugin_manifest.permissions.environment,
             network: plugin_manifest.permissions.network,
@@ -333,14 +486,36 @@ impl PluginLoader {
             system: plugin_manifest.permissions.system,
             clipboard,
             main_search_bar,
+            active_window,
+            secrets: DbPluginPermissionsSecrets {
+                read: plugin_manifest.permissions.secrets.read,
+            },
+            events: DbPluginPermissionsEvents {
+                publish: plugin_manifest.permissions.events.publish,
+                subscribe: plugin_manifest.permissions.events.subscribe,
+            },
         };
 
         Ok(PluginDownloadData {
             id: plugin_id.to_string(),
             name: plugin_name,
             description: plugin_description,
+            keyword: plugin_keyword,
+            interactive: plugin_interactive,
             code: DbCode {
-                js
+                js,
+                version: plugin_version,
+                style: DbPluginStyle {
+                    accent_color: plugin_manifest.style.accent_color,
+                    muted_color: plugin_manifest.style.muted_color,
+                    success_color: plugin_manifest.style.success_color,
+                    warning_color: plugin_manifest.style.warning_color,
+                    danger_color: plugin_manifest.style.danger_color,
+                    link_color: plugin_manifest.style.link_color,
+                    content_spacing: plugin_manifest.style.content_spacing,
+                },
+                environment_variables: plugin_manifest.environment_variables,
+                runtime: plugin_runtime,
             },
             entrypoints,
             asset_data,
@@ -350,6 +525,36 @@ impl PluginLoader {
         })
     }
 
+    fn preference_to_db(preference: PluginManifestPreference) -> (String, DbPluginPreference) {
+        match preference {
+            PluginManifestPreference::Number { id, name, default, min, max, description } => (id, DbPluginPreference::Number { name: Some(name), default, min, max, step: None, description }),
+            PluginManifestPreference::Slider { id, name, default, min, max, step, description } => {
+                let step = Some(step.unwrap_or(1.0));
+
+                (id, DbPluginPreference::Number { name: Some(name), default, min: Some(min), max: Some(max), step, description })
+            },
+            PluginManifestPreference::String { id, name, default, description } => (id, DbPluginPreference::String { name: Some(name), default, description }),
+            PluginManifestPreference::Enum { id, name, default, description, enum_values } => {
+                let enum_values = enum_values.into_iter()
+                    .map(|PluginManifestPreferenceEnumValue { label, value } | DbPreferenceEnumValue { label, value })
+                    .collect();
+
+                (id, DbPluginPreference::Enum { name: Some(name), default, description, enum_values })
+            },
+            PluginManifestPreference::Bool { id, name, default, description } => (id, DbPluginPreference::Bool { name: Some(name), default, description }),
+            PluginManifestPreference::ListOfStrings { id, name, description } => (id, DbPluginPreference::ListOfStrings { name: Some(name), default: None, description }),
+            PluginManifestPreference::ListOfNumbers { id, name, description } => (id, DbPluginPreference::ListOfNumbers { name: Some(name), default: None, description }),
+            PluginManifestPreference::ListOfEnums { id, name, description, enum_values } => {
+                let enum_values = enum_values.into_iter()
+                    .map(|PluginManifestPreferenceEnumValue { label, value } | DbPreferenceEnumValue { label, value })
+                    .collect();
+
+                (id, DbPluginPreference::ListOfEnums { name: Some(name), default: None, description, enum_values })
+            },
+            PluginManifestPreference::Directory { id, name, default, description } => (id, DbPluginPreference::Directory { name: Some(name), default, description }),
+        }
+    }
+
     fn validate_manifest(plugin_manifest: &PluginManifest) -> anyhow::Result<()> {
         let supported_systems = &plugin_manifest.supported_system;
         let supported_systems_str = supported_systems.iter().format(", ");
@@ -358,6 +563,12 @@ impl PluginLoader {
         let supports_macos = &supported_systems.iter().any(|system| matches!(system, PluginManifestSupportedSystem::MacOS));
         let supports_windows = &supported_systems.iter().any(|system| matches!(system, PluginManifestSupportedSystem::Windows));
 
+        if let Some(keyword) = &plugin_manifest.gauntlet.keyword {
+            if keyword.is_empty() || keyword.chars().any(|char| char.is_whitespace()) {
+                return Err(anyhow!("Plugin keyword cannot be empty or contain whitespace: {:?}", keyword))
+            }
+        }
+
         let permissions = &plugin_manifest.permissions;
 
         Self::validate_string_permissions(&permissions.environment)?;
@@ -410,6 +621,236 @@ impl PluginLoader {
             }
         }
 
+        Self::validate_preferences(&plugin_manifest.preferences)
+            .context("Invalid plugin preferences")?;
+
+        Self::validate_style(&plugin_manifest.style)
+            .context("Invalid plugin style")?;
+
+        let preference_ids = plugin_manifest.preferences
+            .iter()
+            .map(|preference| preference.id())
+            .collect::<Vec<_>>();
+
+        validate_environment_variables(&plugin_manifest.environment_variables, &preference_ids, &permissions.secrets.read)
+            .context("Invalid plugin environment variables")?;
+
+        for entrypoint in &plugin_manifest.entrypoint {
+            Self::validate_preferences(&entrypoint.preferences)
+                .with_context(|| format!("Invalid preferences of entrypoint '{}'", entrypoint.id))?;
+            Self::validate_arguments(entrypoint)?;
+            Self::validate_fallback(entrypoint)?;
+
+            if entrypoint.priority.is_some() && !matches!(entrypoint.entrypoint_type, PluginManifestEntrypointTypes::InlineView) {
+                Err(anyhow!("Entrypoint '{}' declares priority, but only entrypoints of type 'inline-view' can have priority", entrypoint.id))?
+            }
+
+            if entrypoint.cache_view && !matches!(entrypoint.entrypoint_type, PluginManifestEntrypointTypes::View) {
+                Err(anyhow!("Entrypoint '{}' declares cache_view, but only entrypoints of type 'view' can be cached", entrypoint.id))?
+            }
+
+            if let Some(icon) = &entrypoint.icon {
+                normalize_asset_path(icon)
+                    .with_context(|| format!("Invalid icon of entrypoint '{}'", entrypoint.id))?;
+            }
+
+            for locale in entrypoint.translations.keys() {
+                if !is_valid_locale(locale) {
+                    Err(anyhow!("Entrypoint '{}' has translation for invalid locale '{}'", entrypoint.id, locale))?
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_style(style: &PluginManifestStyle) -> anyhow::Result<()> {
+        let colors = [
+            ("accent_color", &style.accent_color),
+            ("muted_color", &style.muted_color),
+            ("success_color", &style.success_color),
+            ("warning_color", &style.warning_color),
+            ("danger_color", &style.danger_color),
+            ("link_color", &style.link_color),
+        ];
+
+        for (name, color) in colors {
+            if let Some(color) = color {
+                if !is_valid_hex_color(color) {
+                    Err(anyhow!("Style token '{}' has invalid color {:?}, expected hex color like '#RRGGBB' or '#RRGGBBAA'", name, color))?
+                }
+            }
+        }
+
+        if let Some(content_spacing) = style.content_spacing {
+            if !(0.0..=MAX_STYLE_CONTENT_SPACING).contains(&content_spacing) {
+                Err(anyhow!("Style token 'content_spacing' has to be between 0 and {}", MAX_STYLE_CONTENT_SPACING))?
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_preferences(preferences: &[PluginManifestPreference]) -> anyhow::Result<()> {
+        let mut ids = vec![];
+
+        for preference in preferences {
+            let id = match preference {
+                PluginManifestPreference::Number { id, default, min, max, .. } => {
+                    if let (Some(min), Some(max)) = (min, max) {
+                        if min > max {
+                            Err(anyhow!("Preference '{}' has min value greater than max value", id))?
+                        }
+                    }
+
+                    if let Some(default) = default {
+                        let below_min = min.is_some_and(|min| *default < min);
+                        let above_max = max.is_some_and(|max| *default > max);
+
+                        if below_min || above_max {
+                            Err(anyhow!("Default value of preference '{}' is outside of allowed range", id))?
+                        }
+                    }
+
+                    id
+                }
+                PluginManifestPreference::Slider { id, default, min, max, step, .. } => {
+                    if min > max {
+                        Err(anyhow!("Preference '{}' has min value greater than max value", id))?
+                    }
+
+                    if step.is_some_and(|step| step <= 0.0) {
+                        Err(anyhow!("Preference '{}' has step value which is not positive", id))?
+                    }
+
+                    if let Some(default) = default {
+                        if default < min || default > max {
+                            Err(anyhow!("Default value of preference '{}' is outside of allowed range", id))?
+                        }
+                    }
+
+                    id
+                }
+                PluginManifestPreference::Enum { id, default, enum_values, .. } => {
+                    if enum_values.is_empty() {
+                        Err(anyhow!("Preference '{}' is of type 'enum' but has no enum_values", id))?
+                    }
+
+                    if let Some(default) = default {
+                        if !enum_values.iter().any(|enum_value| &enum_value.value == default) {
+                            Err(anyhow!("Default value of preference '{}' is not one of its enum_values", id))?
+                        }
+                    }
+
+                    id
+                }
+                PluginManifestPreference::ListOfEnums { id, enum_values, .. } => {
+                    if enum_values.is_empty() {
+                        Err(anyhow!("Preference '{}' is of type 'list_of_enums' but has no enum_values", id))?
+                    }
+
+                    id
+                }
+                PluginManifestPreference::String { id, .. } => id,
+                PluginManifestPreference::Bool { id, .. } => id,
+                PluginManifestPreference::ListOfStrings { id, .. } => id,
+                PluginManifestPreference::ListOfNumbers { id, .. } => id,
+                PluginManifestPreference::Directory { id, .. } => id,
+            };
+
+            if ids.contains(&id) {
+                Err(anyhow!("Preference '{}' is declared more than once", id))?
+            }
+
+            ids.push(id);
+        }
+
+        Ok(())
+    }
+
+    fn validate_arguments(entrypoint: &PluginManifestEntrypoint) -> anyhow::Result<()> {
+        if entrypoint.arguments.is_empty() {
+            return Ok(())
+        }
+
+        if !matches!(entrypoint.entrypoint_type, PluginManifestEntrypointTypes::Command) {
+            Err(anyhow!("Entrypoint '{}' declares arguments, but only entrypoints of type 'command' can have arguments", entrypoint.id))?
+        }
+
+        let mut ids = vec![];
+        let mut optional_found = false;
+
+        for argument in &entrypoint.arguments {
+            let (id, optional) = match argument {
+                PluginManifestArgument::String { id, optional, max_length, .. } => {
+                    if *max_length == Some(0) {
+                        Err(anyhow!("Argument '{}' of entrypoint '{}' has max_length of 0", id, entrypoint.id))?
+                    }
+
+                    (id, *optional)
+                },
+                PluginManifestArgument::Number { id, optional, min, max, .. } => {
+                    if let (Some(min), Some(max)) = (min, max) {
+                        if min > max {
+                            Err(anyhow!("Argument '{}' of entrypoint '{}' has min greater than max", id, entrypoint.id))?
+                        }
+                    }
+
+                    (id, *optional)
+                },
+                PluginManifestArgument::Enum { id, optional, enum_values, .. } => {
+                    if enum_values.is_empty() {
+                        Err(anyhow!("Argument '{}' of entrypoint '{}' is of type 'enum' but has no enum_values", id, entrypoint.id))?
+                    }
+
+                    (id, *optional)
+                },
+            };
+
+            if ids.contains(&id) {
+                Err(anyhow!("Entrypoint '{}' declares argument '{}' more than once", entrypoint.id, id))?
+            }
+
+            // arguments are positional when passed from cli
+            if optional_found && !optional {
+                Err(anyhow!("Required argument '{}' of entrypoint '{}' cannot be declared after optional argument", id, entrypoint.id))?
+            }
+
+            optional_found = optional_found || optional;
+            ids.push(id);
+        }
+
+        Ok(())
+    }
+
+    fn validate_fallback(entrypoint: &PluginManifestEntrypoint) -> anyhow::Result<()> {
+        if !entrypoint.fallback {
+            return Ok(())
+        }
+
+        if !matches!(entrypoint.entrypoint_type, PluginManifestEntrypointTypes::Command) {
+            Err(anyhow!("Entrypoint '{}' is declared as fallback, but only entrypoints of type 'command' can be fallback", entrypoint.id))?
+        }
+
+        // search query is passed as value of the first argument
+        match entrypoint.arguments.as_slice() {
+            [PluginManifestArgument::String { .. }, rest @ ..] => {
+                let has_required = rest.iter()
+                    .any(|argument| match argument {
+                        PluginManifestArgument::String { optional, .. } => !optional,
+                        PluginManifestArgument::Number { optional, .. } => !optional,
+                        PluginManifestArgument::Enum { optional, .. } => !optional,
+                    });
+
+                if has_required {
+                    Err(anyhow!("Fallback entrypoint '{}' can only have optional arguments after the first one", entrypoint.id))?
+                }
+            }
+            _ => {
+                Err(anyhow!("Fallback entrypoint '{}' has to declare argument of type 'string' as first argument", entrypoint.id))?
+            }
+        }
+
         Ok(())
     }
 
@@ -453,6 +894,7 @@ impl PluginLoader {
                         ("windows", "user-home") => windows_like_path,
                         ("common", "plugin-data") => windows_like_path,
                         ("common", "plugin-cache") => windows_like_path,
+                        ("common", "script-commands") => windows_like_path,
                         (namespace, name) => {
                             Err(anyhow!("Unknown variable namespace and name combination in path in permissions: {}:{}", namespace, name))?
                         }
@@ -573,6 +1015,8 @@ struct PluginDownloadData {
     pub id: String,
     pub name: String,
     pub description: String,
+    pub keyword: Option<String>,
+    pub interactive: bool,
     pub code: DbCode,
     pub entrypoints: Vec<DbWritePluginEntrypoint>,
     pub asset_data: Vec<DbWritePluginAssetData>,
@@ -591,6 +1035,24 @@ struct PluginManifest {
     permissions: PluginManifestPermissions,
     #[serde(default)]
     preferences: Vec<PluginManifestPreference>,
+    #[serde(default)]
+    style: PluginManifestStyle,
+    // passed to plugin runtime process, so they are inherited by commands it runs and read by Deno itself, e.g. HTTPS_PROXY
+    #[serde(default)]
+    environment_variables: HashMap<String, String>,
+}
+
+// only tokens listed here can be overridden, so plugin cannot change anything outside of its own views
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct PluginManifestStyle {
+    accent_color: Option<String>,
+    muted_color: Option<String>,
+    success_color: Option<String>,
+    warning_color: Option<String>,
+    danger_color: Option<String>,
+    link_color: Option<String>,
+    content_spacing: Option<f32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -607,6 +1069,55 @@ struct PluginManifestEntrypoint {
     preferences: Vec<PluginManifestPreference>,
     #[serde(default)]
     actions: Vec<PluginManifestAction>,
+    #[serde(default)]
+    arguments: Vec<PluginManifestArgument>,
+    #[serde(default)]
+    fallback: bool,
+    priority: Option<i64>,
+    #[serde(default)]
+    cache_view: bool,
+    // keyed by locale, e.g. "de" or "pt-BR"
+    #[serde(default)]
+    translations: HashMap<String, PluginManifestEntrypointTranslation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PluginManifestEntrypointTranslation {
+    name: String,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum PluginManifestArgument {
+    #[serde(rename = "string")]
+    String {
+        id: String,
+        name: String,
+        #[serde(default)]
+        optional: bool,
+        hint: Option<String>,
+        max_length: Option<usize>,
+    },
+    #[serde(rename = "number")]
+    Number {
+        id: String,
+        name: String,
+        #[serde(default)]
+        optional: bool,
+        hint: Option<String>,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    #[serde(rename = "enum")]
+    Enum {
+        id: String,
+        name: String,
+        #[serde(default)]
+        optional: bool,
+        hint: Option<String>,
+        enum_values: Vec<PluginManifestPreferenceEnumValue>,
+    },
 }
 
 #[derive(Debug, Deserialize)]
@@ -617,6 +1128,18 @@ enum PluginManifestPreference {
         id: String,
         name: String,
         default: Option<f64>,
+        min: Option<f64>,
+        max: Option<f64>,
+        description: String,
+    },
+    #[serde(rename = "slider")]
+    Slider {
+        id: String,
+        name: String,
+        default: Option<f64>,
+        min: f64,
+        max: f64,
+        step: Option<f64>,
         description: String,
     },
     #[serde(rename = "string")]
@@ -662,6 +1185,29 @@ enum PluginManifestPreference {
         // default: Option<Vec<String>>,
         enum_values: Vec<PluginManifestPreferenceEnumValue>,
         description: String,
+    },
+    #[serde(rename = "directory")]
+    Directory {
+        id: String,
+        name: String,
+        default: Option<String>,
+        description: String,
+    }
+}
+
+impl PluginManifestPreference {
+    fn id(&self) -> &str {
+        match self {
+            PluginManifestPreference::Number { id, .. } => id,
+            PluginManifestPreference::Slider { id, .. } => id,
+            PluginManifestPreference::String { id, .. } => id,
+            PluginManifestPreference::Enum { id, .. } => id,
+            PluginManifestPreference::Bool { id, .. } => id,
+            PluginManifestPreference::ListOfStrings { id, .. } => id,
+            PluginManifestPreference::ListOfNumbers { id, .. } => id,
+            PluginManifestPreference::ListOfEnums { id, .. } => id,
+            PluginManifestPreference::Directory { id, .. } => id,
+        }
     }
 }
 
@@ -1022,6 +1568,24 @@ impl std::fmt::Display for PluginManifestSupportedSystem {
 struct PluginManifestMetadata {
     name: String,
     description: String,
+    // prompt starting with keyword followed by space searches only in this plugin
+    keyword: Option<String>,
+    // window is not hidden on focus loss while view of this plugin is open, e.g. for views that open other windows
+    #[serde(default)]
+    interactive: bool,
+    // not interpreted, only included in bug reports
+    version: Option<String>,
+    #[serde(default)]
+    runtime: PluginManifestRuntime,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+enum PluginManifestRuntime {
+    #[default]
+    Deno,
+    // experimental, plugin is a webassembly component in assets directory instead of js code
+    Wasm,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -1040,6 +1604,13 @@ pub struct PluginManifestPermissions {
     clipboard: Vec<PluginManifestClipboardPermissions>,
     #[serde(default)]
     main_search_bar: Vec<PluginManifestMainSearchBarPermissions>,
+    // application and title of window focused when launcher was shown
+    #[serde(default)]
+    active_window: Vec<PluginManifestActiveWindowPermissions>,
+    #[serde(default)]
+    secrets: PluginManifestPermissionsSecrets,
+    #[serde(default)]
+    events: PluginManifestPermissionsEvents,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -1050,6 +1621,23 @@ pub struct PluginManifestPermissionsFileSystem {
     pub write: Vec<String>,
 }
 
+#[derive(Debug, Deserialize, Default)]
+pub struct PluginManifestPermissionsSecrets {
+    // names of services in system secret store
+    #[serde(default)]
+    pub read: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PluginManifestPermissionsEvents {
+    // topics of events plugin can send to other plugins
+    #[serde(default)]
+    pub publish: Vec<String>,
+    // topics of events plugin receives while running
+    #[serde(default)]
+    pub subscribe: Vec<String>,
+}
+
 #[derive(Debug, Deserialize, Default)]
 pub struct PluginManifestPermissionsExec {
     #[serde(default)]
@@ -1074,3 +1662,9 @@ pub enum PluginManifestMainSearchBarPermissions {
     Read,
 }
 
+#[derive(Debug, Deserialize)]
+pub enum PluginManifestActiveWindowPermissions {
+    #[serde(rename = "read")]
+    Read,
+}
+
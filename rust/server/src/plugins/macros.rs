@@ -0,0 +1,93 @@
+use gauntlet_common::model::{EntrypointId, Macro, MacroStep, PluginId, SearchResultCategory, SearchResultEntrypointType};
+
+use crate::plugins::data_db_repository::{DbMacro, DbMacroStep};
+use crate::search::{SearchIndexItem, SearchIndexItemRanking};
+
+// macros are not provided by any plugin, they are shown in search under this synthetic plugin,
+// running its command is intercepted by server and macro steps are run instead
+pub const MACROS_PLUGIN_ID: &str = "gauntlet://macros";
+pub const MACROS_PLUGIN_NAME: &str = "Macros";
+
+// delay steps longer than this are most likely a mistake, e.g. seconds entered instead of milliseconds
+pub const MAX_MACRO_STEP_DELAY_MILLIS: u64 = 60 * 1000;
+
+pub fn macros_plugin_id() -> PluginId {
+    PluginId::from_string(MACROS_PLUGIN_ID)
+}
+
+pub fn is_macros_plugin(plugin_id: &PluginId) -> bool {
+    plugin_id.to_string() == MACROS_PLUGIN_ID
+}
+
+pub fn macro_search_items(macros: &[DbMacro]) -> Vec<SearchIndexItem> {
+    macros.iter()
+        .map(|item| {
+            SearchIndexItem {
+                entrypoint_type: SearchResultEntrypointType::Command,
+                entrypoint_name: item.name.clone(),
+                entrypoint_id: EntrypointId::from_string(&item.id),
+                entrypoint_icon_path: None,
+                entrypoint_frecency: 0.0,
+                entrypoint_actions: vec![],
+                entrypoint_accessory: None,
+                entrypoint_preview: false,
+                entrypoint_drag: None,
+                entrypoint_arguments: vec![],
+                entrypoint_fallback: false,
+                entrypoint_hidden: false,
+                entrypoint_generator_id: None,
+                entrypoint_ranking: SearchIndexItemRanking::default(),
+                entrypoint_dedupe_key: None,
+                entrypoint_category: SearchResultCategory::Command,
+                entrypoint_aliases: vec![],
+            }
+        })
+        .collect()
+}
+
+pub fn macro_from_db(value: DbMacro) -> Macro {
+    Macro {
+        id: value.id,
+        name: value.name,
+        steps: value.steps
+            .into_iter()
+            .map(|step| {
+                match step {
+                    DbMacroStep::RunEntrypoint { plugin_id, plugin_name, entrypoint_id, entrypoint_name, action_index } => {
+                        MacroStep::RunEntrypoint {
+                            plugin_id: PluginId::from_string(plugin_id),
+                            plugin_name,
+                            entrypoint_id: EntrypointId::from_string(entrypoint_id),
+                            entrypoint_name,
+                            action_index,
+                        }
+                    }
+                    DbMacroStep::Delay { millis } => MacroStep::Delay { millis },
+                }
+            })
+            .collect(),
+    }
+}
+
+pub fn macro_step_to_db(value: MacroStep) -> DbMacroStep {
+    match value {
+        MacroStep::RunEntrypoint { plugin_id, plugin_name, entrypoint_id, entrypoint_name, action_index } => {
+            DbMacroStep::RunEntrypoint {
+                plugin_id: plugin_id.to_string(),
+                plugin_name,
+                entrypoint_id: entrypoint_id.to_string(),
+                entrypoint_name,
+                action_index,
+            }
+        }
+        MacroStep::Delay { millis } => DbMacroStep::Delay { millis },
+    }
+}
+
+// shown in hud, so it only names the step, details are in the log
+pub fn macro_step_display(step: &DbMacroStep) -> String {
+    match step {
+        DbMacroStep::RunEntrypoint { plugin_name, entrypoint_name, .. } => format!("{} ({})", entrypoint_name, plugin_name),
+        DbMacroStep::Delay { millis } => format!("wait {} ms", millis),
+    }
+}
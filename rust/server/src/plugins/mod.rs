@@ -1,83 +1,225 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
-use std::sync::Mutex;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
-use anyhow::anyhow;
+use anyhow::{anyhow, Context};
 use include_dir::{include_dir, Dir};
 use tokio::runtime::Handle;
+use tokio_util::task::TaskTracker;
+use uuid::Uuid;
 
-use gauntlet_common::model::{DownloadStatus, EntrypointId, KeyboardEventOrigin, LocalSaveData, PhysicalKey, PhysicalShortcut, PluginId, PluginPreference, PluginPreferenceUserData, PreferenceEnumValue, SearchResult, SettingsEntrypoint, SettingsEntrypointType, SettingsPlugin, UiPropertyValue, UiRequestData, UiResponseData, UiWidgetId};
-use gauntlet_common::rpc::frontend_api::FrontendApi;
+use gauntlet_common::model::{parse_entrypoint_arguments, ActionShortcutConflict, ActiveWindowContext, AvailableUpdate, ConfigError, DiagnosticsData, DownloadStatus, EntrypointArgumentValue, EntrypointId, GeneralSettings, ImportItem, ImportSource, KeyboardEventOrigin, LocalSaveData, Macro, MacroStep, PhysicalKey, PhysicalShortcut, PluginId, PluginLogs, PluginPermissionKind, PluginPreference, PluginPreferenceUserData, PluginStyle, PreferenceEnumValue, Profiles, SearchKeywordMode, SearchResult, SearchResultCategory, SearchResultEntrypointType, SettingsEntrypoint, SettingsEntrypointAction, SettingsEntrypointReference, SettingsEntrypointType, SettingsEntrypointUpdate, SettingsInlineView, SettingsPlugin, SettingsPluginPermission, SettingsPluginPermissions, SimpleThemeTokens, UiImageId, UiPropertyValue, UiRenderLocation, UiRequestData, UiResponseData, UiWidgetId, UsageStatistics};
+use gauntlet_common::rpc::frontend_api::{FrontendApi, FrontendApiImpl};
 use gauntlet_common::{settings_env_data_to_string, SettingsEnvData};
 use gauntlet_utils::channel::RequestSender;
-use gauntlet_common::dirs::Dirs;
-use gauntlet_plugin_runtime::{JsPluginCode, JsPluginPermissions, JsPluginPermissionsExec, JsPluginPermissionsFileSystem, JsPluginPermissionsMainSearchBar};
+use gauntlet_common::dirs::{is_valid_profile_name, Dirs, DEFAULT_PROFILE};
+use gauntlet_plugin_runtime::{JsPluginCode, JsPluginRuntime, JsPluginPermissions, JsPluginPermissionsExec, JsPluginPermissionsFileSystem, JsPluginPermissionsMainSearchBar};
 use crate::model::{ActionShortcutKey};
 use crate::plugins::clipboard::Clipboard;
+use crate::plugins::sound::SoundPlayer;
 use crate::plugins::config_reader::ConfigReader;
-use crate::plugins::data_db_repository::{db_entrypoint_from_str, DataDbRepository, DbPluginActionShortcutKind, DbPluginClipboardPermissions, DbPluginEntrypointType, DbPluginMainSearchBarPermissions, DbPluginPreference, DbPluginPreferenceUserData, DbReadPluginEntrypoint};
+use crate::plugins::config_watcher::ConfigWatcher;
+use crate::plugins::connectivity::ConnectivityMonitor;
+use crate::plugins::session_lock::SessionLockWatcher;
+use crate::plugins::environment_variables::{resolve_environment_variables, uses_preference};
+use crate::plugins::config_writer::write_general_settings;
+use crate::plugins::data_db_repository::{db_entrypoint_from_str, db_permission_kind_from_str, DataDbRepository, DbEntrypointReference, DbMacroStep, DbPluginActionShortcutKind, DbPluginActiveWindowPermissions, DbPluginClipboardPermissions, DbPluginEntrypointType, DbPluginMainSearchBarPermissions, DbPluginPreference, DbPluginPreferenceUserData, DbPluginRuntime, DbReadPlugin, DbReadPluginEntrypoint};
 use crate::plugins::icon_cache::IconCache;
-use crate::plugins::js::{start_plugin_runtime, AllPluginCommandData, OnePluginCommandData, PluginCommand, PluginPermissions, PluginPermissionsClipboard, PluginRuntimeData};
+use crate::plugins::idle::IdleCoordinator;
+use crate::plugins::js::{any_preferences_missing_value, entrypoint_arguments_from_db, reload_plugin_search_index, start_plugin_runtime, AllPluginCommandData, OnePluginCommandData, PluginCommand, PluginPermissions, PluginPermissionsActiveWindow, PluginPermissionsClipboard, PluginPermissionsEvents, PluginPermissionsSecrets, PluginRuntimeData};
 use crate::plugins::loader::PluginLoader;
+use crate::plugins::plugin_logs::PluginLogReader;
+use crate::plugins::bug_report::{write_bug_report, BugReport};
+use crate::plugins::settings_archive::{SettingsArchive, SettingsArchivePlugin};
+use crate::plugins::launcher_import::LauncherImport;
+use crate::plugins::macros::{is_macros_plugin, macro_from_db, macro_search_items, macro_step_display, macro_step_to_db, macros_plugin_id, MACROS_PLUGIN_NAME, MAX_MACRO_STEP_DELAY_MILLIS};
 use crate::plugins::run_status::RunStatusHolder;
-use crate::search::SearchIndex;
+use crate::plugins::updates::{changelog_since, fetch_available_update, gauntlet_version};
+use crate::plugins::usage_statistics::{usage_statistics, write_usage_csv, USAGE_STATISTICS_DAYS};
+use crate::search::{SearchIndex, RECENTLY_USED_HISTORY_SIZE};
+use crate::search_aggregator::SearchAggregator;
+use crate::plugins::image_store::ImageStore;
+use crate::plugins::view_cache::ViewCache;
+use crate::plugins::undo_stack::UndoStack;
+use crate::plugins::event_bus::EventBus;
+use crate::plugins::active_window::ActiveWindow;
+use crate::diagnostics::{diagnostics_data, set_diagnostics_enabled};
+use crate::shutdown::Shutdown;
 use crate::SETTINGS_ENV;
 
 pub mod js;
 mod data_db_repository;
+mod data_db_migration;
 mod config_reader;
+mod config_writer;
+mod config_watcher;
 mod loader;
+mod environment_variables;
 mod run_status;
 mod download_status;
 mod icon_cache;
 pub(super) mod frecency;
 mod clipboard;
+mod secrets;
+mod sound;
+mod system_info;
 mod runtime;
 mod image_gatherer;
+mod image_store;
+mod thumbnail_cache;
+mod view_cache;
+mod render_coalescer;
+mod undo_stack;
+mod event_bus;
+mod active_window;
+mod plugin_logs;
+mod bug_report;
+mod settings_archive;
+mod launcher_import;
+mod updates;
+mod usage_statistics;
+mod web_search_suggestions;
+mod idle;
+mod connectivity;
+mod session_lock;
+pub mod remote_host;
+pub(super) mod macros;
+pub(super) mod doctor;
 
 static BUNDLED_PLUGINS: [(&str, Dir); 1] = [
     ("gauntlet", include_dir!("$CARGO_MANIFEST_DIR/../../bundled_plugins/gauntlet/dist")),
 ];
 
+// part of SHUTDOWN_TIMEOUT, the rest is left for database to be closed
+const PLUGIN_STOP_TIMEOUT: Duration = Duration::from_secs(3);
+
 pub struct ApplicationManager {
     config_reader: ConfigReader,
     search_index: SearchIndex,
+    search_aggregator: SearchAggregator,
     command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>,
+    idle_coordinator: IdleCoordinator,
+    connectivity_monitor: ConnectivityMonitor,
     db_repository: DataDbRepository,
     plugin_downloader: PluginLoader,
     run_status_holder: RunStatusHolder,
+    // enabled plugins which runtime is not started yet, see start_or_defer_plugin
+    deferred_plugins: tokio::sync::Mutex<HashSet<PluginId>>,
+    plugins_warmed_up: AtomicBool,
     icon_cache: IconCache,
-    frontend_api: FrontendApi,
+    image_store: ImageStore,
+    view_cache: ViewCache,
+    undo_stack: UndoStack,
+    event_bus: EventBus,
+    active_window: ActiveWindow,
+    plugin_log_reader: PluginLogReader,
+    settings_archive: SettingsArchive,
+    launcher_import: LauncherImport,
+    frontend_api: Arc<dyn FrontendApi>,
     dirs: Dirs,
     clipboard: Clipboard,
+    sound_player: SoundPlayer,
+    shutdown: Shutdown,
+    // database writes done in background, waited for before database is closed
+    background_writes: TaskTracker,
+    safe_mode: bool,
 }
 
 impl ApplicationManager {
-    pub async fn create(frontend_sender: RequestSender<UiRequestData, UiResponseData>) -> anyhow::Result<Self> {
-        let frontend_api = FrontendApi::new(frontend_sender);
+    pub async fn create(frontend_sender: RequestSender<UiRequestData, UiResponseData>, safe_mode: bool, shutdown: Shutdown) -> anyhow::Result<Self> {
+        let frontend_api: Arc<dyn FrontendApi> = Arc::new(FrontendApiImpl::new(frontend_sender));
         let dirs = Dirs::new();
-        let db_repository = DataDbRepository::new(dirs.clone()).await?;
+        let (db_repository, db_recovery) = DataDbRepository::new(dirs.clone()).await?;
+
+        if let Some(db_recovery) = db_recovery {
+            let result = frontend_api.show_database_recovery_view(
+                db_recovery.reason,
+                db_recovery.broken_db_file.to_string_lossy().to_string(),
+                db_recovery.restored_backup_file.map(|path| path.to_string_lossy().to_string()),
+            ).await;
+
+            if let Err(err) = result {
+                tracing::warn!("Unable to show database recovery view: {:?}", err);
+            }
+        }
+
         let plugin_downloader = PluginLoader::new(db_repository.clone());
         let config_reader = ConfigReader::new(dirs.clone(), db_repository.clone());
         let icon_cache = IconCache::new(dirs.clone());
+        let image_store = ImageStore::new();
+        let view_cache = ViewCache::new();
+        let undo_stack = UndoStack::new();
+        let plugin_log_reader = PluginLogReader::new(dirs.clone());
+        let settings_archive = SettingsArchive::new(dirs.clone());
+        let launcher_import = LauncherImport::new(dirs.clone());
         let run_status_holder = RunStatusHolder::new();
         let search_index = SearchIndex::create_index(frontend_api.clone())?;
+
+        let recently_used = db_repository.recently_used_entrypoints(RECENTLY_USED_HISTORY_SIZE)
+            .await?
+            .into_iter()
+            .map(|(plugin_id, entrypoint_id)| (PluginId::from_string(plugin_id), EntrypointId::from_string(entrypoint_id)))
+            .collect();
+
+        search_index.set_recently_used(recently_used);
+
+        let favorites = db_repository.get_favorites()
+            .await?
+            .into_iter()
+            .map(|favorite| (PluginId::from_string(favorite.plugin_id), EntrypointId::from_string(favorite.entrypoint_id)))
+            .collect();
+
+        search_index.set_favorites(favorites);
+
+        let fallback_order = db_repository.get_fallback_order()
+            .await?
+            .into_iter()
+            .map(|fallback| (PluginId::from_string(fallback.plugin_id), EntrypointId::from_string(fallback.entrypoint_id)))
+            .collect();
+
+        search_index.set_fallback_order(fallback_order);
+        let search_aggregator = SearchAggregator::new(frontend_api.clone());
         let clipboard = Clipboard::new()?;
+        let sound_player = SoundPlayer::new();
 
         let (command_broadcaster, _) = tokio::sync::broadcast::channel::<PluginCommand>(100);
+        let idle_coordinator = IdleCoordinator::new(command_broadcaster.clone(), shutdown.subsystem_token());
+        let connectivity_monitor = ConnectivityMonitor::new(command_broadcaster.clone(), shutdown.subsystem_token());
+        connectivity_monitor.start();
+        let event_bus = EventBus::new(command_broadcaster.clone());
+        let active_window = ActiveWindow::new();
 
         let manager = Self {
             config_reader,
             search_index,
+            search_aggregator,
             command_broadcaster,
+            idle_coordinator,
+            connectivity_monitor,
             db_repository,
             plugin_downloader,
             run_status_holder,
+            deferred_plugins: tokio::sync::Mutex::new(HashSet::new()),
+            plugins_warmed_up: AtomicBool::new(false),
             icon_cache,
+            image_store,
+            view_cache,
+            undo_stack,
+            event_bus,
+            active_window,
+            plugin_log_reader,
+            settings_archive,
+            launcher_import,
             frontend_api,
             clipboard,
-            dirs
+            sound_player,
+            dirs,
+            shutdown,
+            background_writes: TaskTracker::new(),
+            safe_mode,
         };
 
         match manager.get_global_shortcut().await? {
@@ -107,9 +249,36 @@ impl ApplicationManager {
             }
         };
 
+        manager.reload_macros_search_index()
+            .await?;
+
+        if let Err(err) = manager.show_whats_new_if_updated().await {
+            tracing::warn!("Unable to show what's new view: {:?}", err);
+        }
+
         Ok(manager)
     }
 
+    async fn show_whats_new_if_updated(&self) -> anyhow::Result<()> {
+        let current_version = gauntlet_version();
+        let last_seen_version = self.db_repository.get_last_seen_version().await?;
+
+        if last_seen_version == Some(current_version) {
+            return Ok(())
+        }
+
+        // nothing to show on first start
+        if let Some(last_seen_version) = last_seen_version {
+            let changelog = changelog_since(last_seen_version, current_version);
+
+            if last_seen_version < current_version && !changelog.is_empty() {
+                self.frontend_api.show_whats_new_view(current_version, changelog).await?;
+            }
+        }
+
+        self.db_repository.set_last_seen_version(current_version).await
+    }
+
     pub fn clear_all_icon_cache_dir(&self) -> anyhow::Result<()> {
         tracing::debug!("clearing all icon cache");
 
@@ -124,11 +293,22 @@ impl ApplicationManager {
         self.plugin_downloader.download_status()
     }
 
-    pub fn search(&self, text: &str, render_inline_view: bool) -> anyhow::Result<Vec<SearchResult>> {
-        let result = self.search_index.search(&text);
+    pub fn search(&self, text: &str, keyword_plugin_id: Option<&PluginId>, category: Option<SearchResultCategory>, render_inline_view: bool, generation: u64) -> anyhow::Result<Vec<SearchResult>> {
+        let result = self.search_index.search(&text, category, self.config_reader.search_transliteration())
+            .map(|mut result| {
+                if let Some(keyword_plugin_id) = keyword_plugin_id {
+                    result.retain(|item| &item.plugin_id == keyword_plugin_id);
+                }
+
+                if let Some(max_results) = self.config_reader.search_max_results() {
+                    result.truncate(max_results as usize);
+                }
+
+                result
+            });
 
         if render_inline_view {
-            self.handle_inline_view(&text);
+            self.handle_inline_view(&text, generation);
         }
 
         result
@@ -140,6 +320,100 @@ impl ApplicationManager {
         Ok(())
     }
 
+    pub async fn hide_window(&self) -> anyhow::Result<()> {
+        self.frontend_api.hide_window().await?;
+
+        Ok(())
+    }
+
+    pub async fn toggle_window(&self) -> anyhow::Result<()> {
+        self.frontend_api.toggle_window().await?;
+
+        Ok(())
+    }
+
+    pub async fn open_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> anyhow::Result<()> {
+        let (plugin, entrypoint) = self.entrypoint_for_external_request(&plugin_id, &entrypoint_id).await?;
+
+        let missing_preferences = request_missing_preferences(
+            self.frontend_api.as_ref(),
+            &plugin_id,
+            &entrypoint_id,
+            (&plugin.preferences, &plugin.preferences_user_data),
+            (&entrypoint.preferences, &entrypoint.preferences_user_data),
+        ).await?;
+
+        if missing_preferences {
+            return Ok(())
+        }
+
+        match db_entrypoint_from_str(&entrypoint.entrypoint_type) {
+            DbPluginEntrypointType::Command => {
+                let arguments = parse_entrypoint_arguments(&entrypoint_arguments_from_db(entrypoint.arguments), &[])?;
+
+                self.handle_run_command(plugin_id, entrypoint_id, arguments).await;
+            }
+            DbPluginEntrypointType::View => {
+                self.frontend_api.show_plugin_view(plugin_id, plugin.name, entrypoint_id, entrypoint.localized_name()).await?;
+            }
+            DbPluginEntrypointType::InlineView | DbPluginEntrypointType::CommandGenerator => {
+                return Err(anyhow!("Entrypoint with id '{}' cannot be opened directly", entrypoint_id.to_string()))
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn run_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: Vec<String>) -> anyhow::Result<()> {
+        let (plugin, entrypoint) = self.entrypoint_for_external_request(&plugin_id, &entrypoint_id).await?;
+
+        let DbPluginEntrypointType::Command = db_entrypoint_from_str(&entrypoint.entrypoint_type) else {
+            return Err(anyhow!("Entrypoint with id '{}' is not a command", entrypoint_id.to_string()))
+        };
+
+        if entrypoint.arguments.is_empty() && !arguments.is_empty() {
+            return Err(anyhow!("Entrypoint with id '{}' doesn't accept arguments", entrypoint_id.to_string()))
+        }
+
+        let missing_preferences = request_missing_preferences(
+            self.frontend_api.as_ref(),
+            &plugin_id,
+            &entrypoint_id,
+            (&plugin.preferences, &plugin.preferences_user_data),
+            (&entrypoint.preferences, &entrypoint.preferences_user_data),
+        ).await?;
+
+        if missing_preferences {
+            return Ok(())
+        }
+
+        let arguments = parse_entrypoint_arguments(&entrypoint_arguments_from_db(entrypoint.arguments), &arguments)?;
+
+        self.handle_run_command(plugin_id, entrypoint_id, arguments).await;
+
+        Ok(())
+    }
+
+    async fn entrypoint_for_external_request(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> anyhow::Result<(DbReadPlugin, DbReadPluginEntrypoint)> {
+        let plugin = self.db_repository.get_plugin_by_id_option(&plugin_id.to_string())
+            .await?
+            .ok_or_else(|| anyhow!("Plugin with id '{}' doesn't exist", plugin_id.to_string()))?;
+
+        let entrypoint = self.db_repository.get_entrypoint_by_id_option(&plugin_id.to_string(), &entrypoint_id.to_string())
+            .await?
+            .ok_or_else(|| anyhow!("Entrypoint with id '{}' doesn't exist in plugin '{}'", entrypoint_id.to_string(), plugin_id.to_string()))?;
+
+        if !plugin.enabled {
+            return Err(anyhow!("Plugin with id '{}' is disabled", plugin_id.to_string()))
+        }
+
+        if !entrypoint.enabled {
+            return Err(anyhow!("Entrypoint with id '{}' is disabled", entrypoint_id.to_string()))
+        }
+
+        Ok((plugin, entrypoint))
+    }
+
     pub async fn save_local_plugin(
         &self,
         path: &str,
@@ -174,6 +448,9 @@ impl ApplicationManager {
     }
 
     pub async fn plugins(&self) -> anyhow::Result<Vec<SettingsPlugin>> {
+        let favorites = self.db_repository.get_favorites()
+            .await?;
+
         let result = self.db_repository
             .list_plugins_and_entrypoints()
             .await?
@@ -182,13 +459,22 @@ impl ApplicationManager {
                 let entrypoints = entrypoints
                     .into_iter()
                     .map(|entrypoint| {
-                        let entrypoint_id = EntrypointId::from_string(entrypoint.id);
+                        let entrypoint_name = entrypoint.localized_name();
+                        let entrypoint_description = entrypoint.localized_description();
+                        let entrypoint_id = EntrypointId::from_string(entrypoint.id.clone());
+
+                        let actions = settings_entrypoint_actions(&entrypoint)
+                            .unwrap_or_else(|err| {
+                                tracing::warn!(target = "plugin", "Unable to get action shortcuts for entrypoint id: {:?}, {:?}", entrypoint_id, err);
+                                vec![]
+                            });
 
                         let entrypoint = SettingsEntrypoint {
                             enabled: entrypoint.enabled,
+                            hidden: entrypoint.hidden,
                             entrypoint_id: entrypoint_id.clone(),
-                            entrypoint_name: entrypoint.name,
-                            entrypoint_description: entrypoint.description,
+                            entrypoint_name,
+                            entrypoint_description,
                             entrypoint_type: match db_entrypoint_from_str(&entrypoint.entrypoint_type) {
                                 DbPluginEntrypointType::Command => SettingsEntrypointType::Command,
                                 DbPluginEntrypointType::View => SettingsEntrypointType::View,
@@ -204,6 +490,10 @@ impl ApplicationManager {
                             preferences_user_data: entrypoint.preferences_user_data.into_iter()
                                 .map(|(key, value)| (key, plugin_preference_user_data_from_db(value)))
                                 .collect(),
+                            actions,
+                            aliases: entrypoint.aliases,
+                            favorite: favorites.iter()
+                                .any(|favorite| favorite.plugin_id == plugin.id && favorite.entrypoint_id == entrypoint.id),
                         };
 
                         (entrypoint_id, entrypoint)
@@ -233,7 +523,7 @@ impl ApplicationManager {
     }
 
     pub async fn set_plugin_state(&self, plugin_id: PluginId, set_enabled: bool) -> anyhow::Result<()> {
-        let currently_running = self.run_status_holder.is_plugin_running(&plugin_id);
+        let currently_running = self.is_plugin_running(&plugin_id).await;
         let currently_enabled = self.is_plugin_enabled(&plugin_id).await?;
 
         tracing::info!(target = "plugin", "Setting plugin state for plugin id: {:?}, currently_running: {}, currently_enabled: {}, set_enabled: {}", plugin_id, currently_running, currently_enabled, set_enabled);
@@ -270,11 +560,88 @@ impl ApplicationManager {
         self.db_repository.set_plugin_entrypoint_enabled(&plugin_id.to_string(), &entrypoint_id.to_string(), enabled)
             .await?;
 
+        // search index of deferred plugin can only be reloaded by its runtime
+        self.start_deferred_plugin(&plugin_id).await?;
+
+        self.request_search_index_reload(plugin_id);
+
+        Ok(())
+    }
+
+    pub async fn set_entrypoint_hidden(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, hidden: bool) -> anyhow::Result<()> {
+        tracing::debug!(target = "plugin", "Setting entrypoint visibility for plugin id: {:?}, entrypoint_id: {:?}, hidden: {}", plugin_id, entrypoint_id, hidden);
+
+        self.db_repository.set_plugin_entrypoint_hidden(&plugin_id.to_string(), &entrypoint_id.to_string(), hidden)
+            .await?;
+
+        self.search_index.set_entrypoint_hidden(plugin_id, entrypoint_id, hidden);
+
+        Ok(())
+    }
+
+    // set from action panel of search result, generated entrypoints can have aliases too
+    pub async fn set_search_result_aliases(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, aliases: Vec<String>) -> anyhow::Result<()> {
+        tracing::debug!(target = "plugin", "Setting aliases for plugin id: {:?}, entrypoint_id: {:?}, aliases: {:?}", plugin_id, entrypoint_id, aliases);
+
+        let aliases = unique_aliases(aliases);
+
+        let entrypoint = self.db_repository.get_entrypoint_by_id_option(&plugin_id.to_string(), &entrypoint_id.to_string())
+            .await?;
+
+        match entrypoint {
+            Some(_) => {
+                self.db_repository.set_plugin_entrypoint_aliases(&plugin_id.to_string(), &entrypoint_id.to_string(), aliases)
+                    .await?;
+            }
+            None => {
+                self.db_repository.set_generated_entrypoint_aliases(&plugin_id.to_string(), &entrypoint_id.to_string(), aliases)
+                    .await?;
+            }
+        }
+
+        self.start_deferred_plugin(&plugin_id).await?;
+
         self.request_search_index_reload(plugin_id);
 
         Ok(())
     }
 
+    pub async fn update_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, update: SettingsEntrypointUpdate) -> anyhow::Result<()> {
+        tracing::debug!(target = "plugin", "Updating entrypoint settings for plugin id: {:?}, entrypoint_id: {:?}, update: {:?}", plugin_id, entrypoint_id, update);
+
+        if let Some(hidden) = update.hidden {
+            self.set_entrypoint_hidden(plugin_id.clone(), entrypoint_id.clone(), hidden)
+                .await?;
+        }
+
+        if let Some(aliases) = update.aliases {
+            self.db_repository.set_plugin_entrypoint_aliases(&plugin_id.to_string(), &entrypoint_id.to_string(), unique_aliases(aliases))
+                .await?;
+
+            // aliases are indexed together with the rest of entrypoint data
+            if update.enabled.is_none() {
+                self.start_deferred_plugin(&plugin_id).await?;
+
+                self.request_search_index_reload(plugin_id.clone());
+            }
+        }
+
+        if let Some(favorite) = update.favorite {
+            self.db_repository.set_entrypoint_favorite(&plugin_id.to_string(), &entrypoint_id.to_string(), favorite)
+                .await?;
+
+            self.reload_favorites()
+                .await?;
+        }
+
+        if let Some(enabled) = update.enabled {
+            self.set_entrypoint_state(plugin_id, entrypoint_id, enabled)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn set_global_shortcut(&self, shortcut: Option<PhysicalShortcut>) -> anyhow::Result<()> {
         let err = self.frontend_api.set_global_shortcut(shortcut.clone()).await;
 
@@ -290,78 +657,954 @@ impl ApplicationManager {
         self.db_repository.get_global_shortcut().await
     }
 
+    pub async fn set_action_shortcut(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        action_id: String,
+        shortcut: Option<PhysicalShortcut>
+    ) -> anyhow::Result<Option<ActionShortcutConflict>> {
+        tracing::debug!(target = "plugin", "Setting action shortcut for plugin id: {:?}, entrypoint_id: {:?}, action_id: {}, shortcut: {:?}", plugin_id, entrypoint_id, action_id, shortcut);
+
+        let entrypoint = self.db_repository.get_entrypoint_by_id(&plugin_id.to_string(), &entrypoint_id.to_string())
+            .await?;
+
+        if !entrypoint.actions.iter().any(|action| action.id == action_id) {
+            return Err(anyhow!("Action '{}' doesn't exist", action_id))
+        }
+
+        if let Some(shortcut) = &shortcut {
+            let global_shortcut = self.db_repository.get_global_shortcut()
+                .await?
+                .and_then(|(shortcut, _)| shortcut);
+
+            if let Some(conflict) = action_shortcut_conflict(&entrypoint, &action_id, shortcut, global_shortcut.as_ref())? {
+                return Ok(Some(conflict))
+            }
+        }
+
+        self.db_repository.set_action_shortcut(&plugin_id.to_string(), &entrypoint_id.to_string(), &action_id, shortcut)
+            .await?;
+
+        let shortcuts = self.db_repository.action_shortcuts(&plugin_id.to_string(), &entrypoint_id.to_string())
+            .await?;
+
+        // shortcuts shown in main view action panel are stored in search index
+        self.request_search_index_reload(plugin_id.clone());
+
+        if let Err(err) = self.frontend_api.update_action_shortcuts(plugin_id, entrypoint_id, shortcuts).await {
+            tracing::warn!(target = "plugin", "Unable to update action shortcuts in frontend: {:?}", err);
+        }
+
+        Ok(None)
+    }
+
+    pub fn get_images(&self, image_ids: Vec<UiImageId>) -> HashMap<UiImageId, bytes::Bytes> {
+        self.image_store.get_images(image_ids)
+    }
+
+    pub async fn get_inline_view_order(&self) -> anyhow::Result<Vec<SettingsInlineView>> {
+        let result = self.db_repository.get_inline_view_order()
+            .await?
+            .into_iter()
+            .map(|inline_view| SettingsInlineView {
+                plugin_id: PluginId::from_string(inline_view.plugin_id),
+                plugin_name: inline_view.plugin_name,
+                entrypoint_id: EntrypointId::from_string(inline_view.entrypoint_id),
+                entrypoint_name: inline_view.entrypoint_name,
+                enabled: inline_view.enabled,
+            })
+            .collect();
+
+        Ok(result)
+    }
+
+    pub async fn set_inline_view_order(&self, plugin_ids: Vec<PluginId>) -> anyhow::Result<()> {
+        let plugin_ids = plugin_ids.into_iter()
+            .map(|plugin_id| plugin_id.to_string())
+            .collect();
+
+        self.db_repository.set_inline_view_order(plugin_ids)
+            .await?;
+
+        // main view fetches the new order together with search results
+        self.frontend_api.request_search_results_update()
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_favorites(&self) -> anyhow::Result<Vec<SettingsEntrypointReference>> {
+        let result = self.db_repository.get_favorites()
+            .await?
+            .into_iter()
+            .map(|favorite| settings_entrypoint_reference(favorite))
+            .collect();
+
+        Ok(result)
+    }
+
+    pub async fn set_favorites(&self, favorites: Vec<(PluginId, EntrypointId)>) -> anyhow::Result<()> {
+        let favorites = favorites.into_iter()
+            .map(|(plugin_id, entrypoint_id)| (plugin_id.to_string(), entrypoint_id.to_string()))
+            .collect();
+
+        self.db_repository.set_favorites(favorites)
+            .await?;
+
+        self.reload_favorites()
+            .await
+    }
+
+    // favorites are shown when prompt is empty, so main view is updated right away
+    async fn reload_favorites(&self) -> anyhow::Result<()> {
+        let favorites = self.db_repository.get_favorites()
+            .await?
+            .into_iter()
+            .map(|favorite| (PluginId::from_string(favorite.plugin_id), EntrypointId::from_string(favorite.entrypoint_id)))
+            .collect();
+
+        self.search_index.set_favorites(favorites);
+
+        self.frontend_api.request_search_results_update()
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_fallback_order(&self) -> anyhow::Result<Vec<SettingsEntrypointReference>> {
+        let result = self.db_repository.get_fallback_order()
+            .await?
+            .into_iter()
+            .map(|fallback| settings_entrypoint_reference(fallback))
+            .collect();
+
+        Ok(result)
+    }
+
+    pub async fn set_fallback_order(&self, fallbacks: Vec<(PluginId, EntrypointId)>) -> anyhow::Result<()> {
+        let db_fallbacks = fallbacks.iter()
+            .map(|(plugin_id, entrypoint_id)| (plugin_id.to_string(), entrypoint_id.to_string()))
+            .collect();
+
+        self.db_repository.set_fallback_order(db_fallbacks)
+            .await?;
+
+        self.search_index.set_fallback_order(fallbacks);
+
+        self.frontend_api.request_search_results_update()
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn set_preference_value(&self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>, preference_id: String, preference_value: PluginPreferenceUserData) -> anyhow::Result<()> {
         tracing::debug!(target = "plugin", "Setting preference value for plugin id: {:?}, entrypoint_id: {:?}, preference_id: {}", plugin_id, entrypoint_id, preference_id);
 
-        let user_data = plugin_preference_user_data_to_db(preference_value);
+        let preference = match &entrypoint_id {
+            None => {
+                self.db_repository.get_plugin_by_id(&plugin_id.to_string())
+                    .await?
+                    .preferences
+                    .remove(&preference_id)
+            }
+            Some(entrypoint_id) => {
+                self.db_repository.get_entrypoint_by_id(&plugin_id.to_string(), &entrypoint_id.to_string())
+                    .await?
+                    .preferences
+                    .remove(&preference_id)
+            }
+        };
+
+        let preference = preference
+            .ok_or_else(|| anyhow!("Preference '{}' doesn't exist", preference_id))?;
+
+        plugin_preference_from_db(&preference_id, preference)
+            .validate(&preference_value)
+            .with_context(|| format!("Invalid value for preference '{}'", preference_id))?;
+
+        let user_data = plugin_preference_user_data_to_db(preference_value);
+
+        self.db_repository.set_preference_value(plugin_id.to_string(), entrypoint_id.as_ref().map(|id| id.to_string()), preference_id.clone(), user_data)
+            .await?;
+
+        // environment of plugin runtime process cannot be changed after it is started
+        if entrypoint_id.is_none() && self.is_plugin_running(&plugin_id).await {
+            let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())
+                .await?;
+
+            if uses_preference(&plugin.code.environment_variables, &preference_id) {
+                return self.reload_plugin(plugin_id).await
+            }
+        }
+
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::PreferenceChanged {
+                entrypoint_id,
+                preference_id,
+            }
+        });
+
+        Ok(())
+    }
+
+    pub async fn reload_config(&self) -> anyhow::Result<()> {
+        let config_error = self.config_reader.reload_config().await?;
+
+        self.sound_player.set_muted(self.config_reader.general_settings().sound_muted);
+
+        if let Some(config_error) = config_error {
+            if let Err(err) = self.frontend_api.show_config_error(config_error).await {
+                tracing::warn!("Unable to show config error: {:?}", err);
+            }
+        }
+
+        Ok(())
+    }
+
+    // changes made to config file by hand are applied without restart
+    pub async fn watch_config(&self) -> anyhow::Result<()> {
+        let mut config_watcher = ConfigWatcher::new(&self.dirs)?;
+        let shutdown_token = self.shutdown.subsystem_token();
+
+        loop {
+            let changed = tokio::select! {
+                changed = config_watcher.next_change() => changed,
+                _ = shutdown_token.cancelled() => break,
+            };
+
+            if !changed {
+                break
+            }
+
+            tracing::info!("Config file changed, reloading");
+
+            if let Err(err) = self.reload_config().await {
+                tracing::error!("Unable to reload config: {:?}", err);
+                continue
+            }
+
+            // theme and other settings used by frontend are read by it directly
+            if let Err(err) = self.frontend_api.config_reloaded().await {
+                tracing::warn!("Unable to notify frontend about config reload: {:?}", err);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn watch_session_lock(&self) -> anyhow::Result<()> {
+        let mut session_lock_watcher = SessionLockWatcher::new()?;
+        let shutdown_token = self.shutdown.subsystem_token();
+        let mut current_locked = false;
+
+        loop {
+            let locked = tokio::select! {
+                locked = session_lock_watcher.next_change() => locked,
+                _ = shutdown_token.cancelled() => break,
+            };
+
+            let Some(locked) = locked else {
+                break
+            };
+
+            // e.g. both user switch and screen lock notifications are received on macos
+            if locked == current_locked {
+                continue
+            }
+
+            current_locked = locked;
+
+            tracing::info!("Screen lock changed, locked: {}", locked);
+
+            self.clipboard.set_paused(locked);
+
+            if locked {
+                self.idle_coordinator.session_locked()
+            } else {
+                self.idle_coordinator.session_unlocked(self.config_reader.plugin_idle_after())
+            }
+
+            if let Err(err) = self.frontend_api.session_lock_changed(locked).await {
+                tracing::warn!("Unable to notify frontend about screen lock: {:?}", err);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn request_shutdown(&self) {
+        self.shutdown.request()
+    }
+
+    // runtimes can still write plugin data while stopping, so database is closed only after they are stopped
+    pub async fn shutdown(&self) {
+        tracing::info!(target = "plugin", "Stopping all plugins");
+
+        self.run_status_holder.stop_all_plugins();
+
+        if tokio::time::timeout(PLUGIN_STOP_TIMEOUT, self.run_status_holder.all_stopped()).await.is_err() {
+            tracing::warn!(target = "plugin", "Not all plugins stopped in {:?}, closing database anyway", PLUGIN_STOP_TIMEOUT);
+        }
+
+        self.background_writes.close();
+        self.background_writes.wait().await;
+
+        self.db_repository.close().await;
+    }
+
+    pub fn config_revision(&self) -> u64 {
+        self.config_reader.revision()
+    }
+
+    pub fn config_error(&self) -> Option<ConfigError> {
+        self.config_reader.config_error()
+    }
+
+    pub fn general_settings(&self) -> GeneralSettings {
+        self.config_reader.general_settings()
+    }
+
+    pub fn is_managed(&self) -> bool {
+        self.config_reader.managed()
+    }
+
+    // frontend is notified about the change by config watcher, startup settings are applied after restart
+    pub async fn set_general_settings(&self, settings: GeneralSettings) -> anyhow::Result<()> {
+        tracing::info!("Saving general settings: {:?}", settings);
+
+        let config_file = self.dirs.config_file();
+
+        tokio::task::spawn_blocking(move || write_general_settings(&config_file, &settings))
+            .await??;
+
+        self.reload_config().await
+    }
+
+    pub async fn check_for_updates(&self) -> anyhow::Result<Option<AvailableUpdate>> {
+        if !self.config_reader.general_settings().check_for_updates {
+            return Ok(None)
+        }
+
+        // checked again next time settings are opened
+        if !self.connectivity_monitor.is_online() {
+            tracing::debug!("Skipping update check because network is unavailable");
+
+            return Ok(None)
+        }
+
+        let current_version = gauntlet_version();
+
+        tokio::task::spawn_blocking(move || fetch_available_update(current_version))
+            .await?
+    }
+
+    pub async fn get_simple_theme(&self) -> anyhow::Result<SimpleThemeTokens> {
+        let theme = tokio::task::spawn_blocking(gauntlet_client::current_simple_theme)
+            .await?;
+
+        Ok(theme)
+    }
+
+    pub async fn preview_simple_theme(&self, theme: Option<SimpleThemeTokens>) -> anyhow::Result<()> {
+        self.frontend_api.preview_theme(theme).await?;
+
+        Ok(())
+    }
+
+    pub async fn save_simple_theme(&self, name: String, theme: SimpleThemeTokens) -> anyhow::Result<String> {
+        let path = tokio::task::spawn_blocking(move || gauntlet_client::save_simple_theme(&name, theme))
+            .await??;
+
+        tracing::info!("Saved simple theme to {:?}", path);
+
+        Ok(path.to_string_lossy().to_string())
+    }
+
+    pub async fn reload_all_plugins(&self) -> anyhow::Result<()> {
+        tracing::info!("Reloading all plugins");
+
+        self.reload_config().await?;
+
+        for plugin in self.db_repository.list_plugins().await? {
+            let plugin_id = PluginId::from_string(plugin.id);
+            let running = self.is_plugin_running(&plugin_id).await;
+            match (running, plugin.enabled) {
+                (false, true) => {
+                    // one broken plugin should not prevent others from starting
+                    if let Err(err) = self.start_or_defer_plugin(plugin_id.clone()).await {
+                        tracing::error!(target = "plugin", "Unable to start plugin with id {:?}: {:?}", plugin_id, err);
+                    }
+                }
+                (true, false) => {
+                    self.stop_plugin(plugin_id.clone()).await;
+                    self.search_index.remove_for_plugin(plugin_id)?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    // preferences, local storage and data directory are kept unless purged,
+    // and are restored if the plugin is installed again
+    pub async fn remove_plugin(&self, plugin_id: PluginId, purge_data: bool) -> anyhow::Result<()> {
+        tracing::info!(target = "plugin", "Removing plugin with id: {:?}, purge data: {}", plugin_id, purge_data);
+
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())
+            .await?;
+
+        let running = self.is_plugin_running(&plugin_id).await;
+        if running {
+            self.stop_plugin(plugin_id.clone()).await;
+        }
+
+        self.db_repository.remove_plugin(&plugin_id.to_string(), !purge_data).await?;
+        self.search_index.remove_for_plugin(plugin_id.clone())?;
+
+        self.image_store.remove_plugin(&plugin_id);
+        self.view_cache.remove_plugin(&plugin_id);
+        self.undo_stack.remove_plugin(&plugin_id);
+
+        // plugin is already removed from database, leftover files are only logged
+        let (stdout_file, _) = self.dirs.plugin_log_files(&plugin.uuid);
+
+        let mut dirs = vec![
+            self.dirs.plugin_cache(&plugin.uuid)?,
+            stdout_file.parent().expect("log file is always in a directory").to_path_buf(),
+        ];
+
+        if purge_data {
+            dirs.push(self.dirs.plugin_data(&plugin.uuid)?);
+            dirs.push(self.dirs.plugin_local_storage(&plugin.uuid));
+        }
+
+        for dir in dirs {
+            if let Err(err) = remove_dir_if_exists(&dir) {
+                tracing::warn!(target = "plugin", "Unable to remove {:?} of plugin {:?}: {:?}", dir, plugin_id, err);
+            }
+        }
+
+        if let Err(err) = self.icon_cache.clear_plugin_icon_cache_dir(&plugin.uuid) {
+            tracing::warn!(target = "plugin", "Unable to remove icon cache of plugin {:?}: {:?}", plugin_id, err);
+        }
+
+        Ok(())
+    }
+
+    pub async fn plugin_logs(&self, plugin_id: PluginId, stdout_offset: u64, stderr_offset: u64) -> anyhow::Result<PluginLogs> {
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())
+            .await?;
+
+        let plugin_log_reader = self.plugin_log_reader.clone();
+
+        tokio::task::spawn_blocking(move || plugin_log_reader.read_logs(&plugin.uuid, stdout_offset, stderr_offset))
+            .await?
+    }
+
+    pub async fn save_bug_report(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, widget_tree: String, screenshot: Vec<u8>) -> anyhow::Result<String> {
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())
+            .await?;
+
+        let entrypoint = self.db_repository.get_entrypoint_by_id(&plugin_id.to_string(), &entrypoint_id.to_string())
+            .await?;
+
+        let plugin_log_reader = self.plugin_log_reader.clone();
+        let dirs = self.dirs.clone();
+
+        let path = tokio::task::spawn_blocking(move || {
+            let logs = plugin_log_reader.read_logs_excerpt(&plugin.uuid)?;
+
+            write_bug_report(&dirs, BugReport {
+                plugin_id: plugin.id,
+                plugin_name: plugin.name,
+                plugin_version: plugin.code.version,
+                entrypoint_id: entrypoint.id,
+                entrypoint_name: entrypoint.name,
+                widget_tree,
+                screenshot,
+                logs,
+            })
+        }).await??;
+
+        tracing::info!("Bug report for plugin {:?} saved to: {:?}", plugin_id, path);
+
+        Ok(path.display().to_string())
+    }
+
+    pub async fn export_settings(&self, path: String) -> anyhow::Result<()> {
+        tracing::info!("Exporting settings to: {:?}", path);
+
+        let temp_dir = tempfile::tempdir()?;
+        let db_snapshot = temp_dir.path().join("data.db");
+
+        self.db_repository.export_to(&db_snapshot)
+            .await?;
+
+        let plugins = self.db_repository.list_plugins()
+            .await?
+            .into_iter()
+            .map(|plugin| SettingsArchivePlugin {
+                id: plugin.id,
+                uuid: plugin.uuid,
+                name: plugin.name,
+                enabled: plugin.enabled,
+            })
+            .collect();
+
+        let settings_archive = self.settings_archive.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let result = settings_archive.export(Path::new(&path), &db_snapshot, plugins);
+
+            drop(temp_dir);
+
+            result
+        }).await?
+    }
+
+    pub async fn import_settings(&self, path: String) -> anyhow::Result<()> {
+        tracing::info!("Importing settings from: {:?}", path);
+
+        let settings_archive = self.settings_archive.clone();
+
+        let plugins = tokio::task::spawn_blocking(move || settings_archive.import(Path::new(&path)))
+            .await??;
+
+        tracing::info!("Imported settings with {} plugins, they will be applied on next start", plugins.len());
+
+        Ok(())
+    }
+
+    pub async fn import_from_launcher(&self, source: ImportSource, path: String, dry_run: bool) -> anyhow::Result<Vec<ImportItem>> {
+        tracing::info!("Importing {:?} from: {:?}", source, path);
+
+        let launcher_import = self.launcher_import.clone();
+
+        // new scripts are picked up by script commands directory watcher
+        tokio::task::spawn_blocking(move || launcher_import.import(source, Path::new(&path), dry_run))
+            .await?
+    }
+
+    pub async fn get_usage_statistics(&self) -> anyhow::Result<UsageStatistics> {
+        let enabled = self.config_reader.general_settings().usage_statistics;
+
+        let entrypoints = self.db_repository.usage_entrypoint_launches()
+            .await?;
+
+        let daily = self.db_repository.usage_daily(USAGE_STATISTICS_DAYS)
+            .await?;
+
+        let (search_count, total_search_latency_micros) = self.db_repository.usage_search_totals()
+            .await?;
+
+        Ok(usage_statistics(enabled, entrypoints, daily, search_count, total_search_latency_micros))
+    }
+
+    pub async fn export_usage_statistics(&self, path: String) -> anyhow::Result<()> {
+        tracing::info!("Exporting usage statistics to: {:?}", path);
+
+        let rows = self.db_repository.usage_entrypoint_launches_per_day()
+            .await?;
+
+        tokio::task::spawn_blocking(move || write_usage_csv(Path::new(&path), rows))
+            .await?
+    }
+
+    pub async fn get_macros(&self) -> anyhow::Result<Vec<Macro>> {
+        let macros = self.db_repository.get_macros()
+            .await?
+            .into_iter()
+            .map(|item| macro_from_db(item))
+            .collect();
+
+        Ok(macros)
+    }
+
+    pub async fn save_macro(&self, item: Macro) -> anyhow::Result<String> {
+        let name = item.name.trim();
+
+        if name.is_empty() {
+            return Err(anyhow!("Macro name cannot be empty"))
+        }
+
+        if item.steps.is_empty() {
+            return Err(anyhow!("Macro needs at least one step"))
+        }
+
+        for step in &item.steps {
+            match step {
+                MacroStep::RunEntrypoint { plugin_id, .. } if is_macros_plugin(plugin_id) => {
+                    return Err(anyhow!("Macro cannot run other macros"))
+                }
+                MacroStep::Delay { millis } if *millis > MAX_MACRO_STEP_DELAY_MILLIS => {
+                    return Err(anyhow!("Delay cannot be longer than {} ms", MAX_MACRO_STEP_DELAY_MILLIS))
+                }
+                _ => {}
+            }
+        }
+
+        let id = if item.id.is_empty() {
+            Uuid::new_v4().to_string()
+        } else {
+            item.id
+        };
+
+        let steps = item.steps
+            .into_iter()
+            .map(|step| macro_step_to_db(step))
+            .collect();
+
+        self.db_repository.save_macro(&id, name, steps)
+            .await?;
+
+        self.reload_macros_search_index()
+            .await?;
+
+        Ok(id)
+    }
+
+    pub async fn remove_macro(&self, id: String) -> anyhow::Result<()> {
+        self.db_repository.remove_macro(&id)
+            .await?;
+
+        self.reload_macros_search_index()
+            .await
+    }
+
+    pub fn macro_step_candidates(&self) -> Vec<SettingsEntrypointReference> {
+        self.search_index.runnable_entrypoints()
+            .into_iter()
+            .filter(|entrypoint| !is_macros_plugin(&entrypoint.plugin_id))
+            .collect()
+    }
+
+    // permissions from manifest, the ones that were revoked are included, so they can be granted again
+    pub async fn plugin_permissions(&self) -> anyhow::Result<Vec<SettingsPluginPermissions>> {
+        let mut result = vec![];
+
+        for plugin in self.db_repository.list_plugins().await? {
+            let grants = self.db_repository.get_permission_grants(&plugin.id)
+                .await?
+                .into_iter()
+                .map(|grant| ((db_permission_kind_from_str(&grant.kind), grant.value), (grant.granted_at, grant.revoked)))
+                .collect::<HashMap<_, _>>();
+
+            let permissions = plugin.permissions
+                .revocable()
+                .into_iter()
+                .map(|(kind, value)| {
+                    let (granted_at, revoked) = grants.get(&(kind, value.clone()))
+                        .cloned()
+                        .unwrap_or((None, false));
+
+                    SettingsPluginPermission {
+                        kind,
+                        value,
+                        granted_at,
+                        revoked,
+                    }
+                })
+                .collect();
+
+            result.push(SettingsPluginPermissions {
+                plugin_id: PluginId::from_string(plugin.id),
+                plugin_name: plugin.name,
+                permissions,
+            });
+        }
+
+        result.sort_by(|a, b| a.plugin_name.cmp(&b.plugin_name));
+
+        Ok(result)
+    }
+
+    // deno permissions cannot be changed while runtime is running, so it is restarted
+    pub async fn set_plugin_permission_revoked(&self, plugin_id: PluginId, kind: PluginPermissionKind, value: String, revoked: bool) -> anyhow::Result<()> {
+        tracing::info!(target = "plugin", "Setting permission {:?} {:?} of plugin {:?} revoked: {}", kind, value, plugin_id, revoked);
+
+        self.db_repository.set_permission_revoked(&plugin_id.to_string(), kind, &value, revoked)
+            .await?;
+
+        // deferred plugin is started with up-to-date permissions when it is used
+        if self.run_status_holder.is_plugin_running(&plugin_id) {
+            self.reload_plugin(plugin_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn reload_macros_search_index(&self) -> anyhow::Result<()> {
+        let macros = self.db_repository.get_macros()
+            .await?;
+
+        self.search_index.save_for_plugin(macros_plugin_id(), MACROS_PLUGIN_NAME.to_string(), macro_search_items(&macros), true)?;
+
+        Ok(())
+    }
+
+    // steps are run one after another, first failed step stops the macro and is shown to user.
+    // macros are not plugin entrypoints, so they are not tracked in frecency and usage statistics
+    pub async fn run_macro(&self, macro_id: EntrypointId) {
+        let item = match self.db_repository.get_macro(&macro_id.to_string()).await {
+            Ok(Some(item)) => item,
+            Ok(None) => {
+                tracing::warn!(target = "plugin", "Macro with id {:?} doesn't exist", macro_id);
+                return
+            }
+            Err(err) => {
+                tracing::error!(target = "plugin", "Unable to read macro with id {:?}: {:?}", macro_id, err);
+                return
+            }
+        };
+
+        tracing::info!(target = "plugin", "Running macro {:?} with {} steps", item.name, item.steps.len());
+
+        for (index, step) in item.steps.iter().enumerate() {
+            if let Err(err) = self.run_macro_step(step).await {
+                tracing::error!(target = "plugin", "Macro {:?} failed at step {}: {:?}", item.name, index + 1, err);
+
+                let display = format!("Macro '{}' failed at step {}, {}: {:#}", item.name, index + 1, macro_step_display(step), err);
+
+                if let Err(err) = self.frontend_api.show_hud(display).await {
+                    tracing::warn!("Unable to show macro error: {:?}", err);
+                }
+
+                return
+            }
+        }
+    }
+
+    async fn run_macro_step(&self, step: &DbMacroStep) -> anyhow::Result<()> {
+        match step {
+            DbMacroStep::RunEntrypoint { plugin_id, entrypoint_id, action_index, .. } => {
+                let plugin_id = PluginId::from_string(plugin_id);
+                let entrypoint_id = EntrypointId::from_string(entrypoint_id);
+
+                match self.search_index.entrypoint_type(&plugin_id, &entrypoint_id) {
+                    // generated commands exist only in search index, so only their presence there can be checked
+                    Some(SearchResultEntrypointType::GeneratedCommand) => {
+                        self.handle_run_generated_command(plugin_id, entrypoint_id, *action_index)
+                            .await?
+                    }
+                    Some(_) => {
+                        self.run_entrypoint(plugin_id, entrypoint_id, vec![])
+                            .await?
+                    }
+                    None => {
+                        return Err(anyhow!("Entrypoint is not available, its plugin is disabled or it no longer exists"))
+                    }
+                }
+            }
+            DbMacroStep::Delay { millis } => {
+                tokio::time::sleep(Duration::from_millis(*millis))
+                    .await
+            }
+        }
+
+        Ok(())
+    }
+
+    // called after every search, database is written in background to not delay search results
+    pub fn record_search_latency(&self, latency: Duration) {
+        if !self.config_reader.general_settings().usage_statistics {
+            return;
+        }
+
+        let db_repository = self.db_repository.clone();
+
+        self.background_writes.spawn(async move {
+            if let Err(err) = db_repository.record_search_latency(latency.as_micros() as i64).await {
+                tracing::warn!("error occurred when recording search latency {:?}", err)
+            }
+        });
+    }
+
+    pub async fn get_profiles(&self) -> anyhow::Result<Profiles> {
+        let dirs = self.dirs.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut profiles = vec![];
+
+            match std::fs::read_dir(dirs.profiles_dir()) {
+                Ok(entries) => {
+                    for entry in entries {
+                        let entry = entry?;
+
+                        if !entry.file_type()?.is_dir() {
+                            continue
+                        }
+
+                        if let Some(name) = entry.file_name().to_str().filter(|name| is_valid_profile_name(name)) {
+                            profiles.push(name.to_string());
+                        }
+                    }
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => Err(err).context("Unable to read profiles directory")?,
+            }
+
+            profiles.sort();
+            profiles.insert(0, DEFAULT_PROFILE.to_string());
 
-        self.db_repository.set_preference_value(plugin_id.to_string(), entrypoint_id.map(|id| id.to_string()), preference_id, user_data)
-            .await?;
+            // current profile is pinned for the whole process, active one is read again because it could have been changed
+            let active = match std::fs::read_to_string(dirs.active_profile_file()) {
+                Ok(active) => Some(active.trim().to_string()).filter(|active| is_valid_profile_name(active)),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+                Err(err) => Err(err).context("Unable to read active profile")?,
+            };
 
-        Ok(())
+            anyhow::Ok(Profiles {
+                profiles,
+                current: dirs.profile().to_string(),
+                active: active.unwrap_or_else(|| DEFAULT_PROFILE.to_string()),
+            })
+        }).await?
     }
 
-    pub async fn reload_config(&self) -> anyhow::Result<()> {
-        self.config_reader.reload_config().await?;
+    pub async fn set_active_profile(&self, name: String) -> anyhow::Result<()> {
+        if !is_valid_profile_name(&name) {
+            return Err(anyhow!("Profile name can only contain latin letters, numbers, '-' and '_'"))
+        }
 
-        Ok(())
-    }
+        tracing::info!("Setting active profile to: {:?}", name);
 
-    pub async fn reload_all_plugins(&self) -> anyhow::Result<()> {
-        tracing::info!("Reloading all plugins");
+        let dirs = self.dirs.clone();
 
-        self.reload_config().await?;
+        tokio::task::spawn_blocking(move || {
+            let active_profile_file = dirs.active_profile_file();
 
-        for plugin in self.db_repository.list_plugins().await? {
-            let plugin_id = PluginId::from_string(plugin.id);
-            let running = self.run_status_holder.is_plugin_running(&plugin_id);
-            match (running, plugin.enabled) {
-                (false, true) => {
-                    self.start_plugin(plugin_id).await?;
-                }
-                (true, false) => {
-                    self.stop_plugin(plugin_id.clone()).await;
-                    self.search_index.remove_for_plugin(plugin_id)?;
+            if name == DEFAULT_PROFILE {
+                match std::fs::remove_file(&active_profile_file) {
+                    Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err).context("Unable to reset active profile")?,
+                    _ => {}
                 }
-                _ => {}
+            } else {
+                // directory of profile makes it appear in the list even before it is used
+                std::fs::create_dir_all(dirs.profiles_dir().join(&name))
+                    .context("Unable to create profile directory")?;
+
+                std::fs::write(&active_profile_file, &name)
+                    .context("Unable to save active profile")?;
             }
-        }
 
-        Ok(())
+            anyhow::Ok(())
+        }).await?
     }
 
-    pub async fn remove_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
-        tracing::info!(target = "plugin", "Removing plugin with id: {:?}", plugin_id);
+    pub fn get_diagnostics(&self) -> DiagnosticsData {
+        diagnostics_data()
+    }
 
-        let running = self.run_status_holder.is_plugin_running(&plugin_id);
-        if running {
-            self.stop_plugin(plugin_id.clone()).await;
-        }
-        self.db_repository.remove_plugin(&plugin_id.to_string()).await?;
-        self.search_index.remove_for_plugin(plugin_id)?;
-        Ok(())
+    pub fn set_diagnostics_enabled(&self, enabled: bool) {
+        tracing::info!("Diagnostics mode enabled: {}", enabled);
+
+        set_diagnostics_enabled(enabled)
     }
 
-    pub fn handle_inline_view(&self, text: &str) {
+    pub fn handle_inline_view(&self, text: &str, generation: u64) {
         self.send_command(PluginCommand::All {
             data: AllPluginCommandData::OpenInlineView {
-                text: text.to_owned()
+                text: text.to_owned(),
+                generation,
             }
         })
     }
 
-    pub async fn handle_run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
+    // plugins that provide dynamic search results are queried concurrently,
+    // results are sent to frontend separately from results of search index
+    pub fn handle_plugin_search(&self, text: &str, keyword_plugin_id: Option<PluginId>, category: Option<SearchResultCategory>, generation: u64) {
+        self.search_aggregator.start(generation, category);
+
+        if text.trim().is_empty() {
+            return
+        }
+
+        match keyword_plugin_id {
+            Some(plugin_id) => {
+                self.send_command(PluginCommand::One {
+                    id: plugin_id,
+                    data: OnePluginCommandData::SearchQuery {
+                        text: text.to_owned(),
+                        generation,
+                    }
+                })
+            }
+            None => {
+                self.send_command(PluginCommand::All {
+                    data: AllPluginCommandData::SearchQuery {
+                        text: text.to_owned(),
+                        generation,
+                    }
+                })
+            }
+        }
+    }
+
+    // prompt that starts with keyword of enabled plugin followed by space is routed to that plugin,
+    // returns the plugin and the rest of the prompt
+    pub async fn search_keyword_mode(&self, text: &str) -> anyhow::Result<Option<(SearchKeywordMode, String)>> {
+        let Some((keyword, rest)) = text.split_once(' ') else {
+            return Ok(None)
+        };
+
+        if keyword.is_empty() {
+            return Ok(None)
+        }
+
+        let plugin = self.db_repository.list_plugins()
+            .await?
+            .into_iter()
+            .find(|plugin| plugin.enabled && plugin.keyword.as_deref().is_some_and(|plugin_keyword| plugin_keyword.eq_ignore_ascii_case(keyword)));
+
+        let Some(plugin) = plugin else {
+            return Ok(None)
+        };
+
+        let plugin_id = PluginId::from_string(plugin.id);
+
+        // plugin needs to be running to respond to search query
+        if let Err(err) = self.start_deferred_plugin(&plugin_id).await {
+            tracing::error!(target = "plugin", "Unable to start plugin with id {:?}: {:?}", plugin_id, err);
+        }
+
+        let keyword_mode = SearchKeywordMode {
+            plugin_id,
+            plugin_name: plugin.name,
+            keyword: keyword.to_string(),
+        };
+
+        Ok(Some((keyword_mode, rest.trim_start().to_string())))
+    }
+
+    pub async fn handle_run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: HashMap<String, EntrypointArgumentValue>) {
+        if let Err(err) = self.start_deferred_plugin(&plugin_id).await {
+            tracing::error!(target = "plugin", "Unable to start plugin with id {:?}: {:?}", plugin_id, err);
+        }
+
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::RunCommand {
                 entrypoint_id: entrypoint_id.to_string(),
+                arguments,
             }
         });
 
         self.mark_entrypoint_frecency(plugin_id, entrypoint_id).await
     }
 
-    pub async fn handle_run_generated_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, action_index: Option<usize>) {
+    pub async fn handle_run_generated_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, action_index: Option<usize>) -> anyhow::Result<()> {
+        self.start_deferred_plugin(&plugin_id)
+            .await
+            .context("Unable to start plugin")?;
+
+        // command sent to plugin that isn't running is silently dropped
+        if !self.run_status_holder.is_plugin_running(&plugin_id) {
+            return Err(anyhow!("Plugin is not running"))
+        }
+
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::RunGeneratedCommand {
@@ -370,10 +1613,55 @@ impl ApplicationManager {
             }
         });
 
-        self.mark_entrypoint_frecency(plugin_id, entrypoint_id).await
+        self.mark_entrypoint_frecency(plugin_id, entrypoint_id).await;
+
+        Ok(())
+    }
+
+    pub fn handle_argument_suggestions(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, argument_id: String, values: HashMap<String, String>, generation: u64) {
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::RequestArgumentSuggestions {
+                entrypoint_id: entrypoint_id.to_string(),
+                argument_id,
+                values,
+                generation,
+            }
+        })
+    }
+
+    pub fn handle_search_result_preview(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::RequestGeneratedCommandPreview {
+                entrypoint_id: entrypoint_id.to_string(),
+            }
+        })
     }
 
-    pub async fn handle_render_view(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> anyhow::Result<HashMap<String, PhysicalShortcut>> {
+    pub async fn handle_render_view(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> anyhow::Result<(HashMap<String, PhysicalShortcut>, bool, PluginStyle)> {
+        self.start_deferred_plugin(&plugin_id).await?;
+
+        // shown until plugin renders the view again, sent before render command so that fresh view always replaces it
+        match self.view_cache.get_stale(&plugin_id, &entrypoint_id) {
+            Ok(Some(view)) => {
+                self.frontend_api.replace_view(
+                    plugin_id.clone(),
+                    view.plugin_name,
+                    entrypoint_id.clone(),
+                    view.entrypoint_name,
+                    UiRenderLocation::View,
+                    1,
+                    view.container,
+                    view.images,
+                ).await?;
+            }
+            Ok(None) => {}
+            Err(err) => {
+                tracing::warn!(target = "plugin", "Unable to read cached view of entrypoint {:?}: {:?}", entrypoint_id, err);
+            }
+        }
+
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::RenderView {
@@ -383,22 +1671,63 @@ impl ApplicationManager {
 
         self.mark_entrypoint_frecency(plugin_id.clone(), entrypoint_id.clone()).await;
 
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())
+            .await?;
+
+        let style = plugin.code.style;
+
+        let style = PluginStyle {
+            accent_color: style.accent_color,
+            muted_color: style.muted_color,
+            success_color: style.success_color,
+            warning_color: style.warning_color,
+            danger_color: style.danger_color,
+            link_color: style.link_color,
+            content_spacing: style.content_spacing,
+        };
+
         let shortcuts = self.action_shortcuts(plugin_id, entrypoint_id).await?;
 
-        Ok(shortcuts)
+        Ok((shortcuts, plugin.interactive, style))
+    }
+
+    pub fn handle_view_close(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::CloseView { entrypoint_id }
+        })
+    }
+
+    pub fn handle_view_visibility(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, visible: bool) {
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::ViewVisibilityChanged { entrypoint_id, visible }
+        })
+    }
+
+    pub fn handle_window_visibility(&self, visible: bool, active_window: Option<ActiveWindowContext>) {
+        if visible {
+            self.active_window.set(active_window);
+
+            self.idle_coordinator.window_shown()
+        } else {
+            self.idle_coordinator.window_hidden(self.config_reader.plugin_idle_after())
+        }
     }
 
-    pub fn handle_view_close(&self, plugin_id: PluginId) {
+    pub fn handle_view_pop(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
         self.send_command(PluginCommand::One {
             id: plugin_id,
-            data: OnePluginCommandData::CloseView
+            data: OnePluginCommandData::PopView { entrypoint_id }
         })
     }
 
-    pub fn handle_view_event(&self, plugin_id: PluginId, widget_id: UiWidgetId, event_name: String, event_arguments: Vec<UiPropertyValue>) {
+    pub fn handle_view_event(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, render_location: UiRenderLocation, widget_id: UiWidgetId, event_name: String, event_arguments: Vec<UiPropertyValue>) {
         self.send_command(PluginCommand::One {
             id: plugin_id,
             data: OnePluginCommandData::HandleViewEvent {
+                render_location,
+                entrypoint_id,
                 widget_id,
                 event_name,
                 event_arguments
@@ -442,6 +1771,40 @@ impl ApplicationManager {
         }
     }
 
+    pub fn handle_copy_to_clipboard(&self, text: String) -> anyhow::Result<()> {
+        self.clipboard.write_text(text)
+    }
+
+    // noop if there is nothing to undo
+    pub fn handle_undo(&self) {
+        let Some(entry) = self.undo_stack.pop_undo() else {
+            return
+        };
+
+        self.send_command(PluginCommand::One {
+            id: entry.plugin_id,
+            data: OnePluginCommandData::RunUndo {
+                undo_id: entry.undo_id,
+                redo: false,
+            }
+        });
+    }
+
+    // noop if there is nothing to redo
+    pub fn handle_redo(&self) {
+        let Some(entry) = self.undo_stack.pop_redo() else {
+            return
+        };
+
+        self.send_command(PluginCommand::One {
+            id: entry.plugin_id,
+            data: OnePluginCommandData::RunUndo {
+                undo_id: entry.undo_id,
+                redo: true,
+            }
+        });
+    }
+
     pub fn handle_open_settings_window(&self) {
         let current_exe = std::env::current_exe()
             .expect("unable to get current_exe");
@@ -477,7 +1840,7 @@ impl ApplicationManager {
     async fn reload_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
         tracing::info!(target = "plugin", "Reloading plugin with id: {:?}", plugin_id);
 
-        let running = self.run_status_holder.is_plugin_running(&plugin_id);
+        let running = self.is_plugin_running(&plugin_id).await;
         if running {
             self.stop_plugin(plugin_id.clone()).await;
         }
@@ -503,13 +1866,33 @@ impl ApplicationManager {
 
         let plugin_id_str = plugin_id.to_string();
 
+        if self.safe_mode && !plugin_id_str.starts_with("bundled://") {
+            tracing::warn!(target = "plugin", "Not starting plugin with id {:?} because Gauntlet is running in safe mode", plugin_id);
+            return Ok(())
+        }
+
         let plugin = self.db_repository.get_plugin_by_id(&plugin_id_str)
             .await?;
 
-        let entrypoint_names = self.db_repository.get_entrypoints_by_plugin_id(&plugin_id_str)
-            .await?
-            .into_iter()
-            .map(|entrypoint| (EntrypointId::from_string(entrypoint.id), entrypoint.name))
+        let revoked_permissions = self.db_repository.get_revoked_permissions(&plugin_id_str)
+            .await?;
+
+        let permissions = plugin.permissions.without_revoked(&revoked_permissions);
+
+        let entrypoints = self.db_repository.get_entrypoints_by_plugin_id(&plugin_id_str)
+            .await?;
+
+        let cached_view_entrypoint_ids = entrypoints.iter()
+            .filter(|entrypoint| entrypoint.cache_view)
+            .map(|entrypoint| EntrypointId::from_string(&entrypoint.id))
+            .collect::<HashSet<EntrypointId>>();
+
+        let entrypoint_names = entrypoints.into_iter()
+            .map(|entrypoint| {
+                let entrypoint_name = entrypoint.localized_name();
+
+                (EntrypointId::from_string(entrypoint.id), entrypoint_name)
+            })
             .collect::<HashMap<EntrypointId, String>>();
 
         let inline_view_entrypoint_id = self.db_repository.get_inline_view_entrypoint_id_for_plugin(&plugin_id_str)
@@ -517,7 +1900,16 @@ impl ApplicationManager {
 
         let receiver = self.command_broadcaster.subscribe();
 
-        let clipboard_permissions = plugin.permissions
+        let environment_variables = {
+            let environment_variables = plugin.code.environment_variables;
+            let preferences = plugin.preferences;
+            let preferences_user_data = plugin.preferences_user_data;
+
+            tokio::task::spawn_blocking(move || resolve_environment_variables(environment_variables, &preferences, &preferences_user_data))
+                .await?
+        };
+
+        let clipboard_permissions = permissions
             .clipboard
             .into_iter()
             .map(|permission| match permission {
@@ -527,7 +1919,7 @@ impl ApplicationManager {
             })
             .collect();
 
-        let main_search_bar_permissions = plugin.permissions
+        let main_search_bar_permissions = permissions
             .main_search_bar
             .into_iter()
             .map(|permission| match permission {
@@ -535,35 +1927,71 @@ impl ApplicationManager {
             })
             .collect();
 
+        let active_window_permissions = permissions
+            .active_window
+            .into_iter()
+            .map(|permission| match permission {
+                DbPluginActiveWindowPermissions::Read => PluginPermissionsActiveWindow::Read,
+            })
+            .collect();
+
         let data = PluginRuntimeData {
             id: plugin_id,
             uuid: plugin.uuid,
             name: plugin.name,
             entrypoint_names,
-            code: JsPluginCode { js: plugin.code.js },
+            code: JsPluginCode {
+                js: plugin.code.js,
+                runtime: match plugin.code.runtime {
+                    DbPluginRuntime::Deno => JsPluginRuntime::Deno,
+                    DbPluginRuntime::Wasm => JsPluginRuntime::Wasm,
+                },
+            },
+            environment_variables,
             inline_view_entrypoint_id,
             permissions: PluginPermissions {
-                environment: plugin.permissions.environment,
-                network: plugin.permissions.network,
+                environment: permissions.environment,
+                network: permissions.network,
                 filesystem: JsPluginPermissionsFileSystem {
-                    read: plugin.permissions.filesystem.read,
-                    write: plugin.permissions.filesystem.write,
+                    read: permissions.filesystem.read,
+                    write: permissions.filesystem.write,
                 },
                 exec: JsPluginPermissionsExec {
-                    command: plugin.permissions.exec.command,
-                    executable: plugin.permissions.exec.executable,
+                    command: permissions.exec.command,
+                    executable: permissions.exec.executable,
                 },
-                system: plugin.permissions.system,
+                system: permissions.system,
                 clipboard: clipboard_permissions,
-                main_search_bar: main_search_bar_permissions
+                main_search_bar: main_search_bar_permissions,
+                active_window: active_window_permissions,
+                secrets: PluginPermissionsSecrets {
+                    read: permissions.secrets.read,
+                },
+                events: PluginPermissionsEvents {
+                    publish: permissions.events.publish,
+                    subscribe: permissions.events.subscribe,
+                },
             },
             command_receiver: receiver,
+            idle_coordinator: self.idle_coordinator.clone(),
+            connectivity_monitor: self.connectivity_monitor.clone(),
+            remote_host: self.config_reader.remote_plugin_host(&plugin_id_str),
             db_repository: self.db_repository.clone(),
             search_index: self.search_index.clone(),
+            search_aggregator: self.search_aggregator.clone(),
             icon_cache: self.icon_cache.clone(),
+            image_store: self.image_store.clone(),
+            view_cache: self.view_cache.clone(),
+            undo_stack: self.undo_stack.clone(),
+            event_bus: self.event_bus.clone(),
+            active_window: self.active_window.clone(),
+            cached_view_entrypoint_ids,
+            min_render_interval: self.config_reader.plugin_min_render_interval(&plugin_id_str),
+            network_rate_limit: self.config_reader.plugin_network_rate_limit(&plugin_id_str),
             frontend_api: self.frontend_api.clone(),
             dirs: self.dirs.clone(),
             clipboard: self.clipboard.clone(),
+            sound_player: self.sound_player.clone(),
         };
 
         self.start_plugin_runtime(data);
@@ -574,12 +2002,122 @@ impl ApplicationManager {
     async fn stop_plugin(&self, plugin_id: PluginId) {
         tracing::info!(target = "plugin", "Stopping plugin with id: {:?}", plugin_id);
 
+        if self.deferred_plugins.lock().await.remove(&plugin_id) {
+            return
+        }
+
         self.run_status_holder.stop_plugin(&plugin_id)
     }
 
+    // deferred plugins are considered running, their entrypoints are already in search index
+    async fn is_plugin_running(&self, plugin_id: &PluginId) -> bool {
+        self.run_status_holder.is_plugin_running(plugin_id) || self.deferred_plugins.lock().await.contains(plugin_id)
+    }
+
+    // starting all runtimes at once makes startup slow when many plugins are installed,
+    // so runtime is started only when one of plugin entrypoints is used or in background after first search.
+    // results of command generators cannot be known without running them, so such plugins are started right away,
+    // same for plugins subscribing to events of other plugins, events are only delivered to running plugins
+    async fn start_or_defer_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        let plugin_id_str = plugin_id.to_string();
+
+        if !self.config_reader.lazy_plugin_start() || (self.safe_mode && !plugin_id_str.starts_with("bundled://")) {
+            return self.start_plugin(plugin_id).await
+        }
+
+        let has_command_generators = self.db_repository.get_entrypoints_by_plugin_id(&plugin_id_str)
+            .await?
+            .into_iter()
+            .filter(|entrypoint| entrypoint.enabled)
+            .any(|entrypoint| matches!(db_entrypoint_from_str(&entrypoint.entrypoint_type), DbPluginEntrypointType::CommandGenerator));
+
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id_str)
+            .await?;
+
+        // subscriptions exist only while plugin is running, deferred plugin would miss events
+        let subscribes_to_events = !plugin.permissions.events.subscribe.is_empty();
+
+        if has_command_generators || subscribes_to_events {
+            return self.start_plugin(plugin_id).await
+        }
+
+        tracing::info!(target = "plugin", "Deferring start of plugin with id: {:?}", plugin_id);
+
+        reload_plugin_search_index(
+            &self.db_repository,
+            &self.icon_cache,
+            &self.search_index,
+            &plugin_id,
+            &plugin.uuid,
+            vec![],
+            true
+        ).await?;
+
+        self.deferred_plugins.lock().await.insert(plugin_id);
+
+        Ok(())
+    }
+
+    async fn start_deferred_plugin(&self, plugin_id: &PluginId) -> anyhow::Result<()> {
+        // lock is held until runtime is subscribed to commands, so that commands sent right after are not lost
+        let mut deferred_plugins = self.deferred_plugins.lock().await;
+
+        if deferred_plugins.remove(plugin_id) {
+            self.start_plugin(plugin_id.clone()).await?;
+        }
+
+        Ok(())
+    }
+
+    // returns true only once, deferred plugins are started in background after that
+    pub fn should_warm_up_plugins(&self) -> bool {
+        !self.plugins_warmed_up.swap(true, Ordering::SeqCst)
+    }
+
+    pub async fn warm_up_plugins(&self) -> anyhow::Result<()> {
+        let deferred_plugins = self.deferred_plugins.lock().await
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut with_inline_view = vec![];
+        let mut rest = vec![];
+
+        for plugin_id in deferred_plugins {
+            let inline_view_entrypoint_id = self.db_repository.get_inline_view_entrypoint_id_for_plugin(&plugin_id.to_string())
+                .await?;
+
+            match inline_view_entrypoint_id {
+                Some(_) => with_inline_view.push(plugin_id),
+                None => rest.push(plugin_id),
+            }
+        }
+
+        tracing::info!(target = "plugin", "Warming up {} plugins with inline views and {} other plugins", with_inline_view.len(), rest.len());
+
+        // inline views are shown while typing, so these plugins are needed first
+        for plugin_id in with_inline_view.into_iter().chain(rest) {
+            if let Err(err) = self.start_deferred_plugin(&plugin_id).await {
+                tracing::error!(target = "plugin", "Unable to start plugin with id {:?}: {:?}", plugin_id, err);
+            }
+        }
+
+        Ok(())
+    }
+
     fn start_plugin_runtime(&self, data: PluginRuntimeData) {
+        // e.g. plugins that are being warmed up while shutdown is requested
+        if self.shutdown.is_requested() {
+            tracing::info!(target = "plugin", "Not starting plugin with id {:?}, shutdown is in progress", data.id);
+            return
+        }
+
         let run_status_guard = self.run_status_holder.start_block(data.id.clone());
 
+        // registered before runtime process is connected, events published in the meantime
+        // wait in command receiver of the plugin instead of being lost
+        self.event_bus.add_plugin(data.id.clone(), data.permissions.events.subscribe.clone());
+
         tokio::spawn(async {
             start_plugin_runtime(data, run_status_guard)
                 .await
@@ -600,6 +2138,17 @@ impl ApplicationManager {
             tracing::warn!(target = "rpc", "error occurred when marking entrypoint frecency {:?}", err)
         }
 
+        if self.config_reader.general_settings().usage_statistics {
+            let result = self.db_repository.record_entrypoint_launch(&plugin_id.to_string(), &entrypoint_id.to_string())
+                .await;
+
+            if let Err(err) = &result {
+                tracing::warn!(target = "rpc", "error occurred when recording entrypoint launch {:?}", err)
+            }
+        }
+
+        self.search_index.mark_recently_used(plugin_id.clone(), entrypoint_id);
+
         self.request_search_index_refresh(plugin_id);
     }
 
@@ -614,12 +2163,38 @@ impl ApplicationManager {
     }
 }
 
+fn remove_dir_if_exists(dir: &Path) -> anyhow::Result<()> {
+    if dir.exists() {
+        std::fs::remove_dir_all(dir)?;
+    }
+
+    Ok(())
+}
+
+// whitespace around aliases is removed, empty and repeated ones are dropped
+fn unique_aliases(aliases: Vec<String>) -> Vec<String> {
+    let mut unique_aliases = vec![];
+
+    for alias in aliases {
+        let alias = alias.trim().to_owned();
+
+        if !alias.is_empty() && !unique_aliases.contains(&alias) {
+            unique_aliases.push(alias);
+        }
+    }
+
+    unique_aliases
+}
+
 fn plugin_preference_from_db(id: &str, value: DbPluginPreference) -> PluginPreference {
     match value {
-        DbPluginPreference::Number { name, default, description } => {
+        DbPluginPreference::Number { name, default, min, max, step, description } => {
             PluginPreference::Number {
                 name: name.unwrap_or_else(|| id.to_string()),
                 default,
+                min,
+                max,
+                step,
                 description
             }
         },
@@ -675,7 +2250,41 @@ fn plugin_preference_from_db(id: &str, value: DbPluginPreference) -> PluginPrefe
                 description
             }
         },
+        DbPluginPreference::Directory { name, default, description } => {
+            PluginPreference::Directory {
+                name: name.unwrap_or_else(|| id.to_string()),
+                default,
+                description
+            }
+        },
+    }
+}
+
+// instead of failing, user is shown a view that asks to fill in missing preferences
+async fn request_missing_preferences(
+    frontend_api: &dyn FrontendApi,
+    plugin_id: &PluginId,
+    entrypoint_id: &EntrypointId,
+    (plugin_preferences, plugin_preferences_user_data): (&HashMap<String, DbPluginPreference>, &HashMap<String, DbPluginPreferenceUserData>),
+    (entrypoint_preferences, entrypoint_preferences_user_data): (&HashMap<String, DbPluginPreference>, &HashMap<String, DbPluginPreferenceUserData>),
+) -> anyhow::Result<bool> {
+    let plugin_preferences_required = any_preferences_missing_value(plugin_preferences, plugin_preferences_user_data);
+    let entrypoint_preferences_required = any_preferences_missing_value(entrypoint_preferences, entrypoint_preferences_user_data);
+
+    if !plugin_preferences_required && !entrypoint_preferences_required {
+        return Ok(false)
     }
+
+    frontend_api.show_window().await?;
+
+    frontend_api.show_preference_required_view(
+        plugin_id.clone(),
+        entrypoint_id.clone(),
+        plugin_preferences_required,
+        entrypoint_preferences_required,
+    ).await?;
+
+    Ok(true)
 }
 
 fn plugin_preference_user_data_to_db(value: PluginPreferenceUserData) -> DbPluginPreferenceUserData {
@@ -687,6 +2296,7 @@ fn plugin_preference_user_data_to_db(value: PluginPreferenceUserData) -> DbPlugi
         PluginPreferenceUserData::ListOfStrings { value } => DbPluginPreferenceUserData::ListOfStrings { value },
         PluginPreferenceUserData::ListOfNumbers { value } => DbPluginPreferenceUserData::ListOfNumbers { value },
         PluginPreferenceUserData::ListOfEnums { value } => DbPluginPreferenceUserData::ListOfEnums { value },
+        PluginPreferenceUserData::Directory { value } => DbPluginPreferenceUserData::Directory { value },
     }
 }
 
@@ -699,6 +2309,177 @@ fn plugin_preference_user_data_from_db(value: DbPluginPreferenceUserData) -> Plu
         DbPluginPreferenceUserData::ListOfStrings { value, .. } => PluginPreferenceUserData::ListOfStrings { value },
         DbPluginPreferenceUserData::ListOfNumbers { value, .. } => PluginPreferenceUserData::ListOfNumbers { value },
         DbPluginPreferenceUserData::ListOfEnums { value, .. } => PluginPreferenceUserData::ListOfEnums { value },
+        DbPluginPreferenceUserData::Directory { value } => PluginPreferenceUserData::Directory { value },
+    }
+}
+
+fn settings_entrypoint_reference(value: DbEntrypointReference) -> SettingsEntrypointReference {
+    SettingsEntrypointReference {
+        plugin_id: PluginId::from_string(value.plugin_id),
+        plugin_name: value.plugin_name,
+        entrypoint_id: EntrypointId::from_string(value.entrypoint_id),
+        entrypoint_name: value.entrypoint_name,
     }
 }
 
+fn settings_entrypoint_actions(entrypoint: &DbReadPluginEntrypoint) -> anyhow::Result<Vec<SettingsEntrypointAction>> {
+    let shortcuts = entrypoint.action_shortcuts()?;
+
+    entrypoint.actions.iter()
+        .map(|action| {
+            let default_shortcut = action.default_shortcut()?;
+
+            Ok(SettingsEntrypointAction {
+                action_id: action.id.clone(),
+                description: action.description.clone(),
+                shortcut: shortcuts.get(&action.id).cloned().unwrap_or_else(|| default_shortcut.clone()),
+                default_shortcut,
+            })
+        })
+        .collect()
+}
+
+fn action_shortcut_conflict(
+    entrypoint: &DbReadPluginEntrypoint,
+    action_id: &str,
+    shortcut: &PhysicalShortcut,
+    global_shortcut: Option<&PhysicalShortcut>
+) -> anyhow::Result<Option<ActionShortcutConflict>> {
+    if !shortcut.modifier_control && !shortcut.modifier_alt && !shortcut.modifier_meta {
+        return Ok(Some(ActionShortcutConflict::MissingModifier))
+    }
+
+    if global_shortcut == Some(shortcut) {
+        return Ok(Some(ActionShortcutConflict::GlobalShortcut))
+    }
+
+    // keys that frontend handles before looking up plugin actions
+    let builtin_key = matches!(
+        shortcut.physical_key,
+        PhysicalKey::Enter | PhysicalKey::NumpadEnter | PhysicalKey::Escape | PhysicalKey::Tab | PhysicalKey::Backspace
+            | PhysicalKey::ArrowUp | PhysicalKey::ArrowDown | PhysicalKey::ArrowLeft | PhysicalKey::ArrowRight
+    );
+
+    let action_panel_shortcut = shortcut.physical_key == PhysicalKey::KeyK
+        && shortcut.modifier_alt
+        && !shortcut.modifier_control
+        && !shortcut.modifier_meta
+        && !shortcut.modifier_shift;
+
+    let prompt_history_shortcut = shortcut.physical_key == PhysicalKey::KeyR
+        && shortcut.modifier_control
+        && !shortcut.modifier_alt
+        && !shortcut.modifier_meta
+        && !shortcut.modifier_shift;
+
+    let preview_pane_shortcut = shortcut.physical_key == PhysicalKey::KeyP
+        && shortcut.modifier_alt
+        && !shortcut.modifier_control
+        && !shortcut.modifier_meta
+        && !shortcut.modifier_shift;
+
+    let window_pin_shortcut = shortcut.physical_key == PhysicalKey::KeyP
+        && shortcut.modifier_control
+        && shortcut.modifier_shift
+        && !shortcut.modifier_alt
+        && !shortcut.modifier_meta;
+
+    if builtin_key || action_panel_shortcut || prompt_history_shortcut || preview_pane_shortcut || window_pin_shortcut {
+        return Ok(Some(ActionShortcutConflict::Builtin))
+    }
+
+    let shortcuts = entrypoint.action_shortcuts()?;
+
+    let conflicting_action = entrypoint.actions.iter()
+        .filter(|action| action.id != action_id)
+        .find(|action| shortcuts.get(&action.id) == Some(shortcut));
+
+    if let Some(action) = conflicting_action {
+        return Ok(Some(ActionShortcutConflict::Action {
+            action_description: action.description.clone(),
+        }))
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use gauntlet_common::rpc::frontend_api::RecordingFrontendApi;
+
+    use super::*;
+
+    fn string_preference(default: Option<&str>) -> DbPluginPreference {
+        DbPluginPreference::String {
+            name: None,
+            default: default.map(|default| default.to_string()),
+            description: "".to_string(),
+        }
+    }
+
+    fn check(
+        plugin_preferences: HashMap<String, DbPluginPreference>,
+        entrypoint_preferences: HashMap<String, DbPluginPreference>,
+        entrypoint_preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
+    ) -> (bool, Vec<UiRequestData>) {
+        let frontend_api = RecordingFrontendApi::new();
+
+        let missing_preferences = futures::executor::block_on(request_missing_preferences(
+            &frontend_api,
+            &PluginId::from_string("plugin"),
+            &EntrypointId::from_string("entrypoint"),
+            (&plugin_preferences, &HashMap::new()),
+            (&entrypoint_preferences, &entrypoint_preferences_user_data),
+        )).unwrap();
+
+        (missing_preferences, frontend_api.take_requests())
+    }
+
+    #[test]
+    fn no_preferences_missing() {
+        let (missing_preferences, requests) = check(
+            HashMap::from([("a".to_string(), string_preference(Some("value")))]),
+            HashMap::from([("b".to_string(), string_preference(None))]),
+            HashMap::from([("b".to_string(), DbPluginPreferenceUserData::String { value: Some("value".to_string()) })]),
+        );
+
+        assert!(!missing_preferences);
+        assert!(requests.is_empty());
+    }
+
+    #[test]
+    fn plugin_preference_missing() {
+        let (missing_preferences, requests) = check(
+            HashMap::from([("a".to_string(), string_preference(None))]),
+            HashMap::new(),
+            HashMap::new(),
+        );
+
+        assert!(missing_preferences);
+        assert!(matches!(
+            requests.as_slice(),
+            [
+                UiRequestData::ShowWindow,
+                UiRequestData::ShowPreferenceRequiredView { plugin_preferences_required: true, entrypoint_preferences_required: false, .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn entrypoint_preference_missing_value() {
+        let (missing_preferences, requests) = check(
+            HashMap::new(),
+            HashMap::from([("b".to_string(), string_preference(None))]),
+            HashMap::from([("b".to_string(), DbPluginPreferenceUserData::String { value: None })]),
+        );
+
+        assert!(missing_preferences);
+        assert!(matches!(
+            requests.as_slice(),
+            [
+                UiRequestData::ShowWindow,
+                UiRequestData::ShowPreferenceRequiredView { plugin_preferences_required: false, entrypoint_preferences_required: true, .. }
+            ]
+        ));
+    }
+}
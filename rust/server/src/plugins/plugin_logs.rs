@@ -0,0 +1,117 @@
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::{PluginLogLine, PluginLogStream, PluginLogs};
+
+// limits how much is sent in one response, the rest is picked up by the next poll
+const MAX_CHUNK_SIZE: u64 = 256 * 1024;
+
+// how much of the end of each log file is included in bug reports
+const EXCERPT_SIZE: u64 = 32 * 1024;
+
+#[derive(Clone)]
+pub struct PluginLogReader {
+    dirs: Dirs,
+}
+
+impl PluginLogReader {
+    pub fn new(dirs: Dirs) -> Self {
+        Self {
+            dirs
+        }
+    }
+
+    pub fn read_logs(&self, plugin_uuid: &str, stdout_offset: u64, stderr_offset: u64) -> anyhow::Result<PluginLogs> {
+        let (stdout_file, stderr_file) = self.dirs.plugin_log_files(plugin_uuid);
+
+        let stdout_len = file_len(&stdout_file)?;
+        let stderr_len = file_len(&stderr_file)?;
+
+        // log files are recreated when plugin is restarted
+        let reset = stdout_len < stdout_offset || stderr_len < stderr_offset;
+
+        let (stdout_offset, stderr_offset) = if reset {
+            (0, 0)
+        } else {
+            (stdout_offset, stderr_offset)
+        };
+
+        let (stdout_lines, stdout_offset) = read_lines(&stdout_file, stdout_offset)?;
+        let (stderr_lines, stderr_offset) = read_lines(&stderr_file, stderr_offset)?;
+
+        let lines = stdout_lines.into_iter()
+            .map(|text| PluginLogLine { stream: PluginLogStream::Stdout, text })
+            .chain(stderr_lines.into_iter().map(|text| PluginLogLine { stream: PluginLogStream::Stderr, text }))
+            .collect();
+
+        Ok(PluginLogs {
+            lines,
+            stdout_offset,
+            stderr_offset,
+            reset,
+        })
+    }
+
+    pub fn read_logs_excerpt(&self, plugin_uuid: &str) -> anyhow::Result<Vec<PluginLogLine>> {
+        let (stdout_file, stderr_file) = self.dirs.plugin_log_files(plugin_uuid);
+
+        let stdout_lines = read_last_lines(&stdout_file)?;
+        let stderr_lines = read_last_lines(&stderr_file)?;
+
+        let lines = stdout_lines.into_iter()
+            .map(|text| PluginLogLine { stream: PluginLogStream::Stdout, text })
+            .chain(stderr_lines.into_iter().map(|text| PluginLogLine { stream: PluginLogStream::Stderr, text }))
+            .collect();
+
+        Ok(lines)
+    }
+}
+
+fn file_len(path: &Path) -> anyhow::Result<u64> {
+    if !path.exists() {
+        return Ok(0)
+    }
+
+    Ok(std::fs::metadata(path)?.len())
+}
+
+fn read_last_lines(path: &Path) -> anyhow::Result<Vec<String>> {
+    let offset = file_len(path)?.saturating_sub(EXCERPT_SIZE);
+
+    let (mut lines, _) = read_lines(path, offset)?;
+
+    // excerpt most likely starts in the middle of a line
+    if offset > 0 && !lines.is_empty() {
+        lines.remove(0);
+    }
+
+    Ok(lines)
+}
+
+fn read_lines(path: &Path, offset: u64) -> anyhow::Result<(Vec<String>, u64)> {
+    if !path.exists() {
+        return Ok((vec![], 0))
+    }
+
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(offset))?;
+
+    let mut buffer = vec![];
+    file.take(MAX_CHUNK_SIZE).read_to_end(&mut buffer)?;
+
+    // incomplete last line is left for the next read, unless the line doesn't fit into the chunk at all
+    let consumed = match buffer.iter().rposition(|byte| *byte == b'\n') {
+        Some(index) => index + 1,
+        None if buffer.len() as u64 == MAX_CHUNK_SIZE => buffer.len(),
+        None => 0,
+    };
+
+    let lines = String::from_utf8_lossy(&buffer[..consumed])
+        .lines()
+        .map(|line| line.to_string())
+        .collect();
+
+    Ok((lines, offset + consumed as u64))
+}
@@ -0,0 +1,430 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use interprocess::local_socket::tokio::Stream as LocalSocketStream;
+use interprocess::local_socket::traits::tokio::Stream as _;
+#[cfg(unix)]
+use interprocess::local_socket::ToFsName;
+#[cfg(target_os = "windows")]
+use interprocess::local_socket::ToNsName;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{ring, verify_tls12_signature, verify_tls13_signature, CryptoProvider, WebPkiSupportedAlgorithms};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer, ServerName, UnixTime};
+use rustls::server::danger::{ClientCertVerified, ClientCertVerifier};
+use rustls::{ClientConfig, DigitallySignedStruct, DistinguishedName, ServerConfig, SignatureScheme};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::{TlsAcceptor, TlsConnector};
+use uuid::Uuid;
+
+use gauntlet_common::dirs::Dirs;
+
+use crate::plugins::js::{spawn_plugin_runtime_process, PluginRuntimeDirs, PluginRuntimeSocket};
+
+// runtimes of selected plugins can be started on another machine which runs `gauntlet plugin-host`.
+// the launcher sends plugin code over tls the same way it does to local runtime process, so widget trees
+// and api requests are streamed back without runtime knowing it is not on the same machine.
+// each machine has self-signed certificate, both sides only accept certificates which fingerprints they were given
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_HANDSHAKE_MESSAGE_SIZE: u32 = 1024 * 1024;
+
+// certificates are pinned, so name is not checked, but it is required by tls
+const CERTIFICATE_NAME: &'static str = "gauntlet-plugin-host";
+
+#[derive(Clone, Debug)]
+pub struct RemotePluginHost {
+    pub address: String,
+    // sha256 of host certificate, printed by `gauntlet plugin-host --fingerprint`
+    pub host_fingerprint: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RemoteRuntimeRequest {
+    plugin_uuid: String,
+    // already resolved on the launcher side
+    environment_variables: HashMap<String, String>,
+}
+
+// runtime started on the host, connected but not yet forwarding
+pub struct RemoteRuntime {
+    stream: TlsStream<TcpStream>,
+    pub dirs: PluginRuntimeDirs,
+}
+
+impl RemoteRuntime {
+    pub async fn connect(dirs: &Dirs, host: &RemotePluginHost, plugin_uuid: &str, environment_variables: &HashMap<String, String>) -> anyhow::Result<Self> {
+        tracing::info!(target = "plugin", "Starting plugin runtime on remote plugin host {}", &host.address);
+
+        let identity = Identity::load_or_create(dirs)?;
+
+        let fingerprint = identity.fingerprint();
+
+        let config = ClientConfig::builder_with_provider(crypto_provider())
+            .with_protocol_versions(&[&rustls::version::TLS13])?
+            .dangerous()
+            .with_custom_certificate_verifier(PinnedCertificateVerifier::new(vec![host.host_fingerprint.clone()]))
+            .with_client_auth_cert(vec![identity.certificate], identity.key)?;
+
+        let stream = tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect(&host.address))
+            .await
+            .map_err(|_| anyhow!("Timed out connecting to remote plugin host {}", &host.address))?
+            .with_context(|| format!("Unable to connect to remote plugin host {}", &host.address))?;
+
+        stream.set_nodelay(true)?;
+
+        let (stream, dirs) = tokio::time::timeout(HANDSHAKE_TIMEOUT, async {
+            let mut stream = TlsConnector::from(Arc::new(config))
+                .connect(ServerName::try_from(CERTIFICATE_NAME)?, stream)
+                .await
+                .with_context(|| format!("Remote plugin host {} was not verified, or it doesn't trust this machine. Start it with `--trust {}`", &host.address, fingerprint))?;
+
+            let request = RemoteRuntimeRequest {
+                plugin_uuid: plugin_uuid.to_string(),
+                environment_variables: environment_variables.clone(),
+            };
+
+            write_message(&mut stream, &request).await?;
+
+            let dirs = read_message::<PluginRuntimeDirs>(&mut stream).await?;
+
+            anyhow::Ok((stream, dirs))
+        })
+            .await
+            .map_err(|_| anyhow!("Timed out waiting for remote plugin host {}", &host.address))??;
+
+        Ok(Self {
+            stream,
+            dirs,
+        })
+    }
+
+    // takes place of plugin runtime process on the launcher side
+    pub async fn forward(mut self, socket_name: String) -> anyhow::Result<()> {
+        #[cfg(target_os = "windows")]
+        let name = socket_name.to_ns_name::<interprocess::local_socket::GenericNamespaced>()?;
+
+        #[cfg(unix)]
+        let name = socket_name.to_fs_name::<interprocess::os::unix::local_socket::FilesystemUdSocket>()?;
+
+        let mut conn = LocalSocketStream::connect(name).await?;
+
+        tokio::io::copy_bidirectional(&mut self.stream, &mut conn).await?;
+
+        Ok(())
+    }
+}
+
+// same on both machines, launcher fingerprint is passed to host with --trust and host fingerprint is set in launcher config
+pub fn plugin_host_fingerprint() -> anyhow::Result<String> {
+    let identity = Identity::load_or_create(&Dirs::new())?;
+
+    Ok(identity.fingerprint())
+}
+
+// started with `gauntlet plugin-host`, has no window and doesn't load plugins by itself
+pub fn run_plugin_host(listen: String, trusted_launchers: Vec<String>) -> anyhow::Result<()> {
+    if trusted_launchers.is_empty() {
+        return Err(anyhow!("At least one launcher has to be trusted using --trust <fingerprint>, fingerprint is printed by `gauntlet plugin-host --fingerprint` on that machine"))
+    }
+
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .context("unable to start plugin host tokio runtime")?
+        .block_on(serve(listen, trusted_launchers))
+}
+
+async fn serve(listen: String, trusted_launchers: Vec<String>) -> anyhow::Result<()> {
+    let dirs = Dirs::new();
+
+    let identity = Identity::load_or_create(&dirs)?;
+
+    let fingerprint = identity.fingerprint();
+
+    let config = ServerConfig::builder_with_provider(crypto_provider())
+        .with_protocol_versions(&[&rustls::version::TLS13])?
+        .with_client_cert_verifier(PinnedCertificateVerifier::new(trusted_launchers))
+        .with_single_cert(vec![identity.certificate], identity.key)?;
+
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+
+    let listener = TcpListener::bind(&listen)
+        .await
+        .with_context(|| format!("Unable to listen on {}", &listen))?;
+
+    tracing::info!("Plugin host is listening on {}, certificate fingerprint: {}", &listen, fingerprint);
+
+    loop {
+        let (stream, address) = listener.accept().await?;
+
+        let dirs = dirs.clone();
+        let acceptor = acceptor.clone();
+
+        tokio::spawn(async move {
+            match host_plugin_runtime(stream, acceptor, &dirs).await {
+                Ok(()) => tracing::info!("Plugin runtime started by {} has stopped", address),
+                Err(err) => tracing::warn!("Plugin runtime started by {} has stopped with error: {:?}", address, err),
+            }
+        });
+    }
+}
+
+async fn host_plugin_runtime(stream: TcpStream, acceptor: TlsAcceptor, dirs: &Dirs) -> anyhow::Result<()> {
+    stream.set_nodelay(true)?;
+
+    // nothing is read from launcher before its certificate is verified
+    let (mut stream, request) = tokio::time::timeout(HANDSHAKE_TIMEOUT, async {
+        let mut stream = acceptor.accept(stream)
+            .await
+            .context("Launcher was not verified")?;
+
+        let request = read_message::<RemoteRuntimeRequest>(&mut stream).await?;
+
+        anyhow::Ok((stream, request))
+    })
+        .await
+        .map_err(|_| anyhow!("Timed out waiting for launcher"))??;
+
+    // used in paths
+    Uuid::parse_str(&request.plugin_uuid)
+        .context("Invalid plugin uuid")?;
+
+    tracing::info!("Starting plugin runtime for plugin with uuid {}", &request.plugin_uuid);
+
+    let runtime_dirs = PluginRuntimeDirs::new(dirs, &request.plugin_uuid)?;
+
+    let socket = PluginRuntimeSocket::create(dirs, &request.plugin_uuid)?;
+
+    spawn_plugin_runtime_process(&socket.name, &request.environment_variables)?;
+
+    write_message(&mut stream, &runtime_dirs).await?;
+
+    let mut conn = socket.accept().await?;
+
+    tokio::io::copy_bidirectional(&mut stream, &mut conn).await?;
+
+    Ok(())
+}
+
+fn crypto_provider() -> Arc<CryptoProvider> {
+    Arc::new(ring::default_provider())
+}
+
+// self-signed, generated on first use and kept in data directory
+struct Identity {
+    certificate: CertificateDer<'static>,
+    key: PrivateKeyDer<'static>,
+}
+
+impl Identity {
+    fn load_or_create(dirs: &Dirs) -> anyhow::Result<Self> {
+        let (certificate_file, key_file) = dirs.remote_plugin_host_identity()?;
+
+        if certificate_file.exists() && key_file.exists() {
+            let certificate = std::fs::read(&certificate_file)
+                .context("Unable to read remote plugin host certificate")?;
+
+            let key = std::fs::read(&key_file)
+                .context("Unable to read remote plugin host key")?;
+
+            return Ok(Self {
+                certificate: CertificateDer::from(certificate),
+                key: PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key)),
+            })
+        }
+
+        let (certificate, key) = Self::generate()?;
+
+        std::fs::create_dir_all(certificate_file.parent().unwrap())?;
+
+        std::fs::write(&certificate_file, &certificate)?;
+        write_private_file(&key_file, &key)?;
+
+        Ok(Self {
+            certificate: CertificateDer::from(certificate),
+            key: PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key)),
+        })
+    }
+
+    fn generate() -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
+        let certified_key = rcgen::generate_simple_self_signed(vec![CERTIFICATE_NAME.to_string()])
+            .context("Unable to generate remote plugin host certificate")?;
+
+        Ok((certified_key.cert.der().to_vec(), certified_key.key_pair.serialize_der()))
+    }
+
+    fn fingerprint(&self) -> String {
+        certificate_fingerprint(&self.certificate)
+    }
+}
+
+#[cfg(unix)]
+fn write_private_file(path: &Path, data: &[u8]) -> anyhow::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?
+        .write_all(data)?;
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn write_private_file(path: &Path, data: &[u8]) -> anyhow::Result<()> {
+    std::fs::write(path, data)?;
+
+    Ok(())
+}
+
+fn certificate_fingerprint(certificate: &CertificateDer) -> String {
+    Sha256::digest(certificate.as_ref())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+// accepts only certificates with given fingerprints, signatures of handshake are still verified
+#[derive(Debug)]
+struct PinnedCertificateVerifier {
+    fingerprints: Vec<String>,
+    algorithms: WebPkiSupportedAlgorithms,
+}
+
+impl PinnedCertificateVerifier {
+    fn new(fingerprints: Vec<String>) -> Arc<Self> {
+        let fingerprints = fingerprints.into_iter()
+            .map(|fingerprint| fingerprint.replace(':', "").to_lowercase())
+            .collect();
+
+        Arc::new(Self {
+            fingerprints,
+            algorithms: ring::default_provider().signature_verification_algorithms,
+        })
+    }
+
+    fn verify_pinned(&self, certificate: &CertificateDer) -> Result<(), rustls::Error> {
+        let fingerprint = certificate_fingerprint(certificate);
+
+        if self.fingerprints.contains(&fingerprint) {
+            Ok(())
+        } else {
+            tracing::warn!("Rejected remote plugin host connection with certificate fingerprint {}", fingerprint);
+
+            Err(rustls::Error::General(format!("certificate with fingerprint {} is not trusted", fingerprint)))
+        }
+    }
+}
+
+impl ServerCertVerifier for PinnedCertificateVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        self.verify_pinned(end_entity)?;
+
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn verify_tls13_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.algorithms.supported_schemes()
+    }
+}
+
+impl ClientCertVerifier for PinnedCertificateVerifier {
+    fn root_hint_subjects(&self) -> &[DistinguishedName] {
+        &[]
+    }
+
+    fn verify_client_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _now: UnixTime,
+    ) -> Result<ClientCertVerified, rustls::Error> {
+        self.verify_pinned(end_entity)?;
+
+        Ok(ClientCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn verify_tls13_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.algorithms.supported_schemes()
+    }
+}
+
+async fn write_message<T: Serialize>(stream: &mut (impl AsyncWrite + Unpin), value: &T) -> anyhow::Result<()> {
+    let bytes = serde_json::to_vec(value)?;
+
+    stream.write_u32(bytes.len() as u32).await?;
+    stream.write_all(&bytes).await?;
+    stream.flush().await?;
+
+    Ok(())
+}
+
+async fn read_message<T: DeserializeOwned>(stream: &mut (impl AsyncRead + Unpin)) -> anyhow::Result<T> {
+    let len = stream.read_u32().await?;
+
+    if len > MAX_HANDSHAKE_MESSAGE_SIZE {
+        return Err(anyhow!("Remote plugin host message is too big: {} bytes", len))
+    }
+
+    let mut bytes = vec![0u8; len as usize];
+    stream.read_exact(&mut bytes).await?;
+
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_pinned_certificates_are_accepted() {
+        let (trusted, _) = Identity::generate().unwrap();
+        let (other, _) = Identity::generate().unwrap();
+
+        let trusted = CertificateDer::from(trusted);
+        let other = CertificateDer::from(other);
+
+        // fingerprint can be copied in upper case
+        let fingerprint = certificate_fingerprint(&trusted).to_uppercase();
+
+        let verifier = PinnedCertificateVerifier::new(vec![fingerprint]);
+
+        assert!(verifier.verify_pinned(&trusted).is_ok());
+        assert!(verifier.verify_pinned(&other).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use gauntlet_common::model::{EntrypointId, RootWidget, UiRenderLocation};
+
+pub struct PendingRender {
+    pub entrypoint_id: EntrypointId,
+    pub view_stack_depth: usize,
+    pub container: RootWidget,
+}
+
+pub enum RenderDecision {
+    Send(PendingRender),
+    // sent later by flush loop
+    Deferred,
+}
+
+// plugin can have several views open at once, one per entrypoint
+pub type RenderTarget = (UiRenderLocation, EntrypointId);
+
+// plugin that re-renders faster than allowed, e.g. timer, would flood ipc and client.
+// renders of the same view that arrive within interval replace each other, only the latest one is sent
+#[derive(Clone)]
+pub struct RenderCoalescer {
+    min_interval: Duration,
+    targets: Arc<Mutex<HashMap<RenderTarget, TargetState>>>,
+    flush_sender: UnboundedSender<(RenderTarget, Instant)>,
+}
+
+pub struct RenderFlushReceiver {
+    receiver: UnboundedReceiver<(RenderTarget, Instant)>,
+}
+
+#[derive(Default)]
+struct TargetState {
+    last_sent: Option<Instant>,
+    pending: Option<PendingRender>,
+}
+
+impl RenderCoalescer {
+    // zero interval disables coalescing
+    pub fn new(min_interval: Duration) -> (Self, RenderFlushReceiver) {
+        let (flush_sender, receiver) = unbounded_channel();
+
+        let coalescer = Self {
+            min_interval,
+            targets: Arc::new(Mutex::new(HashMap::new())),
+            flush_sender,
+        };
+
+        (coalescer, RenderFlushReceiver { receiver })
+    }
+
+    pub fn submit(&self, render_location: UiRenderLocation, render: PendingRender) -> RenderDecision {
+        let now = Instant::now();
+
+        let target = (render_location, render.entrypoint_id.clone());
+
+        let mut targets = self.targets.lock().unwrap();
+        let state = targets.entry(target.clone()).or_default();
+
+        match state.last_sent {
+            Some(last_sent) if last_sent + self.min_interval > now => {
+                // later renders replace pending one and are sent by the flush that is already scheduled
+                if state.pending.replace(render).is_none() {
+                    let _ = self.flush_sender.send((target, last_sent + self.min_interval));
+                }
+
+                RenderDecision::Deferred
+            }
+            _ => {
+                // flush is late, render that is newer than pending one makes it obsolete
+                state.pending = None;
+                state.last_sent = Some(now);
+
+                RenderDecision::Send(render)
+            }
+        }
+    }
+
+    // none if pending render was already sent or discarded
+    pub fn take_pending(&self, target: &RenderTarget) -> Option<PendingRender> {
+        let mut targets = self.targets.lock().unwrap();
+        let state = targets.entry(target.clone()).or_default();
+
+        let render = state.pending.take()?;
+
+        state.last_sent = Some(Instant::now());
+
+        Some(render)
+    }
+
+    // deferred render must not arrive after view was closed or replaced by error view
+    pub fn discard(&self, render_location: UiRenderLocation, entrypoint_id: &EntrypointId) {
+        let mut targets = self.targets.lock().unwrap();
+
+        if let Some(state) = targets.get_mut(&(render_location, entrypoint_id.clone())) {
+            state.pending = None;
+        }
+    }
+
+    // e.g. inline view which is cleared without knowing which entrypoint rendered it
+    pub fn discard_location(&self, render_location: UiRenderLocation) {
+        let mut targets = self.targets.lock().unwrap();
+
+        for ((location, _), state) in targets.iter_mut() {
+            if *location == render_location {
+                state.pending = None;
+            }
+        }
+    }
+}
+
+impl RenderFlushReceiver {
+    // waits until pending render of returned view can be sent, none if coalescer was dropped
+    pub async fn next_flush(&mut self) -> Option<RenderTarget> {
+        let (target, flush_at) = self.receiver.recv().await?;
+
+        tokio::time::sleep_until(tokio::time::Instant::from_std(flush_at)).await;
+
+        Some(target)
+    }
+}
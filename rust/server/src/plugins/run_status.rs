@@ -1,26 +1,32 @@
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
+use tokio::sync::watch;
 use tokio_util::sync::{CancellationToken, WaitForCancellationFutureOwned};
 
 use gauntlet_common::model::PluginId;
 
 pub struct RunStatusHolder {
-    running_plugins: Arc<Mutex<HashMap<PluginId, CancellationToken>>>
+    running_plugins: Arc<Mutex<HashMap<PluginId, CancellationToken>>>,
+    // number of guards that are not dropped yet, i.e. runtimes which are still stopping are also counted
+    active_guards: Arc<watch::Sender<usize>>,
 }
 
 impl RunStatusHolder {
     pub fn new() -> Self {
         Self {
-            running_plugins: Arc::new(Mutex::new(HashMap::new()))
+            running_plugins: Arc::new(Mutex::new(HashMap::new())),
+            active_guards: Arc::new(watch::Sender::new(0)),
         }
     }
 
     pub fn start_block(&self, plugin_id: PluginId) -> RunStatusGuard {
         let mut running_plugins = self.running_plugins.lock().expect("lock is poisoned");
         running_plugins.insert(plugin_id.clone(), CancellationToken::new());
+        self.active_guards.send_modify(|count| *count += 1);
         RunStatusGuard {
             running_plugins: self.running_plugins.clone(),
+            active_guards: self.active_guards.clone(),
             id: plugin_id,
         }
     }
@@ -38,11 +44,35 @@ impl RunStatusHolder {
             .expect("value should always exist for specified id")
             .cancel()
     }
+
+    // tokens are kept, runtime which is just starting still has to find its token
+    pub fn stop_all_plugins(&self) {
+        let running_plugins = self.running_plugins.lock().expect("lock is poisoned");
+
+        for token in running_plugins.values() {
+            token.cancel()
+        }
+    }
+
+    // resolves when every runtime has finished, including ones which stopped because of an error
+    pub async fn all_stopped(&self) {
+        let mut receiver = self.active_guards.subscribe();
+
+        // sender is owned by self, so it can't be closed while waiting
+        let _ = receiver.wait_for(|count| *count == 0).await;
+    }
 }
 
 pub struct RunStatusGuard {
     id: PluginId,
     running_plugins: Arc<Mutex<HashMap<PluginId, CancellationToken>>>,
+    active_guards: Arc<watch::Sender<usize>>,
+}
+
+impl Drop for RunStatusGuard {
+    fn drop(&mut self) {
+        self.active_guards.send_modify(|count| *count -= 1);
+    }
 }
 
 impl RunStatusGuard {
@@ -0,0 +1,13 @@
+use anyhow::anyhow;
+
+// backed by Secret Service on linux, Keychain on macos and Credential Manager on windows
+pub fn read_secret(service: &str, account: &str) -> anyhow::Result<Option<String>> {
+    let entry = keyring::Entry::new(service, account)
+        .map_err(|err| anyhow!("invalid secret service or account: {}", err))?;
+
+    match entry.get_password() {
+        Ok(secret) => Ok(Some(secret)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(err) => Err(anyhow!("unable to read secret: {}", err)),
+    }
+}
@@ -0,0 +1,126 @@
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+// reports when screen is locked and unlocked, so that launcher is not shown over lock screen
+// and privacy-sensitive subsystems like clipboard access are paused.
+// logind on linux and workspace notifications on macos, not supported on windows
+pub struct SessionLockWatcher {
+    receiver: UnboundedReceiver<bool>,
+}
+
+impl SessionLockWatcher {
+    pub fn new() -> anyhow::Result<Self> {
+        let (sender, receiver) = unbounded_channel();
+
+        platform_watch(sender)?;
+
+        Ok(Self {
+            receiver,
+        })
+    }
+
+    // returns none when watcher stopped producing events
+    pub async fn next_change(&mut self) -> Option<bool> {
+        self.receiver.recv().await
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn platform_watch(sender: UnboundedSender<bool>) -> anyhow::Result<()> {
+    tokio::spawn(async move {
+        if let Err(err) = linux::watch_locked_hint(sender).await {
+            tracing::warn!("unable to watch session lock using logind: {:?}", err);
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use anyhow::Context;
+    use futures::StreamExt;
+    use tokio::sync::mpsc::UnboundedSender;
+    use zbus::{Connection, Proxy};
+
+    const LOGIND_BUS_NAME: &str = "org.freedesktop.login1";
+    // resolves to session of the caller, or to graphical session of the user if caller is not part of any
+    const LOGIND_SESSION_PATH: &str = "/org/freedesktop/login1/session/auto";
+    const LOGIND_SESSION_INTERFACE: &str = "org.freedesktop.login1.Session";
+
+    // LockedHint is set by screen lockers of most desktop environments
+    pub async fn watch_locked_hint(sender: UnboundedSender<bool>) -> anyhow::Result<()> {
+        let connection = Connection::system()
+            .await
+            .context("unable to connect to dbus system bus")?;
+
+        let session = Proxy::new(&connection, LOGIND_BUS_NAME, LOGIND_SESSION_PATH, LOGIND_SESSION_INTERFACE)
+            .await?;
+
+        let mut changes = session.receive_property_changed::<bool>("LockedHint")
+            .await;
+
+        let locked: bool = session.get_property("LockedHint")
+            .await?;
+
+        if sender.send(locked).is_err() {
+            return Ok(())
+        }
+
+        while let Some(change) = changes.next().await {
+            let locked = change.get().await?;
+
+            if sender.send(locked).is_err() {
+                break
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn platform_watch(sender: UnboundedSender<bool>) -> anyhow::Result<()> {
+    use objc2_app_kit::{NSWorkspace, NSWorkspaceSessionDidBecomeActiveNotification, NSWorkspaceSessionDidResignActiveNotification};
+    use objc2_foundation::{NSDistributedNotificationCenter, NSString};
+
+    unsafe {
+        // switching to another user
+        let workspace_center = NSWorkspace::sharedWorkspace().notificationCenter();
+
+        observe(&workspace_center, NSWorkspaceSessionDidResignActiveNotification, true, &sender);
+        observe(&workspace_center, NSWorkspaceSessionDidBecomeActiveNotification, false, &sender);
+
+        // locking the screen, e.g. with Ctrl+Cmd+Q or after screen saver starts
+        let distributed_center = NSDistributedNotificationCenter::defaultCenter();
+
+        observe(&distributed_center, &NSString::from_str("com.apple.screenIsLocked"), true, &sender);
+        observe(&distributed_center, &NSString::from_str("com.apple.screenIsUnlocked"), false, &sender);
+    }
+
+    Ok(())
+}
+
+// notifications are delivered on main thread, which runs event loop of the launcher window
+#[cfg(target_os = "macos")]
+unsafe fn observe(center: &objc2_foundation::NSNotificationCenter, name: &objc2_foundation::NSNotificationName, locked: bool, sender: &UnboundedSender<bool>) {
+    use std::ptr::NonNull;
+
+    use block2::RcBlock;
+    use objc2_foundation::{NSNotification, NSOperationQueue};
+
+    let sender = sender.clone();
+
+    let block = RcBlock::new(move |_: NonNull<NSNotification>| {
+        let _ = sender.send(locked);
+    });
+
+    // observer stays registered for the lifetime of the application
+    let _ = center.addObserverForName_object_queue_usingBlock(Some(name), None, Some(&NSOperationQueue::mainQueue()), &block);
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn platform_watch(_sender: UnboundedSender<bool>) -> anyhow::Result<()> {
+    tracing::debug!("Session lock is not watched on this platform");
+
+    Ok(())
+}
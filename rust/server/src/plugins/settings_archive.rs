@@ -0,0 +1,221 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use gauntlet_common::dirs::Dirs;
+
+// bump when archive layout changes in a way older versions cannot read
+const ARCHIVE_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const DATA_DB_FILE: &str = "data.db";
+const CONFIG_DIR: &str = "config";
+const PLUGIN_DATA_DIR: &str = "plugin_data";
+const PLUGIN_LOCAL_STORAGE_DIR: &str = "plugin_local_storage";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SettingsArchiveManifest {
+    format_version: u32,
+    created_at: u64,
+    plugins: Vec<SettingsArchivePlugin>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsArchivePlugin {
+    pub id: String,
+    pub uuid: String,
+    pub name: String,
+    pub enabled: bool,
+}
+
+#[derive(Clone)]
+pub struct SettingsArchive {
+    dirs: Dirs,
+}
+
+impl SettingsArchive {
+    pub fn new(dirs: Dirs) -> Self {
+        Self {
+            dirs
+        }
+    }
+
+    fn config_files(&self) -> [PathBuf; 3] {
+        [
+            self.dirs.config_file(),
+            self.dirs.complex_theme_file(),
+            self.dirs.theme_simple_file(),
+        ]
+    }
+
+    pub fn export(&self, archive_path: &Path, db_snapshot: &Path, plugins: Vec<SettingsArchivePlugin>) -> anyhow::Result<()> {
+        let file = File::create(archive_path)
+            .context("Unable to create archive file")?;
+
+        let mut zip = ZipWriter::new(file);
+
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated);
+
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        let manifest = SettingsArchiveManifest {
+            format_version: ARCHIVE_FORMAT_VERSION,
+            created_at,
+            plugins: plugins.clone(),
+        };
+
+        zip.start_file(MANIFEST_FILE, options)?;
+        zip.write_all(&serde_json::to_vec_pretty(&manifest)?)?;
+
+        zip.start_file(DATA_DB_FILE, options)?;
+        std::io::copy(&mut File::open(db_snapshot)?, &mut zip)?;
+
+        for config_file in self.config_files() {
+            if !config_file.exists() {
+                continue
+            }
+
+            let file_name = config_file.file_name()
+                .and_then(|file_name| file_name.to_str())
+                .ok_or(anyhow!("Unexpected config file path: {:?}", config_file))?;
+
+            zip.start_file(format!("{}/{}", CONFIG_DIR, file_name), options)?;
+            std::io::copy(&mut File::open(&config_file)?, &mut zip)?;
+        }
+
+        for plugin in plugins {
+            let plugin_data_dir = self.dirs.plugin_data(&plugin.uuid)?;
+            add_dir(&mut zip, options, &plugin_data_dir, &format!("{}/{}", PLUGIN_DATA_DIR, plugin.uuid))?;
+
+            let plugin_local_storage_dir = self.dirs.plugin_local_storage(&plugin.uuid);
+            add_dir(&mut zip, options, &plugin_local_storage_dir, &format!("{}/{}", PLUGIN_LOCAL_STORAGE_DIR, plugin.uuid))?;
+        }
+
+        zip.finish()?;
+
+        Ok(())
+    }
+
+    // database is in use and cannot be replaced while server is running,
+    // so it is staged together with config files and they replace current ones on next start
+    pub fn import(&self, archive_path: &Path) -> anyhow::Result<Vec<SettingsArchivePlugin>> {
+        let file = File::open(archive_path)
+            .context("Unable to open archive file")?;
+
+        let mut zip = ZipArchive::new(file)
+            .context("File is not a valid settings archive")?;
+
+        let manifest: SettingsArchiveManifest = {
+            let manifest = zip.by_name(MANIFEST_FILE)
+                .context("Archive doesn't contain manifest, is it a Gauntlet settings archive?")?;
+
+            serde_json::from_reader(manifest)
+                .context("Unable to read archive manifest")?
+        };
+
+        if manifest.format_version > ARCHIVE_FORMAT_VERSION {
+            return Err(anyhow!("Archive was created by a newer version of Gauntlet, format version: {}", manifest.format_version))
+        }
+
+        if zip.by_name(DATA_DB_FILE).is_err() {
+            return Err(anyhow!("Archive doesn't contain database"))
+        }
+
+        let config_file_names: Vec<_> = self.config_files()
+            .into_iter()
+            .filter_map(|config_file| config_file.file_name().map(|file_name| file_name.to_owned()))
+            .collect();
+
+        // left by previous import which wasn't applied yet
+        let config_import_dir = self.dirs.config_import_dir()?;
+        if config_import_dir.exists() {
+            std::fs::remove_dir_all(&config_import_dir)
+                .context("Unable to remove previously imported config files")?;
+        }
+
+        for index in 0..zip.len() {
+            let mut entry = zip.by_index(index)?;
+
+            if entry.is_dir() {
+                continue
+            }
+
+            // protects from entries with paths outside the archive root
+            let Some(entry_path) = entry.enclosed_name() else {
+                tracing::warn!("Skipping archive entry with unsafe path: {:?}", entry.name());
+                continue
+            };
+
+            let components: Vec<_> = entry_path.components()
+                .filter_map(|component| match component {
+                    Component::Normal(component) => Some(component.to_owned()),
+                    _ => None
+                })
+                .collect();
+
+            let target = match components.as_slice() {
+                [name] if name == DATA_DB_FILE => self.dirs.data_db_import_file()?,
+                [dir, name] if dir == CONFIG_DIR && config_file_names.contains(name) => config_import_dir.join(name),
+                [dir, uuid, rest @ ..] if dir == PLUGIN_DATA_DIR && !rest.is_empty() => {
+                    let uuid = uuid.to_str().ok_or(anyhow!("Unexpected plugin uuid: {:?}", uuid))?;
+
+                    rest.iter().fold(self.dirs.plugin_data(uuid)?, |path, component| path.join(component))
+                }
+                [dir, uuid, rest @ ..] if dir == PLUGIN_LOCAL_STORAGE_DIR && !rest.is_empty() => {
+                    let uuid = uuid.to_str().ok_or(anyhow!("Unexpected plugin uuid: {:?}", uuid))?;
+
+                    rest.iter().fold(self.dirs.plugin_local_storage(uuid), |path, component| path.join(component))
+                }
+                _ => continue,
+            };
+
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            let mut target_file = File::create(&target)
+                .with_context(|| format!("Unable to write file {:?}", target))?;
+
+            std::io::copy(&mut entry, &mut target_file)?;
+        }
+
+        Ok(manifest.plugins)
+    }
+}
+
+fn add_dir(zip: &mut ZipWriter<File>, options: SimpleFileOptions, dir: &Path, archive_dir: &str) -> anyhow::Result<()> {
+    if !dir.exists() {
+        return Ok(())
+    }
+
+    for entry in WalkDir::new(dir) {
+        let entry = entry?;
+
+        if !entry.file_type().is_file() {
+            continue
+        }
+
+        let relative_path = entry.path()
+            .strip_prefix(dir)?
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        zip.start_file(format!("{}/{}", archive_dir, relative_path), options)?;
+        std::io::copy(&mut File::open(entry.path())?, zip)?;
+    }
+
+    Ok(())
+}
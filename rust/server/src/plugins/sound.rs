@@ -0,0 +1,153 @@
+use std::io::Cursor;
+use std::sync::{mpsc, Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+use rodio::source::{SineWave, Zero};
+use gauntlet_plugin_runtime::JsBuiltinSound;
+
+enum SoundRequest {
+    Builtin(JsBuiltinSound),
+    Data(Vec<u8>),
+}
+
+#[derive(Clone)]
+pub struct SoundPlayer {
+    muted: Arc<AtomicBool>,
+    // audio output is opened when first sound is played and is owned by separate thread,
+    // because rodio output stream cannot be sent between threads
+    output: Arc<Mutex<Option<mpsc::Sender<SoundRequest>>>>,
+}
+
+impl SoundPlayer {
+    pub fn new() -> Self {
+        Self {
+            muted: Arc::new(AtomicBool::new(false)),
+            output: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn set_muted(&self, muted: bool) {
+        self.muted.store(muted, Ordering::SeqCst);
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted.load(Ordering::SeqCst)
+    }
+
+    pub fn play_builtin(&self, sound: JsBuiltinSound) -> anyhow::Result<()> {
+        if self.is_muted() {
+            return Ok(())
+        }
+
+        self.send(SoundRequest::Builtin(sound))
+    }
+
+    pub fn play_data(&self, data: Vec<u8>) -> anyhow::Result<()> {
+        if self.is_muted() {
+            return Ok(())
+        }
+
+        // decoded here as well, so that unsupported file is reported to plugin
+        Decoder::new(Cursor::new(data.clone()))
+            .context("Unable to decode sound, supported formats are wav, ogg, mp3 and flac")?;
+
+        self.send(SoundRequest::Data(data))
+    }
+
+    fn send(&self, request: SoundRequest) -> anyhow::Result<()> {
+        let mut output = self.output.lock().expect("lock is poisoned");
+
+        let request = match output.as_ref() {
+            Some(sender) => match sender.send(request) {
+                Ok(()) => return Ok(()),
+                Err(mpsc::SendError(request)) => request,
+            },
+            None => request,
+        };
+
+        // output is not started yet or its thread has exited
+        let sender = start_output()?;
+
+        sender.send(request)
+            .map_err(|_| anyhow!("Audio output thread has exited"))?;
+
+        *output = Some(sender);
+
+        Ok(())
+    }
+}
+
+fn start_output() -> anyhow::Result<mpsc::Sender<SoundRequest>> {
+    let (sender, receiver) = mpsc::channel::<SoundRequest>();
+    let (ready_sender, ready_receiver) = mpsc::channel::<anyhow::Result<()>>();
+
+    thread::Builder::new()
+        .name("sound-output".to_string())
+        .spawn(move || {
+            let (_stream, handle) = match OutputStream::try_default() {
+                Ok(output) => {
+                    let _ = ready_sender.send(Ok(()));
+
+                    output
+                }
+                Err(err) => {
+                    let _ = ready_sender.send(Err(anyhow!(err).context("Unable to open audio output")));
+
+                    return;
+                }
+            };
+
+            for request in receiver {
+                if let Err(err) = play(&handle, request) {
+                    tracing::warn!("Unable to play sound: {:?}", err);
+                }
+            }
+        })?;
+
+    ready_receiver.recv()
+        .context("Audio output thread has exited")??;
+
+    Ok(sender)
+}
+
+fn play(handle: &OutputStreamHandle, request: SoundRequest) -> anyhow::Result<()> {
+    let sink = Sink::try_new(handle)?;
+
+    match request {
+        SoundRequest::Builtin(sound) => {
+            for (frequency, duration_ms) in builtin_sound_tones(sound) {
+                let duration = Duration::from_millis(*duration_ms);
+
+                match frequency {
+                    Some(frequency) => {
+                        sink.append(SineWave::new(*frequency).take_duration(duration).fade_in(Duration::from_millis(5)).amplify(0.2));
+                    }
+                    None => {
+                        sink.append(Zero::<f32>::new(1, 48000).take_duration(duration));
+                    }
+                }
+            }
+        }
+        SoundRequest::Data(data) => {
+            sink.append(Decoder::new(Cursor::new(data))?);
+        }
+    }
+
+    // keeps playing after sink is dropped
+    sink.detach();
+
+    Ok(())
+}
+
+// builtin sounds are generated, so that no audio files need to be shipped.
+// frequency in Hz and duration in milliseconds, none is a pause
+fn builtin_sound_tones(sound: JsBuiltinSound) -> &'static [(Option<f32>, u64)] {
+    match sound {
+        JsBuiltinSound::TimerFinished => &[(Some(880.0), 150), (None, 100), (Some(880.0), 150), (None, 100), (Some(880.0), 300)],
+        JsBuiltinSound::Error => &[(Some(440.0), 150), (Some(330.0), 250)],
+    }
+}
@@ -0,0 +1,184 @@
+use std::net::IpAddr;
+use std::process::Command;
+
+use gauntlet_plugin_runtime::{JsSystemInfo, JsSystemInfoBattery, JsSystemInfoDisk, JsSystemInfoIpAddress};
+use sysinfo::{Disks, Networks, System};
+
+// blocking, wifi and battery are not provided by sysinfo so they are read using platform tools
+pub fn read_system_info() -> anyhow::Result<JsSystemInfo> {
+    Ok(JsSystemInfo {
+        hostname: System::host_name(),
+        os: System::long_os_version(),
+        uptime: System::uptime(),
+        ip_addresses: ip_addresses(),
+        wifi_ssid: wifi_ssid(),
+        battery: battery(),
+        disks: disks(),
+    })
+}
+
+fn ip_addresses() -> Vec<JsSystemInfoIpAddress> {
+    let networks = Networks::new_with_refreshed_list();
+
+    let mut addresses: Vec<_> = networks.iter()
+        .flat_map(|(interface, data)| {
+            data.ip_networks()
+                .iter()
+                .filter(|network| !is_local_address(&network.addr))
+                .map(|network| JsSystemInfoIpAddress {
+                    interface: interface.to_string(),
+                    address: network.addr.to_string(),
+                })
+        })
+        .collect();
+
+    // ipv4 first, it is what people usually look for
+    addresses.sort_by_key(|address| (address.address.contains(':'), address.interface.clone()));
+
+    addresses
+}
+
+fn is_local_address(address: &IpAddr) -> bool {
+    match address {
+        IpAddr::V4(address) => address.is_loopback(),
+        // link-local
+        IpAddr::V6(address) => address.is_loopback() || (address.segments()[0] & 0xffc0) == 0xfe80,
+    }
+}
+
+fn disks() -> Vec<JsSystemInfoDisk> {
+    let disks = Disks::new_with_refreshed_list();
+
+    disks.iter()
+        .filter(|disk| disk.total_space() > 0)
+        // snap packages are mounted as separate read-only file systems
+        .filter(|disk| disk.file_system() != "squashfs")
+        // system volumes on macos share space with root volume
+        .filter(|disk| !(cfg!(target_os = "macos") && disk.mount_point().starts_with("/System/Volumes")))
+        .map(|disk| JsSystemInfoDisk {
+            name: disk.name().to_string_lossy().to_string(),
+            mount_point: disk.mount_point().to_string_lossy().to_string(),
+            total_space: disk.total_space(),
+            available_space: disk.available_space(),
+        })
+        .collect()
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .inspect_err(|err| tracing::debug!("unable to run {}: {:?}", program, err))
+        .ok()?;
+
+    if !output.status.success() {
+        return None
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn wifi_ssid() -> Option<String> {
+    let from_network_manager = command_output("nmcli", &["-t", "-f", "active,ssid", "dev", "wifi"])
+        .and_then(|output| {
+            output.lines()
+                .find_map(|line| line.strip_prefix("yes:"))
+                .map(|ssid| ssid.replace("\\:", ":"))
+        });
+
+    from_network_manager
+        .or_else(|| command_output("iwgetid", &["-r"]).map(|output| output.trim().to_string()))
+        .filter(|ssid| !ssid.is_empty())
+}
+
+#[cfg(target_os = "macos")]
+fn wifi_ssid() -> Option<String> {
+    // en0 is wi-fi interface on all macs that have wi-fi
+    command_output("networksetup", &["-getairportnetwork", "en0"])?
+        .trim()
+        .strip_prefix("Current Wi-Fi Network: ")
+        .map(|ssid| ssid.to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn wifi_ssid() -> Option<String> {
+    command_output("netsh", &["wlan", "show", "interfaces"])?
+        .lines()
+        .find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+
+            // there is also BSSID field
+            if key.trim() == "SSID" {
+                Some(value.trim().to_string())
+            } else {
+                None
+            }
+        })
+        .filter(|ssid| !ssid.is_empty())
+}
+
+#[cfg(target_os = "linux")]
+fn battery() -> Option<JsSystemInfoBattery> {
+    let entries = std::fs::read_dir("/sys/class/power_supply").ok()?;
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| {
+            std::fs::read_to_string(path.join("type"))
+                .is_ok_and(|value| value.trim() == "Battery")
+        })
+        .and_then(|path| {
+            let percentage = std::fs::read_to_string(path.join("capacity"))
+                .ok()?
+                .trim()
+                .parse::<f64>()
+                .ok()?;
+
+            let status = std::fs::read_to_string(path.join("status"))
+                .unwrap_or_default();
+
+            Some(JsSystemInfoBattery {
+                percentage,
+                charging: status.trim() == "Charging",
+            })
+        })
+}
+
+#[cfg(target_os = "macos")]
+fn battery() -> Option<JsSystemInfoBattery> {
+    // e.g. " -InternalBattery-0 (id=1234)	85%; charging; 1:23 remaining present: true"
+    let output = command_output("pmset", &["-g", "batt"])?;
+
+    let regex = regex::Regex::new(r"(\d+)%;\s*([\w ]+);").expect("invalid regex");
+
+    let captures = regex.captures(&output)?;
+
+    Some(JsSystemInfoBattery {
+        percentage: captures[1].parse().ok()?,
+        charging: &captures[2] == "charging",
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn battery() -> Option<JsSystemInfoBattery> {
+    use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    const BATTERY_FLAG_CHARGING: u8 = 8;
+    const BATTERY_FLAG_NO_BATTERY: u8 = 128;
+    const BATTERY_PERCENT_UNKNOWN: u8 = 255;
+
+    let mut status = SYSTEM_POWER_STATUS::default();
+
+    unsafe { GetSystemPowerStatus(&mut status) }.ok()?;
+
+    if status.BatteryFlag & BATTERY_FLAG_NO_BATTERY != 0 || status.BatteryLifePercent == BATTERY_PERCENT_UNKNOWN {
+        return None
+    }
+
+    Some(JsSystemInfoBattery {
+        percentage: status.BatteryLifePercent as f64,
+        charging: status.BatteryFlag & BATTERY_FLAG_CHARGING != 0,
+    })
+}
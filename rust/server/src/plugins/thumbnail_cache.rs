@@ -0,0 +1,207 @@
+use std::fs::File;
+use std::io::{Cursor, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+use anyhow::{anyhow, Context};
+use gauntlet_common::dirs::Dirs;
+use image::{DynamicImage, ImageFormat};
+use sha2::{Digest, Sha256};
+use url::Url;
+
+const THUMBNAIL_SIZE: u32 = 256;
+
+// least recently used thumbnails are removed when cache grows over this size
+const MAX_THUMBNAIL_CACHE_SIZE: u64 = 100 * 1024 * 1024;
+
+const VIDEO_EXTENSIONS: [&str; 8] = ["mp4", "m4v", "mkv", "webm", "mov", "avi", "wmv", "flv"];
+
+// thumbnails are kept on disk, so that files don't need to be decoded again every time plugin view is opened
+#[derive(Clone)]
+pub struct ThumbnailCache {
+    dirs: Dirs,
+}
+
+impl ThumbnailCache {
+    pub fn new(dirs: Dirs) -> Self {
+        Self {
+            dirs
+        }
+    }
+
+    // blocking, generating thumbnail of a large video can take a while
+    pub fn get_thumbnail(&self, source: &str) -> anyhow::Result<Vec<u8>> {
+        if source.starts_with("http://") || source.starts_with("https://") {
+            return self.url_thumbnail(source)
+        }
+
+        let path = if source.starts_with("file://") {
+            Url::parse(source)
+                .ok()
+                .and_then(|url| url.to_file_path().ok())
+                .ok_or_else(|| anyhow!("invalid file url: {}", source))?
+        } else {
+            PathBuf::from(source)
+        };
+
+        self.file_thumbnail(&path)
+    }
+
+    fn file_thumbnail(&self, path: &Path) -> anyhow::Result<Vec<u8>> {
+        let metadata = std::fs::metadata(path)
+            .with_context(|| format!("unable to read metadata of {:?}", path))?;
+
+        let modified = metadata.modified()?
+            .duration_since(UNIX_EPOCH)?
+            .as_secs();
+
+        // changed file gets a new thumbnail, old one is eventually evicted
+        let key = format!("file:{}:{}:{}", path.display(), modified, metadata.len());
+
+        self.get_or_generate(&key, || generate_file_thumbnail(path))
+    }
+
+    fn url_thumbnail(&self, url: &str) -> anyhow::Result<Vec<u8>> {
+        self.get_or_generate(&format!("url:{}", url), || {
+            let bytes = ureq::get(url)
+                .call()?
+                .into_reader()
+                .bytes()
+                .collect::<std::io::Result<Vec<u8>>>()?;
+
+            let image = image::load_from_memory(&bytes)
+                .with_context(|| format!("unable to decode image from {}", url))?;
+
+            encode_thumbnail(image)
+        })
+    }
+
+    fn get_or_generate(&self, key: &str, generate: impl FnOnce() -> anyhow::Result<Vec<u8>>) -> anyhow::Result<Vec<u8>> {
+        let cache_dir = self.dirs.thumbnail_cache_dir();
+        let thumbnail_path = cache_dir.join(format!("{:x}.png", Sha256::digest(key)));
+
+        if let Ok(data) = std::fs::read(&thumbnail_path) {
+            // modification time is used as last access time when evicting
+            let _ = File::options()
+                .write(true)
+                .open(&thumbnail_path)
+                .and_then(|file| file.set_modified(SystemTime::now()));
+
+            return Ok(data)
+        }
+
+        let data = generate()?;
+
+        std::fs::create_dir_all(&cache_dir)?;
+        std::fs::write(&thumbnail_path, &data)
+            .with_context(|| format!("unable to write thumbnail {:?}", &thumbnail_path))?;
+
+        if let Err(err) = evict(&cache_dir) {
+            tracing::warn!("unable to evict thumbnails from cache: {:?}", err);
+        }
+
+        Ok(data)
+    }
+}
+
+fn evict(cache_dir: &Path) -> anyhow::Result<()> {
+    let mut entries = vec![];
+
+    for entry in std::fs::read_dir(cache_dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+
+        if metadata.is_file() {
+            entries.push((entry.path(), metadata.len(), metadata.modified()?));
+        }
+    }
+
+    let mut total_size: u64 = entries.iter()
+        .map(|(_, size, _)| size)
+        .sum();
+
+    if total_size <= MAX_THUMBNAIL_CACHE_SIZE {
+        return Ok(())
+    }
+
+    entries.sort_by_key(|(_, _, modified)| *modified);
+
+    for (path, size, _) in entries {
+        if total_size <= MAX_THUMBNAIL_CACHE_SIZE {
+            break
+        }
+
+        std::fs::remove_file(&path)?;
+
+        total_size -= size;
+    }
+
+    Ok(())
+}
+
+fn generate_file_thumbnail(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let extension = path.extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_lowercase())
+        .unwrap_or_default();
+
+    let image = if extension == "pdf" {
+        // first page only
+        let output = run_tool(
+            Command::new("pdftoppm")
+                .args(["-png", "-singlefile", "-f", "1", "-l", "1", "-scale-to", &THUMBNAIL_SIZE.to_string()])
+                .arg(path)
+        )?;
+
+        image::load_from_memory_with_format(&output, ImageFormat::Png)?
+    } else if VIDEO_EXTENSIONS.contains(&extension.as_str()) {
+        // "thumbnail" filter picks representative frame from the beginning of the video
+        let output = run_tool(
+            Command::new("ffmpeg")
+                .args(["-v", "error", "-i"])
+                .arg(path)
+                .args(["-vf", "thumbnail", "-frames:v", "1", "-f", "image2pipe", "-c:v", "png", "-"])
+        )?;
+
+        image::load_from_memory_with_format(&output, ImageFormat::Png)?
+    } else {
+        image::open(path)
+            .with_context(|| format!("unable to decode image {:?}", path))?
+    };
+
+    encode_thumbnail(image)
+}
+
+// external tools are optional, files which need them just don't get a thumbnail when they are not installed
+fn run_tool(command: &mut Command) -> anyhow::Result<Vec<u8>> {
+    let program = command.get_program().to_string_lossy().to_string();
+
+    let output = command.output()
+        .map_err(|err| {
+            match err.kind() {
+                ErrorKind::NotFound => anyhow!("{} is not installed", program),
+                _ => anyhow!(err).context(format!("unable to run {}", program)),
+            }
+        })?;
+
+    if !output.status.success() {
+        return Err(anyhow!("{} exited with {}: {}", program, output.status, String::from_utf8_lossy(&output.stderr).trim()))
+    }
+
+    Ok(output.stdout)
+}
+
+fn encode_thumbnail(image: DynamicImage) -> anyhow::Result<Vec<u8>> {
+    let image = if image.width() > THUMBNAIL_SIZE || image.height() > THUMBNAIL_SIZE {
+        image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+    } else {
+        image
+    };
+
+    let mut result = Cursor::new(vec![]);
+
+    image.write_to(&mut result, ImageFormat::Png)
+        .context("unable to encode thumbnail")?;
+
+    Ok(result.into_inner())
+}
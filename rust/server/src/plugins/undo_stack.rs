@@ -0,0 +1,86 @@
+use std::sync::{Arc, Mutex};
+
+use gauntlet_common::model::PluginId;
+
+const UNDO_STACK_SIZE: usize = 20;
+
+// undo functions are kept inside plugin runtime, here only order of registered actions is tracked.
+// shared between plugins so that ctrl+z always reverts the latest action regardless of which plugin did it
+#[derive(Clone)]
+pub struct UndoStack {
+    inner: Arc<Mutex<UndoStackInner>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UndoEntry {
+    pub plugin_id: PluginId,
+    pub undo_id: u32,
+    pub description: String,
+}
+
+struct UndoStackInner {
+    undo: Vec<UndoEntry>,
+    redo: Vec<UndoEntry>,
+}
+
+impl UndoStack {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(UndoStackInner {
+                undo: vec![],
+                redo: vec![],
+            })),
+        }
+    }
+
+    // new action makes redo history invalid, same as in text editors
+    pub fn push_action(&self, entry: UndoEntry) {
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+
+        inner.redo.clear();
+
+        push_limited(&mut inner.undo, entry);
+    }
+
+    // action was redone and can be undone again
+    pub fn push_undo(&self, entry: UndoEntry) {
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+
+        push_limited(&mut inner.undo, entry);
+    }
+
+    // action was undone and can be redone
+    pub fn push_redo(&self, entry: UndoEntry) {
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+
+        push_limited(&mut inner.redo, entry);
+    }
+
+    pub fn pop_undo(&self) -> Option<UndoEntry> {
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+
+        inner.undo.pop()
+    }
+
+    pub fn pop_redo(&self) -> Option<UndoEntry> {
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+
+        inner.redo.pop()
+    }
+
+    // undo functions are lost when plugin runtime is stopped
+    pub fn remove_plugin(&self, plugin_id: &PluginId) {
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+
+        inner.undo.retain(|entry| &entry.plugin_id != plugin_id);
+        inner.redo.retain(|entry| &entry.plugin_id != plugin_id);
+    }
+}
+
+fn push_limited(entries: &mut Vec<UndoEntry>, entry: UndoEntry) {
+    entries.push(entry);
+
+    if entries.len() > UNDO_STACK_SIZE {
+        entries.remove(0);
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::{anyhow, Context};
+use serde::Deserialize;
+
+use gauntlet_common::model::AvailableUpdate;
+
+const CHANGELOG: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../CHANGELOG.md"));
+
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/project-gauntlet/gauntlet/releases/latest";
+
+pub fn gauntlet_version() -> u16 {
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../VERSION"))
+        .trim()
+        .parse()
+        .expect("version is not a number?")
+}
+
+// sections of CHANGELOG.md for versions newer than last seen one, newest first as in the file
+pub fn changelog_since(last_seen_version: u16, current_version: u16) -> String {
+    let mut sections = vec![];
+    let mut current_section: Option<Vec<&str>> = None;
+
+    for line in CHANGELOG.lines() {
+        if let Some(header) = line.strip_prefix("## ") {
+            if let Some(section) = current_section.take() {
+                sections.push(section.join("\n").trim().to_string());
+            }
+
+            // e.g. "## [12] - 2024-12-22", unreleased section is skipped
+            let version = header.strip_prefix("[")
+                .and_then(|header| header.split_once("]"))
+                .and_then(|(version, _)| version.parse::<u16>().ok());
+
+            if let Some(version) = version {
+                if last_seen_version < version && version <= current_version {
+                    current_section = Some(vec![line]);
+                }
+            }
+
+            continue;
+        }
+
+        if let Some(section) = &mut current_section {
+            section.push(line);
+        }
+    }
+
+    if let Some(section) = current_section.take() {
+        sections.push(section.join("\n").trim().to_string());
+    }
+
+    sections.join("\n\n")
+}
+
+#[derive(Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    html_url: String,
+}
+
+// blocking, makes request to github api
+pub fn fetch_available_update(current_version: u16) -> anyhow::Result<Option<AvailableUpdate>> {
+    let response = ureq::get(LATEST_RELEASE_URL)
+        .set("User-Agent", "Gauntlet")
+        .set("Accept", "application/vnd.github+json")
+        .call()
+        .context("unable to fetch latest release")?
+        .into_string()?;
+
+    let release: GithubRelease = serde_json::from_str(&response)
+        .context("unable to parse latest release")?;
+
+    // releases are tagged as "v12"
+    let version = release.tag_name
+        .trim_start_matches('v')
+        .parse::<u16>()
+        .map_err(|_| anyhow!("unexpected release tag: {}", release.tag_name))?;
+
+    if version <= current_version {
+        return Ok(None)
+    }
+
+    Ok(Some(AvailableUpdate {
+        version,
+        url: release.html_url,
+    }))
+}
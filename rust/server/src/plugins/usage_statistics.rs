@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::Context;
+
+use gauntlet_common::model::{EntrypointId, PluginId, UsageDayStatistics, UsageEntrypointStatistics, UsagePluginStatistics, UsageStatistics};
+use crate::plugins::data_db_repository::{DbUsageDay, DbUsageEntrypointDay, DbUsageEntrypointLaunches};
+
+// days shown in histogram of statistics tab
+pub const USAGE_STATISTICS_DAYS: u32 = 30;
+
+pub fn usage_statistics(
+    enabled: bool,
+    entrypoints: Vec<DbUsageEntrypointLaunches>,
+    daily: Vec<DbUsageDay>,
+    search_count: i64,
+    total_search_latency_micros: i64,
+) -> UsageStatistics {
+    let mut plugins: Vec<UsagePluginStatistics> = vec![];
+    let mut plugin_index: HashMap<String, usize> = HashMap::new();
+
+    for entrypoint in &entrypoints {
+        match plugin_index.get(&entrypoint.plugin_id) {
+            Some(index) => {
+                plugins[*index].launches += entrypoint.launches as u64;
+            }
+            None => {
+                plugin_index.insert(entrypoint.plugin_id.clone(), plugins.len());
+
+                plugins.push(UsagePluginStatistics {
+                    plugin_id: PluginId::from_string(&entrypoint.plugin_id),
+                    plugin_name: entrypoint.plugin_name.clone(),
+                    launches: entrypoint.launches as u64,
+                });
+            }
+        }
+    }
+
+    plugins.sort_by(|a, b| b.launches.cmp(&a.launches));
+
+    let entrypoints = entrypoints.into_iter()
+        .map(|entrypoint| UsageEntrypointStatistics {
+            plugin_id: PluginId::from_string(entrypoint.plugin_id),
+            plugin_name: entrypoint.plugin_name,
+            entrypoint_id: EntrypointId::from_string(entrypoint.entrypoint_id),
+            entrypoint_name: entrypoint.entrypoint_name,
+            launches: entrypoint.launches as u64,
+        })
+        .collect();
+
+    let daily = daily.into_iter()
+        .map(|day| UsageDayStatistics {
+            date: day.day,
+            launches: day.launches as u64,
+            searches: day.searches as u64,
+        })
+        .collect();
+
+    let average_search_latency_micros = (search_count > 0)
+        .then(|| (total_search_latency_micros / search_count) as u64);
+
+    UsageStatistics {
+        enabled,
+        entrypoints,
+        plugins,
+        daily,
+        search_count: search_count as u64,
+        average_search_latency_micros,
+    }
+}
+
+// one row per entrypoint per day, so that it can be pivoted in spreadsheet
+pub fn write_usage_csv(path: &Path, rows: Vec<DbUsageEntrypointDay>) -> anyhow::Result<()> {
+    let mut content = String::from("date,plugin_id,plugin_name,entrypoint_id,entrypoint_name,launches\n");
+
+    for row in rows {
+        let fields = [
+            row.day,
+            row.plugin_id,
+            row.plugin_name,
+            row.entrypoint_id,
+            row.entrypoint_name,
+            row.launches.to_string(),
+        ];
+
+        let line = fields.iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        content.push_str(&line);
+        content.push('\n');
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(path, content)
+        .context("unable to write usage statistics file")?;
+
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use gauntlet_common::model::{EntrypointId, PluginId, RootWidget, RootWidgetMembers, UiImageId, UiWidgetId};
+
+// last rendered root view of entrypoints which opted in using "cache_view" in plugin manifest,
+// shown right away when view is opened again while plugin renders fresh one in background
+#[derive(Clone)]
+pub struct ViewCache {
+    views: Arc<Mutex<HashMap<PluginId, HashMap<EntrypointId, EncodedView>>>>,
+}
+
+pub struct CachedView {
+    pub plugin_name: String,
+    pub entrypoint_name: String,
+    pub container: RootWidget,
+    pub images: HashMap<UiWidgetId, UiImageId>,
+}
+
+// widget tree is not cloneable, so it is kept encoded
+struct EncodedView {
+    plugin_name: String,
+    entrypoint_name: String,
+    container: Vec<u8>,
+    images: HashMap<UiWidgetId, UiImageId>,
+}
+
+impl ViewCache {
+    pub fn new() -> Self {
+        Self {
+            views: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn insert(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, plugin_name: String, entrypoint_name: String, container: &RootWidget, images: HashMap<UiWidgetId, UiImageId>) -> anyhow::Result<()> {
+        let container = bincode::encode_to_vec(container, bincode::config::standard())?;
+
+        let mut views = self.views.lock().expect("lock is poisoned");
+
+        views.entry(plugin_id)
+            .or_default()
+            .insert(entrypoint_id, EncodedView {
+                plugin_name,
+                entrypoint_name,
+                container,
+                images,
+            });
+
+        Ok(())
+    }
+
+    // returned view is marked as loading, so that user can see that it is not up-to-date yet
+    pub fn get_stale(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> anyhow::Result<Option<CachedView>> {
+        let views = self.views.lock().expect("lock is poisoned");
+
+        let Some(view) = views.get(plugin_id).and_then(|views| views.get(entrypoint_id)) else {
+            return Ok(None)
+        };
+
+        let (mut container, _): (RootWidget, _) = bincode::decode_from_slice(&view.container, bincode::config::standard())?;
+
+        match &mut container.content {
+            Some(RootWidgetMembers::Detail(widget)) => widget.is_loading = Some(true),
+            Some(RootWidgetMembers::Form(widget)) => widget.is_loading = Some(true),
+            Some(RootWidgetMembers::List(widget)) => widget.is_loading = Some(true),
+            Some(RootWidgetMembers::Grid(widget)) => widget.is_loading = Some(true),
+            Some(RootWidgetMembers::Inline(_)) | None => return Ok(None),
+        }
+
+        Ok(Some(CachedView {
+            plugin_name: view.plugin_name.clone(),
+            entrypoint_name: view.entrypoint_name.clone(),
+            container,
+            images: view.images.clone(),
+        }))
+    }
+
+    // widget tree references plugin images which are removed from image store when plugin is stopped
+    pub fn remove_plugin(&self, plugin_id: &PluginId) {
+        let mut views = self.views.lock().expect("lock is poisoned");
+
+        views.remove(plugin_id);
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context};
+use url::Url;
+
+// wait until user stops typing before making a request
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(250);
+const CACHE_DURATION: Duration = Duration::from_secs(5 * 60);
+const CACHE_MAX_ENTRIES: usize = 500;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(3);
+const MAX_SUGGESTIONS: usize = 8;
+
+#[derive(Clone)]
+pub struct WebSearchSuggestions {
+    latest_request: Arc<AtomicU64>,
+    cache: Arc<Mutex<HashMap<(String, String), (Instant, Vec<String>)>>>,
+}
+
+impl WebSearchSuggestions {
+    pub fn new() -> Self {
+        Self {
+            latest_request: Arc::new(AtomicU64::new(0)),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    // returns empty list if request was superseded by a newer one while debouncing
+    pub async fn suggestions(&self, engine: String, query: String) -> anyhow::Result<Vec<String>> {
+        let query = query.trim().to_string();
+
+        if query.is_empty() {
+            return Ok(vec![])
+        }
+
+        let key = (engine.clone(), query.clone());
+
+        if let Some(suggestions) = self.cached(&key) {
+            return Ok(suggestions)
+        }
+
+        let request_id = self.latest_request.fetch_add(1, Ordering::SeqCst) + 1;
+
+        tokio::time::sleep(DEBOUNCE_DURATION).await;
+
+        if self.latest_request.load(Ordering::SeqCst) != request_id {
+            return Ok(vec![])
+        }
+
+        let url = suggestions_url(&engine, &query)?;
+
+        let suggestions = tokio::task::spawn_blocking(move || fetch_suggestions(&url)).await??;
+
+        self.insert(key, suggestions.clone());
+
+        Ok(suggestions)
+    }
+
+    fn cached(&self, key: &(String, String)) -> Option<Vec<String>> {
+        let cache = self.cache.lock().expect("lock is poisoned");
+
+        cache.get(key)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < CACHE_DURATION)
+            .map(|(_, suggestions)| suggestions.clone())
+    }
+
+    fn insert(&self, key: (String, String), suggestions: Vec<String>) {
+        let mut cache = self.cache.lock().expect("lock is poisoned");
+
+        cache.retain(|_, (fetched_at, _)| fetched_at.elapsed() < CACHE_DURATION);
+
+        if cache.len() >= CACHE_MAX_ENTRIES {
+            cache.clear();
+        }
+
+        cache.insert(key, (Instant::now(), suggestions));
+    }
+}
+
+fn suggestions_url(engine: &str, query: &str) -> anyhow::Result<String> {
+    let (base, params) = match engine {
+        "google" => ("https://suggestqueries.google.com/complete/search", vec![("client", "firefox"), ("q", query)]),
+        "duckduckgo" => ("https://duckduckgo.com/ac/", vec![("type", "list"), ("q", query)]),
+        "kagi" => ("https://kagi.com/api/autosuggest", vec![("q", query)]),
+        "bing" => ("https://api.bing.com/osjson.aspx", vec![("query", query)]),
+        _ => return Err(anyhow!("unknown web search engine: {}", engine)),
+    };
+
+    Ok(Url::parse_with_params(base, params)?.to_string())
+}
+
+// blocking, all supported engines respond in opensearch suggestions format: ["query", ["suggestion", ...]]
+fn fetch_suggestions(url: &str) -> anyhow::Result<Vec<String>> {
+    let response = ureq::get(url)
+        .set("User-Agent", "Gauntlet")
+        .timeout(REQUEST_TIMEOUT)
+        .call()
+        .context("unable to fetch web search suggestions")?
+        .into_string()?;
+
+    // engines may add extra elements after suggestions list
+    let response: Vec<serde_json::Value> = serde_json::from_str(&response)
+        .context("unable to parse web search suggestions")?;
+
+    let suggestions = response.into_iter()
+        .nth(1)
+        .ok_or_else(|| anyhow!("unexpected web search suggestions response"))?;
+
+    let suggestions: Vec<String> = serde_json::from_value(suggestions)
+        .context("unable to parse web search suggestions")?;
+
+    Ok(suggestions.into_iter().take(MAX_SUGGESTIONS).collect())
+}
@@ -2,7 +2,8 @@ use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::Arc;
 use gauntlet_common::{settings_env_data_to_string, SettingsEnvData};
-use gauntlet_common::model::{DownloadStatus, EntrypointId, PluginId, PluginPreferenceUserData, SettingsPlugin, UiPropertyValue, SearchResult, UiWidgetId, PhysicalKey, PhysicalShortcut, LocalSaveData};
+use gauntlet_common::error::{ErrorCode, GauntletError};
+use gauntlet_common::model::{DownloadStatus, EntrypointId, PluginId, PluginPreferenceUserData, SettingsInlineView, SettingsPlugin, UiPropertyValue, SearchResult, UiWidgetId, PhysicalKey, PhysicalShortcut, LocalSaveData, PluginLogs, DiagnosticsData, ConfigError, ActionShortcutConflict, SettingsEntrypointUpdate, SettingsEntrypointReference, GeneralSettings, AvailableUpdate, ImportItem, ImportSource, Profiles, UsageStatistics, Macro, SimpleThemeTokens, SettingsPluginPermissions, PluginPermissionKind};
 use gauntlet_common::rpc::backend_server::BackendServer;
 
 use crate::plugins::ApplicationManager;
@@ -19,6 +20,17 @@ impl BackendServerImpl {
             application_manager
         }
     }
+
+    // settings deployed by administrator, changes are rejected regardless of which client sends them
+    fn ensure_not_managed(&self, request_name: &str) -> anyhow::Result<()> {
+        if self.application_manager.is_managed() {
+            tracing::warn!(target = "rpc", "rejected '{}' request because settings are managed", request_name);
+
+            return Err(GauntletError::new(ErrorCode::Managed, "Settings are managed by administrator and cannot be changed").into())
+        }
+
+        Ok(())
+    }
 }
 
 #[tonic::async_trait]
@@ -34,6 +46,38 @@ impl BackendServer for BackendServerImpl {
         Ok(())
     }
 
+    async fn toggle_window(&self) -> anyhow::Result<()> {
+        self.application_manager.toggle_window().await
+    }
+
+    async fn quit(&self) -> anyhow::Result<()> {
+        self.application_manager.request_shutdown();
+
+        Ok(())
+    }
+
+    async fn open_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> anyhow::Result<()> {
+        let result = self.application_manager.open_entrypoint(plugin_id, entrypoint_id)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'open_entrypoint' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn run_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: Vec<String>) -> anyhow::Result<()> {
+        let result = self.application_manager.run_entrypoint(plugin_id, entrypoint_id, arguments)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'run_entrypoint' request {:?}", err)
+        }
+
+        result
+    }
+
     async fn plugins(&self) -> anyhow::Result<Vec<SettingsPlugin>> {
         let result = self.application_manager.plugins()
             .await;
@@ -46,6 +90,8 @@ impl BackendServer for BackendServerImpl {
     }
 
     async fn set_plugin_state(&self, plugin_id: PluginId, enabled: bool) -> anyhow::Result<()> {
+        self.ensure_not_managed("set_plugin_state")?;
+
         let result = self.application_manager.set_plugin_state(plugin_id, enabled)
             .await;
 
@@ -57,6 +103,8 @@ impl BackendServer for BackendServerImpl {
     }
 
     async fn set_entrypoint_state(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, enabled: bool) -> anyhow::Result<()> {
+        self.ensure_not_managed("set_entrypoint_state")?;
+
         let result = self.application_manager.set_entrypoint_state(plugin_id, entrypoint_id, enabled)
             .await;
 
@@ -67,7 +115,35 @@ impl BackendServer for BackendServerImpl {
         Ok(())
     }
 
+    async fn set_entrypoint_hidden(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, hidden: bool) -> anyhow::Result<()> {
+        self.ensure_not_managed("set_entrypoint_hidden")?;
+
+        let result = self.application_manager.set_entrypoint_hidden(plugin_id, entrypoint_id, hidden)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_entrypoint_hidden' request {:?}", err)
+        }
+
+        Ok(())
+    }
+
+    async fn update_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, update: SettingsEntrypointUpdate) -> anyhow::Result<()> {
+        self.ensure_not_managed("update_entrypoint")?;
+
+        let result = self.application_manager.update_entrypoint(plugin_id, entrypoint_id, update)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'update_entrypoint' request {:?}", err)
+        }
+
+        Ok(())
+    }
+
     async fn set_global_shortcut(&self, shortcut: Option<PhysicalShortcut>) -> anyhow::Result<()> {
+        self.ensure_not_managed("set_global_shortcut")?;
+
         let result = self.application_manager.set_global_shortcut(shortcut)
             .await;
 
@@ -86,7 +162,94 @@ impl BackendServer for BackendServerImpl {
         Ok(result)
     }
 
+    async fn set_action_shortcut(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, action_id: String, shortcut: Option<PhysicalShortcut>) -> anyhow::Result<Option<ActionShortcutConflict>> {
+        self.ensure_not_managed("set_action_shortcut")?;
+
+        let result = self.application_manager.set_action_shortcut(plugin_id, entrypoint_id, action_id, shortcut)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_action_shortcut' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_inline_view_order(&self) -> anyhow::Result<Vec<SettingsInlineView>> {
+        let result = self.application_manager.get_inline_view_order()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'get_inline_view_order' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn set_inline_view_order(&self, plugin_ids: Vec<PluginId>) -> anyhow::Result<()> {
+        self.ensure_not_managed("set_inline_view_order")?;
+
+        let result = self.application_manager.set_inline_view_order(plugin_ids)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_inline_view_order' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_favorites(&self) -> anyhow::Result<Vec<SettingsEntrypointReference>> {
+        let result = self.application_manager.get_favorites()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'get_favorites' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn set_favorites(&self, favorites: Vec<(PluginId, EntrypointId)>) -> anyhow::Result<()> {
+        self.ensure_not_managed("set_favorites")?;
+
+        let result = self.application_manager.set_favorites(favorites)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_favorites' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_fallback_order(&self) -> anyhow::Result<Vec<SettingsEntrypointReference>> {
+        let result = self.application_manager.get_fallback_order()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'get_fallback_order' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn set_fallback_order(&self, fallbacks: Vec<(PluginId, EntrypointId)>) -> anyhow::Result<()> {
+        self.ensure_not_managed("set_fallback_order")?;
+
+        let result = self.application_manager.set_fallback_order(fallbacks)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_fallback_order' request {:?}", err)
+        }
+
+        result
+    }
+
     async fn set_preference_value(&self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>, preference_id: String, preference_value: PluginPreferenceUserData) -> anyhow::Result<()> {
+        self.ensure_not_managed("set_preference_value")?;
+
         let result = self.application_manager.set_preference_value(plugin_id, entrypoint_id, preference_id, preference_value)
             .await;
 
@@ -98,6 +261,8 @@ impl BackendServer for BackendServerImpl {
     }
 
     async fn download_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        self.ensure_not_managed("download_plugin")?;
+
         let result = self.application_manager.download_plugin(plugin_id)
             .await;
 
@@ -112,8 +277,10 @@ impl BackendServer for BackendServerImpl {
         Ok(self.application_manager.download_status())
     }
 
-    async fn remove_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
-        let result = self.application_manager.remove_plugin(plugin_id)
+    async fn remove_plugin(&self, plugin_id: PluginId, purge_data: bool) -> anyhow::Result<()> {
+        self.ensure_not_managed("remove_plugin")?;
+
+        let result = self.application_manager.remove_plugin(plugin_id, purge_data)
             .await;
 
         if let Err(err) = &result {
@@ -123,7 +290,243 @@ impl BackendServer for BackendServerImpl {
         Ok(())
     }
 
+    async fn plugin_logs(&self, plugin_id: PluginId, stdout_offset: u64, stderr_offset: u64) -> anyhow::Result<PluginLogs> {
+        let result = self.application_manager.plugin_logs(plugin_id, stdout_offset, stderr_offset)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'plugin_logs' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_diagnostics(&self) -> anyhow::Result<DiagnosticsData> {
+        Ok(self.application_manager.get_diagnostics())
+    }
+
+    async fn set_diagnostics_enabled(&self, enabled: bool) -> anyhow::Result<()> {
+        self.application_manager.set_diagnostics_enabled(enabled);
+
+        Ok(())
+    }
+
+    async fn get_config_error(&self) -> anyhow::Result<Option<ConfigError>> {
+        Ok(self.application_manager.config_error())
+    }
+
+    async fn is_managed(&self) -> anyhow::Result<bool> {
+        Ok(self.application_manager.is_managed())
+    }
+
+    async fn get_config_revision(&self) -> anyhow::Result<u64> {
+        Ok(self.application_manager.config_revision())
+    }
+
+    async fn get_general_settings(&self) -> anyhow::Result<GeneralSettings> {
+        Ok(self.application_manager.general_settings())
+    }
+
+    async fn set_general_settings(&self, settings: GeneralSettings) -> anyhow::Result<()> {
+        self.ensure_not_managed("set_general_settings")?;
+
+        let result = self.application_manager.set_general_settings(settings)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_general_settings' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn check_for_updates(&self) -> anyhow::Result<Option<AvailableUpdate>> {
+        let result = self.application_manager.check_for_updates()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'check_for_updates' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_simple_theme(&self) -> anyhow::Result<SimpleThemeTokens> {
+        self.application_manager.get_simple_theme()
+            .await
+    }
+
+    async fn preview_simple_theme(&self, theme: Option<SimpleThemeTokens>) -> anyhow::Result<()> {
+        self.application_manager.preview_simple_theme(theme)
+            .await
+    }
+
+    async fn save_simple_theme(&self, name: String, theme: SimpleThemeTokens) -> anyhow::Result<String> {
+        self.ensure_not_managed("save_simple_theme")?;
+
+        let result = self.application_manager.save_simple_theme(name, theme)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'save_simple_theme' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn export_settings(&self, path: String) -> anyhow::Result<()> {
+        let result = self.application_manager.export_settings(path)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'export_settings' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn import_settings(&self, path: String) -> anyhow::Result<()> {
+        self.ensure_not_managed("import_settings")?;
+
+        let result = self.application_manager.import_settings(path)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'import_settings' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn import_from_launcher(&self, source: ImportSource, path: String, dry_run: bool) -> anyhow::Result<Vec<ImportItem>> {
+        if !dry_run {
+            self.ensure_not_managed("import_from_launcher")?;
+        }
+
+        let result = self.application_manager.import_from_launcher(source, path, dry_run)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'import_from_launcher' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_profiles(&self) -> anyhow::Result<Profiles> {
+        let result = self.application_manager.get_profiles()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'get_profiles' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn set_active_profile(&self, name: String) -> anyhow::Result<()> {
+        self.ensure_not_managed("set_active_profile")?;
+
+        let result = self.application_manager.set_active_profile(name)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_active_profile' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_usage_statistics(&self) -> anyhow::Result<UsageStatistics> {
+        let result = self.application_manager.get_usage_statistics()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'get_usage_statistics' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn export_usage_statistics(&self, path: String) -> anyhow::Result<()> {
+        let result = self.application_manager.export_usage_statistics(path)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'export_usage_statistics' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_macros(&self) -> anyhow::Result<Vec<Macro>> {
+        let result = self.application_manager.get_macros()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'get_macros' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn save_macro(&self, item: Macro) -> anyhow::Result<String> {
+        self.ensure_not_managed("save_macro")?;
+
+        let result = self.application_manager.save_macro(item)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'save_macro' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn remove_macro(&self, id: String) -> anyhow::Result<()> {
+        self.ensure_not_managed("remove_macro")?;
+
+        let result = self.application_manager.remove_macro(id)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'remove_macro' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_macro_step_candidates(&self) -> anyhow::Result<Vec<SettingsEntrypointReference>> {
+        Ok(self.application_manager.macro_step_candidates())
+    }
+
+    async fn get_plugin_permissions(&self) -> anyhow::Result<Vec<SettingsPluginPermissions>> {
+        let result = self.application_manager.plugin_permissions()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'get_plugin_permissions' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn set_plugin_permission_revoked(&self, plugin_id: PluginId, kind: PluginPermissionKind, value: String, revoked: bool) -> anyhow::Result<()> {
+        self.ensure_not_managed("set_plugin_permission_revoked")?;
+
+        let result = self.application_manager.set_plugin_permission_revoked(plugin_id, kind, value, revoked)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_plugin_permission_revoked' request {:?}", err)
+        }
+
+        result
+    }
+
     async fn save_local_plugin(&self, path: String) -> anyhow::Result<LocalSaveData> {
+        self.ensure_not_managed("save_local_plugin")?;
+
         let result = self.application_manager.save_local_plugin(&path)
             .await?;
 
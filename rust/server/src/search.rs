@@ -1,34 +1,93 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::ops::Range;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use itertools::Itertools;
 use tantivy::{doc, Index, IndexReader, IndexWriter, ReloadPolicy, Searcher};
 use tantivy::collector::TopDocs;
-use tantivy::query::{AllQuery, BooleanQuery, FuzzyTermQuery, Query, RegexQuery, TermQuery};
+use tantivy::query::{AllQuery, BooleanQuery, BoostQuery, ConstScoreQuery, DisjunctionMaxQuery, FuzzyTermQuery, Occur, PhraseQuery, Query, RegexQuery, TermQuery};
 use tantivy::schema::*;
-use tantivy::tokenizer::TokenizerManager;
-use gauntlet_common::model::{EntrypointId, PhysicalShortcut, PluginId, SearchResult, SearchResultEntrypointAction, SearchResultEntrypointType};
+use tantivy::tokenizer::{AsciiFoldingFilter, LowerCaser, RawTokenizer, RemoveLongFilter, SimpleTokenizer, TextAnalyzer, TokenizerManager};
+use gauntlet_common::model::{DragData, EntrypointArgument, EntrypointId, PhysicalShortcut, PluginId, SearchResult, SearchResultActionSource, SearchResultBadge, SearchResultCategory, SearchResultCopyItem, SearchResultCopyKind, SearchResultEntrypointAction, SearchResultEntrypointType, SettingsEntrypointReference};
 use gauntlet_common::rpc::frontend_api::FrontendApi;
+use crate::diagnostics::DIAGNOSTICS_TARGET;
+use crate::search_syntax::{QueryClauseKind, QuerySyntax};
+
+// same as default tokenizer, but diacritics are removed, so "cafe" matches "Café" and the other way around
+const NAME_TOKENIZER: &str = "name";
+// whole entrypoint name as single lowercase token, used to find names starting with the query
+const RAW_LOWERCASE_TOKENIZER: &str = "raw_lowercase";
+
+// boosts are relative to "contains" match which has score of 1
+const WORD_PREFIX_MATCH_BOOST: f32 = 2.0;
+const NAME_PREFIX_MATCH_BOOST: f32 = 4.0;
+const KEYWORD_MATCH_BOOST: f32 = 8.0;
+
+// ranking hints that plugins can set on generated commands
+const MIN_PLUGIN_BOOST: f64 = 0.1;
+const MAX_PLUGIN_BOOST: f64 = 10.0;
+const RECENCY_MAX_BONUS: f64 = 1.0;
+const RECENCY_HALF_LIFE_MILLIS: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+
+// entrypoints shown in "Recent" section when prompt is empty
+const RECENTLY_USED_LIMIT: usize = 5;
+pub const RECENTLY_USED_HISTORY_SIZE: usize = 20;
 
 #[derive(Clone)]
 pub struct SearchIndex {
-    frontend_api: FrontendApi,
-    index: Index,
-    index_reader: IndexReader,
-    index_writer_mutex: Arc<Mutex<()>>,
+    frontend_api: Arc<dyn FrontendApi>,
+    schema: Schema,
+    // readers always search complete index, on every change new index is built on the side and swapped in once it is ready,
+    // so that queries don't come back empty while plugins are reloaded
+    active_buffer: Arc<RwLock<Arc<SearchIndexBuffer>>>,
+    // source of every rebuilt index, lock is held while index is being built, so only one is built at a time
+    documents: Arc<Mutex<HashMap<PluginId, Vec<TantivyDocument>>>>,
 
     entrypoint_data: Arc<Mutex<HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>>>,
+    // separate from entrypoint data, so that badges survive search index reload
+    entrypoint_badges: Arc<Mutex<HashMap<PluginId, HashMap<EntrypointId, SearchResultBadge>>>>,
+    // most recently used first, more entries than shown are kept in case some of them are removed or hidden
+    recently_used: Arc<Mutex<Vec<(PluginId, EntrypointId)>>>,
+    // both ordered by user in settings
+    favorites: Arc<Mutex<Vec<(PluginId, EntrypointId)>>>,
+    fallback_order: Arc<Mutex<Vec<(PluginId, EntrypointId)>>>,
 
     entrypoint_name: Field,
+    entrypoint_name_raw: Field,
+    entrypoint_name_transliterated: Field,
     entrypoint_id: Field,
     plugin_name: Field,
+    plugin_name_transliterated: Field,
     plugin_id: Field,
+    keywords: Field,
+    category: Field,
+}
+
+struct SearchIndexBuffer {
+    index: Index,
+    index_reader: IndexReader,
 }
 
 struct EntrypointData {
     entrypoint_type: SearchResultEntrypointType,
+    entrypoint_name: String,
+    plugin_name: String,
     icon_path: Option<String>,
     frecency: f64,
     actions: Vec<EntrypointActionData>,
+    accessory: Option<String>,
+    preview: bool,
+    drag: Option<DragData>,
+    arguments: Vec<EntrypointArgument>,
+    fallback: bool,
+    hidden: bool,
+    generator_entrypoint_id: Option<EntrypointId>,
+    boost: f64,
+    timestamp: Option<f64>,
+    dedupe_key: Option<String>,
+    category: SearchResultCategory,
+    aliases: Vec<String>,
 }
 
 struct EntrypointActionData {
@@ -44,6 +103,18 @@ pub struct SearchIndexItem {
     pub entrypoint_icon_path: Option<String>,
     pub entrypoint_frecency: f64,
     pub entrypoint_actions: Vec<SearchIndexItemAction>,
+    pub entrypoint_accessory: Option<String>,
+    pub entrypoint_preview: bool,
+    pub entrypoint_drag: Option<DragData>,
+    pub entrypoint_arguments: Vec<EntrypointArgument>,
+    pub entrypoint_fallback: bool,
+    pub entrypoint_hidden: bool,
+    pub entrypoint_generator_id: Option<EntrypointId>,
+    pub entrypoint_ranking: SearchIndexItemRanking,
+    pub entrypoint_dedupe_key: Option<String>,
+    pub entrypoint_category: SearchResultCategory,
+    // set by user, shown in search results. they are also expected to be in ranking keywords
+    pub entrypoint_aliases: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -52,86 +123,223 @@ pub struct SearchIndexItemAction {
     pub shortcut: Option<PhysicalShortcut>,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct SearchIndexItemRanking {
+    pub boost: Option<f64>,
+    pub keywords: Vec<String>,
+    // milliseconds since unix epoch
+    pub timestamp: Option<f64>,
+}
+
+// generated entrypoint ids are not stable and are not meaningful to user, so they are not offered for copying
+pub fn search_result_copy_items(entrypoint_id: &EntrypointId, entrypoint_name: &str, entrypoint_type: &SearchResultEntrypointType, drag: &Option<DragData>) -> Vec<SearchResultCopyItem> {
+    let mut items = vec![
+        SearchResultCopyItem {
+            kind: SearchResultCopyKind::Name,
+            value: entrypoint_name.to_string(),
+        }
+    ];
+
+    match drag {
+        Some(DragData::File(path)) => items.push(SearchResultCopyItem {
+            kind: SearchResultCopyKind::Path,
+            value: path.clone(),
+        }),
+        Some(DragData::Url(url)) => items.push(SearchResultCopyItem {
+            kind: SearchResultCopyKind::Url,
+            value: url.clone(),
+        }),
+        None => {}
+    }
+
+    match entrypoint_type {
+        SearchResultEntrypointType::Command | SearchResultEntrypointType::View => items.push(SearchResultCopyItem {
+            kind: SearchResultCopyKind::Id,
+            value: entrypoint_id.to_string(),
+        }),
+        SearchResultEntrypointType::GeneratedCommand => {}
+    }
+
+    items
+}
+
+// same as in the index, matching is case-insensitive and ignores diacritics,
+// names in other scripts are also compared by their latin transliteration
+fn fold_char(character: char, folded: &mut String) {
+    let transliterated = if character.is_ascii() {
+        None
+    } else {
+        deunicode::deunicode_char(character).map(|value| value.trim())
+    };
+
+    match transliterated {
+        Some(value) if !value.is_empty() => folded.extend(value.chars().flat_map(char::to_lowercase)),
+        _ => folded.extend(character.to_lowercase()),
+    }
+}
+
+// each term is highlighted once, occurrence at the start of a word is preferred, same as in ranking
+fn name_matches(name: &str, terms: &[String]) -> Vec<Range<usize>> {
+    let mut folded = String::new();
+    // byte range of the char in the name, for each byte of the folded name
+    let mut origins: Vec<Range<usize>> = vec![];
+
+    for (index, character) in name.char_indices() {
+        let start = folded.len();
+        fold_char(character, &mut folded);
+        origins.extend(std::iter::repeat(index..index + character.len_utf8()).take(folded.len() - start));
+    }
+
+    let is_word_start = |index: usize| {
+        name[..index].chars()
+            .next_back()
+            .map_or(true, |character| !character.is_alphanumeric())
+    };
+
+    let mut matches: Vec<Range<usize>> = terms.iter()
+        .filter_map(|term| {
+            let mut folded_term = String::new();
+            for character in term.chars() {
+                fold_char(character, &mut folded_term);
+            }
+
+            if folded_term.is_empty() {
+                return None
+            }
+
+            let start = folded.match_indices(&folded_term)
+                .map(|(start, _)| start)
+                .find_or_first(|start| is_word_start(origins[*start].start))?;
+
+            let end = start + folded_term.len() - 1;
+
+            Some(origins[start].start..origins[end].end)
+        })
+        .collect();
+
+    matches.sort_by_key(|range| range.start);
+
+    // overlapping matches of different terms are shown as one
+    matches.into_iter()
+        .fold(vec![], |mut merged: Vec<Range<usize>>, range| {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+            merged
+        })
+}
+
 impl SearchIndex {
-    pub fn create_index(frontend_api: FrontendApi) -> tantivy::Result<Self> {
+    pub fn create_index(frontend_api: Arc<dyn FrontendApi>) -> tantivy::Result<Self> {
         let schema = {
             let mut schema_builder = Schema::builder();
 
-            schema_builder.add_text_field("entrypoint_name", TEXT | STORED);
+            let indexed_with = |tokenizer: &str| {
+                TextOptions::default().set_indexing_options(
+                    TextFieldIndexing::default()
+                        .set_tokenizer(tokenizer)
+                        .set_index_option(IndexRecordOption::WithFreqsAndPositions)
+                )
+            };
+
+            schema_builder.add_text_field("entrypoint_name", indexed_with(NAME_TOKENIZER) | STORED);
+            schema_builder.add_text_field("entrypoint_name_raw", indexed_with(RAW_LOWERCASE_TOKENIZER));
+            schema_builder.add_text_field("entrypoint_name_transliterated", indexed_with(NAME_TOKENIZER));
             schema_builder.add_text_field("entrypoint_id", STRING | STORED);
-            schema_builder.add_text_field("plugin_name", TEXT | STORED);
+            schema_builder.add_text_field("plugin_name", indexed_with(NAME_TOKENIZER) | STORED);
+            schema_builder.add_text_field("plugin_name_transliterated", indexed_with(NAME_TOKENIZER));
             schema_builder.add_text_field("plugin_id", STRING | STORED);
+            schema_builder.add_text_field("keywords", indexed_with(RAW_LOWERCASE_TOKENIZER));
+            schema_builder.add_text_field("category", STRING);
 
             schema_builder.build()
         };
 
         let entrypoint_name = schema.get_field("entrypoint_name").expect("entrypoint_name field should exist");
+        let entrypoint_name_raw = schema.get_field("entrypoint_name_raw").expect("entrypoint_name_raw field should exist");
+        let entrypoint_name_transliterated = schema.get_field("entrypoint_name_transliterated").expect("entrypoint_name_transliterated field should exist");
         let entrypoint_id = schema.get_field("entrypoint_id").expect("entrypoint_id field should exist");
         let plugin_name = schema.get_field("plugin_name").expect("plugin_name field should exist");
+        let plugin_name_transliterated = schema.get_field("plugin_name_transliterated").expect("plugin_name_transliterated field should exist");
         let plugin_id = schema.get_field("plugin_id").expect("plugin_id field should exist");
+        let keywords = schema.get_field("keywords").expect("keywords field should exist");
+        let category = schema.get_field("category").expect("category field should exist");
 
-        let index = Index::create_in_ram(schema.clone());
-
-        let index_reader = index
-            .reader_builder()
-            .reload_policy(ReloadPolicy::Manual)
-            .try_into()?;
+        let active_buffer = create_index_buffer(&schema, vec![])?;
 
         Ok(Self {
             frontend_api,
-            index,
-            index_reader,
-            index_writer_mutex: Arc::new(Mutex::new(())),
+            schema,
+            active_buffer: Arc::new(RwLock::new(Arc::new(active_buffer))),
+            documents: Arc::new(Mutex::new(HashMap::new())),
             entrypoint_data: Arc::new(Mutex::new(HashMap::new())),
+            entrypoint_badges: Arc::new(Mutex::new(HashMap::new())),
+            recently_used: Arc::new(Mutex::new(vec![])),
+            favorites: Arc::new(Mutex::new(vec![])),
+            fallback_order: Arc::new(Mutex::new(vec![])),
             entrypoint_name,
+            entrypoint_name_raw,
+            entrypoint_name_transliterated,
             entrypoint_id,
             plugin_name,
+            plugin_name_transliterated,
             plugin_id,
+            keywords,
+            category,
         })
     }
 
     pub fn remove_for_plugin(&self, plugin_id: PluginId) -> tantivy::Result<()> {
-        // writer panics if another writer exists
-        let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
-        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+        let mut documents = self.documents.lock().expect("lock is poisoned");
 
-        let mut index_writer = self.index.writer::<TantivyDocument>(15_000_000)?;
+        documents.remove(&plugin_id);
 
-        index_writer.delete_query(Box::new(
-            TermQuery::new(Term::from_field_text(self.plugin_id, &plugin_id.to_string()), IndexRecordOption::Basic)
-        ))?;
-        index_writer.commit()?;
-        self.index_reader.reload()?;
+        let buffer = create_index_buffer(&self.schema, documents.values().flatten().cloned().collect())?;
+
+        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        *self.active_buffer.write().expect("lock is poisoned") = Arc::new(buffer);
 
         entrypoint_data.remove(&plugin_id);
 
+        self.entrypoint_badges.lock().expect("lock is poisoned").remove(&plugin_id);
+
         Ok(())
     }
 
     pub fn save_for_plugin(&self, plugin_id: PluginId, plugin_name: String, search_items: Vec<SearchIndexItem>, refresh_search_list: bool) -> tantivy::Result<()> {
         tracing::debug!("Reloading search index for plugin {:?}", plugin_id);
 
-        // writer panics if another writer exists
-        let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
-        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+        let mut documents = self.documents.lock().expect("lock is poisoned");
 
-        let mut index_writer = self.index.writer::<TantivyDocument>(15_000_000)?;
+        let plugin_name_transliterated = deunicode::deunicode(&plugin_name);
 
-        index_writer.delete_query(Box::new(
-            TermQuery::new(Term::from_field_text(self.plugin_id, &plugin_id.to_string()), IndexRecordOption::Basic)
-        ))?;
+        let mut plugin_documents = vec![];
 
         for search_item in &search_items {
-            index_writer.add_document(doc!(
+            let mut document = doc!(
                 self.entrypoint_name => search_item.entrypoint_name.clone(),
+                self.entrypoint_name_raw => search_item.entrypoint_name.clone(),
+                self.entrypoint_name_transliterated => deunicode::deunicode(&search_item.entrypoint_name),
                 self.entrypoint_id => search_item.entrypoint_id.to_string(),
                 self.plugin_name => plugin_name.clone(),
+                self.plugin_name_transliterated => plugin_name_transliterated.clone(),
                 self.plugin_id => plugin_id.to_string(),
-            ))?;
+                self.category => search_item.entrypoint_category.as_str(),
+            );
+
+            for keyword in &search_item.entrypoint_ranking.keywords {
+                document.add_text(self.keywords, keyword);
+            }
+
+            plugin_documents.push(document);
         }
 
-        index_writer.commit()?;
-        self.index_reader.reload()?;
+        documents.insert(plugin_id.clone(), plugin_documents);
+
+        // searches keep using previous index while this one is built
+        let buffer = create_index_buffer(&self.schema, documents.values().flatten().cloned().collect())?;
 
         let data = search_items.iter()
             .map(|item| {
@@ -144,51 +352,208 @@ impl SearchIndex {
 
                 let data = EntrypointData {
                     entrypoint_type: item.entrypoint_type.clone(),
+                    entrypoint_name: item.entrypoint_name.clone(),
+                    plugin_name: plugin_name.clone(),
                     icon_path: item.entrypoint_icon_path.clone(),
                     frecency: item.entrypoint_frecency,
                     actions,
+                    accessory: item.entrypoint_accessory.clone(),
+                    preview: item.entrypoint_preview,
+                    drag: item.entrypoint_drag.clone(),
+                    arguments: item.entrypoint_arguments.clone(),
+                    fallback: item.entrypoint_fallback,
+                    hidden: item.entrypoint_hidden,
+                    generator_entrypoint_id: item.entrypoint_generator_id.clone(),
+                    boost: item.entrypoint_ranking.boost
+                        .unwrap_or(1.0)
+                        .clamp(MIN_PLUGIN_BOOST, MAX_PLUGIN_BOOST),
+                    timestamp: item.entrypoint_ranking.timestamp,
+                    dedupe_key: item.entrypoint_dedupe_key.clone(),
+                    category: item.entrypoint_category,
+                    aliases: item.entrypoint_aliases.clone(),
                 };
 
                 (item.entrypoint_id.clone(), data)
             })
             .collect();
 
+        // entrypoint data is locked before index is swapped, so search never sees index and entrypoint data which don't match
+        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        *self.active_buffer.write().expect("lock is poisoned") = Arc::new(buffer);
+
         entrypoint_data.insert(plugin_id.clone(), data);
 
         if refresh_search_list {
-            let mut frontend_api = self.frontend_api.clone();
-            tokio::spawn(async move {
-                tracing::info!("requesting search results update because search index update for plugin: {:?}", plugin_id);
+            self.request_search_results_update(plugin_id);
+        }
 
-                let result = frontend_api.request_search_results_update()
-                    .await;
+        Ok(())
+    }
 
-                if let Err(err) = &result {
-                    tracing::warn!("error occurred when requesting search results update {:?}", err)
-                }
-            });
+    // hidden entrypoints stay in the index, so toggling visibility doesn't require rerunning command generators
+    pub fn set_entrypoint_hidden(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, hidden: bool) {
+        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        let Some(entrypoints) = entrypoint_data.get_mut(&plugin_id) else {
+            return;
+        };
+
+        for (id, data) in entrypoints.iter_mut() {
+            if id == &entrypoint_id || data.generator_entrypoint_id.as_ref() == Some(&entrypoint_id) {
+                data.hidden = hidden;
+            }
         }
 
-        Ok(())
+        drop(entrypoint_data);
+
+        self.request_search_results_update(plugin_id);
+    }
+
+    pub fn set_entrypoint_badge(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, badge: Option<SearchResultBadge>) {
+        let mut entrypoint_badges = self.entrypoint_badges.lock().expect("lock is poisoned");
+
+        let badges = entrypoint_badges.entry(plugin_id.clone()).or_default();
+
+        let changed = match badge {
+            Some(badge) => badges.insert(entrypoint_id, badge.clone()) != Some(badge),
+            None => badges.remove(&entrypoint_id).is_some(),
+        };
+
+        drop(entrypoint_badges);
+
+        // plugins may update badge periodically, avoid refreshing search list when nothing changed
+        if changed {
+            self.request_search_results_update(plugin_id);
+        }
+    }
+
+    pub fn clear_entrypoint_badges(&self, plugin_id: &PluginId) {
+        let removed = self.entrypoint_badges.lock().expect("lock is poisoned").remove(plugin_id);
+
+        if removed.is_some_and(|badges| !badges.is_empty()) {
+            self.request_search_results_update(plugin_id.clone());
+        }
+    }
+
+    pub fn set_recently_used(&self, recently_used: Vec<(PluginId, EntrypointId)>) {
+        *self.recently_used.lock().expect("lock is poisoned") = recently_used;
+    }
+
+    pub fn mark_recently_used(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
+        let mut recently_used = self.recently_used.lock().expect("lock is poisoned");
+
+        recently_used.retain(|(used_plugin_id, used_entrypoint_id)| used_plugin_id != &plugin_id || used_entrypoint_id != &entrypoint_id);
+        recently_used.insert(0, (plugin_id, entrypoint_id));
+        recently_used.truncate(RECENTLY_USED_HISTORY_SIZE);
+    }
+
+    pub fn set_favorites(&self, favorites: Vec<(PluginId, EntrypointId)>) {
+        *self.favorites.lock().expect("lock is poisoned") = favorites;
+    }
+
+    pub fn set_fallback_order(&self, fallback_order: Vec<(PluginId, EntrypointId)>) {
+        *self.fallback_order.lock().expect("lock is poisoned") = fallback_order;
+    }
+
+    pub fn entrypoint_badge(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> Option<SearchResultBadge> {
+        self.entrypoint_badges.lock().expect("lock is poisoned")
+            .get(plugin_id)
+            .and_then(|badges| badges.get(entrypoint_id))
+            .cloned()
+    }
+
+    pub fn entrypoint_type(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> Option<SearchResultEntrypointType> {
+        self.entrypoint_data.lock().expect("lock is poisoned")
+            .get(plugin_id)
+            .and_then(|entrypoints| entrypoints.get(entrypoint_id))
+            .map(|data| data.entrypoint_type.clone())
+    }
+
+    // commands which can be run without asking user for anything, ordered by plugin and entrypoint name
+    pub fn runnable_entrypoints(&self) -> Vec<SettingsEntrypointReference> {
+        let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        entrypoint_data.iter()
+            .flat_map(|(plugin_id, entrypoints)| {
+                entrypoints.iter()
+                    .filter(|(_, data)| matches!(data.entrypoint_type, SearchResultEntrypointType::Command | SearchResultEntrypointType::GeneratedCommand))
+                    .filter(|(_, data)| !data.hidden && data.arguments.iter().all(|argument| argument.optional))
+                    .map(|(entrypoint_id, data)| SettingsEntrypointReference {
+                        plugin_id: plugin_id.clone(),
+                        plugin_name: data.plugin_name.clone(),
+                        entrypoint_id: entrypoint_id.clone(),
+                        entrypoint_name: data.entrypoint_name.clone(),
+                    })
+            })
+            .sorted_by(|a, b| a.plugin_name.cmp(&b.plugin_name).then_with(|| a.entrypoint_name.cmp(&b.entrypoint_name)))
+            .collect()
+    }
+
+    fn request_search_results_update(&self, plugin_id: PluginId) {
+        let frontend_api = self.frontend_api.clone();
+        tokio::spawn(async move {
+            tracing::info!("requesting search results update because search index update for plugin: {:?}", plugin_id);
+
+            let result = frontend_api.request_search_results_update()
+                .await;
+
+            if let Err(err) = &result {
+                tracing::warn!("error occurred when requesting search results update {:?}", err)
+            }
+        });
     }
 
-    pub fn search(&self, query: &str) -> anyhow::Result<Vec<SearchResult>> {
+    // with transliteration, names in any script are also matched by their latin transliteration,
+    // so "muzyka" finds "музыка" and "музыка" finds "Muzyka"
+    pub fn search(&self, query: &str, category: Option<SearchResultCategory>, transliteration: bool) -> anyhow::Result<Vec<SearchResult>> {
+        let _span = tracing::info_span!(target: DIAGNOSTICS_TARGET, "search", name = "main").entered();
+
         let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
 
-        let searcher = self.index_reader.searcher();
+        let buffer = self.active_buffer.read().expect("lock is poisoned").clone();
+
+        let searcher = buffer.index_reader.searcher();
 
         let query_parser = QueryParser::new(
-            self.index.tokenizers().clone(),
+            buffer.index.tokenizers().clone(),
             self.entrypoint_name,
+            self.entrypoint_name_raw,
+            self.entrypoint_name_transliterated,
             self.plugin_name,
+            self.plugin_name_transliterated,
+            self.plugin_id,
+            self.keywords,
+            self.category,
         );
 
-        let query = query_parser.create_query(query);
+        let syntax = QuerySyntax::parse(query);
+
+        let parsed_query = match &syntax {
+            Some(syntax) => query_parser.create_syntax_query(syntax, transliteration),
+            None => query_parser.create_query(query, transliteration),
+        };
+
+        let parsed_query: Box<dyn Query> = match category {
+            Some(category) => {
+                let category_query = TermQuery::new(Term::from_field_text(self.category, category.as_str()), IndexRecordOption::Basic);
+
+                Box::new(BooleanQuery::new(vec![
+                    (Occur::Must, parsed_query),
+                    (Occur::Must, Box::new(category_query)),
+                ]))
+            }
+            None => parsed_query,
+        };
+
+        // without query all items are listed by frecency, ranking hints are not applied,
+        // so plugins cannot move their items to the top of the list
+        let ranking_hints = !query.is_empty();
 
         let mut index = 0;
 
-        let fetch = std::iter::from_fn(|| -> Option<anyhow::Result<Vec<(SearchResult, f64)>>> {
-            let result = self.fetch(&entrypoint_data, &query, TopDocs::with_limit(20).and_offset(index * 20), &searcher);
+        let fetch = std::iter::from_fn(|| -> Option<anyhow::Result<Vec<(SearchResult, f64, f64)>>> {
+            let result = self.fetch(&entrypoint_data, &parsed_query, TopDocs::with_limit(20).and_offset(index * 20), &searcher, ranking_hints);
 
             index += 1;
 
@@ -210,20 +575,294 @@ impl SearchIndex {
 
         let mut result = result.into_iter()
             .flatten()
+            .filter(|(item, _, _)| !Self::is_hidden(&entrypoint_data, &item.plugin_id, &item.entrypoint_id))
             .collect::<Vec<_>>();
 
-        result.sort_by(|(_, score_a), (_, score_b)| score_b.total_cmp(score_a));
+        // see ranking_score, frecency decides order of items with the same score
+        result.sort_by(|(_, score_a, frecency_a), (_, score_b, frecency_b)| {
+            score_b.total_cmp(score_a)
+                .then_with(|| frecency_b.total_cmp(frecency_a))
+        });
 
-        let result = result.into_iter()
-            .map(|(item, _)| item)
+        let mut result = result.into_iter()
+            .map(|(item, _, _)| item)
             .collect::<Vec<_>>();
 
+        if query.trim().is_empty() {
+            let favorites = self.favorites(&entrypoint_data);
+            let recently_used = self.recently_used(&entrypoint_data, &favorites);
+
+            let sections = favorites.into_iter()
+                .chain(recently_used)
+                .collect::<Vec<_>>();
+
+            result.retain(|item| {
+                !sections.iter()
+                    .any(|section_item| section_item.plugin_id == item.plugin_id && section_item.entrypoint_id == item.entrypoint_id)
+            });
+
+            result.splice(0..0, sections);
+        }
+
+        // filters would end up in argument values or in query passed to fallback
+        if syntax.is_none() {
+            let with_arguments = self.search_with_arguments(&entrypoint_data, query, &result);
+
+            result.splice(0..0, with_arguments);
+
+            if result.is_empty() && !query.trim().is_empty() {
+                result = self.fallbacks(&entrypoint_data);
+            }
+        }
+
+        let mut result = Self::merge_duplicates(&entrypoint_data, result);
+
+        if !query.trim().is_empty() {
+            let highlighted_text = match &syntax {
+                Some(syntax) => syntax.highlighted_text(),
+                None => query.to_string(),
+            };
+
+            let terms = query_parser.tokenize(NAME_TOKENIZER, &highlighted_text);
+
+            // fallbacks are shown because nothing matched
+            for item in result.iter_mut().filter(|item| !item.fallback) {
+                item.entrypoint_name_matches = name_matches(&item.entrypoint_name, &terms);
+            }
+        }
+
+        // recently used, entrypoints with arguments and fallbacks are not found using the index
+        if let Some(category) = category {
+            result.retain(|item| item.entrypoint_category == category);
+        }
+
         drop(entrypoint_data);
 
         Ok(result)
     }
 
-    fn fetch(&self, entrypoint_data: &HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>, query: &dyn Query, collector: TopDocs, searcher: &Searcher) -> anyhow::Result<Vec<(SearchResult, f64)>> {
+    // query like "translate hello world" doesn't match "Translate" entrypoint because
+    // all words need to match, so entrypoints that accept arguments are matched only by first word
+    // and the rest of the query is treated as argument values
+    fn search_with_arguments(&self, entrypoint_data: &HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>, query: &str, already_found: &[SearchResult]) -> Vec<SearchResult> {
+        let Some((first_word, rest)) = query.trim().split_once(char::is_whitespace) else {
+            return vec![]
+        };
+
+        if rest.trim().is_empty() {
+            return vec![]
+        }
+
+        let first_word = first_word.to_lowercase();
+
+        let mut result = entrypoint_data.iter()
+            .flat_map(|(plugin_id, entrypoints)| {
+                entrypoints.iter()
+                    .map(move |(entrypoint_id, data)| (plugin_id, entrypoint_id, data))
+            })
+            .filter(|(_, _, data)| !data.hidden)
+            .filter(|(_, _, data)| !data.arguments.is_empty())
+            .filter(|(_, _, data)| data.entrypoint_name.to_lowercase().contains(&first_word))
+            .filter(|(plugin_id, entrypoint_id, _)| {
+                !already_found.iter()
+                    .any(|found| &found.plugin_id == *plugin_id && &found.entrypoint_id == *entrypoint_id)
+            })
+            .map(|(plugin_id, entrypoint_id, data)| (self.create_search_result(plugin_id.clone(), entrypoint_id.clone(), data), data.frecency))
+            .collect::<Vec<_>>();
+
+        result.sort_by(|(_, score_a), (_, score_b)| score_b.total_cmp(score_a));
+
+        result.into_iter()
+            .map(|(item, _)| item)
+            .collect()
+    }
+
+    // highest ranked result is kept, actions of its duplicates are added after its own actions
+    fn merge_duplicates(entrypoint_data: &HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>, results: Vec<SearchResult>) -> Vec<SearchResult> {
+        let mut merged: Vec<SearchResult> = vec![];
+        let mut index_by_key: HashMap<&str, usize> = HashMap::new();
+
+        for result in results {
+            let dedupe_key = entrypoint_data.get(&result.plugin_id)
+                .and_then(|entrypoints| entrypoints.get(&result.entrypoint_id))
+                .and_then(|data| data.dedupe_key.as_deref());
+
+            let Some(dedupe_key) = dedupe_key else {
+                merged.push(result);
+                continue
+            };
+
+            match index_by_key.get(dedupe_key) {
+                Some(&index) => {
+                    let actions = Self::merged_actions(result);
+
+                    merged[index].entrypoint_actions.extend(actions);
+                }
+                None => {
+                    index_by_key.insert(dedupe_key, merged.len());
+                    merged.push(result);
+                }
+            }
+        }
+
+        merged
+    }
+
+    // shortcuts are not kept, they could conflict with shortcuts of the result they are merged into
+    fn merged_actions(duplicate: SearchResult) -> Vec<SearchResultEntrypointAction> {
+        let source = |action_index| SearchResultActionSource {
+            plugin_id: duplicate.plugin_id.clone(),
+            entrypoint_id: duplicate.entrypoint_id.clone(),
+            action_index,
+        };
+
+        let primary_action = SearchResultEntrypointAction {
+            label: format!("{} ({})", duplicate.entrypoint_name, duplicate.plugin_name),
+            shortcut: None,
+            merged_from: Some(source(None)),
+        };
+
+        let actions = duplicate.entrypoint_actions
+            .iter()
+            .enumerate()
+            .map(|(index, action)| SearchResultEntrypointAction {
+                label: format!("{} ({})", action.label, duplicate.plugin_name),
+                shortcut: None,
+                merged_from: Some(source(Some(index))),
+            });
+
+        std::iter::once(primary_action)
+            .chain(actions)
+            .collect()
+    }
+
+    fn favorites(&self, entrypoint_data: &HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>) -> Vec<SearchResult> {
+        let favorites = self.favorites.lock().expect("lock is poisoned");
+
+        favorites.iter()
+            .filter_map(|(plugin_id, entrypoint_id)| {
+                let data = entrypoint_data.get(plugin_id)?.get(entrypoint_id)?;
+
+                if data.hidden {
+                    return None
+                }
+
+                let mut item = self.create_search_result(plugin_id.clone(), entrypoint_id.clone(), data);
+                item.favorite = true;
+                Some(item)
+            })
+            .collect()
+    }
+
+    // favorites are already shown above, so they don't take place of other recently used entrypoints
+    fn recently_used(&self, entrypoint_data: &HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>, favorites: &[SearchResult]) -> Vec<SearchResult> {
+        let recently_used = self.recently_used.lock().expect("lock is poisoned");
+
+        recently_used.iter()
+            .filter(|(plugin_id, entrypoint_id)| {
+                !favorites.iter()
+                    .any(|favorite| &favorite.plugin_id == plugin_id && &favorite.entrypoint_id == entrypoint_id)
+            })
+            .filter_map(|(plugin_id, entrypoint_id)| {
+                let data = entrypoint_data.get(plugin_id)?.get(entrypoint_id)?;
+
+                if data.hidden {
+                    return None
+                }
+
+                let mut item = self.create_search_result(plugin_id.clone(), entrypoint_id.clone(), data);
+                item.recent = true;
+                Some(item)
+            })
+            .take(RECENTLY_USED_LIMIT)
+            .collect()
+    }
+
+    // shown only when nothing else matched, whole query is passed to the entrypoint.
+    // order set by user comes first, the rest is ordered by frecency
+    fn fallbacks(&self, entrypoint_data: &HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>) -> Vec<SearchResult> {
+        let fallback_order = self.fallback_order.lock().expect("lock is poisoned");
+
+        let position = |item: &SearchResult| {
+            fallback_order.iter()
+                .position(|(plugin_id, entrypoint_id)| plugin_id == &item.plugin_id && entrypoint_id == &item.entrypoint_id)
+                .unwrap_or(usize::MAX)
+        };
+
+        let mut result = entrypoint_data.iter()
+            .flat_map(|(plugin_id, entrypoints)| {
+                entrypoints.iter()
+                    .map(move |(entrypoint_id, data)| (plugin_id, entrypoint_id, data))
+            })
+            .filter(|(_, _, data)| !data.hidden)
+            .filter(|(_, _, data)| data.fallback)
+            .map(|(plugin_id, entrypoint_id, data)| {
+                let mut item = self.create_search_result(plugin_id.clone(), entrypoint_id.clone(), data);
+                item.fallback = true;
+                (item, data.frecency)
+            })
+            .collect::<Vec<_>>();
+
+        result.sort_by(|(item_a, score_a), (item_b, score_b)| {
+            position(item_a).cmp(&position(item_b))
+                .then_with(|| score_b.total_cmp(score_a))
+                .then_with(|| item_a.entrypoint_name.cmp(&item_b.entrypoint_name))
+        });
+
+        result.into_iter()
+            .map(|(item, _)| item)
+            .collect()
+    }
+
+    fn is_hidden(entrypoint_data: &HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> bool {
+        entrypoint_data.get(plugin_id)
+            .and_then(|entrypoints| entrypoints.get(entrypoint_id))
+            .map(|data| data.hidden)
+            .unwrap_or(false)
+    }
+
+    fn create_search_result(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, entrypoint_data: &EntrypointData) -> SearchResult {
+        let entrypoint_actions = entrypoint_data.actions.iter()
+            .map(|data| SearchResultEntrypointAction {
+                label: data.label.clone(),
+                shortcut: data.shortcut.clone(),
+                merged_from: None,
+            })
+            .collect();
+
+        let entrypoint_badge = self.entrypoint_badge(&plugin_id, &entrypoint_id);
+
+        let entrypoint_copy_items = search_result_copy_items(
+            &entrypoint_id,
+            &entrypoint_data.entrypoint_name,
+            &entrypoint_data.entrypoint_type,
+            &entrypoint_data.drag
+        );
+
+        SearchResult {
+            entrypoint_type: entrypoint_data.entrypoint_type.clone(),
+            entrypoint_name: entrypoint_data.entrypoint_name.clone(),
+            entrypoint_name_matches: vec![],
+            entrypoint_id,
+            entrypoint_icon: entrypoint_data.icon_path.clone(),
+            plugin_name: entrypoint_data.plugin_name.clone(),
+            plugin_id,
+            entrypoint_actions,
+            entrypoint_accessory: entrypoint_data.accessory.clone(),
+            entrypoint_badge,
+            entrypoint_preview: entrypoint_data.preview,
+            entrypoint_drag: entrypoint_data.drag.clone(),
+            entrypoint_arguments: entrypoint_data.arguments.clone(),
+            entrypoint_copy_items,
+            fallback: false,
+            recent: false,
+            favorite: false,
+            entrypoint_category: entrypoint_data.category,
+            aliases: entrypoint_data.aliases.clone(),
+        }
+    }
+
+    fn fetch(&self, entrypoint_data: &HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>, query: &dyn Query, collector: TopDocs, searcher: &Searcher, ranking_hints: bool) -> anyhow::Result<Vec<(SearchResult, f64, f64)>> {
         let get_str_field = |retrieved_doc: &TantivyDocument, field: Field| -> String {
             retrieved_doc.get_first(field)
                 .unwrap_or_else(|| panic!("there should be a field with name {:?}", searcher.schema().get_field_name(field)))
@@ -232,16 +871,19 @@ impl SearchIndex {
                 .to_owned()
         };
 
+        let now_millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as f64)
+            .unwrap_or(0.0);
+
         let result = searcher.search(query, &collector)?
             .into_iter()
-            .map(|(_score, doc_address)| {
+            .map(|(score, doc_address)| {
                 let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)
                     .expect("index should contain just searched results");
 
                 let entrypoint_id = EntrypointId::from_string(get_str_field(&retrieved_doc, self.entrypoint_id));
                 let plugin_id = PluginId::from_string(get_str_field(&retrieved_doc, self.plugin_id));
-                let entrypoint_name = get_str_field(&retrieved_doc, self.entrypoint_name);
-                let plugin_name = get_str_field(&retrieved_doc, self.plugin_name);
 
                 let entrypoint_data = entrypoint_data
                     .get(&plugin_id)
@@ -249,88 +891,278 @@ impl SearchIndex {
                     .get(&entrypoint_id)
                     .expect("Entrypoint should always exist in plugin in entrypoint data");
 
-                let entrypoint_actions = entrypoint_data.actions.iter()
-                    .map(|data| SearchResultEntrypointAction {
-                        label: data.label.clone(),
-                        shortcut: data.shortcut.clone(),
-                    })
-                    .collect();
+                let result_item = self.create_search_result(plugin_id, entrypoint_id, entrypoint_data);
 
-                let result_item = SearchResult {
-                    entrypoint_type: entrypoint_data.entrypoint_type.clone(),
-                    entrypoint_name,
-                    entrypoint_id,
-                    entrypoint_icon: entrypoint_data.icon_path.clone(),
-                    plugin_name,
-                    plugin_id,
-                    entrypoint_actions,
+                let score = if ranking_hints {
+                    Self::ranking_score(score, entrypoint_data, now_millis)
+                } else {
+                    score as f64
                 };
 
-                (result_item, entrypoint_data.frecency)
+                (result_item, score, entrypoint_data.frecency)
             })
             .collect::<Vec<_>>();
 
         Ok(result)
     }
+
+    // score of search index item is
+    //
+    //     text_score * boost + recency_bonus
+    //
+    // text_score depends on how well the query matches, relative to 1 for name containing each query word:
+    //     + WORD_PREFIX_MATCH_BOOST for each word of the name starting with query word
+    //     + NAME_PREFIX_MATCH_BOOST if the whole name starts with the query
+    //     + KEYWORD_MATCH_BOOST if the query is exactly one of the keywords of the item
+    // boost is set by plugin per item, 1 by default, clamped between MIN_PLUGIN_BOOST and MAX_PLUGIN_BOOST
+    // recency_bonus is RECENCY_MAX_BONUS for item with timestamp of current time set by plugin,
+    // and halves every RECENCY_HALF_LIFE_MILLIS, 0 for items without timestamp
+    fn ranking_score(text_score: f32, entrypoint_data: &EntrypointData, now_millis: f64) -> f64 {
+        let recency_bonus = match entrypoint_data.timestamp {
+            None => 0.0,
+            Some(timestamp) => {
+                let age = (now_millis - timestamp).max(0.0);
+
+                RECENCY_MAX_BONUS * 0.5f64.powf(age / RECENCY_HALF_LIFE_MILLIS)
+            }
+        };
+
+        text_score as f64 * entrypoint_data.boost + recency_bonus
+    }
+}
+
+fn create_index_buffer(schema: &Schema, documents: Vec<TantivyDocument>) -> tantivy::Result<SearchIndexBuffer> {
+    let index = Index::create_in_ram(schema.clone());
+
+    index.tokenizers().register(
+        NAME_TOKENIZER,
+        TextAnalyzer::builder(SimpleTokenizer::default())
+            .filter(RemoveLongFilter::limit(40))
+            .filter(LowerCaser)
+            .filter(AsciiFoldingFilter)
+            .build()
+    );
+
+    index.tokenizers().register(
+        RAW_LOWERCASE_TOKENIZER,
+        TextAnalyzer::builder(RawTokenizer::default())
+            .filter(LowerCaser)
+            .filter(AsciiFoldingFilter)
+            .build()
+    );
+
+    let mut index_writer: IndexWriter<TantivyDocument> = index.writer(15_000_000)?;
+
+    for document in documents {
+        index_writer.add_document(document)?;
+    }
+
+    index_writer.commit()?;
+
+    let index_reader = index
+        .reader_builder()
+        .reload_policy(ReloadPolicy::Manual)
+        .try_into()?;
+
+    Ok(SearchIndexBuffer {
+        index,
+        index_reader,
+    })
 }
 
 struct QueryParser {
     tokenizer_manager: TokenizerManager,
     entrypoint_name: Field,
+    entrypoint_name_raw: Field,
+    entrypoint_name_transliterated: Field,
     plugin_name: Field,
+    plugin_name_transliterated: Field,
+    plugin_id: Field,
+    keywords: Field,
+    category: Field,
 }
 
 impl QueryParser {
-    fn new(tokenizer_manager: TokenizerManager, entrypoint_name: Field, plugin_name: Field) -> Self {
+    fn new(
+        tokenizer_manager: TokenizerManager,
+        entrypoint_name: Field,
+        entrypoint_name_raw: Field,
+        entrypoint_name_transliterated: Field,
+        plugin_name: Field,
+        plugin_name_transliterated: Field,
+        plugin_id: Field,
+        keywords: Field,
+        category: Field,
+    ) -> Self {
         Self {
             tokenizer_manager,
             entrypoint_name,
+            entrypoint_name_raw,
+            entrypoint_name_transliterated,
             plugin_name,
+            plugin_name_transliterated,
+            plugin_id,
+            keywords,
+            category,
+        }
+    }
+
+    // words are matched and ranked the same way as plain query, every other clause has to match too.
+    // filters don't change the score, so results are in the same order as without them
+    fn create_syntax_query(&self, syntax: &QuerySyntax, transliteration: bool) -> Box<dyn Query> {
+        let mut clauses: Vec<(Occur, Box<dyn Query>)> = vec![
+            (Occur::Must, self.create_query(&syntax.text(), transliteration))
+        ];
+
+        for clause in &syntax.clauses {
+            let query: Box<dyn Query> = match &clause.kind {
+                QueryClauseKind::Term(_) if !clause.negated => continue,
+                QueryClauseKind::Term(term) => self.create_query(term, transliteration),
+                QueryClauseKind::Phrase(phrase) => {
+                    let Some(query) = self.create_phrase_query(phrase) else {
+                        continue
+                    };
+
+                    query
+                }
+                QueryClauseKind::Plugin(plugin) => Box::new(ConstScoreQuery::new(self.create_plugin_query(plugin), 0.0)),
+                QueryClauseKind::Category(category) => {
+                    let category_query = TermQuery::new(Term::from_field_text(self.category, category.as_str()), IndexRecordOption::Basic);
+
+                    Box::new(ConstScoreQuery::new(Box::new(category_query), 0.0))
+                }
+            };
+
+            let occur = if clause.negated { Occur::MustNot } else { Occur::Must };
+
+            clauses.push((occur, query));
         }
+
+        Box::new(BooleanQuery::new(clauses))
     }
 
-    fn create_query(&self, query: &str) -> Box<dyn Query> {
+    // none if phrase has no words, e.g. only punctuation
+    fn create_phrase_query(&self, phrase: &str) -> Option<Box<dyn Query>> {
+        let terms = self.tokenize(NAME_TOKENIZER, phrase)
+            .into_iter()
+            .map(|term| Term::from_field_text(self.entrypoint_name, &term))
+            .collect::<Vec<_>>();
+
+        // phrase query requires at least two words
+        match terms.len() {
+            0 => None,
+            1 => Some(Box::new(TermQuery::new(terms[0].clone(), IndexRecordOption::WithFreqs))),
+            _ => Some(Box::new(PhraseQuery::new(terms))),
+        }
+    }
+
+    // plugin name containing all the words, or exact plugin id
+    fn create_plugin_query(&self, plugin: &str) -> Box<dyn Query> {
+        let terms = self.tokenize(NAME_TOKENIZER, plugin);
+
+        let plugin_id_query = TermQuery::new(Term::from_field_text(self.plugin_id, plugin), IndexRecordOption::Basic);
+
+        if terms.is_empty() {
+            return Box::new(plugin_id_query)
+        }
+
+        Box::new(BooleanQuery::union(vec![
+            self.create_contains_query(self.plugin_name, &terms, false),
+            Box::new(plugin_id_query),
+        ]))
+    }
+
+    fn create_query(&self, query: &str, transliteration: bool) -> Box<dyn Query> {
         if query.is_empty() {
             return Box::new(AllQuery);
         }
 
-        let contains_terms_fn = |field: Field| -> Box<dyn Query> {
-            let res = self.tokenize(query)
-                .into_iter()
-                .map(|term| -> Box<dyn Query> {
-                    Box::new(
-                        // basically a "contains" query
-                        RegexQuery::from_pattern(&format!(".*{}.*", regex::escape(&term)), field)
-                            .expect("there should not exist a situation where that regex is invalid")
-                    )
-                })
-                .collect::<Vec<_>>();
+        let terms = self.tokenize(NAME_TOKENIZER, query);
+        let transliterated_terms = self.tokenize(NAME_TOKENIZER, &deunicode::deunicode(query));
 
-            Box::new(BooleanQuery::intersection(res))
-        };
+        let mut entrypoint_name_terms = self.create_contains_query(self.entrypoint_name, &terms, true);
+        let mut plugin_name_terms = self.create_contains_query(self.plugin_name, &terms, false);
 
-        let terms_fn = |field: Field| -> Box<dyn Query> {
-            Box::new(
-                contains_terms_fn(field)
-            )
-        };
+        if transliteration {
+            // names in latin script match both ways, max is used so they are not ranked higher because of that
+            entrypoint_name_terms = Box::new(DisjunctionMaxQuery::new(vec![
+                entrypoint_name_terms,
+                self.create_contains_query(self.entrypoint_name_transliterated, &transliterated_terms, true),
+            ]));
 
-        let entrypoint_name_terms = terms_fn(self.entrypoint_name);
-        let plugin_name_terms = terms_fn(self.plugin_name);
+            plugin_name_terms = Box::new(DisjunctionMaxQuery::new(vec![
+                plugin_name_terms,
+                self.create_contains_query(self.plugin_name_transliterated, &transliterated_terms, false),
+            ]));
+        }
+
+        let raw_query = self.tokenize(RAW_LOWERCASE_TOKENIZER, query.trim())
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+
+        let name_prefix: Box<dyn Query> = Box::new(
+            RegexQuery::from_pattern(&format!("{}.*", regex::escape(&raw_query)), self.entrypoint_name_raw)
+                .expect("there should not exist a situation where that regex is invalid")
+        );
+
+        let keyword: Box<dyn Query> = Box::new(BoostQuery::new(
+            Box::new(TermQuery::new(Term::from_field_text(self.keywords, &raw_query), IndexRecordOption::Basic)),
+            KEYWORD_MATCH_BOOST
+        ));
 
+        let name_terms: Box<dyn Query> = Box::new(BooleanQuery::union(vec![entrypoint_name_terms, plugin_name_terms, keyword]));
+        let name_prefix: Box<dyn Query> = Box::new(BoostQuery::new(name_prefix, NAME_PREFIX_MATCH_BOOST));
+
+        // name prefix doesn't match anything on its own,
+        // it only adds to the score of entrypoints that already matched
         Box::new(
-            BooleanQuery::union(vec![
-                Box::new(entrypoint_name_terms),
-                Box::new(plugin_name_terms),
+            BooleanQuery::new(vec![
+                (Occur::Must, name_terms),
+                (Occur::Should, name_prefix),
             ]),
         )
     }
 
-    fn tokenize(&self, query: &str) -> Vec<String> {
+    fn create_contains_query(&self, field: Field, terms: &[String], boost_word_prefix: bool) -> Box<dyn Query> {
+        let res = terms.iter()
+            .map(|term| -> Box<dyn Query> {
+                let term = regex::escape(&term);
+
+                // basically a "contains" query
+                let contains_query: Box<dyn Query> = Box::new(
+                    RegexQuery::from_pattern(&format!(".*{}.*", term), field)
+                        .expect("there should not exist a situation where that regex is invalid")
+                );
+
+                if !boost_word_prefix {
+                    return contains_query
+                }
+
+                // matches the same words as "contains" query, but only the ones starting with the term,
+                // so while typing "fire", "Firefox" is ranked higher than "Campfire"
+                let word_prefix_query = Box::new(
+                    RegexQuery::from_pattern(&format!("{}.*", term), field)
+                        .expect("there should not exist a situation where that regex is invalid")
+                );
+
+                Box::new(
+                    BooleanQuery::union(vec![
+                        contains_query,
+                        Box::new(BoostQuery::new(word_prefix_query, WORD_PREFIX_MATCH_BOOST)),
+                    ])
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Box::new(BooleanQuery::intersection(res))
+    }
+
+    fn tokenize(&self, tokenizer: &str, query: &str) -> Vec<String> {
         let mut text_analyzer = self
             .tokenizer_manager
-            .get("default")
-            .expect("default tokenizer should exist");
+            .get(tokenizer)
+            .expect("tokenizer should be registered when index is created");
 
         let mut terms: Vec<String> = Vec::new();
         let mut token_stream = text_analyzer.token_stream(query);
@@ -0,0 +1,83 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use gauntlet_common::model::{PluginId, SearchResult, SearchResultCategory};
+use gauntlet_common::rpc::frontend_api::FrontendApi;
+
+// results that plugin returns after this time are still shown,
+// but frontend is told that they are late, so they are inserted without moving focused item
+const PLUGIN_SEARCH_TIMEOUT: Duration = Duration::from_millis(150);
+// results that plugin returns after this time are dropped
+const PLUGIN_SEARCH_DEADLINE: Duration = Duration::from_secs(5);
+
+// search query is sent to all plugins at the same time and every plugin responds on its own,
+// results are forwarded to frontend as soon as they arrive, so slow plugin doesn't delay the rest
+#[derive(Clone)]
+pub struct SearchAggregator {
+    frontend_api: Arc<dyn FrontendApi>,
+    current: Arc<Mutex<Option<PendingSearch>>>,
+}
+
+struct PendingSearch {
+    generation: u64,
+    started_at: Instant,
+    category: Option<SearchResultCategory>,
+}
+
+impl SearchAggregator {
+    pub fn new(frontend_api: Arc<dyn FrontendApi>) -> Self {
+        Self {
+            frontend_api,
+            current: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    // results for previous queries are dropped after this
+    pub fn start(&self, generation: u64, category: Option<SearchResultCategory>) {
+        let mut current = self.current.lock().expect("lock is poisoned");
+
+        *current = Some(PendingSearch {
+            generation,
+            started_at: Instant::now(),
+            category,
+        });
+    }
+
+    pub async fn submit(&self, plugin_id: PluginId, generation: u64, mut results: Vec<SearchResult>) -> anyhow::Result<()> {
+        let (elapsed, category) = {
+            let current = self.current.lock().expect("lock is poisoned");
+
+            match current.as_ref() {
+                Some(search) if search.generation == generation => (search.started_at.elapsed(), search.category),
+                _ => {
+                    tracing::debug!("plugin {:?} returned results for outdated search query, dropping", plugin_id);
+
+                    return Ok(())
+                }
+            }
+        };
+
+        if elapsed > PLUGIN_SEARCH_DEADLINE {
+            tracing::warn!("plugin {:?} took {:?} to return search results, dropping", plugin_id, elapsed);
+
+            return Ok(())
+        }
+
+        if let Some(category) = category {
+            results.retain(|result| result.entrypoint_category == category);
+        }
+
+        if results.is_empty() {
+            return Ok(())
+        }
+
+        let late = elapsed > PLUGIN_SEARCH_TIMEOUT;
+
+        if late {
+            tracing::debug!("plugin {:?} took {:?} to return search results, marking them as late", plugin_id, elapsed);
+        }
+
+        self.frontend_api.append_search_results(generation, plugin_id, results, late).await?;
+
+        Ok(())
+    }
+}
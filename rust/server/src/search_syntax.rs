@@ -0,0 +1,207 @@
+use itertools::Itertools;
+use gauntlet_common::model::SearchResultCategory;
+
+// optional query syntax, e.g. `"new tab" plugin:firefox -private`.
+// query that doesn't use any of it, or has a syntax error, e.g. unterminated quote, is searched as plain text
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuerySyntax {
+    pub clauses: Vec<QueryClause>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryClause {
+    // `-` before the clause, entrypoints matching it are excluded
+    pub negated: bool,
+    pub kind: QueryClauseKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryClauseKind {
+    // matched the same way as plain query
+    Term(String),
+    // `"new tab"`, words have to follow each other in entrypoint name
+    Phrase(String),
+    // `plugin:firefox` or `plugin:"my plugin"`, plugin name or id
+    Plugin(String),
+    // `type:app`, same names as in search filter prefix
+    Category(SearchResultCategory),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum QuerySyntaxError {
+    UnterminatedQuote,
+    UnexpectedQuote,
+    EmptyValue,
+    EmptyNegation,
+    UnknownType(String),
+}
+
+impl QuerySyntax {
+    // none if query should be searched as plain text
+    pub fn parse(query: &str) -> Option<QuerySyntax> {
+        match Self::try_parse(query) {
+            Ok(syntax) if syntax.uses_syntax() => Some(syntax),
+            Ok(_) => None,
+            Err(err) => {
+                tracing::debug!("Search query {:?} is searched as plain text because of syntax error: {:?}", query, err);
+
+                None
+            }
+        }
+    }
+
+    // words that are not negated, searched the same way as plain query
+    pub fn text(&self) -> String {
+        self.clauses.iter()
+            .filter(|clause| !clause.negated)
+            .filter_map(|clause| match &clause.kind {
+                QueryClauseKind::Term(term) => Some(term.as_str()),
+                _ => None,
+            })
+            .join(" ")
+    }
+
+    // parts of the query that can be found in entrypoint name
+    pub fn highlighted_text(&self) -> String {
+        self.clauses.iter()
+            .filter(|clause| !clause.negated)
+            .filter_map(|clause| match &clause.kind {
+                QueryClauseKind::Term(text) | QueryClauseKind::Phrase(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .join(" ")
+    }
+
+    fn uses_syntax(&self) -> bool {
+        self.clauses.iter()
+            .any(|clause| clause.negated || !matches!(clause.kind, QueryClauseKind::Term(_)))
+    }
+
+    fn try_parse(query: &str) -> Result<QuerySyntax, QuerySyntaxError> {
+        let mut clauses = vec![];
+        let mut rest = query.trim_start();
+
+        while !rest.is_empty() {
+            let (negated, unsigned) = match rest.strip_prefix('-') {
+                Some(unsigned) => (true, unsigned),
+                None => (false, rest),
+            };
+
+            let (kind, remaining) = parse_clause(unsigned)?;
+
+            clauses.push(QueryClause { negated, kind });
+
+            rest = remaining.trim_start();
+        }
+
+        Ok(QuerySyntax { clauses })
+    }
+}
+
+// returns clause and the rest of the query after it
+fn parse_clause(query: &str) -> Result<(QueryClauseKind, &str), QuerySyntaxError> {
+    if let Some(quoted) = query.strip_prefix('"') {
+        let (phrase, rest) = parse_quoted(quoted)?;
+
+        return Ok((QueryClauseKind::Phrase(phrase.to_string()), rest))
+    }
+
+    if let Some(value) = query.strip_prefix("plugin:") {
+        let (plugin, rest) = parse_value(value)?;
+
+        return Ok((QueryClauseKind::Plugin(plugin.to_string()), rest))
+    }
+
+    if let Some(value) = query.strip_prefix("type:") {
+        let (name, rest) = parse_value(value)?;
+
+        let category = SearchResultCategory::from_query_name(name)
+            .ok_or_else(|| QuerySyntaxError::UnknownType(name.to_string()))?;
+
+        return Ok((QueryClauseKind::Category(category), rest))
+    }
+
+    let (word, rest) = split_word(query);
+
+    if word.is_empty() {
+        return Err(QuerySyntaxError::EmptyNegation)
+    }
+
+    if word.contains('"') {
+        return Err(QuerySyntaxError::UnexpectedQuote)
+    }
+
+    Ok((QueryClauseKind::Term(word.to_string()), rest))
+}
+
+fn parse_value(query: &str) -> Result<(&str, &str), QuerySyntaxError> {
+    let (value, rest) = match query.strip_prefix('"') {
+        Some(quoted) => parse_quoted(quoted)?,
+        None => split_word(query),
+    };
+
+    if value.trim().is_empty() {
+        return Err(QuerySyntaxError::EmptyValue)
+    }
+
+    Ok((value.trim(), rest))
+}
+
+fn parse_quoted(query: &str) -> Result<(&str, &str), QuerySyntaxError> {
+    let (value, rest) = query.split_once('"')
+        .ok_or(QuerySyntaxError::UnterminatedQuote)?;
+
+    if value.trim().is_empty() {
+        return Err(QuerySyntaxError::EmptyValue)
+    }
+
+    Ok((value.trim(), rest))
+}
+
+fn split_word(query: &str) -> (&str, &str) {
+    query.split_once(char::is_whitespace)
+        .unwrap_or((query, ""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clause(negated: bool, kind: QueryClauseKind) -> QueryClause {
+        QueryClause { negated, kind }
+    }
+
+    #[test]
+    fn plain_query_is_not_parsed() {
+        assert_eq!(QuerySyntax::parse("fire fox"), None);
+        assert_eq!(QuerySyntax::parse("wi-fi settings"), None);
+    }
+
+    #[test]
+    fn phrases_filters_and_negation_are_parsed() {
+        let syntax = QuerySyntax::parse(r#"tab "new window" plugin:"my plugin" type:app -private -plugin:firefox"#).unwrap();
+
+        assert_eq!(syntax.clauses, vec![
+            clause(false, QueryClauseKind::Term("tab".to_string())),
+            clause(false, QueryClauseKind::Phrase("new window".to_string())),
+            clause(false, QueryClauseKind::Plugin("my plugin".to_string())),
+            clause(false, QueryClauseKind::Category(SearchResultCategory::Application)),
+            clause(true, QueryClauseKind::Term("private".to_string())),
+            clause(true, QueryClauseKind::Plugin("firefox".to_string())),
+        ]);
+
+        assert_eq!(syntax.text(), "tab");
+        assert_eq!(syntax.highlighted_text(), "tab new window");
+    }
+
+    #[test]
+    fn syntax_errors_fall_back_to_plain_text() {
+        assert_eq!(QuerySyntax::try_parse(r#"say "hello"#), Err(QuerySyntaxError::UnterminatedQuote));
+        assert_eq!(QuerySyntax::try_parse(r#"5" screen"#), Err(QuerySyntaxError::UnexpectedQuote));
+        assert_eq!(QuerySyntax::try_parse("plugin: firefox"), Err(QuerySyntaxError::EmptyValue));
+        assert_eq!(QuerySyntax::try_parse("a - b"), Err(QuerySyntaxError::EmptyNegation));
+        assert_eq!(QuerySyntax::try_parse("type:video"), Err(QuerySyntaxError::UnknownType("video".to_string())));
+
+        assert_eq!(QuerySyntax::parse(r#"say "hello"#), None);
+    }
+}
@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
+
+// whole shutdown is bounded, process exits even if some subsystem doesn't stop in time
+pub const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+// Requested by tray menu, `gauntlet quit` or SIGINT/SIGTERM.
+// Every long-running subsystem, e.g. socket servers, config watcher and idle timers, gets a child token
+// and stops by itself when it is cancelled. Plugin runtimes and database are stopped by ApplicationManager::shutdown,
+// because they have to be stopped in order
+#[derive(Clone)]
+pub struct Shutdown {
+    token: CancellationToken,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self {
+            token: CancellationToken::new(),
+        }
+    }
+
+    pub fn request(&self) {
+        if !self.token.is_cancelled() {
+            tracing::info!("Shutdown requested");
+        }
+
+        self.token.cancel()
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    pub fn requested(&self) -> WaitForCancellationFuture<'_> {
+        self.token.cancelled()
+    }
+
+    pub fn subsystem_token(&self) -> CancellationToken {
+        self.token.child_token()
+    }
+}
+
+pub fn shutdown_on_signal(shutdown: Shutdown) {
+    tokio::spawn(async move {
+        if let Err(err) = wait_for_signal().await {
+            tracing::error!("Unable to listen for termination signals: {:?}", err);
+            return;
+        }
+
+        shutdown.request();
+    });
+}
+
+#[cfg(unix)]
+async fn wait_for_signal() -> std::io::Result<()> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut terminate = signal(SignalKind::terminate())?;
+
+    tokio::select! {
+        result = tokio::signal::ctrl_c() => result?,
+        _ = terminate.recv() => {}
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn wait_for_signal() -> std::io::Result<()> {
+    tokio::signal::ctrl_c().await
+}
@@ -63,9 +63,11 @@ impl<Req: std::fmt::Debug, Res: std::fmt::Debug> RequestSender<Req, Res> {
     }
 
     pub async fn send_receive(&self, request: Req) -> Result<Res, RequestError> {
-        let mut receiver = self.send(request)?;
+        self.send_receive_timeout(request, Duration::from_secs(30)).await
+    }
 
-        let duration = Duration::from_secs(30);
+    pub async fn send_receive_timeout(&self, request: Req, duration: Duration) -> Result<Res, RequestError> {
+        let mut receiver = self.send(request)?;
 
         let result = tokio::time::timeout(duration, receiver.recv()).await?;
 
@@ -0,0 +1,15 @@
+import { Detail } from "@project-gauntlet/api/components";
+import { ReactNode } from "react";
+
+export default function Main(): ReactNode {
+    return (
+        <Detail>
+            <Detail.Content>
+                <Detail.Content.Paragraph>
+                    CI durations, minutes
+                </Detail.Content.Paragraph>
+                <Detail.Content.BarChart data={[8, 9, 7, 12, 8, 10]} color="#659b5e" height={64}/>
+            </Detail.Content>
+        </Detail>
+    )
+}
@@ -0,0 +1,15 @@
+import { Detail } from "@project-gauntlet/api/components";
+import { ReactNode } from "react";
+
+export default function Main(): ReactNode {
+    return (
+        <Detail>
+            <Detail.Content>
+                <Detail.Content.Paragraph>
+                    Coral
+                </Detail.Content.Paragraph>
+                <Detail.Content.ColorSwatch color="#ff7f50"/>
+            </Detail.Content>
+        </Detail>
+    )
+}
@@ -0,0 +1,16 @@
+import { Detail, TextColor } from "@project-gauntlet/api/components";
+import { ReactNode } from "react";
+
+export default function Main(): ReactNode {
+    return (
+        <Detail>
+            <Detail.Content>
+                <Detail.Content.Paragraph>
+                    The <Detail.Content.Paragraph.Bold>Ezaraa</Detail.Content.Paragraph.Bold> were a species of <Detail.Content.Paragraph.Italic>warmongering</Detail.Content.Paragraph.Italic> carnivorous sentients that were native to the planet <Detail.Content.Paragraph.Code>Ezaraa</Detail.Content.Paragraph.Code>.
+                    They intended to <Detail.Content.Paragraph.Color color={TextColor.Danger}>overthrow the Galactic Empire</Detail.Content.Paragraph.Color>, only to replace it with their own dominion.
+                    To arm their revolution, the dominion sent Ezaraa to the <Detail.Content.Paragraph.Link href="https://starwars.fandom.com/wiki/Auction_of_Rur">Auction of Rur</Detail.Content.Paragraph.Link>.
+                </Detail.Content.Paragraph>
+            </Detail.Content>
+        </Detail>
+    )
+}
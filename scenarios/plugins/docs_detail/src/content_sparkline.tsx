@@ -0,0 +1,15 @@
+import { Detail } from "@project-gauntlet/api/components";
+import { ReactNode } from "react";
+
+export default function Main(): ReactNode {
+    return (
+        <Detail>
+            <Detail.Content>
+                <Detail.Content.Paragraph>
+                    CPU usage
+                </Detail.Content.Paragraph>
+                <Detail.Content.Sparkline data={[12, 18, 15, 40, 62, 55, 30, 24, 28, 20]}/>
+            </Detail.Content>
+        </Detail>
+    )
+}
@@ -0,0 +1,19 @@
+import { ReactElement } from 'react';
+import { Form } from "@project-gauntlet/api/components";
+
+export default function Main(): ReactElement {
+    return (
+        <Form>
+            <Form.Slider
+                label="Volume"
+                value={30}
+                min={0}
+                max={100}
+                step={5}
+                onChange={value => {
+                    console.log(`value: ${value}`)
+                }}
+            />
+        </Form>
+    );
+};